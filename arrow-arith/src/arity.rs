@@ -220,23 +220,7 @@ where
         ))));
     }
 
-    let mut builder = a.into_builder()?;
-
-    builder
-        .values_slice_mut()
-        .iter_mut()
-        .zip(b.values())
-        .for_each(|(l, r)| *l = op(*l, *r));
-
-    let array = builder.finish();
-
-    // The builder has the null buffer from `a`, it is not changed.
-    let nulls = NullBuffer::union(array.logical_nulls().as_ref(), b.logical_nulls().as_ref());
-
-    let array_builder = array.into_data().into_builder().nulls(nulls);
-
-    let array_data = unsafe { array_builder.build_unchecked() };
-    Ok(Ok(PrimitiveArray::<T>::from(array_data)))
+    a.binary_mut(b, op)
 }
 
 /// Applies the provided fallible binary operation across `a` and `b`.
@@ -0,0 +1,486 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines cumulative aggregation kernels on [`PrimitiveArray`], such as [`cumsum`]
+//!
+//! These compute a running aggregate over the values of an array, as used by
+//! window functions with an unbounded preceding frame and time-series analysis.
+
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
+use arrow_array::*;
+use arrow_buffer::{BooleanBufferBuilder, NullBuffer};
+use arrow_schema::{ArrowError, DataType};
+
+/// How null values are handled by cumulative kernels
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CumulativeNulls {
+    /// Null values are skipped, producing a null in the output without affecting
+    /// the running aggregate, e.g. `cumsum([1, null, 2]) = [1, null, 3]`
+    #[default]
+    Skip,
+    /// Null values are skipped, producing the running aggregate in the output, as
+    /// for a SQL window function with an unbounded preceding frame. The output is
+    /// only null before the first non-null value, e.g. `cumsum([null, 1, null, 2]) = [null, 1, 1, 3]`
+    Carry,
+    /// The first null value and all values following it produce nulls in the output,
+    /// e.g. `cumsum([1, null, 2]) = [1, null, null]`
+    Propagate,
+}
+
+/// Computes the running sum of `array`, returning an error on overflow
+///
+/// Supports numeric, decimal and [`DataType::Duration`] arrays. For decimals, the
+/// precision and scale of the input are preserved, and an error is returned if
+/// a sum exceeds the precision.
+///
+/// ```
+/// # use arrow_array::{Array, Int32Array};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_arith::cumulative::{cumsum, CumulativeNulls};
+/// let array = Int32Array::from(vec![Some(1), None, Some(2), Some(3)]);
+/// let result = cumsum(&array, CumulativeNulls::Skip).unwrap();
+/// let expected = Int32Array::from(vec![Some(1), None, Some(3), Some(6)]);
+/// assert_eq!(result.as_primitive::<Int32Type>(), &expected);
+///
+/// let result = cumsum(&array, CumulativeNulls::Carry).unwrap();
+/// let expected = Int32Array::from(vec![1, 1, 3, 6]);
+/// assert_eq!(result.as_primitive::<Int32Type>(), &expected);
+///
+/// let overflow = Int32Array::from(vec![i32::MAX, 1]);
+/// assert!(cumsum(&overflow, CumulativeNulls::Skip).is_err());
+/// ```
+pub fn cumsum(array: &dyn Array, nulls: CumulativeNulls) -> Result<ArrayRef, ArrowError> {
+    cumulative_op(Op::Sum, array, nulls)
+}
+
+/// Computes the running sum of `array`, wrapping on overflow for [`DataType::is_integer`]
+///
+/// See [`cumsum`] for the supported types
+pub fn cumsum_wrapping(array: &dyn Array, nulls: CumulativeNulls) -> Result<ArrayRef, ArrowError> {
+    cumulative_op(Op::SumWrapping, array, nulls)
+}
+
+/// Computes the running product of `array`, returning an error on overflow
+///
+/// Supports numeric arrays
+pub fn cumprod(array: &dyn Array, nulls: CumulativeNulls) -> Result<ArrayRef, ArrowError> {
+    cumulative_op(Op::Prod, array, nulls)
+}
+
+/// Computes the running product of `array`, wrapping on overflow for [`DataType::is_integer`]
+///
+/// Supports numeric arrays
+pub fn cumprod_wrapping(array: &dyn Array, nulls: CumulativeNulls) -> Result<ArrayRef, ArrowError> {
+    cumulative_op(Op::ProdWrapping, array, nulls)
+}
+
+/// Computes the running minimum of `array`
+///
+/// Supports numeric, decimal and temporal arrays. Floating point values are compared
+/// using IEEE 754 totalOrder, and so NaN is greater than any other value.
+pub fn cummin(array: &dyn Array, nulls: CumulativeNulls) -> Result<ArrayRef, ArrowError> {
+    cumulative_op(Op::Min, array, nulls)
+}
+
+/// Computes the running maximum of `array`
+///
+/// See [`cummin`] for the supported types
+pub fn cummax(array: &dyn Array, nulls: CumulativeNulls) -> Result<ArrayRef, ArrowError> {
+    cumulative_op(Op::Max, array, nulls)
+}
+
+/// A cumulative operation
+#[derive(Debug, Copy, Clone)]
+enum Op {
+    Sum,
+    SumWrapping,
+    Prod,
+    ProdWrapping,
+    Min,
+    Max,
+}
+
+impl std::fmt::Display for Op {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Op::Sum | Op::SumWrapping => write!(f, "cumsum"),
+            Op::Prod | Op::ProdWrapping => write!(f, "cumprod"),
+            Op::Min => write!(f, "cummin"),
+            Op::Max => write!(f, "cummax"),
+        }
+    }
+}
+
+impl Op {
+    /// Returns true if this operation is supported for all non-numeric types
+    fn is_min_max(self) -> bool {
+        matches!(self, Op::Min | Op::Max)
+    }
+
+    /// Returns true if this operation is supported for decimals and durations
+    fn is_additive(self) -> bool {
+        matches!(self, Op::Sum | Op::SumWrapping | Op::Min | Op::Max)
+    }
+}
+
+fn cumulative_op(
+    op: Op,
+    array: &dyn Array,
+    nulls: CumulativeNulls,
+) -> Result<ArrayRef, ArrowError> {
+    use arrow_schema::IntervalUnit::*;
+    use arrow_schema::TimeUnit::*;
+    use DataType::*;
+
+    match (array.data_type(), op) {
+        (Int8, _) => primitive_op::<Int8Type>(op, array, nulls),
+        (Int16, _) => primitive_op::<Int16Type>(op, array, nulls),
+        (Int32, _) => primitive_op::<Int32Type>(op, array, nulls),
+        (Int64, _) => primitive_op::<Int64Type>(op, array, nulls),
+        (UInt8, _) => primitive_op::<UInt8Type>(op, array, nulls),
+        (UInt16, _) => primitive_op::<UInt16Type>(op, array, nulls),
+        (UInt32, _) => primitive_op::<UInt32Type>(op, array, nulls),
+        (UInt64, _) => primitive_op::<UInt64Type>(op, array, nulls),
+        (Float16, _) => primitive_op::<Float16Type>(op, array, nulls),
+        (Float32, _) => primitive_op::<Float32Type>(op, array, nulls),
+        (Float64, _) => primitive_op::<Float64Type>(op, array, nulls),
+        (Decimal32(_, _), op) if op.is_additive() => decimal_op::<Decimal32Type>(op, array, nulls),
+        (Decimal64(_, _), op) if op.is_additive() => decimal_op::<Decimal64Type>(op, array, nulls),
+        (Decimal128(_, _), op) if op.is_additive() => {
+            decimal_op::<Decimal128Type>(op, array, nulls)
+        }
+        (Decimal256(_, _), op) if op.is_additive() => {
+            decimal_op::<Decimal256Type>(op, array, nulls)
+        }
+        (Duration(Second), op) if op.is_additive() => {
+            primitive_op::<DurationSecondType>(op, array, nulls)
+        }
+        (Duration(Millisecond), op) if op.is_additive() => {
+            primitive_op::<DurationMillisecondType>(op, array, nulls)
+        }
+        (Duration(Microsecond), op) if op.is_additive() => {
+            primitive_op::<DurationMicrosecondType>(op, array, nulls)
+        }
+        (Duration(Nanosecond), op) if op.is_additive() => {
+            primitive_op::<DurationNanosecondType>(op, array, nulls)
+        }
+        (Date32, op) if op.is_min_max() => primitive_op::<Date32Type>(op, array, nulls),
+        (Date64, op) if op.is_min_max() => primitive_op::<Date64Type>(op, array, nulls),
+        (Time32(Second), op) if op.is_min_max() => {
+            primitive_op::<Time32SecondType>(op, array, nulls)
+        }
+        (Time32(Millisecond), op) if op.is_min_max() => {
+            primitive_op::<Time32MillisecondType>(op, array, nulls)
+        }
+        (Time64(Microsecond), op) if op.is_min_max() => {
+            primitive_op::<Time64MicrosecondType>(op, array, nulls)
+        }
+        (Time64(Nanosecond), op) if op.is_min_max() => {
+            primitive_op::<Time64NanosecondType>(op, array, nulls)
+        }
+        (Timestamp(Second, _), op) if op.is_min_max() => {
+            primitive_op::<TimestampSecondType>(op, array, nulls)
+        }
+        (Timestamp(Millisecond, _), op) if op.is_min_max() => {
+            primitive_op::<TimestampMillisecondType>(op, array, nulls)
+        }
+        (Timestamp(Microsecond, _), op) if op.is_min_max() => {
+            primitive_op::<TimestampMicrosecondType>(op, array, nulls)
+        }
+        (Timestamp(Nanosecond, _), op) if op.is_min_max() => {
+            primitive_op::<TimestampNanosecondType>(op, array, nulls)
+        }
+        (Interval(YearMonth), op) if op.is_min_max() => {
+            primitive_op::<IntervalYearMonthType>(op, array, nulls)
+        }
+        (t, op) => Err(ArrowError::InvalidArgumentError(format!(
+            "Invalid cumulative operation: {op} of {t}"
+        ))),
+    }
+}
+
+fn primitive_op<T: ArrowPrimitiveType>(
+    op: Op,
+    array: &dyn Array,
+    nulls: CumulativeNulls,
+) -> Result<ArrayRef, ArrowError> {
+    Ok(Arc::new(scan_op(op, array.as_primitive::<T>(), nulls)?))
+}
+
+fn decimal_op<T: DecimalType>(
+    op: Op,
+    array: &dyn Array,
+    nulls: CumulativeNulls,
+) -> Result<ArrayRef, ArrowError> {
+    let array = array.as_primitive::<T>();
+    let result = scan_op(op, array, nulls)?;
+    if matches!(op, Op::Sum) {
+        result.validate_decimal_precision(array.precision())?;
+    }
+    Ok(Arc::new(result))
+}
+
+fn scan_op<T: ArrowPrimitiveType>(
+    op: Op,
+    array: &PrimitiveArray<T>,
+    nulls: CumulativeNulls,
+) -> Result<PrimitiveArray<T>, ArrowError> {
+    match op {
+        Op::Sum => scan(array, nulls, |a, b| a.add_checked(b)),
+        Op::SumWrapping => scan(array, nulls, |a, b| Ok(a.add_wrapping(b))),
+        Op::Prod => scan(array, nulls, |a, b| a.mul_checked(b)),
+        Op::ProdWrapping => scan(array, nulls, |a, b| Ok(a.mul_wrapping(b))),
+        Op::Min => scan(array, nulls, |a, b| Ok(if b.is_lt(a) { b } else { a })),
+        Op::Max => scan(array, nulls, |a, b| Ok(if b.is_gt(a) { b } else { a })),
+    }
+}
+
+/// Computes the running aggregate of `array`, combining the aggregate of the
+/// preceding values with the next value using `op`
+fn scan<T, F>(
+    array: &PrimitiveArray<T>,
+    nulls: CumulativeNulls,
+    op: F,
+) -> Result<PrimitiveArray<T>, ArrowError>
+where
+    T: ArrowPrimitiveType,
+    F: Fn(T::Native, T::Native) -> Result<T::Native, ArrowError>,
+{
+    let data_type = array.data_type().clone();
+    let input_nulls = match array.nulls().filter(|n| n.null_count() > 0) {
+        Some(n) => n,
+        None => {
+            let mut acc = None;
+            let values = array
+                .values()
+                .iter()
+                .map(|v| {
+                    let next = match acc {
+                        Some(acc) => op(acc, *v)?,
+                        None => *v,
+                    };
+                    acc = Some(next);
+                    Ok(next)
+                })
+                .collect::<Result<Vec<_>, ArrowError>>()?;
+            return Ok(PrimitiveArray::new(values.into(), None).with_data_type(data_type));
+        }
+    };
+
+    let len = array.len();
+    let mut values = Vec::with_capacity(len);
+    let mut validity = BooleanBufferBuilder::new(len);
+    let mut acc: Option<T::Native> = None;
+    for (v, is_valid) in array.values().iter().zip(input_nulls.iter()) {
+        if !is_valid {
+            match nulls {
+                CumulativeNulls::Skip => {
+                    values.push(T::default_value());
+                    validity.append(false);
+                }
+                CumulativeNulls::Carry => {
+                    values.push(acc.unwrap_or_else(T::default_value));
+                    validity.append(acc.is_some());
+                }
+                CumulativeNulls::Propagate => {
+                    // All subsequent values are null
+                    values.resize(len, T::default_value());
+                    validity.append_n(len - validity.len(), false);
+                    break;
+                }
+            }
+            continue;
+        }
+        let next = match acc {
+            Some(acc) => op(acc, *v)?,
+            None => *v,
+        };
+        acc = Some(next);
+        values.push(next);
+        validity.append(true);
+    }
+
+    let nulls = NullBuffer::new(validity.finish());
+    Ok(PrimitiveArray::new(values.into(), Some(nulls)).with_data_type(data_type))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_buffer::i256;
+
+    fn check<T: ArrowPrimitiveType>(result: ArrayRef, expected: PrimitiveArray<T>) {
+        assert_eq!(result.as_primitive::<T>(), &expected);
+    }
+
+    #[test]
+    fn test_null_handling() {
+        let array = Int64Array::from(vec![None, Some(3), None, Some(1), Some(5), None]);
+        let cases = [
+            (
+                CumulativeNulls::Skip,
+                vec![None, Some(3), None, Some(4), Some(9), None],
+                vec![None, Some(3), None, Some(1), Some(1), None],
+            ),
+            (
+                CumulativeNulls::Carry,
+                vec![None, Some(3), Some(3), Some(4), Some(9), Some(9)],
+                vec![None, Some(3), Some(3), Some(1), Some(1), Some(1)],
+            ),
+            (CumulativeNulls::Propagate, vec![None; 6], vec![None; 6]),
+        ];
+        for (nulls, sum, min) in cases {
+            check(cumsum(&array, nulls).unwrap(), Int64Array::from(sum));
+            check(cummin(&array, nulls).unwrap(), Int64Array::from(min));
+        }
+
+        let array = Int64Array::from(vec![Some(2), Some(3), None, Some(4)]);
+        let expected = Int64Array::from(vec![Some(2), Some(6), None, None]);
+        check(
+            cumprod(&array, CumulativeNulls::Propagate).unwrap(),
+            expected,
+        );
+        let expected = Int64Array::from(vec![Some(2), Some(3), Some(3), Some(4)]);
+        check(cummax(&array, CumulativeNulls::Carry).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_numeric() {
+        let array = Int32Array::from(vec![2, -1, 3, 5]);
+        check(
+            cumsum(&array, CumulativeNulls::Skip).unwrap(),
+            Int32Array::from(vec![2, 1, 4, 9]),
+        );
+        check(
+            cumprod(&array, CumulativeNulls::Skip).unwrap(),
+            Int32Array::from(vec![2, -2, -6, -30]),
+        );
+        check(
+            cummin(&array, CumulativeNulls::Skip).unwrap(),
+            Int32Array::from(vec![2, -1, -1, -1]),
+        );
+        check(
+            cummax(&array, CumulativeNulls::Skip).unwrap(),
+            Int32Array::from(vec![2, 2, 3, 5]),
+        );
+
+        let array = UInt8Array::from(vec![200, 100]);
+        let err = cumsum(&array, CumulativeNulls::Skip).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Arithmetic overflow: Overflow happened on: 200 + 100"
+        );
+        check(
+            cumsum_wrapping(&array, CumulativeNulls::Skip).unwrap(),
+            UInt8Array::from(vec![200, 44]),
+        );
+        assert!(cumprod(&array, CumulativeNulls::Skip).is_err());
+        check(
+            cumprod_wrapping(&array, CumulativeNulls::Skip).unwrap(),
+            UInt8Array::from(vec![200, 32]),
+        );
+
+        let array = Float64Array::from(vec![1.5, f64::NAN, -2.0]);
+        let result = cumsum(&array, CumulativeNulls::Skip).unwrap();
+        let result = result.as_primitive::<Float64Type>();
+        assert_eq!(result.value(0), 1.5);
+        assert!(result.value(1).is_nan() && result.value(2).is_nan());
+        let result = cummax(&array, CumulativeNulls::Skip).unwrap();
+        let result = result.as_primitive::<Float64Type>();
+        assert!(result.value(1).is_nan() && result.value(2).is_nan());
+        let result = cummin(&array, CumulativeNulls::Skip).unwrap();
+        check(result, Float64Array::from(vec![1.5, 1.5, -2.0]));
+
+        let empty = Int32Array::from(Vec::<i32>::new());
+        assert!(cumsum(&empty, CumulativeNulls::Skip).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_decimal() {
+        let array = Decimal128Array::from(vec![Some(999), None, Some(1), Some(-5)])
+            .with_precision_and_scale(4, 2)
+            .unwrap();
+        let result = cumsum(&array, CumulativeNulls::Skip).unwrap();
+        assert_eq!(result.data_type(), &DataType::Decimal128(4, 2));
+        let expected = Decimal128Array::from(vec![Some(999), None, Some(1000), Some(995)])
+            .with_precision_and_scale(4, 2)
+            .unwrap();
+        check(result, expected);
+
+        let array = Decimal128Array::from(vec![9999, 1])
+            .with_precision_and_scale(4, 2)
+            .unwrap();
+        let err = cumsum(&array, CumulativeNulls::Skip).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("too large to store in a Decimal128 of precision 4"),
+            "{err}"
+        );
+        cumsum_wrapping(&array, CumulativeNulls::Skip).unwrap();
+
+        let array = Decimal256Array::from(vec![i256::from(5), i256::from(2)]);
+        let expected = Decimal256Array::from(vec![i256::from(5), i256::from(2)]);
+        check(cummin(&array, CumulativeNulls::Skip).unwrap(), expected);
+
+        let err = cumprod(&array, CumulativeNulls::Skip).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Invalid cumulative operation: cumprod of Decimal256(76, 10)"
+        );
+    }
+
+    #[test]
+    fn test_temporal() {
+        let array = TimestampSecondArray::from(vec![Some(10), Some(5), None, Some(20)])
+            .with_timezone("+01:00");
+        let result = cummax(&array, CumulativeNulls::Skip).unwrap();
+        let expected = TimestampSecondArray::from(vec![Some(10), Some(10), None, Some(20)])
+            .with_timezone("+01:00");
+        check(result, expected);
+
+        let err = cumsum(&array, CumulativeNulls::Skip).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Invalid cumulative operation: cumsum of Timestamp(Second, Some(\"+01:00\"))"
+        );
+
+        let array = DurationMillisecondArray::from(vec![100, 250]);
+        let result = cumsum(&array, CumulativeNulls::Skip).unwrap();
+        check(result, DurationMillisecondArray::from(vec![100, 350]));
+
+        let array = Date32Array::from(vec![3, 1, 2]);
+        check(
+            cummin(&array, CumulativeNulls::Skip).unwrap(),
+            Date32Array::from(vec![3, 1, 1]),
+        );
+    }
+
+    #[test]
+    fn test_unsupported() {
+        let array = StringArray::from(vec!["a"]);
+        let err = cummin(&array, CumulativeNulls::Skip).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Invalid cumulative operation: cummin of Utf8"
+        );
+    }
+}
@@ -0,0 +1,572 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Decimal arithmetic kernels that infer the result precision and scale
+//!
+//! Unlike the kernels in [`crate::numeric`], the inputs may be decimals of different
+//! widths, and the result precision and scale follow the rules used by SQL Server and
+//! Spark SQL, where `p1`, `s1` and `p2`, `s2` are the precision and scale of the inputs:
+//!
+//! | Operation | Result precision                      | Result scale          |
+//! |-----------|---------------------------------------|-----------------------|
+//! | `+`, `-`  | `max(s1, s2) + max(p1-s1, p2-s2) + 1` | `max(s1, s2)`         |
+//! | `*`       | `p1 + p2 + 1`                         | `s1 + s2`             |
+//! | `/`       | `p1 - s1 + s2 + max(6, s1 + p2 + 1)`  | `max(6, s1 + p2 + 1)` |
+//!
+//! The result is of the wider of the two input types. If the result precision exceeds the
+//! maximum precision of that type, it is reduced to the maximum, and the scale is reduced to
+//! preserve the integral digits, but not below `min(scale, 6)`. Results are rounded half
+//! away from zero to the result scale.
+//!
+//! Results that do not fit in the result precision are handled according to
+//! [`DecimalOverflow`]
+//!
+//! ```
+//! # use arrow_array::{Decimal128Array, Decimal32Array};
+//! # use arrow_array::cast::AsArray;
+//! # use arrow_array::types::Decimal128Type;
+//! # use arrow_arith::decimal::{add, DecimalOverflow};
+//! # use arrow_schema::DataType;
+//! let a = Decimal32Array::from(vec![123]).with_precision_and_scale(5, 2).unwrap(); // 1.23
+//! let b = Decimal128Array::from(vec![1]).with_precision_and_scale(10, 1).unwrap(); // 0.1
+//!
+//! let result = add(&a, &b, DecimalOverflow::Error).unwrap();
+//! assert_eq!(result.data_type(), &DataType::Decimal128(12, 2));
+//! assert_eq!(result.as_primitive::<Decimal128Type>().value(0), 133); // 1.33
+//! ```
+
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
+use arrow_array::*;
+use arrow_buffer::{i256, NullBuffer};
+use arrow_data::decimal::MAX_DECIMAL256_FOR_EACH_PRECISION;
+use arrow_schema::{
+    ArrowError, DataType, DECIMAL128_MAX_PRECISION, DECIMAL256_MAX_PRECISION,
+    DECIMAL32_MAX_PRECISION, DECIMAL64_MAX_PRECISION,
+};
+
+/// The minimum scale of a result whose scale is reduced to fit its precision
+const MIN_ADJUSTED_SCALE: i32 = 6;
+
+/// A decimal arithmetic operation
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum DecimalOp {
+    /// `lhs + rhs`
+    Add,
+    /// `lhs - rhs`
+    Sub,
+    /// `lhs * rhs`
+    Mul,
+    /// `lhs / rhs`
+    Div,
+}
+
+impl std::fmt::Display for DecimalOp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Add => write!(f, "+"),
+            Self::Sub => write!(f, "-"),
+            Self::Mul => write!(f, "*"),
+            Self::Div => write!(f, "/"),
+        }
+    }
+}
+
+/// How to handle results that do not fit in the result precision
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub enum DecimalOverflow {
+    /// Return an error
+    #[default]
+    Error,
+    /// Return the largest or smallest value of the result precision
+    Saturate,
+    /// Return a [`DataType::Decimal256`] if the result precision would exceed the maximum
+    /// precision of the wider input type, returning an error if it still overflows
+    PromoteToDecimal256,
+}
+
+/// Perform `lhs + rhs` on decimal [`Datum`], see the [module docs](self)
+pub fn add(
+    lhs: &dyn Datum,
+    rhs: &dyn Datum,
+    overflow: DecimalOverflow,
+) -> Result<ArrayRef, ArrowError> {
+    decimal_arithmetic(DecimalOp::Add, lhs, rhs, overflow)
+}
+
+/// Perform `lhs - rhs` on decimal [`Datum`], see the [module docs](self)
+pub fn sub(
+    lhs: &dyn Datum,
+    rhs: &dyn Datum,
+    overflow: DecimalOverflow,
+) -> Result<ArrayRef, ArrowError> {
+    decimal_arithmetic(DecimalOp::Sub, lhs, rhs, overflow)
+}
+
+/// Perform `lhs * rhs` on decimal [`Datum`], see the [module docs](self)
+pub fn mul(
+    lhs: &dyn Datum,
+    rhs: &dyn Datum,
+    overflow: DecimalOverflow,
+) -> Result<ArrayRef, ArrowError> {
+    decimal_arithmetic(DecimalOp::Mul, lhs, rhs, overflow)
+}
+
+/// Perform `lhs / rhs` on decimal [`Datum`], see the [module docs](self)
+///
+/// Returns an error if a valid `rhs` is zero
+pub fn div(
+    lhs: &dyn Datum,
+    rhs: &dyn Datum,
+    overflow: DecimalOverflow,
+) -> Result<ArrayRef, ArrowError> {
+    decimal_arithmetic(DecimalOp::Div, lhs, rhs, overflow)
+}
+
+/// Returns the [`DataType`] of the result of `op` on decimals of type `lhs` and `rhs`
+///
+/// ```
+/// # use arrow_arith::decimal::{result_type, DecimalOp, DecimalOverflow};
+/// # use arrow_schema::DataType;
+/// let l = DataType::Decimal128(38, 10);
+/// let r = DataType::Decimal128(38, 10);
+/// let t = result_type(DecimalOp::Mul, &l, &r, DecimalOverflow::Error).unwrap();
+/// assert_eq!(t, DataType::Decimal128(38, 6));
+/// let t = result_type(DecimalOp::Mul, &l, &r, DecimalOverflow::PromoteToDecimal256).unwrap();
+/// assert_eq!(t, DataType::Decimal256(76, 19));
+/// ```
+pub fn result_type(
+    op: DecimalOp,
+    lhs: &DataType,
+    rhs: &DataType,
+    overflow: DecimalOverflow,
+) -> Result<DataType, ArrowError> {
+    let (p1, s1, w1) = decimal_type(op, lhs, rhs, lhs)?;
+    let (p2, s2, w2) = decimal_type(op, lhs, rhs, rhs)?;
+
+    let (s1, s2) = (s1 as i32, s2 as i32);
+    let (p1, p2) = (p1 as i32, p2 as i32);
+    let (precision, scale) = match op {
+        DecimalOp::Add | DecimalOp::Sub => {
+            let scale = s1.max(s2);
+            ((p1 - s1).max(p2 - s2) + scale + 1, scale)
+        }
+        DecimalOp::Mul => (p1 + p2 + 1, s1 + s2),
+        DecimalOp::Div => {
+            let scale = MIN_ADJUSTED_SCALE.max(s1 + p2 + 1);
+            (p1 - s1 + s2 + scale, scale)
+        }
+    };
+
+    let mut max_precision = w1.max(w2);
+    if precision > max_precision as i32 && overflow == DecimalOverflow::PromoteToDecimal256 {
+        max_precision = DECIMAL256_MAX_PRECISION;
+    }
+
+    let (precision, scale) = match precision > max_precision as i32 {
+        true => {
+            let integral = precision - scale;
+            let min_scale = scale.min(MIN_ADJUSTED_SCALE);
+            let scale = (max_precision as i32 - integral).max(min_scale);
+            (max_precision, scale as i8)
+        }
+        false => (precision as u8, scale as i8),
+    };
+
+    Ok(match max_precision {
+        DECIMAL32_MAX_PRECISION => DataType::Decimal32(precision, scale),
+        DECIMAL64_MAX_PRECISION => DataType::Decimal64(precision, scale),
+        DECIMAL128_MAX_PRECISION => DataType::Decimal128(precision, scale),
+        _ => DataType::Decimal256(precision, scale),
+    })
+}
+
+/// Returns the precision, scale and maximum precision of the decimal `t`
+fn decimal_type(
+    op: DecimalOp,
+    lhs: &DataType,
+    rhs: &DataType,
+    t: &DataType,
+) -> Result<(u8, i8, u8), ArrowError> {
+    match t {
+        DataType::Decimal32(p, s) => Ok((*p, *s, DECIMAL32_MAX_PRECISION)),
+        DataType::Decimal64(p, s) => Ok((*p, *s, DECIMAL64_MAX_PRECISION)),
+        DataType::Decimal128(p, s) => Ok((*p, *s, DECIMAL128_MAX_PRECISION)),
+        DataType::Decimal256(p, s) => Ok((*p, *s, DECIMAL256_MAX_PRECISION)),
+        _ => Err(ArrowError::InvalidArgumentError(format!(
+            "Invalid decimal arithmetic operation: {lhs} {op} {rhs}"
+        ))),
+    }
+}
+
+/// Returns a function returning the value at an index of the decimal array `a` as an [`i256`]
+fn decimal_values(a: &dyn Array) -> Box<dyn Fn(usize) -> i256 + '_> {
+    match a.data_type() {
+        DataType::Decimal32(_, _) => {
+            let a = a.as_primitive::<Decimal32Type>();
+            Box::new(move |i| i256::from_i128(a.value(i) as i128))
+        }
+        DataType::Decimal64(_, _) => {
+            let a = a.as_primitive::<Decimal64Type>();
+            Box::new(move |i| i256::from_i128(a.value(i) as i128))
+        }
+        DataType::Decimal128(_, _) => {
+            let a = a.as_primitive::<Decimal128Type>();
+            Box::new(move |i| i256::from_i128(a.value(i)))
+        }
+        DataType::Decimal256(_, _) => {
+            let a = a.as_primitive::<Decimal256Type>();
+            Box::new(move |i| a.value(i))
+        }
+        d => unreachable!("expected decimal, got {d}"),
+    }
+}
+
+/// Returns `10^exp`, or `None` on overflow
+fn pow10(exp: i32) -> Option<i256> {
+    i256::from_i128(10).checked_pow(exp.try_into().ok()?)
+}
+
+/// Returns `num / den` rounded half away from zero, or `None` on overflow
+fn div_round(num: i256, den: i256) -> Option<i256> {
+    let quotient = num.checked_div(den)?;
+    let remainder = num.checked_rem(den)?;
+    match remainder.wrapping_abs().checked_mul(i256::from_i128(2))? >= den.wrapping_abs() {
+        true => quotient.checked_add(num.signum() * den.signum()),
+        false => Some(quotient),
+    }
+}
+
+/// Rescale `v` from scale `from` to scale `to`, returning `None` on overflow
+fn rescale(v: i256, from: i32, to: i32) -> Option<i256> {
+    match from.cmp(&to) {
+        std::cmp::Ordering::Equal => Some(v),
+        std::cmp::Ordering::Less => v.checked_mul(pow10(to - from)?),
+        std::cmp::Ordering::Greater => div_round(v, pow10(from - to)?),
+    }
+}
+
+fn decimal_arithmetic(
+    op: DecimalOp,
+    lhs: &dyn Datum,
+    rhs: &dyn Datum,
+    overflow: DecimalOverflow,
+) -> Result<ArrayRef, ArrowError> {
+    let (l, l_scalar) = lhs.get();
+    let (r, r_scalar) = rhs.get();
+    let result_type = result_type(op, l.data_type(), r.data_type(), overflow)?;
+    let (precision, scale) = match result_type {
+        DataType::Decimal32(p, s)
+        | DataType::Decimal64(p, s)
+        | DataType::Decimal128(p, s)
+        | DataType::Decimal256(p, s) => (p, s as i32),
+        _ => unreachable!(),
+    };
+    let (s1, s2) = match (l.data_type(), r.data_type()) {
+        (
+            DataType::Decimal32(_, s1)
+            | DataType::Decimal64(_, s1)
+            | DataType::Decimal128(_, s1)
+            | DataType::Decimal256(_, s1),
+            DataType::Decimal32(_, s2)
+            | DataType::Decimal64(_, s2)
+            | DataType::Decimal128(_, s2)
+            | DataType::Decimal256(_, s2),
+        ) => (*s1 as i32, *s2 as i32),
+        _ => unreachable!(),
+    };
+
+    let len = match (l_scalar, r_scalar) {
+        (true, true) => 1,
+        (true, false) => r.len(),
+        (false, true) => l.len(),
+        (false, false) if l.len() == r.len() => l.len(),
+        (false, false) => {
+            return Err(ArrowError::ComputeError(
+                "Cannot perform binary operation on arrays of different length".to_string(),
+            ))
+        }
+    };
+    if (l_scalar && l.is_null(0)) || (r_scalar && r.is_null(0)) {
+        return Ok(new_null_array(&result_type, len));
+    }
+    let nulls = NullBuffer::union(
+        (!l_scalar).then(|| l.logical_nulls()).flatten().as_ref(),
+        (!r_scalar).then(|| r.logical_nulls()).flatten().as_ref(),
+    );
+
+    let l_values = decimal_values(l);
+    let r_values = decimal_values(r);
+    let max = MAX_DECIMAL256_FOR_EACH_PRECISION[precision as usize];
+
+    // Computes the result at `scale`, returning `Err(negative)` on overflow
+    let compute = |l: i256, r: i256| -> Result<i256, bool> {
+        let negative = match op {
+            DecimalOp::Add => l.is_negative(),
+            DecimalOp::Sub => l.is_negative() || (l == i256::ZERO && r.is_positive()),
+            DecimalOp::Mul | DecimalOp::Div => l.is_negative() != r.is_negative(),
+        };
+        let v = match op {
+            DecimalOp::Add | DecimalOp::Sub => {
+                let natural = s1.max(s2);
+                let l = l.checked_mul(pow10(natural - s1).ok_or(negative)?);
+                let r = r.checked_mul(pow10(natural - s2).ok_or(negative)?);
+                let v = match op {
+                    DecimalOp::Add => l.zip(r).and_then(|(l, r)| l.checked_add(r)),
+                    _ => l.zip(r).and_then(|(l, r)| l.checked_sub(r)),
+                };
+                v.and_then(|v| rescale(v, natural, scale))
+            }
+            DecimalOp::Mul => l.checked_mul(r).and_then(|v| rescale(v, s1 + s2, scale)),
+            DecimalOp::Div => {
+                let exp = scale - s1 + s2;
+                match exp >= 0 {
+                    true => pow10(exp)
+                        .and_then(|m| l.checked_mul(m))
+                        .and_then(|l| div_round(l, r)),
+                    false => pow10(-exp)
+                        .and_then(|m| r.checked_mul(m))
+                        .and_then(|r| div_round(l, r)),
+                }
+            }
+        };
+        match v {
+            Some(v) if v <= max && v >= max.wrapping_neg() => Ok(v),
+            Some(v) => Err(v.is_negative()),
+            None => Err(negative),
+        }
+    };
+
+    let mut values = Vec::with_capacity(len);
+    for i in 0..len {
+        if nulls.as_ref().is_some_and(|n| n.is_null(i)) {
+            values.push(i256::ZERO);
+            continue;
+        }
+        let l = l_values(if l_scalar { 0 } else { i });
+        let r = r_values(if r_scalar { 0 } else { i });
+        if op == DecimalOp::Div && r == i256::ZERO {
+            return Err(ArrowError::DivideByZero);
+        }
+        let v = match compute(l, r) {
+            Ok(v) => v,
+            Err(negative) if overflow == DecimalOverflow::Saturate => match negative {
+                true => max.wrapping_neg(),
+                false => max,
+            },
+            Err(_) => {
+                return Err(ArrowError::ArithmeticOverflow(format!(
+                    "Overflow happened on: {l} {op} {r}, the result does not fit in {result_type}"
+                )))
+            }
+        };
+        values.push(v);
+    }
+
+    Ok(match result_type {
+        DataType::Decimal32(_, _) => Arc::new(
+            Decimal32Array::new(values.iter().map(|v| v.as_i128() as i32).collect(), nulls)
+                .with_data_type(result_type),
+        ),
+        DataType::Decimal64(_, _) => Arc::new(
+            Decimal64Array::new(values.iter().map(|v| v.as_i128() as i64).collect(), nulls)
+                .with_data_type(result_type),
+        ),
+        DataType::Decimal128(_, _) => Arc::new(
+            Decimal128Array::new(values.iter().map(|v| v.as_i128()).collect(), nulls)
+                .with_data_type(result_type),
+        ),
+        _ => Arc::new(Decimal256Array::new(values.into(), nulls).with_data_type(result_type)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decimal128(values: Vec<Option<i128>>, p: u8, s: i8) -> Decimal128Array {
+        Decimal128Array::from(values)
+            .with_precision_and_scale(p, s)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_result_type() {
+        use DataType::*;
+        use DecimalOp::*;
+        let error = DecimalOverflow::Error;
+        let promote = DecimalOverflow::PromoteToDecimal256;
+        let cases = [
+            (
+                Add,
+                Decimal32(5, 2),
+                Decimal64(7, 4),
+                error,
+                Decimal64(8, 4),
+            ),
+            (
+                Sub,
+                Decimal128(38, 10),
+                Decimal128(38, 2),
+                error,
+                Decimal128(38, 6),
+            ),
+            (
+                Mul,
+                Decimal32(5, 2),
+                Decimal32(4, 1),
+                error,
+                Decimal32(9, 3),
+            ),
+            (
+                Mul,
+                Decimal32(9, 2),
+                Decimal32(4, 1),
+                error,
+                Decimal32(9, 3),
+            ),
+            (
+                Mul,
+                Decimal32(9, 2),
+                Decimal32(4, 1),
+                promote,
+                Decimal256(14, 3),
+            ),
+            (
+                Div,
+                Decimal128(10, 2),
+                Decimal128(5, 1),
+                error,
+                Decimal128(17, 8),
+            ),
+            (
+                Div,
+                Decimal128(38, 10),
+                Decimal128(38, 10),
+                error,
+                Decimal128(38, 6),
+            ),
+            (
+                Div,
+                Decimal128(38, 0),
+                Decimal128(38, 0),
+                error,
+                Decimal128(38, 6),
+            ),
+        ];
+        for (op, l, r, overflow, expected) in cases {
+            let actual = result_type(op, &l, &r, overflow).unwrap();
+            assert_eq!(actual, expected, "{l} {op} {r}");
+        }
+
+        let err = result_type(Add, &Decimal128(10, 2), &Int32, error).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Invalid decimal arithmetic operation: Decimal128(10, 2) + Int32"
+        );
+    }
+
+    #[test]
+    fn test_decimal_arithmetic() {
+        // [1.25, null, -2.50]
+        let a = decimal128(vec![Some(125), None, Some(-250)], 5, 2);
+        // [0.3, 0.5, 0.4]
+        let b = Decimal32Array::from(vec![3, 5, 4])
+            .with_precision_and_scale(3, 1)
+            .unwrap();
+
+        let r = add(&a, &b, DecimalOverflow::Error).unwrap();
+        assert_eq!(r.data_type(), &DataType::Decimal128(6, 2));
+        assert_eq!(
+            r.as_ref(),
+            &decimal128(vec![Some(155), None, Some(-210)], 6, 2)
+        );
+
+        let r = sub(&b, &a, DecimalOverflow::Error).unwrap();
+        assert_eq!(
+            r.as_ref(),
+            &decimal128(vec![Some(-95), None, Some(290)], 6, 2)
+        );
+
+        let r = mul(&a, &b, DecimalOverflow::Error).unwrap();
+        assert_eq!(r.data_type(), &DataType::Decimal128(9, 3));
+        assert_eq!(
+            r.as_ref(),
+            &decimal128(vec![Some(375), None, Some(-1000)], 9, 3)
+        );
+
+        // Decimal128(5 - 2 + 1 + 6, 6)
+        let r = div(&a, &b, DecimalOverflow::Error).unwrap();
+        assert_eq!(r.data_type(), &DataType::Decimal128(10, 6));
+        let expected = decimal128(vec![Some(4_166_667), None, Some(-6_250_000)], 10, 6);
+        assert_eq!(r.as_ref(), &expected);
+
+        let scalar = Scalar::new(decimal128(vec![Some(1)], 1, 0));
+        let r = div(&scalar, &a, DecimalOverflow::Error).unwrap();
+        let expected = decimal128(vec![Some(800_000), None, Some(-400_000)], 9, 6);
+        assert_eq!(r.as_ref(), &expected);
+
+        let null = Scalar::new(decimal128(vec![None], 1, 0));
+        let r = add(&a, &null, DecimalOverflow::Error).unwrap();
+        assert_eq!(r.null_count(), 3);
+
+        let zero = Scalar::new(decimal128(vec![Some(0)], 1, 0));
+        let err = div(&a, &zero, DecimalOverflow::Error).unwrap_err();
+        assert_eq!(err.to_string(), "Divide by zero error");
+    }
+
+    #[test]
+    fn test_decimal_overflow() {
+        let max = Decimal32Array::from(vec![999_999_999, -999_999_999])
+            .with_precision_and_scale(9, 0)
+            .unwrap();
+        let two = Scalar::new(
+            Decimal32Array::from(vec![2])
+                .with_precision_and_scale(1, 0)
+                .unwrap(),
+        );
+
+        let err = mul(&max, &two, DecimalOverflow::Error).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Arithmetic overflow: Overflow happened on: 999999999 * 2, the result does not fit in Decimal32(9, 0)"
+        );
+
+        let r = mul(&max, &two, DecimalOverflow::Saturate).unwrap();
+        let expected = Decimal32Array::from(vec![999_999_999, -999_999_999])
+            .with_precision_and_scale(9, 0)
+            .unwrap();
+        assert_eq!(r.as_ref(), &expected);
+
+        let r = mul(&max, &two, DecimalOverflow::PromoteToDecimal256).unwrap();
+        let expected = Decimal256Array::from(vec![
+            i256::from_i128(1_999_999_998),
+            i256::from_i128(-1_999_999_998),
+        ])
+        .with_precision_and_scale(11, 0)
+        .unwrap();
+        assert_eq!(r.as_ref(), &expected);
+
+        // Scale is reduced to fit the precision, rounding half away from zero
+        let a = decimal128(vec![Some(15), Some(-15)], 38, 10);
+        let b = decimal128(vec![Some(10_i128.pow(13)); 2], 38, 10);
+        let r = mul(&a, &b, DecimalOverflow::Error).unwrap();
+        assert_eq!(r.as_ref(), &decimal128(vec![Some(2), Some(-2)], 38, 6));
+    }
+}
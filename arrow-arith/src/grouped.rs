@@ -0,0 +1,421 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines grouped aggregation kernels, such as [`aggregate_by_group`]
+//!
+//! These accumulate the values of an array into one output slot per group, in a
+//! single pass, as used by hash aggregation in query engines.
+
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
+use arrow_array::*;
+use arrow_buffer::{BooleanBufferBuilder, NullBuffer};
+use arrow_schema::{ArrowError, DataType};
+
+/// A grouped aggregation computed by [`aggregate_by_group`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Agg {
+    /// The sum of the non-null values of each group, wrapping on overflow as for
+    /// [`crate::aggregate::sum`]
+    Sum,
+    /// The minimum non-null value of each group
+    Min,
+    /// The maximum non-null value of each group
+    Max,
+    /// The number of non-null values of each group, as an [`Int64Array`]
+    Count,
+}
+
+impl std::fmt::Display for Agg {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Agg::Sum => write!(f, "sum"),
+            Agg::Min => write!(f, "min"),
+            Agg::Max => write!(f, "max"),
+            Agg::Count => write!(f, "count"),
+        }
+    }
+}
+
+/// Aggregates `values` into `num_groups` groups, where the value at index `i`
+/// belongs to the group `group_ids[i]`
+///
+/// Returns an array of length `num_groups` containing the aggregate of each group.
+/// Null values are ignored, and the result is null for a group without any non-null
+/// values, except for [`Agg::Count`] which is never null.
+///
+/// Supports the following types:
+///
+/// * [`Agg::Sum`]: numeric, decimal and [`DataType::Duration`] arrays. The type of the
+///   input, including the precision and scale of decimals, is preserved
+/// * [`Agg::Min`] and [`Agg::Max`]: primitive and byte arrays, including byte views.
+///   Floating point values are compared using IEEE 754 totalOrder
+/// * [`Agg::Count`]: all arrays
+///
+/// Returns an error if `group_ids` and `values` have different lengths, or a group
+/// id is not less than `num_groups`.
+///
+/// ```
+/// # use arrow_array::{Int32Array, StringArray};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_arith::grouped::{aggregate_by_group, Agg};
+/// let values = Int32Array::from(vec![Some(1), Some(2), None, Some(4)]);
+/// let group_ids = [0, 1, 2, 0];
+///
+/// let sums = aggregate_by_group(&values, &group_ids, 3, Agg::Sum).unwrap();
+/// let expected = Int32Array::from(vec![Some(5), Some(2), None]);
+/// assert_eq!(sums.as_primitive::<Int32Type>(), &expected);
+///
+/// let values = StringArray::from(vec!["b", "a", "c", "d"]);
+/// let min = aggregate_by_group(&values, &group_ids, 3, Agg::Min).unwrap();
+/// assert_eq!(min.as_string::<i32>(), &StringArray::from(vec!["b", "a", "c"]));
+/// ```
+pub fn aggregate_by_group(
+    values: &dyn Array,
+    group_ids: &[usize],
+    num_groups: usize,
+    agg: Agg,
+) -> Result<ArrayRef, ArrowError> {
+    if group_ids.len() != values.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Cannot aggregate array of length {} by {} group ids",
+            values.len(),
+            group_ids.len()
+        )));
+    }
+    if let Some(g) = group_ids.iter().find(|g| **g >= num_groups) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Group id {g} out of bounds for {num_groups} groups"
+        )));
+    }
+
+    let groups = Groups {
+        ids: group_ids,
+        num_groups,
+        nulls: values.logical_nulls(),
+    };
+
+    match agg {
+        Agg::Count => Ok(Arc::new(groups.count())),
+        Agg::Sum => sum(values, &groups),
+        Agg::Min | Agg::Max => min_max(values, &groups, agg),
+    }
+}
+
+/// The group ids of the values to aggregate
+struct Groups<'a> {
+    ids: &'a [usize],
+    num_groups: usize,
+    nulls: Option<NullBuffer>,
+}
+
+impl Groups<'_> {
+    /// Calls `f` with the index and group id of each non-null value
+    #[inline]
+    fn for_each_valid(&self, mut f: impl FnMut(usize, usize)) {
+        match self.nulls.as_ref().filter(|n| n.null_count() > 0) {
+            Some(n) => n.valid_indices().for_each(|i| f(i, self.ids[i])),
+            None => self.ids.iter().enumerate().for_each(|(i, g)| f(i, *g)),
+        }
+    }
+
+    fn count(&self) -> Int64Array {
+        let mut counts = vec![0_i64; self.num_groups];
+        self.for_each_valid(|_, g| counts[g] += 1);
+        counts.into()
+    }
+
+    /// Folds the non-null values of each group with `f`, returning the accumulator of
+    /// each group and a [`NullBuffer`] of the groups that contain a non-null value
+    fn fold<T: Copy + Default>(
+        &self,
+        mut f: impl FnMut(usize, Option<T>) -> T,
+    ) -> (Vec<T>, Option<NullBuffer>) {
+        let mut acc = vec![T::default(); self.num_groups];
+        let mut seen = BooleanBufferBuilder::new(self.num_groups);
+        seen.append_n(self.num_groups, false);
+        self.for_each_valid(|i, g| {
+            let prev = seen.get_bit(g).then(|| acc[g]);
+            acc[g] = f(i, prev);
+            seen.set_bit(g, true);
+        });
+        let nulls = NullBuffer::new(seen.finish());
+        (acc, Some(nulls).filter(|n| n.null_count() > 0))
+    }
+}
+
+fn sum(values: &dyn Array, groups: &Groups) -> Result<ArrayRef, ArrowError> {
+    use arrow_schema::TimeUnit::*;
+    use DataType::*;
+
+    match values.data_type() {
+        Int8 => Ok(primitive_sum::<Int8Type>(values, groups)),
+        Int16 => Ok(primitive_sum::<Int16Type>(values, groups)),
+        Int32 => Ok(primitive_sum::<Int32Type>(values, groups)),
+        Int64 => Ok(primitive_sum::<Int64Type>(values, groups)),
+        UInt8 => Ok(primitive_sum::<UInt8Type>(values, groups)),
+        UInt16 => Ok(primitive_sum::<UInt16Type>(values, groups)),
+        UInt32 => Ok(primitive_sum::<UInt32Type>(values, groups)),
+        UInt64 => Ok(primitive_sum::<UInt64Type>(values, groups)),
+        Float16 => Ok(primitive_sum::<Float16Type>(values, groups)),
+        Float32 => Ok(primitive_sum::<Float32Type>(values, groups)),
+        Float64 => Ok(primitive_sum::<Float64Type>(values, groups)),
+        Decimal32(_, _) => Ok(primitive_sum::<Decimal32Type>(values, groups)),
+        Decimal64(_, _) => Ok(primitive_sum::<Decimal64Type>(values, groups)),
+        Decimal128(_, _) => Ok(primitive_sum::<Decimal128Type>(values, groups)),
+        Decimal256(_, _) => Ok(primitive_sum::<Decimal256Type>(values, groups)),
+        Duration(Second) => Ok(primitive_sum::<DurationSecondType>(values, groups)),
+        Duration(Millisecond) => Ok(primitive_sum::<DurationMillisecondType>(values, groups)),
+        Duration(Microsecond) => Ok(primitive_sum::<DurationMicrosecondType>(values, groups)),
+        Duration(Nanosecond) => Ok(primitive_sum::<DurationNanosecondType>(values, groups)),
+        t => Err(unsupported(Agg::Sum, t)),
+    }
+}
+
+fn primitive_sum<T: ArrowPrimitiveType>(values: &dyn Array, groups: &Groups) -> ArrayRef {
+    let values = values.as_primitive::<T>();
+    let v = values.values();
+    let (sums, nulls) = groups.fold(|i, acc: Option<T::Native>| match acc {
+        Some(acc) => acc.add_wrapping(v[i]),
+        None => v[i],
+    });
+    let array = PrimitiveArray::<T>::new(sums.into(), nulls);
+    Arc::new(array.with_data_type(values.data_type().clone()))
+}
+
+fn min_max(values: &dyn Array, groups: &Groups, agg: Agg) -> Result<ArrayRef, ArrowError> {
+    let is_min = agg == Agg::Min;
+    downcast_primitive_array! {
+        values => Ok(primitive_min_max(values, groups, is_min)),
+        DataType::Utf8 => Ok(bytes_min_max(values.as_string::<i32>(), groups, is_min)),
+        DataType::LargeUtf8 => Ok(bytes_min_max(values.as_string::<i64>(), groups, is_min)),
+        DataType::Binary => Ok(bytes_min_max(values.as_binary::<i32>(), groups, is_min)),
+        DataType::LargeBinary => Ok(bytes_min_max(values.as_binary::<i64>(), groups, is_min)),
+        DataType::Utf8View => Ok(byte_view_min_max(values.as_string_view(), groups, is_min)),
+        DataType::BinaryView => Ok(byte_view_min_max(values.as_binary_view(), groups, is_min)),
+        t => Err(unsupported(agg, t)),
+    }
+}
+
+fn primitive_min_max<T: ArrowPrimitiveType>(
+    values: &PrimitiveArray<T>,
+    groups: &Groups,
+    is_min: bool,
+) -> ArrayRef {
+    let v = values.values();
+    let (acc, nulls) = groups.fold(|i, acc: Option<T::Native>| match acc {
+        Some(acc) if is_min && !v[i].is_lt(acc) => acc,
+        Some(acc) if !is_min && !v[i].is_gt(acc) => acc,
+        _ => v[i],
+    });
+    let array = PrimitiveArray::<T>::new(acc.into(), nulls);
+    Arc::new(array.with_data_type(values.data_type().clone()))
+}
+
+/// Returns the index of the minimum or maximum value of each group, according to `cmp`
+fn min_max_indices(
+    groups: &Groups,
+    is_min: bool,
+    cmp: impl Fn(usize, usize) -> std::cmp::Ordering,
+) -> Vec<Option<usize>> {
+    let target = match is_min {
+        true => std::cmp::Ordering::Less,
+        false => std::cmp::Ordering::Greater,
+    };
+    // The accumulator is the index of the best value, which is only read once set
+    let (acc, nulls) = groups.fold(|i, acc: Option<usize>| match acc {
+        Some(acc) if cmp(i, acc) != target => acc,
+        _ => i,
+    });
+    acc.into_iter()
+        .enumerate()
+        .map(|(g, i)| nulls.as_ref().is_none_or(|n| n.is_valid(g)).then_some(i))
+        .collect()
+}
+
+fn bytes_min_max<T: ByteArrayType>(
+    values: &GenericByteArray<T>,
+    groups: &Groups,
+    is_min: bool,
+) -> ArrayRef {
+    let indices = min_max_indices(groups, is_min, |a, b| {
+        let a: &[u8] = values.value(a).as_ref();
+        a.cmp(values.value(b).as_ref())
+    });
+    let array: GenericByteArray<T> = indices
+        .into_iter()
+        .map(|i| i.map(|i| values.value(i)))
+        .collect();
+    Arc::new(array)
+}
+
+fn byte_view_min_max<T: ByteViewType>(
+    values: &GenericByteViewArray<T>,
+    groups: &Groups,
+    is_min: bool,
+) -> ArrayRef {
+    let indices = min_max_indices(groups, is_min, |a, b| {
+        // SAFETY: the indices are within the bounds of `values`
+        unsafe { GenericByteViewArray::compare_unchecked(values, a, values, b) }
+    });
+    let array: GenericByteViewArray<T> = indices
+        .into_iter()
+        .map(|i| i.map(|i| values.value(i)))
+        .collect();
+    Arc::new(array)
+}
+
+fn unsupported(agg: Agg, data_type: &DataType) -> ArrowError {
+    ArrowError::InvalidArgumentError(format!("Invalid grouped aggregation: {agg} of {data_type}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_buffer::i256;
+
+    #[test]
+    fn test_sum() {
+        let values = Int64Array::from(vec![Some(1), None, Some(3), Some(i64::MAX), Some(5)]);
+        let groups = [0, 1, 0, 2, 2];
+        let result = aggregate_by_group(&values, &groups, 4, Agg::Sum).unwrap();
+        let expected = Int64Array::from(vec![Some(4), None, Some(i64::MIN + 4), None]);
+        assert_eq!(result.as_primitive::<Int64Type>(), &expected);
+
+        let values = Float64Array::from(vec![1.5, 2.5, -1.0]);
+        let result = aggregate_by_group(&values, &[1, 1, 0], 2, Agg::Sum).unwrap();
+        let expected = Float64Array::from(vec![-1.0, 4.0]);
+        assert_eq!(result.as_primitive::<Float64Type>(), &expected);
+
+        let values = Decimal128Array::from(vec![Some(150), None, Some(-25)])
+            .with_precision_and_scale(10, 2)
+            .unwrap();
+        let result = aggregate_by_group(&values, &[0, 0, 0], 1, Agg::Sum).unwrap();
+        assert_eq!(result.data_type(), &DataType::Decimal128(10, 2));
+        assert_eq!(result.as_primitive::<Decimal128Type>().value(0), 125);
+
+        let values = Decimal256Array::from(vec![i256::from_i128(1), i256::from_i128(2)]);
+        let result = aggregate_by_group(&values, &[1, 1], 2, Agg::Sum).unwrap();
+        let result = result.as_primitive::<Decimal256Type>();
+        assert!(result.is_null(0));
+        assert_eq!(result.value(1), i256::from_i128(3));
+    }
+
+    #[test]
+    fn test_min_max() {
+        let values = Int32Array::from(vec![Some(5), Some(-3), None, Some(8), Some(1)]);
+        let groups = [0, 0, 1, 1, 0];
+        let min = aggregate_by_group(&values, &groups, 3, Agg::Min).unwrap();
+        let expected = Int32Array::from(vec![Some(-3), Some(8), None]);
+        assert_eq!(min.as_primitive::<Int32Type>(), &expected);
+        let max = aggregate_by_group(&values, &groups, 3, Agg::Max).unwrap();
+        let expected = Int32Array::from(vec![Some(5), Some(8), None]);
+        assert_eq!(max.as_primitive::<Int32Type>(), &expected);
+
+        let values = Float32Array::from(vec![1.0, f32::NAN, -0.0, 0.0]);
+        let groups = [0, 0, 1, 1];
+        let min = aggregate_by_group(&values, &groups, 2, Agg::Min).unwrap();
+        let min = min.as_primitive::<Float32Type>();
+        assert_eq!(min.value(0), 1.0);
+        assert!(min.value(1).is_sign_negative());
+        let max = aggregate_by_group(&values, &groups, 2, Agg::Max).unwrap();
+        let max = max.as_primitive::<Float32Type>();
+        assert!(max.value(0).is_nan());
+        assert!(max.value(1).is_sign_positive());
+
+        let values = TimestampMillisecondArray::from(vec![10, 5]).with_timezone("+01:00");
+        let max = aggregate_by_group(&values, &[0, 0], 1, Agg::Max).unwrap();
+        assert_eq!(max.data_type(), values.data_type());
+        assert_eq!(max.as_primitive::<TimestampMillisecondType>().value(0), 10);
+    }
+
+    #[test]
+    fn test_min_max_bytes() {
+        let values = StringArray::from(vec![Some("b"), None, Some("a"), Some("ca"), Some("c")]);
+        let groups = [0, 2, 0, 1, 1];
+        let min = aggregate_by_group(&values, &groups, 3, Agg::Min).unwrap();
+        let expected = StringArray::from(vec![Some("a"), Some("c"), None]);
+        assert_eq!(min.as_string::<i32>(), &expected);
+        let max = aggregate_by_group(&values, &groups, 3, Agg::Max).unwrap();
+        let expected = StringArray::from(vec![Some("b"), Some("ca"), None]);
+        assert_eq!(max.as_string::<i32>(), &expected);
+
+        let values = LargeBinaryArray::from(vec![b"\x01\x02".as_ref(), b"\x01", b"\xff"]);
+        let max = aggregate_by_group(&values, &[0, 0, 1], 2, Agg::Max).unwrap();
+        let expected = LargeBinaryArray::from(vec![b"\x01\x02".as_ref(), b"\xff"]);
+        assert_eq!(max.as_binary::<i64>(), &expected);
+
+        let values = StringViewArray::from(vec![
+            Some("a string longer than twelve bytes"),
+            Some("a string longer than twelve"),
+            None,
+            Some("short"),
+        ]);
+        let min = aggregate_by_group(&values, &[0, 0, 1, 0], 2, Agg::Min).unwrap();
+        let expected = StringViewArray::from(vec![Some("a string longer than twelve"), None]);
+        assert_eq!(min.as_string_view(), &expected);
+    }
+
+    #[test]
+    fn test_count() {
+        let values = StringArray::from(vec![Some("a"), None, Some("b"), None]);
+        let result = aggregate_by_group(&values, &[1, 1, 1, 0], 3, Agg::Count).unwrap();
+        let expected = Int64Array::from(vec![0, 2, 0]);
+        assert_eq!(result.as_primitive::<Int64Type>(), &expected);
+
+        let values = NullArray::new(2);
+        let result = aggregate_by_group(&values, &[0, 0], 1, Agg::Count).unwrap();
+        assert_eq!(result.as_primitive::<Int64Type>().value(0), 0);
+    }
+
+    #[test]
+    fn test_errors() {
+        let values = Int32Array::from(vec![1, 2]);
+        let err = aggregate_by_group(&values, &[0], 1, Agg::Sum).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot aggregate array of length 2 by 1 group ids"
+        );
+
+        let err = aggregate_by_group(&values, &[0, 2], 2, Agg::Sum).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Group id 2 out of bounds for 2 groups"
+        );
+
+        let values = StringArray::from(vec!["a"]);
+        let err = aggregate_by_group(&values, &[0], 1, Agg::Sum).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Invalid grouped aggregation: sum of Utf8"
+        );
+
+        let values = Date32Array::from(vec![1]);
+        assert!(aggregate_by_group(&values, &[0], 1, Agg::Sum).is_err());
+        assert!(aggregate_by_group(&values, &[0], 1, Agg::Max).is_ok());
+
+        let values = BooleanArray::from(vec![true]);
+        let err = aggregate_by_group(&values, &[0], 1, Agg::Min).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Invalid grouped aggregation: min of Boolean"
+        );
+    }
+}
@@ -29,5 +29,10 @@ pub mod arithmetic;
 pub mod arity;
 pub mod bitwise;
 pub mod boolean;
+pub mod cumulative;
+pub mod decimal;
+pub mod grouped;
+pub mod list_aggregate;
 pub mod numeric;
 pub mod temporal;
+pub mod vector;
@@ -0,0 +1,372 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines kernels that aggregate the elements of each row of a list array, such as
+//! [`list_sum`]
+//!
+//! These accept [`ListArray`], [`LargeListArray`] and [`FixedSizeListArray`] of numeric
+//! elements, and return an array with one value per row. Null elements are ignored, and
+//! the result is null for a null row or a row without any non-null elements.
+
+use std::ops::Range;
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
+use arrow_array::*;
+use arrow_buffer::{BooleanBufferBuilder, NullBuffer};
+use arrow_schema::{ArrowError, DataType};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ListAgg {
+    Sum,
+    Min,
+    Max,
+    Mean,
+}
+
+impl std::fmt::Display for ListAgg {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ListAgg::Sum => write!(f, "list_sum"),
+            ListAgg::Min => write!(f, "list_min"),
+            ListAgg::Max => write!(f, "list_max"),
+            ListAgg::Mean => write!(f, "list_mean"),
+        }
+    }
+}
+
+/// Returns the sum of the elements of each row of `array`, wrapping on overflow
+///
+/// Supports integer, floating point and decimal elements, returning an array of the
+/// element type, including the precision and scale of decimals.
+///
+/// ```
+/// # use arrow_array::{Int32Array, ListArray};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_arith::list_aggregate::list_sum;
+/// let list = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+///     Some(vec![Some(1), Some(2), None]),
+///     Some(vec![]),
+///     None,
+/// ]);
+/// let sums = list_sum(&list).unwrap();
+/// assert_eq!(sums.as_primitive::<Int32Type>(), &Int32Array::from(vec![Some(3), None, None]));
+/// ```
+pub fn list_sum(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    list_aggregate(array, ListAgg::Sum)
+}
+
+/// Returns the minimum element of each row of `array`
+///
+/// Supports integer, floating point and decimal elements, returning an array of the
+/// element type. Floating point values are compared using IEEE 754 totalOrder.
+pub fn list_min(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    list_aggregate(array, ListAgg::Min)
+}
+
+/// Returns the maximum element of each row of `array`
+///
+/// Supports integer, floating point and decimal elements, returning an array of the
+/// element type. Floating point values are compared using IEEE 754 totalOrder.
+pub fn list_max(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    list_aggregate(array, ListAgg::Max)
+}
+
+/// Returns the arithmetic mean of the elements of each row of `array` as a [`Float64Array`]
+///
+/// Supports integer and floating point elements.
+///
+/// ```
+/// # use arrow_array::{Float64Array, FixedSizeListArray};
+/// # use arrow_array::types::Int64Type;
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_arith::list_aggregate::list_mean;
+/// let list = FixedSizeListArray::from_iter_primitive::<Int64Type, _, _>(
+///     vec![Some(vec![Some(1), Some(2)]), Some(vec![None, Some(5)])],
+///     2,
+/// );
+/// let means = list_mean(&list).unwrap();
+/// assert_eq!(means.as_primitive(), &Float64Array::from(vec![1.5, 5.0]));
+/// ```
+pub fn list_mean(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    list_aggregate(array, ListAgg::Mean)
+}
+
+/// The rows of a list array, as ranges of its values
+struct ListRows {
+    offsets: Vec<usize>,
+    nulls: Option<NullBuffer>,
+}
+
+impl ListRows {
+    fn len(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    fn range(&self, i: usize) -> Range<usize> {
+        self.offsets[i]..self.offsets[i + 1]
+    }
+
+    fn is_valid(&self, i: usize) -> bool {
+        self.nulls.as_ref().is_none_or(|n| n.is_valid(i))
+    }
+
+    /// Folds the non-null elements of each valid row with `f`, returning the accumulator
+    /// of each row and a [`NullBuffer`] of the rows that contain a non-null element
+    fn fold<T: ArrowPrimitiveType, A: Copy + Default>(
+        &self,
+        values: &PrimitiveArray<T>,
+        f: impl Fn(Option<A>, T::Native) -> A,
+    ) -> (Vec<A>, Option<NullBuffer>) {
+        let data = values.values();
+        let element_nulls = values.nulls().filter(|n| n.null_count() > 0);
+        let mut valid = BooleanBufferBuilder::new(self.len());
+        let acc = (0..self.len())
+            .map(|i| {
+                let acc = match self.is_valid(i) {
+                    false => None,
+                    true => match element_nulls {
+                        None => data[self.range(i)]
+                            .iter()
+                            .fold(None, |acc, v| Some(f(acc, *v))),
+                        Some(n) => self
+                            .range(i)
+                            .filter(|j| n.is_valid(*j))
+                            .fold(None, |acc, j| Some(f(acc, data[j]))),
+                    },
+                };
+                valid.append(acc.is_some());
+                acc.unwrap_or_default()
+            })
+            .collect();
+        let nulls = NullBuffer::new(valid.finish());
+        (acc, Some(nulls).filter(|n| n.null_count() > 0))
+    }
+}
+
+fn list_aggregate(array: &dyn Array, agg: ListAgg) -> Result<ArrayRef, ArrowError> {
+    let (values, rows) = match array.data_type() {
+        DataType::List(_) => list_rows(array.as_list::<i32>()),
+        DataType::LargeList(_) => list_rows(array.as_list::<i64>()),
+        DataType::FixedSizeList(_, size) => {
+            let list = array.as_fixed_size_list();
+            let size = *size as usize;
+            let rows = ListRows {
+                offsets: (0..=list.len()).map(|i| i * size).collect(),
+                nulls: list.logical_nulls(),
+            };
+            (list.values(), rows)
+        }
+        t => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "{agg} expects a List, LargeList or FixedSizeList array, got {t}"
+            )))
+        }
+    };
+
+    macro_rules! helper {
+        ($t:ty) => {
+            match agg {
+                ListAgg::Sum => Ok(primitive_sum::<$t>(values, &rows)),
+                ListAgg::Min | ListAgg::Max => Ok(primitive_min_max::<$t>(values, &rows, agg)),
+                ListAgg::Mean => Ok(primitive_mean::<$t>(values, &rows, |v| v as f64)),
+            }
+        };
+    }
+
+    use DataType::*;
+    match (values.data_type(), agg) {
+        (Int8, _) => helper!(Int8Type),
+        (Int16, _) => helper!(Int16Type),
+        (Int32, _) => helper!(Int32Type),
+        (Int64, _) => helper!(Int64Type),
+        (UInt8, _) => helper!(UInt8Type),
+        (UInt16, _) => helper!(UInt16Type),
+        (UInt32, _) => helper!(UInt32Type),
+        (UInt64, _) => helper!(UInt64Type),
+        (Float16, ListAgg::Mean) => {
+            Ok(primitive_mean::<Float16Type>(values, &rows, |v| v.to_f64()))
+        }
+        (Float32, _) => helper!(Float32Type),
+        (Float64, _) => helper!(Float64Type),
+        (Float16, ListAgg::Sum) => Ok(primitive_sum::<Float16Type>(values, &rows)),
+        (Float16, _) => Ok(primitive_min_max::<Float16Type>(values, &rows, agg)),
+        (Decimal32(_, _), ListAgg::Sum) => Ok(primitive_sum::<Decimal32Type>(values, &rows)),
+        (Decimal64(_, _), ListAgg::Sum) => Ok(primitive_sum::<Decimal64Type>(values, &rows)),
+        (Decimal128(_, _), ListAgg::Sum) => Ok(primitive_sum::<Decimal128Type>(values, &rows)),
+        (Decimal256(_, _), ListAgg::Sum) => Ok(primitive_sum::<Decimal256Type>(values, &rows)),
+        (Decimal32(_, _), ListAgg::Min | ListAgg::Max) => {
+            Ok(primitive_min_max::<Decimal32Type>(values, &rows, agg))
+        }
+        (Decimal64(_, _), ListAgg::Min | ListAgg::Max) => {
+            Ok(primitive_min_max::<Decimal64Type>(values, &rows, agg))
+        }
+        (Decimal128(_, _), ListAgg::Min | ListAgg::Max) => {
+            Ok(primitive_min_max::<Decimal128Type>(values, &rows, agg))
+        }
+        (Decimal256(_, _), ListAgg::Min | ListAgg::Max) => {
+            Ok(primitive_min_max::<Decimal256Type>(values, &rows, agg))
+        }
+        (t, _) => Err(ArrowError::InvalidArgumentError(format!(
+            "{agg} does not support elements of type {t}"
+        ))),
+    }
+}
+
+fn list_rows<O: OffsetSizeTrait>(list: &GenericListArray<O>) -> (&ArrayRef, ListRows) {
+    let rows = ListRows {
+        offsets: list.value_offsets().iter().map(|o| o.as_usize()).collect(),
+        nulls: list.logical_nulls(),
+    };
+    (list.values(), rows)
+}
+
+fn primitive_sum<T: ArrowPrimitiveType>(values: &dyn Array, rows: &ListRows) -> ArrayRef {
+    let values = values.as_primitive::<T>();
+    let (sums, nulls) = rows.fold(values, |acc: Option<T::Native>, v| match acc {
+        Some(acc) => acc.add_wrapping(v),
+        None => v,
+    });
+    let array = PrimitiveArray::<T>::new(sums.into(), nulls);
+    Arc::new(array.with_data_type(values.data_type().clone()))
+}
+
+fn primitive_min_max<T: ArrowPrimitiveType>(
+    values: &dyn Array,
+    rows: &ListRows,
+    agg: ListAgg,
+) -> ArrayRef {
+    let values = values.as_primitive::<T>();
+    let replace = match agg {
+        ListAgg::Min => |acc: T::Native, v: T::Native| v.is_lt(acc),
+        _ => |acc: T::Native, v: T::Native| v.is_gt(acc),
+    };
+    let (result, nulls) = rows.fold(values, |acc: Option<T::Native>, v| match acc {
+        Some(acc) if !replace(acc, v) => acc,
+        _ => v,
+    });
+    let array = PrimitiveArray::<T>::new(result.into(), nulls);
+    Arc::new(array.with_data_type(values.data_type().clone()))
+}
+
+fn primitive_mean<T: ArrowPrimitiveType>(
+    values: &dyn Array,
+    rows: &ListRows,
+    to_f64: impl Fn(T::Native) -> f64,
+) -> ArrayRef {
+    let values = values.as_primitive::<T>();
+    let (acc, nulls) = rows.fold(values, |acc: Option<(f64, usize)>, v| {
+        let (sum, count) = acc.unwrap_or_default();
+        (sum + to_f64(v), count + 1)
+    });
+    let means = acc.into_iter().map(|(sum, count)| match count {
+        0 => 0.,
+        _ => sum / count as f64,
+    });
+    Arc::new(Float64Array::new(means.collect(), nulls))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_buffer::OffsetBuffer;
+    use arrow_schema::Field;
+
+    fn list(rows: Vec<Option<Vec<Option<i32>>>>) -> ListArray {
+        ListArray::from_iter_primitive::<Int32Type, _, _>(rows)
+    }
+
+    #[test]
+    fn test_list_aggregates() {
+        let list = list(vec![
+            Some(vec![Some(3), Some(-1), Some(4)]),
+            Some(vec![None, Some(2), None]),
+            Some(vec![None]),
+            None,
+            Some(vec![]),
+            Some(vec![Some(i32::MAX), Some(1)]),
+        ]);
+
+        let sums = list_sum(&list).unwrap();
+        let expected = Int32Array::from(vec![Some(6), Some(2), None, None, None, Some(i32::MIN)]);
+        assert_eq!(sums.as_primitive::<Int32Type>(), &expected);
+
+        let min = list_min(&list).unwrap();
+        let expected = Int32Array::from(vec![Some(-1), Some(2), None, None, None, Some(1)]);
+        assert_eq!(min.as_primitive::<Int32Type>(), &expected);
+
+        let max = list_max(&list).unwrap();
+        let expected = Int32Array::from(vec![Some(4), Some(2), None, None, None, Some(i32::MAX)]);
+        assert_eq!(max.as_primitive::<Int32Type>(), &expected);
+
+        let mean = list_mean(&list.slice(1, 5)).unwrap();
+        let expected = Float64Array::from(vec![
+            Some(2.),
+            None,
+            None,
+            None,
+            Some((i32::MAX as f64 + 1.) / 2.),
+        ]);
+        assert_eq!(mean.as_primitive::<Float64Type>(), &expected);
+    }
+
+    #[test]
+    fn test_list_aggregates_float_and_decimal() {
+        let values = Float64Array::from(vec![1.5, f64::NAN, -0.0, 0.0]);
+        let field = Arc::new(Field::new_list_field(DataType::Float64, true));
+        let offsets = OffsetBuffer::from_lengths([2, 2]);
+        let list = LargeListArray::new(field, offsets, Arc::new(values), None);
+
+        let max = list_max(&list).unwrap();
+        let max = max.as_primitive::<Float64Type>();
+        assert!(max.value(0).is_nan());
+        assert_eq!(max.value(1).to_bits(), 0.0_f64.to_bits());
+
+        let min = list_min(&list).unwrap();
+        let min = min.as_primitive::<Float64Type>();
+        assert_eq!(min.value(0), 1.5);
+        assert_eq!(min.value(1).to_bits(), (-0.0_f64).to_bits());
+
+        let values = Decimal128Array::from(vec![150, 275, 1])
+            .with_precision_and_scale(10, 2)
+            .unwrap();
+        let list = FixedSizeListArray::new(
+            Arc::new(Field::new_list_field(values.data_type().clone(), true)),
+            1,
+            Arc::new(values),
+            None,
+        );
+        let sums = list_sum(&list.slice(1, 2)).unwrap();
+        let expected = Decimal128Array::from(vec![275, 1])
+            .with_precision_and_scale(10, 2)
+            .unwrap();
+        assert_eq!(sums.as_primitive::<Decimal128Type>(), &expected);
+
+        let err = list_mean(&list).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: list_mean does not support elements of type Decimal128(10, 2)"
+        );
+        let err = list_sum(&Int32Array::from(vec![1])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: list_sum expects a List, LargeList or FixedSizeList array, got Int32"
+        );
+    }
+}
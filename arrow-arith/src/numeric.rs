@@ -16,6 +16,9 @@
 // under the License.
 
 //! Defines numeric arithmetic kernels on [`PrimitiveArray`], such as [`add`]
+//!
+//! Where one side is a [`RunArray`] and the other a scalar, the kernels operate on the
+//! values of each run and return a [`RunArray`] with the same runs
 
 use std::cmp::Ordering;
 use std::fmt::Formatter;
@@ -28,7 +31,7 @@ use arrow_array::*;
 use arrow_buffer::{ArrowNativeType, IntervalDayTime, IntervalMonthDayNano};
 use arrow_schema::{ArrowError, DataType, IntervalUnit, TimeUnit};
 
-use crate::arity::{binary, try_binary};
+use crate::arity::{binary, binary_mut, try_binary, try_binary_mut};
 
 /// Perform `lhs + rhs`, returning an error on overflow
 pub fn add(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<ArrayRef, ArrowError> {
@@ -78,6 +81,60 @@ pub fn rem(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<ArrayRef, ArrowError> {
     arithmetic_op(Op::Rem, lhs, rhs)
 }
 
+/// Perform `lhs + rhs`, returning an error on overflow
+///
+/// Unlike [`add`] this takes ownership of `lhs` and, for integer and floating point
+/// arrays, writes the result into its buffers if they are not shared. Otherwise,
+/// this falls back to allocating new buffers as [`add`].
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_arith::numeric::add_mut;
+/// # use arrow_array::{ArrayRef, Int32Array, Scalar};
+/// let a: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), None, Some(3)]));
+/// let b = Scalar::new(Int32Array::from(vec![10]));
+/// let c = add_mut(a, &b).unwrap();
+/// assert_eq!(c.as_ref(), &Int32Array::from(vec![Some(11), None, Some(13)]));
+/// ```
+pub fn add_mut(lhs: ArrayRef, rhs: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    arithmetic_op_mut(Op::Add, lhs, rhs)
+}
+
+/// Perform `lhs + rhs`, wrapping on overflow for [`DataType::is_integer`], see [`add_mut`]
+pub fn add_wrapping_mut(lhs: ArrayRef, rhs: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    arithmetic_op_mut(Op::AddWrapping, lhs, rhs)
+}
+
+/// Perform `lhs - rhs`, returning an error on overflow, see [`add_mut`]
+pub fn sub_mut(lhs: ArrayRef, rhs: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    arithmetic_op_mut(Op::Sub, lhs, rhs)
+}
+
+/// Perform `lhs - rhs`, wrapping on overflow for [`DataType::is_integer`], see [`add_mut`]
+pub fn sub_wrapping_mut(lhs: ArrayRef, rhs: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    arithmetic_op_mut(Op::SubWrapping, lhs, rhs)
+}
+
+/// Perform `lhs * rhs`, returning an error on overflow, see [`add_mut`]
+pub fn mul_mut(lhs: ArrayRef, rhs: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    arithmetic_op_mut(Op::Mul, lhs, rhs)
+}
+
+/// Perform `lhs * rhs`, wrapping on overflow for [`DataType::is_integer`], see [`add_mut`]
+pub fn mul_wrapping_mut(lhs: ArrayRef, rhs: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    arithmetic_op_mut(Op::MulWrapping, lhs, rhs)
+}
+
+/// Perform `lhs / rhs`, see [`div`] and [`add_mut`]
+pub fn div_mut(lhs: ArrayRef, rhs: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    arithmetic_op_mut(Op::Div, lhs, rhs)
+}
+
+/// Perform `lhs % rhs`, see [`rem`] and [`add_mut`]
+pub fn rem_mut(lhs: ArrayRef, rhs: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    arithmetic_op_mut(Op::Rem, lhs, rhs)
+}
+
 macro_rules! neg_checked {
     ($t:ty, $a:ident) => {{
         let array = $a
@@ -183,6 +240,48 @@ pub fn neg_wrapping(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
     }
 }
 
+/// Negates each element of `array`, returning an error on overflow
+///
+/// Unlike [`neg`] this takes ownership of `array` and, for signed integer and floating
+/// point arrays, writes the result into its buffers if they are not shared. Otherwise,
+/// this falls back to allocating new buffers as [`neg`].
+pub fn neg_mut(array: ArrayRef) -> Result<ArrayRef, ArrowError> {
+    macro_rules! neg_checked_mut {
+        ($t:ty, $a:ident) => {
+            match into_primitive::<$t>($a).try_unary_mut(|x| x.neg_checked()) {
+                Ok(a) => Ok(Arc::new(a?)),
+                Err(a) => neg(&a),
+            }
+        };
+    }
+
+    macro_rules! neg_wrapping_mut {
+        ($t:ty, $a:ident) => {
+            match into_primitive::<$t>($a).unary_mut(|x| x.neg_wrapping()) {
+                Ok(a) => Ok(Arc::new(a)),
+                Err(a) => neg(&a),
+            }
+        };
+    }
+
+    match array.data_type() {
+        DataType::Int8 => neg_checked_mut!(Int8Type, array),
+        DataType::Int16 => neg_checked_mut!(Int16Type, array),
+        DataType::Int32 => neg_checked_mut!(Int32Type, array),
+        DataType::Int64 => neg_checked_mut!(Int64Type, array),
+        DataType::Float16 => neg_wrapping_mut!(Float16Type, array),
+        DataType::Float32 => neg_wrapping_mut!(Float32Type, array),
+        DataType::Float64 => neg_wrapping_mut!(Float64Type, array),
+        _ => neg(&array),
+    }
+}
+
+/// Converts `array` into a [`PrimitiveArray`], releasing the reference held by the
+/// [`ArrayRef`] so that the buffers can be reused if not otherwise shared
+fn into_primitive<T: ArrowPrimitiveType>(array: ArrayRef) -> PrimitiveArray<T> {
+    array.as_primitive::<T>().clone()
+}
+
 /// An enumeration of arithmetic operations
 ///
 /// This allows sharing the type dispatch logic across the various kernels
@@ -230,6 +329,15 @@ fn arithmetic_op(op: Op, lhs: &dyn Datum, rhs: &dyn Datum) -> Result<ArrayRef, A
 
     let (l, l_scalar) = lhs.get();
     let (r, r_scalar) = rhs.get();
+    match (l.data_type(), l_scalar, r.data_type(), r_scalar) {
+        (RunEndEncoded(_, _), false, _, true) => {
+            return run_end_encoded_op(l, |l| arithmetic_op(op, &l, rhs))
+        }
+        (_, true, RunEndEncoded(_, _), false) => {
+            return run_end_encoded_op(r, |r| arithmetic_op(op, lhs, &r))
+        }
+        _ => {}
+    }
     downcast_integer! {
         l.data_type(), r.data_type() => (integer_helper, op, l, l_scalar, r, r_scalar),
         (Float16, Float16) => float_op::<Float16Type>(op, l, l_scalar, r, r_scalar),
@@ -263,6 +371,125 @@ fn arithmetic_op(op: Op, lhs: &dyn Datum, rhs: &dyn Datum) -> Result<ArrayRef, A
     }
 }
 
+/// Dispatch the given `op` to a kernel writing the result into the buffers of `lhs`
+/// where possible, falling back to [`arithmetic_op`] otherwise
+fn arithmetic_op_mut(op: Op, lhs: ArrayRef, rhs: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    use DataType::*;
+
+    macro_rules! integer_helper {
+        ($t:ty, $op:ident, $l:ident, $r:ident, $r_scalar:ident) => {
+            integer_op_mut::<$t>($op, $l, $r, $r_scalar)
+        };
+    }
+
+    let (r, r_scalar) = rhs.get();
+    if lhs.data_type() != r.data_type() || (!r_scalar && lhs.len() != r.len()) {
+        return arithmetic_op(op, &lhs, rhs);
+    }
+    downcast_integer! {
+        lhs.data_type() => (integer_helper, op, lhs, r, r_scalar),
+        Float16 => float_op_mut::<Float16Type>(op, lhs, r, r_scalar),
+        Float32 => float_op_mut::<Float32Type>(op, lhs, r, r_scalar),
+        Float64 => float_op_mut::<Float64Type>(op, lhs, r, r_scalar),
+        _ => arithmetic_op(op, &lhs, rhs),
+    }
+}
+
+/// Perform an infallible binary operation in place on `l`, returning `Err(l)` if its
+/// buffers are shared
+macro_rules! op_mut {
+    ($l:ident, $r:ident, $r_s:expr, $op:expr) => {
+        match $r_s {
+            true => match ($r.null_count() == 0).then(|| $r.value(0)) {
+                None => Ok(Ok(PrimitiveArray::new_null($l.len()))),
+                Some($r) => $l.unary_mut(|$l| $op).map(Ok),
+            },
+            false => binary_mut($l, $r, |$l, $r| $op),
+        }
+    };
+}
+
+/// Same as `op_mut` but for a fallible operation
+macro_rules! try_op_mut {
+    ($l:ident, $r:ident, $r_s:expr, $op:expr) => {
+        match $r_s {
+            true => match ($r.null_count() == 0).then(|| $r.value(0)) {
+                None => Ok(Ok(PrimitiveArray::new_null($l.len()))),
+                Some($r) => $l.try_unary_mut(|$l| $op),
+            },
+            false => try_binary_mut($l, $r, |$l, $r| $op),
+        }
+    };
+}
+
+/// Perform an arithmetic operation on integers in place, see [`integer_op`]
+fn integer_op_mut<T: ArrowPrimitiveType>(
+    op: Op,
+    l: ArrayRef,
+    r: &dyn Array,
+    r_s: bool,
+) -> Result<ArrayRef, ArrowError> {
+    let l = into_primitive::<T>(l);
+    let r = r.as_primitive::<T>();
+    let result = match op {
+        Op::AddWrapping => op_mut!(l, r, r_s, l.add_wrapping(r)),
+        Op::Add => try_op_mut!(l, r, r_s, l.add_checked(r)),
+        Op::SubWrapping => op_mut!(l, r, r_s, l.sub_wrapping(r)),
+        Op::Sub => try_op_mut!(l, r, r_s, l.sub_checked(r)),
+        Op::MulWrapping => op_mut!(l, r, r_s, l.mul_wrapping(r)),
+        Op::Mul => try_op_mut!(l, r, r_s, l.mul_checked(r)),
+        Op::Div => try_op_mut!(l, r, r_s, l.div_checked(r)),
+        Op::Rem => try_op_mut!(l, r, r_s, {
+            if r.is_zero() {
+                Err(ArrowError::DivideByZero)
+            } else {
+                Ok(l.mod_wrapping(r))
+            }
+        }),
+    };
+    match result {
+        Ok(array) => Ok(Arc::new(array?)),
+        Err(l) => integer_op::<T>(op, &l, false, r, r_s),
+    }
+}
+
+/// Perform an arithmetic operation on floats in place, see [`float_op`]
+fn float_op_mut<T: ArrowPrimitiveType>(
+    op: Op,
+    l: ArrayRef,
+    r: &dyn Array,
+    r_s: bool,
+) -> Result<ArrayRef, ArrowError> {
+    let l = into_primitive::<T>(l);
+    let r = r.as_primitive::<T>();
+    let result = match op {
+        Op::AddWrapping | Op::Add => op_mut!(l, r, r_s, l.add_wrapping(r)),
+        Op::SubWrapping | Op::Sub => op_mut!(l, r, r_s, l.sub_wrapping(r)),
+        Op::MulWrapping | Op::Mul => op_mut!(l, r, r_s, l.mul_wrapping(r)),
+        Op::Div => op_mut!(l, r, r_s, l.div_wrapping(r)),
+        Op::Rem => op_mut!(l, r, r_s, l.mod_wrapping(r)),
+    };
+    match result {
+        Ok(array) => Ok(Arc::new(array?)),
+        Err(l) => float_op::<T>(op, &l, false, r, r_s),
+    }
+}
+
+/// Applies `f` to the values of the runs of the [`RunArray`] `array`, returning a
+/// [`RunArray`] with the same runs
+fn run_end_encoded_op(
+    array: &dyn Array,
+    f: impl FnOnce(ArrayRef) -> Result<ArrayRef, ArrowError>,
+) -> Result<ArrayRef, ArrowError> {
+    downcast_run_array!(
+        array => {
+            let values = f(array.sliced_values())?;
+            Ok(Arc::new(array.with_sliced_values(values.as_ref())?))
+        },
+        t => unreachable!("expected run-end encoded array, got {t}")
+    )
+}
+
 /// Perform an infallible binary operation on potentially scalar inputs
 macro_rules! op {
     ($l:ident, $l_s:expr, $r:ident, $r_s:expr, $op:expr) => {
@@ -949,18 +1176,83 @@ mod tests {
         out: Result<&[T::Native], &str>,
     ) {
         let a = PrimitiveArray::<T>::new(ScalarBuffer::from(input.to_vec()), None);
+        let owned: ArrayRef = Arc::new(PrimitiveArray::<T>::new(input.to_vec().into(), None));
         match out {
             Ok(expected) => {
                 let result = neg(&a).unwrap();
                 assert_eq!(result.as_primitive::<T>().values(), expected);
+                let result = neg_mut(owned).unwrap();
+                assert_eq!(result.as_primitive::<T>().values(), expected);
             }
             Err(e) => {
                 let err = neg(&a).unwrap_err().to_string();
                 assert_eq!(e, err);
+                let err = neg_mut(owned).unwrap_err().to_string();
+                assert_eq!(e, err);
             }
         }
     }
 
+    #[test]
+    fn test_arithmetic_mut() {
+        let a = Int32Array::from(vec![Some(1), None, Some(3), Some(i32::MAX)]);
+        let ptr = a.values().as_ptr();
+
+        // Operations on unshared arrays reuse the buffer of the left hand side
+        let b = Scalar::new(Int32Array::from(vec![2]));
+        let result = mul_wrapping_mut(Arc::new(a), &b).unwrap();
+        assert_eq!(result.as_primitive::<Int32Type>().values().as_ptr(), ptr);
+        let expected = Int32Array::from(vec![Some(2), None, Some(6), Some(-2)]);
+        assert_eq!(result.as_ref(), &expected);
+
+        let b = Int32Array::from(vec![Some(1), Some(1), None, Some(1)]);
+        let result = sub_mut(result, &b).unwrap();
+        assert_eq!(result.as_primitive::<Int32Type>().values().as_ptr(), ptr);
+        let expected = Int32Array::from(vec![Some(1), None, None, Some(-3)]);
+        assert_eq!(result.as_ref(), &expected);
+
+        // Shared arrays fall back to allocating a new buffer
+        let shared = Arc::clone(&result);
+        let b = Scalar::new(Int32Array::from(vec![1]));
+        let added = add_mut(result, &b).unwrap();
+        assert_ne!(added.as_primitive::<Int32Type>().values().as_ptr(), ptr);
+        let expected = Int32Array::from(vec![Some(2), None, None, Some(-2)]);
+        assert_eq!(added.as_ref(), &expected);
+        assert_eq!(shared.as_primitive::<Int32Type>().value(0), 1);
+
+        // Errors are reported as for the allocating kernels
+        let b = Scalar::new(Int32Array::from(vec![i32::MAX]));
+        let err = add_mut(added, &b).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Arithmetic overflow: Overflow happened on: 2 + 2147483647"
+        );
+
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2]));
+        let b = Scalar::new(Int32Array::from(vec![0]));
+        let err = div_mut(Arc::clone(&a), &b).unwrap_err();
+        assert_eq!(err.to_string(), "Divide by zero error");
+        let err = rem_mut(a, &b).unwrap_err();
+        assert_eq!(err.to_string(), "Divide by zero error");
+
+        // Null scalars produce a null array
+        let a: ArrayRef = Arc::new(Float64Array::from(vec![1.5, 2.5]));
+        let b = Scalar::new(Float64Array::from(vec![None]));
+        let result = add_mut(a, &b).unwrap();
+        assert_eq!(result.null_count(), 2);
+
+        let a: ArrayRef = Arc::new(Float64Array::from(vec![1.5, 2.5]));
+        let b = Float64Array::from(vec![0.5, 2.0]);
+        let result = div_mut(a, &b).unwrap();
+        assert_eq!(result.as_ref(), &Float64Array::from(vec![3.0, 1.25]));
+
+        // Other types use the allocating kernels
+        let a: ArrayRef = Arc::new(Date32Array::from(vec![1, 2]));
+        let b = Scalar::new(IntervalYearMonthArray::from(vec![0]));
+        let result = add_mut(a, &b).unwrap();
+        assert_eq!(result.as_ref(), &Date32Array::from(vec![1, 2]));
+    }
+
     #[test]
     fn test_neg() {
         let input = &[1, -5, 2, 693, 3929];
@@ -2172,4 +2464,37 @@ mod tests {
             "Subtracting zero interval should always succeed for valid dates"
         );
     }
+
+    #[test]
+    fn test_run_end_encoded_scalar() {
+        let run_ends = Int32Array::from(vec![2, 5, 6]);
+        let values = Int64Array::from(vec![Some(1), None, Some(i64::MAX)]);
+        let array = RunArray::<Int32Type>::try_new(&run_ends, &values).unwrap();
+        let one = Int64Array::new_scalar(1);
+
+        // The run outside of the slice would overflow
+        let sliced = array.slice(1, 3);
+        let r = add(&sliced, &one).unwrap();
+        let r = r.as_run::<Int32Type>();
+        assert_eq!(r.len(), 3);
+        assert_eq!(r.run_ends().values(), &[1, 3]);
+        let expected = Int64Array::from(vec![Some(2), None]);
+        assert_eq!(r.values().as_primitive::<Int64Type>(), &expected);
+
+        let r = sub(&one, &sliced).unwrap();
+        let expected = Int64Array::from(vec![Some(0), None]);
+        assert_eq!(r.as_run::<Int32Type>().values().as_ref(), &expected);
+
+        let err = add(&array, &one).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Arithmetic overflow: Overflow happened on: 9223372036854775807 + 1"
+        );
+
+        let err = add(&array, &Int32Array::new_scalar(1)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Invalid arithmetic operation: Int64 + Int32"
+        );
+    }
 }
@@ -21,12 +21,16 @@ use std::sync::Arc;
 
 use arrow_array::cast::AsArray;
 use cast::as_primitive_array;
-use chrono::{Datelike, TimeZone, Timelike, Utc};
+use chrono::{
+    Datelike, Days, FixedOffset, LocalResult, Months, NaiveDate, NaiveDateTime, Offset, TimeDelta,
+    TimeZone, Timelike, Utc,
+};
 
 use arrow_array::temporal_conversions::{
-    date32_to_datetime, date64_to_datetime, timestamp_ms_to_datetime, timestamp_ns_to_datetime,
-    timestamp_s_to_datetime, timestamp_us_to_datetime, MICROSECONDS, MICROSECONDS_IN_DAY,
-    MILLISECONDS, MILLISECONDS_IN_DAY, NANOSECONDS, NANOSECONDS_IN_DAY, SECONDS_IN_DAY,
+    as_datetime, date32_to_datetime, date64_to_datetime, timestamp_ms_to_datetime,
+    timestamp_ns_to_datetime, timestamp_s_to_datetime, timestamp_us_to_datetime, MICROSECONDS,
+    MICROSECONDS_IN_DAY, MILLISECONDS, MILLISECONDS_IN_DAY, NANOSECONDS, NANOSECONDS_IN_DAY,
+    SECONDS_IN_DAY,
 };
 use arrow_array::timezone::Tz;
 use arrow_array::types::*;
@@ -647,6 +651,348 @@ impl ExtractDatePartExt for PrimitiveArray<DurationNanosecondType> {
     }
 }
 
+/// Granularities to truncate timestamps to with [`date_trunc`]
+///
+/// Marked as non-exhaustive as may expand to support more granularities in the future.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DateTruncGranularity {
+    /// The first instant of the calendar year
+    Year,
+    /// The first instant of the quarter, starting in January, April, July or October
+    Quarter,
+    /// The first instant of the month
+    Month,
+    /// The first instant of the ISO 8601 week, starting on Monday
+    Week,
+    /// Midnight
+    Day,
+    /// The start of the hour
+    Hour,
+    /// The start of the minute
+    Minute,
+    /// The start of the second
+    Second,
+    /// The start of the millisecond
+    Millisecond,
+    /// The start of the microsecond
+    Microsecond,
+}
+
+impl std::fmt::Display for DateTruncGranularity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+impl DateTruncGranularity {
+    /// The length of this granularity in nanoseconds, if it is a fixed duration
+    fn nanoseconds(&self) -> Option<i64> {
+        match self {
+            Self::Year | Self::Quarter | Self::Month | Self::Week => None,
+            Self::Day => Some(NANOSECONDS_IN_DAY),
+            Self::Hour => Some(3_600 * NANOSECONDS),
+            Self::Minute => Some(60 * NANOSECONDS),
+            Self::Second => Some(NANOSECONDS),
+            Self::Millisecond => Some(1_000_000),
+            Self::Microsecond => Some(1_000),
+        }
+    }
+
+    /// Truncate `local` wall clock time to this granularity
+    fn truncate(&self, local: NaiveDateTime) -> Option<NaiveDateTime> {
+        let date = local.date();
+        let nanos = local.nanosecond();
+        match self {
+            Self::Year => date.with_ordinal(1)?.and_hms_opt(0, 0, 0),
+            Self::Quarter => {
+                let month = (date.month0() / 3) * 3 + 1;
+                NaiveDate::from_ymd_opt(date.year(), month, 1)?.and_hms_opt(0, 0, 0)
+            }
+            Self::Month => date.with_day(1)?.and_hms_opt(0, 0, 0),
+            Self::Week => {
+                let days = date.weekday().num_days_from_monday() as u64;
+                date.checked_sub_days(Days::new(days))?.and_hms_opt(0, 0, 0)
+            }
+            Self::Day => date.and_hms_opt(0, 0, 0),
+            Self::Hour => local.with_nanosecond(0)?.with_second(0)?.with_minute(0),
+            Self::Minute => local.with_nanosecond(0)?.with_second(0),
+            Self::Second => local.with_nanosecond(0),
+            Self::Millisecond => local.with_nanosecond(nanos - nanos % 1_000_000),
+            Self::Microsecond => local.with_nanosecond(nanos - nanos % 1_000),
+        }
+    }
+}
+
+/// Truncate each timestamp of `array` to the start of the [`DateTruncGranularity`]
+/// containing it, as in SQL `date_trunc`
+///
+/// Timestamps with a timezone are truncated in the local time of that timezone, so for
+/// example truncating to [`DateTruncGranularity::Day`] returns local midnight, which
+/// accounts for daylight saving time transitions. Where the truncated local time occurs
+/// twice, such as the repeated hour when clocks are set back, the occurrence with the same
+/// UTC offset as the input is used if any, otherwise the earlier. Where it does not occur
+/// at all, such as midnight in a timezone whose clocks are set forward at midnight, the
+/// instant of the transition is used.
+///
+/// Supports all [`DataType::Timestamp`] arrays, and dictionaries of these, returning an
+/// array of the same type.
+///
+/// # Examples
+///
+/// ```
+/// # use arrow_array::TimestampSecondArray;
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::TimestampSecondType;
+/// # use arrow_arith::temporal::{date_trunc, DateTruncGranularity};
+/// // 2024-03-10T12:15:00+05:30
+/// let input = TimestampSecondArray::from(vec![Some(1710053100), None]).with_timezone("+05:30");
+///
+/// let hours = date_trunc(&input, DateTruncGranularity::Hour).unwrap();
+/// let expected = TimestampSecondArray::from(vec![Some(1710052200), None]).with_timezone("+05:30");
+/// assert_eq!(hours.as_primitive::<TimestampSecondType>(), &expected);
+///
+/// // Local midnight, which is 2024-03-09T18:30:00Z
+/// let days = date_trunc(&input, DateTruncGranularity::Day).unwrap();
+/// assert_eq!(days.as_primitive::<TimestampSecondType>().value(0), 1710009000);
+/// ```
+pub fn date_trunc(
+    array: &dyn Array,
+    granularity: DateTruncGranularity,
+) -> Result<ArrayRef, ArrowError> {
+    timestamp_op(array, &TimestampOp::Trunc(granularity))
+}
+
+/// Assign each timestamp of `array` to a bin of width `stride` aligned to `origin`,
+/// returning the start of the bin containing it, as in SQL `date_bin`
+///
+/// `stride` may be a number of months, or a number of days and nanoseconds, but not both.
+/// `origin` is a timestamp of the same [`TimeUnit`] and timezone as `array`.
+///
+/// As for [`date_trunc`], timestamps with a timezone are binned in the local time of that
+/// timezone. For example, with an `origin` of local midnight, a one day `stride` bins
+/// timestamps by local date regardless of daylight saving time transitions.
+///
+/// Supports all [`DataType::Timestamp`] arrays, and dictionaries of these, returning an
+/// array of the same type.
+///
+/// # Examples
+///
+/// ```
+/// # use arrow_array::TimestampMillisecondArray;
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::{IntervalMonthDayNano, TimestampMillisecondType};
+/// # use arrow_arith::temporal::date_bin;
+/// // 00:07, 00:16 and 00:31 on 1970-01-01
+/// let input = TimestampMillisecondArray::from(vec![420_000, 960_000, 1_860_000]);
+///
+/// // 15 minute bins starting at 00:05
+/// let stride = IntervalMonthDayNano::new(0, 0, 15 * 60 * 1_000_000_000);
+/// let bins = date_bin(&input, stride, 300_000).unwrap();
+/// let expected = TimestampMillisecondArray::from(vec![300_000, 300_000, 1_200_000]);
+/// assert_eq!(bins.as_primitive::<TimestampMillisecondType>(), &expected);
+/// ```
+pub fn date_bin(
+    array: &dyn Array,
+    stride: IntervalMonthDayNano,
+    origin: i64,
+) -> Result<ArrayRef, ArrowError> {
+    let op = match stride {
+        IntervalMonthDayNano {
+            months,
+            days: 0,
+            nanoseconds: 0,
+        } if months > 0 => TimestampOp::BinMonths {
+            months,
+            origin,
+        },
+        IntervalMonthDayNano {
+            months: 0,
+            days,
+            nanoseconds,
+        } => {
+            let nanoseconds = (days as i64)
+                .checked_mul(NANOSECONDS_IN_DAY)
+                .and_then(|d| d.checked_add(nanoseconds))
+                .filter(|n| *n > 0)
+                .ok_or_else(|| {
+                    ArrowError::ComputeError(format!(
+                        "date_bin stride must be positive, got {stride:?}"
+                    ))
+                })?;
+            TimestampOp::BinNanoseconds {
+                nanoseconds,
+                origin,
+            }
+        }
+        _ => {
+            return Err(ArrowError::ComputeError(format!(
+                "date_bin stride must be positive, and cannot combine months with days or nanoseconds, got {stride:?}"
+            )))
+        }
+    };
+    timestamp_op(array, &op)
+}
+
+/// The operation applied to each timestamp by [`timestamp_op`]
+enum TimestampOp {
+    Trunc(DateTruncGranularity),
+    BinMonths { months: i32, origin: i64 },
+    BinNanoseconds { nanoseconds: i64, origin: i64 },
+}
+
+impl std::fmt::Display for TimestampOp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Trunc(_) => write!(f, "date_trunc"),
+            Self::BinMonths { .. } | Self::BinNanoseconds { .. } => write!(f, "date_bin"),
+        }
+    }
+}
+
+fn timestamp_op(array: &dyn Array, op: &TimestampOp) -> Result<ArrayRef, ArrowError> {
+    let array: ArrayRef = match array.data_type() {
+        DataType::Timestamp(TimeUnit::Second, _) => Arc::new(timestamp_op_impl(
+            array.as_primitive::<TimestampSecondType>(),
+            op,
+        )?),
+        DataType::Timestamp(TimeUnit::Millisecond, _) => Arc::new(timestamp_op_impl(
+            array.as_primitive::<TimestampMillisecondType>(),
+            op,
+        )?),
+        DataType::Timestamp(TimeUnit::Microsecond, _) => Arc::new(timestamp_op_impl(
+            array.as_primitive::<TimestampMicrosecondType>(),
+            op,
+        )?),
+        DataType::Timestamp(TimeUnit::Nanosecond, _) => Arc::new(timestamp_op_impl(
+            array.as_primitive::<TimestampNanosecondType>(),
+            op,
+        )?),
+        DataType::Dictionary(_, _) => {
+            let array = array.as_any_dictionary();
+            let values = timestamp_op(array.values(), op)?;
+            array.with_values(values)
+        }
+        t => return_compute_error_with!(format!("{op} does not support"), t),
+    };
+    Ok(array)
+}
+
+fn timestamp_op_impl<T: ArrowTimestampType>(
+    array: &PrimitiveArray<T>,
+    op: &TimestampOp,
+) -> Result<PrimitiveArray<T>, ArrowError> {
+    let tz = get_tz(array.data_type())?;
+    let unit_nanos = match T::UNIT {
+        TimeUnit::Second => NANOSECONDS,
+        TimeUnit::Millisecond => 1_000_000,
+        TimeUnit::Microsecond => 1_000,
+        TimeUnit::Nanosecond => 1,
+    };
+
+    // Without a timezone, fixed duration truncation is plain integer arithmetic
+    let fixed = match op {
+        TimestampOp::Trunc(granularity) => granularity.nanoseconds().map(|n| (n, 0)),
+        TimestampOp::BinNanoseconds {
+            nanoseconds,
+            origin,
+        } => Some((*nanoseconds, *origin)),
+        TimestampOp::BinMonths { .. } => None,
+    };
+    if let (None, Some((nanoseconds, origin))) = (&tz, fixed) {
+        if nanoseconds % unit_nanos == 0 {
+            let stride = nanoseconds / unit_nanos;
+            return array.try_unary(|v| {
+                v.checked_sub(origin)
+                    .and_then(|elapsed| v.checked_sub(elapsed.rem_euclid(stride)))
+                    .ok_or_else(|| timestamp_op_error(op, v))
+            });
+        }
+    }
+
+    let to_local = |v: i64| {
+        let utc = as_datetime::<T>(v)?;
+        Some(match &tz {
+            Some(tz) => (utc, tz.offset_from_utc_datetime(&utc).fix()),
+            None => (utc, FixedOffset::east_opt(0)?),
+        })
+    };
+    let from_local = |local: NaiveDateTime, offset: FixedOffset| match &tz {
+        Some(tz) => local_to_utc(tz, local, offset),
+        None => Some(local),
+    };
+
+    let origin = match op {
+        TimestampOp::Trunc(_) => None,
+        TimestampOp::BinMonths { origin, .. } | TimestampOp::BinNanoseconds { origin, .. } => {
+            let (utc, offset) = to_local(*origin).ok_or_else(|| timestamp_op_error(op, *origin))?;
+            Some(utc + offset)
+        }
+    };
+
+    let result = array.try_unary(|v| {
+        let (utc, offset) = to_local(v).ok_or_else(|| timestamp_op_error(op, v))?;
+        let local = utc + offset;
+        let start = match (op, origin) {
+            (TimestampOp::Trunc(granularity), _) => granularity.truncate(local),
+            (TimestampOp::BinMonths { months, .. }, Some(origin)) => {
+                let elapsed = (local.year() as i64 * 12 + local.month0() as i64)
+                    - (origin.year() as i64 * 12 + origin.month0() as i64);
+                let bins = elapsed.div_euclid(*months as i64);
+                add_months(origin, bins * *months as i64).and_then(|start| {
+                    // The day or time of month of the origin may be later than that of `local`
+                    match start > local {
+                        true => add_months(start, -(*months as i64)),
+                        false => Some(start),
+                    }
+                })
+            }
+            (TimestampOp::BinNanoseconds { nanoseconds, .. }, Some(origin)) => (local - origin)
+                .num_nanoseconds()
+                .and_then(|elapsed| elapsed.div_euclid(*nanoseconds).checked_mul(*nanoseconds))
+                .and_then(|n| origin.checked_add_signed(TimeDelta::nanoseconds(n))),
+            _ => unreachable!(),
+        };
+        start
+            .and_then(|start| from_local(start, offset))
+            .and_then(T::make_value)
+            .ok_or_else(|| timestamp_op_error(op, v))
+    })?;
+    Ok(result.with_data_type(array.data_type().clone()))
+}
+
+fn timestamp_op_error(op: &TimestampOp, v: i64) -> ArrowError {
+    ArrowError::ComputeError(format!("{op} overflow for timestamp {v}"))
+}
+
+/// Adds a possibly negative number of `months` to `datetime`, clamping the day of month
+fn add_months(datetime: NaiveDateTime, months: i64) -> Option<NaiveDateTime> {
+    let abs = Months::new(months.unsigned_abs().try_into().ok()?);
+    match months >= 0 {
+        true => datetime.checked_add_months(abs),
+        false => datetime.checked_sub_months(abs),
+    }
+}
+
+/// Converts `local` time in `tz` to UTC, preferring `offset` if `local` is ambiguous
+fn local_to_utc(tz: &Tz, local: NaiveDateTime, offset: FixedOffset) -> Option<NaiveDateTime> {
+    match tz.from_local_datetime(&local) {
+        LocalResult::Single(dt) => Some(dt.naive_utc()),
+        LocalResult::Ambiguous(a, b) => {
+            let same_offset = [&a, &b].into_iter().find(|dt| dt.offset().fix() == offset);
+            Some(same_offset.unwrap_or(&a.min(b)).naive_utc())
+        }
+        // `local` was skipped by a transition, and so the transition itself is used, which
+        // is `local` with the offset in effect before it
+        LocalResult::None => {
+            let before = tz
+                .offset_from_utc_datetime(&(local - TimeDelta::days(1)))
+                .fix();
+            Some(local - TimeDelta::seconds(before.local_minus_utc() as i64))
+        }
+    }
+}
+
 macro_rules! return_compute_error_with {
     ($msg:expr, $param:expr) => {
         return { Err(ArrowError::ComputeError(format!("{}: {:?}", $msg, $param))) }
@@ -1968,4 +2314,132 @@ mod tests {
         assert_eq!(2015, actual.value(1));
         assert_eq!(2016, actual.value(2));
     }
+
+    #[test]
+    fn test_date_trunc() {
+        // 2021-05-19T13:47:59.123456789Z
+        let nanos = 1621432079123456789;
+        let cases = [
+            (DateTruncGranularity::Year, 1609459200000000000),
+            (DateTruncGranularity::Quarter, 1617235200000000000),
+            (DateTruncGranularity::Month, 1619827200000000000),
+            (DateTruncGranularity::Week, 1621209600000000000),
+            (DateTruncGranularity::Day, 1621382400000000000),
+            (DateTruncGranularity::Hour, 1621429200000000000),
+            (DateTruncGranularity::Minute, 1621432020000000000),
+            (DateTruncGranularity::Second, 1621432079000000000),
+            (DateTruncGranularity::Millisecond, 1621432079123000000),
+            (DateTruncGranularity::Microsecond, 1621432079123456000),
+        ];
+        for (granularity, expected) in cases {
+            let a = TimestampNanosecondArray::from(vec![Some(nanos), None]);
+            let b = date_trunc(&a, granularity).unwrap();
+            let expected_array = TimestampNanosecondArray::from(vec![Some(expected), None]);
+            assert_eq!(b.as_primitive(), &expected_array, "{granularity}");
+
+            // With a timezone, truncating in local time
+            let a = a.with_timezone("+00:00");
+            let b = date_trunc(&a, granularity).unwrap();
+            let expected_array = expected_array.clone().with_timezone("+00:00");
+            assert_eq!(b.as_primitive(), &expected_array, "{granularity}");
+
+            for tz in [None, Some("+00:00")] {
+                let a =
+                    TimestampMillisecondArray::from(vec![nanos / 1_000_000]).with_timezone_opt(tz);
+                let b = date_trunc(&a, granularity).unwrap();
+                let b = b.as_primitive::<TimestampMillisecondType>();
+                assert_eq!(b.value(0), expected / 1_000_000, "{granularity} {tz:?}");
+
+                let a =
+                    TimestampSecondArray::from(vec![nanos / 1_000_000_000]).with_timezone_opt(tz);
+                let b = date_trunc(&a, granularity).unwrap();
+                let b = b.as_primitive::<TimestampSecondType>();
+                assert_eq!(b.value(0), expected / 1_000_000_000, "{granularity} {tz:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_date_trunc_timezone() {
+        // 1969-12-31T23:59:59Z
+        let a = TimestampSecondArray::from(vec![-1]);
+        let b = date_trunc(&a, DateTruncGranularity::Day).unwrap();
+        assert_eq!(b.as_primitive::<TimestampSecondType>().value(0), -86400);
+
+        // 2021-05-19T19:17:59+05:30 is truncated to local midnight
+        let a = TimestampMicrosecondArray::from(vec![1621432079000000]).with_timezone("+05:30");
+        let b = date_trunc(&a, DateTruncGranularity::Day).unwrap();
+        let b = b.as_primitive::<TimestampMicrosecondType>();
+        assert_eq!(b.value(0), 1621362600000000);
+        assert_eq!(b.timezone(), Some("+05:30"));
+
+        let b = date_trunc(&a, DateTruncGranularity::Hour).unwrap();
+        let b = b.as_primitive::<TimestampMicrosecondType>();
+        assert_eq!(b.value(0), 1621431000000000);
+
+        // Dictionary values are truncated
+        let keys = Int8Array::from(vec![Some(0), None, Some(0)]);
+        let dict = DictionaryArray::new(keys, Arc::new(a));
+        let b = date_trunc(&dict, DateTruncGranularity::Day).unwrap();
+        let b = b.as_dictionary::<Int8Type>();
+        let values = b.values().as_primitive::<TimestampMicrosecondType>();
+        assert_eq!(values.value(0), 1621362600000000);
+        assert_eq!(b.keys(), &Int8Array::from(vec![Some(0), None, Some(0)]));
+
+        let err = date_trunc(&Int32Array::from(vec![1]), DateTruncGranularity::Day).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: date_trunc does not support: Int32"
+        );
+    }
+
+    #[test]
+    fn test_date_bin() {
+        // Two month bins starting 2021-01-15, of 2021-05-19, 2021-05-10 and 2020-12-01
+        let stride = IntervalMonthDayNano::new(2, 0, 0);
+        for tz in [None, Some("+00:00")] {
+            let a = TimestampSecondArray::from(vec![1621432079, 1620604800, 1606780800])
+                .with_timezone_opt(tz);
+            let b = date_bin(&a, stride, 1610668800).unwrap();
+            let b = b.as_primitive::<TimestampSecondType>();
+            assert_eq!(b.values(), &[1621036800, 1615766400, 1605398400], "{tz:?}");
+        }
+
+        // Ten day bins starting at the epoch
+        let stride = IntervalMonthDayNano::new(0, 10, 0);
+        for tz in [None, Some("+00:00")] {
+            let a = TimestampMillisecondArray::from(vec![Some(-1), None, Some(864_000_000)])
+                .with_timezone_opt(tz);
+            let b = date_bin(&a, stride, 0).unwrap();
+            let b = b.as_primitive::<TimestampMillisecondType>();
+            assert_eq!(b.value(0), -864_000_000, "{tz:?}");
+            assert!(b.is_null(1));
+            assert_eq!(b.value(2), 864_000_000, "{tz:?}");
+            assert_eq!(b.timezone(), tz);
+        }
+
+        let stride = IntervalMonthDayNano::new(0, 0, 1_000);
+        let a = TimestampNanosecondArray::from(vec![-1, 1_500]);
+        let b = date_bin(&a, stride, 250).unwrap();
+        let b = b.as_primitive::<TimestampNanosecondType>();
+        assert_eq!(b.values(), &[-750, 1_250]);
+    }
+
+    #[test]
+    fn test_date_bin_invalid_stride() {
+        let a = TimestampSecondArray::from(vec![0]);
+        for stride in [
+            IntervalMonthDayNano::new(0, 0, 0),
+            IntervalMonthDayNano::new(0, 0, -1),
+            IntervalMonthDayNano::new(-1, 0, 0),
+            IntervalMonthDayNano::new(1, 1, 0),
+        ] {
+            let err = date_bin(&a, stride, 0).unwrap_err();
+            assert!(
+                err.to_string()
+                    .starts_with("Compute error: date_bin stride must be positive"),
+                "{err}"
+            );
+        }
+    }
 }
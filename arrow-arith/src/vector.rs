@@ -0,0 +1,384 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines vector similarity kernels for [`FixedSizeListArray`] of floating point values
+//!
+//! Each kernel accepts two [`Datum`], where each is a [`FixedSizeListArray`] with
+//! [`DataType::Float32`] or [`DataType::Float16`] values. A query vector can be provided
+//! as a [`Scalar`](arrow_array::Scalar) wrapping a single element [`FixedSizeListArray`],
+//! which is then compared against every vector of the other side. Otherwise, vectors
+//! are compared pairwise.
+//!
+//! The result is a [`Float32Array`], with null for any vector that is null or contains
+//! a null element.
+//!
+//! ```
+//! # use std::sync::Arc;
+//! # use arrow_arith::vector::{cosine_similarity, dot_product, l2_distance};
+//! # use arrow_array::{FixedSizeListArray, Float32Array, Scalar};
+//! # use arrow_schema::{DataType, Field};
+//! let field = Arc::new(Field::new_list_field(DataType::Float32, true));
+//! let values = Float32Array::from(vec![1., 0., 0., 1., 3., 4.]);
+//! let vectors = FixedSizeListArray::new(field.clone(), 2, Arc::new(values), None);
+//!
+//! let query = Float32Array::from(vec![1., 0.]);
+//! let query = Scalar::new(FixedSizeListArray::new(field, 2, Arc::new(query), None));
+//!
+//! let dot = dot_product(&vectors, &query).unwrap();
+//! assert_eq!(dot.values(), &[1., 0., 3.]);
+//!
+//! let distance = l2_distance(&vectors, &query).unwrap();
+//! assert_eq!(distance.values(), &[0., 2_f32.sqrt(), 20_f32.sqrt()]);
+//!
+//! let similarity = cosine_similarity(&vectors, &query).unwrap();
+//! assert_eq!(similarity.values(), &[1., 0., 0.6]);
+//! ```
+
+use std::borrow::Cow;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::{Float16Type, Float32Type};
+use arrow_array::{Array, Datum, FixedSizeListArray, Float32Array};
+use arrow_buffer::{BooleanBufferBuilder, NullBuffer};
+use arrow_schema::{ArrowError, DataType};
+
+/// The number of independent accumulators used by the inner loops, allowing
+/// them to be vectorized by the compiler
+const LANES: usize = 16;
+
+/// Returns the dot product of the vectors in `lhs` and `rhs`
+///
+/// See the [module documentation](self) for details on the accepted inputs
+pub fn dot_product(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<Float32Array, ArrowError> {
+    vector_op(lhs, rhs, dot)
+}
+
+/// Returns the euclidean distance between the vectors in `lhs` and `rhs`
+///
+/// See the [module documentation](self) for details on the accepted inputs
+pub fn l2_distance(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<Float32Array, ArrowError> {
+    vector_op(lhs, rhs, |a, b| l2_squared(a, b).sqrt())
+}
+
+/// Returns the cosine similarity of the vectors in `lhs` and `rhs`
+///
+/// The similarity of a zero vector with any other vector is `NaN`
+///
+/// See the [module documentation](self) for details on the accepted inputs
+pub fn cosine_similarity(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<Float32Array, ArrowError> {
+    vector_op(lhs, rhs, cosine)
+}
+
+/// Dispatch `op` for each pair of vectors in `lhs` and `rhs`
+fn vector_op<F>(lhs: &dyn Datum, rhs: &dyn Datum, op: F) -> Result<Float32Array, ArrowError>
+where
+    F: Fn(&[f32], &[f32]) -> f32,
+{
+    let (l, l_scalar) = lhs.get();
+    let (r, r_scalar) = rhs.get();
+
+    let l = as_vectors(l)?;
+    let r = as_vectors(r)?;
+
+    if l.value_length() != r.value_length() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Cannot compare vectors of different lengths, got {} and {}",
+            l.value_length(),
+            r.value_length()
+        )));
+    }
+
+    let len = match (l_scalar, r_scalar) {
+        (true, false) => r.len(),
+        (false, true) | (true, true) => l.len(),
+        (false, false) if l.len() == r.len() => l.len(),
+        (false, false) => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Cannot compare arrays of different lengths, got {} vs {}",
+                l.len(),
+                r.len()
+            )))
+        }
+    };
+
+    let size = l.value_length() as usize;
+    let l_values = vector_values(l)?;
+    let r_values = vector_values(r)?;
+    let l_valid = vector_validity(l);
+    let r_valid = vector_validity(r);
+
+    let mut values = Vec::with_capacity(len);
+    let mut nulls = BooleanBufferBuilder::new(len);
+    for idx in 0..len {
+        let l_idx = if l_scalar { 0 } else { idx };
+        let r_idx = if r_scalar { 0 } else { idx };
+
+        let valid = l_valid.as_ref().is_none_or(|v| v.is_valid(l_idx))
+            && r_valid.as_ref().is_none_or(|v| v.is_valid(r_idx));
+        nulls.append(valid);
+
+        match valid {
+            true => {
+                let a = &l_values[l_idx * size..(l_idx + 1) * size];
+                let b = &r_values[r_idx * size..(r_idx + 1) * size];
+                values.push(op(a, b))
+            }
+            false => values.push(0.),
+        }
+    }
+
+    let nulls = NullBuffer::new(nulls.finish());
+    let nulls = (nulls.null_count() > 0).then_some(nulls);
+    Ok(Float32Array::new(values.into(), nulls))
+}
+
+fn as_vectors(array: &dyn Array) -> Result<&FixedSizeListArray, ArrowError> {
+    array.as_fixed_size_list_opt().ok_or_else(|| {
+        ArrowError::InvalidArgumentError(format!(
+            "Expected FixedSizeList of Float32 or Float16, got {}",
+            array.data_type()
+        ))
+    })
+}
+
+/// Returns the values of `array` as a contiguous slice of `f32`
+fn vector_values(array: &FixedSizeListArray) -> Result<Cow<'_, [f32]>, ArrowError> {
+    let values = array.values();
+    match values.data_type() {
+        DataType::Float32 => Ok(Cow::Borrowed(
+            values.as_primitive::<Float32Type>().values().as_ref(),
+        )),
+        DataType::Float16 => Ok(Cow::Owned(
+            values
+                .as_primitive::<Float16Type>()
+                .values()
+                .iter()
+                .map(|v| v.to_f32())
+                .collect(),
+        )),
+        d => Err(ArrowError::InvalidArgumentError(format!(
+            "Expected FixedSizeList of Float32 or Float16, got FixedSizeList of {d}"
+        ))),
+    }
+}
+
+/// Returns the validity of the vectors in `array`, where a vector is null if it
+/// is null in `array` or contains a null element
+fn vector_validity(array: &FixedSizeListArray) -> Option<NullBuffer> {
+    let nulls = array.logical_nulls();
+    let values = match array.values().logical_nulls() {
+        Some(values) if values.null_count() > 0 => values,
+        _ => return nulls,
+    };
+
+    let size = array.value_length() as usize;
+    let mut builder = BooleanBufferBuilder::new(array.len());
+    for idx in 0..array.len() {
+        let valid = nulls.as_ref().is_none_or(|n| n.is_valid(idx))
+            && (idx * size..(idx + 1) * size).all(|i| values.is_valid(i));
+        builder.append(valid);
+    }
+    Some(NullBuffer::new(builder.finish()))
+}
+
+/// Returns the dot product of `a` and `b`
+fn dot(a: &[f32], b: &[f32]) -> f32 {
+    let mut acc = [0_f32; LANES];
+    let a_chunks = a.chunks_exact(LANES);
+    let b_chunks = b.chunks_exact(LANES);
+    let tail = a_chunks
+        .remainder()
+        .iter()
+        .zip(b_chunks.remainder())
+        .map(|(a, b)| a * b)
+        .sum::<f32>();
+
+    for (a, b) in a_chunks.zip(b_chunks) {
+        for i in 0..LANES {
+            acc[i] += a[i] * b[i];
+        }
+    }
+    acc.iter().sum::<f32>() + tail
+}
+
+/// Returns the squared euclidean distance between `a` and `b`
+fn l2_squared(a: &[f32], b: &[f32]) -> f32 {
+    let mut acc = [0_f32; LANES];
+    let a_chunks = a.chunks_exact(LANES);
+    let b_chunks = b.chunks_exact(LANES);
+    let tail = a_chunks
+        .remainder()
+        .iter()
+        .zip(b_chunks.remainder())
+        .map(|(a, b)| (a - b) * (a - b))
+        .sum::<f32>();
+
+    for (a, b) in a_chunks.zip(b_chunks) {
+        for i in 0..LANES {
+            let d = a[i] - b[i];
+            acc[i] += d * d;
+        }
+    }
+    acc.iter().sum::<f32>() + tail
+}
+
+/// Returns the cosine similarity of `a` and `b`
+fn cosine(a: &[f32], b: &[f32]) -> f32 {
+    let mut dot = [0_f32; LANES];
+    let mut a_norm = [0_f32; LANES];
+    let mut b_norm = [0_f32; LANES];
+
+    let a_chunks = a.chunks_exact(LANES);
+    let b_chunks = b.chunks_exact(LANES);
+    let (mut dot_tail, mut a_tail, mut b_tail) = (0_f32, 0_f32, 0_f32);
+    for (a, b) in a_chunks.remainder().iter().zip(b_chunks.remainder()) {
+        dot_tail += a * b;
+        a_tail += a * a;
+        b_tail += b * b;
+    }
+
+    for (a, b) in a_chunks.zip(b_chunks) {
+        for i in 0..LANES {
+            dot[i] += a[i] * b[i];
+            a_norm[i] += a[i] * a[i];
+            b_norm[i] += b[i] * b[i];
+        }
+    }
+
+    let dot = dot.iter().sum::<f32>() + dot_tail;
+    let a_norm = a_norm.iter().sum::<f32>() + a_tail;
+    let b_norm = b_norm.iter().sum::<f32>() + b_tail;
+    dot / (a_norm.sqrt() * b_norm.sqrt())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::{Float16Array, Int32Array, Scalar};
+    use arrow_schema::Field;
+    use num::FromPrimitive;
+    use std::sync::Arc;
+
+    fn vectors(size: i32, values: Vec<f32>, nulls: Option<NullBuffer>) -> FixedSizeListArray {
+        let field = Arc::new(Field::new_list_field(DataType::Float32, true));
+        FixedSizeListArray::new(field, size, Arc::new(Float32Array::from(values)), nulls)
+    }
+
+    fn naive_dot(a: &[f32], b: &[f32]) -> f32 {
+        a.iter().zip(b).map(|(a, b)| a * b).sum()
+    }
+
+    #[test]
+    fn test_pairwise() {
+        let a = vectors(3, vec![1., 2., 3., 4., 5., 6.], None);
+        let b = vectors(3, vec![1., 1., 1., -4., -5., -6.], None);
+
+        let dot = dot_product(&a, &b).unwrap();
+        assert_eq!(dot.values(), &[6., -77.]);
+
+        let distance = l2_distance(&a, &b).unwrap();
+        assert_eq!(distance.values(), &[5_f32.sqrt(), 308_f32.sqrt()]);
+
+        let similarity = cosine_similarity(&a, &b).unwrap();
+        assert!((similarity.value(0) - 6. / (14_f32.sqrt() * 3_f32.sqrt())).abs() < 1e-6);
+        assert!((similarity.value(1) + 1.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_long_vectors() {
+        // Exercise both the chunked loop and the remainder
+        let size = 37;
+        let a: Vec<f32> = (0..size * 2).map(|x| x as f32 * 0.5).collect();
+        let b: Vec<f32> = (0..size * 2).map(|x| 3. - x as f32).collect();
+        let query = Scalar::new(vectors(size as i32, b[..size].to_vec(), None));
+        let array = vectors(size as i32, a.clone(), None);
+
+        let dot = dot_product(&array, &query).unwrap();
+        for (idx, v) in dot.values().iter().enumerate() {
+            let expected = naive_dot(&a[idx * size..(idx + 1) * size], &b[..size]);
+            assert!((v - expected).abs() < 1e-2, "{v} != {expected}");
+        }
+
+        let reversed = dot_product(&query, &array).unwrap();
+        assert_eq!(dot, reversed);
+    }
+
+    #[test]
+    fn test_nulls() {
+        let nulls = NullBuffer::from(vec![true, false, true]);
+        let values =
+            Float32Array::from(vec![Some(1.), Some(0.), Some(1.), Some(1.), None, Some(1.)]);
+        let field = Arc::new(Field::new_list_field(DataType::Float32, true));
+        let array = FixedSizeListArray::new(field, 2, Arc::new(values), Some(nulls));
+        let query = Scalar::new(vectors(2, vec![2., 2.], None));
+
+        let dot = dot_product(&array, &query).unwrap();
+        assert_eq!(dot.len(), 3);
+        assert!(dot.is_valid(0));
+        assert_eq!(dot.value(0), 2.);
+        assert!(dot.is_null(1));
+        assert!(dot.is_null(2));
+
+        let sliced = array.slice(2, 1);
+        assert!(l2_distance(&sliced, &query).unwrap().is_null(0));
+        let sliced = array.slice(0, 1);
+        let distance = l2_distance(&sliced, &query).unwrap();
+        assert_eq!(distance.value(0), 5_f32.sqrt());
+    }
+
+    #[test]
+    fn test_float16() {
+        let field = Arc::new(Field::new_list_field(DataType::Float16, true));
+        let values = Float16Array::from_iter_values(
+            [3_f32, 4., 0., 0.].map(|v| FromPrimitive::from_f32(v).unwrap()),
+        );
+        let a = FixedSizeListArray::new(field, 2, Arc::new(values), None);
+        let query = Scalar::new(vectors(2, vec![3., 4.], None));
+
+        let similarity = cosine_similarity(&a, &query).unwrap();
+        assert_eq!(similarity.value(0), 1.);
+        assert!(similarity.value(1).is_nan());
+
+        let dot = dot_product(&a, &query).unwrap();
+        assert_eq!(dot.values(), &[25., 0.]);
+    }
+
+    #[test]
+    fn test_invalid() {
+        let a = vectors(2, vec![1., 2.], None);
+        let b = vectors(3, vec![1., 2., 3.], None);
+        let err = dot_product(&a, &b).unwrap_err().to_string();
+        assert_eq!(
+            err,
+            "Invalid argument error: Cannot compare vectors of different lengths, got 2 and 3"
+        );
+
+        let c = vectors(2, vec![1., 2., 3., 4.], None);
+        let err = dot_product(&a, &c).unwrap_err().to_string();
+        assert_eq!(
+            err,
+            "Invalid argument error: Cannot compare arrays of different lengths, got 1 vs 2"
+        );
+
+        let ints = Int32Array::from(vec![1, 2]);
+        let err = dot_product(&a, &ints).unwrap_err().to_string();
+        assert_eq!(
+            err,
+            "Invalid argument error: Expected FixedSizeList of Float32 or Float16, got Int32"
+        );
+    }
+}
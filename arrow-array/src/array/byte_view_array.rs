@@ -520,6 +520,35 @@ impl<T: ByteViewType + ?Sized> GenericByteViewArray<T> {
         unsafe { GenericByteViewArray::new_unchecked(views_scalar, data_blocks, nulls) }
     }
 
+    /// Compacts the data buffers with [`Self::gc`] if less than `threshold` of their bytes
+    /// are referenced by the views, otherwise returns a clone of this array
+    ///
+    /// `threshold` is a fraction between `0.0` and `1.0`, for example, with a threshold of
+    /// `0.5` the array is only compacted once more than half of its buffer bytes are
+    /// unreferenced. This allows long-lived arrays, e.g. the output of filters or slices
+    /// that are kept in a cache, to release unused memory without paying for a copy when
+    /// the buffers are mostly in use.
+    ///
+    /// See [`Self::total_buffer_bytes_used`] for how referenced bytes are counted
+    ///
+    /// ```
+    /// # use arrow_array::StringViewArray;
+    /// let array = StringViewArray::from(vec!["a string longer than 12 bytes", "short"]);
+    /// let sliced = array.slice(1, 1);
+    /// // None of the buffer bytes are referenced by the slice
+    /// assert_eq!(sliced.gc_if_needed(0.5).data_buffers().len(), 0);
+    /// // All of the buffer bytes are referenced by the original array
+    /// assert_eq!(array.gc_if_needed(0.5).data_buffers().len(), 1);
+    /// ```
+    pub fn gc_if_needed(&self, threshold: f64) -> Self {
+        let allocated: usize = self.data_buffers().iter().map(|b| b.len()).sum();
+        let used = self.total_buffer_bytes_used();
+        match (used as f64) < threshold * allocated as f64 {
+            true => self.gc(),
+            false => self.clone(),
+        }
+    }
+
     /// Copy the i‑th view into `data_buf` if it refers to an out‑of‑line buffer.
     ///
     /// # Safety
@@ -1268,6 +1297,39 @@ mod tests {
         check_gc(&array.slice(3, 1));
     }
 
+    #[test]
+    fn test_gc_if_needed() {
+        let long = "longer than 12 bytes";
+        let array = {
+            let mut builder = StringViewBuilder::new();
+            (0..10).for_each(|_| builder.append_value(long));
+            builder.finish()
+        };
+
+        // All bytes referenced
+        let gc = array.gc_if_needed(1.0);
+        assert_eq!(
+            gc.data_buffers()[0].as_ptr(),
+            array.data_buffers()[0].as_ptr()
+        );
+
+        // 20% of bytes referenced
+        let sliced = array.slice(0, 2);
+        let gc = sliced.gc_if_needed(0.1);
+        assert_eq!(
+            gc.data_buffers()[0].as_ptr(),
+            array.data_buffers()[0].as_ptr()
+        );
+
+        let gc = sliced.gc_if_needed(0.5);
+        assert_eq!(gc.data_buffers()[0].len(), 2 * long.len());
+        assert_eq!(gc, sliced);
+
+        // No data buffers
+        let inline = StringViewArray::from(vec!["short"]);
+        assert_eq!(inline.gc_if_needed(1.0), inline);
+    }
+
     /// 1) Empty array: no elements, expect gc to return empty with no data buffers
     #[test]
     fn test_gc_empty_array() {
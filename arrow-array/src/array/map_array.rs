@@ -16,6 +16,7 @@
 // under the License.
 
 use crate::array::{get_offsets, print_long_array};
+use crate::builder::{ArrayBuilder, MapBuilder};
 use crate::iterator::MapArrayIter;
 use crate::{make_array, Array, ArrayAccessor, ArrayRef, ListArray, StringArray, StructArray};
 use arrow_buffer::{ArrowNativeType, Buffer, NullBuffer, OffsetBuffer, ToByteSlice};
@@ -304,6 +305,39 @@ impl MapArray {
         })
     }
 
+    /// Creates a [`MapArray`] from an iterator of optional maps, each given as an
+    /// iterator of key value pairs
+    ///
+    /// Keys are appended to `key_builder` and values to `value_builder`, see
+    /// [`MapBuilder::append_pairs`](crate::builder::MapBuilder::append_pairs). Use
+    /// [`MapBuilder::from_pairs`](crate::builder::MapBuilder::from_pairs) to also
+    /// customise the field names.
+    ///
+    /// ```
+    /// # use arrow_array::builder::{Int32Builder, StringBuilder};
+    /// # use arrow_array::{Array, MapArray};
+    /// let data = vec![Some(vec![("a", Some(1)), ("b", None)]), None, Some(vec![])];
+    /// let map = MapArray::from_iter_pairs(StringBuilder::new(), Int32Builder::new(), data);
+    /// assert_eq!(map.len(), 3);
+    /// assert_eq!(map.value_offsets(), &[0, 2, 2, 2]);
+    /// assert!(map.is_null(1));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key_builder` and `value_builder` do not have the same length
+    pub fn from_iter_pairs<K, V, I, E, KI, VI>(key_builder: K, value_builder: V, iter: I) -> Self
+    where
+        K: ArrayBuilder + Extend<Option<KI>>,
+        V: ArrayBuilder + Extend<VI>,
+        I: IntoIterator<Item = Option<E>>,
+        E: IntoIterator<Item = (KI, VI)>,
+    {
+        MapBuilder::from_pairs(None, key_builder, value_builder, iter)
+            .expect("key and value builders must have equal lengths")
+            .finish()
+    }
+
     /// Creates map array from provided keys, values and entry_offsets.
     pub fn new_from_strings<'a>(
         keys: impl Iterator<Item = &'a str>,
@@ -691,6 +725,29 @@ mod tests {
         let _ = MapArray::from(ArrayData::new_empty(&dict_t));
     }
 
+    #[test]
+    fn test_from_iter_pairs() {
+        use crate::builder::{StringBuilder, UInt32Builder};
+
+        let data = vec![
+            Some(vec![("a", Some(1)), ("b", Some(2))]),
+            None,
+            Some(vec![]),
+            Some(vec![("c", None)]),
+        ];
+        let map = MapArray::from_iter_pairs(StringBuilder::new(), UInt32Builder::new(), data);
+
+        assert_eq!(map.len(), 4);
+        assert_eq!(map.null_count(), 1);
+        assert_eq!(map.value_offsets(), &[0, 2, 2, 2, 3]);
+        assert_eq!(map.keys().as_ref(), &StringArray::from(vec!["a", "b", "c"]));
+        assert_eq!(
+            map.values().as_primitive::<UInt32Type>(),
+            &UInt32Array::from(vec![Some(1), Some(2), None])
+        );
+        assert_eq!(map.key_type(), &DataType::Utf8);
+    }
+
     #[test]
     fn test_new_from_strings() {
         let keys = vec!["a", "b", "c", "d", "e", "f", "g", "h"];
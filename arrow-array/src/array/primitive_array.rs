@@ -696,6 +696,26 @@ impl<T: ArrowPrimitiveType> PrimitiveArray<T> {
         &self.values
     }
 
+    /// Copies the values of this array into a new [`Arc<[T::Native]>`]
+    ///
+    /// The values at null slots are unspecified, and the null buffer is discarded.
+    ///
+    /// An [`Arc<[T::Native]>`] can be converted back into a [`ScalarBuffer`]
+    /// without copying, see [`ScalarBuffer::from`].
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::Int32Array;
+    /// let array = Int32Array::from(vec![1, 2, 3]);
+    /// let values: Arc<[i32]> = array.into_shared_slice();
+    /// assert_eq!(values.as_ref(), &[1, 2, 3]);
+    /// let array = Int32Array::new(values.into(), None);
+    /// assert_eq!(array.values(), &[1, 2, 3]);
+    /// ```
+    pub fn into_shared_slice(self) -> Arc<[T::Native]> {
+        Arc::from(self.values.as_ref())
+    }
+
     /// Returns a new primitive array builder
     pub fn builder(capacity: usize) -> PrimitiveBuilder<T> {
         PrimitiveBuilder::<T>::with_capacity(capacity)
@@ -1001,6 +1021,55 @@ impl<T: ArrowPrimitiveType> PrimitiveArray<T> {
         Ok(Ok(builder.finish()))
     }
 
+    /// Applies a binary infallible function to the values of this array and `other`,
+    /// mutating the values of this array in place, if possible.
+    ///
+    /// Calls `op(self[i], other[i])` for `i` in `0..len`. If any index is null in either
+    /// array, the corresponding index in the result will also be null.
+    ///
+    /// # Buffer Reuse
+    ///
+    /// See [`Self::unary_mut`] for more information on buffer reuse. Returns `Err(self)`
+    /// if the buffers of this array are shared, or `Ok(Err(_))` if the arrays have
+    /// different lengths.
+    ///
+    /// ```
+    /// # use arrow_array::{Float32Array, Int32Array};
+    /// let a = Float32Array::from(vec![Some(5.1f32), None, Some(6.8)]);
+    /// let b = Int32Array::from(vec![Some(1), Some(2), None]);
+    /// let c = a.binary_mut(&b, |a, b| a + b as f32).unwrap().unwrap();
+    /// assert_eq!(c, Float32Array::from(vec![Some(6.1), None, None]));
+    /// ```
+    pub fn binary_mut<U, F>(
+        self,
+        other: &PrimitiveArray<U>,
+        op: F,
+    ) -> Result<Result<PrimitiveArray<T>, ArrowError>, PrimitiveArray<T>>
+    where
+        U: ArrowPrimitiveType,
+        F: Fn(T::Native, U::Native) -> T::Native,
+    {
+        if self.len() != other.len() {
+            return Ok(Err(ArrowError::ComputeError(
+                "Cannot perform binary operation on arrays of different length".to_string(),
+            )));
+        }
+
+        let data_type = self.data_type().clone();
+        let mut builder = self.into_builder()?;
+        builder
+            .values_slice_mut()
+            .iter_mut()
+            .zip(other.values())
+            .for_each(|(l, r)| *l = op(*l, *r));
+
+        let (_, values, nulls) = builder.finish().into_parts();
+        let nulls = NullBuffer::union(nulls.as_ref(), other.logical_nulls().as_ref());
+        Ok(Ok(
+            PrimitiveArray::new(values, nulls).with_data_type(data_type)
+        ))
+    }
+
     /// Applies a unary and nullable function to all valid values in a primitive array
     ///
     /// Applies `op` to only rows that are valid, which is often significantly
@@ -1088,6 +1157,7 @@ impl<T: ArrowPrimitiveType> PrimitiveArray<T> {
     pub fn into_builder(self) -> Result<PrimitiveBuilder<T>, Self> {
         let len = self.len();
         let data = self.into_data();
+        let data_type = data.data_type().clone();
         let null_bit_buffer = data.nulls().map(|b| b.inner().sliced());
 
         let element_len = std::mem::size_of::<T::Native>();
@@ -1128,7 +1198,7 @@ impl<T: ArrowPrimitiveType> PrimitiveArray<T> {
         match try_mutable_buffers {
             Ok(builder) => Ok(builder),
             Err((buffer, null_bit_buffer)) => {
-                let builder = ArrayData::builder(T::DATA_TYPE)
+                let builder = ArrayData::builder(data_type)
                     .len(len)
                     .add_buffer(buffer)
                     .null_bit_buffer(null_bit_buffer);
@@ -2778,6 +2848,35 @@ mod tests {
         assert_eq!(c, Int32Array::from(vec![Some(11), Some(15), None]));
     }
 
+    #[test]
+    fn test_binary_mut() {
+        let a = Int32Array::from(vec![Some(1), None, Some(3), Some(4)]);
+        let b = Int32Array::from(vec![Some(10), Some(20), None, Some(40)]);
+        let c = a.binary_mut(&b, |a, b| a + b).unwrap().unwrap();
+        assert_eq!(c, Int32Array::from(vec![Some(11), None, None, Some(44)]));
+
+        // Shared buffers cannot be mutated
+        let a = TimestampSecondArray::from(vec![1, 2]).with_timezone("+01:00");
+        let shared = a.clone();
+        let a = a
+            .binary_mut(&b.slice(0, 2), |a, b| a + b as i64)
+            .unwrap_err();
+        drop(shared);
+
+        let c = a
+            .binary_mut(&b.slice(0, 2), |a, b| a + b as i64)
+            .unwrap()
+            .unwrap();
+        assert_eq!(c.values(), &[11, 22]);
+        assert_eq!(c.timezone(), Some("+01:00"));
+
+        let err = c.binary_mut(&b, |a, b| a + b as i64).unwrap().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Cannot perform binary operation on arrays of different length"
+        );
+    }
+
     #[test]
     #[should_panic(
         expected = "PrimitiveArray expected data type Interval(MonthDayNano) got Interval(DayTime)"
@@ -2903,4 +3002,16 @@ mod tests {
         .collect();
         let _ = array.into_builder();
     }
+
+    #[test]
+    fn test_into_shared_slice() {
+        let array = Int32Array::from(vec![Some(1), None, Some(3)]).slice(1, 2);
+        let values = array.into_shared_slice();
+        assert_eq!(values.len(), 2);
+        assert_eq!(values[1], 3);
+
+        let array = Int32Array::new(values.clone().into(), None);
+        assert_eq!(array.values().as_ptr(), values.as_ptr());
+        assert_eq!(array.value(1), 3);
+    }
 }
@@ -130,6 +130,63 @@ impl<R: RunEndIndexType> RunArray<R> {
         &self.values
     }
 
+    /// Returns the values of the runs referenced by this array
+    ///
+    /// Unlike [`Self::values`], this accounts for any slicing of this [`RunArray`],
+    /// returning only the values from [`Self::get_start_physical_index`] to
+    /// [`Self::get_end_physical_index`] inclusive
+    pub fn sliced_values(&self) -> ArrayRef {
+        if self.run_ends.is_empty() {
+            return self.values.slice(0, 0);
+        }
+        let start = self.get_start_physical_index();
+        let end = self.get_end_physical_index();
+        self.values.slice(start, end - start + 1)
+    }
+
+    /// Returns a new [`RunArray`] with the same runs as this array and the provided `values`
+    ///
+    /// `values` must contain one value for each value of [`Self::sliced_values`], and
+    /// the returned array is not sliced. This allows kernels to operate on the values of
+    /// each run without materializing the logical array
+    ///
+    /// ```
+    /// # use arrow_array::{Array, Int32Array, RunArray, types::Int32Type};
+    /// # use arrow_array::cast::AsArray;
+    /// let run_ends = Int32Array::from(vec![2, 5, 6]);
+    /// let values = Int32Array::from(vec![1, 2, 3]);
+    /// let array = RunArray::try_new(&run_ends, &values).unwrap().slice(1, 3);
+    ///
+    /// let doubled: Int32Array = array.sliced_values().as_primitive::<Int32Type>().unary(|x| x * 2);
+    /// let array = array.with_sliced_values(&doubled).unwrap();
+    /// assert_eq!(array.run_ends().values(), &[1, 3]);
+    /// assert_eq!(array.values().as_primitive::<Int32Type>(), &Int32Array::from(vec![2, 4]));
+    /// ```
+    pub fn with_sliced_values(&self, values: &dyn Array) -> Result<Self, ArrowError> {
+        let offset = self.run_ends.offset();
+        let len = self.run_ends.len();
+        let run_ends: PrimitiveArray<R> = match len {
+            0 => PrimitiveArray::from_iter_values([]),
+            _ => {
+                let start = self.get_start_physical_index();
+                let end = self.get_end_physical_index();
+                let ends = &self.run_ends.values()[start..=end];
+                PrimitiveArray::from_iter_values(
+                    ends.iter()
+                        .map(|e| R::Native::usize_as((e.as_usize() - offset).min(len))),
+                )
+            }
+        };
+        if run_ends.len() != values.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Expected {} run values, got {}",
+                run_ends.len(),
+                values.len()
+            )));
+        }
+        Self::try_new(&run_ends, values)
+    }
+
     /// Returns the physical index at which the array slice starts.
     pub fn get_start_physical_index(&self) -> usize {
         self.run_ends.get_start_physical_index()
@@ -1169,4 +1226,35 @@ mod tests {
 
         assert_eq!(array_i16_1, array_i16_2);
     }
+
+    #[test]
+    fn test_run_array_with_sliced_values() {
+        let run_ends = Int16Array::from(vec![2_i16, 4, 6]);
+        let values = StringArray::from(vec!["a", "b", "c"]);
+        let array = RunArray::<Int16Type>::try_new(&run_ends, &values).unwrap();
+
+        for (offset, len, expected_ends, expected_values) in [
+            (0, 6, vec![2, 4, 6], vec!["a", "b", "c"]),
+            (1, 4, vec![1, 3, 4], vec!["a", "b", "c"]),
+            (2, 2, vec![2], vec!["b"]),
+            (3, 0, vec![], vec![]),
+        ] {
+            let sliced = array.slice(offset, len);
+            let values = sliced.sliced_values();
+            assert_eq!(
+                values.as_string::<i32>(),
+                &StringArray::from(expected_values)
+            );
+
+            let new = sliced.with_sliced_values(&values).unwrap();
+            assert_eq!(new.len(), len);
+            assert_eq!(new.run_ends().values(), &expected_ends);
+        }
+
+        let err = array.slice(2, 2).with_sliced_values(&values).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Expected 1 run values, got 3"
+        );
+    }
 }
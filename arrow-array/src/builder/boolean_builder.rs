@@ -81,6 +81,31 @@ impl BooleanBuilder {
         }
     }
 
+    /// Reports the memory allocated by this builder to `pool`
+    ///
+    /// See [`BooleanBufferBuilder::with_memory_pool`] and
+    /// [`NullBufferBuilder::with_memory_pool`]
+    #[cfg(feature = "pool")]
+    pub fn with_memory_pool(self, pool: Arc<dyn arrow_buffer::MemoryPool>) -> Self {
+        Self {
+            values_builder: self.values_builder.with_memory_pool(Arc::clone(&pool)),
+            null_buffer_builder: self.null_buffer_builder.with_memory_pool(pool),
+        }
+    }
+
+    /// Reserves capacity for at least `additional` more values, returning an error if
+    /// this would exceed the memory available in the pool set by [`Self::with_memory_pool`]
+    ///
+    /// See [`BooleanBufferBuilder::try_reserve`] and [`NullBufferBuilder::try_reserve`]
+    #[cfg(feature = "pool")]
+    pub fn try_reserve(
+        &mut self,
+        additional: usize,
+    ) -> Result<(), arrow_buffer::MemoryPoolExhausted> {
+        self.values_builder.try_reserve(additional)?;
+        self.null_buffer_builder.try_reserve(additional)
+    }
+
     /// Returns the capacity of this builder measured in slots of type `T`
     pub fn capacity(&self) -> usize {
         self.values_builder.capacity()
@@ -405,4 +430,27 @@ mod tests {
         assert_eq!(actual, array);
         assert_eq!(actual.values(), array.values())
     }
+
+    #[test]
+    #[cfg(feature = "pool")]
+    fn test_boolean_builder_with_pool() {
+        use arrow_buffer::{BoundedMemoryPool, MemoryPool};
+
+        let pool = Arc::new(BoundedMemoryPool::new(128));
+        let mut builder = BooleanBuilder::with_capacity(8).with_memory_pool(pool.clone());
+        assert_eq!(pool.used(), 64);
+
+        let err = builder.try_reserve(2048).unwrap_err();
+        assert_eq!(err.available, 64);
+        builder.try_reserve(1024).unwrap();
+        assert_eq!(pool.used(), 128);
+
+        builder.append_value(true);
+        builder.append_null();
+        let array = builder.finish();
+        drop(builder);
+        assert!(pool.used() > 0);
+        drop(array);
+        assert_eq!(pool.used(), 0);
+    }
 }
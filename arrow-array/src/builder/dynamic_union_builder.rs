@@ -0,0 +1,392 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::builder::buffer_builder::{Int32BufferBuilder, Int8BufferBuilder};
+use crate::{make_array, new_empty_array, new_null_array, ArrayRef, Datum, UnionArray};
+use arrow_data::transform::MutableArrayData;
+use arrow_data::ArrayData;
+use arrow_schema::{ArrowError, DataType, Field, FieldRef, UnionFields, UnionMode};
+use std::sync::Arc;
+
+/// A contiguous run of values appended to a [`DynamicUnionChild`]
+#[derive(Debug)]
+enum Chunk {
+    /// A slice of an appended array
+    Values(ArrayData),
+    /// A run of nulls, used to pad the children of sparse unions
+    Nulls(usize),
+}
+
+/// Tracks the values appended to a single child of a [`DynamicUnionBuilder`]
+#[derive(Debug)]
+struct DynamicUnionChild {
+    type_id: i8,
+    field: FieldRef,
+    chunks: Vec<Chunk>,
+    len: usize,
+}
+
+impl DynamicUnionChild {
+    fn append_values(&mut self, data: ArrayData) {
+        self.len += data.len();
+        self.chunks.push(Chunk::Values(data));
+    }
+
+    fn append_nulls(&mut self, n: usize) {
+        self.len += n;
+        match self.chunks.last_mut() {
+            Some(Chunk::Nulls(len)) => *len += n,
+            _ => self.chunks.push(Chunk::Nulls(n)),
+        }
+    }
+
+    fn finish(self) -> ArrayRef {
+        let data_type = self.field.data_type();
+        let values: Vec<&ArrayData> = self
+            .chunks
+            .iter()
+            .filter_map(|c| match c {
+                Chunk::Values(data) => Some(data),
+                Chunk::Nulls(_) => None,
+            })
+            .collect();
+
+        match values.len() {
+            0 => return new_null_array(data_type, self.len),
+            1 if self.chunks.len() == 1 => return make_array(values[0].clone()),
+            _ => {}
+        }
+
+        let use_nulls = self.chunks.iter().any(|c| matches!(c, Chunk::Nulls(_)));
+        let mut mutable = MutableArrayData::new(values, use_nulls, self.len);
+        let mut idx = 0;
+        for chunk in &self.chunks {
+            match chunk {
+                Chunk::Values(data) => {
+                    mutable.extend(idx, 0, data.len());
+                    idx += 1;
+                }
+                Chunk::Nulls(n) => mutable.extend_nulls(*n),
+            }
+        }
+        make_array(mutable.freeze())
+    }
+}
+
+/// Builder for [`UnionArray`] from arbitrary, heterogeneously typed values
+///
+/// Unlike [`UnionBuilder`](crate::builder::UnionBuilder), which requires the caller to name
+/// the field and is limited to primitive values, this builder accepts any [`Datum`], that is
+/// either an [`Array`](crate::Array) or a [`Scalar`](crate::Scalar), and registers a new type id the first
+/// time a value of a given [`DataType`] is appended. The values for each type id are collected
+/// as they arrive and combined into the child arrays when [`Self::build`] is called.
+///
+/// Fields registered automatically are nullable and named after their [`DataType`]. Use
+/// [`Self::register_field`] to choose the field, and therefore its type id, up front.
+///
+/// ```
+/// # use arrow_array::builder::DynamicUnionBuilder;
+/// # use arrow_array::{Array, Int32Array, Scalar, StringArray};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int32Type;
+/// let mut builder = DynamicUnionBuilder::new_dense();
+/// builder.append(&Int32Array::from(vec![1, 2])).unwrap();
+/// builder.append(&Scalar::new(StringArray::from(vec!["foo"]))).unwrap();
+/// builder.append(&Scalar::new(Int32Array::from(vec![3]))).unwrap();
+/// let union = builder.build().unwrap();
+///
+/// assert_eq!(union.len(), 4);
+/// assert_eq!(union.type_ids(), &[0, 0, 1, 0]);
+/// assert_eq!(union.offsets().unwrap(), &[0, 1, 0, 2]);
+/// assert_eq!(union.child(0).as_primitive::<Int32Type>().values(), &[1, 2, 3]);
+/// assert_eq!(union.child(1).as_string::<i32>().value(0), "foo");
+/// ```
+#[derive(Debug)]
+pub struct DynamicUnionBuilder {
+    mode: UnionMode,
+    len: usize,
+    children: Vec<DynamicUnionChild>,
+    type_id_builder: Int8BufferBuilder,
+    value_offset_builder: Option<Int32BufferBuilder>,
+}
+
+impl DynamicUnionBuilder {
+    /// Creates a new builder producing a union with the given [`UnionMode`]
+    pub fn new(mode: UnionMode) -> Self {
+        Self::with_capacity(mode, 1024)
+    }
+
+    /// Creates a new dense union builder
+    pub fn new_dense() -> Self {
+        Self::new(UnionMode::Dense)
+    }
+
+    /// Creates a new sparse union builder
+    pub fn new_sparse() -> Self {
+        Self::new(UnionMode::Sparse)
+    }
+
+    /// Creates a new builder with space for `capacity` slots
+    pub fn with_capacity(mode: UnionMode, capacity: usize) -> Self {
+        Self {
+            mode,
+            len: 0,
+            children: vec![],
+            type_id_builder: Int8BufferBuilder::new(capacity),
+            value_offset_builder: match mode {
+                UnionMode::Dense => Some(Int32BufferBuilder::new(capacity)),
+                UnionMode::Sparse => None,
+            },
+        }
+    }
+
+    /// Returns the number of slots appended so far
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no slots have been appended
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the [`UnionMode`] of the array being built
+    pub fn mode(&self) -> UnionMode {
+        self.mode
+    }
+
+    /// Registers `field` as a child of the union, returning its type id
+    ///
+    /// Values whose [`DataType`] matches that of `field` are subsequently appended to this
+    /// child. Returns an error if a field with the same [`DataType`] is already registered,
+    /// or if the union already contains the maximum of 128 fields.
+    pub fn register_field(&mut self, field: impl Into<FieldRef>) -> Result<i8, ArrowError> {
+        let field = field.into();
+        if self.type_id(field.data_type()).is_some() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Union already contains a field of type {}",
+                field.data_type()
+            )));
+        }
+        if self.children.len() > i8::MAX as usize {
+            return Err(ArrowError::InvalidArgumentError(
+                "Union cannot contain more than 128 fields".to_string(),
+            ));
+        }
+
+        let type_id = self.children.len() as i8;
+        let mut child = DynamicUnionChild {
+            type_id,
+            field,
+            chunks: vec![],
+            len: 0,
+        };
+        if self.mode == UnionMode::Sparse && self.len > 0 {
+            child.append_nulls(self.len);
+        }
+        self.children.push(child);
+        Ok(type_id)
+    }
+
+    /// Returns the type id registered for `data_type`, if any
+    pub fn type_id(&self, data_type: &DataType) -> Option<i8> {
+        self.children
+            .iter()
+            .find(|c| c.field.data_type() == data_type)
+            .map(|c| c.type_id)
+    }
+
+    fn get_or_register(&mut self, data_type: &DataType) -> Result<usize, ArrowError> {
+        match self.type_id(data_type) {
+            Some(type_id) => Ok(type_id as usize),
+            None => {
+                let field = Field::new(data_type.to_string(), data_type.clone(), true);
+                Ok(self.register_field(field)? as usize)
+            }
+        }
+    }
+
+    /// Appends `value` to this builder
+    ///
+    /// If `value` is a [`Scalar`](crate::Scalar) a single slot is appended, otherwise a
+    /// slot is appended for each element of the array. Null elements are recorded as nulls
+    /// of the child for the value's [`DataType`], which is registered if not already present.
+    pub fn append(&mut self, value: &dyn Datum) -> Result<(), ArrowError> {
+        let (array, is_scalar) = value.get();
+        let len = match is_scalar {
+            true => 1,
+            false => array.len(),
+        };
+        if len == 0 {
+            return Ok(());
+        }
+        if array.len() < len {
+            return Err(ArrowError::InvalidArgumentError(
+                "Scalar value must contain a single element".to_string(),
+            ));
+        }
+        let idx = self.get_or_register(array.data_type())?;
+        let data = array.to_data().slice(0, len);
+        self.append_data(idx, len, |child| child.append_values(data));
+        Ok(())
+    }
+
+    /// Appends a null of type `data_type` to this builder
+    ///
+    /// As [`UnionArray`] has no validity bitmap of its own, the null is recorded in the child
+    /// for `data_type`, which is registered if not already present.
+    pub fn append_null(&mut self, data_type: &DataType) -> Result<(), ArrowError> {
+        let idx = self.get_or_register(data_type)?;
+        self.append_data(idx, 1, |child| child.append_nulls(1));
+        Ok(())
+    }
+
+    fn append_data(&mut self, idx: usize, len: usize, f: impl FnOnce(&mut DynamicUnionChild)) {
+        let child = &mut self.children[idx];
+        self.type_id_builder.append_n(len, child.type_id);
+        if let Some(offsets) = self.value_offset_builder.as_mut() {
+            offsets.extend((child.len..child.len + len).map(|o| o as i32));
+        }
+        f(child);
+
+        if self.mode == UnionMode::Sparse {
+            self.children
+                .iter_mut()
+                .enumerate()
+                .filter(|(i, _)| *i != idx)
+                .for_each(|(_, c)| c.append_nulls(len));
+        }
+        self.len += len;
+    }
+
+    /// Builds the [`UnionArray`]
+    pub fn build(self) -> Result<UnionArray, ArrowError> {
+        let mut fields = Vec::with_capacity(self.children.len());
+        let mut children = Vec::with_capacity(self.children.len());
+        for child in self.children {
+            fields.push((child.type_id, Arc::clone(&child.field)));
+            children.push(match child.len {
+                0 => new_empty_array(child.field.data_type()),
+                _ => child.finish(),
+            });
+        }
+
+        UnionArray::try_new(
+            fields.into_iter().collect::<UnionFields>(),
+            self.type_id_builder.into(),
+            self.value_offset_builder.map(Into::into),
+            children,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cast::AsArray;
+    use crate::types::{Float64Type, Int32Type};
+    use crate::{Array, Float64Array, Int32Array, Scalar, StringArray};
+
+    #[test]
+    fn test_dynamic_union_dense() {
+        let mut builder = DynamicUnionBuilder::new_dense();
+        builder
+            .append(&Int32Array::from(vec![Some(1), None, Some(3)]))
+            .unwrap();
+        builder
+            .append(&Scalar::new(StringArray::from(vec!["a"])))
+            .unwrap();
+        builder.append_null(&DataType::Float64).unwrap();
+        builder
+            .append(&Scalar::new(Int32Array::from(vec![4])))
+            .unwrap();
+        builder
+            .append(&StringArray::from(vec!["b", "c"]).slice(1, 1))
+            .unwrap();
+        assert_eq!(builder.len(), 7);
+        let union = builder.build().unwrap();
+
+        assert_eq!(union.len(), 7);
+        assert_eq!(union.type_ids(), &[0, 0, 0, 1, 2, 0, 1]);
+        assert_eq!(union.offsets().unwrap(), &[0, 1, 2, 0, 0, 3, 1]);
+
+        let (fields, ..) = union.clone().into_parts();
+        let names: Vec<_> = fields.iter().map(|(_, f)| f.name().as_str()).collect();
+        assert_eq!(names, &["Int32", "Utf8", "Float64"]);
+
+        let ints = union.child(0).as_primitive::<Int32Type>();
+        assert_eq!(
+            ints,
+            &Int32Array::from(vec![Some(1), None, Some(3), Some(4)])
+        );
+        let strings = union.child(1).as_string::<i32>();
+        assert_eq!(strings, &StringArray::from(vec!["a", "c"]));
+        let floats = union.child(2).as_primitive::<Float64Type>();
+        assert_eq!(floats, &Float64Array::from(vec![None]));
+
+        let nulls = union.logical_nulls().unwrap();
+        assert_eq!(nulls.null_count(), 2);
+        assert!(nulls.is_null(1));
+        assert!(nulls.is_null(4));
+    }
+
+    #[test]
+    fn test_dynamic_union_sparse() {
+        let mut builder = DynamicUnionBuilder::new_sparse();
+        builder.append(&Int32Array::from(vec![1, 2])).unwrap();
+        builder
+            .append(&Scalar::new(StringArray::from(vec!["a"])))
+            .unwrap();
+        builder.append(&Int32Array::from(vec![3])).unwrap();
+        let union = builder.build().unwrap();
+
+        assert_eq!(union.type_ids(), &[0, 0, 1, 0]);
+        assert!(union.offsets().is_none());
+        let ints = union.child(0).as_primitive::<Int32Type>();
+        assert_eq!(
+            ints,
+            &Int32Array::from(vec![Some(1), Some(2), None, Some(3)])
+        );
+        let strings = union.child(1).as_string::<i32>();
+        assert_eq!(
+            strings,
+            &StringArray::from(vec![None, None, Some("a"), None])
+        );
+    }
+
+    #[test]
+    fn test_dynamic_union_register_field() {
+        let mut builder = DynamicUnionBuilder::new_dense();
+        let field = Field::new("strings", DataType::Utf8, false);
+        assert_eq!(builder.register_field(field.clone()).unwrap(), 0);
+        let err = builder.register_field(field).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Union already contains a field of type Utf8"
+        );
+
+        builder.append(&Int32Array::from(vec![1])).unwrap();
+        assert_eq!(builder.type_id(&DataType::Int32), Some(1));
+        assert_eq!(builder.type_id(&DataType::Utf8), Some(0));
+
+        let union = builder.build().unwrap();
+        assert_eq!(union.type_ids(), &[1]);
+        assert_eq!(union.child(0).len(), 0);
+        let (fields, ..) = union.into_parts();
+        assert_eq!(fields.iter().next().unwrap().1.name(), "strings");
+    }
+}
@@ -56,6 +56,35 @@ impl<T: ByteArrayType> GenericByteBuilder<T> {
         }
     }
 
+    /// Reports the memory allocated by this builder to `pool`
+    ///
+    /// See [`BufferBuilder::with_memory_pool`] and
+    /// [`NullBufferBuilder::with_memory_pool`]
+    #[cfg(feature = "pool")]
+    pub fn with_memory_pool(self, pool: Arc<dyn arrow_buffer::MemoryPool>) -> Self {
+        Self {
+            value_builder: self.value_builder.with_memory_pool(Arc::clone(&pool)),
+            offsets_builder: self.offsets_builder.with_memory_pool(Arc::clone(&pool)),
+            null_buffer_builder: self.null_buffer_builder.with_memory_pool(pool),
+        }
+    }
+
+    /// Reserves capacity for at least `additional_items` more values, with a total of
+    /// `additional_bytes` bytes of data, returning an error if this would exceed the
+    /// memory available in the pool set by [`Self::with_memory_pool`]
+    ///
+    /// See [`BufferBuilder::try_reserve`] and [`NullBufferBuilder::try_reserve`]
+    #[cfg(feature = "pool")]
+    pub fn try_reserve(
+        &mut self,
+        additional_items: usize,
+        additional_bytes: usize,
+    ) -> Result<(), arrow_buffer::MemoryPoolExhausted> {
+        self.value_builder.try_reserve(additional_bytes)?;
+        self.offsets_builder.try_reserve(additional_items)?;
+        self.null_buffer_builder.try_reserve(additional_items)
+    }
+
     /// Creates a new  [`GenericByteBuilder`] from buffers.
     ///
     /// # Safety
@@ -833,4 +862,36 @@ mod tests {
 
         assert_eq!(actual, full_array);
     }
+
+    #[test]
+    #[cfg(feature = "pool")]
+    fn test_string_builder_with_pool() {
+        use arrow_buffer::{BoundedMemoryPool, MemoryPool};
+
+        let pool = Arc::new(BoundedMemoryPool::new(1024));
+        let mut builder =
+            GenericStringBuilder::<i32>::with_capacity(4, 16).with_memory_pool(pool.clone());
+        let initial = pool.used();
+        assert!(initial > 0);
+
+        builder.try_reserve(10, 100).unwrap();
+        let used = pool.used();
+        assert!(used > initial);
+
+        let err = builder.try_reserve(10, 1000).unwrap_err();
+        assert_eq!(err.available, 1024 - used as isize);
+        assert_eq!(pool.used(), used);
+
+        for _ in 0..10 {
+            builder.append_value("0123456789");
+        }
+        builder.append_null();
+        assert!(pool.used() >= used);
+
+        let array = builder.finish();
+        drop(builder);
+        assert!(pool.used() > 0);
+        drop(array);
+        assert_eq!(pool.used(), 0);
+    }
 }
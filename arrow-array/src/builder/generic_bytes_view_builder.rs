@@ -35,20 +35,22 @@ const MAX_BLOCK_SIZE: u32 = 2 * 1024 * 1024; // 2MiB
 
 enum BlockSizeGrowthStrategy {
     Fixed { size: u32 },
-    Exponential { current_size: u32 },
+    Exponential { current_size: u32, max_size: u32 },
 }
 
 impl BlockSizeGrowthStrategy {
     fn next_size(&mut self) -> u32 {
         match self {
             Self::Fixed { size } => *size,
-            Self::Exponential { current_size } => {
-                if *current_size < MAX_BLOCK_SIZE {
-                    // we have fixed start/end block sizes, so we can't overflow
-                    *current_size = current_size.saturating_mul(2);
+            Self::Exponential {
+                current_size,
+                max_size,
+            } => {
+                if *current_size < *max_size {
+                    *current_size = current_size.saturating_mul(2).min(*max_size);
                     *current_size
                 } else {
-                    MAX_BLOCK_SIZE
+                    *max_size
                 }
             }
         }
@@ -105,6 +107,7 @@ impl<T: ByteViewType + ?Sized> GenericByteViewBuilder<T> {
             in_progress: vec![],
             block_size: BlockSizeGrowthStrategy::Exponential {
                 current_size: STARTING_BLOCK_SIZE,
+                max_size: MAX_BLOCK_SIZE,
             },
             string_tracker: None,
             phantom: Default::default(),
@@ -134,6 +137,30 @@ impl<T: ByteViewType + ?Sized> GenericByteViewBuilder<T> {
         }
     }
 
+    /// Set the maximum size of the buffers allocated for variable length strings
+    ///
+    /// By default buffers grow exponentially up to 2MB, see [`Self::with_fixed_block_size`].
+    /// This caps that growth at `max_block_size` bytes, or caps the size of a fixed block size,
+    /// which bounds the memory retained by any single buffer, e.g. when a slice of the
+    /// resulting array is kept alive by a long-running service.
+    ///
+    /// Values larger than `max_block_size` are still written to a buffer of their own
+    pub fn with_max_block_size(self, max_block_size: u32) -> Self {
+        debug_assert!(max_block_size > 0, "Block size must be greater than 0");
+        let block_size = match self.block_size {
+            BlockSizeGrowthStrategy::Fixed { size } => BlockSizeGrowthStrategy::Fixed {
+                size: size.min(max_block_size),
+            },
+            BlockSizeGrowthStrategy::Exponential { current_size, .. } => {
+                BlockSizeGrowthStrategy::Exponential {
+                    current_size: current_size.min(max_block_size),
+                    max_size: max_block_size,
+                }
+            }
+        };
+        Self { block_size, ..self }
+    }
+
     /// Deduplicate strings while building the array
     ///
     /// This will potentially decrease the memory usage if the array have repeated strings
@@ -230,6 +257,70 @@ impl<T: ByteViewType + ?Sized> GenericByteViewBuilder<T> {
         }
     }
 
+    /// Appends an array to the builder, copying values stored in data buffers smaller than
+    /// `min_block_size` bytes instead of appending those buffers
+    ///
+    /// Unlike [`Self::append_array`], which appends every data buffer of `array` without
+    /// copying, this coalesces the values of small buffers into the builder's own blocks.
+    /// This avoids accumulating many small buffers when concatenating many small arrays,
+    /// such as the output of filters, while still reusing large buffers without copying.
+    ///
+    /// ```
+    /// # use arrow_array::builder::StringViewBuilder;
+    /// # use arrow_array::{Array, StringViewArray};
+    /// let small = StringViewArray::from(vec!["a string longer than 12 bytes"]);
+    /// let mut builder = StringViewBuilder::new();
+    /// for _ in 0..10 {
+    ///     builder.append_array_coalesced(&small, 1024);
+    /// }
+    /// let array = builder.finish();
+    /// assert_eq!(array.len(), 10);
+    /// assert_eq!(array.data_buffers().len(), 1);
+    /// ```
+    pub fn append_array_coalesced(
+        &mut self,
+        array: &GenericByteViewArray<T>,
+        min_block_size: usize,
+    ) {
+        // Maps the data buffers of `array` to the blocks they are appended as, if any
+        let mut blocks = Vec::with_capacity(array.data_buffers().len());
+        let mut flushed = false;
+        for buffer in array.data_buffers() {
+            if buffer.len() < min_block_size {
+                blocks.push(None);
+                continue;
+            }
+            if !flushed {
+                self.flush_in_progress();
+                flushed = true;
+            }
+            blocks.push(Some(self.completed.len() as u32));
+            self.push_completed(buffer.clone());
+        }
+
+        for (idx, view) in array.views().iter().enumerate() {
+            if array.is_null(idx) {
+                self.append_null();
+                continue;
+            }
+            let mut byte_view = ByteView::from(*view);
+            if byte_view.length <= MAX_INLINE_VIEW_LEN {
+                self.views_buffer.push(*view);
+                self.null_buffer_builder.append_non_null();
+                continue;
+            }
+            match blocks[byte_view.buffer_index as usize] {
+                Some(block) => {
+                    byte_view.buffer_index = block;
+                    self.views_buffer.push(byte_view.as_u128());
+                    self.null_buffer_builder.append_non_null();
+                }
+                // SAFETY: idx is in bounds of the array
+                None => self.append_value(unsafe { array.value_unchecked(idx) }),
+            }
+        }
+    }
+
     /// Try to append a view of the given `block`, `offset` and `length`
     ///
     /// See [`Self::append_block`]
@@ -581,7 +672,7 @@ mod tests {
     use core::str;
 
     use super::*;
-    use crate::Array;
+    use crate::{Array, StringViewArray};
 
     #[test]
     fn test_string_view_deduplicate() {
@@ -712,6 +803,66 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_string_view_with_max_block_size() {
+        let long_string = str::from_utf8(&[b'a'; STARTING_BLOCK_SIZE as usize]).unwrap();
+        let max = STARTING_BLOCK_SIZE * 4;
+
+        let mut builder = StringViewBuilder::new().with_max_block_size(max);
+        for _ in 0..32 {
+            builder.append_value(long_string);
+        }
+        builder.flush_in_progress();
+        let sizes: Vec<_> = builder.completed.iter().map(|b| b.len()).collect();
+        assert!(sizes.iter().all(|s| *s <= max as usize), "{sizes:?}");
+        assert_eq!(sizes.iter().sum::<usize>(), 32 * long_string.len());
+
+        // Fixed block sizes are capped
+        let mut builder = StringViewBuilder::new()
+            .with_fixed_block_size(max * 2)
+            .with_max_block_size(max);
+        builder.append_value(long_string);
+        assert_eq!(builder.in_progress.capacity(), max as usize);
+
+        // Larger values are written to a buffer of their own
+        let mut builder = StringViewBuilder::new().with_max_block_size(16);
+        builder.append_value(long_string);
+        builder.append_value(long_string);
+        builder.flush_in_progress();
+        assert_eq!(builder.completed.len(), 2);
+    }
+
+    #[test]
+    fn test_append_array_coalesced() {
+        let long = "a string longer than 12 bytes";
+        let small = StringViewArray::from(vec![Some(long), None, Some("short")]);
+        let large = {
+            let mut builder = StringViewBuilder::new();
+            (0..100).for_each(|_| builder.append_value(long));
+            builder.finish()
+        };
+
+        let mut builder = StringViewBuilder::new();
+        builder.append_array_coalesced(&small, 1024);
+        builder.append_array_coalesced(&small.slice(0, 2), 1024);
+        builder.append_array_coalesced(&large.slice(10, 5), 1024);
+        let array = builder.finish();
+
+        // The large buffer is reused, the small values are copied into a single block
+        assert_eq!(array.data_buffers().len(), 2);
+        assert_eq!(array.data_buffers()[0].len(), 2 * long.len());
+        assert_eq!(
+            array.data_buffers()[1].as_ptr(),
+            large.data_buffers()[0].as_ptr()
+        );
+
+        let expected: Vec<_> = [Some(long), None, Some("short"), Some(long), None]
+            .into_iter()
+            .chain(std::iter::repeat_n(Some(long), 5))
+            .collect();
+        assert_eq!(array.iter().collect::<Vec<_>>(), expected);
+    }
+
     #[test]
     fn test_string_view_with_block_size_growth() {
         let mut exp_builder = StringViewBuilder::new();
@@ -171,6 +171,85 @@ impl<K: ArrayBuilder, V: ArrayBuilder> MapBuilder<K, V> {
         Ok(())
     }
 
+    /// Appends a map slot containing the key value pairs of `entries`, or a null
+    /// slot if `entries` is `None`
+    ///
+    /// Keys are appended to the key builder as `Some(key)`, whereas values are appended
+    /// to the value builder as given, and so should be `Option`s for builders implementing
+    /// `Extend<Option<_>>`, allowing null values.
+    ///
+    /// ```
+    /// # use arrow_array::builder::{Int32Builder, MapBuilder, StringBuilder};
+    /// let mut builder = MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+    /// builder.append_pairs(Some([("a", Some(1)), ("b", None)])).unwrap();
+    /// builder.append_pairs(None::<Vec<(&str, Option<i32>)>>).unwrap();
+    /// let array = builder.finish();
+    /// assert_eq!(array.value_offsets(), &[0, 2, 2]);
+    /// ```
+    ///
+    /// Returns an error if the key and values builders are in an inconsistent state.
+    pub fn append_pairs<E, KI, VI>(&mut self, entries: Option<E>) -> Result<(), ArrowError>
+    where
+        E: IntoIterator<Item = (KI, VI)>,
+        K: Extend<Option<KI>>,
+        V: Extend<VI>,
+    {
+        let is_valid = entries.is_some();
+        for (key, value) in entries.into_iter().flatten() {
+            self.key_builder.extend(std::iter::once(Some(key)));
+            self.value_builder.extend(std::iter::once(value));
+        }
+        self.append(is_valid)
+    }
+
+    /// Creates a new `MapBuilder` containing a map slot for each item of `iter`, see
+    /// [`Self::append_pairs`]
+    ///
+    /// ```
+    /// # use arrow_array::builder::{Int32Builder, MapBuilder, MapFieldNames, StringBuilder};
+    /// # use arrow_array::{Array, Int32Array, StringArray};
+    /// let names = MapFieldNames {
+    ///     entry: "key_value".to_string(),
+    ///     key: "key".to_string(),
+    ///     value: "value".to_string(),
+    /// };
+    /// let data = vec![
+    ///     Some(vec![("joe", Some(1))]),
+    ///     None,
+    ///     Some(vec![("blogs", Some(2)), ("foo", None)]),
+    /// ];
+    /// let mut builder =
+    ///     MapBuilder::from_pairs(Some(names), StringBuilder::new(), Int32Builder::new(), data)
+    ///         .unwrap();
+    /// let array = builder.finish();
+    ///
+    /// assert_eq!(array.value_offsets(), &[0, 1, 1, 3]);
+    /// assert!(array.is_null(1));
+    /// assert_eq!(array.keys().as_ref(), &StringArray::from(vec!["joe", "blogs", "foo"]));
+    /// assert_eq!(array.values().as_ref(), &Int32Array::from(vec![Some(1), Some(2), None]));
+    /// assert_eq!(array.entries().fields()[0].name(), "key");
+    /// ```
+    pub fn from_pairs<I, E, KI, VI>(
+        field_names: Option<MapFieldNames>,
+        key_builder: K,
+        value_builder: V,
+        iter: I,
+    ) -> Result<Self, ArrowError>
+    where
+        I: IntoIterator<Item = Option<E>>,
+        E: IntoIterator<Item = (KI, VI)>,
+        K: Extend<Option<KI>>,
+        V: Extend<VI>,
+    {
+        let iter = iter.into_iter();
+        let capacity = iter.size_hint().0;
+        let mut builder = Self::with_capacity(field_names, key_builder, value_builder, capacity);
+        for entries in iter {
+            builder.append_pairs(entries)?;
+        }
+        Ok(builder)
+    }
+
     /// Builds the [`MapArray`]
     pub fn finish(&mut self) -> MapArray {
         let len = self.len();
@@ -288,6 +367,45 @@ mod tests {
     use crate::{Int32Array, StringArray};
     use std::collections::HashMap;
 
+    #[test]
+    fn test_map_builder_from_pairs() {
+        let data = [
+            Some(HashMap::from([(1, "a".to_string())])),
+            Some(HashMap::new()),
+            None,
+        ];
+        let values_builder = StringBuilder::new();
+        let mut builder = MapBuilder::from_pairs(
+            None,
+            Int32Builder::new(),
+            values_builder,
+            data.iter()
+                .map(|m| m.as_ref().map(|m| m.iter().map(|(k, v)| (*k, Some(v))))),
+        )
+        .unwrap();
+        builder
+            .append_pairs(Some([(2, Some("b")), (3, None)]))
+            .unwrap();
+        let map = builder.finish();
+
+        assert_eq!(map.len(), 4);
+        assert_eq!(map.null_count(), 1);
+        assert_eq!(map.value_offsets(), &[0, 1, 1, 1, 3]);
+        assert_eq!(map.keys().as_ref(), &Int32Array::from(vec![1, 2, 3]));
+        assert_eq!(
+            map.values().as_ref(),
+            &StringArray::from(vec![Some("a"), Some("b"), None])
+        );
+
+        let mut builder = MapBuilder::new(None, Int32Builder::new(), Int32Builder::new());
+        builder.keys().append_value(1);
+        let err = builder.append_pairs(Some([(2, Some(3))])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot append to a map builder when its keys and values have unequal lengths of 2 and 1"
+        );
+    }
+
     #[test]
     #[should_panic(expected = "Keys array must have no null values, found 1 null value(s)")]
     fn test_map_builder_with_null_keys_panics() {
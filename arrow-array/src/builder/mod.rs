@@ -272,6 +272,8 @@ pub use generic_list_view_builder::*;
 mod union_builder;
 
 pub use union_builder::*;
+mod dynamic_union_builder;
+pub use dynamic_union_builder::*;
 
 use crate::types::{Int16Type, Int32Type, Int64Type, Int8Type};
 use crate::ArrayRef;
@@ -195,6 +195,32 @@ impl<T: ArrowPrimitiveType> PrimitiveBuilder<T> {
         Self { data_type, ..self }
     }
 
+    /// Reports the memory allocated by this builder to `pool`
+    ///
+    /// See [`BufferBuilder::with_memory_pool`] and
+    /// [`NullBufferBuilder::with_memory_pool`]
+    #[cfg(feature = "pool")]
+    pub fn with_memory_pool(self, pool: Arc<dyn arrow_buffer::MemoryPool>) -> Self {
+        Self {
+            values_builder: self.values_builder.with_memory_pool(Arc::clone(&pool)),
+            null_buffer_builder: self.null_buffer_builder.with_memory_pool(pool),
+            ..self
+        }
+    }
+
+    /// Reserves capacity for at least `additional` more values, returning an error if
+    /// this would exceed the memory available in the pool set by [`Self::with_memory_pool`]
+    ///
+    /// See [`BufferBuilder::try_reserve`] and [`NullBufferBuilder::try_reserve`]
+    #[cfg(feature = "pool")]
+    pub fn try_reserve(
+        &mut self,
+        additional: usize,
+    ) -> Result<(), arrow_buffer::MemoryPoolExhausted> {
+        self.values_builder.try_reserve(additional)?;
+        self.null_buffer_builder.try_reserve(additional)
+    }
+
     /// Returns the capacity of this builder measured in slots of type `T`
     pub fn capacity(&self) -> usize {
         self.values_builder.capacity()
@@ -702,4 +728,42 @@ mod tests {
         let mut builder = Decimal128Builder::new().with_data_type(DataType::Decimal128(2, 3));
         builder.append_array(&array)
     }
+
+    #[test]
+    #[cfg(feature = "pool")]
+    fn test_primitive_builder_with_pool() {
+        use arrow_buffer::{MemoryPool, TrackingMemoryPool};
+
+        let pool = Arc::new(TrackingMemoryPool::default());
+        let mut builder = Int32Builder::with_capacity(16).with_memory_pool(pool.clone());
+        assert_eq!(pool.used(), 64);
+
+        builder.append_value(1);
+        builder.append_null();
+        let array = builder.finish();
+        assert!(pool.used() > 0);
+
+        drop(builder);
+        drop(array);
+        assert_eq!(pool.used(), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "pool")]
+    fn test_primitive_builder_try_reserve() {
+        use arrow_buffer::{BoundedMemoryPool, MemoryPool};
+
+        let pool = Arc::new(BoundedMemoryPool::new(1024));
+        let mut builder = Int32Builder::with_capacity(16).with_memory_pool(pool.clone());
+        builder.try_reserve(100).unwrap();
+        let used = pool.used();
+        assert!(used >= 400);
+
+        let err = builder.try_reserve(1000).unwrap_err();
+        assert_eq!(err.available, 1024 - used as isize);
+        assert_eq!(pool.used(), used);
+
+        builder.append_slice(&[1; 100]);
+        assert_eq!(pool.used(), used);
+    }
 }
@@ -20,6 +20,7 @@
 
 use crate::cast::AsArray;
 use crate::{new_empty_array, Array, ArrayRef, StructArray};
+use arrow_buffer::BufferRecycler;
 use arrow_schema::{ArrowError, DataType, Field, FieldRef, Schema, SchemaBuilder, SchemaRef};
 use std::ops::Index;
 use std::sync::Arc;
@@ -375,6 +376,19 @@ impl RecordBatch {
         (self.schema, self.columns, self.row_count)
     }
 
+    /// Returns the buffers of this [`RecordBatch`] to `recycler` so that they can
+    /// be reused by subsequent batches
+    ///
+    /// Buffers still referenced elsewhere, e.g. by a clone of one of the columns,
+    /// are not recycled
+    pub fn recycle_buffers(self, recycler: &BufferRecycler) {
+        for column in self.columns {
+            let data = column.to_data();
+            drop(column);
+            data.recycle_buffers(recycler);
+        }
+    }
+
     /// Override the schema of this [`RecordBatch`]
     ///
     /// Returns an error if `schema` is not a superset of the current schema
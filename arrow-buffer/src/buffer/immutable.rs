@@ -506,6 +506,41 @@ impl From<bytes::Bytes> for Buffer {
     }
 }
 
+/// Convert from a `Buffer` to [`bytes::Bytes`] without copying
+///
+/// The returned [`bytes::Bytes`] keeps the underlying allocation alive, and
+/// will only release it once all references to it have been dropped.
+impl From<Buffer> for bytes::Bytes {
+    fn from(buffer: Buffer) -> Self {
+        bytes::Bytes::from_owner(BufferOwner(buffer))
+    }
+}
+
+/// Wrapper used to hand ownership of a [`Buffer`] to [`bytes::Bytes`]
+struct BufferOwner(Buffer);
+
+impl AsRef<[u8]> for BufferOwner {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_slice()
+    }
+}
+
+/// Convert from an [`Arc<[T]>`] to `Buffer` without copying
+///
+/// The returned `Buffer` holds a reference to the [`Arc`], keeping the
+/// values alive for as long as the `Buffer`, or any slice of it, exists.
+impl<T: ArrowNativeType> From<Arc<[T]>> for Buffer {
+    fn from(value: Arc<[T]>) -> Self {
+        let len = std::mem::size_of_val(value.as_ref());
+        // Arc<[T]> never has a null data pointer, even when empty
+        let ptr = NonNull::new(value.as_ptr() as *mut u8).unwrap();
+        // The values are immutable and so cannot be observed in an inconsistent state
+        let owner = Arc::new(std::panic::AssertUnwindSafe(value));
+        // SAFETY: `ptr` is valid for `len` bytes for as long as `owner` is alive
+        unsafe { Self::from_custom_allocation(ptr, len, owner) }
+    }
+}
+
 /// Create a `Buffer` instance by storing the boolean values into the buffer
 impl FromIterator<bool> for Buffer {
     fn from_iter<I>(iter: I) -> Self
@@ -1059,4 +1094,47 @@ mod tests {
         drop(capture);
         assert_eq!(buffer2.strong_count(), 1);
     }
+
+    #[test]
+    fn test_bytes_roundtrip() {
+        let buffer = Buffer::from_vec(vec![1_u32, 2, 3, 4]).slice(4);
+        let ptr = buffer.as_ptr();
+
+        let bytes = bytes::Bytes::from(buffer.clone());
+        assert_eq!(bytes.as_ptr(), ptr);
+        assert_eq!(bytes.as_ref(), buffer.as_slice());
+        assert_eq!(buffer.strong_count(), 2);
+
+        let roundtrip = Buffer::from(bytes);
+        assert_eq!(roundtrip.as_ptr(), ptr);
+        assert_eq!(roundtrip.typed_data::<u32>(), &[2, 3, 4]);
+
+        drop(roundtrip);
+        assert_eq!(buffer.strong_count(), 1);
+    }
+
+    #[test]
+    fn test_from_arc_slice() {
+        let values: Arc<[i64]> = Arc::from(vec![1_i64, 2, 3]);
+        let buffer = Buffer::from(values.clone());
+        assert_eq!(buffer.as_ptr(), values.as_ptr() as *const u8);
+        assert_eq!(buffer.len(), 24);
+        assert_eq!(buffer.typed_data::<i64>(), &[1, 2, 3]);
+        assert_eq!(Arc::strong_count(&values), 2);
+
+        let sliced = buffer.slice(8);
+        drop(buffer);
+        assert_eq!(sliced.typed_data::<i64>(), &[2, 3]);
+        assert_eq!(Arc::strong_count(&values), 2);
+
+        drop(sliced);
+        assert_eq!(Arc::strong_count(&values), 1);
+
+        let empty: Arc<[i64]> = Arc::from(vec![]);
+        let buffer = Buffer::from(empty);
+        assert!(buffer.is_empty());
+
+        let scalar = ScalarBuffer::from(values.clone());
+        assert_eq!(scalar.as_ref(), values.as_ref());
+    }
 }
@@ -33,3 +33,5 @@ mod null;
 pub use null::*;
 mod run;
 pub use run::*;
+mod recycler;
+pub use recycler::*;
@@ -17,17 +17,18 @@
 
 use std::alloc::{handle_alloc_error, Layout};
 use std::mem;
+use std::ops::Range;
 use std::ptr::NonNull;
 
 use crate::alloc::{Deallocation, ALIGNMENT};
 use crate::{
     bytes::Bytes,
     native::{ArrowNativeType, ToByteSlice},
-    util::bit_util,
+    util::{bit_mask, bit_util},
 };
 
 #[cfg(feature = "pool")]
-use crate::pool::{MemoryPool, MemoryReservation};
+use crate::pool::{MemoryPool, MemoryPoolExhausted, MemoryReservation};
 #[cfg(feature = "pool")]
 use std::sync::Mutex;
 
@@ -199,6 +200,61 @@ impl MutableBuffer {
         }
     }
 
+    /// Sets the bits in `range` of this buffer, as packed bits, to `val`
+    ///
+    /// Whole bytes are written at once, only the bits at either end of the range are
+    /// masked individually.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range.end` exceeds the number of bits in the buffer, `len * 8`
+    pub fn set_bit_range(&mut self, range: Range<usize>, val: bool) {
+        let len = range.end.saturating_sub(range.start);
+        bit_mask::fill_bits(self.as_slice_mut(), range.start, len, val);
+    }
+
+    /// Overwrites the bits of this buffer, as packed bits, starting at bit `offset`
+    /// with the bits in `range` of `to_set`, packed LSB-first
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset + range.len()` exceeds the number of bits in the buffer,
+    /// `len * 8`, or if `to_set` does not contain `ceil(range.end / 8)` bytes
+    pub fn copy_bits_from_slice_at(&mut self, offset: usize, range: Range<usize>, to_set: &[u8]) {
+        let len = range.end - range.start;
+        let data = self.as_slice_mut();
+        bit_mask::fill_bits(data, offset, len, false);
+        bit_mask::set_bits(data, to_set, offset, range.start, len);
+    }
+
+    /// Performs an in-place bitwise AND of `len` bits of this buffer starting at bit
+    /// `offset` with the bits of `other` starting at bit `other_offset`, 64 bits at a time
+    ///
+    /// # Panics
+    ///
+    /// Panics if either range exceeds the number of bits in its buffer
+    pub fn and_assign_bits(
+        &mut self,
+        offset: usize,
+        other: &[u8],
+        other_offset: usize,
+        len: usize,
+    ) {
+        let data = self.as_slice_mut();
+        bit_mask::apply_bitwise_assign_op(data, offset, other, other_offset, len, |a, b| a & b)
+    }
+
+    /// Performs an in-place bitwise OR of `len` bits of this buffer starting at bit
+    /// `offset` with the bits of `other` starting at bit `other_offset`, 64 bits at a time
+    ///
+    /// # Panics
+    ///
+    /// Panics if either range exceeds the number of bits in its buffer
+    pub fn or_assign_bits(&mut self, offset: usize, other: &[u8], other_offset: usize, len: usize) {
+        let data = self.as_slice_mut();
+        bit_mask::apply_bitwise_assign_op(data, offset, other, other_offset, len, |a, b| a | b)
+    }
+
     /// Ensures that this buffer has at least `self.len + additional` bytes. This re-allocates iff
     /// `self.len + additional > capacity`.
     /// # Example
@@ -216,10 +272,48 @@ impl MutableBuffer {
     pub fn reserve(&mut self, additional: usize) {
         let required_cap = self.len + additional;
         if required_cap > self.layout.size() {
-            let new_capacity = bit_util::round_upto_multiple_of_64(required_cap);
-            let new_capacity = std::cmp::max(new_capacity, self.layout.size() * 2);
+            self.reallocate(self.grown_capacity(required_cap))
+        }
+    }
+
+    /// Ensures that this buffer has at least `self.len + additional` bytes, as by
+    /// [`Self::reserve`], but returning an error if the memory reservation of this
+    /// buffer, see [`Self::claim`], cannot grow to the new capacity
+    ///
+    /// On error the buffer is not reallocated. Buffers without a reservation, or
+    /// claimed by an unbounded [`MemoryPool`], never fail.
+    ///
+    /// ```
+    /// # use arrow_buffer::{BoundedMemoryPool, MemoryPool, MutableBuffer};
+    /// let pool = BoundedMemoryPool::new(1024);
+    /// let mut buffer = MutableBuffer::new(0);
+    /// buffer.claim(&pool);
+    ///
+    /// buffer.try_reserve(512).unwrap();
+    /// assert_eq!(pool.used(), 512);
+    ///
+    /// let err = buffer.try_reserve(2048).unwrap_err();
+    /// assert_eq!(err.to_string(), "Failed to reserve 1536 bytes from memory pool with 512 bytes available");
+    /// assert_eq!(buffer.capacity(), 512);
+    /// ```
+    #[cfg(feature = "pool")]
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), MemoryPoolExhausted> {
+        let required_cap = self.len + additional;
+        if required_cap > self.layout.size() {
+            let new_capacity = self.grown_capacity(required_cap);
+            if let Some(reservation) = self.reservation.lock().unwrap().as_mut() {
+                reservation.try_resize(new_capacity)?;
+            }
             self.reallocate(new_capacity)
         }
+        Ok(())
+    }
+
+    /// Returns the capacity to grow this buffer to for at least `required_cap` bytes
+    #[inline]
+    fn grown_capacity(&self, required_cap: usize) -> usize {
+        let new_capacity = bit_util::round_upto_multiple_of_64(required_cap);
+        std::cmp::max(new_capacity, self.layout.size() * 2)
     }
 
     #[cold]
@@ -525,6 +619,18 @@ impl MutableBuffer {
     pub fn claim(&self, pool: &dyn MemoryPool) {
         *self.reservation.lock().unwrap() = Some(pool.reserve(self.capacity()));
     }
+
+    /// Register this [`MutableBuffer`] with the provided [`MemoryPool`], returning
+    /// an error if the pool has insufficient memory available
+    ///
+    /// See [`MemoryPool::try_reserve`] and [`Self::claim`]. On error any prior
+    /// reservation is retained.
+    #[cfg(feature = "pool")]
+    pub fn try_claim(&self, pool: &dyn MemoryPool) -> Result<(), MemoryPoolExhausted> {
+        let reservation = pool.try_reserve(self.capacity())?;
+        *self.reservation.lock().unwrap() = Some(reservation);
+        Ok(())
+    }
 }
 
 /// Creates a non-null pointer with alignment of [`ALIGNMENT`]
@@ -1065,6 +1171,29 @@ mod tests {
         buffer.set_null_bits(1, usize::MAX);
     }
 
+    #[test]
+    fn test_mutable_bit_range_ops() {
+        let mut buffer = MutableBuffer::new_null(20);
+        buffer.set_bit_range(2..17, true);
+        assert_eq!(buffer.as_slice(), &[0b11111100, 0xFF, 0b00000001]);
+
+        buffer.copy_bits_from_slice_at(4, 1..9, &[0b01010100, 0b00000001]);
+        assert_eq!(buffer.as_slice(), &[0b10101100, 0b11111010, 0b00000001]);
+
+        buffer.and_assign_bits(8, &[0b00001111], 0, 8);
+        assert_eq!(buffer.as_slice(), &[0b10101100, 0b00001010, 0b00000001]);
+
+        buffer.or_assign_bits(3, &[0b11110000], 4, 4);
+        assert_eq!(buffer.as_slice(), &[0b11111100, 0b00001010, 0b00000001]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mutable_set_bit_range_oob() {
+        let mut buffer = MutableBuffer::new_null(8);
+        buffer.set_bit_range(4..9, true);
+    }
+
     #[test]
     fn from_iter() {
         let buffer = [1u16, 2, 3, 4].into_iter().collect::<MutableBuffer>();
@@ -1082,7 +1211,7 @@ mod tests {
     #[cfg(feature = "pool")]
     mod pool_tests {
         use super::*;
-        use crate::pool::{MemoryPool, TrackingMemoryPool};
+        use crate::pool::{BoundedMemoryPool, MemoryPool, TrackingMemoryPool};
 
         #[test]
         fn test_reallocate_with_pool() {
@@ -1181,5 +1310,25 @@ mod tests {
             drop(buffer);
             assert_eq!(pool.used(), 0);
         }
+
+        #[test]
+        fn test_try_claim_with_pool() {
+            let pool = BoundedMemoryPool::new(256);
+            let first = MutableBuffer::with_capacity(128);
+            first.try_claim(&pool).unwrap();
+            assert_eq!(pool.used(), 128);
+
+            let second = MutableBuffer::with_capacity(192);
+            let err = second.try_claim(&pool).unwrap_err();
+            assert_eq!(err.requested, 192);
+            assert_eq!(err.available, 128);
+
+            // A failed claim retains the prior reservation
+            second.claim(&TrackingMemoryPool::default());
+            second.try_claim(&pool).unwrap_err();
+            drop(first);
+            second.try_claim(&pool).unwrap();
+            assert_eq!(pool.used(), 192);
+        }
     }
 }
@@ -0,0 +1,185 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Mutex;
+
+use crate::{Buffer, MutableBuffer};
+
+/// A pool of previously allocated [`MutableBuffer`] that can be reused to avoid
+/// repeated allocations when producing a stream of similarly sized arrays.
+///
+/// Readers such as the JSON and CSV decoders can be configured with a
+/// [`BufferRecycler`], and will [`take`](Self::take) their value and offset
+/// buffers from it. Once a batch is no longer needed, its buffers can be handed
+/// back with [`recycle`](Self::recycle), where they retain their capacity, so that
+/// subsequent batches are sized from the largest buffers seen so far.
+///
+/// Only buffers that are uniquely owned, not sliced, and allocated by this crate
+/// can be recycled, all others are silently dropped.
+///
+/// ```
+/// # use arrow_buffer::{Buffer, BufferRecycler};
+/// let recycler = BufferRecycler::new(4);
+///
+/// let mut buffer = recycler.take(64);
+/// buffer.extend_from_slice(&[1_u32, 2, 3]);
+/// let ptr = buffer.as_ptr();
+///
+/// assert!(recycler.recycle(Buffer::from(buffer)));
+/// assert_eq!(recycler.len(), 1);
+///
+/// // The allocation is reused
+/// let buffer = recycler.take(32);
+/// assert_eq!(buffer.as_ptr(), ptr);
+/// assert!(buffer.is_empty());
+/// ```
+#[derive(Debug)]
+pub struct BufferRecycler {
+    buffers: Mutex<Vec<MutableBuffer>>,
+    max_buffers: usize,
+}
+
+impl Default for BufferRecycler {
+    fn default() -> Self {
+        Self::new(64)
+    }
+}
+
+impl BufferRecycler {
+    /// Create a new [`BufferRecycler`] that retains at most `max_buffers` buffers
+    pub fn new(max_buffers: usize) -> Self {
+        Self {
+            buffers: Mutex::new(Vec::with_capacity(max_buffers)),
+            max_buffers,
+        }
+    }
+
+    /// Returns an empty [`MutableBuffer`] with a capacity of at least `capacity` bytes
+    ///
+    /// This returns the smallest pooled buffer large enough to hold `capacity`
+    /// bytes, or allocates a new buffer if there is no such buffer
+    pub fn take(&self, capacity: usize) -> MutableBuffer {
+        let mut buffers = self.buffers.lock().unwrap();
+        let best = buffers
+            .iter()
+            .enumerate()
+            .filter(|(_, b)| b.capacity() >= capacity)
+            .min_by_key(|(_, b)| b.capacity())
+            .map(|(idx, _)| idx);
+
+        match best {
+            Some(idx) => {
+                let mut buffer = buffers.swap_remove(idx);
+                buffer.clear();
+                buffer
+            }
+            None => MutableBuffer::with_capacity(capacity),
+        }
+    }
+
+    /// Returns `buffer` to the pool, returning `true` if it was retained
+    ///
+    /// If the pool is full, the smallest pooled buffer is evicted in favour of
+    /// `buffer` if `buffer` is larger
+    pub fn recycle(&self, buffer: Buffer) -> bool {
+        if buffer.ptr_offset() != 0 || self.max_buffers == 0 {
+            return false;
+        }
+        let Ok(buffer) = buffer.into_mutable() else {
+            return false;
+        };
+        if buffer.capacity() == 0 {
+            return false;
+        }
+
+        let mut buffers = self.buffers.lock().unwrap();
+        if buffers.len() < self.max_buffers {
+            buffers.push(buffer);
+            return true;
+        }
+
+        let (idx, smallest) = buffers
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, b)| b.capacity())
+            .unwrap();
+
+        if smallest.capacity() < buffer.capacity() {
+            buffers[idx] = buffer;
+            return true;
+        }
+        false
+    }
+
+    /// Returns the number of buffers currently retained by this pool
+    pub fn len(&self) -> usize {
+        self.buffers.lock().unwrap().len()
+    }
+
+    /// Returns `true` if this pool contains no buffers
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_take_smallest() {
+        let recycler = BufferRecycler::new(4);
+        for capacity in [1024, 64, 256] {
+            assert!(recycler.recycle(MutableBuffer::with_capacity(capacity).into()));
+        }
+        assert_eq!(recycler.len(), 3);
+
+        let buffer = recycler.take(100);
+        assert_eq!(buffer.capacity(), 256);
+        let buffer = recycler.take(100);
+        assert_eq!(buffer.capacity(), 1024);
+        let buffer = recycler.take(100);
+        assert_eq!(buffer.capacity(), 128);
+        assert_eq!(recycler.len(), 1);
+    }
+
+    #[test]
+    fn test_recycle_evicts_smallest() {
+        let recycler = BufferRecycler::new(2);
+        assert!(recycler.recycle(MutableBuffer::with_capacity(64).into()));
+        assert!(recycler.recycle(MutableBuffer::with_capacity(128).into()));
+        assert!(!recycler.recycle(MutableBuffer::with_capacity(64).into()));
+        assert!(recycler.recycle(MutableBuffer::with_capacity(512).into()));
+
+        assert_eq!(recycler.take(0).capacity(), 128);
+        assert_eq!(recycler.take(0).capacity(), 512);
+        assert!(recycler.is_empty());
+    }
+
+    #[test]
+    fn test_recycle_shared_or_sliced() {
+        let recycler = BufferRecycler::default();
+        let buffer = Buffer::from_vec(vec![0_u64; 16]);
+        let shared = buffer.clone();
+        assert!(!recycler.recycle(buffer));
+        assert!(!recycler.recycle(shared.slice(8)));
+        assert!(recycler.is_empty());
+
+        assert!(recycler.recycle(shared));
+        assert_eq!(recycler.len(), 1);
+    }
+}
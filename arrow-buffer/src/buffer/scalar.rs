@@ -22,6 +22,7 @@ use crate::{BufferBuilder, MutableBuffer, OffsetBuffer};
 use std::fmt::Formatter;
 use std::marker::PhantomData;
 use std::ops::Deref;
+use std::sync::Arc;
 
 /// A strongly-typed [`Buffer`] supporting zero-copy cloning and slicing
 ///
@@ -165,6 +166,15 @@ impl<T: ArrowNativeType> From<Vec<T>> for ScalarBuffer<T> {
     }
 }
 
+impl<T: ArrowNativeType> From<Arc<[T]>> for ScalarBuffer<T> {
+    fn from(value: Arc<[T]>) -> Self {
+        Self {
+            buffer: Buffer::from(value),
+            phantom: Default::default(),
+        }
+    }
+}
+
 impl<T: ArrowNativeType> From<ScalarBuffer<T>> for Vec<T> {
     fn from(value: ScalarBuffer<T>) -> Self {
         value
@@ -226,7 +236,7 @@ impl<T: ArrowNativeType + Eq> Eq for ScalarBuffer<T> {}
 
 #[cfg(test)]
 mod tests {
-    use std::{ptr::NonNull, sync::Arc};
+    use std::ptr::NonNull;
 
     use super::*;
 
@@ -15,8 +15,12 @@
 // specific language governing permissions and limitations
 // under the License.
 
+#[cfg(feature = "pool")]
+use crate::pool::{MemoryPool, MemoryPoolExhausted};
 use crate::{bit_mask, bit_util, BooleanBuffer, Buffer, MutableBuffer};
 use std::ops::Range;
+#[cfg(feature = "pool")]
+use std::sync::Arc;
 
 /// Builder for [`BooleanBuffer`]
 ///
@@ -29,6 +33,9 @@ use std::ops::Range;
 pub struct BooleanBufferBuilder {
     buffer: MutableBuffer,
     len: usize,
+    /// The pool that the memory allocated by this builder is reported to
+    #[cfg(feature = "pool")]
+    pool: Option<Arc<dyn MemoryPool>>,
 }
 
 impl BooleanBufferBuilder {
@@ -41,7 +48,12 @@ impl BooleanBufferBuilder {
     pub fn new(capacity: usize) -> Self {
         let byte_capacity = bit_util::ceil(capacity, 8);
         let buffer = MutableBuffer::new(byte_capacity);
-        Self { buffer, len: 0 }
+        Self {
+            buffer,
+            len: 0,
+            #[cfg(feature = "pool")]
+            pool: None,
+        }
     }
 
     /// Creates a new `BooleanBufferBuilder` from [`MutableBuffer`] of `len`
@@ -50,6 +62,8 @@ impl BooleanBufferBuilder {
         let mut s = Self {
             len: buffer.len() * 8,
             buffer,
+            #[cfg(feature = "pool")]
+            pool: None,
         };
         s.truncate(len);
         s
@@ -150,6 +164,21 @@ impl BooleanBufferBuilder {
         }
     }
 
+    /// Reserve space for at least `additional` new bits, as by [`Self::reserve`], but
+    /// returning an error if this would exceed the memory available in the pool set
+    /// by [`Self::with_memory_pool`]
+    ///
+    /// See [`MutableBuffer::try_reserve`]
+    #[cfg(feature = "pool")]
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), MemoryPoolExhausted> {
+        let capacity = self.len + additional;
+        if capacity > self.capacity() {
+            let additional = bit_util::ceil(capacity, 8) - self.buffer.len();
+            self.buffer.try_reserve(additional)?;
+        }
+        Ok(())
+    }
+
     /// Resizes the buffer, either truncating its contents (with no change in capacity), or
     /// growing it (potentially reallocating it) and writing `false` in the newly available bits.
     #[inline]
@@ -234,6 +263,70 @@ impl BooleanBufferBuilder {
         self.append_packed_range(range, buffer.values())
     }
 
+    /// Sets the bits in `range` to `v`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range.end` exceeds the length of the builder
+    pub fn set_range(&mut self, range: Range<usize>, v: bool) {
+        assert!(
+            range.end <= self.len,
+            "range end {} exceeds length {}",
+            range.end,
+            self.len
+        );
+        self.buffer.set_bit_range(range, v);
+    }
+
+    /// Overwrites the bits starting at `offset` with `range` bits from `to_set`
+    ///
+    /// `to_set` is a slice of bits packed LSB-first into `[u8]`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset + range.len()` exceeds the length of the builder, or
+    /// if `to_set` does not contain `ceil(range.end / 8)` bytes
+    pub fn copy_from_slice_at(&mut self, offset: usize, range: Range<usize>, to_set: &[u8]) {
+        let len = range.end - range.start;
+        assert!(
+            offset + len <= self.len,
+            "range end {} exceeds length {}",
+            offset + len,
+            self.len
+        );
+        self.buffer.copy_bits_from_slice_at(offset, range, to_set);
+    }
+
+    /// Performs an in-place bitwise AND of this builder with `other`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` does not have the same length as the builder
+    pub fn and_assign(&mut self, other: &BooleanBuffer) {
+        self.check_assign_len(other);
+        let (offset, len) = (other.offset(), self.len);
+        self.buffer.and_assign_bits(0, other.values(), offset, len)
+    }
+
+    /// Performs an in-place bitwise OR of this builder with `other`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` does not have the same length as the builder
+    pub fn or_assign(&mut self, other: &BooleanBuffer) {
+        self.check_assign_len(other);
+        let (offset, len) = (other.offset(), self.len);
+        self.buffer.or_assign_bits(0, other.values(), offset, len)
+    }
+
+    fn check_assign_len(&self, other: &BooleanBuffer) {
+        assert_eq!(
+            other.len(),
+            self.len,
+            "BooleanBuffer length does not match builder length"
+        );
+    }
+
     /// Returns the packed bits
     pub fn as_slice(&self) -> &[u8] {
         self.buffer.as_slice()
@@ -249,9 +342,25 @@ impl BooleanBufferBuilder {
     pub fn finish(&mut self) -> BooleanBuffer {
         let buf = std::mem::replace(&mut self.buffer, MutableBuffer::new(0));
         let len = std::mem::replace(&mut self.len, 0);
+        #[cfg(feature = "pool")]
+        if let Some(pool) = &self.pool {
+            self.buffer.claim(pool.as_ref());
+        }
         BooleanBuffer::new(buf.into(), 0, len)
     }
 
+    /// Reports the memory allocated by this builder to `pool`
+    ///
+    /// This claims the current buffer as by [`MutableBuffer::claim`], and any
+    /// buffers subsequently allocated after [`Self::finish`]. Appending grows the
+    /// reservation infallibly, see [`Self::try_reserve`] to reserve fallibly.
+    #[cfg(feature = "pool")]
+    pub fn with_memory_pool(mut self, pool: Arc<dyn MemoryPool>) -> Self {
+        self.buffer.claim(pool.as_ref());
+        self.pool = Some(pool);
+        self
+    }
+
     /// Builds the [BooleanBuffer] without resetting the builder.
     pub fn finish_cloned(&self) -> BooleanBuffer {
         BooleanBuffer::new(Buffer::from_slice_ref(self.as_slice()), 0, self.len)
@@ -523,4 +632,76 @@ mod tests {
         assert_eq!(buf.len(), buf2.inner().len());
         assert_eq!(buf.as_slice(), buf2.values());
     }
+
+    #[test]
+    fn test_set_range() {
+        let mut builder = BooleanBufferBuilder::new(0);
+        builder.append_n(100, false);
+        builder.set_range(3..90, true);
+        builder.set_range(10..20, false);
+        builder.set_range(50..50, false);
+
+        let buffer = builder.finish();
+        let expected: Vec<_> = (0..100)
+            .map(|i| (3..90).contains(&i) && !(10..20).contains(&i))
+            .collect();
+        assert_eq!(buffer.iter().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "range end 11 exceeds length 10")]
+    fn test_set_range_out_of_bounds() {
+        let mut builder = BooleanBufferBuilder::new(10);
+        builder.append_n(10, true);
+        builder.set_range(5..11, false);
+    }
+
+    #[test]
+    fn test_copy_from_slice_at() {
+        let mut builder = BooleanBufferBuilder::new(0);
+        builder.append_n(20, true);
+
+        // 0b10100101, 0b00001111
+        let to_set = [0xA5_u8, 0x0F];
+        builder.copy_from_slice_at(5, 2..12, &to_set);
+
+        let source = BooleanBuffer::new(Buffer::from(to_set), 0, 16);
+        let buffer = builder.finish();
+        for i in 0..20 {
+            let expected = match i {
+                5..15 => source.value(i - 3),
+                _ => true,
+            };
+            assert_eq!(buffer.value(i), expected, "{i}");
+        }
+    }
+
+    #[test]
+    fn test_bitwise_assign() {
+        let a: Vec<_> = (0..130).map(|i| i % 3 == 0).collect();
+        let b: Vec<_> = (0..131).map(|i| i % 5 == 0).collect();
+        let b = BooleanBuffer::from(b.as_slice()).slice(1, 130);
+
+        let mut builder = BooleanBufferBuilder::new(130);
+        builder.append_slice(&a);
+        builder.and_assign(&b);
+        let buffer = builder.finish();
+        let expected: Vec<_> = (0..130).map(|i| a[i] && b.value(i)).collect();
+        assert_eq!(buffer.iter().collect::<Vec<_>>(), expected);
+
+        let mut builder = BooleanBufferBuilder::new(130);
+        builder.append_slice(&a);
+        builder.or_assign(&b);
+        let buffer = builder.finish();
+        let expected: Vec<_> = (0..130).map(|i| a[i] || b.value(i)).collect();
+        assert_eq!(buffer.iter().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "BooleanBuffer length does not match builder length")]
+    fn test_bitwise_assign_length_mismatch() {
+        let mut builder = BooleanBufferBuilder::new(10);
+        builder.append_n(10, true);
+        builder.and_assign(&BooleanBuffer::new_set(11));
+    }
 }
@@ -25,8 +25,12 @@ pub use boolean::*;
 pub use null::*;
 pub use offset::*;
 
+#[cfg(feature = "pool")]
+use crate::pool::{MemoryPool, MemoryPoolExhausted};
 use crate::{ArrowNativeType, Buffer, MutableBuffer};
 use std::marker::PhantomData;
+#[cfg(feature = "pool")]
+use std::sync::Arc;
 
 /// Builder for creating a [Buffer] object.
 ///
@@ -52,6 +56,9 @@ pub struct BufferBuilder<T: ArrowNativeType> {
     buffer: MutableBuffer,
     len: usize,
     _marker: PhantomData<T>,
+    /// The pool that the memory allocated by this builder is reported to
+    #[cfg(feature = "pool")]
+    pool: Option<Arc<dyn MemoryPool>>,
 }
 
 impl<T: ArrowNativeType> BufferBuilder<T> {
@@ -83,6 +90,8 @@ impl<T: ArrowNativeType> BufferBuilder<T> {
             buffer,
             len: 0,
             _marker: PhantomData,
+            #[cfg(feature = "pool")]
+            pool: None,
         }
     }
 
@@ -93,6 +102,8 @@ impl<T: ArrowNativeType> BufferBuilder<T> {
             buffer,
             len: buffer_len / std::mem::size_of::<T>(),
             _marker: PhantomData,
+            #[cfg(feature = "pool")]
+            pool: None,
         }
     }
 
@@ -178,6 +189,16 @@ impl<T: ArrowNativeType> BufferBuilder<T> {
         self.buffer.reserve(n * std::mem::size_of::<T>());
     }
 
+    /// Reserves memory for at least `n` more elements, as by [`Self::reserve`], but
+    /// returning an error if this would exceed the memory available in the pool set
+    /// by [`Self::with_memory_pool`]
+    ///
+    /// See [`MutableBuffer::try_reserve`]
+    #[cfg(feature = "pool")]
+    pub fn try_reserve(&mut self, n: usize) -> Result<(), MemoryPoolExhausted> {
+        self.buffer.try_reserve(n * std::mem::size_of::<T>())
+    }
+
     /// Appends a value of type `T` into the builder,
     /// growing the internal buffer as needed.
     ///
@@ -357,8 +378,28 @@ impl<T: ArrowNativeType> BufferBuilder<T> {
     pub fn finish(&mut self) -> Buffer {
         let buf = std::mem::take(&mut self.buffer);
         self.len = 0;
+        #[cfg(feature = "pool")]
+        if let Some(pool) = &self.pool {
+            self.buffer.claim(pool.as_ref());
+        }
         buf.into()
     }
+
+    /// Reports the memory allocated by this builder to `pool`
+    ///
+    /// This claims the current buffer as by [`MutableBuffer::claim`], and any
+    /// buffers subsequently allocated after [`Self::finish`]. The reservation
+    /// of each buffer is transferred to the [`Buffer`] returned by [`Self::finish`].
+    ///
+    /// Appending values grows the reservation infallibly, and so may exceed the
+    /// capacity of a [`BoundedMemoryPool`](crate::BoundedMemoryPool), use
+    /// [`Self::try_reserve`] to reserve capacity fallibly beforehand.
+    #[cfg(feature = "pool")]
+    pub fn with_memory_pool(mut self, pool: Arc<dyn MemoryPool>) -> Self {
+        self.buffer.claim(pool.as_ref());
+        self.pool = Some(pool);
+        self
+    }
 }
 
 impl<T: ArrowNativeType> Default for BufferBuilder<T> {
@@ -418,4 +459,56 @@ mod tests {
         builder.extend([3, 4]);
         assert_eq!(builder.len(), 4);
     }
+
+    #[test]
+    #[cfg(feature = "pool")]
+    fn test_buffer_builder_with_pool() {
+        use crate::pool::TrackingMemoryPool;
+
+        let pool = Arc::new(TrackingMemoryPool::default());
+        let mut builder = BufferBuilder::<i64>::new(8).with_memory_pool(pool.clone());
+        assert_eq!(pool.used(), 64);
+
+        builder.append_n(100, 1);
+        let capacity = builder.capacity() * mem::size_of::<i64>();
+        assert_eq!(pool.used(), capacity);
+
+        // The reservation is transferred to the finished buffer
+        let first = builder.finish();
+        assert_eq!(pool.used(), capacity);
+
+        // Buffers allocated after finish are also claimed
+        builder.append_n(10, 2);
+        let second = builder.finish();
+        assert_eq!(pool.used(), capacity + 128);
+
+        drop(first);
+        drop(second);
+        assert_eq!(pool.used(), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "pool")]
+    fn test_buffer_builder_try_reserve() {
+        use crate::pool::{BoundedMemoryPool, MemoryPoolExhausted};
+
+        let pool = Arc::new(BoundedMemoryPool::new(1024));
+        let mut builder = BufferBuilder::<i64>::new(8).with_memory_pool(pool.clone());
+        builder.try_reserve(100).unwrap();
+        assert_eq!(pool.used(), 832);
+
+        let err = builder.try_reserve(200).unwrap_err();
+        assert_eq!(
+            err,
+            MemoryPoolExhausted {
+                requested: 832,
+                available: 192
+            }
+        );
+        assert_eq!(builder.capacity(), 104);
+
+        // Appending within the reserved capacity does not grow the reservation
+        builder.append_n(100, 1);
+        assert_eq!(pool.used(), 832);
+    }
 }
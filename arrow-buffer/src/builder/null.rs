@@ -15,7 +15,11 @@
 // specific language governing permissions and limitations
 // under the License.
 
+#[cfg(feature = "pool")]
+use crate::pool::{MemoryPool, MemoryPoolExhausted};
 use crate::{BooleanBufferBuilder, MutableBuffer, NullBuffer};
+#[cfg(feature = "pool")]
+use std::sync::Arc;
 
 /// Builder for creating [`NullBuffer`]
 ///
@@ -58,6 +62,9 @@ pub struct NullBufferBuilder {
     len: usize,
     /// Initial capacity of the `bitmap_builder`, when it is materialized.
     capacity: usize,
+    /// The pool that the memory allocated by this builder is reported to
+    #[cfg(feature = "pool")]
+    pool: Option<Arc<dyn MemoryPool>>,
 }
 
 impl NullBufferBuilder {
@@ -71,6 +78,8 @@ impl NullBufferBuilder {
             bitmap_builder: None,
             len: 0,
             capacity,
+            #[cfg(feature = "pool")]
+            pool: None,
         }
     }
 
@@ -80,6 +89,8 @@ impl NullBufferBuilder {
             bitmap_builder: None,
             len,
             capacity: len,
+            #[cfg(feature = "pool")]
+            pool: None,
         }
     }
 
@@ -93,6 +104,8 @@ impl NullBufferBuilder {
             bitmap_builder,
             len,
             capacity,
+            #[cfg(feature = "pool")]
+            pool: None,
         }
     }
 
@@ -200,6 +213,33 @@ impl NullBufferBuilder {
         Some(NullBuffer::new(self.bitmap_builder.take()?.finish()))
     }
 
+    /// Reports the memory allocated by this builder to `pool`
+    ///
+    /// As the null buffer is only materialized when a null is appended, no memory
+    /// is reserved until then. See [`BooleanBufferBuilder::with_memory_pool`].
+    #[cfg(feature = "pool")]
+    pub fn with_memory_pool(mut self, pool: Arc<dyn MemoryPool>) -> Self {
+        self.bitmap_builder = self
+            .bitmap_builder
+            .take()
+            .map(|b| b.with_memory_pool(Arc::clone(&pool)));
+        self.pool = Some(pool);
+        self
+    }
+
+    /// Reserves space for at least `additional` more bits, returning an error if this
+    /// would exceed the memory available in the pool set by [`Self::with_memory_pool`]
+    ///
+    /// As the null buffer is only materialized when a null is appended, this has no
+    /// effect until then. See [`BooleanBufferBuilder::try_reserve`].
+    #[cfg(feature = "pool")]
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), MemoryPoolExhausted> {
+        match self.bitmap_builder.as_mut() {
+            Some(b) => b.try_reserve(additional),
+            None => Ok(()),
+        }
+    }
+
     /// Builds the [NullBuffer] without resetting the builder.
     pub fn finish_cloned(&self) -> Option<NullBuffer> {
         let buffer = self.bitmap_builder.as_ref()?.finish_cloned();
@@ -221,6 +261,10 @@ impl NullBufferBuilder {
     fn materialize(&mut self) {
         if self.bitmap_builder.is_none() {
             let mut b = BooleanBufferBuilder::new(self.len.max(self.capacity));
+            #[cfg(feature = "pool")]
+            if let Some(pool) = &self.pool {
+                b = b.with_memory_pool(Arc::clone(pool));
+            }
             b.append_n(self.len, true);
             self.bitmap_builder = Some(b);
         }
@@ -402,4 +446,28 @@ mod tests {
 
         assert_eq!(builder.finish(), None);
     }
+
+    #[test]
+    #[cfg(feature = "pool")]
+    fn test_null_buffer_builder_with_pool() {
+        use crate::pool::TrackingMemoryPool;
+
+        let pool = Arc::new(TrackingMemoryPool::default());
+        let mut builder = NullBufferBuilder::new(10).with_memory_pool(pool.clone());
+        builder.append_n_non_nulls(10);
+        assert_eq!(pool.used(), 0);
+
+        builder.append_null();
+        let used = pool.used();
+        assert!(used >= 2);
+        let nulls = builder.finish().unwrap();
+        assert_eq!(pool.used(), used);
+
+        builder.append_n_nulls(1000);
+        assert!(pool.used() >= used + 125);
+
+        drop(nulls);
+        drop(builder);
+        assert_eq!(pool.used(), 0);
+    }
 }
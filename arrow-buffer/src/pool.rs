@@ -19,8 +19,9 @@
 //! for tracking memory usage.
 //!
 //! The basic traits are [`MemoryPool`] and [`MemoryReservation`]. And default
-//! implementation of [`MemoryPool`] is [`TrackingMemoryPool`]. Their relationship
-//! is as follows:
+//! implementation of [`MemoryPool`] is [`TrackingMemoryPool`], with
+//! [`BoundedMemoryPool`] additionally enforcing a limit on the memory reserved
+//! through [`MemoryPool::try_reserve`]. Their relationship is as follows:
 //!
 //! ```text
 //!     (pool tracker)                        (resizable)           
@@ -29,7 +30,7 @@
 //!  └──────────────────┘              └─────────────────────────┘
 //! ```
 
-use std::fmt::Debug;
+use std::fmt::{Debug, Display, Formatter};
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 
@@ -40,6 +41,16 @@ pub trait MemoryReservation: Debug + Send + Sync {
 
     /// Resize this reservation to a new size in bytes.
     fn resize(&mut self, new_size: usize);
+
+    /// Resize this reservation to a new size in bytes, returning an error and leaving
+    /// the reservation unchanged if growing it would exceed the memory available in
+    /// its [`MemoryPool`]
+    ///
+    /// The default implementation calls [`Self::resize`] and never fails.
+    fn try_resize(&mut self, new_size: usize) -> Result<(), MemoryPoolExhausted> {
+        self.resize(new_size);
+        Ok(())
+    }
 }
 
 /// A pool of memory that can be reserved and released.
@@ -86,8 +97,47 @@ pub trait MemoryPool: Debug + Send + Sync {
 
     /// Returns the maximum memory that can be reserved from the pool.
     fn capacity(&self) -> usize;
+
+    /// Reserves memory from the pool, returning an error if this would exceed
+    /// the [`available`](Self::available) memory.
+    ///
+    /// The default implementation checks [`Self::available`] before calling
+    /// [`Self::reserve`], implementations shared between threads should override
+    /// this to perform the check and reservation atomically.
+    fn try_reserve(&self, size: usize) -> Result<Box<dyn MemoryReservation>, MemoryPoolExhausted> {
+        let available = self.available();
+        if available < 0 || size > available as usize {
+            return Err(MemoryPoolExhausted {
+                requested: size,
+                available,
+            });
+        }
+        Ok(self.reserve(size))
+    }
+}
+
+/// The error returned by [`MemoryPool::try_reserve`] when there is insufficient
+/// memory available in the pool
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryPoolExhausted {
+    /// The number of bytes requested
+    pub requested: usize,
+    /// The number of bytes available in the pool at the time of the request
+    pub available: isize,
+}
+
+impl Display for MemoryPoolExhausted {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Failed to reserve {} bytes from memory pool with {} bytes available",
+            self.requested, self.available
+        )
+    }
 }
 
+impl std::error::Error for MemoryPoolExhausted {}
+
 /// A simple [`MemoryPool`] that reports the total memory usage
 #[derive(Debug, Default)]
 pub struct TrackingMemoryPool(Arc<AtomicUsize>);
@@ -105,6 +155,7 @@ impl MemoryPool for TrackingMemoryPool {
         Box::new(Tracker {
             size,
             shared: Arc::clone(&self.0),
+            capacity: None,
         })
     }
 
@@ -121,10 +172,94 @@ impl MemoryPool for TrackingMemoryPool {
     }
 }
 
+/// A [`MemoryPool`] with a fixed capacity
+///
+/// [`MemoryPool::try_reserve`] and [`MemoryReservation::try_resize`] fail if the
+/// reservation would exceed the capacity of the pool, whereas [`MemoryPool::reserve`]
+/// and [`MemoryReservation::resize`], used when an existing buffer grows infallibly,
+/// always succeed and may overfill the pool.
+///
+/// Buffers and builders can grow fallibly with methods such as
+/// [`MutableBuffer::try_reserve`](crate::MutableBuffer::try_reserve).
+///
+/// ```
+/// # use arrow_buffer::{BoundedMemoryPool, MemoryPool, MutableBuffer};
+/// let pool = BoundedMemoryPool::new(1024);
+///
+/// let buffer = MutableBuffer::with_capacity(512);
+/// buffer.try_claim(&pool).unwrap();
+/// assert_eq!(pool.used(), 512);
+///
+/// let buffer = MutableBuffer::with_capacity(1024);
+/// let err = buffer.try_claim(&pool).unwrap_err();
+/// assert_eq!(err.to_string(), "Failed to reserve 1024 bytes from memory pool with 512 bytes available");
+/// ```
+#[derive(Debug)]
+pub struct BoundedMemoryPool {
+    capacity: usize,
+    used: Arc<AtomicUsize>,
+}
+
+impl BoundedMemoryPool {
+    /// Creates a new [`BoundedMemoryPool`] with the given capacity in bytes
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            used: Default::default(),
+        }
+    }
+}
+
+impl MemoryPool for BoundedMemoryPool {
+    fn reserve(&self, size: usize) -> Box<dyn MemoryReservation> {
+        self.used.fetch_add(size, Ordering::Relaxed);
+        Box::new(Tracker {
+            size,
+            shared: Arc::clone(&self.used),
+            capacity: Some(self.capacity),
+        })
+    }
+
+    fn available(&self) -> isize {
+        self.capacity as isize - self.used() as isize
+    }
+
+    fn used(&self) -> usize {
+        self.used.load(Ordering::Relaxed)
+    }
+
+    fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    fn try_reserve(&self, size: usize) -> Result<Box<dyn MemoryReservation>, MemoryPoolExhausted> {
+        try_add(&self.used, size, self.capacity)?;
+        Ok(Box::new(Tracker {
+            size,
+            shared: Arc::clone(&self.used),
+            capacity: Some(self.capacity),
+        }))
+    }
+}
+
+/// Atomically adds `size` to `used`, returning an error if this would exceed `capacity`
+fn try_add(used: &AtomicUsize, size: usize, capacity: usize) -> Result<(), MemoryPoolExhausted> {
+    used.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |used| {
+        used.checked_add(size).filter(|new| *new <= capacity)
+    })
+    .map_err(|used| MemoryPoolExhausted {
+        requested: size,
+        available: capacity as isize - used as isize,
+    })?;
+    Ok(())
+}
+
 #[derive(Debug)]
 struct Tracker {
     size: usize,
     shared: Arc<AtomicUsize>,
+    /// The capacity of a [`BoundedMemoryPool`], enforced by [`MemoryReservation::try_resize`]
+    capacity: Option<usize>,
 }
 
 impl Drop for Tracker {
@@ -145,6 +280,17 @@ impl MemoryReservation for Tracker {
         };
         self.size = new;
     }
+
+    fn try_resize(&mut self, new: usize) -> Result<(), MemoryPoolExhausted> {
+        match self.capacity {
+            Some(capacity) if new > self.size => {
+                try_add(&self.shared, new - self.size, capacity)?;
+                self.size = new;
+            }
+            _ => self.resize(new),
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -186,4 +332,78 @@ mod tests {
         drop(reservation2);
         assert_eq!(pool.used(), 0);
     }
+
+    #[test]
+    fn test_bounded_memory_pool() {
+        let pool = BoundedMemoryPool::new(1000);
+        assert_eq!(pool.capacity(), 1000);
+
+        let mut reservation = pool.try_reserve(600).unwrap();
+        assert_eq!(pool.used(), 600);
+        assert_eq!(pool.available(), 400);
+
+        let err = pool.try_reserve(500).unwrap_err();
+        assert_eq!(
+            err,
+            MemoryPoolExhausted {
+                requested: 500,
+                available: 400
+            }
+        );
+        assert_eq!(pool.used(), 600);
+
+        // Resizing and infallible reservations may overfill the pool
+        reservation.resize(1200);
+        assert_eq!(pool.available(), -200);
+        assert!(pool.try_reserve(0).is_err());
+
+        let other = pool.reserve(100);
+        assert_eq!(pool.used(), 1300);
+
+        drop(reservation);
+        drop(other);
+        assert_eq!(pool.used(), 0);
+        assert!(pool.try_reserve(1000).is_ok());
+        assert_eq!(pool.used(), 0);
+    }
+
+    #[test]
+    fn test_bounded_memory_pool_try_resize() {
+        let pool = BoundedMemoryPool::new(1000);
+        let mut reservation = pool.try_reserve(600).unwrap();
+        let _other = pool.reserve(100);
+
+        reservation.try_resize(900).unwrap();
+        assert_eq!(reservation.size(), 900);
+        assert_eq!(pool.used(), 1000);
+
+        // Growing beyond the capacity fails without changing the reservation
+        let err = reservation.try_resize(950).unwrap_err();
+        assert_eq!(
+            err,
+            MemoryPoolExhausted {
+                requested: 50,
+                available: 0
+            }
+        );
+        assert_eq!(reservation.size(), 900);
+        assert_eq!(pool.used(), 1000);
+
+        // Shrinking always succeeds
+        reservation.try_resize(100).unwrap();
+        assert_eq!(pool.used(), 200);
+
+        // Reservations from an unbounded pool never fail
+        let mut unbounded = TrackingMemoryPool::default().reserve(0);
+        unbounded.try_resize(usize::MAX / 2).unwrap();
+    }
+
+    #[test]
+    fn test_default_try_reserve() {
+        let pool = TrackingMemoryPool::default();
+        let reservation = pool.try_reserve(1024).unwrap();
+        assert_eq!(pool.used(), 1024);
+        drop(reservation);
+        assert_eq!(pool.used(), 0);
+    }
 }
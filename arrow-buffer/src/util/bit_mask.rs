@@ -17,7 +17,8 @@
 
 //! Utils for working with packed bit masks
 
-use crate::bit_util::ceil;
+use crate::bit_chunk_iterator::BitChunks;
+use crate::bit_util::{ceil, get_bit, set_bit, unset_bit};
 
 /// Util function to set bits in a slice of bytes.
 ///
@@ -56,6 +57,94 @@ pub fn set_bits(
     null_count
 }
 
+/// Sets all bits of `data` in the range `[offset..offset+len]` to `value`
+///
+/// Whole bytes are written at once, only the bits at either end of the range
+/// are masked individually. `offset` and `len` are in terms of bits
+pub fn fill_bits(data: &mut [u8], offset: usize, len: usize, value: bool) {
+    assert!(offset + len <= data.len() * 8);
+    if len == 0 {
+        return;
+    }
+
+    let apply = |byte: &mut u8, mask: u8| match value {
+        true => *byte |= mask,
+        false => *byte &= !mask,
+    };
+
+    let end = offset + len;
+    let (start_byte, start_shift) = (offset / 8, offset % 8);
+    let (end_byte, end_shift) = (end / 8, end % 8);
+
+    if start_byte == end_byte {
+        // The range lies entirely within a single byte
+        let mask = (u8::MAX << start_shift) & !(u8::MAX << end_shift);
+        apply(&mut data[start_byte], mask);
+        return;
+    }
+
+    let mut full_start = start_byte;
+    if start_shift != 0 {
+        apply(&mut data[start_byte], u8::MAX << start_shift);
+        full_start += 1;
+    }
+    data[full_start..end_byte].fill(if value { u8::MAX } else { 0 });
+    if end_shift != 0 {
+        apply(&mut data[end_byte], !(u8::MAX << end_shift));
+    }
+}
+
+/// Applies `op` to the bits of `left` in the range `[left_offset..left_offset+len]`
+/// and the bits of `right` in the range `[right_offset..right_offset+len]`,
+/// writing the result back into `left`
+///
+/// `op` is applied to 64 bits at a time, and bits of `left` outside of the range
+/// are left unchanged. `left_offset`, `right_offset` and `len` are in terms of bits
+pub fn apply_bitwise_assign_op<F>(
+    left: &mut [u8],
+    left_offset: usize,
+    right: &[u8],
+    right_offset: usize,
+    len: usize,
+    mut op: F,
+) where
+    F: FnMut(u64, u64) -> u64,
+{
+    assert!(left_offset + len <= left.len() * 8);
+    assert!(right_offset + len <= right.len() * 8);
+
+    // Process the leading bits individually until `left` is byte aligned
+    let lead = ((8 - left_offset % 8) % 8).min(len);
+    for i in 0..lead {
+        let l = get_bit(left, left_offset + i) as u64;
+        let r = get_bit(right, right_offset + i) as u64;
+        match op(l, r) & 1 {
+            1 => set_bit(left, left_offset + i),
+            _ => unset_bit(left, left_offset + i),
+        }
+    }
+
+    let left = &mut left[(left_offset + lead) / 8..];
+    let chunks = BitChunks::new(right, right_offset + lead, len - lead);
+    let chunk_bytes = chunks.chunk_len() * 8;
+    for (l, r) in left[..chunk_bytes].chunks_exact_mut(8).zip(chunks.iter()) {
+        let v = u64::from_le_bytes(l.try_into().unwrap());
+        l.copy_from_slice(&op(v, r).to_le_bytes());
+    }
+
+    let remainder_len = chunks.remainder_len();
+    if remainder_len != 0 {
+        let bytes = &mut left[chunk_bytes..chunk_bytes + ceil(remainder_len, 8)];
+        let mut buf = [0_u8; 8];
+        buf[..bytes.len()].copy_from_slice(bytes);
+        let v = u64::from_le_bytes(buf);
+        let mask = u64::MAX >> (64 - remainder_len);
+        let v = (v & !mask) | (op(v, chunks.remainder_bits()) & mask);
+        let len = bytes.len();
+        bytes.copy_from_slice(&v.to_le_bytes()[..len]);
+    }
+}
+
 /// Similar to `set_bits` but sets only upto 64 bits, actual number of bits set may vary.
 /// Returns a pair of the number of `0` bits and the number of bits set
 ///
@@ -429,4 +518,55 @@ mod tests {
         assert_eq!(len_set, 1);
         assert_eq!(write_data, &[0b00000010]);
     }
+
+    #[test]
+    fn test_fill_bits() {
+        let mut data = vec![0_u8; 4];
+        fill_bits(&mut data, 2, 3, true);
+        assert_eq!(data, &[0b00011100, 0, 0, 0]);
+
+        fill_bits(&mut data, 6, 20, true);
+        assert_eq!(data, &[0b11011100, 0xFF, 0xFF, 0b00000011]);
+
+        fill_bits(&mut data, 3, 13, false);
+        assert_eq!(data, &[0b00000100, 0, 0xFF, 0b00000011]);
+
+        fill_bits(&mut data, 32, 0, true);
+        assert_eq!(data, &[0b00000100, 0, 0xFF, 0b00000011]);
+    }
+
+    #[test]
+    fn test_apply_bitwise_assign_op_fuzz() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..100 {
+            let len = rng.random_range(0..=200);
+            let left_offset = rng.random_range(0..=70);
+            let right_offset = rng.random_range(0..=70);
+            let left_bytes = ceil(left_offset + len, 8) + rng.random_range(0..=2);
+            let right_bytes = ceil(right_offset + len, 8) + rng.random_range(0..=2);
+
+            let mut left = vec![0_u8; left_bytes];
+            let mut right = vec![0_u8; right_bytes];
+            rng.fill(left.as_mut_slice());
+            rng.fill(right.as_mut_slice());
+
+            let mut expected = left.clone();
+            for i in 0..len {
+                let l = get_bit(&left, left_offset + i);
+                let r = get_bit(&right, right_offset + i);
+                match l & !r {
+                    true => set_bit(&mut expected, left_offset + i),
+                    false => unset_bit(&mut expected, left_offset + i),
+                }
+            }
+
+            apply_bitwise_assign_op(&mut left, left_offset, &right, right_offset, len, |a, b| {
+                a & !b
+            });
+            assert_eq!(
+                left, expected,
+                "len {len} offsets {left_offset} {right_offset}"
+            );
+        }
+    }
 }
@@ -19,3 +19,4 @@ pub mod bit_chunk_iterator;
 pub mod bit_iterator;
 pub mod bit_mask;
 pub mod bit_util;
+pub mod utf8;
@@ -0,0 +1,85 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Utils for validating UTF-8 data
+
+use std::str::Utf8Error;
+
+/// Converts a slice of bytes to a string slice, validating that it is UTF-8
+///
+/// This is a drop-in replacement for [`std::str::from_utf8`]. If the `simdutf8`
+/// feature is enabled, validation uses SIMD-accelerated routines from the
+/// [`simdutf8`] crate, only falling back to [`std::str::from_utf8`] to compute
+/// the details of the [`Utf8Error`] when the input is invalid.
+///
+/// ```
+/// # use arrow_buffer::utf8::from_utf8;
+/// assert_eq!(from_utf8(b"hello").unwrap(), "hello");
+///
+/// let err = from_utf8(b"hello \xF0\x9F\x98").unwrap_err();
+/// assert_eq!(err.valid_up_to(), 6);
+/// ```
+///
+/// [`simdutf8`]: https://crates.io/crates/simdutf8
+#[inline]
+pub fn from_utf8(v: &[u8]) -> Result<&str, Utf8Error> {
+    #[cfg(feature = "simdutf8")]
+    match simdutf8::basic::from_utf8(v) {
+        Ok(s) => Ok(s),
+        Err(_) => std::str::from_utf8(v),
+    }
+    #[cfg(not(feature = "simdutf8"))]
+    std::str::from_utf8(v)
+}
+
+/// Returns `true` if `v` is a valid UTF-8 sequence
+///
+/// See [`from_utf8`] for details
+#[inline]
+pub fn is_valid_utf8(v: &[u8]) -> bool {
+    #[cfg(feature = "simdutf8")]
+    return simdutf8::basic::from_utf8(v).is_ok();
+    #[cfg(not(feature = "simdutf8"))]
+    return std::str::from_utf8(v).is_ok();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_utf8() {
+        let cases: &[&[u8]] = &[
+            b"",
+            b"hello",
+            "ünïcödé 😎".as_bytes(),
+            &[b'a'; 100],
+            b"\xF0\x9F\x98",
+            b"hello \xF0\x9F\x98",
+            b"\xFFabc",
+            b"abc\xC3",
+            b"abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz\xC3\x28",
+        ];
+
+        for case in cases {
+            let expected = std::str::from_utf8(case);
+            let actual = from_utf8(case);
+            assert_eq!(actual, expected, "{case:?}");
+            assert_eq!(is_valid_utf8(case), expected.is_ok());
+        }
+    }
+}
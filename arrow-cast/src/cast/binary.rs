@@ -0,0 +1,245 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::cast::*;
+use arrow_buffer::{Buffer, MutableBuffer};
+
+/// An integer that can be read from and written to bytes with a given [`Endianness`]
+pub(crate) trait IntegerBytes: ArrowNativeType {
+    /// Reads an integer from `bytes`, returning `None` if it has the wrong length
+    fn from_bytes(bytes: &[u8], endianness: Endianness) -> Option<Self>;
+
+    /// Appends the bytes of this integer to `out`
+    fn extend_bytes(self, endianness: Endianness, out: &mut MutableBuffer);
+}
+
+macro_rules! integer_bytes {
+    ($($t:ty),*) => {
+        $(
+            impl IntegerBytes for $t {
+                fn from_bytes(bytes: &[u8], endianness: Endianness) -> Option<Self> {
+                    let bytes = bytes.try_into().ok()?;
+                    Some(match endianness {
+                        Endianness::Little => Self::from_le_bytes(bytes),
+                        Endianness::Big => Self::from_be_bytes(bytes),
+                    })
+                }
+
+                fn extend_bytes(self, endianness: Endianness, out: &mut MutableBuffer) {
+                    match endianness {
+                        Endianness::Little => out.extend_from_slice(&self.to_le_bytes()),
+                        Endianness::Big => out.extend_from_slice(&self.to_be_bytes()),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+integer_bytes!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+/// Returns the bytes of the values of `array` with the given `endianness`, without
+/// copying if they match the native representation
+pub(crate) fn integer_bytes<T>(array: &PrimitiveArray<T>, endianness: Endianness) -> Buffer
+where
+    T: ArrowPrimitiveType,
+    T::Native: IntegerBytes,
+{
+    match endianness {
+        Endianness::Little if cfg!(target_endian = "little") => array.values().inner().clone(),
+        _ => {
+            let mut out = MutableBuffer::new(array.len() * std::mem::size_of::<T::Native>());
+            for v in array.values() {
+                v.extend_bytes(endianness, &mut out);
+            }
+            out.into()
+        }
+    }
+}
+
+/// Casts an integer array to a [`FixedSizeBinaryArray`] of the width of the integers
+pub(crate) fn cast_integer_to_fixed_size_binary<T>(
+    array: &dyn Array,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError>
+where
+    T: ArrowPrimitiveType,
+    T::Native: IntegerBytes,
+{
+    let array = array.as_primitive::<T>();
+    let size = std::mem::size_of::<T::Native>() as i32;
+    let values = integer_bytes(array, cast_options.binary_endianness);
+    Ok(Arc::new(FixedSizeBinaryArray::try_new(
+        size,
+        values,
+        array.nulls().cloned(),
+    )?))
+}
+
+/// Casts a binary array to integers, reading each value as the bytes of an integer with
+/// [`CastOptions::binary_endianness`]
+///
+/// Values with a length other than the width of the integer are null, or an error if not
+/// [`CastOptions::safe`]
+pub(crate) fn cast_binary_to_integer<T>(
+    array: &dyn Array,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError>
+where
+    T: ArrowPrimitiveType,
+    T::Native: IntegerBytes,
+{
+    let array: PrimitiveArray<T> = match array.data_type() {
+        DataType::Binary => binary_to_integer(array.as_binary::<i32>().iter(), cast_options)?,
+        DataType::LargeBinary => binary_to_integer(array.as_binary::<i64>().iter(), cast_options)?,
+        DataType::BinaryView => binary_to_integer(array.as_binary_view().iter(), cast_options)?,
+        DataType::FixedSizeBinary(_) => {
+            binary_to_integer(array.as_fixed_size_binary().iter(), cast_options)?
+        }
+        d => unreachable!("Casting from {d:?} to integer is not supported"),
+    };
+    Ok(Arc::new(array))
+}
+
+fn binary_to_integer<'a, T, I>(
+    iter: I,
+    cast_options: &CastOptions,
+) -> Result<PrimitiveArray<T>, ArrowError>
+where
+    T: ArrowPrimitiveType,
+    T::Native: IntegerBytes,
+    I: Iterator<Item = Option<&'a [u8]>>,
+{
+    let endianness = cast_options.binary_endianness;
+    let parse = |v: &[u8]| T::Native::from_bytes(v, endianness);
+    if cast_options.safe {
+        return Ok(iter.map(|v| v.and_then(parse)).collect());
+    }
+    iter.map(|v| {
+        v.map(|v| {
+            parse(v).ok_or_else(|| {
+                ArrowError::CastError(format!(
+                    "Cannot cast binary value of {} bytes to {}, expected {} bytes",
+                    v.len(),
+                    T::DATA_TYPE,
+                    std::mem::size_of::<T::Native>()
+                ))
+            })
+        })
+        .transpose()
+    })
+    .collect()
+}
+
+#[cfg(feature = "canonical_extension_types")]
+/// Formats 16 bytes as a UUID in the canonical `8-4-4-4-12` hexadecimal format
+fn format_uuid(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(36);
+    for (idx, b) in bytes.iter().enumerate() {
+        if matches!(idx, 4 | 6 | 8 | 10) {
+            out.push('-');
+        }
+        out.push(char::from_digit((b >> 4) as u32, 16).unwrap());
+        out.push(char::from_digit((b & 0xf) as u32, 16).unwrap());
+    }
+    out
+}
+
+#[cfg(feature = "canonical_extension_types")]
+/// Parses a UUID in the canonical `8-4-4-4-12` hexadecimal format, or as 32 hexadecimal
+/// digits without hyphens
+fn parse_uuid(s: &str) -> Option<[u8; 16]> {
+    let digits: Vec<u8> = match s.len() {
+        36 => {
+            let hyphens = [8, 13, 18, 23];
+            if !hyphens.iter().all(|idx| s.as_bytes()[*idx] == b'-') {
+                return None;
+            }
+            s.bytes().filter(|b| *b != b'-').collect()
+        }
+        32 => s.bytes().collect(),
+        _ => return None,
+    };
+    if digits.len() != 32 {
+        return None;
+    }
+
+    let mut out = [0; 16];
+    for (out, pair) in out.iter_mut().zip(digits.chunks_exact(2)) {
+        let hi = (pair[0] as char).to_digit(16)?;
+        let lo = (pair[1] as char).to_digit(16)?;
+        *out = (hi << 4 | lo) as u8;
+    }
+    Some(out)
+}
+
+#[cfg(feature = "canonical_extension_types")]
+/// Casts a `FixedSizeBinary(16)` array, the storage type of the canonical `arrow.uuid`
+/// extension type, to strings in the canonical UUID format
+pub(crate) fn cast_uuid_to_string(
+    array: &dyn Array,
+    to_type: &DataType,
+) -> Result<ArrayRef, ArrowError> {
+    let uuids = array
+        .as_fixed_size_binary()
+        .iter()
+        .map(|v| v.map(format_uuid));
+    Ok(match to_type {
+        DataType::Utf8 => Arc::new(uuids.collect::<StringArray>()),
+        DataType::LargeUtf8 => Arc::new(uuids.collect::<LargeStringArray>()),
+        DataType::Utf8View => Arc::new(uuids.collect::<StringViewArray>()),
+        d => unreachable!("Casting UUID to {d:?} is not supported"),
+    })
+}
+
+#[cfg(feature = "canonical_extension_types")]
+/// Casts strings in the canonical UUID format to a `FixedSizeBinary(16)` array, the
+/// storage type of the canonical `arrow.uuid` extension type
+///
+/// Invalid UUIDs are null, or an error if not [`CastOptions::safe`]
+pub(crate) fn cast_string_to_uuid(
+    array: &dyn Array,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    match array.data_type() {
+        DataType::Utf8 => string_to_uuid(array.as_string::<i32>().iter(), cast_options),
+        DataType::LargeUtf8 => string_to_uuid(array.as_string::<i64>().iter(), cast_options),
+        DataType::Utf8View => string_to_uuid(array.as_string_view().iter(), cast_options),
+        d => unreachable!("Casting from {d:?} to UUID is not supported"),
+    }
+}
+
+#[cfg(feature = "canonical_extension_types")]
+fn string_to_uuid<'a, I>(iter: I, cast_options: &CastOptions) -> Result<ArrayRef, ArrowError>
+where
+    I: ExactSizeIterator<Item = Option<&'a str>>,
+{
+    let mut builder = FixedSizeBinaryBuilder::with_capacity(iter.len(), 16);
+    for v in iter {
+        match v.map(|s| (s, parse_uuid(s))) {
+            None => builder.append_null(),
+            Some((_, Some(uuid))) => builder.append_value(uuid)?,
+            Some((_, None)) if cast_options.safe => builder.append_null(),
+            Some((s, None)) => {
+                return Err(ArrowError::CastError(format!(
+                    "Cannot cast string '{s}' to UUID"
+                )))
+            }
+        }
+    }
+    Ok(Arc::new(builder.finish()))
+}
@@ -20,6 +20,10 @@ use crate::cast::*;
 /// A utility trait that provides checked conversions between
 /// decimal types inspired by [`NumCast`]
 pub(crate) trait DecimalCast: Sized {
+    fn to_i32(self) -> Option<i32>;
+
+    fn to_i64(self) -> Option<i64>;
+
     fn to_i128(self) -> Option<i128>;
 
     fn to_i256(self) -> Option<i256>;
@@ -29,7 +33,67 @@ pub(crate) trait DecimalCast: Sized {
     fn from_f64(n: f64) -> Option<Self>;
 }
 
+impl DecimalCast for i32 {
+    fn to_i32(self) -> Option<i32> {
+        Some(self)
+    }
+
+    fn to_i64(self) -> Option<i64> {
+        Some(self as i64)
+    }
+
+    fn to_i128(self) -> Option<i128> {
+        Some(self as i128)
+    }
+
+    fn to_i256(self) -> Option<i256> {
+        Some(i256::from_i128(self as i128))
+    }
+
+    fn from_decimal<T: DecimalCast>(n: T) -> Option<Self> {
+        n.to_i32()
+    }
+
+    fn from_f64(n: f64) -> Option<Self> {
+        num::ToPrimitive::to_i32(&n)
+    }
+}
+
+impl DecimalCast for i64 {
+    fn to_i32(self) -> Option<i32> {
+        i32::try_from(self).ok()
+    }
+
+    fn to_i64(self) -> Option<i64> {
+        Some(self)
+    }
+
+    fn to_i128(self) -> Option<i128> {
+        Some(self as i128)
+    }
+
+    fn to_i256(self) -> Option<i256> {
+        Some(i256::from_i128(self as i128))
+    }
+
+    fn from_decimal<T: DecimalCast>(n: T) -> Option<Self> {
+        n.to_i64()
+    }
+
+    fn from_f64(n: f64) -> Option<Self> {
+        num::ToPrimitive::to_i64(&n)
+    }
+}
+
 impl DecimalCast for i128 {
+    fn to_i32(self) -> Option<i32> {
+        i32::try_from(self).ok()
+    }
+
+    fn to_i64(self) -> Option<i64> {
+        i64::try_from(self).ok()
+    }
+
     fn to_i128(self) -> Option<i128> {
         Some(self)
     }
@@ -48,6 +112,14 @@ impl DecimalCast for i128 {
 }
 
 impl DecimalCast for i256 {
+    fn to_i32(self) -> Option<i32> {
+        self.to_i128().and_then(|v| i32::try_from(v).ok())
+    }
+
+    fn to_i64(self) -> Option<i64> {
+        self.to_i128().and_then(|v| i64::try_from(v).ok())
+    }
+
     fn to_i128(self) -> Option<i128> {
         self.to_i128()
     }
@@ -119,7 +191,7 @@ where
         .pow_checked(delta_scale as u32)?;
 
     let half = div.div_wrapping(I::Native::from_usize(2).unwrap());
-    let half_neg = half.neg_wrapping();
+    let rounding_mode = cast_options.rounding_mode;
 
     let f = |x: I::Native| {
         // div is >= 10 and so this cannot overflow
@@ -127,11 +199,7 @@ where
         let r = x.mod_wrapping(div);
 
         // Round result
-        let adjusted = match x >= I::Native::ZERO {
-            true if r >= half => d.add_wrapping(I::Native::ONE),
-            false if r <= half_neg => d.sub_wrapping(I::Native::ONE),
-            _ => d,
-        };
+        let adjusted = round_quotient(d, r, half, rounding_mode);
         O::Native::from_decimal(adjusted)
     };
 
@@ -151,6 +219,44 @@ where
     })
 }
 
+/// Rounds the quotient `d` of a division with remainder `r` by a divisor of `2 * half`
+/// according to `mode`
+///
+/// The remainder has the sign of the dividend, and so the quotient is rounded away from
+/// zero by adding one if `r` is positive, or subtracting one if `r` is negative
+fn round_quotient<T: ArrowNativeTypeOp>(d: T, r: T, half: T, mode: RoundingMode) -> T {
+    let away_from_zero = match mode {
+        RoundingMode::Truncate => false,
+        RoundingMode::Floor => r < T::ZERO,
+        RoundingMode::Ceil => r > T::ZERO,
+        RoundingMode::HalfUp => r >= half || r <= half.neg_wrapping(),
+        RoundingMode::HalfEven => {
+            let tie = r == half || r == half.neg_wrapping();
+            let odd = d.mod_wrapping(T::ONE.add_wrapping(T::ONE)) != T::ZERO;
+            match tie {
+                true => odd,
+                false => r > half || r < half.neg_wrapping(),
+            }
+        }
+    };
+    match away_from_zero {
+        true if r < T::ZERO => d.sub_wrapping(T::ONE),
+        true => d.add_wrapping(T::ONE),
+        false => d,
+    }
+}
+
+/// Rounds `v` to an integer according to `mode`
+fn round_f64(v: f64, mode: RoundingMode) -> f64 {
+    match mode {
+        RoundingMode::HalfUp => v.round(),
+        RoundingMode::HalfEven => v.round_ties_even(),
+        RoundingMode::Floor => v.floor(),
+        RoundingMode::Ceil => v.ceil(),
+        RoundingMode::Truncate => v.trunc(),
+    }
+}
+
 pub(crate) fn convert_to_bigger_or_equal_scale_decimal<I, O>(
     array: &PrimitiveArray<I>,
     input_precision: u8,
@@ -280,7 +386,7 @@ where
     )?))
 }
 
-/// Parses given string to specified decimal native (i128/i256) based on given
+/// Parses given string to specified decimal native (i32/i64/i128/i256) based on given
 /// scale. Returns an `Err` if it cannot parse given string.
 pub(crate) fn parse_string_to_decimal_native<T: DecimalType>(
     value_str: &str,
@@ -525,11 +631,12 @@ where
     <D as ArrowPrimitiveType>::Native: DecimalCast,
 {
     let mul = 10_f64.powi(scale as i32);
+    let round = |v: f64| round_f64(v, cast_options.rounding_mode);
 
     if cast_options.safe {
         array
             .unary_opt::<_, D>(|v| {
-                D::Native::from_f64((mul * v.as_()).round())
+                D::Native::from_f64(round(mul * v.as_()))
                     .filter(|v| D::is_valid_decimal_precision(*v, precision))
             })
             .with_precision_and_scale(precision, scale)
@@ -537,7 +644,7 @@ where
     } else {
         array
             .try_unary::<_, D, _>(|v| {
-                D::Native::from_f64((mul * v.as_()).round())
+                D::Native::from_f64(round(mul * v.as_()))
                     .ok_or_else(|| {
                         ArrowError::CastError(format!(
                             "Cannot cast to {}({}, {}). Overflowing on {:?}",
@@ -191,3 +191,106 @@ pub(crate) fn cast_list<I: OffsetSizeTrait, O: OffsetSizeTrait>(
         nulls,
     )))
 }
+
+/// Cast a List/LargeList array to a ListView/LargeListView array along with the inner datatype
+pub(crate) fn cast_list_to_list_view<I: OffsetSizeTrait, O: OffsetSizeTrait>(
+    array: &dyn Array,
+    field: &FieldRef,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let list = array.as_list::<I>();
+
+    if !O::IS_LARGE && list.values().len() > i32::MAX as usize {
+        return Err(ArrowError::ComputeError(
+            "LargeList too large to cast to ListView".into(),
+        ));
+    }
+
+    let values = cast_with_options(list.values(), field.data_type(), cast_options)?;
+    let offsets = list.offsets();
+    let sizes = offsets.lengths().map(O::usize_as).collect::<Vec<_>>();
+    let offsets = offsets[..list.len()]
+        .iter()
+        .map(|x| O::usize_as(x.as_usize()))
+        .collect::<Vec<_>>();
+
+    Ok(Arc::new(GenericListViewArray::<O>::try_new(
+        field.clone(),
+        offsets.into(),
+        sizes.into(),
+        values,
+        list.nulls().cloned(),
+    )?))
+}
+
+/// Cast a ListView/LargeListView array to a List/LargeList array along with the inner datatype
+///
+/// As the values of a list view need not be contiguous, these are first copied in order,
+/// omitting any values that are not referenced or only referenced by null list views
+pub(crate) fn cast_list_view_to_list<I: OffsetSizeTrait, O: OffsetSizeTrait>(
+    array: &dyn Array,
+    field: &FieldRef,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let list = array.as_list_view::<I>();
+    let nulls = list.nulls().cloned();
+
+    let size = |i: usize| match list.is_null(i) {
+        true => 0,
+        false => list.value_size(i).as_usize(),
+    };
+
+    let values_len: usize = (0..list.len()).map(size).sum();
+    if !O::IS_LARGE && values_len > i32::MAX as usize {
+        return Err(ArrowError::ComputeError(
+            "LargeListView too large to cast to List".into(),
+        ));
+    }
+
+    let values_data = list.values().to_data();
+    let mut mutable = MutableArrayData::new(vec![&values_data], false, values_len);
+    for i in 0..list.len() {
+        let len = size(i);
+        if len != 0 {
+            let offset = list.value_offset(i).as_usize();
+            mutable.extend(0, offset, offset + len);
+        }
+    }
+    let values = make_array(mutable.freeze());
+    let values = cast_with_options(&values, field.data_type(), cast_options)?;
+    let offsets = OffsetBuffer::<O>::from_lengths((0..list.len()).map(size));
+
+    Ok(Arc::new(GenericListArray::<O>::try_new(
+        field.clone(),
+        offsets,
+        values,
+        nulls,
+    )?))
+}
+
+/// Cast the container type of a ListView/LargeListView array along with the inner datatype
+pub(crate) fn cast_list_view<I: OffsetSizeTrait, O: OffsetSizeTrait>(
+    array: &dyn Array,
+    field: &FieldRef,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let list = array.as_list_view::<I>();
+
+    if !O::IS_LARGE && list.values().len() > i32::MAX as usize {
+        return Err(ArrowError::ComputeError(
+            "LargeListView too large to cast to ListView".into(),
+        ));
+    }
+
+    let values = cast_with_options(list.values(), field.data_type(), cast_options)?;
+    let offsets = list.offsets().iter().map(|x| O::usize_as(x.as_usize()));
+    let sizes = list.sizes().iter().map(|x| O::usize_as(x.as_usize()));
+
+    Ok(Arc::new(GenericListViewArray::<O>::try_new(
+        field.clone(),
+        offsets.collect::<Vec<_>>().into(),
+        sizes.collect::<Vec<_>>().into(),
+        values,
+        list.nulls().cloned(),
+    )?))
+}
@@ -37,16 +37,20 @@
 //! assert_eq!(7.0, c.value(2));
 //! ```
 
+mod binary;
 mod decimal;
 mod dictionary;
 mod list;
 mod map;
 mod string;
+mod union;
+use crate::cast::binary::*;
 use crate::cast::decimal::*;
 use crate::cast::dictionary::*;
 use crate::cast::list::*;
 use crate::cast::map::*;
 use crate::cast::string::*;
+use crate::cast::union::*;
 
 use arrow_buffer::IntervalMonthDayNano;
 use arrow_data::ByteView;
@@ -55,10 +59,7 @@ use std::cmp::Ordering;
 use std::sync::Arc;
 
 use crate::display::{ArrayFormatter, FormatOptions};
-use crate::parse::{
-    parse_interval_day_time, parse_interval_month_day_nano, parse_interval_year_month,
-    string_to_datetime, Parser,
-};
+use crate::parse::{string_to_datetime, string_to_datetime_formatted, Parser};
 use arrow_array::{builder::*, cast::*, temporal_conversions::*, timezone::Tz, types::*, *};
 use arrow_buffer::{i256, ArrowNativeType, OffsetBuffer};
 use arrow_data::transform::MutableArrayData;
@@ -69,12 +70,39 @@ use num::cast::AsPrimitive;
 use num::{NumCast, ToPrimitive};
 
 /// CastOptions provides a way to override the default cast behaviors
+///
+/// This is `#[non_exhaustive]` so that options can be added without breaking changes,
+/// use the `with_*` methods to override the [`Default`] options
+///
+/// ```
+/// # use arrow_cast::{CastOptions, RoundingMode};
+/// let options = CastOptions::default()
+///     .with_safe(false)
+///     .with_rounding_mode(RoundingMode::HalfEven);
+/// assert!(!options.safe);
+/// ```
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub struct CastOptions<'a> {
     /// how to handle cast failures, either return NULL (safe=true) or return ERR (safe=false)
     pub safe: bool,
     /// Formatting options when casting from temporal types to string
     pub format_options: FormatOptions<'a>,
+    /// How to round when casting floats or decimals to decimals with a smaller scale
+    pub rounding_mode: RoundingMode,
+    /// Chrono format strings tried in order for each value when casting strings to
+    /// timestamps or dates, with the first successful parse used. The format `"auto"` uses
+    /// the default parser, see [`string_to_datetime`]. If `None`, only the default parser
+    /// is used
+    pub datetime_formats: Option<&'a [&'a str]>,
+    /// The byte order of integers when casting between integers and binary
+    pub binary_endianness: Endianness,
+    /// If true, fields are matched by name rather than position when casting between
+    /// structs, filling fields missing from the source with nulls
+    pub match_struct_fields_by_name: bool,
+    /// If true, strings are parsed strictly when casting them to intervals, see
+    /// [`IntervalParseConfig::with_strict`](crate::parse::IntervalParseConfig::with_strict)
+    pub strict_interval_parsing: bool,
 }
 
 impl Default for CastOptions<'_> {
@@ -82,10 +110,117 @@ impl Default for CastOptions<'_> {
         Self {
             safe: true,
             format_options: FormatOptions::default(),
+            rounding_mode: RoundingMode::default(),
+            datetime_formats: None,
+            binary_endianness: Endianness::Little,
+            match_struct_fields_by_name: false,
+            strict_interval_parsing: false,
+        }
+    }
+}
+
+impl<'a> CastOptions<'a> {
+    /// Overrides whether cast failures return null (`true`) or an error (`false`)
+    ///
+    /// Defaults to `true`
+    pub const fn with_safe(self, safe: bool) -> Self {
+        Self { safe, ..self }
+    }
+
+    /// Overrides the [`FormatOptions`] used when casting from temporal types to string
+    pub const fn with_format_options(self, format_options: FormatOptions<'a>) -> Self {
+        Self {
+            format_options,
+            ..self
+        }
+    }
+
+    /// Overrides the [`RoundingMode`] used when casting floats or decimals to decimals
+    /// with a smaller scale
+    ///
+    /// Defaults to [`RoundingMode::HalfUp`]
+    pub const fn with_rounding_mode(self, rounding_mode: RoundingMode) -> Self {
+        Self {
+            rounding_mode,
+            ..self
+        }
+    }
+
+    /// Overrides the chrono format strings tried in order for each value when casting
+    /// strings to timestamps or dates, see [`Self::datetime_formats`]
+    ///
+    /// Defaults to `None`
+    pub const fn with_datetime_formats(self, datetime_formats: Option<&'a [&'a str]>) -> Self {
+        Self {
+            datetime_formats,
+            ..self
+        }
+    }
+
+    /// Overrides the byte order of integers when casting between integers and binary
+    ///
+    /// Defaults to [`Endianness::Little`]
+    pub const fn with_binary_endianness(self, binary_endianness: Endianness) -> Self {
+        Self {
+            binary_endianness,
+            ..self
+        }
+    }
+
+    /// Overrides whether fields are matched by name rather than position when casting
+    /// between structs
+    ///
+    /// Defaults to `false`
+    pub const fn with_match_struct_fields_by_name(self, match_struct_fields_by_name: bool) -> Self {
+        Self {
+            match_struct_fields_by_name,
+            ..self
+        }
+    }
+
+    /// Overrides whether strings are parsed strictly when casting them to intervals, see
+    /// [`IntervalParseConfig::with_strict`](crate::parse::IntervalParseConfig::with_strict)
+    ///
+    /// Defaults to `false`
+    pub const fn with_strict_interval_parsing(self, strict_interval_parsing: bool) -> Self {
+        Self {
+            strict_interval_parsing,
+            ..self
         }
     }
 }
 
+/// The byte order of an integer's binary representation
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Endianness {
+    /// Least significant byte first, as used by the Arrow format
+    #[default]
+    Little,
+    /// Most significant byte first, as used by network protocols and which sorts
+    /// non-negative integers in byte order
+    Big,
+}
+
+/// How to round a value that cannot be exactly represented in the target type
+///
+/// Used when casting floats or decimals to decimals with a smaller scale
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Round to the nearest value, with ties rounded away from zero, e.g. `2.5 => 3`
+    /// and `-2.5 => -3`
+    #[default]
+    HalfUp,
+    /// Round to the nearest value, with ties rounded to the nearest even value, also known
+    /// as banker's rounding, e.g. `2.5 => 2` and `3.5 => 4`
+    HalfEven,
+    /// Round towards negative infinity, e.g. `2.7 => 2` and `-2.1 => -3`
+    Floor,
+    /// Round towards positive infinity, e.g. `2.1 => 3` and `-2.7 => -2`
+    Ceil,
+    /// Round towards zero, e.g. `2.7 => 2` and `-2.7 => -2`
+    Truncate,
+}
+
 /// Return true if a value of type `from_type` can be cast into a value of `to_type`.
 ///
 /// See [`cast_with_options`] for more information
@@ -138,6 +273,16 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         }
         (Dictionary(_, value_type), _) => can_cast_types(value_type, to_type),
         (_, Dictionary(_, value_type)) => can_cast_types(from_type, value_type),
+        (Union(_, _), Union(_, _)) => false,
+        (Union(fields, _), _) => fields
+            .iter()
+            .all(|(_, f)| can_cast_types(f.data_type(), to_type)),
+        (_, Union(fields, _)) => {
+            fields.len() == 1
+                && fields
+                    .iter()
+                    .all(|(_, f)| can_cast_types(from_type, f.data_type()))
+        }
         (List(list_from) | LargeList(list_from), List(list_to) | LargeList(list_to)) => {
             can_cast_types(list_from.data_type(), list_to.data_type())
         }
@@ -147,7 +292,15 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (List(list_from) | LargeList(list_from), FixedSizeList(list_to, _)) => {
             can_cast_types(list_from.data_type(), list_to.data_type())
         }
+        (
+            List(list_from) | LargeList(list_from) | ListView(list_from) | LargeListView(list_from),
+            ListView(list_to) | LargeListView(list_to),
+        ) => can_cast_types(list_from.data_type(), list_to.data_type()),
+        (ListView(list_from) | LargeListView(list_from), List(list_to) | LargeList(list_to)) => {
+            can_cast_types(list_from.data_type(), list_to.data_type())
+        }
         (List(_), _) => false,
+        (ListView(_) | LargeListView(_), _) => false,
         (FixedSizeList(list_from,_), List(list_to)) |
         (FixedSizeList(list_from,_), LargeList(list_to)) => {
             can_cast_types(list_from.data_type(), list_to.data_type())
@@ -168,30 +321,33 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
                 _ => false
             },
         // cast one decimal type to another decimal type
-        (Decimal128(_, _), Decimal128(_, _)) => true,
-        (Decimal256(_, _), Decimal256(_, _)) => true,
-        (Decimal128(_, _), Decimal256(_, _)) => true,
-        (Decimal256(_, _), Decimal128(_, _)) => true,
+        (
+            Decimal32(_, _) | Decimal64(_, _) | Decimal128(_, _) | Decimal256(_, _),
+            Decimal32(_, _) | Decimal64(_, _) | Decimal128(_, _) | Decimal256(_, _),
+        ) => true,
         // unsigned integer to decimal
-        (UInt8 | UInt16 | UInt32 | UInt64, Decimal128(_, _)) |
-        (UInt8 | UInt16 | UInt32 | UInt64, Decimal256(_, _)) |
+        (UInt8 | UInt16 | UInt32 | UInt64, Decimal32(_, _) | Decimal64(_, _) | Decimal128(_, _) | Decimal256(_, _)) |
         // signed numeric to decimal
-        (Null | Int8 | Int16 | Int32 | Int64 | Float32 | Float64, Decimal128(_, _)) |
-        (Null | Int8 | Int16 | Int32 | Int64 | Float32 | Float64, Decimal256(_, _)) |
+        (Null | Int8 | Int16 | Int32 | Int64 | Float32 | Float64, Decimal32(_, _) | Decimal64(_, _) | Decimal128(_, _) | Decimal256(_, _)) |
         // decimal to unsigned numeric
-        (Decimal128(_, _) | Decimal256(_, _), UInt8 | UInt16 | UInt32 | UInt64) |
+        (Decimal32(_, _) | Decimal64(_, _) | Decimal128(_, _) | Decimal256(_, _), UInt8 | UInt16 | UInt32 | UInt64) |
         // decimal to signed numeric
-        (Decimal128(_, _) | Decimal256(_, _), Null | Int8 | Int16 | Int32 | Int64 | Float32 | Float64) => true,
+        (Decimal32(_, _) | Decimal64(_, _) | Decimal128(_, _) | Decimal256(_, _), Null | Int8 | Int16 | Int32 | Int64 | Float32 | Float64) => true,
         // decimal to string
-        (Decimal128(_, _) | Decimal256(_, _), Utf8View | Utf8 | LargeUtf8) => true,
+        (Decimal32(_, _) | Decimal64(_, _) | Decimal128(_, _) | Decimal256(_, _), Utf8View | Utf8 | LargeUtf8) => true,
         // string to decimal
-        (Utf8View | Utf8 | LargeUtf8, Decimal128(_, _) | Decimal256(_, _)) => true,
+        (Utf8View | Utf8 | LargeUtf8, Decimal32(_, _) | Decimal64(_, _) | Decimal128(_, _) | Decimal256(_, _)) => true,
         (Struct(from_fields), Struct(to_fields)) => {
-            from_fields.len() == to_fields.len() &&
+            (from_fields.len() == to_fields.len() &&
                 from_fields.iter().zip(to_fields.iter()).all(|(f1, f2)| {
                     // Assume that nullability between two structs are compatible, if not,
                     // cast kernel will return error.
                     can_cast_types(f1.data_type(), f2.data_type())
+                }))
+                // Fields may instead be matched by name, see CastOptions::match_struct_fields_by_name
+                || to_fields.iter().all(|to| match from_fields.find(to.name()) {
+                    Some((_, from)) => can_cast_types(from.data_type(), to.data_type()),
+                    None => to.is_nullable(),
                 })
         }
         (Struct(_), _) => false,
@@ -214,6 +370,10 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (Binary, LargeBinary | Utf8 | LargeUtf8 | FixedSizeBinary(_) | BinaryView | Utf8View ) => true,
         (LargeBinary, Binary | Utf8 | LargeUtf8 | FixedSizeBinary(_) | BinaryView | Utf8View ) => true,
         (FixedSizeBinary(_), Binary | LargeBinary | BinaryView) => true,
+        (Binary | LargeBinary | BinaryView | FixedSizeBinary(_), _) if to_type.is_integer() => true,
+        (_, FixedSizeBinary(size)) if from_type.is_integer() => {
+            from_type.primitive_width() == Some(*size as usize)
+        }
         (
             Utf8 | LargeUtf8 | Utf8View,
             Binary
@@ -307,6 +467,64 @@ pub fn cast(array: &dyn Array, to_type: &DataType) -> Result<ArrayRef, ArrowErro
     cast_with_options(array, to_type, &CastOptions::default())
 }
 
+/// Return true if an array of the type and extension type of `from` can be cast to
+/// those of `to`, see [`cast_field`]
+pub fn can_cast_fields(from: &Field, to: &Field) -> bool {
+    #[cfg(feature = "canonical_extension_types")]
+    if uuid_string_cast(from, to).is_some() {
+        return true;
+    }
+    can_cast_types(from.data_type(), to.data_type())
+}
+
+/// Cast `array`, with the type and extension type of `from`, to the type of `to`
+///
+/// This is the same as [`cast_with_options`] to the data type of `to`, other than the
+/// casts of extension types, which are only supported if the extension type metadata
+/// of `from` or `to` identifies them:
+///
+/// * With the `canonical_extension_types` feature, the `FixedSizeBinary(16)` storage of
+///   the canonical [`Uuid`] extension type to/from `Utf8`: UUIDs are formatted as
+///   `67e55044-10b1-426f-9247-bb680e5fe0c8`, invalid UUID strings return null or error
+///
+/// [`Uuid`]: https://arrow.apache.org/docs/format/CanonicalExtensions.html#uuid
+#[cfg_attr(not(feature = "canonical_extension_types"), allow(unused_variables))]
+pub fn cast_field(
+    array: &dyn Array,
+    from: &Field,
+    to: &Field,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    #[cfg(feature = "canonical_extension_types")]
+    match uuid_string_cast(from, to) {
+        Some(true) => return cast_uuid_to_string(array, to.data_type()),
+        Some(false) => return cast_string_to_uuid(array, cast_options),
+        None => {}
+    }
+    cast_with_options(array, to.data_type(), cast_options)
+}
+
+/// Returns `Some(true)` if casting from `from` to `to` casts a [`extension::Uuid`] to a
+/// string, `Some(false)` if it casts a string to a [`extension::Uuid`], otherwise `None`
+#[cfg(feature = "canonical_extension_types")]
+fn uuid_string_cast(from: &Field, to: &Field) -> Option<bool> {
+    let is_uuid = |f: &Field| {
+        f.data_type() == &DataType::FixedSizeBinary(16)
+            && f.extension_type_name() == Some(<extension::Uuid as extension::ExtensionType>::NAME)
+    };
+    let is_string = |f: &Field| {
+        matches!(
+            f.data_type(),
+            DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View
+        )
+    };
+    match (is_uuid(from), is_uuid(to)) {
+        (true, _) if is_string(to) => Some(true),
+        (_, true) if is_string(from) => Some(false),
+        _ => None,
+    }
+}
+
 fn cast_integer_to_decimal<
     T: ArrowPrimitiveType,
     D: DecimalType + ArrowPrimitiveType<Native = M>,
@@ -319,8 +537,8 @@ fn cast_integer_to_decimal<
     cast_options: &CastOptions,
 ) -> Result<ArrayRef, ArrowError>
 where
-    <T as ArrowPrimitiveType>::Native: AsPrimitive<M>,
-    M: ArrowNativeTypeOp,
+    <T as ArrowPrimitiveType>::Native: AsPrimitive<i128>,
+    M: ArrowNativeTypeOp + DecimalCast,
 {
     let scale_factor = base.pow_checked(scale.unsigned_abs() as u32).map_err(|_| {
         ArrowError::CastError(format!(
@@ -331,16 +549,29 @@ where
         ))
     })?;
 
+    // The integer may not fit in the native type of narrower decimals
+    let to_native = |v: T::Native| {
+        M::from_decimal::<i128>(v.as_()).ok_or_else(|| {
+            ArrowError::CastError(format!(
+                "Cannot cast to {}({}, {}). Overflowing on {:?}",
+                D::PREFIX,
+                precision,
+                scale,
+                v
+            ))
+        })
+    };
+
     let array = if scale < 0 {
         match cast_options.safe {
             true => array.unary_opt::<_, D>(|v| {
-                v.as_()
-                    .div_checked(scale_factor)
+                to_native(v)
+                    .and_then(|v| v.div_checked(scale_factor))
                     .ok()
                     .and_then(|v| (D::is_valid_decimal_precision(v, precision)).then_some(v))
             }),
             false => array.try_unary::<_, D, _>(|v| {
-                v.as_()
+                to_native(v)?
                     .div_checked(scale_factor)
                     .and_then(|v| D::validate_decimal_precision(v, precision).map(|_| v))
             })?,
@@ -348,13 +579,13 @@ where
     } else {
         match cast_options.safe {
             true => array.unary_opt::<_, D>(|v| {
-                v.as_()
-                    .mul_checked(scale_factor)
+                to_native(v)
+                    .and_then(|v| v.mul_checked(scale_factor))
                     .ok()
                     .and_then(|v| (D::is_valid_decimal_precision(v, precision)).then_some(v))
             }),
             false => array.try_unary::<_, D, _>(|v| {
-                v.as_()
+                to_native(v)?
                     .mul_checked(scale_factor)
                     .and_then(|v| D::validate_decimal_precision(v, precision).map(|_| v))
             })?,
@@ -592,6 +823,8 @@ fn timestamp_to_date32<T: ArrowTimestampType>(
 ///   short variants are accepted, other strings return null or error
 /// * `Utf8` to Numeric: strings that can't be parsed to numbers return null, float strings
 ///   in integer casts return null
+/// * `Utf8` to `Interval`: accepts the Postgres verbose format (`1 year 2 mons`), ISO-8601
+///   durations (`P1Y2M3DT4H`) and the SQL standard format (`1-2 3 4:05:06`)
 /// * Numeric to `Boolean`: 0 returns `false`, any other value returns `true`
 /// * `List` to `List`: the underlying data type is cast
 /// * `List` to `FixedSizeList`: the underlying data type is cast. If safe is true and a list element
@@ -602,10 +835,22 @@ fn timestamp_to_date32<T: ArrowTimestampType>(
 /// * `Timestamp` and `Date{32|64}`: precision lost when going to higher interval
 /// * Temporal to/from backing Primitive: zero-copy with data type change
 /// * `Float32/Float64` to `Decimal(precision, scale)` rounds to the `scale` decimals
-///   (i.e. casting `6.4999` to `Decimal(10, 1)` becomes `6.5`).
+///   (i.e. casting `6.4999` to `Decimal(10, 1)` becomes `6.5`), as does `Decimal` to
+///   `Decimal` with a smaller scale, using [`CastOptions::rounding_mode`].
+/// * `Struct` to `Struct`: fields are matched by position, or by name if
+///   [`CastOptions::match_struct_fields_by_name`], in which case missing nullable fields
+///   are filled with nulls and extra fields are dropped if safe is true. Children are
+///   cast recursively
+/// * Integer to/from `Binary` and `FixedSizeBinary`: the bytes of the integer in
+///   [`CastOptions::binary_endianness`] byte order. Binary values of the wrong length
+///   return null or error
+/// * `Union` to any type: each child is cast and the value selected by each slot taken
+/// * Any type to a `Union` with a single field: the array is cast to the type of the field
+/// * Extension types: see [`cast_field`]
 ///
 /// Unsupported Casts (check with `can_cast_types` before calling):
-/// * To or from `StructArray`
+/// * To or from `StructArray`, other than `Struct` to `Struct`
+/// * `Union` to `Union`, and to a `Union` with more than one field
 /// * `List` to `Primitive`
 /// * `Interval` and `Duration`
 ///
@@ -765,6 +1010,11 @@ pub fn cast_with_options(
                 "Casting from type {from_type:?} to dictionary type {to_type:?} not supported",
             ))),
         },
+        (Union(_, _), Union(_, _)) => Err(ArrowError::CastError(format!(
+            "Casting from {from_type:?} to {to_type:?} not supported",
+        ))),
+        (Union(_, _), _) => cast_union_values(array, to_type, cast_options),
+        (_, Union(fields, mode)) => cast_to_union(array, fields, *mode, cast_options),
         (List(_), List(to)) => cast_list_values::<i32>(array, to, cast_options),
         (LargeList(_), LargeList(to)) => cast_list_values::<i64>(array, to, cast_options),
         (List(_), LargeList(list_to)) => cast_list::<i32, i64>(array, list_to, cast_options),
@@ -777,6 +1027,18 @@ pub fn cast_with_options(
             let array = array.as_list::<i64>();
             cast_list_to_fixed_size_list::<i64>(array, field, *size, cast_options)
         }
+        (List(_), ListView(list_to)) => {
+            cast_list_to_list_view::<i32, i32>(array, list_to, cast_options)
+        }
+        (List(_), LargeListView(list_to)) => {
+            cast_list_to_list_view::<i32, i64>(array, list_to, cast_options)
+        }
+        (LargeList(_), ListView(list_to)) => {
+            cast_list_to_list_view::<i64, i32>(array, list_to, cast_options)
+        }
+        (LargeList(_), LargeListView(list_to)) => {
+            cast_list_to_list_view::<i64, i64>(array, list_to, cast_options)
+        }
         (List(_) | LargeList(_), _) => match to_type {
             Utf8 => value_to_string::<i32>(array, cast_options),
             LargeUtf8 => value_to_string::<i64>(array, cast_options),
@@ -784,6 +1046,30 @@ pub fn cast_with_options(
                 "Cannot cast list to non-list data types".to_string(),
             )),
         },
+        (ListView(_), List(list_to)) => {
+            cast_list_view_to_list::<i32, i32>(array, list_to, cast_options)
+        }
+        (ListView(_), LargeList(list_to)) => {
+            cast_list_view_to_list::<i32, i64>(array, list_to, cast_options)
+        }
+        (LargeListView(_), List(list_to)) => {
+            cast_list_view_to_list::<i64, i32>(array, list_to, cast_options)
+        }
+        (LargeListView(_), LargeList(list_to)) => {
+            cast_list_view_to_list::<i64, i64>(array, list_to, cast_options)
+        }
+        (ListView(_), ListView(list_to)) => {
+            cast_list_view::<i32, i32>(array, list_to, cast_options)
+        }
+        (ListView(_), LargeListView(list_to)) => {
+            cast_list_view::<i32, i64>(array, list_to, cast_options)
+        }
+        (LargeListView(_), ListView(list_to)) => {
+            cast_list_view::<i64, i32>(array, list_to, cast_options)
+        }
+        (LargeListView(_), LargeListView(list_to)) => {
+            cast_list_view::<i64, i64>(array, list_to, cast_options)
+        }
         (FixedSizeList(list_from, size), List(list_to)) => {
             if list_to.data_type() != list_from.data_type() {
                 // To transform inner type, can first cast to FSL with new inner type.
@@ -831,6 +1117,26 @@ pub fn cast_with_options(
             cast_map_values(array.as_map(), to_type, cast_options, ordered1.to_owned())
         }
         // Decimal to decimal, same width
+        (Decimal32(p1, s1), Decimal32(p2, s2)) => {
+            cast_decimal_to_decimal_same_type::<Decimal32Type>(
+                array.as_primitive(),
+                *p1,
+                *s1,
+                *p2,
+                *s2,
+                cast_options,
+            )
+        }
+        (Decimal64(p1, s1), Decimal64(p2, s2)) => {
+            cast_decimal_to_decimal_same_type::<Decimal64Type>(
+                array.as_primitive(),
+                *p1,
+                *s1,
+                *p2,
+                *s2,
+                cast_options,
+            )
+        }
         (Decimal128(p1, s1), Decimal128(p2, s2)) => {
             cast_decimal_to_decimal_same_type::<Decimal128Type>(
                 array.as_primitive(),
@@ -852,6 +1158,86 @@ pub fn cast_with_options(
             )
         }
         // Decimal to decimal, different width
+        (Decimal32(p1, s1), Decimal64(p2, s2)) => {
+            cast_decimal_to_decimal::<Decimal32Type, Decimal64Type>(
+                array.as_primitive(),
+                *p1,
+                *s1,
+                *p2,
+                *s2,
+                cast_options,
+            )
+        }
+        (Decimal32(p1, s1), Decimal128(p2, s2)) => {
+            cast_decimal_to_decimal::<Decimal32Type, Decimal128Type>(
+                array.as_primitive(),
+                *p1,
+                *s1,
+                *p2,
+                *s2,
+                cast_options,
+            )
+        }
+        (Decimal32(p1, s1), Decimal256(p2, s2)) => {
+            cast_decimal_to_decimal::<Decimal32Type, Decimal256Type>(
+                array.as_primitive(),
+                *p1,
+                *s1,
+                *p2,
+                *s2,
+                cast_options,
+            )
+        }
+        (Decimal64(p1, s1), Decimal32(p2, s2)) => {
+            cast_decimal_to_decimal::<Decimal64Type, Decimal32Type>(
+                array.as_primitive(),
+                *p1,
+                *s1,
+                *p2,
+                *s2,
+                cast_options,
+            )
+        }
+        (Decimal64(p1, s1), Decimal128(p2, s2)) => {
+            cast_decimal_to_decimal::<Decimal64Type, Decimal128Type>(
+                array.as_primitive(),
+                *p1,
+                *s1,
+                *p2,
+                *s2,
+                cast_options,
+            )
+        }
+        (Decimal64(p1, s1), Decimal256(p2, s2)) => {
+            cast_decimal_to_decimal::<Decimal64Type, Decimal256Type>(
+                array.as_primitive(),
+                *p1,
+                *s1,
+                *p2,
+                *s2,
+                cast_options,
+            )
+        }
+        (Decimal128(p1, s1), Decimal32(p2, s2)) => {
+            cast_decimal_to_decimal::<Decimal128Type, Decimal32Type>(
+                array.as_primitive(),
+                *p1,
+                *s1,
+                *p2,
+                *s2,
+                cast_options,
+            )
+        }
+        (Decimal128(p1, s1), Decimal64(p2, s2)) => {
+            cast_decimal_to_decimal::<Decimal128Type, Decimal64Type>(
+                array.as_primitive(),
+                *p1,
+                *s1,
+                *p2,
+                *s2,
+                cast_options,
+            )
+        }
         (Decimal128(p1, s1), Decimal256(p2, s2)) => {
             cast_decimal_to_decimal::<Decimal128Type, Decimal256Type>(
                 array.as_primitive(),
@@ -862,6 +1248,26 @@ pub fn cast_with_options(
                 cast_options,
             )
         }
+        (Decimal256(p1, s1), Decimal32(p2, s2)) => {
+            cast_decimal_to_decimal::<Decimal256Type, Decimal32Type>(
+                array.as_primitive(),
+                *p1,
+                *s1,
+                *p2,
+                *s2,
+                cast_options,
+            )
+        }
+        (Decimal256(p1, s1), Decimal64(p2, s2)) => {
+            cast_decimal_to_decimal::<Decimal256Type, Decimal64Type>(
+                array.as_primitive(),
+                *p1,
+                *s1,
+                *p2,
+                *s2,
+                cast_options,
+            )
+        }
         (Decimal256(p1, s1), Decimal128(p2, s2)) => {
             cast_decimal_to_decimal::<Decimal256Type, Decimal128Type>(
                 array.as_primitive(),
@@ -873,6 +1279,28 @@ pub fn cast_with_options(
             )
         }
         // Decimal to non-decimal
+        (Decimal32(_, scale), _) if !to_type.is_temporal() => {
+            cast_from_decimal::<Decimal32Type, _>(
+                array,
+                10_i32,
+                scale,
+                from_type,
+                to_type,
+                |x: i32| x as f64,
+                cast_options,
+            )
+        }
+        (Decimal64(_, scale), _) if !to_type.is_temporal() => {
+            cast_from_decimal::<Decimal64Type, _>(
+                array,
+                10_i64,
+                scale,
+                from_type,
+                to_type,
+                |x: i64| x as f64,
+                cast_options,
+            )
+        }
         (Decimal128(_, scale), _) if !to_type.is_temporal() => {
             cast_from_decimal::<Decimal128Type, _>(
                 array,
@@ -896,6 +1324,28 @@ pub fn cast_with_options(
             )
         }
         // Non-decimal to decimal
+        (_, Decimal32(precision, scale)) if !from_type.is_temporal() => {
+            cast_to_decimal::<Decimal32Type, _>(
+                array,
+                10_i32,
+                precision,
+                scale,
+                from_type,
+                to_type,
+                cast_options,
+            )
+        }
+        (_, Decimal64(precision, scale)) if !from_type.is_temporal() => {
+            cast_to_decimal::<Decimal64Type, _>(
+                array,
+                10_i64,
+                precision,
+                scale,
+                from_type,
+                to_type,
+                cast_options,
+            )
+        }
         (_, Decimal128(precision, scale)) if !from_type.is_temporal() => {
             cast_to_decimal::<Decimal128Type, _>(
                 array,
@@ -919,15 +1369,7 @@ pub fn cast_with_options(
             )
         }
         (Struct(_), Struct(to_fields)) => {
-            let array = array.as_struct();
-            let fields = array
-                .columns()
-                .iter()
-                .zip(to_fields.iter())
-                .map(|(l, field)| cast_with_options(l, field.data_type(), cast_options))
-                .collect::<Result<Vec<ArrayRef>, ArrowError>>()?;
-            let array = StructArray::try_new(to_fields.clone(), fields, array.nulls().cloned())?;
-            Ok(Arc::new(array) as ArrayRef)
+            cast_struct_to_struct(array.as_struct(), to_fields, cast_options)
         }
         (Struct(_), _) => Err(ArrowError::CastError(format!(
             "Casting from {from_type:?} to {to_type:?} not supported"
@@ -973,6 +1415,19 @@ pub fn cast_with_options(
                 "Casting from {from_type:?} to {to_type:?} not supported",
             ))),
         },
+        (Binary | LargeBinary | BinaryView | FixedSizeBinary(_), _) if to_type.is_integer() => {
+            match to_type {
+                UInt8 => cast_binary_to_integer::<UInt8Type>(array, cast_options),
+                UInt16 => cast_binary_to_integer::<UInt16Type>(array, cast_options),
+                UInt32 => cast_binary_to_integer::<UInt32Type>(array, cast_options),
+                UInt64 => cast_binary_to_integer::<UInt64Type>(array, cast_options),
+                Int8 => cast_binary_to_integer::<Int8Type>(array, cast_options),
+                Int16 => cast_binary_to_integer::<Int16Type>(array, cast_options),
+                Int32 => cast_binary_to_integer::<Int32Type>(array, cast_options),
+                Int64 => cast_binary_to_integer::<Int64Type>(array, cast_options),
+                _ => unreachable!(),
+            }
+        }
         (Utf8, _) => match to_type {
             UInt8 => parse_string::<UInt8Type, i32>(array, cast_options),
             UInt16 => parse_string::<UInt16Type, i32>(array, cast_options),
@@ -984,8 +1439,8 @@ pub fn cast_with_options(
             Int64 => parse_string::<Int64Type, i32>(array, cast_options),
             Float32 => parse_string::<Float32Type, i32>(array, cast_options),
             Float64 => parse_string::<Float64Type, i32>(array, cast_options),
-            Date32 => parse_string::<Date32Type, i32>(array, cast_options),
-            Date64 => parse_string::<Date64Type, i32>(array, cast_options),
+            Date32 => parse_string_datetime::<Date32Type, i32>(array, cast_options),
+            Date64 => parse_string_datetime::<Date64Type, i32>(array, cast_options),
             Binary => Ok(Arc::new(BinaryArray::from(
                 array.as_string::<i32>().clone(),
             ))),
@@ -1046,8 +1501,8 @@ pub fn cast_with_options(
             Int64 => parse_string_view::<Int64Type>(array, cast_options),
             Float32 => parse_string_view::<Float32Type>(array, cast_options),
             Float64 => parse_string_view::<Float64Type>(array, cast_options),
-            Date32 => parse_string_view::<Date32Type>(array, cast_options),
-            Date64 => parse_string_view::<Date64Type>(array, cast_options),
+            Date32 => parse_string_view_datetime::<Date32Type>(array, cast_options),
+            Date64 => parse_string_view_datetime::<Date64Type>(array, cast_options),
             Binary => cast_view_to_byte::<StringViewType, GenericBinaryType<i32>>(array),
             LargeBinary => cast_view_to_byte::<StringViewType, GenericBinaryType<i64>>(array),
             BinaryView => Ok(Arc::new(array.as_string_view().clone().to_binary_view())),
@@ -1097,8 +1552,8 @@ pub fn cast_with_options(
             Int64 => parse_string::<Int64Type, i64>(array, cast_options),
             Float32 => parse_string::<Float32Type, i64>(array, cast_options),
             Float64 => parse_string::<Float64Type, i64>(array, cast_options),
-            Date32 => parse_string::<Date32Type, i64>(array, cast_options),
-            Date64 => parse_string::<Date64Type, i64>(array, cast_options),
+            Date32 => parse_string_datetime::<Date32Type, i64>(array, cast_options),
+            Date64 => parse_string_datetime::<Date64Type, i64>(array, cast_options),
             Utf8 => cast_byte_container::<LargeUtf8Type, Utf8Type>(array),
             Binary => {
                 let large_binary = LargeBinaryArray::from(array.as_string::<i64>().clone());
@@ -1225,27 +1680,42 @@ pub fn cast_with_options(
             value_to_string::<i32>(array, cast_options)
         }
         (from_type, Binary) if from_type.is_integer() => match from_type {
-            UInt8 => cast_numeric_to_binary::<UInt8Type, i32>(array),
-            UInt16 => cast_numeric_to_binary::<UInt16Type, i32>(array),
-            UInt32 => cast_numeric_to_binary::<UInt32Type, i32>(array),
-            UInt64 => cast_numeric_to_binary::<UInt64Type, i32>(array),
-            Int8 => cast_numeric_to_binary::<Int8Type, i32>(array),
-            Int16 => cast_numeric_to_binary::<Int16Type, i32>(array),
-            Int32 => cast_numeric_to_binary::<Int32Type, i32>(array),
-            Int64 => cast_numeric_to_binary::<Int64Type, i32>(array),
+            UInt8 => cast_numeric_to_binary::<UInt8Type, i32>(array, cast_options),
+            UInt16 => cast_numeric_to_binary::<UInt16Type, i32>(array, cast_options),
+            UInt32 => cast_numeric_to_binary::<UInt32Type, i32>(array, cast_options),
+            UInt64 => cast_numeric_to_binary::<UInt64Type, i32>(array, cast_options),
+            Int8 => cast_numeric_to_binary::<Int8Type, i32>(array, cast_options),
+            Int16 => cast_numeric_to_binary::<Int16Type, i32>(array, cast_options),
+            Int32 => cast_numeric_to_binary::<Int32Type, i32>(array, cast_options),
+            Int64 => cast_numeric_to_binary::<Int64Type, i32>(array, cast_options),
             _ => unreachable!(),
         },
         (from_type, LargeBinary) if from_type.is_integer() => match from_type {
-            UInt8 => cast_numeric_to_binary::<UInt8Type, i64>(array),
-            UInt16 => cast_numeric_to_binary::<UInt16Type, i64>(array),
-            UInt32 => cast_numeric_to_binary::<UInt32Type, i64>(array),
-            UInt64 => cast_numeric_to_binary::<UInt64Type, i64>(array),
-            Int8 => cast_numeric_to_binary::<Int8Type, i64>(array),
-            Int16 => cast_numeric_to_binary::<Int16Type, i64>(array),
-            Int32 => cast_numeric_to_binary::<Int32Type, i64>(array),
-            Int64 => cast_numeric_to_binary::<Int64Type, i64>(array),
+            UInt8 => cast_numeric_to_binary::<UInt8Type, i64>(array, cast_options),
+            UInt16 => cast_numeric_to_binary::<UInt16Type, i64>(array, cast_options),
+            UInt32 => cast_numeric_to_binary::<UInt32Type, i64>(array, cast_options),
+            UInt64 => cast_numeric_to_binary::<UInt64Type, i64>(array, cast_options),
+            Int8 => cast_numeric_to_binary::<Int8Type, i64>(array, cast_options),
+            Int16 => cast_numeric_to_binary::<Int16Type, i64>(array, cast_options),
+            Int32 => cast_numeric_to_binary::<Int32Type, i64>(array, cast_options),
+            Int64 => cast_numeric_to_binary::<Int64Type, i64>(array, cast_options),
             _ => unreachable!(),
         },
+        (from_type, FixedSizeBinary(size))
+            if from_type.is_integer() && from_type.primitive_width() == Some(*size as usize) =>
+        {
+            match from_type {
+                UInt8 => cast_integer_to_fixed_size_binary::<UInt8Type>(array, cast_options),
+                UInt16 => cast_integer_to_fixed_size_binary::<UInt16Type>(array, cast_options),
+                UInt32 => cast_integer_to_fixed_size_binary::<UInt32Type>(array, cast_options),
+                UInt64 => cast_integer_to_fixed_size_binary::<UInt64Type>(array, cast_options),
+                Int8 => cast_integer_to_fixed_size_binary::<Int8Type>(array, cast_options),
+                Int16 => cast_integer_to_fixed_size_binary::<Int16Type>(array, cast_options),
+                Int32 => cast_integer_to_fixed_size_binary::<Int32Type>(array, cast_options),
+                Int64 => cast_integer_to_fixed_size_binary::<Int64Type>(array, cast_options),
+                _ => unreachable!(),
+            }
+        }
         // start numeric casts
         (UInt8, UInt16) => cast_numeric_arrays::<UInt8Type, UInt16Type>(array, cast_options),
         (UInt8, UInt32) => cast_numeric_arrays::<UInt8Type, UInt32Type>(array, cast_options),
@@ -1952,6 +2422,66 @@ pub fn cast_with_options(
     }
 }
 
+/// Casts `array` to a [`StructArray`] with `to_fields`
+///
+/// If [`CastOptions::match_struct_fields_by_name`], fields are matched by name: fields
+/// missing from `array` are filled with nulls, returning an error if they are not
+/// nullable, and fields of `array` missing from `to_fields` are dropped if
+/// [`CastOptions::safe`], otherwise returning an error. Otherwise fields are matched by
+/// position.
+fn cast_struct_to_struct(
+    array: &StructArray,
+    to_fields: &Fields,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let from_fields = array.fields();
+    let columns = match cast_options.match_struct_fields_by_name {
+        true => {
+            if !cast_options.safe {
+                if let Some(f) = from_fields
+                    .iter()
+                    .find(|f| to_fields.find(f.name()).is_none())
+                {
+                    return Err(ArrowError::CastError(format!(
+                        "Cannot cast struct field '{}' which is not present in the target type",
+                        f.name()
+                    )));
+                }
+            }
+            to_fields
+                .iter()
+                .map(|to| match from_fields.find(to.name()) {
+                    Some((idx, _)) => {
+                        cast_with_options(array.column(idx), to.data_type(), cast_options)
+                    }
+                    None if to.is_nullable() => Ok(new_null_array(to.data_type(), array.len())),
+                    None => Err(ArrowError::CastError(format!(
+                        "Cannot cast struct: non-nullable field '{}' is not present in the source",
+                        to.name()
+                    ))),
+                })
+                .collect::<Result<Vec<ArrayRef>, ArrowError>>()?
+        }
+        false => {
+            if from_fields.len() != to_fields.len() {
+                return Err(ArrowError::CastError(format!(
+                    "Cannot cast struct with {} fields to struct with {} fields",
+                    from_fields.len(),
+                    to_fields.len()
+                )));
+            }
+            array
+                .columns()
+                .iter()
+                .zip(to_fields.iter())
+                .map(|(l, field)| cast_with_options(l, field.data_type(), cast_options))
+                .collect::<Result<Vec<ArrayRef>, ArrowError>>()?
+        }
+    };
+    let array = StructArray::try_new(to_fields.clone(), columns, array.nulls().cloned())?;
+    Ok(Arc::new(array))
+}
+
 fn cast_from_decimal<D, F>(
     array: &dyn Array,
     base: D::Native,
@@ -2005,14 +2535,6 @@ fn cast_to_decimal<D, M>(
 where
     D: DecimalType + ArrowPrimitiveType<Native = M>,
     M: ArrowNativeTypeOp + DecimalCast,
-    u8: num::traits::AsPrimitive<M>,
-    u16: num::traits::AsPrimitive<M>,
-    u32: num::traits::AsPrimitive<M>,
-    u64: num::traits::AsPrimitive<M>,
-    i8: num::traits::AsPrimitive<M>,
-    i16: num::traits::AsPrimitive<M>,
-    i32: num::traits::AsPrimitive<M>,
-    i64: num::traits::AsPrimitive<M>,
 {
     use DataType::*;
     // cast data to decimal
@@ -2164,13 +2686,17 @@ where
 
 fn cast_numeric_to_binary<FROM: ArrowPrimitiveType, O: OffsetSizeTrait>(
     array: &dyn Array,
-) -> Result<ArrayRef, ArrowError> {
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError>
+where
+    FROM::Native: IntegerBytes,
+{
     let array = array.as_primitive::<FROM>();
     let size = std::mem::size_of::<FROM::Native>();
     let offsets = OffsetBuffer::from_lengths(std::iter::repeat_n(size, array.len()));
     Ok(Arc::new(GenericBinaryArray::<O>::new(
         offsets,
-        array.values().inner().clone(),
+        integer_bytes(array, cast_options.binary_endianness),
         array.nulls().cloned(),
     )))
 }
@@ -2426,7 +2952,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use arrow_buffer::{Buffer, IntervalDayTime, NullBuffer};
+    use arrow_buffer::{Buffer, IntervalDayTime, NullBuffer, ScalarBuffer};
     use chrono::NaiveDate;
     use half::f16;
 
@@ -2458,6 +2984,11 @@ mod tests {
             let cast_option = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                rounding_mode: RoundingMode::default(),
+                datetime_formats: None,
+                binary_endianness: Endianness::Little,
+                match_struct_fields_by_name: false,
+                strict_interval_parsing: false,
             };
             let result = cast_with_options($INPUT_ARRAY, $OUTPUT_TYPE, &cast_option).unwrap();
             assert_eq!($OUTPUT_TYPE, result.data_type());
@@ -2655,6 +3186,70 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_cast_decimal_to_decimal_rounding_mode() {
+        // 2.5, 3.5, -2.5, -2.7, 2.1, 2.0
+        let values = vec![25, 35, -25, -27, 21, 20];
+        let array = create_decimal128_array(values.into_iter().map(Some).collect(), 10, 1).unwrap();
+        let array256 = cast(&array, &DataType::Decimal256(10, 1)).unwrap();
+
+        let cases = [
+            (RoundingMode::HalfUp, [3, 4, -3, -3, 2, 2]),
+            (RoundingMode::HalfEven, [2, 4, -2, -3, 2, 2]),
+            (RoundingMode::Floor, [2, 3, -3, -3, 2, 2]),
+            (RoundingMode::Ceil, [3, 4, -2, -2, 3, 2]),
+            (RoundingMode::Truncate, [2, 3, -2, -2, 2, 2]),
+        ];
+        for (rounding_mode, expected) in cases {
+            let options = CastOptions {
+                rounding_mode,
+                ..Default::default()
+            };
+            for array in [&array as &dyn Array, &array256] {
+                let casted =
+                    cast_with_options(array, &DataType::Decimal128(10, 0), &options).unwrap();
+                assert_eq!(
+                    casted.as_primitive::<Decimal128Type>().values(),
+                    &expected,
+                    "{rounding_mode:?}"
+                );
+
+                let casted =
+                    cast_with_options(array, &DataType::Decimal256(10, 0), &options).unwrap();
+                let expected = expected.map(i256::from_i128);
+                assert_eq!(
+                    casted.as_primitive::<Decimal256Type>().values(),
+                    &expected,
+                    "{rounding_mode:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_cast_float_to_decimal_rounding_mode() {
+        let array = Float64Array::from(vec![0.25, 0.35, -0.25, -0.27, 0.21]);
+        let cases = [
+            (RoundingMode::HalfUp, [3, 4, -3, -3, 2]),
+            (RoundingMode::HalfEven, [2, 4, -2, -3, 2]),
+            (RoundingMode::Floor, [2, 3, -3, -3, 2]),
+            (RoundingMode::Ceil, [3, 4, -2, -2, 3]),
+            (RoundingMode::Truncate, [2, 3, -2, -2, 2]),
+        ];
+        for (rounding_mode, expected) in cases {
+            let options = CastOptions {
+                rounding_mode,
+                ..Default::default()
+            };
+            let casted = cast_with_options(&array, &DataType::Decimal128(10, 1), &options).unwrap();
+            assert_eq!(
+                casted.as_primitive::<Decimal128Type>().values(),
+                &expected,
+                "{rounding_mode:?}"
+            );
+        }
+    }
+
     #[test]
     fn test_cast_decimal128_to_decimal128() {
         let input_type = DataType::Decimal128(20, 3);
@@ -2734,6 +3329,11 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                rounding_mode: RoundingMode::default(),
+                datetime_formats: None,
+                binary_endianness: Endianness::Little,
+                match_struct_fields_by_name: false,
+                strict_interval_parsing: false,
             },
         );
         assert_eq!("Cast error: Cannot cast to Decimal128(38, 38). Overflowing on 170141183460469231731687303715884105727",
@@ -2754,6 +3354,11 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                rounding_mode: RoundingMode::default(),
+                datetime_formats: None,
+                binary_endianness: Endianness::Little,
+                match_struct_fields_by_name: false,
+                strict_interval_parsing: false,
             },
         );
         assert_eq!("Cast error: Cannot cast to Decimal256(76, 76). Overflowing on 170141183460469231731687303715884105727",
@@ -2780,6 +3385,107 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_cast_decimal32_to_decimal64() {
+        let array = Decimal32Array::from(vec![Some(1123456), Some(-2123456), None])
+            .with_precision_and_scale(9, 3)
+            .unwrap();
+        generate_cast_test_case!(
+            &array,
+            Decimal64Array,
+            &DataType::Decimal64(12, 4),
+            vec![Some(11234560_i64), Some(-21234560_i64), None]
+        );
+        generate_cast_test_case!(
+            &array,
+            Decimal128Array,
+            &DataType::Decimal128(10, 2),
+            vec![Some(112346_i128), Some(-212346_i128), None]
+        );
+        generate_cast_test_case!(
+            &array,
+            Decimal32Array,
+            &DataType::Decimal32(9, 1),
+            vec![Some(11235_i32), Some(-21235_i32), None]
+        );
+    }
+
+    #[test]
+    fn test_cast_decimal128_to_decimal32_overflow() {
+        let array = Decimal128Array::from(vec![Some(12345678901), Some(1)])
+            .with_precision_and_scale(20, 0)
+            .unwrap();
+        let output_type = DataType::Decimal32(9, 0);
+        assert!(can_cast_types(array.data_type(), &output_type));
+
+        let result = cast(&array, &output_type).unwrap();
+        let result = result.as_primitive::<Decimal32Type>();
+        assert!(result.is_null(0));
+        assert_eq!(result.value(1), 1);
+
+        let options = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        let err = cast_with_options(&array, &output_type, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast to Decimal32(9, 0). Overflowing on 12345678901"
+        );
+    }
+
+    #[test]
+    fn test_cast_decimal32_and_decimal64_to_and_from_numeric() {
+        let ints = Int64Array::from(vec![Some(1), Some(-2), Some(i64::MAX), None]);
+        let result = cast(&ints, &DataType::Decimal32(9, 2)).unwrap();
+        let expected = Decimal32Array::from(vec![Some(100), Some(-200), None, None])
+            .with_precision_and_scale(9, 2)
+            .unwrap();
+        assert_eq!(result.as_primitive::<Decimal32Type>(), &expected);
+
+        let options = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        let err = cast_with_options(&ints, &DataType::Decimal32(9, 2), &options).unwrap_err();
+        assert!(err.to_string().contains("Overflowing"), "{err}");
+
+        let result = cast(&expected, &DataType::Int16).unwrap();
+        let expected_ints = Int16Array::from(vec![Some(1), Some(-2), None, None]);
+        assert_eq!(result.as_primitive::<Int16Type>(), &expected_ints);
+
+        let floats = Float64Array::from(vec![Some(1.125), Some(-0.5), None]);
+        let result = cast(&floats, &DataType::Decimal64(18, 2)).unwrap();
+        let decimals = Decimal64Array::from(vec![Some(113), Some(-50), None])
+            .with_precision_and_scale(18, 2)
+            .unwrap();
+        assert_eq!(result.as_primitive::<Decimal64Type>(), &decimals);
+
+        let result = cast(&decimals, &DataType::Float32).unwrap();
+        let expected = Float32Array::from(vec![Some(1.13), Some(-0.5), None]);
+        assert_eq!(result.as_primitive::<Float32Type>(), &expected);
+    }
+
+    #[test]
+    fn test_cast_decimal32_and_decimal64_to_and_from_string() {
+        let strings = StringArray::from(vec![Some("1.235"), Some("-12"), Some("abc"), None]);
+        let result = cast(&strings, &DataType::Decimal32(5, 2)).unwrap();
+        let expected = Decimal32Array::from(vec![Some(124), Some(-1200), None, None])
+            .with_precision_and_scale(5, 2)
+            .unwrap();
+        assert_eq!(result.as_primitive::<Decimal32Type>(), &expected);
+
+        let result = cast(&expected, &DataType::Utf8).unwrap();
+        let expected = StringArray::from(vec![Some("1.24"), Some("-12.00"), None, None]);
+        assert_eq!(result.as_string::<i32>(), &expected);
+
+        let result = cast(&strings, &DataType::Decimal64(18, 1)).unwrap();
+        let expected = Decimal64Array::from(vec![Some(12), Some(-120), None, None])
+            .with_precision_and_scale(18, 1)
+            .unwrap();
+        assert_eq!(result.as_primitive::<Decimal64Type>(), &expected);
+    }
+
     #[test]
     fn test_cast_decimal256_to_decimal128_overflow() {
         let input_type = DataType::Decimal256(76, 5);
@@ -2793,6 +3499,11 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                rounding_mode: RoundingMode::default(),
+                datetime_formats: None,
+                binary_endianness: Endianness::Little,
+                match_struct_fields_by_name: false,
+                strict_interval_parsing: false,
             },
         );
         assert_eq!("Cast error: Cannot cast to Decimal128(38, 7). Overflowing on 170141183460469231731687303715884105727",
@@ -2812,6 +3523,11 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                rounding_mode: RoundingMode::default(),
+                datetime_formats: None,
+                binary_endianness: Endianness::Little,
+                match_struct_fields_by_name: false,
+                strict_interval_parsing: false,
             },
         );
         assert_eq!("Cast error: Cannot cast to Decimal256(76, 55). Overflowing on 170141183460469231731687303715884105727",
@@ -2972,6 +3688,11 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                rounding_mode: RoundingMode::default(),
+                datetime_formats: None,
+                binary_endianness: Endianness::Little,
+                match_struct_fields_by_name: false,
+                strict_interval_parsing: false,
             },
         );
         assert_eq!(
@@ -2985,6 +3706,11 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                rounding_mode: RoundingMode::default(),
+                datetime_formats: None,
+                binary_endianness: Endianness::Little,
+                match_struct_fields_by_name: false,
+                strict_interval_parsing: false,
             },
         );
         assert!(casted_array.is_ok());
@@ -2999,6 +3725,11 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                rounding_mode: RoundingMode::default(),
+                datetime_formats: None,
+                binary_endianness: Endianness::Little,
+                match_struct_fields_by_name: false,
+                strict_interval_parsing: false,
             },
         );
         assert_eq!(
@@ -3012,6 +3743,11 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                rounding_mode: RoundingMode::default(),
+                datetime_formats: None,
+                binary_endianness: Endianness::Little,
+                match_struct_fields_by_name: false,
+                strict_interval_parsing: false,
             },
         );
         assert!(casted_array.is_ok());
@@ -3175,6 +3911,11 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                rounding_mode: RoundingMode::default(),
+                datetime_formats: None,
+                binary_endianness: Endianness::Little,
+                match_struct_fields_by_name: false,
+                strict_interval_parsing: false,
             },
         );
         assert_eq!(
@@ -3188,6 +3929,11 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                rounding_mode: RoundingMode::default(),
+                datetime_formats: None,
+                binary_endianness: Endianness::Little,
+                match_struct_fields_by_name: false,
+                strict_interval_parsing: false,
             },
         );
         assert!(casted_array.is_ok());
@@ -3605,6 +4351,11 @@ mod tests {
         let cast_option = CastOptions {
             safe: false,
             format_options: FormatOptions::default(),
+            rounding_mode: RoundingMode::default(),
+            datetime_formats: None,
+            binary_endianness: Endianness::Little,
+            match_struct_fields_by_name: false,
+            strict_interval_parsing: false,
         };
         let result = cast_with_options(&array, &DataType::UInt8, &cast_option);
         assert!(result.is_err());
@@ -3817,6 +4568,11 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                rounding_mode: RoundingMode::default(),
+                datetime_formats: None,
+                binary_endianness: Endianness::Little,
+                match_struct_fields_by_name: false,
+                strict_interval_parsing: false,
             },
         );
         match result {
@@ -3856,6 +4612,11 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                rounding_mode: RoundingMode::default(),
+                datetime_formats: None,
+                binary_endianness: Endianness::Little,
+                match_struct_fields_by_name: false,
+                strict_interval_parsing: false,
             },
         );
         match casted {
@@ -4199,6 +4960,11 @@ mod tests {
         let options = CastOptions {
             safe: true,
             format_options: FormatOptions::default(),
+            rounding_mode: RoundingMode::default(),
+            datetime_formats: None,
+            binary_endianness: Endianness::Little,
+            match_struct_fields_by_name: false,
+            strict_interval_parsing: false,
         };
         let res = cast_with_options(&str, &DataType::Int16, &options).expect("should cast to i16");
         let expected =
@@ -4272,6 +5038,11 @@ mod tests {
                 let options = CastOptions {
                     safe: false,
                     format_options: FormatOptions::default(),
+                    rounding_mode: RoundingMode::default(),
+                    datetime_formats: None,
+                    binary_endianness: Endianness::Little,
+                    match_struct_fields_by_name: false,
+                    strict_interval_parsing: false,
                 };
                 let err = cast_with_options(array, &to_type, &options).unwrap_err();
                 assert_eq!(
@@ -4290,6 +5061,62 @@ mod tests {
         assert_eq!(result.values(), &[247112596800]);
     }
 
+    #[test]
+    fn test_cast_string_with_datetime_formats() {
+        let formats = ["%d/%m/%Y %H:%M", "auto", "%Y%m%d"];
+        let options = CastOptions {
+            datetime_formats: Some(&formats),
+            ..Default::default()
+        };
+        let values = vec![
+            Some("31/01/2020 10:30"),
+            Some("2020-01-31T10:30:00Z"),
+            Some("20200131"),
+            Some("01-31-2020"),
+            None,
+        ];
+
+        let to_type = DataType::Timestamp(TimeUnit::Second, None);
+        let expected = [1580466600, 1580466600, 1580428800, 0, 0];
+        for array in [
+            Arc::new(StringArray::from(values.clone())) as ArrayRef,
+            Arc::new(LargeStringArray::from(values.clone())),
+            Arc::new(StringViewArray::from(values.clone())),
+        ] {
+            let casted = cast_with_options(&array, &to_type, &options).unwrap();
+            let casted = casted.as_primitive::<TimestampSecondType>();
+            assert_eq!(casted.values(), &expected);
+            assert_eq!(casted.logical_nulls().unwrap().null_count(), 2);
+
+            let casted = cast_with_options(&array, &DataType::Date32, &options).unwrap();
+            let casted = casted.as_primitive::<Date32Type>();
+            assert_eq!(&casted.values()[..3], &[18292, 18292, 18292]);
+            assert_eq!(casted.null_count(), 2);
+        }
+
+        // timestamps may specify their offset
+        let formats = ["%d/%m/%Y %H:%M %z"];
+        let options = CastOptions {
+            safe: false,
+            datetime_formats: Some(&formats),
+            ..Default::default()
+        };
+        let array = StringArray::from(vec!["31/01/2020 15:30 +0500"]);
+        let tz_type = DataType::Timestamp(TimeUnit::Second, Some("+01:00".into()));
+        let casted = cast_with_options(&array, &tz_type, &options).unwrap();
+        assert_eq!(
+            casted.as_primitive::<TimestampSecondType>().value(0),
+            1580466600
+        );
+
+        let array = StringArray::from(vec!["2020-01-31"]);
+        let err = cast_with_options(&array, &to_type, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"Parser error: Error parsing timestamp from '2020-01-31': no matching format in ["%d/%m/%Y %H:%M %z"]"#
+        );
+    }
+
     #[test]
     fn test_cast_string_to_date32() {
         let a0 = Arc::new(StringViewArray::from(vec![
@@ -4318,6 +5145,11 @@ mod tests {
             let options = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                rounding_mode: RoundingMode::default(),
+                datetime_formats: None,
+                binary_endianness: Endianness::Little,
+                match_struct_fields_by_name: false,
+                strict_interval_parsing: false,
             };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
             assert_eq!(
@@ -4341,6 +5173,11 @@ mod tests {
         let options = CastOptions {
             safe: false,
             format_options: FormatOptions::default(),
+            rounding_mode: RoundingMode::default(),
+            datetime_formats: None,
+            binary_endianness: Endianness::Little,
+            match_struct_fields_by_name: false,
+            strict_interval_parsing: false,
         };
         let b = cast_with_options(&array, &to_type, &options).unwrap();
         let c = b.as_primitive::<Date32Type>();
@@ -4361,6 +5198,11 @@ mod tests {
         let options = CastOptions {
             safe: false,
             format_options: FormatOptions::default(),
+            rounding_mode: RoundingMode::default(),
+            datetime_formats: None,
+            binary_endianness: Endianness::Little,
+            match_struct_fields_by_name: false,
+            strict_interval_parsing: false,
         };
         let err = cast_with_options(&array, &to_type, &options).unwrap_err();
         assert_eq!(
@@ -4389,6 +5231,11 @@ mod tests {
             let options = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                rounding_mode: RoundingMode::default(),
+                datetime_formats: None,
+                binary_endianness: Endianness::Little,
+                match_struct_fields_by_name: false,
+                strict_interval_parsing: false,
             };
             let result = cast_with_options(&array, &to_type, &options).unwrap();
             let c = result.as_primitive::<Date32Type>();
@@ -4439,6 +5286,11 @@ mod tests {
             let options = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                rounding_mode: RoundingMode::default(),
+                datetime_formats: None,
+                binary_endianness: Endianness::Little,
+                match_struct_fields_by_name: false,
+                strict_interval_parsing: false,
             };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
             assert_eq!(err.to_string(), "Cast error: Cannot cast string '08:08:61.091323414' to value of Time32(Second) type");
@@ -4481,6 +5333,11 @@ mod tests {
             let options = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                rounding_mode: RoundingMode::default(),
+                datetime_formats: None,
+                binary_endianness: Endianness::Little,
+                match_struct_fields_by_name: false,
+                strict_interval_parsing: false,
             };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
             assert_eq!(err.to_string(), "Cast error: Cannot cast string '08:08:61.091323414' to value of Time32(Millisecond) type");
@@ -4515,6 +5372,11 @@ mod tests {
             let options = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                rounding_mode: RoundingMode::default(),
+                datetime_formats: None,
+                binary_endianness: Endianness::Little,
+                match_struct_fields_by_name: false,
+                strict_interval_parsing: false,
             };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
             assert_eq!(err.to_string(), "Cast error: Cannot cast string 'Not a valid time' to value of Time64(Microsecond) type");
@@ -4549,6 +5411,11 @@ mod tests {
             let options = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                rounding_mode: RoundingMode::default(),
+                datetime_formats: None,
+                binary_endianness: Endianness::Little,
+                match_struct_fields_by_name: false,
+                strict_interval_parsing: false,
             };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
             assert_eq!(err.to_string(), "Cast error: Cannot cast string 'Not a valid time' to value of Time64(Nanosecond) type");
@@ -4583,6 +5450,11 @@ mod tests {
             let options = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                rounding_mode: RoundingMode::default(),
+                datetime_formats: None,
+                binary_endianness: Endianness::Little,
+                match_struct_fields_by_name: false,
+                strict_interval_parsing: false,
             };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
             assert_eq!(
@@ -4599,6 +5471,11 @@ mod tests {
             let options = CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                rounding_mode: RoundingMode::default(),
+                datetime_formats: None,
+                binary_endianness: Endianness::Little,
+                match_struct_fields_by_name: false,
+                strict_interval_parsing: false,
             };
 
             let target_interval_array = cast_with_options(
@@ -4720,12 +5597,75 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_cast_string_to_interval_iso8601_and_sql_standard() {
+        test_safe_string_to_interval!(
+            vec![
+                Some("P1Y2M3DT4H5M6S"),
+                Some("-P2W"),
+                Some("1-2 3 4:05:06.5"),
+                Some("1 day 12:00"),
+                Some("P1H"),
+                Some("1-13"),
+            ],
+            IntervalUnit::MonthDayNano,
+            IntervalMonthDayNanoArray,
+            vec![
+                Some("14 mons 3 days 4 hours 5 mins 6.000000000 secs"),
+                Some("-14 days"),
+                Some("14 mons 3 days 4 hours 5 mins 6.500000000 secs"),
+                Some("1 days 12 hours"),
+                None,
+                None,
+            ]
+        );
+
+        let array = StringViewArray::from(vec!["P1Y2M", "2-3"]);
+        let casted = cast(&array, &DataType::Interval(IntervalUnit::YearMonth)).unwrap();
+        let casted = casted.as_primitive::<IntervalYearMonthType>();
+        assert_eq!(casted.values(), &[14, 27]);
+    }
+
+    #[test]
+    fn test_cast_string_to_interval_strict() {
+        let array = StringArray::from(vec!["3", "3 days", "1 day 4:05:06"]);
+        for unit in [IntervalUnit::DayTime, IntervalUnit::MonthDayNano] {
+            let to_type = DataType::Interval(unit);
+            let casted = cast(&array, &to_type).unwrap();
+            assert_eq!(casted.null_count(), 0);
+
+            let options = CastOptions::default().with_strict_interval_parsing(true);
+            let casted = cast_with_options(&array, &to_type, &options).unwrap();
+            assert_eq!(casted.logical_nulls().unwrap().null_count(), 2);
+            assert!(casted.is_valid(1));
+
+            let options = options.with_safe(false);
+            let err = cast_with_options(&array, &to_type, &options).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "Parser error: Invalid input syntax for type interval: \"3\""
+            );
+        }
+
+        let array = StringViewArray::from(vec!["3", "3 years"]);
+        let options = CastOptions::default().with_strict_interval_parsing(true);
+        let to_type = DataType::Interval(IntervalUnit::YearMonth);
+        let casted = cast_with_options(&array, &to_type, &options).unwrap();
+        assert!(casted.is_null(0));
+        assert_eq!(casted.as_primitive::<IntervalYearMonthType>().value(1), 36);
+    }
+
     macro_rules! test_unsafe_string_to_interval_err {
         ($data_vec:expr, $interval_unit:expr, $error_msg:expr) => {
             let string_array = Arc::new(StringArray::from($data_vec.clone())) as ArrayRef;
             let options = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                rounding_mode: RoundingMode::default(),
+                datetime_formats: None,
+                binary_endianness: Endianness::Little,
+                match_struct_fields_by_name: false,
+                strict_interval_parsing: false,
             };
             let arrow_err = cast_with_options(
                 &string_array.clone(),
@@ -4835,6 +5775,11 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                rounding_mode: RoundingMode::default(),
+                datetime_formats: None,
+                binary_endianness: Endianness::Little,
+                match_struct_fields_by_name: false,
+                strict_interval_parsing: false,
             },
         );
         assert!(array_ref.is_err());
@@ -4845,6 +5790,11 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                rounding_mode: RoundingMode::default(),
+                datetime_formats: None,
+                binary_endianness: Endianness::Little,
+                match_struct_fields_by_name: false,
+                strict_interval_parsing: false,
             },
         );
         assert!(array_ref.is_err());
@@ -4976,6 +5926,111 @@ mod tests {
         assert!(down_cast.is_null(2));
     }
 
+    #[test]
+    fn test_binary_to_integer_endianness() {
+        let a = Int32Array::from(vec![Some(1), Some(-2), None]);
+        let big = CastOptions {
+            binary_endianness: Endianness::Big,
+            ..Default::default()
+        };
+
+        let b = cast_with_options(&a, &DataType::Binary, &big).unwrap();
+        let binary = b.as_binary::<i32>();
+        assert_eq!(binary.value(0), &[0, 0, 0, 1]);
+        assert_eq!(binary.value(1), &(-2_i32).to_be_bytes());
+        assert!(binary.is_null(2));
+
+        let c = cast_with_options(&b, &DataType::Int32, &big).unwrap();
+        assert_eq!(c.as_ref(), &a);
+
+        // Reading big endian bytes as little endian
+        let c = cast(&b, &DataType::UInt32).unwrap();
+        assert_eq!(c.as_primitive::<UInt32Type>().value(0), 1 << 24);
+
+        let b = cast(&a, &DataType::FixedSizeBinary(4)).unwrap();
+        assert_eq!(b.as_fixed_size_binary().value(0), &[1, 0, 0, 0]);
+        let c = cast(&b, &DataType::Int32).unwrap();
+        assert_eq!(c.as_ref(), &a);
+
+        assert!(can_cast_types(
+            &DataType::Int64,
+            &DataType::FixedSizeBinary(8)
+        ));
+        assert!(!can_cast_types(
+            &DataType::Int64,
+            &DataType::FixedSizeBinary(4)
+        ));
+        assert!(can_cast_types(&DataType::BinaryView, &DataType::UInt16));
+    }
+
+    #[test]
+    fn test_binary_to_integer_wrong_length() {
+        let a = BinaryArray::from_iter([Some(&[1_u8, 0][..]), Some(&[1, 2, 3]), None]);
+        let b = cast(&a, &DataType::Int16).unwrap();
+        let b = b.as_primitive::<Int16Type>();
+        assert_eq!(b.value(0), 1);
+        assert!(b.is_null(1));
+        assert!(b.is_null(2));
+
+        let options = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        let err = cast_with_options(&a, &DataType::Int16, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast binary value of 3 bytes to Int16, expected 2 bytes"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "canonical_extension_types")]
+    fn test_cast_uuid_string() {
+        let uuid = Field::new("u", DataType::FixedSizeBinary(16), true)
+            .with_extension_type(extension::Uuid);
+        let string = Field::new("s", DataType::Utf8, true);
+        let a = StringArray::from(vec![
+            Some("67e55044-10b1-426f-9247-bb680e5fe0c8"),
+            Some("67E5504410B1426F9247BB680E5FE0C8"),
+            Some("not a uuid"),
+            None,
+        ]);
+        assert!(can_cast_fields(&string, &uuid));
+        let b = cast_field(&a, &string, &uuid, &CastOptions::default()).unwrap();
+        let uuids = b.as_fixed_size_binary();
+        assert_eq!(uuids.value(0)[..4], [0x67, 0xe5, 0x50, 0x44]);
+        assert_eq!(uuids.value(0), uuids.value(1));
+        assert!(uuids.is_null(2));
+        assert!(uuids.is_null(3));
+
+        for to_type in [DataType::Utf8, DataType::LargeUtf8, DataType::Utf8View] {
+            let to = Field::new("s", to_type, true);
+            assert!(can_cast_fields(&uuid, &to));
+            let c = cast_field(&b, &uuid, &to, &CastOptions::default()).unwrap();
+            let c = cast(&c, &DataType::Utf8).unwrap();
+            let c = c.as_string::<i32>();
+            assert_eq!(c.value(0), "67e55044-10b1-426f-9247-bb680e5fe0c8");
+            assert_eq!(c.value(1), "67e55044-10b1-426f-9247-bb680e5fe0c8");
+            assert!(c.is_null(2));
+        }
+
+        let options = CastOptions::default().with_safe(false);
+        let err = cast_field(&a, &string, &uuid, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast string 'not a uuid' to UUID"
+        );
+
+        // Without the extension type, FixedSizeBinary(16) is not a UUID
+        let binary = Field::new("b", DataType::FixedSizeBinary(16), true);
+        assert!(!can_cast_fields(&binary, &string));
+        assert!(!can_cast_types(
+            &DataType::FixedSizeBinary(16),
+            &DataType::Utf8
+        ));
+        assert!(cast_field(&b, &binary, &string, &options).is_err());
+    }
+
     #[test]
     fn test_cast_date32_to_int32() {
         let array = Date32Array::from(vec![10000, 17890]);
@@ -5050,6 +6105,11 @@ mod tests {
         let options = CastOptions {
             safe: false,
             format_options: FormatOptions::default(),
+            rounding_mode: RoundingMode::default(),
+            datetime_formats: None,
+            binary_endianness: Endianness::Little,
+            match_struct_fields_by_name: false,
+            strict_interval_parsing: false,
         };
         let b = cast_with_options(&array, &DataType::Date64, &options);
         assert!(b.is_err());
@@ -5598,6 +6658,11 @@ mod tests {
             format_options: FormatOptions::default()
                 .with_timestamp_format(Some(ts_format))
                 .with_timestamp_tz_format(Some(ts_format)),
+            rounding_mode: RoundingMode::default(),
+            datetime_formats: None,
+            binary_endianness: Endianness::Little,
+            match_struct_fields_by_name: false,
+            strict_interval_parsing: false,
         };
 
         // "2018-12-25T00:00:02.001", "1997-05-19T00:00:03.005", None
@@ -7841,6 +8906,60 @@ mod tests {
         assert_eq!(&expected.value(2), &actual.value(2));
     }
 
+    #[test]
+    fn test_cast_list_view() {
+        let field = Arc::new(Field::new_list_field(DataType::Int32, true));
+        // [[3, 4], null, [1], [1, 2, 3]]
+        let list_view = Arc::new(ListViewArray::new(
+            field,
+            vec![2, 1, 0, 0].into(),
+            vec![2, 2, 1, 3].into(),
+            Arc::new(Int32Array::from(vec![1, 2, 3, 4])),
+            Some(NullBuffer::from(vec![true, false, true, true])),
+        )) as ArrayRef;
+
+        let to_field = Arc::new(Field::new_list_field(DataType::Int64, true));
+        let expected = LargeListArray::from_iter_primitive::<Int64Type, _, _>([
+            Some(vec![Some(3), Some(4)]),
+            None,
+            Some(vec![Some(1)]),
+            Some(vec![Some(1), Some(2), Some(3)]),
+        ]);
+
+        for to_type in [
+            DataType::ListView(to_field.clone()),
+            DataType::LargeListView(to_field.clone()),
+            DataType::List(to_field.clone()),
+            DataType::LargeList(to_field.clone()),
+        ] {
+            assert!(can_cast_types(list_view.data_type(), &to_type));
+            let casted = cast(&list_view, &to_type).unwrap();
+            assert_eq!(casted.data_type(), &to_type);
+
+            // Round trip back through a large list to compare the logical values
+            let large = cast(&casted, expected.data_type()).unwrap();
+            assert_eq!(large.as_list::<i64>(), &expected);
+
+            let back = cast(&casted, list_view.data_type()).unwrap();
+            assert_eq!(back.len(), 4);
+            let large = cast(&back, expected.data_type()).unwrap();
+            assert_eq!(large.as_list::<i64>(), &expected);
+        }
+
+        // Lists are copied compactly, omitting unreferenced values
+        let list = cast(&list_view, &DataType::List(to_field)).unwrap();
+        assert_eq!(list.as_list::<i32>().values().len(), 6);
+
+        assert!(!can_cast_types(list_view.data_type(), &DataType::Utf8));
+        assert!(!can_cast_types(
+            list_view.data_type(),
+            &DataType::ListView(Arc::new(Field::new_list_field(
+                DataType::Struct(Fields::empty()),
+                true
+            )))
+        ));
+    }
+
     #[test]
     fn test_cast_list_to_fsl() {
         // There four noteworthy cases we should handle:
@@ -8495,6 +9614,11 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                rounding_mode: RoundingMode::default(),
+                datetime_formats: None,
+                binary_endianness: Endianness::Little,
+                match_struct_fields_by_name: false,
+                strict_interval_parsing: false,
             },
         );
         assert!(casted_array.is_ok());
@@ -8506,6 +9630,11 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                rounding_mode: RoundingMode::default(),
+                datetime_formats: None,
+                binary_endianness: Endianness::Little,
+                match_struct_fields_by_name: false,
+                strict_interval_parsing: false,
             },
         );
         assert!(casted_array.is_err());
@@ -8521,6 +9650,11 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                rounding_mode: RoundingMode::default(),
+                datetime_formats: None,
+                binary_endianness: Endianness::Little,
+                match_struct_fields_by_name: false,
+                strict_interval_parsing: false,
             },
         );
         assert!(casted_array.is_ok());
@@ -8532,6 +9666,11 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                rounding_mode: RoundingMode::default(),
+                datetime_formats: None,
+                binary_endianness: Endianness::Little,
+                match_struct_fields_by_name: false,
+                strict_interval_parsing: false,
             },
         );
         assert!(casted_array.is_err());
@@ -8547,6 +9686,11 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                rounding_mode: RoundingMode::default(),
+                datetime_formats: None,
+                binary_endianness: Endianness::Little,
+                match_struct_fields_by_name: false,
+                strict_interval_parsing: false,
             },
         );
         assert!(casted_array.is_ok());
@@ -8558,6 +9702,11 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                rounding_mode: RoundingMode::default(),
+                datetime_formats: None,
+                binary_endianness: Endianness::Little,
+                match_struct_fields_by_name: false,
+                strict_interval_parsing: false,
             },
         );
         let err = casted_array.unwrap_err().to_string();
@@ -8578,6 +9727,11 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                rounding_mode: RoundingMode::default(),
+                datetime_formats: None,
+                binary_endianness: Endianness::Little,
+                match_struct_fields_by_name: false,
+                strict_interval_parsing: false,
             },
         );
         assert!(casted_array.is_ok());
@@ -8589,6 +9743,11 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                rounding_mode: RoundingMode::default(),
+                datetime_formats: None,
+                binary_endianness: Endianness::Little,
+                match_struct_fields_by_name: false,
+                strict_interval_parsing: false,
             },
         );
         let err = casted_array.unwrap_err().to_string();
@@ -8609,6 +9768,11 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                rounding_mode: RoundingMode::default(),
+                datetime_formats: None,
+                binary_endianness: Endianness::Little,
+                match_struct_fields_by_name: false,
+                strict_interval_parsing: false,
             },
         );
         assert!(casted_array.is_ok());
@@ -8620,6 +9784,11 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                rounding_mode: RoundingMode::default(),
+                datetime_formats: None,
+                binary_endianness: Endianness::Little,
+                match_struct_fields_by_name: false,
+                strict_interval_parsing: false,
             },
         );
         let err = casted_array.unwrap_err().to_string();
@@ -8640,6 +9809,11 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                rounding_mode: RoundingMode::default(),
+                datetime_formats: None,
+                binary_endianness: Endianness::Little,
+                match_struct_fields_by_name: false,
+                strict_interval_parsing: false,
             },
         );
         assert!(casted_array.is_ok());
@@ -8651,6 +9825,11 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                rounding_mode: RoundingMode::default(),
+                datetime_formats: None,
+                binary_endianness: Endianness::Little,
+                match_struct_fields_by_name: false,
+                strict_interval_parsing: false,
             },
         );
         let err = casted_array.unwrap_err().to_string();
@@ -9115,6 +10294,11 @@ mod tests {
         let option = CastOptions {
             safe: false,
             format_options: FormatOptions::default(),
+            rounding_mode: RoundingMode::default(),
+            datetime_formats: None,
+            binary_endianness: Endianness::Little,
+            match_struct_fields_by_name: false,
+            strict_interval_parsing: false,
         };
         let casted_err = cast_with_options(&array, &output_type, &option).unwrap_err();
         assert!(casted_err
@@ -9157,6 +10341,11 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                rounding_mode: RoundingMode::default(),
+                datetime_formats: None,
+                binary_endianness: Endianness::Little,
+                match_struct_fields_by_name: false,
+                strict_interval_parsing: false,
             },
         );
         assert!(casted_array.is_ok());
@@ -9168,6 +10357,11 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                rounding_mode: RoundingMode::default(),
+                datetime_formats: None,
+                binary_endianness: Endianness::Little,
+                match_struct_fields_by_name: false,
+                strict_interval_parsing: false,
             },
         );
         assert_eq!("Invalid argument error: 100000000000 is too large to store in a Decimal128 of precision 10. Max is 9999999999", err.unwrap_err().to_string());
@@ -9240,6 +10434,11 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                rounding_mode: RoundingMode::default(),
+                datetime_formats: None,
+                binary_endianness: Endianness::Little,
+                match_struct_fields_by_name: false,
+                strict_interval_parsing: false,
             },
         );
         assert!(casted_array.is_ok());
@@ -9251,6 +10450,11 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                rounding_mode: RoundingMode::default(),
+                datetime_formats: None,
+                binary_endianness: Endianness::Little,
+                match_struct_fields_by_name: false,
+                strict_interval_parsing: false,
             },
         );
         assert_eq!("Invalid argument error: 100000000000 is too large to store in a Decimal256 of precision 10. Max is 9999999999", err.unwrap_err().to_string());
@@ -9297,6 +10501,11 @@ mod tests {
         let cast_options = CastOptions {
             safe: false,
             format_options: FormatOptions::default(),
+            rounding_mode: RoundingMode::default(),
+            datetime_formats: None,
+            binary_endianness: Endianness::Little,
+            match_struct_fields_by_name: false,
+            strict_interval_parsing: false,
         };
 
         let result = cast_string_to_timestamp::<i32, TimestampNanosecondType>(
@@ -9425,6 +10634,11 @@ mod tests {
                 &CastOptions {
                     safe: false,
                     format_options: FormatOptions::default(),
+                    rounding_mode: RoundingMode::default(),
+                    datetime_formats: None,
+                    binary_endianness: Endianness::Little,
+                    match_struct_fields_by_name: false,
+                    strict_interval_parsing: false,
                 },
             )
             .unwrap();
@@ -9476,6 +10690,11 @@ mod tests {
         let options = CastOptions {
             safe: true,
             format_options: FormatOptions::default(),
+            rounding_mode: RoundingMode::default(),
+            datetime_formats: None,
+            binary_endianness: Endianness::Little,
+            match_struct_fields_by_name: false,
+            strict_interval_parsing: false,
         };
         let array = cast_with_options(&s, &DataType::Utf8, &options).unwrap();
         let a = array.as_string::<i32>();
@@ -9608,6 +10827,11 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                rounding_mode: RoundingMode::default(),
+                datetime_formats: None,
+                binary_endianness: Endianness::Little,
+                match_struct_fields_by_name: false,
+                strict_interval_parsing: false,
             },
         );
         assert!(casted_array.is_ok());
@@ -9619,6 +10843,11 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                rounding_mode: RoundingMode::default(),
+                datetime_formats: None,
+                binary_endianness: Endianness::Little,
+                match_struct_fields_by_name: false,
+                strict_interval_parsing: false,
             },
         );
         assert_eq!("Invalid argument error: 1234567000 is too large to store in a Decimal128 of precision 7. Max is 9999999", err.unwrap_err().to_string());
@@ -9634,6 +10863,11 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                rounding_mode: RoundingMode::default(),
+                datetime_formats: None,
+                binary_endianness: Endianness::Little,
+                match_struct_fields_by_name: false,
+                strict_interval_parsing: false,
             },
         );
         assert!(casted_array.is_ok());
@@ -9645,6 +10879,11 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                rounding_mode: RoundingMode::default(),
+                datetime_formats: None,
+                binary_endianness: Endianness::Little,
+                match_struct_fields_by_name: false,
+                strict_interval_parsing: false,
             },
         );
         assert_eq!("Invalid argument error: 1234567000 is too large to store in a Decimal256 of precision 7. Max is 9999999", err.unwrap_err().to_string());
@@ -9692,6 +10931,11 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                rounding_mode: RoundingMode::default(),
+                datetime_formats: None,
+                binary_endianness: Endianness::Little,
+                match_struct_fields_by_name: false,
+                strict_interval_parsing: false,
             },
         );
         assert!(casted_array.is_err());
@@ -9725,6 +10969,11 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                rounding_mode: RoundingMode::default(),
+                datetime_formats: None,
+                binary_endianness: Endianness::Little,
+                match_struct_fields_by_name: false,
+                strict_interval_parsing: false,
             },
         );
         assert!(casted_array.is_err());
@@ -9758,6 +11007,11 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                rounding_mode: RoundingMode::default(),
+                datetime_formats: None,
+                binary_endianness: Endianness::Little,
+                match_struct_fields_by_name: false,
+                strict_interval_parsing: false,
             },
         );
         assert!(casted_array.is_err());
@@ -9784,6 +11038,11 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                rounding_mode: RoundingMode::default(),
+                datetime_formats: None,
+                binary_endianness: Endianness::Little,
+                match_struct_fields_by_name: false,
+                strict_interval_parsing: false,
             },
         )
         .unwrap();
@@ -9814,6 +11073,11 @@ mod tests {
         let fallible = CastOptions {
             safe: false,
             format_options: FormatOptions::default(),
+            rounding_mode: RoundingMode::default(),
+            datetime_formats: None,
+            binary_endianness: Endianness::Little,
+            match_struct_fields_by_name: false,
+            strict_interval_parsing: false,
         };
         let v = IntervalMonthDayNano::new(0, 0, 1234567);
 
@@ -9981,6 +11245,11 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                rounding_mode: RoundingMode::default(),
+                datetime_formats: None,
+                binary_endianness: Endianness::Little,
+                match_struct_fields_by_name: false,
+                strict_interval_parsing: false,
             },
         )
         .unwrap();
@@ -10042,6 +11311,11 @@ mod tests {
     const CAST_OPTIONS: CastOptions<'static> = CastOptions {
         safe: true,
         format_options: FormatOptions::new(),
+        rounding_mode: RoundingMode::HalfUp,
+        datetime_formats: None,
+        binary_endianness: Endianness::Little,
+        match_struct_fields_by_name: false,
+        strict_interval_parsing: false,
     };
 
     #[test]
@@ -10055,6 +11329,11 @@ mod tests {
         let options = CastOptions {
             safe: false,
             format_options: FormatOptions::default().with_null("null"),
+            rounding_mode: RoundingMode::default(),
+            datetime_formats: None,
+            binary_endianness: Endianness::Little,
+            match_struct_fields_by_name: false,
+            strict_interval_parsing: false,
         };
         let array = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
             Some(vec![Some(0), Some(1), Some(2)]),
@@ -10226,6 +11505,171 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_cast_union_to_primitive() {
+        let fields = UnionFields::new(
+            [0, 1],
+            [
+                Field::new("int", DataType::Int32, true),
+                Field::new("str", DataType::Utf8, true),
+            ],
+        );
+        let ints = Int32Array::from(vec![Some(1), None]);
+        let strs = StringArray::from(vec!["2", "x"]);
+
+        // Dense: [1, "2", null, "x"]
+        let type_ids = ScalarBuffer::from(vec![0, 1, 0, 1]);
+        let offsets = ScalarBuffer::from(vec![0, 0, 1, 1]);
+        let children: Vec<ArrayRef> = vec![Arc::new(ints.clone()), Arc::new(strs.clone())];
+        let dense = UnionArray::try_new(fields.clone(), type_ids, Some(offsets), children).unwrap();
+
+        assert!(can_cast_types(dense.data_type(), &DataType::Int64));
+        let b = cast(&dense, &DataType::Int64).unwrap();
+        let expected = Int64Array::from(vec![Some(1), Some(2), None, None]);
+        assert_eq!(b.as_primitive::<Int64Type>(), &expected);
+
+        let options = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        let err = cast_with_options(&dense, &DataType::Int64, &options).unwrap_err();
+        assert!(err.to_string().contains("Cannot cast string 'x'"), "{err}");
+
+        // Sparse: [1, "x"]
+        let type_ids = ScalarBuffer::from(vec![0, 1]);
+        let children: Vec<ArrayRef> = vec![Arc::new(ints), Arc::new(strs)];
+        let sparse = UnionArray::try_new(fields.clone(), type_ids, None, children).unwrap();
+        let b = cast(&sparse, &DataType::Utf8).unwrap();
+        let expected = StringArray::from(vec!["1", "x"]);
+        assert_eq!(b.as_string::<i32>(), &expected);
+        let b = cast(&sparse.slice(1, 1), &DataType::Utf8).unwrap();
+        assert_eq!(b.as_string::<i32>(), &StringArray::from(vec!["x"]));
+
+        // Sparse: [1, "2"], the unselected "x" is not cast
+        let type_ids = ScalarBuffer::from(vec![0, 1]);
+        let children: Vec<ArrayRef> = vec![
+            Arc::new(Int32Array::from(vec![Some(1), None])),
+            Arc::new(StringArray::from(vec!["x", "2"])),
+        ];
+        let sparse = UnionArray::try_new(fields, type_ids, None, children).unwrap();
+        let b = cast_with_options(&sparse, &DataType::Int64, &options).unwrap();
+        assert_eq!(b.as_primitive::<Int64Type>(), &Int64Array::from(vec![1, 2]));
+
+        assert!(!can_cast_types(
+            sparse.data_type(),
+            &DataType::Struct(Fields::empty())
+        ));
+    }
+
+    #[test]
+    fn test_cast_to_single_field_union() {
+        let a = Int32Array::from(vec![Some(1), None, Some(3)]);
+        for mode in [UnionMode::Dense, UnionMode::Sparse] {
+            let fields = UnionFields::new([5], [Field::new("v", DataType::Int64, true)]);
+            let to_type = DataType::Union(fields, mode);
+            assert!(can_cast_types(a.data_type(), &to_type));
+
+            let b = cast(&a, &to_type).unwrap();
+            assert_eq!(b.data_type(), &to_type);
+            let union = b.as_union();
+            assert_eq!(union.type_ids(), &[5, 5, 5]);
+            assert_eq!(union.child(5).as_primitive::<Int64Type>().value(2), 3);
+
+            let c = cast(&b, &DataType::Int32).unwrap();
+            assert_eq!(c.as_primitive::<Int32Type>(), &a);
+        }
+
+        let fields = UnionFields::new(
+            [0, 1],
+            [
+                Field::new("a", DataType::Int32, true),
+                Field::new("b", DataType::Utf8, true),
+            ],
+        );
+        let to_type = DataType::Union(fields, UnionMode::Sparse);
+        assert!(!can_cast_types(a.data_type(), &to_type));
+        assert!(cast(&a, &to_type).is_err());
+    }
+
+    #[test]
+    fn test_cast_struct_to_struct_by_name() {
+        let inner = StructArray::from(vec![(
+            Arc::new(Field::new("x", DataType::Int32, false)),
+            Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef,
+        )]);
+        let struct_array = StructArray::from(vec![
+            (
+                Arc::new(Field::new("a", DataType::Int32, false)),
+                Arc::new(Int32Array::from(vec![4, 5, 6])) as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new("b", inner.data_type().clone(), false)),
+                Arc::new(inner) as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new("c", DataType::Boolean, false)),
+                Arc::new(BooleanArray::from(vec![true, false, true])) as ArrayRef,
+            ),
+        ]);
+
+        // fields are reordered, `c` is dropped, `d` is filled with nulls and `b` is cast recursively
+        let inner_type = DataType::Struct(vec![Field::new("x", DataType::Utf8, false)].into());
+        let to_type = DataType::Struct(
+            vec![
+                Field::new("d", DataType::Float64, true),
+                Field::new("b", inner_type, false),
+                Field::new("a", DataType::Int64, false),
+            ]
+            .into(),
+        );
+        assert!(can_cast_types(struct_array.data_type(), &to_type));
+        let by_name = CastOptions::default().with_match_struct_fields_by_name(true);
+        let casted = cast_with_options(&struct_array, &to_type, &by_name).unwrap();
+        let casted = casted.as_struct();
+        assert_eq!(casted.data_type(), &to_type);
+        assert_eq!(casted.column(0).null_count(), 3);
+        let x = casted.column(1).as_struct().column(0).as_string::<i32>();
+        assert_eq!(x, &StringArray::from(vec!["1", "2", "3"]));
+        let a = casted.column(2).as_primitive::<Int64Type>();
+        assert_eq!(a.values(), &[4, 5, 6]);
+
+        // extra fields are an error if not safe
+        let options = by_name.clone().with_safe(false);
+        let err = cast_with_options(&struct_array, &to_type, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast struct field 'c' which is not present in the target type"
+        );
+
+        // missing non-nullable fields are an error
+        let to_type = DataType::Struct(
+            vec![
+                Field::new("a", DataType::Int64, false),
+                Field::new("d", DataType::Float64, false),
+            ]
+            .into(),
+        );
+        assert!(!can_cast_types(struct_array.data_type(), &to_type));
+        let err = cast_with_options(&struct_array, &to_type, &by_name).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast struct: non-nullable field 'd' is not present in the source"
+        );
+
+        // fields are matched by position by default
+        let to_type = DataType::Struct(
+            vec![
+                Field::new("c", DataType::Int64, false),
+                Field::new("a", struct_array.column(1).data_type().clone(), false),
+                Field::new("b", DataType::Utf8, false),
+            ]
+            .into(),
+        );
+        let casted = cast(&struct_array, &to_type).unwrap();
+        let c = casted.as_struct().column(0).as_primitive::<Int64Type>();
+        assert_eq!(c.values(), &[4, 5, 6]);
+    }
+
     #[test]
     fn test_cast_struct_to_non_struct() {
         let boolean = Arc::new(BooleanArray::from(vec![true, false]));
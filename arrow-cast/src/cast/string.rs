@@ -16,7 +16,12 @@
 // under the License.
 
 use crate::cast::*;
+use crate::parse::{
+    parse_interval_day_time_config, parse_interval_month_day_nano_config,
+    parse_interval_year_month_config, IntervalParseConfig, IntervalUnit,
+};
 use arrow_buffer::NullBuffer;
+use chrono::DateTime;
 
 pub(crate) fn value_to_string<O: OffsetSizeTrait>(
     array: &dyn Array,
@@ -68,7 +73,19 @@ pub(crate) fn parse_string<P: Parser, O: OffsetSizeTrait>(
     cast_options: &CastOptions,
 ) -> Result<ArrayRef, ArrowError> {
     let string_array = array.as_string::<O>();
-    parse_string_iter::<P, _, _>(string_array.iter(), cast_options, || {
+    parse_string_iter::<P, _, _>(string_array.iter(), cast_options, None, || {
+        string_array.nulls().cloned()
+    })
+}
+
+/// Parse UTF-8 to a date or time type, using [`CastOptions::datetime_formats`]
+pub(crate) fn parse_string_datetime<P: Parser, O: OffsetSizeTrait>(
+    array: &dyn Array,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let string_array = array.as_string::<O>();
+    let formats = cast_options.datetime_formats;
+    parse_string_iter::<P, _, _>(string_array.iter(), cast_options, formats, || {
         string_array.nulls().cloned()
     })
 }
@@ -79,11 +96,59 @@ pub(crate) fn parse_string_view<P: Parser>(
     cast_options: &CastOptions,
 ) -> Result<ArrayRef, ArrowError> {
     let string_view_array = array.as_string_view();
-    parse_string_iter::<P, _, _>(string_view_array.iter(), cast_options, || {
+    parse_string_iter::<P, _, _>(string_view_array.iter(), cast_options, None, || {
+        string_view_array.nulls().cloned()
+    })
+}
+
+/// Parse UTF-8 View to a date or time type, using [`CastOptions::datetime_formats`]
+pub(crate) fn parse_string_view_datetime<P: Parser>(
+    array: &dyn Array,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let string_view_array = array.as_string_view();
+    let formats = cast_options.datetime_formats;
+    parse_string_iter::<P, _, _>(string_view_array.iter(), cast_options, formats, || {
         string_view_array.nulls().cloned()
     })
 }
 
+/// Parse `value` with each of `formats` in order, where `"auto"` uses [`Parser::parse`],
+/// returning the first successful parse. If `formats` is `None`, uses [`Parser::parse`]
+fn parse_with_formats<P: Parser>(value: &str, formats: Option<&[&str]>) -> Option<P::Native> {
+    match formats {
+        None => P::parse(value),
+        Some(formats) => formats.iter().find_map(|format| match *format {
+            "auto" => P::parse(value),
+            format => P::parse_formatted(value, format),
+        }),
+    }
+}
+
+/// Parse `value` as a [`DateTime`] with each of `formats` in order, where `"auto"` uses
+/// [`string_to_datetime`], returning the first successful parse. If `formats` is `None`,
+/// uses [`string_to_datetime`]
+fn string_to_datetime_with_formats<T: TimeZone>(
+    timezone: &T,
+    value: &str,
+    formats: Option<&[&str]>,
+) -> Result<DateTime<T>, ArrowError> {
+    let Some(formats) = formats else {
+        return string_to_datetime(timezone, value);
+    };
+    formats
+        .iter()
+        .find_map(|format| match *format {
+            "auto" => string_to_datetime(timezone, value).ok(),
+            format => string_to_datetime_formatted(timezone, value, format).ok(),
+        })
+        .ok_or_else(|| {
+            ArrowError::ParseError(format!(
+                "Error parsing timestamp from '{value}': no matching format in {formats:?}"
+            ))
+        })
+}
+
 fn parse_string_iter<
     'a,
     P: Parser,
@@ -92,10 +157,11 @@ fn parse_string_iter<
 >(
     iter: I,
     cast_options: &CastOptions,
+    formats: Option<&[&str]>,
     nulls: F,
 ) -> Result<ArrayRef, ArrowError> {
     let array = if cast_options.safe {
-        let iter = iter.map(|x| x.and_then(P::parse));
+        let iter = iter.map(|x| x.and_then(|v| parse_with_formats::<P>(v, formats)));
 
         // Benefit:
         //     20% performance improvement
@@ -105,7 +171,7 @@ fn parse_string_iter<
     } else {
         let v = iter
             .map(|x| match x {
-                Some(v) => P::parse(v).ok_or_else(|| {
+                Some(v) => parse_with_formats::<P>(v, formats).ok_or_else(|| {
                     ArrowError::CastError(format!(
                         "Cannot cast string '{}' to value of {:?} type",
                         v,
@@ -165,10 +231,13 @@ fn cast_string_to_timestamp_impl<
     tz: &Tz,
     cast_options: &CastOptions,
 ) -> Result<PrimitiveArray<T>, ArrowError> {
+    let formats = cast_options.datetime_formats;
     if cast_options.safe {
         let iter = iter.map(|v| {
             v.and_then(|v| {
-                let naive = string_to_datetime(tz, v).ok()?.naive_utc();
+                let naive = string_to_datetime_with_formats(tz, v, formats)
+                    .ok()?
+                    .naive_utc();
                 T::make_value(naive)
             })
         });
@@ -182,7 +251,7 @@ fn cast_string_to_timestamp_impl<
         let vec = iter
             .map(|v| {
                 v.map(|v| {
-                    let naive = string_to_datetime(tz, v)?.naive_utc();
+                    let naive = string_to_datetime_with_formats(tz, v, formats)?.naive_utc();
                     T::make_value(naive).ok_or_else(|| match T::UNIT {
                         TimeUnit::Nanosecond => ArrowError::CastError(format!(
                             "Overflow converting {naive} to Nanosecond. The dates that can be represented as nanoseconds have to be between 1677-09-21T00:12:44.0 and 2262-04-11T23:47:16.854775804"
@@ -205,6 +274,11 @@ fn cast_string_to_timestamp_impl<
     }
 }
 
+/// Returns the [`IntervalParseConfig`] for casting strings to intervals with `cast_options`
+fn interval_parse_config(unit: IntervalUnit, cast_options: &CastOptions) -> IntervalParseConfig {
+    IntervalParseConfig::new(unit).with_strict(cast_options.strict_interval_parsing)
+}
+
 pub(crate) fn cast_string_to_interval<Offset, F, ArrowType>(
     array: &dyn Array,
     cast_options: &CastOptions,
@@ -230,33 +304,30 @@ pub(crate) fn cast_string_to_year_month_interval<Offset: OffsetSizeTrait>(
     array: &dyn Array,
     cast_options: &CastOptions,
 ) -> Result<ArrayRef, ArrowError> {
-    cast_string_to_interval::<Offset, _, IntervalYearMonthType>(
-        array,
-        cast_options,
-        parse_interval_year_month,
-    )
+    let config = interval_parse_config(IntervalUnit::Year, cast_options);
+    cast_string_to_interval::<Offset, _, IntervalYearMonthType>(array, cast_options, |v| {
+        parse_interval_year_month_config(v, config)
+    })
 }
 
 pub(crate) fn cast_string_to_day_time_interval<Offset: OffsetSizeTrait>(
     array: &dyn Array,
     cast_options: &CastOptions,
 ) -> Result<ArrayRef, ArrowError> {
-    cast_string_to_interval::<Offset, _, IntervalDayTimeType>(
-        array,
-        cast_options,
-        parse_interval_day_time,
-    )
+    let config = interval_parse_config(IntervalUnit::Day, cast_options);
+    cast_string_to_interval::<Offset, _, IntervalDayTimeType>(array, cast_options, |v| {
+        parse_interval_day_time_config(v, config)
+    })
 }
 
 pub(crate) fn cast_string_to_month_day_nano_interval<Offset: OffsetSizeTrait>(
     array: &dyn Array,
     cast_options: &CastOptions,
 ) -> Result<ArrayRef, ArrowError> {
-    cast_string_to_interval::<Offset, _, IntervalMonthDayNanoType>(
-        array,
-        cast_options,
-        parse_interval_month_day_nano,
-    )
+    let config = interval_parse_config(IntervalUnit::Month, cast_options);
+    cast_string_to_interval::<Offset, _, IntervalMonthDayNanoType>(array, cast_options, |v| {
+        parse_interval_month_day_nano_config(v, config)
+    })
 }
 
 pub(crate) fn cast_view_to_interval<F, ArrowType>(
@@ -280,29 +351,30 @@ pub(crate) fn cast_view_to_year_month_interval(
     array: &dyn Array,
     cast_options: &CastOptions,
 ) -> Result<ArrayRef, ArrowError> {
-    cast_view_to_interval::<_, IntervalYearMonthType>(
-        array,
-        cast_options,
-        parse_interval_year_month,
-    )
+    let config = interval_parse_config(IntervalUnit::Year, cast_options);
+    cast_view_to_interval::<_, IntervalYearMonthType>(array, cast_options, |v| {
+        parse_interval_year_month_config(v, config)
+    })
 }
 
 pub(crate) fn cast_view_to_day_time_interval(
     array: &dyn Array,
     cast_options: &CastOptions,
 ) -> Result<ArrayRef, ArrowError> {
-    cast_view_to_interval::<_, IntervalDayTimeType>(array, cast_options, parse_interval_day_time)
+    let config = interval_parse_config(IntervalUnit::Day, cast_options);
+    cast_view_to_interval::<_, IntervalDayTimeType>(array, cast_options, |v| {
+        parse_interval_day_time_config(v, config)
+    })
 }
 
 pub(crate) fn cast_view_to_month_day_nano_interval(
     array: &dyn Array,
     cast_options: &CastOptions,
 ) -> Result<ArrayRef, ArrowError> {
-    cast_view_to_interval::<_, IntervalMonthDayNanoType>(
-        array,
-        cast_options,
-        parse_interval_month_day_nano,
-    )
+    let config = interval_parse_config(IntervalUnit::Month, cast_options);
+    cast_view_to_interval::<_, IntervalMonthDayNanoType>(array, cast_options, |v| {
+        parse_interval_month_day_nano_config(v, config)
+    })
 }
 
 fn cast_string_to_interval_impl<'a, I, ArrowType, F>(
@@ -0,0 +1,119 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::cast::*;
+use arrow_buffer::ScalarBuffer;
+use arrow_select::interleave::interleave;
+use arrow_select::take::take;
+
+/// Casts a [`UnionArray`] to `to_type` by casting each of its children to `to_type`
+/// and taking the value selected by each slot
+///
+/// Values that fail to cast are null if [`CastOptions::safe`], as for any other cast.
+/// Only the values selected by the slots of the union are cast
+pub(crate) fn cast_union_values(
+    array: &dyn Array,
+    to_type: &DataType,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let array = array.as_union();
+    let DataType::Union(fields, _) = array.data_type() else {
+        unreachable!()
+    };
+    if fields.is_empty() {
+        return Ok(new_null_array(to_type, array.len()));
+    }
+
+    // Maps each type id to the position of its cast child
+    let mut positions = [0; 128];
+    for (idx, (type_id, _)) in fields.iter().enumerate() {
+        positions[type_id as usize] = idx;
+    }
+
+    let (children, indices): (Vec<_>, Vec<_>) = match array.offsets() {
+        Some(offsets) => {
+            let children = fields
+                .iter()
+                .map(|(type_id, _)| cast_with_options(array.child(type_id), to_type, cast_options))
+                .collect::<Result<_, _>>()?;
+            let indices = array
+                .type_ids()
+                .iter()
+                .zip(offsets.iter())
+                .map(|(type_id, offset)| (positions[*type_id as usize], *offset as usize))
+                .collect();
+            (children, indices)
+        }
+        None => {
+            // Only the slots selected by its type id are cast from each child, as the
+            // values of other slots are undefined and may fail to cast
+            let mut selected = vec![vec![]; fields.len()];
+            let indices = array
+                .type_ids()
+                .iter()
+                .enumerate()
+                .map(|(idx, type_id)| {
+                    let position = positions[*type_id as usize];
+                    selected[position].push(idx as u32);
+                    (position, selected[position].len() - 1)
+                })
+                .collect();
+            let children = fields
+                .iter()
+                .zip(selected)
+                .map(|((type_id, _), selected)| {
+                    let values = take(array.child(type_id), &UInt32Array::from(selected), None)?;
+                    cast_with_options(&values, to_type, cast_options)
+                })
+                .collect::<Result<_, _>>()?;
+            (children, indices)
+        }
+    };
+
+    let children: Vec<_> = children.iter().map(|c| c.as_ref()).collect();
+    interleave(&children, &indices)
+}
+
+/// Casts an array to a `Union` with a single field by casting it to the type of that field
+pub(crate) fn cast_to_union(
+    array: &dyn Array,
+    fields: &UnionFields,
+    mode: UnionMode,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let mut iter = fields.iter();
+    let (Some((type_id, field)), None) = (iter.next(), iter.next()) else {
+        return Err(ArrowError::CastError(format!(
+            "Casting from {:?} to a union with {} fields not supported",
+            array.data_type(),
+            fields.len()
+        )));
+    };
+
+    let values = cast_with_options(array, field.data_type(), cast_options)?;
+    let type_ids = ScalarBuffer::from(vec![type_id; values.len()]);
+    let offsets = match mode {
+        UnionMode::Dense => Some(ScalarBuffer::from_iter(0..values.len() as i32)),
+        UnionMode::Sparse => None,
+    };
+    Ok(Arc::new(UnionArray::try_new(
+        fields.clone(),
+        type_ids,
+        offsets,
+        vec![values],
+    )?))
+}
@@ -39,13 +39,32 @@ use lexical_core::FormattedSize;
 type TimeFormat<'a> = Option<&'a str>;
 
 /// Format for displaying durations
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
 #[non_exhaustive]
 pub enum DurationFormat {
     /// ISO 8601 - `P198DT72932.972880S`
+    #[default]
     ISO8601,
     /// A human readable representation - `198 days 16 hours 34 mins 15.407810000 secs`
     Pretty,
+    /// A compact human readable representation - `198d 16h 34m 15.40781s`
+    Compact,
+    /// The raw number of ticks in the unit of the duration - `17167855407810`
+    Ticks,
+}
+
+/// Format for displaying intervals
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum IntervalFormat {
+    /// A human readable representation - `1 years 2 mons`, `3 days 4 hours 5 mins 6.500000000 secs`
+    #[default]
+    Pretty,
+    /// ISO 8601 - `P1Y2M3DT4H5M6.5S`
+    ISO8601,
+    /// SQL standard - `1-2`, `3 4:05:06.5`, or `+1-2 +3 +4:05:06.5` if year-month and day-time
+    /// fields are mixed or have different signs
+    SqlStandard,
 }
 
 /// Options for formatting arrays
@@ -72,6 +91,8 @@ pub struct FormatOptions<'a> {
     time_format: TimeFormat<'a>,
     /// Duration format
     duration_format: DurationFormat,
+    /// Interval format
+    interval_format: IntervalFormat,
     /// Show types in visual representation batches
     types_info: bool,
 }
@@ -94,6 +115,7 @@ impl<'a> FormatOptions<'a> {
             timestamp_tz_format: None,
             time_format: None,
             duration_format: DurationFormat::ISO8601,
+            interval_format: IntervalFormat::Pretty,
             types_info: false,
         }
     }
@@ -162,6 +184,16 @@ impl<'a> FormatOptions<'a> {
         }
     }
 
+    /// Overrides the format used for interval columns
+    ///
+    /// Defaults to [`IntervalFormat::Pretty`]
+    pub const fn with_interval_format(self, interval_format: IntervalFormat) -> Self {
+        Self {
+            interval_format,
+            ..self
+        }
+    }
+
     /// Overrides if types should be shown
     ///
     /// Defaults to [`false`]
@@ -610,6 +642,8 @@ macro_rules! duration_display {
                 match fmt {
                     DurationFormat::ISO8601 => write!(f, "{}", $convert(v))?,
                     DurationFormat::Pretty => duration_fmt!(f, v, $scale)?,
+                    DurationFormat::Compact => compact_duration_fmt(f, v, $scale)?,
+                    DurationFormat::Ticks => write!(f, "{v}")?,
                 }
                 Ok(())
             }
@@ -638,6 +672,8 @@ macro_rules! duration_option_display {
                         Some(_) => duration_fmt!(f, v, $scale)?,
                         None => write!(f, "<invalid>")?,
                     },
+                    DurationFormat::Compact => compact_duration_fmt(f, v, $scale)?,
+                    DurationFormat::Ticks => write!(f, "{v}")?,
                 }
                 Ok(())
             }
@@ -691,25 +727,90 @@ macro_rules! duration_fmt {
     }};
 }
 
+/// Writes `nanos` as a fraction of a second with trailing zeros removed, e.g. `.5`,
+/// writing nothing if `nanos` is zero
+fn fraction_fmt(f: &mut dyn Write, nanos: u64) -> FormatResult {
+    if nanos != 0 {
+        let fraction = format!("{nanos:09}");
+        write!(f, ".{}", fraction.trim_end_matches('0'))?;
+    }
+    Ok(())
+}
+
+/// Writes a duration of `v` ticks of `10^-scale` seconds as [`DurationFormat::Compact`]
+fn compact_duration_fmt(f: &mut dyn Write, v: i64, scale: u32) -> FormatResult {
+    let ticks_per_sec = 10_u64.pow(scale);
+    let abs = v.unsigned_abs();
+    let nanos = (abs % ticks_per_sec) * 10_u64.pow(9 - scale);
+    let secs = abs / ticks_per_sec;
+    let parts = [
+        (secs / 86_400, "d"),
+        (secs / 3_600 % 24, "h"),
+        (secs / 60 % 60, "m"),
+    ];
+
+    if v < 0 {
+        write!(f, "-")?;
+    }
+    let mut prefix = "";
+    for (value, unit) in parts.into_iter().filter(|(value, _)| *value != 0) {
+        write!(f, "{prefix}{value}{unit}")?;
+        prefix = " ";
+    }
+    if secs % 60 != 0 || nanos != 0 || prefix.is_empty() {
+        write!(f, "{prefix}{}", secs % 60)?;
+        fraction_fmt(f, nanos)?;
+        write!(f, "s")?;
+    }
+    Ok(())
+}
+
 duration_option_display!(try_duration_s_to_duration, DurationSecondType, 0);
 duration_option_display!(try_duration_ms_to_duration, DurationMillisecondType, 3);
 duration_display!(duration_us_to_duration, DurationMicrosecondType, 6);
 duration_display!(duration_ns_to_duration, DurationNanosecondType, 9);
 
-impl DisplayIndex for &PrimitiveArray<IntervalYearMonthType> {
-    fn write(&self, idx: usize, f: &mut dyn Write) -> FormatResult {
-        let interval = self.value(idx) as f64;
-        let years = (interval / 12_f64).floor();
-        let month = interval - (years * 12_f64);
+impl<'a> DisplayIndexState<'a> for &'a PrimitiveArray<IntervalYearMonthType> {
+    type State = IntervalFormat;
+
+    fn prepare(&self, options: &FormatOptions<'a>) -> Result<Self::State, ArrowError> {
+        Ok(options.interval_format)
+    }
+
+    fn write(&self, fmt: &Self::State, idx: usize, f: &mut dyn Write) -> FormatResult {
+        let value = self.value(idx);
+        match fmt {
+            IntervalFormat::Pretty => {
+                let interval = value as f64;
+                let years = (interval / 12_f64).floor();
+                let month = interval - (years * 12_f64);
 
-        write!(f, "{years} years {month} mons",)?;
+                write!(f, "{years} years {month} mons",)?;
+            }
+            IntervalFormat::ISO8601 => iso8601_interval_fmt(f, value, 0, 0)?,
+            IntervalFormat::SqlStandard => sql_standard_interval_fmt(f, value, 0, 0)?,
+        }
         Ok(())
     }
 }
 
-impl DisplayIndex for &PrimitiveArray<IntervalDayTimeType> {
-    fn write(&self, idx: usize, f: &mut dyn Write) -> FormatResult {
+impl<'a> DisplayIndexState<'a> for &'a PrimitiveArray<IntervalDayTimeType> {
+    type State = IntervalFormat;
+
+    fn prepare(&self, options: &FormatOptions<'a>) -> Result<Self::State, ArrowError> {
+        Ok(options.interval_format)
+    }
+
+    fn write(&self, fmt: &Self::State, idx: usize, f: &mut dyn Write) -> FormatResult {
         let value = self.value(idx);
+        let nanos = value.milliseconds as i64 * 1_000_000;
+        match fmt {
+            IntervalFormat::Pretty => {}
+            IntervalFormat::ISO8601 => return iso8601_interval_fmt(f, 0, value.days, nanos),
+            IntervalFormat::SqlStandard => {
+                return sql_standard_interval_fmt(f, 0, value.days, nanos)
+            }
+        }
         let mut prefix = "";
 
         if value.days != 0 {
@@ -730,9 +831,24 @@ impl DisplayIndex for &PrimitiveArray<IntervalDayTimeType> {
     }
 }
 
-impl DisplayIndex for &PrimitiveArray<IntervalMonthDayNanoType> {
-    fn write(&self, idx: usize, f: &mut dyn Write) -> FormatResult {
+impl<'a> DisplayIndexState<'a> for &'a PrimitiveArray<IntervalMonthDayNanoType> {
+    type State = IntervalFormat;
+
+    fn prepare(&self, options: &FormatOptions<'a>) -> Result<Self::State, ArrowError> {
+        Ok(options.interval_format)
+    }
+
+    fn write(&self, fmt: &Self::State, idx: usize, f: &mut dyn Write) -> FormatResult {
         let value = self.value(idx);
+        match fmt {
+            IntervalFormat::Pretty => {}
+            IntervalFormat::ISO8601 => {
+                return iso8601_interval_fmt(f, value.months, value.days, value.nanoseconds)
+            }
+            IntervalFormat::SqlStandard => {
+                return sql_standard_interval_fmt(f, value.months, value.days, value.nanoseconds)
+            }
+        }
         let mut prefix = "";
 
         if value.months != 0 {
@@ -757,6 +873,86 @@ impl DisplayIndex for &PrimitiveArray<IntervalMonthDayNanoType> {
     }
 }
 
+/// Splits the absolute value of `nanos` into hours, minutes, seconds and nanoseconds
+fn split_nanos(nanos: i64) -> (u64, u64, u64, u64) {
+    let abs = nanos.unsigned_abs();
+    let secs = abs / 1_000_000_000;
+    (secs / 3_600, secs / 60 % 60, secs % 60, abs % 1_000_000_000)
+}
+
+/// Writes an interval as [`IntervalFormat::ISO8601`]
+fn iso8601_interval_fmt(f: &mut dyn Write, months: i32, days: i32, nanos: i64) -> FormatResult {
+    if months == 0 && days == 0 && nanos == 0 {
+        write!(f, "PT0S")?;
+        return Ok(());
+    }
+
+    write!(f, "P")?;
+    let date_parts = [(months / 12, "Y"), (months % 12, "M"), (days, "D")];
+    for (value, unit) in date_parts.into_iter().filter(|(value, _)| *value != 0) {
+        write!(f, "{value}{unit}")?;
+    }
+
+    if nanos != 0 {
+        let sign = if nanos < 0 { "-" } else { "" };
+        let (hours, mins, secs, nanos) = split_nanos(nanos);
+        write!(f, "T")?;
+        for (value, unit) in [(hours, "H"), (mins, "M")] {
+            if value != 0 {
+                write!(f, "{sign}{value}{unit}")?;
+            }
+        }
+        if secs != 0 || nanos != 0 {
+            write!(f, "{sign}{secs}")?;
+            fraction_fmt(f, nanos)?;
+            write!(f, "S")?;
+        }
+    }
+    Ok(())
+}
+
+/// Writes an interval as [`IntervalFormat::SqlStandard`]
+fn sql_standard_interval_fmt(
+    f: &mut dyn Write,
+    months: i32,
+    days: i32,
+    nanos: i64,
+) -> FormatResult {
+    let has_negative = months < 0 || days < 0 || nanos < 0;
+    let has_positive = months > 0 || days > 0 || nanos > 0;
+    let has_year_month = months != 0;
+    let has_day_time = days != 0 || nanos != 0;
+
+    let (years, months_abs) = (months.unsigned_abs() / 12, months.unsigned_abs() % 12);
+    let (hours, mins, secs, frac) = split_nanos(nanos);
+    let sign = |negative: bool| if negative { "-" } else { "" };
+    let forced_sign = |negative: bool| if negative { "-" } else { "+" };
+
+    if !has_negative && !has_positive {
+        write!(f, "0")?;
+    } else if (has_negative && has_positive) || (has_year_month && has_day_time) {
+        // fields must be explicitly signed
+        write!(
+            f,
+            "{}{years}-{months_abs} {}{} {}{hours}:{mins:02}:{secs:02}",
+            forced_sign(months < 0),
+            forced_sign(days < 0),
+            days.unsigned_abs(),
+            forced_sign(nanos < 0),
+        )?;
+        fraction_fmt(f, frac)?;
+    } else if has_year_month {
+        write!(f, "{}{years}-{months_abs}", sign(months < 0))?;
+    } else {
+        if days != 0 {
+            write!(f, "{days} ")?;
+        }
+        write!(f, "{}{hours}:{mins:02}:{secs:02}", sign(nanos < 0))?;
+        fraction_fmt(f, frac)?;
+    }
+    Ok(())
+}
+
 struct NanosecondsFormatter<'a> {
     nanoseconds: i64,
     prefix: &'a str,
@@ -1243,6 +1439,84 @@ mod tests {
         assert_eq!(pretty[5], "-45 days -14 hours -2 mins -34 secs");
     }
 
+    #[test]
+    fn test_array_value_to_string_duration_compact_and_ticks() {
+        let compact_fmt = FormatOptions::new().with_duration_format(DurationFormat::Compact);
+        let ticks_fmt = FormatOptions::new().with_duration_format(DurationFormat::Ticks);
+
+        let array = DurationMillisecondArray::from(vec![
+            0,
+            1500,
+            -(2 * 60 * 60 + 3 * 60) * 1000,
+            (45 * 60 * 60 * 24 + 2 * 60) * 1000 + 10,
+        ]);
+        let compact = format_array(&array, &compact_fmt);
+        assert_eq!(compact, ["0s", "1.5s", "-2h 3m", "45d 2m 0.01s"]);
+        let ticks = format_array(&array, &ticks_fmt);
+        assert_eq!(ticks, ["0", "1500", "-7380000", "3888120010"]);
+
+        let array = DurationSecondArray::from(vec![i64::MIN, 86_400]);
+        let compact = format_array(&array, &compact_fmt);
+        assert_eq!(compact, ["-106751991167300d 15h 30m 8s", "1d"]);
+        let ticks = format_array(&array, &ticks_fmt);
+        assert_eq!(ticks, ["-9223372036854775808", "86400"]);
+    }
+
+    #[test]
+    fn test_array_value_to_string_interval_formats() {
+        let iso_fmt = FormatOptions::new().with_interval_format(IntervalFormat::ISO8601);
+        let sql_fmt = FormatOptions::new().with_interval_format(IntervalFormat::SqlStandard);
+
+        let array = IntervalMonthDayNanoArray::from(vec![
+            IntervalMonthDayNano::new(0, 0, 0),
+            IntervalMonthDayNano::new(14, 0, 0),
+            IntervalMonthDayNano::new(0, 3, 14_706_500_000_000),
+            IntervalMonthDayNano::new(14, 3, 14_706_500_000_000),
+            IntervalMonthDayNano::new(-14, 0, 0),
+            IntervalMonthDayNano::new(0, 3, -14_706_000_000_000),
+            IntervalMonthDayNano::new(0, 0, -500_000_000),
+        ]);
+        let iso = format_array(&array, &iso_fmt);
+        assert_eq!(
+            iso,
+            [
+                "PT0S",
+                "P1Y2M",
+                "P3DT4H5M6.5S",
+                "P1Y2M3DT4H5M6.5S",
+                "P-1Y-2M",
+                "P3DT-4H-5M-6S",
+                "PT-0.5S"
+            ]
+        );
+        let sql = format_array(&array, &sql_fmt);
+        assert_eq!(
+            sql,
+            [
+                "0",
+                "1-2",
+                "3 4:05:06.5",
+                "+1-2 +3 +4:05:06.5",
+                "-1-2",
+                "+0-0 +3 -4:05:06",
+                "-0:00:00.5"
+            ]
+        );
+
+        let array = IntervalYearMonthArray::from(vec![14]);
+        assert_eq!(format_array(&array, &iso_fmt), ["P1Y2M"]);
+        assert_eq!(format_array(&array, &sql_fmt), ["1-2"]);
+
+        let array = IntervalDayTimeArray::from(vec![IntervalDayTime::new(3, 1_500)]);
+        assert_eq!(format_array(&array, &iso_fmt), ["P3DT1.5S"]);
+        assert_eq!(format_array(&array, &sql_fmt), ["3 0:00:01.5"]);
+        // the default format is unchanged
+        assert_eq!(
+            format_array(&array, &FormatOptions::new()),
+            ["3 days 1.500 secs"]
+        );
+    }
+
     #[test]
     fn test_null() {
         let array = NullArray::new(2);
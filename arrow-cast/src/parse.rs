@@ -25,6 +25,7 @@ use arrow_buffer::ArrowNativeType;
 use arrow_schema::ArrowError;
 use chrono::prelude::*;
 use half::f16;
+use std::borrow::Cow;
 use std::str::FromStr;
 
 /// Parse nanoseconds from the first `N` values in digits, subtracting the offset `O`
@@ -227,6 +228,42 @@ pub fn string_to_datetime<T: TimeZone>(timezone: &T, s: &str) -> Result<DateTime
     Ok(parsed.with_timezone(timezone))
 }
 
+/// Parses `s` as a [`DateTime`] in the given `timezone` using the chrono format string `format`
+///
+/// If `format` includes a timezone offset, the parsed offset is used, otherwise the parsed
+/// local time is interpreted in `timezone`. A `format` with only date fields parses to
+/// midnight of that date.
+///
+/// ```
+/// # use arrow_cast::parse::string_to_datetime_formatted;
+/// # use chrono::Utc;
+/// let a = string_to_datetime_formatted(&Utc, "31/01/1997 09:26", "%d/%m/%Y %H:%M").unwrap();
+/// let b = string_to_datetime_formatted(&Utc, "31/01/1997 14:26 +0500", "%d/%m/%Y %H:%M %z").unwrap();
+/// assert_eq!(a, b);
+/// ```
+pub fn string_to_datetime_formatted<T: TimeZone>(
+    timezone: &T,
+    s: &str,
+    format: &str,
+) -> Result<DateTime<T>, ArrowError> {
+    let err = || {
+        ArrowError::ParseError(format!(
+            "Error parsing timestamp from '{s}' with format '{format}'"
+        ))
+    };
+
+    if let Ok(datetime) = DateTime::parse_from_str(s, format) {
+        return Ok(datetime.with_timezone(timezone));
+    }
+    let datetime = NaiveDateTime::parse_from_str(s, format)
+        .or_else(|_| NaiveDate::parse_from_str(s, format).map(|d| d.and_time(NaiveTime::MIN)))
+        .map_err(|_| err())?;
+    timezone
+        .from_local_datetime(&datetime)
+        .single()
+        .ok_or_else(err)
+}
+
 /// Accepts a string in RFC3339 / ISO8601 standard format and some
 /// variants and converts it to a nanosecond precision timestamp.
 ///
@@ -990,7 +1027,14 @@ pub fn parse_decimal<T: DecimalType>(
 pub fn parse_interval_year_month(
     value: &str,
 ) -> Result<<IntervalYearMonthType as ArrowPrimitiveType>::Native, ArrowError> {
-    let config = IntervalParseConfig::new(IntervalUnit::Year);
+    parse_interval_year_month_config(value, IntervalParseConfig::new(IntervalUnit::Year))
+}
+
+/// Parse human-readable interval string to Arrow [IntervalYearMonthType]
+pub fn parse_interval_year_month_config(
+    value: &str,
+    config: IntervalParseConfig,
+) -> Result<<IntervalYearMonthType as ArrowPrimitiveType>::Native, ArrowError> {
     let interval = Interval::parse(value, &config)?;
 
     let months = interval.to_year_months().map_err(|_| {
@@ -1006,7 +1050,14 @@ pub fn parse_interval_year_month(
 pub fn parse_interval_day_time(
     value: &str,
 ) -> Result<<IntervalDayTimeType as ArrowPrimitiveType>::Native, ArrowError> {
-    let config = IntervalParseConfig::new(IntervalUnit::Day);
+    parse_interval_day_time_config(value, IntervalParseConfig::new(IntervalUnit::Day))
+}
+
+/// Parse human-readable interval string to Arrow [IntervalDayTimeType]
+pub fn parse_interval_day_time_config(
+    value: &str,
+    config: IntervalParseConfig,
+) -> Result<<IntervalDayTimeType as ArrowPrimitiveType>::Native, ArrowError> {
     let interval = Interval::parse(value, &config)?;
 
     let (days, millis) = interval.to_day_time().map_err(|_| ArrowError::CastError(format!(
@@ -1044,18 +1095,35 @@ const NANOS_PER_DAY: i64 = 24 * NANOS_PER_HOUR;
 
 /// Config to parse interval strings
 ///
-/// Currently stores the `default_unit` to use if the string doesn't have one specified
-#[derive(Debug, Clone)]
+/// Interval strings may use the Postgres verbose format such as `1 year 2 mons 3 days`,
+/// the ISO-8601 format such as `P1Y2M3DT4H5M6S`, or the SQL standard format such as
+/// `1-2 3 4:05:06`
+#[derive(Debug, Clone, Copy)]
 pub struct IntervalParseConfig {
     /// The default unit to use if none is specified
     /// e.g. `INTERVAL 1` represents `INTERVAL 1 SECOND` when default_unit = [IntervalUnit::Second]
     default_unit: IntervalUnit,
+    /// If true, amounts without a unit and strings mixing the SQL standard and
+    /// Postgres verbose formats are rejected
+    strict: bool,
 }
 
 impl IntervalParseConfig {
     /// Create a new [IntervalParseConfig] with the given default unit
     pub fn new(default_unit: IntervalUnit) -> Self {
-        Self { default_unit }
+        Self {
+            default_unit,
+            strict: false,
+        }
+    }
+
+    /// Set whether parsing is strict, defaults to false
+    ///
+    /// If true, amounts must have a unit, e.g. `1` is rejected in favour of `1 day`, and the
+    /// SQL standard and Postgres verbose formats cannot be mixed, e.g. `1 day 4:05:06`
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
     }
 }
 
@@ -1130,6 +1198,9 @@ impl IntervalUnit {
     ) -> Result<Self, ArrowError> {
         match s {
             Some(s) => s.parse(),
+            None if config.strict => Err(ArrowError::ParseError(
+                "Interval amount without a unit is not allowed in strict mode".to_string(),
+            )),
             None => Ok(config.default_unit),
         }
     }
@@ -1265,9 +1336,13 @@ impl Interval {
     }
 
     /// Parse string value in traditional Postgres format such as
-    /// `1 year 2 months 3 days 4 hours 5 minutes 6 seconds`
+    /// `1 year 2 months 3 days 4 hours 5 minutes 6 seconds`, ISO-8601 format such as
+    /// `P1Y2M3DT4H5M6S`, or SQL standard format such as `1-2 3 4:05:06`
     fn parse(value: &str, config: &IntervalParseConfig) -> Result<Self, ArrowError> {
-        let components = parse_interval_components(value, config)?;
+        let components = match parse_iso8601_components(value) {
+            Some(components) => components?,
+            None => parse_interval_components(value, config)?,
+        };
 
         components
             .into_iter()
@@ -1438,39 +1513,228 @@ fn parse_interval_components(
     value: &str,
     config: &IntervalParseConfig,
 ) -> Result<Vec<(IntervalAmount, IntervalUnit)>, ArrowError> {
-    let raw_pairs = split_interval_components(value);
+    let invalid =
+        || ArrowError::ParseError(format!("Invalid input syntax for type interval: {value:?}"));
 
-    // parse amounts and units
-    let Ok(pairs): Result<Vec<(IntervalAmount, IntervalUnit)>, ArrowError> = raw_pairs
-        .iter()
-        .map(|(a, u)| Ok((a.parse()?, IntervalUnit::from_str_or_config(*u, config)?)))
-        .collect()
-    else {
-        return Err(ArrowError::ParseError(format!(
-            "Invalid input syntax for type interval: {value:?}"
-        )));
-    };
+    let (mut components, verbose) = split_sql_standard_components(value)?;
+    if config.strict && !components.is_empty() && verbose.is_some() {
+        return Err(invalid());
+    }
 
-    // collect parsed results
-    let (amounts, units): (Vec<_>, Vec<_>) = pairs.into_iter().unzip();
+    let raw_pairs = verbose
+        .as_deref()
+        .map(split_interval_components)
+        .unwrap_or_default();
+
+    // parse amounts and units
+    for (amount, unit) in &raw_pairs {
+        let (Ok(amount), Ok(parsed_unit)) = (
+            amount.parse(),
+            IntervalUnit::from_str_or_config(*unit, config),
+        ) else {
+            return Err(invalid());
+        };
+        components.push((amount, parsed_unit, unit.unwrap_or_default()));
+    }
 
     // duplicate units?
     let mut observed_interval_types = 0;
-    for (unit, (_, raw_unit)) in units.iter().zip(raw_pairs) {
+    for (_, unit, raw_unit) in &components {
         if observed_interval_types & (*unit as u16) != 0 {
             return Err(ArrowError::ParseError(format!(
-                "Invalid input syntax for type interval: {:?}. Repeated type '{}'",
-                value,
-                raw_unit.unwrap_or_default(),
+                "Invalid input syntax for type interval: {value:?}. Repeated type '{raw_unit}'"
             )));
         }
 
         observed_interval_types |= *unit as u16;
     }
 
-    let result = amounts.iter().copied().zip(units.iter().copied());
+    Ok(components.into_iter().map(|(a, u, _)| (a, u)).collect())
+}
+
+/// An interval component parsed from the SQL standard format, with the name of its unit
+type SqlStandardComponent = (IntervalAmount, IntervalUnit, &'static str);
+
+/// Extract the SQL standard year-month (`1-2`) and time (`4:05:06.5`) fields from an interval,
+/// along with a bare day amount preceding a time field or following a year-month field.
+///
+/// Returns the parsed fields, and the remaining words to parse in the Postgres verbose format,
+/// if any.
+fn split_sql_standard_components(
+    value: &str,
+) -> Result<(Vec<SqlStandardComponent>, Option<Cow<'_, str>>), ArrowError> {
+    let words: Vec<&str> = value.split(char::is_whitespace).collect();
+    let mut components = vec![];
+    let mut verbose = vec![];
+    let mut follows_year_month = false;
+    for (idx, word) in words.iter().enumerate() {
+        if let Some((sign, years, months)) = split_sql_year_month(word) {
+            components.push((
+                parse_sql_field(sign, years, None)?,
+                IntervalUnit::Year,
+                "year",
+            ));
+            let months = parse_sql_field(sign, months, Some(12))?;
+            components.push((months, IntervalUnit::Month, "month"));
+            follows_year_month = true;
+            continue;
+        }
+        if let Some((sign, hours, minutes, seconds)) = split_sql_time(word) {
+            components.push((
+                parse_sql_field(sign, hours, None)?,
+                IntervalUnit::Hour,
+                "hour",
+            ));
+            let minutes = parse_sql_field(sign, minutes, Some(60))?;
+            components.push((minutes, IntervalUnit::Minute, "minute"));
+            if let Some(seconds) = seconds {
+                let seconds = parse_sql_field(sign, seconds, Some(60))?;
+                components.push((seconds, IntervalUnit::Second, "second"));
+            }
+            follows_year_month = false;
+            continue;
+        }
+
+        let next = words.get(idx + 1);
+        let is_days = !word.is_empty()
+            && word.find(not_interval_amount).is_none()
+            && match next {
+                Some(next) => split_sql_time(next).is_some(),
+                None => follows_year_month,
+            };
+        match is_days {
+            true => components.push((word.parse()?, IntervalUnit::Day, "day")),
+            false => verbose.push(*word),
+        }
+        follows_year_month = false;
+    }
+
+    let verbose = match (components.is_empty(), verbose.is_empty()) {
+        (true, _) => Some(Cow::Borrowed(value)),
+        (false, true) => None,
+        (false, false) => Some(Cow::Owned(verbose.join(" "))),
+    };
+    Ok((components, verbose))
+}
+
+/// Split a leading `+` or `-` from `word`
+fn split_sign(word: &str) -> (&str, &str) {
+    match word.strip_prefix(['+', '-']) {
+        Some(rest) => word.split_at(word.len() - rest.len()),
+        None => ("", word),
+    }
+}
+
+fn is_ascii_digits(s: &str) -> bool {
+    !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Parse a SQL standard signed amount, returning an error if it is not less than `max`
+fn parse_sql_field(
+    sign: &str,
+    field: &str,
+    max: Option<i64>,
+) -> Result<IntervalAmount, ArrowError> {
+    let amount: IntervalAmount = format!("{sign}{field}").parse()?;
+    match max {
+        Some(max) if amount.integer.abs() >= max => Err(ArrowError::ParseError(format!(
+            "Interval field value out of range: {field:?}"
+        ))),
+        _ => Ok(amount),
+    }
+}
+
+/// Split a SQL standard year-month field such as `1-2` into its sign, years and months,
+/// returning `None` if `word` is not one
+fn split_sql_year_month(word: &str) -> Option<(&str, &str, &str)> {
+    let (sign, rest) = split_sign(word);
+    let (years, months) = rest.split_once('-')?;
+    (is_ascii_digits(years) && is_ascii_digits(months)).then_some((sign, years, months))
+}
+
+/// Split a SQL standard time field such as `4:05` or `4:05:06.5` into its sign, hours,
+/// minutes and seconds, returning `None` if `word` is not one
+fn split_sql_time(word: &str) -> Option<(&str, &str, &str, Option<&str>)> {
+    let (sign, rest) = split_sign(word);
+    let mut fields = rest.split(':');
+    let hours = fields.next()?;
+    let minutes = fields.next()?;
+    let seconds = fields.next();
+    let valid_seconds = match seconds.map(|s| s.split_once('.')) {
+        Some(Some((integer, frac))) => is_ascii_digits(integer) && is_ascii_digits(frac),
+        Some(None) => seconds.is_some_and(is_ascii_digits),
+        None => true,
+    };
+    let valid = fields.next().is_none()
+        && is_ascii_digits(hours)
+        && is_ascii_digits(minutes)
+        && valid_seconds;
+    valid.then_some((sign, hours, minutes, seconds))
+}
+
+/// Parse an ISO-8601 duration such as `P1Y2M3DT4H5M6.5S` into interval components,
+/// returning `None` if `value` is not an ISO-8601 duration.
+///
+/// A leading `-` negates all components, and `,` may be used as the decimal separator.
+fn parse_iso8601_components(
+    value: &str,
+) -> Option<Result<Vec<(IntervalAmount, IntervalUnit)>, ArrowError>> {
+    let (sign, rest) = split_sign(value);
+    let designators = rest.strip_prefix(['P', 'p'])?;
+    Some(parse_iso8601_designators(value, designators, sign == "-"))
+}
+
+fn parse_iso8601_designators(
+    value: &str,
+    designators: &str,
+    negative: bool,
+) -> Result<Vec<(IntervalAmount, IntervalUnit)>, ArrowError> {
+    let invalid =
+        || ArrowError::ParseError(format!("Invalid input syntax for type interval: {value:?}"));
+
+    let (date, time) = match designators.split_once(['T', 't']) {
+        Some((_, "")) => return Err(invalid()),
+        Some((date, time)) => (date, time),
+        None if designators.is_empty() => return Err(invalid()),
+        None => (designators, ""),
+    };
 
-    Ok(result.collect::<Vec<_>>())
+    let date_units = [
+        ('Y', IntervalUnit::Year),
+        ('M', IntervalUnit::Month),
+        ('W', IntervalUnit::Week),
+        ('D', IntervalUnit::Day),
+    ];
+    let time_units = [
+        ('H', IntervalUnit::Hour),
+        ('M', IntervalUnit::Minute),
+        ('S', IntervalUnit::Second),
+    ];
+
+    let mut components = vec![];
+    for (mut rest, units) in [(date, &date_units[..]), (time, &time_units[..])] {
+        // designators must appear at most once, and in order
+        let mut units = units.iter();
+        while !rest.is_empty() {
+            let idx = rest
+                .find(|c: char| c.is_ascii_alphabetic())
+                .ok_or_else(invalid)?;
+            let (amount, tail) = rest.split_at(idx);
+            let designator = tail.as_bytes()[0].to_ascii_uppercase() as char;
+            rest = &tail[1..];
+
+            let (_, unit) = units.find(|(d, _)| *d == designator).ok_or_else(invalid)?;
+            let amount = amount.replace(',', ".");
+            let amount = match (negative, amount.strip_prefix('-')) {
+                (true, Some(positive)) => positive.to_string(),
+                (true, None) => format!("-{amount}"),
+                (false, _) => amount,
+            };
+            let amount: IntervalAmount = amount.parse().map_err(|_| invalid())?;
+            components.push((amount, *unit));
+        }
+    }
+    Ok(components)
 }
 
 /// Split an interval into a vec of amounts and units.
@@ -2371,6 +2635,116 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_interval_iso8601() {
+        let config = IntervalParseConfig::new(IntervalUnit::Month);
+
+        assert_eq!(
+            Interval::parse("P1Y2M3DT4H5M6.5S", &config).unwrap(),
+            Interval::new(
+                14,
+                3,
+                4 * NANOS_PER_HOUR + 5 * NANOS_PER_MINUTE + 6_500_000_000
+            ),
+        );
+        assert_eq!(
+            Interval::parse("P2W", &config).unwrap(),
+            Interval::new(0, 14, 0)
+        );
+        assert_eq!(
+            Interval::parse("PT0,5H", &config).unwrap(),
+            Interval::new(0, 0, 30 * NANOS_PER_MINUTE)
+        );
+        assert_eq!(
+            Interval::parse("-P1Y-2DT1M", &config).unwrap(),
+            Interval::new(-12, 2, -NANOS_PER_MINUTE)
+        );
+
+        for invalid in [
+            "P", "PT", "P1DT", "P1D2Y", "P1H", "P1Y1Y", "PT1D", "P1.Y", "P1",
+        ] {
+            assert_eq!(
+                Interval::parse(invalid, &config).unwrap_err().to_string(),
+                format!("Parser error: Invalid input syntax for type interval: {invalid:?}")
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_interval_sql_standard() {
+        let config = IntervalParseConfig::new(IntervalUnit::Month);
+
+        assert_eq!(
+            Interval::parse("1-2", &config).unwrap(),
+            Interval::new(14, 0, 0)
+        );
+        assert_eq!(
+            Interval::parse("-1-2 3", &config).unwrap(),
+            Interval::new(-14, 3, 0)
+        );
+        assert_eq!(
+            Interval::parse("3 4:05:06.5", &config).unwrap(),
+            Interval::new(
+                0,
+                3,
+                4 * NANOS_PER_HOUR + 5 * NANOS_PER_MINUTE + 6_500_000_000
+            ),
+        );
+        assert_eq!(
+            Interval::parse("1-2 -3 -4:05", &config).unwrap(),
+            Interval::new(14, -3, -4 * NANOS_PER_HOUR - 5 * NANOS_PER_MINUTE)
+        );
+        // mixed with the Postgres verbose format
+        assert_eq!(
+            Interval::parse("1 year 2 days 12:00", &config).unwrap(),
+            Interval::new(12, 2, 12 * NANOS_PER_HOUR)
+        );
+
+        assert_eq!(
+            Interval::parse("1-12", &config).unwrap_err().to_string(),
+            r#"Parser error: Interval field value out of range: "12""#
+        );
+        assert_eq!(
+            Interval::parse("4:60", &config).unwrap_err().to_string(),
+            r#"Parser error: Interval field value out of range: "60""#
+        );
+        assert_eq!(
+            Interval::parse("1 hour 4:05", &config)
+                .unwrap_err()
+                .to_string(),
+            r#"Parser error: Invalid input syntax for type interval: "1 hour 4:05". Repeated type 'hour'"#
+        );
+    }
+
+    #[test]
+    fn test_parse_interval_strict() {
+        let config = IntervalParseConfig::new(IntervalUnit::Month).with_strict(true);
+
+        assert_eq!(
+            Interval::parse("1 year 2 mons", &config).unwrap(),
+            Interval::new(14, 0, 0)
+        );
+        assert_eq!(
+            Interval::parse("1-2 3 4:05:06", &config).unwrap(),
+            Interval::new(
+                14,
+                3,
+                4 * NANOS_PER_HOUR + 5 * NANOS_PER_MINUTE + 6 * NANOS_PER_SECOND
+            )
+        );
+        assert_eq!(
+            Interval::parse("P1D", &config).unwrap(),
+            Interval::new(0, 1, 0)
+        );
+
+        for invalid in ["1", "1 year 2", "1 day 4:05:06"] {
+            assert_eq!(
+                Interval::parse(invalid, &config).unwrap_err().to_string(),
+                format!("Parser error: Invalid input syntax for type interval: {invalid:?}")
+            );
+        }
+    }
+
     #[test]
     fn test_interval_amount_parsing() {
         // integer
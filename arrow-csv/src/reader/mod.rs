@@ -123,16 +123,22 @@
 //! ```
 //!
 
+#[cfg(feature = "rayon")]
+mod parallel;
 mod records;
 
 use arrow_array::builder::{NullBuilder, PrimitiveBuilder};
 use arrow_array::types::*;
 use arrow_array::*;
-use arrow_cast::parse::{parse_decimal, string_to_datetime, Parser};
+use arrow_buffer::{BufferRecycler, MutableBuffer, NullBufferBuilder, OffsetBuffer, ScalarBuffer};
+use arrow_cast::parse::{parse_decimal, string_to_datetime, string_to_datetime_formatted, Parser};
 use arrow_schema::*;
+use chrono::format::{Fixed, Item, Numeric, StrftimeItems};
 use chrono::{TimeZone, Utc};
 use csv::StringRecord;
 use regex::{Regex, RegexSet};
+use std::borrow::Cow;
+use std::collections::HashMap;
 use std::fmt::{self, Debug};
 use std::fs::File;
 use std::io::{BufRead, BufReader as StdBufReader, Read};
@@ -186,6 +192,8 @@ struct InferredDataType {
     /// 6 - Timestamp(Microsecond)
     /// 7 - Timestamp(Nanosecond)
     /// 8 - Utf8
+    /// 9 - Time32(Second)
+    /// 10 - Time64(Nanosecond)
     packed: u16,
 }
 
@@ -206,6 +214,11 @@ impl InferredDataType {
                 12 => DataType::Date32,
                 _ => unreachable!(),
             },
+            // Promote to highest precision time type
+            b if (b & !0b110_0000_0000) == 0 => match b & (1 << 10) {
+                0 => DataType::Time32(TimeUnit::Second),
+                _ => DataType::Time64(TimeUnit::Nanosecond),
+            },
             _ => DataType::Utf8,
         }
     }
@@ -227,6 +240,64 @@ impl InferredDataType {
             1 << 8 // Utf8
         }
     }
+
+    /// Updates the [`InferredDataType`] with the given string, using the formats of `parser`
+    fn update_with_parser(&mut self, string: &str, parser: &ParserSpec) {
+        if parser.has_boolean_values() && parser.parse_bool(string).is_some() {
+            self.packed |= 1 << 0; // Boolean
+        } else if parser
+            .date_format
+            .as_deref()
+            .is_some_and(|format| Date32Type::parse_formatted(string, format).is_some())
+        {
+            self.packed |= 1 << 3; // Date32
+        } else if let Some(format) = parser
+            .timestamp_format
+            .as_deref()
+            .filter(|format| string_to_datetime_formatted(&Utc, string, format).is_ok())
+        {
+            self.packed |= match has_fractional_seconds(format) {
+                true => 1 << 7,  // Timestamp(Nanosecond)
+                false => 1 << 4, // Timestamp(Second)
+            };
+        } else if let Some(format) = parser
+            .time_format
+            .as_deref()
+            .filter(|format| Time64NanosecondType::parse_formatted(string, format).is_some())
+        {
+            self.packed |= match has_fractional_seconds(format) {
+                true => 1 << 10, // Time64(Nanosecond)
+                false => 1 << 9, // Time32(Second)
+            };
+        } else {
+            self.update(&normalize_decimal(string, parser.decimal_separator))
+        }
+    }
+}
+
+/// Returns true if the chrono `format` string parses fractional seconds
+fn has_fractional_seconds(format: &str) -> bool {
+    StrftimeItems::new(format).any(|item| {
+        matches!(
+            item,
+            Item::Numeric(Numeric::Nanosecond, _)
+                | Item::Fixed(
+                    Fixed::Nanosecond
+                        | Fixed::Nanosecond3
+                        | Fixed::Nanosecond6
+                        | Fixed::Nanosecond9
+                        | Fixed::Internal(_)
+                )
+        )
+    })
+}
+
+/// Replaces `separator`, if any, in `string` with `.`
+fn normalize_decimal(string: &str, separator: Option<char>) -> Cow<'_, str> {
+    match separator {
+        Some(separator) if string.contains(separator) => Cow::Owned(string.replace(separator, ".")),
+        _ => Cow::Borrowed(string),
+    }
 }
 
 /// The format specification for the CSV file
@@ -240,6 +311,7 @@ pub struct Format {
     comment: Option<u8>,
     null_regex: NullRegex,
     truncated_rows: bool,
+    column_parsers: HashMap<String, ParserSpec>,
 }
 
 impl Format {
@@ -300,6 +372,16 @@ impl Format {
         self
     }
 
+    /// Override how the values of the column named `column` are parsed, see [`ParserSpec`]
+    ///
+    /// This applies both when inferring the schema and when reading, where specs for
+    /// columns not present in the schema are ignored. Files without a header name their
+    /// columns `column_1`, `column_2`, etc.
+    pub fn with_column_parser(mut self, column: impl Into<String>, parser: ParserSpec) -> Self {
+        self.column_parsers.insert(column.into(), parser);
+        self
+    }
+
     /// Infer schema of CSV records from the provided `reader`
     ///
     /// If `max_records` is `None`, all records will be read, otherwise up to `max_records`
@@ -343,8 +425,17 @@ impl Format {
             // they could be nullable
             for (i, column_type) in column_types.iter_mut().enumerate().take(header_length) {
                 if let Some(string) = record.get(i) {
-                    if !self.null_regex.is_null(string) {
-                        column_type.update(string)
+                    match self.column_parsers.get(&headers[i]) {
+                        Some(parser) => {
+                            if !parser.null_regex(&self.null_regex).is_null(string) {
+                                column_type.update_with_parser(string, parser)
+                            }
+                        }
+                        None => {
+                            if !self.null_regex.is_null(string) {
+                                column_type.update(string)
+                            }
+                        }
                     }
                 }
             }
@@ -401,6 +492,145 @@ impl Format {
     }
 }
 
+/// Options overriding how the values of a single column are parsed, see
+/// [`Format::with_column_parser`]
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::{Date32Type, Float64Type};
+/// # use arrow_csv::reader::{Format, ParserSpec};
+/// # use arrow_csv::ReaderBuilder;
+/// let data = "date,price,paid\n31/01/2024,\"1,5\",Y\n01/02/2024,-,N\n";
+/// let format = Format::default()
+///     .with_header(true)
+///     .with_column_parser("date", ParserSpec::new().with_date_format("%d/%m/%Y"))
+///     .with_column_parser(
+///         "price",
+///         ParserSpec::new().with_decimal_separator(',').with_null_values(["-"]),
+///     )
+///     .with_column_parser("paid", ParserSpec::new().with_boolean_values(["Y"], ["N"]));
+///
+/// let (schema, _) = format.infer_schema(data.as_bytes(), None).unwrap();
+/// let mut reader = ReaderBuilder::new(Arc::new(schema))
+///     .with_format(format)
+///     .build(data.as_bytes())
+///     .unwrap();
+///
+/// let batch = reader.next().unwrap().unwrap();
+/// assert_eq!(batch.column(0).as_primitive::<Date32Type>().value(0), 19753);
+/// assert_eq!(batch.column(1).as_primitive::<Float64Type>().value(0), 1.5);
+/// assert!(batch.column(1).is_null(1));
+/// assert!(!batch.column(2).as_boolean().value(1));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ParserSpec {
+    date_format: Option<String>,
+    time_format: Option<String>,
+    timestamp_format: Option<String>,
+    decimal_separator: Option<char>,
+    true_values: Vec<String>,
+    false_values: Vec<String>,
+    null_regex: Option<NullRegex>,
+}
+
+impl ParserSpec {
+    /// Create a new [`ParserSpec`] that parses values the same as other columns
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Specify a chrono format string, e.g. `%d/%m/%Y`, with which to parse
+    /// [`DataType::Date32`] and [`DataType::Date64`] values
+    pub fn with_date_format(mut self, format: impl Into<String>) -> Self {
+        self.date_format = Some(format.into());
+        self
+    }
+
+    /// Specify a chrono format string, e.g. `%I:%M %p`, with which to parse
+    /// [`DataType::Time32`] and [`DataType::Time64`] values
+    pub fn with_time_format(mut self, format: impl Into<String>) -> Self {
+        self.time_format = Some(format.into());
+        self
+    }
+
+    /// Specify a chrono format string, e.g. `%d/%m/%Y %H:%M`, with which to parse
+    /// [`DataType::Timestamp`] values
+    ///
+    /// If the format does not include a timezone offset, values are interpreted in the
+    /// timezone of the column, or UTC if it has none
+    pub fn with_timestamp_format(mut self, format: impl Into<String>) -> Self {
+        self.timestamp_format = Some(format.into());
+        self
+    }
+
+    /// Specify the character separating the integer and fractional parts of floating
+    /// point and decimal values, defaults to `.`
+    pub fn with_decimal_separator(mut self, separator: char) -> Self {
+        self.decimal_separator = Some(separator);
+        self
+    }
+
+    /// Specify the values parsed as `true` and `false` for [`DataType::Boolean`] columns,
+    /// replacing the default of case-insensitive `true` and `false`
+    ///
+    /// Values are matched exactly
+    pub fn with_boolean_values<T, F>(mut self, true_values: T, false_values: F) -> Self
+    where
+        T: IntoIterator,
+        T::Item: Into<String>,
+        F: IntoIterator,
+        F::Item: Into<String>,
+    {
+        self.true_values = true_values.into_iter().map(Into::into).collect();
+        self.false_values = false_values.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Specify the values that are null, replacing the null regex of the [`Format`]
+    ///
+    /// Values are matched exactly, and empty values are only null if included
+    pub fn with_null_values<I>(mut self, values: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        let values: Vec<String> = values
+            .into_iter()
+            .map(|value| regex::escape(&value.into()))
+            .collect();
+        // Escaped values always form a valid regex, and no values an empty class
+        // that never matches
+        let regex = match values.is_empty() {
+            true => Regex::new(r"[^\s\S]").unwrap(),
+            false => Regex::new(&format!("^(?:{})$", values.join("|"))).unwrap(),
+        };
+        self.null_regex = Some(NullRegex(Some(regex)));
+        self
+    }
+
+    fn has_boolean_values(&self) -> bool {
+        !self.true_values.is_empty() || !self.false_values.is_empty()
+    }
+
+    fn parse_bool(&self, string: &str) -> Option<bool> {
+        if !self.has_boolean_values() {
+            parse_bool(string)
+        } else if self.true_values.iter().any(|v| v == string) {
+            Some(true)
+        } else if self.false_values.iter().any(|v| v == string) {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the [`NullRegex`] for this column, falling back to `default`
+    fn null_regex<'a>(&'a self, default: &'a NullRegex) -> &'a NullRegex {
+        self.null_regex.as_ref().unwrap_or(default)
+    }
+}
+
 /// Infer schema from a list of CSV files by reading through first n records
 /// with `max_read_records` controlling the maximum number of records to read.
 ///
@@ -576,6 +806,12 @@ pub struct Decoder {
 
     /// Check if the string matches this pattern for `NULL`.
     null_regex: NullRegex,
+
+    /// The [`ParserSpec`] of each field of `schema`, if any
+    column_parsers: Vec<Option<ParserSpec>>,
+
+    /// Optional pool from which to obtain value and offset buffers
+    recycler: Option<Arc<BufferRecycler>>,
 }
 
 impl Decoder {
@@ -622,6 +858,8 @@ impl Decoder {
             self.projection.as_ref(),
             self.line_number,
             &self.null_regex,
+            &self.column_parsers,
+            self.recycler.as_deref(),
         )?;
         self.line_number += rows.len();
         Ok(Some(batch))
@@ -634,6 +872,7 @@ impl Decoder {
 }
 
 /// Parses a slice of [`StringRecords`] into a [RecordBatch]
+#[allow(clippy::too_many_arguments)]
 fn parse(
     rows: &StringRecords<'_>,
     fields: &Fields,
@@ -641,6 +880,8 @@ fn parse(
     projection: Option<&Vec<usize>>,
     line_number: usize,
     null_regex: &NullRegex,
+    column_parsers: &[Option<ParserSpec>],
+    recycler: Option<&BufferRecycler>,
 ) -> Result<RecordBatch, ArrowError> {
     let projection: Vec<usize> = match projection {
         Some(v) => v.clone(),
@@ -652,14 +893,52 @@ fn parse(
         .map(|i| {
             let i = *i;
             let field = &fields[i];
+            let mut null_regex = null_regex;
+            if let Some(parser) = &column_parsers[i] {
+                null_regex = parser.null_regex(null_regex);
+                let data_type = field.data_type();
+                let array = build_array_with_parser(
+                    line_number,
+                    rows,
+                    i,
+                    data_type,
+                    parser,
+                    null_regex,
+                    recycler,
+                )?;
+                if let Some(array) = array {
+                    return Ok(array);
+                }
+            }
             match field.data_type() {
-                DataType::Boolean => build_boolean_array(line_number, rows, i, null_regex),
+                DataType::Boolean => {
+                    build_boolean_array(line_number, rows, i, null_regex, parse_bool)
+                }
+                DataType::Decimal32(precision, scale) => build_decimal_array::<Decimal32Type>(
+                    line_number,
+                    rows,
+                    i,
+                    *precision,
+                    *scale,
+                    None,
+                    null_regex,
+                ),
+                DataType::Decimal64(precision, scale) => build_decimal_array::<Decimal64Type>(
+                    line_number,
+                    rows,
+                    i,
+                    *precision,
+                    *scale,
+                    None,
+                    null_regex,
+                ),
                 DataType::Decimal128(precision, scale) => build_decimal_array::<Decimal128Type>(
                     line_number,
                     rows,
                     i,
                     *precision,
                     *scale,
+                    None,
                     null_regex,
                 ),
                 DataType::Decimal256(precision, scale) => build_decimal_array::<Decimal256Type>(
@@ -668,55 +947,78 @@ fn parse(
                     i,
                     *precision,
                     *scale,
+                    None,
                     null_regex,
                 ),
                 DataType::Int8 => {
-                    build_primitive_array::<Int8Type>(line_number, rows, i, null_regex)
+                    build_primitive_array::<Int8Type>(line_number, rows, i, null_regex, recycler)
                 }
                 DataType::Int16 => {
-                    build_primitive_array::<Int16Type>(line_number, rows, i, null_regex)
+                    build_primitive_array::<Int16Type>(line_number, rows, i, null_regex, recycler)
                 }
                 DataType::Int32 => {
-                    build_primitive_array::<Int32Type>(line_number, rows, i, null_regex)
+                    build_primitive_array::<Int32Type>(line_number, rows, i, null_regex, recycler)
                 }
                 DataType::Int64 => {
-                    build_primitive_array::<Int64Type>(line_number, rows, i, null_regex)
+                    build_primitive_array::<Int64Type>(line_number, rows, i, null_regex, recycler)
                 }
                 DataType::UInt8 => {
-                    build_primitive_array::<UInt8Type>(line_number, rows, i, null_regex)
+                    build_primitive_array::<UInt8Type>(line_number, rows, i, null_regex, recycler)
                 }
                 DataType::UInt16 => {
-                    build_primitive_array::<UInt16Type>(line_number, rows, i, null_regex)
+                    build_primitive_array::<UInt16Type>(line_number, rows, i, null_regex, recycler)
                 }
                 DataType::UInt32 => {
-                    build_primitive_array::<UInt32Type>(line_number, rows, i, null_regex)
+                    build_primitive_array::<UInt32Type>(line_number, rows, i, null_regex, recycler)
                 }
                 DataType::UInt64 => {
-                    build_primitive_array::<UInt64Type>(line_number, rows, i, null_regex)
+                    build_primitive_array::<UInt64Type>(line_number, rows, i, null_regex, recycler)
                 }
                 DataType::Float32 => {
-                    build_primitive_array::<Float32Type>(line_number, rows, i, null_regex)
+                    build_primitive_array::<Float32Type>(line_number, rows, i, null_regex, recycler)
                 }
                 DataType::Float64 => {
-                    build_primitive_array::<Float64Type>(line_number, rows, i, null_regex)
+                    build_primitive_array::<Float64Type>(line_number, rows, i, null_regex, recycler)
                 }
                 DataType::Date32 => {
-                    build_primitive_array::<Date32Type>(line_number, rows, i, null_regex)
+                    build_primitive_array::<Date32Type>(line_number, rows, i, null_regex, recycler)
                 }
                 DataType::Date64 => {
-                    build_primitive_array::<Date64Type>(line_number, rows, i, null_regex)
-                }
-                DataType::Time32(TimeUnit::Second) => {
-                    build_primitive_array::<Time32SecondType>(line_number, rows, i, null_regex)
+                    build_primitive_array::<Date64Type>(line_number, rows, i, null_regex, recycler)
                 }
+                DataType::Time32(TimeUnit::Second) => build_primitive_array::<Time32SecondType>(
+                    line_number,
+                    rows,
+                    i,
+                    null_regex,
+                    recycler,
+                ),
                 DataType::Time32(TimeUnit::Millisecond) => {
-                    build_primitive_array::<Time32MillisecondType>(line_number, rows, i, null_regex)
+                    build_primitive_array::<Time32MillisecondType>(
+                        line_number,
+                        rows,
+                        i,
+                        null_regex,
+                        recycler,
+                    )
                 }
                 DataType::Time64(TimeUnit::Microsecond) => {
-                    build_primitive_array::<Time64MicrosecondType>(line_number, rows, i, null_regex)
+                    build_primitive_array::<Time64MicrosecondType>(
+                        line_number,
+                        rows,
+                        i,
+                        null_regex,
+                        recycler,
+                    )
                 }
                 DataType::Time64(TimeUnit::Nanosecond) => {
-                    build_primitive_array::<Time64NanosecondType>(line_number, rows, i, null_regex)
+                    build_primitive_array::<Time64NanosecondType>(
+                        line_number,
+                        rows,
+                        i,
+                        null_regex,
+                        recycler,
+                    )
                 }
                 DataType::Timestamp(TimeUnit::Second, tz) => {
                     build_timestamp_array::<TimestampSecondType>(
@@ -724,6 +1026,7 @@ fn parse(
                         rows,
                         i,
                         tz.as_deref(),
+                        None,
                         null_regex,
                     )
                 }
@@ -733,6 +1036,7 @@ fn parse(
                         rows,
                         i,
                         tz.as_deref(),
+                        None,
                         null_regex,
                     )
                 }
@@ -742,6 +1046,7 @@ fn parse(
                         rows,
                         i,
                         tz.as_deref(),
+                        None,
                         null_regex,
                     )
                 }
@@ -751,6 +1056,7 @@ fn parse(
                         rows,
                         i,
                         tz.as_deref(),
+                        None,
                         null_regex,
                     )
                 }
@@ -759,14 +1065,7 @@ fn parse(
                     builder.append_nulls(rows.len());
                     builder.finish()
                 }) as ArrayRef),
-                DataType::Utf8 => Ok(Arc::new(
-                    rows.iter()
-                        .map(|row| {
-                            let s = row.get(i);
-                            (!null_regex.is_null(s)).then_some(s)
-                        })
-                        .collect::<StringArray>(),
-                ) as ArrayRef),
+                DataType::Utf8 => build_string_array(rows, i, null_regex, recycler),
                 DataType::Utf8View => Ok(Arc::new(
                     rows.iter()
                         .map(|row| {
@@ -873,6 +1172,173 @@ fn parse(
     })
 }
 
+/// Parses a column using the formats of `parser`, returning `None` if `parser` does
+/// not override the parsing of `data_type`
+fn build_array_with_parser(
+    line_number: usize,
+    rows: &StringRecords<'_>,
+    col_idx: usize,
+    data_type: &DataType,
+    parser: &ParserSpec,
+    null_regex: &NullRegex,
+    recycler: Option<&BufferRecycler>,
+) -> Result<Option<ArrayRef>, ArrowError> {
+    macro_rules! formatted_helper {
+        ($t:ty, $format:expr) => {
+            build_primitive_array_with::<$t>(
+                line_number,
+                rows,
+                col_idx,
+                null_regex,
+                recycler,
+                |s| <$t>::parse_formatted(s, $format),
+            )
+        };
+    }
+
+    macro_rules! separated_helper {
+        ($t:ty, $separator:expr) => {
+            build_primitive_array_with::<$t>(
+                line_number,
+                rows,
+                col_idx,
+                null_regex,
+                recycler,
+                |s| <$t>::parse(&normalize_decimal(s, Some($separator))),
+            )
+        };
+    }
+
+    let array = match data_type {
+        DataType::Boolean if parser.has_boolean_values() => {
+            build_boolean_array(line_number, rows, col_idx, null_regex, |s| {
+                parser.parse_bool(s)
+            })
+        }
+        DataType::Date32 | DataType::Date64 => {
+            let Some(format) = parser.date_format.as_deref() else {
+                return Ok(None);
+            };
+            match data_type {
+                DataType::Date32 => formatted_helper!(Date32Type, format),
+                _ => formatted_helper!(Date64Type, format),
+            }
+        }
+        DataType::Time32(_) | DataType::Time64(_) => {
+            let Some(format) = parser.time_format.as_deref() else {
+                return Ok(None);
+            };
+            match data_type {
+                DataType::Time32(TimeUnit::Second) => formatted_helper!(Time32SecondType, format),
+                DataType::Time32(TimeUnit::Millisecond) => {
+                    formatted_helper!(Time32MillisecondType, format)
+                }
+                DataType::Time64(TimeUnit::Microsecond) => {
+                    formatted_helper!(Time64MicrosecondType, format)
+                }
+                DataType::Time64(TimeUnit::Nanosecond) => {
+                    formatted_helper!(Time64NanosecondType, format)
+                }
+                _ => return Ok(None),
+            }
+        }
+        DataType::Timestamp(unit, tz) => {
+            let Some(format) = parser.timestamp_format.as_deref() else {
+                return Ok(None);
+            };
+            let (tz, format) = (tz.as_deref(), Some(format));
+            match unit {
+                TimeUnit::Second => build_timestamp_array::<TimestampSecondType>(
+                    line_number,
+                    rows,
+                    col_idx,
+                    tz,
+                    format,
+                    null_regex,
+                ),
+                TimeUnit::Millisecond => build_timestamp_array::<TimestampMillisecondType>(
+                    line_number,
+                    rows,
+                    col_idx,
+                    tz,
+                    format,
+                    null_regex,
+                ),
+                TimeUnit::Microsecond => build_timestamp_array::<TimestampMicrosecondType>(
+                    line_number,
+                    rows,
+                    col_idx,
+                    tz,
+                    format,
+                    null_regex,
+                ),
+                TimeUnit::Nanosecond => build_timestamp_array::<TimestampNanosecondType>(
+                    line_number,
+                    rows,
+                    col_idx,
+                    tz,
+                    format,
+                    null_regex,
+                ),
+            }
+        }
+        DataType::Float32
+        | DataType::Float64
+        | DataType::Decimal32(_, _)
+        | DataType::Decimal64(_, _)
+        | DataType::Decimal128(_, _)
+        | DataType::Decimal256(_, _) => {
+            let Some(separator) = parser.decimal_separator else {
+                return Ok(None);
+            };
+            let separator_opt = Some(separator);
+            match data_type {
+                DataType::Float32 => separated_helper!(Float32Type, separator),
+                DataType::Float64 => separated_helper!(Float64Type, separator),
+                DataType::Decimal32(precision, scale) => build_decimal_array::<Decimal32Type>(
+                    line_number,
+                    rows,
+                    col_idx,
+                    *precision,
+                    *scale,
+                    separator_opt,
+                    null_regex,
+                ),
+                DataType::Decimal64(precision, scale) => build_decimal_array::<Decimal64Type>(
+                    line_number,
+                    rows,
+                    col_idx,
+                    *precision,
+                    *scale,
+                    separator_opt,
+                    null_regex,
+                ),
+                DataType::Decimal128(precision, scale) => build_decimal_array::<Decimal128Type>(
+                    line_number,
+                    rows,
+                    col_idx,
+                    *precision,
+                    *scale,
+                    separator_opt,
+                    null_regex,
+                ),
+                DataType::Decimal256(precision, scale) => build_decimal_array::<Decimal256Type>(
+                    line_number,
+                    rows,
+                    col_idx,
+                    *precision,
+                    *scale,
+                    separator_opt,
+                    null_regex,
+                ),
+                _ => unreachable!(),
+            }
+        }
+        _ => return Ok(None),
+    };
+    array.map(Some)
+}
+
 fn parse_bool(string: &str) -> Option<bool> {
     if string.eq_ignore_ascii_case("false") {
         Some(false)
@@ -890,6 +1356,7 @@ fn build_decimal_array<T: DecimalType>(
     col_idx: usize,
     precision: u8,
     scale: i8,
+    decimal_separator: Option<char>,
     null_regex: &NullRegex,
 ) -> Result<ArrayRef, ArrowError> {
     let mut decimal_builder = PrimitiveBuilder::<T>::with_capacity(rows.len());
@@ -899,7 +1366,8 @@ fn build_decimal_array<T: DecimalType>(
             // append null
             decimal_builder.append_null();
         } else {
-            let decimal_value: Result<T::Native, _> = parse_decimal::<T>(s, precision, scale);
+            let s = normalize_decimal(s, decimal_separator);
+            let decimal_value: Result<T::Native, _> = parse_decimal::<T>(&s, precision, scale);
             match decimal_value {
                 Ok(v) => {
                     decimal_builder.append_value(v);
@@ -917,24 +1385,49 @@ fn build_decimal_array<T: DecimalType>(
     ))
 }
 
+/// Returns an empty [`MutableBuffer`] with at least `capacity` bytes, reusing
+/// a buffer from `recycler` if possible
+fn take_buffer(recycler: Option<&BufferRecycler>, capacity: usize) -> MutableBuffer {
+    match recycler {
+        Some(recycler) => recycler.take(capacity),
+        None => MutableBuffer::with_capacity(capacity),
+    }
+}
+
 // parses a specific column (col_idx) into an Arrow Array.
 fn build_primitive_array<T: ArrowPrimitiveType + Parser>(
     line_number: usize,
     rows: &StringRecords<'_>,
     col_idx: usize,
     null_regex: &NullRegex,
+    recycler: Option<&BufferRecycler>,
 ) -> Result<ArrayRef, ArrowError> {
-    rows.iter()
-        .enumerate()
-        .map(|(row_index, row)| {
-            let s = row.get(col_idx);
-            if null_regex.is_null(s) {
-                return Ok(None);
-            }
+    build_primitive_array_with::<T>(line_number, rows, col_idx, null_regex, recycler, T::parse)
+}
 
-            match T::parse(s) {
-                Some(e) => Ok(Some(e)),
-                None => Err(ArrowError::ParseError(format!(
+// parses a specific column (col_idx) into an Arrow Array, using `parse` for each value.
+fn build_primitive_array_with<T: ArrowPrimitiveType>(
+    line_number: usize,
+    rows: &StringRecords<'_>,
+    col_idx: usize,
+    null_regex: &NullRegex,
+    recycler: Option<&BufferRecycler>,
+    parse: impl Fn(&str) -> Option<T::Native>,
+) -> Result<ArrayRef, ArrowError> {
+    let values = take_buffer(recycler, rows.len() * std::mem::size_of::<T::Native>());
+    let mut builder = PrimitiveBuilder::<T>::new_from_buffer(values, None);
+
+    for (row_index, row) in rows.iter().enumerate() {
+        let s = row.get(col_idx);
+        if null_regex.is_null(s) {
+            builder.append_null();
+            continue;
+        }
+
+        match parse(s) {
+            Some(e) => builder.append_value(e),
+            None => {
+                return Err(ArrowError::ParseError(format!(
                     // TODO: we should surface the underlying error here.
                     "Error while parsing value '{}' as type '{}' for column {} at line {}. Row data: '{}'",
                     s,
@@ -942,11 +1435,52 @@ fn build_primitive_array<T: ArrowPrimitiveType + Parser>(
                     col_idx,
                     line_number + row_index,
                     row
-                ))),
+                )));
             }
-        })
-        .collect::<Result<PrimitiveArray<T>, ArrowError>>()
-        .map(|e| Arc::new(e) as ArrayRef)
+        }
+    }
+    Ok(Arc::new(builder.finish()))
+}
+
+// parses a specific column (col_idx) into a StringArray
+fn build_string_array(
+    rows: &StringRecords<'_>,
+    col_idx: usize,
+    null_regex: &NullRegex,
+    recycler: Option<&BufferRecycler>,
+) -> Result<ArrayRef, ArrowError> {
+    let data_capacity: usize = rows.iter().map(|row| row.get(col_idx).len()).sum();
+    if i32::try_from(data_capacity).is_err() {
+        return Err(ArrowError::CsvError(format!(
+            "offset overflow decoding {} for column {}",
+            DataType::Utf8,
+            col_idx
+        )));
+    }
+
+    let mut offsets = take_buffer(recycler, (rows.len() + 1) * std::mem::size_of::<i32>());
+    let mut values = take_buffer(recycler, data_capacity);
+    let mut nulls = NullBufferBuilder::new(rows.len());
+
+    offsets.push(0_i32);
+    for row in rows.iter() {
+        let s = row.get(col_idx);
+        if null_regex.is_null(s) {
+            nulls.append_null();
+        } else {
+            values.extend_from_slice(s.as_bytes());
+            nulls.append_non_null();
+        }
+        offsets.push(values.len() as i32);
+    }
+
+    // Safety:
+    // Offsets are monotonic, in bounds, and values are valid UTF-8 by construction
+    let array = unsafe {
+        let offsets = OffsetBuffer::new_unchecked(ScalarBuffer::from(offsets));
+        StringArray::new_unchecked(offsets, values.into(), nulls.finish())
+    };
+    Ok(Arc::new(array))
 }
 
 fn build_timestamp_array<T: ArrowTimestampType>(
@@ -954,15 +1488,23 @@ fn build_timestamp_array<T: ArrowTimestampType>(
     rows: &StringRecords<'_>,
     col_idx: usize,
     timezone: Option<&str>,
+    format: Option<&str>,
     null_regex: &NullRegex,
 ) -> Result<ArrayRef, ArrowError> {
     Ok(Arc::new(match timezone {
         Some(timezone) => {
             let tz: Tz = timezone.parse()?;
-            build_timestamp_array_impl::<T, _>(line_number, rows, col_idx, &tz, null_regex)?
+            build_timestamp_array_impl::<T, _>(line_number, rows, col_idx, &tz, format, null_regex)?
                 .with_timezone(timezone)
         }
-        None => build_timestamp_array_impl::<T, _>(line_number, rows, col_idx, &Utc, null_regex)?,
+        None => build_timestamp_array_impl::<T, _>(
+            line_number,
+            rows,
+            col_idx,
+            &Utc,
+            format,
+            null_regex,
+        )?,
     }))
 }
 
@@ -971,6 +1513,7 @@ fn build_timestamp_array_impl<T: ArrowTimestampType, Tz: TimeZone>(
     rows: &StringRecords<'_>,
     col_idx: usize,
     timezone: &Tz,
+    format: Option<&str>,
     null_regex: &NullRegex,
 ) -> Result<PrimitiveArray<T>, ArrowError> {
     rows.iter()
@@ -981,7 +1524,11 @@ fn build_timestamp_array_impl<T: ArrowTimestampType, Tz: TimeZone>(
                 return Ok(None);
             }
 
-            let date = string_to_datetime(timezone, s)
+            let date = match format {
+                Some(format) => string_to_datetime_formatted(timezone, s, format),
+                None => string_to_datetime(timezone, s),
+            };
+            let date = date
                 .and_then(|date| match T::UNIT {
                     TimeUnit::Second => Ok(date.timestamp()),
                     TimeUnit::Millisecond => Ok(date.timestamp_millis()),
@@ -1011,6 +1558,7 @@ fn build_boolean_array(
     rows: &StringRecords<'_>,
     col_idx: usize,
     null_regex: &NullRegex,
+    parse: impl Fn(&str) -> Option<bool>,
 ) -> Result<ArrayRef, ArrowError> {
     rows.iter()
         .enumerate()
@@ -1019,7 +1567,7 @@ fn build_boolean_array(
             if null_regex.is_null(s) {
                 return Ok(None);
             }
-            let parsed = parse_bool(s);
+            let parsed = parse(s);
             match parsed {
                 Some(e) => Ok(Some(e)),
                 None => Err(ArrowError::ParseError(format!(
@@ -1052,6 +1600,8 @@ pub struct ReaderBuilder {
     bounds: Bounds,
     /// Optional projection for which columns to load (zero-based column indices)
     projection: Option<Vec<usize>>,
+    /// Optional pool from which to obtain value and offset buffers
+    recycler: Option<Arc<BufferRecycler>>,
 }
 
 impl ReaderBuilder {
@@ -1083,6 +1633,7 @@ impl ReaderBuilder {
             batch_size: 1024,
             bounds: None,
             projection: None,
+            recycler: None,
         }
     }
 
@@ -1153,6 +1704,16 @@ impl ReaderBuilder {
         self
     }
 
+    /// Sets a [`BufferRecycler`] from which the decoder will obtain the value and
+    /// offset buffers of primitive and [`DataType::Utf8`] columns
+    ///
+    /// Buffers of batches returned by [`RecordBatch::recycle_buffers`] are then reused
+    /// by subsequent batches, instead of allocating new buffers for each batch
+    pub fn with_buffer_recycler(mut self, recycler: Arc<BufferRecycler>) -> Self {
+        self.recycler = Some(recycler);
+        self
+    }
+
     /// Whether to allow truncated rows when parsing.
     ///
     /// By default this is set to `false` and will error if the CSV rows have different lengths.
@@ -1164,6 +1725,13 @@ impl ReaderBuilder {
         self
     }
 
+    /// Override how the values of the column named `column` are parsed, see
+    /// [`Format::with_column_parser`]
+    pub fn with_column_parser(mut self, column: impl Into<String>, parser: ParserSpec) -> Self {
+        self.format.column_parsers.insert(column.into(), parser);
+        self
+    }
+
     /// Create a new `Reader` from a non-buffered reader
     ///
     /// If `R: BufRead` consider using [`Self::build_buffered`] to avoid unnecessary additional
@@ -1180,6 +1748,25 @@ impl ReaderBuilder {
         })
     }
 
+    /// Reads all the records of `data`, such as a memory mapped file, decoding chunks of
+    /// approximately `chunk_size` bytes in parallel using [rayon]
+    ///
+    /// The data is split on record boundaries, following the quoting rules of the
+    /// [`Format`], and the [`RecordBatch`]es of each chunk are returned in order. The last
+    /// [`RecordBatch`] of each chunk may contain fewer than the batch size rows.
+    ///
+    /// Returns an error if bounds have been set with [`Self::with_bounds`]
+    ///
+    /// [rayon]: https://docs.rs/rayon
+    #[cfg(feature = "rayon")]
+    pub fn read_parallel(
+        self,
+        data: &[u8],
+        chunk_size: usize,
+    ) -> Result<Vec<RecordBatch>, ArrowError> {
+        parallel::read_parallel(self, data, chunk_size)
+    }
+
     /// Builds a decoder that can be used to decode CSV from an arbitrary byte stream
     pub fn build_decoder(self) -> Decoder {
         let delimiter = self.format.build_parser();
@@ -1196,6 +1783,13 @@ impl ReaderBuilder {
             None => (header, usize::MAX),
         };
 
+        let column_parsers = self
+            .schema
+            .fields()
+            .iter()
+            .map(|field| self.format.column_parsers.get(field.name()).cloned())
+            .collect();
+
         Decoder {
             schema: self.schema,
             to_skip: start,
@@ -1205,6 +1799,8 @@ impl ReaderBuilder {
             projection: self.projection,
             batch_size: self.batch_size,
             null_regex: self.format.null_regex,
+            column_parsers,
+            recycler: self.recycler,
         }
     }
 }
@@ -1315,6 +1911,28 @@ mod tests {
         assert_eq!("0.290472", lng.value_as_string(9));
     }
 
+    #[test]
+    fn test_csv_reader_with_decimal32_and_decimal64() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("city", DataType::Utf8, false),
+            Field::new("lat", DataType::Decimal32(9, 6), false),
+            Field::new("lng", DataType::Decimal64(18, 6), false),
+        ]));
+
+        let file = File::open("test/data/decimal_test.csv").unwrap();
+
+        let mut csv = ReaderBuilder::new(schema).build(file).unwrap();
+        let batch = csv.next().unwrap().unwrap();
+
+        let lat = batch.column(1).as_primitive::<Decimal32Type>();
+        assert_eq!("57.653484", lat.value_as_string(0));
+        assert_eq!("-50.760000", lat.value_as_string(9));
+
+        let lng = batch.column(2).as_primitive::<Decimal64Type>();
+        assert_eq!("-3.335724", lng.value_as_string(0));
+        assert_eq!("0.290472", lng.value_as_string(9));
+    }
+
     #[test]
     fn test_csv_from_buf_reader() {
         let schema = Schema::new(vec![
@@ -2731,4 +3349,165 @@ mod tests {
         assert_eq!(c2.value(1), "something_cannot_be_inlined");
         assert_eq!(c2.value(2), "bar");
     }
+
+    #[test]
+    fn test_buffer_recycler() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ]));
+        let recycler = Arc::new(BufferRecycler::new(8));
+
+        let data = "1,foo\n2,\n,bar\n4,baz\n";
+        let mut reader = ReaderBuilder::new(schema)
+            .with_batch_size(2)
+            .with_buffer_recycler(recycler.clone())
+            .build_buffered(Cursor::new(data.as_bytes()))
+            .unwrap();
+
+        let batch = reader.next().unwrap().unwrap();
+        let a_ptr = batch
+            .column(0)
+            .as_primitive::<Int32Type>()
+            .values()
+            .as_ptr();
+        assert_eq!(batch.column(1).as_string::<i32>().value(0), "foo");
+        assert!(batch.column(1).is_null(1));
+        batch.recycle_buffers(&recycler);
+        // Null buffer of "b" and the values and offsets buffers
+        assert_eq!(recycler.len(), 4);
+
+        let batch = reader.next().unwrap().unwrap();
+        let a = batch.column(0).as_primitive::<Int32Type>();
+        let b = batch.column(1).as_string::<i32>();
+        assert_eq!(recycler.len(), 1);
+        assert!(a.is_null(0));
+        assert_eq!(a.value(1), 4);
+        assert_eq!(b.value(0), "bar");
+        assert_eq!(b.value(1), "baz");
+
+        // The allocations of the first batch are reused
+        let ptrs = [
+            a.values().inner().as_ptr(),
+            b.offsets().inner().inner().as_ptr(),
+            b.values().as_ptr(),
+        ];
+        assert!(ptrs.contains(&a_ptr.cast()));
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn test_column_parsers() {
+        let data = "ts,time,amount,flag,other\n\
+            31/01/2024 09:30:00.250,09:30 AM,\"1,25\",oui,n/a\n\
+            01/02/2024 14:00:00.000,02:00 PM,-,non,1\n\
+            ,,3,,\n";
+        let format = Format::default()
+            .with_header(true)
+            .with_column_parser(
+                "ts",
+                ParserSpec::new().with_timestamp_format("%d/%m/%Y %H:%M:%S%.f"),
+            )
+            .with_column_parser("time", ParserSpec::new().with_time_format("%I:%M %p"))
+            .with_column_parser(
+                "amount",
+                ParserSpec::new()
+                    .with_decimal_separator(',')
+                    .with_null_values(["-"]),
+            )
+            .with_column_parser(
+                "flag",
+                ParserSpec::new().with_boolean_values(["oui"], ["non"]),
+            )
+            .with_column_parser("other", ParserSpec::new().with_null_values(["", "n/a"]));
+
+        let (schema, _) = format.infer_schema(data.as_bytes(), None).unwrap();
+        let data_types: Vec<_> = schema.fields().iter().map(|f| f.data_type()).collect();
+        assert_eq!(
+            data_types,
+            [
+                &DataType::Timestamp(TimeUnit::Nanosecond, None),
+                &DataType::Time32(TimeUnit::Second),
+                &DataType::Float64,
+                &DataType::Boolean,
+                &DataType::Int64,
+            ]
+        );
+
+        let schema = Schema::new(vec![
+            Field::new("ts", DataType::Timestamp(TimeUnit::Millisecond, None), true),
+            Field::new("time", DataType::Time32(TimeUnit::Second), true),
+            Field::new("amount", DataType::Decimal128(10, 2), true),
+            Field::new("flag", DataType::Boolean, true),
+            Field::new("other", DataType::Int64, true),
+        ]);
+        let mut reader = ReaderBuilder::new(Arc::new(schema))
+            .with_format(format)
+            .build(data.as_bytes())
+            .unwrap();
+        let batch = reader.next().unwrap().unwrap();
+
+        let ts = batch.column(0).as_primitive::<TimestampMillisecondType>();
+        assert_eq!(ts.value(0), 1706693400250);
+        assert_eq!(ts.value(1), 1706796000000);
+        assert!(ts.is_null(2));
+
+        let time = batch.column(1).as_primitive::<Time32SecondType>();
+        assert_eq!(time.value(0), 9 * 3600 + 30 * 60);
+        assert_eq!(time.value(1), 14 * 3600);
+        assert!(time.is_null(2));
+
+        let amount = batch.column(2).as_primitive::<Decimal128Type>();
+        assert_eq!(amount.value(0), 125);
+        assert!(amount.is_null(1));
+        assert_eq!(amount.value(2), 300);
+
+        let flag = batch.column(3).as_boolean();
+        assert!(flag.value(0));
+        assert!(!flag.value(1));
+        assert!(flag.is_null(2));
+
+        let other = batch.column(4).as_primitive::<Int64Type>();
+        assert!(other.is_null(0));
+        assert_eq!(other.value(1), 1);
+        assert!(other.is_null(2));
+
+        // Values not matching the boolean values are an error
+        let schema = Schema::new(vec![Field::new("flag", DataType::Boolean, true)]);
+        let mut reader = ReaderBuilder::new(Arc::new(schema))
+            .with_column_parser("flag", ParserSpec::new().with_boolean_values(["Y"], ["N"]))
+            .build("Y\ntrue\n".as_bytes())
+            .unwrap();
+        let err = reader.next().unwrap().unwrap_err().to_string();
+        assert!(err.contains("Error while parsing value 'true'"), "{err}");
+
+        // Times with fractional seconds are inferred with nanosecond precision
+        let format = Format::default()
+            .with_header(true)
+            .with_column_parser("a", ParserSpec::new().with_time_format("%H:%M:%S%.f"))
+            .with_column_parser("b", ParserSpec::new().with_time_format("%H:%M:%S"));
+        let data = "a,b\n09:30:00.5,09:30:00\n";
+        let (schema, _) = format.infer_schema(data.as_bytes(), None).unwrap();
+        assert_eq!(
+            schema.field(0).data_type(),
+            &DataType::Time64(TimeUnit::Nanosecond)
+        );
+        assert_eq!(
+            schema.field(1).data_type(),
+            &DataType::Time32(TimeUnit::Second)
+        );
+
+        // No null values means no value, even an empty one, is null
+        let schema = Schema::new(vec![Field::new("a", DataType::Utf8, true)]);
+        let mut reader = ReaderBuilder::new(Arc::new(schema))
+            .with_column_parser(
+                "a",
+                ParserSpec::new().with_null_values(Vec::<String>::new()),
+            )
+            .build("x\n\"\"\n".as_bytes())
+            .unwrap();
+        let batch = reader.next().unwrap().unwrap();
+        assert_eq!(batch.column(0).null_count(), 0);
+        assert_eq!(batch.column(0).as_string::<i32>().value(1), "");
+    }
 }
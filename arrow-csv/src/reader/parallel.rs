@@ -0,0 +1,311 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::ops::Range;
+
+use arrow_array::RecordBatch;
+use arrow_schema::ArrowError;
+use rayon::prelude::*;
+
+use crate::reader::{Decoder, Format, ReaderBuilder};
+
+/// A range of bytes of the input that starts and ends on a record boundary
+#[derive(Debug, PartialEq)]
+struct Chunk {
+    range: Range<usize>,
+    /// The number of records, including any header, preceding this chunk
+    records_before: usize,
+}
+
+/// Reads `data` by splitting it into chunks of records, and decoding them in parallel,
+/// see [`ReaderBuilder::read_parallel`]
+pub(crate) fn read_parallel(
+    builder: ReaderBuilder,
+    data: &[u8],
+    chunk_size: usize,
+) -> Result<Vec<RecordBatch>, ArrowError> {
+    if builder.bounds.is_some() {
+        return Err(ArrowError::InvalidArgumentError(
+            "Bounds are not supported when reading CSV in parallel".to_string(),
+        ));
+    }
+
+    let chunks = split_chunks(data, chunk_size, &builder.format);
+    let batches = chunks
+        .par_iter()
+        .enumerate()
+        .map(|(idx, chunk)| {
+            let mut decoder = chunk_decoder(&builder, idx == 0);
+            if idx != 0 {
+                decoder.line_number = chunk.records_before;
+            }
+            decode_chunk(decoder, &data[chunk.range.clone()])
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(batches.into_iter().flatten().collect())
+}
+
+/// Returns a [`Decoder`] for a chunk, only the first of which contains the header
+fn chunk_decoder(builder: &ReaderBuilder, first: bool) -> Decoder {
+    let mut format = builder.format.clone();
+    format.header &= first;
+    ReaderBuilder {
+        schema: builder.schema.clone(),
+        format,
+        batch_size: builder.batch_size,
+        bounds: None,
+        projection: builder.projection.clone(),
+        recycler: builder.recycler.clone(),
+    }
+    .build_decoder()
+}
+
+/// Decodes all the records of `data` with `decoder`
+fn decode_chunk(mut decoder: Decoder, mut data: &[u8]) -> Result<Vec<RecordBatch>, ArrowError> {
+    let mut batches = vec![];
+    loop {
+        loop {
+            let decoded = decoder.decode(data)?;
+            if decoded == 0 {
+                break;
+            }
+            data = &data[decoded..];
+        }
+        match decoder.flush()? {
+            Some(batch) => batches.push(batch),
+            None => return Ok(batches),
+        }
+    }
+}
+
+/// Splits `data` into chunks of at least `chunk_size` bytes, except for the last, that
+/// end on record boundaries
+///
+/// This follows the quoting rules of the CSV parser, so that terminators within quoted
+/// fields are not mistaken for record boundaries
+fn split_chunks(data: &[u8], chunk_size: usize, format: &Format) -> Vec<Chunk> {
+    let delimiter = format.delimiter.unwrap_or(b',');
+    let quote = format.quote.unwrap_or(b'"');
+    let is_terminator = |b: u8| match format.terminator {
+        Some(terminator) => b == terminator,
+        // The default CRLF terminator accepts any of `\r`, `\n` or `\r\n`
+        None => b == b'\n' || b == b'\r',
+    };
+
+    let mut chunks = vec![];
+    let mut start = 0;
+    let mut records_before = 0;
+    let mut records = 0;
+
+    let mut in_quotes = false;
+    // True if the previous byte closed a quoted field, as `""` is an escaped quote
+    let mut quote_closed = false;
+    let mut in_comment = false;
+    let mut record_start = true;
+    let mut field_start = true;
+
+    let mut idx = 0;
+    while idx < data.len() {
+        let b = data[idx];
+        idx += 1;
+
+        if in_quotes {
+            if Some(b) == format.escape {
+                // Skip the escaped byte
+                idx += 1;
+            } else if b == quote {
+                in_quotes = false;
+                quote_closed = true;
+            }
+            continue;
+        }
+
+        if std::mem::take(&mut quote_closed) && b == quote {
+            in_quotes = true;
+            continue;
+        }
+
+        if !is_terminator(b) {
+            if in_comment {
+                continue;
+            }
+            in_comment = record_start && Some(b) == format.comment;
+            in_quotes = field_start && b == quote;
+            field_start = b == delimiter;
+            record_start = false;
+            continue;
+        }
+
+        // Empty lines and comments are not records
+        records += (!record_start && !in_comment) as usize;
+        in_comment = false;
+        record_start = true;
+        field_start = true;
+
+        if idx - start >= chunk_size {
+            chunks.push(Chunk {
+                range: start..idx,
+                records_before,
+            });
+            start = idx;
+            records_before = records;
+        }
+    }
+
+    if start < data.len() || chunks.is_empty() {
+        chunks.push(Chunk {
+            range: start..data.len(),
+            records_before,
+        });
+    }
+    chunks
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int32Type;
+    use arrow_schema::{DataType, Field, Schema, SchemaRef};
+
+    use super::*;
+
+    fn chunk(range: Range<usize>, records_before: usize) -> Chunk {
+        Chunk {
+            range,
+            records_before,
+        }
+    }
+
+    #[test]
+    fn test_split_chunks() {
+        let format = Format::default();
+        let data = b"a,b\n\"1\n2\",3\n\n4,\"\"\"5\n\"\"\"\n6,7";
+        assert_eq!(
+            split_chunks(data, 1, &format),
+            vec![
+                chunk(0..4, 0),
+                chunk(4..12, 1),
+                chunk(12..13, 2),
+                chunk(13..24, 2),
+                chunk(24..27, 3)
+            ]
+        );
+        assert_eq!(
+            split_chunks(data, 10, &format),
+            vec![chunk(0..12, 0), chunk(12..24, 2), chunk(24..27, 3)]
+        );
+        assert_eq!(split_chunks(data, 100, &format), vec![chunk(0..27, 0)]);
+        assert_eq!(split_chunks(b"", 1, &format), vec![chunk(0..0, 0)]);
+
+        let format = Format::default()
+            .with_escape(b'\\')
+            .with_comment(b'#')
+            .with_terminator(b';');
+        let data = b"#\"x;1,\"a\\\";b\";\r2,c;";
+        assert_eq!(
+            split_chunks(data, 1, &format),
+            vec![chunk(0..4, 0), chunk(4..14, 0), chunk(14..19, 1)]
+        );
+    }
+
+    fn schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ]))
+    }
+
+    /// Returns the values of each row of `batches`
+    fn rows(batches: &[RecordBatch]) -> Vec<(Option<i32>, Option<String>)> {
+        batches
+            .iter()
+            .flat_map(|batch| {
+                let a = batch.column(0).as_primitive::<Int32Type>();
+                let b = batch.column(1).as_string::<i32>();
+                a.iter().zip(b.iter().map(|b| b.map(String::from)))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_read_parallel() {
+        let mut data = String::from("a,b\n");
+        for i in 0..100 {
+            match i % 4 {
+                0 => data.push_str(&format!("{i},\"multi\nline\"\n")),
+                1 => data.push_str(&format!("{i},\"quoted \"\" ,\"\r\n")),
+                2 => data.push_str("\n,\n"),
+                _ => data.push_str(&format!("{i},plain\n")),
+            }
+        }
+        let reader = ReaderBuilder::new(schema())
+            .with_header(true)
+            .build(data.as_bytes())
+            .unwrap();
+        let expected = rows(&reader.collect::<Result<Vec<_>, _>>().unwrap());
+        assert_eq!(expected.len(), 100);
+
+        for chunk_size in [1, 7, 64, 1024, 1 << 20] {
+            let batches = ReaderBuilder::new(schema())
+                .with_header(true)
+                .with_batch_size(3)
+                .read_parallel(data.as_bytes(), chunk_size)
+                .unwrap();
+            assert!(batches.iter().all(|b| b.num_rows() <= 3));
+            assert_eq!(rows(&batches), expected, "{chunk_size}");
+        }
+
+        let batches = ReaderBuilder::new(schema())
+            .with_header(true)
+            .with_projection(vec![0])
+            .read_parallel(data.as_bytes(), 64)
+            .unwrap();
+        let a = batches[0].column(0).as_primitive::<Int32Type>();
+        assert_eq!(a.value(0), 0);
+        assert_eq!(batches[0].num_columns(), 1);
+    }
+
+    #[test]
+    fn test_read_parallel_error() {
+        let data = b"a,b\n1,x\n2,y\n\n3,z\nfour,w\n5,v\n";
+        let sequential = ReaderBuilder::new(schema())
+            .with_header(true)
+            .build(data.as_slice())
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap_err();
+
+        let parallel = ReaderBuilder::new(schema())
+            .with_header(true)
+            .read_parallel(data, 1)
+            .unwrap_err();
+        assert_eq!(parallel.to_string(), sequential.to_string());
+
+        let err = ReaderBuilder::new(schema())
+            .with_bounds(0, 1)
+            .read_parallel(data, 1)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Bounds are not supported when reading CSV in parallel"
+        );
+    }
+}
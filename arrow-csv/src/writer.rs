@@ -72,6 +72,33 @@ use std::io::Write;
 use crate::map_csv_error;
 const DEFAULT_NULL_VALUE: &str = "";
 
+/// When fields are quoted, see [`WriterBuilder::with_quote_style`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum QuoteStyle {
+    /// Quote all fields, including the header and null values
+    Always,
+    /// Only quote fields that contain a quote, delimiter or record terminator
+    #[default]
+    Necessary,
+    /// Never quote fields, even if this produces invalid CSV
+    Never,
+}
+
+/// The terminator written after each record, see [`WriterBuilder::with_terminator`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Terminator {
+    /// `\r\n`, as expected by tools such as Excel
+    CRLF,
+    /// The given byte
+    Any(u8),
+}
+
+impl Default for Terminator {
+    fn default() -> Self {
+        Self::Any(b'\n')
+    }
+}
+
 /// A CSV writer
 #[derive(Debug)]
 pub struct Writer<W: Write> {
@@ -89,6 +116,10 @@ pub struct Writer<W: Write> {
     timestamp_tz_format: Option<String>,
     /// The time format for time arrays, defaults to RFC3339
     time_format: Option<String>,
+    /// The format for duration arrays, defaults to ISO 8601
+    duration_format: DurationFormat,
+    /// The format for interval arrays, defaults to [`IntervalFormat::Pretty`]
+    interval_format: IntervalFormat,
     /// Is the beginning-of-writer
     beginning: bool,
     /// The value to represent null entries, defaults to [`DEFAULT_NULL_VALUE`]
@@ -126,7 +157,9 @@ impl<W: Write> Writer<W> {
             .with_datetime_format(self.datetime_format.as_deref())
             .with_timestamp_format(self.timestamp_format.as_deref())
             .with_timestamp_tz_format(self.timestamp_tz_format.as_deref())
-            .with_time_format(self.time_format.as_deref());
+            .with_time_format(self.time_format.as_deref())
+            .with_duration_format(self.duration_format)
+            .with_interval_format(self.interval_format);
 
         let converters = batch
             .columns()
@@ -199,6 +232,10 @@ pub struct WriterBuilder {
     escape: u8,
     /// Enable double quote escapes. Defaults to `true`
     double_quote: bool,
+    /// When to quote fields. Defaults to [`QuoteStyle::Necessary`]
+    quote_style: QuoteStyle,
+    /// The record terminator. Defaults to `b'\n'`
+    terminator: Terminator,
     /// Optional date format for date arrays
     date_format: Option<String>,
     /// Optional datetime format for datetime arrays
@@ -209,6 +246,10 @@ pub struct WriterBuilder {
     timestamp_tz_format: Option<String>,
     /// Optional time format for time arrays
     time_format: Option<String>,
+    /// Format for duration arrays. Defaults to [`DurationFormat::ISO8601`]
+    duration_format: DurationFormat,
+    /// Format for interval arrays. Defaults to [`IntervalFormat::Pretty`]
+    interval_format: IntervalFormat,
     /// Optional value to represent null
     null_value: Option<String>,
 }
@@ -221,11 +262,15 @@ impl Default for WriterBuilder {
             quote: b'"',
             escape: b'\\',
             double_quote: true,
+            quote_style: QuoteStyle::default(),
+            terminator: Terminator::default(),
             date_format: None,
             datetime_format: None,
             timestamp_format: None,
             timestamp_tz_format: None,
             time_format: None,
+            duration_format: DurationFormat::default(),
+            interval_format: IntervalFormat::default(),
             null_value: None,
         }
     }
@@ -323,6 +368,28 @@ impl WriterBuilder {
         self.double_quote
     }
 
+    /// Set when fields are quoted, defaults to [`QuoteStyle::Necessary`]
+    pub fn with_quote_style(mut self, quote_style: QuoteStyle) -> Self {
+        self.quote_style = quote_style;
+        self
+    }
+
+    /// Get when fields are quoted
+    pub fn quote_style(&self) -> QuoteStyle {
+        self.quote_style
+    }
+
+    /// Set the terminator written after each record, defaults to `\n`
+    pub fn with_terminator(mut self, terminator: Terminator) -> Self {
+        self.terminator = terminator;
+        self
+    }
+
+    /// Get the terminator written after each record
+    pub fn terminator(&self) -> Terminator {
+        self.terminator
+    }
+
     /// Set the CSV file's date format
     pub fn with_date_format(mut self, format: String) -> Self {
         self.date_format = Some(format);
@@ -378,6 +445,28 @@ impl WriterBuilder {
         self.timestamp_tz_format.as_deref()
     }
 
+    /// Set the CSV file's duration format
+    pub fn with_duration_format(mut self, format: DurationFormat) -> Self {
+        self.duration_format = format;
+        self
+    }
+
+    /// Get the CSV file's duration format, defaults to [`DurationFormat::ISO8601`]
+    pub fn duration_format(&self) -> DurationFormat {
+        self.duration_format
+    }
+
+    /// Set the CSV file's interval format
+    pub fn with_interval_format(mut self, format: IntervalFormat) -> Self {
+        self.interval_format = format;
+        self
+    }
+
+    /// Get the CSV file's interval format, defaults to [`IntervalFormat::Pretty`]
+    pub fn interval_format(&self) -> IntervalFormat {
+        self.interval_format
+    }
+
     /// Set the value to represent null in output
     pub fn with_null(mut self, null_value: String) -> Self {
         self.null_value = Some(null_value);
@@ -397,6 +486,15 @@ impl WriterBuilder {
             .quote(self.quote)
             .double_quote(self.double_quote)
             .escape(self.escape)
+            .quote_style(match self.quote_style {
+                QuoteStyle::Always => csv::QuoteStyle::Always,
+                QuoteStyle::Necessary => csv::QuoteStyle::Necessary,
+                QuoteStyle::Never => csv::QuoteStyle::Never,
+            })
+            .terminator(match self.terminator {
+                Terminator::CRLF => csv::Terminator::CRLF,
+                Terminator::Any(b) => csv::Terminator::Any(b),
+            })
             .from_writer(writer);
         Writer {
             writer,
@@ -407,6 +505,8 @@ impl WriterBuilder {
             time_format: self.time_format,
             timestamp_format: self.timestamp_format,
             timestamp_tz_format: self.timestamp_tz_format,
+            duration_format: self.duration_format,
+            interval_format: self.interval_format,
             null_value: self.null_value,
         }
     }
@@ -626,6 +726,47 @@ sed do eiusmod tempor,-556132.25,1,,2019-04-18T02:45:55.555,23:46:03,foo
         );
     }
 
+    #[test]
+    fn test_write_csv_quote_style_and_terminator() {
+        let schema = Schema::new(vec![
+            Field::new("c1", DataType::Utf8, true),
+            Field::new("c2", DataType::Int32, true),
+        ]);
+        let c1 = StringArray::from(vec![Some("a,b"), None, Some("say \"hi\"")]);
+        let c2 = Int32Array::from(vec![Some(1), Some(2), None]);
+        let batch =
+            RecordBatch::try_new(Arc::new(schema), vec![Arc::new(c1), Arc::new(c2)]).unwrap();
+
+        let write = |builder: WriterBuilder| {
+            let mut buffer = vec![];
+            builder.build(&mut buffer).write(&batch).unwrap();
+            String::from_utf8(buffer).unwrap()
+        };
+
+        assert_eq!(
+            write(WriterBuilder::new()),
+            "c1,c2\n\"a,b\",1\n,2\n\"say \"\"hi\"\"\",\n"
+        );
+        assert_eq!(
+            write(
+                WriterBuilder::new()
+                    .with_quote_style(QuoteStyle::Always)
+                    .with_terminator(Terminator::CRLF)
+                    .with_null("NULL".to_string())
+            ),
+            "\"c1\",\"c2\"\r\n\"a,b\",\"1\"\r\n\"NULL\",\"2\"\r\n\"say \"\"hi\"\"\",\"NULL\"\r\n"
+        );
+        assert_eq!(
+            write(
+                WriterBuilder::new()
+                    .with_header(false)
+                    .with_quote_style(QuoteStyle::Never)
+                    .with_terminator(Terminator::Any(b';'))
+            ),
+            "a,b,1;,2;say \"hi\",;"
+        );
+    }
+
     #[test]
     fn test_conversion_consistency() {
         // test if we can serialize and deserialize whilst retaining the same type information/ precision
@@ -794,6 +935,34 @@ sed do eiusmod tempor,-556132.25,1,,2019-04-18T02:45:55.555,23:46:03,foo
         );
     }
 
+    #[test]
+    fn test_write_csv_duration_interval_format() {
+        let schema = Schema::new(vec![
+            Field::new("c1", DataType::Duration(TimeUnit::Second), true),
+            Field::new("c2", DataType::Interval(IntervalUnit::MonthDayNano), true),
+        ]);
+        let c1 = DurationSecondArray::from(vec![Some(7380), None]);
+        let c2 = IntervalMonthDayNanoArray::from(vec![
+            Some(IntervalMonthDayNano::new(14, 3, 0)),
+            Some(IntervalMonthDayNano::new(0, 0, 1_500_000_000)),
+        ]);
+        let batch =
+            RecordBatch::try_new(Arc::new(schema), vec![Arc::new(c1), Arc::new(c2)]).unwrap();
+
+        let mut buffer: Vec<u8> = vec![];
+        let mut writer = WriterBuilder::new()
+            .with_duration_format(DurationFormat::Compact)
+            .with_interval_format(IntervalFormat::ISO8601)
+            .build(&mut buffer);
+        writer.write(&batch).unwrap();
+        drop(writer);
+
+        assert_eq!(
+            "c1,c2\n2h 3m,P1Y2M3D\n,PT1.5S\n",
+            String::from_utf8(buffer).unwrap()
+        );
+    }
+
     #[test]
     fn test_write_csv_binary() {
         let fixed_size = 8;
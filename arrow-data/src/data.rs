@@ -21,7 +21,8 @@
 use crate::bit_iterator::BitSliceIterator;
 use arrow_buffer::buffer::{BooleanBuffer, NullBuffer};
 use arrow_buffer::{
-    bit_util, i256, ArrowNativeType, Buffer, IntervalDayTime, IntervalMonthDayNano, MutableBuffer,
+    bit_util, i256, ArrowNativeType, Buffer, BufferRecycler, IntervalDayTime, IntervalMonthDayNano,
+    MutableBuffer,
 };
 use arrow_schema::{ArrowError, DataType, UnionMode};
 use std::mem;
@@ -976,7 +977,7 @@ impl ArrayData {
     ) -> Result<(), ArrowError> {
         let offsets: &[T] = self.typed_buffer(0, self.len)?;
         let sizes: &[T] = self.typed_buffer(1, self.len)?;
-        for i in 0..values_length {
+        for i in 0..self.len {
             let size = sizes[i].to_usize().ok_or_else(|| {
                 ArrowError::InvalidArgumentError(format!(
                     "Error converting size[{}] ({}) to usize for {}",
@@ -1423,7 +1424,7 @@ impl ArrayData {
         T: ArrowNativeType + TryInto<usize> + num::Num + std::fmt::Display,
     {
         let values_buffer = &self.buffers[1].as_slice();
-        if let Ok(values_str) = std::str::from_utf8(values_buffer) {
+        if let Ok(values_str) = arrow_buffer::utf8::from_utf8(values_buffer) {
             // Validate Offsets are correct
             self.validate_each_offset::<T, _>(values_buffer.len(), |string_index, range| {
                 if !values_str.is_char_boundary(range.start)
@@ -1438,7 +1439,7 @@ impl ArrayData {
         } else {
             // find specific offset that failed utf8 validation
             self.validate_each_offset::<T, _>(values_buffer.len(), |string_index, range| {
-                std::str::from_utf8(&values_buffer[range.clone()]).map_err(|e| {
+                arrow_buffer::utf8::from_utf8(&values_buffer[range.clone()]).map_err(|e| {
                     ArrowError::InvalidArgumentError(format!(
                         "Invalid UTF8 sequence at string index {string_index} ({range:?}): {e}"
                     ))
@@ -1572,6 +1573,22 @@ impl ArrayData {
     pub fn into_builder(self) -> ArrayDataBuilder {
         self.into()
     }
+
+    /// Returns the buffers of this [`ArrayData`], including those of its children
+    /// and null buffers, to `recycler` so that they can be reused
+    ///
+    /// Buffers that are shared with other arrays are dropped, see [`BufferRecycler::recycle`]
+    pub fn recycle_buffers(self, recycler: &BufferRecycler) {
+        if let Some(nulls) = self.nulls {
+            recycler.recycle(nulls.into_inner().into_inner());
+        }
+        for buffer in self.buffers {
+            recycler.recycle(buffer);
+        }
+        for child in self.child_data {
+            child.recycle_buffers(recycler);
+        }
+    }
 }
 
 /// Return the expected [`DataTypeLayout`] Arrays of this data
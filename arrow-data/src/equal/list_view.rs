@@ -0,0 +1,63 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::data::ArrayData;
+use arrow_buffer::ArrowNativeType;
+use num::Integer;
+
+use super::equal_range;
+
+pub(super) fn list_view_equal<T: ArrowNativeType + Integer>(
+    lhs: &ArrayData,
+    rhs: &ArrayData,
+    lhs_start: usize,
+    rhs_start: usize,
+    len: usize,
+) -> bool {
+    let lhs_offsets = lhs.buffer::<T>(0);
+    let lhs_sizes = lhs.buffer::<T>(1);
+    let rhs_offsets = rhs.buffer::<T>(0);
+    let rhs_sizes = rhs.buffer::<T>(1);
+
+    let lhs_values = &lhs.child_data()[0];
+    let rhs_values = &rhs.child_data()[0];
+
+    // Unlike lists, the values of consecutive list views need not be contiguous, or
+    // even ordered, and so each list view is compared separately
+    (0..len).all(|i| {
+        let lhs_pos = lhs_start + i;
+        let rhs_pos = rhs_start + i;
+
+        let lhs_is_null = lhs.is_null(lhs_pos);
+        if lhs_is_null != rhs.is_null(rhs_pos) {
+            return false;
+        }
+
+        let lhs_len = lhs_sizes[lhs_pos].as_usize();
+        let rhs_len = rhs_sizes[rhs_pos].as_usize();
+
+        lhs_is_null
+            || (lhs_len == rhs_len
+                && equal_range(
+                    lhs_values,
+                    rhs_values,
+                    lhs_offsets[lhs_pos].as_usize(),
+                    rhs_offsets[rhs_pos].as_usize(),
+                    lhs_len,
+                ))
+    })
+}
@@ -0,0 +1,54 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use super::{Extend, _MutableArrayData};
+use crate::ArrayData;
+use arrow_buffer::ArrowNativeType;
+use num::Integer;
+
+pub(super) fn build_extend<T: ArrowNativeType + Integer>(array: &ArrayData) -> Extend<'_> {
+    let offsets = array.buffer::<T>(0);
+    let sizes = array.buffer::<T>(1);
+    Box::new(
+        move |mutable: &mut _MutableArrayData, index: usize, start: usize, len: usize| {
+            mutable.buffer1.reserve(len * size_of::<T>());
+            mutable.buffer2.reserve(len * size_of::<T>());
+
+            // The values of each list view are copied, so that they are contiguous
+            // in the output, with the values of null list views omitted
+            for i in start..start + len {
+                let size = match array.is_null(i) {
+                    true => T::zero(),
+                    false => sizes[i],
+                };
+                let child = &mut mutable.child_data[0];
+                let new_offset = T::from_usize(child.len()).expect("offset overflow");
+                if !size.is_zero() {
+                    let offset = offsets[i].as_usize();
+                    child.extend(index, offset, offset + size.as_usize());
+                }
+                mutable.buffer1.push(new_offset);
+                mutable.buffer2.push(size);
+            }
+        },
+    )
+}
+
+pub(super) fn extend_nulls<T: ArrowNativeType>(mutable: &mut _MutableArrayData, len: usize) {
+    mutable.buffer1.extend_zeros(len * size_of::<T>());
+    mutable.buffer2.extend_zeros(len * size_of::<T>());
+}
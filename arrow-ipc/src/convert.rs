@@ -297,6 +297,92 @@ pub fn try_schema_from_ipc_buffer(buffer: &[u8]) -> Result<Schema, ArrowError> {
     Ok(fb_to_schema(ipc_schema))
 }
 
+/// Compact binary encoding of a [`Schema`] or [`DataType`]
+///
+/// This allows persisting schemas, for example in a catalog, without the overhead
+/// of a full IPC writer. The encoding is the flatbuffer `Message` containing the
+/// schema that begins an IPC stream, without the encapsulation prefix. The message
+/// records the [`MetadataVersion`] it was written with, allowing the encoding to
+/// evolve along with the IPC format.
+///
+/// A [`DataType`] is encoded as a schema with a single nullable field named `item`.
+///
+/// ```
+/// # use arrow_ipc::convert::SchemaEncoding;
+/// # use arrow_schema::{DataType, Field, Schema};
+/// let schema = Schema::new(vec![
+///     Field::new("a", DataType::Int32, false),
+///     Field::new_list("b", Field::new_list_field(DataType::Utf8, true), true),
+/// ]);
+/// let bytes = schema.to_bytes();
+/// assert_eq!(Schema::from_bytes(&bytes).unwrap(), schema);
+///
+/// let data_type = DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8));
+/// let bytes = data_type.to_bytes();
+/// assert_eq!(DataType::from_bytes(&bytes).unwrap(), data_type);
+/// ```
+///
+/// [`MetadataVersion`]: crate::MetadataVersion
+pub trait SchemaEncoding: Sized {
+    /// Encode this value to bytes
+    fn to_bytes(&self) -> Vec<u8>;
+
+    /// Decode a value from bytes produced by [`Self::to_bytes`]
+    fn from_bytes(bytes: &[u8]) -> Result<Self, ArrowError>;
+}
+
+impl SchemaEncoding for Schema {
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut dictionary_tracker = DictionaryTracker::new(false);
+        let mut fbb = FlatBufferBuilder::new();
+        let schema = IpcSchemaEncoder::new()
+            .with_dictionary_tracker(&mut dictionary_tracker)
+            .schema_to_fb_offset(&mut fbb, self);
+
+        let mut message = crate::MessageBuilder::new(&mut fbb);
+        message.add_version(crate::MetadataVersion::V5);
+        message.add_header_type(crate::MessageHeader::Schema);
+        message.add_bodyLength(0);
+        message.add_header(schema.as_union_value());
+        let message = message.finish();
+        fbb.finish(message, None);
+        fbb.finished_data().to_vec()
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, ArrowError> {
+        let message = crate::root_as_message(bytes).map_err(|err| {
+            ArrowError::ParseError(format!("Unable to get root as message: {err:?}"))
+        })?;
+        if message.version() < crate::MetadataVersion::V4 {
+            return Err(ArrowError::ParseError(format!(
+                "Unsupported schema metadata version {:?}",
+                message.version()
+            )));
+        }
+        let schema = message
+            .header_as_schema()
+            .filter(|schema| schema.fields().is_some())
+            .ok_or_else(|| ArrowError::ParseError("Unable to get header as schema".to_string()))?;
+        Ok(fb_to_schema(schema))
+    }
+}
+
+impl SchemaEncoding for DataType {
+    fn to_bytes(&self) -> Vec<u8> {
+        Schema::new(vec![Field::new_list_field(self.clone(), true)]).to_bytes()
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, ArrowError> {
+        let schema = Schema::from_bytes(bytes)?;
+        match schema.fields().len() {
+            1 => Ok(schema.field(0).data_type().clone()),
+            n => Err(ArrowError::ParseError(format!(
+                "Expected a single field when decoding a data type, got {n}"
+            ))),
+        }
+    }
+}
+
 /// Get the Arrow data type from the flatbuffer Field table
 pub(crate) fn get_data_type(field: crate::Field, may_be_dictionary: bool) -> DataType {
     if let Some(dictionary) = field.dictionary() {
@@ -1208,6 +1294,31 @@ mod tests {
         let ipc = crate::root_as_schema(fb.finished_data()).unwrap();
         let schema2 = fb_to_schema(ipc);
         assert_eq!(schema, schema2);
+
+        let schema3 = Schema::from_bytes(&schema.to_bytes()).unwrap();
+        assert_eq!(schema, schema3);
+        for field in schema.fields() {
+            let data_type = DataType::from_bytes(&field.data_type().to_bytes()).unwrap();
+            assert_eq!(&data_type, field.data_type());
+        }
+    }
+
+    #[test]
+    fn schema_encoding_errors() {
+        let err = Schema::from_bytes(&[1, 2, 3]).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("Parser error: Unable to get root as message"));
+
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Int32, false),
+        ]);
+        let err = DataType::from_bytes(&schema.to_bytes()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Expected a single field when decoding a data type, got 2"
+        );
     }
 
     #[test]
@@ -0,0 +1,399 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! A lightweight index of the messages in an [IPC Streaming Format] stream
+//!
+//! The [IPC Streaming Format] has no footer, so a reader can only locate the
+//! Nth [`RecordBatch`] by decoding every message before it. A [`StreamIndex`]
+//! records the byte offset, length and row count of each message, allowing
+//! a long-running consumer to resume from a given batch or byte offset, for
+//! example after a restart, without re-reading the data before it.
+//!
+//! An index can be obtained:
+//!
+//! * From [`StreamWriter::index`] while writing a stream
+//! * From the trailer appended by a [`StreamWriter`] configured with
+//!   [`IpcWriteOptions::with_stream_index`], using [`StreamIndex::read_trailer`]
+//! * By scanning the message headers of an existing, possibly truncated, stream
+//!   with [`StreamIndex::try_from_stream`]
+//!
+//! The index can then be passed to [`StreamReader::seek_to_batch`] or
+//! [`StreamReader::seek_to_offset`].
+//!
+//! # Trailer format
+//!
+//! The trailer is written after the end-of-stream marker, and so is ignored by
+//! readers that are unaware of it. It consists of one 25 byte entry per message,
+//! a 1 byte kind followed by the little-endian `u64` offset, length and row
+//! count, followed by the little-endian `u64` number of entries and the 8 byte
+//! magic `ARROWIDX`.
+//!
+//! [IPC Streaming Format]: https://arrow.apache.org/docs/format/Columnar.html#ipc-streaming-format
+//! [`RecordBatch`]: arrow_array::RecordBatch
+//! [`StreamWriter`]: crate::writer::StreamWriter
+//! [`StreamWriter::index`]: crate::writer::StreamWriter::index
+//! [`IpcWriteOptions::with_stream_index`]: crate::writer::IpcWriteOptions::with_stream_index
+//! [`StreamReader::seek_to_batch`]: crate::reader::StreamReader::seek_to_batch
+//! [`StreamReader::seek_to_offset`]: crate::reader::StreamReader::seek_to_offset
+
+use std::io::{ErrorKind, Read, Seek, SeekFrom, Write};
+
+use arrow_schema::ArrowError;
+
+use crate::{MessageHeader, CONTINUATION_MARKER};
+
+const INDEX_MAGIC: [u8; 8] = *b"ARROWIDX";
+
+/// The size in bytes of a serialized [`IndexEntry`]
+const ENTRY_SIZE: usize = 25;
+
+/// The size in bytes of the trailer footer, the entry count and magic
+const FOOTER_SIZE: usize = 16;
+
+/// The kind of message described by an [`IndexEntry`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndexEntryKind {
+    /// A dictionary batch, which must be replayed before any later record batch
+    Dictionary,
+    /// A record batch
+    RecordBatch,
+}
+
+/// The location of a single message within a stream
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IndexEntry {
+    kind: IndexEntryKind,
+    offset: u64,
+    length: u64,
+    num_rows: u64,
+}
+
+impl IndexEntry {
+    /// Create a new [`IndexEntry`]
+    pub fn new(kind: IndexEntryKind, offset: u64, length: u64, num_rows: u64) -> Self {
+        Self {
+            kind,
+            offset,
+            length,
+            num_rows,
+        }
+    }
+
+    /// The kind of message
+    pub fn kind(&self) -> IndexEntryKind {
+        self.kind
+    }
+
+    /// The byte offset of the message, including its continuation marker,
+    /// relative to the start of the stream
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// The length of the message in bytes, including its metadata and body
+    pub fn length(&self) -> u64 {
+        self.length
+    }
+
+    /// The number of rows in the record batch, or zero for a dictionary batch
+    pub fn num_rows(&self) -> u64 {
+        self.num_rows
+    }
+}
+
+/// An index of the dictionary and record batch messages in an IPC stream
+///
+/// See the [module docs](self) for more information
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StreamIndex {
+    entries: Vec<IndexEntry>,
+    num_batches: usize,
+}
+
+impl StreamIndex {
+    /// Create a new [`StreamIndex`] from a list of entries, in stream order
+    pub fn new(entries: Vec<IndexEntry>) -> Self {
+        let num_batches = entries
+            .iter()
+            .filter(|e| e.kind == IndexEntryKind::RecordBatch)
+            .count();
+        Self {
+            entries,
+            num_batches,
+        }
+    }
+
+    /// Append an entry to this index
+    pub fn push(&mut self, entry: IndexEntry) {
+        if entry.kind == IndexEntryKind::RecordBatch {
+            self.num_batches += 1;
+        }
+        self.entries.push(entry);
+    }
+
+    /// The entries of this index, in stream order
+    pub fn entries(&self) -> &[IndexEntry] {
+        &self.entries
+    }
+
+    /// The number of record batches in the stream
+    pub fn num_batches(&self) -> usize {
+        self.num_batches
+    }
+
+    /// The total number of rows across all record batches in the stream
+    pub fn num_rows(&self) -> u64 {
+        self.batches().map(|e| e.num_rows).sum()
+    }
+
+    /// Returns the entry for the `batch`th record batch, if any
+    pub fn batch(&self, batch: usize) -> Option<&IndexEntry> {
+        self.batches().nth(batch)
+    }
+
+    /// Returns the index of the first record batch starting at or after `offset`, if any
+    pub fn batch_at_offset(&self, offset: u64) -> Option<usize> {
+        self.batches().position(|e| e.offset >= offset)
+    }
+
+    /// Returns the index of the record batch containing the `row`th row of the
+    /// stream, along with the offset of that row within the batch
+    pub fn batch_for_row(&self, row: u64) -> Option<(usize, u64)> {
+        let mut start = 0;
+        for (idx, entry) in self.batches().enumerate() {
+            if row < start + entry.num_rows {
+                return Some((idx, row - start));
+            }
+            start += entry.num_rows;
+        }
+        None
+    }
+
+    fn batches(&self) -> impl Iterator<Item = &IndexEntry> {
+        self.entries
+            .iter()
+            .filter(|e| e.kind == IndexEntryKind::RecordBatch)
+    }
+
+    /// Write this index as a trailer, returning the number of bytes written
+    ///
+    /// This should be written after the end-of-stream marker, see
+    /// [`IpcWriteOptions::with_stream_index`](crate::writer::IpcWriteOptions::with_stream_index)
+    pub fn write_trailer<W: Write>(&self, mut writer: W) -> Result<usize, ArrowError> {
+        let mut buf = Vec::with_capacity(self.entries.len() * ENTRY_SIZE + FOOTER_SIZE);
+        for entry in &self.entries {
+            buf.push(match entry.kind {
+                IndexEntryKind::Dictionary => 0,
+                IndexEntryKind::RecordBatch => 1,
+            });
+            buf.extend_from_slice(&entry.offset.to_le_bytes());
+            buf.extend_from_slice(&entry.length.to_le_bytes());
+            buf.extend_from_slice(&entry.num_rows.to_le_bytes());
+        }
+        buf.extend_from_slice(&(self.entries.len() as u64).to_le_bytes());
+        buf.extend_from_slice(&INDEX_MAGIC);
+        writer.write_all(&buf)?;
+        Ok(buf.len())
+    }
+
+    /// Read an index from the trailer at the end of `reader`, returning `None`
+    /// if `reader` does not end with an index trailer
+    ///
+    /// This leaves `reader` positioned at an unspecified location
+    pub fn read_trailer<R: Read + Seek>(mut reader: R) -> Result<Option<Self>, ArrowError> {
+        let len = reader.seek(SeekFrom::End(0))?;
+        if len < FOOTER_SIZE as u64 {
+            return Ok(None);
+        }
+
+        let mut footer = [0; FOOTER_SIZE];
+        reader.seek(SeekFrom::End(-(FOOTER_SIZE as i64)))?;
+        reader.read_exact(&mut footer)?;
+        if footer[8..] != INDEX_MAGIC {
+            return Ok(None);
+        }
+
+        let num_entries = u64::from_le_bytes(footer[..8].try_into().unwrap());
+        let entries_len = num_entries
+            .checked_mul(ENTRY_SIZE as u64)
+            .filter(|l| l + FOOTER_SIZE as u64 <= len)
+            .ok_or_else(|| {
+                ArrowError::IpcError(format!(
+                    "Invalid stream index trailer with {num_entries} entries in stream of {len} bytes"
+                ))
+            })?;
+
+        let mut buf = vec![0; entries_len as usize];
+        reader.seek(SeekFrom::Start(len - FOOTER_SIZE as u64 - entries_len))?;
+        reader.read_exact(&mut buf)?;
+
+        let entries = buf
+            .chunks_exact(ENTRY_SIZE)
+            .map(|chunk| {
+                let kind = match chunk[0] {
+                    0 => IndexEntryKind::Dictionary,
+                    1 => IndexEntryKind::RecordBatch,
+                    k => {
+                        return Err(ArrowError::IpcError(format!(
+                            "Invalid stream index entry kind {k}"
+                        )))
+                    }
+                };
+                let read_u64 = |i: usize| u64::from_le_bytes(chunk[i..i + 8].try_into().unwrap());
+                Ok(IndexEntry::new(
+                    kind,
+                    read_u64(1),
+                    read_u64(9),
+                    read_u64(17),
+                ))
+            })
+            .collect::<Result<_, ArrowError>>()?;
+
+        Ok(Some(Self::new(entries)))
+    }
+
+    /// Build an index by scanning the message headers of the stream in `reader`
+    ///
+    /// Message bodies are skipped over rather than read, so this is much cheaper than
+    /// decoding the stream. Scanning stops at the end-of-stream marker, or at the first
+    /// incomplete message, allowing an index to be built for a stream whose writer did
+    /// not finish, for example because it crashed.
+    ///
+    /// The stream is expected to start at the beginning of `reader`, and `reader` is left
+    /// positioned at an unspecified location
+    pub fn try_from_stream<R: Read + Seek>(mut reader: R) -> Result<Self, ArrowError> {
+        let len = reader.seek(SeekFrom::End(0))?;
+        let mut offset = reader.seek(SeekFrom::Start(0))?;
+        let mut index = Self::default();
+        let mut meta_buffer = Vec::new();
+
+        loop {
+            let mut meta_size = [0; 4];
+            let mut prefix_len = 4;
+            match reader.read_exact(&mut meta_size) {
+                Ok(()) => {}
+                Err(e) if e.kind() == ErrorKind::UnexpectedEof => break,
+                Err(e) => return Err(e.into()),
+            }
+            if meta_size == CONTINUATION_MARKER {
+                match reader.read_exact(&mut meta_size) {
+                    Ok(()) => {}
+                    Err(e) if e.kind() == ErrorKind::UnexpectedEof => break,
+                    Err(e) => return Err(e.into()),
+                }
+                prefix_len = 8;
+            }
+
+            let meta_len = i32::from_le_bytes(meta_size);
+            if meta_len <= 0 {
+                break;
+            }
+
+            meta_buffer.resize(meta_len as usize, 0);
+            match reader.read_exact(&mut meta_buffer) {
+                Ok(()) => {}
+                Err(e) if e.kind() == ErrorKind::UnexpectedEof => break,
+                Err(e) => return Err(e.into()),
+            }
+
+            let message = crate::root_as_message(&meta_buffer).map_err(|err| {
+                ArrowError::ParseError(format!("Unable to get root as message: {err:?}"))
+            })?;
+            let body_len = message.bodyLength() as u64;
+            let length = prefix_len + meta_len as u64 + body_len;
+            if offset + length > len {
+                break;
+            }
+
+            match message.header_type() {
+                MessageHeader::RecordBatch => {
+                    let num_rows = message
+                        .header_as_record_batch()
+                        .map(|b| b.length() as u64)
+                        .unwrap_or_default();
+                    index.push(IndexEntry::new(
+                        IndexEntryKind::RecordBatch,
+                        offset,
+                        length,
+                        num_rows,
+                    ));
+                }
+                MessageHeader::DictionaryBatch => {
+                    index.push(IndexEntry::new(
+                        IndexEntryKind::Dictionary,
+                        offset,
+                        length,
+                        0,
+                    ));
+                }
+                _ => {}
+            }
+
+            offset = reader.seek(SeekFrom::Current(body_len as i64))?;
+        }
+
+        Ok(index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_trailer_roundtrip() {
+        let index = StreamIndex::new(vec![
+            IndexEntry::new(IndexEntryKind::Dictionary, 100, 50, 0),
+            IndexEntry::new(IndexEntryKind::RecordBatch, 150, 200, 10),
+            IndexEntry::new(IndexEntryKind::RecordBatch, 350, 200, 5),
+        ]);
+        assert_eq!(index.num_batches(), 2);
+        assert_eq!(index.num_rows(), 15);
+        assert_eq!(index.batch(1).unwrap().offset(), 350);
+        assert_eq!(index.batch_at_offset(151), Some(1));
+        assert_eq!(index.batch_at_offset(351), None);
+        assert_eq!(index.batch_for_row(0), Some((0, 0)));
+        assert_eq!(index.batch_for_row(12), Some((1, 2)));
+        assert_eq!(index.batch_for_row(15), None);
+
+        let mut buf = vec![0; 10];
+        let written = index.write_trailer(&mut buf).unwrap();
+        assert_eq!(written, 3 * ENTRY_SIZE + FOOTER_SIZE);
+
+        let read = StreamIndex::read_trailer(Cursor::new(&buf)).unwrap();
+        assert_eq!(read, Some(index));
+
+        assert_eq!(
+            StreamIndex::read_trailer(Cursor::new(&buf[..20])).unwrap(),
+            None
+        );
+        assert_eq!(StreamIndex::read_trailer(Cursor::new(&[])).unwrap(), None);
+    }
+
+    #[test]
+    fn test_invalid_trailer() {
+        let mut buf = vec![];
+        buf.extend_from_slice(&100_u64.to_le_bytes());
+        buf.extend_from_slice(&INDEX_MAGIC);
+        let err = StreamIndex::read_trailer(Cursor::new(&buf)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Ipc error: Invalid stream index trailer with 100 entries in stream of 16 bytes"
+        );
+    }
+}
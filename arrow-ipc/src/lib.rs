@@ -45,6 +45,7 @@
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 #![warn(missing_docs)]
 pub mod convert;
+pub mod index;
 pub mod reader;
 pub mod writer;
 
@@ -40,6 +40,7 @@ use arrow_data::{ArrayData, ArrayDataBuilder, UnsafeFlag};
 use arrow_schema::*;
 
 use crate::compression::CompressionCodec;
+use crate::index::{IndexEntryKind, StreamIndex};
 use crate::{Block, FieldNode, Message, MetadataVersion, CONTINUATION_MARKER};
 use DataType::*;
 
@@ -1454,8 +1455,20 @@ impl<R: Read> StreamReader<R> {
     }
 
     fn maybe_next(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
+        loop {
+            match self.read_message()? {
+                StreamMessage::RecordBatch(batch) => return Ok(Some(batch)),
+                // read the next message until we encounter a RecordBatch
+                StreamMessage::Dictionary => continue,
+                StreamMessage::End => return Ok(None),
+            }
+        }
+    }
+
+    /// Reads the next message, decoding any dictionary batch into `dictionaries_by_id`
+    fn read_message(&mut self) -> Result<StreamMessage, ArrowError> {
         if self.finished {
-            return Ok(None);
+            return Ok(StreamMessage::End);
         }
         // determine metadata length
         let mut meta_size: [u8; 4] = [0; 4];
@@ -1468,7 +1481,7 @@ impl<R: Read> StreamReader<R> {
                     // valid according to:
                     // https://arrow.apache.org/docs/format/Columnar.html#ipc-streaming-format
                     self.finished = true;
-                    Ok(None)
+                    Ok(StreamMessage::End)
                 } else {
                     Err(ArrowError::from(e))
                 };
@@ -1487,7 +1500,7 @@ impl<R: Read> StreamReader<R> {
         if meta_len == 0 {
             // the stream has ended, mark the reader as finished
             self.finished = true;
-            return Ok(None);
+            return Ok(StreamMessage::End);
         }
 
         let mut meta_buffer = vec![0; meta_len as usize];
@@ -1521,7 +1534,7 @@ impl<R: Read> StreamReader<R> {
                 .with_require_alignment(false)
                 .with_skip_validation(self.skip_validation.clone())
                 .read_record_batch()
-                .map(Some)
+                .map(StreamMessage::RecordBatch)
             }
             crate::MessageHeader::DictionaryBatch => {
                 let batch = message.header_as_dictionary_batch().ok_or_else(|| {
@@ -1542,11 +1555,9 @@ impl<R: Read> StreamReader<R> {
                     false,
                     self.skip_validation.clone(),
                 )?;
-
-                // read the next message until we encounter a RecordBatch
-                self.maybe_next()
+                Ok(StreamMessage::Dictionary)
             }
-            crate::MessageHeader::NONE => Ok(None),
+            crate::MessageHeader::NONE => Ok(StreamMessage::End),
             t => Err(ArrowError::InvalidArgumentError(format!(
                 "Reading types other than record batches not yet supported, unable to read {t:?} "
             ))),
@@ -1578,6 +1589,100 @@ impl<R: Read> StreamReader<R> {
     }
 }
 
+impl<R: Read + Seek> StreamReader<R> {
+    /// Seek to the `batch`th [`RecordBatch`] of the stream using a [`StreamIndex`]
+    ///
+    /// Any dictionary batches preceding the record batch are re-read, so that
+    /// subsequent batches can be decoded, but no other data before it is read.
+    /// Seeking to `index.num_batches()` positions the reader at the end of the stream.
+    ///
+    /// The stream is expected to start at the beginning of the reader, as the
+    /// offsets in the index are relative to the start of the stream.
+    ///
+    /// ```
+    /// # use arrow_array::record_batch;
+    /// # use arrow_ipc::index::StreamIndex;
+    /// # use arrow_ipc::reader::StreamReader;
+    /// # use arrow_ipc::writer::{IpcWriteOptions, StreamWriter};
+    /// # use std::io::Cursor;
+    /// let batch = record_batch!(("a", Int32, [1, 2, 3])).unwrap();
+    /// let options = IpcWriteOptions::default().with_stream_index(true);
+    /// let mut writer = StreamWriter::try_new_with_options(vec![], &batch.schema(), options).unwrap();
+    /// for _ in 0..10 {
+    ///     writer.write(&batch).unwrap();
+    /// }
+    /// let mut stream = Cursor::new(writer.into_inner().unwrap());
+    ///
+    /// // After a restart, resume reading from the 8th batch
+    /// let index = StreamIndex::read_trailer(&mut stream).unwrap().unwrap();
+    /// stream.set_position(0);
+    /// let mut reader = StreamReader::try_new(stream, None).unwrap();
+    /// reader.seek_to_batch(&index, 7).unwrap();
+    /// assert_eq!(reader.count(), 3);
+    /// ```
+    pub fn seek_to_batch(&mut self, index: &StreamIndex, batch: usize) -> Result<(), ArrowError> {
+        let target = match index.batch(batch) {
+            Some(entry) => entry.offset(),
+            None if batch == index.num_batches() => {
+                self.finished = true;
+                return Ok(());
+            }
+            None => {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Cannot seek to batch {batch} of stream with {} batches",
+                    index.num_batches()
+                )))
+            }
+        };
+
+        self.finished = false;
+        self.dictionaries_by_id.clear();
+        for entry in index.entries() {
+            if entry.offset() >= target {
+                break;
+            }
+            if entry.kind() != IndexEntryKind::Dictionary {
+                continue;
+            }
+            self.reader.seek(SeekFrom::Start(entry.offset()))?;
+            match self.read_message()? {
+                StreamMessage::Dictionary => {}
+                _ => {
+                    return Err(ArrowError::IpcError(format!(
+                    "Stream index does not match stream, expected dictionary batch at offset {}",
+                    entry.offset()
+                )))
+                }
+            }
+        }
+
+        self.reader.seek(SeekFrom::Start(target))?;
+        Ok(())
+    }
+
+    /// Seek to the first [`RecordBatch`] starting at or after byte `offset` of the
+    /// stream using a [`StreamIndex`], returning the index of that batch
+    ///
+    /// This allows resuming from a byte offset recorded by a previous consumer,
+    /// see [`Self::seek_to_batch`] for more details
+    pub fn seek_to_offset(
+        &mut self,
+        index: &StreamIndex,
+        offset: u64,
+    ) -> Result<usize, ArrowError> {
+        let batch = index.batch_at_offset(offset).unwrap_or(index.num_batches());
+        self.seek_to_batch(index, batch)?;
+        Ok(batch)
+    }
+}
+
+/// A message read by a [`StreamReader`]
+enum StreamMessage {
+    RecordBatch(RecordBatch),
+    Dictionary,
+    End,
+}
+
 impl<R: Read> Iterator for StreamReader<R> {
     type Item = Result<RecordBatch, ArrowError>;
 
@@ -2875,4 +2980,100 @@ mod tests {
 
         assert_eq!(schema, new_schema);
     }
+
+    fn stream_index_batches() -> Vec<RecordBatch> {
+        (0..5)
+            .map(|i| {
+                let values = [format!("a{i}"), format!("b{i}")];
+                let dict: DictionaryArray<Int32Type> = values
+                    .iter()
+                    .map(|s| s.as_str())
+                    .chain([values[0].as_str()])
+                    .collect();
+                let ints = Int32Array::from(vec![i; 3]);
+                RecordBatch::try_from_iter([
+                    ("dict", Arc::new(dict) as ArrayRef),
+                    ("int", Arc::new(ints) as ArrayRef),
+                ])
+                .unwrap()
+            })
+            .collect()
+    }
+
+    fn write_indexed_stream(batches: &[RecordBatch], write_index: bool) -> (Vec<u8>, StreamIndex) {
+        let options = IpcWriteOptions::default().with_stream_index(write_index);
+        let mut writer = crate::writer::StreamWriter::try_new_with_options(
+            vec![],
+            &batches[0].schema(),
+            options,
+        )
+        .unwrap();
+        for batch in batches {
+            writer.write(batch).unwrap();
+        }
+        let index = writer.index().clone();
+        (writer.into_inner().unwrap(), index)
+    }
+
+    #[test]
+    fn test_stream_index_seek() {
+        let batches = stream_index_batches();
+        let (buf, index) = write_indexed_stream(&batches, true);
+        assert_eq!(index.num_batches(), 5);
+        assert_eq!(index.num_rows(), 15);
+        assert_eq!(index.entries().len(), 10);
+
+        // The trailer is ignored by readers
+        let reader = StreamReader::try_new(buf.as_slice(), None).unwrap();
+        let read: Vec<_> = reader.collect::<Result<_, _>>().unwrap();
+        assert_eq!(read, batches);
+
+        let trailer = StreamIndex::read_trailer(std::io::Cursor::new(&buf)).unwrap();
+        assert_eq!(trailer.as_ref(), Some(&index));
+
+        let scanned = StreamIndex::try_from_stream(std::io::Cursor::new(&buf)).unwrap();
+        assert_eq!(scanned, index);
+
+        let mut reader = StreamReader::try_new(std::io::Cursor::new(&buf), None).unwrap();
+        reader.seek_to_batch(&index, 3).unwrap();
+        let read: Vec<_> = reader.by_ref().collect::<Result<_, _>>().unwrap();
+        assert_eq!(read, batches[3..]);
+
+        reader.seek_to_batch(&index, 1).unwrap();
+        assert_eq!(reader.next().unwrap().unwrap(), batches[1]);
+
+        let offset = index.batch(2).unwrap().offset() - 1;
+        assert_eq!(reader.seek_to_offset(&index, offset).unwrap(), 2);
+        assert_eq!(reader.next().unwrap().unwrap(), batches[2]);
+
+        assert_eq!(reader.seek_to_offset(&index, u64::MAX).unwrap(), 5);
+        assert!(reader.next().is_none());
+
+        let err = reader.seek_to_batch(&index, 6).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot seek to batch 6 of stream with 5 batches"
+        );
+    }
+
+    #[test]
+    fn test_stream_index_truncated() {
+        let batches = stream_index_batches();
+        let (buf, index) = write_indexed_stream(&batches, false);
+        assert_eq!(
+            StreamIndex::read_trailer(std::io::Cursor::new(&buf)).unwrap(),
+            None
+        );
+
+        // Simulate a writer that crashed part way through the 4th batch
+        let entry = index.batch(3).unwrap();
+        let truncated = &buf[..(entry.offset() + entry.length() / 2) as usize];
+        let scanned = StreamIndex::try_from_stream(std::io::Cursor::new(truncated)).unwrap();
+        assert_eq!(scanned.num_batches(), 3);
+        assert_eq!(scanned.entries(), &index.entries()[..7]);
+
+        let mut reader = StreamReader::try_new(std::io::Cursor::new(truncated), None).unwrap();
+        reader.seek_to_batch(&scanned, 2).unwrap();
+        assert_eq!(reader.next().unwrap().unwrap(), batches[2]);
+    }
 }
@@ -43,6 +43,7 @@ use arrow_schema::*;
 
 use crate::compression::CompressionCodec;
 use crate::convert::IpcSchemaEncoder;
+use crate::index::{IndexEntry, IndexEntryKind, StreamIndex};
 use crate::CONTINUATION_MARKER;
 
 /// IPC write options used to control the behaviour of the [`IpcDataGenerator`]
@@ -74,6 +75,9 @@ pub struct IpcWriteOptions {
         note = "The ability to preserve dictionary IDs will be removed. With it, all fields related to it."
     )]
     preserve_dict_id: bool,
+    /// Whether a [`StreamWriter`] should append a [`StreamIndex`] trailer after
+    /// the end-of-stream marker. Defaults to `false`
+    write_stream_index: bool,
 }
 
 impl IpcWriteOptions {
@@ -123,6 +127,7 @@ impl IpcWriteOptions {
                 metadata_version,
                 batch_compression_type: None,
                 preserve_dict_id: false,
+                write_stream_index: false,
             }),
             crate::MetadataVersion::V5 => {
                 if write_legacy_ipc_format {
@@ -137,6 +142,7 @@ impl IpcWriteOptions {
                         metadata_version,
                         batch_compression_type: None,
                         preserve_dict_id: false,
+                        write_stream_index: false,
                     })
                 }
             }
@@ -173,6 +179,17 @@ impl IpcWriteOptions {
         self.preserve_dict_id = preserve_dict_id;
         self
     }
+
+    /// Set whether a [`StreamWriter`] should append a [`StreamIndex`] of the
+    /// messages it has written after the end-of-stream marker (defaults to `false`)
+    ///
+    /// The trailer is ignored by readers unaware of it, and can be read with
+    /// [`StreamIndex::read_trailer`] to resume reading a stream from a given batch.
+    /// This has no effect on a [`FileWriter`], whose footer already locates each batch
+    pub fn with_stream_index(mut self, write_stream_index: bool) -> Self {
+        self.write_stream_index = write_stream_index;
+        self
+    }
 }
 
 impl Default for IpcWriteOptions {
@@ -184,6 +201,7 @@ impl Default for IpcWriteOptions {
             metadata_version: crate::MetadataVersion::V5,
             batch_compression_type: None,
             preserve_dict_id: false,
+            write_stream_index: false,
         }
     }
 }
@@ -1192,6 +1210,10 @@ pub struct StreamWriter<W> {
     finished: bool,
     /// Keeps track of dictionaries that have been written
     dictionary_tracker: DictionaryTracker,
+    /// The number of bytes written to the stream
+    offset: u64,
+    /// The location of each dictionary and record batch message written
+    index: StreamIndex,
 
     data_gen: IpcDataGenerator,
 }
@@ -1241,12 +1263,14 @@ impl<W: Write> StreamWriter<W> {
             &mut dictionary_tracker,
             &write_options,
         );
-        write_message(&mut writer, encoded_message, &write_options)?;
+        let (meta, data) = write_message(&mut writer, encoded_message, &write_options)?;
         Ok(Self {
             writer,
             write_options,
             finished: false,
             dictionary_tracker,
+            offset: (meta + data) as u64,
+            index: StreamIndex::default(),
             data_gen,
         })
     }
@@ -1265,14 +1289,35 @@ impl<W: Write> StreamWriter<W> {
             .expect("StreamWriter is configured to not error on dictionary replacement");
 
         for encoded_dictionary in encoded_dictionaries {
-            write_message(&mut self.writer, encoded_dictionary, &self.write_options)?;
+            let (meta, data) =
+                write_message(&mut self.writer, encoded_dictionary, &self.write_options)?;
+            self.record(IndexEntryKind::Dictionary, (meta + data) as u64, 0);
         }
 
-        write_message(&mut self.writer, encoded_message, &self.write_options)?;
+        let (meta, data) = write_message(&mut self.writer, encoded_message, &self.write_options)?;
+        let num_rows = batch.num_rows();
+        self.record(IndexEntryKind::RecordBatch, (meta + data) as u64, num_rows);
         Ok(())
     }
 
+    fn record(&mut self, kind: IndexEntryKind, length: u64, num_rows: usize) {
+        let entry = IndexEntry::new(kind, self.offset, length, num_rows as u64);
+        self.index.push(entry);
+        self.offset += length;
+    }
+
+    /// Returns a [`StreamIndex`] of the messages written so far
+    ///
+    /// This can be persisted alongside the stream, to later resume reading from a
+    /// given batch with [`StreamReader::seek_to_batch`](crate::reader::StreamReader::seek_to_batch)
+    pub fn index(&self) -> &StreamIndex {
+        &self.index
+    }
+
     /// Write continuation bytes, and mark the stream as done
+    ///
+    /// If [`IpcWriteOptions::with_stream_index`] is set, this also writes the
+    /// [`StreamIndex`] trailer
     pub fn finish(&mut self) -> Result<(), ArrowError> {
         if self.finished {
             return Err(ArrowError::IpcError(
@@ -1281,6 +1326,9 @@ impl<W: Write> StreamWriter<W> {
         }
 
         write_continuation(&mut self.writer, &self.write_options, 0)?;
+        if self.write_options.write_stream_index {
+            self.index.write_trailer(&mut self.writer)?;
+        }
 
         self.finished = true;
 
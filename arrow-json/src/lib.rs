@@ -72,6 +72,7 @@
 #![warn(missing_docs)]
 
 pub mod reader;
+pub mod records;
 pub mod writer;
 
 pub use self::reader::{Reader, ReaderBuilder};
@@ -82,6 +83,10 @@ pub use self::writer::{
 use half::f16;
 use serde_json::{Number, Value};
 
+/// The name of the arrow extension type of variant columns
+#[cfg(feature = "variant_experimental")]
+const VARIANT_EXTENSION_NAME: &str = "arrow.parquet.variant";
+
 /// Specifies what is considered valid JSON when reading or writing
 /// RecordBatches or StructArrays.
 ///
@@ -0,0 +1,309 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+
+use arrow_array::RecordBatch;
+use arrow_schema::{ArrowError, SchemaRef};
+use futures::Stream;
+use tokio::io::AsyncBufRead;
+
+use crate::reader::{BadRecords, Decoder};
+
+/// Reads JSON data with a known schema from an [`AsyncBufRead`] into a [`Stream`] of
+/// [`RecordBatch`], see [`ReaderBuilder::build_async`]
+///
+/// Data is only read from the [`AsyncBufRead`] when the next [`RecordBatch`] is polled,
+/// and at most one [`RecordBatch`] is buffered at a time
+///
+/// [`ReaderBuilder::build_async`]: crate::reader::ReaderBuilder::build_async
+pub struct AsyncReader<R> {
+    reader: R,
+    decoder: Decoder,
+    /// True if data has been decoded since the last [`RecordBatch`]
+    consumed: bool,
+}
+
+impl<R> std::fmt::Debug for AsyncReader<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncReader")
+            .field("decoder", &self.decoder)
+            .finish()
+    }
+}
+
+impl<R> AsyncReader<R> {
+    pub(crate) fn new(reader: R, decoder: Decoder) -> Self {
+        Self {
+            reader,
+            decoder,
+            consumed: false,
+        }
+    }
+
+    /// Returns the schema of the [`RecordBatch`] read
+    pub fn schema(&self) -> SchemaRef {
+        self.decoder.schema.clone()
+    }
+
+    /// Returns the records that could not be decoded since the last call to this
+    /// method, see [`Decoder::take_bad_records`]
+    pub fn take_bad_records(&mut self) -> BadRecords {
+        self.decoder.take_bad_records()
+    }
+
+    /// Returns the underlying reader
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: AsyncBufRead + Unpin> Stream for AsyncReader<R> {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        loop {
+            loop {
+                let buf = match ready!(Pin::new(&mut this.reader).poll_fill_buf(cx)) {
+                    Ok([]) => break,
+                    Ok(buf) => buf,
+                    Err(e) => return Poll::Ready(Some(Err(e.into()))),
+                };
+                let read = buf.len();
+                let decoded = match this.decoder.decode(buf) {
+                    Ok(decoded) => decoded,
+                    Err(e) => return Poll::Ready(Some(Err(e))),
+                };
+                Pin::new(&mut this.reader).consume(decoded);
+                this.consumed |= decoded != 0;
+                if decoded != read {
+                    break;
+                }
+            }
+
+            match this.decoder.flush() {
+                // Every record read was skipped, see BadRecordMode::Skip
+                Ok(None) if std::mem::take(&mut this.consumed) => continue,
+                result => {
+                    this.consumed = false;
+                    return Poll::Ready(result.transpose());
+                }
+            }
+        }
+    }
+}
+
+/// Reads JSON data with a known schema from a [`Stream`] of byte buffers, such as
+/// `bytes::Bytes`, into a [`Stream`] of [`RecordBatch`], see [`ReaderBuilder::build_stream`]
+///
+/// The buffers may contain arbitrary ranges of the JSON data, and are only polled when
+/// the next [`RecordBatch`] is polled
+///
+/// [`ReaderBuilder::build_stream`]: crate::reader::ReaderBuilder::build_stream
+pub struct ByteStreamReader<S, B> {
+    input: S,
+    decoder: Decoder,
+    /// The buffer being decoded, and the number of bytes of it decoded
+    buffered: Option<(B, usize)>,
+    /// True if data has been decoded since the last [`RecordBatch`]
+    consumed: bool,
+    /// True if the input has returned `None`, and must not be polled again
+    finished: bool,
+}
+
+impl<S, B> std::fmt::Debug for ByteStreamReader<S, B> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ByteStreamReader")
+            .field("decoder", &self.decoder)
+            .finish()
+    }
+}
+
+impl<S, B> ByteStreamReader<S, B> {
+    pub(crate) fn new(input: S, decoder: Decoder) -> Self {
+        Self {
+            input,
+            decoder,
+            buffered: None,
+            consumed: false,
+            finished: false,
+        }
+    }
+
+    /// Returns the schema of the [`RecordBatch`] read
+    pub fn schema(&self) -> SchemaRef {
+        self.decoder.schema.clone()
+    }
+
+    /// Returns the records that could not be decoded since the last call to this
+    /// method, see [`Decoder::take_bad_records`]
+    pub fn take_bad_records(&mut self) -> BadRecords {
+        self.decoder.take_bad_records()
+    }
+}
+
+impl<S, B, E> Stream for ByteStreamReader<S, B>
+where
+    S: Stream<Item = Result<B, E>> + Unpin,
+    B: AsRef<[u8]> + Unpin,
+    E: Into<ArrowError>,
+{
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        loop {
+            loop {
+                let (buf, offset) = match &mut this.buffered {
+                    Some((buf, offset)) if *offset < buf.as_ref().len() => (buf, offset),
+                    _ if this.finished => break,
+                    _ => match ready!(Pin::new(&mut this.input).poll_next(cx)) {
+                        Some(Ok(buf)) => {
+                            this.buffered = Some((buf, 0));
+                            continue;
+                        }
+                        Some(Err(e)) => return Poll::Ready(Some(Err(e.into()))),
+                        None => {
+                            this.buffered = None;
+                            this.finished = true;
+                            break;
+                        }
+                    },
+                };
+                let remaining = &buf.as_ref()[*offset..];
+                let decoded = match this.decoder.decode(remaining) {
+                    Ok(decoded) => decoded,
+                    Err(e) => return Poll::Ready(Some(Err(e))),
+                };
+                *offset += decoded;
+                this.consumed |= decoded != 0;
+                if decoded != remaining.len() {
+                    break;
+                }
+            }
+
+            match this.decoder.flush() {
+                // Every record read was skipped, see BadRecordMode::Skip
+                Ok(None) if std::mem::take(&mut this.consumed) => continue,
+                result => {
+                    this.consumed = false;
+                    return Poll::Ready(result.transpose());
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int64Type;
+    use arrow_schema::{DataType, Field, Schema};
+    use futures::executor::block_on;
+    use futures::{StreamExt, TryStreamExt};
+
+    use crate::reader::{BadRecordMode, ReaderBuilder};
+
+    use super::*;
+
+    fn schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, true)]))
+    }
+
+    fn values(batches: &[RecordBatch]) -> Vec<Vec<i64>> {
+        batches
+            .iter()
+            .map(|b| b.column(0).as_primitive::<Int64Type>().values().to_vec())
+            .collect()
+    }
+
+    #[test]
+    fn test_async_reader() {
+        let data = "{\"a\": 1}\n{\"a\": 2}\n\n{\"a\": 3}\n{\"a\": 4}\n{\"a\": 5}";
+        for capacity in [1, 3, 1024] {
+            let reader = tokio::io::BufReader::with_capacity(capacity, data.as_bytes());
+            let stream = ReaderBuilder::new(schema())
+                .with_batch_size(2)
+                .build_async(reader)
+                .unwrap();
+            assert_eq!(stream.schema(), schema());
+
+            let batches: Vec<_> = block_on(stream.try_collect()).unwrap();
+            assert_eq!(values(&batches), vec![vec![1, 2], vec![3, 4], vec![5]]);
+        }
+
+        let mut stream = ReaderBuilder::new(schema())
+            .build_async("{\"a\": 1}\n{\"a\": \"x\"}\n".as_bytes())
+            .unwrap();
+        let err = block_on(stream.next()).unwrap().unwrap_err();
+        assert!(err.to_string().contains("failed to parse \"x\""), "{err}");
+    }
+
+    #[test]
+    fn test_byte_stream_reader() {
+        let chunks = [
+            "{\"a\": 1}\n{\"a\"",
+            ": 2}\n{\"a\": \"x\"}\n{\"a\"",
+            "",
+            ": 3}",
+        ];
+        let input = futures::stream::iter(chunks.map(|c| Ok::<_, ArrowError>(c.as_bytes())));
+        let mut stream = ReaderBuilder::new(schema())
+            .with_batch_size(1)
+            .with_bad_record_mode(BadRecordMode::Skip)
+            .build_stream(input)
+            .unwrap();
+
+        let batches: Vec<_> = block_on(stream.by_ref().try_collect()).unwrap();
+        assert_eq!(values(&batches), vec![vec![1], vec![2], vec![3]]);
+        assert_eq!(stream.take_bad_records().len(), 1);
+
+        let input = futures::stream::iter([
+            Ok("{\"a\": 1}\n".as_bytes()),
+            Err(std::io::Error::other("failed")),
+        ]);
+        let stream = ReaderBuilder::new(schema()).build_stream(input).unwrap();
+        let mut results: Vec<_> = block_on(stream.collect());
+        assert_eq!(results.len(), 2);
+        assert_eq!(
+            results[0].as_ref().unwrap_err().to_string(),
+            "Io error: failed"
+        );
+        assert_eq!(values(&[results.remove(1).unwrap()]), vec![vec![1]]);
+    }
+
+    #[test]
+    fn test_byte_stream_reader_not_fused() {
+        // Unfold panics if polled after returning None
+        let input = futures::stream::unfold(0, |idx| {
+            futures::future::ready(match idx {
+                0 => Some((Ok::<_, ArrowError>("{\"a\": 1}\n{\"a\": 2}".as_bytes()), 1)),
+                _ => None,
+            })
+        });
+        let mut stream = ReaderBuilder::new(schema()).build_stream(input).unwrap();
+
+        let batch = block_on(stream.next()).unwrap().unwrap();
+        assert_eq!(values(&[batch]), vec![vec![1, 2]]);
+        assert!(block_on(stream.next()).is_none());
+        assert!(block_on(stream.next()).is_none());
+    }
+}
@@ -0,0 +1,298 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use arrow_array::builder::StringBuilder;
+use arrow_array::{Array, StringArray};
+use arrow_schema::ArrowError;
+
+use crate::reader::tape::TapeDecoder;
+
+/// Determines how a [`Decoder`] handles records that cannot be decoded, see
+/// [`ReaderBuilder::with_bad_record_mode`]
+///
+/// [`Decoder`]: crate::reader::Decoder
+/// [`ReaderBuilder::with_bad_record_mode`]: crate::reader::ReaderBuilder::with_bad_record_mode
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum BadRecordMode {
+    /// Return an error for the first record that cannot be decoded
+    #[default]
+    Error,
+    /// Decode each line that cannot be decoded as a row of nulls
+    ///
+    /// All fields of the schema must be nullable
+    Null,
+    /// Skip each line that cannot be decoded
+    Skip,
+}
+
+/// The lines that could not be decoded, along with the error for each, see
+/// [`Decoder::take_bad_records`]
+///
+/// Within each batch, lines that are not a single valid JSON value precede those
+/// whose values cannot be converted to the schema, as they are found first
+///
+/// [`Decoder::take_bad_records`]: crate::reader::Decoder::take_bad_records
+#[derive(Debug, Clone)]
+pub struct BadRecords {
+    records: StringArray,
+    errors: StringArray,
+}
+
+impl BadRecords {
+    /// The text of each line that could not be decoded
+    ///
+    /// Any invalid UTF-8 is replaced with [`char::REPLACEMENT_CHARACTER`]
+    pub fn records(&self) -> &StringArray {
+        &self.records
+    }
+
+    /// The error encountered decoding each line of [`Self::records`]
+    pub fn errors(&self) -> &StringArray {
+        &self.errors
+    }
+
+    /// The number of bad records
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// True if there are no bad records
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+}
+
+impl Default for BadRecords {
+    fn default() -> Self {
+        Self {
+            records: StringArray::new_null(0),
+            errors: StringArray::new_null(0),
+        }
+    }
+}
+
+/// Decodes newline delimited JSON a line at a time, so that the lines that cannot
+/// be decoded can be discarded without aborting the rest of the batch
+pub struct BadRecordHandler {
+    mode: BadRecordMode,
+    max_bad_records: usize,
+    num_bad_records: usize,
+    batch_size: usize,
+    /// The JSON decoded in place of a bad record in [`BadRecordMode::Null`]
+    null_row: &'static [u8],
+    /// The start of a line whose end has not yet been passed to [`Self::decode`]
+    partial_line: Vec<u8>,
+    /// The lines of the rows buffered in the tape, to rebuild the tape if some
+    /// of its rows fail to decode to arrays
+    lines: Vec<u8>,
+    line_offsets: Vec<usize>,
+    /// False if rows not decoded from lines, i.e. serialized, are buffered in the tape
+    lines_complete: bool,
+    records: StringBuilder,
+    errors: StringBuilder,
+}
+
+impl BadRecordHandler {
+    pub fn new(
+        mode: BadRecordMode,
+        max_bad_records: usize,
+        batch_size: usize,
+        is_field: bool,
+    ) -> Self {
+        Self {
+            mode,
+            max_bad_records,
+            num_bad_records: 0,
+            batch_size,
+            null_row: match is_field {
+                true => b"null",
+                false => b"{}",
+            },
+            partial_line: vec![],
+            lines: vec![],
+            line_offsets: vec![0],
+            lines_complete: true,
+            records: StringBuilder::new(),
+            errors: StringBuilder::new(),
+        }
+    }
+
+    /// Decodes the complete lines of `buf` into `tape`, returning the number of bytes read
+    pub fn decode(&mut self, tape: &mut TapeDecoder, buf: &[u8]) -> Result<usize, ArrowError> {
+        let mut read = 0;
+        while read < buf.len() && tape.num_buffered_rows() < self.batch_size {
+            let remaining = &buf[read..];
+            match memchr::memchr(b'\n', remaining) {
+                Some(end) => {
+                    self.partial_line.extend_from_slice(&remaining[..end]);
+                    read += end + 1;
+                    let line = std::mem::take(&mut self.partial_line);
+                    self.decode_line(tape, &line)?;
+                    // Reuse the allocation for the next line
+                    self.partial_line = line;
+                    self.partial_line.clear();
+                }
+                None => {
+                    self.partial_line.extend_from_slice(remaining);
+                    read = buf.len();
+                }
+            }
+        }
+        Ok(read)
+    }
+
+    /// Decodes the final line, if it was not terminated by a newline
+    pub fn decode_partial_line(&mut self, tape: &mut TapeDecoder) -> Result<(), ArrowError> {
+        let line = std::mem::take(&mut self.partial_line);
+        self.decode_line(tape, &line)
+    }
+
+    /// True if part way through a line
+    pub fn has_partial_line(&self) -> bool {
+        !is_blank(&self.partial_line)
+    }
+
+    fn decode_line(&mut self, tape: &mut TapeDecoder, line: &[u8]) -> Result<(), ArrowError> {
+        if is_blank(line) {
+            return Ok(());
+        }
+
+        let checkpoint = tape.checkpoint();
+        let num_rows = tape.num_buffered_rows();
+        let result = match std::str::from_utf8(line) {
+            Ok(_) => decode_terminated(tape, line).and_then(|read| {
+                if tape.has_partial_row() {
+                    Err(ArrowError::JsonError("Truncated record".to_string()))
+                } else if read != line.len() || tape.num_buffered_rows() != num_rows + 1 {
+                    Err(ArrowError::JsonError(
+                        "Encountered more than one record in a line".to_string(),
+                    ))
+                } else {
+                    Ok(())
+                }
+            }),
+            Err(_) => Err(ArrowError::JsonError(
+                "Encountered non-UTF-8 data".to_string(),
+            )),
+        };
+
+        match result {
+            Ok(()) => {
+                self.push_line(line);
+                Ok(())
+            }
+            Err(e) => {
+                tape.rollback(checkpoint);
+                self.bad_record(tape, line, e)
+            }
+        }
+    }
+
+    /// Records `line` as a bad record, and decodes a row of nulls in its place if
+    /// the mode is [`BadRecordMode::Null`]
+    fn bad_record(
+        &mut self,
+        tape: &mut TapeDecoder,
+        line: &[u8],
+        error: ArrowError,
+    ) -> Result<(), ArrowError> {
+        self.num_bad_records += 1;
+        if self.num_bad_records > self.max_bad_records {
+            return Err(ArrowError::JsonError(format!(
+                "Encountered more than {} bad records: {error}",
+                self.max_bad_records
+            )));
+        }
+
+        self.records.append_value(String::from_utf8_lossy(line));
+        self.errors.append_value(error.to_string());
+
+        if self.mode == BadRecordMode::Null {
+            decode_terminated(tape, self.null_row)?;
+            self.push_line(self.null_row);
+        }
+        Ok(())
+    }
+
+    fn push_line(&mut self, line: &[u8]) {
+        self.lines.extend_from_slice(line);
+        self.line_offsets.push(self.lines.len());
+    }
+
+    /// Rebuilds `tape` from the buffered lines, handling the rows of `bad_rows`
+    /// that failed to decode as bad records
+    ///
+    /// Returns `false` if the tape cannot be rebuilt, as it contains rows that
+    /// were not decoded from lines
+    pub fn redecode(
+        &mut self,
+        tape: &mut TapeDecoder,
+        bad_rows: Vec<(usize, ArrowError)>,
+    ) -> Result<bool, ArrowError> {
+        if !self.lines_complete || self.line_offsets.len() != tape.num_buffered_rows() + 1 {
+            return Ok(false);
+        }
+
+        let lines = std::mem::take(&mut self.lines);
+        let line_offsets = std::mem::replace(&mut self.line_offsets, vec![0]);
+        tape.clear();
+
+        let mut bad_rows = bad_rows.into_iter().peekable();
+        for (row, range) in line_offsets.windows(2).enumerate() {
+            let line = &lines[range[0]..range[1]];
+            match bad_rows.next_if(|(bad_row, _)| *bad_row == row) {
+                Some((_, error)) => self.bad_record(tape, line, error)?,
+                None => {
+                    decode_terminated(tape, line)?;
+                    self.push_line(line);
+                }
+            }
+        }
+        Ok(true)
+    }
+
+    /// Notes that rows have been added to the tape without a line, preventing
+    /// [`Self::redecode`] until the next [`Self::clear`]
+    pub fn add_serialized_rows(&mut self) {
+        self.lines_complete = false;
+    }
+
+    /// Clears the buffered lines once their rows have been decoded
+    pub fn clear(&mut self) {
+        self.lines.clear();
+        self.line_offsets.truncate(1);
+        self.lines_complete = true;
+    }
+
+    pub fn take_bad_records(&mut self) -> BadRecords {
+        BadRecords {
+            records: self.records.finish(),
+            errors: self.errors.finish(),
+        }
+    }
+}
+
+/// Decodes `line` to `tape`, terminating any trailing top-level number
+fn decode_terminated(tape: &mut TapeDecoder, line: &[u8]) -> Result<usize, ArrowError> {
+    let read = tape.decode(line)?;
+    tape.decode(b"\n")?;
+    Ok(read)
+}
+
+fn is_blank(line: &[u8]) -> bool {
+    line.iter().all(u8::is_ascii_whitespace)
+}
@@ -56,7 +56,7 @@ where
                 TapeElement::Null => builder.append_null(),
                 TapeElement::String(idx) => {
                     let s = tape.get_string(idx);
-                    let value = parse_decimal::<D>(s, self.precision, self.scale)?;
+                    let value = parse_decimal::<D>(s.trim(), self.precision, self.scale)?;
                     builder.append_value(value)
                 }
                 TapeElement::Number(idx) => {
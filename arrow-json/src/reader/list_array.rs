@@ -21,9 +21,11 @@ use crate::StructMode;
 use arrow_array::builder::{BooleanBufferBuilder, BufferBuilder};
 use arrow_array::OffsetSizeTrait;
 use arrow_buffer::buffer::NullBuffer;
+use arrow_buffer::BufferRecycler;
 use arrow_data::{ArrayData, ArrayDataBuilder};
 use arrow_schema::{ArrowError, DataType};
 use std::marker::PhantomData;
+use std::sync::Arc;
 
 pub struct ListArrayDecoder<O> {
     data_type: DataType,
@@ -39,6 +41,7 @@ impl<O: OffsetSizeTrait> ListArrayDecoder<O> {
         strict_mode: bool,
         is_nullable: bool,
         struct_mode: StructMode,
+        recycler: Option<Arc<BufferRecycler>>,
     ) -> Result<Self, ArrowError> {
         let field = match &data_type {
             DataType::List(f) if !O::IS_LARGE => f,
@@ -51,6 +54,7 @@ impl<O: OffsetSizeTrait> ListArrayDecoder<O> {
             strict_mode,
             field.is_nullable(),
             struct_mode,
+            recycler,
         )?;
 
         Ok(Self {
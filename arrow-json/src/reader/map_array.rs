@@ -20,9 +20,10 @@ use crate::reader::{make_decoder, ArrayDecoder};
 use crate::StructMode;
 use arrow_array::builder::{BooleanBufferBuilder, BufferBuilder};
 use arrow_buffer::buffer::NullBuffer;
-use arrow_buffer::ArrowNativeType;
+use arrow_buffer::{ArrowNativeType, BufferRecycler};
 use arrow_data::{ArrayData, ArrayDataBuilder};
 use arrow_schema::{ArrowError, DataType};
+use std::sync::Arc;
 
 pub struct MapArrayDecoder {
     data_type: DataType,
@@ -38,6 +39,7 @@ impl MapArrayDecoder {
         strict_mode: bool,
         is_nullable: bool,
         struct_mode: StructMode,
+        recycler: Option<Arc<BufferRecycler>>,
     ) -> Result<Self, ArrowError> {
         let fields = match &data_type {
             DataType::Map(_, true) => {
@@ -62,6 +64,7 @@ impl MapArrayDecoder {
             strict_mode,
             fields[0].is_nullable(),
             struct_mode,
+            recycler.clone(),
         )?;
         let values = make_decoder(
             fields[1].data_type().clone(),
@@ -69,6 +72,7 @@ impl MapArrayDecoder {
             strict_mode,
             fields[1].is_nullable(),
             struct_mode,
+            recycler,
         )?;
 
         Ok(Self {
@@ -25,6 +25,11 @@
 //! parsing of sequences of one or more arbitrarily formatted JSON values, including
 //! but not limited to newline-delimited JSON.
 //!
+//! Numeric and decimal fields also accept numbers quoted as strings, e.g. `"123.45"` or
+//! `"18446744073709551615"`, as commonly used to avoid a loss of precision by consumers
+//! that parse all JSON numbers as double-precision floats. Quoted numbers decoded as an
+//! integer type must be exact, e.g. `"12.7"` is an error rather than being truncated.
+//!
 //! # Basic Usage
 //!
 //! [`Reader`] can be used directly with synchronous data sources, such as [`std::fs::File`]
@@ -53,6 +58,10 @@
 //! and is designed to be agnostic to the various different kinds of async IO primitives found
 //! within the Rust ecosystem.
 //!
+//! With the `async` feature enabled, `ReaderBuilder::build_async` and
+//! `ReaderBuilder::build_stream` provide ready-made streams over a tokio `AsyncBufRead`
+//! and a `Stream` of byte buffers respectively.
+//!
 //! For example, see below for how it can be used with an arbitrary `Stream` of `Bytes`
 //!
 //! ```
@@ -143,10 +152,15 @@ use serde::Serialize;
 use arrow_array::timezone::Tz;
 use arrow_array::types::*;
 use arrow_array::{downcast_integer, make_array, RecordBatch, RecordBatchReader, StructArray};
+use arrow_buffer::{BufferRecycler, MutableBuffer};
 use arrow_data::ArrayData;
 use arrow_schema::{ArrowError, DataType, FieldRef, Schema, SchemaRef, TimeUnit};
+#[cfg(feature = "async")]
+pub use async_reader::{AsyncReader, ByteStreamReader};
+pub use bad_record::{BadRecordMode, BadRecords};
 pub use schema::*;
 
+use crate::reader::bad_record::BadRecordHandler;
 use crate::reader::boolean_array::BooleanArrayDecoder;
 use crate::reader::decimal_array::DecimalArrayDecoder;
 use crate::reader::list_array::ListArrayDecoder;
@@ -158,7 +172,12 @@ use crate::reader::string_view_array::StringViewArrayDecoder;
 use crate::reader::struct_array::StructArrayDecoder;
 use crate::reader::tape::{Tape, TapeDecoder};
 use crate::reader::timestamp_array::TimestampArrayDecoder;
+#[cfg(feature = "variant_experimental")]
+use crate::reader::variant_array::{unknown_fields_field, UnknownFieldsDecoder};
 
+#[cfg(feature = "async")]
+mod async_reader;
+mod bad_record;
 mod boolean_array;
 mod decimal_array;
 mod list_array;
@@ -172,6 +191,8 @@ mod string_view_array;
 mod struct_array;
 mod tape;
 mod timestamp_array;
+#[cfg(feature = "variant_experimental")]
+mod variant_array;
 
 /// A builder for [`Reader`] and [`Decoder`]
 pub struct ReaderBuilder {
@@ -180,6 +201,11 @@ pub struct ReaderBuilder {
     strict_mode: bool,
     is_field: bool,
     struct_mode: StructMode,
+    recycler: Option<Arc<BufferRecycler>>,
+    bad_record_mode: BadRecordMode,
+    max_bad_records: usize,
+    #[cfg(feature = "variant_experimental")]
+    unknown_fields_column: Option<String>,
 
     schema: SchemaRef,
 }
@@ -200,6 +226,11 @@ impl ReaderBuilder {
             strict_mode: false,
             is_field: false,
             struct_mode: Default::default(),
+            recycler: None,
+            bad_record_mode: BadRecordMode::Error,
+            max_bad_records: usize::MAX,
+            #[cfg(feature = "variant_experimental")]
+            unknown_fields_column: None,
             schema,
         }
     }
@@ -241,6 +272,11 @@ impl ReaderBuilder {
             strict_mode: false,
             is_field: true,
             struct_mode: Default::default(),
+            recycler: None,
+            bad_record_mode: BadRecordMode::Error,
+            max_bad_records: usize::MAX,
+            #[cfg(feature = "variant_experimental")]
+            unknown_fields_column: None,
             schema: Arc::new(Schema::new([field.into()])),
         }
     }
@@ -281,6 +317,126 @@ impl ReaderBuilder {
         }
     }
 
+    /// Sets a [`BufferRecycler`] from which the decoder will obtain the value and
+    /// offset buffers of primitive and string columns
+    ///
+    /// Buffers of batches returned by [`RecordBatch::recycle_buffers`] are then reused
+    /// by subsequent batches, instead of allocating new buffers for each batch
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_buffer::BufferRecycler;
+    /// # use arrow_json::ReaderBuilder;
+    /// # use arrow_schema::{DataType, Field, Schema};
+    /// let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, true)]));
+    /// let recycler = Arc::new(BufferRecycler::default());
+    ///
+    /// let data = "{\"a\": 1}\n{\"a\": 2}\n{\"a\": 3}\n";
+    /// let reader = ReaderBuilder::new(schema)
+    ///     .with_batch_size(1)
+    ///     .with_buffer_recycler(recycler.clone())
+    ///     .build(data.as_bytes())
+    ///     .unwrap();
+    ///
+    /// for batch in reader {
+    ///     let batch = batch.unwrap();
+    ///     // ... process batch ...
+    ///     batch.recycle_buffers(&recycler);
+    /// }
+    /// ```
+    pub fn with_buffer_recycler(self, recycler: Arc<BufferRecycler>) -> Self {
+        Self {
+            recycler: Some(recycler),
+            ..self
+        }
+    }
+
+    /// Sets the [`BadRecordMode`] that determines how lines of newline delimited JSON
+    /// that cannot be decoded are handled. Default is [`BadRecordMode::Error`].
+    ///
+    /// With [`BadRecordMode::Null`] or [`BadRecordMode::Skip`] the input is decoded a
+    /// line at a time, and each line that is not a single valid record, or whose values
+    /// cannot be converted to `schema`, is replaced with a row of nulls or skipped. Its
+    /// text and error are collected, and can be retrieved with [`Decoder::take_bad_records`].
+    /// Any final line not terminated by a newline is decoded by [`Decoder::flush`].
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::cast::AsArray;
+    /// # use arrow_array::types::Int64Type;
+    /// # use arrow_json::ReaderBuilder;
+    /// # use arrow_json::reader::BadRecordMode;
+    /// # use arrow_schema::{DataType, Field, Schema};
+    /// let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, true)]));
+    /// let data = "{\"a\": 1}\n{\"a\": \n{\"a\": 3}\n";
+    /// let mut reader = ReaderBuilder::new(schema)
+    ///     .with_bad_record_mode(BadRecordMode::Skip)
+    ///     .build(data.as_bytes())
+    ///     .unwrap();
+    ///
+    /// let batch = reader.next().unwrap().unwrap();
+    /// assert_eq!(batch.column(0).as_primitive::<Int64Type>().values(), &[1, 3]);
+    ///
+    /// let bad_records = reader.take_bad_records();
+    /// assert_eq!(bad_records.records().value(0), "{\"a\": ");
+    /// ```
+    pub fn with_bad_record_mode(self, bad_record_mode: BadRecordMode) -> Self {
+        Self {
+            bad_record_mode,
+            ..self
+        }
+    }
+
+    /// Sets the maximum number of bad records to tolerate with [`Self::with_bad_record_mode`],
+    /// after which an error is returned. Default is unlimited.
+    pub fn with_max_bad_records(self, max_bad_records: usize) -> Self {
+        Self {
+            max_bad_records,
+            ..self
+        }
+    }
+
+    /// Preserves the fields of the top-level JSON objects that are not present in
+    /// `schema` in a variant column named `column_name`, instead of ignoring them
+    ///
+    /// Each row of the column is a variant object of the unknown fields, or null if
+    /// there are none. If `schema` does not contain `column_name`, a nullable column
+    /// is appended to it, otherwise the column must be a nullable struct of binary view
+    /// `metadata` and `value` fields. JSON fields named `column_name` are themselves
+    /// treated as unknown fields, and [`Self::with_strict_mode`] no longer returns an
+    /// error for unknown top-level fields.
+    ///
+    /// Requires [`StructMode::ObjectOnly`], and cannot be used with
+    /// [`ReaderBuilder::new_with_field`].
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_json::ReaderBuilder;
+    /// # use arrow_schema::{DataType, Field, Schema};
+    /// let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, true)]));
+    /// let data = r#"{"a": 1, "b": "x"}
+    /// {"a": 2}
+    /// "#;
+    /// let batch = ReaderBuilder::new(schema)
+    ///     .with_unknown_fields_as_variant("_rest")
+    ///     .build(data.as_bytes())
+    ///     .unwrap()
+    ///     .next()
+    ///     .unwrap()
+    ///     .unwrap();
+    ///
+    /// let rest = batch.column_by_name("_rest").unwrap();
+    /// assert!(rest.is_valid(0));
+    /// assert!(rest.is_null(1));
+    /// ```
+    #[cfg(feature = "variant_experimental")]
+    pub fn with_unknown_fields_as_variant(self, column_name: impl Into<String>) -> Self {
+        Self {
+            unknown_fields_column: Some(column_name.into()),
+            ..self
+        }
+    }
+
     /// Create a [`Reader`] with the provided [`BufRead`]
     pub fn build<R: BufRead>(self, reader: R) -> Result<Reader<R>, ArrowError> {
         Ok(Reader {
@@ -289,8 +445,45 @@ impl ReaderBuilder {
         })
     }
 
+    /// Create an [`AsyncReader`] with the provided [`AsyncBufRead`]
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::RecordBatch;
+    /// # use arrow_json::ReaderBuilder;
+    /// # use arrow_schema::{ArrowError, DataType, Field, Schema};
+    /// # use futures::TryStreamExt;
+    /// # use tokio::io::AsyncBufRead;
+    /// async fn read<R: AsyncBufRead + Unpin>(reader: R) -> Result<Vec<RecordBatch>, ArrowError> {
+    ///     let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, true)]));
+    ///     let stream = ReaderBuilder::new(schema).build_async(reader)?;
+    ///     stream.try_collect().await
+    /// }
+    /// # let batches = futures::executor::block_on(read("{\"a\": 1}\n".as_bytes())).unwrap();
+    /// # assert_eq!(batches[0].num_rows(), 1);
+    /// ```
+    ///
+    /// [`AsyncBufRead`]: tokio::io::AsyncBufRead
+    #[cfg(feature = "async")]
+    pub fn build_async<R>(self, reader: R) -> Result<AsyncReader<R>, ArrowError> {
+        Ok(AsyncReader::new(reader, self.build_decoder()?))
+    }
+
+    /// Create a [`ByteStreamReader`] with the provided [`Stream`] of byte buffers
+    ///
+    /// [`Stream`]: futures::Stream
+    #[cfg(feature = "async")]
+    pub fn build_stream<S, B>(self, input: S) -> Result<ByteStreamReader<S, B>, ArrowError> {
+        Ok(ByteStreamReader::new(input, self.build_decoder()?))
+    }
+
     /// Create a [`Decoder`]
     pub fn build_decoder(self) -> Result<Decoder, ArrowError> {
+        #[cfg(feature = "variant_experimental")]
+        if let Some(column_name) = &self.unknown_fields_column {
+            return self.build_unknown_fields_decoder(column_name);
+        }
+
         let (data_type, nullable) = match self.is_field {
             false => (DataType::Struct(self.schema.fields.clone()), false),
             true => {
@@ -305,17 +498,90 @@ impl ReaderBuilder {
             self.strict_mode,
             nullable,
             self.struct_mode,
+            self.recycler.clone(),
+        )?;
+
+        Ok(self.finish_decoder(decoder, self.schema.clone()))
+    }
+
+    /// Create a [`Decoder`] that decodes the unknown fields of each object to the
+    /// variant column `column_name`, see [`Self::with_unknown_fields_as_variant`]
+    #[cfg(feature = "variant_experimental")]
+    fn build_unknown_fields_decoder(&self, column_name: &str) -> Result<Decoder, ArrowError> {
+        if self.is_field || self.struct_mode != StructMode::ObjectOnly {
+            return Err(ArrowError::InvalidArgumentError(
+                "Decoding unknown fields to a variant column requires objects of a schema's fields"
+                    .to_string(),
+            ));
+        }
+
+        let expected = unknown_fields_field(column_name);
+        let schema = match self.schema.index_of(column_name) {
+            Ok(idx) => {
+                let field = self.schema.field(idx);
+                if field.data_type() != expected.data_type() || !field.is_nullable() {
+                    return Err(ArrowError::InvalidArgumentError(format!(
+                        "Unknown fields column '{column_name}' must be a nullable {}, got {}",
+                        expected.data_type(),
+                        field.data_type()
+                    )));
+                }
+                self.schema.clone()
+            }
+            Err(_) => {
+                let mut fields: Vec<_> = self.schema.fields().iter().cloned().collect();
+                fields.push(Arc::new(expected));
+                Arc::new(Schema::new_with_metadata(
+                    fields,
+                    self.schema.metadata().clone(),
+                ))
+            }
+        };
+
+        let idx = schema.index_of(column_name)?;
+        let known_fields = schema
+            .fields()
+            .iter()
+            .filter(|f| f.name() != column_name)
+            .map(|f| f.name().clone())
+            .collect();
+        let unknown_fields =
+            UnknownFieldsDecoder::new(schema.field(idx).data_type().clone(), known_fields);
+
+        let decoder = StructArrayDecoder::new_with_unknown_fields(
+            DataType::Struct(schema.fields().clone()),
+            self.coerce_primitive,
+            self.strict_mode,
+            false,
+            self.struct_mode,
+            self.recycler.clone(),
+            Some((idx, Box::new(unknown_fields))),
         )?;
 
-        let num_fields = self.schema.flattened_fields().len();
+        Ok(self.finish_decoder(Box::new(decoder), schema))
+    }
+
+    fn finish_decoder(&self, decoder: Box<dyn ArrayDecoder>, schema: SchemaRef) -> Decoder {
+        let num_fields = schema.flattened_fields().len();
 
-        Ok(Decoder {
+        let bad_records = match self.bad_record_mode {
+            BadRecordMode::Error => None,
+            mode => Some(BadRecordHandler::new(
+                mode,
+                self.max_bad_records,
+                self.batch_size,
+                self.is_field,
+            )),
+        };
+
+        Decoder {
             decoder,
             is_field: self.is_field,
             tape_decoder: TapeDecoder::new(self.batch_size, num_fields),
+            bad_records,
             batch_size: self.batch_size,
-            schema: self.schema,
-        })
+            schema,
+        }
     }
 }
 
@@ -335,23 +601,40 @@ impl<R> std::fmt::Debug for Reader<R> {
     }
 }
 
+impl<R> Reader<R> {
+    /// Returns the records that could not be decoded since the last call to this
+    /// method, see [`Decoder::take_bad_records`]
+    pub fn take_bad_records(&mut self) -> BadRecords {
+        self.decoder.take_bad_records()
+    }
+}
+
 impl<R: BufRead> Reader<R> {
     /// Reads the next [`RecordBatch`] returning `Ok(None)` if EOF
     fn read(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
         loop {
-            let buf = self.reader.fill_buf()?;
-            if buf.is_empty() {
-                break;
+            let mut consumed = false;
+            loop {
+                let buf = self.reader.fill_buf()?;
+                if buf.is_empty() {
+                    break;
+                }
+                let read = buf.len();
+
+                let decoded = self.decoder.decode(buf)?;
+                self.reader.consume(decoded);
+                consumed |= decoded != 0;
+                if decoded != read {
+                    break;
+                }
             }
-            let read = buf.len();
 
-            let decoded = self.decoder.decode(buf)?;
-            self.reader.consume(decoded);
-            if decoded != read {
-                break;
+            match self.decoder.flush()? {
+                // Every record read was skipped, see BadRecordMode::Skip
+                None if consumed => continue,
+                batch => return Ok(batch),
             }
         }
-        self.decoder.flush()
     }
 }
 
@@ -412,6 +695,7 @@ impl<R: BufRead> RecordBatchReader for Reader<R> {
 pub struct Decoder {
     tape_decoder: TapeDecoder,
     decoder: Box<dyn ArrayDecoder>,
+    bad_records: Option<BadRecordHandler>,
     batch_size: usize,
     is_field: bool,
     schema: SchemaRef,
@@ -436,7 +720,10 @@ impl Decoder {
     /// There is no requirement that `buf` contains a whole number of records, facilitating
     /// integration with arbitrary byte streams, such as those yielded by [`BufRead`]
     pub fn decode(&mut self, buf: &[u8]) -> Result<usize, ArrowError> {
-        self.tape_decoder.decode(buf)
+        match &mut self.bad_records {
+            Some(bad_records) => bad_records.decode(&mut self.tape_decoder, buf),
+            None => self.tape_decoder.decode(buf),
+        }
     }
 
     /// Serialize `rows` to this [`Decoder`]
@@ -614,17 +901,27 @@ impl Decoder {
     ///
     /// Note: this ignores any batch size setting, and always decodes all rows
     pub fn serialize<S: Serialize>(&mut self, rows: &[S]) -> Result<(), ArrowError> {
+        if let Some(bad_records) = &mut self.bad_records {
+            bad_records.add_serialized_rows();
+        }
         self.tape_decoder.serialize(rows)
     }
 
     /// True if the decoder is currently part way through decoding a record.
     pub fn has_partial_record(&self) -> bool {
-        self.tape_decoder.has_partial_row()
+        match &self.bad_records {
+            Some(bad_records) => bad_records.has_partial_line(),
+            None => self.tape_decoder.has_partial_row(),
+        }
     }
 
     /// The number of unflushed records, including the partially decoded record (if any).
     pub fn len(&self) -> usize {
-        self.tape_decoder.num_buffered_rows()
+        let partial = self
+            .bad_records
+            .as_ref()
+            .is_some_and(|b| b.has_partial_line());
+        self.tape_decoder.num_buffered_rows() + partial as usize
     }
 
     /// True if there are no records to flush, i.e. [`Self::len`] is zero.
@@ -634,28 +931,51 @@ impl Decoder {
 
     /// Flushes the currently buffered data to a [`RecordBatch`]
     ///
-    /// Returns `Ok(None)` if no buffered data, i.e. [`Self::is_empty`] is true.
+    /// Returns `Ok(None)` if no buffered data, i.e. [`Self::is_empty`] is true, or
+    /// if every buffered record was skipped with [`BadRecordMode::Skip`].
     ///
     /// Note: This will return an error if called part way through decoding a record,
-    /// i.e. [`Self::has_partial_record`] is true.
+    /// i.e. [`Self::has_partial_record`] is true, unless a [`BadRecordMode`] other than
+    /// [`BadRecordMode::Error`] is set, in which case the partial line is decoded as
+    /// the final line of the input.
     pub fn flush(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
-        let tape = self.tape_decoder.finish()?;
+        let decoded = match &mut self.bad_records {
+            Some(bad_records) => {
+                bad_records.decode_partial_line(&mut self.tape_decoder)?;
+                match decode_tape(&self.tape_decoder, self.decoder.as_mut()) {
+                    Ok(decoded) => decoded,
+                    Err(e) => {
+                        // Decode each row alone to find those that cannot be decoded,
+                        // and rebuild the tape without them
+                        let tape = self.tape_decoder.finish()?;
+                        let bad_rows: Vec<_> = row_positions(&tape)
+                            .into_iter()
+                            .enumerate()
+                            .filter_map(|(row, p)| {
+                                let e = self.decoder.decode(&tape, &[p]).err()?;
+                                Some((row, e))
+                            })
+                            .collect();
+
+                        if bad_rows.is_empty()
+                            || !bad_records.redecode(&mut self.tape_decoder, bad_rows)?
+                        {
+                            return Err(e);
+                        }
+                        decode_tape(&self.tape_decoder, self.decoder.as_mut())?
+                    }
+                }
+            }
+            None => decode_tape(&self.tape_decoder, self.decoder.as_mut())?,
+        };
 
-        if tape.num_rows() == 0 {
+        let Some(decoded) = decoded else {
             return Ok(None);
-        }
-
-        // First offset is null sentinel
-        let mut next_object = 1;
-        let pos: Vec<_> = (0..tape.num_rows())
-            .map(|_| {
-                let next = tape.next(next_object, "row").unwrap();
-                std::mem::replace(&mut next_object, next)
-            })
-            .collect();
-
-        let decoded = self.decoder.decode(&tape, &pos)?;
+        };
         self.tape_decoder.clear();
+        if let Some(bad_records) = &mut self.bad_records {
+            bad_records.clear();
+        }
 
         let batch = match self.is_field {
             true => RecordBatch::try_new(self.schema.clone(), vec![make_array(decoded)])?,
@@ -666,6 +986,41 @@ impl Decoder {
 
         Ok(Some(batch))
     }
+
+    /// Returns the records that could not be decoded since the last call to this method
+    ///
+    /// This is always empty unless a [`BadRecordMode`] other than [`BadRecordMode::Error`]
+    /// is set with [`ReaderBuilder::with_bad_record_mode`]
+    pub fn take_bad_records(&mut self) -> BadRecords {
+        match &mut self.bad_records {
+            Some(bad_records) => bad_records.take_bad_records(),
+            None => BadRecords::default(),
+        }
+    }
+}
+
+/// Decodes the rows buffered in `tape_decoder`, returning `Ok(None)` if there are none
+fn decode_tape(
+    tape_decoder: &TapeDecoder,
+    decoder: &mut dyn ArrayDecoder,
+) -> Result<Option<ArrayData>, ArrowError> {
+    let tape = tape_decoder.finish()?;
+    if tape.num_rows() == 0 {
+        return Ok(None);
+    }
+    decoder.decode(&tape, &row_positions(&tape)).map(Some)
+}
+
+/// Returns the position of the start of each row of `tape`
+fn row_positions(tape: &Tape<'_>) -> Vec<u32> {
+    // First offset is null sentinel
+    let mut next_object = 1;
+    (0..tape.num_rows())
+        .map(|_| {
+            let next = tape.next(next_object, "row").unwrap();
+            std::mem::replace(&mut next_object, next)
+        })
+        .collect()
 }
 
 trait ArrayDecoder: Send {
@@ -673,9 +1028,20 @@ trait ArrayDecoder: Send {
     fn decode(&mut self, tape: &Tape<'_>, pos: &[u32]) -> Result<ArrayData, ArrowError>;
 }
 
+/// Returns an empty [`MutableBuffer`] with at least `capacity` bytes, reusing
+/// a buffer from `recycler` if possible
+fn take_buffer(recycler: Option<&BufferRecycler>, capacity: usize) -> MutableBuffer {
+    match recycler {
+        Some(recycler) => recycler.take(capacity),
+        None => MutableBuffer::with_capacity(capacity),
+    }
+}
+
 macro_rules! primitive_decoder {
-    ($t:ty, $data_type:expr) => {
-        Ok(Box::new(PrimitiveArrayDecoder::<$t>::new($data_type)))
+    ($t:ty, $data_type:expr, $recycler:expr) => {
+        Ok(Box::new(PrimitiveArrayDecoder::<$t>::new(
+            $data_type, $recycler,
+        )))
     };
 }
 
@@ -685,13 +1051,14 @@ fn make_decoder(
     strict_mode: bool,
     is_nullable: bool,
     struct_mode: StructMode,
+    recycler: Option<Arc<BufferRecycler>>,
 ) -> Result<Box<dyn ArrayDecoder>, ArrowError> {
     downcast_integer! {
-        data_type => (primitive_decoder, data_type),
+        data_type => (primitive_decoder, data_type, recycler),
         DataType::Null => Ok(Box::<NullArrayDecoder>::default()),
-        DataType::Float16 => primitive_decoder!(Float16Type, data_type),
-        DataType::Float32 => primitive_decoder!(Float32Type, data_type),
-        DataType::Float64 => primitive_decoder!(Float64Type, data_type),
+        DataType::Float16 => primitive_decoder!(Float16Type, data_type, recycler),
+        DataType::Float32 => primitive_decoder!(Float32Type, data_type, recycler),
+        DataType::Float64 => primitive_decoder!(Float64Type, data_type, recycler),
         DataType::Timestamp(TimeUnit::Second, None) => {
             Ok(Box::new(TimestampArrayDecoder::<TimestampSecondType, _>::new(data_type, Utc)))
         },
@@ -720,29 +1087,31 @@ fn make_decoder(
             let tz: Tz = tz.parse()?;
             Ok(Box::new(TimestampArrayDecoder::<TimestampNanosecondType, _>::new(data_type, tz)))
         },
-        DataType::Date32 => primitive_decoder!(Date32Type, data_type),
-        DataType::Date64 => primitive_decoder!(Date64Type, data_type),
-        DataType::Time32(TimeUnit::Second) => primitive_decoder!(Time32SecondType, data_type),
-        DataType::Time32(TimeUnit::Millisecond) => primitive_decoder!(Time32MillisecondType, data_type),
-        DataType::Time64(TimeUnit::Microsecond) => primitive_decoder!(Time64MicrosecondType, data_type),
-        DataType::Time64(TimeUnit::Nanosecond) => primitive_decoder!(Time64NanosecondType, data_type),
-        DataType::Duration(TimeUnit::Nanosecond) => primitive_decoder!(DurationNanosecondType, data_type),
-        DataType::Duration(TimeUnit::Microsecond) => primitive_decoder!(DurationMicrosecondType, data_type),
-        DataType::Duration(TimeUnit::Millisecond) => primitive_decoder!(DurationMillisecondType, data_type),
-        DataType::Duration(TimeUnit::Second) => primitive_decoder!(DurationSecondType, data_type),
+        DataType::Date32 => primitive_decoder!(Date32Type, data_type, recycler),
+        DataType::Date64 => primitive_decoder!(Date64Type, data_type, recycler),
+        DataType::Time32(TimeUnit::Second) => primitive_decoder!(Time32SecondType, data_type, recycler),
+        DataType::Time32(TimeUnit::Millisecond) => primitive_decoder!(Time32MillisecondType, data_type, recycler),
+        DataType::Time64(TimeUnit::Microsecond) => primitive_decoder!(Time64MicrosecondType, data_type, recycler),
+        DataType::Time64(TimeUnit::Nanosecond) => primitive_decoder!(Time64NanosecondType, data_type, recycler),
+        DataType::Duration(TimeUnit::Nanosecond) => primitive_decoder!(DurationNanosecondType, data_type, recycler),
+        DataType::Duration(TimeUnit::Microsecond) => primitive_decoder!(DurationMicrosecondType, data_type, recycler),
+        DataType::Duration(TimeUnit::Millisecond) => primitive_decoder!(DurationMillisecondType, data_type, recycler),
+        DataType::Duration(TimeUnit::Second) => primitive_decoder!(DurationSecondType, data_type, recycler),
+        DataType::Decimal32(p, s) => Ok(Box::new(DecimalArrayDecoder::<Decimal32Type>::new(p, s))),
+        DataType::Decimal64(p, s) => Ok(Box::new(DecimalArrayDecoder::<Decimal64Type>::new(p, s))),
         DataType::Decimal128(p, s) => Ok(Box::new(DecimalArrayDecoder::<Decimal128Type>::new(p, s))),
         DataType::Decimal256(p, s) => Ok(Box::new(DecimalArrayDecoder::<Decimal256Type>::new(p, s))),
         DataType::Boolean => Ok(Box::<BooleanArrayDecoder>::default()),
-        DataType::Utf8 => Ok(Box::new(StringArrayDecoder::<i32>::new(coerce_primitive))),
+        DataType::Utf8 => Ok(Box::new(StringArrayDecoder::<i32>::new(coerce_primitive, recycler))),
         DataType::Utf8View => Ok(Box::new(StringViewArrayDecoder::new(coerce_primitive))),
-        DataType::LargeUtf8 => Ok(Box::new(StringArrayDecoder::<i64>::new(coerce_primitive))),
-        DataType::List(_) => Ok(Box::new(ListArrayDecoder::<i32>::new(data_type, coerce_primitive, strict_mode, is_nullable, struct_mode)?)),
-        DataType::LargeList(_) => Ok(Box::new(ListArrayDecoder::<i64>::new(data_type, coerce_primitive, strict_mode, is_nullable, struct_mode)?)),
-        DataType::Struct(_) => Ok(Box::new(StructArrayDecoder::new(data_type, coerce_primitive, strict_mode, is_nullable, struct_mode)?)),
+        DataType::LargeUtf8 => Ok(Box::new(StringArrayDecoder::<i64>::new(coerce_primitive, recycler))),
+        DataType::List(_) => Ok(Box::new(ListArrayDecoder::<i32>::new(data_type, coerce_primitive, strict_mode, is_nullable, struct_mode, recycler)?)),
+        DataType::LargeList(_) => Ok(Box::new(ListArrayDecoder::<i64>::new(data_type, coerce_primitive, strict_mode, is_nullable, struct_mode, recycler)?)),
+        DataType::Struct(_) => Ok(Box::new(StructArrayDecoder::new(data_type, coerce_primitive, strict_mode, is_nullable, struct_mode, recycler)?)),
         DataType::Binary | DataType::LargeBinary | DataType::FixedSizeBinary(_) => {
             Err(ArrowError::JsonError(format!("{data_type} is not supported by JSON")))
         }
-        DataType::Map(_, _) => Ok(Box::new(MapArrayDecoder::new(data_type, coerce_primitive, strict_mode, is_nullable, struct_mode)?)),
+        DataType::Map(_, _) => Ok(Box::new(MapArrayDecoder::new(data_type, coerce_primitive, strict_mode, is_nullable, struct_mode, recycler)?)),
         d => Err(ArrowError::NotYetImplemented(format!("Support for {d} in JSON reader")))
     }
 }
@@ -1345,10 +1714,67 @@ mod tests {
 
     #[test]
     fn test_decimals() {
+        test_decimal::<Decimal32Type>(DataType::Decimal32(8, 2));
+        test_decimal::<Decimal64Type>(DataType::Decimal64(10, 2));
         test_decimal::<Decimal128Type>(DataType::Decimal128(10, 2));
         test_decimal::<Decimal256Type>(DataType::Decimal256(10, 2));
     }
 
+    #[test]
+    fn test_quoted_numbers() {
+        let buf = r#"
+        {"i": "123", "u": "18446744073709551615", "d": "123.45", "b": "-12345678901234567890123456789.5"}
+        {"i": "1e3", "u": " 7 ", "d": " 1e2 ", "b": "98765432109876543210"}
+        {"i": " -12.0 ", "u": 18446744073709551615, "d": 0.5, "b": 1}
+        "#;
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("i", DataType::Int64, true),
+            Field::new("u", DataType::UInt64, true),
+            Field::new("d", DataType::Decimal128(10, 2), true),
+            Field::new("b", DataType::Decimal256(40, 1), true),
+        ]));
+
+        let batches = do_read(buf, 1024, false, false, schema.clone());
+        assert_eq!(batches.len(), 1);
+
+        let i = batches[0].column(0).as_primitive::<Int64Type>();
+        assert_eq!(i.values(), &[123, 1000, -12]);
+
+        let u = batches[0].column(1).as_primitive::<UInt64Type>();
+        assert_eq!(u.values(), &[u64::MAX, 7, u64::MAX]);
+
+        let d = batches[0].column(2).as_primitive::<Decimal128Type>();
+        assert_eq!(d.values(), &[12345, 10000, 50]);
+
+        let b = batches[0].column(3).as_primitive::<Decimal256Type>();
+        let b: Vec<_> = (0..b.len()).map(|idx| b.value_as_string(idx)).collect();
+        assert_eq!(
+            b,
+            [
+                "-12345678901234567890123456789.5",
+                "98765432109876543210.0",
+                "1.0"
+            ]
+        );
+
+        // Quoted numbers that do not exactly fit the type are still an error
+        for value in ["99999999999999999999", "12.7", "-0.5", "1e17"] {
+            let buf = format!(r#"{{"i": "{value}"}}"#);
+            let err = ReaderBuilder::new(schema.clone())
+                .build(Cursor::new(buf.as_bytes()))
+                .unwrap()
+                .read()
+                .unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                format!(
+                    "Json error: whilst decoding field 'i': failed to parse \"{value}\" as Int64"
+                )
+            );
+        }
+    }
+
     fn test_timestamp<T: ArrowTimestampType>() {
         let buf = r#"
         {"a": 1, "b": "2020-09-08T13:42:29.190855+00:00", "c": 38.30, "d": "1997-01-31T09:26:56.123"}
@@ -1772,6 +2198,77 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(feature = "variant_experimental")]
+    fn test_unknown_fields_as_variant() {
+        use parquet_variant::Variant;
+
+        let buf = r#"
+        {"a": 1, "b": "x", "c": {"d": [1, 2.5, null]}}
+        {"a": 2}
+        {"_rest": true, "a": 3, "e": -300}
+        "#;
+
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, true)]));
+        let batch = ReaderBuilder::new(schema)
+            .with_strict_mode(true)
+            .with_unknown_fields_as_variant("_rest")
+            .build(Cursor::new(buf.as_bytes()))
+            .unwrap()
+            .read()
+            .unwrap()
+            .unwrap();
+        assert_eq!(batch.schema().field(1).name(), "_rest");
+        assert_eq!(
+            batch.schema().field(1).extension_type_name(),
+            Some("arrow.parquet.variant")
+        );
+
+        let a = batch.column(0).as_primitive::<Int32Type>();
+        assert_eq!(a, &arrow_array::Int32Array::from(vec![1, 2, 3]));
+
+        let rest = batch.column(1).as_struct();
+        assert_eq!(rest.null_count(), 1);
+        let variant = |row: usize| {
+            let metadata = rest.column(0).as_binary_view().value(row);
+            let value = rest.column(1).as_binary_view().value(row);
+            Variant::try_new(metadata, value).unwrap()
+        };
+
+        let first = variant(0);
+        let first = first.as_object().unwrap();
+        assert_eq!(first.len(), 2);
+        assert_eq!(first.get("b"), Some(Variant::from("x")));
+        let c = first.get("c").unwrap();
+        let d = c.get_object_field("d").unwrap();
+        let d = d.as_list().unwrap();
+        assert_eq!(d.get(0), Some(Variant::Int8(1)));
+        assert_eq!(d.get(1), Some(Variant::Double(2.5)));
+        assert_eq!(d.get(2), Some(Variant::Null));
+
+        assert!(rest.is_null(1));
+
+        let last = variant(2);
+        assert_eq!(last.get_object_field("_rest"), Some(Variant::BooleanTrue));
+        assert_eq!(last.get_object_field("e"), Some(Variant::Int16(-300)));
+        assert_eq!(last.get_object_field("a"), None);
+    }
+
+    #[test]
+    #[cfg(feature = "variant_experimental")]
+    fn test_unknown_fields_as_variant_invalid_column() {
+        let schema = Arc::new(Schema::new(vec![Field::new("_rest", DataType::Utf8, true)]));
+        let err = ReaderBuilder::new(schema)
+            .with_unknown_fields_as_variant("_rest")
+            .build_decoder()
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Unknown fields column '_rest' must be a nullable Struct"),
+            "{err}"
+        );
+    }
+
     fn read_file(path: &str, schema: Option<Schema>) -> Reader<BufReader<File>> {
         let file = File::open(path).unwrap();
         let mut reader = BufReader::new(file);
@@ -2804,4 +3301,167 @@ mod tests {
             "Json error: whilst decoding field 'a': failed to parse \"a\" as Int32".to_owned()
         );
     }
+
+    #[test]
+    fn test_buffer_recycler() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Utf8, true),
+        ]));
+        let recycler = Arc::new(BufferRecycler::default());
+
+        let data = r#"
+            {"a": 1, "b": "hello"}
+            {"a": 2, "b": "world"}
+            {"a": null, "b": null}
+            {"a": 4, "b": "foo"}
+        "#;
+        let mut reader = ReaderBuilder::new(schema)
+            .with_batch_size(2)
+            .with_buffer_recycler(recycler.clone())
+            .build(Cursor::new(data.as_bytes()))
+            .unwrap();
+
+        let batch = reader.next().unwrap().unwrap();
+        let a_ptr = batch
+            .column(0)
+            .as_primitive::<Int64Type>()
+            .values()
+            .as_ptr();
+        assert_eq!(batch.column(1).as_string::<i32>().value(1), "world");
+        batch.recycle_buffers(&recycler);
+        assert_eq!(recycler.len(), 3);
+
+        let batch = reader.next().unwrap().unwrap();
+        let a = batch.column(0).as_primitive::<Int64Type>();
+        let b = batch.column(1).as_string::<i32>();
+        assert!(recycler.is_empty());
+        assert!(a.is_null(0));
+        assert_eq!(a.value(1), 4);
+        assert!(b.is_null(0));
+        assert_eq!(b.value(1), "foo");
+
+        // The allocations of the first batch are reused
+        let ptrs = [
+            a.values().inner().as_ptr(),
+            b.offsets().inner().inner().as_ptr(),
+            b.values().as_ptr(),
+        ];
+        assert!(ptrs.contains(&a_ptr.cast()));
+        assert!(reader.next().is_none());
+    }
+
+    fn read_bad_records(
+        buf: &str,
+        mode: BadRecordMode,
+        batch_size: usize,
+        buffer_size: usize,
+    ) -> (Vec<Option<i32>>, Vec<Option<String>>, BadRecords) {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ]));
+        let mut reader = ReaderBuilder::new(schema)
+            .with_batch_size(batch_size)
+            .with_bad_record_mode(mode)
+            .build(BufReader::with_capacity(
+                buffer_size,
+                Cursor::new(buf.as_bytes()),
+            ))
+            .unwrap();
+        let mut a = vec![];
+        let mut b = vec![];
+        for batch in reader.by_ref() {
+            let batch = batch.unwrap();
+            assert!(batch.num_rows() <= batch_size);
+            a.extend(batch.column(0).as_primitive::<Int32Type>());
+            b.extend(
+                batch
+                    .column(1)
+                    .as_string::<i32>()
+                    .iter()
+                    .map(|s| s.map(String::from)),
+            );
+        }
+        (a, b, reader.take_bad_records())
+    }
+
+    #[test]
+    fn test_bad_record_mode() {
+        let buf = r#"{"a": 1, "b": "x"}
+{"a": "not a number", "b": "y"}
+{"a": 3, "b":
+{"a": 4} {"a": 5}
+
+{"a": 6, "b": "z"}
+{"a": 7}"#;
+
+        for batch_size in [1, 2, 3, 100] {
+            for buffer_size in [1, 5, 1024] {
+                let (a, b, bad) =
+                    read_bad_records(buf, BadRecordMode::Skip, batch_size, buffer_size);
+                assert_eq!(a, vec![Some(1), Some(6), Some(7)]);
+                assert_eq!(b, vec![Some("x".to_string()), Some("z".to_string()), None]);
+
+                // Bad records of a batch are not collected in input order
+                let mut bad: Vec<_> = bad.records().iter().zip(bad.errors()).collect();
+                bad.sort_unstable();
+                assert_eq!(bad.len(), 3);
+                assert_eq!(bad[0].0, Some(r#"{"a": "not a number", "b": "y"}"#));
+                assert!(bad[0].1.unwrap().contains("not a number"));
+                assert_eq!(bad[1].0, Some(r#"{"a": 3, "b":"#));
+                assert_eq!(bad[2].0, Some(r#"{"a": 4} {"a": 5}"#));
+                assert!(bad[2].1.unwrap().contains("more than one record"));
+
+                let (a, _, bad) =
+                    read_bad_records(buf, BadRecordMode::Null, batch_size, buffer_size);
+                assert_eq!(a, vec![Some(1), None, None, None, Some(6), Some(7)]);
+                assert_eq!(bad.len(), 3);
+            }
+        }
+    }
+
+    #[test]
+    fn test_bad_record_mode_error() {
+        let buf = "{\"a\": 1}\n{\"a\": \"x\"}\n";
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, true)]));
+        let mut reader = ReaderBuilder::new(schema.clone())
+            .build(Cursor::new(buf.as_bytes()))
+            .unwrap();
+        assert!(reader.next().unwrap().is_err());
+        assert!(reader.take_bad_records().is_empty());
+
+        let buf = "{\"a\": 1}\n{\"a\": \"x\"}\n{\"a\": \n{\"a\": 4}\n";
+        let err = ReaderBuilder::new(schema)
+            .with_bad_record_mode(BadRecordMode::Skip)
+            .with_max_bad_records(1)
+            .build(Cursor::new(buf.as_bytes()))
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Encountered more than 1 bad records"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_bad_record_mode_field() {
+        let buf = "1\n\"x\"\n[3]\n4\n";
+        let field = Field::new("int", DataType::Int32, true);
+        let mut reader = ReaderBuilder::new_with_field(field)
+            .with_bad_record_mode(BadRecordMode::Null)
+            .build(Cursor::new(buf.as_bytes()))
+            .unwrap();
+        let batch = reader.next().unwrap().unwrap();
+        let a = batch.column(0).as_primitive::<Int32Type>();
+        assert_eq!(
+            a.iter().collect::<Vec<_>>(),
+            vec![Some(1), None, None, Some(4)]
+        );
+        let bad = reader.take_bad_records();
+        assert_eq!(bad.records(), &StringArray::from(vec!["\"x\"", "[3]"]));
+    }
 }
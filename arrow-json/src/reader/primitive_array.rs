@@ -17,16 +17,18 @@
 
 use num::NumCast;
 use std::marker::PhantomData;
+use std::sync::Arc;
 
 use arrow_array::builder::PrimitiveBuilder;
 use arrow_array::{Array, ArrowPrimitiveType};
+use arrow_buffer::BufferRecycler;
 use arrow_cast::parse::Parser;
 use arrow_data::ArrayData;
 use arrow_schema::{ArrowError, DataType};
 use half::f16;
 
 use crate::reader::tape::{Tape, TapeElement};
-use crate::reader::ArrayDecoder;
+use crate::reader::{take_buffer, ArrayDecoder};
 
 /// A trait for JSON-specific primitive parsing logic
 ///
@@ -38,8 +40,17 @@ use crate::reader::ArrayDecoder;
 /// and fallback to parsing as a floating point if this fails
 trait ParseJsonNumber: Sized {
     fn parse(s: &[u8]) -> Option<Self>;
+
+    /// Parses `s` as [`Self::parse`], but returning `None` if the value cannot be represented
+    /// exactly, such as `12.7` for an integer, rather than truncating it
+    fn parse_exact(s: &[u8]) -> Option<Self> {
+        Self::parse(s)
+    }
 }
 
+/// The largest integer below which all integers can be exactly represented by an `f64`
+const MAX_EXACT_F64_INTEGER: f64 = (1u64 << f64::MANTISSA_DIGITS) as f64;
+
 macro_rules! primitive_parse {
     ($($t:ty),+) => {
         $(impl ParseJsonNumber for $t {
@@ -49,6 +60,16 @@ macro_rules! primitive_parse {
                     Err(_) => lexical_core::parse::<f64>(s).ok().and_then(NumCast::from),
                 }
             }
+
+            fn parse_exact(s: &[u8]) -> Option<Self> {
+                match lexical_core::parse::<Self>(s) {
+                    Ok(f) => Some(f),
+                    Err(_) => lexical_core::parse::<f64>(s)
+                        .ok()
+                        .filter(|f| f.fract() == 0. && f.abs() <= MAX_EXACT_F64_INTEGER)
+                        .and_then(NumCast::from),
+                }
+            }
         })+
     };
 }
@@ -73,16 +94,30 @@ impl ParseJsonNumber for f64 {
     }
 }
 
+/// Parses the quoted string `s` of a numeric `data_type` as if it were unquoted,
+/// allowing for quoted numbers such as `"1e3"` or `" 12.0 "`
+///
+/// Unlike unquoted numbers, quoted values that an integer type cannot represent exactly,
+/// such as `"12.7"`, are not truncated but return `None`
+fn parse_quoted_number<N: ParseJsonNumber>(data_type: &DataType, s: &str) -> Option<N> {
+    match data_type.is_numeric() {
+        true => N::parse_exact(s.trim().as_bytes()),
+        false => None,
+    }
+}
+
 pub struct PrimitiveArrayDecoder<P: ArrowPrimitiveType> {
     data_type: DataType,
+    recycler: Option<Arc<BufferRecycler>>,
     // Invariant and Send
     phantom: PhantomData<fn(P) -> P>,
 }
 
 impl<P: ArrowPrimitiveType> PrimitiveArrayDecoder<P> {
-    pub fn new(data_type: DataType) -> Self {
+    pub fn new(data_type: DataType, recycler: Option<Arc<BufferRecycler>>) -> Self {
         Self {
             data_type,
+            recycler,
             phantom: Default::default(),
         }
     }
@@ -94,8 +129,10 @@ where
     P::Native: ParseJsonNumber + NumCast,
 {
     fn decode(&mut self, tape: &Tape<'_>, pos: &[u32]) -> Result<ArrayData, ArrowError> {
-        let mut builder =
-            PrimitiveBuilder::<P>::with_capacity(pos.len()).with_data_type(self.data_type.clone());
+        let capacity = pos.len() * std::mem::size_of::<P::Native>();
+        let values = take_buffer(self.recycler.as_deref(), capacity);
+        let mut builder = PrimitiveBuilder::<P>::new_from_buffer(values, None)
+            .with_data_type(self.data_type.clone());
         let d = &self.data_type;
 
         for p in pos {
@@ -103,9 +140,11 @@ where
                 TapeElement::Null => builder.append_null(),
                 TapeElement::String(idx) => {
                     let s = tape.get_string(idx);
-                    let value = P::parse(s).ok_or_else(|| {
-                        ArrowError::JsonError(format!("failed to parse \"{s}\" as {d}",))
-                    })?;
+                    let value = P::parse(s)
+                        .or_else(|| parse_quoted_number(d, s))
+                        .ok_or_else(|| {
+                            ArrowError::JsonError(format!("failed to parse \"{s}\" as {d}",))
+                        })?;
 
                     builder.append_value(value)
                 }
@@ -276,6 +276,42 @@ pub fn infer_json_schema<R: BufRead>(
     Ok((schema, values.record_count))
 }
 
+/// Infer the fields of a JSON file as [`infer_json_schema`], additionally returning a
+/// [`SchemaInferenceReport`] of the types observed for each field, and how any conflicts
+/// between them were resolved
+///
+/// This allows callers to review the decisions made during inference, and override them
+/// by adjusting the returned [`Schema`] before creating a [`Reader`]
+///
+/// # Examples
+/// ```
+/// use std::io::Cursor;
+/// use arrow_json::reader::{infer_json_schema_with_report, JsonType, TypeResolution};
+///
+/// let data = r#"{"a": 1, "b": "x"}
+/// {"a": 2.5, "b": true}
+/// "#;
+/// let (schema, report) = infer_json_schema_with_report(Cursor::new(data), None).unwrap();
+/// assert_eq!(report.num_records(), 2);
+///
+/// let a = report.field(&["a"]).unwrap();
+/// assert_eq!(a.observed_types(), &[JsonType::Integer, JsonType::Float]);
+/// assert_eq!(a.resolution(), Some(TypeResolution::PromoteToFloat));
+///
+/// let b = report.field(&["b"]).unwrap();
+/// assert_eq!(b.resolution(), Some(TypeResolution::WidenToUtf8));
+/// assert_eq!(report.conflicts().count(), 2);
+/// ```
+///
+/// [`Reader`]: super::Reader
+pub fn infer_json_schema_with_report<R: BufRead>(
+    reader: R,
+    max_read_records: Option<usize>,
+) -> Result<(Schema, SchemaInferenceReport), ArrowError> {
+    let mut values = ValueIter::new(reader, max_read_records);
+    infer_json_schema_from_iterator_with_report(&mut values)
+}
+
 fn set_object_scalar_field_type(
     field_types: &mut HashMap<String, InferredType>,
     key: &str,
@@ -489,6 +525,32 @@ fn collect_field_types_from_object(
 /// interpreted as Strings. We should match Spark's behavior once we added more JSON parsing
 /// kernels in the future.
 pub fn infer_json_schema_from_iterator<I, V>(value_iter: I) -> Result<Schema, ArrowError>
+where
+    I: Iterator<Item = Result<V, ArrowError>>,
+    V: Borrow<Value>,
+{
+    infer_schema_from_iterator(value_iter, None)
+}
+
+/// Infer the fields of a JSON file by reading all items from the JSON Value Iterator, as
+/// [`infer_json_schema_from_iterator`], additionally returning a [`SchemaInferenceReport`]
+pub fn infer_json_schema_from_iterator_with_report<I, V>(
+    value_iter: I,
+) -> Result<(Schema, SchemaInferenceReport), ArrowError>
+where
+    I: Iterator<Item = Result<V, ArrowError>>,
+    V: Borrow<Value>,
+{
+    let mut observations = Observations::default();
+    let schema = infer_schema_from_iterator(value_iter, Some(&mut observations))?;
+    let report = observations.into_report(&schema);
+    Ok((schema, report))
+}
+
+fn infer_schema_from_iterator<I, V>(
+    value_iter: I,
+    mut observations: Option<&mut Observations>,
+) -> Result<Schema, ArrowError>
 where
     I: Iterator<Item = Result<V, ArrowError>>,
     V: Borrow<Value>,
@@ -496,7 +558,8 @@ where
     let mut field_types: HashMap<String, InferredType> = HashMap::new();
 
     for record in value_iter {
-        match record?.borrow() {
+        let record = record?;
+        match record.borrow() {
             Value::Object(map) => {
                 collect_field_types_from_object(&mut field_types, map)?;
             }
@@ -506,11 +569,278 @@ where
                 )));
             }
         };
+        if let Some(observations) = observations.as_deref_mut() {
+            observations.observe_record(record.borrow());
+        }
     }
 
     generate_schema(field_types)
 }
 
+/// The type of a non-null JSON value observed during schema inference
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum JsonType {
+    /// `true` or `false`
+    Boolean,
+    /// A number that fits in an `i64`
+    Integer,
+    /// Any other number
+    Float,
+    /// A string
+    String,
+    /// An array
+    Array,
+    /// An object
+    Object,
+}
+
+impl JsonType {
+    fn of(value: &Value) -> Option<Self> {
+        Some(match value {
+            Value::Null => return None,
+            Value::Bool(_) => Self::Boolean,
+            Value::Number(n) if n.is_i64() => Self::Integer,
+            Value::Number(_) => Self::Float,
+            Value::String(_) => Self::String,
+            Value::Array(_) => Self::Array,
+            Value::Object(_) => Self::Object,
+        })
+    }
+}
+
+/// How schema inference resolved conflicting [`JsonType`] observed for a field
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TypeResolution {
+    /// Both integers and floats were observed, and are read as `Float64`
+    PromoteToFloat,
+    /// Both scalars and arrays were observed, and scalars are read as single element lists
+    ScalarToList,
+    /// Incompatible scalars were observed, and are read as `Utf8`
+    WidenToUtf8,
+}
+
+/// The types observed for a field during schema inference, see [`SchemaInferenceReport`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldInference {
+    path: Vec<String>,
+    data_type: DataType,
+    observed_types: Vec<JsonType>,
+    null_count: usize,
+    missing_count: usize,
+    resolution: Option<TypeResolution>,
+}
+
+impl FieldInference {
+    /// The names of the fields from the root of the schema to this field
+    ///
+    /// The elements of a list are named after its child field, i.e. `item`
+    pub fn path(&self) -> &[String] {
+        &self.path
+    }
+
+    /// The inferred [`DataType`] of this field
+    pub fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    /// The distinct [`JsonType`] of the non-null values observed for this field, sorted
+    pub fn observed_types(&self) -> &[JsonType] {
+        &self.observed_types
+    }
+
+    /// The number of null values observed for this field
+    pub fn null_count(&self) -> usize {
+        self.null_count
+    }
+
+    /// The number of objects observed without this field
+    pub fn missing_count(&self) -> usize {
+        self.missing_count
+    }
+
+    /// True if this field was observed to be null or missing
+    pub fn is_nullable(&self) -> bool {
+        self.null_count != 0 || self.missing_count != 0
+    }
+
+    /// How conflicting [`Self::observed_types`] were resolved, if any
+    pub fn resolution(&self) -> Option<TypeResolution> {
+        self.resolution
+    }
+}
+
+/// A report of the types observed for each field during schema inference, see
+/// [`infer_json_schema_with_report`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaInferenceReport {
+    num_records: usize,
+    fields: Vec<FieldInference>,
+}
+
+impl SchemaInferenceReport {
+    /// The number of records read
+    pub fn num_records(&self) -> usize {
+        self.num_records
+    }
+
+    /// The [`FieldInference`] of every field of the inferred schema, including nested
+    /// fields and list elements, in depth-first order
+    pub fn fields(&self) -> &[FieldInference] {
+        &self.fields
+    }
+
+    /// Returns the [`FieldInference`] of the field at `path`, see [`FieldInference::path`]
+    pub fn field(&self, path: &[&str]) -> Option<&FieldInference> {
+        self.fields.iter().find(|f| f.path.iter().eq(path.iter()))
+    }
+
+    /// Returns the [`FieldInference`] of the fields with conflicting observed types
+    pub fn conflicts(&self) -> impl Iterator<Item = &FieldInference> + '_ {
+        self.fields.iter().filter(|f| f.resolution.is_some())
+    }
+}
+
+/// The values observed at a path of the JSON records
+#[derive(Debug, Default)]
+struct Observation {
+    types: HashSet<JsonType>,
+    null_count: usize,
+    /// The number of objects with a field at this path
+    present_count: usize,
+    /// The number of objects observed at this path
+    object_count: usize,
+}
+
+/// Collects an [`Observation`] for every path of the JSON records
+#[derive(Debug, Default)]
+struct Observations {
+    num_records: usize,
+    paths: HashMap<Vec<String>, Observation>,
+    path: Vec<String>,
+}
+
+impl Observations {
+    fn observe_record(&mut self, record: &Value) {
+        self.num_records += 1;
+        if let Value::Object(map) = record {
+            self.observe_object(map);
+        }
+    }
+
+    fn observe_object(&mut self, map: &serde_json::map::Map<String, Value>) {
+        self.observation().object_count += 1;
+        for (k, v) in map {
+            self.path.push(k.clone());
+            self.observation().present_count += 1;
+            self.observe_value(v);
+            self.path.pop();
+        }
+    }
+
+    fn observe_value(&mut self, value: &Value) {
+        let observation = self.observation();
+        match JsonType::of(value) {
+            Some(t) => {
+                observation.types.insert(t);
+            }
+            None => observation.null_count += 1,
+        }
+        match value {
+            Value::Array(values) => {
+                self.path.push(Field::LIST_FIELD_DEFAULT_NAME.to_string());
+                values.iter().for_each(|v| self.observe_value(v));
+                self.path.pop();
+            }
+            Value::Object(map) => self.observe_object(map),
+            _ => {}
+        }
+    }
+
+    fn observation(&mut self) -> &mut Observation {
+        if !self.paths.contains_key(&self.path) {
+            self.paths.insert(self.path.clone(), Observation::default());
+        }
+        self.paths.get_mut(&self.path).unwrap()
+    }
+
+    fn into_report(self, schema: &Schema) -> SchemaInferenceReport {
+        let mut fields = vec![];
+        let mut path = vec![];
+        for field in schema.fields() {
+            self.report_field(field, self.num_records, &[], &mut path, &mut fields);
+        }
+        SchemaInferenceReport {
+            num_records: self.num_records,
+            fields,
+        }
+    }
+
+    /// Appends the [`FieldInference`] of `field` and its children to `out`
+    ///
+    /// `object_count` is the number of objects observed containing `field`, and
+    /// `list_scalars` the scalars read as single element lists of `field`
+    fn report_field(
+        &self,
+        field: &Field,
+        object_count: usize,
+        list_scalars: &[JsonType],
+        path: &mut Vec<String>,
+        out: &mut Vec<FieldInference>,
+    ) {
+        path.push(field.name().clone());
+        let observation = self.paths.get(path.as_slice());
+        let mut observed_types: Vec<_> = list_scalars.to_vec();
+        if let Some(o) = observation {
+            observed_types.extend(o.types.iter().copied());
+        }
+        observed_types.sort_unstable();
+        observed_types.dedup();
+
+        let scalars: Vec<_> = observed_types
+            .iter()
+            .copied()
+            .filter(|t| !matches!(t, JsonType::Array | JsonType::Object))
+            .collect();
+        let has_array = observed_types.contains(&JsonType::Array);
+        let resolution = if has_array && !scalars.is_empty() {
+            Some(TypeResolution::ScalarToList)
+        } else if field.data_type() == &DataType::Utf8 && scalars.len() > 1 {
+            Some(TypeResolution::WidenToUtf8)
+        } else if field.data_type() == &DataType::Float64 && scalars.len() > 1 {
+            Some(TypeResolution::PromoteToFloat)
+        } else {
+            None
+        };
+
+        out.push(FieldInference {
+            path: path.clone(),
+            data_type: field.data_type().clone(),
+            observed_types,
+            null_count: observation.map(|o| o.null_count).unwrap_or_default(),
+            missing_count: object_count - observation.map(|o| o.present_count).unwrap_or_default(),
+            resolution,
+        });
+
+        match field.data_type() {
+            DataType::List(element) => {
+                let list_scalars = match resolution {
+                    Some(TypeResolution::ScalarToList) => scalars.as_slice(),
+                    _ => &[],
+                };
+                self.report_field(element, 0, list_scalars, path, out);
+            }
+            DataType::Struct(children) => {
+                let object_count = observation.map(|o| o.object_count).unwrap_or_default();
+                for child in children {
+                    self.report_field(child, object_count, &[], path, out);
+                }
+            }
+            _ => {}
+        }
+        path.pop();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -738,4 +1068,57 @@ mod tests {
         )]);
         assert_eq!(inferred_schema, schema);
     }
+
+    #[test]
+    fn test_infer_json_schema_with_report() {
+        let data = r#"
+            {"a": 1,   "b": [1, 2],  "c": {"d": true}, "e": "x"}
+            {"a": 2.5, "b": 3,       "c": {"d": null}, "e": 1}
+            {"a": null, "b": ["x"],  "c": {}}
+        "#;
+        let (schema, report) = infer_json_schema_with_report(Cursor::new(data), None).unwrap();
+        let (expected, _) = infer_json_schema(Cursor::new(data), None).unwrap();
+        assert_eq!(schema, expected);
+        assert_eq!(report.num_records(), 3);
+
+        let paths: Vec<_> = report.fields().iter().map(|f| f.path().join(".")).collect();
+        assert_eq!(paths, ["a", "b", "b.item", "c", "c.d", "e"]);
+
+        let a = report.field(&["a"]).unwrap();
+        assert_eq!(a.data_type(), &DataType::Float64);
+        assert_eq!(a.observed_types(), &[JsonType::Integer, JsonType::Float]);
+        assert_eq!(a.null_count(), 1);
+        assert_eq!(a.missing_count(), 0);
+        assert_eq!(a.resolution(), Some(TypeResolution::PromoteToFloat));
+
+        let b = report.field(&["b"]).unwrap();
+        assert_eq!(b.observed_types(), &[JsonType::Integer, JsonType::Array]);
+        assert!(!b.is_nullable());
+        assert_eq!(b.resolution(), Some(TypeResolution::ScalarToList));
+
+        // Scalars read as single element lists are observed as elements
+        let item = report.field(&["b", "item"]).unwrap();
+        assert_eq!(item.data_type(), &DataType::Utf8);
+        assert_eq!(
+            item.observed_types(),
+            &[JsonType::Integer, JsonType::String]
+        );
+        assert_eq!(item.resolution(), Some(TypeResolution::WidenToUtf8));
+
+        let c = report.field(&["c"]).unwrap();
+        assert_eq!(c.observed_types(), &[JsonType::Object]);
+        assert_eq!(c.resolution(), None);
+
+        let d = report.field(&["c", "d"]).unwrap();
+        assert_eq!(d.data_type(), &DataType::Boolean);
+        assert_eq!(d.null_count(), 1);
+        assert_eq!(d.missing_count(), 1);
+
+        let e = report.field(&["e"]).unwrap();
+        assert_eq!(e.missing_count(), 1);
+        assert_eq!(e.resolution(), Some(TypeResolution::WidenToUtf8));
+
+        let conflicts: Vec<_> = report.conflicts().map(|f| f.path().join(".")).collect();
+        assert_eq!(conflicts, ["a", "b", "b.item", "e"]);
+    }
 }
@@ -15,32 +15,95 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use arrow_array::builder::GenericStringBuilder;
-use arrow_array::{Array, GenericStringArray, OffsetSizeTrait};
-use arrow_data::ArrayData;
+use arrow_array::{GenericStringArray, OffsetSizeTrait};
+use arrow_buffer::{BufferRecycler, MutableBuffer, NullBufferBuilder};
+use arrow_data::{ArrayData, ArrayDataBuilder};
 use arrow_schema::ArrowError;
 use std::marker::PhantomData;
+use std::sync::Arc;
 
 use crate::reader::tape::{Tape, TapeElement};
-use crate::reader::ArrayDecoder;
+use crate::reader::{take_buffer, ArrayDecoder};
 
 const TRUE: &str = "true";
 const FALSE: &str = "false";
 
 pub struct StringArrayDecoder<O: OffsetSizeTrait> {
     coerce_primitive: bool,
+    recycler: Option<Arc<BufferRecycler>>,
     phantom: PhantomData<O>,
 }
 
 impl<O: OffsetSizeTrait> StringArrayDecoder<O> {
-    pub fn new(coerce_primitive: bool) -> Self {
+    pub fn new(coerce_primitive: bool, recycler: Option<Arc<BufferRecycler>>) -> Self {
         Self {
             coerce_primitive,
+            recycler,
             phantom: Default::default(),
         }
     }
 }
 
+/// Builds the offsets and values of a [`GenericStringArray`] in buffers
+/// obtained from an optional [`BufferRecycler`]
+struct StringBuffers<O: OffsetSizeTrait> {
+    offsets: MutableBuffer,
+    values: MutableBuffer,
+    nulls: NullBufferBuilder,
+    phantom: PhantomData<O>,
+}
+
+impl<O: OffsetSizeTrait> StringBuffers<O> {
+    fn new(recycler: Option<&BufferRecycler>, len: usize, data_capacity: usize) -> Self {
+        let mut offsets = take_buffer(recycler, (len + 1) * std::mem::size_of::<O>());
+        offsets.push(O::usize_as(0));
+        Self {
+            offsets,
+            values: take_buffer(recycler, data_capacity),
+            nulls: NullBufferBuilder::new(len),
+            phantom: Default::default(),
+        }
+    }
+
+    fn append_value(&mut self, value: impl AsRef<str>) -> Result<(), ArrowError> {
+        self.values.extend_from_slice(value.as_ref().as_bytes());
+        self.push_offset()?;
+        self.nulls.append_non_null();
+        Ok(())
+    }
+
+    fn append_null(&mut self) -> Result<(), ArrowError> {
+        self.push_offset()?;
+        self.nulls.append_null();
+        Ok(())
+    }
+
+    /// Appends the end offset of the current value, returning an error if it overflows `O`
+    fn push_offset(&mut self) -> Result<(), ArrowError> {
+        let offset = O::from_usize(self.values.len()).ok_or_else(|| {
+            ArrowError::JsonError(format!(
+                "offset overflow decoding {}",
+                GenericStringArray::<O>::DATA_TYPE
+            ))
+        })?;
+        self.offsets.push(offset);
+        Ok(())
+    }
+
+    fn finish(mut self) -> ArrayData {
+        let builder = ArrayDataBuilder::new(GenericStringArray::<O>::DATA_TYPE)
+            .len(self.nulls.len())
+            .add_buffer(self.offsets.into())
+            .add_buffer(self.values.into())
+            .nulls(self.nulls.finish());
+
+        // Safety:
+        // Offsets are monotonic and checked to fit in O, and values are valid UTF-8
+        // by construction
+        unsafe { builder.build_unchecked() }
+    }
+}
+
 impl<O: OffsetSizeTrait> ArrayDecoder for StringArrayDecoder<O> {
     fn decode(&mut self, tape: &Tape<'_>, pos: &[u32]) -> Result<ArrayData, ArrowError> {
         let coerce_primitive = self.coerce_primitive;
@@ -83,40 +146,41 @@ impl<O: OffsetSizeTrait> ArrayDecoder for StringArrayDecoder<O> {
             )));
         }
 
-        let mut builder = GenericStringBuilder::<O>::with_capacity(pos.len(), data_capacity);
+        let recycler = self.recycler.as_deref();
+        let mut builder = StringBuffers::<O>::new(recycler, pos.len(), data_capacity);
 
         for p in pos {
             match tape.get(*p) {
                 TapeElement::String(idx) => {
-                    builder.append_value(tape.get_string(idx));
+                    builder.append_value(tape.get_string(idx))?;
                 }
-                TapeElement::Null => builder.append_null(),
+                TapeElement::Null => builder.append_null()?,
                 TapeElement::True if coerce_primitive => {
-                    builder.append_value(TRUE);
+                    builder.append_value(TRUE)?;
                 }
                 TapeElement::False if coerce_primitive => {
-                    builder.append_value(FALSE);
+                    builder.append_value(FALSE)?;
                 }
                 TapeElement::Number(idx) if coerce_primitive => {
-                    builder.append_value(tape.get_string(idx));
+                    builder.append_value(tape.get_string(idx))?;
                 }
                 TapeElement::I64(high) if coerce_primitive => match tape.get(p + 1) {
                     TapeElement::I32(low) => {
                         let val = ((high as i64) << 32) | (low as u32) as i64;
-                        builder.append_value(val.to_string());
+                        builder.append_value(val.to_string())?;
                     }
                     _ => unreachable!(),
                 },
                 TapeElement::I32(n) if coerce_primitive => {
-                    builder.append_value(n.to_string());
+                    builder.append_value(n.to_string())?;
                 }
                 TapeElement::F32(n) if coerce_primitive => {
-                    builder.append_value(n.to_string());
+                    builder.append_value(n.to_string())?;
                 }
                 TapeElement::F64(high) if coerce_primitive => match tape.get(p + 1) {
                     TapeElement::F32(low) => {
                         let val = f64::from_bits(((high as u64) << 32) | low as u64);
-                        builder.append_value(val.to_string());
+                        builder.append_value(val.to_string())?;
                     }
                     _ => unreachable!(),
                 },
@@ -124,6 +188,6 @@ impl<O: OffsetSizeTrait> ArrayDecoder for StringArrayDecoder<O> {
             }
         }
 
-        Ok(builder.finish().into_data())
+        Ok(builder.finish())
     }
 }
@@ -19,8 +19,10 @@ use crate::reader::tape::{Tape, TapeElement};
 use crate::reader::{make_decoder, ArrayDecoder, StructMode};
 use arrow_array::builder::BooleanBufferBuilder;
 use arrow_buffer::buffer::NullBuffer;
+use arrow_buffer::BufferRecycler;
 use arrow_data::{ArrayData, ArrayDataBuilder};
 use arrow_schema::{ArrowError, DataType, Fields};
+use std::sync::Arc;
 
 pub struct StructArrayDecoder {
     data_type: DataType,
@@ -28,6 +30,9 @@ pub struct StructArrayDecoder {
     strict_mode: bool,
     is_nullable: bool,
     struct_mode: StructMode,
+    /// The index of the field that decodes each object in its entirety, to collect
+    /// the fields not present in the schema
+    unknown_fields: Option<usize>,
 }
 
 impl StructArrayDecoder {
@@ -37,10 +42,40 @@ impl StructArrayDecoder {
         strict_mode: bool,
         is_nullable: bool,
         struct_mode: StructMode,
+        recycler: Option<Arc<BufferRecycler>>,
     ) -> Result<Self, ArrowError> {
+        Self::new_with_unknown_fields(
+            data_type,
+            coerce_primitive,
+            strict_mode,
+            is_nullable,
+            struct_mode,
+            recycler,
+            None,
+        )
+    }
+
+    /// Create a new [`StructArrayDecoder`] where the field at the index of `unknown_fields`
+    /// is decoded by the provided decoder from the position of each object, instead of the
+    /// position of the field's value
+    pub fn new_with_unknown_fields(
+        data_type: DataType,
+        coerce_primitive: bool,
+        strict_mode: bool,
+        is_nullable: bool,
+        struct_mode: StructMode,
+        recycler: Option<Arc<BufferRecycler>>,
+        unknown_fields: Option<(usize, Box<dyn ArrayDecoder>)>,
+    ) -> Result<Self, ArrowError> {
+        let unknown_fields_idx = unknown_fields.as_ref().map(|(idx, _)| *idx);
+        let mut unknown_fields_decoder = unknown_fields.map(|(_, decoder)| decoder);
         let decoders = struct_fields(&data_type)
             .iter()
-            .map(|f| {
+            .enumerate()
+            .map(|(idx, f)| {
+                if Some(idx) == unknown_fields_idx {
+                    return Ok(unknown_fields_decoder.take().unwrap());
+                }
                 // If this struct nullable, need to permit nullability in child array
                 // StructArrayDecoder::decode verifies that if the child is not nullable
                 // it doesn't contain any nulls not masked by its parent
@@ -51,6 +86,7 @@ impl StructArrayDecoder {
                     strict_mode,
                     nullable,
                     struct_mode,
+                    recycler.clone(),
                 )
             })
             .collect::<Result<Vec<_>, ArrowError>>()?;
@@ -61,6 +97,7 @@ impl StructArrayDecoder {
             strict_mode,
             is_nullable,
             struct_mode,
+            unknown_fields: unknown_fields_idx,
         })
     }
 }
@@ -102,9 +139,11 @@ impl ArrayDecoder for StructArrayDecoder {
 
                         // Update child pos if match found
                         match fields.iter().position(|x| x.name() == field_name) {
-                            Some(field_idx) => child_pos[field_idx][row] = cur_idx + 1,
-                            None => {
-                                if self.strict_mode {
+                            Some(field_idx) if Some(field_idx) != self.unknown_fields => {
+                                child_pos[field_idx][row] = cur_idx + 1
+                            }
+                            _ => {
+                                if self.strict_mode && self.unknown_fields.is_none() {
                                     return Err(ArrowError::JsonError(format!(
                                         "column '{field_name}' missing from schema",
                                     )));
@@ -114,6 +153,10 @@ impl ArrayDecoder for StructArrayDecoder {
                         // Advance to next field
                         cur_idx = tape.next(cur_idx + 1, "field value")?;
                     }
+
+                    if let Some(field_idx) = self.unknown_fields {
+                        child_pos[field_idx][row] = *p;
+                    }
                 }
             }
             StructMode::ListOnly => {
@@ -294,6 +294,15 @@ macro_rules! next {
     };
 }
 
+/// The state of a [`TapeDecoder`] between two rows, see [`TapeDecoder::checkpoint`]
+#[derive(Debug, Copy, Clone)]
+pub struct TapeCheckpoint {
+    num_rows: usize,
+    num_elements: usize,
+    num_bytes: usize,
+    num_offsets: usize,
+}
+
 /// Implements a state machine for decoding JSON to a tape
 pub struct TapeDecoder {
     elements: Vec<TapeElement>,
@@ -583,7 +592,7 @@ impl TapeDecoder {
             self.bytes.len()
         );
 
-        let strings = simdutf8::basic::from_utf8(&self.bytes)
+        let strings = arrow_buffer::utf8::from_utf8(&self.bytes)
             .map_err(|_| ArrowError::JsonError("Encountered non-UTF-8 data".to_string()))?;
 
         for offset in self.offsets.iter().copied() {
@@ -602,6 +611,30 @@ impl TapeDecoder {
         })
     }
 
+    /// Returns a [`TapeCheckpoint`] of the rows decoded so far, to which this
+    /// [`TapeDecoder`] can be rolled back with [`Self::rollback`]
+    ///
+    /// Must not be called part way through decoding a row
+    pub fn checkpoint(&self) -> TapeCheckpoint {
+        assert!(self.stack.is_empty());
+        TapeCheckpoint {
+            num_rows: self.cur_row,
+            num_elements: self.elements.len(),
+            num_bytes: self.bytes.len(),
+            num_offsets: self.offsets.len(),
+        }
+    }
+
+    /// Discards any data decoded since `checkpoint` was taken, including a
+    /// partially decoded row
+    pub fn rollback(&mut self, checkpoint: TapeCheckpoint) {
+        self.stack.clear();
+        self.cur_row = checkpoint.num_rows;
+        self.elements.truncate(checkpoint.num_elements);
+        self.bytes.truncate(checkpoint.num_bytes);
+        self.offsets.truncate(checkpoint.num_offsets);
+    }
+
     /// Clears this [`TapeDecoder`] in preparation to read the next batch
     pub fn clear(&mut self) {
         assert!(self.stack.is_empty());
@@ -0,0 +1,232 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow_array::builder::{BinaryViewBuilder, BooleanBufferBuilder};
+use arrow_array::{Array, ArrayRef, StructArray};
+use arrow_buffer::NullBuffer;
+use arrow_data::ArrayData;
+use arrow_schema::extension::{EXTENSION_TYPE_METADATA_KEY, EXTENSION_TYPE_NAME_KEY};
+use arrow_schema::{ArrowError, DataType, Field, Fields};
+use parquet_variant::{ListBuilder, ObjectBuilder, Variant, VariantBuilder, VariantBuilderExt};
+
+use crate::reader::tape::{Tape, TapeElement};
+use crate::reader::ArrayDecoder;
+use crate::VARIANT_EXTENSION_NAME;
+
+/// Returns the field of a column of unknown fields, a nullable variant column
+/// with binary view `metadata` and `value` fields
+pub fn unknown_fields_field(name: &str) -> Field {
+    let fields = Fields::from(vec![
+        Field::new("metadata", DataType::BinaryView, false),
+        Field::new("value", DataType::BinaryView, false),
+    ]);
+    Field::new(name, DataType::Struct(fields), true).with_metadata(HashMap::from([
+        (
+            EXTENSION_TYPE_NAME_KEY.to_owned(),
+            VARIANT_EXTENSION_NAME.to_owned(),
+        ),
+        (EXTENSION_TYPE_METADATA_KEY.to_owned(), String::new()),
+    ]))
+}
+
+/// Decodes the fields of JSON objects that are not present in the schema into
+/// a variant object per row
+///
+/// Rows without unknown fields, or that are null, are decoded as null
+pub struct UnknownFieldsDecoder {
+    data_type: DataType,
+    known_fields: Vec<String>,
+}
+
+impl UnknownFieldsDecoder {
+    /// Create a new [`UnknownFieldsDecoder`] for objects with the fields `known_fields`
+    pub fn new(data_type: DataType, known_fields: Vec<String>) -> Self {
+        Self {
+            data_type,
+            known_fields,
+        }
+    }
+}
+
+impl ArrayDecoder for UnknownFieldsDecoder {
+    fn decode(&mut self, tape: &Tape<'_>, pos: &[u32]) -> Result<ArrayData, ArrowError> {
+        let mut metadata = BinaryViewBuilder::with_capacity(pos.len());
+        let mut value = BinaryViewBuilder::with_capacity(pos.len());
+        let mut nulls = BooleanBufferBuilder::new(pos.len());
+
+        for p in pos {
+            let mut cur_idx = *p + 1;
+            let end_idx = match tape.get(*p) {
+                TapeElement::StartObject(end_idx) => end_idx,
+                TapeElement::Null => cur_idx,
+                _ => return Err(tape.error(*p, "{")),
+            };
+
+            let mut builder = VariantBuilder::new();
+            let mut object = builder.new_object();
+            let mut has_unknown = false;
+            while cur_idx < end_idx {
+                let field_name = match tape.get(cur_idx) {
+                    TapeElement::String(s) => tape.get_string(s),
+                    _ => return Err(tape.error(cur_idx, "field name")),
+                };
+                if !self.known_fields.iter().any(|f| f == field_name) {
+                    has_unknown = true;
+                    let mut field = ObjectFieldBuilder {
+                        key: field_name,
+                        builder: &mut object,
+                    };
+                    append_value(tape, cur_idx + 1, &mut field)?;
+                }
+                cur_idx = tape.next(cur_idx + 1, "field value")?;
+            }
+
+            if has_unknown {
+                object.finish()?;
+                let (m, v) = builder.finish();
+                metadata.append_value(m);
+                value.append_value(v);
+            } else {
+                // Discard the empty object
+                drop(object);
+                metadata.append_value([]);
+                value.append_value([]);
+            }
+            nulls.append(has_unknown);
+        }
+
+        let fields = match &self.data_type {
+            DataType::Struct(fields) => fields.clone(),
+            _ => unreachable!(),
+        };
+        let columns = vec![
+            Arc::new(metadata.finish()) as ArrayRef,
+            Arc::new(value.finish()) as ArrayRef,
+        ];
+        let nulls = NullBuffer::new(nulls.finish());
+        Ok(StructArray::try_new(fields, columns, Some(nulls))?.into_data())
+    }
+}
+
+/// Appends the JSON value at `idx` of `tape` to `builder`
+fn append_value<'m, 'v>(
+    tape: &Tape<'v>,
+    idx: u32,
+    builder: &mut impl VariantBuilderExt<'m, 'v>,
+) -> Result<(), ArrowError> {
+    match tape.get(idx) {
+        TapeElement::Null => builder.append_value(Variant::Null),
+        TapeElement::True => builder.append_value(true),
+        TapeElement::False => builder.append_value(false),
+        TapeElement::String(s) => builder.append_value(tape.get_string(s)),
+        TapeElement::Number(s) => builder.append_value(parse_number(tape.get_string(s))?),
+        TapeElement::I32(v) => builder.append_value(narrowest_int(v as i64)),
+        TapeElement::I64(high) => match tape.get(idx + 1) {
+            TapeElement::I32(low) => {
+                let v = ((high as i64) << 32) | (low as u32) as i64;
+                builder.append_value(narrowest_int(v))
+            }
+            _ => unreachable!(),
+        },
+        TapeElement::F32(v) => builder.append_value(f32::from_bits(v)),
+        TapeElement::F64(high) => match tape.get(idx + 1) {
+            TapeElement::F32(low) => {
+                builder.append_value(f64::from_bits(((high as u64) << 32) | low as u64))
+            }
+            _ => unreachable!(),
+        },
+        TapeElement::StartList(end_idx) => {
+            let mut list = builder.new_list();
+            let mut cur_idx = idx + 1;
+            while cur_idx < end_idx {
+                append_value(tape, cur_idx, &mut list)?;
+                cur_idx = tape.next(cur_idx, "list value")?;
+            }
+            list.finish();
+        }
+        TapeElement::StartObject(end_idx) => {
+            let mut object = builder.new_object();
+            let mut cur_idx = idx + 1;
+            while cur_idx < end_idx {
+                let key = match tape.get(cur_idx) {
+                    TapeElement::String(s) => tape.get_string(s),
+                    _ => return Err(tape.error(cur_idx, "field name")),
+                };
+                let mut field = ObjectFieldBuilder {
+                    key,
+                    builder: &mut object,
+                };
+                append_value(tape, cur_idx + 1, &mut field)?;
+                cur_idx = tape.next(cur_idx + 1, "field value")?;
+            }
+            object.finish()?;
+        }
+        TapeElement::EndObject(_) | TapeElement::EndList(_) => {
+            return Err(tape.error(idx, "value"));
+        }
+    }
+    Ok(())
+}
+
+/// Parses a JSON number as the narrowest integer that can hold it, falling back
+/// to a double
+fn parse_number<'m, 'v>(s: &str) -> Result<Variant<'m, 'v>, ArrowError> {
+    if let Ok(v) = lexical_core::parse::<i64>(s.as_bytes()) {
+        return Ok(narrowest_int(v));
+    }
+    match lexical_core::parse::<f64>(s.as_bytes()) {
+        Ok(v) => Ok(v.into()),
+        Err(_) => Err(ArrowError::JsonError(format!(
+            "failed to parse {s} as number"
+        ))),
+    }
+}
+
+fn narrowest_int<'m, 'v>(v: i64) -> Variant<'m, 'v> {
+    if let Ok(v) = i8::try_from(v) {
+        v.into()
+    } else if let Ok(v) = i16::try_from(v) {
+        v.into()
+    } else if let Ok(v) = i32::try_from(v) {
+        v.into()
+    } else {
+        v.into()
+    }
+}
+
+/// Appends values to a field of an [`ObjectBuilder`]
+struct ObjectFieldBuilder<'o, 'v, 's> {
+    key: &'s str,
+    builder: &'o mut ObjectBuilder<'v>,
+}
+
+impl<'m, 'v> VariantBuilderExt<'m, 'v> for ObjectFieldBuilder<'_, '_, '_> {
+    fn append_value(&mut self, value: impl Into<Variant<'m, 'v>>) {
+        self.builder.insert(self.key, value);
+    }
+
+    fn new_list(&mut self) -> ListBuilder<'_> {
+        self.builder.new_list(self.key)
+    }
+
+    fn new_object(&mut self) -> ObjectBuilder<'_> {
+        self.builder.new_object(self.key)
+    }
+}
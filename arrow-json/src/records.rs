@@ -0,0 +1,221 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Conversion between [`RecordBatch`] and slices of Rust values implementing
+//! [`Serialize`] and [`Deserialize`](serde::Deserialize)
+//!
+//! This provides a runtime alternative to hand-writing the glue between Rust structs and
+//! trees of [`StructBuilder`](arrow_array::builder::StructBuilder), using the existing
+//! [`serde`] support of the JSON [`Decoder`](crate::reader::Decoder) and [`Encoder`](crate::Encoder).
+//!
+//! ```
+//! # use arrow_json::records::{from_rows, to_rows};
+//! # use arrow_schema::DataType;
+//! # use serde::{Deserialize, Serialize};
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Point {
+//!     x: i64,
+//!     y: Option<f64>,
+//!     tags: Vec<String>,
+//! }
+//!
+//! let rows = vec![
+//!     Point { x: 1, y: Some(1.5), tags: vec!["a".to_string()] },
+//!     Point { x: 2, y: None, tags: vec![] },
+//! ];
+//!
+//! let batch = from_rows(&rows).unwrap();
+//! assert_eq!(batch.num_rows(), 2);
+//! let schema = batch.schema();
+//! assert_eq!(schema.field_with_name("x").unwrap().data_type(), &DataType::Int64);
+//!
+//! let back: Vec<Point> = to_rows(&batch).unwrap();
+//! assert_eq!(back, rows);
+//! ```
+
+use crate::reader::{infer_json_schema_from_iterator, ReaderBuilder};
+use crate::LineDelimitedWriter;
+use arrow_array::RecordBatch;
+use arrow_schema::{ArrowError, Schema, SchemaRef};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::sync::Arc;
+
+/// Converts `rows` into a [`RecordBatch`], inferring the schema from the serialized values
+///
+/// Each row must serialize to a map or struct, whose entries become the columns of the
+/// returned [`RecordBatch`]. The schema is inferred as by [`infer_json_schema_from_iterator`],
+/// and so all fields are nullable, integers are mapped to [`Int64`](arrow_schema::DataType::Int64)
+/// and the columns are sorted by name.
+/// Use [`from_rows_with_schema`] to control the schema of the result.
+///
+/// Returns an empty [`RecordBatch`] with no columns if `rows` is empty.
+pub fn from_rows<S: Serialize>(rows: &[S]) -> Result<RecordBatch, ArrowError> {
+    if rows.is_empty() {
+        return Ok(RecordBatch::new_empty(Arc::new(Schema::empty())));
+    }
+    let values = rows
+        .iter()
+        .map(|row| serde_json::to_value(row).map_err(|e| ArrowError::JsonError(e.to_string())));
+    let schema = infer_json_schema_from_iterator(values)?;
+    from_rows_with_schema(rows, Arc::new(schema))
+}
+
+/// Converts `rows` into a [`RecordBatch`] with the provided `schema`
+///
+/// See [`Decoder::serialize`](crate::reader::Decoder::serialize) for how values are mapped
+/// to the columns of `schema`.
+pub fn from_rows_with_schema<S: Serialize>(
+    rows: &[S],
+    schema: SchemaRef,
+) -> Result<RecordBatch, ArrowError> {
+    let mut decoder = ReaderBuilder::new(Arc::clone(&schema))
+        .with_batch_size(rows.len().max(1))
+        .build_decoder()?;
+    decoder.serialize(rows)?;
+    Ok(decoder
+        .flush()?
+        .unwrap_or_else(|| RecordBatch::new_empty(schema)))
+}
+
+/// Converts the rows of `batch` into values of type `T`
+///
+/// Each row is encoded as a JSON object, as by [`LineDelimitedWriter`], and then deserialized
+/// into `T`. Null values are omitted from the object, and so should be mapped to [`Option`]
+/// fields of `T`.
+pub fn to_rows<T: DeserializeOwned>(batch: &RecordBatch) -> Result<Vec<T>, ArrowError> {
+    let mut writer = LineDelimitedWriter::new(Vec::new());
+    writer.write(batch)?;
+    writer.finish()?;
+    let buf = writer.into_inner();
+
+    serde_json::Deserializer::from_slice(&buf)
+        .into_iter::<T>()
+        .map(|row| row.map_err(|e| ArrowError::JsonError(e.to_string())))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int32Type;
+    use arrow_schema::{DataType, Field};
+    use serde::Deserialize;
+    use std::collections::BTreeMap;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Inner {
+        name: String,
+        values: BTreeMap<String, i64>,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Outer {
+        id: i32,
+        score: Option<f64>,
+        inner: Option<Inner>,
+        list: Vec<Inner>,
+    }
+
+    fn test_rows() -> Vec<Outer> {
+        vec![
+            Outer {
+                id: 1,
+                score: Some(0.5),
+                inner: Some(Inner {
+                    name: "a".to_string(),
+                    values: [("x".to_string(), 1)].into_iter().collect(),
+                }),
+                list: vec![],
+            },
+            Outer {
+                id: 2,
+                score: None,
+                inner: None,
+                list: vec![Inner {
+                    name: "b".to_string(),
+                    values: BTreeMap::new(),
+                }],
+            },
+        ]
+    }
+
+    #[test]
+    fn test_roundtrip_inferred() {
+        let rows = test_rows();
+        let batch = from_rows(&rows).unwrap();
+        assert_eq!(batch.num_rows(), 2);
+
+        let schema = batch.schema();
+        let names: Vec<_> = schema.fields().iter().map(|f| f.name().as_str()).collect();
+        assert_eq!(names, &["id", "inner", "list", "score"]);
+        assert_eq!(schema.field(0).data_type(), &DataType::Int64);
+        assert!(matches!(schema.field(1).data_type(), DataType::Struct(_)));
+
+        let back: Vec<Outer> = to_rows(&batch).unwrap();
+        assert_eq!(back, rows);
+    }
+
+    #[test]
+    fn test_roundtrip_with_schema() {
+        let rows = test_rows();
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new("score", DataType::Float64, true),
+        ]));
+        let batch = from_rows_with_schema(&rows, schema.clone()).unwrap();
+        assert_eq!(batch.schema(), schema);
+        assert_eq!(
+            batch.column(0).as_primitive::<Int32Type>().values(),
+            &[1, 2]
+        );
+        assert_eq!(batch.column(1).null_count(), 1);
+
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Projected {
+            id: i32,
+            score: Option<f64>,
+        }
+        let back: Vec<Projected> = to_rows(&batch).unwrap();
+        assert_eq!(
+            back,
+            vec![
+                Projected {
+                    id: 1,
+                    score: Some(0.5)
+                },
+                Projected { id: 2, score: None }
+            ]
+        );
+
+        let err = to_rows::<Outer>(&batch).unwrap_err();
+        assert!(err.to_string().contains("missing field `list`"), "{err}");
+    }
+
+    #[test]
+    fn test_empty() {
+        let batch = from_rows::<Outer>(&[]).unwrap();
+        assert_eq!(batch.num_rows(), 0);
+        assert_eq!(batch.num_columns(), 0);
+
+        let schema = Arc::new(Schema::new(vec![Field::new("id", DataType::Int32, false)]));
+        let batch = from_rows_with_schema::<Outer>(&[], schema.clone()).unwrap();
+        assert_eq!(batch.schema(), schema);
+        assert!(to_rows::<Outer>(&batch).unwrap().is_empty());
+    }
+}
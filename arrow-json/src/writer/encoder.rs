@@ -21,13 +21,46 @@ use crate::StructMode;
 use arrow_array::cast::AsArray;
 use arrow_array::types::*;
 use arrow_array::*;
-use arrow_buffer::{ArrowNativeType, NullBuffer, OffsetBuffer, ScalarBuffer};
-use arrow_cast::display::{ArrayFormatter, FormatOptions};
-use arrow_schema::{ArrowError, DataType, FieldRef};
+use arrow_buffer::{ArrowNativeType, NullBuffer, OffsetBuffer, RunEndBuffer, ScalarBuffer};
+use arrow_cast::display::{ArrayFormatter, DurationFormat, FormatOptions, IntervalFormat};
+use arrow_schema::{ArrowError, DataType, FieldRef, TimeUnit};
 use half::f16;
 use lexical_core::FormattedSize;
 use serde::Serializer;
 
+/// How timestamp and date values are encoded, see [`EncoderOptions::with_timestamp_encoding`]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum TimestampEncoding {
+    /// Strings formatted as ISO 8601, e.g. `"2021-03-04T05:06:07.123Z"`, including
+    /// an offset only if the timestamp has a timezone
+    #[default]
+    Iso8601,
+    /// Numbers of milliseconds since the UNIX epoch, truncating any sub-millisecond
+    /// precision
+    EpochMillis,
+}
+
+/// How decimal values are encoded, see [`EncoderOptions::with_decimal_encoding`]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum DecimalEncoding {
+    /// Numbers, e.g. `1.23`, which consumers may parse with a loss of precision
+    #[default]
+    Number,
+    /// Strings, e.g. `"1.23"`
+    String,
+}
+
+/// How floating point `NaN` and infinite values are encoded, which have no
+/// representation as JSON numbers, see [`EncoderOptions::with_non_finite_encoding`]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum NonFiniteEncoding {
+    /// `null`
+    #[default]
+    Null,
+    /// The strings `"NaN"`, `"Infinity"` and `"-Infinity"`
+    String,
+}
+
 /// Configuration options for the JSON encoder.
 #[derive(Debug, Clone, Default)]
 pub struct EncoderOptions {
@@ -37,6 +70,16 @@ pub struct EncoderOptions {
     struct_mode: StructMode,
     /// An optional hook for customizing encoding behavior.
     encoder_factory: Option<Arc<dyn EncoderFactory>>,
+    /// The format for duration values.
+    duration_format: DurationFormat,
+    /// The format for interval values.
+    interval_format: IntervalFormat,
+    /// The encoding of timestamp and date values.
+    timestamp_encoding: TimestampEncoding,
+    /// The encoding of decimal values.
+    decimal_encoding: DecimalEncoding,
+    /// The encoding of non-finite floating point values.
+    non_finite_encoding: NonFiniteEncoding,
 }
 
 impl EncoderOptions {
@@ -58,6 +101,37 @@ impl EncoderOptions {
         self
     }
 
+    /// Set the format for duration values, defaults to [`DurationFormat::ISO8601`].
+    pub fn with_duration_format(mut self, duration_format: DurationFormat) -> Self {
+        self.duration_format = duration_format;
+        self
+    }
+
+    /// Set the format for interval values, defaults to [`IntervalFormat::Pretty`].
+    pub fn with_interval_format(mut self, interval_format: IntervalFormat) -> Self {
+        self.interval_format = interval_format;
+        self
+    }
+
+    /// Set the encoding of timestamp and date values, defaults to [`TimestampEncoding::Iso8601`].
+    pub fn with_timestamp_encoding(mut self, timestamp_encoding: TimestampEncoding) -> Self {
+        self.timestamp_encoding = timestamp_encoding;
+        self
+    }
+
+    /// Set the encoding of decimal values, defaults to [`DecimalEncoding::Number`].
+    pub fn with_decimal_encoding(mut self, decimal_encoding: DecimalEncoding) -> Self {
+        self.decimal_encoding = decimal_encoding;
+        self
+    }
+
+    /// Set the encoding of `NaN` and infinite floating point values, defaults to
+    /// [`NonFiniteEncoding::Null`].
+    pub fn with_non_finite_encoding(mut self, non_finite_encoding: NonFiniteEncoding) -> Self {
+        self.non_finite_encoding = non_finite_encoding;
+        self
+    }
+
     /// Get whether to include nulls in the output or elide them.
     pub fn explicit_nulls(&self) -> bool {
         self.explicit_nulls
@@ -72,6 +146,31 @@ impl EncoderOptions {
     pub fn encoder_factory(&self) -> Option<&Arc<dyn EncoderFactory>> {
         self.encoder_factory.as_ref()
     }
+
+    /// Get the format for duration values.
+    pub fn duration_format(&self) -> DurationFormat {
+        self.duration_format
+    }
+
+    /// Get the format for interval values.
+    pub fn interval_format(&self) -> IntervalFormat {
+        self.interval_format
+    }
+
+    /// Get the encoding of timestamp and date values.
+    pub fn timestamp_encoding(&self) -> TimestampEncoding {
+        self.timestamp_encoding
+    }
+
+    /// Get the encoding of decimal values.
+    pub fn decimal_encoding(&self) -> DecimalEncoding {
+        self.decimal_encoding
+    }
+
+    /// Get the encoding of `NaN` and infinite floating point values.
+    pub fn non_finite_encoding(&self) -> NonFiniteEncoding {
+        self.non_finite_encoding
+    }
 }
 
 /// A trait to create custom encoders for specific data types.
@@ -176,7 +275,7 @@ pub trait EncoderFactory: std::fmt::Debug + Send + Sync {
     ///
     /// Note that the type of the field may not match the type of the array: for dictionary arrays unless the top-level dictionary is handled this
     /// will be called again for the keys and values of the dictionary, at which point the field type will still be the outer dictionary type but the
-    /// array will have a different type.
+    /// array will have a different type. The same applies to the values of run end encoded arrays.
     /// For example, `field`` might have the type `Dictionary(i32, Utf8)` but `array` will be `Utf8`.
     fn make_default_encoder<'a>(
         &self,
@@ -252,6 +351,32 @@ pub fn make_encoder<'a>(
         }};
     }
 
+    macro_rules! float_helper {
+        ($t:ty) => {{
+            let array = array.as_primitive::<$t>();
+            let nulls = array.nulls().cloned();
+            let encoder = PrimitiveEncoder::new(array);
+            match options.non_finite_encoding() {
+                NonFiniteEncoding::Null => NullableEncoder::new(Box::new(encoder), nulls),
+                NonFiniteEncoding::String => {
+                    NullableEncoder::new(Box::new(NonFiniteStringEncoder(encoder)), nulls)
+                }
+            }
+        }};
+    }
+
+    macro_rules! epoch_millis_helper {
+        ($t:ty, $to_millis:expr) => {{
+            let array = array.as_primitive::<$t>();
+            let encoder = EpochMillisEncoder {
+                values: array.values().clone(),
+                to_millis: $to_millis,
+                buffer: [0; i64::FORMATTED_SIZE],
+            };
+            NullableEncoder::new(Box::new(encoder), array.nulls().cloned())
+        }};
+    }
+
     if let Some(factory) = options.encoder_factory() {
         if let Some(encoder) = factory.make_default_encoder(field, array, options)? {
             return Ok(encoder);
@@ -261,9 +386,9 @@ pub fn make_encoder<'a>(
     let nulls = array.nulls().cloned();
     let encoder = downcast_integer! {
         array.data_type() => (primitive_helper),
-        DataType::Float16 => primitive_helper!(Float16Type),
-        DataType::Float32 => primitive_helper!(Float32Type),
-        DataType::Float64 => primitive_helper!(Float64Type),
+        DataType::Float16 => float_helper!(Float16Type),
+        DataType::Float32 => float_helper!(Float32Type),
+        DataType::Float64 => float_helper!(Float64Type),
         DataType::Boolean => {
             let array = array.as_boolean();
             NullableEncoder::new(Box::new(BooleanEncoder(array)), array.nulls().cloned())
@@ -301,6 +426,13 @@ pub fn make_encoder<'a>(
             _ => unreachable!()
         }
 
+        DataType::RunEndEncoded(_, _) => downcast_run_array! {
+            array => {
+                NullableEncoder::new(Box::new(RunEndEncoder::try_new(field, array, options)?), array.logical_nulls())
+            },
+            _ => unreachable!()
+        }
+
         DataType::Map(_, _) => {
             let array = array.as_map();
             NullableEncoder::new(Box::new(MapEncoder::try_new(field, array, options)?), array.nulls().cloned())
@@ -339,10 +471,28 @@ pub fn make_encoder<'a>(
             let nulls = array.nulls().cloned();
             NullableEncoder::new(Box::new(encoder) as Box<dyn Encoder + 'a>, nulls)
         }
-        DataType::Decimal128(_, _) | DataType::Decimal256(_, _) => {
-            let options = FormatOptions::new().with_display_error(true);
-            let formatter = JsonArrayFormatter::new(ArrayFormatter::try_new(array, &options)?);
-            NullableEncoder::new(Box::new(RawArrayFormatter(formatter)) as Box<dyn Encoder + 'a>, nulls)
+        DataType::Decimal32(_, _)
+        | DataType::Decimal64(_, _)
+        | DataType::Decimal128(_, _)
+        | DataType::Decimal256(_, _) => {
+            let format_options = FormatOptions::new().with_display_error(true);
+            let formatter = JsonArrayFormatter::new(ArrayFormatter::try_new(array, &format_options)?);
+            match options.decimal_encoding() {
+                DecimalEncoding::Number => NullableEncoder::new(Box::new(RawArrayFormatter(formatter)) as Box<dyn Encoder + 'a>, nulls),
+                DecimalEncoding::String => NullableEncoder::new(Box::new(formatter) as Box<dyn Encoder + 'a>, nulls),
+            }
+        }
+        DataType::Timestamp(unit, _) if options.timestamp_encoding() == TimestampEncoding::EpochMillis => match unit {
+            TimeUnit::Second => epoch_millis_helper!(TimestampSecondType, |v: i64| v.saturating_mul(1000)),
+            TimeUnit::Millisecond => epoch_millis_helper!(TimestampMillisecondType, |v: i64| v),
+            TimeUnit::Microsecond => epoch_millis_helper!(TimestampMicrosecondType, |v: i64| v.div_euclid(1_000)),
+            TimeUnit::Nanosecond => epoch_millis_helper!(TimestampNanosecondType, |v: i64| v.div_euclid(1_000_000)),
+        }
+        DataType::Date32 if options.timestamp_encoding() == TimestampEncoding::EpochMillis => {
+            epoch_millis_helper!(Date32Type, |v: i32| v as i64 * 86_400_000)
+        }
+        DataType::Date64 if options.timestamp_encoding() == TimestampEncoding::EpochMillis => {
+            epoch_millis_helper!(Date64Type, |v: i64| v)
         }
         d => match d.is_temporal() {
             true => {
@@ -350,7 +500,10 @@ pub fn make_encoder<'a>(
                 // characters that would need to be escaped within a JSON string, e.g. `'"'`.
                 // If support for user-provided format specifications is added, this assumption
                 // may need to be revisited
-                let options = FormatOptions::new().with_display_error(true);
+                let options = FormatOptions::new()
+                    .with_display_error(true)
+                    .with_duration_format(options.duration_format())
+                    .with_interval_format(options.interval_format());
                 let formatter = ArrayFormatter::try_new(array, &options)?;
                 let formatter = JsonArrayFormatter::new(formatter);
                 NullableEncoder::new(Box::new(formatter) as Box<dyn Encoder + 'a>, nulls)
@@ -511,6 +664,37 @@ impl<N: PrimitiveEncode> Encoder for PrimitiveEncoder<N> {
     }
 }
 
+/// Encodes `NaN` and infinite values of a [`PrimitiveEncoder`] as strings,
+/// see [`NonFiniteEncoding::String`]
+struct NonFiniteStringEncoder<N: PrimitiveEncode>(PrimitiveEncoder<N>);
+
+impl<N: PrimitiveEncode + Into<f64>> Encoder for NonFiniteStringEncoder<N> {
+    fn encode(&mut self, idx: usize, out: &mut Vec<u8>) {
+        let value: f64 = self.0.values[idx].into();
+        match value {
+            v if v.is_nan() => out.extend_from_slice(b"\"NaN\""),
+            f64::INFINITY => out.extend_from_slice(b"\"Infinity\""),
+            f64::NEG_INFINITY => out.extend_from_slice(b"\"-Infinity\""),
+            _ => self.0.encode(idx, out),
+        }
+    }
+}
+
+/// Encodes temporal values as milliseconds since the UNIX epoch, see
+/// [`TimestampEncoding::EpochMillis`]
+struct EpochMillisEncoder<N: ArrowNativeType> {
+    values: ScalarBuffer<N>,
+    to_millis: fn(N) -> i64,
+    buffer: [u8; i64::FORMATTED_SIZE],
+}
+
+impl<N: ArrowNativeType> Encoder for EpochMillisEncoder<N> {
+    fn encode(&mut self, idx: usize, out: &mut Vec<u8>) {
+        let millis = (self.to_millis)(self.values[idx]);
+        out.extend_from_slice(lexical_core::write(millis, &mut self.buffer));
+    }
+}
+
 struct BooleanEncoder<'a>(&'a BooleanArray);
 
 impl Encoder for BooleanEncoder<'_> {
@@ -659,6 +843,33 @@ impl<K: ArrowDictionaryKeyType> Encoder for DictionaryEncoder<'_, K> {
     }
 }
 
+struct RunEndEncoder<'a, R: RunEndIndexType> {
+    run_ends: RunEndBuffer<R::Native>,
+    encoder: NullableEncoder<'a>,
+}
+
+impl<'a, R: RunEndIndexType> RunEndEncoder<'a, R> {
+    fn try_new(
+        field: &'a FieldRef,
+        array: &'a RunArray<R>,
+        options: &'a EncoderOptions,
+    ) -> Result<Self, ArrowError> {
+        let encoder = make_encoder(field, array.values().as_ref(), options)?;
+
+        Ok(Self {
+            run_ends: array.run_ends().clone(),
+            encoder,
+        })
+    }
+}
+
+impl<R: RunEndIndexType> Encoder for RunEndEncoder<'_, R> {
+    fn encode(&mut self, idx: usize, out: &mut Vec<u8>) {
+        self.encoder
+            .encode(self.run_ends.get_physical_index(idx), out)
+    }
+}
+
 /// A newtype wrapper around [`ArrayFormatter`] to keep our usage of it private and not implement `Encoder` for the public type
 struct JsonArrayFormatter<'a> {
     formatter: ArrayFormatter<'a>,
@@ -713,7 +924,7 @@ impl<'a> MapEncoder<'a> {
         let values = array.values();
         let keys = array.keys();
 
-        if !matches!(keys.data_type(), DataType::Utf8 | DataType::LargeUtf8) {
+        if !is_string_key(keys.data_type()) {
             return Err(ArrowError::JsonError(format!(
                 "Only UTF8 keys supported by JSON MapArray Writer: got {:?}",
                 keys.data_type()
@@ -745,6 +956,16 @@ impl<'a> MapEncoder<'a> {
     }
 }
 
+/// Returns true if `data_type` encodes to JSON strings, and can therefore be used for map keys
+fn is_string_key(data_type: &DataType) -> bool {
+    match data_type {
+        DataType::Utf8 | DataType::LargeUtf8 => true,
+        DataType::Dictionary(_, values) => is_string_key(values),
+        DataType::RunEndEncoded(_, values) => is_string_key(values.data_type()),
+        _ => false,
+    }
+}
+
 impl Encoder for MapEncoder<'_> {
     fn encode(&mut self, idx: usize, out: &mut Vec<u8>) {
         let end = self.offsets[idx + 1].as_usize();
@@ -105,14 +105,22 @@
 //! );
 //! ```
 mod encoder;
+mod registry;
 
 use std::{fmt::Debug, io::Write, sync::Arc};
 
 use crate::StructMode;
 use arrow_array::*;
+use arrow_cast::display::{DurationFormat, IntervalFormat};
 use arrow_schema::*;
 
-pub use encoder::{make_encoder, Encoder, EncoderFactory, EncoderOptions, NullableEncoder};
+pub use encoder::{
+    make_encoder, DecimalEncoding, Encoder, EncoderFactory, EncoderOptions, NonFiniteEncoding,
+    NullableEncoder, TimestampEncoding,
+};
+#[cfg(feature = "variant_experimental")]
+pub use registry::VariantEncoderFactory;
+pub use registry::{BinaryEncoderFactory, BinaryEncoding, EncoderRegistry, UuidEncoderFactory};
 
 /// This trait defines how to format a sequence of JSON objects to a
 /// byte stream.
@@ -279,6 +287,62 @@ impl WriterBuilder {
         self
     }
 
+    /// Set the format used to write duration values. Default is
+    /// [`DurationFormat::ISO8601`].
+    pub fn with_duration_format(mut self, format: DurationFormat) -> Self {
+        self.0 = self.0.with_duration_format(format);
+        self
+    }
+
+    /// Set the format used to write interval values. Default is
+    /// [`IntervalFormat::Pretty`].
+    pub fn with_interval_format(mut self, format: IntervalFormat) -> Self {
+        self.0 = self.0.with_interval_format(format);
+        self
+    }
+
+    /// Set how timestamp and date values are written, as ISO 8601 strings or as
+    /// milliseconds since the UNIX epoch. Default is [`TimestampEncoding::Iso8601`].
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{ArrayRef, RecordBatch, TimestampMicrosecondArray};
+    /// # use arrow_json::writer::{LineDelimited, TimestampEncoding};
+    /// # use arrow_json::WriterBuilder;
+    /// let ts = TimestampMicrosecondArray::from(vec![1_614_834_367_123_456]).with_timezone("+00:00");
+    /// let batch = RecordBatch::try_from_iter([("ts", Arc::new(ts) as ArrayRef)]).unwrap();
+    ///
+    /// let mut buf = Vec::new();
+    /// let mut writer = WriterBuilder::new().build::<_, LineDelimited>(&mut buf);
+    /// writer.write(&batch).unwrap();
+    /// assert_eq!(buf, b"{\"ts\":\"2021-03-04T05:06:07.123456Z\"}\n");
+    ///
+    /// let mut buf = Vec::new();
+    /// let mut writer = WriterBuilder::new()
+    ///     .with_timestamp_encoding(TimestampEncoding::EpochMillis)
+    ///     .build::<_, LineDelimited>(&mut buf);
+    /// writer.write(&batch).unwrap();
+    /// assert_eq!(buf, b"{\"ts\":1614834367123}\n");
+    /// ```
+    pub fn with_timestamp_encoding(mut self, encoding: TimestampEncoding) -> Self {
+        self.0 = self.0.with_timestamp_encoding(encoding);
+        self
+    }
+
+    /// Set whether decimal values are written as JSON numbers or strings. Default is
+    /// [`DecimalEncoding::Number`].
+    pub fn with_decimal_encoding(mut self, encoding: DecimalEncoding) -> Self {
+        self.0 = self.0.with_decimal_encoding(encoding);
+        self
+    }
+
+    /// Set whether `NaN` and infinite floating point values are written as `null` or
+    /// as strings. Default is [`NonFiniteEncoding::Null`].
+    pub fn with_non_finite_encoding(mut self, encoding: NonFiniteEncoding) -> Self {
+        self.0 = self.0.with_non_finite_encoding(encoding);
+        self
+    }
+
     /// Create a new `Writer` with specified `JsonFormat` and builder options.
     pub fn build<W, F>(self, writer: W) -> Writer<W, F>
     where
@@ -627,6 +691,80 @@ mod tests {
         );
     }
 
+    #[test]
+    fn write_nested_dictionary_and_run_end_encoded() {
+        // Dictionary nested within a struct
+        let dict = DictionaryArray::<Int8Type>::new(
+            Int8Array::from(vec![Some(0), None, None, Some(1)]),
+            Arc::new(StringArray::from(vec!["a", "b"])),
+        );
+        let inner = Field::new("d", dict.data_type().clone(), true);
+        let strct = StructArray::new(vec![inner].into(), vec![Arc::new(dict)], None);
+
+        // Sliced run end encoded array within a list
+        let ree = RunArray::<Int16Type>::try_new(
+            &Int16Array::from(vec![2, 3, 6]),
+            &Int32Array::from(vec![Some(1), None, Some(2)]),
+        )
+        .unwrap()
+        .slice(1, 5);
+        let item = Arc::new(Field::new_list_field(ree.data_type().clone(), true));
+        let list = ListArray::new(
+            item,
+            OffsetBuffer::from_lengths([2, 0, 1, 2]),
+            Arc::new(ree),
+            None,
+        );
+
+        // Map with dictionary encoded keys
+        let keys = DictionaryArray::<Int32Type>::new(
+            Int32Array::from(vec![0, 1, 1]),
+            Arc::new(StringArray::from(vec!["k1", "k2"])),
+        );
+        let entries = StructArray::new(
+            vec![
+                Field::new("keys", keys.data_type().clone(), false),
+                Field::new("values", DataType::Int32, true),
+            ]
+            .into(),
+            vec![Arc::new(keys), Arc::new(Int32Array::from(vec![1, 2, 3]))],
+            None,
+        );
+        let map = MapArray::new(
+            Arc::new(Field::new("entries", entries.data_type().clone(), false)),
+            OffsetBuffer::from_lengths([2, 0, 1, 0]),
+            entries,
+            None,
+            false,
+        );
+
+        let schema = Schema::new(vec![
+            Field::new("s", strct.data_type().clone(), true),
+            Field::new("l", list.data_type().clone(), true),
+            Field::new("m", map.data_type().clone(), true),
+        ]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema),
+            vec![Arc::new(strct), Arc::new(list), Arc::new(map)],
+        )
+        .unwrap();
+
+        let mut buf = Vec::new();
+        {
+            let mut writer = LineDelimitedWriter::new(&mut buf);
+            writer.write_batches(&[&batch]).unwrap();
+        }
+
+        assert_json_eq(
+            &buf,
+            r#"{"s":{"d":"a"},"l":[1,null],"m":{"k1":1,"k2":2}}
+{"s":{},"l":[],"m":{}}
+{"s":{},"l":[2],"m":{"k2":3}}
+{"s":{"d":"b"},"l":[2,2],"m":{}}
+"#,
+        );
+    }
+
     #[test]
     fn write_list_of_dictionary_large_values() {
         let dict_field = Arc::new(Field::new_dictionary(
@@ -1356,6 +1494,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_writer_duration_interval_format() {
+        let schema = Schema::new(vec![
+            Field::new("d", DataType::Duration(TimeUnit::Millisecond), true),
+            Field::new("i", DataType::Interval(IntervalUnit::YearMonth), true),
+        ]);
+        let d = DurationMillisecondArray::from(vec![Some(1500), None]);
+        let i = IntervalYearMonthArray::from(vec![Some(14), Some(-3)]);
+        let batch = RecordBatch::try_new(Arc::new(schema), vec![Arc::new(d), Arc::new(i)]).unwrap();
+
+        let mut buf = Vec::new();
+        {
+            let mut writer = WriterBuilder::new()
+                .with_duration_format(DurationFormat::Ticks)
+                .with_interval_format(IntervalFormat::SqlStandard)
+                .build::<_, LineDelimited>(&mut buf);
+            writer.write(&batch).unwrap();
+        }
+
+        assert_json_eq(
+            &buf,
+            r#"{"d":"1500","i":"1-2"}
+{"i":"-0-3"}
+"#,
+        );
+    }
+
     #[test]
     fn test_write_single_batch() {
         let test_file = "test/data/basic.json";
@@ -1953,6 +2118,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_decimal32_and_decimal64_encoder() {
+        let decimal32 = Decimal32Array::from_iter([Some(1234), None])
+            .with_precision_and_scale(6, 2)
+            .unwrap();
+        let decimal64 = Decimal64Array::from_iter([Some(-5678), Some(9012)])
+            .with_precision_and_scale(12, 3)
+            .unwrap();
+        let batch = RecordBatch::try_from_iter([
+            ("a", Arc::new(decimal32) as ArrayRef),
+            ("b", Arc::new(decimal64) as ArrayRef),
+        ])
+        .unwrap();
+
+        let mut buf = Vec::new();
+        {
+            let mut writer = LineDelimitedWriter::new(&mut buf);
+            writer.write_batches(&[&batch]).unwrap();
+        }
+
+        assert_json_eq(
+            &buf,
+            r#"{"a":12.34,"b":-5.678}
+{"b":9.012}
+"#,
+        );
+    }
+
     #[test]
     fn test_decimal256_encoder() {
         let array = Decimal256Array::from_iter_values([
@@ -2480,4 +2673,164 @@ mod tests {
 
         assert_eq!(json_value, expected);
     }
+
+    #[test]
+    fn test_writer_encodings() {
+        let schema = Schema::new(vec![
+            Field::new("ts", DataType::Timestamp(TimeUnit::Nanosecond, None), true),
+            Field::new("ts_s", DataType::Timestamp(TimeUnit::Second, None), true),
+            Field::new("date32", DataType::Date32, true),
+            Field::new("date64", DataType::Date64, true),
+            Field::new("decimal", DataType::Decimal128(10, 2), true),
+            Field::new("float", DataType::Float32, true),
+        ]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema),
+            vec![
+                Arc::new(TimestampNanosecondArray::from(vec![
+                    Some(1_500_999_999),
+                    Some(-1),
+                    None,
+                ])),
+                Arc::new(TimestampSecondArray::from(vec![Some(2), None, None])),
+                Arc::new(Date32Array::from(vec![Some(1), Some(-1), None])),
+                Arc::new(Date64Array::from(vec![Some(86_400_000), None, None])),
+                Arc::new(
+                    Decimal128Array::from(vec![Some(12345), Some(-5), None])
+                        .with_precision_and_scale(10, 2)
+                        .unwrap(),
+                ),
+                Arc::new(Float32Array::from(vec![
+                    Some(f32::NAN),
+                    Some(f32::NEG_INFINITY),
+                    Some(1.5),
+                ])),
+            ],
+        )
+        .unwrap();
+
+        let write = |builder: WriterBuilder| {
+            let mut buf = Vec::new();
+            let mut writer = builder.build::<_, LineDelimited>(&mut buf);
+            writer.write(&batch).unwrap();
+            String::from_utf8(buf).unwrap()
+        };
+
+        assert_eq!(
+            write(WriterBuilder::new()),
+            r#"{"ts":"1970-01-01T00:00:01.500999999","ts_s":"1970-01-01T00:00:02","date32":"1970-01-02","date64":"1970-01-02T00:00:00","decimal":123.45,"float":null}
+{"ts":"1969-12-31T23:59:59.999999999","date32":"1969-12-31","decimal":-0.05,"float":null}
+{"float":1.5}
+"#
+        );
+
+        let builder = WriterBuilder::new()
+            .with_timestamp_encoding(TimestampEncoding::EpochMillis)
+            .with_decimal_encoding(DecimalEncoding::String)
+            .with_non_finite_encoding(NonFiniteEncoding::String);
+        assert_eq!(
+            write(builder),
+            r#"{"ts":1500,"ts_s":2000,"date32":86400000,"date64":86400000,"decimal":"123.45","float":"NaN"}
+{"ts":-1,"date32":-86400000,"decimal":"-0.05","float":"-Infinity"}
+{"float":1.5}
+"#
+        );
+    }
+
+    #[test]
+    fn test_encoder_registry() {
+        let uuid =
+            Field::new("id", DataType::FixedSizeBinary(16), true).with_metadata(HashMap::from([(
+                extension::EXTENSION_TYPE_NAME_KEY.to_string(),
+                "arrow.uuid".to_string(),
+            )]));
+        let ids = FixedSizeBinaryArray::try_from_sparse_iter_with_size(
+            [Some([0xab_u8; 16]), None].into_iter(),
+            16,
+        )
+        .unwrap();
+
+        let nested = Field::new("data", DataType::BinaryView, true);
+        let nested_values = BinaryViewArray::from(vec![Some(b"ab".as_slice()), None]);
+        let inner = StructArray::new(
+            Fields::from(vec![nested.clone()]),
+            vec![Arc::new(nested_values)],
+            None,
+        );
+
+        let dictionary = DictionaryArray::<Int32Type>::new(
+            Int32Array::from(vec![1, 0]),
+            Arc::new(BinaryArray::from_vec(vec![b"x", b"yz"])),
+        );
+
+        let schema = Schema::new(vec![
+            uuid,
+            Field::new("inner", inner.data_type().clone(), false),
+            Field::new("dict", dictionary.data_type().clone(), false),
+        ]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema),
+            vec![Arc::new(ids), Arc::new(inner), Arc::new(dictionary)],
+        )
+        .unwrap();
+
+        let mut registry = EncoderRegistry::new();
+        registry.register_extension_type("arrow.uuid", Arc::new(UuidEncoderFactory));
+        registry.register_field(
+            "dict",
+            Arc::new(BinaryEncoderFactory::new(BinaryEncoding::Base64)),
+        );
+        assert!(!registry.register_field("data", Arc::new(BinaryEncoderFactory::default())));
+
+        let mut buf = Vec::new();
+        let mut writer = WriterBuilder::new()
+            .with_encoder_factory(Arc::new(registry))
+            .build::<_, JsonArray>(&mut buf);
+        writer.write(&batch).unwrap();
+        writer.finish().unwrap();
+
+        let json_value: Value = serde_json::from_slice(&buf).unwrap();
+        let expected = json!([
+            {"id": "abababab-abab-abab-abab-abababababab", "inner": {"data": "6162"}, "dict": "eXo="},
+            {"inner": {}, "dict": "eA=="},
+        ]);
+        assert_eq!(json_value, expected);
+
+        // Without the registry binary view is not supported
+        let mut writer = WriterBuilder::new().build::<_, JsonArray>(Vec::new());
+        assert!(writer.write(&batch).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "variant_experimental")]
+    fn test_variant_encoder_factory() {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, true)]));
+        let data = r#"{"a": 1, "b": {"c": [1, "x", null]}}
+{"a": 2}
+"#;
+        let batch = ReaderBuilder::new(schema)
+            .with_unknown_fields_as_variant("rest")
+            .build(data.as_bytes())
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+
+        let mut registry = EncoderRegistry::new();
+        registry.register(Arc::new(VariantEncoderFactory));
+
+        let mut buf = Vec::new();
+        let mut writer = WriterBuilder::new()
+            .with_encoder_factory(Arc::new(registry))
+            .build::<_, JsonArray>(&mut buf);
+        writer.write(&batch).unwrap();
+        writer.finish().unwrap();
+
+        let json_value: Value = serde_json::from_slice(&buf).unwrap();
+        let expected = json!([
+            {"a": 1, "rest": {"b": {"c": [1, "x", null]}}},
+            {"a": 2},
+        ]);
+        assert_eq!(json_value, expected);
+    }
 }
@@ -0,0 +1,361 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::io::Write;
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::{Array, ArrayAccessor};
+use arrow_cast::base64::{Engine, BASE64_STANDARD};
+use arrow_schema::extension::{ExtensionType, Uuid};
+use arrow_schema::{ArrowError, DataType, FieldRef};
+
+use crate::writer::encoder::{Encoder, EncoderFactory, EncoderOptions, NullableEncoder};
+
+/// The name of the canonical UUID extension type
+const UUID_EXTENSION_NAME: &str = <Uuid as ExtensionType>::NAME;
+
+/// An [`EncoderFactory`] that dispatches to the [`EncoderFactory`] registered for the
+/// name of a field, or the name of its extension type
+///
+/// For each field the factories are tried in order: the factory registered for the
+/// field's name, then the factory registered for its extension type, then each factory
+/// registered with [`Self::register`]. Fields for which none provides an encoder use
+/// the default encoders.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, BinaryArray, FixedSizeBinaryArray, RecordBatch};
+/// # use arrow_json::writer::{BinaryEncoderFactory, BinaryEncoding, EncoderRegistry, UuidEncoderFactory};
+/// # use arrow_json::WriterBuilder;
+/// # use arrow_json::writer::LineDelimited;
+/// # use arrow_schema::extension::EXTENSION_TYPE_NAME_KEY;
+/// # use arrow_schema::{DataType, Field, Schema};
+/// # use std::collections::HashMap;
+/// let id = Field::new("id", DataType::FixedSizeBinary(16), false).with_metadata(HashMap::from([(
+///     EXTENSION_TYPE_NAME_KEY.to_string(),
+///     "arrow.uuid".to_string(),
+/// )]));
+/// let schema = Schema::new(vec![id, Field::new("data", DataType::Binary, false)]);
+/// let ids = FixedSizeBinaryArray::try_from_iter([[0x12_u8; 16]].into_iter()).unwrap();
+/// let data = BinaryArray::from_vec(vec![b"hello"]);
+/// let batch = RecordBatch::try_new(
+///     Arc::new(schema),
+///     vec![Arc::new(ids) as ArrayRef, Arc::new(data) as ArrayRef],
+/// )
+/// .unwrap();
+///
+/// let mut registry = EncoderRegistry::new();
+/// registry.register_extension_type("arrow.uuid", Arc::new(UuidEncoderFactory));
+/// registry.register_field("data", Arc::new(BinaryEncoderFactory::new(BinaryEncoding::Base64)));
+///
+/// let mut buf = Vec::new();
+/// let mut writer = WriterBuilder::new()
+///     .with_encoder_factory(Arc::new(registry))
+///     .build::<_, LineDelimited>(&mut buf);
+/// writer.write(&batch).unwrap();
+/// writer.finish().unwrap();
+/// assert_eq!(
+///     String::from_utf8(buf).unwrap(),
+///     "{\"id\":\"12121212-1212-1212-1212-121212121212\",\"data\":\"aGVsbG8=\"}\n"
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct EncoderRegistry {
+    fields: HashMap<String, Arc<dyn EncoderFactory>>,
+    extension_types: HashMap<String, Arc<dyn EncoderFactory>>,
+    factories: Vec<Arc<dyn EncoderFactory>>,
+}
+
+impl EncoderRegistry {
+    /// Creates an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `factory` for fields named `name`, at any level of nesting, replacing
+    /// any factory previously registered for the same name
+    ///
+    /// Returns `true` if a factory was already registered for this name
+    pub fn register_field(
+        &mut self,
+        name: impl Into<String>,
+        factory: Arc<dyn EncoderFactory>,
+    ) -> bool {
+        self.fields.insert(name.into(), factory).is_some()
+    }
+
+    /// Registers `factory` for fields of the extension type `name`, replacing any
+    /// factory previously registered for the same extension type
+    ///
+    /// Returns `true` if a factory was already registered for this extension type
+    pub fn register_extension_type(
+        &mut self,
+        name: impl Into<String>,
+        factory: Arc<dyn EncoderFactory>,
+    ) -> bool {
+        self.extension_types.insert(name.into(), factory).is_some()
+    }
+
+    /// Registers `factory` for all fields, after the factories previously registered
+    pub fn register(&mut self, factory: Arc<dyn EncoderFactory>) {
+        self.factories.push(factory);
+    }
+}
+
+impl EncoderFactory for EncoderRegistry {
+    fn make_default_encoder<'a>(
+        &self,
+        field: &'a FieldRef,
+        array: &'a dyn Array,
+        options: &'a EncoderOptions,
+    ) -> Result<Option<NullableEncoder<'a>>, ArrowError> {
+        let by_field = self.fields.get(field.name());
+        let by_extension = field
+            .extension_type_name()
+            .and_then(|name| self.extension_types.get(name));
+
+        for factory in by_field
+            .into_iter()
+            .chain(by_extension)
+            .chain(&self.factories)
+        {
+            if let Some(encoder) = factory.make_default_encoder(field, array, options)? {
+                return Ok(Some(encoder));
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// The encoding of binary values as JSON strings, see [`BinaryEncoderFactory`]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum BinaryEncoding {
+    /// Lowercase hexadecimal, as for the default encoders
+    #[default]
+    Hex,
+    /// Standard base64 with padding
+    Base64,
+}
+
+/// An [`EncoderFactory`] encoding binary, binary view and fixed size binary arrays as
+/// JSON strings with a [`BinaryEncoding`]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct BinaryEncoderFactory {
+    encoding: BinaryEncoding,
+}
+
+impl BinaryEncoderFactory {
+    /// Create a new [`BinaryEncoderFactory`] using `encoding`
+    pub fn new(encoding: BinaryEncoding) -> Self {
+        Self { encoding }
+    }
+}
+
+impl EncoderFactory for BinaryEncoderFactory {
+    fn make_default_encoder<'a>(
+        &self,
+        _field: &'a FieldRef,
+        array: &'a dyn Array,
+        _options: &'a EncoderOptions,
+    ) -> Result<Option<NullableEncoder<'a>>, ArrowError> {
+        let encoding = self.encoding;
+        let encoder: Box<dyn Encoder + 'a> = match array.data_type() {
+            DataType::Binary => Box::new(BinaryStringEncoder(array.as_binary::<i32>(), encoding)),
+            DataType::LargeBinary => {
+                Box::new(BinaryStringEncoder(array.as_binary::<i64>(), encoding))
+            }
+            DataType::BinaryView => Box::new(BinaryStringEncoder(array.as_binary_view(), encoding)),
+            DataType::FixedSizeBinary(_) => {
+                Box::new(BinaryStringEncoder(array.as_fixed_size_binary(), encoding))
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(NullableEncoder::new(encoder, array.nulls().cloned())))
+    }
+}
+
+struct BinaryStringEncoder<B>(B, BinaryEncoding);
+
+impl<'a, B> Encoder for BinaryStringEncoder<B>
+where
+    B: ArrayAccessor<Item = &'a [u8]>,
+{
+    fn encode(&mut self, idx: usize, out: &mut Vec<u8>) {
+        let value = self.0.value(idx);
+        out.push(b'"');
+        match self.1 {
+            BinaryEncoding::Hex => {
+                for byte in value {
+                    // this write is infallible
+                    write!(out, "{byte:02x}").unwrap();
+                }
+            }
+            // Base64 does not contain any characters that need escaping
+            BinaryEncoding::Base64 => {
+                out.extend_from_slice(BASE64_STANDARD.encode(value).as_bytes())
+            }
+        }
+        out.push(b'"');
+    }
+}
+
+/// An [`EncoderFactory`] encoding the values of the canonical `arrow.uuid` extension
+/// type as hyphenated UUID strings, e.g. `"67e55044-10b1-426f-9247-bb680e5fe0c8"`
+#[derive(Debug, Copy, Clone, Default)]
+pub struct UuidEncoderFactory;
+
+impl EncoderFactory for UuidEncoderFactory {
+    fn make_default_encoder<'a>(
+        &self,
+        field: &'a FieldRef,
+        array: &'a dyn Array,
+        _options: &'a EncoderOptions,
+    ) -> Result<Option<NullableEncoder<'a>>, ArrowError> {
+        if field.extension_type_name() != Some(UUID_EXTENSION_NAME) {
+            return Ok(None);
+        }
+        match array.data_type() {
+            DataType::FixedSizeBinary(16) => {
+                let array = array.as_fixed_size_binary();
+                let encoder = Box::new(UuidEncoder(array));
+                Ok(Some(NullableEncoder::new(encoder, array.nulls().cloned())))
+            }
+            // Dictionaries are called again with their values
+            DataType::Dictionary(_, _) => Ok(None),
+            d => Err(ArrowError::JsonError(format!(
+                "Expected FixedSizeBinary(16) for {UUID_EXTENSION_NAME}, got {d}"
+            ))),
+        }
+    }
+}
+
+struct UuidEncoder<'a>(&'a arrow_array::FixedSizeBinaryArray);
+
+impl Encoder for UuidEncoder<'_> {
+    fn encode(&mut self, idx: usize, out: &mut Vec<u8>) {
+        let value = self.0.value(idx);
+        out.push(b'"');
+        for (i, byte) in value.iter().enumerate() {
+            if matches!(i, 4 | 6 | 8 | 10) {
+                out.push(b'-');
+            }
+            // this write is infallible
+            write!(out, "{byte:02x}").unwrap();
+        }
+        out.push(b'"');
+    }
+}
+
+#[cfg(feature = "variant_experimental")]
+pub use variant::VariantEncoderFactory;
+
+#[cfg(feature = "variant_experimental")]
+mod variant {
+    use arrow_array::cast::AsArray;
+    use arrow_array::{Array, BinaryArray, BinaryViewArray, LargeBinaryArray};
+    use arrow_schema::{ArrowError, DataType, FieldRef};
+    use parquet_variant::Variant;
+    use parquet_variant_json::variant_to_json;
+
+    use crate::writer::encoder::{Encoder, EncoderFactory, EncoderOptions, NullableEncoder};
+    use crate::VARIANT_EXTENSION_NAME;
+
+    /// An [`EncoderFactory`] encoding the values of the `arrow.parquet.variant` extension
+    /// type, structs of binary `metadata` and `value` fields, as inline JSON
+    #[derive(Debug, Copy, Clone, Default)]
+    pub struct VariantEncoderFactory;
+
+    impl EncoderFactory for VariantEncoderFactory {
+        fn make_default_encoder<'a>(
+            &self,
+            field: &'a FieldRef,
+            array: &'a dyn Array,
+            _options: &'a EncoderOptions,
+        ) -> Result<Option<NullableEncoder<'a>>, ArrowError> {
+            if field.extension_type_name() != Some(VARIANT_EXTENSION_NAME) {
+                return Ok(None);
+            }
+            let Some(array) = array.as_struct_opt() else {
+                return Err(ArrowError::JsonError(format!(
+                    "Expected Struct for {VARIANT_EXTENSION_NAME}, got {}",
+                    array.data_type()
+                )));
+            };
+            let metadata = binary_column(array.column_by_name("metadata").map(|c| c.as_ref()))?;
+            let value = binary_column(array.column_by_name("value").map(|c| c.as_ref()))?;
+
+            // Render each row upfront, as encoding cannot fail
+            let mut values = Vec::new();
+            let mut offsets = Vec::with_capacity(array.len() + 1);
+            offsets.push(0);
+            for idx in 0..array.len() {
+                if array.is_valid(idx) {
+                    let variant = Variant::try_new(metadata(idx), value(idx))?;
+                    variant_to_json(&mut values, &variant)?;
+                }
+                offsets.push(values.len());
+            }
+
+            let encoder = Box::new(VariantEncoder { values, offsets });
+            Ok(Some(NullableEncoder::new(encoder, array.nulls().cloned())))
+        }
+    }
+
+    /// Returns the value at an index of a binary column
+    type BinaryAccessor<'a> = Box<dyn Fn(usize) -> &'a [u8] + 'a>;
+
+    /// Returns a [`BinaryAccessor`] of a binary `column` of a variant struct
+    fn binary_column(column: Option<&dyn Array>) -> Result<BinaryAccessor<'_>, ArrowError> {
+        let Some(column) = column else {
+            return Err(ArrowError::JsonError(format!(
+                "Expected metadata and value fields for {VARIANT_EXTENSION_NAME}"
+            )));
+        };
+        Ok(match column.data_type() {
+            DataType::BinaryView => {
+                let array: &BinaryViewArray = column.as_binary_view();
+                Box::new(move |idx| array.value(idx))
+            }
+            DataType::Binary => {
+                let array: &BinaryArray = column.as_binary();
+                Box::new(move |idx| array.value(idx))
+            }
+            DataType::LargeBinary => {
+                let array: &LargeBinaryArray = column.as_binary();
+                Box::new(move |idx| array.value(idx))
+            }
+            d => {
+                return Err(ArrowError::JsonError(format!(
+                    "Expected binary fields for {VARIANT_EXTENSION_NAME}, got {d}"
+                )))
+            }
+        })
+    }
+
+    struct VariantEncoder {
+        values: Vec<u8>,
+        offsets: Vec<usize>,
+    }
+
+    impl Encoder for VariantEncoder {
+        fn encode(&mut self, idx: usize, out: &mut Vec<u8>) {
+            out.extend_from_slice(&self.values[self.offsets[idx]..self.offsets[idx + 1]]);
+        }
+    }
+}
@@ -26,8 +26,9 @@
 use arrow_array::cast::AsArray;
 use arrow_array::types::{ByteArrayType, ByteViewType};
 use arrow_array::{
-    downcast_primitive_array, AnyDictionaryArray, Array, ArrowNativeTypeOp, BooleanArray, Datum,
-    FixedSizeBinaryArray, GenericByteArray, GenericByteViewArray,
+    downcast_primitive_array, downcast_run_array, AnyDictionaryArray, Array, ArrayRef,
+    ArrowNativeTypeOp, BooleanArray, Datum, FixedSizeBinaryArray, GenericByteArray,
+    GenericByteViewArray,
 };
 use arrow_buffer::bit_util::ceil;
 use arrow_buffer::{BooleanBuffer, MutableBuffer, NullBuffer};
@@ -35,6 +36,7 @@ use arrow_schema::ArrowError;
 use arrow_select::take::take;
 use std::cmp::Ordering;
 use std::ops::Not;
+use std::sync::Arc;
 
 #[derive(Debug, Copy, Clone)]
 enum Op {
@@ -201,6 +203,202 @@ pub fn not_distinct(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<BooleanArray, Ar
     compare_op(Op::NotDistinct, lhs, rhs)
 }
 
+/// Options that control the handling of nulls by the comparison kernels
+///
+/// By default comparisons follow SQL three-valued logic, where comparing a null
+/// on either side yields a null. See [`eq_with_options`] and friends
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct CompareOptions {
+    /// If true, nulls compare equal to other nulls and not equal to any non-null
+    /// value, as for SQL `IS NOT DISTINCT FROM`, and the result never contains nulls
+    pub nulls_equal: bool,
+    /// If `nulls_equal` is true, whether nulls order before non-null values in the
+    /// ordering comparisons `<`, `<=`, `>` and `>=`. Ignored otherwise
+    pub nulls_first: bool,
+}
+
+impl CompareOptions {
+    /// Set [`Self::nulls_equal`]
+    pub fn with_nulls_equal(mut self, nulls_equal: bool) -> Self {
+        self.nulls_equal = nulls_equal;
+        self
+    }
+
+    /// Set [`Self::nulls_first`]
+    pub fn with_nulls_first(mut self, nulls_first: bool) -> Self {
+        self.nulls_first = nulls_first;
+        self
+    }
+}
+
+/// Perform `left == right` operation on two [`Datum`] with the provided [`CompareOptions`]
+///
+/// With [`CompareOptions::nulls_equal`] this is equivalent to [`not_distinct`], otherwise
+/// to [`eq`]
+pub fn eq_with_options(
+    lhs: &dyn Datum,
+    rhs: &dyn Datum,
+    options: CompareOptions,
+) -> Result<BooleanArray, ArrowError> {
+    compare_op_with_options(Op::Equal, lhs, rhs, options)
+}
+
+/// Perform `left != right` operation on two [`Datum`] with the provided [`CompareOptions`]
+///
+/// With [`CompareOptions::nulls_equal`] this is equivalent to [`distinct`], otherwise
+/// to [`neq`]
+pub fn neq_with_options(
+    lhs: &dyn Datum,
+    rhs: &dyn Datum,
+    options: CompareOptions,
+) -> Result<BooleanArray, ArrowError> {
+    compare_op_with_options(Op::NotEqual, lhs, rhs, options)
+}
+
+/// Perform `left < right` operation on two [`Datum`] with the provided [`CompareOptions`]
+///
+/// With [`CompareOptions::nulls_equal`], nulls are ordered according to
+/// [`CompareOptions::nulls_first`] and the result never contains nulls, otherwise
+/// this is equivalent to [`lt`]
+pub fn lt_with_options(
+    lhs: &dyn Datum,
+    rhs: &dyn Datum,
+    options: CompareOptions,
+) -> Result<BooleanArray, ArrowError> {
+    compare_op_with_options(Op::Less, lhs, rhs, options)
+}
+
+/// Perform `left <= right` operation on two [`Datum`] with the provided [`CompareOptions`]
+///
+/// With [`CompareOptions::nulls_equal`], nulls are ordered according to
+/// [`CompareOptions::nulls_first`] and the result never contains nulls, otherwise
+/// this is equivalent to [`lt_eq`]
+pub fn lt_eq_with_options(
+    lhs: &dyn Datum,
+    rhs: &dyn Datum,
+    options: CompareOptions,
+) -> Result<BooleanArray, ArrowError> {
+    compare_op_with_options(Op::LessEqual, lhs, rhs, options)
+}
+
+/// Perform `left > right` operation on two [`Datum`] with the provided [`CompareOptions`]
+///
+/// With [`CompareOptions::nulls_equal`], nulls are ordered according to
+/// [`CompareOptions::nulls_first`] and the result never contains nulls, otherwise
+/// this is equivalent to [`gt`]
+pub fn gt_with_options(
+    lhs: &dyn Datum,
+    rhs: &dyn Datum,
+    options: CompareOptions,
+) -> Result<BooleanArray, ArrowError> {
+    compare_op_with_options(Op::Greater, lhs, rhs, options)
+}
+
+/// Perform `left >= right` operation on two [`Datum`] with the provided [`CompareOptions`]
+///
+/// With [`CompareOptions::nulls_equal`], nulls are ordered according to
+/// [`CompareOptions::nulls_first`] and the result never contains nulls, otherwise
+/// this is equivalent to [`gt_eq`]
+pub fn gt_eq_with_options(
+    lhs: &dyn Datum,
+    rhs: &dyn Datum,
+    options: CompareOptions,
+) -> Result<BooleanArray, ArrowError> {
+    compare_op_with_options(Op::GreaterEqual, lhs, rhs, options)
+}
+
+/// Applies the comparison kernel `op`, such as [`eq`] or [`lt`], to the values of the
+/// runs of the run-end encoded `lhs` and the scalar `rhs`
+///
+/// Unlike calling `op` on `lhs` directly, this does not materialize the logical values of
+/// `lhs` and returns a [`RunArray`](arrow_array::RunArray) of booleans with the same runs
+///
+/// ```
+/// # use arrow_array::{BooleanArray, Int32Array, RunArray};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_ord::cmp::{compare_run_end_encoded, gt};
+/// let run_ends = Int32Array::from(vec![3, 5]);
+/// let values = Int32Array::from(vec![1, 5]);
+/// let array = RunArray::try_new(&run_ends, &values).unwrap();
+///
+/// let result = compare_run_end_encoded(&array, &Int32Array::new_scalar(2), gt).unwrap();
+/// let result = result.as_run::<Int32Type>();
+/// assert_eq!(result.run_ends().values(), &[3, 5]);
+/// assert_eq!(result.values().as_boolean(), &BooleanArray::from(vec![false, true]));
+/// ```
+pub fn compare_run_end_encoded(
+    lhs: &dyn Array,
+    rhs: &dyn Datum,
+    op: impl FnOnce(&dyn Datum, &dyn Datum) -> Result<BooleanArray, ArrowError>,
+) -> Result<ArrayRef, ArrowError> {
+    let (r, r_scalar) = rhs.get();
+    if !r_scalar {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Expected scalar to compare with run-end encoded array, got array of length {}",
+            r.len()
+        )));
+    }
+    downcast_run_array!(
+        lhs => {
+            let values = op(&lhs.sliced_values(), rhs)?;
+            Ok(Arc::new(lhs.with_sliced_values(&values)?))
+        },
+        t => Err(ArrowError::InvalidArgumentError(format!(
+            "Expected run-end encoded array, got {t}"
+        )))
+    )
+}
+
+/// Perform `op` on the provided `Datum` with the provided [`CompareOptions`]
+fn compare_op_with_options(
+    op: Op,
+    lhs: &dyn Datum,
+    rhs: &dyn Datum,
+    options: CompareOptions,
+) -> Result<BooleanArray, ArrowError> {
+    if !options.nulls_equal {
+        return compare_op(op, lhs, rhs);
+    }
+
+    let inclusive = match op {
+        Op::Equal | Op::NotDistinct => return compare_op(Op::NotDistinct, lhs, rhs),
+        Op::NotEqual | Op::Distinct => return compare_op(Op::Distinct, lhs, rhs),
+        Op::Less | Op::Greater => false,
+        Op::LessEqual | Op::GreaterEqual => true,
+    };
+
+    // The values are only well-defined where both sides are valid
+    let values = compare_op(op, lhs, rhs)?.into_parts().0;
+    let len = values.len();
+    let l = datum_validity(lhs, len);
+    let r = datum_validity(rhs, len);
+
+    let mut result = &(&l & &r) & &values;
+    // Whether the left side orders first when exactly one side is null
+    let left_first = matches!(op, Op::Less | Op::LessEqual);
+    let null_first = match left_first == options.nulls_first {
+        true => &!&l & &r,
+        false => &l & &!&r,
+    };
+    result = &result | &null_first;
+    if inclusive {
+        result = &result | &!&(&l | &r);
+    }
+    Ok(BooleanArray::new(result, None))
+}
+
+/// Returns the logical validity of `datum` broadcast to `len`
+fn datum_validity(datum: &dyn Datum, len: usize) -> BooleanBuffer {
+    let (array, is_scalar) = datum.get();
+    match (array.logical_nulls(), is_scalar) {
+        (None, _) => BooleanBuffer::new_set(len),
+        (Some(n), true) if n.is_null(0) => BooleanBuffer::new_unset(len),
+        (Some(_), true) => BooleanBuffer::new_set(len),
+        (Some(n), false) => n.into_inner(),
+    }
+}
+
 /// Perform `op` on the provided `Datum`
 #[inline(never)]
 fn compare_op(op: Op, lhs: &dyn Datum, rhs: &dyn Datum) -> Result<BooleanArray, ArrowError> {
@@ -663,6 +861,41 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_compare_run_end_encoded() {
+        let run_ends = Int32Array::from(vec![2, 4, 7]);
+        let values = StringArray::from(vec![Some("a"), None, Some("c")]);
+        let array = arrow_array::RunArray::try_new(&run_ends, &values).unwrap();
+        let scalar = StringArray::new_scalar("c");
+
+        let r = compare_run_end_encoded(&array.slice(3, 3), &scalar, eq).unwrap();
+        let r = r.as_run::<arrow_array::types::Int32Type>();
+        assert_eq!(r.len(), 3);
+        assert_eq!(r.run_ends().values(), &[1, 3]);
+        let expected = BooleanArray::from(vec![None, Some(true)]);
+        assert_eq!(r.values().as_boolean(), &expected);
+
+        let r = compare_run_end_encoded(&array, &scalar, not_distinct).unwrap();
+        let expected = BooleanArray::from(vec![false, false, true]);
+        assert_eq!(
+            r.as_run::<arrow_array::types::Int32Type>()
+                .values()
+                .as_boolean(),
+            &expected
+        );
+
+        let err = compare_run_end_encoded(&array, &values, eq).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Expected scalar to compare with run-end encoded array, got array of length 3"
+        );
+        let err = compare_run_end_encoded(&values, &scalar, eq).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Expected run-end encoded array, got Utf8"
+        );
+    }
+
     #[test]
     fn test_null_dict() {
         let a = DictionaryArray::new(Int32Array::new_null(10), Arc::new(Int32Array::new_null(0)));
@@ -785,6 +1018,74 @@ mod tests {
         assert_eq!(not_distinct(&b, &a).unwrap(), expected);
     }
 
+    #[test]
+    fn test_compare_with_options() {
+        // [1, NULL, 3, NULL, 2]
+        let a = Int32Array::from(vec![Some(1), None, Some(3), None, Some(2)]);
+        // [1, 2, NULL, NULL, 3]
+        let b = Int32Array::from(vec![Some(1), Some(2), None, None, Some(3)]);
+
+        let options = CompareOptions::default();
+        assert_eq!(
+            eq_with_options(&a, &b, options).unwrap(),
+            eq(&a, &b).unwrap()
+        );
+        assert_eq!(
+            lt_with_options(&a, &b, options).unwrap(),
+            lt(&a, &b).unwrap()
+        );
+
+        let options = CompareOptions::default().with_nulls_equal(true);
+        assert_eq!(
+            eq_with_options(&a, &b, options).unwrap(),
+            not_distinct(&a, &b).unwrap()
+        );
+        assert_eq!(
+            neq_with_options(&a, &b, options).unwrap(),
+            distinct(&a, &b).unwrap()
+        );
+
+        // Nulls last
+        let expected = BooleanArray::from(vec![false, false, true, false, true]);
+        assert_eq!(lt_with_options(&a, &b, options).unwrap(), expected);
+        let expected = BooleanArray::from(vec![true, false, true, true, true]);
+        assert_eq!(lt_eq_with_options(&a, &b, options).unwrap(), expected);
+        let expected = BooleanArray::from(vec![false, true, false, false, false]);
+        assert_eq!(gt_with_options(&a, &b, options).unwrap(), expected);
+        let expected = BooleanArray::from(vec![true, true, false, true, false]);
+        assert_eq!(gt_eq_with_options(&a, &b, options).unwrap(), expected);
+
+        // Nulls first
+        let options = options.with_nulls_first(true);
+        let expected = BooleanArray::from(vec![false, true, false, false, true]);
+        assert_eq!(lt_with_options(&a, &b, options).unwrap(), expected);
+        let expected = BooleanArray::from(vec![true, true, false, true, true]);
+        assert_eq!(lt_eq_with_options(&a, &b, options).unwrap(), expected);
+        let expected = BooleanArray::from(vec![false, false, true, false, false]);
+        assert_eq!(gt_with_options(&a, &b, options).unwrap(), expected);
+        let expected = BooleanArray::from(vec![true, false, true, true, false]);
+        assert_eq!(gt_eq_with_options(&a, &b, options).unwrap(), expected);
+
+        // Scalars
+        let null = Int32Array::new_null(1);
+        let null = Scalar::new(&null);
+        let expected = BooleanArray::from(vec![true, false, true, false, true]);
+        assert_eq!(lt_with_options(&null, &a, options).unwrap(), expected);
+        let expected = BooleanArray::from(vec![false, true, false, true, false]);
+        assert_eq!(lt_eq_with_options(&a, &null, options).unwrap(), expected);
+
+        let two = Int32Array::new_scalar(2);
+        let expected = BooleanArray::from(vec![true, true, false, true, false]);
+        assert_eq!(lt_with_options(&a, &two, options).unwrap(), expected);
+        let expected = BooleanArray::from(vec![false, false, true, false, false]);
+        assert_eq!(gt_with_options(&a, &two, options).unwrap(), expected);
+
+        let err = lt_with_options(&a, &Int32Array::from(vec![1]), options).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Cannot compare arrays of different lengths"));
+    }
+
     #[test]
     fn test_scalar_negation() {
         let a = Int32Array::new_scalar(54);
@@ -55,4 +55,5 @@ pub mod comparison;
 pub mod ord;
 pub mod partition;
 pub mod rank;
+pub mod set;
 pub mod sort;
@@ -202,6 +202,39 @@ fn compare_list<O: OffsetSizeTrait>(
     Ok(f)
 }
 
+fn compare_list_view<O: OffsetSizeTrait>(
+    left: &dyn Array,
+    right: &dyn Array,
+    opts: SortOptions,
+) -> Result<DynComparator, ArrowError> {
+    let left = left.as_list_view::<O>();
+    let right = right.as_list_view::<O>();
+
+    let c_opts = child_opts(opts);
+    let cmp = make_comparator(left.values().as_ref(), right.values().as_ref(), c_opts)?;
+
+    let l_o = left.offsets().clone();
+    let l_s = left.sizes().clone();
+    let r_o = right.offsets().clone();
+    let r_s = right.sizes().clone();
+    let f = compare(left, right, opts, move |i, j| {
+        let l_start = l_o[i].as_usize();
+        let l_len = l_s[i].as_usize();
+
+        let r_start = r_o[j].as_usize();
+        let r_len = r_s[j].as_usize();
+
+        for (i, j) in (l_start..l_start + l_len).zip(r_start..r_start + r_len) {
+            match cmp(i, j) {
+                Ordering::Equal => continue,
+                r => return r,
+            }
+        }
+        l_len.cmp(&r_len)
+    });
+    Ok(f)
+}
+
 fn compare_fixed_list(
     left: &dyn Array,
     right: &dyn Array,
@@ -398,6 +431,8 @@ pub fn make_comparator(
         },
         (List(_), List(_)) => compare_list::<i32>(left, right, opts),
         (LargeList(_), LargeList(_)) => compare_list::<i64>(left, right, opts),
+        (ListView(_), ListView(_)) => compare_list_view::<i32>(left, right, opts),
+        (LargeListView(_), LargeListView(_)) => compare_list_view::<i64>(left, right, opts),
         (FixedSizeList(_, _), FixedSizeList(_, _)) => compare_fixed_list(left, right, opts),
         (Struct(_), Struct(_)) => compare_struct(left, right, opts),
         (Dictionary(l_key, _), Dictionary(r_key, _)) => {
@@ -871,6 +906,42 @@ mod tests {
         assert_eq!(cmp(2, 0), Ordering::Less);
     }
 
+    #[test]
+    fn test_list_view() {
+        let field = Arc::new(Field::new_list_field(DataType::Int32, true));
+        // [[3, 4], null, [1], [1, 2, 3]]
+        let a = ListViewArray::new(
+            field.clone(),
+            vec![2, 1, 0, 0].into(),
+            vec![2, 2, 1, 3].into(),
+            Arc::new(Int32Array::from(vec![1, 2, 3, 4])),
+            Some(NullBuffer::from(vec![true, false, true, true])),
+        );
+        // [[1, 2], [3, 4]]
+        let b = ListViewArray::new(
+            field,
+            vec![2, 0].into(),
+            vec![2, 2].into(),
+            Arc::new(Int32Array::from(vec![3, 4, 1, 2])),
+            None,
+        );
+
+        let cmp = make_comparator(&a, &b, SortOptions::default()).unwrap();
+        assert_eq!(cmp(0, 1), Ordering::Equal);
+        assert_eq!(cmp(1, 0), Ordering::Less);
+        assert_eq!(cmp(2, 0), Ordering::Less);
+        assert_eq!(cmp(3, 0), Ordering::Greater);
+        assert_eq!(cmp(3, 1), Ordering::Less);
+
+        let opts = SortOptions {
+            descending: true,
+            nulls_first: false,
+        };
+        let cmp = make_comparator(&a, &b, opts).unwrap();
+        assert_eq!(cmp(1, 0), Ordering::Greater);
+        assert_eq!(cmp(3, 1), Ordering::Greater);
+    }
+
     #[test]
     fn test_struct() {
         let fields = Fields::from(vec![
@@ -15,12 +15,15 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! Provides `rank` function to assign a rank to each value in an array
+//! Provides `rank` function to assign a rank to each value in an array, and
+//! window ranking functions over multiple columns
 
+use crate::sort::{LexicographicalComparator, SortColumn};
 use arrow_array::cast::AsArray;
 use arrow_array::types::*;
 use arrow_array::{
-    downcast_primitive_array, Array, ArrowNativeTypeOp, BooleanArray, GenericByteArray,
+    downcast_primitive_array, Array, ArrowNativeTypeOp, BooleanArray, Float64Array,
+    GenericByteArray, UInt32Array,
 };
 use arrow_buffer::NullBuffer;
 use arrow_schema::{ArrowError, DataType, SortOptions};
@@ -220,6 +223,165 @@ fn boolean_rank(array: &BooleanArray, options: SortOptions) -> Vec<u32> {
     }
 }
 
+/// How rows that compare equal are ranked by [`rank_columns`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum RankTies {
+    /// Equal rows are assigned the lowest of their ranks, leaving gaps after
+    /// them, as in the SQL `RANK()` window function, e.g. `1, 2, 2, 4`
+    #[default]
+    Min,
+    /// Equal rows are assigned the highest of their ranks, leaving gaps before
+    /// them, as in [`rank`], e.g. `1, 3, 3, 4`
+    Max,
+    /// Equal rows are assigned the same rank, without gaps, as in the SQL
+    /// `DENSE_RANK()` window function, e.g. `1, 2, 2, 3`
+    Dense,
+    /// Equal rows are assigned consecutive ranks in the order they appear, as in
+    /// the SQL `ROW_NUMBER()` window function, e.g. `1, 2, 3, 4`
+    Ordinal,
+}
+
+/// Assigns a rank, starting at 1, to each row of `columns` based on its position
+/// when sorted lexicographically, with rows that compare equal ranked according
+/// to `ties`
+///
+/// Nulls compare equal to each other, and are ordered according to the `nulls_first`
+/// flag of each column's [`SortOptions`]. The rows are compared using a
+/// [`LexicographicalComparator`], and if they are already sorted, they are ranked
+/// without sorting them again.
+///
+/// Returns an error if `columns` is empty, the columns have different lengths,
+/// or any data type cannot be compared.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array, UInt32Array};
+/// # use arrow_ord::rank::{rank_columns, RankTies};
+/// # use arrow_ord::sort::SortColumn;
+/// let column = SortColumn {
+///     values: Arc::new(Int32Array::from(vec![Some(20), None, Some(10), Some(20)])) as ArrayRef,
+///     options: None,
+/// };
+/// let ranks = rank_columns(&[column.clone()], RankTies::Min).unwrap();
+/// assert_eq!(ranks, UInt32Array::from(vec![3, 1, 2, 3]));
+///
+/// let ranks = rank_columns(&[column], RankTies::Ordinal).unwrap();
+/// assert_eq!(ranks, UInt32Array::from(vec![3, 1, 2, 4]));
+/// ```
+pub fn rank_columns(columns: &[SortColumn], ties: RankTies) -> Result<UInt32Array, ArrowError> {
+    let (comparator, len) = sort_columns_comparator(columns)?;
+    let order = sorted_order(&comparator, len);
+
+    let mut ranks = vec![0_u32; len];
+    let mut start = 0;
+    let mut dense = 0;
+    while start < len {
+        let row = order[start] as usize;
+        let end = start
+            + order[start..]
+                .iter()
+                .take_while(|idx| comparator.compare(**idx as usize, row).is_eq())
+                .count();
+        dense += 1;
+        for (position, idx) in (start..end).zip(&order[start..end]) {
+            // Lengths were checked to fit in u32 by `sort_columns_comparator`
+            ranks[*idx as usize] = match ties {
+                RankTies::Min => start as u32 + 1,
+                RankTies::Max => end as u32,
+                RankTies::Dense => dense,
+                RankTies::Ordinal => position as u32 + 1,
+            };
+        }
+        start = end;
+    }
+    Ok(ranks.into())
+}
+
+/// Assigns a rank to each row of `columns`, with equal rows assigned the same rank
+/// without gaps, as in the SQL `DENSE_RANK()` window function
+///
+/// See [`rank_columns`] for more details
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, StringArray, UInt32Array};
+/// # use arrow_ord::rank::dense_rank;
+/// # use arrow_ord::sort::{SortColumn, SortOptions};
+/// let column = SortColumn {
+///     values: Arc::new(StringArray::from(vec!["b", "a", "c", "a"])) as ArrayRef,
+///     options: Some(SortOptions::default().desc()),
+/// };
+/// let ranks = dense_rank(&[column]).unwrap();
+/// assert_eq!(ranks, UInt32Array::from(vec![2, 3, 1, 3]));
+/// ```
+pub fn dense_rank(columns: &[SortColumn]) -> Result<UInt32Array, ArrowError> {
+    rank_columns(columns, RankTies::Dense)
+}
+
+/// Computes the relative rank of each row of `columns`, as in the SQL `PERCENT_RANK()`
+/// window function, defined as `(rank - 1) / (num_rows - 1)` where `rank` uses
+/// [`RankTies::Min`], or `0` if there is a single row
+///
+/// See [`rank_columns`] for more details
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Float64Array, Int32Array};
+/// # use arrow_ord::rank::percent_rank;
+/// # use arrow_ord::sort::SortColumn;
+/// let column = SortColumn {
+///     values: Arc::new(Int32Array::from(vec![5, 1, 5, 9, 3])) as ArrayRef,
+///     options: None,
+/// };
+/// let ranks = percent_rank(&[column]).unwrap();
+/// assert_eq!(ranks, Float64Array::from(vec![0.5, 0.0, 0.5, 1.0, 0.25]));
+/// ```
+pub fn percent_rank(columns: &[SortColumn]) -> Result<Float64Array, ArrowError> {
+    let ranks = rank_columns(columns, RankTies::Min)?;
+    let denominator = ranks.len().saturating_sub(1).max(1) as f64;
+    Ok(ranks
+        .values()
+        .iter()
+        .map(|rank| (rank - 1) as f64 / denominator)
+        .collect())
+}
+
+/// Returns a comparator of the rows of `columns` and their number, validating they
+/// are suitable for ranking
+fn sort_columns_comparator(
+    columns: &[SortColumn],
+) -> Result<(LexicographicalComparator, usize), ArrowError> {
+    if columns.is_empty() {
+        return Err(ArrowError::InvalidArgumentError(
+            "Rank requires at least one column".to_string(),
+        ));
+    }
+    let row_count = columns[0].values.len();
+    if columns.iter().any(|item| item.values.len() != row_count) {
+        return Err(ArrowError::ComputeError(
+            "rank columns have different row counts".to_string(),
+        ));
+    }
+    if u32::try_from(row_count).is_err() {
+        return Err(ArrowError::ComputeError(format!(
+            "Cannot rank {row_count} rows, exceeds the maximum of {}",
+            u32::MAX
+        )));
+    }
+    Ok((LexicographicalComparator::try_new(columns)?, row_count))
+}
+
+/// Returns the indices of the `len` rows of `comparator` in sorted order, with equal
+/// rows in the order they appear
+fn sorted_order(comparator: &LexicographicalComparator, len: usize) -> Vec<u32> {
+    let mut order: Vec<u32> = (0..len as u32).collect();
+    let is_sorted = (1..len).all(|i| comparator.compare(i - 1, i).is_le());
+    if !is_sorted {
+        order.sort_by(|a, b| comparator.compare(*a as usize, *b as usize));
+    }
+    order
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -358,4 +520,98 @@ mod tests {
         let res = rank(&values, None).unwrap();
         assert_eq!(res, &[3, 1, 4, 3]);
     }
+
+    fn sort_column(values: impl Array + 'static, options: Option<SortOptions>) -> SortColumn {
+        SortColumn {
+            values: std::sync::Arc::new(values),
+            options,
+        }
+    }
+
+    #[test]
+    fn test_rank_columns() {
+        let a = Int32Array::from(vec![Some(2), None, Some(1), Some(2), None, Some(2)]);
+        let cases = [
+            (None, RankTies::Min, vec![4, 1, 3, 4, 1, 4]),
+            (None, RankTies::Max, vec![6, 2, 3, 6, 2, 6]),
+            (None, RankTies::Dense, vec![3, 1, 2, 3, 1, 3]),
+            (None, RankTies::Ordinal, vec![4, 1, 3, 5, 2, 6]),
+            (
+                Some(SortOptions::default().desc().nulls_last()),
+                RankTies::Min,
+                vec![1, 5, 4, 1, 5, 1],
+            ),
+            (
+                Some(SortOptions::default().nulls_last()),
+                RankTies::Dense,
+                vec![2, 3, 1, 2, 3, 2],
+            ),
+        ];
+        for (options, ties, expected) in cases {
+            let columns = [sort_column(a.clone(), options)];
+            let ranks = rank_columns(&columns, ties).unwrap();
+            assert_eq!(ranks, UInt32Array::from(expected), "{options:?} {ties:?}");
+        }
+
+        // Max is consistent with `rank`
+        let columns = [sort_column(a.clone(), None)];
+        let ranks = rank_columns(&columns, RankTies::Max).unwrap();
+        assert_eq!(ranks.values(), rank(&a, None).unwrap().as_slice());
+    }
+
+    #[test]
+    fn test_rank_columns_multiple() {
+        let a = StringArray::from(vec!["x", "y", "x", "x", "y"]);
+        let b = Int32Array::from(vec![1, 1, 2, 1, 0]);
+        let columns = [
+            sort_column(a, None),
+            sort_column(b, Some(SortOptions::default().desc())),
+        ];
+        // Sorted order: (x, 2), (x, 1), (x, 1), (y, 1), (y, 0)
+        let ranks = rank_columns(&columns, RankTies::Min).unwrap();
+        assert_eq!(ranks, UInt32Array::from(vec![2, 4, 1, 2, 5]));
+        let ranks = dense_rank(&columns).unwrap();
+        assert_eq!(ranks, UInt32Array::from(vec![2, 3, 1, 2, 4]));
+        let ranks = percent_rank(&columns).unwrap();
+        assert_eq!(ranks, Float64Array::from(vec![0.25, 0.75, 0.0, 0.25, 1.0]));
+    }
+
+    #[test]
+    fn test_rank_columns_sorted() {
+        let a = Int32Array::from(vec![1, 1, 2, 3, 3, 3]);
+        let columns = [sort_column(a, None)];
+        let ranks = rank_columns(&columns, RankTies::Min).unwrap();
+        assert_eq!(ranks, UInt32Array::from(vec![1, 1, 3, 4, 4, 4]));
+        let ranks = rank_columns(&columns, RankTies::Ordinal).unwrap();
+        assert_eq!(ranks, UInt32Array::from(vec![1, 2, 3, 4, 5, 6]));
+    }
+
+    #[test]
+    fn test_rank_columns_edge_cases() {
+        let empty = [sort_column(Int32Array::from(Vec::<i32>::new()), None)];
+        assert!(rank_columns(&empty, RankTies::Min).unwrap().is_empty());
+        assert!(percent_rank(&empty).unwrap().is_empty());
+
+        let single = [sort_column(Int32Array::from(vec![7]), None)];
+        assert_eq!(
+            percent_rank(&single).unwrap(),
+            Float64Array::from(vec![0.0])
+        );
+
+        let err = rank_columns(&[], RankTies::Min).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Rank requires at least one column"
+        );
+
+        let columns = [
+            sort_column(Int32Array::from(vec![1, 2]), None),
+            sort_column(Int32Array::from(vec![1]), None),
+        ];
+        let err = dense_rank(&columns).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: rank columns have different row counts"
+        );
+    }
 }
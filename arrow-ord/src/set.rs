@@ -0,0 +1,441 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Set operations on the elements of each row of list arrays
+//!
+//! Elements are compared using [`make_comparator`], and so any element type it
+//! supports can be used, including nested types. Null elements are equal to each
+//! other, as are floating point NaNs.
+//!
+//! The elements of each output row are distinct, in the order of their first occurrence.
+//! For the binary kernels, an output row is null if either input row is null.
+
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::{Array, ArrayRef, GenericListArray, OffsetSizeTrait};
+use arrow_buffer::{NullBuffer, OffsetBuffer};
+use arrow_schema::{ArrowError, DataType, SortOptions};
+use arrow_select::interleave::interleave;
+
+use crate::ord::{make_comparator, DynComparator};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SetOp {
+    Distinct,
+    Union,
+    Intersect,
+    Except,
+}
+
+impl std::fmt::Display for SetOp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Distinct => write!(f, "array_distinct"),
+            Self::Union => write!(f, "array_union"),
+            Self::Intersect => write!(f, "array_intersect"),
+            Self::Except => write!(f, "array_except"),
+        }
+    }
+}
+
+/// Returns the distinct elements of each row of a `List` or `LargeList` array
+///
+/// ```
+/// # use arrow_array::{Int32Array, ListArray};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_ord::set::array_distinct;
+/// let list = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+///     Some(vec![Some(1), Some(2), Some(1), None, None]),
+///     None,
+///     Some(vec![]),
+/// ]);
+/// let distinct = array_distinct(&list).unwrap();
+/// let expected = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+///     Some(vec![Some(1), Some(2), None]),
+///     None,
+///     Some(vec![]),
+/// ]);
+/// assert_eq!(distinct.as_list::<i32>(), &expected);
+/// ```
+pub fn array_distinct(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    match array.data_type() {
+        DataType::List(_) => Ok(Arc::new(set_op(
+            array.as_list::<i32>(),
+            None,
+            SetOp::Distinct,
+        )?)),
+        DataType::LargeList(_) => Ok(Arc::new(set_op(
+            array.as_list::<i64>(),
+            None,
+            SetOp::Distinct,
+        )?)),
+        d => Err(ArrowError::InvalidArgumentError(format!(
+            "{} expects a List or LargeList array, got {d}",
+            SetOp::Distinct
+        ))),
+    }
+}
+
+/// Returns the distinct elements of each row of `left` followed by those of the
+/// corresponding row of `right` not in `left`
+///
+/// `left` and `right` must be `List` or `LargeList` arrays of the same type and length.
+///
+/// ```
+/// # use arrow_array::ListArray;
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_ord::set::array_union;
+/// let left = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![Some(vec![Some(3), Some(1)])]);
+/// let right = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![Some(vec![Some(1), Some(2)])]);
+/// let union = array_union(&left, &right).unwrap();
+/// let expected = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+///     Some(vec![Some(3), Some(1), Some(2)]),
+/// ]);
+/// assert_eq!(union.as_list::<i32>(), &expected);
+/// ```
+pub fn array_union(left: &dyn Array, right: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    binary_set_op(left, right, SetOp::Union)
+}
+
+/// Returns the distinct elements of each row of `left` that are in the corresponding
+/// row of `right`
+///
+/// `left` and `right` must be `List` or `LargeList` arrays of the same type and length.
+///
+/// ```
+/// # use arrow_array::ListArray;
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_ord::set::array_intersect;
+/// let left = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![Some(vec![Some(3), Some(1), Some(2)])]);
+/// let right = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![Some(vec![Some(2), Some(3)])]);
+/// let intersect = array_intersect(&left, &right).unwrap();
+/// let expected = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![Some(vec![Some(3), Some(2)])]);
+/// assert_eq!(intersect.as_list::<i32>(), &expected);
+/// ```
+pub fn array_intersect(left: &dyn Array, right: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    binary_set_op(left, right, SetOp::Intersect)
+}
+
+/// Returns the distinct elements of each row of `left` that are not in the corresponding
+/// row of `right`
+///
+/// `left` and `right` must be `List` or `LargeList` arrays of the same type and length.
+///
+/// ```
+/// # use arrow_array::ListArray;
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_ord::set::array_except;
+/// let left = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![Some(vec![Some(3), Some(1), Some(3)])]);
+/// let right = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![Some(vec![Some(1)])]);
+/// let except = array_except(&left, &right).unwrap();
+/// let expected = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![Some(vec![Some(3)])]);
+/// assert_eq!(except.as_list::<i32>(), &expected);
+/// ```
+pub fn array_except(left: &dyn Array, right: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    binary_set_op(left, right, SetOp::Except)
+}
+
+fn binary_set_op(left: &dyn Array, right: &dyn Array, op: SetOp) -> Result<ArrayRef, ArrowError> {
+    if left.len() != right.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "{op} requires arrays of the same length, got {} and {}",
+            left.len(),
+            right.len()
+        )));
+    }
+    match (left.data_type(), right.data_type()) {
+        (DataType::List(l), DataType::List(r)) if l.data_type() == r.data_type() => Ok(Arc::new(
+            set_op(left.as_list::<i32>(), Some(right.as_list::<i32>()), op)?,
+        )),
+        (DataType::LargeList(l), DataType::LargeList(r)) if l.data_type() == r.data_type() => {
+            Ok(Arc::new(set_op(
+                left.as_list::<i64>(),
+                Some(right.as_list::<i64>()),
+                op,
+            )?))
+        }
+        (l, r) => Err(ArrowError::InvalidArgumentError(format!(
+            "{op} expects List or LargeList arrays of the same type, got {l} and {r}"
+        ))),
+    }
+}
+
+/// The elements of a list array referenced by its offsets, and the offsets of each row
+/// into them
+struct ListElements {
+    values: ArrayRef,
+    offsets: Vec<usize>,
+}
+
+impl ListElements {
+    fn new<O: OffsetSizeTrait>(list: &GenericListArray<O>) -> Self {
+        let value_offsets = list.value_offsets();
+        let start = value_offsets[0].as_usize();
+        let end = value_offsets[list.len()].as_usize();
+        let values = list.values().slice(start, end - start);
+        let offsets = value_offsets.iter().map(|o| o.as_usize() - start).collect();
+        Self { values, offsets }
+    }
+
+    fn range(&self, i: usize) -> std::ops::Range<usize> {
+        self.offsets[i]..self.offsets[i + 1]
+    }
+}
+
+/// Compares the `(array, index)` elements of `left` and `right`, where array 0 is
+/// `left` and 1 is `right`
+struct ElementComparator {
+    left: DynComparator,
+    /// Comparators of left to right, and right to right elements
+    right: Option<(DynComparator, DynComparator)>,
+}
+
+impl ElementComparator {
+    fn try_new(left: &dyn Array, right: Option<&dyn Array>) -> Result<Self, ArrowError> {
+        let options = SortOptions::default();
+        let right = right
+            .map(|right| {
+                Ok::<_, ArrowError>((
+                    make_comparator(left, right, options)?,
+                    make_comparator(right, right, options)?,
+                ))
+            })
+            .transpose()?;
+        Ok(Self {
+            left: make_comparator(left, left, options)?,
+            right,
+        })
+    }
+
+    fn compare(&self, a: (usize, usize), b: (usize, usize)) -> Ordering {
+        match (a.0, b.0, &self.right) {
+            (0, 0, _) => (self.left)(a.1, b.1),
+            (0, _, Some((left_right, _))) => left_right(a.1, b.1),
+            (_, 0, Some((left_right, _))) => left_right(b.1, a.1).reverse(),
+            (_, _, Some((_, right))) => right(a.1, b.1),
+            _ => unreachable!("right elements require a right array"),
+        }
+    }
+}
+
+fn set_op<O: OffsetSizeTrait>(
+    left: &GenericListArray<O>,
+    right: Option<&GenericListArray<O>>,
+    op: SetOp,
+) -> Result<GenericListArray<O>, ArrowError> {
+    let l = ListElements::new(left);
+    let r = right.map(ListElements::new);
+    let comparator =
+        ElementComparator::try_new(l.values.as_ref(), r.as_ref().map(|r| r.values.as_ref()))?;
+    let nulls = NullBuffer::union(left.nulls(), right.and_then(|r| r.nulls()));
+
+    // The (array, index) of each output element, where array 0 is `left` and 1 is `right`
+    let mut indices: Vec<(usize, usize)> = Vec::with_capacity(l.values.len());
+    let mut offsets = Vec::with_capacity(left.len() + 1);
+    offsets.push(O::usize_as(0));
+    let mut elements = vec![];
+    let mut kept = vec![];
+    for i in 0..left.len() {
+        if nulls.as_ref().is_none_or(|n| n.is_valid(i)) {
+            elements.clear();
+            elements.extend(l.range(i).map(|j| (0, j)));
+            if let Some(r) = &r {
+                elements.extend(r.range(i).map(|j| (1, j)));
+            }
+            // A stable sort, so that each run of equal elements starts with the first
+            // occurrence in `left`, if any, and ends with the last in `right`, if any
+            elements.sort_by(|a, b| comparator.compare(*a, *b));
+
+            kept.clear();
+            for run in elements.chunk_by(|a, b| comparator.compare(*a, *b).is_eq()) {
+                let (first, last) = (run[0], run[run.len() - 1]);
+                let keep = match op {
+                    SetOp::Distinct | SetOp::Union => true,
+                    SetOp::Intersect => first.0 == 0 && last.0 == 1,
+                    SetOp::Except => last.0 == 0,
+                };
+                if keep {
+                    kept.push(first);
+                }
+            }
+            // Output in the order of first occurrence, the elements of `left` first
+            kept.sort_unstable();
+            indices.extend_from_slice(&kept);
+        }
+        let offset = O::from_usize(indices.len())
+            .ok_or_else(|| ArrowError::OffsetOverflowError(indices.len()))?;
+        offsets.push(offset);
+    }
+
+    let mut sources = vec![l.values.as_ref()];
+    sources.extend(r.as_ref().map(|r| r.values.as_ref()));
+    let values = interleave(&sources, &indices)?;
+
+    let (field, nullable) = match (left.data_type(), right.map(|r| r.data_type())) {
+        (
+            DataType::List(l) | DataType::LargeList(l),
+            Some(DataType::List(r) | DataType::LargeList(r)),
+        ) => (l, l.is_nullable() || r.is_nullable()),
+        (DataType::List(l) | DataType::LargeList(l), _) => (l, l.is_nullable()),
+        _ => unreachable!(),
+    };
+    let field = Arc::new(field.as_ref().clone().with_nullable(nullable));
+    GenericListArray::try_new(field, OffsetBuffer::new(offsets.into()), values, nulls)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::builder::{ListBuilder, StringBuilder};
+    use arrow_array::types::Int32Type;
+    use arrow_array::{Float64Array, LargeListArray, ListArray, StringArray};
+    use arrow_schema::Field;
+
+    fn list(rows: Vec<Option<Vec<Option<i32>>>>) -> ListArray {
+        ListArray::from_iter_primitive::<Int32Type, _, _>(rows)
+    }
+
+    #[test]
+    fn test_set_ops() {
+        let left = list(vec![
+            Some(vec![Some(1), Some(2), Some(2), None, Some(3)]),
+            Some(vec![]),
+            None,
+            Some(vec![Some(4), None, Some(4)]),
+            Some(vec![Some(5)]),
+        ]);
+        let right = list(vec![
+            Some(vec![Some(3), Some(4), None, Some(3)]),
+            Some(vec![Some(1)]),
+            Some(vec![Some(1)]),
+            Some(vec![]),
+            None,
+        ]);
+
+        let distinct = array_distinct(&left).unwrap();
+        let expected = list(vec![
+            Some(vec![Some(1), Some(2), None, Some(3)]),
+            Some(vec![]),
+            None,
+            Some(vec![Some(4), None]),
+            Some(vec![Some(5)]),
+        ]);
+        assert_eq!(distinct.as_list::<i32>(), &expected);
+
+        let union = array_union(&left, &right).unwrap();
+        let expected = list(vec![
+            Some(vec![Some(1), Some(2), None, Some(3), Some(4)]),
+            Some(vec![Some(1)]),
+            None,
+            Some(vec![Some(4), None]),
+            None,
+        ]);
+        assert_eq!(union.as_list::<i32>(), &expected);
+
+        let intersect = array_intersect(&left, &right).unwrap();
+        let expected = list(vec![
+            Some(vec![None, Some(3)]),
+            Some(vec![]),
+            None,
+            Some(vec![]),
+            None,
+        ]);
+        assert_eq!(intersect.as_list::<i32>(), &expected);
+
+        let except = array_except(&left, &right).unwrap();
+        let expected = list(vec![
+            Some(vec![Some(1), Some(2)]),
+            Some(vec![]),
+            None,
+            Some(vec![Some(4), None]),
+            None,
+        ]);
+        assert_eq!(except.as_list::<i32>(), &expected);
+
+        // Sliced inputs
+        let except = array_except(&left.slice(0, 1), &right.slice(1, 1)).unwrap();
+        let expected = list(vec![Some(vec![Some(2), None, Some(3)])]);
+        assert_eq!(except.as_list::<i32>(), &expected);
+    }
+
+    #[test]
+    fn test_set_ops_nested_and_large() {
+        // Lists of lists of strings
+        let mut builder = ListBuilder::new(ListBuilder::new(StringBuilder::new()));
+        for row in [
+            vec![vec!["a"], vec!["b", "c"], vec!["a"]],
+            vec![vec![], vec![]],
+        ] {
+            for inner in row {
+                for s in inner {
+                    builder.values().values().append_value(s);
+                }
+                builder.values().append(true);
+            }
+            builder.append(true);
+        }
+        let nested = builder.finish();
+        let distinct = array_distinct(&nested).unwrap();
+        let distinct = distinct.as_list::<i32>();
+        assert_eq!(distinct.value_offsets(), &[0, 2, 3]);
+        let inner = distinct.values().as_list::<i32>();
+        let strings = inner
+            .values()
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!(strings, &StringArray::from(vec!["a", "b", "c"]));
+
+        // NaN is equal to itself
+        let values = Arc::new(Float64Array::from(vec![f64::NAN, 1.0, f64::NAN]));
+        let field = Arc::new(Field::new_list_field(DataType::Float64, true));
+        let large = LargeListArray::new(field, OffsetBuffer::from_lengths([3]), values, None);
+        let distinct = array_distinct(&large).unwrap();
+        assert_eq!(distinct.as_list::<i64>().value(0).len(), 2);
+    }
+
+    #[test]
+    fn test_set_ops_invalid() {
+        let left = list(vec![Some(vec![Some(1)])]);
+        let err = array_union(&left, &list(vec![None, None])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: array_union requires arrays of the same length, got 1 and 2"
+        );
+
+        let right =
+            ListArray::from_iter_primitive::<arrow_array::types::Int64Type, _, _>(vec![Some(
+                vec![Some(1)],
+            )]);
+        let err = array_intersect(&left, &right).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("Invalid argument error: array_intersect expects List or LargeList arrays of the same type"));
+
+        let err = array_distinct(&StringArray::from(vec!["a"])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: array_distinct expects a List or LargeList array, got Utf8"
+        );
+    }
+}
@@ -207,6 +207,8 @@ fn can_sort_to_indices(data_type: &DataType) -> bool {
         || match data_type {
             DataType::List(f) if can_rank(f.data_type()) => true,
             DataType::LargeList(f) if can_rank(f.data_type()) => true,
+            DataType::ListView(f) if can_rank(f.data_type()) => true,
+            DataType::LargeListView(f) if can_rank(f.data_type()) => true,
             DataType::FixedSizeList(f, _) if can_rank(f.data_type()) => true,
             DataType::Dictionary(_, values) if can_rank(values.as_ref()) => true,
             DataType::RunEndEncoded(_, f) if can_sort_to_indices(f.data_type()) => true,
@@ -237,6 +239,8 @@ pub fn sort_to_indices(
         DataType::FixedSizeBinary(_) => sort_fixed_size_binary(array.as_fixed_size_binary(), v, n, options, limit),
         DataType::List(_) => sort_list(array.as_list::<i32>(), v, n, options, limit)?,
         DataType::LargeList(_) => sort_list(array.as_list::<i64>(), v, n, options, limit)?,
+        DataType::ListView(_) => sort_list_view(array.as_list_view::<i32>(), v, n, options, limit)?,
+        DataType::LargeListView(_) => sort_list_view(array.as_list_view::<i64>(), v, n, options, limit)?,
         DataType::FixedSizeList(_, _) => sort_fixed_size_list(array.as_fixed_size_list(), v, n, options, limit)?,
         DataType::Dictionary(_, _) => downcast_dictionary_array!{
             array => sort_dictionary(array, v, n, options, limit)?,
@@ -455,6 +459,27 @@ fn sort_list<O: OffsetSizeTrait>(
     Ok(sort_impl(options, &mut valids, &null_indices, limit, Ord::cmp).into())
 }
 
+fn sort_list_view<O: OffsetSizeTrait>(
+    array: &GenericListViewArray<O>,
+    value_indices: Vec<u32>,
+    null_indices: Vec<u32>,
+    options: SortOptions,
+    limit: Option<usize>,
+) -> Result<UInt32Array, ArrowError> {
+    let rank = child_rank(array.values().as_ref(), options)?;
+    let offsets = array.value_offsets();
+    let sizes = array.value_sizes();
+    let mut valids = value_indices
+        .into_iter()
+        .map(|index| {
+            let start = offsets[index as usize].as_usize();
+            let size = sizes[index as usize].as_usize();
+            (index, &rank[start..start + size])
+        })
+        .collect::<Vec<(u32, &[u32])>>();
+    Ok(sort_impl(options, &mut valids, &null_indices, limit, Ord::cmp).into())
+}
+
 fn sort_fixed_size_list(
     array: &FixedSizeListArray,
     value_indices: Vec<u32>,
@@ -775,35 +800,19 @@ pub fn lexsort(columns: &[SortColumn], limit: Option<usize>) -> Result<Vec<Array
 /// (`UInt32Array`) of indices.
 ///
 /// Note: for multi-column sorts without a limit, using the [row format](https://docs.rs/arrow-row/latest/arrow_row/)
-/// may be significantly faster
+/// may be significantly faster, and with a small limit, so may [`lexsort_limit`]
 pub fn lexsort_to_indices(
     columns: &[SortColumn],
     limit: Option<usize>,
 ) -> Result<UInt32Array, ArrowError> {
-    if columns.is_empty() {
-        return Err(ArrowError::InvalidArgumentError(
-            "Sort requires at least one column".to_string(),
-        ));
-    }
+    let (row_count, len) = lexsort_len(columns, limit)?;
     if columns.len() == 1 && can_sort_to_indices(columns[0].values.data_type()) {
         // fallback to non-lexical sort
         let column = &columns[0];
         return sort_to_indices(&column.values, column.options, limit);
     }
 
-    let row_count = columns[0].values.len();
-    if columns.iter().any(|item| item.values.len() != row_count) {
-        return Err(ArrowError::ComputeError(
-            "lexical sort columns have different row counts".to_string(),
-        ));
-    };
-
     let mut value_indices = (0..row_count).collect::<Vec<usize>>();
-    let mut len = value_indices.len();
-
-    if let Some(limit) = limit {
-        len = limit.min(len);
-    }
 
     // Instantiate specialized versions of comparisons for small numbers
     // of columns as it helps the compiler generate better code.
@@ -860,6 +869,128 @@ where
     }
 }
 
+/// Returns the indices of the first `k` elements of `values` when sorted according
+/// to `options`, without sorting the remaining elements
+///
+/// This is equivalent to `sort_to_indices(values, options, Some(k))`, but selects
+/// the elements using a bounded heap, and is therefore more efficient when `k` is
+/// small relative to the length of `values`. Unlike [`sort_to_indices`], the sort
+/// is stable, equal elements are returned in the order they appear in `values`.
+///
+/// See [`lexsort_limit`] for more details
+///
+/// ```
+/// # use arrow_array::{Int32Array, UInt32Array};
+/// # use arrow_ord::sort::{partial_sort_to_indices, SortOptions};
+/// let array = Int32Array::from(vec![Some(5), None, Some(3), Some(7), Some(3)]);
+///
+/// let indices = partial_sort_to_indices(&array, 2, None).unwrap();
+/// assert_eq!(indices, UInt32Array::from(vec![1, 2]));
+///
+/// let options = SortOptions::default().desc().nulls_last();
+/// let indices = partial_sort_to_indices(&array, 3, Some(options)).unwrap();
+/// assert_eq!(indices, UInt32Array::from(vec![3, 0, 2]));
+/// ```
+pub fn partial_sort_to_indices(
+    values: &dyn Array,
+    k: usize,
+    options: Option<SortOptions>,
+) -> Result<UInt32Array, ArrowError> {
+    let column = SortColumn {
+        values: make_array(values.to_data()),
+        options,
+    };
+    lexsort_limit(&[column], k)
+}
+
+/// Returns the indices of the first `k` rows of `columns` when sorted lexicographically,
+/// without sorting the remaining rows
+///
+/// This is equivalent to `lexsort_to_indices(columns, Some(k))`, but rather than
+/// sorting all the rows, it keeps a buffer of at most `2 * k` candidate rows, which
+/// is reduced to the smallest `k` whenever it fills. Rows that compare greater than the
+/// largest of these are discarded without being buffered. This requires memory
+/// proportional to `k`, rather than the number of rows, and is significantly faster
+/// when `k` is small, as is typical for `ORDER BY ... LIMIT` queries.
+///
+/// The sort is stable, rows that compare equal are returned in the order they appear
+/// in `columns`.
+///
+/// Returns an error if the columns have different lengths, or any of the data types
+/// cannot be compared.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array, StringArray, UInt32Array};
+/// # use arrow_ord::sort::{lexsort_limit, SortColumn, SortOptions};
+/// let columns = vec![
+///     SortColumn {
+///         values: Arc::new(Int32Array::from(vec![2, 1, 2, 1, 3])) as ArrayRef,
+///         options: None,
+///     },
+///     SortColumn {
+///         values: Arc::new(StringArray::from(vec!["a", "b", "c", "d", "e"])) as ArrayRef,
+///         options: Some(SortOptions::default().desc()),
+///     },
+/// ];
+///
+/// let indices = lexsort_limit(&columns, 3).unwrap();
+/// assert_eq!(indices, UInt32Array::from(vec![3, 1, 2]));
+/// ```
+pub fn lexsort_limit(columns: &[SortColumn], k: usize) -> Result<UInt32Array, ArrowError> {
+    let (row_count, k) = lexsort_len(columns, Some(k))?;
+    let comparator = LexicographicalComparator::try_new(columns)?;
+    // Ties are broken by row index, making the sort stable
+    let compare = |a: &u32, b: &u32| comparator.compare(*a as usize, *b as usize).then(a.cmp(b));
+
+    if k == 0 {
+        return Ok(UInt32Array::from(Vec::<u32>::new()));
+    }
+    let capacity = k.saturating_mul(2).min(row_count);
+    let mut candidates: Vec<u32> = Vec::with_capacity(capacity);
+    // The largest of the smallest `k` rows seen, once the buffer has been reduced
+    let mut threshold = None;
+    for idx in 0..row_count as u32 {
+        // Rows are visited in index order, and so a row equal to the threshold
+        // can't displace it
+        if threshold.is_some_and(|t| compare(&idx, &t).is_gt()) {
+            continue;
+        }
+        candidates.push(idx);
+        if candidates.len() == capacity {
+            candidates.select_nth_unstable_by(k - 1, compare);
+            candidates.truncate(k);
+            threshold = Some(candidates[k - 1]);
+        }
+    }
+
+    candidates.sort_unstable_by(compare);
+    candidates.truncate(k);
+    Ok(UInt32Array::from(candidates))
+}
+
+/// Returns the number of rows of the lexical sort `columns`, and the number of sorted
+/// rows to return for `limit`
+///
+/// Returns an error if there are no columns, or they have different numbers of rows
+fn lexsort_len(columns: &[SortColumn], limit: Option<usize>) -> Result<(usize, usize), ArrowError> {
+    if columns.is_empty() {
+        return Err(ArrowError::InvalidArgumentError(
+            "Sort requires at least one column".to_string(),
+        ));
+    }
+    let row_count = columns[0].values.len();
+    if columns.iter().any(|item| item.values.len() != row_count) {
+        return Err(ArrowError::ComputeError(
+            "lexical sort columns have different row counts".to_string(),
+        ));
+    };
+    Ok((
+        row_count,
+        limit.map_or(row_count, |limit| limit.min(row_count)),
+    ))
+}
+
 /// A lexicographical comparator that wraps given array data (columns) and can lexicographically compare data
 /// at given two indices. The lifetime is the same at the data wrapped.
 pub struct LexicographicalComparator {
@@ -2181,6 +2312,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_sort_indices_decimal32() {
+        test_sort_indices_decimal::<Decimal32Type>(8, 3);
+    }
+
+    #[test]
+    fn test_sort_indices_decimal64() {
+        test_sort_indices_decimal::<Decimal64Type>(17, 5);
+    }
+
     #[test]
     fn test_sort_indices_decimal128() {
         test_sort_indices_decimal::<Decimal128Type>(23, 6);
@@ -2334,6 +2475,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_sort_decimal32() {
+        test_sort_decimal::<Decimal32Type>(8, 3);
+    }
+
+    #[test]
+    fn test_sort_decimal64() {
+        test_sort_decimal::<Decimal64Type>(17, 5);
+    }
+
     #[test]
     fn test_sort_decimal128() {
         test_sort_decimal::<Decimal128Type>(23, 6);
@@ -4639,6 +4790,34 @@ mod tests {
         assert_eq!(sort_indices.values(), &[1, 2, 0]);
     }
 
+    #[test]
+    fn sort_list_view() {
+        let field = Arc::new(Field::new_list_field(DataType::Int32, true));
+        // [[3, 4], null, [1], [1, 2, 3], [], [4]]
+        let array = LargeListViewArray::new(
+            field,
+            vec![2, 1, 0, 0, 4, 3].into(),
+            vec![2, 2, 1, 3, 0, 1].into(),
+            Arc::new(Int32Array::from(vec![1, 2, 3, 4])),
+            Some(NullBuffer::from(vec![true, false, true, true, true, true])),
+        );
+
+        let indices = sort_to_indices(&array, None, None).unwrap();
+        assert_eq!(indices.values(), &[1, 4, 2, 3, 0, 5]);
+
+        let options = SortOptions {
+            descending: true,
+            nulls_first: false,
+        };
+        let indices = sort_to_indices(&array, Some(options), Some(3)).unwrap();
+        assert_eq!(indices.values(), &[5, 0, 3]);
+
+        let sorted = sort(&array, None).unwrap();
+        let sorted = sorted.as_list_view::<i64>();
+        assert!(sorted.is_null(0));
+        assert_eq!(sorted.value(2).as_primitive::<Int32Type>().values(), &[1]);
+    }
+
     #[test]
     fn sort_struct_fallback_to_lexsort() {
         let float = Arc::new(Float32Array::from(vec![1.0, -0.1, 3.5, 1.0]));
@@ -4681,4 +4860,110 @@ mod tests {
 
         assert_eq!(&sorted[0], &expected_struct_array);
     }
+
+    #[test]
+    fn test_partial_sort_to_indices() {
+        let array = Int32Array::from(vec![Some(2), None, Some(1), Some(2), None, Some(0)]);
+        let cases = [
+            (None, 3, vec![1, 4, 5]),
+            (
+                Some(SortOptions::default().nulls_last()),
+                4,
+                vec![5, 2, 0, 3],
+            ),
+            (
+                Some(SortOptions::default().desc().nulls_last()),
+                3,
+                vec![0, 3, 2],
+            ),
+            (
+                Some(SortOptions::default().desc()),
+                10,
+                vec![1, 4, 0, 3, 2, 5],
+            ),
+            (None, 0, vec![]),
+        ];
+        for (options, k, expected) in cases {
+            let indices = partial_sort_to_indices(&array, k, options).unwrap();
+            assert_eq!(indices, UInt32Array::from(expected), "{options:?} {k}");
+        }
+
+        let empty = Int32Array::from(Vec::<i32>::new());
+        assert!(partial_sort_to_indices(&empty, 5, None).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_lexsort_limit_errors() {
+        let err = lexsort_limit(&[], 1).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Sort requires at least one column"
+        );
+
+        let columns = [
+            SortColumn {
+                values: Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef,
+                options: None,
+            },
+            SortColumn {
+                values: Arc::new(Int32Array::from(vec![1])) as ArrayRef,
+                options: None,
+            },
+        ];
+        let err = lexsort_limit(&columns, 1).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: lexical sort columns have different row counts"
+        );
+    }
+
+    #[test]
+    fn test_lexsort_limit_random() {
+        let mut rng = StdRng::seed_from_u64(42);
+        // Many duplicates to exercise tie breaking
+        let len = 20_000;
+        let a: Int32Array = (0..len)
+            .map(|_| rng.random_bool(0.9).then(|| rng.random_range(0..10)))
+            .collect();
+        let b: StringArray = (0..len)
+            .map(|_| {
+                rng.random_bool(0.9)
+                    .then(|| ["x", "y", "z"][rng.random_range(0..3)])
+            })
+            .collect();
+
+        for options in [
+            SortOptions::default(),
+            SortOptions::default().desc(),
+            SortOptions::default().nulls_last(),
+            SortOptions::default().desc().nulls_last(),
+        ] {
+            let columns = [
+                SortColumn {
+                    values: Arc::new(a.clone()) as ArrayRef,
+                    options: Some(options),
+                },
+                SortColumn {
+                    values: Arc::new(b.clone()) as ArrayRef,
+                    options: Some(!options),
+                },
+            ];
+            // A stable sort of all the rows
+            let comparator = LexicographicalComparator::try_new(&columns).unwrap();
+            let mut expected = (0..len).collect::<Vec<_>>();
+            expected.sort_by(|a, b| comparator.compare(*a, *b));
+
+            for k in [1, 10, 1000, len] {
+                let indices = lexsort_limit(&columns, k).unwrap();
+                let expected: UInt32Array = expected[..k].iter().map(|i| *i as u32).collect();
+                assert_eq!(indices, expected, "{options:?} {k}");
+            }
+
+            let indices = partial_sort_to_indices(&a, 100, Some(options)).unwrap();
+            let mut expected = (0..len as u32).collect::<Vec<_>>();
+            let comparator = make_comparator(&a, &a, options).unwrap();
+            expected.sort_by(|x, y| comparator(*x as usize, *y as usize));
+            assert_eq!(indices.values(), &expected[..100]);
+        }
+    }
 }
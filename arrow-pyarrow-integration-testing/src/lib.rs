@@ -25,15 +25,18 @@ use arrow::array::new_empty_array;
 use arrow::record_batch::{RecordBatchIterator, RecordBatchReader};
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
+use pyo3::types::PyCapsule;
 use pyo3::wrap_pyfunction;
 
-use arrow::array::{make_array, Array, ArrayData, ArrayRef, Int64Array};
+use arrow::array::{make_array, Array, ArrayData, ArrayRef, Int64Array, StringArray};
 use arrow::compute::kernels;
 use arrow::datatypes::{DataType, Field, Schema};
 use arrow::error::ArrowError;
 use arrow::ffi_stream::ArrowArrayStreamReader;
-use arrow::pyarrow::{FromPyArrow, PyArrowException, PyArrowType, ToPyArrow};
+use arrow::pyarrow::{to_pycapsule_stream, FromPyArrow, PyArrowException, PyArrowType, ToPyArrow};
 use arrow::record_batch::RecordBatch;
+use parquet_variant_compute::{batch_json_string_to_variant, VariantArray};
+use parquet_variant_json::variant_to_json_string;
 
 fn to_py_err(err: ArrowError) -> PyErr {
     PyArrowException::new_err(err.to_string())
@@ -161,6 +164,43 @@ fn boxed_reader_roundtrip(
     PyArrowType(reader)
 }
 
+/// Re-exports a stream as an `arrow_array_stream` PyCapsule
+#[pyfunction]
+#[pyo3(signature = (obj, requested_schema=None))]
+fn stream_to_pycapsule<'py>(
+    py: Python<'py>,
+    obj: PyArrowType<ArrowArrayStreamReader>,
+    requested_schema: Option<Bound<'py, PyCapsule>>,
+) -> PyResult<Bound<'py, PyCapsule>> {
+    let reader: Box<dyn RecordBatchReader + Send> = Box::new(obj.0);
+    to_pycapsule_stream(py, reader, requested_schema.as_ref())
+}
+
+/// Converts a variant struct array, e.g. produced by PySpark, to JSON strings
+#[pyfunction]
+fn variant_to_json(array: PyArrowType<ArrayData>) -> PyResult<PyArrowType<ArrayData>> {
+    let array = VariantArray::try_new(make_array(array.0)).map_err(to_py_err)?;
+    let json = (0..array.len())
+        .map(|i| {
+            array
+                .is_valid(i)
+                .then(|| variant_to_json_string(&array.value(i)))
+                .transpose()
+        })
+        .collect::<Result<StringArray, _>>()
+        .map_err(to_py_err)?;
+    Ok(PyArrowType(json.into_data()))
+}
+
+/// Parses JSON strings into a record batch with a single variant column
+#[pyfunction]
+fn json_to_variant(array: PyArrowType<ArrayData>) -> PyResult<PyArrowType<RecordBatch>> {
+    let array = batch_json_string_to_variant(&make_array(array.0)).map_err(to_py_err)?;
+    let schema = Schema::new(vec![array.field("variant")]);
+    let batch = RecordBatch::try_new(Arc::new(schema), vec![Arc::new(array)]).map_err(to_py_err)?;
+    Ok(PyArrowType(batch))
+}
+
 #[pymodule]
 fn arrow_pyarrow_integration_testing(_py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(double))?;
@@ -176,5 +216,8 @@ fn arrow_pyarrow_integration_testing(_py: Python, m: &Bound<PyModule>) -> PyResu
     m.add_wrapped(wrap_pyfunction!(round_trip_record_batch_reader))?;
     m.add_wrapped(wrap_pyfunction!(reader_return_errors))?;
     m.add_wrapped(wrap_pyfunction!(boxed_reader_roundtrip))?;
+    m.add_wrapped(wrap_pyfunction!(stream_to_pycapsule))?;
+    m.add_wrapped(wrap_pyfunction!(variant_to_json))?;
+    m.add_wrapped(wrap_pyfunction!(json_to_variant))?;
     Ok(())
 }
@@ -28,6 +28,7 @@ import pytz
 import arrow_pyarrow_integration_testing as rust
 
 PYARROW_PRE_14 = int(pa.__version__.split('.')[0]) < 14
+PYARROW_PRE_15 = int(pa.__version__.split('.')[0]) < 15
 
 
 @contextlib.contextmanager
@@ -553,6 +554,93 @@ def test_record_batch_reader_pycapsule():
     assert got_batches == batches
 
 
+class RustStreamWrapper:
+    def __init__(self, stream):
+        self.stream = stream
+
+    def __arrow_c_stream__(self, requested_schema=None):
+        return rust.stream_to_pycapsule(self.stream, requested_schema)
+
+
+@pytest.mark.skipif(PYARROW_PRE_15, reason="requires pyarrow 15")
+def test_record_batch_reader_to_pycapsule_extension():
+    """
+    Python -> Rust -> PyCapsule -> Python, preserving extension types
+    """
+    schema = pa.schema([
+        pa.field('json', pa.string(), metadata={
+            b'ARROW:extension:name': b'arrow.json',
+            b'ARROW:extension:metadata': b'',
+        }),
+        ('ints', pa.int32()),
+    ])
+    batches = [
+        pa.record_batch([['{"a": 1}', 'null'], [1, 2]], schema),
+    ]
+    a = pa.RecordBatchReader.from_batches(schema, batches)
+    b = pa.RecordBatchReader.from_stream(RustStreamWrapper(a))
+
+    assert b.schema == schema
+    assert b.schema.field('json').metadata == schema.field('json').metadata
+    assert list(b) == batches
+
+
+@pytest.mark.skipif(PYARROW_PRE_15, reason="requires pyarrow 15")
+def test_record_batch_reader_to_pycapsule_requested_extension():
+    """
+    Storage arrays are annotated with extension types requested by the consumer
+    """
+    storage_schema = pa.schema([('json', pa.string())])
+    extension_field = pa.field('json', pa.string(), metadata={
+        b'ARROW:extension:name': b'arrow.json',
+        b'ARROW:extension:metadata': b'',
+    })
+    requested = pa.schema([extension_field])
+    batch = pa.record_batch([['{"a": 1}']], storage_schema)
+
+    a = pa.RecordBatchReader.from_batches(storage_schema, [batch])
+    capsule = RustStreamWrapper(a).__arrow_c_stream__(requested.__arrow_c_schema__())
+    b = pa.RecordBatchReader._import_from_c_capsule(capsule)
+
+    assert b.schema.field('json').metadata == extension_field.metadata
+    assert b.read_all().column(0).to_pylist() == ['{"a": 1}']
+
+
+def test_variant_binary_python():
+    """
+    Python -> Rust, consuming a variant struct of binary fields as produced by PySpark
+    """
+    # metadata: version 1 with an empty dictionary
+    metadata = b'\x01\x00\x00'
+    variant_type = pa.struct([
+        pa.field('metadata', pa.binary(), nullable=False),
+        pa.field('value', pa.binary(), nullable=False),
+    ])
+    a = pa.array([
+        {'metadata': metadata, 'value': b'\x0c\x2a'},  # int8 42
+        {'metadata': metadata, 'value': b'\x09hi'},  # short string "hi"
+        None,
+    ], variant_type)
+    b = rust.variant_to_json(a)
+    assert b.to_pylist() == ['42', '"hi"', None]
+
+
+@pytest.mark.skipif(PYARROW_PRE_15, reason="requires pyarrow 15")
+def test_variant_extension_rust():
+    """
+    Rust -> Python -> Rust, preserving the variant extension type
+    """
+    a = pa.array(['{"a": 1}', '[true, null]', None])
+    batch = rust.json_to_variant(a)
+
+    field = batch.schema.field('variant')
+    assert field.metadata[b'ARROW:extension:name'] == b'arrow.parquet.variant'
+    assert pa.types.is_struct(field.type)
+
+    b = rust.variant_to_json(batch.column(0))
+    assert b.to_pylist() == ['{"a":1}', '[true,null]', None]
+
+
 def test_record_batch_reader_error():
     schema = pa.schema([('ints', pa.list_(pa.int32()))])
 
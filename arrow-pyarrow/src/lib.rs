@@ -55,8 +55,20 @@
 //! `Vec<RecordBatch>`. A `pyarrow.Table` can be imported to Rust by calling
 //! [pyarrow.Table.to_reader()](https://arrow.apache.org/docs/python/generated/pyarrow.Table.html#pyarrow.Table.to_reader)
 //! and then importing the reader as a [ArrowArrayStreamReader].
+//!
+//! # PyCapsule Interface
+//!
+//! Python libraries implementing the [Arrow PyCapsule Interface] can be exported to
+//! without depending on `pyarrow`, by returning the result of [to_pycapsule_stream]
+//! from a `__arrow_c_stream__` method. Field metadata, including
+//! [extension type](arrow_schema::extension) annotations such as `arrow.uuid` or
+//! `arrow.json`, is exported as part of the stream's schema so consumers receive
+//! extension arrays rather than bare storage arrays.
+//!
+//! [Arrow PyCapsule Interface]: https://arrow.apache.org/docs/format/CDataInterface/PyCapsuleInterface.html
 
 use std::convert::{From, TryFrom};
+use std::ffi::CString;
 use std::ptr::{addr_of, addr_of_mut};
 use std::sync::Arc;
 
@@ -68,7 +80,8 @@ use arrow_array::{
     StructArray,
 };
 use arrow_data::ArrayData;
-use arrow_schema::{ArrowError, DataType, Field, Schema};
+use arrow_schema::extension::{EXTENSION_TYPE_METADATA_KEY, EXTENSION_TYPE_NAME_KEY};
+use arrow_schema::{ArrowError, DataType, Field, Schema, SchemaRef};
 use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::ffi::Py_uintptr_t;
 use pyo3::import_exception;
@@ -484,6 +497,93 @@ impl IntoPyArrow for ArrowArrayStreamReader {
     }
 }
 
+/// Export a [`RecordBatchReader`] as an `arrow_array_stream` [`PyCapsule`]
+///
+/// This implements the producer side of the [Arrow PyCapsule Interface], and the
+/// returned capsule is intended to be returned from a `__arrow_c_stream__` method.
+///
+/// If `requested_schema` is provided, it must be an `arrow_schema` [`PyCapsule`].
+/// Fields of the requested schema that carry extension type metadata, and whose
+/// storage type matches that of the corresponding field of `reader`, are exported
+/// with that extension metadata. Other differences from the requested schema are
+/// ignored, which is permitted by the PyCapsule Interface.
+///
+/// ```ignore
+/// #[pymethods]
+/// impl MyStream {
+///     #[pyo3(signature = (requested_schema=None))]
+///     fn __arrow_c_stream__<'py>(
+///         &self,
+///         py: Python<'py>,
+///         requested_schema: Option<Bound<'py, PyCapsule>>,
+///     ) -> PyResult<Bound<'py, PyCapsule>> {
+///         to_pycapsule_stream(py, self.reader(), requested_schema.as_ref())
+///     }
+/// }
+/// ```
+///
+/// [Arrow PyCapsule Interface]: https://arrow.apache.org/docs/format/CDataInterface/PyCapsuleInterface.html
+pub fn to_pycapsule_stream<'py>(
+    py: Python<'py>,
+    reader: Box<dyn RecordBatchReader + Send>,
+    requested_schema: Option<&Bound<'py, PyCapsule>>,
+) -> PyResult<Bound<'py, PyCapsule>> {
+    let reader = match requested_schema {
+        Some(capsule) => {
+            validate_pycapsule(capsule, "arrow_schema")?;
+            let schema_ptr = unsafe { capsule.reference::<FFI_ArrowSchema>() };
+            let requested = Schema::try_from(schema_ptr).map_err(to_py_err)?;
+            with_requested_extensions(reader, &requested)
+        }
+        None => reader,
+    };
+
+    let stream = FFI_ArrowArrayStream::new(reader);
+    let name = CString::new("arrow_array_stream").unwrap();
+    PyCapsule::new(py, stream, Some(name))
+}
+
+/// Annotates the fields of `reader` with the extension type metadata of the
+/// corresponding fields in `requested`, where their storage types match
+fn with_requested_extensions(
+    reader: Box<dyn RecordBatchReader + Send>,
+    requested: &Schema,
+) -> Box<dyn RecordBatchReader + Send> {
+    let schema = reader.schema();
+    if schema.fields().len() != requested.fields().len() {
+        return reader;
+    }
+
+    let mut annotated = false;
+    let fields = schema
+        .fields()
+        .iter()
+        .zip(requested.fields())
+        .map(|(field, requested)| {
+            let is_extension = requested.metadata().contains_key(EXTENSION_TYPE_NAME_KEY);
+            if !is_extension || field.data_type() != requested.data_type() {
+                return Arc::clone(field);
+            }
+            let mut metadata = field.metadata().clone();
+            for key in [EXTENSION_TYPE_NAME_KEY, EXTENSION_TYPE_METADATA_KEY] {
+                if let Some(v) = requested.metadata().get(key) {
+                    annotated |= metadata.insert(key.to_string(), v.clone()).as_ref() != Some(v);
+                }
+            }
+            Arc::new(field.as_ref().clone().with_metadata(metadata))
+        })
+        .collect::<Vec<_>>();
+
+    if !annotated {
+        return reader;
+    }
+
+    let schema: SchemaRef = Arc::new(Schema::new_with_metadata(fields, schema.metadata().clone()));
+    let batches_schema = Arc::clone(&schema);
+    let batches = reader.map(move |batch| batch?.with_schema(Arc::clone(&batches_schema)));
+    Box::new(RecordBatchIterator::new(batches, schema))
+}
+
 /// A newtype wrapper for types implementing [`FromPyArrow`] or [`IntoPyArrow`].
 ///
 /// When wrapped around a type `T: FromPyArrow`, it
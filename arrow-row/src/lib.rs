@@ -461,7 +461,10 @@ impl Codec {
                 Ok(Self::RunEndEncoded(converter))
             }
             d if !d.is_nested() => Ok(Self::Stateless),
-            DataType::List(f) | DataType::LargeList(f) => {
+            DataType::List(f)
+            | DataType::LargeList(f)
+            | DataType::ListView(f)
+            | DataType::LargeListView(f) => {
                 // The encoded contents will be inverted if descending is set to true
                 // As such we set `descending` to false and negate nulls first if it
                 // it set to true
@@ -520,6 +523,8 @@ impl Codec {
                 let values = match array.data_type() {
                     DataType::List(_) => as_list_array(array).values(),
                     DataType::LargeList(_) => as_large_list_array(array).values(),
+                    DataType::ListView(_) => array.as_list_view::<i32>().values(),
+                    DataType::LargeListView(_) => array.as_list_view::<i64>().values(),
                     DataType::FixedSizeList(_, _) => as_fixed_size_list_array(array).values(),
                     _ => unreachable!(),
                 };
@@ -623,9 +628,11 @@ impl RowConverter {
     fn supports_datatype(d: &DataType) -> bool {
         match d {
             _ if !d.is_nested() => true,
-            DataType::List(f) | DataType::LargeList(f) | DataType::FixedSizeList(f, _) => {
-                Self::supports_datatype(f.data_type())
-            }
+            DataType::List(f)
+            | DataType::LargeList(f)
+            | DataType::ListView(f)
+            | DataType::LargeListView(f)
+            | DataType::FixedSizeList(f, _) => Self::supports_datatype(f.data_type()),
             DataType::Struct(f) => f.iter().all(|x| Self::supports_datatype(x.data_type())),
             DataType::RunEndEncoded(_, values) => Self::supports_datatype(values.data_type()),
             _ => false,
@@ -1434,6 +1441,16 @@ fn row_lengths(cols: &[ArrayRef], encoders: &[Encoder]) -> LengthTracker {
                 DataType::LargeList(_) => {
                     list::compute_lengths(tracker.materialized(), rows, as_large_list_array(array))
                 }
+                DataType::ListView(_) => list::compute_lengths_list_view(
+                    tracker.materialized(),
+                    rows,
+                    array.as_list_view::<i32>(),
+                ),
+                DataType::LargeListView(_) => list::compute_lengths_list_view(
+                    tracker.materialized(),
+                    rows,
+                    array.as_list_view::<i64>(),
+                ),
                 DataType::FixedSizeList(_, _) => compute_lengths_fixed_size_list(
                     &mut tracker,
                     rows,
@@ -1556,6 +1573,12 @@ fn encode_column(
             DataType::LargeList(_) => {
                 list::encode(data, offsets, rows, opts, as_large_list_array(column))
             }
+            DataType::ListView(_) => {
+                list::encode_list_view(data, offsets, rows, opts, column.as_list_view::<i32>())
+            }
+            DataType::LargeListView(_) => {
+                list::encode_list_view(data, offsets, rows, opts, column.as_list_view::<i64>())
+            }
             DataType::FixedSizeList(_, _) => {
                 encode_fixed_size_list(data, offsets, rows, opts, as_fixed_size_list_array(column))
             }
@@ -1659,6 +1682,18 @@ unsafe fn decode_column(
             DataType::LargeList(_) => {
                 Arc::new(list::decode::<i64>(converter, rows, field, validate_utf8)?)
             }
+            DataType::ListView(_) => Arc::new(list::decode_list_view::<i32>(
+                converter,
+                rows,
+                field,
+                validate_utf8,
+            )?),
+            DataType::LargeListView(_) => Arc::new(list::decode_list_view::<i64>(
+                converter,
+                rows,
+                field,
+                validate_utf8,
+            )?),
             DataType::FixedSizeList(_, value_length) => Arc::new(list::decode_fixed_size_list(
                 converter,
                 rows,
@@ -2462,6 +2497,68 @@ mod tests {
         test_nested_list::<i64>();
     }
 
+    fn test_list_view_generic<O: OffsetSizeTrait>() {
+        let field = Arc::new(Field::new_list_field(DataType::Int32, true));
+        // [[3, 4], null, [1], [1, 2, 3], [], [4, null]]
+        let values = Int32Array::from(vec![Some(1), Some(2), Some(3), Some(4), None]);
+        let list_view = GenericListViewArray::<O>::new(
+            field.clone(),
+            [2, 1, 0, 0, 4, 3].into_iter().map(O::usize_as).collect(),
+            [2, 2, 1, 3, 0, 2].into_iter().map(O::usize_as).collect(),
+            Arc::new(values),
+            Some(NullBuffer::from(vec![true, false, true, true, true, true])),
+        );
+        let list_view = Arc::new(list_view) as ArrayRef;
+
+        let mut builder = GenericListBuilder::<O, _>::new(Int32Builder::new()).with_field(field);
+        builder.append_value([Some(3), Some(4)]);
+        builder.append_null();
+        builder.append_value([Some(1)]);
+        builder.append_value([Some(1), Some(2), Some(3)]);
+        builder.append_value([]);
+        builder.append_value([Some(4), None]);
+        let list = Arc::new(builder.finish()) as ArrayRef;
+
+        for options in [
+            SortOptions::default(),
+            SortOptions::default().desc(),
+            SortOptions::default().with_nulls_first(false),
+        ] {
+            let converter = RowConverter::new(vec![SortField::new_with_options(
+                list_view.data_type().clone(),
+                options,
+            )])
+            .unwrap();
+            let rows = converter
+                .convert_columns(&[Arc::clone(&list_view)])
+                .unwrap();
+
+            // The row encoding is the same as that of the equivalent list
+            let list_converter = RowConverter::new(vec![SortField::new_with_options(
+                list.data_type().clone(),
+                options,
+            )])
+            .unwrap();
+            let list_rows = list_converter
+                .convert_columns(&[Arc::clone(&list)])
+                .unwrap();
+            for i in 0..list.len() {
+                assert_eq!(rows.row(i).as_ref(), list_rows.row(i).as_ref());
+            }
+
+            let back = converter.convert_rows(&rows).unwrap();
+            assert_eq!(back.len(), 1);
+            back[0].to_data().validate_full().unwrap();
+            assert_eq!(&back[0], &list_view);
+        }
+    }
+
+    #[test]
+    fn test_list_view() {
+        test_list_view_generic::<i32>();
+        test_list_view_generic::<i64>();
+    }
+
     #[test]
     fn test_fixed_size_list() {
         let mut builder = FixedSizeListBuilder::new(Int32Builder::new(), 3);
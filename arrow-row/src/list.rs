@@ -16,7 +16,10 @@
 // under the License.
 
 use crate::{fixed, null_sentinel, LengthTracker, RowConverter, Rows, SortField};
-use arrow_array::{new_null_array, Array, FixedSizeListArray, GenericListArray, OffsetSizeTrait};
+use arrow_array::{
+    new_null_array, Array, FixedSizeListArray, GenericListArray, GenericListViewArray,
+    OffsetSizeTrait,
+};
 use arrow_buffer::{ArrowNativeType, Buffer, MutableBuffer};
 use arrow_data::ArrayDataBuilder;
 use arrow_schema::{ArrowError, DataType, SortOptions};
@@ -185,6 +188,84 @@ pub unsafe fn decode<O: OffsetSizeTrait>(
     Ok(GenericListArray::from(unsafe { builder.build_unchecked() }))
 }
 
+pub fn compute_lengths_list_view<O: OffsetSizeTrait>(
+    lengths: &mut [usize],
+    rows: &Rows,
+    array: &GenericListViewArray<O>,
+) {
+    lengths.iter_mut().enumerate().for_each(|(idx, length)| {
+        let range = array.is_valid(idx).then(|| list_view_range(array, idx));
+        *length += encoded_len(rows, range);
+    });
+}
+
+/// Encodes the provided `GenericListViewArray` to `out` with the provided `SortOptions`
+///
+/// The encoding is identical to that of a `GenericListArray` with the same logical values
+///
+/// `rows` should contain the encoded child elements
+pub fn encode_list_view<O: OffsetSizeTrait>(
+    data: &mut [u8],
+    offsets: &mut [usize],
+    rows: &Rows,
+    opts: SortOptions,
+    array: &GenericListViewArray<O>,
+) {
+    offsets
+        .iter_mut()
+        .skip(1)
+        .enumerate()
+        .for_each(|(idx, offset)| {
+            let range = array.is_valid(idx).then(|| list_view_range(array, idx));
+            let out = &mut data[*offset..];
+            *offset += encode_one(out, rows, range, opts)
+        });
+}
+
+fn list_view_range<O: OffsetSizeTrait>(
+    array: &GenericListViewArray<O>,
+    idx: usize,
+) -> Range<usize> {
+    let start = array.value_offset(idx).as_usize();
+    start..start + array.value_size(idx).as_usize()
+}
+
+/// Decodes a `GenericListViewArray` from `rows` with the provided `options`
+///
+/// # Safety
+///
+/// `rows` must contain valid data for the provided `converter`
+pub unsafe fn decode_list_view<O: OffsetSizeTrait>(
+    converter: &RowConverter,
+    rows: &mut [&[u8]],
+    field: &SortField,
+    validate_utf8: bool,
+) -> Result<GenericListViewArray<O>, ArrowError> {
+    let (DataType::ListView(f) | DataType::LargeListView(f)) = &field.data_type else {
+        unreachable!()
+    };
+    let list_field = SortField::new_with_options(
+        DataType::new_large_list(f.data_type().clone(), f.is_nullable()),
+        field.options,
+    );
+    let list = decode::<i64>(converter, rows, &list_field, validate_utf8)?;
+
+    let (_, list_offsets, values, nulls) = list.into_parts();
+    let sizes = list_offsets.lengths().map(O::usize_as).collect::<Vec<_>>();
+    let offsets = list_offsets[..sizes.len()]
+        .iter()
+        .map(|x| O::usize_as(x.as_usize()))
+        .collect::<Vec<_>>();
+
+    Ok(GenericListViewArray::new(
+        f.clone(),
+        offsets.into(),
+        sizes.into(),
+        values,
+        nulls,
+    ))
+}
+
 pub fn compute_lengths_fixed_size_list(
     tracker: &mut LengthTracker,
     rows: &Rows,
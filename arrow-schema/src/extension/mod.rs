@@ -23,6 +23,8 @@
 mod canonical;
 #[cfg(feature = "canonical_extension_types")]
 pub use canonical::*;
+mod registry;
+pub use registry::*;
 
 use crate::{ArrowError, DataType};
 
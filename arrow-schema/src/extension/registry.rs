@@ -0,0 +1,329 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Runtime registry of [`ExtensionType`]s.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::{Arc, OnceLock, RwLock};
+
+use crate::{ArrowError, DataType, Field, Schema};
+
+use super::ExtensionType;
+
+/// A type-erased [`ExtensionType`], as returned by [`ExtensionTypeRegistry::resolve`].
+///
+/// This is implemented for all [`ExtensionType`]s that are [`Debug`], [`Send`] and [`Sync`].
+/// Use [`Self::as_any`] to downcast to the concrete extension type.
+pub trait DynExtensionType: Debug + Send + Sync {
+    /// Returns the name of this extension type, see [`ExtensionType::NAME`].
+    fn name(&self) -> &'static str;
+
+    /// Returns the serialized metadata of this extension type, see
+    /// [`ExtensionType::serialize_metadata`].
+    fn serialized_metadata(&self) -> Option<String>;
+
+    /// Returns `Ok(())` iff the given data type is supported by this extension
+    /// type, see [`ExtensionType::supports_data_type`].
+    fn validate_data_type(&self, data_type: &DataType) -> Result<(), ArrowError>;
+
+    /// Returns this extension type as [`Any`] so that it can be downcast to
+    /// the concrete type.
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl<E: ExtensionType + Debug + Send + Sync + 'static> DynExtensionType for E {
+    fn name(&self) -> &'static str {
+        E::NAME
+    }
+
+    fn serialized_metadata(&self) -> Option<String> {
+        self.serialize_metadata()
+    }
+
+    fn validate_data_type(&self, data_type: &DataType) -> Result<(), ArrowError> {
+        self.supports_data_type(data_type)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// A function constructing an extension type from the data type and serialized
+/// metadata of a [`Field`].
+pub type ExtensionTypeFactory = Arc<
+    dyn Fn(&DataType, Option<&str>) -> Result<Arc<dyn DynExtensionType>, ArrowError> + Send + Sync,
+>;
+
+/// A registry of extension types, keyed by their [`ExtensionType::NAME`].
+///
+/// Formats such as IPC, Flight and Parquet only preserve the storage type and
+/// [`Field::metadata`] of extension types. A registry resolves the
+/// [`EXTENSION_TYPE_NAME_KEY`](super::EXTENSION_TYPE_NAME_KEY) of such a field into the
+/// corresponding extension type.
+///
+/// The readers of these formats do not consult a registry themselves, the schemas they
+/// return contain the storage types along with the extension metadata. Use
+/// [`Self::resolve_schema`] to resolve the extension types of the top-level fields of
+/// such a schema.
+///
+/// A registry can either be constructed and passed around explicitly, or the
+/// process-wide registry returned by [`ExtensionTypeRegistry::global`] can be used.
+///
+/// ```
+/// # #[cfg(feature = "canonical_extension_types")]
+/// # {
+/// # use arrow_schema::extension::{ExtensionType, ExtensionTypeRegistry, Uuid};
+/// # use arrow_schema::{DataType, Field};
+/// let mut registry = ExtensionTypeRegistry::new();
+/// registry.register::<Uuid>();
+///
+/// let field = Field::new("id", DataType::FixedSizeBinary(16), false).with_extension_type(Uuid);
+/// let extension = registry.resolve(&field).unwrap().unwrap();
+/// assert_eq!(extension.name(), Uuid::NAME);
+/// assert!(extension.as_any().downcast_ref::<Uuid>().is_some());
+///
+/// // Fields without a registered extension type resolve to `None`
+/// let field = Field::new("id", DataType::FixedSizeBinary(16), false);
+/// assert!(registry.resolve(&field).unwrap().is_none());
+/// # }
+/// ```
+#[derive(Clone, Default)]
+pub struct ExtensionTypeRegistry {
+    factories: HashMap<String, ExtensionTypeFactory>,
+}
+
+impl Debug for ExtensionTypeRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut names: Vec<_> = self.factories.keys().collect();
+        names.sort_unstable();
+        f.debug_struct("ExtensionTypeRegistry")
+            .field("names", &names)
+            .finish()
+    }
+}
+
+impl ExtensionTypeRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a registry containing all the canonical extension types.
+    #[cfg(feature = "canonical_extension_types")]
+    pub fn with_canonical_types() -> Self {
+        use super::{Bool8, FixedShapeTensor, Json, Opaque, Uuid, VariableShapeTensor};
+
+        let mut registry = Self::new();
+        registry.register::<FixedShapeTensor>();
+        registry.register::<VariableShapeTensor>();
+        registry.register::<Json>();
+        registry.register::<Uuid>();
+        registry.register::<Opaque>();
+        registry.register::<Bool8>();
+        registry
+    }
+
+    /// Returns the process-wide registry.
+    ///
+    /// This initially contains the canonical extension types if the
+    /// `canonical_extension_types` feature is enabled, and is otherwise empty.
+    /// Crates defining their own extension types can register them here to make
+    /// them available to all readers using the global registry.
+    pub fn global() -> &'static RwLock<ExtensionTypeRegistry> {
+        static GLOBAL: OnceLock<RwLock<ExtensionTypeRegistry>> = OnceLock::new();
+        GLOBAL.get_or_init(|| {
+            #[cfg(feature = "canonical_extension_types")]
+            let registry = Self::with_canonical_types();
+            #[cfg(not(feature = "canonical_extension_types"))]
+            let registry = Self::new();
+            RwLock::new(registry)
+        })
+    }
+
+    /// Registers the extension type `E`, replacing any extension type
+    /// previously registered with the same name.
+    ///
+    /// Returns `true` if an extension type with this name was already registered.
+    pub fn register<E: ExtensionType + Debug + Send + Sync + 'static>(&mut self) -> bool {
+        self.register_factory(
+            E::NAME,
+            Arc::new(|data_type, metadata| {
+                let metadata = E::deserialize_metadata(metadata)?;
+                Ok(Arc::new(E::try_new(data_type, metadata)?))
+            }),
+        )
+    }
+
+    /// Registers `factory` for the extension type `name`, replacing any
+    /// extension type previously registered with the same name.
+    ///
+    /// This allows registering extension types that are not known at compile
+    /// time. Returns `true` if an extension type with this name was already
+    /// registered.
+    pub fn register_factory(
+        &mut self,
+        name: impl Into<String>,
+        factory: ExtensionTypeFactory,
+    ) -> bool {
+        self.factories.insert(name.into(), factory).is_some()
+    }
+
+    /// Removes the extension type `name`, returning `true` if it was registered.
+    pub fn unregister(&mut self, name: &str) -> bool {
+        self.factories.remove(name).is_some()
+    }
+
+    /// Returns `true` if an extension type is registered with `name`.
+    pub fn contains(&self, name: &str) -> bool {
+        self.factories.contains_key(name)
+    }
+
+    /// Returns an iterator over the names of the registered extension types.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.factories.keys().map(String::as_str)
+    }
+
+    /// Resolves the extension type of `field`.
+    ///
+    /// Returns `Ok(None)` if `field` has no extension type name, or if no
+    /// extension type is registered with that name. Returns an error if the
+    /// extension type is registered but cannot be constructed from the field's
+    /// metadata and data type.
+    pub fn resolve(&self, field: &Field) -> Result<Option<Arc<dyn DynExtensionType>>, ArrowError> {
+        let Some(name) = field.extension_type_name() else {
+            return Ok(None);
+        };
+        match self.factories.get(name) {
+            Some(factory) => factory(field.data_type(), field.extension_type_metadata()).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Resolves the extension types of the top-level fields of `schema`, see [`Self::resolve`].
+    ///
+    /// Returns one entry per field of `schema`, in order.
+    pub fn resolve_schema(
+        &self,
+        schema: &Schema,
+    ) -> Result<Vec<Option<Arc<dyn DynExtensionType>>>, ArrowError> {
+        schema.fields().iter().map(|f| self.resolve(f)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extension::EXTENSION_TYPE_NAME_KEY;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Point;
+
+    impl ExtensionType for Point {
+        const NAME: &'static str = "myorg.point";
+
+        type Metadata = ();
+
+        fn metadata(&self) -> &Self::Metadata {
+            &()
+        }
+
+        fn serialize_metadata(&self) -> Option<String> {
+            None
+        }
+
+        fn deserialize_metadata(_metadata: Option<&str>) -> Result<Self::Metadata, ArrowError> {
+            Ok(())
+        }
+
+        fn supports_data_type(&self, data_type: &DataType) -> Result<(), ArrowError> {
+            match data_type {
+                DataType::FixedSizeList(_, 2) => Ok(()),
+                data_type => Err(ArrowError::InvalidArgumentError(format!(
+                    "Point data type mismatch, expected FixedSizeList(2), found {data_type}"
+                ))),
+            }
+        }
+
+        fn try_new(data_type: &DataType, _metadata: Self::Metadata) -> Result<Self, ArrowError> {
+            Self.supports_data_type(data_type).map(|_| Self)
+        }
+    }
+
+    fn point_field(data_type: DataType) -> Field {
+        Field::new("p", data_type, true).with_metadata(
+            [(EXTENSION_TYPE_NAME_KEY.to_owned(), Point::NAME.to_owned())]
+                .into_iter()
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn register_and_resolve() {
+        let mut registry = ExtensionTypeRegistry::new();
+        assert!(!registry.register::<Point>());
+        assert!(registry.register::<Point>());
+        assert!(registry.contains(Point::NAME));
+        assert_eq!(registry.names().collect::<Vec<_>>(), &[Point::NAME]);
+
+        let list = DataType::new_fixed_size_list(DataType::Float64, 2, false);
+        let resolved = registry
+            .resolve(&point_field(list.clone()))
+            .unwrap()
+            .unwrap();
+        assert_eq!(resolved.name(), Point::NAME);
+        assert_eq!(resolved.as_any().downcast_ref::<Point>(), Some(&Point));
+        assert_eq!(resolved.serialized_metadata(), None);
+        assert!(resolved.validate_data_type(&DataType::Float64).is_err());
+
+        let err = registry
+            .resolve(&point_field(DataType::Float64))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Point data type mismatch, expected FixedSizeList(2), found Float64"
+        );
+
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Float64, true),
+            point_field(list),
+        ]);
+        let resolved = registry.resolve_schema(&schema).unwrap();
+        assert!(resolved[0].is_none());
+        assert_eq!(resolved[1].as_ref().unwrap().name(), Point::NAME);
+
+        assert!(registry.unregister(Point::NAME));
+        assert!(registry
+            .resolve(&point_field(DataType::Float64))
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn global() {
+        ExtensionTypeRegistry::global()
+            .write()
+            .unwrap()
+            .register::<Point>();
+        let registry = ExtensionTypeRegistry::global().read().unwrap();
+        assert!(registry.contains(Point::NAME));
+        #[cfg(feature = "canonical_extension_types")]
+        assert!(registry.contains(crate::extension::Uuid::NAME));
+    }
+}
@@ -24,7 +24,7 @@ use std::sync::Arc;
 use crate::datatype::DataType;
 #[cfg(feature = "canonical_extension_types")]
 use crate::extension::CanonicalExtensionType;
-use crate::schema::SchemaBuilder;
+use crate::schema::{promote_types, MergeOptions, SchemaBuilder};
 use crate::{
     extension::{ExtensionType, EXTENSION_TYPE_METADATA_KEY, EXTENSION_TYPE_NAME_KEY},
     Fields, UnionFields, UnionMode,
@@ -695,6 +695,27 @@ impl Field {
     /// assert!(field.is_nullable());
     /// ```
     pub fn try_merge(&mut self, from: &Field) -> Result<(), ArrowError> {
+        self.try_merge_with_options(from, &MergeOptions::default())
+    }
+
+    /// Merge this field into self as by [`Field::try_merge`], using the provided
+    /// [`MergeOptions`] to resolve metadata conflicts, differing nullability and
+    /// differing types
+    ///
+    /// NOTE: `self` may be updated to a partial / unexpected state in case of merge failure.
+    ///
+    /// ```
+    /// # use arrow_schema::*;
+    /// let options = MergeOptions::default().with_promote_types(true);
+    /// let mut field = Field::new("c1", DataType::Utf8, false);
+    /// field.try_merge_with_options(&Field::new("c1", DataType::LargeUtf8, false), &options).unwrap();
+    /// assert_eq!(field.data_type(), &DataType::LargeUtf8);
+    /// ```
+    pub fn try_merge_with_options(
+        &mut self,
+        from: &Field,
+        options: &MergeOptions,
+    ) -> Result<(), ArrowError> {
         #[allow(deprecated)]
         if from.dict_id != self.dict_id {
             return Err(ArrowError::SchemaError(format!(
@@ -708,6 +729,13 @@ impl Field {
                 self.name, from.dict_is_ordered, self.dict_is_ordered
             )));
         }
+        // Checked before any of the branches below updates the nullability of self
+        if !options.widen_nullability && self.nullable != from.nullable {
+            return Err(ArrowError::SchemaError(format!(
+                "Fail to merge schema field '{}' because the from nullable = {} does not equal {}",
+                self.name, from.nullable, self.nullable
+            )));
+        }
         // merge metadata
         match (self.metadata().is_empty(), from.metadata().is_empty()) {
             (false, false) => {
@@ -715,10 +743,16 @@ impl Field {
                 for (key, from_value) in from.metadata() {
                     if let Some(self_value) = self.metadata.get(key) {
                         if self_value != from_value {
-                            return Err(ArrowError::SchemaError(format!(
-                                "Fail to merge field '{}' due to conflicting metadata data value for key {}.
-                                    From value = {} does not match {}", self.name, key, from_value, self_value),
-                            ));
+                            let Some(value) = options
+                                .metadata_conflict
+                                .resolve(key, self_value, from_value)
+                            else {
+                                return Err(ArrowError::SchemaError(format!(
+                                    "Fail to merge field '{}' due to conflicting metadata data value for key {}.
+                                        From value = {} does not match {}", self.name, key, from_value, self_value),
+                                ));
+                            };
+                            merged.insert(key.clone(), value);
                         }
                     } else {
                         merged.insert(key.clone(), from_value.clone());
@@ -735,7 +769,7 @@ impl Field {
             DataType::Struct(nested_fields) => match &from.data_type {
                 DataType::Struct(from_nested_fields) => {
                     let mut builder = SchemaBuilder::new();
-                    nested_fields.iter().chain(from_nested_fields).try_for_each(|f| builder.try_merge(f))?;
+                    nested_fields.iter().chain(from_nested_fields).try_for_each(|f| builder.try_merge_with_options(f, options))?;
                     *nested_fields = builder.finish().fields;
                 }
                 _ => {
@@ -758,7 +792,7 @@ impl Field {
             DataType::List(field) => match &from.data_type {
                 DataType::List(from_field) => {
                     let mut f = (**field).clone();
-                    f.try_merge(from_field)?;
+                    f.try_merge_with_options(from_field, options)?;
                     (*field) = Arc::new(f);
                 },
                 _ => {
@@ -770,7 +804,7 @@ impl Field {
             DataType::LargeList(field) => match &from.data_type {
                 DataType::LargeList(from_field) => {
                     let mut f = (**field).clone();
-                    f.try_merge(from_field)?;
+                    f.try_merge_with_options(from_field, options)?;
                     (*field) = Arc::new(f);
                 },
                 _ => {
@@ -780,7 +814,7 @@ impl Field {
                 ))}
             },
             DataType::Null => {
-                self.nullable = true;
+                self.nullable |= options.widen_nullability;
                 self.data_type = from.data_type.clone();
             }
             | DataType::Boolean
@@ -820,12 +854,17 @@ impl Field {
             | DataType::Decimal128(_, _)
             | DataType::Decimal256(_, _) => {
                 if from.data_type == DataType::Null {
-                    self.nullable = true;
+                    self.nullable |= options.widen_nullability;
                 } else if self.data_type != from.data_type {
-                    return Err(ArrowError::SchemaError(
-                        format!("Fail to merge schema field '{}' because the from data_type = {} does not equal {}",
-                            self.name, from.data_type, self.data_type)
-                    ));
+                    match promote_types(&self.data_type, &from.data_type) {
+                        Some(promoted) if options.promote_types => self.data_type = promoted,
+                        _ => {
+                            return Err(ArrowError::SchemaError(
+                                format!("Fail to merge schema field '{}' because the from data_type = {} does not equal {}",
+                                    self.name, from.data_type, self.data_type)
+                            ));
+                        }
+                    }
                 }
             }
         }
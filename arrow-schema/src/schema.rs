@@ -15,16 +15,32 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
 use std::fmt;
 use std::hash::Hash;
 use std::sync::Arc;
 
 use crate::error::ArrowError;
+use crate::extension::{EXTENSION_TYPE_METADATA_KEY, EXTENSION_TYPE_NAME_KEY};
 use crate::field::Field;
 use crate::{DataType, FieldRef, Fields};
 
 /// A builder to facilitate building a [`Schema`] from iteratively from [`FieldRef`]
+///
+/// [`SchemaBuilder::try_finish`] can be used to validate the resulting schema,
+/// for example when it is assembled from user input
+///
+/// ```
+/// # use arrow_schema::{DataType, Field, SchemaBuilder, SchemaValidationError};
+/// let err = SchemaBuilder::new()
+///     .with_field(Field::new("a", DataType::Int32, false))
+///     .with_field(Field::new("a", DataType::Utf8, true))
+///     .try_finish()
+///     .unwrap_err();
+///
+/// assert_eq!(err, SchemaValidationError::DuplicateFieldName { path: "a".to_string() });
+/// ```
 #[derive(Debug, Default)]
 pub struct SchemaBuilder {
     fields: Vec<FieldRef>,
@@ -50,6 +66,20 @@ impl SchemaBuilder {
         self.fields.push(field.into())
     }
 
+    /// Appends a [`FieldRef`] to this [`SchemaBuilder`] and returns self
+    ///
+    /// Collisions are reported by [`Self::try_finish`]
+    pub fn with_field(mut self, field: impl Into<FieldRef>) -> Self {
+        self.push(field);
+        self
+    }
+
+    /// Sets the custom metadata `key` to `value` and returns self
+    pub fn with_metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.metadata.insert(key.into(), value.into());
+        self
+    }
+
     /// Removes and returns the [`FieldRef`] as index `idx`
     ///
     /// # Panics
@@ -96,15 +126,26 @@ impl SchemaBuilder {
     ///
     /// If an existing field exists with the same name, calls [`Field::try_merge`]
     pub fn try_merge(&mut self, field: &FieldRef) -> Result<(), ArrowError> {
+        self.try_merge_with_options(field, &MergeOptions::default())
+    }
+
+    /// Appends a [`FieldRef`] to this [`SchemaBuilder`] checking for collision
+    ///
+    /// If an existing field exists with the same name, calls [`Field::try_merge_with_options`]
+    pub fn try_merge_with_options(
+        &mut self,
+        field: &FieldRef,
+        options: &MergeOptions,
+    ) -> Result<(), ArrowError> {
         // This could potentially be sped up with a HashMap or similar
         let existing = self.fields.iter_mut().find(|f| f.name() == field.name());
         match existing {
             Some(e) if Arc::ptr_eq(e, field) => {} // Nothing to do
             Some(e) => match Arc::get_mut(e) {
-                Some(e) => e.try_merge(field.as_ref())?,
+                Some(e) => e.try_merge_with_options(field.as_ref(), options)?,
                 None => {
                     let mut t = e.as_ref().clone();
-                    t.try_merge(field)?;
+                    t.try_merge_with_options(field, options)?;
                     *e = Arc::new(t)
                 }
             },
@@ -120,6 +161,357 @@ impl SchemaBuilder {
             metadata: self.metadata,
         }
     }
+
+    /// Validates the fields and metadata of this [`SchemaBuilder`], see [`Self::try_finish`]
+    pub fn validate(&self) -> Result<(), SchemaValidationError> {
+        if let Some(key) = self
+            .metadata
+            .keys()
+            .find(|k| k.starts_with(RESERVED_PREFIX))
+        {
+            return Err(SchemaValidationError::ReservedMetadataKey {
+                path: None,
+                key: key.clone(),
+            });
+        }
+        let mut dictionaries = HashMap::new();
+        validate_fields(self.fields.iter(), None, &mut dictionaries)
+    }
+
+    /// Consume this [`SchemaBuilder`] yielding the final [`Schema`] if it is valid
+    ///
+    /// Returns an error if
+    /// - two sibling fields, at the top level or within a `Struct` or `Union`, have the same name
+    /// - the schema metadata has a key with the reserved `ARROW:` prefix, or field
+    ///   metadata has such a key other than the extension type keys
+    /// - two dictionary fields have the same explicitly assigned dictionary id,
+    ///   but different value types. Fields with the default id of `0` are not checked
+    /// - a field has extension type metadata but no extension type name, or an
+    ///   empty name. If the `canonical_extension_types` feature is enabled,
+    ///   canonical extension types must also be well-formed
+    pub fn try_finish(self) -> Result<Schema, SchemaValidationError> {
+        self.validate()?;
+        Ok(self.finish())
+    }
+}
+
+/// How to resolve a metadata key with different values when merging schemas or fields
+///
+/// See [`MergeOptions`]
+#[derive(Debug, Clone, Copy, Default)]
+pub enum MetadataConflictPolicy {
+    /// Return an error
+    #[default]
+    Error,
+    /// Keep the value already present in the merged output
+    KeepLeft,
+    /// Replace the value with the one being merged in
+    KeepRight,
+    /// Compute the value by calling the function with the key,
+    /// the existing value and the value being merged in
+    Combine(fn(&str, &str, &str) -> String),
+}
+
+impl MetadataConflictPolicy {
+    /// Resolves the conflicting `left` and `right` values for `key`,
+    /// returning `None` if the conflict should be reported as an error
+    pub(crate) fn resolve(&self, key: &str, left: &str, right: &str) -> Option<String> {
+        match self {
+            Self::Error => None,
+            Self::KeepLeft => Some(left.to_string()),
+            Self::KeepRight => Some(right.to_string()),
+            Self::Combine(f) => Some(f(key, left, right)),
+        }
+    }
+}
+
+/// Options for [`Schema::try_merge_with_options`] and [`Field::try_merge_with_options`]
+///
+/// The default options match the behaviour of [`Schema::try_merge`]
+///
+/// ```
+/// # use arrow_schema::{DataType, Field, MergeOptions, MetadataConflictPolicy, Schema};
+/// # use std::collections::HashMap;
+/// let options = MergeOptions::default()
+///     .with_metadata_conflict(MetadataConflictPolicy::KeepRight)
+///     .with_promote_types(true);
+///
+/// let left = Schema::new(vec![Field::new("a", DataType::Int32, false)])
+///     .with_metadata(HashMap::from([("k".to_string(), "1".to_string())]));
+/// let right = Schema::new(vec![Field::new("a", DataType::Int64, false)])
+///     .with_metadata(HashMap::from([("k".to_string(), "2".to_string())]));
+///
+/// let merged = Schema::try_merge_with_options([left, right], &options).unwrap();
+/// assert_eq!(merged.field(0).data_type(), &DataType::Int64);
+/// assert_eq!(merged.metadata()["k"], "2");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct MergeOptions {
+    /// How to resolve metadata keys with conflicting values,
+    /// defaults to [`MetadataConflictPolicy::Error`]
+    pub metadata_conflict: MetadataConflictPolicy,
+    /// If `true`, merging a nullable and a non-nullable field yields a nullable
+    /// field, otherwise this is an error. Defaults to `true`
+    pub widen_nullability: bool,
+    /// If `true`, fields with different but compatible types are merged to the
+    /// wider of the two types, e.g. `Int32` and `Int64` to `Int64`, or `Utf8`
+    /// and `LargeUtf8` to `LargeUtf8`. Defaults to `false`
+    pub promote_types: bool,
+}
+
+impl Default for MergeOptions {
+    fn default() -> Self {
+        Self {
+            metadata_conflict: MetadataConflictPolicy::Error,
+            widen_nullability: true,
+            promote_types: false,
+        }
+    }
+}
+
+impl MergeOptions {
+    /// Set the [`MetadataConflictPolicy`], see [`Self::metadata_conflict`]
+    pub fn with_metadata_conflict(mut self, metadata_conflict: MetadataConflictPolicy) -> Self {
+        self.metadata_conflict = metadata_conflict;
+        self
+    }
+
+    /// Set whether to widen nullability, see [`Self::widen_nullability`]
+    pub fn with_widen_nullability(mut self, widen_nullability: bool) -> Self {
+        self.widen_nullability = widen_nullability;
+        self
+    }
+
+    /// Set whether to promote types, see [`Self::promote_types`]
+    pub fn with_promote_types(mut self, promote_types: bool) -> Self {
+        self.promote_types = promote_types;
+        self
+    }
+}
+
+/// Returns the type that both `a` and `b` can be losslessly widened to, if any
+///
+/// Only types within the same family are promoted, e.g. signed integers are never
+/// promoted to floats or unsigned integers
+pub(crate) fn promote_types(a: &DataType, b: &DataType) -> Option<DataType> {
+    const FAMILIES: &[&[DataType]] = &[
+        &[
+            DataType::Int8,
+            DataType::Int16,
+            DataType::Int32,
+            DataType::Int64,
+        ],
+        &[
+            DataType::UInt8,
+            DataType::UInt16,
+            DataType::UInt32,
+            DataType::UInt64,
+        ],
+        &[DataType::Float16, DataType::Float32, DataType::Float64],
+        &[DataType::Utf8, DataType::LargeUtf8],
+        &[DataType::Binary, DataType::LargeBinary],
+    ];
+    FAMILIES.iter().find_map(|family| {
+        let a = family.iter().position(|t| t == a)?;
+        let b = family.iter().position(|t| t == b)?;
+        Some(family[a.max(b)].clone())
+    })
+}
+
+/// Metadata key prefix reserved by the Arrow specification
+const RESERVED_PREFIX: &str = "ARROW:";
+
+/// Validates `fields` with path `parent` recursively, see [`SchemaBuilder::try_finish`]
+fn validate_fields<'a>(
+    fields: impl Iterator<Item = &'a FieldRef>,
+    parent: Option<&str>,
+    dictionaries: &mut HashMap<i64, (String, &'a DataType)>,
+) -> Result<(), SchemaValidationError> {
+    let mut names = HashSet::new();
+    for field in fields {
+        let path = match parent {
+            Some(parent) => format!("{parent}.{}", field.name()),
+            None => field.name().clone(),
+        };
+        if !names.insert(field.name()) {
+            return Err(SchemaValidationError::DuplicateFieldName { path });
+        }
+        validate_field(field, path, dictionaries)?;
+    }
+    Ok(())
+}
+
+fn validate_field<'a>(
+    field: &'a FieldRef,
+    path: String,
+    dictionaries: &mut HashMap<i64, (String, &'a DataType)>,
+) -> Result<(), SchemaValidationError> {
+    let metadata = field.metadata();
+    let reserved = metadata.keys().find(|k| {
+        k.starts_with(RESERVED_PREFIX)
+            && k.as_str() != EXTENSION_TYPE_NAME_KEY
+            && k.as_str() != EXTENSION_TYPE_METADATA_KEY
+    });
+    if let Some(key) = reserved {
+        return Err(SchemaValidationError::ReservedMetadataKey {
+            path: Some(path),
+            key: key.clone(),
+        });
+    }
+
+    let invalid_extension = |message: String| SchemaValidationError::InvalidExtensionMetadata {
+        path: path.clone(),
+        message,
+    };
+    match metadata.get(EXTENSION_TYPE_NAME_KEY) {
+        Some(name) if name.is_empty() => {
+            return Err(invalid_extension(
+                "extension type name is empty".to_string(),
+            ))
+        }
+        Some(_name) => {
+            #[cfg(feature = "canonical_extension_types")]
+            {
+                use crate::extension::*;
+                if matches!(
+                    _name.as_str(),
+                    FixedShapeTensor::NAME
+                        | VariableShapeTensor::NAME
+                        | Json::NAME
+                        | Uuid::NAME
+                        | Opaque::NAME
+                        | Bool8::NAME
+                ) {
+                    if let Err(e) = field.try_canonical_extension_type() {
+                        return Err(invalid_extension(e.to_string()));
+                    }
+                }
+            }
+        }
+        None if metadata.contains_key(EXTENSION_TYPE_METADATA_KEY) => {
+            return Err(invalid_extension(format!(
+                "{EXTENSION_TYPE_METADATA_KEY} is set without {EXTENSION_TYPE_NAME_KEY}"
+            )))
+        }
+        None => {}
+    }
+
+    if let DataType::Dictionary(_, value_type) = field.data_type() {
+        #[allow(deprecated)]
+        let dict_id = field.dict_id().unwrap_or_default();
+        if dict_id != 0 {
+            match dictionaries.get(&dict_id) {
+                Some((first, existing)) if *existing != value_type.as_ref() => {
+                    return Err(SchemaValidationError::DictionaryIdCollision {
+                        dict_id,
+                        first: first.clone(),
+                        second: path,
+                    })
+                }
+                Some(_) => {}
+                None => {
+                    dictionaries.insert(dict_id, (path.clone(), value_type.as_ref()));
+                }
+            }
+        }
+    }
+
+    validate_children(field.data_type(), &path, dictionaries)
+}
+
+fn validate_children<'a>(
+    data_type: &'a DataType,
+    path: &str,
+    dictionaries: &mut HashMap<i64, (String, &'a DataType)>,
+) -> Result<(), SchemaValidationError> {
+    match data_type {
+        DataType::Struct(fields) => validate_fields(fields.iter(), Some(path), dictionaries),
+        DataType::Union(fields, _) => {
+            validate_fields(fields.iter().map(|(_, f)| f), Some(path), dictionaries)
+        }
+        DataType::List(field)
+        | DataType::LargeList(field)
+        | DataType::ListView(field)
+        | DataType::LargeListView(field)
+        | DataType::FixedSizeList(field, _)
+        | DataType::Map(field, _) => {
+            validate_fields(std::iter::once(field), Some(path), dictionaries)
+        }
+        DataType::RunEndEncoded(_, values) => {
+            validate_fields(std::iter::once(values), Some(path), dictionaries)
+        }
+        DataType::Dictionary(_, values) => validate_children(values, path, dictionaries),
+        _ => Ok(()),
+    }
+}
+
+/// An error returned by [`SchemaBuilder::try_finish`]
+///
+/// Paths identify nested fields by their names separated by `.`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SchemaValidationError {
+    /// Two sibling fields have the same name
+    DuplicateFieldName {
+        /// The path of the second field
+        path: String,
+    },
+    /// A metadata key uses the reserved `ARROW:` prefix
+    ReservedMetadataKey {
+        /// The path of the field, or `None` for the schema metadata
+        path: Option<String>,
+        /// The reserved key
+        key: String,
+    },
+    /// Two dictionary fields with the same dictionary id have different value types
+    DictionaryIdCollision {
+        /// The dictionary id
+        dict_id: i64,
+        /// The path of the first field using `dict_id`
+        first: String,
+        /// The path of the conflicting field
+        second: String,
+    },
+    /// The extension type metadata of a field is malformed
+    InvalidExtensionMetadata {
+        /// The path of the field
+        path: String,
+        /// A description of the problem
+        message: String,
+    },
+}
+
+impl fmt::Display for SchemaValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DuplicateFieldName { path } => write!(f, "Duplicate field name: {path}"),
+            Self::ReservedMetadataKey { path: None, key } => {
+                write!(f, "Reserved metadata key {key} in schema metadata")
+            }
+            Self::ReservedMetadataKey {
+                path: Some(path),
+                key,
+            } => write!(f, "Reserved metadata key {key} in metadata of field {path}"),
+            Self::DictionaryIdCollision {
+                dict_id,
+                first,
+                second,
+            } => write!(
+                f,
+                "Dictionary id {dict_id} of field {second} is already used by field {first} with a different value type"
+            ),
+            Self::InvalidExtensionMetadata { path, message } => {
+                write!(f, "Invalid extension metadata for field {path}: {message}")
+            }
+        }
+    }
+}
+
+impl Error for SchemaValidationError {}
+
+impl From<SchemaValidationError> for ArrowError {
+    fn from(value: SchemaValidationError) -> Self {
+        ArrowError::SchemaError(value.to_string())
+    }
 }
 
 impl From<&Fields> for SchemaBuilder {
@@ -293,26 +685,41 @@ impl Schema {
     /// );
     /// ```
     pub fn try_merge(schemas: impl IntoIterator<Item = Self>) -> Result<Self, ArrowError> {
-        let mut out_meta = HashMap::new();
+        Self::try_merge_with_options(schemas, &MergeOptions::default())
+    }
+
+    /// Merge schemas as by [`Schema::try_merge`], using the provided [`MergeOptions`]
+    /// to resolve metadata conflicts, differing nullability and differing types
+    pub fn try_merge_with_options(
+        schemas: impl IntoIterator<Item = Self>,
+        options: &MergeOptions,
+    ) -> Result<Self, ArrowError> {
+        let mut out_meta: HashMap<String, String> = HashMap::new();
         let mut out_fields = SchemaBuilder::new();
         for schema in schemas {
             let Schema { metadata, fields } = schema;
 
             // merge metadata
             for (key, value) in metadata.into_iter() {
-                if let Some(old_val) = out_meta.get(&key) {
-                    if old_val != &value {
-                        return Err(ArrowError::SchemaError(format!(
-                            "Fail to merge schema due to conflicting metadata. \
+                let value = match out_meta.get(&key) {
+                    Some(old_val) if old_val != &value => options
+                        .metadata_conflict
+                        .resolve(&key, old_val, &value)
+                        .ok_or_else(|| {
+                            ArrowError::SchemaError(format!(
+                                "Fail to merge schema due to conflicting metadata. \
                                          Key '{key}' has different values '{old_val}' and '{value}'"
-                        )));
-                    }
-                }
+                            ))
+                        })?,
+                    _ => value,
+                };
                 out_meta.insert(key, value);
             }
 
             // merge fields
-            fields.iter().try_for_each(|x| out_fields.try_merge(x))?
+            fields
+                .iter()
+                .try_for_each(|x| out_fields.try_merge_with_options(x, options))?
         }
 
         Ok(out_fields.finish().with_metadata(out_meta))
@@ -1433,6 +1840,103 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_schema_merge_with_options() {
+        let metadata = |v: &str| HashMap::from([("foo".to_string(), v.to_string())]);
+        let field_metadata = |v: &str| HashMap::from([("k".to_string(), v.to_string())]);
+        let schemas = || {
+            vec![
+                Schema::new_with_metadata(
+                    vec![
+                        Field::new("a", DataType::Int32, false),
+                        Field::new("b", DataType::Utf8, true),
+                        Field::new_list("c", Field::new_list_field(DataType::Int8, true), true)
+                            .with_metadata(field_metadata("1")),
+                    ],
+                    metadata("bar"),
+                ),
+                Schema::new_with_metadata(
+                    vec![
+                        Field::new("a", DataType::Int64, true),
+                        Field::new("b", DataType::LargeUtf8, false),
+                        Field::new_list("c", Field::new_list_field(DataType::Int16, true), true)
+                            .with_metadata(field_metadata("2")),
+                    ],
+                    metadata("baz"),
+                ),
+            ]
+        };
+
+        let options = MergeOptions::default().with_promote_types(true);
+        let err = Schema::try_merge_with_options(schemas(), &options).unwrap_err();
+        assert!(err.to_string().contains("conflicting metadata"), "{err}");
+
+        let options = options.with_metadata_conflict(MetadataConflictPolicy::KeepLeft);
+        let merged = Schema::try_merge_with_options(schemas(), &options).unwrap();
+        let expected = Schema::new_with_metadata(
+            vec![
+                Field::new("a", DataType::Int64, true),
+                Field::new("b", DataType::LargeUtf8, true),
+                Field::new_list("c", Field::new_list_field(DataType::Int16, true), true)
+                    .with_metadata(field_metadata("1")),
+            ],
+            metadata("bar"),
+        );
+        assert_eq!(merged, expected);
+
+        let options = options.with_metadata_conflict(MetadataConflictPolicy::KeepRight);
+        let merged = Schema::try_merge_with_options(schemas(), &options).unwrap();
+        assert_eq!(merged.metadata(), &metadata("baz"));
+        assert_eq!(merged.field(2).metadata(), &field_metadata("2"));
+
+        let combine = |_: &str, l: &str, r: &str| format!("{l},{r}");
+        let options = options.with_metadata_conflict(MetadataConflictPolicy::Combine(combine));
+        let merged = Schema::try_merge_with_options(schemas(), &options).unwrap();
+        assert_eq!(merged.metadata(), &metadata("bar,baz"));
+        assert_eq!(merged.field(2).metadata(), &field_metadata("1,2"));
+
+        let options = options.with_widen_nullability(false);
+        let err = Schema::try_merge_with_options(schemas(), &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Schema error: Fail to merge schema field 'a' because the from nullable = true does not equal false"
+        );
+
+        // Merging with a Null field does not widen the nullability either
+        let null_field = |nullable| Schema::new(vec![Field::new("a", DataType::Null, nullable)]);
+        let non_null = Schema::new(vec![Field::new("a", DataType::Int32, false)]);
+        let err = Schema::try_merge_with_options([non_null.clone(), null_field(true)], &options)
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("nullable = true does not equal false"),
+            "{err}"
+        );
+        let err = Schema::try_merge_with_options([null_field(true), non_null.clone()], &options)
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("nullable = false does not equal true"),
+            "{err}"
+        );
+        let merged =
+            Schema::try_merge_with_options([non_null.clone(), null_field(false)], &options)
+                .unwrap();
+        assert_eq!(merged, non_null);
+
+        // Types in different families are not promoted
+        let options = MergeOptions::default().with_promote_types(true);
+        let err = Schema::try_merge_with_options(
+            [
+                Schema::new(vec![Field::new("a", DataType::Int64, false)]),
+                Schema::new(vec![Field::new("a", DataType::UInt8, false)]),
+            ],
+            &options,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("does not equal"), "{err}");
+    }
+
     #[test]
     fn test_schema_builder_change_field() {
         let mut builder = SchemaBuilder::new();
@@ -1476,4 +1980,158 @@ mod tests {
         assert_eq!(out.metadata["k"], "v");
         assert_eq!(out.metadata["key"], "value");
     }
+
+    #[test]
+    fn test_schema_builder_try_finish() {
+        let schema = SchemaBuilder::new()
+            .with_field(Field::new("a", DataType::Int32, false))
+            .with_field(Field::new_list(
+                "b",
+                Field::new_list_field(DataType::Utf8, true),
+                true,
+            ))
+            .with_metadata("k", "v")
+            .try_finish()
+            .unwrap();
+        assert_eq!(schema.fields().len(), 2);
+        assert_eq!(schema.metadata["k"], "v");
+    }
+
+    #[test]
+    fn test_schema_builder_duplicate_name() {
+        let nested = Fields::from(vec![
+            Field::new("x", DataType::Int32, false),
+            Field::new("x", DataType::Int64, false),
+        ]);
+        let err = SchemaBuilder::new()
+            .with_field(Field::new("a", DataType::Int32, false))
+            .with_field(Field::new_struct("s", nested, true))
+            .try_finish()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            SchemaValidationError::DuplicateFieldName {
+                path: "s.x".to_string()
+            }
+        );
+        assert_eq!(err.to_string(), "Duplicate field name: s.x");
+
+        let err: ArrowError = err.into();
+        assert_eq!(err.to_string(), "Schema error: Duplicate field name: s.x");
+    }
+
+    #[test]
+    fn test_schema_builder_reserved_metadata() {
+        let err = SchemaBuilder::new()
+            .with_metadata("ARROW:schema", "")
+            .try_finish()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Reserved metadata key ARROW:schema in schema metadata"
+        );
+
+        let metadata = HashMap::from([("ARROW:foo".to_string(), "bar".to_string())]);
+        let child = Field::new_list_field(DataType::Int32, true).with_metadata(metadata);
+        let err = SchemaBuilder::new()
+            .with_field(Field::new_list("l", child, true))
+            .try_finish()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            SchemaValidationError::ReservedMetadataKey {
+                path: Some("l.item".to_string()),
+                key: "ARROW:foo".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_schema_builder_extension_metadata() {
+        let extension = |metadata: &[(&str, &str)]| {
+            let metadata = metadata
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect();
+            Field::new("e", DataType::Utf8, true).with_metadata(metadata)
+        };
+
+        SchemaBuilder::new()
+            .with_field(extension(&[
+                (EXTENSION_TYPE_NAME_KEY, "my.extension"),
+                (EXTENSION_TYPE_METADATA_KEY, "{}"),
+            ]))
+            .try_finish()
+            .unwrap();
+
+        let err = SchemaBuilder::new()
+            .with_field(extension(&[(EXTENSION_TYPE_NAME_KEY, "")]))
+            .try_finish()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid extension metadata for field e: extension type name is empty"
+        );
+
+        let err = SchemaBuilder::new()
+            .with_field(extension(&[(EXTENSION_TYPE_METADATA_KEY, "")]))
+            .try_finish()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid extension metadata for field e: ARROW:extension:metadata is set without ARROW:extension:name"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "canonical_extension_types")]
+    fn test_schema_builder_canonical_extension_metadata() {
+        let metadata = HashMap::from([
+            (
+                EXTENSION_TYPE_NAME_KEY.to_string(),
+                "arrow.bool8".to_string(),
+            ),
+            (EXTENSION_TYPE_METADATA_KEY.to_string(), "".to_string()),
+        ]);
+        let err = SchemaBuilder::new()
+            .with_field(Field::new("b", DataType::Utf8, true).with_metadata(metadata))
+            .try_finish()
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            SchemaValidationError::InvalidExtensionMetadata { .. }
+        ));
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_schema_builder_dict_id_collision() {
+        let dictionary = |name: &str, values: DataType, dict_id: i64| {
+            let data_type = DataType::Dictionary(Box::new(DataType::Int32), Box::new(values));
+            Field::new_dict(name, data_type, true, dict_id, false)
+        };
+
+        // Shared dictionaries with matching value types are permitted
+        SchemaBuilder::new()
+            .with_field(dictionary("a", DataType::Utf8, 1))
+            .with_field(dictionary("b", DataType::Utf8, 1))
+            .with_field(dictionary("c", DataType::Int64, 0))
+            .try_finish()
+            .unwrap();
+
+        let nested = Fields::from(vec![dictionary("b", DataType::Int64, 1)]);
+        let err = SchemaBuilder::new()
+            .with_field(dictionary("a", DataType::Utf8, 1))
+            .with_field(Field::new_struct("s", nested, true))
+            .try_finish()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            SchemaValidationError::DictionaryIdCollision {
+                dict_id: 1,
+                first: "a".to_string(),
+                second: "s.b".to_string(),
+            }
+        );
+    }
 }
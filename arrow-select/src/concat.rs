@@ -30,19 +30,45 @@
 //! assert_eq!(arr.len(), 3);
 //! ```
 
-use crate::dictionary::{merge_dictionary_values, should_merge_dictionary_values};
+use crate::dictionary::{
+    merge_dictionary_values, should_merge_dictionary_values, DictionaryMergeOptions,
+};
 use arrow_array::builder::{
     BooleanBuilder, GenericByteBuilder, GenericByteViewBuilder, PrimitiveBuilder,
 };
 use arrow_array::cast::AsArray;
 use arrow_array::types::*;
 use arrow_array::*;
-use arrow_buffer::{ArrowNativeType, BooleanBufferBuilder, NullBuffer, OffsetBuffer};
+use arrow_buffer::{ArrowNativeType, BooleanBufferBuilder, NullBuffer, OffsetBuffer, ScalarBuffer};
 use arrow_data::transform::{Capacities, MutableArrayData};
 use arrow_data::ArrayDataBuilder;
-use arrow_schema::{ArrowError, DataType, FieldRef, Fields, SchemaRef};
+use arrow_schema::{ArrowError, DataType, Field, FieldRef, Fields, Schema, SchemaRef};
 use std::{collections::HashSet, ops::Add, sync::Arc};
 
+/// Options that define how [`concat_with_options`] and [`concat_batches_with_options`]
+/// should behave
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ConcatOptions {
+    /// How to handle `Utf8`, `Binary` and `List` inputs whose combined values
+    /// cannot be addressed by `i32` offsets
+    ///
+    /// If `false` (the default) an error is returned. If `true` the output is
+    /// instead promoted to `LargeUtf8`, `LargeBinary` or `LargeList` respectively.
+    pub promote_large_offsets: bool,
+    /// How to merge the values of dictionary arrays
+    ///
+    /// If `None` (the default) dictionaries are only merged when a heuristic suggests
+    /// it is worthwhile, see [`DictionaryMergeOptions`]
+    pub dictionary: Option<DictionaryMergeOptions>,
+    /// How to handle the data buffers of `Utf8View` and `BinaryView` inputs
+    ///
+    /// If `None` (the default) the data buffers of all inputs are appended to the output
+    /// without copying. If `Some(size)` the values in data buffers smaller than `size`
+    /// bytes are instead copied into new, larger buffers, see
+    /// [`GenericByteViewBuilder::append_array_coalesced`]
+    pub coalesce_view_buffers: Option<usize>,
+}
+
 fn binary_capacity<T: ByteArrayType>(arrays: &[&dyn Array]) -> Capacities {
     let mut item_capacity = 0;
     let mut bytes_capacity = 0;
@@ -86,17 +112,24 @@ fn fixed_size_list_capacity(arrays: &[&dyn Array], data_type: &DataType) -> Capa
     }
 }
 
-fn concat_byte_view<B: ByteViewType>(arrays: &[&dyn Array]) -> Result<ArrayRef, ArrowError> {
+fn concat_byte_view<B: ByteViewType>(
+    arrays: &[&dyn Array],
+    options: &ConcatOptions,
+) -> Result<ArrayRef, ArrowError> {
     let mut builder =
         GenericByteViewBuilder::<B>::with_capacity(arrays.iter().map(|a| a.len()).sum());
     for &array in arrays.iter() {
-        builder.append_array(array.as_byte_view());
+        match options.coalesce_view_buffers {
+            Some(size) => builder.append_array_coalesced(array.as_byte_view(), size),
+            None => builder.append_array(array.as_byte_view()),
+        }
     }
     Ok(Arc::new(builder.finish()))
 }
 
 fn concat_dictionaries<K: ArrowDictionaryKeyType>(
     arrays: &[&dyn Array],
+    options: &ConcatOptions,
 ) -> Result<ArrayRef, ArrowError> {
     let mut output_len = 0;
     let dictionaries: Vec<_> = arrays
@@ -105,11 +138,15 @@ fn concat_dictionaries<K: ArrowDictionaryKeyType>(
         .inspect(|d| output_len += d.len())
         .collect();
 
-    if !should_merge_dictionary_values::<K>(&dictionaries, output_len) {
+    let merge_options = options
+        .dictionary
+        .as_ref()
+        .and_then(|o| o.for_values(dictionaries[0].values().data_type()));
+    if merge_options.is_none() && !should_merge_dictionary_values::<K>(&dictionaries, output_len) {
         return concat_fallback(arrays, Capacities::Array(output_len));
     }
 
-    let merged = merge_dictionary_values(&dictionaries, None)?;
+    let merged = merge_dictionary_values(&dictionaries, None, merge_options)?;
 
     // Recompute keys
     let mut key_values = Vec::with_capacity(output_len);
@@ -145,6 +182,7 @@ fn concat_dictionaries<K: ArrowDictionaryKeyType>(
 fn concat_lists<OffsetSize: OffsetSizeTrait>(
     arrays: &[&dyn Array],
     field: &FieldRef,
+    options: &ConcatOptions,
 ) -> Result<ArrayRef, ArrowError> {
     let mut output_len = 0;
     let mut list_has_nulls = false;
@@ -190,14 +228,25 @@ fn concat_lists<OffsetSize: OffsetSizeTrait>(
         lists.iter().map(|x| x.values().as_ref()).collect()
     };
 
-    let concatenated_values = concat(values.as_slice())?;
+    let concatenated_values = concat_with_options(values.as_slice(), options)?;
+
+    // The values may have been promoted to use larger offsets
+    let field = match concatenated_values.data_type() == field.data_type() {
+        true => Arc::clone(field),
+        false => Arc::new(
+            field
+                .as_ref()
+                .clone()
+                .with_data_type(concatenated_values.data_type().clone()),
+        ),
+    };
 
     // Merge value offsets from the lists
     let value_offset_buffer =
         OffsetBuffer::<OffsetSize>::from_lengths(lists.iter().flat_map(|x| x.offsets().lengths()));
 
     let array = GenericListArray::<OffsetSize>::try_new(
-        Arc::clone(field),
+        field,
         value_offset_buffer,
         concatenated_values,
         lists_nulls,
@@ -206,6 +255,73 @@ fn concat_lists<OffsetSize: OffsetSizeTrait>(
     Ok(Arc::new(array))
 }
 
+fn concat_list_views<OffsetSize: OffsetSizeTrait>(
+    arrays: &[&dyn Array],
+    field: &FieldRef,
+    options: &ConcatOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let lists = arrays
+        .iter()
+        .map(|x| x.as_list_view::<OffsetSize>())
+        .collect::<Vec<_>>();
+
+    let output_len = lists.iter().map(|l| l.len()).sum();
+    let lists_nulls = lists.iter().any(|l| l.null_count() != 0).then(|| {
+        let mut nulls = BooleanBufferBuilder::new(output_len);
+        for l in &lists {
+            match l.nulls() {
+                Some(n) => nulls.append_buffer(n.inner()),
+                None => nulls.append_n(l.len(), true),
+            }
+        }
+        NullBuffer::new(nulls.finish())
+    });
+
+    // As list views may reference their values in any order, the values are
+    // concatenated in full and the offsets of each list view shifted accordingly
+    let values: Vec<&dyn Array> = lists.iter().map(|x| x.values().as_ref()).collect();
+    let concatenated_values = concat_with_options(values.as_slice(), options)?;
+
+    let field = match concatenated_values.data_type() == field.data_type() {
+        true => Arc::clone(field),
+        false => Arc::new(
+            field
+                .as_ref()
+                .clone()
+                .with_data_type(concatenated_values.data_type().clone()),
+        ),
+    };
+
+    let mut offsets = Vec::with_capacity(output_len);
+    let mut sizes = Vec::with_capacity(output_len);
+    let mut values_offset = 0_usize;
+    for l in &lists {
+        for (offset, size) in l.offsets().iter().zip(l.sizes().iter()) {
+            let offset =
+                OffsetSize::from_usize(offset.as_usize() + values_offset).ok_or_else(|| {
+                    ArrowError::ComputeError(format!(
+                        "Offset overflow concatenating {} arrays of type {}",
+                        arrays.len(),
+                        arrays[0].data_type()
+                    ))
+                })?;
+            offsets.push(offset);
+            sizes.push(*size);
+        }
+        values_offset += l.values().len();
+    }
+
+    let array = GenericListViewArray::<OffsetSize>::try_new(
+        field,
+        offsets.into(),
+        sizes.into(),
+        concatenated_values,
+        lists_nulls,
+    )?;
+
+    Ok(Arc::new(array))
+}
+
 fn concat_primitives<T: ArrowPrimitiveType>(arrays: &[&dyn Array]) -> Result<ArrayRef, ArrowError> {
     let mut builder = PrimitiveBuilder::<T>::with_capacity(arrays.iter().map(|a| a.len()).sum())
         .with_data_type(arrays[0].data_type().clone());
@@ -242,7 +358,11 @@ fn concat_bytes<T: ByteArrayType>(arrays: &[&dyn Array]) -> Result<ArrayRef, Arr
     Ok(Arc::new(builder.finish()))
 }
 
-fn concat_structs(arrays: &[&dyn Array], fields: &Fields) -> Result<ArrayRef, ArrowError> {
+fn concat_structs(
+    arrays: &[&dyn Array],
+    fields: &Fields,
+    options: &ConcatOptions,
+) -> Result<ArrayRef, ArrowError> {
     let mut len = 0;
     let mut has_nulls = false;
     let structs = arrays
@@ -271,12 +391,22 @@ fn concat_structs(arrays: &[&dyn Array], fields: &Fields) -> Result<ArrayRef, Ar
                 .iter()
                 .map(|s| s.column(i).as_ref())
                 .collect::<Vec<_>>();
-            concat(&extracted_cols)
+            concat_with_options(&extracted_cols, options)
         })
         .collect::<Result<Vec<_>, ArrowError>>()?;
 
+    // The columns may have been promoted to use larger offsets
+    let fields = fields
+        .iter()
+        .zip(&column_concat_result)
+        .map(|(f, c)| match f.data_type() == c.data_type() {
+            true => Arc::clone(f),
+            false => Arc::new(f.as_ref().clone().with_data_type(c.data_type().clone())),
+        })
+        .collect();
+
     Ok(Arc::new(StructArray::try_new_with_length(
-        fields.clone(),
+        fields,
         column_concat_result,
         nulls,
         len,
@@ -345,8 +475,8 @@ where
 }
 
 macro_rules! dict_helper {
-    ($t:ty, $arrays:expr) => {
-        return Ok(Arc::new(concat_dictionaries::<$t>($arrays)?) as _)
+    ($t:ty, $arrays:expr, $options:expr) => {
+        return Ok(Arc::new(concat_dictionaries::<$t>($arrays, $options)?) as _)
     };
 }
 
@@ -367,8 +497,97 @@ fn get_capacity(arrays: &[&dyn Array], data_type: &DataType) -> Capacities {
     }
 }
 
+/// Returns the combined length of the values referenced by `offsets`
+fn offsets_values_len<'a>(offsets: impl Iterator<Item = &'a OffsetBuffer<i32>>) -> usize {
+    offsets
+        .map(|o| o.last().unwrap().as_usize() - o.first().unwrap().as_usize())
+        .sum()
+}
+
+/// Returns the combined length of the values of `arrays` if `data_type` uses `i32` offsets
+fn i32_offsets_values_len(arrays: &[&dyn Array], data_type: &DataType) -> Option<usize> {
+    match data_type {
+        DataType::Utf8 => Some(offsets_values_len(
+            arrays.iter().map(|a| a.as_string::<i32>().offsets()),
+        )),
+        DataType::Binary => Some(offsets_values_len(
+            arrays.iter().map(|a| a.as_binary::<i32>().offsets()),
+        )),
+        DataType::List(_) => Some(offsets_values_len(
+            arrays.iter().map(|a| a.as_list::<i32>().offsets()),
+        )),
+        _ => None,
+    }
+}
+
+/// Converts an array with `i32` offsets to the equivalent array with `i64` offsets
+fn promote_large_offsets(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    fn widen(offsets: &OffsetBuffer<i32>) -> OffsetBuffer<i64> {
+        let widened: ScalarBuffer<i64> = offsets.iter().map(|o| *o as i64).collect();
+        // Safety: widening preserves the monotonicity of the offsets
+        unsafe { OffsetBuffer::new_unchecked(widened) }
+    }
+
+    Ok(match array.data_type() {
+        DataType::Utf8 => {
+            let a = array.as_string::<i32>();
+            let offsets = widen(a.offsets());
+            // Safety: the offsets and values were already valid UTF-8
+            Arc::new(unsafe {
+                LargeStringArray::new_unchecked(offsets, a.values().clone(), a.nulls().cloned())
+            })
+        }
+        DataType::Binary => {
+            let a = array.as_binary::<i32>();
+            let offsets = widen(a.offsets());
+            Arc::new(LargeBinaryArray::try_new(
+                offsets,
+                a.values().clone(),
+                a.nulls().cloned(),
+            )?)
+        }
+        DataType::List(field) => {
+            let a = array.as_list::<i32>();
+            let offsets = widen(a.offsets());
+            Arc::new(LargeListArray::try_new(
+                Arc::clone(field),
+                offsets,
+                a.values().clone(),
+                a.nulls().cloned(),
+            )?)
+        }
+        d => unreachable!("cannot promote offsets of {d}"),
+    })
+}
+
 /// Concatenate multiple [Array] of the same type into a single [ArrayRef].
+///
+/// Returns an error if the output would be a `Utf8`, `Binary` or `List` array
+/// whose values cannot be addressed by `i32` offsets, see [`concat_with_options`]
+/// to instead promote such outputs to the corresponding `Large` type.
 pub fn concat(arrays: &[&dyn Array]) -> Result<ArrayRef, ArrowError> {
+    concat_with_options(arrays, &ConcatOptions::default())
+}
+
+/// Concatenate multiple [Array] of the same type into a single [ArrayRef]
+/// with the provided [`ConcatOptions`]
+///
+/// ```
+/// # use arrow_array::{Array, StringArray};
+/// # use arrow_schema::DataType;
+/// # use arrow_select::concat::{concat_with_options, ConcatOptions};
+/// let options = ConcatOptions { promote_large_offsets: true, ..Default::default() };
+/// let arr = concat_with_options(&[
+///     &StringArray::from(vec!["hello", "world"]),
+///     &StringArray::from(vec!["!"]),
+/// ], &options).unwrap();
+/// // Promotion only happens if the output would overflow
+/// assert_eq!(arr.data_type(), &DataType::Utf8);
+/// ```
+pub fn concat_with_options(
+    arrays: &[&dyn Array],
+    options: &ConcatOptions,
+) -> Result<ArrayRef, ArrowError> {
     if arrays.is_empty() {
         return Err(ArrowError::ComputeError(
             "concat requires input of at least one array".to_string(),
@@ -411,18 +630,40 @@ pub fn concat(arrays: &[&dyn Array]) -> Result<ArrayRef, ArrowError> {
         return Err(ArrowError::InvalidArgumentError(error_message));
     }
 
+    if let Some(values_len) = i32_offsets_values_len(arrays, d) {
+        if values_len > i32::MAX as usize {
+            if !options.promote_large_offsets {
+                return Err(ArrowError::ComputeError(format!(
+                    "Offset overflow concatenating {} arrays of type {d}: the combined length \
+                     of {values_len} values exceeds the maximum of {} for 32-bit offsets, \
+                     consider ConcatOptions::promote_large_offsets",
+                    arrays.len(),
+                    i32::MAX
+                )));
+            }
+            let promoted = arrays
+                .iter()
+                .map(|a| promote_large_offsets(*a))
+                .collect::<Result<Vec<_>, _>>()?;
+            let promoted = promoted.iter().map(|a| a.as_ref()).collect::<Vec<_>>();
+            return concat_with_options(&promoted, options);
+        }
+    }
+
     downcast_primitive! {
         d => (primitive_concat, arrays),
         DataType::Boolean => concat_boolean(arrays),
         DataType::Dictionary(k, _) => {
             downcast_integer! {
-                k.as_ref() => (dict_helper, arrays),
+                k.as_ref() => (dict_helper, arrays, options),
                 _ => unreachable!("illegal dictionary key type {k}")
             }
         }
-        DataType::List(field) => concat_lists::<i32>(arrays, field),
-        DataType::LargeList(field) => concat_lists::<i64>(arrays, field),
-        DataType::Struct(fields) => concat_structs(arrays, fields),
+        DataType::List(field) => concat_lists::<i32>(arrays, field, options),
+        DataType::LargeList(field) => concat_lists::<i64>(arrays, field, options),
+        DataType::ListView(field) => concat_list_views::<i32>(arrays, field, options),
+        DataType::LargeListView(field) => concat_list_views::<i64>(arrays, field, options),
+        DataType::Struct(fields) => concat_structs(arrays, fields, options),
         DataType::Utf8 => concat_bytes::<Utf8Type>(arrays),
         DataType::LargeUtf8 => concat_bytes::<LargeUtf8Type>(arrays),
         DataType::Binary => concat_bytes::<BinaryType>(arrays),
@@ -437,8 +678,8 @@ pub fn concat(arrays: &[&dyn Array]) -> Result<ArrayRef, ArrowError> {
                 _ => unreachable!("Unsupported run end index type: {r:?}"),
             }
         }
-        DataType::Utf8View => concat_byte_view::<StringViewType>(arrays),
-        DataType::BinaryView => concat_byte_view::<BinaryViewType>(arrays),
+        DataType::Utf8View => concat_byte_view::<StringViewType>(arrays, options),
+        DataType::BinaryView => concat_byte_view::<BinaryViewType>(arrays, options),
         _ => {
             let capacity = get_capacity(arrays, d);
             concat_fallback(arrays, capacity)
@@ -470,6 +711,19 @@ fn concat_fallback(arrays: &[&dyn Array], capacity: Capacities) -> Result<ArrayR
 pub fn concat_batches<'a>(
     schema: &SchemaRef,
     input_batches: impl IntoIterator<Item = &'a RecordBatch>,
+) -> Result<RecordBatch, ArrowError> {
+    concat_batches_with_options(schema, input_batches, &ConcatOptions::default())
+}
+
+/// Concatenates `batches` together into a single [`RecordBatch`] with the
+/// provided [`ConcatOptions`]
+///
+/// The output batch has the specified `schema`, except for any columns promoted
+/// to use larger offsets, see [`ConcatOptions::promote_large_offsets`]
+pub fn concat_batches_with_options<'a>(
+    schema: &SchemaRef,
+    input_batches: impl IntoIterator<Item = &'a RecordBatch>,
+    options: &ConcatOptions,
 ) -> Result<RecordBatch, ArrowError> {
     // When schema is empty, sum the number of the rows of all batches
     if schema.fields().is_empty() {
@@ -486,22 +740,46 @@ pub fn concat_batches<'a>(
     let field_num = schema.fields().len();
     let mut arrays = Vec::with_capacity(field_num);
     for i in 0..field_num {
-        let array = concat(
+        let array = concat_with_options(
             &batches
                 .iter()
                 .map(|batch| batch.column(i).as_ref())
                 .collect::<Vec<_>>(),
+            options,
         )?;
         arrays.push(array);
     }
-    RecordBatch::try_new(schema.clone(), arrays)
+
+    let promoted = schema
+        .fields()
+        .iter()
+        .zip(&arrays)
+        .any(|(f, a)| f.data_type() != a.data_type());
+    let schema = match promoted {
+        false => schema.clone(),
+        true => {
+            let fields: Fields = schema
+                .fields()
+                .iter()
+                .zip(&arrays)
+                .map(|(f, a)| match f.data_type() == a.data_type() {
+                    true => Arc::clone(f),
+                    false => Arc::new(Field::clone(f).with_data_type(a.data_type().clone())),
+                })
+                .collect();
+            Arc::new(Schema::new_with_metadata(fields, schema.metadata().clone()))
+        }
+    };
+    RecordBatch::try_new(schema, arrays)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use arrow_array::builder::{GenericListBuilder, StringDictionaryBuilder};
-    use arrow_schema::{Field, Schema};
+    use arrow_array::builder::{
+        GenericListBuilder, Int32Builder, ListViewBuilder, StringDictionaryBuilder,
+    };
+    use arrow_buffer::Buffer;
     use std::fmt::Debug;
 
     #[test]
@@ -673,6 +951,26 @@ mod tests {
         assert_eq!(&arr, &expected_output);
     }
 
+    #[test]
+    fn test_concat_string_view_arrays_coalesced() {
+        let long = "a string longer than 12 bytes";
+        let arrays: Vec<_> = (0..10)
+            .map(|i| StringViewArray::from(vec![Some(long), None, Some(&*i.to_string())]))
+            .collect();
+        let arrays: Vec<&dyn Array> = arrays.iter().map(|a| a as &dyn Array).collect();
+
+        let arr = concat(&arrays).unwrap();
+        assert_eq!(arr.as_string_view().data_buffers().len(), 10);
+
+        let options = ConcatOptions {
+            coalesce_view_buffers: Some(1024),
+            ..Default::default()
+        };
+        let coalesced = concat_with_options(&arrays, &options).unwrap();
+        assert_eq!(coalesced.as_string_view().data_buffers().len(), 1);
+        assert_eq!(&arr, &coalesced);
+    }
+
     #[test]
     fn test_concat_primitive_arrays() {
         let arr = concat(&[
@@ -754,6 +1052,39 @@ mod tests {
         assert_eq!(&arr, &expected_output);
     }
 
+    #[test]
+    fn test_concat_list_view_arrays() {
+        let field = Arc::new(Field::new_list_field(DataType::Int32, true));
+        // [[3, 4], null, [1]]
+        let a = ListViewArray::new(
+            field.clone(),
+            vec![2, 1, 0].into(),
+            vec![2, 2, 1].into(),
+            Arc::new(Int32Array::from(vec![1, 2, 3, 4])),
+            Some(NullBuffer::from(vec![true, false, true])),
+        );
+        // [[], [6, 5]]
+        let b = ListViewArray::new(
+            field.clone(),
+            vec![0, 1].into(),
+            vec![0, 2].into(),
+            Arc::new(Int32Array::from(vec![5, 6, 5])),
+            None,
+        );
+
+        let concat = concat(&[&a, &b.slice(1, 1), &a.slice(2, 1)]).unwrap();
+        let concat = concat.as_list_view::<i32>();
+        assert_eq!(concat.values().len(), 11);
+
+        let mut builder = ListViewBuilder::new(Int32Builder::new());
+        builder.append_value([Some(3), Some(4)]);
+        builder.append_null();
+        builder.append_value([Some(1)]);
+        builder.append_value([Some(6), Some(5)]);
+        builder.append_value([Some(1)]);
+        assert_eq!(concat, &builder.finish());
+    }
+
     #[test]
     fn test_concat_primitive_list_arrays() {
         let list1 = vec![
@@ -1196,6 +1527,67 @@ mod tests {
         assert_eq!(arr.to_data().buffers()[1].capacity(), 960);
     }
 
+    #[test]
+    fn test_dictionary_concat_merge_options() {
+        let a: DictionaryArray<Int8Type> = vec!["d", "b", "d", "a"].into_iter().collect();
+        let b: DictionaryArray<Int8Type> = vec![Some("b"), None, Some("c")].into_iter().collect();
+        // Values are shared, so would not be merged by default
+        let c = a.slice(1, 1);
+
+        let options = ConcatOptions {
+            dictionary: Some(DictionaryMergeOptions::default()),
+            ..Default::default()
+        };
+        let result = concat_with_options(&[&c, &a, &b], &options).unwrap();
+        let result = result.as_dictionary::<Int8Type>();
+        assert_eq!(
+            result.values().as_ref(),
+            &StringArray::from(vec!["b", "d", "a", "c"])
+        );
+        let expected = Int8Array::from(vec![
+            Some(0),
+            Some(1),
+            Some(0),
+            Some(1),
+            Some(2),
+            Some(0),
+            None,
+            Some(3),
+        ]);
+        assert_eq!(result.keys(), &expected);
+
+        let options = ConcatOptions {
+            dictionary: Some(DictionaryMergeOptions::default().with_sort(true)),
+            ..Default::default()
+        };
+        let result = concat_with_options(&[&a, &b], &options).unwrap();
+        let result = result.as_dictionary::<Int8Type>();
+        assert_eq!(
+            result.values().as_ref(),
+            &StringArray::from(vec!["a", "b", "c", "d"])
+        );
+        let expected = Int8Array::from(vec![
+            Some(3),
+            Some(1),
+            Some(3),
+            Some(0),
+            Some(1),
+            None,
+            Some(2),
+        ]);
+        assert_eq!(result.keys(), &expected);
+
+        let options = ConcatOptions {
+            dictionary: Some(DictionaryMergeOptions::default().with_max_values(3)),
+            ..Default::default()
+        };
+        let err = concat_with_options(&[&a, &b], &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Merged dictionary of 4 values exceeds the maximum of 3"
+        );
+    }
+
     #[test]
     fn test_dictionary_concat_reuse() {
         let array: DictionaryArray<Int8Type> = vec!["a", "a", "b", "c"].into_iter().collect();
@@ -1682,4 +2074,90 @@ mod tests {
         assert_eq!(values.len(), 6);
         assert_eq!(&[10, 20, 30, 40, 50, 60], values.values());
     }
+
+    /// Length of values that overflows `i32` offsets when concatenated twice
+    const OVERFLOW_LEN: usize = i32::MAX as usize / 2 + 1;
+
+    #[test]
+    fn test_concat_binary_offset_overflow() {
+        // The buffer of 1 MiB is shared by 2048 arrays, and the overflow is detected
+        // before any copying
+        let values = Buffer::from_vec(vec![0_u8; 1 << 20]);
+        let offsets = OffsetBuffer::from_lengths([1 << 20]);
+        let array = BinaryArray::new(offsets, values, None);
+
+        let err = concat(&[&array as &dyn Array; 2048]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Offset overflow concatenating 2048 arrays of type Binary: the \
+             combined length of 2147483648 values exceeds the maximum of 2147483647 for 32-bit \
+             offsets, consider ConcatOptions::promote_large_offsets"
+        );
+    }
+
+    #[test]
+    fn test_concat_list_offset_promotion() {
+        let field = Arc::new(Field::new_list_field(DataType::Null, true));
+        let offsets = OffsetBuffer::from_lengths([OVERFLOW_LEN, 0]);
+        let values = Arc::new(NullArray::new(OVERFLOW_LEN));
+        let list = ListArray::new(Arc::clone(&field), offsets, values, None);
+
+        assert!(concat(&[&list, &list]).is_err());
+
+        let options = ConcatOptions {
+            promote_large_offsets: true,
+            ..Default::default()
+        };
+        let result = concat_with_options(&[&list, &list.slice(0, 1)], &options).unwrap();
+        assert_eq!(result.data_type(), &DataType::LargeList(field));
+        let result = result.as_list::<i64>();
+        assert_eq!(result.len(), 3);
+        assert_eq!(
+            result.value_offsets(),
+            &[
+                0,
+                OVERFLOW_LEN as i64,
+                OVERFLOW_LEN as i64,
+                2 * OVERFLOW_LEN as i64
+            ]
+        );
+    }
+
+    #[test]
+    fn test_concat_batches_offset_promotion() {
+        let field = Arc::new(Field::new_list_field(DataType::Null, true));
+        let offsets = OffsetBuffer::from_lengths([OVERFLOW_LEN]);
+        let values = Arc::new(NullArray::new(OVERFLOW_LEN));
+        let list = ListArray::new(Arc::clone(&field), offsets, values, None);
+        let strings = StringArray::from(vec!["a"]);
+        let structs = StructArray::from(vec![(
+            Arc::new(Field::new("l", list.data_type().clone(), false)),
+            Arc::new(list) as ArrayRef,
+        )]);
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("s", strings.data_type().clone(), false),
+            Field::new("t", structs.data_type().clone(), false),
+        ]));
+        let batch =
+            RecordBatch::try_new(schema.clone(), vec![Arc::new(strings), Arc::new(structs)])
+                .unwrap();
+
+        assert!(concat_batches(&schema, [&batch, &batch]).is_err());
+
+        let options = ConcatOptions {
+            promote_large_offsets: true,
+            ..Default::default()
+        };
+        let result = concat_batches_with_options(&schema, [&batch, &batch], &options).unwrap();
+        assert_eq!(result.num_rows(), 2);
+        // only the overflowing column is promoted
+        assert_eq!(result.schema().field(0).data_type(), &DataType::Utf8);
+        let expected = DataType::Struct(Fields::from(vec![Field::new(
+            "l",
+            DataType::LargeList(field),
+            false,
+        )]));
+        assert_eq!(result.schema().field(1).data_type(), &expected);
+    }
 }
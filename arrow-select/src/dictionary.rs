@@ -17,10 +17,13 @@
 
 //! Dictionary utilities for Arrow arrays
 
+use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use crate::filter::filter;
 use crate::interleave::interleave;
+use crate::take::take;
 use ahash::RandomState;
 use arrow_array::builder::BooleanBufferBuilder;
 use arrow_array::types::{
@@ -30,11 +33,57 @@ use arrow_array::types::{
 use arrow_array::{cast::AsArray, downcast_primitive};
 use arrow_array::{
     downcast_dictionary_array, AnyDictionaryArray, Array, ArrayRef, ArrowNativeTypeOp,
-    BooleanArray, DictionaryArray, GenericByteArray, PrimitiveArray,
+    BooleanArray, DictionaryArray, GenericByteArray, PrimitiveArray, UInt64Array,
 };
 use arrow_buffer::{ArrowNativeType, BooleanBuffer, ScalarBuffer, ToByteSlice};
 use arrow_schema::{ArrowError, DataType};
 
+/// Options that control how the values of [`DictionaryArray`] are merged when
+/// concatenating or interleaving them, see [`ConcatOptions::dictionary`] and
+/// [`InterleaveOptions::dictionary`]
+///
+/// By default, dictionaries are only merged when a heuristic suggests it is worthwhile,
+/// and the merged values may contain duplicates. With these options, dictionaries with
+/// primitive, `Utf8`, `LargeUtf8`, `Binary` or `LargeBinary` values are always merged,
+/// keeping only the distinct values referenced by the output. Dictionaries with other
+/// value types are handled as without these options.
+///
+/// [`ConcatOptions::dictionary`]: crate::concat::ConcatOptions::dictionary
+/// [`InterleaveOptions::dictionary`]: crate::interleave::InterleaveOptions::dictionary
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DictionaryMergeOptions {
+    /// If true, sort the merged values with nulls last
+    ///
+    /// Strings and binary values are ordered bytewise, and floating point values
+    /// using IEEE 754 totalOrder
+    pub sort: bool,
+    /// The maximum number of merged values, returning an error if it is exceeded
+    pub max_values: Option<usize>,
+}
+
+impl DictionaryMergeOptions {
+    /// Set [`Self::sort`]
+    pub fn with_sort(mut self, sort: bool) -> Self {
+        self.sort = sort;
+        self
+    }
+
+    /// Set [`Self::max_values`]
+    pub fn with_max_values(mut self, max_values: usize) -> Self {
+        self.max_values = Some(max_values);
+        self
+    }
+
+    /// Returns `self` if dictionaries with values of `data_type` can be merged with
+    /// these options
+    pub(crate) fn for_values(&self, data_type: &DataType) -> Option<&Self> {
+        use DataType::*;
+        let supported = data_type.is_primitive()
+            || matches!(data_type, Utf8 | LargeUtf8 | Binary | LargeBinary);
+        supported.then_some(self)
+    }
+}
+
 /// Garbage collects a [DictionaryArray] by removing unreferenced values.
 ///
 /// Returns a new [DictionaryArray] such that there are no values
@@ -217,10 +266,65 @@ pub(crate) fn should_merge_dictionary_values<K: ArrowDictionaryKeyType>(
 /// This method is meant to be very fast and the output dictionary values
 /// may not be unique, unlike `GenericByteDictionaryBuilder` which is slower
 /// but produces unique values
+///
+/// If `options` are provided the merged values are instead distinct, see
+/// [`DictionaryMergeOptions`]
 pub(crate) fn merge_dictionary_values<K: ArrowDictionaryKeyType>(
     dictionaries: &[&DictionaryArray<K>],
     masks: Option<&[BooleanBuffer]>,
+    options: Option<&DictionaryMergeOptions>,
 ) -> Result<MergedDictionaries<K>, ArrowError> {
+    let (values_arrays, value_slices, num_values) = masked_dictionary_values(dictionaries, masks);
+    if let Some(options) = options {
+        return merge_distinct_dictionary_values(values_arrays, value_slices, num_values, options);
+    }
+
+    // Map from value to new index
+    let mut interner = Interner::new(num_values);
+    // Interleave indices for new values array
+    let mut indices = Vec::with_capacity(num_values);
+
+    // Compute the mapping for each dictionary
+    let key_mappings = dictionaries
+        .iter()
+        .enumerate()
+        .zip(value_slices)
+        .map(|((dictionary_idx, dictionary), values)| {
+            let zero = K::Native::from_usize(0).unwrap();
+            let mut mapping = vec![zero; dictionary.values().len()];
+
+            for (value_idx, value) in values {
+                mapping[value_idx] =
+                    *interner.intern(value, || match K::Native::from_usize(indices.len()) {
+                        Some(idx) => {
+                            indices.push((dictionary_idx, value_idx));
+                            Ok(idx)
+                        }
+                        None => Err(ArrowError::DictionaryKeyOverflowError),
+                    })?;
+            }
+            Ok(mapping)
+        })
+        .collect::<Result<Vec<_>, ArrowError>>()?;
+
+    Ok(MergedDictionaries {
+        key_mappings,
+        values: interleave(&values_arrays, &indices)?,
+    })
+}
+
+/// The index and bytes of the referenced values of a dictionary
+type MaskedValues<'a> = Vec<(usize, Option<&'a [u8]>)>;
+
+/// The values of each dictionary, their [`MaskedValues`], and the total number of
+/// referenced values
+type MaskedDictionaryValues<'a> = (Vec<&'a dyn Array>, Vec<MaskedValues<'a>>, usize);
+
+/// Returns the values of `dictionaries` referenced by the keys selected by `masks`
+fn masked_dictionary_values<'a, K: ArrowDictionaryKeyType>(
+    dictionaries: &[&'a DictionaryArray<K>],
+    masks: Option<&[BooleanBuffer]>,
+) -> MaskedDictionaryValues<'a> {
     let mut num_values = 0;
 
     let mut values_arrays = Vec::with_capacity(dictionaries.len());
@@ -248,37 +352,118 @@ pub(crate) fn merge_dictionary_values<K: ArrowDictionaryKeyType>(
         values_arrays.push(values)
     }
 
-    // Map from value to new index
-    let mut interner = Interner::new(num_values);
+    (values_arrays, value_slices, num_values)
+}
+
+/// Merges the distinct values of `value_slices`, as returned by [`masked_dictionary_values`]
+fn merge_distinct_dictionary_values<K: ArrowDictionaryKeyType>(
+    values_arrays: Vec<&dyn Array>,
+    value_slices: Vec<MaskedValues<'_>>,
+    num_values: usize,
+    options: &DictionaryMergeOptions,
+) -> Result<MergedDictionaries<K>, ArrowError> {
+    // Map from value to index in `indices`
+    let mut distinct = HashMap::with_capacity_and_hasher(num_values, RandomState::new());
     // Interleave indices for new values array
     let mut indices = Vec::with_capacity(num_values);
 
-    // Compute the mapping for each dictionary
-    let key_mappings = dictionaries
+    let mappings: Vec<Vec<usize>> = values_arrays
         .iter()
-        .enumerate()
         .zip(value_slices)
-        .map(|((dictionary_idx, dictionary), values)| {
-            let zero = K::Native::from_usize(0).unwrap();
-            let mut mapping = vec![zero; dictionary.values().len()];
-
-            for (value_idx, value) in values {
-                mapping[value_idx] =
-                    *interner.intern(value, || match K::Native::from_usize(indices.len()) {
-                        Some(idx) => {
-                            indices.push((dictionary_idx, value_idx));
-                            Ok(idx)
-                        }
-                        None => Err(ArrowError::DictionaryKeyOverflowError),
-                    })?;
+        .enumerate()
+        .map(|(dictionary_idx, (values, slices))| {
+            let mut mapping = vec![0; values.len()];
+            for (value_idx, value) in slices {
+                let next = indices.len();
+                mapping[value_idx] = *distinct.entry(value).or_insert_with(|| {
+                    indices.push((dictionary_idx, value_idx));
+                    next
+                });
             }
-            Ok(mapping)
+            mapping
         })
-        .collect::<Result<Vec<_>, ArrowError>>()?;
+        .collect();
+
+    if let Some(max_values) = options.max_values {
+        if indices.len() > max_values {
+            return Err(ArrowError::ComputeError(format!(
+                "Merged dictionary of {} values exceeds the maximum of {max_values}",
+                indices.len()
+            )));
+        }
+    }
+    if K::Native::from_usize(indices.len().saturating_sub(1)).is_none() {
+        return Err(ArrowError::DictionaryKeyOverflowError);
+    }
+
+    let mut values = interleave(&values_arrays, &indices)?;
+    let mut ranks = None;
+    if options.sort {
+        let order = sort_order(values.as_ref());
+        let mut r = vec![0; order.len()];
+        for (rank, idx) in order.iter().enumerate() {
+            r[*idx] = rank;
+        }
+        let order = UInt64Array::from_iter_values(order.into_iter().map(|i| i as u64));
+        values = take(values.as_ref(), &order, None)?;
+        ranks = Some(r);
+    }
 
+    let key_mappings = mappings
+        .into_iter()
+        .map(|mapping| {
+            mapping
+                .into_iter()
+                .map(|idx| K::Native::usize_as(ranks.as_ref().map_or(idx, |r| r[idx])))
+                .collect()
+        })
+        .collect();
     Ok(MergedDictionaries {
         key_mappings,
-        values: interleave(&values_arrays, &indices)?,
+        values,
+    })
+}
+
+macro_rules! sort_primitive_helper {
+    ($t:ty, $values:expr, $order:expr) => {{
+        let values = $values.as_primitive::<$t>();
+        sort_nulls_last(&mut $order, values, |a, b| {
+            values.value(a).compare(values.value(b))
+        })
+    }};
+}
+
+/// Returns the indices of `values` in sorted order, with nulls last
+fn sort_order(values: &dyn Array) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    downcast_primitive! {
+        values.data_type() => (sort_primitive_helper, values, order),
+        DataType::Utf8 => sort_bytes(&mut order, values.as_string::<i32>()),
+        DataType::LargeUtf8 => sort_bytes(&mut order, values.as_string::<i64>()),
+        DataType::Binary => sort_bytes(&mut order, values.as_binary::<i32>()),
+        DataType::LargeBinary => sort_bytes(&mut order, values.as_binary::<i64>()),
+        d => unreachable!("cannot merge dictionary values of type {d}"),
+    }
+    order
+}
+
+fn sort_bytes<T: ByteArrayType>(order: &mut [usize], values: &GenericByteArray<T>) {
+    sort_nulls_last(order, values, |a, b| {
+        let a: &[u8] = values.value(a).as_ref();
+        a.cmp(values.value(b).as_ref())
+    })
+}
+
+fn sort_nulls_last(
+    order: &mut [usize],
+    values: &dyn Array,
+    cmp: impl Fn(usize, usize) -> Ordering,
+) {
+    order.sort_unstable_by(|a, b| match (values.is_valid(*a), values.is_valid(*b)) {
+        (true, true) => cmp(*a, *b),
+        (true, false) => Ordering::Less,
+        (false, true) => Ordering::Greater,
+        (false, false) => Ordering::Equal,
     })
 }
 
@@ -365,9 +550,10 @@ mod tests {
     use super::*;
 
     use arrow_array::cast::as_string_array;
+    use arrow_array::types::Float64Type;
     use arrow_array::types::Int32Type;
     use arrow_array::types::Int8Type;
-    use arrow_array::{DictionaryArray, Int32Array, Int8Array, StringArray};
+    use arrow_array::{DictionaryArray, Float64Array, Int32Array, Int8Array, StringArray};
     use arrow_buffer::{BooleanBuffer, Buffer, NullBuffer, OffsetBuffer};
     use std::sync::Arc;
 
@@ -448,7 +634,7 @@ mod tests {
     fn test_merge_strings() {
         let a = DictionaryArray::<Int32Type>::from_iter(["a", "b", "a", "b", "d", "c", "e"]);
         let b = DictionaryArray::<Int32Type>::from_iter(["c", "f", "c", "d", "a", "d"]);
-        let merged = merge_dictionary_values(&[&a, &b], None).unwrap();
+        let merged = merge_dictionary_values(&[&a, &b], None, None).unwrap();
 
         let values = as_string_array(merged.values.as_ref());
         let actual: Vec<_> = values.iter().map(Option::unwrap).collect();
@@ -459,7 +645,7 @@ mod tests {
         assert_eq!(&merged.key_mappings[1], &[3, 5, 2, 0]);
 
         let a_slice = a.slice(1, 4);
-        let merged = merge_dictionary_values(&[&a_slice, &b], None).unwrap();
+        let merged = merge_dictionary_values(&[&a_slice, &b], None, None).unwrap();
 
         let values = as_string_array(merged.values.as_ref());
         let actual: Vec<_> = values.iter().map(Option::unwrap).collect();
@@ -472,7 +658,7 @@ mod tests {
         // Mask out only ["b", "b", "d"] from a
         let a_mask = BooleanBuffer::from_iter([false, true, false, true, true, false, false]);
         let b_mask = BooleanBuffer::new_set(b.len());
-        let merged = merge_dictionary_values(&[&a, &b], Some(&[a_mask, b_mask])).unwrap();
+        let merged = merge_dictionary_values(&[&a, &b], Some(&[a_mask, b_mask]), None).unwrap();
 
         let values = as_string_array(merged.values.as_ref());
         let actual: Vec<_> = values.iter().map(Option::unwrap).collect();
@@ -498,7 +684,7 @@ mod tests {
 
         let b = DictionaryArray::new(Int32Array::new_null(10), Arc::new(StringArray::new_null(0)));
 
-        let merged = merge_dictionary_values(&[&a, &b], None).unwrap();
+        let merged = merge_dictionary_values(&[&a, &b], None, None).unwrap();
         let expected = StringArray::from(vec![None, Some("bingo"), Some("hello")]);
         assert_eq!(merged.values.as_ref(), &expected);
         assert_eq!(merged.key_mappings.len(), 2);
@@ -506,13 +692,32 @@ mod tests {
         assert_eq!(&merged.key_mappings[1], &[] as &[i32; 0]);
     }
 
+    #[test]
+    fn test_merge_distinct_sorted() {
+        let values = Float64Array::from(vec![Some(2.5), None, Some(-1.0), Some(2.5)]);
+        let a = DictionaryArray::new(Int8Array::from(vec![0, 1, 2, 3]), Arc::new(values));
+        let values = Float64Array::from(vec![Some(f64::NAN), Some(-1.0), Some(7.0)]);
+        let b = DictionaryArray::new(Int8Array::from(vec![0, 1]), Arc::new(values));
+
+        let options = DictionaryMergeOptions::default().with_sort(true);
+        let merged = merge_dictionary_values(&[&a, &b], None, Some(&options)).unwrap();
+        let values = merged.values.as_primitive::<Float64Type>();
+        assert_eq!(values.len(), 4);
+        assert_eq!(values.value(0), -1.0);
+        assert_eq!(values.value(1), 2.5);
+        assert!(values.value(2).is_nan());
+        assert!(values.is_null(3));
+        assert_eq!(&merged.key_mappings[0], &[1, 3, 0, 1]);
+        assert_eq!(&merged.key_mappings[1][..2], &[2, 0]);
+    }
+
     #[test]
     fn test_merge_keys_smaller() {
         let values = StringArray::from_iter_values(["a", "b"]);
         let keys = Int32Array::from_iter_values([1]);
         let a = DictionaryArray::new(keys, Arc::new(values));
 
-        let merged = merge_dictionary_values(&[&a], None).unwrap();
+        let merged = merge_dictionary_values(&[&a], None, None).unwrap();
         let expected = StringArray::from(vec!["b"]);
         assert_eq!(merged.values.as_ref(), &expected);
     }
@@ -343,6 +343,12 @@ fn filter_array(values: &dyn Array, predicate: &FilterPredicate) -> Result<Array
             DataType::BinaryView => {
                 Ok(Arc::new(filter_byte_view(values.as_binary_view(), predicate)))
             }
+            DataType::ListView(_) => {
+                Ok(Arc::new(filter_list_view(values.as_list_view::<i32>(), predicate)))
+            }
+            DataType::LargeListView(_) => {
+                Ok(Arc::new(filter_list_view(values.as_list_view::<i64>(), predicate)))
+            }
             DataType::FixedSizeBinary(_) => {
                 Ok(Arc::new(filter_fixed_size_binary(values.as_fixed_size_binary(), predicate)))
             }
@@ -400,17 +406,26 @@ where
     R::Native: AddAssign,
 {
     let run_ends: &RunEndBuffer<R::Native> = array.run_ends();
-    let mut new_run_ends = vec![R::default_value(); run_ends.len()];
+    // Only consider the runs within the slice of `array`, relative to its offset
+    let offset = run_ends.offset() as u64;
+    let (physical_start, physical_len) = match run_ends.is_empty() {
+        true => (0, 0),
+        false => {
+            let start = run_ends.get_start_physical_index();
+            (start, run_ends.get_end_physical_index() - start + 1)
+        }
+    };
+    let mut new_run_ends = vec![R::default_value(); physical_len];
 
     let mut start = 0u64;
     let mut j = 0;
     let mut count = R::default_value();
     let filter_values = predicate.filter.values();
-    let run_ends = run_ends.inner();
+    let run_ends = &run_ends.values()[physical_start..physical_start + physical_len];
 
     let pred: BooleanArray = BooleanBuffer::collect_bool(run_ends.len(), |i| {
         let mut keep = false;
-        let mut end = run_ends[i].into() as u64;
+        let mut end = (run_ends[i].into() as u64).saturating_sub(offset);
         let difference = end.saturating_sub(filter_values.len() as u64);
         end -= difference;
 
@@ -430,7 +445,7 @@ where
 
     new_run_ends.truncate(j);
 
-    let values = array.values();
+    let values = array.sliced_values();
     let values = filter(&values, &pred)?;
 
     let run_ends = PrimitiveArray::<R>::new(new_run_ends.into(), None);
@@ -732,6 +747,29 @@ fn filter_byte_view<T: ByteViewType>(
     GenericByteViewArray::from(unsafe { builder.build_unchecked() })
 }
 
+/// `filter` implementation for list view arrays
+///
+/// Only the offsets and sizes are filtered, the values are shared with the input array
+fn filter_list_view<O: OffsetSizeTrait>(
+    array: &GenericListViewArray<O>,
+    predicate: &FilterPredicate,
+) -> GenericListViewArray<O> {
+    let offsets = filter_native(array.value_offsets(), predicate);
+    let sizes = filter_native(array.value_sizes(), predicate);
+
+    let mut builder = ArrayDataBuilder::new(array.data_type().clone())
+        .len(predicate.count)
+        .add_buffer(offsets)
+        .add_buffer(sizes)
+        .add_child_data(array.values().to_data());
+
+    if let Some((null_count, nulls)) = filter_null_mask(array.nulls(), predicate) {
+        builder = builder.null_count(null_count).null_bit_buffer(Some(nulls));
+    }
+
+    GenericListViewArray::from(unsafe { builder.build_unchecked() })
+}
+
 fn filter_fixed_size_binary(
     array: &FixedSizeBinaryArray,
     predicate: &FilterPredicate,
@@ -1279,6 +1317,25 @@ mod tests {
         assert_eq!(0, actual.len());
     }
 
+    #[test]
+    fn test_filter_run_end_encoding_array_sliced() {
+        let run_ends = Int32Array::from(vec![2, 3, 8, 10]);
+        let values = Int32Array::from(vec![7, -2, 9, -8]);
+        let a = RunArray::try_new(&run_ends, &values).expect("Failed to create RunArray");
+        // Logical values [7, -2, 9, 9, 9, 9]
+        let a = a.slice(1, 6);
+        let b = BooleanArray::from(vec![true, false, true, false, true, true]);
+        let c = filter(&a, &b).unwrap();
+        let actual: &RunArray<Int32Type> = as_run_array(&c);
+        assert_eq!(4, actual.len());
+
+        assert_eq!(actual.run_ends().values(), &[1, 4]);
+        assert_eq!(
+            actual.values().as_primitive::<Int32Type>(),
+            &Int32Array::from(vec![7, 9])
+        );
+    }
+
     #[test]
     fn test_filter_run_end_encoding_array_max_value_gt_predicate_len() {
         let run_ends = Int64Array::from(vec![2, 3, 8, 10]);
@@ -1320,6 +1377,38 @@ mod tests {
         assert_eq!("world", values.value(d.keys().value(1) as usize));
     }
 
+    #[test]
+    fn test_filter_list_view_array() {
+        let field = Arc::new(Field::new_list_field(DataType::Int32, true));
+        // [[3, 4], null, [1], [], [1, 2, 3]]
+        let list = ListViewArray::new(
+            field,
+            vec![2, 1, 0, 0, 0].into(),
+            vec![2, 2, 1, 0, 3].into(),
+            Arc::new(Int32Array::from(vec![1, 2, 3, 4])),
+            Some(NullBuffer::from(vec![true, false, true, true, true])),
+        );
+
+        let predicate = BooleanArray::from(vec![true, true, false, false, true]);
+        let filtered = filter(&list, &predicate).unwrap();
+        let filtered = filtered.as_list_view::<i32>();
+        assert_eq!(filtered.values(), list.values());
+
+        let mut builder = ListViewBuilder::new(Int32Builder::new());
+        builder.append_value([Some(3), Some(4)]);
+        builder.append_null();
+        builder.append_value([Some(1), Some(2), Some(3)]);
+        assert_eq!(filtered, &builder.finish());
+
+        let sliced = list.slice(2, 3);
+        let predicate = BooleanArray::from(vec![false, true, true]);
+        let filtered = filter(&sliced, &predicate).unwrap();
+        let mut builder = ListViewBuilder::new(Int32Builder::new());
+        builder.append_value([] as [Option<i32>; 0]);
+        builder.append_value([Some(1), Some(2), Some(3)]);
+        assert_eq!(filtered.as_list_view::<i32>(), &builder.finish());
+    }
+
     #[test]
     fn test_filter_list_array() {
         let value_data = ArrayData::builder(DataType::Int32)
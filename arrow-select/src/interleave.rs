@@ -17,7 +17,9 @@
 
 //! Interleave elements from multiple arrays
 
-use crate::dictionary::{merge_dictionary_values, should_merge_dictionary_values};
+use crate::dictionary::{
+    merge_dictionary_values, should_merge_dictionary_values, DictionaryMergeOptions,
+};
 use arrow_array::builder::{BooleanBufferBuilder, PrimitiveBuilder};
 use arrow_array::cast::AsArray;
 use arrow_array::types::*;
@@ -35,11 +37,21 @@ macro_rules! primitive_helper {
 }
 
 macro_rules! dict_helper {
-    ($t:ty, $values:expr, $indices:expr) => {
-        Ok(Arc::new(interleave_dictionaries::<$t>($values, $indices)?) as _)
+    ($t:ty, $values:expr, $indices:expr, $options:expr) => {
+        Ok(Arc::new(interleave_dictionaries::<$t>($values, $indices, $options)?) as _)
     };
 }
 
+/// Options that define how [`interleave_with_options`] should behave
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct InterleaveOptions {
+    /// How to merge the values of dictionary arrays
+    ///
+    /// If `None` (the default) dictionaries are only merged when a heuristic suggests
+    /// it is worthwhile, see [`DictionaryMergeOptions`]
+    pub dictionary: Option<DictionaryMergeOptions>,
+}
+
 ///
 /// Takes elements by index from a list of [`Array`], creating a new [`Array`] from those values.
 ///
@@ -70,6 +82,34 @@ macro_rules! dict_helper {
 pub fn interleave(
     values: &[&dyn Array],
     indices: &[(usize, usize)],
+) -> Result<ArrayRef, ArrowError> {
+    interleave_with_options(values, indices, &InterleaveOptions::default())
+}
+
+/// Interleaves the elements of `values` selected by `indices`, as for [`interleave`],
+/// with the provided [`InterleaveOptions`]
+///
+/// ```
+/// # use arrow_array::{Array, DictionaryArray, StringArray};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_select::dictionary::DictionaryMergeOptions;
+/// # use arrow_select::interleave::{interleave_with_options, InterleaveOptions};
+/// let a: DictionaryArray<Int32Type> = vec!["c", "a"].into_iter().collect();
+/// let b: DictionaryArray<Int32Type> = vec!["b", "a"].into_iter().collect();
+/// let options = InterleaveOptions {
+///     dictionary: Some(DictionaryMergeOptions::default().with_sort(true)),
+/// };
+/// let result = interleave_with_options(&[&a, &b], &[(0, 0), (1, 1), (0, 1), (1, 0)], &options);
+/// let result = result.unwrap();
+/// let result = result.as_dictionary::<Int32Type>();
+/// assert_eq!(result.values().as_ref(), &StringArray::from(vec!["a", "b", "c"]));
+/// assert_eq!(result.keys().values(), &[2, 0, 0, 1]);
+/// ```
+pub fn interleave_with_options(
+    values: &[&dyn Array],
+    indices: &[(usize, usize)],
+    options: &InterleaveOptions,
 ) -> Result<ArrayRef, ArrowError> {
     if values.is_empty() {
         return Err(ArrowError::InvalidArgumentError(
@@ -101,7 +141,7 @@ pub fn interleave(
         DataType::BinaryView => interleave_views::<BinaryViewType>(values, indices),
         DataType::Utf8View => interleave_views::<StringViewType>(values, indices),
         DataType::Dictionary(k, _) => downcast_integer! {
-            k.as_ref() => (dict_helper, values, indices),
+            k.as_ref() => (dict_helper, values, indices, options),
             _ => unreachable!("illegal dictionary key type {k}")
         },
         _ => interleave_fallback(values, indices)
@@ -192,9 +232,15 @@ fn interleave_bytes<T: ByteArrayType>(
 fn interleave_dictionaries<K: ArrowDictionaryKeyType>(
     arrays: &[&dyn Array],
     indices: &[(usize, usize)],
+    options: &InterleaveOptions,
 ) -> Result<ArrayRef, ArrowError> {
     let dictionaries: Vec<_> = arrays.iter().map(|x| x.as_dictionary::<K>()).collect();
-    if !should_merge_dictionary_values::<K>(&dictionaries, indices.len()) {
+    let merge_options = options
+        .dictionary
+        .as_ref()
+        .and_then(|o| o.for_values(dictionaries[0].values().data_type()));
+    if merge_options.is_none() && !should_merge_dictionary_values::<K>(&dictionaries, indices.len())
+    {
         return interleave_fallback(arrays, indices);
     }
 
@@ -214,7 +260,7 @@ fn interleave_dictionaries<K: ArrowDictionaryKeyType>(
         })
         .collect();
 
-    let merged = merge_dictionary_values(&dictionaries, Some(&masks))?;
+    let merged = merge_dictionary_values(&dictionaries, Some(&masks), merge_options)?;
 
     // Recompute keys
     let mut keys = PrimitiveBuilder::<K>::with_capacity(indices.len());
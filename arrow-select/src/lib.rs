@@ -29,7 +29,9 @@ pub mod concat;
 pub mod dictionary;
 pub mod filter;
 pub mod interleave;
+pub mod map;
 pub mod nullif;
+pub mod selection;
 pub mod take;
 pub mod union_extract;
 pub mod window;
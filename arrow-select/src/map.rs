@@ -0,0 +1,298 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines kernels for accessing the keys and values of a [`MapArray`]
+
+use crate::take::take;
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
+use arrow_array::*;
+use arrow_buffer::ArrowNativeType;
+use arrow_schema::{ArrowError, DataType, Field};
+use std::sync::Arc;
+
+/// Returns the keys of each entry of `map` as a [`ListArray`]
+///
+/// The returned list is null where `map` is null.
+///
+/// ```
+/// # use arrow_array::builder::{Int32Builder, MapBuilder, StringBuilder};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::{Array, StringArray};
+/// # use arrow_select::map::map_keys;
+/// let mut builder = MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+/// builder.keys().append_value("a");
+/// builder.values().append_value(1);
+/// builder.append(true).unwrap();
+/// builder.append(false).unwrap();
+/// let map = builder.finish();
+///
+/// let keys = map_keys(&map);
+/// assert_eq!(keys.value(0).as_string::<i32>(), &StringArray::from(vec!["a"]));
+/// assert!(keys.is_null(1));
+/// ```
+pub fn map_keys(map: &MapArray) -> ListArray {
+    let field = Arc::new(Field::new_list_field(map.key_type().clone(), false));
+    ListArray::new(
+        field,
+        map.offsets().clone(),
+        Arc::clone(map.keys()),
+        map.nulls().cloned(),
+    )
+}
+
+/// Returns the values of each entry of `map` as a [`ListArray`]
+///
+/// The returned list is null where `map` is null.
+///
+/// ```
+/// # use arrow_array::builder::{Int32Builder, MapBuilder, StringBuilder};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_array::Int32Array;
+/// # use arrow_select::map::map_values;
+/// let mut builder = MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+/// builder.keys().append_value("a");
+/// builder.values().append_value(1);
+/// builder.keys().append_value("b");
+/// builder.values().append_null();
+/// builder.append(true).unwrap();
+/// let map = builder.finish();
+///
+/// let values = map_values(&map);
+/// assert_eq!(values.value(0).as_primitive::<Int32Type>(), &Int32Array::from(vec![Some(1), None]));
+/// ```
+pub fn map_values(map: &MapArray) -> ListArray {
+    let value_field = map.entries().fields()[1].as_ref();
+    let field = Arc::new(Field::new_list_field(
+        map.value_type().clone(),
+        value_field.is_nullable(),
+    ));
+    ListArray::new(
+        field,
+        map.offsets().clone(),
+        Arc::clone(map.values()),
+        map.nulls().cloned(),
+    )
+}
+
+/// Looks up `key` in each row of `map`, returning the corresponding value
+///
+/// `key` may be a scalar, which is looked up in every row, or an array of the same
+/// length as `map`, in which case each row is searched for the key at the same index.
+/// The key must be of the same type as the keys of `map`.
+///
+/// The result is null where `map` is null, `key` is null or the key is not found. If a
+/// row contains the key more than once, the value of its first occurrence is returned.
+///
+/// ```
+/// # use arrow_array::builder::{Int32Builder, MapBuilder, StringBuilder};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_array::{Int32Array, Scalar, StringArray};
+/// # use arrow_select::map::element_at;
+/// let mut builder = MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+/// builder.keys().append_value("a");
+/// builder.values().append_value(1);
+/// builder.keys().append_value("b");
+/// builder.values().append_value(2);
+/// builder.append(true).unwrap();
+/// builder.keys().append_value("a");
+/// builder.values().append_value(3);
+/// builder.append(true).unwrap();
+/// let map = builder.finish();
+///
+/// let key = Scalar::new(StringArray::from(vec!["b"]));
+/// let values = element_at(&map, &key).unwrap();
+/// assert_eq!(values.as_primitive::<Int32Type>(), &Int32Array::from(vec![Some(2), None]));
+/// ```
+pub fn element_at(map: &MapArray, key: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    let (key, is_scalar) = key.get();
+    if key.data_type() != map.key_type() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "element_at key of type {} does not match map key type {}",
+            key.data_type(),
+            map.key_type()
+        )));
+    }
+    if !is_scalar && key.len() != map.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "element_at key array of length {} does not match map of length {}",
+            key.len(),
+            map.len()
+        )));
+    }
+
+    let eq = key_eq(map.keys().as_ref(), key)?;
+    let offsets = map.value_offsets();
+    let indices: UInt64Array = (0..map.len())
+        .map(|i| {
+            let k = if is_scalar { 0 } else { i };
+            if map.is_null(i) || key.is_null(k) {
+                return None;
+            }
+            let start = offsets[i].as_usize();
+            let end = offsets[i + 1].as_usize();
+            (start..end).find(|&j| eq(j, k)).map(|j| j as u64)
+        })
+        .collect();
+    take(map.values().as_ref(), &indices, None)
+}
+
+type KeyEq<'a> = Box<dyn Fn(usize, usize) -> bool + 'a>;
+
+macro_rules! primitive_helper {
+    ($t:ty, $keys:ident, $key:ident) => {
+        Ok(primitive_eq::<$t>($keys, $key))
+    };
+}
+
+/// Returns a function comparing the valid key at the first index of `keys` with the
+/// valid key at the second index of `key`, which must be of the same type
+fn key_eq<'a>(keys: &'a dyn Array, key: &'a dyn Array) -> Result<KeyEq<'a>, ArrowError> {
+    downcast_primitive! {
+        keys.data_type() => (primitive_helper, keys, key),
+        DataType::Boolean => {
+            let (keys, key) = (keys.as_boolean(), key.as_boolean());
+            Ok(Box::new(move |i, j| keys.value(i) == key.value(j)))
+        }
+        DataType::Utf8 => Ok(bytes_eq::<Utf8Type>(keys, key)),
+        DataType::LargeUtf8 => Ok(bytes_eq::<LargeUtf8Type>(keys, key)),
+        DataType::Binary => Ok(bytes_eq::<BinaryType>(keys, key)),
+        DataType::LargeBinary => Ok(bytes_eq::<LargeBinaryType>(keys, key)),
+        DataType::Utf8View => Ok(byte_view_eq::<StringViewType>(keys, key)),
+        DataType::BinaryView => Ok(byte_view_eq::<BinaryViewType>(keys, key)),
+        _ => {
+            let (keys, key) = (keys.to_data(), key.to_data());
+            Ok(Box::new(move |i, j| keys.slice(i, 1) == key.slice(j, 1)))
+        }
+    }
+}
+
+fn primitive_eq<'a, T: ArrowPrimitiveType>(keys: &'a dyn Array, key: &'a dyn Array) -> KeyEq<'a> {
+    let (keys, key) = (keys.as_primitive::<T>(), key.as_primitive::<T>());
+    Box::new(move |i, j| keys.value(i).is_eq(key.value(j)))
+}
+
+fn bytes_eq<'a, T: ByteArrayType>(keys: &'a dyn Array, key: &'a dyn Array) -> KeyEq<'a> {
+    let (keys, key) = (keys.as_bytes::<T>(), key.as_bytes::<T>());
+    Box::new(move |i, j| {
+        AsRef::<[u8]>::as_ref(keys.value(i)) == AsRef::<[u8]>::as_ref(key.value(j))
+    })
+}
+
+fn byte_view_eq<'a, T: ByteViewType>(keys: &'a dyn Array, key: &'a dyn Array) -> KeyEq<'a> {
+    let (keys, key) = (keys.as_byte_view::<T>(), key.as_byte_view::<T>());
+    Box::new(move |i, j| {
+        AsRef::<[u8]>::as_ref(keys.value(i)) == AsRef::<[u8]>::as_ref(key.value(j))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::builder::{Int32Builder, MapBuilder, StringBuilder};
+
+    fn build_map() -> MapArray {
+        let mut builder = MapBuilder::new(None, Int32Builder::new(), StringBuilder::new());
+        for (k, v) in [(1, Some("a")), (2, None), (1, Some("c"))] {
+            builder.keys().append_value(k);
+            builder.values().append_option(v);
+        }
+        builder.append(true).unwrap();
+        builder.append(false).unwrap();
+        builder.append(true).unwrap();
+        builder.keys().append_value(2);
+        builder.values().append_value("d");
+        builder.append(true).unwrap();
+        builder.finish()
+    }
+
+    #[test]
+    fn test_map_keys_values() {
+        let map = build_map().slice(1, 3);
+        let keys = map_keys(&map);
+        assert_eq!(keys.len(), 3);
+        assert!(keys.is_null(0));
+        assert_eq!(keys.value_length(1), 0);
+        assert_eq!(
+            keys.value(2).as_primitive::<Int32Type>(),
+            &Int32Array::from(vec![2])
+        );
+
+        let values = map_values(&map);
+        assert!(values.is_null(0));
+        assert_eq!(
+            values.value(2).as_string::<i32>(),
+            &StringArray::from(vec!["d"])
+        );
+    }
+
+    #[test]
+    fn test_element_at() {
+        let map = build_map();
+
+        let key = Scalar::new(Int32Array::from(vec![1]));
+        let values = element_at(&map, &key).unwrap();
+        let expected = StringArray::from(vec![Some("a"), None, None, None]);
+        assert_eq!(values.as_string::<i32>(), &expected);
+
+        let keys = Int32Array::from(vec![Some(2), Some(1), None, Some(2)]);
+        let values = element_at(&map, &keys).unwrap();
+        let expected = StringArray::from(vec![None, None, None, Some("d")]);
+        assert_eq!(values.as_string::<i32>(), &expected);
+
+        let values = element_at(&map.slice(3, 1), &Scalar::new(Int32Array::from(vec![2])));
+        let expected = StringArray::from(vec!["d"]);
+        assert_eq!(values.unwrap().as_string::<i32>(), &expected);
+
+        let err = element_at(&map, &Scalar::new(StringArray::from(vec!["a"]))).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: element_at key of type Utf8 does not match map key type Int32"
+        );
+
+        let err = element_at(&map, &Int32Array::from(vec![1])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: element_at key array of length 1 does not match map of length 4"
+        );
+    }
+
+    #[test]
+    fn test_element_at_nested_key() {
+        let mut builder = MapBuilder::new(
+            None,
+            arrow_array::builder::ListBuilder::new(Int32Builder::new()),
+            Int32Builder::new(),
+        );
+        for (k, v) in [(vec![1, 2], 10), (vec![3], 20)] {
+            builder.keys().values().append_slice(&k);
+            builder.keys().append(true);
+            builder.values().append_value(v);
+        }
+        builder.append(true).unwrap();
+        let map = builder.finish();
+
+        let key = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![Some(vec![Some(3)])]);
+        let values = element_at(&map, &Scalar::new(key)).unwrap();
+        assert_eq!(
+            values.as_primitive::<Int32Type>(),
+            &Int32Array::from(vec![20])
+        );
+    }
+}
@@ -0,0 +1,365 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines [`SelectionVector`] to lazily compose filters and takes
+
+use crate::filter::{filter, filter_record_batch, prep_null_mask_filter};
+use crate::take::{take, take_record_batch, TakeOptions};
+use arrow_array::cast::AsArray;
+use arrow_array::*;
+use arrow_buffer::{bit_util, BooleanBuffer, MutableBuffer};
+use arrow_schema::ArrowError;
+
+/// A selection of rows from an input of a known length, composed lazily from
+/// a sequence of filters and takes
+///
+/// Query pipelines frequently apply several filters and takes in succession, for
+/// example evaluating one predicate, then another on the rows that remain. Applying
+/// each of these eagerly with [`filter`] or [`take`] materializes an intermediate
+/// array at each step. A [`SelectionVector`] instead composes these operations on
+/// the selected row positions, so that data is only copied once, by [`Self::apply`].
+///
+/// Internally a selection is stored as either all rows, a bitmask of the selected
+/// rows, or a list of row indices, whichever the composed operations require.
+///
+/// # Example
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, BooleanArray, Int32Array, UInt32Array};
+/// # use arrow_select::selection::SelectionVector;
+/// let values = Int32Array::from(vec![1, 2, 3, 4, 5, 6]);
+///
+/// // Select the even values, then those greater than 2, then reverse them
+/// let selection = SelectionVector::all(values.len())
+///     .filter(&BooleanArray::from(vec![false, true, false, true, false, true]))
+///     .unwrap()
+///     .filter(&BooleanArray::from(vec![false, true, true]))
+///     .unwrap()
+///     .take(&UInt32Array::from(vec![1, 0]))
+///     .unwrap();
+///
+/// // Only the final result is materialized
+/// let result = selection.apply(&values).unwrap();
+/// let expected: ArrayRef = Arc::new(Int32Array::from(vec![6, 4]));
+/// assert_eq!(&result, &expected);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SelectionVector {
+    /// The number of rows in the input
+    len: usize,
+    selection: Selection,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Selection {
+    /// All rows are selected in order
+    All,
+    /// The rows with a set bit are selected in order
+    Mask(BooleanBuffer),
+    /// The rows at the given indices are selected, a null index selects a null
+    Indices(UInt32Array),
+}
+
+impl SelectionVector {
+    /// Create a [`SelectionVector`] selecting all `len` rows of the input
+    pub fn all(len: usize) -> Self {
+        Self {
+            len,
+            selection: Selection::All,
+        }
+    }
+
+    /// Create a [`SelectionVector`] selecting the rows where `predicate` is `true`
+    ///
+    /// A `NULL` in `predicate` is treated as `false`, as in [`filter`]
+    pub fn from_filter(predicate: &BooleanArray) -> Self {
+        Self {
+            len: predicate.len(),
+            selection: Selection::Mask(mask_values(predicate)),
+        }
+    }
+
+    /// Create a [`SelectionVector`] selecting the rows at `indices` from an input of `len` rows
+    ///
+    /// A `NULL` index selects a `NULL`, as in [`take`]. Returns an error if any index
+    /// is out of bounds
+    pub fn try_from_indices(len: usize, indices: UInt32Array) -> Result<Self, ArrowError> {
+        check_bounds(&indices, len)?;
+        Ok(Self {
+            len,
+            selection: Selection::Indices(indices),
+        })
+    }
+
+    /// Returns the number of rows in the input
+    pub fn input_len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the number of rows selected, i.e. the length of the output of [`Self::apply`]
+    pub fn len(&self) -> usize {
+        match &self.selection {
+            Selection::All => self.len,
+            Selection::Mask(mask) => mask.count_set_bits(),
+            Selection::Indices(indices) => indices.len(),
+        }
+    }
+
+    /// Returns true if no rows are selected
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Further select the rows of this selection where `predicate` is `true`
+    ///
+    /// `predicate` applies to the rows selected by this [`SelectionVector`], and so must
+    /// have length [`Self::len`]. A `NULL` in `predicate` is treated as `false`
+    pub fn filter(&self, predicate: &BooleanArray) -> Result<Self, ArrowError> {
+        let selected = self.len();
+        if predicate.len() != selected {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Filter predicate of length {} does not match selection of length {selected}",
+                predicate.len()
+            )));
+        }
+
+        let selection = match &self.selection {
+            Selection::All => Selection::Mask(mask_values(predicate)),
+            Selection::Mask(mask) => {
+                let predicate = mask_values(predicate);
+                let mut buffer = MutableBuffer::new_null(self.len);
+                let rows = mask.set_indices().zip(predicate.iter());
+                rows.filter(|(_, keep)| *keep)
+                    .for_each(|(row, _)| bit_util::set_bit(buffer.as_slice_mut(), row));
+                Selection::Mask(BooleanBuffer::new(buffer.into(), 0, self.len))
+            }
+            Selection::Indices(indices) => {
+                Selection::Indices(filter(indices, predicate)?.as_primitive().clone())
+            }
+        };
+
+        Ok(Self {
+            len: self.len,
+            selection,
+        })
+    }
+
+    /// Further select the rows of this selection at `indices`
+    ///
+    /// `indices` index into the rows selected by this [`SelectionVector`], and so must
+    /// be less than [`Self::len`]. A `NULL` index selects a `NULL`
+    pub fn take(&self, indices: &UInt32Array) -> Result<Self, ArrowError> {
+        let selection = match &self.selection {
+            Selection::All => {
+                check_bounds(indices, self.len)?;
+                Selection::Indices(indices.clone())
+            }
+            Selection::Mask(_) | Selection::Indices(_) => {
+                let options = TakeOptions { check_bounds: true };
+                let current = self.to_indices()?;
+                let taken = take(&current, indices, Some(options))?;
+                Selection::Indices(taken.as_primitive().clone())
+            }
+        };
+
+        Ok(Self {
+            len: self.len,
+            selection,
+        })
+    }
+
+    /// Returns the indices of the input rows selected by this [`SelectionVector`]
+    ///
+    /// Returns an error if the input is too large to be indexed by `u32`
+    pub fn to_indices(&self) -> Result<UInt32Array, ArrowError> {
+        let to_u32 = |idx: usize| {
+            u32::try_from(idx).map_err(|_| {
+                ArrowError::InvalidArgumentError(format!(
+                    "Cannot represent selection of {} rows as u32 indices",
+                    self.len
+                ))
+            })
+        };
+
+        match &self.selection {
+            Selection::All => (0..self.len).map(to_u32).collect(),
+            Selection::Mask(mask) => mask.set_indices().map(to_u32).collect(),
+            Selection::Indices(indices) => Ok(indices.clone()),
+        }
+    }
+
+    /// Materialize the rows of `values` selected by this [`SelectionVector`]
+    ///
+    /// `values` must have length [`Self::input_len`]
+    pub fn apply(&self, values: &dyn Array) -> Result<ArrayRef, ArrowError> {
+        self.check_input_len(values.len())?;
+        match &self.selection {
+            Selection::All => Ok(values.slice(0, values.len())),
+            Selection::Mask(mask) => filter(values, &BooleanArray::new(mask.clone(), None)),
+            Selection::Indices(indices) => take(values, indices, None),
+        }
+    }
+
+    /// Materialize the rows of `batch` selected by this [`SelectionVector`]
+    ///
+    /// `batch` must have [`Self::input_len`] rows
+    pub fn apply_batch(&self, batch: &RecordBatch) -> Result<RecordBatch, ArrowError> {
+        self.check_input_len(batch.num_rows())?;
+        match &self.selection {
+            Selection::All => Ok(batch.clone()),
+            Selection::Mask(mask) => {
+                filter_record_batch(batch, &BooleanArray::new(mask.clone(), None))
+            }
+            Selection::Indices(indices) => take_record_batch(batch, indices),
+        }
+    }
+
+    fn check_input_len(&self, len: usize) -> Result<(), ArrowError> {
+        if len != self.len {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Cannot apply selection over {} rows to input of {len} rows",
+                self.len
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Returns the values of `predicate` with `NULL` treated as `false`
+fn mask_values(predicate: &BooleanArray) -> BooleanBuffer {
+    match predicate.null_count() {
+        0 => predicate.values().clone(),
+        _ => prep_null_mask_filter(predicate).values().clone(),
+    }
+}
+
+fn check_bounds(indices: &UInt32Array, len: usize) -> Result<(), ArrowError> {
+    match indices.iter().flatten().find(|idx| *idx as usize >= len) {
+        Some(idx) => Err(ArrowError::InvalidArgumentError(format!(
+            "Take index {idx} out of bounds for selection of length {len}"
+        ))),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_compose() {
+        let values = StringArray::from_iter((0..10).map(|i| Some(format!("v{i}"))));
+        let p1 = BooleanArray::from_iter((0..10).map(|i| Some(i % 2 == 0)));
+        let p2 = BooleanArray::from(vec![Some(true), None, Some(true), Some(false), Some(true)]);
+        let indices = UInt32Array::from(vec![Some(2), None, Some(0), Some(2)]);
+        let p3 = BooleanArray::from(vec![true, true, false, true]);
+
+        let selection = SelectionVector::all(10);
+        assert_eq!(selection.len(), 10);
+        let selection = selection.filter(&p1).unwrap();
+        assert_eq!(selection.len(), 5);
+        let selection = selection.filter(&p2).unwrap();
+        assert_eq!(selection.len(), 3);
+        let selection = selection.take(&indices).unwrap();
+        assert_eq!(selection.len(), 4);
+        let selection = selection.filter(&p3).unwrap();
+        assert_eq!(selection.len(), 3);
+        assert_eq!(selection.input_len(), 10);
+
+        // Compare to eager evaluation
+        let expected = filter(&values, &p1).unwrap();
+        let expected = filter(&expected, &p2).unwrap();
+        let expected = take(&expected, &indices, None).unwrap();
+        let expected = filter(&expected, &p3).unwrap();
+
+        let actual = selection.apply(&values).unwrap();
+        assert_eq!(&actual, &expected);
+        assert_eq!(
+            actual.as_string::<i32>(),
+            &StringArray::from(vec![Some("v8"), None, Some("v8")])
+        );
+    }
+
+    #[test]
+    fn test_apply_batch() {
+        let batch = RecordBatch::try_from_iter([
+            (
+                "a",
+                Arc::new(Int32Array::from(vec![1, 2, 3, 4])) as ArrayRef,
+            ),
+            (
+                "b",
+                Arc::new(StringArray::from(vec!["w", "x", "y", "z"])) as ArrayRef,
+            ),
+        ])
+        .unwrap();
+
+        let selection = SelectionVector::all(4);
+        assert_eq!(selection.apply_batch(&batch).unwrap(), batch);
+
+        let selection =
+            SelectionVector::from_filter(&BooleanArray::from(vec![true, false, true, true]));
+        assert_eq!(
+            selection.to_indices().unwrap(),
+            UInt32Array::from(vec![0, 2, 3])
+        );
+        let result = selection.apply_batch(&batch).unwrap();
+        assert_eq!(result.num_rows(), 3);
+        assert_eq!(
+            result.column(1).as_string::<i32>(),
+            &StringArray::from(vec!["w", "y", "z"])
+        );
+
+        let selection = selection.take(&UInt32Array::from(vec![2, 0])).unwrap();
+        let result = selection.apply_batch(&batch).unwrap();
+        assert_eq!(
+            result
+                .column(0)
+                .as_primitive::<arrow_array::types::Int32Type>(),
+            &Int32Array::from(vec![4, 1])
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let selection = SelectionVector::from_filter(&BooleanArray::from(vec![true, false, true]));
+
+        let err = selection
+            .filter(&BooleanArray::from(vec![true]))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Filter predicate of length 1 does not match selection of length 2"
+        );
+
+        let err = selection.take(&UInt32Array::from(vec![2])).unwrap_err();
+        assert!(err.to_string().contains("out of bounds"), "{err}");
+
+        let err = SelectionVector::try_from_indices(2, UInt32Array::from(vec![0, 2])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Take index 2 out of bounds for selection of length 2"
+        );
+
+        let err = selection.apply(&Int32Array::from(vec![1, 2])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot apply selection over 3 rows to input of 2 rows"
+        );
+    }
+}
@@ -218,6 +218,12 @@ fn take_impl<IndexType: ArrowPrimitiveType>(
         DataType::LargeList(_) => {
             Ok(Arc::new(take_list::<_, Int64Type>(values.as_list(), indices)?))
         }
+        DataType::ListView(_) => {
+            Ok(Arc::new(take_list_view(values.as_list_view::<i32>(), indices)))
+        }
+        DataType::LargeListView(_) => {
+            Ok(Arc::new(take_list_view(values.as_list_view::<i64>(), indices)))
+        }
         DataType::FixedSizeList(_, length) => {
             let values = values
                 .as_any()
@@ -621,6 +627,24 @@ where
     Ok(GenericListArray::<OffsetType::Native>::from(list_data))
 }
 
+/// `take` implementation for list view arrays
+///
+/// As the offsets and sizes of a list view need not be ordered, only these are
+/// taken, and the values are shared with the input array
+fn take_list_view<O: OffsetSizeTrait, IndexType: ArrowPrimitiveType>(
+    values: &GenericListViewArray<O>,
+    indices: &PrimitiveArray<IndexType>,
+) -> GenericListViewArray<O> {
+    let offsets = take_native(values.offsets(), indices);
+    let sizes = take_native(values.sizes(), indices);
+    let nulls = take_nulls(values.nulls(), indices);
+    let field = match values.data_type() {
+        DataType::ListView(f) | DataType::LargeListView(f) => f.clone(),
+        _ => unreachable!(),
+    };
+    GenericListViewArray::new(field, offsets, sizes, values.values().clone(), nulls)
+}
+
 /// `take` implementation for `FixedSizeListArray`
 ///
 /// Calculates the index and indexed offset for the inner array,
@@ -1871,6 +1895,52 @@ mod tests {
         test_take_list_with_nulls!(i64, LargeList, LargeListArray);
     }
 
+    fn test_take_list_view_generic<O: OffsetSizeTrait>() {
+        let field = Arc::new(Field::new_list_field(DataType::Int32, true));
+        // [[3, 4], null, [1], [], [1, 2, 3]]
+        let values = Arc::new(Int32Array::from(vec![1, 2, 3, 4]));
+        let list = GenericListViewArray::<O>::new(
+            field.clone(),
+            ScalarBuffer::from(vec![
+                O::usize_as(2),
+                O::usize_as(1),
+                O::zero(),
+                O::zero(),
+                O::zero(),
+            ]),
+            ScalarBuffer::from(vec![
+                O::usize_as(2),
+                O::usize_as(2),
+                O::one(),
+                O::zero(),
+                O::usize_as(3),
+            ]),
+            values.clone(),
+            Some(NullBuffer::from(vec![true, false, true, true, true])),
+        );
+
+        let indices = UInt32Array::from(vec![Some(4), None, Some(0), Some(1), Some(4)]);
+        let taken = take(&list, &indices, None).unwrap();
+        let taken = taken.as_list_view::<O>();
+
+        // The values are shared with the input
+        assert!(Arc::ptr_eq(taken.values(), list.values()));
+
+        let mut builder = GenericListViewBuilder::<O, _>::new(Int32Builder::new());
+        builder.append_value([Some(1), Some(2), Some(3)]);
+        builder.append_null();
+        builder.append_value([Some(3), Some(4)]);
+        builder.append_null();
+        builder.append_value([Some(1), Some(2), Some(3)]);
+        assert_eq!(taken, &builder.finish());
+    }
+
+    #[test]
+    fn test_take_list_view() {
+        test_take_list_view_generic::<i32>();
+        test_take_list_view_generic::<i64>();
+    }
+
     #[test]
     fn test_take_fixed_size_list() {
         do_take_fixed_size_list_test::<Int32Type>(
@@ -18,7 +18,8 @@
 //! Defines windowing functions, like `shift`ing
 
 use crate::concat::concat;
-use arrow_array::{make_array, new_null_array, Array, ArrayRef};
+use crate::take::take;
+use arrow_array::{make_array, new_null_array, Array, ArrayRef, Datum, UInt32Array};
 use arrow_schema::ArrowError;
 use num::abs;
 
@@ -59,30 +60,97 @@ pub fn shift(array: &dyn Array, offset: i64) -> Result<ArrayRef, ArrowError> {
     } else if offset == i64::MIN || abs(offset) >= value_len {
         Ok(new_null_array(array.data_type(), array.len()))
     } else {
-        // Concatenate both arrays, add nulls after if shift > 0 else before
-        if offset > 0 {
-            let length = array.len() - offset as usize;
-            let slice = array.slice(0, length);
-
-            // Generate array with remaining `null` items
-            let null_arr = new_null_array(array.data_type(), offset as usize);
-            concat(&[null_arr.as_ref(), slice.as_ref()])
-        } else {
-            let offset = -offset as usize;
-            let length = array.len() - offset;
-            let slice = array.slice(offset, length);
-
-            // Generate array with remaining `null` items
-            let null_arr = new_null_array(array.data_type(), offset);
-            concat(&[slice.as_ref(), null_arr.as_ref()])
-        }
+        let fill = new_null_array(array.data_type(), offset.unsigned_abs() as usize);
+        shift_impl(array, offset, fill)
+    }
+}
+
+/// Shifts array by defined number of items (to left or right), as [`shift`], filling the
+/// vacated positions with `fill_value` instead of nulls
+///
+/// This is the building block of the `LAG` and `LEAD` window functions, where `LAG(x, n, d)`
+/// is `shift_with_fill(x, n, d)` and `LEAD(x, n, d)` is `shift_with_fill(x, -n, d)`.
+///
+/// `fill_value` must be a [`Scalar`](arrow_array::Scalar) of the same type as `array`, which
+/// may be null.
+///
+/// # Examples
+/// ```
+/// # use arrow_array::{Int32Array, Scalar, StringArray};
+/// # use arrow_select::window::shift_with_fill;
+/// let a: Int32Array = vec![Some(1), None, Some(4)].into();
+///
+/// // shift array 1 element to the right, filling with 0
+/// let fill = Int32Array::new_scalar(0);
+/// let res = shift_with_fill(&a, 1, &fill).unwrap();
+/// let expected: Int32Array = vec![Some(0), Some(1), None].into();
+/// assert_eq!(res.as_ref(), &expected);
+///
+/// // shift array 2 elements to the left, filling with -1
+/// let fill = Int32Array::new_scalar(-1);
+/// let res = shift_with_fill(&a, -2, &fill).unwrap();
+/// let expected: Int32Array = vec![Some(4), Some(-1), Some(-1)].into();
+/// assert_eq!(res.as_ref(), &expected);
+///
+/// // any type is supported
+/// let s = StringArray::from(vec!["a", "b", "c"]);
+/// let fill = Scalar::new(StringArray::from(vec!["-"]));
+/// let res = shift_with_fill(&s, 1, &fill).unwrap();
+/// assert_eq!(res.as_ref(), &StringArray::from(vec!["-", "a", "b"]));
+/// ```
+pub fn shift_with_fill(
+    array: &dyn Array,
+    offset: i64,
+    fill_value: &dyn Datum,
+) -> Result<ArrayRef, ArrowError> {
+    let (fill_value, is_scalar) = fill_value.get();
+    if !is_scalar || fill_value.len() != 1 {
+        return Err(ArrowError::InvalidArgumentError(
+            "shift_with_fill requires a scalar fill value".to_string(),
+        ));
+    }
+    if fill_value.data_type() != array.data_type() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "shift_with_fill requires a fill value of the same type as the array, got {} and {}",
+            fill_value.data_type(),
+            array.data_type()
+        )));
+    }
+
+    if offset == 0 {
+        return Ok(make_array(array.to_data()));
+    }
+    let fill_len = offset.unsigned_abs().min(array.len() as u64) as usize;
+    let fill = take(fill_value, &UInt32Array::from(vec![0; fill_len]), None)?;
+    if fill_len == array.len() {
+        return Ok(fill);
+    }
+    shift_impl(array, offset, fill)
+}
+
+/// Shifts `array` by `offset`, where `offset` is non-zero and less than the length of
+/// `array` in magnitude, filling the vacated positions with `fill`
+fn shift_impl(array: &dyn Array, offset: i64, fill: ArrayRef) -> Result<ArrayRef, ArrowError> {
+    // Concatenate both arrays, add the fill before if shift > 0 else after
+    let length = array.len() - fill.len();
+    if offset > 0 {
+        let slice = array.slice(0, length);
+        concat(&[fill.as_ref(), slice.as_ref()])
+    } else {
+        let slice = array.slice(fill.len(), length);
+        concat(&[slice.as_ref(), fill.as_ref()])
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use arrow_array::{Float64Array, Int32Array, Int32DictionaryArray};
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int32Type;
+    use arrow_array::{
+        Float64Array, Int32Array, Int32DictionaryArray, Int64Array, ListArray, Scalar, StringArray,
+    };
+    use arrow_schema::DataType;
 
     #[test]
     fn test_shift_neg() {
@@ -191,4 +259,76 @@ mod tests {
         let expected: Int32Array = vec![None, None, None].into();
         assert_eq!(res.as_ref(), &expected);
     }
+
+    #[test]
+    fn test_shift_with_fill() {
+        let a: Int32Array = vec![Some(1), None, Some(4), Some(5)].into();
+        let fill = Int32Array::new_scalar(9);
+        let cases: [(i64, Vec<Option<i32>>); 6] = [
+            (0, vec![Some(1), None, Some(4), Some(5)]),
+            (1, vec![Some(9), Some(1), None, Some(4)]),
+            (-3, vec![Some(5), Some(9), Some(9), Some(9)]),
+            (4, vec![Some(9); 4]),
+            (i64::MIN, vec![Some(9); 4]),
+            (i64::MAX, vec![Some(9); 4]),
+        ];
+        for (offset, expected) in cases {
+            let res = shift_with_fill(&a, offset, &fill).unwrap();
+            assert_eq!(res.as_ref(), &Int32Array::from(expected), "{offset}");
+        }
+
+        // A null fill value is the same as shift
+        let fill = Scalar::new(new_null_array(&DataType::Int32, 1));
+        let res = shift_with_fill(&a, -2, &fill).unwrap();
+        assert_eq!(res.as_ref(), shift(&a, -2).unwrap().as_ref());
+
+        // Sliced input
+        let sliced = a.slice(1, 3);
+        let res = shift_with_fill(&sliced, 1, &Int32Array::new_scalar(0)).unwrap();
+        let expected: Int32Array = vec![Some(0), None, Some(4)].into();
+        assert_eq!(res.as_ref(), &expected);
+    }
+
+    #[test]
+    fn test_shift_with_fill_nested() {
+        let a = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1)]),
+            None,
+            Some(vec![Some(2), Some(3)]),
+        ]);
+        let fill = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![Some(vec![])]);
+        let res = shift_with_fill(&a, -1, &Scalar::new(fill)).unwrap();
+        let expected = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            None,
+            Some(vec![Some(2), Some(3)]),
+            Some(vec![]),
+        ]);
+        assert_eq!(res.as_list::<i32>(), &expected);
+
+        let a: Int32DictionaryArray = vec!["a", "b", "c"].into_iter().collect();
+        let fill: Int32DictionaryArray = vec!["z"].into_iter().collect();
+        let res = shift_with_fill(&a, 2, &Scalar::new(fill)).unwrap();
+        let res = res
+            .as_dictionary::<Int32Type>()
+            .downcast_dict::<StringArray>()
+            .unwrap();
+        let res: Vec<_> = res.into_iter().collect();
+        assert_eq!(res, vec![Some("z"), Some("z"), Some("a")]);
+    }
+
+    #[test]
+    fn test_shift_with_fill_invalid() {
+        let a: Int32Array = vec![Some(1), None, Some(4)].into();
+        let err = shift_with_fill(&a, 1, &Int32Array::from(vec![1])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: shift_with_fill requires a scalar fill value"
+        );
+
+        let err = shift_with_fill(&a, 1, &Int64Array::new_scalar(1)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: shift_with_fill requires a fill value of the same type as the array, got Int64 and Int32"
+        );
+    }
 }
@@ -16,9 +16,12 @@
 // under the License.
 
 //! [`zip`]: Combine values from two arrays based on boolean mask
+//!
+//! [`case_when`]: Combine values from several arrays based on a list of boolean masks
 
 use crate::filter::SlicesIterator;
 use arrow_array::*;
+use arrow_buffer::BooleanBuffer;
 use arrow_data::transform::MutableArrayData;
 use arrow_schema::ArrowError;
 
@@ -166,9 +169,126 @@ pub fn zip(
     Ok(make_array(data))
 }
 
+/// Select values from one of several arrays based on the first matching condition,
+/// equivalent to the SQL `CASE WHEN c0 THEN v0 WHEN c1 THEN v1 ... ELSE e END`
+///
+/// For each row, the value is taken from `values[i]` where `conditions[i]` is the
+/// first condition that is `true` for that row, with `NULL` treated as `false`. If
+/// no condition is `true`, the value is taken from `else_value`, or is `NULL` if
+/// `else_value` is `None`.
+///
+/// Each value may be an array of the same length as the conditions, or a [`Scalar`].
+/// The output is built in a single pass, copying each row once, which is cheaper
+/// than chaining calls to [`zip`] that each copy the entire output.
+///
+/// # Example
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, BooleanArray, Datum, Int32Array, Scalar};
+/// # use arrow_select::zip::case_when;
+/// let c0 = BooleanArray::from(vec![Some(true), Some(false), None, Some(false)]);
+/// let c1 = BooleanArray::from(vec![Some(true), Some(true), Some(true), Some(false)]);
+/// let v0 = Int32Array::from(vec![1, 2, 3, 4]);
+/// let v1 = Scalar::new(Int32Array::from(vec![10]));
+/// let else_value = Int32Array::from(vec![100, 200, 300, 400]);
+///
+/// let result = case_when(&[c0, c1], &[&v0, &v1], Some(&else_value)).unwrap();
+/// let expected: ArrayRef = Arc::new(Int32Array::from(vec![1, 10, 10, 400]));
+/// assert_eq!(&result, &expected);
+/// ```
+pub fn case_when(
+    conditions: &[BooleanArray],
+    values: &[&dyn Datum],
+    else_value: Option<&dyn Datum>,
+) -> Result<ArrayRef, ArrowError> {
+    if conditions.len() != values.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "case_when requires the same number of conditions and values, got {} and {}",
+            conditions.len(),
+            values.len()
+        )));
+    }
+    let Some(first) = conditions.first() else {
+        return Err(ArrowError::InvalidArgumentError(
+            "case_when requires at least one condition".into(),
+        ));
+    };
+    let len = first.len();
+    if conditions.iter().any(|c| c.len() != len) {
+        return Err(ArrowError::InvalidArgumentError(
+            "all arrays should have the same length".into(),
+        ));
+    }
+
+    let sources: Vec<_> = values
+        .iter()
+        .copied()
+        .chain(else_value)
+        .map(|v| v.get())
+        .collect();
+    let data_type = sources[0].0.data_type();
+    for (array, is_scalar) in &sources {
+        if array.data_type() != data_type {
+            return Err(ArrowError::InvalidArgumentError(
+                "arguments need to have the same data type".into(),
+            ));
+        }
+        if *is_scalar && array.len() != 1 {
+            return Err(ArrowError::InvalidArgumentError(
+                "scalar arrays must have 1 element".into(),
+            ));
+        }
+        if !*is_scalar && array.len() != len {
+            return Err(ArrowError::InvalidArgumentError(
+                "all arrays should have the same length".into(),
+            ));
+        }
+    }
+
+    // The index of the source selected for each row, with `conditions.len()` for `else_value`
+    let else_idx = conditions.len();
+    let mut selected = vec![else_idx; len];
+    let mut remaining = BooleanBuffer::new_set(len);
+    for (idx, condition) in conditions.iter().enumerate() {
+        let matched = match condition.nulls() {
+            Some(nulls) => &(condition.values() & nulls.inner()) & &remaining,
+            None => condition.values() & &remaining,
+        };
+        if matched.count_set_bits() == 0 {
+            continue;
+        }
+        matched.set_indices().for_each(|row| selected[row] = idx);
+        remaining = &remaining & &!&matched;
+    }
+
+    let data: Vec<_> = sources.iter().map(|(array, _)| array.to_data()).collect();
+    let mut mutable = MutableArrayData::new(data.iter().collect(), else_value.is_none(), len);
+
+    // copy each run of rows selected from the same source
+    let mut start = 0;
+    while start < len {
+        let source = selected[start];
+        let end = selected[start..]
+            .iter()
+            .position(|s| *s != source)
+            .map_or(len, |p| start + p);
+
+        match sources.get(source) {
+            Some((_, true)) => (start..end).for_each(|_| mutable.extend(source, 0, 1)),
+            Some((_, false)) => mutable.extend(source, start, end),
+            None => mutable.extend_nulls(end - start),
+        }
+        start = end;
+    }
+
+    Ok(make_array(mutable.freeze()))
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int32Type;
 
     #[test]
     fn test_zip_kernel_one() {
@@ -279,4 +399,77 @@ mod test {
         let expected = Int32Array::from(vec![None, None, Some(42), Some(42), None]);
         assert_eq!(actual, &expected);
     }
+
+    #[test]
+    fn test_case_when() {
+        let c0 = BooleanArray::from(vec![Some(true), Some(false), None, Some(false), Some(true)]);
+        let c1 = BooleanArray::from(vec![Some(false), Some(true), Some(true), None, Some(true)]);
+        let v0 = StringArray::from(vec![Some("a0"), Some("b0"), Some("c0"), Some("d0"), None]);
+        let v1 = StringArray::from(vec!["a1", "b1", "c1", "d1", "e1"]);
+
+        let out = case_when(&[c0.clone(), c1.clone()], &[&v0, &v1], None).unwrap();
+        let expected = StringArray::from(vec![Some("a0"), Some("b1"), Some("c1"), None, None]);
+        assert_eq!(out.as_string::<i32>(), &expected);
+
+        let else_value = Scalar::new(StringArray::from(vec!["x"]));
+        let out = case_when(&[c0, c1], &[&v0, &v1], Some(&else_value)).unwrap();
+        let expected = StringArray::from(vec![Some("a0"), Some("b1"), Some("c1"), Some("x"), None]);
+        assert_eq!(out.as_string::<i32>(), &expected);
+    }
+
+    #[test]
+    fn test_case_when_scalars() {
+        let c0 = BooleanArray::from(vec![true, true, false, false, false, true]);
+        let c1 = BooleanArray::from(vec![false, true, true, true, false, false]);
+        let v0 = Scalar::new(Int32Array::from(vec![Some(1)]));
+        let v1 = Scalar::new(Int32Array::new_null(1));
+        let else_value = Scalar::new(Int32Array::from(vec![3]));
+
+        let out = case_when(&[c0, c1], &[&v0, &v1], Some(&else_value)).unwrap();
+        let expected = Int32Array::from(vec![Some(1), Some(1), None, None, Some(3), Some(1)]);
+        assert_eq!(out.as_primitive::<Int32Type>(), &expected);
+    }
+
+    #[test]
+    fn test_case_when_sliced() {
+        let c0 = BooleanArray::from(vec![false, true, false, true]).slice(1, 3);
+        let v0 = Int32Array::from(vec![1, 2, 3, 4]).slice(1, 3);
+        let else_value = Int32Array::from(vec![10, 20, 30, 40]).slice(1, 3);
+
+        let out = case_when(&[c0], &[&v0], Some(&else_value)).unwrap();
+        let expected = Int32Array::from(vec![2, 30, 4]);
+        assert_eq!(out.as_primitive::<Int32Type>(), &expected);
+    }
+
+    #[test]
+    fn test_case_when_errors() {
+        let c0 = BooleanArray::from(vec![true, false]);
+        let v0 = Int32Array::from(vec![1, 2]);
+
+        let err = case_when(std::slice::from_ref(&c0), &[], None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: case_when requires the same number of conditions and values, got 1 and 0"
+        );
+
+        let err = case_when(&[], &[], Some(&v0)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: case_when requires at least one condition"
+        );
+
+        let v1 = Int64Array::from(vec![1, 2]);
+        let err = case_when(std::slice::from_ref(&c0), &[&v0], Some(&v1)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: arguments need to have the same data type"
+        );
+
+        let v1 = Int32Array::from(vec![1, 2, 3]);
+        let err = case_when(&[c0], &[&v0], Some(&v1)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: all arrays should have the same length"
+        );
+    }
 }
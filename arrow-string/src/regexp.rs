@@ -25,11 +25,12 @@ use arrow_array::builder::{
 };
 use arrow_array::cast::AsArray;
 use arrow_array::*;
-use arrow_buffer::NullBuffer;
+use arrow_buffer::{NullBuffer, OffsetBuffer};
 use arrow_data::{ArrayData, ArrayDataBuilder};
 use arrow_schema::{ArrowError, DataType, Field};
 use regex::Regex;
 
+use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -526,6 +527,311 @@ pub fn regexp_match(
     }
 }
 
+/// Compiled regular expressions for [`regexp_extract_all`] and [`regexp_split`]
+///
+/// A scalar pattern is compiled once, whereas an array of patterns is compiled
+/// lazily, with each distinct pattern and flag combination compiled at most once
+enum RegexCache<'a> {
+    /// A scalar pattern, `None` if the pattern is null
+    Scalar(Option<Regex>),
+    /// An array of patterns with optional per-row flags
+    Array {
+        patterns: Vec<Option<&'a str>>,
+        flags: Option<Vec<Option<&'a str>>>,
+        compiled: HashMap<(&'a str, Option<&'a str>), Regex>,
+    },
+}
+
+impl<'a> RegexCache<'a> {
+    fn try_new(
+        kernel: &str,
+        len: usize,
+        regex_array: &'a dyn Datum,
+        flags_array: Option<&'a dyn Datum>,
+    ) -> Result<Self, ArrowError> {
+        let (rhs, is_rhs_scalar) = regex_array.get();
+        let patterns = string_values(kernel, "pattern", rhs)?;
+
+        let flags = match flags_array {
+            Some(flags) => {
+                let (flags, is_flags_scalar) = flags.get();
+                if is_flags_scalar != is_rhs_scalar {
+                    return Err(ArrowError::ComputeError(format!(
+                        "{kernel}() requires both pattern and flags to be either scalar or array"
+                    )));
+                }
+                Some(string_values(kernel, "flags", flags)?)
+            }
+            None => None,
+        };
+
+        if is_rhs_scalar {
+            let flag = flags.and_then(|flags| flags[0]);
+            let regex = patterns[0]
+                .map(|pattern| compile_regex(pattern, flag))
+                .transpose()?;
+            return Ok(Self::Scalar(regex));
+        }
+
+        let flags_len = flags.as_ref().map(|flags| flags.len()).unwrap_or(len);
+        if patterns.len() != len || flags_len != len {
+            return Err(ArrowError::ComputeError(format!(
+                "{kernel}() requires pattern and flags arrays to have the same length as array, got {} and {flags_len} vs {len}",
+                patterns.len()
+            )));
+        }
+
+        Ok(Self::Array {
+            patterns,
+            flags,
+            compiled: HashMap::new(),
+        })
+    }
+
+    /// Returns the regular expression for row `idx`, or `None` if its pattern is null
+    fn get(&mut self, idx: usize) -> Result<Option<&Regex>, ArrowError> {
+        match self {
+            Self::Scalar(regex) => Ok(regex.as_ref()),
+            Self::Array {
+                patterns,
+                flags,
+                compiled,
+            } => {
+                let Some(pattern) = patterns[idx] else {
+                    return Ok(None);
+                };
+                let flag = flags.as_ref().and_then(|flags| flags[idx]);
+                match compiled.entry((pattern, flag)) {
+                    Entry::Occupied(e) => Ok(Some(e.into_mut())),
+                    Entry::Vacant(e) => Ok(Some(e.insert(compile_regex(pattern, flag)?))),
+                }
+            }
+        }
+    }
+}
+
+fn compile_regex(pattern: &str, flag: Option<&str>) -> Result<Regex, ArrowError> {
+    let pattern = match flag {
+        Some(flag) => format!("(?{flag}){pattern}"),
+        None => pattern.to_string(),
+    };
+    Regex::new(pattern.as_str())
+        .map_err(|e| ArrowError::ComputeError(format!("Regular expression did not compile: {e:?}")))
+}
+
+fn string_values<'a>(
+    kernel: &str,
+    name: &str,
+    array: &'a dyn Array,
+) -> Result<Vec<Option<&'a str>>, ArrowError> {
+    match array.data_type() {
+        DataType::Utf8View => Ok(array.as_string_view().iter().collect()),
+        DataType::Utf8 => Ok(array.as_string::<i32>().iter().collect()),
+        DataType::LargeUtf8 => Ok(array.as_string::<i64>().iter().collect()),
+        _ => Err(ArrowError::ComputeError(format!(
+            "{kernel}() requires {name} to be either Utf8, Utf8View or LargeUtf8"
+        ))),
+    }
+}
+
+/// The values, offsets and nulls of a list array of strings
+type StringListParts<'a> = (Vec<&'a str>, OffsetBuffer<i32>, Option<NullBuffer>);
+
+/// Applies `op` to each non-null row of `array` with a non-null pattern, returning
+/// the values, offsets and nulls of the resulting list array
+fn regexp_list_op<'a, S, F>(
+    array: S,
+    cache: &mut RegexCache<'_>,
+    mut op: F,
+) -> Result<StringListParts<'a>, ArrowError>
+where
+    S: StringArrayType<'a>,
+    F: FnMut(&Regex, &'a str, &mut Vec<&'a str>) -> Result<(), ArrowError>,
+{
+    let mut values = Vec::new();
+    let mut offsets = Vec::with_capacity(array.len() + 1);
+    let mut nulls = BooleanBufferBuilder::new(array.len());
+    offsets.push(0_i32);
+
+    for (idx, value) in array.iter().enumerate() {
+        match (value, cache.get(idx)?) {
+            (Some(value), Some(regex)) => {
+                op(regex, value, &mut values)?;
+                nulls.append(true);
+            }
+            _ => nulls.append(false),
+        }
+        let offset = i32::try_from(values.len()).map_err(|_| {
+            ArrowError::ComputeError("List offset overflow, consider using LargeList".to_string())
+        })?;
+        offsets.push(offset);
+    }
+
+    // SAFETY: offsets start at zero and are monotonically increasing
+    let offsets = unsafe { OffsetBuffer::new_unchecked(offsets.into()) };
+    let nulls = Some(NullBuffer::new(nulls.finish())).filter(|n| n.null_count() > 0);
+    Ok((values, offsets, nulls))
+}
+
+fn regexp_list_kernel<F>(
+    kernel: &str,
+    array: &dyn Array,
+    regex_array: &dyn Datum,
+    flags_array: Option<&dyn Datum>,
+    op: F,
+) -> Result<ArrayRef, ArrowError>
+where
+    F: for<'a> FnMut(&Regex, &'a str, &mut Vec<&'a str>) -> Result<(), ArrowError>,
+{
+    let mut cache = RegexCache::try_new(kernel, array.len(), regex_array, flags_array)?;
+
+    let (values, offsets, nulls): (ArrayRef, _, _) = match array.data_type() {
+        DataType::Utf8View => {
+            let (values, offsets, nulls) = regexp_list_op(array.as_string_view(), &mut cache, op)?;
+            let values = StringViewArray::from_iter_values(values);
+            (Arc::new(values), offsets, nulls)
+        }
+        DataType::Utf8 => {
+            let (values, offsets, nulls) =
+                regexp_list_op(array.as_string::<i32>(), &mut cache, op)?;
+            let values = StringArray::from_iter_values(values);
+            (Arc::new(values), offsets, nulls)
+        }
+        DataType::LargeUtf8 => {
+            let (values, offsets, nulls) =
+                regexp_list_op(array.as_string::<i64>(), &mut cache, op)?;
+            let values = LargeStringArray::from_iter_values(values);
+            (Arc::new(values), offsets, nulls)
+        }
+        _ => {
+            return Err(ArrowError::ComputeError(format!(
+                "{kernel}() requires array to be either Utf8, Utf8View or LargeUtf8"
+            )))
+        }
+    };
+
+    let field = Arc::new(Field::new_list_field(array.data_type().clone(), true));
+    Ok(Arc::new(ListArray::new(field, offsets, values, nulls)))
+}
+
+/// Extract all non-overlapping matches of a regular expression for a given String array.
+///
+/// Modelled after the Postgres [regexp_matches] with the `g` flag.
+///
+/// Returns a ListArray of the same string type as `array`, with each element containing
+/// every match of the corresponding pattern in `regex_array` against the string in `array`,
+/// in order of occurrence.
+///
+/// If `group` is `0` each list element contains the substrings matching the whole pattern,
+/// otherwise it contains the substrings matching the `group`'th capturing parenthesized
+/// subexpression, or an empty string for matches where that group did not participate.
+/// Returns an error if the pattern has fewer than `group` capturing groups.
+///
+/// If there is no match the list element is empty. If either the string or the
+/// pattern is NULL, the list element is NULL.
+///
+/// `regex_array` may be a [`Scalar`], in which case the pattern is compiled once,
+/// or an array, in which case each distinct pattern is compiled once and cached.
+/// The optional flags are as for [`regexp_match`].
+///
+/// ```
+/// # use arrow_array::{Array, ListArray, Scalar, StringArray};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_string::regexp::regexp_extract_all;
+/// let array = StringArray::from(vec![Some("a1b22c333"), Some("abc"), None]);
+/// let pattern = Scalar::new(StringArray::from(vec![r"[a-z](\d+)"]));
+///
+/// let result = regexp_extract_all(&array, &pattern, 1, None).unwrap();
+/// let result = result.as_list::<i32>();
+///
+/// assert_eq!(result.value(0).as_string::<i32>(), &StringArray::from(vec!["1", "22", "333"]));
+/// assert!(result.value(1).is_empty());
+/// assert!(result.is_null(2));
+/// ```
+///
+/// [regexp_matches]: https://www.postgresql.org/docs/current/functions-matching.html#FUNCTIONS-POSIX-REGEXP
+pub fn regexp_extract_all(
+    array: &dyn Array,
+    regex_array: &dyn Datum,
+    group: usize,
+    flags_array: Option<&dyn Datum>,
+) -> Result<ArrayRef, ArrowError> {
+    regexp_list_kernel(
+        "regexp_extract_all",
+        array,
+        regex_array,
+        flags_array,
+        |regex, value, out| {
+            if group == 0 {
+                out.extend(regex.find_iter(value).map(|m| m.as_str()));
+                return Ok(());
+            }
+
+            if group >= regex.captures_len() {
+                return Err(ArrowError::ComputeError(format!(
+                    "regexp_extract_all() requested group {group} but pattern '{}' has {} capture groups",
+                    regex.as_str(),
+                    regex.captures_len() - 1
+                )));
+            }
+
+            out.extend(
+                regex
+                    .captures_iter(value)
+                    .map(|caps| caps.get(group).map_or("", |m| m.as_str())),
+            );
+            Ok(())
+        },
+    )
+}
+
+/// Split each string in a String array around the matches of a regular expression.
+///
+/// Modelled after the Postgres [regexp_split_to_array].
+///
+/// Returns a ListArray of the same string type as `array`, with each element containing
+/// the substrings of the string in `array` delimited by matches of the corresponding
+/// pattern in `regex_array`. Adjacent matches, or matches at the start or end of the
+/// string, produce empty substrings.
+///
+/// If either the string or the pattern is NULL, the list element is NULL.
+///
+/// `regex_array` may be a [`Scalar`], in which case the pattern is compiled once,
+/// or an array, in which case each distinct pattern is compiled once and cached.
+/// The optional flags are as for [`regexp_match`].
+///
+/// ```
+/// # use arrow_array::{Array, ListArray, Scalar, StringArray};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_string::regexp::regexp_split;
+/// let array = StringArray::from(vec![Some("a, b,c"), None]);
+/// let pattern = Scalar::new(StringArray::from(vec![r",\s*"]));
+///
+/// let result = regexp_split(&array, &pattern, None).unwrap();
+/// let result = result.as_list::<i32>();
+///
+/// assert_eq!(result.value(0).as_string::<i32>(), &StringArray::from(vec!["a", "b", "c"]));
+/// assert!(result.is_null(1));
+/// ```
+///
+/// [regexp_split_to_array]: https://www.postgresql.org/docs/current/functions-matching.html#FUNCTIONS-POSIX-REGEXP
+pub fn regexp_split(
+    array: &dyn Array,
+    regex_array: &dyn Datum,
+    flags_array: Option<&dyn Datum>,
+) -> Result<ArrayRef, ArrowError> {
+    regexp_list_kernel(
+        "regexp_split",
+        array,
+        regex_array,
+        flags_array,
+        |regex, value, out| {
+            out.extend(regex.split(value));
+            Ok(())
+        },
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1039,4 +1345,186 @@ mod tests {
         regexp_is_match_scalar::<StringViewArray>,
         [true, true, false, false]
     );
+
+    fn list_values(array: &ArrayRef) -> Vec<Option<Vec<String>>> {
+        let list = array.as_list::<i32>();
+        list.iter()
+            .map(|v| {
+                v.map(|v| {
+                    let v = collect_strings(&v);
+                    v.into_iter().map(|s| s.to_string()).collect()
+                })
+            })
+            .collect()
+    }
+
+    fn collect_strings(array: &ArrayRef) -> Vec<&str> {
+        match array.data_type() {
+            DataType::Utf8View => array.as_string_view().iter().map(Option::unwrap).collect(),
+            DataType::Utf8 => array
+                .as_string::<i32>()
+                .iter()
+                .map(Option::unwrap)
+                .collect(),
+            DataType::LargeUtf8 => array
+                .as_string::<i64>()
+                .iter()
+                .map(Option::unwrap)
+                .collect(),
+            d => unreachable!("{d}"),
+        }
+    }
+
+    #[test]
+    fn test_regexp_extract_all() {
+        let values = vec![Some("a1b22c333"), Some("xyz"), None, Some("d4")];
+        let expected = vec![
+            Some(vec!["1".to_string(), "22".to_string(), "333".to_string()]),
+            Some(vec![]),
+            None,
+            Some(vec!["4".to_string()]),
+        ];
+
+        let arrays: Vec<ArrayRef> = vec![
+            Arc::new(StringArray::from(values.clone())),
+            Arc::new(LargeStringArray::from(values.clone())),
+            Arc::new(StringViewArray::from(values)),
+        ];
+        for array in arrays {
+            let pattern = Scalar::new(cast_pattern(r"[a-z](\d+)", array.data_type()));
+            let result = regexp_extract_all(&array, &pattern, 1, None).unwrap();
+            assert_eq!(
+                result.data_type(),
+                &DataType::List(Arc::new(Field::new_list_field(
+                    array.data_type().clone(),
+                    true
+                )))
+            );
+            assert_eq!(list_values(&result), expected);
+
+            let result = regexp_extract_all(&array, &pattern, 0, None).unwrap();
+            assert_eq!(
+                list_values(&result)[0],
+                Some(vec![
+                    "a1".to_string(),
+                    "b22".to_string(),
+                    "c333".to_string()
+                ])
+            );
+        }
+    }
+
+    fn cast_pattern(pattern: &str, data_type: &DataType) -> ArrayRef {
+        match data_type {
+            DataType::Utf8View => Arc::new(StringViewArray::from(vec![pattern])),
+            DataType::Utf8 => Arc::new(StringArray::from(vec![pattern])),
+            DataType::LargeUtf8 => Arc::new(LargeStringArray::from(vec![pattern])),
+            d => unreachable!("{d}"),
+        }
+    }
+
+    #[test]
+    fn test_regexp_extract_all_array_patterns() {
+        let array = StringArray::from(vec!["foo1 FOO2", "bar", "foo3", "baz"]);
+        let patterns =
+            StringArray::from(vec![Some("foo(\\d)"), Some("(a)"), Some("foo(\\d)"), None]);
+        let flags = StringArray::from(vec![Some("i"), None, None, None]);
+
+        let result = regexp_extract_all(&array, &patterns, 1, Some(&flags)).unwrap();
+        assert_eq!(
+            list_values(&result),
+            vec![
+                Some(vec!["1".to_string(), "2".to_string()]),
+                Some(vec!["a".to_string()]),
+                Some(vec!["3".to_string()]),
+                None
+            ]
+        );
+    }
+
+    #[test]
+    fn test_regexp_extract_all_optional_group() {
+        let array = StringArray::from(vec!["a1 b c3"]);
+        let pattern = Scalar::new(StringArray::from(vec!["[a-z](\\d)?"]));
+        let result = regexp_extract_all(&array, &pattern, 1, None).unwrap();
+        assert_eq!(
+            list_values(&result),
+            vec![Some(vec!["1".to_string(), "".to_string(), "3".to_string()])]
+        );
+    }
+
+    #[test]
+    fn test_regexp_extract_all_errors() {
+        let array = StringArray::from(vec!["abc"]);
+
+        let pattern = Scalar::new(StringArray::from(vec!["(b)"]));
+        let err = regexp_extract_all(&array, &pattern, 2, None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: regexp_extract_all() requested group 2 but pattern '(b)' has 1 capture groups"
+        );
+
+        let pattern = Scalar::new(StringArray::from(vec!["(b"]));
+        let err = regexp_extract_all(&array, &pattern, 0, None).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Regular expression did not compile"));
+
+        let patterns = StringArray::from(vec!["a", "b"]);
+        let err = regexp_extract_all(&array, &patterns, 0, None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: regexp_extract_all() requires pattern and flags arrays to have the same length as array, got 2 and 1 vs 1"
+        );
+
+        let flags = Scalar::new(StringArray::from(vec!["i"]));
+        let patterns = StringArray::from(vec!["a"]);
+        let err = regexp_extract_all(&array, &patterns, 0, Some(&flags)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: regexp_extract_all() requires both pattern and flags to be either scalar or array"
+        );
+
+        let ints = Int32Array::from(vec![1]);
+        let pattern = Scalar::new(StringArray::from(vec!["b"]));
+        let err = regexp_extract_all(&ints, &pattern, 0, None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: regexp_extract_all() requires array to be either Utf8, Utf8View or LargeUtf8"
+        );
+    }
+
+    #[test]
+    fn test_regexp_split() {
+        let values = vec![Some("a, b,c"), Some(",x,"), Some(""), None];
+        let expected = vec![
+            Some(vec!["a".to_string(), "b".to_string(), "c".to_string()]),
+            Some(vec!["".to_string(), "x".to_string(), "".to_string()]),
+            Some(vec!["".to_string()]),
+            None,
+        ];
+
+        let arrays: Vec<ArrayRef> = vec![
+            Arc::new(StringArray::from(values.clone())),
+            Arc::new(LargeStringArray::from(values.clone())),
+            Arc::new(StringViewArray::from(values)),
+        ];
+        for array in arrays {
+            let pattern = Scalar::new(cast_pattern(r",\s*", array.data_type()));
+            let result = regexp_split(&array, &pattern, None).unwrap();
+            assert_eq!(list_values(&result), expected);
+        }
+
+        let array = StringArray::from(vec!["aXbxc", "aXbxc"]);
+        let patterns = StringArray::from(vec![Some("x"), None]);
+        let flags = StringArray::from(vec![Some("i"), Some("i")]);
+        let result = regexp_split(&array, &patterns, Some(&flags)).unwrap();
+        assert_eq!(
+            list_values(&result),
+            vec![
+                Some(vec!["a".to_string(), "b".to_string(), "c".to_string()]),
+                None
+            ]
+        );
+    }
 }
@@ -15,7 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use arrow::compute::{lexsort_to_indices, SortColumn};
+use arrow::compute::{lexsort_limit, lexsort_to_indices, SortColumn};
 use arrow::row::{RowConverter, SortField};
 use arrow::util::bench_util::{
     create_dict_from_values, create_primitive_array, create_string_array_with_len,
@@ -131,6 +131,16 @@ fn do_bench(c: &mut Criterion, columns: &[Column], len: usize) {
         b.iter(|| hint::black_box(lexsort_to_indices(&sort_columns, None).unwrap()))
     });
 
+    c.bench_function(
+        &format!("lexsort_to_indices({columns:?}): {len} limit 10"),
+        |b| b.iter(|| hint::black_box(lexsort_to_indices(&sort_columns, Some(10)).unwrap())),
+    );
+
+    c.bench_function(
+        &format!("lexsort_limit({columns:?}): {len} limit 10"),
+        |b| b.iter(|| hint::black_box(lexsort_limit(&sort_columns, 10).unwrap())),
+    );
+
     c.bench_function(&format!("lexsort_rows({columns:?}): {len}"), |b| {
         b.iter(|| {
             hint::black_box({
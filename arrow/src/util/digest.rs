@@ -0,0 +1,413 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Deterministic content hashing of [`RecordBatch`]es
+//!
+//! [`hash_batch`] computes a SHA-256 digest of the logical contents of a
+//! [`RecordBatch`], that is independent of how the data is physically laid out
+//! in memory. This makes it suitable for deduplication, caching, and verifying
+//! data has not been altered after being sent through a transport, such as
+//! Arrow IPC or Arrow Flight, that may change its physical representation.
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::{ByteArrayType, ByteViewType};
+use arrow_array::*;
+use arrow_buffer::{i256, ArrowNativeType, IntervalDayTime, IntervalMonthDayNano};
+use arrow_schema::{DataType, Field, FieldRef, Fields};
+use half::f16;
+use sha2::{Digest, Sha256};
+
+/// Identifies the canonical encoding hashed by [`hash_batch`], so that any
+/// future change to the encoding produces different digests
+const DIGEST_VERSION: &[u8] = b"arrow-batch-digest-v1";
+
+/// Returns a SHA-256 digest of the logical contents of `batch`
+///
+/// The digest covers the number of rows, the name and logical type of each
+/// column, and the logical value of each row. It does not depend on:
+///
+/// * The offset or capacity of any buffer, so sliced and unsliced arrays
+///   with the same values have the same digest
+/// * Whether a column is dictionary or run-end encoded, so these have the same
+///   digest as the equivalent plain array
+/// * The offset width or view representation of variable length types, so for
+///   example [`StringArray`], [`LargeStringArray`] and [`StringViewArray`] with
+///   the same values have the same digest, as do the different list types
+/// * The contents of null slots, including child values of null list or struct slots
+/// * Field nullability, field metadata, or schema metadata
+///
+/// Primitive values, including floating point values, are hashed bitwise as
+/// little-endian bytes, so digests do not depend on the endianness of the platform.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow::array::{DictionaryArray, RecordBatch, StringArray};
+/// # use arrow::array::types::Int32Type;
+/// # use arrow::util::digest::hash_batch;
+/// let plain = StringArray::from(vec![Some("a"), None, Some("b"), Some("a")]);
+/// let dict: DictionaryArray<Int32Type> = vec![Some("a"), None, Some("b"), Some("a")]
+///     .into_iter()
+///     .collect();
+///
+/// let a = RecordBatch::try_from_iter([("c", Arc::new(plain) as _)]).unwrap();
+/// let b = RecordBatch::try_from_iter([("c", Arc::new(dict) as _)]).unwrap();
+/// assert_eq!(hash_batch(&a), hash_batch(&b));
+/// ```
+pub fn hash_batch(batch: &RecordBatch) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(DIGEST_VERSION);
+    hash_len(batch.num_rows(), &mut hasher);
+    hash_fields(batch.schema().fields(), &mut hasher);
+    for column in batch.columns() {
+        for idx in 0..column.len() {
+            hash_row(column.as_ref(), idx, &mut hasher);
+        }
+    }
+    hasher.finalize().into()
+}
+
+fn hash_len(len: usize, hasher: &mut Sha256) {
+    hasher.update((len as u64).to_le_bytes());
+}
+
+fn hash_bytes(bytes: &[u8], hasher: &mut Sha256) {
+    hash_len(bytes.len(), hasher);
+    hasher.update(bytes);
+}
+
+fn hash_fields(fields: &Fields, hasher: &mut Sha256) {
+    hash_len(fields.len(), hasher);
+    for field in fields {
+        hash_bytes(field.name().as_bytes(), hasher);
+        let data_type = logical_type(field.data_type());
+        hash_bytes(data_type.to_string().as_bytes(), hasher);
+    }
+}
+
+/// Returns `data_type` with all encoding and layout specific details removed
+fn logical_type(data_type: &DataType) -> DataType {
+    let field = |f: &FieldRef| Field::new(f.name(), logical_type(f.data_type()), true);
+    let list = |f: &FieldRef| {
+        DataType::List(Field::new_list_field(logical_type(f.data_type()), true).into())
+    };
+    match data_type {
+        DataType::LargeUtf8 | DataType::Utf8View => DataType::Utf8,
+        DataType::LargeBinary | DataType::BinaryView => DataType::Binary,
+        DataType::List(f)
+        | DataType::LargeList(f)
+        | DataType::ListView(f)
+        | DataType::LargeListView(f) => list(f),
+        DataType::FixedSizeList(f, size) => DataType::FixedSizeList(
+            Field::new_list_field(logical_type(f.data_type()), true).into(),
+            *size,
+        ),
+        DataType::Struct(fields) => DataType::Struct(fields.iter().map(field).collect()),
+        DataType::Map(f, sorted) => DataType::Map(field(f).into(), *sorted),
+        DataType::Union(fields, mode) => DataType::Union(
+            fields.iter().map(|(id, f)| (id, field(f).into())).collect(),
+            *mode,
+        ),
+        DataType::Dictionary(_, values) => logical_type(values),
+        DataType::RunEndEncoded(_, values) => logical_type(values.data_type()),
+        d => d.clone(),
+    }
+}
+
+/// Hashes the logical value at `idx` of `array`
+///
+/// Each non-null value is prefixed with `1` and each null value is encoded as `0`
+fn hash_row(array: &dyn Array, idx: usize, hasher: &mut Sha256) {
+    // Encoded types delegate to the value they reference, which encodes its own validity
+    match array.data_type() {
+        DataType::Null => return hasher.update([0]),
+        DataType::Dictionary(_, _) => {
+            return downcast_dictionary_array!(
+                array => match array.key(idx) {
+                    Some(key) => hash_row(array.values().as_ref(), key, hasher),
+                    None => hasher.update([0]),
+                },
+                t => unreachable!("expected dictionary, got {t}")
+            )
+        }
+        DataType::RunEndEncoded(_, _) => {
+            return downcast_run_array!(
+                array => {
+                    let physical = array.get_physical_index(idx);
+                    hash_row(array.values().as_ref(), physical, hasher)
+                },
+                t => unreachable!("expected run array, got {t}")
+            )
+        }
+        DataType::Union(_, _) => {
+            let array = array.as_union();
+            let type_id = array.type_id(idx);
+            hasher.update(type_id.to_le_bytes());
+            return hash_row(
+                array.child(type_id).as_ref(),
+                array.value_offset(idx),
+                hasher,
+            );
+        }
+        _ => {}
+    }
+
+    if array.is_null(idx) {
+        return hasher.update([0]);
+    }
+    hasher.update([1]);
+
+    downcast_primitive_array!(
+        array => array.value(idx).hash_le(hasher),
+        DataType::Boolean => hasher.update([array.as_boolean().value(idx) as u8]),
+        DataType::Utf8 => hash_byte_value(array.as_string::<i32>(), idx, hasher),
+        DataType::LargeUtf8 => hash_byte_value(array.as_string::<i64>(), idx, hasher),
+        DataType::Utf8View => hash_byte_view_value(array.as_string_view(), idx, hasher),
+        DataType::Binary => hash_byte_value(array.as_binary::<i32>(), idx, hasher),
+        DataType::LargeBinary => hash_byte_value(array.as_binary::<i64>(), idx, hasher),
+        DataType::BinaryView => hash_byte_view_value(array.as_binary_view(), idx, hasher),
+        DataType::FixedSizeBinary(_) => {
+            hash_bytes(array.as_fixed_size_binary().value(idx), hasher)
+        }
+        DataType::List(_) => {
+            let array = array.as_list::<i32>();
+            let offsets = array.value_offsets();
+            let (start, end) = (offsets[idx].as_usize(), offsets[idx + 1].as_usize());
+            hash_children(array.values().as_ref(), start, end, hasher)
+        }
+        DataType::LargeList(_) => {
+            let array = array.as_list::<i64>();
+            let offsets = array.value_offsets();
+            let (start, end) = (offsets[idx].as_usize(), offsets[idx + 1].as_usize());
+            hash_children(array.values().as_ref(), start, end, hasher)
+        }
+        DataType::ListView(_) => {
+            let array = array.as_list_view::<i32>();
+            let start = array.value_offsets()[idx].as_usize();
+            let end = start + array.value_sizes()[idx].as_usize();
+            hash_children(array.values().as_ref(), start, end, hasher)
+        }
+        DataType::LargeListView(_) => {
+            let array = array.as_list_view::<i64>();
+            let start = array.value_offsets()[idx].as_usize();
+            let end = start + array.value_sizes()[idx].as_usize();
+            hash_children(array.values().as_ref(), start, end, hasher)
+        }
+        DataType::FixedSizeList(_, size) => {
+            let array = array.as_fixed_size_list();
+            let start = array.value_offset(idx) as usize;
+            hash_children(array.values().as_ref(), start, start + *size as usize, hasher)
+        }
+        DataType::Map(_, _) => {
+            let array = array.as_map();
+            let offsets = array.value_offsets();
+            let (start, end) = (offsets[idx].as_usize(), offsets[idx + 1].as_usize());
+            hash_children(array.entries(), start, end, hasher)
+        }
+        DataType::Struct(_) => {
+            for column in array.as_struct().columns() {
+                hash_row(column.as_ref(), idx, hasher);
+            }
+        }
+        t => unreachable!("unexpected data type {t}")
+    )
+}
+
+/// A primitive value hashed as its little-endian bytes
+trait HashLe {
+    fn hash_le(&self, hasher: &mut Sha256);
+}
+
+macro_rules! hash_le {
+    ($($t:ty),*) => {
+        $(impl HashLe for $t {
+            fn hash_le(&self, hasher: &mut Sha256) {
+                hasher.update(self.to_le_bytes())
+            }
+        })*
+    };
+}
+
+hash_le!(i8, i16, i32, i64, i128, i256, u8, u16, u32, u64, f16, f32, f64);
+
+impl HashLe for IntervalDayTime {
+    fn hash_le(&self, hasher: &mut Sha256) {
+        self.days.hash_le(hasher);
+        self.milliseconds.hash_le(hasher);
+    }
+}
+
+impl HashLe for IntervalMonthDayNano {
+    fn hash_le(&self, hasher: &mut Sha256) {
+        self.months.hash_le(hasher);
+        self.days.hash_le(hasher);
+        self.nanoseconds.hash_le(hasher);
+    }
+}
+
+fn hash_children(values: &dyn Array, start: usize, end: usize, hasher: &mut Sha256) {
+    hash_len(end - start, hasher);
+    for idx in start..end {
+        hash_row(values, idx, hasher);
+    }
+}
+
+fn hash_byte_value<T: ByteArrayType>(array: &GenericByteArray<T>, idx: usize, hasher: &mut Sha256) {
+    let value: &[u8] = array.value(idx).as_ref();
+    hash_bytes(value, hasher)
+}
+
+fn hash_byte_view_value<T: ByteViewType + ?Sized>(
+    array: &GenericByteViewArray<T>,
+    idx: usize,
+    hasher: &mut Sha256,
+) {
+    let value: &[u8] = array.value(idx).as_ref();
+    hash_bytes(value, hasher)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::builder::{ListBuilder, StringBuilder};
+    use arrow_array::types::Int32Type;
+    use arrow_buffer::{NullBuffer, OffsetBuffer, ScalarBuffer};
+    use std::sync::Arc;
+
+    fn batch(column: ArrayRef) -> RecordBatch {
+        RecordBatch::try_from_iter([("c", column)]).unwrap()
+    }
+
+    #[test]
+    fn test_layout_independent() {
+        let values = vec![Some("foo"), None, Some("bar"), Some("foo")];
+        let expected = hash_batch(&batch(Arc::new(StringArray::from(values.clone()))));
+
+        let large = LargeStringArray::from(values.clone());
+        assert_eq!(hash_batch(&batch(Arc::new(large))), expected);
+
+        let view = StringViewArray::from(values.clone());
+        assert_eq!(hash_batch(&batch(Arc::new(view))), expected);
+
+        let dict: DictionaryArray<Int32Type> = values.iter().copied().collect();
+        assert_eq!(hash_batch(&batch(Arc::new(dict))), expected);
+
+        let padded =
+            StringArray::from(vec![Some("x"), Some("foo"), None, Some("bar"), Some("foo")]);
+        assert_eq!(hash_batch(&batch(Arc::new(padded.slice(1, 4)))), expected);
+
+        let run_ends = Int32Array::from(vec![1, 2, 3, 4]);
+        let ree = RunArray::try_new(&run_ends, &StringArray::from(values)).unwrap();
+        assert_eq!(hash_batch(&batch(Arc::new(ree))), expected);
+    }
+
+    #[test]
+    fn test_null_slots() {
+        let a = Int32Array::new(
+            ScalarBuffer::from(vec![1, 2, 3]),
+            Some(NullBuffer::from(vec![true, false, true])),
+        );
+        let b = Int32Array::new(
+            ScalarBuffer::from(vec![1, 42, 3]),
+            Some(NullBuffer::from(vec![true, false, true])),
+        );
+        assert_eq!(
+            hash_batch(&batch(Arc::new(a))),
+            hash_batch(&batch(Arc::new(b)))
+        );
+
+        // A null list slot pointing to non-empty child values
+        let field = Arc::new(Field::new_list_field(DataType::Int32, true));
+        let values = Arc::new(Int32Array::from(vec![1, 2, 3, 4]));
+        let offsets = OffsetBuffer::new(ScalarBuffer::from(vec![0, 2, 4]));
+        let nulls = Some(NullBuffer::from(vec![true, false]));
+        let list = ListArray::new(field.clone(), offsets, values, nulls);
+
+        let values = Arc::new(Int32Array::from(vec![1, 2]));
+        let offsets = OffsetBuffer::new(ScalarBuffer::from(vec![0_i64, 2, 2]));
+        let nulls = Some(NullBuffer::from(vec![true, false]));
+        let large = LargeListArray::new(field, offsets, values, nulls);
+
+        assert_eq!(
+            hash_batch(&batch(Arc::new(list))),
+            hash_batch(&batch(Arc::new(large)))
+        );
+    }
+
+    #[test]
+    fn test_little_endian() {
+        let row_digest = |array: &dyn Array| {
+            let mut hasher = Sha256::new();
+            hash_row(array, 0, &mut hasher);
+            hasher.finalize()
+        };
+        let expected = |bytes: &[u8]| {
+            let mut hasher = Sha256::new();
+            hasher.update([1]);
+            hasher.update(bytes);
+            hasher.finalize()
+        };
+
+        let array = Int32Array::from(vec![0x01020304]);
+        assert_eq!(row_digest(&array), expected(&[4, 3, 2, 1]));
+
+        let array = Decimal256Array::from(vec![i256::from_i128(1)]);
+        let mut bytes = [0; 32];
+        bytes[0] = 1;
+        assert_eq!(row_digest(&array), expected(&bytes));
+
+        let array = IntervalMonthDayNanoArray::from(vec![IntervalMonthDayNano::new(1, 2, 3)]);
+        let bytes = [
+            &1i32.to_le_bytes()[..],
+            &2i32.to_le_bytes(),
+            &3i64.to_le_bytes(),
+        ]
+        .concat();
+        assert_eq!(row_digest(&array), expected(&bytes));
+    }
+
+    #[test]
+    fn test_distinguishes_content() {
+        let a = hash_batch(&batch(Arc::new(Int32Array::from(vec![1, 2, 3]))));
+        let b = hash_batch(&batch(Arc::new(Int32Array::from(vec![1, 2, 4]))));
+        let c = hash_batch(&batch(Arc::new(Int32Array::from(vec![
+            Some(1),
+            Some(2),
+            None,
+        ]))));
+        let d = hash_batch(&batch(Arc::new(Int64Array::from(vec![1, 2, 3]))));
+        let e = hash_batch(
+            &RecordBatch::try_from_iter([("d", Arc::new(Int32Array::from(vec![1, 2, 3])) as _)])
+                .unwrap(),
+        );
+        let digests = [a, b, c, d, e];
+        for (i, x) in digests.iter().enumerate() {
+            for y in &digests[i + 1..] {
+                assert_ne!(x, y);
+            }
+        }
+
+        // Moving a value between adjacent lists changes the digest
+        let mut builder = ListBuilder::new(StringBuilder::new());
+        builder.append_value([Some("a"), Some("b")]);
+        builder.append_value([Some("c")]);
+        let x = hash_batch(&batch(Arc::new(builder.finish())));
+        builder.append_value([Some("a")]);
+        builder.append_value([Some("b"), Some("c")]);
+        let y = hash_batch(&batch(Arc::new(builder.finish())));
+        assert_ne!(x, y);
+    }
+}
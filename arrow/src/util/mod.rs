@@ -25,6 +25,9 @@ pub use arrow_data::bit_mask;
 pub mod bench_util;
 #[cfg(feature = "test_utils")]
 pub mod data_gen;
+#[cfg(feature = "digest")]
+pub mod digest;
+pub mod mutable_table;
 #[cfg(feature = "prettyprint")]
 pub use arrow_cast::pretty;
 pub mod string_writer;
@@ -0,0 +1,495 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`MutableTable`], an in-memory table of [`RecordBatch`]es supporting appends,
+//! deletes and compaction
+
+use std::sync::Arc;
+
+use arrow_array::{new_null_array, Array, ArrayRef, BooleanArray, RecordBatch, RecordBatchOptions};
+use arrow_buffer::BooleanBuffer;
+use arrow_cast::{can_cast_types, cast_with_options, CastOptions};
+use arrow_schema::{ArrowError, SchemaRef};
+use arrow_select::coalesce::BatchCoalescer;
+use arrow_select::filter::filter_record_batch;
+
+/// The default number of rows per batch of a compacted [`MutableTable`]
+const DEFAULT_BATCH_SIZE: usize = 8192;
+
+/// An in-memory table of [`RecordBatch`]es with a fixed schema, supporting appends,
+/// deletes and compaction
+///
+/// # Appends
+///
+/// Batches appended with [`Self::append`] are coerced to the schema of the table:
+///
+/// * Columns are matched by name, and may be in any order
+/// * Columns of a different type are cast to the type of the table, returning an error
+///   if the cast is not supported or a value can't be converted
+/// * Nullable columns of the table missing from the batch are filled with nulls
+///
+/// # Deletes
+///
+/// Rows can be deleted with [`Self::delete_rows`] or [`Self::delete_where`]. Deleted rows
+/// are recorded in a mask per batch, and are only removed from memory on compaction.
+///
+/// # Compaction
+///
+/// [`Self::compact`] removes deleted rows and rechunks the table into batches of
+/// [`Self::with_target_batch_size`] rows, which is useful after many small appends.
+///
+/// # Example
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow::array::{record_batch, Int64Array, AsArray};
+/// # use arrow::datatypes::{DataType, Field, Schema, Int64Type};
+/// # use arrow::util::mutable_table::MutableTable;
+/// let schema = Arc::new(Schema::new(vec![
+///     Field::new("id", DataType::Int64, false),
+///     Field::new("name", DataType::Utf8, true),
+/// ]));
+/// let mut table = MutableTable::new(schema).with_target_batch_size(4);
+///
+/// // `id` is cast from Int32, and the missing `name` column is null
+/// table.append(record_batch!(("id", Int32, [1, 2, 3])).unwrap()).unwrap();
+/// table.append(record_batch!(("name", Utf8, ["d", "e"]), ("id", Int64, [4, 5])).unwrap()).unwrap();
+///
+/// // Delete the rows with an even id
+/// let deleted = table
+///     .delete_where(|batch| {
+///         let ids = batch.column(0).as_primitive::<Int64Type>();
+///         Ok(ids.iter().map(|id| id.map(|id| id % 2 == 0)).collect())
+///     })
+///     .unwrap();
+/// assert_eq!(deleted, 2);
+/// assert_eq!(table.num_rows(), 3);
+///
+/// table.compact().unwrap();
+/// let batches = table.batches().unwrap();
+/// assert_eq!(batches.len(), 1);
+/// assert_eq!(batches[0].column(0).as_ref(), &Int64Array::from(vec![1, 3, 5]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct MutableTable {
+    schema: SchemaRef,
+    target_batch_size: usize,
+    batches: Vec<TableBatch>,
+    /// The number of rows not deleted
+    num_rows: usize,
+}
+
+/// A batch of a [`MutableTable`] and its deleted rows
+#[derive(Debug, Clone)]
+struct TableBatch {
+    batch: RecordBatch,
+    /// The rows of `batch` that are deleted, if any
+    deleted: Option<BooleanBuffer>,
+    num_deleted: usize,
+}
+
+impl TableBatch {
+    fn num_rows(&self) -> usize {
+        self.batch.num_rows() - self.num_deleted
+    }
+
+    /// Returns a filter selecting the rows that are not deleted, if any are deleted
+    fn filter(&self) -> Option<BooleanArray> {
+        self.deleted
+            .as_ref()
+            .map(|deleted| BooleanArray::new(!deleted, None))
+    }
+
+    /// Marks the rows of `delete` as deleted, returning the number not already deleted
+    fn delete(&mut self, delete: BooleanBuffer) -> usize {
+        let deleted = match self.deleted.take() {
+            Some(deleted) => &deleted | &delete,
+            None => delete,
+        };
+        let num_deleted = deleted.count_set_bits();
+        let newly_deleted = num_deleted - self.num_deleted;
+        self.deleted = (num_deleted > 0).then_some(deleted);
+        self.num_deleted = num_deleted;
+        newly_deleted
+    }
+}
+
+impl MutableTable {
+    /// Create a new, empty [`MutableTable`] with `schema`
+    pub fn new(schema: SchemaRef) -> Self {
+        Self {
+            schema,
+            target_batch_size: DEFAULT_BATCH_SIZE,
+            batches: vec![],
+            num_rows: 0,
+        }
+    }
+
+    /// Set the number of rows per batch after [`Self::compact`] (defaults to 8192)
+    pub fn with_target_batch_size(mut self, target_batch_size: usize) -> Self {
+        self.target_batch_size = target_batch_size.max(1);
+        self
+    }
+
+    /// Returns the schema of this table
+    pub fn schema(&self) -> SchemaRef {
+        Arc::clone(&self.schema)
+    }
+
+    /// Returns the number of rows of this table, excluding deleted rows
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    /// Returns true if this table has no rows, excluding deleted rows
+    pub fn is_empty(&self) -> bool {
+        self.num_rows == 0
+    }
+
+    /// Returns the number of deleted rows not yet removed by [`Self::compact`]
+    pub fn num_deleted_rows(&self) -> usize {
+        self.batches.iter().map(|b| b.num_deleted).sum()
+    }
+
+    /// Append `batch` to this table, coercing it to the schema of the table
+    ///
+    /// See the [type level documentation](Self#appends) for how batches are coerced.
+    pub fn append(&mut self, batch: RecordBatch) -> Result<(), ArrowError> {
+        let batch = self.coerce(batch)?;
+        if batch.num_rows() == 0 {
+            return Ok(());
+        }
+        self.num_rows += batch.num_rows();
+        self.batches.push(TableBatch {
+            batch,
+            deleted: None,
+            num_deleted: 0,
+        });
+        Ok(())
+    }
+
+    fn coerce(&self, batch: RecordBatch) -> Result<RecordBatch, ArrowError> {
+        if batch.schema_ref().fields() == self.schema.fields() {
+            return batch.with_schema(self.schema());
+        }
+
+        let batch_schema = batch.schema();
+        if let Some(extra) = batch_schema
+            .fields()
+            .iter()
+            .find(|f| self.schema.field_with_name(f.name()).is_err())
+        {
+            return Err(ArrowError::SchemaError(format!(
+                "Column '{}' not found in table schema",
+                extra.name()
+            )));
+        }
+
+        let cast_options = CastOptions::default().with_safe(false);
+        let columns = self
+            .schema
+            .fields()
+            .iter()
+            .map(|field| match batch.column_by_name(field.name()) {
+                Some(column) if column.data_type() == field.data_type() => Ok(Arc::clone(column)),
+                Some(column) if can_cast_types(column.data_type(), field.data_type()) => {
+                    cast_with_options(column, field.data_type(), &cast_options)
+                }
+                Some(column) => Err(ArrowError::SchemaError(format!(
+                    "Cannot coerce column '{}' of type {} to {}",
+                    field.name(),
+                    column.data_type(),
+                    field.data_type()
+                ))),
+                None if field.is_nullable() => {
+                    Ok(new_null_array(field.data_type(), batch.num_rows()))
+                }
+                None => Err(ArrowError::SchemaError(format!(
+                    "Non-nullable column '{}' missing from appended batch",
+                    field.name()
+                ))),
+            })
+            .collect::<Result<Vec<ArrayRef>, _>>()?;
+
+        let options = RecordBatchOptions::new().with_row_count(Some(batch.num_rows()));
+        RecordBatch::try_new_with_options(self.schema(), columns, &options)
+    }
+
+    /// Deletes the rows at `indices`, returning the number of rows deleted
+    ///
+    /// Indices refer to rows not already deleted, in the order returned by
+    /// [`Self::batches`], and so are invalidated by deletes. Duplicate indices
+    /// are ignored.
+    ///
+    /// Returns an error, deleting no rows, if any index is out of bounds.
+    pub fn delete_rows(&mut self, indices: &[usize]) -> Result<usize, ArrowError> {
+        if let Some(index) = indices.iter().find(|i| **i >= self.num_rows) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Row index {index} out of bounds for table of {} rows",
+                self.num_rows
+            )));
+        }
+        let mut indices = indices.to_vec();
+        indices.sort_unstable();
+        indices.dedup();
+
+        let mut deleted = 0;
+        let mut remaining = indices.as_slice();
+        let mut offset = 0;
+        for batch in &mut self.batches {
+            let end = offset + batch.num_rows();
+            let split = remaining.partition_point(|i| *i < end);
+            let (batch_indices, rest) = remaining.split_at(split);
+            remaining = rest;
+
+            if !batch_indices.is_empty() {
+                // Map the indices of rows not deleted to indices of the batch
+                let live: Vec<usize> = match &batch.deleted {
+                    Some(d) => (!d).set_indices().collect(),
+                    None => (0..batch.batch.num_rows()).collect(),
+                };
+                let mut delete = vec![false; batch.batch.num_rows()];
+                for i in batch_indices {
+                    delete[live[i - offset]] = true;
+                }
+                deleted += batch.delete(BooleanBuffer::from(delete));
+            }
+            offset = end;
+        }
+        self.num_rows -= deleted;
+        Ok(deleted)
+    }
+
+    /// Deletes the rows for which `predicate` returns `true`, returning the number of
+    /// rows deleted
+    ///
+    /// `predicate` is called for each batch of the table, including rows already
+    /// deleted, and must return an array of the same length. Rows for which it returns
+    /// `false` or null are not deleted.
+    pub fn delete_where<F>(&mut self, mut predicate: F) -> Result<usize, ArrowError>
+    where
+        F: FnMut(&RecordBatch) -> Result<BooleanArray, ArrowError>,
+    {
+        // Evaluate the predicate for all batches first, so that an error deletes no rows
+        let deletes = self
+            .batches
+            .iter()
+            .map(|batch| {
+                let delete = predicate(&batch.batch)?;
+                if delete.len() != batch.batch.num_rows() {
+                    return Err(ArrowError::InvalidArgumentError(format!(
+                        "delete_where predicate returned {} values for batch of {} rows",
+                        delete.len(),
+                        batch.batch.num_rows()
+                    )));
+                }
+                Ok(match delete.nulls() {
+                    Some(nulls) => delete.values() & nulls.inner(),
+                    None => delete.values().clone(),
+                })
+            })
+            .collect::<Result<Vec<_>, ArrowError>>()?;
+
+        let deleted = self
+            .batches
+            .iter_mut()
+            .zip(deletes)
+            .map(|(batch, delete)| batch.delete(delete))
+            .sum();
+        self.num_rows -= deleted;
+        Ok(deleted)
+    }
+
+    /// Returns the rows of this table as [`RecordBatch`]es, excluding deleted rows
+    pub fn batches(&self) -> Result<Vec<RecordBatch>, ArrowError> {
+        self.batches
+            .iter()
+            .filter(|b| b.num_rows() > 0)
+            .map(|b| match b.filter() {
+                Some(filter) => filter_record_batch(&b.batch, &filter),
+                None => Ok(b.batch.clone()),
+            })
+            .collect()
+    }
+
+    /// Removes deleted rows, and rechunks the table into batches of the target batch size
+    ///
+    /// After compaction, all batches except the last have exactly
+    /// [target batch size](Self::with_target_batch_size) rows.
+    pub fn compact(&mut self) -> Result<(), ArrowError> {
+        let mut coalescer = BatchCoalescer::new(self.schema(), self.target_batch_size);
+        for batch in &self.batches {
+            match batch.filter() {
+                Some(filter) => coalescer.push_batch_with_filter(batch.batch.clone(), &filter)?,
+                None => coalescer.push_batch(batch.batch.clone())?,
+            }
+        }
+        coalescer.finish_buffered_batch()?;
+
+        self.batches = std::iter::from_fn(|| coalescer.next_completed_batch())
+            .map(|batch| TableBatch {
+                batch,
+                deleted: None,
+                num_deleted: 0,
+            })
+            .collect();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int64Type;
+    use arrow_array::{record_batch, Int64Array};
+    use arrow_schema::{DataType, Field, Schema};
+
+    fn test_table() -> MutableTable {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("name", DataType::Utf8, true),
+        ]));
+        MutableTable::new(schema).with_target_batch_size(3)
+    }
+
+    fn ids(table: &MutableTable) -> Vec<i64> {
+        let batches = table.batches().unwrap();
+        batches
+            .iter()
+            .flat_map(|b| b.column(0).as_primitive::<Int64Type>().values().to_vec())
+            .collect()
+    }
+
+    #[test]
+    fn test_append_coerce() {
+        let mut table = test_table();
+        table
+            .append(record_batch!(("id", Int64, [1, 2]), ("name", Utf8, ["a", "b"])).unwrap())
+            .unwrap();
+        table
+            .append(record_batch!(("name", Utf8, ["c"]), ("id", Utf8, ["3"])).unwrap())
+            .unwrap();
+        table
+            .append(record_batch!(("id", Int8, [4])).unwrap())
+            .unwrap();
+        let empty = Arc::new(Int64Array::from(Vec::<i64>::new())) as ArrayRef;
+        table
+            .append(RecordBatch::try_from_iter([("id", empty)]).unwrap())
+            .unwrap();
+        assert_eq!(table.num_rows(), 4);
+        assert_eq!(ids(&table), vec![1, 2, 3, 4]);
+
+        let batches = table.batches().unwrap();
+        assert_eq!(batches.len(), 3);
+        assert!(batches.iter().all(|b| b.schema() == table.schema()));
+        assert_eq!(batches[1].column(1).as_string::<i32>().value(0), "c");
+        assert!(batches[2].column(1).is_null(0));
+
+        let cases = [
+            (
+                record_batch!(("id", Int64, [1]), ("other", Int64, [1])).unwrap(),
+                "Schema error: Column 'other' not found in table schema",
+            ),
+            (
+                record_batch!(("name", Utf8, ["x"])).unwrap(),
+                "Schema error: Non-nullable column 'id' missing from appended batch",
+            ),
+            (
+                record_batch!(("id", Utf8, ["x"])).unwrap(),
+                "Cast error: Cannot cast string 'x' to value of Int64 type",
+            ),
+            (
+                record_batch!(("id", Int64, [Some(1), None])).unwrap(),
+                "Invalid argument error: Column 'id' is declared as non-nullable but contains null values",
+            ),
+        ];
+        for (batch, expected) in cases {
+            let err = table.append(batch).unwrap_err();
+            assert_eq!(err.to_string(), expected);
+        }
+        assert_eq!(table.num_rows(), 4);
+    }
+
+    #[test]
+    fn test_delete_and_compact() {
+        let mut table = test_table();
+        for ids in [vec![0, 1, 2, 3], vec![4], vec![5, 6]] {
+            let batch =
+                RecordBatch::try_from_iter([("id", Arc::new(Int64Array::from(ids)) as ArrayRef)])
+                    .unwrap();
+            table.append(batch).unwrap();
+        }
+
+        assert_eq!(table.delete_rows(&[1, 4, 1]).unwrap(), 2);
+        assert_eq!(ids(&table), vec![0, 2, 3, 5, 6]);
+        // Indices refer to the remaining rows
+        assert_eq!(table.delete_rows(&[1]).unwrap(), 1);
+        assert_eq!(ids(&table), vec![0, 3, 5, 6]);
+
+        let err = table.delete_rows(&[0, 4]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Row index 4 out of bounds for table of 4 rows"
+        );
+
+        let deleted = table
+            .delete_where(|batch| {
+                let ids = batch.column(0).as_primitive::<Int64Type>();
+                Ok(ids.iter().map(|id| id.map(|id| id % 2 == 0)).collect())
+            })
+            .unwrap();
+        // 0 and 6 are deleted, 2 and 4 are already deleted
+        assert_eq!(deleted, 2);
+        assert_eq!(ids(&table), vec![3, 5]);
+        assert_eq!(table.num_rows(), 2);
+        assert_eq!(table.num_deleted_rows(), 5);
+
+        table.compact().unwrap();
+        assert_eq!(table.num_deleted_rows(), 0);
+        assert_eq!(table.batches().unwrap().len(), 1);
+        assert_eq!(ids(&table), vec![3, 5]);
+    }
+
+    #[test]
+    fn test_compact_rechunks() {
+        let mut table = test_table();
+        for i in 0..7 {
+            table
+                .append(record_batch!(("id", Int64, [i])).unwrap())
+                .unwrap();
+        }
+        table.compact().unwrap();
+        let sizes: Vec<_> = table
+            .batches()
+            .unwrap()
+            .iter()
+            .map(|b| b.num_rows())
+            .collect();
+        assert_eq!(sizes, vec![3, 3, 1]);
+        assert_eq!(ids(&table), (0..7).collect::<Vec<_>>());
+
+        let err = table
+            .delete_where(|_| Ok(BooleanArray::from(vec![true])))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: delete_where predicate returned 1 values for batch of 3 rows"
+        );
+        assert_eq!(table.num_rows(), 7);
+    }
+}
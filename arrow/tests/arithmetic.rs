@@ -16,7 +16,7 @@
 // under the License.
 
 use arrow_arith::numeric::{add, sub};
-use arrow_arith::temporal::{date_part, DatePart};
+use arrow_arith::temporal::{date_bin, date_part, date_trunc, DatePart, DateTruncGranularity};
 use arrow_array::cast::AsArray;
 use arrow_array::temporal_conversions::as_datetime_with_timezone;
 use arrow_array::timezone::Tz;
@@ -188,3 +188,55 @@ fn test_timestamp_with_timezone() {
         test_timestamp_with_timezone_impl::<TimestampNanosecondType>(timezone);
     }
 }
+
+#[test]
+fn test_date_trunc_dst_timezone_using_chrono_tz() {
+    let trunc = |values: Vec<i64>, tz: &str, granularity| {
+        let a = TimestampSecondArray::from(values).with_timezone(tz.to_string());
+        let b = date_trunc(&a, granularity).unwrap();
+        b.as_primitive::<TimestampSecondType>().values().to_vec()
+    };
+
+    // 01:30 EST and 03:30 EDT, either side of the start of daylight saving time on 2024-03-10
+    let values = vec![1710052200, 1710055800];
+    let hours = trunc(
+        values.clone(),
+        "America/New_York",
+        DateTruncGranularity::Hour,
+    );
+    assert_eq!(hours, vec![1710050400, 1710054000]);
+    // Both are on the day starting at 00:00 EST
+    let days = trunc(values, "America/New_York", DateTruncGranularity::Day);
+    assert_eq!(days, vec![1710046800, 1710046800]);
+
+    // 01:30 EDT and 01:30 EST, when 01:00 occurs twice at the end of daylight saving time
+    // on 2024-11-03, are truncated to the 01:00 with the same offset
+    let values = vec![1730611800, 1730615400];
+    let hours = trunc(
+        values.clone(),
+        "America/New_York",
+        DateTruncGranularity::Hour,
+    );
+    assert_eq!(hours, vec![1730610000, 1730613600]);
+    let days = trunc(values, "America/New_York", DateTruncGranularity::Day);
+    assert_eq!(days, vec![1730606400, 1730606400]);
+
+    // Midnight was skipped in Sao Paulo on 2018-11-04, so the day starts at 01:00 -02:00
+    let days = trunc(
+        vec![1541332800],
+        "America/Sao_Paulo",
+        DateTruncGranularity::Day,
+    );
+    assert_eq!(days, vec![1541300400]);
+}
+
+#[test]
+fn test_date_bin_dst_timezone_using_chrono_tz() {
+    // Daily bins from midnight on 2024-03-09 EST, of 12:00 EDT on 2024-03-11
+    let a = TimestampSecondArray::from(vec![1710172800]).with_timezone("America/New_York");
+    let stride = IntervalMonthDayNano::new(0, 1, 0);
+    let b = date_bin(&a, stride, 1709960400).unwrap();
+    let b = b.as_primitive::<TimestampSecondType>();
+    // Midnight EDT on 2024-03-11
+    assert_eq!(b.value(0), 1710129600);
+}
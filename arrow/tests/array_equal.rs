@@ -23,8 +23,8 @@ use arrow::array::{
 };
 use arrow::datatypes::{Int16Type, Int32Type};
 use arrow_array::builder::{StringBuilder, StringViewBuilder, StructBuilder};
-use arrow_array::{DictionaryArray, FixedSizeListArray, StringViewArray};
-use arrow_buffer::{Buffer, ToByteSlice};
+use arrow_array::{DictionaryArray, FixedSizeListArray, ListViewArray, StringViewArray};
+use arrow_buffer::{Buffer, NullBuffer, ToByteSlice};
 use arrow_data::{ArrayData, ArrayDataBuilder};
 use arrow_schema::{DataType, Field, Fields};
 use std::sync::Arc;
@@ -403,6 +403,53 @@ fn test_list_equal() {
     test_equal(&a, &b, false);
 }
 
+#[test]
+fn test_list_view_equal() {
+    let field = Arc::new(Field::new_list_field(DataType::Int32, true));
+    let values = Arc::new(Int32Array::from(vec![1, 2, 3, 4, 5, 6]));
+
+    // [[1, 2, 3], null, [4, 5, 6]] with values in order
+    let a = ListViewArray::new(
+        field.clone(),
+        vec![0, 3, 3].into(),
+        vec![3, 0, 3].into(),
+        values.clone(),
+        Some(NullBuffer::from(vec![true, false, true])),
+    );
+
+    // The same logical values referenced out of order, with a null slot of non-zero size
+    let b_values = Arc::new(Int32Array::from(vec![4, 5, 6, 9, 1, 2, 3]));
+    let b = ListViewArray::new(
+        field.clone(),
+        vec![4, 3, 0].into(),
+        vec![3, 1, 3].into(),
+        b_values,
+        Some(NullBuffer::from(vec![true, false, true])),
+    );
+    test_equal(&a, &b, true);
+
+    // Overlapping views of the same values
+    let c = ListViewArray::new(
+        field.clone(),
+        vec![0, 3, 3].into(),
+        vec![3, 0, 2].into(),
+        values.clone(),
+        Some(NullBuffer::from(vec![true, false, true])),
+    );
+    test_equal(&a, &c, false);
+
+    let d = ListViewArray::new(
+        field,
+        vec![0, 3, 3].into(),
+        vec![3, 0, 3].into(),
+        values,
+        None,
+    );
+    test_equal(&a, &d, false);
+    test_equal(&a.slice(2, 1), &b.slice(2, 1), true);
+    test_equal(&a.slice(0, 1), &d.slice(0, 1), true);
+}
+
 #[test]
 fn test_empty_offsets_list_equal() {
     let empty: Vec<i32> = vec![];
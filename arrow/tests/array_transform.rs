@@ -22,8 +22,8 @@ use arrow::array::{
     UInt16Array, UInt16Builder, UInt8Array, UnionArray,
 };
 use arrow::datatypes::Int16Type;
-use arrow_array::StringViewArray;
-use arrow_buffer::{Buffer, ScalarBuffer};
+use arrow_array::{ListViewArray, StringViewArray};
+use arrow_buffer::{Buffer, NullBuffer, ScalarBuffer};
 use arrow_data::transform::MutableArrayData;
 use arrow_data::ArrayData;
 use arrow_schema::{DataType, Field, Fields, UnionFields};
@@ -611,6 +611,47 @@ fn test_list_append() {
     assert_eq!(finished, expected_list_data);
 }
 
+#[test]
+fn test_list_view_append() {
+    let field = Arc::new(Field::new_list_field(DataType::Int64, true));
+    // [[3, 4], null, [1], [1, 2, 3]]
+    let a = ListViewArray::new(
+        field.clone(),
+        vec![2, 1, 0, 0].into(),
+        vec![2, 2, 1, 3].into(),
+        Arc::new(Int64Array::from(vec![1, 2, 3, 4])),
+        Some(NullBuffer::from(vec![true, false, true, true])),
+    )
+    .into_data();
+    // [[5], []]
+    let b = ListViewArray::new(
+        field.clone(),
+        vec![0, 1].into(),
+        vec![1, 0].into(),
+        Arc::new(Int64Array::from(vec![5])),
+        None,
+    )
+    .into_data();
+
+    let mut mutable = MutableArrayData::new(vec![&a, &b], true, 4);
+    mutable.extend(0, 0, 2);
+    mutable.extend(1, 0, 2);
+    mutable.extend_nulls(1);
+    mutable.extend(0, 3, 4);
+    let result = ListViewArray::from(mutable.freeze());
+
+    let expected = ListViewArray::new(
+        field,
+        vec![0, 2, 2, 3, 0, 3].into(),
+        vec![2, 0, 1, 0, 0, 3].into(),
+        Arc::new(Int64Array::from(vec![3, 4, 5, 1, 2, 3])),
+        Some(NullBuffer::from(vec![true, false, true, true, false, true])),
+    );
+    assert_eq!(result, expected);
+    assert_eq!(result.offsets(), expected.offsets());
+    assert_eq!(result.values(), expected.values());
+}
+
 #[test]
 fn test_list_nulls_append() {
     let mut builder = ListBuilder::<Int64Builder>::new(Int64Builder::with_capacity(32));
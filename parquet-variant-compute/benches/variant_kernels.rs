@@ -0,0 +1,137 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, StringArray};
+use criterion::*;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use parquet_variant::Variant;
+use parquet_variant_compute::{
+    batch_json_string_to_variant, VariantArray, VariantArrayBuilder, VariantPathProgram,
+};
+
+const NUM_ROWS: usize = 1_000_000;
+
+/// JSON objects with a varying set of fields, as commonly seen in event data
+fn json_strings(num_rows: usize) -> ArrayRef {
+    let mut rng = StdRng::seed_from_u64(42);
+    let strings: StringArray = (0..num_rows)
+        .map(|i| {
+            let id = rng.random::<u32>();
+            match i % 3 {
+                0 => Some(format!(
+                    r#"{{"id": {id}, "kind": "click", "x": 10, "y": 20}}"#
+                )),
+                1 => Some(format!(
+                    r#"{{"id": {id}, "kind": "view", "page": {{"url": "/home", "ms": 125}}}}"#
+                )),
+                _ => Some(format!(
+                    r#"{{"id": {id}, "kind": "purchase", "items": [1, 2, 3]}}"#
+                )),
+            }
+        })
+        .collect();
+    Arc::new(strings)
+}
+
+fn int_variants(num_rows: usize) -> VariantArray {
+    let mut builder = VariantArrayBuilder::new(num_rows);
+    for i in 0..num_rows {
+        builder.append_variant(Variant::from(i as i64));
+    }
+    builder.build()
+}
+
+fn bench_batch_json_string_to_variant(c: &mut Criterion) {
+    let input = json_strings(NUM_ROWS);
+    let mut group = c.benchmark_group("batch_json_string_to_variant");
+    group.sample_size(10);
+    group.throughput(Throughput::Elements(NUM_ROWS as u64));
+    group.bench_function("1M rows", |b| {
+        b.iter(|| std::hint::black_box(batch_json_string_to_variant(&input).unwrap()))
+    });
+    group.finish();
+}
+
+fn bench_variant_field_access(c: &mut Criterion) {
+    let array = batch_json_string_to_variant(&json_strings(NUM_ROWS)).unwrap();
+    let mut group = c.benchmark_group("variant_field_access");
+    group.sample_size(10);
+    group.throughput(Throughput::Elements(NUM_ROWS as u64));
+    // Extract a top level field from each row
+    group.bench_function("get id 1M rows", |b| {
+        b.iter(|| {
+            let sum = (0..array.len())
+                .filter_map(|i| array.value(i).get_object_field("id"))
+                .filter_map(|v| v.as_int64())
+                .sum::<i64>();
+            std::hint::black_box(sum)
+        })
+    });
+    // Extract a nested field present in a third of the rows
+    group.bench_function("get page.ms 1M rows", |b| {
+        b.iter(|| {
+            let count = (0..array.len())
+                .filter_map(|i| array.value(i).get_object_field("page"))
+                .filter_map(|v| v.get_object_field("ms"))
+                .count();
+            std::hint::black_box(count)
+        })
+    });
+    group.finish();
+}
+
+fn bench_variant_get(c: &mut Criterion) {
+    let array = batch_json_string_to_variant(&json_strings(NUM_ROWS)).unwrap();
+    let mut group = c.benchmark_group("variant_get");
+    group.sample_size(10);
+    group.throughput(Throughput::Elements(NUM_ROWS as u64));
+    for path in ["id", "page.ms", "items.1"] {
+        group.bench_function(format!("get {path} 1M rows"), |b| {
+            b.iter(|| {
+                let mut program = VariantPathProgram::try_new(path).unwrap();
+                let count = (0..array.len())
+                    .filter_map(|i| program.get(&array, i).unwrap())
+                    .count();
+                std::hint::black_box(count)
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bench_variant_array_builder(c: &mut Criterion) {
+    let mut group = c.benchmark_group("variant_array_builder");
+    group.sample_size(10);
+    group.throughput(Throughput::Elements(NUM_ROWS as u64));
+    group.bench_function("append int64 1M rows", |b| {
+        b.iter(|| std::hint::black_box(int_variants(NUM_ROWS)))
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_batch_json_string_to_variant,
+    bench_variant_field_access,
+    bench_variant_get,
+    bench_variant_array_builder
+);
+
+criterion_main!(benches);
@@ -0,0 +1,422 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`VariantPathCardinality`] distinct count estimates for the paths of variant values
+
+use crate::VariantArray;
+use arrow::array::Array;
+use arrow_schema::ArrowError;
+use parquet_variant::Variant;
+use std::collections::BTreeMap;
+use std::hash::{Hash, Hasher};
+use twox_hash::XxHash64;
+
+/// The default [HyperLogLog] precision, giving a standard error of about 1.6%
+///
+/// [HyperLogLog]: https://en.wikipedia.org/wiki/HyperLogLog
+const DEFAULT_PRECISION: u8 = 12;
+
+/// Estimates the number of distinct values at each path of a collection of
+/// [`Variant`] values, using a [HyperLogLog] sketch per path
+///
+/// Paths are formed by joining object field names with `.`, with `[]` appended for
+/// the elements of a list, for example `user.tags[]`. The path of a top-level value
+/// that is not an object is the empty string. Only values that are neither objects,
+/// lists nor [`Variant::Null`] are counted. Integers of different widths with the same
+/// value are considered equal, as are the two string representations.
+///
+/// Values can be observed while encoding, see [`VariantArrayBuilder::with_path_cardinality`],
+/// or by scanning an existing [`VariantArray`] with [`Self::update`]. To reduce the cost
+/// of scanning large arrays, [`Self::with_sample_interval`] only observes every n-th row,
+/// in which case the estimates are for the sampled rows.
+///
+/// Each path requires `2^precision` bytes of memory, 4 KiB with the default precision.
+///
+/// ```
+/// # use parquet_variant::{Variant, VariantBuilder};
+/// # use parquet_variant_compute::{VariantArrayBuilder, VariantPathCardinality};
+/// let mut builder = VariantArrayBuilder::new(100);
+/// for i in 0..100 {
+///     let mut vb = VariantBuilder::new();
+///     let mut obj = vb.new_object();
+///     obj.insert("id", i);
+///     obj.insert("kind", if i % 2 == 0 { "even" } else { "odd" });
+///     obj.finish().unwrap();
+///     let (metadata, value) = vb.finish();
+///     builder.append_variant_buffers(&metadata, &value);
+/// }
+/// let array = builder.build();
+///
+/// let mut cardinality = VariantPathCardinality::new();
+/// cardinality.update(&array);
+/// assert_eq!(cardinality.estimate("kind"), Some(2));
+/// assert!(cardinality.estimate("id").unwrap().abs_diff(100) <= 3);
+/// assert_eq!(cardinality.estimate("missing"), None);
+/// ```
+///
+/// [HyperLogLog]: https://en.wikipedia.org/wiki/HyperLogLog
+/// [`VariantArrayBuilder::with_path_cardinality`]: crate::VariantArrayBuilder::with_path_cardinality
+#[derive(Debug, Clone, PartialEq)]
+pub struct VariantPathCardinality {
+    precision: u8,
+    sample_interval: usize,
+    /// The number of rows seen, including those not sampled
+    rows_seen: usize,
+    /// The number of rows observed
+    rows_sampled: usize,
+    sketches: BTreeMap<String, HyperLogLog>,
+}
+
+impl Default for VariantPathCardinality {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl VariantPathCardinality {
+    /// Create a new [`VariantPathCardinality`] that observes every row
+    pub fn new() -> Self {
+        Self {
+            precision: DEFAULT_PRECISION,
+            sample_interval: 1,
+            rows_seen: 0,
+            rows_sampled: 0,
+            sketches: BTreeMap::new(),
+        }
+    }
+
+    /// Set the precision of the sketches, between 4 and 18 inclusive, defaults to 12
+    ///
+    /// Higher precisions are more accurate, with a standard error of about
+    /// `1.04 / sqrt(2^precision)`, but use more memory
+    ///
+    /// # Panics
+    ///
+    /// Panics if `precision` is out of range, or any rows have already been observed
+    pub fn with_precision(mut self, precision: u8) -> Self {
+        assert!(
+            (4..=18).contains(&precision),
+            "precision must be between 4 and 18, got {precision}"
+        );
+        assert_eq!(
+            self.rows_seen, 0,
+            "with_precision must be called before observing rows"
+        );
+        self.precision = precision;
+        self
+    }
+
+    /// Only observe every `interval`-th row, starting with the first, defaults to 1
+    ///
+    /// # Panics
+    ///
+    /// Panics if `interval` is zero
+    pub fn with_sample_interval(mut self, interval: usize) -> Self {
+        assert!(interval > 0, "sample interval must be greater than zero");
+        self.sample_interval = interval;
+        self
+    }
+
+    /// Observe the non-null rows of `array`
+    pub fn update(&mut self, array: &VariantArray) {
+        for i in 0..array.len() {
+            if array.is_valid(i) && self.sample_next_row() {
+                self.observe(String::new(), &array.value(i));
+            }
+        }
+    }
+
+    /// Observe `variant` as the next row
+    pub fn update_variant(&mut self, variant: &Variant) {
+        if self.sample_next_row() {
+            self.observe(String::new(), variant);
+        }
+    }
+
+    /// Returns the estimated number of distinct values at `path`, or `None` if
+    /// no values were observed at `path`
+    pub fn estimate(&self, path: &str) -> Option<u64> {
+        self.sketches.get(path).map(HyperLogLog::estimate)
+    }
+
+    /// Returns the paths with observed values and their estimated number of distinct
+    /// values, ordered by path
+    pub fn estimates(&self) -> impl Iterator<Item = (&str, u64)> + '_ {
+        self.sketches
+            .iter()
+            .map(|(p, s)| (p.as_str(), s.estimate()))
+    }
+
+    /// Returns the number of rows observed, excluding those skipped by sampling
+    pub fn num_rows_sampled(&self) -> usize {
+        self.rows_sampled
+    }
+
+    /// Merge the sketches of `other` into `self`, for example to combine the
+    /// estimates of multiple batches computed in parallel
+    ///
+    /// Returns an error if the precisions differ
+    pub fn merge(&mut self, other: &Self) -> Result<(), ArrowError> {
+        if self.precision != other.precision {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Cannot merge VariantPathCardinality with precision {} into precision {}",
+                other.precision, self.precision
+            )));
+        }
+        for (path, sketch) in &other.sketches {
+            match self.sketches.get_mut(path) {
+                Some(existing) => existing.merge(sketch),
+                None => {
+                    self.sketches.insert(path.clone(), sketch.clone());
+                }
+            }
+        }
+        self.rows_seen += other.rows_seen;
+        self.rows_sampled += other.rows_sampled;
+        Ok(())
+    }
+
+    fn sample_next_row(&mut self) -> bool {
+        let sampled = self.rows_seen % self.sample_interval == 0;
+        self.rows_seen += 1;
+        self.rows_sampled += sampled as usize;
+        sampled
+    }
+
+    fn observe(&mut self, path: String, variant: &Variant) {
+        match variant {
+            Variant::Null => {}
+            Variant::Object(obj) => {
+                for (name, value) in obj.iter() {
+                    let path = match path.is_empty() {
+                        true => name.to_string(),
+                        false => format!("{path}.{name}"),
+                    };
+                    self.observe(path, &value);
+                }
+            }
+            Variant::List(list) => {
+                let path = format!("{path}[]");
+                for value in list.iter() {
+                    self.observe(path.clone(), &value);
+                }
+            }
+            leaf => {
+                // A fixed seed keeps sketches built by different processes mergeable
+                let mut hasher = XxHash64::with_seed(0);
+                hash_leaf(leaf, &mut hasher);
+                let precision = self.precision;
+                self.sketches
+                    .entry(path)
+                    .or_insert_with(|| HyperLogLog::new(precision))
+                    .add(hasher.finish());
+            }
+        }
+    }
+}
+
+/// Hash a variant value that is not an object or list
+fn hash_leaf(variant: &Variant, hasher: &mut impl Hasher) {
+    // Prefix each value with a tag, so that values of different types don't collide
+    match variant {
+        Variant::BooleanTrue => (0_u8, true).hash(hasher),
+        Variant::BooleanFalse => (0_u8, false).hash(hasher),
+        Variant::Int8(v) => (1_u8, *v as i64).hash(hasher),
+        Variant::Int16(v) => (1_u8, *v as i64).hash(hasher),
+        Variant::Int32(v) => (1_u8, *v as i64).hash(hasher),
+        Variant::Int64(v) => (1_u8, *v).hash(hasher),
+        Variant::Float(v) => (2_u8, (*v as f64).to_bits()).hash(hasher),
+        Variant::Double(v) => (2_u8, v.to_bits()).hash(hasher),
+        Variant::Decimal4(d) => (3_u8, d.integer() as i128, d.scale()).hash(hasher),
+        Variant::Decimal8(d) => (3_u8, d.integer() as i128, d.scale()).hash(hasher),
+        Variant::Decimal16(d) => (3_u8, d.integer(), d.scale()).hash(hasher),
+        Variant::Date(d) => (4_u8, d).hash(hasher),
+        Variant::TimestampMicros(ts) => (5_u8, ts).hash(hasher),
+        Variant::TimestampNtzMicros(ts) => (6_u8, ts).hash(hasher),
+        Variant::Binary(b) => (7_u8, b).hash(hasher),
+        Variant::String(s) => (8_u8, s).hash(hasher),
+        Variant::ShortString(s) => (8_u8, s.as_str()).hash(hasher),
+        Variant::Opaque(o) => (9_u8, o.as_bytes()).hash(hasher),
+        Variant::Null | Variant::Object(_) | Variant::List(_) => {
+            unreachable!("only leaf values are hashed")
+        }
+        // Types added after this was written are hashed by their debug representation
+        v => (10_u8, format!("{v:?}")).hash(hasher),
+    }
+}
+
+/// A [HyperLogLog] sketch of 64-bit hashes
+///
+/// [HyperLogLog]: https://en.wikipedia.org/wiki/HyperLogLog
+#[derive(Debug, Clone, PartialEq)]
+struct HyperLogLog {
+    precision: u8,
+    registers: Vec<u8>,
+}
+
+impl HyperLogLog {
+    fn new(precision: u8) -> Self {
+        Self {
+            precision,
+            registers: vec![0; 1 << precision],
+        }
+    }
+
+    fn add(&mut self, hash: u64) {
+        let index = (hash >> (64 - self.precision)) as usize;
+        // Set a sentinel bit so the rank is at most 64 - precision + 1
+        let remaining = (hash << self.precision) | (1 << (self.precision - 1));
+        let rank = remaining.leading_zeros() as u8 + 1;
+        self.registers[index] = self.registers[index].max(rank);
+    }
+
+    fn merge(&mut self, other: &Self) {
+        for (a, b) in self.registers.iter_mut().zip(&other.registers) {
+            *a = (*a).max(*b);
+        }
+    }
+
+    fn estimate(&self) -> u64 {
+        let m = self.registers.len() as f64;
+        let alpha = match self.registers.len() {
+            16 => 0.673,
+            32 => 0.697,
+            64 => 0.709,
+            _ => 0.7213 / (1.0 + 1.079 / m),
+        };
+        let sum: f64 = self.registers.iter().map(|r| 2f64.powi(-(*r as i32))).sum();
+        let estimate = alpha * m * m / sum;
+
+        // Use linear counting for small cardinalities, where it is more accurate
+        let zeros = self.registers.iter().filter(|r| **r == 0).count();
+        if estimate <= 2.5 * m && zeros > 0 {
+            return (m * (m / zeros as f64).ln()).round() as u64;
+        }
+        estimate.round() as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::VariantArrayBuilder;
+    use parquet_variant::VariantBuilder;
+
+    fn object_array(n: i64) -> VariantArray {
+        let mut builder = VariantArrayBuilder::new(n as usize);
+        for i in 0..n {
+            let mut vb = VariantBuilder::new();
+            let mut obj = vb.new_object();
+            obj.insert("id", i);
+            let mut user = obj.new_object("user");
+            user.insert("name", format!("user{}", i % 10).as_str());
+            let mut tags = user.new_list("tags");
+            tags.append_value("a");
+            tags.append_value(if i % 2 == 0 { "b" } else { "c" });
+            tags.finish();
+            user.finish().unwrap();
+            obj.insert("flag", Variant::Null);
+            obj.finish().unwrap();
+            let (metadata, value) = vb.finish();
+            builder.append_variant_buffers(&metadata, &value);
+            builder.append_null();
+        }
+        builder.build()
+    }
+
+    fn assert_close(actual: Option<u64>, expected: u64) {
+        let actual = actual.unwrap();
+        let error = actual.abs_diff(expected) as f64 / expected as f64;
+        assert!(error < 0.05, "expected about {expected}, got {actual}");
+    }
+
+    #[test]
+    fn test_paths() {
+        let mut cardinality = VariantPathCardinality::new();
+        cardinality.update(&object_array(1000));
+        assert_eq!(cardinality.num_rows_sampled(), 1000);
+
+        let estimates: Vec<_> = cardinality.estimates().collect();
+        let paths: Vec<_> = estimates.iter().map(|(p, _)| *p).collect();
+        assert_eq!(paths, vec!["id", "user.name", "user.tags[]"]);
+        assert_close(cardinality.estimate("id"), 1000);
+        assert_eq!(cardinality.estimate("user.name"), Some(10));
+        assert_eq!(cardinality.estimate("user.tags[]"), Some(3));
+        assert_eq!(cardinality.estimate("flag"), None);
+    }
+
+    #[test]
+    fn test_equal_values() {
+        let mut cardinality = VariantPathCardinality::new();
+        cardinality.update_variant(&Variant::Int8(1));
+        cardinality.update_variant(&Variant::Int64(1));
+        cardinality.update_variant(&Variant::from("hello"));
+        cardinality.update_variant(&Variant::String("hello"));
+        cardinality.update_variant(&Variant::Double(1.0));
+        assert_eq!(cardinality.estimate(""), Some(3));
+    }
+
+    #[test]
+    fn test_large_cardinality() {
+        for precision in [10, 14] {
+            let mut cardinality = VariantPathCardinality::new().with_precision(precision);
+            for i in 0..100_000 {
+                cardinality.update_variant(&Variant::from(i));
+            }
+            assert_close(cardinality.estimate(""), 100_000);
+        }
+    }
+
+    #[test]
+    fn test_sampling() {
+        let mut cardinality = VariantPathCardinality::new().with_sample_interval(10);
+        // Null rows are not sampled, so every 10th object is observed
+        cardinality.update(&object_array(1000));
+        assert_eq!(cardinality.num_rows_sampled(), 100);
+        assert_close(cardinality.estimate("id"), 100);
+        // Only even ids are sampled
+        assert_eq!(cardinality.estimate("user.tags[]"), Some(2));
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut a = VariantPathCardinality::new();
+        let mut b = VariantPathCardinality::new();
+        for i in 0..2000 {
+            a.update_variant(&Variant::from(i));
+            b.update_variant(&Variant::from(i + 1000));
+        }
+        b.update(&object_array(10));
+        a.merge(&b).unwrap();
+        assert_close(a.estimate(""), 3000);
+        assert_eq!(a.estimate("user.name"), Some(10));
+        assert_eq!(a.num_rows_sampled(), 4010);
+
+        let c = VariantPathCardinality::new().with_precision(8);
+        let err = a.merge(&c).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot merge VariantPathCardinality with precision 8 into precision 12"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "precision must be between 4 and 18, got 20")]
+    fn test_invalid_precision() {
+        VariantPathCardinality::new().with_precision(20);
+    }
+}
@@ -0,0 +1,151 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Exchange [`VariantArray`]s via the [C Data Interface]
+//!
+//! Variant columns are exported as a `Struct` annotated with the
+//! [`VariantType`] extension type, which is preserved across the interface
+//! so consumers such as pyarrow recognize them as variants. Arrays produced
+//! by other implementations, such as PySpark, are imported without copying.
+//!
+//! [C Data Interface]: https://arrow.apache.org/docs/format/CDataInterface.html
+
+use crate::{VariantArray, VariantType};
+use arrow::array::{make_array, Array};
+use arrow::ffi::{from_ffi, FFI_ArrowArray, FFI_ArrowSchema};
+use arrow_schema::extension::ExtensionType;
+use arrow_schema::{ArrowError, Field};
+
+/// Exports `array` to the C Data Interface as a field named `name`
+///
+/// The exported schema is annotated with the [`VariantType`] extension type,
+/// see [`VariantArray::field`]
+pub fn export_variant_array(
+    array: &VariantArray,
+    name: &str,
+) -> Result<(FFI_ArrowArray, FFI_ArrowSchema), ArrowError> {
+    let schema = FFI_ArrowSchema::try_from(&array.field(name))?;
+    let array = FFI_ArrowArray::new(&array.to_data());
+    Ok((array, schema))
+}
+
+/// Imports a [`VariantArray`] from the C Data Interface
+///
+/// If the schema carries an extension type name, it must be [`VariantType`];
+/// otherwise the struct layout alone is validated by [`VariantArray::try_new`].
+///
+/// # Safety
+///
+/// This function is intended to be called with the [`FFI_ArrowArray`] and
+/// [`FFI_ArrowSchema`] of a single array, see [`from_ffi`] for details.
+pub unsafe fn import_variant_array(
+    array: FFI_ArrowArray,
+    schema: &FFI_ArrowSchema,
+) -> Result<VariantArray, ArrowError> {
+    let field = Field::try_from(schema)?;
+    match field.extension_type_name() {
+        Some(VariantType::NAME) | None => {}
+        Some(name) => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Expected extension type {}, found {name}",
+                VariantType::NAME
+            )))
+        }
+    }
+    let data = from_ffi(array, schema)?;
+    VariantArray::try_new(make_array(data))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::VariantArrayBuilder;
+    use arrow::array::{ArrayRef, BinaryArray, StructArray};
+    use arrow_schema::{DataType, Fields};
+    use parquet_variant::{Variant, VariantBuilder};
+    use std::sync::Arc;
+
+    #[test]
+    fn round_trip() {
+        let mut builder = VariantArrayBuilder::new(3);
+        builder.append_variant(Variant::from(42i64));
+        builder.append_null();
+        builder.append_variant(Variant::from("hello"));
+        let array = builder.build();
+
+        let (ffi_array, ffi_schema) = export_variant_array(&array, "v").unwrap();
+        let field = Field::try_from(&ffi_schema).unwrap();
+        assert_eq!(field.name(), "v");
+        assert_eq!(field.extension_type_name(), Some(VariantType::NAME));
+
+        let imported = unsafe { import_variant_array(ffi_array, &ffi_schema) }.unwrap();
+        assert_eq!(imported.len(), 3);
+        assert_eq!(imported.value(0), Variant::from(42i64));
+        assert!(imported.is_null(1));
+        assert_eq!(imported.value(2), Variant::from("hello"));
+    }
+
+    #[test]
+    fn import_binary() {
+        let (metadata, value) = {
+            let mut vb = VariantBuilder::new();
+            vb.append_value(true);
+            vb.finish()
+        };
+        let fields = Fields::from(vec![
+            Field::new("metadata", DataType::Binary, false),
+            Field::new("value", DataType::Binary, false),
+        ]);
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(BinaryArray::from(vec![metadata.as_slice()])),
+            Arc::new(BinaryArray::from(vec![value.as_slice()])),
+        ];
+        let array = StructArray::new(fields, columns, None);
+        let value_ptr = array.column(1).to_data().buffers()[1].as_ptr();
+
+        // Without an extension type annotation
+        let (ffi_array, ffi_schema) = arrow::ffi::to_ffi(&array.to_data()).unwrap();
+        let imported = unsafe { import_variant_array(ffi_array, &ffi_schema) }.unwrap();
+        assert_eq!(imported.value(0), Variant::from(true));
+        // The imported array references the exported buffers
+        let imported_ptr = imported.value_field().to_data().buffers()[1].as_ptr();
+        assert_eq!(imported_ptr, value_ptr);
+    }
+
+    #[test]
+    fn import_other_extension() {
+        let mut builder = VariantArrayBuilder::new(1);
+        builder.append_variant(Variant::from(1i8));
+        let array = builder.build();
+
+        let field = Field::new("v", array.data_type().clone(), true).with_metadata(
+            [(
+                arrow_schema::extension::EXTENSION_TYPE_NAME_KEY.to_owned(),
+                "other".to_owned(),
+            )]
+            .into_iter()
+            .collect(),
+        );
+        let ffi_schema = FFI_ArrowSchema::try_from(&field).unwrap();
+        let ffi_array = FFI_ArrowArray::new(&array.to_data());
+        let err = unsafe { import_variant_array(ffi_array, &ffi_schema) }.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Expected extension type arrow.parquet.variant, found other"
+        );
+    }
+}
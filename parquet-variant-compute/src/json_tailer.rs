@@ -0,0 +1,421 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`JsonLinesTailer`] for ingesting an append-only stream of JSON Lines into
+//! batches of Variants
+
+use crate::{VariantArray, VariantArrayBuilder};
+use arrow_schema::ArrowError;
+use parquet_variant::{VariantBuilder, VariantMetadata};
+use parquet_variant_json::json_to_variant;
+use std::collections::HashSet;
+use std::io::{BufRead, Seek, SeekFrom};
+use std::time::{Duration, Instant};
+
+/// The default number of rows per batch of a [`JsonLinesTailer`]
+const DEFAULT_BATCH_SIZE: usize = 1024;
+
+/// The position a [`JsonLinesTailer`] can be resumed from
+///
+/// This consists of the byte offset of the first line not yet emitted in a batch,
+/// and the field names of the metadata dictionary at that point. Seeding a resumed
+/// tailer with the same field names ensures records are encoded with the same
+/// metadata as before the restart.
+///
+/// Use [`Self::to_bytes`] and [`Self::try_from_bytes`] to persist a checkpoint.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TailerCheckpoint {
+    offset: u64,
+    field_names: Vec<String>,
+}
+
+impl TailerCheckpoint {
+    /// Create a new [`TailerCheckpoint`]
+    pub fn new(offset: u64, field_names: Vec<String>) -> Self {
+        Self {
+            offset,
+            field_names,
+        }
+    }
+
+    /// The byte offset of the first line not yet emitted in a batch
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// The field names of the metadata dictionary, in insertion order
+    pub fn field_names(&self) -> &[String] {
+        &self.field_names
+    }
+
+    /// Serialize this checkpoint
+    ///
+    /// The encoding is the little-endian `u64` offset and `u32` number of field names,
+    /// followed by each field name as a little-endian `u32` length and UTF-8 bytes
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let names_len: usize = self.field_names.iter().map(|n| 4 + n.len()).sum();
+        let mut out = Vec::with_capacity(12 + names_len);
+        out.extend_from_slice(&self.offset.to_le_bytes());
+        out.extend_from_slice(&(self.field_names.len() as u32).to_le_bytes());
+        for name in &self.field_names {
+            out.extend_from_slice(&(name.len() as u32).to_le_bytes());
+            out.extend_from_slice(name.as_bytes());
+        }
+        out
+    }
+
+    /// Deserialize a checkpoint serialized with [`Self::to_bytes`]
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<Self, ArrowError> {
+        let mut remaining = bytes;
+        let mut take = |n: usize| match remaining.split_at_checked(n) {
+            Some((head, tail)) => {
+                remaining = tail;
+                Ok(head)
+            }
+            None => Err(ArrowError::ParseError(format!(
+                "Truncated tailer checkpoint of {} bytes",
+                bytes.len()
+            ))),
+        };
+
+        let offset = u64::from_le_bytes(take(8)?.try_into().unwrap());
+        let num_names = u32::from_le_bytes(take(4)?.try_into().unwrap());
+        let field_names = (0..num_names)
+            .map(|_| {
+                let len = u32::from_le_bytes(take(4)?.try_into().unwrap());
+                let name = std::str::from_utf8(take(len as usize)?).map_err(|e| {
+                    ArrowError::ParseError(format!("Invalid field name in tailer checkpoint: {e}"))
+                })?;
+                Ok(name.to_string())
+            })
+            .collect::<Result<_, ArrowError>>()?;
+
+        Ok(Self {
+            offset,
+            field_names,
+        })
+    }
+}
+
+/// Tails an append-only stream of newline delimited JSON, encoding each record as a
+/// [`Variant`] and emitting them in batches as [`VariantArray`]s
+///
+/// Each call to [`Self::poll`] reads the complete lines currently available from the
+/// underlying reader, and returns a batch once [`Self::with_batch_size`] rows have
+/// accumulated, or once the oldest pending row is older than
+/// [`Self::with_max_batch_delay`]. A trailing line without a newline is assumed to
+/// still be being written, and is buffered until it is complete. Blank lines are skipped.
+///
+/// All records are encoded with a shared metadata dictionary of the field names seen
+/// so far, so records with the same fields have identical metadata. Combined with
+/// [`Self::with_dictionary_metadata`] this stores the metadata once per batch.
+///
+/// After each batch is emitted, [`Self::checkpoint`] returns a [`TailerCheckpoint`]
+/// from which a new tailer can be [resumed](Self::resume), for example after a restart.
+///
+/// # Example
+/// ```
+/// # use std::io::Cursor;
+/// # use arrow::array::Array;
+/// # use parquet_variant::Variant;
+/// # use parquet_variant_compute::JsonLinesTailer;
+/// let log = Cursor::new(b"{\"level\": \"info\"}\n{\"level\": \"warn\"}\n{\"lev".to_vec());
+/// let mut tailer = JsonLinesTailer::new(log).with_batch_size(2);
+///
+/// let batch = tailer.poll().unwrap().unwrap();
+/// assert_eq!(batch.len(), 2);
+/// let level = batch.value(1).get_object_field("level").unwrap();
+/// assert_eq!(level, Variant::from("warn"));
+///
+/// // The incomplete final line is not consumed
+/// assert!(tailer.poll().unwrap().is_none());
+/// assert_eq!(tailer.checkpoint().offset(), 36);
+/// ```
+///
+/// [`Variant`]: parquet_variant::Variant
+#[derive(Debug)]
+pub struct JsonLinesTailer<R> {
+    reader: R,
+    batch_size: usize,
+    max_batch_delay: Option<Duration>,
+    dictionary_metadata: bool,
+    /// The field names of the shared metadata dictionary, in insertion order
+    field_names: Vec<String>,
+    known_names: HashSet<String>,
+    /// The bytes of a line that has not yet been terminated by a newline
+    partial: Vec<u8>,
+    /// The byte offset of the end of the last complete line read
+    offset: u64,
+    /// The rows read but not yet emitted
+    pending: Option<VariantArrayBuilder>,
+    pending_rows: usize,
+    /// When the first pending row was read
+    pending_since: Option<Instant>,
+    checkpoint: TailerCheckpoint,
+}
+
+impl<R: BufRead> JsonLinesTailer<R> {
+    /// Create a new [`JsonLinesTailer`] reading from the current position of `reader`
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            batch_size: DEFAULT_BATCH_SIZE,
+            max_batch_delay: None,
+            dictionary_metadata: false,
+            field_names: Vec::new(),
+            known_names: HashSet::new(),
+            partial: Vec::new(),
+            offset: 0,
+            pending: None,
+            pending_rows: 0,
+            pending_since: None,
+            checkpoint: TailerCheckpoint::default(),
+        }
+    }
+
+    /// Set the maximum number of rows per batch (defaults to 1024)
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Emit a partial batch from [`Self::poll`] once its oldest row has been pending
+    /// for at least `max_batch_delay` (defaults to waiting for a full batch)
+    pub fn with_max_batch_delay(mut self, max_batch_delay: Duration) -> Self {
+        self.max_batch_delay = Some(max_batch_delay);
+        self
+    }
+
+    /// Store the metadata of each batch as a dictionary (defaults to `false`), see
+    /// [`VariantArrayBuilder::with_dictionary_metadata`]
+    pub fn with_dictionary_metadata(mut self, dictionary_metadata: bool) -> Self {
+        self.dictionary_metadata = dictionary_metadata;
+        self
+    }
+
+    /// Returns the checkpoint as of the last batch emitted
+    pub fn checkpoint(&self) -> &TailerCheckpoint {
+        &self.checkpoint
+    }
+
+    /// Gets a reference to the underlying reader
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Gets a mutable reference to the underlying reader
+    ///
+    /// It is inadvisable to directly read from the underlying reader
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+
+    /// Read the complete lines currently available, returning a batch if one is ready
+    ///
+    /// Returns `Ok(None)` if there is not yet enough data for a batch, in which case the
+    /// caller should wait for more data to be appended before polling again.
+    ///
+    /// A line that is not valid JSON results in an error. The line is consumed, and
+    /// subsequent calls continue from the following line.
+    pub fn poll(&mut self) -> Result<Option<VariantArray>, ArrowError> {
+        while self.pending_rows < self.batch_size {
+            let read = self.reader.read_until(b'\n', &mut self.partial)?;
+            if read == 0 || self.partial.last() != Some(&b'\n') {
+                break;
+            }
+
+            let line = std::mem::take(&mut self.partial);
+            let line_offset = self.offset;
+            self.offset += line.len() as u64;
+            self.append_line(&line, line_offset)?;
+        }
+
+        let ready = self.pending_rows >= self.batch_size
+            || match (self.max_batch_delay, self.pending_since) {
+                (Some(delay), Some(since)) => since.elapsed() >= delay,
+                _ => false,
+            };
+        Ok(ready.then(|| self.flush()).flatten())
+    }
+
+    /// Emit any pending rows as a batch, regardless of the batch size and delay
+    ///
+    /// Returns `None` if there are no pending rows
+    pub fn flush(&mut self) -> Option<VariantArray> {
+        let builder = self.pending.take()?;
+        self.pending_rows = 0;
+        self.pending_since = None;
+        self.checkpoint = TailerCheckpoint::new(self.offset, self.field_names.clone());
+        Some(builder.build())
+    }
+
+    fn append_line(&mut self, line: &[u8], line_offset: u64) -> Result<(), ArrowError> {
+        let line = std::str::from_utf8(line).map_err(|e| {
+            ArrowError::ParseError(format!("Invalid UTF-8 at byte offset {line_offset}: {e}"))
+        })?;
+        let line = line.trim();
+        if line.is_empty() {
+            return Ok(());
+        }
+
+        let mut variant_builder =
+            VariantBuilder::new().with_field_names(self.field_names.iter().map(|s| s.as_str()));
+        json_to_variant(line, &mut variant_builder).map_err(|e| {
+            ArrowError::ParseError(format!("Invalid JSON at byte offset {line_offset}: {e}"))
+        })?;
+        let (metadata, value) = variant_builder.finish();
+
+        // Record any field names first seen in this record
+        let dictionary = VariantMetadata::try_new(&metadata)?;
+        if dictionary.len() > self.field_names.len() {
+            for name in dictionary.iter().skip(self.field_names.len()) {
+                if self.known_names.insert(name.to_string()) {
+                    self.field_names.push(name.to_string());
+                }
+            }
+        }
+
+        let (batch_size, dictionary_metadata) = (self.batch_size, self.dictionary_metadata);
+        let builder = self.pending.get_or_insert_with(|| {
+            VariantArrayBuilder::new(batch_size).with_dictionary_metadata(dictionary_metadata)
+        });
+        builder.append_variant_buffers(&metadata, &value);
+        self.pending_rows += 1;
+        self.pending_since.get_or_insert_with(Instant::now);
+        Ok(())
+    }
+}
+
+impl<R: BufRead + Seek> JsonLinesTailer<R> {
+    /// Create a new [`JsonLinesTailer`] resuming from `checkpoint`
+    ///
+    /// `reader` is positioned at [`TailerCheckpoint::offset`], and the metadata
+    /// dictionary is seeded with [`TailerCheckpoint::field_names`]
+    pub fn resume(mut reader: R, checkpoint: TailerCheckpoint) -> Result<Self, ArrowError> {
+        reader.seek(SeekFrom::Start(checkpoint.offset))?;
+        let mut tailer = Self::new(reader);
+        tailer.offset = checkpoint.offset;
+        tailer.field_names = checkpoint.field_names.clone();
+        tailer.known_names = checkpoint.field_names.iter().cloned().collect();
+        tailer.checkpoint = checkpoint;
+        Ok(tailer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::Array;
+    use parquet_variant::Variant;
+    use std::io::{Cursor, Write};
+
+    #[test]
+    fn test_tail() {
+        let mut tailer = JsonLinesTailer::new(Cursor::new(Vec::new())).with_batch_size(2);
+        assert!(tailer.poll().unwrap().is_none());
+
+        let append = |tailer: &mut JsonLinesTailer<Cursor<Vec<u8>>>, data: &[u8]| {
+            tailer.get_mut().get_mut().write_all(data).unwrap();
+        };
+
+        append(&mut tailer, b"{\"a\": 1}\n\n{\"b\"");
+        assert!(tailer.poll().unwrap().is_none());
+        assert_eq!(tailer.checkpoint(), &TailerCheckpoint::default());
+
+        append(&mut tailer, b": 2}\n{\"a\": 3, \"c\": null}\n");
+        let batch = tailer.poll().unwrap().unwrap();
+        assert_eq!(batch.len(), 2);
+        assert_eq!(
+            batch.value(0).get_object_field("a"),
+            Some(Variant::from(1_i8))
+        );
+        assert_eq!(
+            batch.value(1).get_object_field("b"),
+            Some(Variant::from(2_i8))
+        );
+        assert_eq!(tailer.checkpoint().offset(), 19);
+        assert_eq!(tailer.checkpoint().field_names(), &["a", "b"]);
+
+        // The third record is pending until flushed
+        assert!(tailer.poll().unwrap().is_none());
+        let batch = tailer.flush().unwrap();
+        assert_eq!(batch.len(), 1);
+        assert_eq!(tailer.checkpoint().offset(), 39);
+        assert_eq!(tailer.checkpoint().field_names(), &["a", "b", "c"]);
+        assert!(tailer.flush().is_none());
+    }
+
+    #[test]
+    fn test_resume() {
+        let data = b"{\"x\": 1}\n{\"y\": 2}\n{\"x\": 3}\n{\"y\": 4}\n".to_vec();
+        let mut tailer = JsonLinesTailer::new(Cursor::new(data.clone())).with_batch_size(2);
+        let first = tailer.poll().unwrap().unwrap();
+        assert_eq!(first.len(), 2);
+
+        let checkpoint = tailer.checkpoint().clone();
+        let checkpoint = TailerCheckpoint::try_from_bytes(&checkpoint.to_bytes()).unwrap();
+        assert_eq!(&checkpoint, tailer.checkpoint());
+
+        let mut resumed = JsonLinesTailer::resume(Cursor::new(data), checkpoint)
+            .unwrap()
+            .with_batch_size(2);
+        let expected = tailer.poll().unwrap().unwrap();
+        let actual = resumed.poll().unwrap().unwrap();
+        for i in 0..2 {
+            assert_eq!(actual.value(i), expected.value(i));
+            // Encoded with the same metadata dictionary as before the restart
+            assert_eq!(actual.metadata_field(), expected.metadata_field());
+        }
+        assert_eq!(resumed.checkpoint(), tailer.checkpoint());
+    }
+
+    #[test]
+    fn test_max_batch_delay() {
+        let data = b"1\n2\n".to_vec();
+        let mut tailer = JsonLinesTailer::new(Cursor::new(data))
+            .with_dictionary_metadata(true)
+            .with_max_batch_delay(Duration::ZERO);
+        let batch = tailer.poll().unwrap().unwrap();
+        assert_eq!(batch.len(), 2);
+        assert_eq!(batch.value(1), Variant::from(2_i8));
+    }
+
+    #[test]
+    fn test_invalid_json() {
+        let data = b"{\"a\": 1}\n{invalid\n{\"a\": 2}\n".to_vec();
+        let mut tailer = JsonLinesTailer::new(Cursor::new(data)).with_batch_size(2);
+        let err = tailer.poll().unwrap_err();
+        assert!(
+            err.to_string()
+                .starts_with("Parser error: Invalid JSON at byte offset 9"),
+            "{err}"
+        );
+
+        let batch = tailer.poll().unwrap().unwrap();
+        assert_eq!(batch.len(), 2);
+        assert_eq!(tailer.checkpoint().offset(), 27);
+    }
+
+    #[test]
+    fn test_truncated_checkpoint() {
+        let err = TailerCheckpoint::try_from_bytes(&[0; 10]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Truncated tailer checkpoint of 10 bytes"
+        );
+    }
+}
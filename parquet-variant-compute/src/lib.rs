@@ -15,13 +15,25 @@
 // specific language governing permissions and limitations
 // under the License.
 
+mod cardinality;
+#[cfg(feature = "ffi")]
+pub mod ffi;
 mod from_json;
+mod json_tailer;
 mod to_json;
 mod variant_array;
 mod variant_array_builder;
+mod variant_explode;
+mod variant_path_program;
+mod variant_type;
 
+pub use cardinality::VariantPathCardinality;
+pub use json_tailer::{JsonLinesTailer, TailerCheckpoint};
 pub use variant_array::VariantArray;
 pub use variant_array_builder::VariantArrayBuilder;
+pub use variant_explode::variant_explode;
+pub use variant_path_program::VariantPathProgram;
+pub use variant_type::VariantType;
 
 pub use from_json::batch_json_string_to_variant;
 pub use to_json::batch_variant_to_json_string;
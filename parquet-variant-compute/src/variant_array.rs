@@ -17,9 +17,10 @@
 
 //! [`VariantArray`] implementation
 
-use arrow::array::{Array, ArrayData, ArrayRef, AsArray, StructArray};
+use crate::VariantType;
+use arrow::array::{downcast_dictionary_array, Array, ArrayData, ArrayRef, AsArray, StructArray};
 use arrow::buffer::NullBuffer;
-use arrow_schema::{ArrowError, DataType};
+use arrow_schema::{ArrowError, DataType, Field, Fields};
 use parquet_variant::Variant;
 use std::any::Any;
 use std::sync::Arc;
@@ -77,38 +78,28 @@ impl VariantArray {
     /// This structure does not (yet) support the full Arrow Variant Array specification.
     ///
     /// Only `StructArrays` with `metadata` and `value` fields that are
-    /// [`BinaryViewArray`] are supported. Shredded values are not currently supported
-    /// nor are using types other than `BinaryViewArray`
+    /// `Binary`, `LargeBinary` or `BinaryView` are supported, such as those
+    /// produced by PySpark, which are used without copying. The `metadata` field
+    /// may additionally be a [`DictionaryArray`] with any integer key type and
+    /// `Binary` or `BinaryView` values. Shredded values are not currently supported.
     ///
-    /// [`BinaryViewArray`]: arrow::array::BinaryViewArray
+    /// [`DictionaryArray`]: arrow::array::DictionaryArray
     pub fn try_new(inner: ArrayRef) -> Result<Self, ArrowError> {
         let Some(inner) = inner.as_struct_opt() else {
             return Err(ArrowError::InvalidArgumentError(
                 "Invalid VariantArray: requires StructArray as input".to_string(),
             ));
         };
-        // Ensure the StructArray has a metadata field of BinaryView
-        let Some(metadata_field) = inner.fields().iter().find(|f| f.name() == "metadata") else {
+        validate_fields(inner.fields())?;
+        if inner
+            .column_by_name("metadata")
+            .unwrap()
+            .logical_null_count()
+            != 0
+        {
             return Err(ArrowError::InvalidArgumentError(
-                "Invalid VariantArray: StructArray must contain a 'metadata' field".to_string(),
+                "Invalid VariantArray: 'metadata' field must not contain nulls".to_string(),
             ));
-        };
-        if metadata_field.data_type() != &DataType::BinaryView {
-            return Err(ArrowError::NotYetImplemented(format!(
-                "VariantArray 'metadata' field must be BinaryView, got {}",
-                metadata_field.data_type()
-            )));
-        }
-        let Some(value_field) = inner.fields().iter().find(|f| f.name() == "value") else {
-            return Err(ArrowError::InvalidArgumentError(
-                "Invalid VariantArray: StructArray must contain a 'value' field".to_string(),
-            ));
-        };
-        if value_field.data_type() != &DataType::BinaryView {
-            return Err(ArrowError::NotYetImplemented(format!(
-                "VariantArray 'value' field must be BinaryView, got {}",
-                value_field.data_type()
-            )));
         }
 
         Ok(Self {
@@ -133,11 +124,39 @@ impl VariantArray {
     /// Note: Does not do deep validation of the [`Variant`], so it is up to the
     /// caller to ensure that the metadata and value were constructed correctly.
     pub fn value(&self, index: usize) -> Variant {
-        let metadata = self.metadata_field().as_binary_view().value(index);
-        let value = self.value_field().as_binary_view().value(index);
+        let metadata = self.metadata(index);
+        let value = binary_value(self.value_field(), index);
         Variant::new(metadata, value)
     }
 
+    /// Return the raw metadata bytes stored at the given row
+    ///
+    /// This transparently resolves dictionary-encoded metadata, see
+    /// [`Self::is_metadata_dictionary_encoded`]
+    ///
+    /// Panics if the index is out of bounds.
+    pub fn metadata(&self, index: usize) -> &[u8] {
+        let metadata = self.metadata_field();
+        downcast_dictionary_array!(
+            metadata => {
+                let key = metadata.key(index).expect("metadata validated non-null");
+                binary_value(metadata.values(), key)
+            },
+            _ => binary_value(metadata, index)
+        )
+    }
+
+    /// Returns true if the metadata field is a [`DictionaryArray`], in which
+    /// case rows that share identical metadata reference a single copy of it
+    ///
+    /// [`DictionaryArray`]: arrow::array::DictionaryArray
+    pub fn is_metadata_dictionary_encoded(&self) -> bool {
+        matches!(
+            self.metadata_field().data_type(),
+            DataType::Dictionary(_, _)
+        )
+    }
+
     /// Return a reference to the metadata field of the [`StructArray`]
     pub fn metadata_field(&self) -> &ArrayRef {
         // spec says fields order is not guaranteed, so we search by name
@@ -149,6 +168,67 @@ impl VariantArray {
         // spec says fields order is not guaranteed, so we search by name
         self.inner.column_by_name("value").unwrap()
     }
+
+    /// Returns a nullable [`Field`] named `name` for this array, annotated
+    /// with the [`VariantType`] extension type
+    ///
+    /// The extension metadata on this field is preserved when exporting via
+    /// the C Data Interface, allowing other Arrow implementations to recognize
+    /// the column as a variant.
+    pub fn field(&self, name: impl Into<String>) -> Field {
+        Field::new(name, self.data_type().clone(), true).with_extension_type(VariantType)
+    }
+}
+
+/// Returns true if `data_type` can store the `metadata` or `value` of a [`VariantArray`]
+fn is_binary(data_type: &DataType) -> bool {
+    matches!(
+        data_type,
+        DataType::Binary | DataType::LargeBinary | DataType::BinaryView
+    )
+}
+
+/// Validates that `fields` are those of a [`VariantArray`]
+pub(crate) fn validate_fields(fields: &Fields) -> Result<(), ArrowError> {
+    let Some(metadata_field) = fields.iter().find(|f| f.name() == "metadata") else {
+        return Err(ArrowError::InvalidArgumentError(
+            "Invalid VariantArray: StructArray must contain a 'metadata' field".to_string(),
+        ));
+    };
+    let metadata_supported = match metadata_field.data_type() {
+        DataType::Dictionary(key, value) => {
+            key.is_dictionary_key_type()
+                && matches!(value.as_ref(), DataType::Binary | DataType::BinaryView)
+        }
+        data_type => is_binary(data_type),
+    };
+    if !metadata_supported {
+        return Err(ArrowError::NotYetImplemented(format!(
+            "VariantArray 'metadata' field must be Binary, LargeBinary, BinaryView or a Dictionary of Binary or BinaryView, got {}",
+            metadata_field.data_type()
+        )));
+    }
+    let Some(value_field) = fields.iter().find(|f| f.name() == "value") else {
+        return Err(ArrowError::InvalidArgumentError(
+            "Invalid VariantArray: StructArray must contain a 'value' field".to_string(),
+        ));
+    };
+    if !is_binary(value_field.data_type()) {
+        return Err(ArrowError::NotYetImplemented(format!(
+            "VariantArray 'value' field must be Binary, LargeBinary or BinaryView, got {}",
+            value_field.data_type()
+        )));
+    }
+    Ok(())
+}
+
+/// Returns the value at `index` of a `Binary`, `LargeBinary` or `BinaryView` array
+fn binary_value(array: &ArrayRef, index: usize) -> &[u8] {
+    match array.data_type() {
+        DataType::Binary => array.as_binary::<i32>().value(index),
+        DataType::LargeBinary => array.as_binary::<i64>().value(index),
+        _ => array.as_binary_view().value(index),
+    }
 }
 
 impl Array for VariantArray {
@@ -202,8 +282,12 @@ impl Array for VariantArray {
 #[cfg(test)]
 mod test {
     use super::*;
-    use arrow::array::{BinaryArray, BinaryViewArray};
-    use arrow_schema::{Field, Fields};
+    use crate::VariantArrayBuilder;
+    use arrow::array::{
+        BinaryArray, BinaryViewArray, DictionaryArray, Int8Array, LargeBinaryArray, StringArray,
+    };
+    use arrow::datatypes::Int8Type;
+    use parquet_variant::VariantBuilder;
 
     #[test]
     fn invalid_not_a_struct_array() {
@@ -243,18 +327,93 @@ mod test {
     #[test]
     fn invalid_metadata_field_type() {
         let fields = Fields::from(vec![
-            Field::new("metadata", DataType::Binary, true), // Not yet supported
+            Field::new("metadata", DataType::Utf8, true), // Not yet supported
+            Field::new("value", DataType::BinaryView, true),
+        ]);
+        let array = StructArray::new(
+            fields,
+            vec![make_string_array(), make_binary_view_array()],
+            None,
+        );
+        let err = VariantArray::try_new(Arc::new(array));
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            "Not yet implemented: VariantArray 'metadata' field must be Binary, LargeBinary, BinaryView or a Dictionary of Binary or BinaryView, got Utf8"
+        );
+    }
+
+    #[test]
+    fn invalid_metadata_dictionary_value_type() {
+        let metadata: DictionaryArray<Int8Type> = vec!["a"].into_iter().collect();
+        let fields = Fields::from(vec![
+            Field::new("metadata", metadata.data_type().clone(), false),
             Field::new("value", DataType::BinaryView, true),
         ]);
         let array = StructArray::new(
             fields,
-            vec![make_binary_array(), make_binary_view_array()],
+            vec![Arc::new(metadata), make_binary_view_array()],
             None,
         );
         let err = VariantArray::try_new(Arc::new(array));
         assert_eq!(
             err.unwrap_err().to_string(),
-            "Not yet implemented: VariantArray 'metadata' field must be BinaryView, got Binary"
+            "Not yet implemented: VariantArray 'metadata' field must be Binary, LargeBinary, BinaryView or a Dictionary of Binary or BinaryView, got Dictionary(Int8, Utf8)"
+        );
+    }
+
+    #[test]
+    fn dictionary_metadata() {
+        let (metadata, value) = {
+            let mut vb = VariantBuilder::new();
+            let mut obj = vb.new_object();
+            obj.insert("foo", 1i32);
+            obj.finish().unwrap();
+            vb.finish()
+        };
+        let keys = Int8Array::from(vec![0, 0, 0]);
+        let values = BinaryArray::from(vec![metadata.as_slice()]);
+        let metadata_array = DictionaryArray::new(keys, Arc::new(values));
+        let value_array = BinaryViewArray::from(vec![value.as_slice(); 3]);
+
+        let fields = Fields::from(vec![
+            Field::new("metadata", metadata_array.data_type().clone(), false),
+            Field::new("value", DataType::BinaryView, false),
+        ]);
+        let array = StructArray::new(
+            fields,
+            vec![Arc::new(metadata_array), Arc::new(value_array)],
+            None,
+        );
+        let variant_array = VariantArray::try_new(Arc::new(array)).unwrap();
+        assert!(variant_array.is_metadata_dictionary_encoded());
+        for i in 0..3 {
+            assert_eq!(variant_array.metadata(i), metadata.as_slice());
+            let v = variant_array.value(i);
+            assert_eq!(v.get_object_field("foo"), Some(Variant::from(1i32)));
+        }
+    }
+
+    #[test]
+    fn invalid_null_metadata() {
+        let keys = Int8Array::from(vec![Some(0), None]);
+        let values = BinaryArray::from(vec![b"test" as &[u8]]);
+        let metadata_array = DictionaryArray::new(keys, Arc::new(values));
+        let fields = Fields::from(vec![
+            Field::new("metadata", metadata_array.data_type().clone(), true),
+            Field::new("value", DataType::BinaryView, true),
+        ]);
+        let array = StructArray::new(
+            fields,
+            vec![
+                Arc::new(metadata_array),
+                Arc::new(BinaryViewArray::from(vec![b"test" as &[u8]; 2])),
+            ],
+            None,
+        );
+        let err = VariantArray::try_new(Arc::new(array));
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            "Invalid argument error: Invalid VariantArray: 'metadata' field must not contain nulls"
         );
     }
 
@@ -262,25 +421,59 @@ mod test {
     fn invalid_value_field_type() {
         let fields = Fields::from(vec![
             Field::new("metadata", DataType::BinaryView, true),
-            Field::new("value", DataType::Binary, true), // Not yet supported
+            Field::new("value", DataType::Utf8, true), // Not yet supported
         ]);
         let array = StructArray::new(
             fields,
-            vec![make_binary_view_array(), make_binary_array()],
+            vec![make_binary_view_array(), make_string_array()],
             None,
         );
         let err = VariantArray::try_new(Arc::new(array));
         assert_eq!(
             err.unwrap_err().to_string(),
-            "Not yet implemented: VariantArray 'value' field must be BinaryView, got Binary"
+            "Not yet implemented: VariantArray 'value' field must be Binary, LargeBinary or BinaryView, got Utf8"
+        );
+    }
+
+    #[test]
+    fn variant_field() {
+        let mut builder = VariantArrayBuilder::new(1);
+        builder.append_variant(Variant::from(1i32));
+        let array = builder.build();
+        let field = array.field("v");
+        assert_eq!(field.name(), "v");
+        assert_eq!(field.data_type(), array.data_type());
+        field.extension_type::<VariantType>();
+    }
+
+    #[test]
+    fn binary_fields() {
+        let (metadata, value) = {
+            let mut vb = VariantBuilder::new();
+            vb.append_value("hello");
+            vb.finish()
+        };
+        let fields = Fields::from(vec![
+            Field::new("metadata", DataType::Binary, false),
+            Field::new("value", DataType::LargeBinary, false),
+        ]);
+        let array = StructArray::new(
+            fields,
+            vec![
+                Arc::new(BinaryArray::from(vec![metadata.as_slice()])),
+                Arc::new(LargeBinaryArray::from(vec![value.as_slice()])),
+            ],
+            None,
         );
+        let variant_array = VariantArray::try_new(Arc::new(array)).unwrap();
+        assert_eq!(variant_array.value(0), Variant::from("hello"));
     }
 
     fn make_binary_view_array() -> ArrayRef {
         Arc::new(BinaryViewArray::from(vec![b"test" as &[u8]]))
     }
 
-    fn make_binary_array() -> ArrayRef {
-        Arc::new(BinaryArray::from(vec![b"test" as &[u8]]))
+    fn make_string_array() -> ArrayRef {
+        Arc::new(StringArray::from(vec!["test"]))
     }
 }
@@ -17,8 +17,12 @@
 
 //! [`VariantArrayBuilder`] implementation
 
-use crate::VariantArray;
-use arrow::array::{ArrayRef, BinaryViewArray, BinaryViewBuilder, NullBufferBuilder, StructArray};
+use crate::{VariantArray, VariantPathCardinality};
+use arrow::array::{
+    ArrayBuilder, ArrayRef, BinaryDictionaryBuilder, BinaryViewArray, BinaryViewBuilder,
+    NullBufferBuilder, StructArray,
+};
+use arrow::datatypes::Int32Type;
 use arrow_schema::{DataType, Field, Fields};
 use parquet_variant::{Variant, VariantBuilder};
 use std::sync::Arc;
@@ -28,8 +32,12 @@ use std::sync::Arc;
 /// This builder is used to construct a `VariantArray` and allows APIs for
 /// adding metadata
 ///
-/// This builder always creates a `VariantArray` using [`BinaryViewArray`] for both
-/// the metadata and value fields.
+/// By default this builder creates a `VariantArray` using [`BinaryViewArray`] for both
+/// the metadata and value fields. Use [`Self::with_dictionary_metadata`] to instead
+/// store the metadata as a [`DictionaryArray`] so that identical metadata buffers,
+/// which are common for homogeneous event streams, are only stored once per batch.
+///
+/// [`DictionaryArray`]: arrow::array::DictionaryArray
 ///
 /// # TODO
 /// 1. Support shredding: <https://github.com/apache/arrow-rs/issues/7895>
@@ -72,10 +80,8 @@ use std::sync::Arc;
 pub struct VariantArrayBuilder {
     /// Nulls
     nulls: NullBufferBuilder,
-    /// buffer for all the metadata
-    metadata_buffer: Vec<u8>,
-    /// (offset, len) pairs for locations of metadata in the buffer
-    metadata_locations: Vec<(usize, usize)>,
+    /// storage for all the metadata
+    metadata: MetadataStorage,
     /// buffer for values
     value_buffer: Vec<u8>,
     /// (offset, len) pairs for locations of values in the buffer
@@ -85,6 +91,8 @@ pub struct VariantArrayBuilder {
     /// TODO: 1) Add extension type metadata
     /// TODO: 2) Add support for shredding
     fields: Fields,
+    /// Distinct count estimates of the values appended, if enabled
+    path_cardinality: Option<VariantPathCardinality>,
 }
 
 impl VariantArrayBuilder {
@@ -95,51 +103,111 @@ impl VariantArrayBuilder {
 
         Self {
             nulls: NullBufferBuilder::new(row_capacity),
-            metadata_buffer: Vec::new(), // todo allocation capacity
-            metadata_locations: Vec::with_capacity(row_capacity),
+            metadata: MetadataStorage::Views {
+                buffer: Vec::new(), // todo allocation capacity
+                locations: Vec::with_capacity(row_capacity),
+            },
             value_buffer: Vec::new(),
             value_locations: Vec::with_capacity(row_capacity),
             fields: Fields::from(vec![metadata_field, value_field]),
+            path_cardinality: None,
         }
     }
 
+    /// Store the metadata field as a dictionary of `Int32` keys and `Binary` values,
+    /// deduplicating identical metadata buffers
+    ///
+    /// Rows are still accessed transparently via [`VariantArray::value`]
+    ///
+    /// # Panics
+    ///
+    /// Panics if any rows have already been appended
+    pub fn with_dictionary_metadata(mut self, dictionary_metadata: bool) -> Self {
+        assert!(
+            self.value_locations.is_empty(),
+            "with_dictionary_metadata must be called before appending rows"
+        );
+        let row_capacity = self.value_locations.capacity();
+        let (metadata, data_type) = match dictionary_metadata {
+            true => (
+                MetadataStorage::Dictionary(Box::new(BinaryDictionaryBuilder::with_capacity(
+                    row_capacity,
+                    0,
+                    0,
+                ))),
+                DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Binary)),
+            ),
+            false => (
+                MetadataStorage::Views {
+                    buffer: Vec::new(),
+                    locations: Vec::with_capacity(row_capacity),
+                },
+                DataType::BinaryView,
+            ),
+        };
+        self.metadata = metadata;
+        self.fields = Fields::from(vec![
+            Field::new("metadata", data_type, false),
+            self.fields[1].as_ref().clone(),
+        ]);
+        self
+    }
+
+    /// Estimate the number of distinct values at each path of the appended values
+    /// using `path_cardinality`, which can be retrieved with [`Self::path_cardinality`]
+    /// or [`Self::build_with_path_cardinality`]
+    ///
+    /// This avoids a separate pass over the [`VariantArray`] to compute the estimates
+    pub fn with_path_cardinality(mut self, path_cardinality: VariantPathCardinality) -> Self {
+        self.path_cardinality = Some(path_cardinality);
+        self
+    }
+
+    /// Returns the distinct count estimates of the values appended so far, if enabled
+    /// with [`Self::with_path_cardinality`]
+    pub fn path_cardinality(&self) -> Option<&VariantPathCardinality> {
+        self.path_cardinality.as_ref()
+    }
+
     /// Build the final builder
     pub fn build(self) -> VariantArray {
+        self.build_with_path_cardinality().0
+    }
+
+    /// Build the final builder, also returning the distinct count estimates of the
+    /// values appended, if enabled with [`Self::with_path_cardinality`]
+    pub fn build_with_path_cardinality(self) -> (VariantArray, Option<VariantPathCardinality>) {
         let Self {
             mut nulls,
-            metadata_buffer,
-            metadata_locations,
+            metadata,
             value_buffer,
             value_locations,
             fields,
+            path_cardinality,
         } = self;
 
-        let metadata_array = binary_view_array_from_buffers(metadata_buffer, metadata_locations);
+        let metadata_array = metadata.finish();
 
         let value_array = binary_view_array_from_buffers(value_buffer, value_locations);
 
         // The build the final struct array
         let inner = StructArray::new(
             fields,
-            vec![
-                Arc::new(metadata_array) as ArrayRef,
-                Arc::new(value_array) as ArrayRef,
-            ],
+            vec![metadata_array, Arc::new(value_array) as ArrayRef],
             nulls.finish(),
         );
         // TODO add arrow extension type metadata
 
-        VariantArray::try_new(Arc::new(inner)).expect("valid VariantArray by construction")
+        let array =
+            VariantArray::try_new(Arc::new(inner)).expect("valid VariantArray by construction");
+        (array, path_cardinality)
     }
 
     /// Appends a null row to the builder.
     pub fn append_null(&mut self) {
         self.nulls.append_null();
         // The subfields are expected to be non-nullable according to the parquet variant spec.
-        let metadata_offset = self.metadata_buffer.len();
-        let metadata_length = 0;
-        self.metadata_locations
-            .push((metadata_offset, metadata_length));
+        self.metadata.append(&[]);
         let value_offset = self.value_buffer.len();
         let value_length = 0;
         self.value_locations.push((value_offset, value_length));
@@ -155,13 +223,15 @@ impl VariantArrayBuilder {
     }
 
     /// Append a metadata and values buffer to the builder
+    ///
+    /// If [`Self::with_path_cardinality`] is enabled, the buffers are decoded, and
+    /// this may panic if they are not valid
     pub fn append_variant_buffers(&mut self, metadata: &[u8], value: &[u8]) {
+        if let Some(path_cardinality) = &mut self.path_cardinality {
+            path_cardinality.update_variant(&Variant::new(metadata, value));
+        }
         self.nulls.append_non_null();
-        let metadata_length = metadata.len();
-        let metadata_offset = self.metadata_buffer.len();
-        self.metadata_locations
-            .push((metadata_offset, metadata_length));
-        self.metadata_buffer.extend_from_slice(metadata);
+        self.metadata.append(metadata);
         let value_length = value.len();
         let value_offset = self.value_buffer.len();
         self.value_locations.push((value_offset, value_length));
@@ -171,6 +241,61 @@ impl VariantArrayBuilder {
     // TODO: Return a Variant builder that will write to the underlying buffers (TODO)
 }
 
+/// Storage for the metadata field of a [`VariantArrayBuilder`]
+enum MetadataStorage {
+    /// Each row's metadata is stored separately and finished as a [`BinaryViewArray`]
+    Views {
+        /// buffer for all the metadata
+        buffer: Vec<u8>,
+        /// (offset, len) pairs for locations of metadata in the buffer
+        locations: Vec<(usize, usize)>,
+    },
+    /// Distinct metadata is stored once and finished as a `DictionaryArray`
+    Dictionary(Box<BinaryDictionaryBuilder<Int32Type>>),
+}
+
+// `BinaryDictionaryBuilder` only implements `Debug` if `BinaryType` does
+impl std::fmt::Debug for MetadataStorage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Views { buffer, locations } => f
+                .debug_struct("Views")
+                .field("buffer", buffer)
+                .field("locations", locations)
+                .finish(),
+            Self::Dictionary(builder) => f
+                .debug_struct("Dictionary")
+                .field("len", &builder.len())
+                .finish(),
+        }
+    }
+}
+
+impl MetadataStorage {
+    fn append(&mut self, metadata: &[u8]) {
+        match self {
+            Self::Views { buffer, locations } => {
+                locations.push((buffer.len(), metadata.len()));
+                buffer.extend_from_slice(metadata);
+            }
+            Self::Dictionary(builder) => {
+                builder
+                    .append(metadata)
+                    .expect("metadata dictionary should not exceed i32::MAX entries");
+            }
+        }
+    }
+
+    fn finish(self) -> ArrayRef {
+        match self {
+            Self::Views { buffer, locations } => {
+                Arc::new(binary_view_array_from_buffers(buffer, locations))
+            }
+            Self::Dictionary(mut builder) => Arc::new(builder.finish()),
+        }
+    }
+}
+
 fn binary_view_array_from_buffers(
     buffer: Vec<u8>,
     locations: Vec<(usize, usize)>,
@@ -191,7 +316,7 @@ fn binary_view_array_from_buffers(
 #[cfg(test)]
 mod test {
     use super::*;
-    use arrow::array::Array;
+    use arrow::array::{Array, AsArray};
 
     /// Test that both the metadata and value buffers are non nullable
     #[test]
@@ -220,4 +345,65 @@ mod test {
             );
         }
     }
+
+    #[test]
+    fn test_variant_array_builder_dictionary_metadata() {
+        let mut builder = VariantArrayBuilder::new(10).with_dictionary_metadata(true);
+        let (metadata, value) = {
+            let mut vb = VariantBuilder::new();
+            let mut obj = vb.new_object();
+            obj.insert("a", 1i32);
+            obj.finish().unwrap();
+            vb.finish()
+        };
+        for _ in 0..4 {
+            builder.append_variant_buffers(&metadata, &value);
+        }
+        builder.append_null();
+        builder.append_variant(Variant::from("hello"));
+        let variant_array = builder.build();
+
+        assert_eq!(variant_array.len(), 6);
+        assert!(variant_array.is_metadata_dictionary_encoded());
+        assert!(variant_array.metadata_field().nulls().is_none());
+
+        // identical metadata is only stored once
+        let dictionary = variant_array.metadata_field().as_any_dictionary();
+        assert_eq!(dictionary.values().len(), 3);
+
+        for i in 0..4 {
+            assert_eq!(variant_array.metadata(i), metadata.as_slice());
+            assert_eq!(variant_array.value(i), Variant::new(&metadata, &value));
+        }
+        assert!(variant_array.is_null(4));
+        assert_eq!(variant_array.value(5), Variant::from("hello"));
+    }
+
+    #[test]
+    fn test_variant_array_builder_path_cardinality() {
+        let mut builder =
+            VariantArrayBuilder::new(10).with_path_cardinality(VariantPathCardinality::new());
+        for i in 0..10 {
+            let mut vb = VariantBuilder::new();
+            let mut obj = vb.new_object();
+            obj.insert("a", i % 3);
+            obj.finish().unwrap();
+            let (metadata, value) = vb.finish();
+            builder.append_variant_buffers(&metadata, &value);
+        }
+        builder.append_null();
+        builder.append_variant(Variant::from(1));
+
+        let cardinality = builder.path_cardinality().unwrap().clone();
+        let (variant_array, built) = builder.build_with_path_cardinality();
+        assert_eq!(built.as_ref(), Some(&cardinality));
+        assert_eq!(cardinality.num_rows_sampled(), 11);
+        assert_eq!(cardinality.estimate("a"), Some(3));
+        assert_eq!(cardinality.estimate(""), Some(1));
+
+        // Equivalent to scanning the built array
+        let mut scanned = VariantPathCardinality::new();
+        scanned.update(&variant_array);
+        assert_eq!(scanned, cardinality);
+    }
 }
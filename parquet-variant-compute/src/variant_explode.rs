@@ -0,0 +1,214 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`variant_explode`] kernel to unnest the lists of a [`VariantArray`] into rows
+
+use crate::{VariantArray, VariantArrayBuilder};
+use arrow::array::{Array, UInt32Array};
+use arrow_schema::ArrowError;
+use parquet_variant::Variant;
+
+/// Unnest the list at `path` of each row of `array` into multiple output rows, as in
+/// `LATERAL VIEW explode(...)` or `CROSS JOIN UNNEST(...)` in SQL
+///
+/// `path` is a sequence of object field names separated by `.`, where a segment
+/// may also be a list index, for example `"payload.items"` or `"events.0.tags"`.
+/// The empty path refers to the top-level value of each row.
+///
+/// Returns the index of the input row of each output row, which can be used to
+/// [`take`] the other columns of the input batch, and a [`VariantArray`] of the
+/// list elements. The encoded elements are copied without being decoded, and share
+/// the metadata of their input row. Rows that are null, do not contain `path`, or where the value at
+/// `path` is not a list, produce no output rows. Elements that are [`Variant::Null`]
+/// are preserved.
+///
+/// Returns an error if `path` contains an empty segment.
+///
+/// ```
+/// # use parquet_variant::{Variant, VariantBuilder};
+/// # use parquet_variant_compute::{variant_explode, VariantArrayBuilder};
+/// # use arrow::array::{Array, UInt32Array};
+/// let mut builder = VariantArrayBuilder::new(3);
+/// for tags in [vec!["a", "b"], vec![], vec!["c"]] {
+///     let mut vb = VariantBuilder::new();
+///     let mut obj = vb.new_object();
+///     let mut list = obj.new_list("tags");
+///     for tag in tags {
+///         list.append_value(tag);
+///     }
+///     list.finish();
+///     obj.finish().unwrap();
+///     let (metadata, value) = vb.finish();
+///     builder.append_variant_buffers(&metadata, &value);
+/// }
+/// let array = builder.build();
+///
+/// let (indices, elements) = variant_explode(&array, "tags").unwrap();
+/// assert_eq!(indices, UInt32Array::from(vec![0, 0, 2]));
+/// assert_eq!(elements.len(), 3);
+/// assert_eq!(elements.value(1), Variant::from("b"));
+/// assert_eq!(elements.value(2), Variant::from("c"));
+/// ```
+///
+/// [`take`]: arrow::compute::take
+pub fn variant_explode(
+    array: &VariantArray,
+    path: &str,
+) -> Result<(UInt32Array, VariantArray), ArrowError> {
+    let segments = match path.is_empty() {
+        true => vec![],
+        false => path.split('.').collect::<Vec<_>>(),
+    };
+    if segments.iter().any(|s| s.is_empty()) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Invalid variant path '{path}': empty path segment"
+        )));
+    }
+
+    let mut indices = Vec::with_capacity(array.len());
+    let mut builder = VariantArrayBuilder::new(array.len());
+    for i in 0..array.len() {
+        if array.is_null(i) {
+            continue;
+        }
+        let Some(Variant::List(list)) = get_path(array.value(i), &segments) else {
+            continue;
+        };
+        // The elements are valid with the metadata of the row, and so are copied as is
+        let metadata = array.metadata(i);
+        for j in 0..list.len() {
+            indices.push(i as u32);
+            builder.append_variant_buffers(metadata, list.value_bytes(j).unwrap());
+        }
+    }
+    Ok((UInt32Array::from(indices), builder.build()))
+}
+
+/// Returns the value at the path given by `segments` within `variant`, if any
+fn get_path<'m, 'v>(variant: Variant<'m, 'v>, segments: &[&str]) -> Option<Variant<'m, 'v>> {
+    segments
+        .iter()
+        .try_fold(variant, |current, segment| match current {
+            Variant::Object(obj) => obj.get(segment),
+            Variant::List(list) => list.get(segment.parse().ok()?),
+            _ => None,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use parquet_variant::VariantBuilder;
+
+    /// `{"id": <id>, "items": [{"tags": [...]}, ...]}`, for each row of `rows`
+    fn test_array(rows: &[Option<&[&[&str]]>]) -> VariantArray {
+        let mut builder = VariantArrayBuilder::new(rows.len());
+        for (id, row) in rows.iter().enumerate() {
+            let Some(items) = row else {
+                builder.append_null();
+                continue;
+            };
+            let mut vb = VariantBuilder::new();
+            let mut obj = vb.new_object();
+            obj.insert("id", id as i32);
+            let mut list = obj.new_list("items");
+            for tags in items.iter() {
+                let mut item = list.new_object();
+                let mut tag_list = item.new_list("tags");
+                for tag in tags.iter() {
+                    tag_list.append_value(*tag);
+                }
+                tag_list.finish();
+                item.finish().unwrap();
+            }
+            list.finish();
+            obj.finish().unwrap();
+            let (metadata, value) = vb.finish();
+            builder.append_variant_buffers(&metadata, &value);
+        }
+        builder.build()
+    }
+
+    #[test]
+    fn test_explode_nested() {
+        let array = test_array(&[
+            Some(&[&["a", "b"], &["c"]]),
+            None,
+            Some(&[]),
+            Some(&[&["d"]]),
+        ]);
+
+        let (indices, items) = variant_explode(&array, "items").unwrap();
+        assert_eq!(indices, UInt32Array::from(vec![0, 0, 3]));
+        assert_eq!(items.len(), 3);
+        assert_eq!(items.null_count(), 0);
+
+        // Explode the exploded elements again
+        let (indices, tags) = variant_explode(&items, "tags").unwrap();
+        assert_eq!(indices, UInt32Array::from(vec![0, 0, 1, 2]));
+        let tags: Vec<_> = (0..tags.len()).map(|i| tags.value(i)).collect();
+        assert_eq!(
+            tags,
+            vec![
+                Variant::from("a"),
+                Variant::from("b"),
+                Variant::from("c"),
+                Variant::from("d")
+            ]
+        );
+
+        // A list index within the path
+        let (indices, tags) = variant_explode(&array, "items.1.tags").unwrap();
+        assert_eq!(indices, UInt32Array::from(vec![0]));
+        assert_eq!(tags.value(0), Variant::from("c"));
+    }
+
+    #[test]
+    fn test_explode_not_list() {
+        let array = test_array(&[Some(&[&["a"]])]);
+        for path in ["id", "missing", "items.x", "items.5.tags", "id.x", ""] {
+            let (indices, values) = variant_explode(&array, path).unwrap();
+            assert!(indices.is_empty(), "{path}");
+            assert!(values.is_empty(), "{path}");
+        }
+
+        let err = variant_explode(&array, "items..tags").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Invalid variant path 'items..tags': empty path segment"
+        );
+    }
+
+    #[test]
+    fn test_explode_top_level() {
+        let mut builder = VariantArrayBuilder::new(2);
+        let mut vb = VariantBuilder::new();
+        let mut list = vb.new_list();
+        list.append_value(1);
+        list.append_value(Variant::Null);
+        list.finish();
+        let (metadata, value) = vb.finish();
+        builder.append_variant_buffers(&metadata, &value);
+        builder.append_variant(Variant::from(2));
+        let array = builder.build();
+
+        let (indices, values) = variant_explode(&array, "").unwrap();
+        assert_eq!(indices, UInt32Array::from(vec![0, 0]));
+        assert_eq!(values.value(0), Variant::from(1));
+        assert_eq!(values.value(1), Variant::Null);
+    }
+}
@@ -0,0 +1,315 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`VariantPathProgram`] for repeatedly looking up a path within many variant values
+
+use crate::VariantArray;
+use arrow::array::{Array, ArrayRef};
+use arrow_schema::ArrowError;
+use parquet_variant::{Variant, VariantMetadata};
+use std::sync::Arc;
+
+/// A path into [`Variant`] values, compiled against a metadata dictionary so that it can
+/// be evaluated against many values sharing that metadata without comparing field names
+///
+/// `path` uses the same syntax as [`variant_explode`], a sequence of object field names
+/// separated by `.`, where a segment may also be a list index, for example `"user.name"`
+/// or `"events.0.kind"`.
+///
+/// Looking up an object field by name, as in [`VariantObject::get`], requires a binary
+/// search comparing the name against the field names of each object. Instead, compiling
+/// the path resolves each field name to its index in the metadata dictionary once, after
+/// which objects are searched by [field id](VariantObject::get_by_field_id). This is
+/// cheapest for metadata with a [sorted] dictionary.
+///
+/// [`Self::get`] recompiles the path only when the metadata of a row differs from the
+/// metadata it was last compiled against, which is rare for arrays where most rows share
+/// their metadata, for example those built with [`VariantArrayBuilder::with_dictionary_metadata`].
+///
+/// ```
+/// # use parquet_variant::{Variant, VariantBuilder};
+/// # use parquet_variant_compute::{VariantArrayBuilder, VariantPathProgram};
+/// let mut builder = VariantArrayBuilder::new(10).with_dictionary_metadata(true);
+/// for i in 0..10 {
+///     let mut vb = VariantBuilder::new().with_field_names(["id", "user", "name"].into_iter());
+///     let mut obj = vb.new_object();
+///     obj.insert("id", i);
+///     let mut user = obj.new_object("user");
+///     user.insert("name", format!("user{i}").as_str());
+///     user.finish().unwrap();
+///     obj.finish().unwrap();
+///     let (metadata, value) = vb.finish();
+///     builder.append_variant_buffers(&metadata, &value);
+/// }
+/// let array = builder.build();
+///
+/// let mut program = VariantPathProgram::try_new("user.name").unwrap();
+/// for i in 0..10 {
+///     let name = program.get(&array, i).unwrap().unwrap();
+///     assert_eq!(name, Variant::from(format!("user{i}").as_str()));
+/// }
+/// ```
+///
+/// [`variant_explode`]: crate::variant_explode
+/// [`VariantObject::get`]: parquet_variant::VariantObject::get
+/// [`VariantObject::get_by_field_id`]: parquet_variant::VariantObject::get_by_field_id
+/// [sorted]: VariantMetadata::is_sorted
+/// [`VariantArrayBuilder::with_dictionary_metadata`]: crate::VariantArrayBuilder::with_dictionary_metadata
+#[derive(Debug, Clone)]
+pub struct VariantPathProgram {
+    steps: Vec<PathStep>,
+    /// The metadata the field ids of `steps` were resolved against
+    metadata: Option<Vec<u8>>,
+    /// The location of `metadata` within the metadata array of the last [`Self::get`]
+    location: Option<MetadataLocation>,
+}
+
+/// The address and length of metadata bytes within a metadata array
+///
+/// As `_array` is kept alive, and its buffers are immutable, bytes at the same location
+/// are the same metadata, avoiding comparing their contents
+#[derive(Debug, Clone)]
+struct MetadataLocation {
+    _array: ArrayRef,
+    address: usize,
+    len: usize,
+}
+
+impl MetadataLocation {
+    fn matches(&self, metadata: &[u8]) -> bool {
+        self.address == metadata.as_ptr() as usize && self.len == metadata.len()
+    }
+}
+
+/// A segment of the path of a [`VariantPathProgram`]
+#[derive(Debug, Clone)]
+struct PathStep {
+    name: String,
+    /// The list index given by `name`, if it is one
+    index: Option<usize>,
+    /// The index of `name` in the compiled metadata dictionary, if present
+    field_id: Option<u32>,
+}
+
+impl VariantPathProgram {
+    /// Create a new [`VariantPathProgram`] for `path`
+    ///
+    /// Returns an error if `path` contains an empty segment.
+    pub fn try_new(path: &str) -> Result<Self, ArrowError> {
+        let segments = match path.is_empty() {
+            true => vec![],
+            false => path.split('.').collect::<Vec<_>>(),
+        };
+        if segments.iter().any(|s| s.is_empty()) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Invalid variant path '{path}': empty path segment"
+            )));
+        }
+
+        let steps = segments
+            .into_iter()
+            .map(|name| PathStep {
+                name: name.to_string(),
+                index: name.parse().ok(),
+                field_id: None,
+            })
+            .collect();
+        Ok(Self {
+            steps,
+            metadata: None,
+            location: None,
+        })
+    }
+
+    /// Resolve the field names of the path against `metadata`
+    ///
+    /// This is a no-op if the path was last compiled against the same metadata.
+    pub fn compile(&mut self, metadata: &[u8]) -> Result<(), ArrowError> {
+        if self.metadata.as_deref() == Some(metadata) {
+            return Ok(());
+        }
+        let dictionary = VariantMetadata::try_new(metadata)?;
+        for step in &mut self.steps {
+            step.field_id = dictionary.field_id(&step.name);
+        }
+        self.metadata = Some(metadata.to_vec());
+        self.location = None;
+        Ok(())
+    }
+
+    /// Returns the value at the path within `variant`, if any
+    ///
+    /// The path must have been [compiled](Self::compile) against the metadata of `variant`,
+    /// otherwise the result is unspecified.
+    pub fn execute<'m, 'v>(&self, variant: Variant<'m, 'v>) -> Option<Variant<'m, 'v>> {
+        self.steps
+            .iter()
+            .try_fold(variant, |current, step| match current {
+                Variant::Object(obj) => obj.get_by_field_id(step.field_id?),
+                Variant::List(list) => list.get(step.index?),
+                _ => None,
+            })
+    }
+
+    /// Returns the value at the path within row `index` of `array`, if any
+    ///
+    /// Returns `Ok(None)` if the row is null. The path is recompiled if the metadata of
+    /// the row differs from that it was last compiled against. Rows that share the same
+    /// metadata bytes, for example through dictionary encoding, are detected by their
+    /// location without comparing the metadata.
+    pub fn get<'a>(
+        &mut self,
+        array: &'a VariantArray,
+        index: usize,
+    ) -> Result<Option<Variant<'a, 'a>>, ArrowError> {
+        if array.is_null(index) {
+            return Ok(None);
+        }
+        let metadata = array.metadata(index);
+        if !self.location.as_ref().is_some_and(|l| l.matches(metadata)) {
+            self.compile(metadata)?;
+            self.location = Some(MetadataLocation {
+                _array: Arc::clone(array.metadata_field()),
+                address: metadata.as_ptr() as usize,
+                len: metadata.len(),
+            });
+        }
+        Ok(self.execute(array.value(index)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::VariantArrayBuilder;
+    use parquet_variant::VariantBuilder;
+
+    /// Appends `{"id": <id>, "items": [{"kind": <kind>}, ...]}` for each of `rows`,
+    /// seeding the metadata with `field_names`
+    fn append_rows(
+        builder: &mut VariantArrayBuilder,
+        field_names: &[&str],
+        rows: &[Option<&[&str]>],
+    ) {
+        for (id, row) in rows.iter().enumerate() {
+            let Some(kinds) = row else {
+                builder.append_null();
+                continue;
+            };
+            let mut vb = VariantBuilder::new().with_field_names(field_names.iter().copied());
+            let mut obj = vb.new_object();
+            obj.insert("id", id as i32);
+            let mut list = obj.new_list("items");
+            for kind in kinds.iter() {
+                let mut item = list.new_object();
+                item.insert("kind", *kind);
+                item.finish().unwrap();
+            }
+            list.finish();
+            obj.finish().unwrap();
+            let (metadata, value) = vb.finish();
+            builder.append_variant_buffers(&metadata, &value);
+        }
+    }
+
+    #[test]
+    fn test_path_program() {
+        let mut builder = VariantArrayBuilder::new(6);
+        // Sorted, then unsorted metadata
+        append_rows(
+            &mut builder,
+            &["id", "items", "kind"],
+            &[Some(&["a", "b"]), None, Some(&[])],
+        );
+        append_rows(
+            &mut builder,
+            &["kind", "items", "id"],
+            &[Some(&["c"]), Some(&["d", "e"])],
+        );
+        let array = builder.build();
+
+        let mut program = VariantPathProgram::try_new("items.1.kind").unwrap();
+        let kinds = (0..array.len())
+            .map(|i| program.get(&array, i).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            vec![
+                Some(Variant::from("b")),
+                None,
+                None,
+                None,
+                Some(Variant::from("e"))
+            ]
+        );
+
+        let mut program = VariantPathProgram::try_new("id").unwrap();
+        assert_eq!(program.get(&array, 4).unwrap(), Some(Variant::from(1)));
+        assert_eq!(program.get(&array, 0).unwrap(), Some(Variant::from(0)));
+
+        // The top-level value
+        let mut program = VariantPathProgram::try_new("").unwrap();
+        assert_eq!(program.get(&array, 3).unwrap(), Some(array.value(3)));
+    }
+
+    #[test]
+    fn test_path_program_metadata_location() {
+        let mut builder = VariantArrayBuilder::new(4).with_dictionary_metadata(true);
+        append_rows(
+            &mut builder,
+            &["id", "items", "kind"],
+            &[Some(&["a"]), Some(&["b"])],
+        );
+        append_rows(&mut builder, &["kind", "items", "id"], &[Some(&["c"])]);
+        let array = builder.build();
+
+        let mut program = VariantPathProgram::try_new("items.0.kind").unwrap();
+        assert_eq!(program.get(&array, 0).unwrap(), Some(Variant::from("a")));
+        let location = program.location.clone().unwrap();
+        assert!(location.matches(array.metadata(1)));
+        assert_eq!(program.get(&array, 1).unwrap(), Some(Variant::from("b")));
+        assert!(!location.matches(array.metadata(2)));
+        assert_eq!(program.get(&array, 2).unwrap(), Some(Variant::from("c")));
+
+        // Compiling directly invalidates the location
+        program.compile(array.metadata(0)).unwrap();
+        assert!(program.location.is_none());
+        assert_eq!(program.get(&array, 2).unwrap(), Some(Variant::from("c")));
+    }
+
+    #[test]
+    fn test_path_program_missing() {
+        let mut builder = VariantArrayBuilder::new(1);
+        append_rows(&mut builder, &[], &[Some(&["a"])]);
+        let array = builder.build();
+
+        for path in ["missing", "id.x", "items.x", "items.5", "items.0.missing"] {
+            let mut program = VariantPathProgram::try_new(path).unwrap();
+            assert_eq!(program.get(&array, 0).unwrap(), None, "{path}");
+        }
+
+        // Not compiled against the metadata
+        let program = VariantPathProgram::try_new("id").unwrap();
+        assert_eq!(program.execute(array.value(0)), None);
+
+        let err = VariantPathProgram::try_new("items..kind").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Invalid variant path 'items..kind': empty path segment"
+        );
+    }
+}
@@ -0,0 +1,148 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`VariantType`] Arrow extension type
+
+use crate::variant_array::validate_fields;
+use arrow_schema::extension::ExtensionType;
+use arrow_schema::{ArrowError, DataType};
+
+/// The Arrow extension type for Parquet Variant values, see [`VariantArray`]
+///
+/// Extension name: `arrow.parquet.variant`.
+///
+/// The storage type of the extension is a `Struct` with a `metadata` and a
+/// `value` field, both binary. Annotating a field with this extension type
+/// allows variant columns to be recognized after being exchanged with other
+/// Arrow implementations, such as pyarrow, via the C Data Interface.
+///
+/// ```
+/// # use arrow_schema::{DataType, Field, Fields};
+/// # use parquet_variant_compute::VariantType;
+/// let fields = Fields::from(vec![
+///     Field::new("metadata", DataType::Binary, false),
+///     Field::new("value", DataType::Binary, false),
+/// ]);
+/// let field = Field::new("v", DataType::Struct(fields), true).with_extension_type(VariantType);
+/// assert_eq!(field.extension_type_name(), Some("arrow.parquet.variant"));
+/// ```
+///
+/// [`VariantArray`]: crate::VariantArray
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct VariantType;
+
+impl ExtensionType for VariantType {
+    const NAME: &'static str = "arrow.parquet.variant";
+
+    type Metadata = &'static str;
+
+    fn metadata(&self) -> &Self::Metadata {
+        &""
+    }
+
+    fn serialize_metadata(&self) -> Option<String> {
+        Some(String::default())
+    }
+
+    fn deserialize_metadata(metadata: Option<&str>) -> Result<Self::Metadata, ArrowError> {
+        // Other implementations may omit the metadata entirely
+        if metadata.is_none_or(str::is_empty) {
+            Ok("")
+        } else {
+            Err(ArrowError::InvalidArgumentError(
+                "Variant extension type expects empty or no metadata".to_owned(),
+            ))
+        }
+    }
+
+    fn supports_data_type(&self, data_type: &DataType) -> Result<(), ArrowError> {
+        match data_type {
+            DataType::Struct(fields) => validate_fields(fields),
+            data_type => Err(ArrowError::InvalidArgumentError(format!(
+                "Variant data type mismatch, expected Struct, found {data_type}"
+            ))),
+        }
+    }
+
+    fn try_new(data_type: &DataType, _metadata: Self::Metadata) -> Result<Self, ArrowError> {
+        Self.supports_data_type(data_type).map(|_| Self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_schema::extension::{ExtensionTypeRegistry, EXTENSION_TYPE_NAME_KEY};
+    use arrow_schema::{Field, Fields};
+
+    fn variant_fields() -> Fields {
+        Fields::from(vec![
+            Field::new("metadata", DataType::BinaryView, false),
+            Field::new("value", DataType::BinaryView, false),
+        ])
+    }
+
+    #[test]
+    fn valid() -> Result<(), ArrowError> {
+        let mut field = Field::new("v", DataType::Struct(variant_fields()), true);
+        field.try_with_extension_type(VariantType)?;
+        field.try_extension_type::<VariantType>()?;
+        Ok(())
+    }
+
+    #[test]
+    fn missing_metadata() {
+        let field = Field::new("v", DataType::Struct(variant_fields()), true).with_metadata(
+            [(
+                EXTENSION_TYPE_NAME_KEY.to_owned(),
+                VariantType::NAME.to_owned(),
+            )]
+            .into_iter()
+            .collect(),
+        );
+        field.extension_type::<VariantType>();
+    }
+
+    #[test]
+    #[should_panic(expected = "Variant data type mismatch, expected Struct, found Binary")]
+    fn invalid_type() {
+        Field::new("v", DataType::Binary, true).with_extension_type(VariantType);
+    }
+
+    #[test]
+    #[should_panic(expected = "VariantArray 'value' field must be Binary")]
+    fn invalid_fields() {
+        let fields = Fields::from(vec![
+            Field::new("metadata", DataType::Binary, false),
+            Field::new("value", DataType::Utf8, false),
+        ]);
+        Field::new("v", DataType::Struct(fields), true).with_extension_type(VariantType);
+    }
+
+    #[test]
+    fn registry() -> Result<(), ArrowError> {
+        let mut registry = ExtensionTypeRegistry::new();
+        registry.register::<VariantType>();
+
+        let field = Field::new("v", DataType::Struct(variant_fields()), true)
+            .with_extension_type(VariantType);
+        let resolved = registry.resolve(&field)?.unwrap();
+        assert_eq!(resolved.name(), VariantType::NAME);
+        assert!(resolved.as_any().is::<VariantType>());
+        Ok(())
+    }
+}
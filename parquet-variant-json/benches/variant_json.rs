@@ -0,0 +1,90 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use criterion::*;
+
+use parquet_variant::{Variant, VariantBuilder};
+use parquet_variant_json::{json_to_variant, variant_to_json_string};
+
+fn small_object() -> String {
+    r#"{"id": 12345, "name": "Alice", "active": true, "score": 98.5, "tags": ["a", "b"]}"#
+        .to_string()
+}
+
+fn wide_object() -> String {
+    let fields: Vec<_> = (0..1000)
+        .map(|i| format!(r#""field_{i:03}": {i}"#))
+        .collect();
+    format!("{{{}}}", fields.join(","))
+}
+
+fn deep_object() -> String {
+    let mut json = "1".to_string();
+    for _ in 0..64 {
+        json = format!(r#"{{"level": {json}}}"#);
+    }
+    json
+}
+
+fn numeric_array() -> String {
+    let values: Vec<_> = (0..10_000).map(|i| (i as f64 * 1.5).to_string()).collect();
+    format!("[{}]", values.join(","))
+}
+
+fn documents() -> Vec<(&'static str, String)> {
+    vec![
+        ("small_object", small_object()),
+        ("wide_object", wide_object()),
+        ("deep_object", deep_object()),
+        ("numeric_array", numeric_array()),
+    ]
+}
+
+fn bench_json_to_variant(c: &mut Criterion) {
+    let mut group = c.benchmark_group("json_to_variant");
+    for (name, json) in documents() {
+        group.throughput(Throughput::Bytes(json.len() as u64));
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                let mut builder = VariantBuilder::new();
+                json_to_variant(&json, &mut builder).unwrap();
+                std::hint::black_box(builder.finish())
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bench_variant_to_json(c: &mut Criterion) {
+    let mut group = c.benchmark_group("variant_to_json");
+    for (name, json) in documents() {
+        let mut builder = VariantBuilder::new();
+        json_to_variant(&json, &mut builder).unwrap();
+        let (metadata, value) = builder.finish();
+        let variant = Variant::try_new(&metadata, &value).unwrap();
+
+        group.throughput(Throughput::Bytes(json.len() as u64));
+        group.bench_function(name, |b| {
+            b.iter(|| std::hint::black_box(variant_to_json_string(&variant).unwrap()))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_json_to_variant, bench_variant_to_json);
+
+criterion_main!(benches);
@@ -21,7 +21,7 @@ use base64::{engine::general_purpose, Engine as _};
 use serde_json::Value;
 use std::io::Write;
 
-use parquet_variant::{Variant, VariantList, VariantObject};
+use parquet_variant::{Variant, VariantList, VariantObject, VariantOpaque};
 
 // Format string constants to avoid duplication and reduce errors
 const DATE_FORMAT: &str = "%Y-%m-%d";
@@ -138,10 +138,28 @@ pub fn variant_to_json(json_buffer: &mut impl Write, variant: &Variant) -> Resul
         Variant::List(arr) => {
             convert_array_to_json(json_buffer, arr)?;
         }
+        Variant::Opaque(opaque) => {
+            return Err(unknown_type_error(opaque));
+        }
+        v => return Err(unsupported_error(v)),
     }
     Ok(())
 }
 
+/// Variant values of a type unknown to this crate, read in lenient mode, have
+/// no JSON representation
+fn unknown_type_error(opaque: &VariantOpaque) -> ArrowError {
+    ArrowError::InvalidArgumentError(format!(
+        "Cannot convert variant value of unknown type {} to JSON",
+        opaque.type_id()
+    ))
+}
+
+/// Variant values of a type added after this crate was written
+fn unsupported_error(variant: &Variant) -> ArrowError {
+    ArrowError::InvalidArgumentError(format!("Cannot convert variant value {variant:?} to JSON"))
+}
+
 /// Convert object fields to JSON
 fn convert_object_to_json(buffer: &mut impl Write, obj: &VariantObject) -> Result<(), ArrowError> {
     write!(buffer, "{{")?;
@@ -365,6 +383,8 @@ pub fn variant_to_json_value(variant: &Variant) -> Result<Value, ArrowError> {
                 .collect::<Result<_, _>>()?;
             Ok(Value::Array(vec))
         }
+        Variant::Opaque(opaque) => Err(unknown_type_error(opaque)),
+        v => Err(unsupported_error(v)),
     }
 }
 
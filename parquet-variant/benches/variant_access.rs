@@ -0,0 +1,130 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+extern crate parquet_variant;
+
+use criterion::*;
+
+use parquet_variant::{Variant, VariantBuilder};
+
+const WIDE_FIELDS: usize = 1000;
+const DEEP_LEVELS: usize = 64;
+
+/// An object with [`WIDE_FIELDS`] integer fields named `field_000`, `field_001`, ...
+fn generate_wide_object() -> (Vec<u8>, Vec<u8>) {
+    let mut variant_builder = VariantBuilder::new();
+    let mut object_builder = variant_builder.new_object();
+    for i in 0..WIDE_FIELDS {
+        object_builder.insert(&format!("field_{i:03}"), i as i64);
+    }
+    object_builder.finish().unwrap();
+    variant_builder.finish()
+}
+
+/// [`DEEP_LEVELS`] nested objects, each with a field `level` and a field `child`
+fn generate_deep_object() -> (Vec<u8>, Vec<u8>) {
+    fn append_level(object_builder: &mut parquet_variant::ObjectBuilder, depth: usize) {
+        object_builder.insert("level", depth as i64);
+        if depth < DEEP_LEVELS {
+            let mut child = object_builder.new_object("child");
+            append_level(&mut child, depth + 1);
+            child.finish().unwrap();
+        }
+    }
+
+    let mut variant_builder = VariantBuilder::new();
+    let mut object_builder = variant_builder.new_object();
+    append_level(&mut object_builder, 1);
+    object_builder.finish().unwrap();
+    variant_builder.finish()
+}
+
+fn generate_long_list() -> (Vec<u8>, Vec<u8>) {
+    let mut variant_builder = VariantBuilder::new();
+    let mut list_builder = variant_builder.new_list();
+    for i in 0..10_000 {
+        list_builder.append_value(i);
+    }
+    list_builder.finish();
+    variant_builder.finish()
+}
+
+fn bench_wide_object_field_access(c: &mut Criterion) {
+    let (metadata, value) = generate_wide_object();
+    let variant = Variant::try_new(&metadata, &value).unwrap();
+
+    let mut group = c.benchmark_group("wide_object");
+    for name in ["field_000", "field_500", "field_999", "missing"] {
+        group.bench_function(format!("get_object_field {name}"), |b| {
+            b.iter(|| std::hint::black_box(variant.get_object_field(name)))
+        });
+    }
+    group.bench_function("iter all fields", |b| {
+        let object = variant.as_object().unwrap();
+        b.iter(|| std::hint::black_box(object.iter().count()))
+    });
+    group.bench_function("try_new", |b| {
+        b.iter(|| std::hint::black_box(Variant::try_new(&metadata, &value).unwrap()))
+    });
+    group.finish();
+}
+
+fn bench_deep_object_path_access(c: &mut Criterion) {
+    let (metadata, value) = generate_deep_object();
+    let variant = Variant::try_new(&metadata, &value).unwrap();
+
+    let mut group = c.benchmark_group("deep_object");
+    for depth in [1, 16, DEEP_LEVELS] {
+        group.bench_function(format!("path depth {depth}"), |b| {
+            b.iter(|| {
+                let mut current = variant.clone();
+                for _ in 1..depth {
+                    current = current.get_object_field("child").unwrap();
+                }
+                std::hint::black_box(current.get_object_field("level").unwrap())
+            })
+        });
+    }
+    group.bench_function("try_new", |b| {
+        b.iter(|| std::hint::black_box(Variant::try_new(&metadata, &value).unwrap()))
+    });
+    group.finish();
+}
+
+fn bench_list_element_access(c: &mut Criterion) {
+    let (metadata, value) = generate_long_list();
+    let variant = Variant::try_new(&metadata, &value).unwrap();
+
+    let mut group = c.benchmark_group("long_list");
+    group.bench_function("get_list_element", |b| {
+        b.iter(|| std::hint::black_box(variant.get_list_element(5_000)))
+    });
+    group.bench_function("iter all elements", |b| {
+        let list = variant.as_list().unwrap();
+        b.iter(|| std::hint::black_box(list.iter().count()))
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_wide_object_field_access,
+    bench_deep_object_path_access,
+    bench_list_element_access
+);
+
+criterion_main!(benches);
@@ -0,0 +1,326 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Random [`Variant`] generation for fuzz testing, based on [`arbitrary`]
+//!
+//! [`ArbitraryVariant`] produces well-formed variant buffers containing every
+//! primitive type as well as nested lists and objects, while [`MalformedVariant`]
+//! produces buffers that have been deliberately corrupted, for example by
+//! truncation or by overwriting headers and offsets.
+//!
+//! Both implement [`Arbitrary`] so they can be used directly as the input of a
+//! [`cargo fuzz`] target:
+//!
+//! ```
+//! # use arbitrary::{Arbitrary, Unstructured};
+//! # use parquet_variant::{ArbitraryVariant, MalformedVariant, Variant};
+//! let data = [42_u8; 256];
+//! let mut u = Unstructured::new(&data);
+//!
+//! let valid = ArbitraryVariant::arbitrary(&mut u).unwrap();
+//! Variant::try_new(&valid.metadata, &valid.value).unwrap();
+//!
+//! // Decoding malformed input returns an error rather than panicking
+//! let malformed = MalformedVariant::arbitrary(&mut u).unwrap();
+//! let _ = Variant::try_new(&malformed.metadata, &malformed.value);
+//! ```
+//!
+//! [`cargo fuzz`]: https://rust-fuzz.github.io/book/cargo-fuzz.html
+
+use crate::{
+    ListBuilder, ObjectBuilder, Variant, VariantBuilder, VariantBuilderExt, VariantDecimal16,
+    VariantDecimal4, VariantDecimal8,
+};
+use arbitrary::{Arbitrary, Result, Unstructured};
+use chrono::DateTime;
+
+/// The maximum nesting depth of generated lists and objects
+const MAX_DEPTH: usize = 4;
+
+/// The maximum number of elements of generated lists and objects
+const MAX_ELEMENTS: usize = 8;
+
+/// Field names shared between objects, so that generated metadata dictionaries
+/// contain repeated entries
+const FIELD_NAMES: &[&str] = &["a", "b", "id", "name", "value", "nested", ""];
+
+/// The metadata and value buffers of a randomly generated, well-formed [`Variant`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArbitraryVariant {
+    /// The encoded metadata
+    pub metadata: Vec<u8>,
+    /// The encoded value
+    pub value: Vec<u8>,
+}
+
+impl ArbitraryVariant {
+    /// Returns the [`Variant`] stored in these buffers
+    pub fn variant(&self) -> Variant<'_, '_> {
+        Variant::try_new(&self.metadata, &self.value).expect("generated variant is valid")
+    }
+}
+
+impl<'a> Arbitrary<'a> for ArbitraryVariant {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let node = Node::arbitrary_with_depth(u, MAX_DEPTH)?;
+        let mut builder = VariantBuilder::new();
+        node.append_to(&mut builder);
+        let (metadata, value) = builder.finish();
+        Ok(Self { metadata, value })
+    }
+}
+
+/// The metadata and value buffers of a deliberately corrupted [`Variant`]
+///
+/// The buffers are derived from an [`ArbitraryVariant`] by applying one or more
+/// corruptions, and are therefore usually, but not always, invalid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MalformedVariant {
+    /// The encoded metadata
+    pub metadata: Vec<u8>,
+    /// The encoded value
+    pub value: Vec<u8>,
+}
+
+impl<'a> Arbitrary<'a> for MalformedVariant {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let ArbitraryVariant {
+            mut metadata,
+            mut value,
+        } = ArbitraryVariant::arbitrary(u)?;
+
+        for _ in 0..u.int_in_range(1..=3)? {
+            let buffer = match bool::arbitrary(u)? {
+                true => &mut metadata,
+                false => &mut value,
+            };
+            corrupt(u, buffer)?;
+        }
+        Ok(Self { metadata, value })
+    }
+}
+
+/// Applies a random corruption to `buffer`
+fn corrupt(u: &mut Unstructured<'_>, buffer: &mut Vec<u8>) -> Result<()> {
+    match u.int_in_range(0..=5)? {
+        // Truncate
+        0 => {
+            let len = u.choose_index(buffer.len() + 1)?;
+            buffer.truncate(len);
+        }
+        // Overwrite the header byte
+        1 => match buffer.first_mut() {
+            Some(header) => *header = u.arbitrary()?,
+            None => buffer.push(u.arbitrary()?),
+        },
+        // Flip bits of a random byte, such as an offset or field id
+        2 if !buffer.is_empty() => {
+            let idx = u.choose_index(buffer.len())?;
+            buffer[idx] ^= u8::arbitrary(u)? | 1;
+        }
+        // Overwrite a random byte with an extreme value
+        3 if !buffer.is_empty() => {
+            let idx = u.choose_index(buffer.len())?;
+            buffer[idx] = *u.choose(&[0x00, 0x01, 0x7F, 0x80, 0xFF])?;
+        }
+        // Replace with unstructured bytes
+        4 => {
+            let len = u.int_in_range(0..=32)?;
+            *buffer = u.bytes(len)?.to_vec();
+        }
+        // Append trailing bytes
+        _ => {
+            let len = u.int_in_range(1..=8)?;
+            buffer.extend_from_slice(u.bytes(len)?);
+        }
+    }
+    Ok(())
+}
+
+/// An owned tree of values to encode
+#[derive(Debug)]
+enum Node {
+    Primitive(Variant<'static, 'static>),
+    String(String),
+    Binary(Vec<u8>),
+    List(Vec<Node>),
+    Object(Vec<(String, Node)>),
+}
+
+impl Node {
+    fn arbitrary_with_depth(u: &mut Unstructured<'_>, depth: usize) -> Result<Self> {
+        let max_kind = if depth == 0 { 2 } else { 4 };
+        Ok(match u.int_in_range(0..=max_kind)? {
+            0 => Self::Primitive(arbitrary_primitive(u)?),
+            1 => Self::String(u.arbitrary()?),
+            2 => Self::Binary(u.arbitrary()?),
+            3 => {
+                let len = u.int_in_range(0..=MAX_ELEMENTS)?;
+                let elements = (0..len)
+                    .map(|_| Self::arbitrary_with_depth(u, depth - 1))
+                    .collect::<Result<_>>()?;
+                Self::List(elements)
+            }
+            _ => {
+                let len = u.int_in_range(0..=MAX_ELEMENTS)?;
+                let mut fields: Vec<(String, Node)> = Vec::with_capacity(len);
+                for _ in 0..len {
+                    let name = match bool::arbitrary(u)? {
+                        true => u.choose(FIELD_NAMES)?.to_string(),
+                        false => u.arbitrary()?,
+                    };
+                    let value = Self::arbitrary_with_depth(u, depth - 1)?;
+                    // Field names must be unique within an object
+                    if !fields.iter().any(|(n, _)| *n == name) {
+                        fields.push((name, value));
+                    }
+                }
+                Self::Object(fields)
+            }
+        })
+    }
+
+    fn append_to<B: for<'m, 'v> VariantBuilderExt<'m, 'v>>(&self, builder: &mut B) {
+        match self {
+            Self::Primitive(v) => builder.append_value(v.clone()),
+            Self::String(s) => builder.append_value(s.as_str()),
+            Self::Binary(b) => builder.append_value(b.as_slice()),
+            Self::List(elements) => {
+                let mut list = builder.new_list();
+                append_elements(&mut list, elements);
+                list.finish();
+            }
+            Self::Object(fields) => {
+                let mut object = builder.new_object();
+                insert_fields(&mut object, fields);
+                object.finish().expect("field names are unique");
+            }
+        }
+    }
+}
+
+fn append_elements(list: &mut ListBuilder<'_>, elements: &[Node]) {
+    elements.iter().for_each(|e| e.append_to(list));
+}
+
+fn insert_fields(object: &mut ObjectBuilder<'_>, fields: &[(String, Node)]) {
+    for (name, node) in fields {
+        match node {
+            Node::Primitive(v) => object.insert(name, v.clone()),
+            Node::String(s) => object.insert(name, s.as_str()),
+            Node::Binary(b) => object.insert(name, b.as_slice()),
+            Node::List(elements) => {
+                let mut list = object.new_list(name);
+                append_elements(&mut list, elements);
+                list.finish();
+            }
+            Node::Object(fields) => {
+                let mut inner = object.new_object(name);
+                insert_fields(&mut inner, fields);
+                inner.finish().expect("field names are unique");
+            }
+        }
+    }
+}
+
+fn arbitrary_primitive(u: &mut Unstructured<'_>) -> Result<Variant<'static, 'static>> {
+    let timestamp = |u: &mut Unstructured<'_>| -> Result<_> {
+        Ok(DateTime::from_timestamp_micros(u.arbitrary()?).unwrap_or_default())
+    };
+    Ok(match u.int_in_range(0..=13)? {
+        0 => Variant::Null,
+        1 => Variant::from(bool::arbitrary(u)?),
+        2 => Variant::Int8(u.arbitrary()?),
+        3 => Variant::Int16(u.arbitrary()?),
+        4 => Variant::Int32(u.arbitrary()?),
+        5 => Variant::Int64(u.arbitrary()?),
+        6 => Variant::Float(u.arbitrary()?),
+        7 => Variant::Double(u.arbitrary()?),
+        8 => {
+            let max = VariantDecimal4::MAX_UNSCALED_VALUE as i32;
+            let integer = u.int_in_range(-max..=max)?;
+            let scale = u.int_in_range(0..=VariantDecimal4::MAX_PRECISION)?;
+            VariantDecimal4::try_new(integer, scale).unwrap().into()
+        }
+        9 => {
+            let max = VariantDecimal8::MAX_UNSCALED_VALUE as i64;
+            let integer = u.int_in_range(-max..=max)?;
+            let scale = u.int_in_range(0..=VariantDecimal8::MAX_PRECISION)?;
+            VariantDecimal8::try_new(integer, scale).unwrap().into()
+        }
+        10 => {
+            let max = VariantDecimal16::MAX_UNSCALED_VALUE as i128;
+            let integer = u.int_in_range(-max..=max)?;
+            let scale = u.int_in_range(0..=VariantDecimal16::MAX_PRECISION)?;
+            VariantDecimal16::try_new(integer, scale).unwrap().into()
+        }
+        11 => Variant::Date(timestamp(u)?.date_naive()),
+        12 => Variant::TimestampMicros(timestamp(u)?),
+        _ => Variant::TimestampNtzMicros(timestamp(u)?.naive_utc()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    /// Visits every value nested within `variant`, returning the first error
+    fn visit(variant: &Variant) -> std::result::Result<(), crate::VariantError> {
+        match variant {
+            Variant::List(list) => list.iter_try().try_for_each(|v| visit(&v?)),
+            Variant::Object(object) => object.iter_try().try_for_each(|f| visit(&f?.1)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Calls `f` with an [`Unstructured`] of random bytes `iterations` times
+    fn fuzz(iterations: usize, mut f: impl FnMut(&mut Unstructured<'_>)) {
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut data = vec![0; 4096];
+        for _ in 0..iterations {
+            rng.fill(data.as_mut_slice());
+            f(&mut Unstructured::new(&data));
+        }
+    }
+
+    #[test]
+    fn test_arbitrary_variant_is_valid() {
+        fuzz(1000, |u| {
+            let v = ArbitraryVariant::arbitrary(u).unwrap();
+            let variant = Variant::try_new(&v.metadata, &v.value).unwrap();
+            visit(&variant).unwrap();
+        });
+    }
+
+    #[test]
+    fn test_malformed_variant_does_not_panic() {
+        let mut invalid = 0;
+        fuzz(1000, |u| {
+            let v = MalformedVariant::arbitrary(u).unwrap();
+            // Unvalidated access must not panic for any validated variant
+            match Variant::try_new(&v.metadata, &v.value) {
+                Ok(variant) => visit(&variant).unwrap(),
+                Err(_) => invalid += 1,
+            }
+        });
+        assert!(
+            invalid > 500,
+            "only {invalid} malformed variants were invalid"
+        );
+    }
+}
@@ -15,8 +15,11 @@
 // specific language governing permissions and limitations
 // under the License.
 use crate::decoder::{VariantBasicType, VariantPrimitiveType};
-use crate::{ShortString, Variant, VariantDecimal16, VariantDecimal4, VariantDecimal8};
-use arrow_schema::ArrowError;
+use crate::VariantError;
+use crate::{
+    ShortString, Variant, VariantDecimal16, VariantDecimal4, VariantDecimal8, VariantOpaque,
+    VariantVersion,
+};
 use indexmap::{IndexMap, IndexSet};
 use std::collections::HashSet;
 
@@ -208,6 +211,10 @@ impl ValueBuffer {
         self.append_slice(inner.as_bytes());
     }
 
+    fn append_opaque(&mut self, value: VariantOpaque) {
+        self.append_slice(value.as_bytes());
+    }
+
     fn append_string(&mut self, value: &str) {
         self.append_primitive_header(VariantPrimitiveType::String);
         self.append_slice(&(value.len() as u32).to_le_bytes());
@@ -239,6 +246,7 @@ impl ValueBuffer {
             Variant::Binary(v) => self.append_binary(v),
             Variant::String(s) => self.append_string(s),
             Variant::ShortString(s) => self.append_short_string(s),
+            Variant::Opaque(v) => self.append_opaque(v),
             Variant::Object(_) | Variant::List(_) => {
                 unreachable!(
                     "Nested values are handled specially by ObjectBuilder and ListBuilder"
@@ -293,6 +301,9 @@ struct MetadataBuilder {
 
     /// Output buffer. Metadata is written to the end of this buffer
     metadata_buffer: Vec<u8>,
+
+    /// The version to write in the header
+    version: VariantVersion,
 }
 
 /// Create a new MetadataBuilder that will write to the specified metadata buffer
@@ -355,6 +366,7 @@ impl MetadataBuilder {
             field_names,
             is_sorted,
             mut metadata_buffer,
+            version,
         } = self;
 
         // Determine appropriate offset size based on the larger of dict size or total string size
@@ -367,8 +379,8 @@ impl MetadataBuilder {
 
         metadata_buffer.reserve(metadata_size);
 
-        // Write header: version=1, field names are sorted, with calculated offset_size
-        metadata_buffer.push(0x01 | (is_sorted as u8) << 4 | ((offset_size - 1) << 6));
+        // Write header: version, field names are sorted, with calculated offset_size
+        metadata_buffer.push(version.as_u8() | (is_sorted as u8) << 4 | ((offset_size - 1) << 6));
 
         // Write dictionary size
         write_offset(&mut metadata_buffer, nkeys, offset_size);
@@ -748,6 +760,15 @@ impl VariantBuilder {
         self
     }
 
+    /// Sets the [`VariantVersion`] written to the metadata header, defaults to
+    /// [`VariantVersion::CURRENT`]
+    ///
+    /// This allows writing data readable by older readers, once multiple versions exist
+    pub fn with_version(mut self, version: VariantVersion) -> Self {
+        self.metadata_builder.version = version;
+        self
+    }
+
     /// This method pre-populates the field name directory in the Variant metadata with
     /// the specific field names, in order.
     ///
@@ -981,7 +1002,7 @@ impl<'a> ObjectBuilder<'a> {
     }
 
     /// Finalizes this object and appends it to its parent, which otherwise remains unmodified.
-    pub fn finish(mut self) -> Result<(), ArrowError> {
+    pub fn finish(mut self) -> Result<(), VariantError> {
         let metadata_builder = self.parent_state.metadata_builder();
         if self.validate_unique_fields && !self.duplicate_fields.is_empty() {
             let mut names = self
@@ -992,10 +1013,9 @@ impl<'a> ObjectBuilder<'a> {
 
             names.sort_unstable();
 
-            let joined = names.join(", ");
-            return Err(ArrowError::InvalidArgumentError(format!(
-                "Duplicate field keys detected: [{joined}]",
-            )));
+            return Err(VariantError::DuplicateFields(
+                names.into_iter().map(str::to_string).collect(),
+            ));
         }
 
         let data_size = self.buffer.offset();
@@ -1765,7 +1785,7 @@ mod tests {
         let result = root_obj.finish();
         assert_eq!(
             result.unwrap_err().to_string(),
-            "Invalid argument error: Duplicate field keys detected: [a, b]"
+            "Duplicate field keys detected: [a, b]"
         );
 
         // Deeply nested list -> list -> object with duplicate
@@ -1778,7 +1798,7 @@ mod tests {
         let nested_result = nested_obj.finish();
         assert_eq!(
             nested_result.unwrap_err().to_string(),
-            "Invalid argument error: Duplicate field keys detected: [x]"
+            "Duplicate field keys detected: [x]"
         );
 
         inner_list.finish();
@@ -19,7 +19,7 @@ use crate::utils::{
 };
 use crate::ShortString;
 
-use arrow_schema::ArrowError;
+use crate::VariantError;
 use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, Utc};
 
 /// The basic type of a [`Variant`] value, encoded in the first two bits of the
@@ -83,7 +83,7 @@ pub(crate) fn get_basic_type(header: u8) -> VariantBasicType {
 }
 
 impl TryFrom<u8> for VariantPrimitiveType {
-    type Error = ArrowError;
+    type Error = VariantError;
 
     fn try_from(value: u8) -> Result<Self, Self::Error> {
         match value {
@@ -104,9 +104,7 @@ impl TryFrom<u8> for VariantPrimitiveType {
             14 => Ok(VariantPrimitiveType::Float),
             15 => Ok(VariantPrimitiveType::Binary),
             16 => Ok(VariantPrimitiveType::String),
-            _ => Err(ArrowError::InvalidArgumentError(format!(
-                "unknown primitive type: {value}",
-            ))),
+            _ => Err(VariantError::UnknownPrimitiveType(value)),
         }
     }
 }
@@ -124,18 +122,14 @@ pub(crate) enum OffsetSizeBytes {
 
 impl OffsetSizeBytes {
     /// Build from the `offset_size_minus_one` bits (see spec).
-    pub(crate) fn try_new(offset_size_minus_one: u8) -> Result<Self, ArrowError> {
+    pub(crate) fn try_new(offset_size_minus_one: u8) -> Result<Self, VariantError> {
         use OffsetSizeBytes::*;
         let result = match offset_size_minus_one {
             0 => One,
             1 => Two,
             2 => Three,
             3 => Four,
-            _ => {
-                return Err(ArrowError::InvalidArgumentError(
-                    "offset_size_minus_one must be 0–3".to_string(),
-                ))
-            }
+            _ => return Err(VariantError::InvalidOffsetSize(offset_size_minus_one)),
         };
         Ok(result)
     }
@@ -146,7 +140,7 @@ impl OffsetSizeBytes {
     /// * `index` – 0-based index into the buffer
     ///
     /// Each value is `self as u32` bytes wide (1, 2, 3 or 4), zero-extended to 32 bits as needed.
-    pub(crate) fn unpack_u32(&self, bytes: &[u8], index: usize) -> Result<u32, ArrowError> {
+    pub(crate) fn unpack_u32(&self, bytes: &[u8], index: usize) -> Result<u32, VariantError> {
         self.unpack_u32_at_offset(bytes, 0, index)
     }
 
@@ -164,7 +158,7 @@ impl OffsetSizeBytes {
         bytes: &[u8],
         byte_offset: usize,  // how many bytes to skip
         offset_index: usize, // which offset in an array of offsets
-    ) -> Result<u32, ArrowError> {
+    ) -> Result<u32, VariantError> {
         use OffsetSizeBytes::*;
 
         // Index into the byte array:
@@ -210,105 +204,121 @@ pub(crate) fn map_bytes_to_offsets(
 }
 
 /// Extract the primitive type from a Variant value-metadata byte
-pub(crate) fn get_primitive_type(metadata: u8) -> Result<VariantPrimitiveType, ArrowError> {
+pub(crate) fn get_primitive_type(metadata: u8) -> Result<VariantPrimitiveType, VariantError> {
     // last 6 bits contain the primitive-type, see spec
     VariantPrimitiveType::try_from(metadata >> 2)
 }
 
+/// Returns true if `metadata` is the header of a primitive of a type unknown to this
+/// implementation, such as one added by a newer version of the specification
+pub(crate) fn is_unknown_primitive(metadata: u8) -> bool {
+    get_basic_type(metadata) == VariantBasicType::Primitive
+        && metadata >> 2 > VariantPrimitiveType::String as u8
+}
+
 /// Decodes an Int8 from the value section of a variant.
-pub(crate) fn decode_int8(data: &[u8]) -> Result<i8, ArrowError> {
+pub(crate) fn decode_int8(data: &[u8]) -> Result<i8, VariantError> {
     Ok(i8::from_le_bytes(array_from_slice(data, 0)?))
 }
 
 /// Decodes an Int16 from the value section of a variant.
-pub(crate) fn decode_int16(data: &[u8]) -> Result<i16, ArrowError> {
+pub(crate) fn decode_int16(data: &[u8]) -> Result<i16, VariantError> {
     Ok(i16::from_le_bytes(array_from_slice(data, 0)?))
 }
 
 /// Decodes an Int32 from the value section of a variant.
-pub(crate) fn decode_int32(data: &[u8]) -> Result<i32, ArrowError> {
+pub(crate) fn decode_int32(data: &[u8]) -> Result<i32, VariantError> {
     Ok(i32::from_le_bytes(array_from_slice(data, 0)?))
 }
 
 /// Decodes an Int64 from the value section of a variant.
-pub(crate) fn decode_int64(data: &[u8]) -> Result<i64, ArrowError> {
+pub(crate) fn decode_int64(data: &[u8]) -> Result<i64, VariantError> {
     Ok(i64::from_le_bytes(array_from_slice(data, 0)?))
 }
 
 /// Decodes a Decimal4 from the value section of a variant.
-pub(crate) fn decode_decimal4(data: &[u8]) -> Result<(i32, u8), ArrowError> {
+pub(crate) fn decode_decimal4(data: &[u8]) -> Result<(i32, u8), VariantError> {
     let scale = u8::from_le_bytes(array_from_slice(data, 0)?);
     let integer = i32::from_le_bytes(array_from_slice(data, 1)?);
     Ok((integer, scale))
 }
 
 /// Decodes a Decimal8 from the value section of a variant.
-pub(crate) fn decode_decimal8(data: &[u8]) -> Result<(i64, u8), ArrowError> {
+pub(crate) fn decode_decimal8(data: &[u8]) -> Result<(i64, u8), VariantError> {
     let scale = u8::from_le_bytes(array_from_slice(data, 0)?);
     let integer = i64::from_le_bytes(array_from_slice(data, 1)?);
     Ok((integer, scale))
 }
 
 /// Decodes a Decimal16 from the value section of a variant.
-pub(crate) fn decode_decimal16(data: &[u8]) -> Result<(i128, u8), ArrowError> {
+pub(crate) fn decode_decimal16(data: &[u8]) -> Result<(i128, u8), VariantError> {
     let scale = u8::from_le_bytes(array_from_slice(data, 0)?);
     let integer = i128::from_le_bytes(array_from_slice(data, 1)?);
     Ok((integer, scale))
 }
 
 /// Decodes a Float from the value section of a variant.
-pub(crate) fn decode_float(data: &[u8]) -> Result<f32, ArrowError> {
+pub(crate) fn decode_float(data: &[u8]) -> Result<f32, VariantError> {
     Ok(f32::from_le_bytes(array_from_slice(data, 0)?))
 }
 
 /// Decodes a Double from the value section of a variant.
-pub(crate) fn decode_double(data: &[u8]) -> Result<f64, ArrowError> {
+pub(crate) fn decode_double(data: &[u8]) -> Result<f64, VariantError> {
     Ok(f64::from_le_bytes(array_from_slice(data, 0)?))
 }
 
 /// Decodes a Date from the value section of a variant.
-pub(crate) fn decode_date(data: &[u8]) -> Result<NaiveDate, ArrowError> {
+pub(crate) fn decode_date(data: &[u8]) -> Result<NaiveDate, VariantError> {
     let days_since_epoch = i32::from_le_bytes(array_from_slice(data, 0)?);
-    let value = DateTime::UNIX_EPOCH + Duration::days(i64::from(days_since_epoch));
-    Ok(value.date_naive())
+    DateTime::UNIX_EPOCH
+        .checked_add_signed(Duration::days(i64::from(days_since_epoch)))
+        .map(|v| v.date_naive())
+        .ok_or(VariantError::OutOfRange {
+            value: days_since_epoch.into(),
+            unit: "days",
+            target: "NaiveDate",
+        })
 }
 
 /// Decodes a TimestampMicros from the value section of a variant.
-pub(crate) fn decode_timestamp_micros(data: &[u8]) -> Result<DateTime<Utc>, ArrowError> {
+pub(crate) fn decode_timestamp_micros(data: &[u8]) -> Result<DateTime<Utc>, VariantError> {
     let micros_since_epoch = i64::from_le_bytes(array_from_slice(data, 0)?);
-    DateTime::from_timestamp_micros(micros_since_epoch).ok_or_else(|| {
-        ArrowError::CastError(format!(
-            "Could not cast `{micros_since_epoch}` microseconds into a DateTime<Utc>"
-        ))
+    DateTime::from_timestamp_micros(micros_since_epoch).ok_or(VariantError::OutOfRange {
+        value: micros_since_epoch,
+        unit: "microseconds",
+        target: "DateTime<Utc>",
     })
 }
 
 /// Decodes a TimestampNtzMicros from the value section of a variant.
-pub(crate) fn decode_timestampntz_micros(data: &[u8]) -> Result<NaiveDateTime, ArrowError> {
+pub(crate) fn decode_timestampntz_micros(data: &[u8]) -> Result<NaiveDateTime, VariantError> {
     let micros_since_epoch = i64::from_le_bytes(array_from_slice(data, 0)?);
     DateTime::from_timestamp_micros(micros_since_epoch)
-        .ok_or_else(|| {
-            ArrowError::CastError(format!(
-                "Could not cast `{micros_since_epoch}` microseconds into a NaiveDateTime"
-            ))
+        .ok_or(VariantError::OutOfRange {
+            value: micros_since_epoch,
+            unit: "microseconds",
+            target: "NaiveDateTime",
         })
         .map(|v| v.naive_utc())
 }
 
 /// Decodes a Binary from the value section of a variant.
-pub(crate) fn decode_binary(data: &[u8]) -> Result<&[u8], ArrowError> {
+pub(crate) fn decode_binary(data: &[u8]) -> Result<&[u8], VariantError> {
     let len = u32::from_le_bytes(array_from_slice(data, 0)?) as usize;
     slice_from_slice_at_offset(data, 4, 0..len)
 }
 
 /// Decodes a long string from the value section of a variant.
-pub(crate) fn decode_long_string(data: &[u8]) -> Result<&str, ArrowError> {
+pub(crate) fn decode_long_string(data: &[u8]) -> Result<&str, VariantError> {
     let len = u32::from_le_bytes(array_from_slice(data, 0)?) as usize;
     string_from_slice(data, 4, 0..len)
 }
 
 /// Decodes a short string from the value section of a variant.
-pub(crate) fn decode_short_string(metadata: u8, data: &[u8]) -> Result<ShortString, ArrowError> {
+pub(crate) fn decode_short_string(
+    metadata: u8,
+    data: &[u8],
+) -> Result<ShortString<'_>, VariantError> {
     let len = (metadata >> 2) as usize;
     let string = string_from_slice(data, 0, 0..len)?;
     ShortString::try_new(string)
@@ -333,7 +343,7 @@ mod tests {
                     // Remove the last byte of data so that there is not enough to decode
                     let truncated_data = &$data[.. $data.len() - 1];
                     let result = $decode_fn(truncated_data);
-                    assert!(matches!(result, Err(ArrowError::InvalidArgumentError(_))));
+                    assert!(matches!(result, Err(VariantError::OutOfBounds { .. })));
                 }
             }
         };
@@ -436,6 +446,12 @@ mod tests {
                 .and_hms_milli_opt(16, 34, 56, 780)
                 .unwrap()
         );
+
+        #[test]
+        fn test_date_out_of_range() {
+            let result = decode_date(&i32::MAX.to_le_bytes());
+            assert!(matches!(result, Err(VariantError::OutOfRange { .. })));
+        }
     }
 
     #[test]
@@ -458,7 +474,7 @@ mod tests {
             0x03, 0x13, 0x37, 0xde, 0xad, 0xbe, 0xef, 0xca,
         ];
         let result = decode_binary(&data);
-        assert!(matches!(result, Err(ArrowError::InvalidArgumentError(_))));
+        assert!(matches!(result, Err(VariantError::OutOfBounds { .. })));
     }
 
     #[test]
@@ -472,7 +488,7 @@ mod tests {
     fn test_short_string_truncated_length() {
         let data = [b'H', b'e', b'l'];
         let result = decode_short_string(1 | 5 << 2, &data);
-        assert!(matches!(result, Err(ArrowError::InvalidArgumentError(_))));
+        assert!(matches!(result, Err(VariantError::OutOfBounds { .. })));
     }
 
     #[test]
@@ -492,7 +508,7 @@ mod tests {
             b'H', b'e', b'l',
         ];
         let result = decode_long_string(&data);
-        assert!(matches!(result, Err(ArrowError::InvalidArgumentError(_))));
+        assert!(matches!(result, Err(VariantError::OutOfBounds { .. })));
     }
 
     #[test]
@@ -0,0 +1,434 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`VariantError`], the error type of this crate
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::Utf8Error;
+
+use arrow_schema::ArrowError;
+
+use crate::variant::MAX_SHORT_STRING_BYTES;
+
+/// The error returned by fallible operations on [`Variant`](crate::Variant) values
+///
+/// Each variant has a stable, machine readable [`code`](Self::code), allowing callers to
+/// map failures to diagnostics without inspecting the error message. Errors raised at a
+/// known location, such as a field of a nested object or a record of a stream, are wrapped
+/// in [`VariantError::Context`], see [`path`](Self::path) and [`row`](Self::row).
+///
+/// [`VariantError`] converts into an [`ArrowError`], preserving the message of the error.
+///
+/// ```
+/// # use parquet_variant::{Variant, VariantError};
+/// let err = Variant::try_new(&[0x01, 0x00, 0x00], &[]).unwrap_err();
+/// assert!(matches!(err, VariantError::EmptyBuffer));
+/// assert_eq!(err.code(), "VARIANT_EMPTY_BUFFER");
+/// assert_eq!(err.to_string(), "Received empty bytes");
+/// ```
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum VariantError {
+    /// Attempted to read `range` from a buffer of `len` bytes
+    OutOfBounds {
+        /// The requested byte range
+        range: String,
+        /// The length of the buffer
+        len: usize,
+    },
+    /// Integer overflow computing an offset or length
+    Overflow(String),
+    /// A buffer that should contain a header byte was empty
+    EmptyBuffer,
+    /// A [`VariantVersion`](crate::VariantVersion) not supported by this crate
+    UnsupportedVersion(u8),
+    /// The version bits of a metadata header are invalid
+    InvalidMetadataVersion(u8),
+    /// A value header referenced an unknown primitive type
+    UnknownPrimitiveType(u8),
+    /// An invalid `offset_size_minus_one` in a header
+    InvalidOffsetSize(u8),
+    /// The first offset of a metadata dictionary or list was not zero
+    FirstOffsetNotZero(usize),
+    /// The offsets of a metadata dictionary were not monotonically increasing
+    NonMonotonicOffsets,
+    /// A metadata dictionary flagged as sorted was not sorted or contained duplicates
+    UnsortedDictionary,
+    /// The field names of an object were not sorted
+    UnsortedFieldNames,
+    /// An object referenced a field id not present in the metadata dictionary
+    InvalidFieldId,
+    /// A string contained invalid UTF-8
+    InvalidUtf8(Utf8Error),
+    /// The scale of a decimal exceeds its maximum precision
+    DecimalScaleTooLarge {
+        /// The requested scale
+        scale: u8,
+        /// The maximum precision of the decimal type
+        max_precision: u8,
+    },
+    /// The unscaled value of a decimal exceeds its maximum precision
+    DecimalOverflow {
+        /// The unscaled value
+        value: String,
+        /// The maximum precision of the decimal type
+        max_precision: u8,
+    },
+    /// A string of `len` bytes is too long to be a short string
+    ShortStringTooLong(usize),
+    /// An object contained duplicate field names
+    DuplicateFields(Vec<String>),
+    /// A date or timestamp was out of the representable range
+    OutOfRange {
+        /// The encoded value
+        value: i64,
+        /// The unit of `value`
+        unit: &'static str,
+        /// The type `value` could not be represented as
+        target: &'static str,
+    },
+    /// A buffer was too large to be written to a variant stream
+    BufferTooLarge(usize),
+    /// A variant stream did not start with the expected magic bytes
+    MissingMagic,
+    /// A variant stream had an unsupported version
+    UnsupportedStreamVersion(u8),
+    /// A variant stream ended part way through a record
+    TruncatedRecord,
+    /// An IO error reading or writing a variant stream
+    Io(std::io::Error),
+    /// An error that occurred at a known location
+    Context {
+        /// The path of the offending value, e.g. `a.b[2]`
+        path: Option<String>,
+        /// The index of the offending row or record
+        row: Option<usize>,
+        /// The underlying error
+        source: Box<VariantError>,
+    },
+}
+
+impl VariantError {
+    /// Returns a stable, machine readable code identifying the kind of this error
+    ///
+    /// For [`VariantError::Context`] this is the code of the underlying error
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::OutOfBounds { .. } => "VARIANT_OUT_OF_BOUNDS",
+            Self::Overflow(_) => "VARIANT_OVERFLOW",
+            Self::EmptyBuffer => "VARIANT_EMPTY_BUFFER",
+            Self::UnsupportedVersion(_) => "VARIANT_UNSUPPORTED_VERSION",
+            Self::InvalidMetadataVersion(_) => "VARIANT_INVALID_METADATA_VERSION",
+            Self::UnknownPrimitiveType(_) => "VARIANT_UNKNOWN_PRIMITIVE_TYPE",
+            Self::InvalidOffsetSize(_) => "VARIANT_INVALID_OFFSET_SIZE",
+            Self::FirstOffsetNotZero(_) => "VARIANT_FIRST_OFFSET_NOT_ZERO",
+            Self::NonMonotonicOffsets => "VARIANT_NON_MONOTONIC_OFFSETS",
+            Self::UnsortedDictionary => "VARIANT_UNSORTED_DICTIONARY",
+            Self::UnsortedFieldNames => "VARIANT_UNSORTED_FIELD_NAMES",
+            Self::InvalidFieldId => "VARIANT_INVALID_FIELD_ID",
+            Self::InvalidUtf8(_) => "VARIANT_INVALID_UTF8",
+            Self::DecimalScaleTooLarge { .. } => "VARIANT_DECIMAL_SCALE_TOO_LARGE",
+            Self::DecimalOverflow { .. } => "VARIANT_DECIMAL_OVERFLOW",
+            Self::ShortStringTooLong(_) => "VARIANT_SHORT_STRING_TOO_LONG",
+            Self::DuplicateFields(_) => "VARIANT_DUPLICATE_FIELDS",
+            Self::OutOfRange { .. } => "VARIANT_OUT_OF_RANGE",
+            Self::BufferTooLarge(_) => "VARIANT_BUFFER_TOO_LARGE",
+            Self::MissingMagic => "VARIANT_STREAM_MISSING_MAGIC",
+            Self::UnsupportedStreamVersion(_) => "VARIANT_STREAM_UNSUPPORTED_VERSION",
+            Self::TruncatedRecord => "VARIANT_STREAM_TRUNCATED_RECORD",
+            Self::Io(_) => "VARIANT_IO",
+            Self::Context { source, .. } => source.code(),
+        }
+    }
+
+    /// Returns the path of the offending value, if known
+    pub fn path(&self) -> Option<&str> {
+        match self {
+            Self::Context { path, .. } => path.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Returns the index of the offending row or record, if known
+    pub fn row(&self) -> Option<usize> {
+        match self {
+            Self::Context { row, .. } => *row,
+            _ => None,
+        }
+    }
+
+    /// Returns the underlying error, skipping any [`VariantError::Context`]
+    pub fn root(&self) -> &VariantError {
+        match self {
+            Self::Context { source, .. } => source.root(),
+            _ => self,
+        }
+    }
+
+    fn into_root(self) -> VariantError {
+        match self {
+            Self::Context { source, .. } => source.into_root(),
+            _ => self,
+        }
+    }
+
+    /// Records that this error occurred within `segment`, a field name or
+    /// list index, of the value at the current path
+    pub fn with_path_segment(self, segment: PathSegment<'_>) -> Self {
+        match self {
+            Self::Context { path, row, source } => {
+                let path = match path {
+                    Some(path) => segment.prepend_to(&path),
+                    None => segment.to_string(),
+                };
+                Self::Context {
+                    path: Some(path),
+                    row,
+                    source,
+                }
+            }
+            err => Self::Context {
+                path: Some(segment.to_string()),
+                row: None,
+                source: Box::new(err),
+            },
+        }
+    }
+
+    /// Records that this error occurred in the row or record at index `row`
+    pub fn with_row(self, row: usize) -> Self {
+        match self {
+            Self::Context { path, source, .. } => Self::Context {
+                path,
+                row: Some(row),
+                source,
+            },
+            err => Self::Context {
+                path: None,
+                row: Some(row),
+                source: Box::new(err),
+            },
+        }
+    }
+}
+
+/// A segment of the path reported by [`VariantError::path`]
+#[derive(Debug, Clone, Copy)]
+pub enum PathSegment<'a> {
+    /// A field of an object
+    Field(&'a str),
+    /// An element of a list
+    Index(usize),
+}
+
+impl PathSegment<'_> {
+    fn prepend_to(&self, path: &str) -> String {
+        match (self, path.starts_with('[')) {
+            (Self::Field(_), false) => format!("{self}.{path}"),
+            _ => format!("{self}{path}"),
+        }
+    }
+}
+
+impl Display for PathSegment<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Field(name) => write!(f, "{name}"),
+            Self::Index(idx) => write!(f, "[{idx}]"),
+        }
+    }
+}
+
+impl Display for VariantError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::OutOfBounds { range, len } => {
+                write!(f, "Tried to extract byte(s) {range} from {len}-byte buffer")
+            }
+            Self::Overflow(msg) => write!(f, "Integer overflow computing {msg}"),
+            Self::EmptyBuffer => write!(f, "Received empty bytes"),
+            Self::UnsupportedVersion(version) => write!(
+                f,
+                "Unsupported variant version {version}, expected at most {}",
+                crate::VariantVersion::CURRENT.as_u8()
+            ),
+            Self::InvalidMetadataVersion(version) => write!(
+                f,
+                "The version bytes in the header is not {}, got {version:b}",
+                crate::VariantVersion::CURRENT.as_u8()
+            ),
+            Self::UnknownPrimitiveType(value) => write!(f, "unknown primitive type: {value}"),
+            Self::InvalidOffsetSize(_) => write!(f, "offset_size_minus_one must be 0–3"),
+            Self::FirstOffsetNotZero(offset) => write!(f, "First offset is not zero: {offset}"),
+            Self::NonMonotonicOffsets => write!(f, "offsets not monotonically increasing"),
+            Self::UnsortedDictionary => {
+                write!(f, "dictionary values are not unique and ordered")
+            }
+            Self::UnsortedFieldNames => write!(f, "field names not sorted"),
+            Self::InvalidFieldId => write!(f, "field id is not valid"),
+            Self::InvalidUtf8(e) => write!(f, "encountered non UTF-8 data: {e}"),
+            Self::DecimalScaleTooLarge {
+                scale,
+                max_precision,
+            } => write!(
+                f,
+                "Scale {scale} is larger than max precision {max_precision}"
+            ),
+            Self::DecimalOverflow {
+                value,
+                max_precision,
+            } => write!(f, "{value} is wider than max precision {max_precision}"),
+            Self::ShortStringTooLong(_) => {
+                write!(f, "value is larger than {MAX_SHORT_STRING_BYTES} bytes")
+            }
+            Self::DuplicateFields(names) => {
+                write!(f, "Duplicate field keys detected: [{}]", names.join(", "))
+            }
+            Self::OutOfRange {
+                value,
+                unit,
+                target,
+            } => write!(f, "Could not cast `{value}` {unit} into a {target}"),
+            Self::BufferTooLarge(len) => write!(
+                f,
+                "Variant buffer of {len} bytes exceeds the maximum of {} bytes",
+                u32::MAX
+            ),
+            Self::MissingMagic => write!(f, "Invalid variant stream: missing magic bytes"),
+            Self::UnsupportedStreamVersion(version) => {
+                write!(f, "Unsupported variant stream version {version}")
+            }
+            Self::TruncatedRecord => write!(f, "Invalid variant stream: truncated record"),
+            Self::Io(e) => write!(f, "{e}"),
+            Self::Context { path, row, source } => {
+                write!(f, "{source}")?;
+                match (row, path) {
+                    (Some(row), Some(path)) => write!(f, " at row {row}, path {path}"),
+                    (Some(row), None) => write!(f, " at row {row}"),
+                    (None, Some(path)) => write!(f, " at path {path}"),
+                    (None, None) => Ok(()),
+                }
+            }
+        }
+    }
+}
+
+impl Error for VariantError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::InvalidUtf8(e) => Some(e),
+            Self::Io(e) => Some(e),
+            Self::Context { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for VariantError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl From<Utf8Error> for VariantError {
+    fn from(error: Utf8Error) -> Self {
+        Self::InvalidUtf8(error)
+    }
+}
+
+impl From<VariantError> for ArrowError {
+    fn from(error: VariantError) -> Self {
+        let message = error.to_string();
+        match error.into_root() {
+            VariantError::Io(e) => ArrowError::IoError(message, e),
+            VariantError::OutOfRange { .. } => ArrowError::CastError(message),
+            VariantError::MissingMagic
+            | VariantError::UnsupportedStreamVersion(_)
+            | VariantError::TruncatedRecord => ArrowError::ParseError(message),
+            _ => ArrowError::InvalidArgumentError(message),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_context() {
+        let err = VariantError::InvalidFieldId
+            .with_path_segment(PathSegment::Index(2))
+            .with_path_segment(PathSegment::Field("b"))
+            .with_path_segment(PathSegment::Field("a"))
+            .with_row(7);
+
+        assert_eq!(err.code(), "VARIANT_INVALID_FIELD_ID");
+        assert_eq!(err.path(), Some("a.b[2]"));
+        assert_eq!(err.row(), Some(7));
+        assert!(matches!(err.root(), VariantError::InvalidFieldId));
+        assert_eq!(
+            err.to_string(),
+            "field id is not valid at row 7, path a.b[2]"
+        );
+
+        let source = err.source().unwrap();
+        assert_eq!(source.to_string(), "field id is not valid");
+        assert!(source.source().is_none());
+    }
+
+    #[test]
+    fn test_validation_path() {
+        let mut builder = crate::VariantBuilder::new();
+        let mut object = builder.new_object();
+        object.insert("b", 1_i8);
+        let mut list = object.new_list("a");
+        list.append_value(3_i8);
+        list.append_value(4_i8);
+        list.finish();
+        object.finish().unwrap();
+        let (metadata, mut value) = builder.finish();
+
+        // Replace the header of the second list element with an unknown primitive type
+        let idx = value.windows(2).position(|w| w == [0x0C, 4]).unwrap();
+        value[idx] = 30 << 2;
+
+        let err = crate::Variant::try_new(&metadata, &value).unwrap_err();
+        assert_eq!(err.code(), "VARIANT_UNKNOWN_PRIMITIVE_TYPE");
+        assert_eq!(err.path(), Some("a[1]"));
+        assert_eq!(err.row(), None);
+        assert_eq!(err.to_string(), "unknown primitive type: 30 at path a[1]");
+    }
+
+    #[test]
+    fn test_into_arrow_error() {
+        let err: ArrowError = VariantError::UnsortedFieldNames.with_row(1).into();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: field names not sorted at row 1"
+        );
+
+        let err: ArrowError = VariantError::TruncatedRecord.into();
+        assert!(matches!(err, ArrowError::ParseError(_)));
+
+        let io = std::io::Error::other("disk full");
+        let err = VariantError::from(io);
+        assert_eq!(err.code(), "VARIANT_IO");
+        let err: ArrowError = err.into();
+        assert!(matches!(err, ArrowError::IoError(_, _)));
+    }
+}
@@ -27,10 +27,18 @@
 //!
 //! [Variant issue]: https://github.com/apache/arrow-rs/issues/6736
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
 mod builder;
 mod decoder;
+mod error;
+mod stream;
 mod utils;
 mod variant;
 
+#[cfg(feature = "arbitrary")]
+pub use arbitrary::{ArbitraryVariant, MalformedVariant};
 pub use builder::*;
+pub use error::*;
+pub use stream::*;
 pub use variant::*;
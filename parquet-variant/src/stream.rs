@@ -0,0 +1,491 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Simple framing format for streaming [`Variant`] values to and from byte streams
+//!
+//! This is intended for spooling variant rows to disk or sending them over a
+//! socket, outside of Arrow IPC or Parquet. The format is:
+//!
+//! ```text
+//! ┌───────────┬──────────┬──────────┬─────┬──────────┐
+//! │ "PVS" 0x01│ record 0 │ record 1 │ ... │ record N │
+//! └───────────┴──────────┴──────────┴─────┴──────────┘
+//!
+//! record:
+//! ┌──────────────────┬──────────────────┬──────────┬───────┐
+//! │ metadata len u32 │ value len u32    │ metadata │ value │
+//! └──────────────────┴──────────────────┴──────────┴───────┘
+//! ```
+//!
+//! Lengths are little-endian, and the stream ends cleanly at a record boundary.
+
+use crate::{Variant, VariantBuilder, VariantError};
+use std::io::{Read, Write};
+
+/// Magic bytes at the start of a variant stream, the last byte is the format version
+const MAGIC: [u8; 4] = *b"PVS\x01";
+
+/// Size of the length prefix of each record
+const RECORD_HEADER_LEN: usize = 8;
+
+/// The metadata and value buffers of a record
+type RecordBuffers<'a> = (&'a [u8], &'a [u8]);
+
+fn encode_record_header(
+    metadata: &[u8],
+    value: &[u8],
+) -> Result<[u8; RECORD_HEADER_LEN], VariantError> {
+    let to_u32 = |len: usize| u32::try_from(len).map_err(|_| VariantError::BufferTooLarge(len));
+    let mut header = [0; RECORD_HEADER_LEN];
+    header[..4].copy_from_slice(&to_u32(metadata.len())?.to_le_bytes());
+    header[4..].copy_from_slice(&to_u32(value.len())?.to_le_bytes());
+    Ok(header)
+}
+
+fn decode_record_header(header: [u8; RECORD_HEADER_LEN]) -> (usize, usize) {
+    let metadata_len = u32::from_le_bytes(header[..4].try_into().unwrap());
+    let value_len = u32::from_le_bytes(header[4..].try_into().unwrap());
+    (metadata_len as usize, value_len as usize)
+}
+
+fn validate_magic(magic: [u8; 4]) -> Result<(), VariantError> {
+    if magic[..3] != MAGIC[..3] {
+        return Err(VariantError::MissingMagic);
+    }
+    if magic[3] != MAGIC[3] {
+        return Err(VariantError::UnsupportedStreamVersion(magic[3]));
+    }
+    Ok(())
+}
+
+/// Encodes `variant` to a new pair of metadata and value buffers
+fn encode_variant(variant: &Variant) -> (Vec<u8>, Vec<u8>) {
+    let mut builder = VariantBuilder::new();
+    builder.append_value(variant.clone());
+    builder.finish()
+}
+
+/// Writes [`Variant`] values to a [`Write`] using the [framing format](self)
+///
+/// ```
+/// # use parquet_variant::{Variant, VariantStreamReader, VariantStreamWriter};
+/// let mut writer = VariantStreamWriter::try_new(Vec::new()).unwrap();
+/// writer.write_variant(&Variant::from(42)).unwrap();
+/// writer.write_variant(&Variant::from("hello")).unwrap();
+/// let buffer = writer.into_inner();
+///
+/// let mut reader = VariantStreamReader::try_new(buffer.as_slice()).unwrap();
+/// assert_eq!(reader.next_variant().unwrap(), Some(Variant::from(42)));
+/// assert_eq!(reader.next_variant().unwrap(), Some(Variant::from("hello")));
+/// assert_eq!(reader.next_variant().unwrap(), None);
+/// ```
+#[derive(Debug)]
+pub struct VariantStreamWriter<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> VariantStreamWriter<W> {
+    /// Create a new [`VariantStreamWriter`], writing the stream header to `writer`
+    pub fn try_new(mut writer: W) -> Result<Self, VariantError> {
+        writer.write_all(&MAGIC)?;
+        Ok(Self { writer })
+    }
+
+    /// Write a [`Variant`] as the next record
+    pub fn write_variant(&mut self, variant: &Variant) -> Result<(), VariantError> {
+        let (metadata, value) = encode_variant(variant);
+        self.write_buffers(&metadata, &value)
+    }
+
+    /// Write pre-encoded metadata and value buffers as the next record
+    ///
+    /// The buffers are not validated
+    pub fn write_buffers(&mut self, metadata: &[u8], value: &[u8]) -> Result<(), VariantError> {
+        let header = encode_record_header(metadata, value)?;
+        self.writer.write_all(&header)?;
+        self.writer.write_all(metadata)?;
+        self.writer.write_all(value)?;
+        Ok(())
+    }
+
+    /// Flush the underlying writer
+    pub fn flush(&mut self) -> Result<(), VariantError> {
+        Ok(self.writer.flush()?)
+    }
+
+    /// Return a reference to the underlying writer
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Return the underlying writer
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+/// Reads [`Variant`] values written by a [`VariantStreamWriter`] from a [`Read`]
+///
+/// The buffers of each record are reused for the next, and so the returned
+/// [`Variant`] borrows from the reader.
+#[derive(Debug)]
+pub struct VariantStreamReader<R: Read> {
+    reader: R,
+    metadata: Vec<u8>,
+    value: Vec<u8>,
+    /// The index of the next record
+    record: usize,
+}
+
+impl<R: Read> VariantStreamReader<R> {
+    /// Create a new [`VariantStreamReader`], reading and validating the stream header
+    pub fn try_new(mut reader: R) -> Result<Self, VariantError> {
+        let mut magic = [0; 4];
+        reader.read_exact(&mut magic)?;
+        validate_magic(magic)?;
+        Ok(Self {
+            reader,
+            metadata: vec![],
+            value: vec![],
+            record: 0,
+        })
+    }
+
+    /// Read the metadata and value buffers of the next record, returning `None`
+    /// at the end of the stream
+    ///
+    /// The buffers are not validated
+    pub fn next_buffers(&mut self) -> Result<Option<RecordBuffers<'_>>, VariantError> {
+        let mut header = [0; RECORD_HEADER_LEN];
+        let mut read = 0;
+        while read < RECORD_HEADER_LEN {
+            match self.reader.read(&mut header[read..]) {
+                Ok(0) if read == 0 => return Ok(None),
+                Ok(0) => return Err(VariantError::TruncatedRecord),
+                Ok(n) => read += n,
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e.into()),
+            }
+        }
+
+        let (metadata_len, value_len) = decode_record_header(header);
+        read_record_buffer(&mut self.reader, &mut self.metadata, metadata_len)?;
+        read_record_buffer(&mut self.reader, &mut self.value, value_len)?;
+        self.record += 1;
+        Ok(Some((&self.metadata, &self.value)))
+    }
+
+    /// Read and validate the next [`Variant`], returning `None` at the end of the stream
+    ///
+    /// Validation errors report the index of the offending record as [`VariantError::row`]
+    pub fn next_variant(&mut self) -> Result<Option<Variant<'_, '_>>, VariantError> {
+        let record = self.record;
+        match self.next_buffers()? {
+            Some((metadata, value)) => Variant::try_new(metadata, value)
+                .map(Some)
+                .map_err(|e| e.with_row(record)),
+            None => Ok(None),
+        }
+    }
+
+    /// Return the underlying reader
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+/// Reads `len` bytes into `buf`
+///
+/// `len` comes from the record header and so is untrusted, the buffer therefore only
+/// grows with the bytes actually read, rather than being allocated upfront
+fn read_record_buffer<R: Read>(
+    reader: &mut R,
+    buf: &mut Vec<u8>,
+    len: usize,
+) -> Result<(), VariantError> {
+    buf.clear();
+    match reader.take(len as u64).read_to_end(buf)? {
+        read if read == len => Ok(()),
+        _ => Err(VariantError::TruncatedRecord),
+    }
+}
+
+#[cfg(feature = "async")]
+mod r#async {
+    use super::*;
+    use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+    /// Asynchronously writes [`Variant`] values to an [`AsyncWrite`]
+    ///
+    /// See [`VariantStreamWriter`] for the synchronous version
+    #[derive(Debug)]
+    pub struct AsyncVariantStreamWriter<W: AsyncWrite + Unpin> {
+        writer: W,
+    }
+
+    impl<W: AsyncWrite + Unpin> AsyncVariantStreamWriter<W> {
+        /// Create a new [`AsyncVariantStreamWriter`], writing the stream header to `writer`
+        pub async fn try_new(mut writer: W) -> Result<Self, VariantError> {
+            writer.write_all(&MAGIC).await?;
+            Ok(Self { writer })
+        }
+
+        /// Write a [`Variant`] as the next record
+        pub async fn write_variant(
+            &mut self,
+            variant: &Variant<'_, '_>,
+        ) -> Result<(), VariantError> {
+            let (metadata, value) = encode_variant(variant);
+            self.write_buffers(&metadata, &value).await
+        }
+
+        /// Write pre-encoded metadata and value buffers as the next record
+        ///
+        /// The buffers are not validated
+        pub async fn write_buffers(
+            &mut self,
+            metadata: &[u8],
+            value: &[u8],
+        ) -> Result<(), VariantError> {
+            let header = encode_record_header(metadata, value)?;
+            self.writer.write_all(&header).await?;
+            self.writer.write_all(metadata).await?;
+            self.writer.write_all(value).await?;
+            Ok(())
+        }
+
+        /// Flush the underlying writer
+        pub async fn flush(&mut self) -> Result<(), VariantError> {
+            Ok(self.writer.flush().await?)
+        }
+
+        /// Return the underlying writer
+        pub fn into_inner(self) -> W {
+            self.writer
+        }
+    }
+
+    /// Asynchronously reads [`Variant`] values from an [`AsyncRead`]
+    ///
+    /// See [`VariantStreamReader`] for the synchronous version
+    #[derive(Debug)]
+    pub struct AsyncVariantStreamReader<R: AsyncRead + Unpin> {
+        reader: R,
+        metadata: Vec<u8>,
+        value: Vec<u8>,
+        /// The index of the next record
+        record: usize,
+    }
+
+    impl<R: AsyncRead + Unpin> AsyncVariantStreamReader<R> {
+        /// Create a new [`AsyncVariantStreamReader`], reading and validating the stream header
+        pub async fn try_new(mut reader: R) -> Result<Self, VariantError> {
+            let mut magic = [0; 4];
+            reader.read_exact(&mut magic).await?;
+            validate_magic(magic)?;
+            Ok(Self {
+                reader,
+                metadata: vec![],
+                value: vec![],
+                record: 0,
+            })
+        }
+
+        /// Read the metadata and value buffers of the next record, returning `None`
+        /// at the end of the stream
+        pub async fn next_buffers(&mut self) -> Result<Option<RecordBuffers<'_>>, VariantError> {
+            let mut header = [0; RECORD_HEADER_LEN];
+            let mut read = 0;
+            while read < RECORD_HEADER_LEN {
+                match self.reader.read(&mut header[read..]).await? {
+                    0 if read == 0 => return Ok(None),
+                    0 => return Err(VariantError::TruncatedRecord),
+                    n => read += n,
+                }
+            }
+
+            // As for the sync reader, only grow the buffers with the bytes actually read
+            let (metadata_len, value_len) = decode_record_header(header);
+            for (buf, len) in [
+                (&mut self.metadata, metadata_len),
+                (&mut self.value, value_len),
+            ] {
+                buf.clear();
+                let read = (&mut self.reader).take(len as u64).read_to_end(buf).await?;
+                if read != len {
+                    return Err(VariantError::TruncatedRecord);
+                }
+            }
+            self.record += 1;
+            Ok(Some((&self.metadata, &self.value)))
+        }
+
+        /// Read and validate the next [`Variant`], returning `None` at the end of the stream
+        ///
+        /// Validation errors report the index of the offending record as [`VariantError::row`]
+        pub async fn next_variant(&mut self) -> Result<Option<Variant<'_, '_>>, VariantError> {
+            let record = self.record;
+            match self.next_buffers().await? {
+                Some((metadata, value)) => Variant::try_new(metadata, value)
+                    .map(Some)
+                    .map_err(|e| e.with_row(record)),
+                None => Ok(None),
+            }
+        }
+
+        /// Return the underlying reader
+        pub fn into_inner(self) -> R {
+            self.reader
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+pub use r#async::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn object_buffers() -> (Vec<u8>, Vec<u8>) {
+        let mut builder = VariantBuilder::new();
+        let mut obj = builder.new_object();
+        obj.insert("a", 1i32);
+        obj.insert("b", "two");
+        obj.finish().unwrap();
+        builder.finish()
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let (metadata, value) = object_buffers();
+
+        let mut writer = VariantStreamWriter::try_new(vec![]).unwrap();
+        writer.write_variant(&Variant::from(42i64)).unwrap();
+        writer.write_buffers(&metadata, &value).unwrap();
+        writer.write_variant(&Variant::Null).unwrap();
+        writer.flush().unwrap();
+        let buffer = writer.into_inner();
+
+        let mut reader = VariantStreamReader::try_new(buffer.as_slice()).unwrap();
+        assert_eq!(reader.next_variant().unwrap(), Some(Variant::from(42i64)));
+        let v = reader.next_variant().unwrap().unwrap();
+        assert_eq!(v, Variant::try_new(&metadata, &value).unwrap());
+        assert_eq!(reader.next_variant().unwrap(), Some(Variant::Null));
+        assert_eq!(reader.next_variant().unwrap(), None);
+        assert_eq!(reader.next_variant().unwrap(), None);
+    }
+
+    #[test]
+    fn test_empty_stream() {
+        let buffer = VariantStreamWriter::try_new(vec![]).unwrap().into_inner();
+        assert_eq!(buffer, MAGIC);
+        let mut reader = VariantStreamReader::try_new(buffer.as_slice()).unwrap();
+        assert_eq!(reader.next_buffers().unwrap(), None);
+    }
+
+    #[test]
+    fn test_invalid_header() {
+        let err = VariantStreamReader::try_new(b"ABCD".as_slice()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid variant stream: missing magic bytes"
+        );
+
+        let err = VariantStreamReader::try_new(b"PVS\x09".as_slice()).unwrap_err();
+        assert_eq!(err.to_string(), "Unsupported variant stream version 9");
+    }
+
+    #[test]
+    fn test_invalid_record() {
+        let (metadata, value) = object_buffers();
+        let mut writer = VariantStreamWriter::try_new(vec![]).unwrap();
+        writer.write_buffers(&metadata, &value).unwrap();
+        writer.write_buffers(&metadata, &[]).unwrap();
+        let buffer = writer.into_inner();
+
+        let mut reader = VariantStreamReader::try_new(buffer.as_slice()).unwrap();
+        reader.next_variant().unwrap().unwrap();
+        let err = reader.next_variant().unwrap_err();
+        assert_eq!(err.row(), Some(1));
+        assert!(matches!(err.root(), VariantError::EmptyBuffer));
+        assert_eq!(err.to_string(), "Received empty bytes at row 1");
+    }
+
+    #[test]
+    fn test_truncated() {
+        let (metadata, value) = object_buffers();
+        let mut writer = VariantStreamWriter::try_new(vec![]).unwrap();
+        writer.write_buffers(&metadata, &value).unwrap();
+        let buffer = writer.into_inner();
+
+        // truncate within the record header and within the record buffers
+        for len in [MAGIC.len() + 3, buffer.len() - 1] {
+            let mut reader = VariantStreamReader::try_new(&buffer[..len]).unwrap();
+            let err = reader.next_buffers().unwrap_err();
+            assert_eq!(err.to_string(), "Invalid variant stream: truncated record");
+        }
+    }
+
+    #[test]
+    fn test_truncated_large_record() {
+        // A corrupt header claiming ~8 GiB of buffers must not allocate them upfront
+        let mut buffer = MAGIC.to_vec();
+        buffer.extend_from_slice(&u32::MAX.to_le_bytes());
+        buffer.extend_from_slice(&u32::MAX.to_le_bytes());
+        buffer.extend_from_slice(&[1, 2, 3]);
+
+        let mut reader = VariantStreamReader::try_new(buffer.as_slice()).unwrap();
+        let err = reader.next_buffers().unwrap_err();
+        assert!(matches!(err, VariantError::TruncatedRecord));
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_async_round_trip() {
+        let (metadata, value) = object_buffers();
+
+        let mut writer = AsyncVariantStreamWriter::try_new(vec![]).await.unwrap();
+        writer.write_variant(&Variant::from(true)).await.unwrap();
+        writer.write_buffers(&metadata, &value).await.unwrap();
+        writer.flush().await.unwrap();
+        let buffer = writer.into_inner();
+
+        // The sync and async formats are identical
+        let mut reader = VariantStreamReader::try_new(buffer.as_slice()).unwrap();
+        assert_eq!(reader.next_variant().unwrap(), Some(Variant::from(true)));
+
+        let mut reader = AsyncVariantStreamReader::try_new(buffer.as_slice())
+            .await
+            .unwrap();
+        assert_eq!(
+            reader.next_variant().await.unwrap(),
+            Some(Variant::from(true))
+        );
+        let v = reader.next_variant().await.unwrap().unwrap();
+        assert_eq!(v, Variant::try_new(&metadata, &value).unwrap());
+        assert_eq!(reader.next_variant().await.unwrap(), None);
+
+        let mut buffer = MAGIC.to_vec();
+        buffer.extend_from_slice(&[0xFF; RECORD_HEADER_LEN]);
+        let mut reader = AsyncVariantStreamReader::try_new(buffer.as_slice())
+            .await
+            .unwrap();
+        let err = reader.next_buffers().await.unwrap_err();
+        assert!(matches!(err, VariantError::TruncatedRecord));
+    }
+}
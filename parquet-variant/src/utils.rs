@@ -14,29 +14,29 @@
 // KIND, either express or implied.  See the License for the
 // specific language governing permissions and limitations
 // under the License.
-use std::{array::TryFromSliceError, ops::Range, str};
+use std::ops::Range;
 
-use arrow_schema::ArrowError;
+use crate::VariantError;
 
 use std::fmt::Debug;
 use std::slice::SliceIndex;
 
 /// Helper for reporting integer overflow errors in a consistent way.
-pub(crate) fn overflow_error(msg: &str) -> ArrowError {
-    ArrowError::InvalidArgumentError(format!("Integer overflow computing {msg}"))
+pub(crate) fn overflow_error(msg: &str) -> VariantError {
+    VariantError::Overflow(msg.to_string())
 }
 
 #[inline]
 pub(crate) fn slice_from_slice<I: SliceIndex<[u8]> + Clone + Debug>(
     bytes: &[u8],
     index: I,
-) -> Result<&I::Output, ArrowError> {
-    bytes.get(index.clone()).ok_or_else(|| {
-        ArrowError::InvalidArgumentError(format!(
-            "Tried to extract byte(s) {index:?} from {}-byte buffer",
-            bytes.len(),
-        ))
-    })
+) -> Result<&I::Output, VariantError> {
+    bytes
+        .get(index.clone())
+        .ok_or_else(|| VariantError::OutOfBounds {
+            range: format!("{index:?}"),
+            len: bytes.len(),
+        })
 }
 
 /// Helper to safely slice bytes with offset calculations.
@@ -48,7 +48,7 @@ pub(crate) fn slice_from_slice_at_offset(
     bytes: &[u8],
     base_offset: usize,
     range: Range<usize>,
-) -> Result<&[u8], ArrowError> {
+) -> Result<&[u8], VariantError> {
     let start_byte = base_offset
         .checked_add(range.start)
         .ok_or_else(|| overflow_error("slice start"))?;
@@ -61,17 +61,13 @@ pub(crate) fn slice_from_slice_at_offset(
 pub(crate) fn array_from_slice<const N: usize>(
     bytes: &[u8],
     offset: usize,
-) -> Result<[u8; N], ArrowError> {
-    slice_from_slice_at_offset(bytes, offset, 0..N)?
-        .try_into()
-        .map_err(|e: TryFromSliceError| ArrowError::InvalidArgumentError(e.to_string()))
+) -> Result<[u8; N], VariantError> {
+    let slice = slice_from_slice_at_offset(bytes, offset, 0..N)?;
+    Ok(slice.try_into().expect("slice of length N"))
 }
 
-pub(crate) fn first_byte_from_slice(slice: &[u8]) -> Result<u8, ArrowError> {
-    slice
-        .first()
-        .copied()
-        .ok_or_else(|| ArrowError::InvalidArgumentError("Received empty bytes".to_string()))
+pub(crate) fn first_byte_from_slice(slice: &[u8]) -> Result<u8, VariantError> {
+    slice.first().copied().ok_or(VariantError::EmptyBuffer)
 }
 
 /// Helper to get a &str from a slice at the given offset and range, or an error if it contains invalid UTF-8 data.
@@ -80,23 +76,9 @@ pub(crate) fn string_from_slice(
     slice: &[u8],
     offset: usize,
     range: Range<usize>,
-) -> Result<&str, ArrowError> {
+) -> Result<&str, VariantError> {
     let offset_buffer = slice_from_slice_at_offset(slice, offset, range)?;
-
-    //Use simdutf8 by default
-    #[cfg(feature = "simdutf8")]
-    {
-        simdutf8::basic::from_utf8(offset_buffer).map_err(|_| {
-            // Use simdutf8::compat to return details about the decoding error
-            let e = simdutf8::compat::from_utf8(offset_buffer).unwrap_err();
-            ArrowError::InvalidArgumentError(format!("encountered non UTF-8 data: {e}"))
-        })
-    }
-
-    //Use std::str if simdutf8 is not enabled
-    #[cfg(not(feature = "simdutf8"))]
-    str::from_utf8(offset_buffer)
-        .map_err(|_| ArrowError::InvalidArgumentError("invalid UTF-8 string".to_string()))
+    Ok(arrow_buffer::utf8::from_utf8(offset_buffer)?)
 }
 
 /// Performs a binary search over a range using a fallible key extraction function; a failed key
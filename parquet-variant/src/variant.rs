@@ -17,15 +17,16 @@
 
 pub use self::decimal::{VariantDecimal16, VariantDecimal4, VariantDecimal8};
 pub use self::list::VariantList;
-pub use self::metadata::VariantMetadata;
+pub use self::metadata::{VariantMetadata, VariantVersion};
 pub use self::object::VariantObject;
 use crate::decoder::{
-    self, get_basic_type, get_primitive_type, VariantBasicType, VariantPrimitiveType,
+    self, get_basic_type, get_primitive_type, is_unknown_primitive, VariantBasicType,
+    VariantPrimitiveType,
 };
 use crate::utils::{first_byte_from_slice, slice_from_slice};
 use std::ops::Deref;
 
-use arrow_schema::ArrowError;
+use crate::VariantError;
 use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
 
 mod decimal;
@@ -33,7 +34,7 @@ mod list;
 mod metadata;
 mod object;
 
-const MAX_SHORT_STRING_BYTES: usize = 0x3F;
+pub(crate) const MAX_SHORT_STRING_BYTES: usize = 0x3F;
 
 /// A Variant [`ShortString`]
 ///
@@ -49,11 +50,9 @@ impl<'a> ShortString<'a> {
     ///
     /// Returns an error if  `value` is longer than the maximum allowed length
     /// of a Variant short string (63 bytes).
-    pub fn try_new(value: &'a str) -> Result<Self, ArrowError> {
+    pub fn try_new(value: &'a str) -> Result<Self, VariantError> {
         if value.len() > MAX_SHORT_STRING_BYTES {
-            return Err(ArrowError::InvalidArgumentError(format!(
-                "value is larger than {MAX_SHORT_STRING_BYTES} bytes"
-            )));
+            return Err(VariantError::ShortStringTooLong(value.len()));
         }
 
         Ok(Self(value))
@@ -72,7 +71,7 @@ impl<'a> From<ShortString<'a>> for &'a str {
 }
 
 impl<'a> TryFrom<&'a str> for ShortString<'a> {
-    type Error = ArrowError;
+    type Error = VariantError;
 
     fn try_from(value: &'a str) -> Result<Self, Self::Error> {
         Self::try_new(value)
@@ -93,6 +92,34 @@ impl Deref for ShortString<'_> {
     }
 }
 
+/// A Variant primitive value of a type unknown to this implementation
+///
+/// Such values are only produced in the lenient [forward compatibility] mode,
+/// see [`Variant::try_new_lenient`]. The encoded bytes are preserved, so the
+/// value can be written unchanged by a [`VariantBuilder`].
+///
+/// [forward compatibility]: VariantMetadata#forward-compatibility
+/// [`VariantBuilder`]: crate::VariantBuilder
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VariantOpaque<'v>(&'v [u8]);
+
+impl<'v> VariantOpaque<'v> {
+    /// Returns the primitive type id stored in the value header
+    pub fn type_id(&self) -> u8 {
+        self.0[0] >> 2
+    }
+
+    /// Returns the encoded bytes following the value header
+    pub fn value(&self) -> &'v [u8] {
+        &self.0[1..]
+    }
+
+    /// Returns the encoded bytes, including the value header
+    pub fn as_bytes(&self) -> &'v [u8] {
+        self.0
+    }
+}
+
 /// Represents a [Parquet Variant]
 ///
 /// The lifetimes `'m` and `'v` are for metadata and value buffers, respectively.
@@ -210,7 +237,14 @@ impl Deref for ShortString<'_> {
 /// [metadata]: VariantMetadata#Validation
 /// [object]: VariantObject#Validation
 /// [array]: VariantList#Validation
+///
+/// # Forward compatibility
+///
+/// This enum is `#[non_exhaustive]`, as variants are added for new types of the
+/// specification, such as [`Variant::Opaque`], and so matches outside this crate
+/// must include a wildcard arm.
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub enum Variant<'m, 'v> {
     /// Primitive type: Null
     Null,
@@ -254,6 +288,10 @@ pub enum Variant<'m, 'v> {
     Object(VariantObject<'m, 'v>),
     /// Array (type_id=4): N/A
     List(VariantList<'m, 'v>),
+    /// Primitive of an unknown type, only produced in the lenient [forward compatibility] mode
+    ///
+    /// [forward compatibility]: VariantMetadata#forward-compatibility
+    Opaque(VariantOpaque<'v>),
 }
 
 // We don't want this to grow because it could hurt performance of a frequently-created type.
@@ -277,11 +315,38 @@ impl<'m, 'v> Variant<'m, 'v> {
     /// ```
     ///
     /// [validated]: Self#Validation
-    pub fn try_new(metadata: &'m [u8], value: &'v [u8]) -> Result<Self, ArrowError> {
+    pub fn try_new(metadata: &'m [u8], value: &'v [u8]) -> Result<Self, VariantError> {
         let metadata = VariantMetadata::try_new(metadata)?;
         Self::try_new_with_metadata(metadata, value)
     }
 
+    /// Similar to [`Self::try_new`], but in the lenient [forward compatibility] mode
+    ///
+    /// Metadata of any version is accepted, and values of unknown primitive types,
+    /// including those nested within objects and lists, are returned as
+    /// [`Variant::Opaque`] instead of an error.
+    ///
+    /// # Example
+    /// ```
+    /// # use parquet_variant::Variant;
+    /// let metadata = [0x01, 0x00, 0x00];
+    /// // A primitive with the (currently unused) type id 63
+    /// let value = [63 << 2, 0xAB];
+    /// assert!(Variant::try_new(&metadata, &value).is_err());
+    ///
+    /// let Variant::Opaque(opaque) = Variant::try_new_lenient(&metadata, &value).unwrap() else {
+    ///     panic!("expected opaque value")
+    /// };
+    /// assert_eq!(opaque.type_id(), 63);
+    /// assert_eq!(opaque.value(), &[0xAB]);
+    /// ```
+    ///
+    /// [forward compatibility]: VariantMetadata#forward-compatibility
+    pub fn try_new_lenient(metadata: &'m [u8], value: &'v [u8]) -> Result<Self, VariantError> {
+        let metadata = VariantMetadata::try_new_lenient(metadata)?;
+        Self::try_new_with_metadata(metadata, value)
+    }
+
     /// Attempts to interpret a metadata and value buffer pair as a new `Variant`.
     ///
     /// The instance is [unvalidated].
@@ -327,7 +392,7 @@ impl<'m, 'v> Variant<'m, 'v> {
     pub fn try_new_with_metadata(
         metadata: VariantMetadata<'m>,
         value: &'v [u8],
-    ) -> Result<Self, ArrowError> {
+    ) -> Result<Self, VariantError> {
         Self::try_new_with_metadata_and_shallow_validation(metadata, value)?.with_full_validation()
     }
 
@@ -343,9 +408,12 @@ impl<'m, 'v> Variant<'m, 'v> {
     fn try_new_with_metadata_and_shallow_validation(
         metadata: VariantMetadata<'m>,
         value: &'v [u8],
-    ) -> Result<Self, ArrowError> {
+    ) -> Result<Self, VariantError> {
         let value_metadata = first_byte_from_slice(value)?;
         let value_data = slice_from_slice(value, 1..)?;
+        if metadata.is_lenient() && is_unknown_primitive(value_metadata) {
+            return Ok(Variant::Opaque(VariantOpaque(value)));
+        }
         let new_self = match get_basic_type(value_metadata) {
             VariantBasicType::Primitive => match get_primitive_type(value_metadata)? {
                 VariantPrimitiveType::Null => Variant::Null,
@@ -420,7 +488,7 @@ impl<'m, 'v> Variant<'m, 'v> {
     ///
     /// [objects]: VariantObject#Validation
     /// [arrays]: VariantList#Validation
-    pub fn with_full_validation(self) -> Result<Self, ArrowError> {
+    pub fn with_full_validation(self) -> Result<Self, VariantError> {
         use Variant::*;
         match self {
             List(list) => list.with_full_validation().map(List),
@@ -1168,7 +1236,7 @@ impl<'v> From<&'v str> for Variant<'_, 'v> {
 }
 
 impl TryFrom<(i32, u8)> for Variant<'_, '_> {
-    type Error = ArrowError;
+    type Error = VariantError;
 
     fn try_from(value: (i32, u8)) -> Result<Self, Self::Error> {
         Ok(Variant::Decimal4(VariantDecimal4::try_new(
@@ -1178,7 +1246,7 @@ impl TryFrom<(i32, u8)> for Variant<'_, '_> {
 }
 
 impl TryFrom<(i64, u8)> for Variant<'_, '_> {
-    type Error = ArrowError;
+    type Error = VariantError;
 
     fn try_from(value: (i64, u8)) -> Result<Self, Self::Error> {
         Ok(Variant::Decimal8(VariantDecimal8::try_new(
@@ -1188,7 +1256,7 @@ impl TryFrom<(i64, u8)> for Variant<'_, '_> {
 }
 
 impl TryFrom<(i128, u8)> for Variant<'_, '_> {
-    type Error = ArrowError;
+    type Error = VariantError;
 
     fn try_from(value: (i128, u8)) -> Result<Self, Self::Error> {
         Ok(Variant::Decimal16(VariantDecimal16::try_new(
@@ -1208,9 +1276,7 @@ mod tests {
 
         let err = Variant::try_new_with_metadata(metadata, &[]).unwrap_err();
 
-        assert!(matches!(
-            err,
-            ArrowError::InvalidArgumentError(ref msg) if msg == "Received empty bytes"));
+        assert!(matches!(err, VariantError::EmptyBuffer));
     }
 
     #[test]
@@ -1237,4 +1303,53 @@ mod tests {
         let variant = Variant::from(decimal16);
         assert_eq!(variant.as_decimal16(), Some(decimal16));
     }
+
+    #[test]
+    fn test_lenient_opaque_nested() {
+        // A primitive with the unused type id 63, followed by an Int8
+        let opaque = [63 << 2, 0xAB];
+        let int8 = [(VariantPrimitiveType::Int8 as u8) << 2, 7];
+        let metadata = [0x02, 0x02, 0x00, 0x01, 0x02, b'a', b'b'];
+
+        // {"a": opaque, "b": 7}
+        let mut object = vec![0x02, 0x02, 0x00, 0x01, 0x00, 0x02, 0x04];
+        object.extend(opaque);
+        object.extend(int8);
+        // [opaque, 7]
+        let mut list = vec![0x03, 0x02, 0x00, 0x02, 0x04];
+        list.extend(opaque);
+        list.extend(int8);
+
+        for value in [&object, &list] {
+            Variant::try_new(&metadata, value).unwrap_err();
+            Variant::try_new_lenient(&metadata[..1], value).unwrap_err();
+        }
+
+        let variant = Variant::try_new_lenient(&metadata, &object).unwrap();
+        let object = variant.as_object().unwrap();
+        let Some(Variant::Opaque(a)) = object.get("a") else {
+            panic!("expected opaque value")
+        };
+        assert_eq!(a.type_id(), 63);
+        assert_eq!(a.as_bytes(), opaque);
+        assert_eq!(object.get("b"), Some(Variant::Int8(7)));
+
+        let variant = Variant::try_new_lenient(&metadata, &list).unwrap();
+        let list = variant.as_list().unwrap();
+        assert_eq!(list.get(0), Some(Variant::Opaque(a)));
+        assert_eq!(list.get(1), Some(Variant::Int8(7)));
+
+        // Opaque values are written unchanged
+        let mut builder = crate::VariantBuilder::new().with_version(VariantVersion::V1);
+        let mut obj = builder.new_object();
+        for (name, value) in object.iter() {
+            obj.insert(name, value);
+        }
+        obj.finish().unwrap();
+        let (metadata, value) = builder.finish();
+        assert_eq!(metadata[0] & 0x0F, 1);
+        let variant = Variant::try_new_lenient(&metadata, &value).unwrap();
+        assert_eq!(variant.get_object_field("a"), Some(Variant::Opaque(a)));
+        assert_eq!(variant.get_object_field("b"), Some(Variant::Int8(7)));
+    }
 }
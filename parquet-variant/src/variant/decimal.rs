@@ -14,7 +14,7 @@
 // KIND, either express or implied.  See the License for the
 // specific language governing permissions and limitations
 // under the License.
-use arrow_schema::ArrowError;
+use crate::VariantError;
 use std::fmt;
 
 // All decimal types use the same try_new implementation
@@ -22,20 +22,18 @@ macro_rules! decimal_try_new {
     ($integer:ident, $scale:ident) => {{
         // Validate that scale doesn't exceed precision
         if $scale > Self::MAX_PRECISION {
-            return Err(ArrowError::InvalidArgumentError(format!(
-                "Scale {} is larger than max precision {}",
-                $scale,
-                Self::MAX_PRECISION,
-            )));
+            return Err(VariantError::DecimalScaleTooLarge {
+                scale: $scale,
+                max_precision: Self::MAX_PRECISION,
+            });
         }
 
         // Validate that the integer value fits within the precision
         if $integer.unsigned_abs() > Self::MAX_UNSCALED_VALUE {
-            return Err(ArrowError::InvalidArgumentError(format!(
-                "{} is wider than max precision {}",
-                $integer,
-                Self::MAX_PRECISION
-            )));
+            return Err(VariantError::DecimalOverflow {
+                value: $integer.to_string(),
+                max_precision: Self::MAX_PRECISION,
+            });
         }
 
         Ok(Self { $integer, $scale })
@@ -89,7 +87,7 @@ impl VariantDecimal4 {
     pub(crate) const MAX_PRECISION: u8 = 9;
     pub(crate) const MAX_UNSCALED_VALUE: u32 = u32::pow(10, Self::MAX_PRECISION as u32) - 1;
 
-    pub fn try_new(integer: i32, scale: u8) -> Result<Self, ArrowError> {
+    pub fn try_new(integer: i32, scale: u8) -> Result<Self, VariantError> {
         decimal_try_new!(integer, scale)
     }
 
@@ -139,7 +137,7 @@ impl VariantDecimal8 {
     pub(crate) const MAX_PRECISION: u8 = 18;
     pub(crate) const MAX_UNSCALED_VALUE: u64 = u64::pow(10, Self::MAX_PRECISION as u32) - 1;
 
-    pub fn try_new(integer: i64, scale: u8) -> Result<Self, ArrowError> {
+    pub fn try_new(integer: i64, scale: u8) -> Result<Self, VariantError> {
         decimal_try_new!(integer, scale)
     }
 
@@ -186,10 +184,10 @@ pub struct VariantDecimal16 {
 }
 
 impl VariantDecimal16 {
-    const MAX_PRECISION: u8 = 38;
-    const MAX_UNSCALED_VALUE: u128 = u128::pow(10, Self::MAX_PRECISION as u32) - 1;
+    pub(crate) const MAX_PRECISION: u8 = 38;
+    pub(crate) const MAX_UNSCALED_VALUE: u128 = u128::pow(10, Self::MAX_PRECISION as u32) - 1;
 
-    pub fn try_new(integer: i128, scale: u8) -> Result<Self, ArrowError> {
+    pub fn try_new(integer: i128, scale: u8) -> Result<Self, VariantError> {
         decimal_try_new!(integer, scale)
     }
 
@@ -236,15 +234,14 @@ impl_from_decimal_for_decimal!(VariantDecimal8, VariantDecimal16);
 macro_rules! impl_try_from_decimal_for_decimal {
     ($from_ty:ty, $for_ty:ty) => {
         impl TryFrom<$from_ty> for $for_ty {
-            type Error = ArrowError;
+            type Error = VariantError;
 
-            fn try_from(decimal: $from_ty) -> Result<Self, ArrowError> {
+            fn try_from(decimal: $from_ty) -> Result<Self, VariantError> {
                 let Ok(integer) = decimal.integer.try_into() else {
-                    return Err(ArrowError::InvalidArgumentError(format!(
-                        "Value {} is wider than max precision {}",
-                        decimal.integer,
-                        Self::MAX_PRECISION
-                    )));
+                    return Err(VariantError::DecimalOverflow {
+                        value: decimal.integer.to_string(),
+                        max_precision: Self::MAX_PRECISION,
+                    });
                 };
                 Self::try_new(integer, decimal.scale)
             }
@@ -260,9 +257,9 @@ impl_try_from_decimal_for_decimal!(VariantDecimal16, VariantDecimal8);
 macro_rules! impl_try_from_int_for_decimal {
     ($from_ty:ty, $for_ty:ty) => {
         impl TryFrom<$from_ty> for $for_ty {
-            type Error = ArrowError;
+            type Error = VariantError;
 
-            fn try_from(integer: $from_ty) -> Result<Self, ArrowError> {
+            fn try_from(integer: $from_ty) -> Result<Self, VariantError> {
                 Self::try_new(integer, 0)
             }
         }
@@ -20,7 +20,7 @@ use crate::utils::{
 };
 use crate::variant::{Variant, VariantMetadata};
 
-use arrow_schema::ArrowError;
+use crate::{PathSegment, VariantError};
 
 // The value header occupies one byte; use a named constant for readability
 const NUM_HEADER_BYTES: u32 = 1;
@@ -46,7 +46,7 @@ impl VariantListHeader {
         NUM_HEADER_BYTES + self.num_elements_size()
     }
 
-    pub(crate) fn try_new(header_byte: u8) -> Result<Self, ArrowError> {
+    pub(crate) fn try_new(header_byte: u8) -> Result<Self, VariantError> {
         // The 6 first bits to the left are the value_header and the 2 bits
         // to the right are the basic type, so we shift to get only the value_header
         let value_header = header_byte >> 2;
@@ -137,7 +137,7 @@ impl<'m, 'v> VariantList<'m, 'v> {
     ///
     /// This constructor verifies that `value` points to a valid variant array value. In particular,
     /// that all offsets are in-bounds and point to valid (recursively validated) objects.
-    pub fn try_new(metadata: VariantMetadata<'m>, value: &'v [u8]) -> Result<Self, ArrowError> {
+    pub fn try_new(metadata: VariantMetadata<'m>, value: &'v [u8]) -> Result<Self, VariantError> {
         Self::try_new_with_shallow_validation(metadata, value)?.with_full_validation()
     }
 
@@ -152,7 +152,7 @@ impl<'m, 'v> VariantList<'m, 'v> {
     pub(crate) fn try_new_with_shallow_validation(
         metadata: VariantMetadata<'m>,
         value: &'v [u8],
-    ) -> Result<Self, ArrowError> {
+    ) -> Result<Self, VariantError> {
         let header_byte = first_byte_from_slice(value)?;
         let header = VariantListHeader::try_new(header_byte)?;
 
@@ -181,9 +181,7 @@ impl<'m, 'v> VariantList<'m, 'v> {
         // Validate just the first and last offset, ignoring the other offsets and all value bytes.
         let first_offset = new_self.get_offset(0)?;
         if first_offset != 0 {
-            return Err(ArrowError::InvalidArgumentError(format!(
-                "First offset is not zero: {first_offset}"
-            )));
+            return Err(VariantError::FirstOffsetNotZero(first_offset as _));
         }
 
         // Use the last offset to upper-bound the value buffer
@@ -205,7 +203,7 @@ impl<'m, 'v> VariantList<'m, 'v> {
     /// Performs a full [validation] of this variant array and returns the result.
     ///
     /// [validation]: Self#Validation
-    pub fn with_full_validation(mut self) -> Result<Self, ArrowError> {
+    pub fn with_full_validation(mut self) -> Result<Self, VariantError> {
         if !self.validated {
             // Validate the metadata dictionary first, if not already validated, because we pass it
             // by value to all the children (who would otherwise re-validate it repeatedly).
@@ -225,9 +223,10 @@ impl<'m, 'v> VariantList<'m, 'v> {
             let mut offset_iter = map_bytes_to_offsets(offset_buffer, self.header.offset_size);
             let mut current_offset = offset_iter.next().unwrap_or(0);
 
-            for next_offset in offset_iter {
+            for (index, next_offset) in offset_iter.enumerate() {
                 let value_bytes = slice_from_slice(value_buffer, current_offset..next_offset)?;
-                Variant::try_new_with_metadata(self.metadata.clone(), value_bytes)?;
+                Variant::try_new_with_metadata(self.metadata.clone(), value_bytes)
+                    .map_err(|e| e.with_path_segment(PathSegment::Index(index)))?;
                 current_offset = next_offset;
             }
 
@@ -257,21 +256,40 @@ impl<'m, 'v> VariantList<'m, 'v> {
     }
 
     /// Fallible version of `get`. Returns element by index, capturing validation errors
-    pub fn try_get(&self, index: usize) -> Result<Variant<'m, 'v>, ArrowError> {
+    pub fn try_get(&self, index: usize) -> Result<Variant<'m, 'v>, VariantError> {
         self.try_get_with_shallow_validation(index)?
             .with_full_validation()
     }
 
+    /// Returns the encoded value bytes of the element at `index` in `0..self.len()`, if any.
+    ///
+    /// Together with the metadata of this list, these form a complete variant value, and so
+    /// can be copied into another variant without decoding. May panic if this list is [invalid].
+    ///
+    /// [invalid]: Self#Validation
+    pub fn value_bytes(&self, index: usize) -> Option<&'v [u8]> {
+        (index < self.len()).then(|| {
+            self.try_value_bytes(index)
+                .expect("Invalid variant array element")
+        })
+    }
+
     // Fallible version of `get`, performing only basic (constant-time) validation.
-    fn try_get_with_shallow_validation(&self, index: usize) -> Result<Variant<'m, 'v>, ArrowError> {
-        // Fetch the value bytes between the two offsets for this index, from the value array region
-        // of the byte buffer
-        let byte_range = self.get_offset(index)? as _..self.get_offset(index + 1)? as _;
-        let value_bytes =
-            slice_from_slice_at_offset(self.value, self.first_value_byte as _, byte_range)?;
+    fn try_get_with_shallow_validation(
+        &self,
+        index: usize,
+    ) -> Result<Variant<'m, 'v>, VariantError> {
+        let value_bytes = self.try_value_bytes(index)?;
         Variant::try_new_with_metadata_and_shallow_validation(self.metadata.clone(), value_bytes)
     }
 
+    // Fetch the value bytes between the two offsets for this index, from the value array region
+    // of the byte buffer
+    fn try_value_bytes(&self, index: usize) -> Result<&'v [u8], VariantError> {
+        let byte_range = self.get_offset(index)? as _..self.get_offset(index + 1)? as _;
+        slice_from_slice_at_offset(self.value, self.first_value_byte as _, byte_range)
+    }
+
     /// Iterates over the values of this list. When working with [unvalidated] input, consider
     /// [`Self::iter_try`] to avoid panics due to invalid data.
     ///
@@ -282,7 +300,7 @@ impl<'m, 'v> VariantList<'m, 'v> {
     }
 
     /// Fallible iteration over the elements of this list.
-    pub fn iter_try(&self) -> impl Iterator<Item = Result<Variant<'m, 'v>, ArrowError>> + '_ {
+    pub fn iter_try(&self) -> impl Iterator<Item = Result<Variant<'m, 'v>, VariantError>> + '_ {
         self.iter_try_with_shallow_validation()
             .map(|result| result?.with_full_validation())
     }
@@ -290,12 +308,12 @@ impl<'m, 'v> VariantList<'m, 'v> {
     // Fallible iteration that only performs basic (constant-time) validation.
     fn iter_try_with_shallow_validation(
         &self,
-    ) -> impl Iterator<Item = Result<Variant<'m, 'v>, ArrowError>> + '_ {
+    ) -> impl Iterator<Item = Result<Variant<'m, 'v>, VariantError>> + '_ {
         (0..self.len()).map(|i| self.try_get_with_shallow_validation(i))
     }
 
     // Attempts to retrieve the ith offset from the offset array region of the byte buffer.
-    fn get_offset(&self, index: usize) -> Result<u32, ArrowError> {
+    fn get_offset(&self, index: usize) -> Result<u32, VariantError> {
         let byte_range = self.header.first_offset_byte() as _..self.first_value_byte as _;
         let offset_bytes = slice_from_slice(self.value, byte_range)?;
         self.header.offset_size.unpack_u32(offset_bytes, index)
@@ -357,6 +375,14 @@ mod tests {
         let out_of_bounds = variant_list.get(3);
         assert!(out_of_bounds.is_none());
 
+        // Test raw value access
+        assert_eq!(variant_list.value_bytes(0), Some([0x0C, 42].as_slice()));
+        assert_eq!(
+            variant_list.value_bytes(2),
+            Some([0x09, b'h', b'i'].as_slice())
+        );
+        assert_eq!(variant_list.value_bytes(3), None);
+
         // Test values iterator
         let values: Vec<_> = variant_list.iter().collect();
         assert_eq!(values.len(), 3);
@@ -16,9 +16,12 @@
 // under the License.
 
 use crate::decoder::{map_bytes_to_offsets, OffsetSizeBytes};
-use crate::utils::{first_byte_from_slice, overflow_error, slice_from_slice, string_from_slice};
+use crate::utils::{
+    first_byte_from_slice, overflow_error, slice_from_slice, string_from_slice,
+    try_binary_search_range_by,
+};
 
-use arrow_schema::ArrowError;
+use crate::VariantError;
 
 /// Header structure for [`VariantMetadata`]
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -29,9 +32,41 @@ pub(crate) struct VariantMetadataHeader {
     offset_size: OffsetSizeBytes,
 }
 
-// According to the spec this is currently always = 1, and so we store this const for validation
-// purposes and to make that visible.
-const CORRECT_VERSION_VALUE: u8 = 1;
+/// The version of the [Variant Binary Encoding], stored in the metadata header
+///
+/// [`VariantBuilder::with_version`] selects the version to write, and
+/// [`VariantMetadata::variant_version`] returns the version that was read.
+///
+/// [Variant Binary Encoding]: https://github.com/apache/parquet-format/blob/master/VariantEncoding.md
+/// [`VariantBuilder::with_version`]: crate::VariantBuilder::with_version
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum VariantVersion {
+    /// Version 1, the only version currently defined by the specification
+    #[default]
+    V1 = 1,
+}
+
+impl VariantVersion {
+    /// The latest version supported by this implementation
+    pub const CURRENT: Self = Self::V1;
+
+    /// Returns the value of this version as stored in the metadata header
+    pub const fn as_u8(self) -> u8 {
+        self as u8
+    }
+}
+
+impl TryFrom<u8> for VariantVersion {
+    type Error = VariantError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Self::V1),
+            _ => Err(VariantError::UnsupportedVersion(value)),
+        }
+    }
+}
 
 // The metadata header occupies one byte; use a named constant for readability
 const NUM_HEADER_BYTES: u32 = 1;
@@ -59,17 +94,15 @@ impl VariantMetadataHeader {
     ///                 +-- offset_size_minus_one
     /// ```
     ///
-    /// The version is a 4-bit value that must always contain the value 1.
+    /// The version is a 4-bit value that must always contain a known [`VariantVersion`],
+    /// unless `lenient` is true, in which case any non-zero version is accepted
     /// - sorted_strings is a 1-bit value indicating whether dictionary strings are sorted and unique.
     /// - offset_size_minus_one is a 2-bit value providing the number of bytes per dictionary size and offset field.
     /// - The actual number of bytes, offset_size, is offset_size_minus_one + 1
-    pub(crate) fn try_new(header_byte: u8) -> Result<Self, ArrowError> {
+    pub(crate) fn try_new(header_byte: u8, lenient: bool) -> Result<Self, VariantError> {
         let version = header_byte & 0x0F; // First four bits
-        if version != CORRECT_VERSION_VALUE {
-            let err_msg = format!(
-                "The version bytes in the header is not {CORRECT_VERSION_VALUE}, got {version:b}",
-            );
-            return Err(ArrowError::InvalidArgumentError(err_msg));
+        if version == 0 || !lenient && VariantVersion::try_from(version).is_err() {
+            return Err(VariantError::InvalidMetadataVersion(version));
         }
         let is_sorted = (header_byte & 0x10) != 0; // Fifth bit
         let offset_size_minus_one = header_byte >> 6; // Last two bits
@@ -118,6 +151,15 @@ impl VariantMetadataHeader {
 /// NOTE: [`Self::new`] only skips expensive (non-constant cost) validation checks (marked by `(*)`
 /// in the list above); it panics any of the other checks fails.
 ///
+/// # Forward Compatibility
+///
+/// By default, metadata with a version other than a known [`VariantVersion`] is rejected.
+/// Instances produced by [`Self::try_new_lenient`] instead accept any version, assuming
+/// the layout is unchanged, and surface values of unknown primitive types nested within
+/// any [`Variant`] read using this metadata as [`Variant::Opaque`] rather than returning
+/// an error. This allows readers to process data written by newer writers, as long as
+/// they do not need to interpret the new types.
+///
 /// # Safety
 ///
 /// Even an _invalid_ variant metadata instance is still _safe_ to use in the Rust sense. Accessing
@@ -125,6 +167,7 @@ impl VariantMetadataHeader {
 ///
 /// [`Variant`]: crate::Variant
 /// [Variant Spec]: https://github.com/apache/parquet-format/blob/master/VariantEncoding.md#metadata-encoding
+/// [`Variant::Opaque`]: crate::Variant::Opaque
 #[derive(Debug, Clone, PartialEq)]
 pub struct VariantMetadata<'m> {
     bytes: &'m [u8],
@@ -132,6 +175,7 @@ pub struct VariantMetadata<'m> {
     dictionary_size: u32,
     first_value_byte: u32,
     validated: bool,
+    lenient: bool,
 }
 
 // We don't want this to grow because it increases the size of VariantList and VariantObject, which
@@ -143,10 +187,17 @@ impl<'m> VariantMetadata<'m> {
     /// dictionary entries.
     ///
     /// [validation]: Self#Validation
-    pub fn try_new(bytes: &'m [u8]) -> Result<Self, ArrowError> {
+    pub fn try_new(bytes: &'m [u8]) -> Result<Self, VariantError> {
         Self::try_new_with_shallow_validation(bytes)?.with_full_validation()
     }
 
+    /// Similar to [`Self::try_new`], but in the lenient [forward compatibility] mode
+    ///
+    /// [forward compatibility]: Self#forward-compatibility
+    pub fn try_new_lenient(bytes: &'m [u8]) -> Result<Self, VariantError> {
+        Self::try_new_impl(bytes, true)?.with_full_validation()
+    }
+
     /// Interprets `bytes` as a variant metadata instance, without attempting to [validate] dictionary
     /// entries. Panics if basic sanity checking fails, and subsequent infallible accesses such as
     /// indexing and iteration could also panic if the underlying bytes are invalid.
@@ -162,9 +213,13 @@ impl<'m> VariantMetadata<'m> {
     }
 
     // The actual constructor, which performs only basic (constant-const) validation.
-    pub(crate) fn try_new_with_shallow_validation(bytes: &'m [u8]) -> Result<Self, ArrowError> {
+    pub(crate) fn try_new_with_shallow_validation(bytes: &'m [u8]) -> Result<Self, VariantError> {
+        Self::try_new_impl(bytes, false)
+    }
+
+    fn try_new_impl(bytes: &'m [u8], lenient: bool) -> Result<Self, VariantError> {
         let header_byte = first_byte_from_slice(bytes)?;
-        let header = VariantMetadataHeader::try_new(header_byte)?;
+        let header = VariantMetadataHeader::try_new(header_byte, lenient)?;
 
         // First element after header is dictionary size; the offset array immediately follows.
         let dictionary_size =
@@ -188,14 +243,13 @@ impl<'m> VariantMetadata<'m> {
             dictionary_size,
             first_value_byte,
             validated: false,
+            lenient,
         };
 
         // Validate just the first and last offset, ignoring the other offsets and all value bytes.
         let first_offset = new_self.get_offset(0)?;
         if first_offset != 0 {
-            return Err(ArrowError::InvalidArgumentError(format!(
-                "First offset is not zero: {first_offset}"
-            )));
+            return Err(VariantError::FirstOffsetNotZero(first_offset as _));
         }
 
         // Use the last offset to upper-bound the byte slice
@@ -227,7 +281,7 @@ impl<'m> VariantMetadata<'m> {
     /// Performs a full [validation] of this metadata dictionary and returns the result.
     ///
     /// [validation]: Self#Validation
-    pub fn with_full_validation(mut self) -> Result<Self, ArrowError> {
+    pub fn with_full_validation(mut self) -> Result<Self, VariantError> {
         if !self.validated {
             let offset_bytes = slice_from_slice(
                 self.bytes,
@@ -257,21 +311,18 @@ impl<'m> VariantMetadata<'m> {
                     });
 
                 if !are_dictionary_values_unique_and_sorted {
-                    return Err(ArrowError::InvalidArgumentError(
-                        "dictionary values are not unique and ordered".to_string(),
-                    ));
+                    return Err(VariantError::UnsortedDictionary);
                 }
             } else {
                 // Validate offsets are in-bounds and monotonically increasing
                 //
                 // Since shallow validation ensures the first and last offsets are in bounds,
                 // we can also verify all offsets are in-bounds by checking if
-                // offsets are monotonically increasing
-                let are_offsets_monotonic = offsets.is_sorted_by(|a, b| a < b);
+                // offsets are monotonically increasing. Offsets may repeat, as the
+                // empty string is a valid field name
+                let are_offsets_monotonic = offsets.is_sorted_by(|a, b| a <= b);
                 if !are_offsets_monotonic {
-                    return Err(ArrowError::InvalidArgumentError(
-                        "offsets not monotonically increasing".to_string(),
-                    ));
+                    return Err(VariantError::NonMonotonicOffsets);
                 }
             }
 
@@ -295,11 +346,26 @@ impl<'m> VariantMetadata<'m> {
         self.header.version
     }
 
+    /// The variant protocol version, or `None` if this version is not known to this
+    /// implementation, which is only possible in the lenient [forward compatibility] mode
+    ///
+    /// [forward compatibility]: Self#forward-compatibility
+    pub fn variant_version(&self) -> Option<VariantVersion> {
+        VariantVersion::try_from(self.header.version).ok()
+    }
+
+    /// True if this instance was created in the lenient [forward compatibility] mode
+    ///
+    /// [forward compatibility]: Self#forward-compatibility
+    pub const fn is_lenient(&self) -> bool {
+        self.lenient
+    }
+
     /// Gets an offset array entry by index.
     ///
     /// This offset is an index into the dictionary, at the boundary between string `i-1` and string
     /// `i`. See [`Self::get`] to retrieve a specific dictionary entry.
-    fn get_offset(&self, i: usize) -> Result<u32, ArrowError> {
+    fn get_offset(&self, i: usize) -> Result<u32, VariantError> {
         let offset_byte_range = self.header.first_offset_byte() as _..self.first_value_byte as _;
         let bytes = slice_from_slice(self.bytes, offset_byte_range)?;
         self.header.offset_size.unpack_u32(bytes, i)
@@ -309,16 +375,32 @@ impl<'m> VariantMetadata<'m> {
     /// underlying bytes are [invalid].
     ///
     /// [invalid]: Self#Validation
-    pub fn get(&self, i: usize) -> Result<&'m str, ArrowError> {
+    pub fn get(&self, i: usize) -> Result<&'m str, VariantError> {
         let byte_range = self.get_offset(i)? as _..self.get_offset(i + 1)? as _;
         string_from_slice(self.bytes, self.first_value_byte as _, byte_range)
     }
 
+    /// Returns the index of the dictionary entry equal to `name`, if any.
+    ///
+    /// This is a binary search if the dictionary is [sorted], and a linear scan otherwise. The
+    /// result can be used with [`VariantObject::get_by_field_id`] to look up the same field of
+    /// many objects sharing this metadata, without comparing field names.
+    ///
+    /// [sorted]: Self::is_sorted
+    /// [`VariantObject::get_by_field_id`]: crate::VariantObject::get_by_field_id
+    pub fn field_id(&self, name: &str) -> Option<u32> {
+        let i = match self.is_sorted() {
+            true => try_binary_search_range_by(0..self.len(), &name, |i| self.get(i).ok())?.ok()?,
+            false => self.iter_try().position(|entry| entry.ok() == Some(name))?,
+        };
+        Some(i as u32)
+    }
+
     /// Returns an iterator that attempts to visit all dictionary entries, producing `Err` if the
     /// iterator encounters [invalid] data.
     ///
     /// [invalid]: Self#Validation
-    pub fn iter_try(&self) -> impl Iterator<Item = Result<&'m str, ArrowError>> + '_ {
+    pub fn iter_try(&self) -> impl Iterator<Item = Result<&'m str, VariantError>> + '_ {
         (0..self.len()).map(|i| self.get(i))
     }
 
@@ -378,7 +460,7 @@ mod tests {
 
         let err = md.get_offset(3).unwrap_err();
         assert!(
-            matches!(err, ArrowError::InvalidArgumentError(_)),
+            matches!(err, VariantError::OutOfBounds { .. }),
             "unexpected error: {err:?}"
         );
 
@@ -409,7 +491,7 @@ mod tests {
 
         let err = VariantMetadata::try_new(truncated).unwrap_err();
         assert!(
-            matches!(err, ArrowError::InvalidArgumentError(_)),
+            matches!(err, VariantError::OutOfBounds { .. }),
             "unexpected error: {err:?}"
         );
     }
@@ -438,11 +520,29 @@ mod tests {
 
         let err = VariantMetadata::try_new(bytes).unwrap_err();
         assert!(
-            matches!(err, ArrowError::InvalidArgumentError(_)),
+            matches!(err, VariantError::OutOfBounds { .. }),
             "unexpected error: {err:?}"
         );
     }
 
+    #[test]
+    fn try_new_empty_field_name() {
+        // '', 'a'
+        let bytes = &[
+            0b0000_0001, // header, offset_size_minus_one=0 and version=1
+            0x02,        // dictionary_size
+            0x00,
+            0x00, // Empty string
+            0x01,
+            b'a',
+        ];
+
+        let md = VariantMetadata::try_new(bytes).unwrap();
+        assert_eq!(md.dictionary_size(), 2);
+        assert_eq!(&md[0], "");
+        assert_eq!(&md[1], "a");
+    }
+
     #[test]
     fn try_new_fails_non_monotonic2() {
         // this test case checks whether offsets are monotonic in the full validation logic.
@@ -474,7 +574,7 @@ mod tests {
         let err = VariantMetadata::try_new(bytes).unwrap_err();
 
         assert!(
-            matches!(err, ArrowError::InvalidArgumentError(_)),
+            matches!(err, VariantError::NonMonotonicOffsets),
             "unexpected error: {err:?}"
         );
     }
@@ -486,7 +586,46 @@ mod tests {
 
         let err = VariantMetadata::try_new(bytes).unwrap_err();
         assert!(
-            matches!(err, ArrowError::InvalidArgumentError(_)),
+            matches!(err, VariantError::OutOfBounds { .. }),
+            "unexpected error: {err:?}"
+        );
+    }
+
+    #[test]
+    fn test_variant_version() {
+        assert_eq!(VariantVersion::try_from(1).unwrap(), VariantVersion::V1);
+        assert_eq!(VariantVersion::CURRENT.as_u8(), 1);
+        let err = VariantVersion::try_from(2).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Unsupported variant version 2, expected at most 1"
+        );
+
+        let metadata = VariantMetadata::try_new(&[0x01, 0x00, 0x00]).unwrap();
+        assert_eq!(metadata.variant_version(), Some(VariantVersion::V1));
+        assert!(!metadata.is_lenient());
+    }
+
+    #[test]
+    fn try_new_lenient_unknown_version() {
+        // version=2, with a single "a" entry
+        let bytes = &[0b0000_0010, 0x01, 0x00, 0x01, b'a'];
+        let err = VariantMetadata::try_new(bytes).unwrap_err();
+        assert!(
+            matches!(err, VariantError::InvalidMetadataVersion(_)),
+            "unexpected error: {err:?}"
+        );
+
+        let metadata = VariantMetadata::try_new_lenient(bytes).unwrap();
+        assert!(metadata.is_lenient());
+        assert_eq!(metadata.version(), 2);
+        assert_eq!(metadata.variant_version(), None);
+        assert_eq!(&metadata[0], "a");
+
+        // version 0 is never valid
+        let err = VariantMetadata::try_new_lenient(&[0x00, 0x00, 0x00]).unwrap_err();
+        assert!(
+            matches!(err, VariantError::InvalidMetadataVersion(_)),
             "unexpected error: {err:?}"
         );
     }
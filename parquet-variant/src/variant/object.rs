@@ -14,13 +14,13 @@
 // KIND, either express or implied.  See the License for the
 // specific language governing permissions and limitations
 // under the License.
-use crate::decoder::{map_bytes_to_offsets, OffsetSizeBytes};
+use crate::decoder::{is_unknown_primitive, map_bytes_to_offsets, OffsetSizeBytes};
 use crate::utils::{
     first_byte_from_slice, overflow_error, slice_from_slice, try_binary_search_range_by,
 };
 use crate::variant::{Variant, VariantMetadata};
 
-use arrow_schema::ArrowError;
+use crate::{PathSegment, VariantError};
 
 // The value header occupies one byte; use a named constant for readability
 const NUM_HEADER_BYTES: u32 = 1;
@@ -50,7 +50,7 @@ impl VariantObjectHeader {
         NUM_HEADER_BYTES + self.num_elements_size()
     }
 
-    pub(crate) fn try_new(header_byte: u8) -> Result<Self, ArrowError> {
+    pub(crate) fn try_new(header_byte: u8) -> Result<Self, VariantError> {
         // Parse the header byte to get object parameters
         let value_header = header_byte >> 2;
         let field_offset_size_minus_one = value_header & 0x03; // Last 2 bits
@@ -140,7 +140,7 @@ impl<'m, 'v> VariantObject<'m, 'v> {
     /// This constructor verifies that `value` points to a valid variant object value. In
     /// particular, that all field ids exist in `metadata`, and all offsets are in-bounds and point
     /// to valid objects.
-    pub fn try_new(metadata: VariantMetadata<'m>, value: &'v [u8]) -> Result<Self, ArrowError> {
+    pub fn try_new(metadata: VariantMetadata<'m>, value: &'v [u8]) -> Result<Self, VariantError> {
         Self::try_new_with_shallow_validation(metadata, value)?.with_full_validation()
     }
 
@@ -151,7 +151,7 @@ impl<'m, 'v> VariantObject<'m, 'v> {
     pub(crate) fn try_new_with_shallow_validation(
         metadata: VariantMetadata<'m>,
         value: &'v [u8],
-    ) -> Result<Self, ArrowError> {
+    ) -> Result<Self, VariantError> {
         let header_byte = first_byte_from_slice(value)?;
         let header = VariantObjectHeader::try_new(header_byte)?;
 
@@ -206,7 +206,7 @@ impl<'m, 'v> VariantObject<'m, 'v> {
     /// Performs a full [validation] of this variant object.
     ///
     /// [validation]: Self#Validation
-    pub fn with_full_validation(mut self) -> Result<Self, ArrowError> {
+    pub fn with_full_validation(mut self) -> Result<Self, VariantError> {
         if !self.validated {
             // Validate the metadata dictionary first, if not already validated, because we pass it
             // by value to all the children (who would otherwise re-validate it repeatedly).
@@ -225,18 +225,14 @@ impl<'m, 'v> VariantObject<'m, 'v> {
                 // Since the metadata dictionary has unique and sorted field names, we can also guarantee this object's field names
                 // are lexicographically sorted by their field id ordering
                 if !field_ids.is_sorted() {
-                    return Err(ArrowError::InvalidArgumentError(
-                        "field names not sorted".to_string(),
-                    ));
+                    return Err(VariantError::UnsortedFieldNames);
                 }
 
                 // Since field ids are sorted, if the last field is smaller than the dictionary size,
                 // we also know all field ids are smaller than the dictionary size and in-bounds.
                 if let Some(&last_field_id) = field_ids.last() {
                     if last_field_id >= self.metadata.dictionary_size() {
-                        return Err(ArrowError::InvalidArgumentError(
-                            "field id is not valid".to_string(),
-                        ));
+                        return Err(VariantError::InvalidFieldId);
                     }
                 }
             } else {
@@ -251,9 +247,7 @@ impl<'m, 'v> VariantObject<'m, 'v> {
                     .is_sorted();
 
                 if !are_field_names_sorted {
-                    return Err(ArrowError::InvalidArgumentError(
-                        "field names not sorted".to_string(),
-                    ));
+                    return Err(VariantError::UnsortedFieldNames);
                 }
             }
 
@@ -264,15 +258,19 @@ impl<'m, 'v> VariantObject<'m, 'v> {
             )?;
             let num_offsets = field_offset_buffer.len() / self.header.field_offset_size() as usize;
 
-            let value_buffer = slice_from_slice(self.value, self.first_value_byte as _..)?;
-
             map_bytes_to_offsets(field_offset_buffer, self.header.field_offset_size)
                 .take(num_offsets.saturating_sub(1))
-                .try_for_each(|offset| {
-                    let value_bytes = slice_from_slice(value_buffer, offset..)?;
-                    Variant::try_new_with_metadata(self.metadata.clone(), value_bytes)?;
-
-                    Ok::<_, ArrowError>(())
+                .zip(&field_ids)
+                .try_for_each(|(offset, field_id)| {
+                    let value_bytes = self.field_value_bytes(offset)?;
+                    Variant::try_new_with_metadata(self.metadata.clone(), value_bytes).map_err(
+                        |e| match self.metadata.get(*field_id) {
+                            Ok(name) => e.with_path_segment(PathSegment::Field(name)),
+                            Err(_) => e,
+                        },
+                    )?;
+
+                    Ok::<_, VariantError>(())
                 })?;
 
             self.validated = true;
@@ -305,21 +303,43 @@ impl<'m, 'v> VariantObject<'m, 'v> {
     }
 
     /// Fallible version of `field`. Returns field value by index, capturing validation errors
-    pub fn try_field(&self, i: usize) -> Result<Variant<'m, 'v>, ArrowError> {
+    pub fn try_field(&self, i: usize) -> Result<Variant<'m, 'v>, VariantError> {
         self.try_field_with_shallow_validation(i)?
             .with_full_validation()
     }
 
     // Attempts to retrieve the ith field value from the value region of the byte buffer; it
     // performs only basic (constant-cost) validation.
-    fn try_field_with_shallow_validation(&self, i: usize) -> Result<Variant<'m, 'v>, ArrowError> {
-        let value_bytes = slice_from_slice(self.value, self.first_value_byte as _..)?;
-        let value_bytes = slice_from_slice(value_bytes, self.get_offset(i)? as _..)?;
+    fn try_field_with_shallow_validation(&self, i: usize) -> Result<Variant<'m, 'v>, VariantError> {
+        let value_bytes = self.field_value_bytes(self.get_offset(i)? as _)?;
         Variant::try_new_with_metadata_and_shallow_validation(self.metadata.clone(), value_bytes)
     }
 
+    // Returns the bytes of the field value starting at `offset` in the value region. These extend
+    // to the end of the object, as values need not be stored in field order, except for values of
+    // unknown primitive types in lenient mode. As their length can't be decoded, they are bounded
+    // by the next largest field offset instead.
+    fn field_value_bytes(&self, offset: usize) -> Result<&'v [u8], VariantError> {
+        let value_bytes = slice_from_slice(self.value, self.first_value_byte as _..)?;
+        let value_bytes = slice_from_slice(value_bytes, offset..)?;
+        if !(self.metadata.is_lenient()
+            && is_unknown_primitive(first_byte_from_slice(value_bytes)?))
+        {
+            return Ok(value_bytes);
+        }
+        let field_offsets = slice_from_slice(
+            self.value,
+            self.first_field_offset_byte as _..self.first_value_byte as _,
+        )?;
+        let end = map_bytes_to_offsets(field_offsets, self.header.field_offset_size)
+            .filter(|o| *o > offset)
+            .min()
+            .unwrap_or(offset);
+        slice_from_slice(value_bytes, ..end - offset)
+    }
+
     // Attempts to retrieve the ith offset from the field offset region of the byte buffer.
-    fn get_offset(&self, i: usize) -> Result<u32, ArrowError> {
+    fn get_offset(&self, i: usize) -> Result<u32, VariantError> {
         let byte_range = self.first_field_offset_byte as _..self.first_value_byte as _;
         let field_offsets = slice_from_slice(self.value, byte_range)?;
         self.header.field_offset_size.unpack_u32(field_offsets, i)
@@ -338,11 +358,15 @@ impl<'m, 'v> VariantObject<'m, 'v> {
     }
 
     /// Fallible version of `field_name`. Returns field name by index, capturing validation errors
-    fn try_field_name(&self, i: usize) -> Result<&'m str, ArrowError> {
+    fn try_field_name(&self, i: usize) -> Result<&'m str, VariantError> {
+        self.metadata.get(self.try_field_id(i)? as _)
+    }
+
+    // Attempts to retrieve the ith field id from the field id region of the byte buffer.
+    fn try_field_id(&self, i: usize) -> Result<u32, VariantError> {
         let byte_range = self.header.field_ids_start_byte() as _..self.first_field_offset_byte as _;
         let field_id_bytes = slice_from_slice(self.value, byte_range)?;
-        let field_id = self.header.field_id_size.unpack_u32(field_id_bytes, i)?;
-        self.metadata.get(field_id as _)
+        self.header.field_id_size.unpack_u32(field_id_bytes, i)
     }
 
     /// Returns an iterator of (name, value) pairs over the fields of this object.
@@ -354,7 +378,7 @@ impl<'m, 'v> VariantObject<'m, 'v> {
     /// Fallible iteration over the fields of this object.
     pub fn iter_try(
         &self,
-    ) -> impl Iterator<Item = Result<(&'m str, Variant<'m, 'v>), ArrowError>> + '_ {
+    ) -> impl Iterator<Item = Result<(&'m str, Variant<'m, 'v>), VariantError>> + '_ {
         self.iter_try_with_shallow_validation().map(|result| {
             let (name, value) = result?;
             Ok((name, value.with_full_validation()?))
@@ -365,7 +389,7 @@ impl<'m, 'v> VariantObject<'m, 'v> {
     // validation of field values.
     fn iter_try_with_shallow_validation(
         &self,
-    ) -> impl Iterator<Item = Result<(&'m str, Variant<'m, 'v>), ArrowError>> + '_ {
+    ) -> impl Iterator<Item = Result<(&'m str, Variant<'m, 'v>), VariantError>> + '_ {
         (0..self.len()).map(|i| {
             let field = self.try_field_with_shallow_validation(i)?;
             Ok((self.try_field_name(i)?, field))
@@ -385,6 +409,22 @@ impl<'m, 'v> VariantObject<'m, 'v> {
 
         self.field(i)
     }
+
+    /// Returns the value of the field with the specified metadata dictionary index, if any.
+    ///
+    /// Unlike [`Self::get`] this does not compare field names, which is useful when looking up
+    /// the same field of many objects sharing the same metadata, see [`VariantMetadata::field_id`].
+    pub fn get_by_field_id(&self, field_id: u32) -> Option<Variant<'m, 'v>> {
+        let i = match self.metadata.is_sorted() {
+            // Field ids are ordered by name, and so by id if the dictionary is sorted
+            true => {
+                try_binary_search_range_by(0..self.len(), &field_id, |i| self.try_field_id(i).ok())?
+                    .ok()?
+            }
+            false => (0..self.len()).find(|i| self.try_field_id(*i).ok() == Some(field_id))?,
+        };
+        self.field(i)
+    }
 }
 
 #[cfg(test)]
@@ -505,6 +545,35 @@ mod tests {
         assert_eq!(variant_obj.field(2).unwrap().as_string(), Some("hello"));
     }
 
+    #[test]
+    fn test_variant_object_get_by_field_id() {
+        // Both an unsorted and a sorted metadata dictionary
+        for field_names in [["c", "a", "b", "d"], ["a", "b", "c", "d"]] {
+            let mut builder = VariantBuilder::new().with_field_names(field_names.into_iter());
+            let mut obj = builder.new_object();
+            obj.insert("b", 1);
+            obj.insert("c", 2);
+            obj.insert("a", 3);
+            obj.finish().unwrap();
+            let (metadata, value) = builder.finish();
+
+            let variant = Variant::try_new(&metadata, &value).unwrap();
+            let obj = variant.as_object().unwrap();
+            assert_eq!(obj.metadata.is_sorted(), field_names[0] == "a");
+            for (name, expected) in [("a", 3), ("b", 1), ("c", 2)] {
+                let field_id = obj.metadata.field_id(name).unwrap();
+                assert_eq!(obj.metadata.get(field_id as _).unwrap(), name);
+                assert_eq!(obj.get_by_field_id(field_id), Some(Variant::from(expected)));
+            }
+
+            // In the dictionary, but not in the object
+            let field_id = obj.metadata.field_id("d").unwrap();
+            assert_eq!(obj.get_by_field_id(field_id), None);
+            assert_eq!(obj.metadata.field_id("e"), None);
+            assert_eq!(obj.get_by_field_id(10), None);
+        }
+    }
+
     #[test]
     fn test_variant_object_empty() {
         // Create metadata with no fields
@@ -559,7 +628,7 @@ mod tests {
         let err = err.unwrap_err();
         assert!(matches!(
             err,
-            ArrowError::InvalidArgumentError(ref msg) if msg.contains("Tried to extract byte(s) ..13 from 12-byte buffer")
+            VariantError::OutOfBounds { ref range, len: 12 } if range == "..13"
         ));
     }
 
@@ -606,7 +675,7 @@ mod tests {
         let err = err.unwrap_err();
         assert!(matches!(
             err,
-            ArrowError::InvalidArgumentError(ref msg) if msg.contains("Tried to extract byte(s) ..16 from 15-byte buffer")
+            VariantError::OutOfBounds { ref range, len: 15 } if range == "..16"
         ));
     }
 
@@ -0,0 +1,167 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Benchmarks decoding columns of JSON strings, unshredded variants and shredded
+//! variants to variant arrays
+
+use std::sync::Arc;
+
+use arrow_array::{
+    Array, ArrayRef, BinaryArray, Int64Array, RecordBatch, StringArray, StructArray,
+};
+use arrow_schema::{DataType, Field, Fields};
+use bytes::Bytes;
+use criterion::*;
+use parquet::arrow::arrow_reader::{ArrowReaderOptions, ParquetRecordBatchReaderBuilder};
+use parquet::arrow::{ArrowWriter, ProjectionMask};
+use parquet_variant::VariantBuilder;
+
+const NUM_ROWS: usize = 100_000;
+
+/// Returns the metadata and value of the object `{"id": id, "kind": kind, "x": x}`,
+/// omitting the fields that are `None`
+fn encode_object(id: Option<i64>, kind: Option<&str>, x: i64) -> (Vec<u8>, Vec<u8>) {
+    let mut builder = VariantBuilder::new();
+    let mut object = builder.new_object();
+    if let Some(id) = id {
+        object.insert("id", id);
+    }
+    if let Some(kind) = kind {
+        object.insert("kind", kind);
+    }
+    object.insert("x", x);
+    object.finish().unwrap();
+    builder.finish()
+}
+
+/// Returns a `value` and `typed_value` group of a shredded object field
+fn shredded_field(typed_value: ArrayRef) -> (Field, ArrayRef) {
+    let fields = Fields::from(vec![
+        Field::new("value", DataType::Binary, true),
+        Field::new("typed_value", typed_value.data_type().clone(), true),
+    ]);
+    let values = BinaryArray::from(vec![None::<&[u8]>; typed_value.len()]);
+    let group = StructArray::new(fields, vec![Arc::new(values), typed_value], None);
+    let field = Field::new("", group.data_type().clone(), false);
+    (field, Arc::new(group))
+}
+
+/// Writes a file with the same objects stored as JSON strings, as unshredded variants,
+/// and as variants with the `id` and `kind` fields shredded
+fn write_file() -> Bytes {
+    let kinds = ["click", "view", "purchase"];
+    let ids = (0..NUM_ROWS as i64).map(|i| i * 7);
+
+    let json: StringArray = ids
+        .clone()
+        .enumerate()
+        .map(|(i, id)| {
+            let kind = kinds[i % 3];
+            Some(format!(
+                r#"{{"id": {id}, "kind": "{kind}", "x": {}}}"#,
+                i % 10
+            ))
+        })
+        .collect();
+
+    let (metadata, value): (Vec<_>, Vec<_>) = ids
+        .clone()
+        .enumerate()
+        .map(|(i, id)| encode_object(Some(id), Some(kinds[i % 3]), i as i64 % 10))
+        .unzip();
+    let group_fields = Fields::from(vec![
+        Field::new("metadata", DataType::Binary, false),
+        Field::new("value", DataType::Binary, true),
+    ]);
+    let unshredded = StructArray::new(
+        group_fields,
+        vec![
+            Arc::new(BinaryArray::from_iter_values(&metadata)),
+            Arc::new(BinaryArray::from_iter_values(&value)),
+        ],
+        None,
+    );
+
+    // The fields that are not shredded are stored in the value of each row
+    let (metadata, remainder): (Vec<_>, Vec<_>) = (0..NUM_ROWS)
+        .map(|i| encode_object(None, None, i as i64 % 10))
+        .unzip();
+    let (id_field, id) = shredded_field(Arc::new(Int64Array::from_iter_values(ids)));
+    let kind = StringArray::from_iter_values((0..NUM_ROWS).map(|i| kinds[i % 3]));
+    let (kind_field, kind) = shredded_field(Arc::new(kind));
+    let typed_value = StructArray::new(
+        Fields::from(vec![
+            Field::new("id", id_field.data_type().clone(), false),
+            Field::new("kind", kind_field.data_type().clone(), false),
+        ]),
+        vec![id, kind],
+        None,
+    );
+    let shredded = StructArray::new(
+        Fields::from(vec![
+            Field::new("metadata", DataType::Binary, false),
+            Field::new("value", DataType::Binary, true),
+            Field::new("typed_value", typed_value.data_type().clone(), true),
+        ]),
+        vec![
+            Arc::new(BinaryArray::from_iter_values(&metadata)),
+            Arc::new(BinaryArray::from_iter_values(&remainder)),
+            Arc::new(typed_value),
+        ],
+        None,
+    );
+
+    let batch = RecordBatch::try_from_iter([
+        ("json", Arc::new(json) as ArrayRef),
+        ("unshredded", Arc::new(unshredded) as ArrayRef),
+        ("shredded", Arc::new(shredded) as ArrayRef),
+    ])
+    .unwrap();
+
+    let mut buf = Vec::new();
+    let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), None).unwrap();
+    writer.write(&batch).unwrap();
+    writer.close().unwrap();
+    Bytes::from(buf)
+}
+
+fn bench_variant_reader(c: &mut Criterion) {
+    let data = write_file();
+    let mut group = c.benchmark_group("variant_reader");
+    group.sample_size(10);
+    group.throughput(Throughput::Elements(NUM_ROWS as u64));
+
+    for (idx, name) in ["json", "unshredded", "shredded"].into_iter().enumerate() {
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                let options = ArrowReaderOptions::new().with_variant_columns([name]);
+                let builder =
+                    ParquetRecordBatchReaderBuilder::try_new_with_options(data.clone(), options)
+                        .unwrap();
+                let mask = ProjectionMask::roots(builder.parquet_schema(), [idx]);
+                let reader = builder.with_projection(mask).build().unwrap();
+                for batch in reader {
+                    std::hint::black_box(batch.unwrap());
+                }
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_variant_reader);
+criterion_main!(benches);
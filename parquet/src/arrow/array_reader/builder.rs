@@ -19,14 +19,18 @@ use std::sync::Arc;
 
 use arrow_schema::{DataType, Fields, SchemaBuilder};
 
+use crate::arrow::array_reader::byte_array_dictionary::make_cached_byte_array_dictionary_reader;
 use crate::arrow::array_reader::byte_view_array::make_byte_view_array_reader;
 use crate::arrow::array_reader::empty_array::make_empty_array_reader;
 use crate::arrow::array_reader::fixed_len_byte_array::make_fixed_len_byte_array_reader;
 use crate::arrow::array_reader::{
-    make_byte_array_dictionary_reader, make_byte_array_reader, ArrayReader,
-    FixedSizeListArrayReader, ListArrayReader, MapArrayReader, NullArrayReader,
-    PrimitiveArrayReader, RowGroups, StructArrayReader,
+    make_byte_array_reader, ArrayReader, DictionaryCache, FixedSizeListArrayReader,
+    ListArrayReader, MapArrayReader, NullArrayReader, PredicateCache, PrimitiveArrayReader,
+    RowGroups, StructArrayReader,
 };
+#[cfg(feature = "variant_experimental")]
+use crate::arrow::array_reader::VariantArrayReader;
+use crate::arrow::arrow_reader::Int96Overflow;
 use crate::arrow::schema::{ParquetField, ParquetFieldType};
 use crate::arrow::ProjectionMask;
 use crate::basic::Type as PhysicalType;
@@ -37,11 +41,56 @@ use crate::schema::types::{ColumnDescriptor, ColumnPath, Type};
 /// Builds [`ArrayReader`]s from parquet schema, projection mask, and RowGroups reader
 pub struct ArrayReaderBuilder<'a> {
     row_groups: &'a dyn RowGroups,
+    dictionary_cache: Option<DictionaryCache>,
+    predicate_cache: Option<&'a PredicateCache>,
+    parallelism: usize,
+    int96_overflow: Int96Overflow,
 }
 
 impl<'a> ArrayReaderBuilder<'a> {
     pub fn new(row_groups: &'a dyn RowGroups) -> Self {
-        Self { row_groups }
+        Self {
+            row_groups,
+            dictionary_cache: None,
+            predicate_cache: None,
+            parallelism: 1,
+            int96_overflow: Int96Overflow::default(),
+        }
+    }
+
+    /// Return equal dictionaries of dictionary encoded columns as the same values
+    /// array, including those returned by other readers sharing `dictionary_cache`
+    pub(crate) fn with_dictionary_cache(self, dictionary_cache: Option<DictionaryCache>) -> Self {
+        Self {
+            dictionary_cache,
+            ..self
+        }
+    }
+
+    /// Return the top-level columns cached in `predicate_cache` instead of decoding them
+    #[cfg_attr(not(feature = "async"), allow(dead_code))]
+    pub(crate) fn with_predicate_cache(self, predicate_cache: &'a PredicateCache) -> Self {
+        Self {
+            predicate_cache: Some(predicate_cache),
+            ..self
+        }
+    }
+
+    /// Decode the top-level columns using up to `parallelism` threads, see
+    /// [`StructArrayReader::with_parallelism`]
+    pub(crate) fn with_parallelism(self, parallelism: usize) -> Self {
+        Self {
+            parallelism,
+            ..self
+        }
+    }
+
+    /// Set how INT96 timestamps out of range for their arrow type are handled
+    pub(crate) fn with_int96_overflow(self, int96_overflow: Int96Overflow) -> Self {
+        Self {
+            int96_overflow,
+            ..self
+        }
     }
 
     /// Create [`ArrayReader`] from parquet schema, projection mask, and parquet file reader.
@@ -51,7 +100,7 @@ impl<'a> ArrayReaderBuilder<'a> {
         mask: &ProjectionMask,
     ) -> Result<Box<dyn ArrayReader>> {
         let reader = field
-            .and_then(|field| self.build_reader(field, mask).transpose())
+            .and_then(|field| self.build_root_reader(field, mask).transpose())
             .transpose()?
             .unwrap_or_else(|| make_empty_array_reader(self.num_rows()));
 
@@ -63,6 +112,21 @@ impl<'a> ArrayReaderBuilder<'a> {
         self.row_groups.num_rows()
     }
 
+    /// Build the reader for the root of the schema, decoding its children in parallel
+    /// if configured
+    fn build_root_reader(
+        &self,
+        field: &ParquetField,
+        mask: &ProjectionMask,
+    ) -> Result<Option<Box<dyn ArrayReader>>> {
+        match (&field.field_type, &field.arrow_type) {
+            (ParquetFieldType::Group { .. }, DataType::Struct(_)) => {
+                self.build_struct_reader(field, mask, self.parallelism)
+            }
+            _ => self.build_reader(field, mask),
+        }
+    }
+
     fn build_reader(
         &self,
         field: &ParquetField,
@@ -72,15 +136,32 @@ impl<'a> ArrayReaderBuilder<'a> {
             ParquetFieldType::Primitive { .. } => self.build_primitive_reader(field, mask),
             ParquetFieldType::Group { .. } => match &field.arrow_type {
                 DataType::Map(_, _) => self.build_map_reader(field, mask),
-                DataType::Struct(_) => self.build_struct_reader(field, mask),
+                DataType::Struct(_) => self.build_struct_reader(field, mask, 1),
                 DataType::List(_) => self.build_list_reader(field, mask, false),
                 DataType::LargeList(_) => self.build_list_reader(field, mask, true),
                 DataType::FixedSizeList(_, _) => self.build_fixed_size_list_reader(field, mask),
                 d => unimplemented!("reading group type {} not implemented", d),
             },
+            #[cfg(feature = "variant_experimental")]
+            ParquetFieldType::Variant { ref source } => self.build_variant_reader(field, source, mask),
         }
     }
 
+    /// Build a reader decoding `source` to the variant arrays of `field`
+    #[cfg(feature = "variant_experimental")]
+    fn build_variant_reader(
+        &self,
+        field: &ParquetField,
+        source: &ParquetField,
+        mask: &ProjectionMask,
+    ) -> Result<Option<Box<dyn ArrayReader>>> {
+        let Some(reader) = self.build_reader(source, mask)? else {
+            return Ok(None);
+        };
+        let reader = VariantArrayReader::try_new(reader, field.arrow_type.clone())?;
+        Ok(Some(Box::new(reader)))
+    }
+
     /// Build array reader for map type.
     fn build_map_reader(
         &self,
@@ -237,6 +318,11 @@ impl<'a> ArrayReaderBuilder<'a> {
             return Ok(None);
         }
 
+        let cached = self.predicate_cache.and_then(|c| c.reader(col_idx, self.num_rows()));
+        if let Some(reader) = cached {
+            return Ok(Some(Box::new(reader)));
+        }
+
         let physical_type = primitive_type.get_physical_type();
 
         // We don't track the column path in ParquetField as it adds a potential source
@@ -280,11 +366,10 @@ impl<'a> ArrayReaderBuilder<'a> {
                 column_desc,
                 arrow_type,
             )?) as _,
-            PhysicalType::INT96 => Box::new(PrimitiveArrayReader::<Int96Type>::new(
-                page_iterator,
-                column_desc,
-                arrow_type,
-            )?) as _,
+            PhysicalType::INT96 => Box::new(
+                PrimitiveArrayReader::<Int96Type>::new(page_iterator, column_desc, arrow_type)?
+                    .with_int96_overflow(self.int96_overflow),
+            ) as _,
             PhysicalType::FLOAT => Box::new(PrimitiveArrayReader::<FloatType>::new(
                 page_iterator,
                 column_desc,
@@ -296,18 +381,24 @@ impl<'a> ArrayReaderBuilder<'a> {
                 arrow_type,
             )?) as _,
             PhysicalType::BYTE_ARRAY => match arrow_type {
-                Some(DataType::Dictionary(_, _)) => {
-                    make_byte_array_dictionary_reader(page_iterator, column_desc, arrow_type)?
-                }
+                Some(DataType::Dictionary(_, _)) => make_cached_byte_array_dictionary_reader(
+                    page_iterator,
+                    column_desc,
+                    arrow_type,
+                    self.dictionary_cache.clone(),
+                )?,
                 Some(DataType::Utf8View | DataType::BinaryView) => {
                     make_byte_view_array_reader(page_iterator, column_desc, arrow_type)?
                 }
                 _ => make_byte_array_reader(page_iterator, column_desc, arrow_type)?,
             },
             PhysicalType::FIXED_LEN_BYTE_ARRAY => match arrow_type {
-                Some(DataType::Dictionary(_, _)) => {
-                    make_byte_array_dictionary_reader(page_iterator, column_desc, arrow_type)?
-                }
+                Some(DataType::Dictionary(_, _)) => make_cached_byte_array_dictionary_reader(
+                    page_iterator,
+                    column_desc,
+                    arrow_type,
+                    self.dictionary_cache.clone(),
+                )?,
                 _ => make_fixed_len_byte_array_reader(page_iterator, column_desc, arrow_type)?,
             },
         };
@@ -318,6 +409,7 @@ impl<'a> ArrayReaderBuilder<'a> {
         &self,
         field: &ParquetField,
         mask: &ProjectionMask,
+        parallelism: usize,
     ) -> Result<Option<Box<dyn ArrayReader>>> {
         let arrow_fields = match &field.arrow_type {
             DataType::Struct(children) => children,
@@ -342,13 +434,16 @@ impl<'a> ArrayReaderBuilder<'a> {
             return Ok(None);
         }
 
-        Ok(Some(Box::new(StructArrayReader::new(
-            DataType::Struct(builder.finish().fields),
-            readers,
-            field.def_level,
-            field.rep_level,
-            field.nullable,
-        ))))
+        Ok(Some(Box::new(
+            StructArrayReader::new(
+                DataType::Struct(builder.finish().fields),
+                readers,
+                field.def_level,
+                field.rep_level,
+                field.nullable,
+            )
+            .with_parallelism(parallelism),
+        )))
     }
 }
 
@@ -372,6 +467,7 @@ mod tests {
             file_metadata.schema_descr(),
             ProjectionMask::all(),
             file_metadata.key_value_metadata(),
+            None,
         )
         .unwrap();
 
@@ -16,11 +16,13 @@
 // under the License.
 
 use std::any::Any;
+use std::collections::HashMap;
 use std::marker::PhantomData;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
-use arrow_array::{new_empty_array, Array, ArrayRef, OffsetSizeTrait};
+use arrow_array::{make_array, new_empty_array, Array, ArrayRef, OffsetSizeTrait};
 use arrow_buffer::ArrowNativeType;
+use arrow_data::ArrayData;
 use arrow_schema::DataType as ArrowType;
 use bytes::Bytes;
 
@@ -34,22 +36,23 @@ use crate::column::page::PageIterator;
 use crate::column::reader::decoder::ColumnValueDecoder;
 use crate::encodings::rle::RleDecoder;
 use crate::errors::{ParquetError, Result};
-use crate::schema::types::ColumnDescPtr;
+use crate::schema::types::{ColumnDescPtr, ColumnPath};
 use crate::util::bit_util::FromBytes;
 
 /// A macro to reduce verbosity of [`make_byte_array_dictionary_reader`]
 macro_rules! make_reader {
     (
-        ($pages:expr, $column_desc:expr, $data_type:expr) => match ($k:expr, $v:expr) {
+        ($pages:expr, $column_desc:expr, $data_type:expr, $cache:expr) => match ($k:expr, $v:expr) {
             $(($key_arrow:pat, $value_arrow:pat) => ($key_type:ty, $value_type:ty),)+
         }
     ) => {
         match (($k, $v)) {
             $(
                 ($key_arrow, $value_arrow) => {
+                    let cache = $cache.map(|cache| (cache, $column_desc.path().clone()));
                     let reader = GenericRecordReader::new($column_desc);
                     Ok(Box::new(ByteArrayDictionaryReader::<$key_type, $value_type>::new(
-                        $pages, $data_type, reader,
+                        $pages, $data_type, reader, cache,
                     )))
                 }
             )+
@@ -73,10 +76,23 @@ macro_rules! make_reader {
 /// It is therefore recommended that if `pages` contains data from multiple column chunks,
 /// that the read batch size used is a divisor of the row group size
 ///
+#[allow(dead_code)] // Only used for benchmarks
 pub fn make_byte_array_dictionary_reader(
     pages: Box<dyn PageIterator>,
     column_desc: ColumnDescPtr,
     arrow_type: Option<ArrowType>,
+) -> Result<Box<dyn ArrayReader>> {
+    make_cached_byte_array_dictionary_reader(pages, column_desc, arrow_type, None)
+}
+
+/// Returns an [`ArrayReader`] that decodes the provided byte array column, like
+/// [`make_byte_array_dictionary_reader`], that returns the dictionaries in `cache`
+/// instead of equal dictionaries decoded from `pages`
+pub(crate) fn make_cached_byte_array_dictionary_reader(
+    pages: Box<dyn PageIterator>,
+    column_desc: ColumnDescPtr,
+    arrow_type: Option<ArrowType>,
+    cache: Option<DictionaryCache>,
 ) -> Result<Box<dyn ArrayReader>> {
     // Check if Arrow type is specified, else create it from Parquet type
     let data_type = match arrow_type {
@@ -89,7 +105,7 @@ pub fn make_byte_array_dictionary_reader(
     match &data_type {
         ArrowType::Dictionary(key_type, value_type) => {
             make_reader! {
-                (pages, column_desc, data_type) => match (key_type.as_ref(), value_type.as_ref()) {
+                (pages, column_desc, data_type, cache) => match (key_type.as_ref(), value_type.as_ref()) {
                     (ArrowType::UInt8, ArrowType::Binary | ArrowType::Utf8 | ArrowType::FixedSizeBinary(_)) => (u8, i32),
                     (ArrowType::UInt8, ArrowType::LargeBinary | ArrowType::LargeUtf8) => (u8, i64),
                     (ArrowType::Int8, ArrowType::Binary | ArrowType::Utf8 | ArrowType::FixedSizeBinary(_)) => (i8, i32),
@@ -116,6 +132,53 @@ pub fn make_byte_array_dictionary_reader(
     }
 }
 
+/// The dictionaries most recently returned for each column, shared between
+/// [`ArrayReader`]s so that identical dictionaries in different column chunks are
+/// returned as the same values array
+///
+/// This allows consumers to detect unchanged dictionaries by pointer comparison,
+/// for example to avoid re-sending them over Arrow IPC.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct DictionaryCache {
+    dictionaries: Arc<Mutex<HashMap<ColumnPath, CachedDictionary>>>,
+}
+
+#[derive(Debug)]
+struct CachedDictionary {
+    /// The values most recently decoded, which are equal to `shared`
+    decoded: ArrayData,
+    /// The values returned in place of equal decoded values
+    shared: ArrayData,
+}
+
+impl DictionaryCache {
+    /// Returns `array`, a dictionary array of `column`, with its values replaced
+    /// by those of a previously returned array if they are equal
+    fn intern(&self, column: &ColumnPath, array: ArrayRef) -> ArrayRef {
+        let data = array.to_data();
+        let values = &data.child_data()[0];
+
+        let mut dictionaries = self.dictionaries.lock().unwrap();
+        match dictionaries.get_mut(column) {
+            Some(cached) if cached.shared.ptr_eq(values) => array,
+            Some(cached) if cached.decoded.ptr_eq(values) || &cached.shared == values => {
+                cached.decoded = values.clone();
+                let builder = data.into_builder().child_data(vec![cached.shared.clone()]);
+                // SAFETY: the values are equal to those of the valid array `data`
+                make_array(unsafe { builder.build_unchecked() })
+            }
+            _ => {
+                let cached = CachedDictionary {
+                    decoded: values.clone(),
+                    shared: values.clone(),
+                };
+                dictionaries.insert(column.clone(), cached);
+                array
+            }
+        }
+    }
+}
+
 /// An [`ArrayReader`] for dictionary encoded variable length byte arrays
 ///
 /// Will attempt to preserve any dictionary encoding present in the parquet data
@@ -125,6 +188,8 @@ struct ByteArrayDictionaryReader<K: ArrowNativeType, V: OffsetSizeTrait> {
     def_levels_buffer: Option<Vec<i16>>,
     rep_levels_buffer: Option<Vec<i16>>,
     record_reader: GenericRecordReader<DictionaryBuffer<K, V>, DictionaryDecoder<K, V>>,
+    /// If provided, the cache of dictionaries to return, and the path of this column
+    cache: Option<(DictionaryCache, ColumnPath)>,
 }
 
 impl<K, V> ByteArrayDictionaryReader<K, V>
@@ -136,6 +201,7 @@ where
         pages: Box<dyn PageIterator>,
         data_type: ArrowType,
         record_reader: GenericRecordReader<DictionaryBuffer<K, V>, DictionaryDecoder<K, V>>,
+        cache: Option<(DictionaryCache, ColumnPath)>,
     ) -> Self {
         Self {
             data_type,
@@ -143,6 +209,7 @@ where
             def_levels_buffer: None,
             rep_levels_buffer: None,
             record_reader,
+            cache,
         }
     }
 }
@@ -175,6 +242,10 @@ where
         let buffer = self.record_reader.consume_record_data();
         let null_buffer = self.record_reader.consume_bitmap_buffer();
         let array = buffer.into_array(null_buffer, &self.data_type)?;
+        let array = match &self.cache {
+            Some((cache, column)) => cache.intern(column, array),
+            None => array,
+        };
 
         self.def_levels_buffer = self.record_reader.consume_def_levels();
         self.rep_levels_buffer = self.record_reader.consume_rep_levels();
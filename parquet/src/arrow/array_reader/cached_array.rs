@@ -0,0 +1,271 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::ops::Range;
+
+use arrow_array::{new_empty_array, Array, ArrayRef, RecordBatch};
+use arrow_schema::DataType as ArrowType;
+use arrow_select::concat::concat;
+
+use crate::arrow::array_reader::ArrayReader;
+use crate::arrow::arrow_reader::RowSelection;
+use crate::arrow::schema::{ParquetField, ParquetFieldType};
+use crate::arrow::ProjectionMask;
+use crate::errors::{ParquetError, Result};
+
+/// The top-level primitive columns of a row group decoded whilst evaluating
+/// predicates, keyed by leaf column index
+///
+/// See [`RowFilter::with_predicate_cache`](crate::arrow::arrow_reader::RowFilter::with_predicate_cache)
+#[derive(Debug, Default)]
+pub(crate) struct PredicateCache {
+    columns: HashMap<usize, CachedColumn>,
+}
+
+#[derive(Debug)]
+struct CachedColumn {
+    /// The ranges of rows of the row group decoded into `values`, in order
+    ranges: Vec<Range<usize>>,
+    values: ArrayRef,
+}
+
+impl PredicateCache {
+    /// Caches the top-level primitive columns of `batches`, decoded for the columns
+    /// of `mask` of the `root` schema and the rows of `selection`
+    ///
+    /// A `selection` of `None` selects all `num_rows` rows of the row group
+    #[cfg_attr(not(feature = "async"), allow(dead_code))]
+    pub(crate) fn insert(
+        &mut self,
+        root: &ParquetField,
+        mask: &ProjectionMask,
+        selection: Option<&RowSelection>,
+        num_rows: usize,
+        batches: &[RecordBatch],
+    ) -> Result<()> {
+        let Some(children) = root.children().filter(|_| !batches.is_empty()) else {
+            return Ok(());
+        };
+
+        let ranges = match selection {
+            Some(selection) => selected_ranges(selection),
+            None => std::iter::once(0..num_rows).collect(),
+        };
+        let decoded: usize = ranges.iter().map(|r| r.len()).sum();
+
+        // The columns of the batches are the children with any leaf in `mask`
+        let included = children.iter().filter(|child| includes_any(child, mask));
+        for (column, child) in included.enumerate() {
+            let ParquetFieldType::Primitive { col_idx, .. } = &child.field_type else {
+                continue;
+            };
+            let values = match batches {
+                [batch] => batch.column(column).clone(),
+                _ => {
+                    let arrays: Vec<_> = batches.iter().map(|b| b.column(column).as_ref()).collect();
+                    concat(&arrays)?
+                }
+            };
+            // Should not occur, but cannot be used as the rows would be misaligned
+            if values.len() != decoded {
+                continue;
+            }
+            let ranges = ranges.clone();
+            self.columns
+                .insert(*col_idx, CachedColumn { ranges, values });
+        }
+        Ok(())
+    }
+
+    /// Returns a [`CachedArrayReader`] for the leaf column `col_idx` of a row
+    /// group of `num_rows` rows, if cached
+    pub(crate) fn reader(&self, col_idx: usize, num_rows: usize) -> Option<CachedArrayReader> {
+        let column = self.columns.get(&col_idx)?;
+        Some(CachedArrayReader {
+            data_type: column.values.data_type().clone(),
+            values: column.values.clone(),
+            ranges: column.ranges.clone(),
+            range_idx: 0,
+            range_offset: 0,
+            position: 0,
+            num_rows,
+            buffered: vec![],
+        })
+    }
+}
+
+/// Returns the ranges of rows selected by `selection`
+#[cfg_attr(not(feature = "async"), allow(dead_code))]
+fn selected_ranges(selection: &RowSelection) -> Vec<Range<usize>> {
+    let mut ranges = vec![];
+    let mut start = 0;
+    for selector in selection.iter() {
+        let end = start + selector.row_count;
+        if !selector.skip && end > start {
+            ranges.push(start..end);
+        }
+        start = end;
+    }
+    ranges
+}
+
+/// Returns true if `field` contains any leaf column included by `mask`
+#[cfg_attr(not(feature = "async"), allow(dead_code))]
+fn includes_any(field: &ParquetField, mask: &ProjectionMask) -> bool {
+    match &field.field_type {
+        ParquetFieldType::Primitive { col_idx, .. } => mask.leaf_included(*col_idx),
+        ParquetFieldType::Group { children } => children.iter().any(|c| includes_any(c, mask)),
+        #[cfg(feature = "variant_experimental")]
+        ParquetFieldType::Variant { source } => includes_any(source, mask),
+    }
+}
+
+/// An [`ArrayReader`] returning the rows of a top-level column from a [`PredicateCache`]
+///
+/// As the column has no parent other than the root, no definition or repetition levels
+/// are returned. Reading a row that was not decoded by the predicate is an error.
+pub(crate) struct CachedArrayReader {
+    data_type: ArrowType,
+    values: ArrayRef,
+    /// The ranges of rows of the row group in `values`
+    ranges: Vec<Range<usize>>,
+    /// The index of the first range in `ranges` that does not end before `position`
+    range_idx: usize,
+    /// The offset in `values` of the start of `ranges[range_idx]`
+    range_offset: usize,
+    /// The next row of the row group to read or skip
+    position: usize,
+    /// The number of rows in the row group
+    num_rows: usize,
+    /// The slices of `values` read but not yet consumed
+    buffered: Vec<ArrayRef>,
+}
+
+impl ArrayReader for CachedArrayReader {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_data_type(&self) -> &ArrowType {
+        &self.data_type
+    }
+
+    fn read_records(&mut self, batch_size: usize) -> Result<usize> {
+        let end = self.num_rows.min(self.position.saturating_add(batch_size));
+        let read = end.saturating_sub(self.position);
+        while self.position < end {
+            while let Some(range) = self.ranges.get(self.range_idx) {
+                if range.end > self.position {
+                    break;
+                }
+                self.range_offset += range.len();
+                self.range_idx += 1;
+            }
+
+            let range = match self.ranges.get(self.range_idx) {
+                Some(range) if range.start <= self.position => range,
+                _ => return Err(general_err!("row {} was not cached", self.position)),
+            };
+            let len = end.min(range.end) - self.position;
+            let offset = self.range_offset + self.position - range.start;
+            self.buffered.push(self.values.slice(offset, len));
+            self.position += len;
+        }
+        Ok(read)
+    }
+
+    fn consume_batch(&mut self) -> Result<ArrayRef> {
+        let buffered = std::mem::take(&mut self.buffered);
+        Ok(match buffered.len() {
+            0 => new_empty_array(&self.data_type),
+            1 => buffered.into_iter().next().unwrap(),
+            _ => {
+                let arrays: Vec<_> = buffered.iter().map(|a| a.as_ref()).collect();
+                concat(&arrays)?
+            }
+        })
+    }
+
+    fn skip_records(&mut self, num_records: usize) -> Result<usize> {
+        let skipped = num_records.min(self.num_rows.saturating_sub(self.position));
+        self.position += skipped;
+        Ok(skipped)
+    }
+
+    fn get_def_levels(&self) -> Option<&[i16]> {
+        None
+    }
+
+    fn get_rep_levels(&self) -> Option<&[i16]> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrow::arrow_reader::RowSelector;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int32Type;
+    use arrow_array::Int32Array;
+
+    #[test]
+    fn test_cached_array_reader() {
+        let column = CachedColumn {
+            ranges: vec![2..5, 7..10],
+            values: std::sync::Arc::new(Int32Array::from_iter_values([2, 3, 4, 7, 8, 9])),
+        };
+        let mut cache = PredicateCache::default();
+        cache.columns.insert(1, column);
+        assert!(cache.reader(0, 12).is_none());
+
+        let mut reader = cache.reader(1, 12).unwrap();
+        assert_eq!(reader.skip_records(3).unwrap(), 3);
+        assert_eq!(reader.read_records(2).unwrap(), 2);
+        assert_eq!(reader.skip_records(3).unwrap(), 3);
+        assert_eq!(reader.read_records(1).unwrap(), 1);
+        let array = reader.consume_batch().unwrap();
+        assert_eq!(array.as_primitive::<Int32Type>().values(), &[3, 4, 8]);
+
+        assert_eq!(reader.read_records(1).unwrap(), 1);
+        let array = reader.consume_batch().unwrap();
+        assert_eq!(array.as_primitive::<Int32Type>().values(), &[9]);
+
+        // Rows that were not decoded cannot be read
+        let err = reader.read_records(2).unwrap_err();
+        assert_eq!(err.to_string(), "Parquet error: row 10 was not cached");
+
+        let mut reader = cache.reader(1, 12).unwrap();
+        assert_eq!(reader.skip_records(20).unwrap(), 12);
+        assert_eq!(reader.read_records(1).unwrap(), 0);
+        assert_eq!(reader.consume_batch().unwrap().len(), 0);
+    }
+
+    #[test]
+    fn test_selected_ranges() {
+        let selection = RowSelection::from(vec![
+            RowSelector::skip(2),
+            RowSelector::select(3),
+            RowSelector::skip(2),
+            RowSelector::select(0),
+            RowSelector::select(3),
+        ]);
+        assert_eq!(selected_ranges(&selection), vec![2..5, 7..10]);
+    }
+}
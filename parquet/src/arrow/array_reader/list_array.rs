@@ -560,6 +560,7 @@ mod tests {
             schema,
             ProjectionMask::all(),
             file_metadata.key_value_metadata(),
+            None,
         )
         .unwrap();
 
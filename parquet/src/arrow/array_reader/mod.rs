@@ -33,6 +33,7 @@ mod builder;
 mod byte_array;
 mod byte_array_dictionary;
 mod byte_view_array;
+mod cached_array;
 mod empty_array;
 mod fixed_len_byte_array;
 mod fixed_size_list_array;
@@ -41,6 +42,8 @@ mod map_array;
 mod null_array;
 mod primitive_array;
 mod struct_array;
+#[cfg(feature = "variant_experimental")]
+mod variant_array;
 
 #[cfg(test)]
 mod test_util;
@@ -48,7 +51,10 @@ mod test_util;
 // Note that this crate is public under the `experimental` feature flag.
 pub use builder::ArrayReaderBuilder;
 pub use byte_array::make_byte_array_reader;
+#[allow(unused_imports)] // Only used for benchmarks
 pub use byte_array_dictionary::make_byte_array_dictionary_reader;
+pub(crate) use byte_array_dictionary::DictionaryCache;
+pub(crate) use cached_array::PredicateCache;
 #[allow(unused_imports)] // Only used for benchmarks
 pub use byte_view_array::make_byte_view_array_reader;
 #[allow(unused_imports)] // Only used for benchmarks
@@ -59,6 +65,8 @@ pub use map_array::MapArrayReader;
 pub use null_array::NullArrayReader;
 pub use primitive_array::PrimitiveArrayReader;
 pub use struct_array::StructArrayReader;
+#[cfg(feature = "variant_experimental")]
+pub use variant_array::VariantArrayReader;
 
 /// Array reader reads parquet data into arrow array.
 pub trait ArrayReader: Send {
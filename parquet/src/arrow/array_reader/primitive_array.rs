@@ -16,6 +16,7 @@
 // under the License.
 
 use crate::arrow::array_reader::{read_records, skip_records, ArrayReader};
+use crate::arrow::arrow_reader::Int96Overflow;
 use crate::arrow::record_reader::RecordReader;
 use crate::arrow::schema::parquet_to_arrow_field;
 use crate::basic::Type as PhysicalType;
@@ -28,12 +29,12 @@ use arrow_array::{
         TimestampMicrosecondBufferBuilder, TimestampMillisecondBufferBuilder,
         TimestampNanosecondBufferBuilder, TimestampSecondBufferBuilder,
     },
-    ArrayRef, BooleanArray, Decimal128Array, Decimal256Array, Float32Array, Float64Array,
-    Int16Array, Int32Array, Int64Array, Int8Array, TimestampMicrosecondArray,
-    TimestampMillisecondArray, TimestampNanosecondArray, TimestampSecondArray, UInt16Array,
-    UInt32Array, UInt64Array, UInt8Array,
+    ArrayRef, BooleanArray, Decimal128Array, Decimal256Array, Decimal32Array, Decimal64Array,
+    Float32Array, Float64Array, Int16Array, Int32Array, Int64Array, Int8Array,
+    TimestampMicrosecondArray, TimestampMillisecondArray, TimestampNanosecondArray,
+    TimestampSecondArray, UInt16Array, UInt32Array, UInt64Array, UInt8Array,
 };
-use arrow_buffer::{i256, BooleanBuffer, Buffer};
+use arrow_buffer::{bit_util, i256, BooleanBuffer, Buffer, BufferBuilder};
 use arrow_data::ArrayDataBuilder;
 use arrow_schema::{DataType as ArrowType, TimeUnit};
 use std::any::Any;
@@ -42,6 +43,22 @@ use std::sync::Arc;
 /// Provides conversion from `Vec<T>` to `Buffer`
 pub trait IntoBuffer {
     fn into_buffer(self, target_type: &ArrowType) -> Buffer;
+
+    /// Like [`Self::into_buffer`], but handles values that cannot be represented
+    /// in `target_type` according to `overflow`
+    ///
+    /// `mask` identifies the values that are not padding for null slots
+    fn try_into_buffer(
+        self,
+        target_type: &ArrowType,
+        _overflow: Int96Overflow,
+        _mask: Option<&Buffer>,
+    ) -> Result<Buffer>
+    where
+        Self: Sized,
+    {
+        Ok(self.into_buffer(target_type))
+    }
 }
 
 macro_rules! native_buffer {
@@ -95,6 +112,47 @@ impl IntoBuffer for Vec<Int96> {
             _ => unreachable!("Invalid target_type for Int96."),
         }
     }
+
+    fn try_into_buffer(
+        self,
+        target_type: &ArrowType,
+        overflow: Int96Overflow,
+        mask: Option<&Buffer>,
+    ) -> Result<Buffer> {
+        let convert: fn(&Int96) -> Option<i64> = match target_type {
+            ArrowType::Timestamp(TimeUnit::Second, _) => Int96::checked_to_seconds,
+            ArrowType::Timestamp(TimeUnit::Millisecond, _) => Int96::checked_to_millis,
+            ArrowType::Timestamp(TimeUnit::Microsecond, _) => Int96::checked_to_micros,
+            ArrowType::Timestamp(TimeUnit::Nanosecond, _) => Int96::checked_to_nanos,
+            _ => unreachable!("Invalid target_type for Int96."),
+        };
+
+        if overflow == Int96Overflow::Wrap {
+            return Ok(self.into_buffer(target_type));
+        }
+
+        let mut builder = BufferBuilder::<i64>::new(self.len());
+        for (idx, v) in self.iter().enumerate() {
+            let value = match convert(v) {
+                Some(value) => value,
+                // Seconds since epoch cannot overflow, and give the direction to saturate in
+                None if overflow == Int96Overflow::Saturate => match v.to_seconds() < 0 {
+                    true => i64::MIN,
+                    false => i64::MAX,
+                },
+                None if mask.is_some_and(|m| !bit_util::get_bit(m.as_slice(), idx)) => 0,
+                None => {
+                    return Err(general_err!(
+                        "INT96 timestamp with {} seconds since epoch out of range for {}",
+                        v.to_seconds(),
+                        target_type
+                    ))
+                }
+            };
+            builder.append(value);
+        }
+        Ok(builder.finish())
+    }
 }
 
 /// Primitive array readers are leaves of array reader tree. They accept page iterator
@@ -110,6 +168,7 @@ where
     def_levels_buffer: Option<Vec<i16>>,
     rep_levels_buffer: Option<Vec<i16>>,
     record_reader: RecordReader<T>,
+    int96_overflow: Int96Overflow,
 }
 
 impl<T> PrimitiveArrayReader<T>
@@ -140,8 +199,17 @@ where
             def_levels_buffer: None,
             rep_levels_buffer: None,
             record_reader,
+            int96_overflow: Int96Overflow::default(),
         })
     }
+
+    /// Set how INT96 timestamps out of range for the arrow type are handled
+    pub(crate) fn with_int96_overflow(self, int96_overflow: Int96Overflow) -> Self {
+        Self {
+            int96_overflow,
+            ..self
+        }
+    }
 }
 
 /// Implementation of primitive array reader.
@@ -205,15 +273,17 @@ where
         // Convert to arrays by using the Parquet physical type.
         // The physical types are then cast to Arrow types if necessary
 
-        let record_data = self
-            .record_reader
-            .consume_record_data()
-            .into_buffer(target_type);
+        let (nulls, mask) = self.record_reader.consume_bitmap_and_mask();
+        let record_data = self.record_reader.consume_record_data().try_into_buffer(
+            target_type,
+            self.int96_overflow,
+            mask.as_ref(),
+        )?;
 
         let array_data = ArrayDataBuilder::new(arrow_data_type)
             .len(self.record_reader.num_values())
             .add_buffer(record_data)
-            .null_bit_buffer(self.record_reader.consume_bitmap_buffer());
+            .null_bit_buffer(nulls);
 
         let array_data = unsafe { array_data.build_unchecked() };
         let array: ArrayRef = match T::get_physical_type() {
@@ -306,6 +376,55 @@ where
                 let a = arrow_cast::cast(&array, &ArrowType::Date32)?;
                 arrow_cast::cast(&a, target_type)?
             }
+            ArrowType::Decimal32(p, s) => {
+                // The precision of a Decimal32 fits in an `i32`, so truncating an INT64 is lossless
+                let array = match array.data_type() {
+                    ArrowType::Int32 => array
+                        .as_any()
+                        .downcast_ref::<Int32Array>()
+                        .unwrap()
+                        .unary(|i| i) as Decimal32Array,
+                    ArrowType::Int64 => array
+                        .as_any()
+                        .downcast_ref::<Int64Array>()
+                        .unwrap()
+                        .unary(|i| i as i32)
+                        as Decimal32Array,
+                    _ => {
+                        return Err(arrow_err!(
+                            "Cannot convert {:?} to decimal",
+                            array.data_type()
+                        ));
+                    }
+                }
+                .with_precision_and_scale(*p, *s)?;
+
+                Arc::new(array) as ArrayRef
+            }
+            ArrowType::Decimal64(p, s) => {
+                let array = match array.data_type() {
+                    ArrowType::Int32 => array
+                        .as_any()
+                        .downcast_ref::<Int32Array>()
+                        .unwrap()
+                        .unary(|i| i as i64)
+                        as Decimal64Array,
+                    ArrowType::Int64 => array
+                        .as_any()
+                        .downcast_ref::<Int64Array>()
+                        .unwrap()
+                        .unary(|i| i) as Decimal64Array,
+                    _ => {
+                        return Err(arrow_err!(
+                            "Cannot convert {:?} to decimal",
+                            array.data_type()
+                        ));
+                    }
+                }
+                .with_precision_and_scale(*p, *s)?;
+
+                Arc::new(array) as ArrayRef
+            }
             ArrowType::Decimal128(p, s) => {
                 // Apply conversion to all elements regardless of null slots as the conversion
                 // to `i128` is infallible. This improves performance by avoiding a branch in
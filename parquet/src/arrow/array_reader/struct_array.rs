@@ -16,6 +16,7 @@
 // under the License.
 
 use crate::arrow::array_reader::ArrayReader;
+use crate::arrow::map_parallel;
 use crate::errors::{ParquetError, Result};
 use arrow_array::{builder::BooleanBufferBuilder, Array, ArrayRef, StructArray};
 use arrow_data::{ArrayData, ArrayDataBuilder};
@@ -30,6 +31,7 @@ pub struct StructArrayReader {
     struct_def_level: i16,
     struct_rep_level: i16,
     nullable: bool,
+    parallelism: usize,
 }
 
 impl StructArrayReader {
@@ -47,6 +49,20 @@ impl StructArrayReader {
             struct_def_level: def_level,
             struct_rep_level: rep_level,
             nullable,
+            parallelism: 1,
+        }
+    }
+
+    /// Set the number of threads used to decode the children of this reader
+    /// (defaults to `1`)
+    ///
+    /// When greater than `1`, and the `rayon` feature is enabled, the children are
+    /// divided between up to `parallelism` tasks on the global rayon thread pool,
+    /// which read and decode them concurrently.
+    pub fn with_parallelism(self, parallelism: usize) -> Self {
+        Self {
+            parallelism: parallelism.max(1),
+            ..self
         }
     }
 }
@@ -63,9 +79,13 @@ impl ArrayReader for StructArrayReader {
     }
 
     fn read_records(&mut self, batch_size: usize) -> Result<usize> {
+        let children = self.children.iter_mut().collect();
+        let children_read = map_parallel(children, self.parallelism, |child| {
+            child.read_records(batch_size)
+        })?;
+
         let mut read = None;
-        for child in self.children.iter_mut() {
-            let child_read = child.read_records(batch_size)?;
+        for child_read in children_read {
             match read {
                 Some(expected) => {
                     if expected != child_read {
@@ -105,11 +125,10 @@ impl ArrayReader for StructArrayReader {
             return Ok(Arc::new(StructArray::from(Vec::new())));
         }
 
-        let children_array = self
-            .children
-            .iter_mut()
-            .map(|reader| reader.consume_batch())
-            .collect::<Result<Vec<_>>>()?;
+        let children = self.children.iter_mut().collect();
+        let children_array = map_parallel(children, self.parallelism, |reader| {
+            reader.consume_batch()
+        })?;
 
         // check that array child data has same size
         let children_array_len = children_array
@@ -177,9 +196,13 @@ impl ArrayReader for StructArrayReader {
     }
 
     fn skip_records(&mut self, num_records: usize) -> Result<usize> {
+        let children = self.children.iter_mut().collect();
+        let children_skipped = map_parallel(children, self.parallelism, |child| {
+            child.skip_records(num_records)
+        })?;
+
         let mut skipped = None;
-        for child in self.children.iter_mut() {
-            let child_skipped = child.skip_records(num_records)?;
+        for child_skipped in children_skipped {
             match skipped {
                 Some(expected) => {
                     if expected != child_skipped {
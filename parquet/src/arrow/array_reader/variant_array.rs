@@ -0,0 +1,546 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::{
+    Date32Type, Decimal128Type, Float32Type, Float64Type, Int16Type, Int32Type, Int64Type, Int8Type,
+    TimestampMicrosecondType,
+};
+use arrow_array::{Array, ArrayRef, GenericListArray, OffsetSizeTrait, StructArray};
+use arrow_schema::{ArrowError, DataType as ArrowType, TimeUnit};
+use chrono::DateTime;
+use parquet_variant::{
+    ListBuilder, ObjectBuilder, Variant, VariantBuilder, VariantBuilderExt,
+};
+use parquet_variant_compute::VariantArrayBuilder;
+use parquet_variant_json::json_to_variant;
+
+use crate::arrow::array_reader::ArrayReader;
+use crate::errors::{ParquetError, Result};
+
+/// Decodes the arrays read by another [`ArrayReader`] into variant arrays as
+/// each batch is consumed
+///
+/// The source reader either reads a column of JSON strings, which are parsed
+/// into variants, or a variant group of `metadata`, `value` and `typed_value`
+/// columns, which are reassembled into unshredded variants. Shredded objects and
+/// lists, whose `typed_value` is a group of fields or a list of elements that are
+/// themselves `value` and `typed_value` groups, are reassembled recursively.
+///
+/// This is not a fused decode: each batch is first fully decoded by the source
+/// reader into an intermediate arrow array, which is then converted to variants.
+pub struct VariantArrayReader {
+    data_type: ArrowType,
+    reader: Box<dyn ArrayReader>,
+}
+
+impl VariantArrayReader {
+    /// Construct a variant array reader decoding the arrays read by `reader`
+    ///
+    /// Returns an error if `reader` does not read [`ArrowType::Utf8`] strings or
+    /// a struct with a `metadata` field and a `value` or `typed_value` field,
+    /// which can occur if only some columns of a variant group are projected
+    pub fn try_new(reader: Box<dyn ArrayReader>, data_type: ArrowType) -> Result<Self> {
+        match reader.get_data_type() {
+            ArrowType::Utf8 => {}
+            ArrowType::Struct(fields)
+                if fields.find("metadata").is_some()
+                    && (fields.find("value").is_some() || fields.find("typed_value").is_some()) => {
+            }
+            d => {
+                return Err(arrow_err!(
+                    "Cannot decode {} to variant, variant groups must be fully projected",
+                    d
+                ))
+            }
+        }
+        Ok(Self { data_type, reader })
+    }
+}
+
+impl ArrayReader for VariantArrayReader {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_data_type(&self) -> &ArrowType {
+        &self.data_type
+    }
+
+    fn read_records(&mut self, batch_size: usize) -> Result<usize> {
+        self.reader.read_records(batch_size)
+    }
+
+    fn consume_batch(&mut self) -> Result<ArrayRef> {
+        let array = self.reader.consume_batch()?;
+        let variants = match array.data_type() {
+            ArrowType::Utf8 => json_to_variants(array.as_ref())?,
+            _ => group_to_variants(array.as_struct())?,
+        };
+        Ok(Arc::new(variants.into_inner()))
+    }
+
+    fn skip_records(&mut self, num_records: usize) -> Result<usize> {
+        self.reader.skip_records(num_records)
+    }
+
+    fn get_def_levels(&self) -> Option<&[i16]> {
+        self.reader.get_def_levels()
+    }
+
+    fn get_rep_levels(&self) -> Option<&[i16]> {
+        self.reader.get_rep_levels()
+    }
+}
+
+/// Parses an array of JSON strings into variants, preserving nulls
+fn json_to_variants(array: &dyn Array) -> Result<parquet_variant_compute::VariantArray> {
+    let strings = array.as_string::<i32>();
+    let mut builder = VariantArrayBuilder::new(strings.len());
+    for value in strings {
+        match value {
+            Some(json) => {
+                let mut variant_builder = VariantBuilder::new();
+                json_to_variant(json, &mut variant_builder)?;
+                let (metadata, value) = variant_builder.finish();
+                builder.append_variant_buffers(&metadata, &value);
+            }
+            None => builder.append_null(),
+        }
+    }
+    Ok(builder.build())
+}
+
+/// Reassembles the `metadata`, `value` and `typed_value` columns of a variant
+/// group into unshredded variants
+///
+/// A non-null `typed_value` takes precedence over `value`, and a row where both
+/// are null is decoded as [`Variant::Null`]
+fn group_to_variants(array: &StructArray) -> Result<parquet_variant_compute::VariantArray> {
+    let metadata = array.column_by_name("metadata").unwrap();
+    let value = array.column_by_name("value").map(|a| a.as_ref());
+    let typed_value = array.column_by_name("typed_value").map(|a| a.as_ref());
+
+    let mut builder = VariantArrayBuilder::new(array.len());
+    for idx in 0..array.len() {
+        if array.is_null(idx) {
+            builder.append_null();
+            continue;
+        }
+        if metadata.is_null(idx) {
+            return Err(arrow_err!("Variant metadata must not be null"));
+        }
+        let metadata = binary_value(metadata.as_ref(), idx);
+        match (typed_value, value) {
+            (Some(typed_value), _) if typed_value.is_valid(idx) => {
+                let mut variant_builder = VariantBuilder::new();
+                append_typed_value(&mut variant_builder, metadata, value, typed_value, idx)?;
+                let (metadata, value) = variant_builder.finish();
+                builder.append_variant_buffers(&metadata, &value);
+            }
+            (_, Some(value)) if value.is_valid(idx) => {
+                builder.append_variant_buffers(metadata, binary_value(value, idx))
+            }
+            _ => builder.append_variant(Variant::Null),
+        }
+    }
+    Ok(builder.build())
+}
+
+/// Appends the variant of a `value` and `typed_value` group at `idx` to `builder`
+///
+/// A row where both are null is appended as [`Variant::Null`]
+fn append_shredded<'a>(
+    builder: &mut impl VariantBuilderExt<'a, 'a>,
+    metadata: &'a [u8],
+    value: Option<&'a dyn Array>,
+    typed_value: Option<&'a dyn Array>,
+    idx: usize,
+) -> Result<()> {
+    match (typed_value, value) {
+        (Some(typed_value), _) if typed_value.is_valid(idx) => {
+            append_typed_value(builder, metadata, value, typed_value, idx)
+        }
+        (_, Some(value)) if value.is_valid(idx) => {
+            builder.append_value(decode_value(metadata, value, idx)?);
+            Ok(())
+        }
+        _ => {
+            builder.append_value(Variant::Null);
+            Ok(())
+        }
+    }
+}
+
+/// Appends the non-null `typed_value` at `idx` to `builder`
+///
+/// For a shredded object, `value` may contain an object of the fields that were not
+/// shredded, which are merged with the shredded fields
+fn append_typed_value<'a>(
+    builder: &mut impl VariantBuilderExt<'a, 'a>,
+    metadata: &'a [u8],
+    value: Option<&'a dyn Array>,
+    typed_value: &'a dyn Array,
+    idx: usize,
+) -> Result<()> {
+    match typed_value.data_type() {
+        ArrowType::Struct(_) => {
+            let unshredded = match value.filter(|v| v.is_valid(idx)) {
+                Some(value) => match decode_value(metadata, value, idx)? {
+                    Variant::Object(object) => Some(object),
+                    v => {
+                        return Err(arrow_err!(
+                            "Expected an object of unshredded fields for shredded object, got {:?}",
+                            v
+                        ))
+                    }
+                },
+                None => None,
+            };
+
+            let fields = typed_value.as_struct();
+            let mut object = builder.new_object();
+            for (field, group) in fields.fields().iter().zip(fields.columns()) {
+                let group = group.as_struct();
+                let value = group.column_by_name("value").map(|a| a.as_ref());
+                let typed_value = group.column_by_name("typed_value").map(|a| a.as_ref());
+                // A field where both are null is missing from the object
+                let present = group.is_valid(idx)
+                    && (value.is_some_and(|v| v.is_valid(idx))
+                        || typed_value.is_some_and(|v| v.is_valid(idx)));
+                if present {
+                    let mut field_builder = ObjectFieldBuilder {
+                        key: field.name(),
+                        builder: &mut object,
+                    };
+                    append_shredded(&mut field_builder, metadata, value, typed_value, idx)?;
+                }
+            }
+            for (name, variant) in unshredded.iter().flat_map(|o| o.iter()) {
+                if fields.column_by_name(name).is_some() {
+                    return Err(arrow_err!(
+                        "Shredded object field {} must not also be present in value",
+                        name
+                    ));
+                }
+                object.insert(name, variant);
+            }
+            object.finish().map_err(ArrowError::from)?;
+            Ok(())
+        }
+        ArrowType::List(_) => append_list(builder, metadata, typed_value.as_list::<i32>(), idx),
+        ArrowType::LargeList(_) => {
+            append_list(builder, metadata, typed_value.as_list::<i64>(), idx)
+        }
+        _ => {
+            builder.append_value(typed_value_to_variant(typed_value, idx)?);
+            Ok(())
+        }
+    }
+}
+
+/// Appends the shredded list at `idx` of `list` to `builder`, each element of which
+/// is a `value` and `typed_value` group
+fn append_list<'a, O: OffsetSizeTrait>(
+    builder: &mut impl VariantBuilderExt<'a, 'a>,
+    metadata: &'a [u8],
+    list: &'a GenericListArray<O>,
+    idx: usize,
+) -> Result<()> {
+    let elements = list.values().as_struct();
+    let value = elements.column_by_name("value").map(|a| a.as_ref());
+    let typed_value = elements.column_by_name("typed_value").map(|a| a.as_ref());
+
+    let offsets = list.value_offsets();
+    let mut list_builder = builder.new_list();
+    for element in offsets[idx].as_usize()..offsets[idx + 1].as_usize() {
+        append_shredded(&mut list_builder, metadata, value, typed_value, element)?;
+    }
+    list_builder.finish();
+    Ok(())
+}
+
+/// Decodes the unshredded variant value at `idx` of `value`
+fn decode_value<'a>(metadata: &'a [u8], value: &'a dyn Array, idx: usize) -> Result<Variant<'a, 'a>> {
+    Ok(Variant::try_new(metadata, binary_value(value, idx)).map_err(ArrowError::from)?)
+}
+
+/// A [`VariantBuilderExt`] that inserts values as the field `key` of an object
+struct ObjectFieldBuilder<'o, 'v, 's> {
+    key: &'s str,
+    builder: &'o mut ObjectBuilder<'v>,
+}
+
+impl<'m, 'v> VariantBuilderExt<'m, 'v> for ObjectFieldBuilder<'_, '_, '_> {
+    fn append_value(&mut self, value: impl Into<Variant<'m, 'v>>) {
+        self.builder.insert(self.key, value);
+    }
+
+    fn new_list(&mut self) -> ListBuilder<'_> {
+        self.builder.new_list(self.key)
+    }
+
+    fn new_object(&mut self) -> ObjectBuilder<'_> {
+        self.builder.new_object(self.key)
+    }
+}
+
+/// Returns the bytes at `idx` of a binary, large binary or binary view array
+fn binary_value(array: &dyn Array, idx: usize) -> &[u8] {
+    match array.data_type() {
+        ArrowType::Binary => array.as_binary::<i32>().value(idx),
+        ArrowType::LargeBinary => array.as_binary::<i64>().value(idx),
+        ArrowType::BinaryView => array.as_binary_view().value(idx),
+        d => unreachable!("variant group validated to be binary, got {d}"),
+    }
+}
+
+/// Converts the shredded primitive value at `idx` of `array` to a [`Variant`]
+fn typed_value_to_variant(array: &dyn Array, idx: usize) -> Result<Variant<'_, '_>> {
+    let variant = match array.data_type() {
+        ArrowType::Boolean => array.as_boolean().value(idx).into(),
+        ArrowType::Int8 => array.as_primitive::<Int8Type>().value(idx).into(),
+        ArrowType::Int16 => array.as_primitive::<Int16Type>().value(idx).into(),
+        ArrowType::Int32 => array.as_primitive::<Int32Type>().value(idx).into(),
+        ArrowType::Int64 => array.as_primitive::<Int64Type>().value(idx).into(),
+        ArrowType::Float32 => array.as_primitive::<Float32Type>().value(idx).into(),
+        ArrowType::Float64 => array.as_primitive::<Float64Type>().value(idx).into(),
+        ArrowType::Date32 => {
+            let days = array.as_primitive::<Date32Type>().value(idx);
+            Date32Type::to_naive_date(days).into()
+        }
+        ArrowType::Timestamp(TimeUnit::Microsecond, tz) => {
+            let micros = array.as_primitive::<TimestampMicrosecondType>().value(idx);
+            let timestamp = DateTime::from_timestamp_micros(micros)
+                .ok_or_else(|| arrow_err!("Variant timestamp {} out of range", micros))?;
+            match tz {
+                Some(_) => timestamp.into(),
+                None => timestamp.naive_utc().into(),
+            }
+        }
+        ArrowType::Decimal128(precision, scale) => {
+            let value = array.as_primitive::<Decimal128Type>().value(idx);
+            let scale = u8::try_from(*scale)
+                .map_err(|_| arrow_err!("Variant decimal scale {} out of range", scale))?;
+            let variant = match precision {
+                ..=9 => Variant::try_from((value as i32, scale)),
+                10..=18 => Variant::try_from((value as i64, scale)),
+                _ => Variant::try_from((value, scale)),
+            };
+            variant.map_err(ArrowError::from)?
+        }
+        ArrowType::Utf8 => array.as_string::<i32>().value(idx).into(),
+        ArrowType::LargeUtf8 => array.as_string::<i64>().value(idx).into(),
+        ArrowType::Utf8View => array.as_string_view().value(idx).into(),
+        ArrowType::Binary | ArrowType::LargeBinary | ArrowType::BinaryView => {
+            binary_value(array, idx).into()
+        }
+        d => {
+            return Err(arrow_err!(
+                "Decoding shredded variant typed_value of type {} is not supported",
+                d
+            ))
+        }
+    };
+    Ok(variant)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrow::array_reader::test_util::InMemoryArrayReader;
+    use arrow_array::{BinaryArray, Int64Array, ListArray, StringArray};
+    use arrow_buffer::OffsetBuffer;
+    use arrow_schema::{Field, Fields};
+    use parquet_variant_compute::VariantArray;
+
+    fn variant_type() -> ArrowType {
+        ArrowType::Struct(Fields::from(vec![
+            Field::new("metadata", ArrowType::BinaryView, false),
+            Field::new("value", ArrowType::BinaryView, false),
+        ]))
+    }
+
+    fn read(reader: &mut VariantArrayReader) -> VariantArray {
+        reader.read_records(1024).unwrap();
+        let array = reader.consume_batch().unwrap();
+        assert_eq!(array.data_type(), reader.get_data_type());
+        VariantArray::try_new(array).unwrap()
+    }
+
+    #[test]
+    fn test_json_to_variant() {
+        let strings = StringArray::from(vec![Some("1"), None, Some(r#"{"a": "b"}"#)]);
+        let source = InMemoryArrayReader::new(
+            ArrowType::Utf8,
+            Arc::new(strings),
+            Some(vec![1, 0, 1]),
+            None,
+        );
+        let mut reader = VariantArrayReader::try_new(Box::new(source), variant_type()).unwrap();
+
+        let variants = read(&mut reader);
+        assert_eq!(variants.len(), 3);
+        assert_eq!(variants.value(0), Variant::Int8(1));
+        assert!(variants.is_null(1));
+        let object = variants.value(2);
+        assert_eq!(object.get_object_field("a"), Some(Variant::from("b")));
+    }
+
+    #[test]
+    fn test_shredded_group_to_variant() {
+        let (metadata, value) = {
+            let mut builder = VariantBuilder::new();
+            builder.append_value("hello");
+            builder.finish()
+        };
+        let metadatas = BinaryArray::from(vec![metadata.as_slice(); 4]);
+        let values = BinaryArray::from(vec![None, Some(value.as_slice()), None, None]);
+        let typed_values = Int64Array::from(vec![Some(42), None, None, None]);
+        let group = StructArray::new(
+            Fields::from(vec![
+                Field::new("metadata", ArrowType::Binary, false),
+                Field::new("value", ArrowType::Binary, true),
+                Field::new("typed_value", ArrowType::Int64, true),
+            ]),
+            vec![Arc::new(metadatas), Arc::new(values), Arc::new(typed_values)],
+            Some(vec![true, true, true, false].into()),
+        );
+        let source = InMemoryArrayReader::new(
+            group.data_type().clone(),
+            Arc::new(group),
+            Some(vec![1, 1, 1, 0]),
+            None,
+        );
+        let mut reader = VariantArrayReader::try_new(Box::new(source), variant_type()).unwrap();
+
+        let variants = read(&mut reader);
+        assert_eq!(variants.value(0), Variant::Int64(42));
+        assert_eq!(variants.value(1), Variant::from("hello"));
+        assert_eq!(variants.value(2), Variant::Null);
+        assert!(variants.is_null(3));
+    }
+
+    /// Returns a `value` and `typed_value` group
+    fn shredded_group(value: BinaryArray, typed_value: ArrayRef) -> StructArray {
+        StructArray::new(
+            Fields::from(vec![
+                Field::new("value", ArrowType::Binary, true),
+                Field::new("typed_value", typed_value.data_type().clone(), true),
+            ]),
+            vec![Arc::new(value), typed_value],
+            None,
+        )
+    }
+
+    #[test]
+    fn test_nested_shredded_group_to_variant() {
+        let (metadata, remainder) = {
+            let mut builder = VariantBuilder::new();
+            let mut object = builder.new_object();
+            object.insert("c", "y");
+            object.finish().unwrap();
+            builder.finish()
+        };
+        let value_of = |variant: Variant| {
+            let mut builder = VariantBuilder::new();
+            builder.append_value(variant);
+            builder.finish().1
+        };
+        let (x, five) = (value_of(Variant::from("x")), value_of(Variant::Int8(5)));
+
+        // The elements of the list `b`, the second of which is not shredded
+        let elements = shredded_group(
+            BinaryArray::from(vec![None, Some(x.as_slice())]),
+            Arc::new(Int64Array::from(vec![Some(2), None])),
+        );
+        let element_field = Arc::new(Field::new("element", elements.data_type().clone(), false));
+        let list = ListArray::new(
+            element_field,
+            OffsetBuffer::new(vec![0, 2, 2, 2].into()),
+            Arc::new(elements),
+            Some(vec![true, true, false].into()),
+        );
+
+        let a = shredded_group(
+            BinaryArray::from(vec![None::<&[u8]>; 3]),
+            Arc::new(Int64Array::from(vec![Some(1), None, None])),
+        );
+        let b = shredded_group(BinaryArray::from(vec![None::<&[u8]>; 3]), Arc::new(list));
+        let typed_value = StructArray::new(
+            Fields::from(vec![
+                Field::new("a", a.data_type().clone(), false),
+                Field::new("b", b.data_type().clone(), false),
+            ]),
+            vec![Arc::new(a), Arc::new(b)],
+            Some(vec![true, true, false].into()),
+        );
+
+        let group = StructArray::new(
+            Fields::from(vec![
+                Field::new("metadata", ArrowType::Binary, false),
+                Field::new("value", ArrowType::Binary, true),
+                Field::new("typed_value", typed_value.data_type().clone(), true),
+            ]),
+            vec![
+                Arc::new(BinaryArray::from(vec![metadata.as_slice(); 3])),
+                Arc::new(BinaryArray::from(vec![
+                    Some(remainder.as_slice()),
+                    None,
+                    Some(five.as_slice()),
+                ])),
+                Arc::new(typed_value),
+            ],
+            None,
+        );
+        let source =
+            InMemoryArrayReader::new(group.data_type().clone(), Arc::new(group), None, None);
+        let mut reader = VariantArrayReader::try_new(Box::new(source), variant_type()).unwrap();
+
+        let variants = read(&mut reader);
+        let row = variants.value(0);
+        assert_eq!(row.get_object_field("a"), Some(Variant::Int64(1)));
+        assert_eq!(row.get_object_field("c"), Some(Variant::from("y")));
+        let list = row.get_object_field("b").unwrap();
+        assert_eq!(list.get_list_element(0), Some(Variant::Int64(2)));
+        assert_eq!(list.get_list_element(1), Some(Variant::from("x")));
+
+        let row = variants.value(1);
+        assert_eq!(row.get_object_field("a"), None);
+        assert_eq!(row.get_object_field("b").unwrap().as_list().unwrap().len(), 0);
+
+        assert_eq!(variants.value(2), Variant::Int8(5));
+    }
+
+    #[test]
+    fn test_partially_projected_group() {
+        let group = StructArray::new(
+            Fields::from(vec![Field::new("metadata", ArrowType::Binary, false)]),
+            vec![Arc::new(BinaryArray::from(vec![b"".as_slice()]))],
+            None,
+        );
+        let source =
+            InMemoryArrayReader::new(group.data_type().clone(), Arc::new(group), None, None);
+        let err = VariantArrayReader::try_new(Box::new(source), variant_type())
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("must be fully projected"), "{err}");
+    }
+}
@@ -34,6 +34,10 @@ pub trait ArrowPredicate: Send + 'static {
     /// [`evaluate`](Self::evaluate). The projection mask should be as small as
     /// possible because any columns needed for the overall projection mask are
     /// decoded again after a predicate is applied.
+    ///
+    /// The mask may select individual leaves nested within structs or lists,
+    /// see [`ProjectionMask::column_paths`], in which case only those leaves
+    /// are decoded and the parent arrays in `batch` contain just those children.
     fn projection(&self) -> &ProjectionMask;
 
     /// Evaluate this predicate for the given [`RecordBatch`] containing the columns
@@ -169,10 +173,23 @@ where
 /// to filter the data after the RecordBatch has been fully decoded, if the eliminated rows are
 /// not contiguous.
 ///
+/// Where the best order is not known up front, [`RowFilter::with_adaptive_ordering`]
+/// can be used to reorder the predicates based on their observed cost and selectivity,
+/// and [`RowFilter::with_predicate_cache`] to avoid decoding the columns of predicates
+/// again for the output.
+///
 /// [`RowSelection`]: crate::arrow::arrow_reader::RowSelection
 pub struct RowFilter {
     /// A list of [`ArrowPredicate`]
     pub(crate) predicates: Vec<Box<dyn ArrowPredicate>>,
+
+    /// If `Some`, the statistics observed for each predicate, used to
+    /// adaptively reorder their evaluation
+    stats: Option<Vec<PredicateStats>>,
+
+    /// If `true`, the columns decoded to evaluate the predicates are reused for the output
+    #[cfg_attr(not(feature = "async"), allow(dead_code))]
+    pub(crate) predicate_cache: bool,
 }
 
 impl Debug for RowFilter {
@@ -184,6 +201,169 @@ impl Debug for RowFilter {
 impl RowFilter {
     /// Create a new [`RowFilter`] from an array of [`ArrowPredicate`]
     pub fn new(predicates: Vec<Box<dyn ArrowPredicate>>) -> Self {
-        Self { predicates }
+        Self {
+            predicates,
+            stats: None,
+            predicate_cache: false,
+        }
+    }
+
+    /// Sets whether the predicates should be reordered based on their observed
+    /// cost and selectivity, defaults to `false`
+    ///
+    /// When enabled, the reader records the time taken to evaluate each
+    /// predicate, including fetching and decoding its columns, and the fraction
+    /// of rows it eliminates. Before each row group, the predicates are then
+    /// ordered so that cheap predicates which eliminate many rows are evaluated
+    /// first, avoiding decoding the columns of more expensive predicates for rows
+    /// that have already been eliminated. Predicates that have not yet been
+    /// evaluated are always evaluated first, in their original order.
+    ///
+    /// As the result of a [`RowFilter`] is the conjunction of its predicates,
+    /// the order does not change the rows returned, however, predicates must not
+    /// rely on the rows passed to them having been filtered by earlier predicates.
+    ///
+    /// Note: statistics are only gathered between row groups by
+    /// [`ParquetRecordBatchStream`], the synchronous [`ParquetRecordBatchReader`]
+    /// evaluates each predicate only once and so is unaffected by this setting
+    ///
+    /// [`ParquetRecordBatchStream`]: crate::arrow::async_reader::ParquetRecordBatchStream
+    /// [`ParquetRecordBatchReader`]: crate::arrow::arrow_reader::ParquetRecordBatchReader
+    pub fn with_adaptive_ordering(mut self, adaptive: bool) -> Self {
+        self.stats = adaptive.then(|| vec![PredicateStats::default(); self.predicates.len()]);
+        self
+    }
+
+    /// Sets whether the columns decoded to evaluate the predicates are reused for the
+    /// output, defaults to `false`
+    ///
+    /// When enabled, the values of the top-level primitive columns decoded for each
+    /// predicate are retained until the row group has been read, and any rows of these
+    /// columns in the output are then taken from this cache instead of decoding their
+    /// pages a second time. This trades memory, up to the size of the decoded predicate
+    /// columns of a row group, for decode time.
+    ///
+    /// Columns nested within structs or lists, and the columns of a predicate whose
+    /// evaluation stopped early because of a limit, are decoded again as usual.
+    ///
+    /// Note: as with [`Self::with_adaptive_ordering`], this only applies to
+    /// [`ParquetRecordBatchStream`]
+    ///
+    /// [`ParquetRecordBatchStream`]: crate::arrow::async_reader::ParquetRecordBatchStream
+    pub fn with_predicate_cache(self, predicate_cache: bool) -> Self {
+        Self {
+            predicate_cache,
+            ..self
+        }
+    }
+
+    /// Returns the indices of [`Self::predicates`] in the order they should be evaluated
+    #[cfg_attr(not(feature = "async"), allow(dead_code))]
+    pub(crate) fn evaluation_order(&self) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..self.predicates.len()).collect();
+        if let Some(stats) = &self.stats {
+            // Stable sort preserves the original order of unobserved predicates
+            order.sort_by(|a, b| stats[*a].rank().total_cmp(&stats[*b].rank()));
+        }
+        order
+    }
+
+    /// Records that predicate `idx` took `nanos` to reduce `rows_in` rows to `rows_out`
+    #[cfg_attr(not(feature = "async"), allow(dead_code))]
+    pub(crate) fn observe(&mut self, idx: usize, rows_in: usize, rows_out: usize, nanos: u64) {
+        if let Some(stats) = self.stats.as_mut() {
+            let stats = &mut stats[idx];
+            stats.rows_in += rows_in as u64;
+            stats.rows_out += rows_out as u64;
+            stats.nanos += nanos;
+        }
+    }
+}
+
+/// Statistics observed for an [`ArrowPredicate`], see [`RowFilter::with_adaptive_ordering`]
+#[derive(Debug, Clone, Copy, Default)]
+struct PredicateStats {
+    rows_in: u64,
+    rows_out: u64,
+    nanos: u64,
+}
+
+impl PredicateStats {
+    /// Returns the rank of this predicate, predicates with a lower rank should be evaluated first
+    ///
+    /// This is the cost per input row divided by the fraction of rows eliminated, the
+    /// optimal ordering for a conjunction of independent predicates
+    fn rank(&self) -> f64 {
+        if self.rows_in == 0 {
+            return 0.;
+        }
+        // Timings are not available on all platforms, in which case only selectivity is used
+        let cost = match self.nanos {
+            0 => 1.,
+            nanos => nanos as f64 / self.rows_in as f64,
+        };
+        let eliminated = 1. - self.rows_out as f64 / self.rows_in as f64;
+        cost / eliminated.max(1e-3)
+    }
+}
+
+/// Measures the time taken to evaluate an [`ArrowPredicate`]
+#[cfg_attr(not(feature = "async"), allow(dead_code))]
+pub(crate) struct PredicateTimer {
+    #[cfg(not(target_arch = "wasm32"))]
+    start: std::time::Instant,
+}
+
+#[cfg_attr(not(feature = "async"), allow(dead_code))]
+impl PredicateTimer {
+    pub(crate) fn start() -> Self {
+        Self {
+            #[cfg(not(target_arch = "wasm32"))]
+            start: std::time::Instant::now(),
+        }
+    }
+
+    /// Returns the elapsed nanoseconds, or `0` if timings are not available
+    pub(crate) fn elapsed_nanos(&self) -> u64 {
+        #[cfg(not(target_arch = "wasm32"))]
+        return self.start.elapsed().as_nanos() as u64;
+        #[cfg(target_arch = "wasm32")]
+        return 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_adaptive_ordering() {
+        let predicates = (0..3)
+            .map(|_| {
+                Box::new(ArrowPredicateFn::new(ProjectionMask::all(), |batch| {
+                    Ok(BooleanArray::from(vec![true; batch.num_rows()]))
+                })) as Box<dyn ArrowPredicate>
+            })
+            .collect::<Vec<_>>();
+
+        let mut filter = RowFilter::new(predicates);
+        filter.observe(0, 100, 100, 1000);
+        assert_eq!(filter.evaluation_order(), vec![0, 1, 2]);
+
+        let mut filter = filter.with_adaptive_ordering(true);
+        assert_eq!(filter.evaluation_order(), vec![0, 1, 2]);
+
+        // Unobserved predicates are evaluated first
+        filter.observe(0, 100, 100, 1000);
+        assert_eq!(filter.evaluation_order(), vec![1, 2, 0]);
+
+        // Predicate 1 eliminates half the rows, predicate 2 eliminates 90% at the same cost
+        filter.observe(1, 100, 50, 1000);
+        filter.observe(2, 50, 5, 500);
+        assert_eq!(filter.evaluation_order(), vec![2, 1, 0]);
+
+        // Predicate 1 is now much cheaper
+        filter.observe(1, 1000, 500, 0);
+        assert_eq!(filter.evaluation_order(), vec![1, 2, 0]);
     }
 }
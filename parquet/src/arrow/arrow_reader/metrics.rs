@@ -0,0 +1,377 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`ReadMetrics`] for reporting the work performed by a parquet reader
+
+use crate::column::page::{Page, PageMetadata, PageReader};
+use crate::errors::Result;
+use crate::file::reader::{ChunkReader, Length};
+use bytes::Bytes;
+use std::io::Read;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Metrics describing the work performed while reading a parquet file
+///
+/// `ReadMetrics` is a cheaply cloneable handle to a set of shared counters.
+/// Pass a clone to [`ArrowReaderBuilder::with_metrics`] and inspect the
+/// original once the read has completed (or at any point during the read).
+///
+/// The same `ReadMetrics` may be shared between multiple readers, in which
+/// case the counters are accumulated across all of them.
+///
+/// # Phases
+///
+/// * `fetch_time`: time spent fetching bytes from the underlying input
+/// * `filter_time`: time spent evaluating [`RowFilter`] predicates
+/// * `decode_time`: time spent decoding the final [`RecordBatch`]es
+///
+/// The synchronous [`ParquetRecordBatchReader`] performs I/O lazily while
+/// decoding, and so its `fetch_time` overlaps with `filter_time` and
+/// `decode_time`. Timings are not collected on `wasm32` targets.
+///
+/// # Example
+/// ```
+/// # use std::sync::Arc;
+/// # use bytes::Bytes;
+/// # use arrow_array::{Int32Array, RecordBatch};
+/// # use parquet::arrow::ArrowWriter;
+/// # use parquet::arrow::arrow_reader::{ParquetRecordBatchReaderBuilder, ReadMetrics};
+/// # let batch = RecordBatch::try_from_iter([
+/// #     ("a", Arc::new(Int32Array::from(vec![1, 2, 3])) as _),
+/// # ]).unwrap();
+/// # let mut file = vec![];
+/// # let mut writer = ArrowWriter::try_new(&mut file, batch.schema(), None).unwrap();
+/// # writer.write(&batch).unwrap();
+/// # writer.close().unwrap();
+/// # let file = Bytes::from(file);
+/// let metrics = ReadMetrics::new();
+/// let reader = ParquetRecordBatchReaderBuilder::try_new(file)
+///     .unwrap()
+///     .with_metrics(metrics.clone())
+///     .build()
+///     .unwrap();
+/// let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+///
+/// assert_eq!(metrics.rows_selected(), 3);
+/// assert!(metrics.pages_decoded() > 0);
+/// assert!(metrics.bytes_fetched() > 0);
+/// ```
+///
+/// [`ArrowReaderBuilder::with_metrics`]: super::ArrowReaderBuilder::with_metrics
+/// [`RowFilter`]: super::RowFilter
+/// [`RecordBatch`]: arrow_array::RecordBatch
+/// [`ParquetRecordBatchReader`]: super::ParquetRecordBatchReader
+#[derive(Debug, Clone, Default)]
+pub struct ReadMetrics {
+    inner: Arc<MetricsInner>,
+}
+
+#[derive(Debug, Default)]
+struct MetricsInner {
+    bytes_fetched: AtomicU64,
+    pages_decoded: AtomicU64,
+    pages_skipped: AtomicU64,
+    rows_selected: AtomicU64,
+    rows_pruned: AtomicU64,
+    fetch_nanos: AtomicU64,
+    filter_nanos: AtomicU64,
+    decode_nanos: AtomicU64,
+}
+
+/// The phases of a read for which [`ReadMetrics`] records elapsed time
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Phase {
+    Fetch,
+    Filter,
+    Decode,
+}
+
+impl ReadMetrics {
+    /// Create a new [`ReadMetrics`] with all counters set to zero
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of bytes fetched from the underlying input
+    pub fn bytes_fetched(&self) -> u64 {
+        self.inner.bytes_fetched.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of pages, including dictionary pages, that were decoded
+    pub fn pages_decoded(&self) -> u64 {
+        self.inner.pages_decoded.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of data pages skipped without being decoded
+    ///
+    /// Pages can only be skipped when a [`RowSelection`] or [`RowFilter`] is
+    /// used in conjunction with the page index, see [`ArrowReaderOptions::with_page_index`]
+    ///
+    /// [`RowSelection`]: super::RowSelection
+    /// [`RowFilter`]: super::RowFilter
+    /// [`ArrowReaderOptions::with_page_index`]: super::ArrowReaderOptions::with_page_index
+    pub fn pages_skipped(&self) -> u64 {
+        self.inner.pages_skipped.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of rows in the scanned row groups that remained
+    /// selected after applying any [`RowSelection`] and [`RowFilter`]
+    ///
+    /// Note: this is computed before any limit or offset is applied, however a [`RowFilter`]
+    /// stops evaluating rows once enough are selected to satisfy the limit
+    ///
+    /// [`RowSelection`]: super::RowSelection
+    /// [`RowFilter`]: super::RowFilter
+    pub fn rows_selected(&self) -> u64 {
+        self.inner.rows_selected.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of rows in the scanned row groups that were pruned
+    /// by a [`RowSelection`] or [`RowFilter`]
+    ///
+    /// Note: this is computed before any limit or offset is applied, rows not evaluated
+    /// by a [`RowFilter`] once the limit is satisfied are counted as pruned
+    ///
+    /// [`RowSelection`]: super::RowSelection
+    /// [`RowFilter`]: super::RowFilter
+    pub fn rows_pruned(&self) -> u64 {
+        self.inner.rows_pruned.load(Ordering::Relaxed)
+    }
+
+    /// Returns the time spent fetching bytes from the underlying input
+    pub fn fetch_time(&self) -> Duration {
+        Duration::from_nanos(self.inner.fetch_nanos.load(Ordering::Relaxed))
+    }
+
+    /// Returns the time spent evaluating [`RowFilter`] predicates
+    ///
+    /// [`RowFilter`]: super::RowFilter
+    pub fn filter_time(&self) -> Duration {
+        Duration::from_nanos(self.inner.filter_nanos.load(Ordering::Relaxed))
+    }
+
+    /// Returns the time spent decoding the output [`RecordBatch`]es
+    ///
+    /// [`RecordBatch`]: arrow_array::RecordBatch
+    pub fn decode_time(&self) -> Duration {
+        Duration::from_nanos(self.inner.decode_nanos.load(Ordering::Relaxed))
+    }
+
+    pub(crate) fn add_bytes_fetched(&self, bytes: u64) {
+        self.inner.bytes_fetched.fetch_add(bytes, Ordering::Relaxed);
+    }
+
+    /// Records that `selected` of `total` rows remain after applying any
+    /// selection and predicates
+    pub(crate) fn add_rows(&self, total: usize, selected: usize) {
+        let inner = &self.inner;
+        inner
+            .rows_selected
+            .fetch_add(selected as u64, Ordering::Relaxed);
+        inner
+            .rows_pruned
+            .fetch_add(total.saturating_sub(selected) as u64, Ordering::Relaxed);
+    }
+
+    /// Returns a [`PhaseTimer`] that records the time until it is dropped
+    pub(crate) fn timer(&self, phase: Phase) -> PhaseTimer {
+        PhaseTimer {
+            metrics: self.clone(),
+            phase,
+            #[cfg(not(target_arch = "wasm32"))]
+            start: std::time::Instant::now(),
+        }
+    }
+
+    fn counter(&self, phase: Phase) -> &AtomicU64 {
+        match phase {
+            Phase::Fetch => &self.inner.fetch_nanos,
+            Phase::Filter => &self.inner.filter_nanos,
+            Phase::Decode => &self.inner.decode_nanos,
+        }
+    }
+}
+
+/// Records the elapsed time of a [`Phase`] into [`ReadMetrics`] when dropped
+pub(crate) struct PhaseTimer {
+    metrics: ReadMetrics,
+    #[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+    phase: Phase,
+    #[cfg(not(target_arch = "wasm32"))]
+    start: std::time::Instant,
+}
+
+impl Drop for PhaseTimer {
+    fn drop(&mut self) {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let nanos = self.start.elapsed().as_nanos() as u64;
+            self.metrics
+                .counter(self.phase)
+                .fetch_add(nanos, Ordering::Relaxed);
+        }
+    }
+}
+
+/// Starts a [`PhaseTimer`] if `metrics` is provided
+pub(crate) fn start_timer(metrics: Option<&ReadMetrics>, phase: Phase) -> Option<PhaseTimer> {
+    metrics.map(|m| m.timer(phase))
+}
+
+/// A [`PageReader`] that records the pages read and skipped into [`ReadMetrics`]
+pub(crate) struct MeteredPageReader {
+    inner: Box<dyn PageReader>,
+    metrics: ReadMetrics,
+}
+
+impl MeteredPageReader {
+    /// Wraps `inner` if `metrics` is provided, otherwise returns it unchanged
+    pub(crate) fn wrap(
+        inner: Box<dyn PageReader>,
+        metrics: Option<&ReadMetrics>,
+    ) -> Box<dyn PageReader> {
+        match metrics {
+            Some(metrics) => Box::new(Self {
+                inner,
+                metrics: metrics.clone(),
+            }),
+            None => inner,
+        }
+    }
+}
+
+impl Iterator for MeteredPageReader {
+    type Item = Result<Page>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.get_next_page().transpose()
+    }
+}
+
+impl PageReader for MeteredPageReader {
+    fn get_next_page(&mut self) -> Result<Option<Page>> {
+        let page = self.inner.get_next_page()?;
+        if page.is_some() {
+            self.metrics
+                .inner
+                .pages_decoded
+                .fetch_add(1, Ordering::Relaxed);
+        }
+        Ok(page)
+    }
+
+    fn peek_next_page(&mut self) -> Result<Option<PageMetadata>> {
+        self.inner.peek_next_page()
+    }
+
+    fn skip_next_page(&mut self) -> Result<()> {
+        self.inner.skip_next_page()?;
+        self.metrics
+            .inner
+            .pages_skipped
+            .fetch_add(1, Ordering::Relaxed);
+        Ok(())
+    }
+
+    fn at_record_boundary(&mut self) -> Result<bool> {
+        self.inner.at_record_boundary()
+    }
+}
+
+/// A [`ChunkReader`] that records the bytes read and time spent reading into
+/// [`ReadMetrics`], if provided
+pub(crate) struct MeteredChunkReader<T> {
+    inner: T,
+    metrics: Option<ReadMetrics>,
+}
+
+impl<T> MeteredChunkReader<T> {
+    pub(crate) fn new(inner: T, metrics: Option<ReadMetrics>) -> Self {
+        Self { inner, metrics }
+    }
+}
+
+impl<T: ChunkReader> Length for MeteredChunkReader<T> {
+    fn len(&self) -> u64 {
+        self.inner.len()
+    }
+}
+
+impl<T: ChunkReader> ChunkReader for MeteredChunkReader<T> {
+    type T = MeteredRead<T::T>;
+
+    fn get_read(&self, start: u64) -> Result<Self::T> {
+        let _timer = start_timer(self.metrics.as_ref(), Phase::Fetch);
+        Ok(MeteredRead {
+            inner: self.inner.get_read(start)?,
+            metrics: self.metrics.clone(),
+        })
+    }
+
+    fn get_bytes(&self, start: u64, length: usize) -> Result<Bytes> {
+        let _timer = start_timer(self.metrics.as_ref(), Phase::Fetch);
+        let bytes = self.inner.get_bytes(start, length)?;
+        if let Some(metrics) = &self.metrics {
+            metrics.add_bytes_fetched(bytes.len() as u64);
+        }
+        Ok(bytes)
+    }
+}
+
+/// The [`Read`] returned by [`MeteredChunkReader::get_read`]
+pub(crate) struct MeteredRead<R> {
+    inner: R,
+    metrics: Option<ReadMetrics>,
+}
+
+impl<R: Read> Read for MeteredRead<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        match &self.metrics {
+            None => self.inner.read(buf),
+            Some(metrics) => {
+                let _timer = metrics.timer(Phase::Fetch);
+                let read = self.inner.read(buf)?;
+                metrics.add_bytes_fetched(read as u64);
+                Ok(read)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_metrics_shared() {
+        let metrics = ReadMetrics::new();
+        let other = metrics.clone();
+
+        other.add_bytes_fetched(10);
+        other.add_bytes_fetched(5);
+        other.add_rows(100, 40);
+        metrics.add_rows(50, 50);
+
+        assert_eq!(metrics.bytes_fetched(), 15);
+        assert_eq!(metrics.rows_selected(), 90);
+        assert_eq!(metrics.rows_pruned(), 60);
+        assert_eq!(metrics.pages_decoded(), 0);
+        assert_eq!(metrics.pages_skipped(), 0);
+    }
+}
@@ -20,14 +20,15 @@
 use arrow_array::cast::AsArray;
 use arrow_array::Array;
 use arrow_array::{RecordBatch, RecordBatchReader};
-use arrow_schema::{ArrowError, DataType as ArrowType, Schema, SchemaRef};
+use arrow_schema::{ArrowError, DataType as ArrowType, Schema, SchemaRef, TimeUnit};
 pub use filter::{ArrowPredicate, ArrowPredicateFn, RowFilter};
+pub use metrics::ReadMetrics;
 pub use selection::{RowSelection, RowSelector};
 use std::fmt::{Debug, Formatter};
 use std::sync::Arc;
 
 pub use crate::arrow::array_reader::RowGroups;
-use crate::arrow::array_reader::{ArrayReader, ArrayReaderBuilder};
+use crate::arrow::array_reader::{ArrayReader, ArrayReaderBuilder, DictionaryCache};
 use crate::arrow::schema::{parquet_to_arrow_schema_and_fields, ParquetField};
 use crate::arrow::{parquet_to_arrow_field_levels, FieldLevels, ProjectionMask};
 use crate::column::page::{PageIterator, PageReader};
@@ -37,11 +38,16 @@ use crate::errors::{ParquetError, Result};
 use crate::file::metadata::{ParquetMetaData, ParquetMetaDataReader};
 use crate::file::reader::{ChunkReader, SerializedPageReader};
 use crate::schema::types::SchemaDescriptor;
+use metrics::{start_timer, MeteredChunkReader, MeteredPageReader, Phase};
+pub(crate) use schema_adapter::SchemaAdapter;
 
 pub(crate) use read_plan::{ReadPlan, ReadPlanBuilder};
 
-mod filter;
+pub(crate) mod filter;
+pub(crate) mod metrics;
+pub mod pruning;
 mod read_plan;
+mod schema_adapter;
 mod selection;
 pub mod statistics;
 
@@ -112,6 +118,16 @@ pub struct ArrowReaderBuilder<T> {
     pub(crate) limit: Option<usize>,
 
     pub(crate) offset: Option<usize>,
+
+    pub(crate) metrics: Option<ReadMetrics>,
+
+    pub(crate) schema_adapter: Option<Arc<SchemaAdapter>>,
+
+    pub(crate) dictionary_reuse: bool,
+
+    pub(crate) parallelism: usize,
+
+    pub(crate) int96_overflow: Int96Overflow,
 }
 
 impl<T: Debug> Debug for ArrowReaderBuilder<T> {
@@ -128,6 +144,11 @@ impl<T: Debug> Debug for ArrowReaderBuilder<T> {
             .field("selection", &self.selection)
             .field("limit", &self.limit)
             .field("offset", &self.offset)
+            .field("metrics", &self.metrics)
+            .field("schema_adapter", &self.schema_adapter)
+            .field("dictionary_reuse", &self.dictionary_reuse)
+            .field("parallelism", &self.parallelism)
+            .field("int96_overflow", &self.int96_overflow)
             .finish()
     }
 }
@@ -146,6 +167,11 @@ impl<T> ArrowReaderBuilder<T> {
             selection: None,
             limit: None,
             offset: None,
+            metrics: None,
+            schema_adapter: metadata.schema_adapter,
+            dictionary_reuse: false,
+            parallelism: 1,
+            int96_overflow: metadata.int96_overflow,
         }
     }
 
@@ -256,6 +282,89 @@ impl<T> ArrowReaderBuilder<T> {
         }
     }
 
+    /// Provide a [`RowSelection`] over all the rows of the file, for example one created
+    /// from external row ids with [`RowSelection::from_row_ids`]
+    ///
+    /// Unlike [`Self::with_row_selection`], the selection includes the rows of every
+    /// row group of the file. Only the row groups with selected rows are read, with the
+    /// remaining rows skipped as for [`Self::with_row_selection`], which this replaces.
+    /// If [`Self::with_row_groups`] was called first, only rows in those row groups are
+    /// read, and a row group listed more than once is read each time.
+    ///
+    /// Rows past the end of `selection` are not selected.
+    ///
+    /// It is recommended to enable reading the page index if using this functionality, to
+    /// allow skipping data pages without selected rows. See [`ArrowReaderOptions::with_page_index`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use bytes::Bytes;
+    /// # use arrow_array::{ArrayRef, Int64Array, RecordBatch};
+    /// # use parquet::arrow::ArrowWriter;
+    /// # use parquet::arrow::arrow_reader::{ParquetRecordBatchReaderBuilder, RowSelection};
+    /// # use parquet::file::properties::WriterProperties;
+    /// # let values: ArrayRef = Arc::new(Int64Array::from_iter_values(0..100));
+    /// # let batch = RecordBatch::try_from_iter([("id", values)]).unwrap();
+    /// # let props = WriterProperties::builder().set_max_row_group_size(10).build();
+    /// # let mut file = Vec::new();
+    /// # let mut writer = ArrowWriter::try_new(&mut file, batch.schema(), Some(props)).unwrap();
+    /// # writer.write(&batch).unwrap();
+    /// # writer.close().unwrap();
+    /// # let file = Bytes::from(file);
+    /// // Row ids, for example from a secondary index
+    /// let row_ids = [3, 4, 57, 99];
+    ///
+    /// let builder = ParquetRecordBatchReaderBuilder::try_new(file).unwrap();
+    /// let num_rows = builder.metadata().file_metadata().num_rows() as usize;
+    /// let selection = RowSelection::from_row_ids(row_ids, num_rows);
+    /// let reader = builder.with_file_row_selection(selection).build().unwrap();
+    ///
+    /// let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+    /// let rows: usize = batches.iter().map(|b| b.num_rows()).sum();
+    /// assert_eq!(rows, 4);
+    /// ```
+    pub fn with_file_row_selection(self, mut selection: RowSelection) -> Self {
+        let row_groups = self.metadata.row_groups();
+
+        // Split the selection into the selections of each row group
+        let row_group_selections: Vec<_> = row_groups
+            .iter()
+            .map(|rg| selection.split_off(rg.num_rows() as usize))
+            .collect();
+
+        let candidates = match &self.row_groups {
+            Some(row_groups) => row_groups.clone(),
+            None => (0..row_groups.len()).collect(),
+        };
+
+        let mut selected_row_groups = Vec::with_capacity(candidates.len());
+        let mut selectors = Vec::new();
+        for idx in candidates {
+            let Some(rg_selection) = row_group_selections.get(idx) else {
+                // Out of bounds row groups are reported when building the reader
+                selected_row_groups.push(idx);
+                continue;
+            };
+            if !rg_selection.selects_any() {
+                continue;
+            }
+            let num_rows = row_groups[idx].num_rows() as usize;
+            let skipped = num_rows - rg_selection.row_count() - rg_selection.skipped_row_count();
+            selectors.extend(rg_selection.iter().copied());
+            // Rows past the end of `selection`
+            selectors.push(RowSelector::skip(skipped));
+            selected_row_groups.push(idx);
+        }
+
+        Self {
+            row_groups: Some(selected_row_groups),
+            selection: Some(selectors.into()),
+            ..self
+        }
+    }
+
     /// Provide a [`RowFilter`] to skip decoding rows
     ///
     /// Row filters are applied after row group selection and row selection
@@ -274,6 +383,9 @@ impl<T> ArrowReaderBuilder<T> {
     /// The limit will be applied after any [`Self::with_row_selection`] and [`Self::with_row_filter`]
     /// allowing it to limit the final set of rows decoded after any pushed down predicates
     ///
+    /// Once the limit is satisfied no further data is fetched or decoded, including the
+    /// evaluation of any [`RowFilter`] on the remaining rows
+    ///
     /// It is recommended to enable reading the page index if using this functionality, to allow
     /// more efficient skipping over data pages. See [`ArrowReaderOptions::with_page_index`]
     pub fn with_limit(self, limit: usize) -> Self {
@@ -296,6 +408,65 @@ impl<T> ArrowReaderBuilder<T> {
             ..self
         }
     }
+
+    /// Record the work performed by the reader into the provided [`ReadMetrics`]
+    ///
+    /// [`ReadMetrics`] is a shared handle, retain a clone to inspect the bytes
+    /// fetched, pages decoded and skipped, rows pruned and time spent in each
+    /// phase once the read has completed.
+    pub fn with_metrics(self, metrics: ReadMetrics) -> Self {
+        Self {
+            metrics: Some(metrics),
+            ..self
+        }
+    }
+
+    /// Reuse the dictionaries of dictionary encoded columns across row groups
+    /// (defaults to `false`)
+    ///
+    /// Columns read as [`DictionaryArray`] share the same values array for all the
+    /// batches decoded from a column chunk's dictionary page. If enabled, a column
+    /// chunk whose dictionary is equal to that of the previous batch also returns the
+    /// previous values array, allowing consumers to detect unchanged dictionaries by
+    /// pointer comparison, for example to avoid re-sending them over Arrow IPC.
+    ///
+    /// This requires comparing the dictionary of each column chunk with the previous
+    /// one, and so is only worthwhile for files with repetitive dictionaries.
+    ///
+    /// Note: batches spanning multiple row groups, or containing data pages that are
+    /// not dictionary encoded, compute a new dictionary. It is therefore recommended
+    /// to use a batch size that divides the row group size.
+    ///
+    /// [`DictionaryArray`]: arrow_array::DictionaryArray
+    pub fn with_dictionary_reuse(self, dictionary_reuse: bool) -> Self {
+        Self {
+            dictionary_reuse,
+            ..self
+        }
+    }
+
+    /// Set the number of threads used to decode the columns of each batch
+    /// (defaults to `1`)
+    ///
+    /// When greater than `1`, the top-level columns of the projection are divided
+    /// between up to `parallelism` tasks on the global [rayon] thread pool, which
+    /// decode them concurrently before the [`RecordBatch`] is assembled. The returned
+    /// batches are identical.
+    ///
+    /// This can reduce decode latency for wide projections on multicore machines,
+    /// at the cost of synchronizing with the thread pool for each decoded batch, and
+    /// so is best combined with a large batch size.
+    ///
+    /// A value of `0` is treated as `1`. This has no effect unless the `rayon`
+    /// feature is enabled.
+    ///
+    /// [rayon]: https://docs.rs/rayon
+    pub fn with_parallelism(self, parallelism: usize) -> Self {
+        Self {
+            parallelism: parallelism.max(1),
+            ..self
+        }
+    }
 }
 
 /// Options that control how metadata is read for a parquet file
@@ -311,11 +482,22 @@ pub struct ArrowReaderOptions {
     ///
     /// [ARROW_SCHEMA_META_KEY]: crate::arrow::ARROW_SCHEMA_META_KEY
     supplied_schema: Option<SchemaRef>,
+    /// If provided, the schema that decoded batches are converted to
+    target_schema: Option<SchemaRef>,
     /// If true, attempt to read `OffsetIndex` and `ColumnIndex`
     pub(crate) page_index: bool,
     /// If encryption is enabled, the file decryption properties can be provided
     #[cfg(feature = "encryption")]
     pub(crate) file_decryption_properties: Option<FileDecryptionProperties>,
+    /// If provided, the [`TimeUnit`] INT96 timestamps are decoded to
+    int96_unit: Option<TimeUnit>,
+    /// If provided, the timezone INT96 timestamps are annotated with
+    int96_timezone: Option<Arc<str>>,
+    /// How INT96 timestamps out of range for their arrow type are handled
+    int96_overflow: Int96Overflow,
+    /// The top-level columns decoded to variant arrays
+    #[cfg(feature = "variant_experimental")]
+    variant_columns: Vec<String>,
 }
 
 impl ArrowReaderOptions {
@@ -401,6 +583,65 @@ impl ArrowReaderOptions {
         }
     }
 
+    /// Convert the decoded [`RecordBatch`]es to `schema`, allowing files written
+    /// with an older version of a table's schema to be read as the current version
+    ///
+    /// Unlike [`Self::with_schema`], which describes how the columns of the file
+    /// are decoded, the target schema may differ from the file:
+    ///
+    /// * Columns are matched by name, and returned in the order of `schema`
+    /// * Columns of the file not in `schema` are not read
+    /// * Columns of `schema` not in the file are filled with nulls, and must be nullable
+    /// * Columns with a different type are cast, for example `Int32` to `Int64`,
+    ///   `Utf8` to `LargeUtf8`, or between timestamp timezones. Timestamps without
+    ///   a timezone are interpreted as local times in the target timezone. Casts
+    ///   that fail, for example due to overflow, return an error rather than null
+    ///
+    /// An error is returned when constructing the reader if a column of the file
+    /// cannot be cast to the type in `schema`.
+    ///
+    /// Any [`ProjectionMask`] and [`RowFilter`] provided to the reader refer to
+    /// the columns of the file, see [`ArrowReaderBuilder::schema`]. Columns of
+    /// `schema` excluded by the projection are filled with nulls.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use bytes::Bytes;
+    /// # use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+    /// # use arrow_schema::{DataType, Field, Schema};
+    /// # use parquet::arrow::ArrowWriter;
+    /// # use parquet::arrow::arrow_reader::{ArrowReaderOptions, ParquetRecordBatchReaderBuilder};
+    /// # let batch = RecordBatch::try_from_iter([
+    /// #     ("id", Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef),
+    /// # ]).unwrap();
+    /// # let mut file = vec![];
+    /// # let mut writer = ArrowWriter::try_new(&mut file, batch.schema(), None).unwrap();
+    /// # writer.write(&batch).unwrap();
+    /// # writer.close().unwrap();
+    /// # let file = Bytes::from(file);
+    /// // The file contains a single Int32 column "id"
+    /// let target = Arc::new(Schema::new(vec![
+    ///     Field::new("name", DataType::Utf8, true),
+    ///     Field::new("id", DataType::Int64, false),
+    /// ]));
+    /// let options = ArrowReaderOptions::new().with_target_schema(target.clone());
+    /// let mut reader = ParquetRecordBatchReaderBuilder::try_new_with_options(file, options)
+    ///     .unwrap()
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let batch = reader.next().unwrap().unwrap();
+    /// assert_eq!(batch.schema(), target);
+    /// assert_eq!(batch.column(0).null_count(), 3);
+    /// ```
+    pub fn with_target_schema(self, schema: SchemaRef) -> Self {
+        Self {
+            target_schema: Some(schema),
+            ..self
+        }
+    }
+
     /// Enable reading [`PageIndex`], if present (defaults to `false`)
     ///
     /// The `PageIndex` can be used to push down predicates to the parquet scan,
@@ -431,6 +672,94 @@ impl ArrowReaderOptions {
         }
     }
 
+    /// Decode INT96 timestamps to `unit` (defaults to [`TimeUnit::Nanosecond`])
+    ///
+    /// INT96 is a deprecated timestamp representation, still written by some
+    /// legacy systems such as Hive and Spark, that encodes a Julian day and the
+    /// nanoseconds within that day. Nanosecond timestamps can only represent
+    /// dates between the years 1677 and 2262, and so a coarser unit is
+    /// necessary to decode values outside this range, for example
+    /// `9999-12-31` often used to denote an unbounded date.
+    ///
+    /// This takes precedence over the type of the column in any arrow schema
+    /// embedded in the file, but is ignored if a schema is provided with
+    /// [`Self::with_schema`].
+    ///
+    /// See [`Self::with_int96_overflow`] for how values that cannot be
+    /// represented in `unit` are handled.
+    pub fn with_int96_unit(self, unit: TimeUnit) -> Self {
+        Self {
+            int96_unit: Some(unit),
+            ..self
+        }
+    }
+
+    /// Annotate timestamps decoded from INT96 columns with `timezone`
+    /// (defaults to no timezone)
+    ///
+    /// INT96 timestamps do not record whether they are relative to UTC or a
+    /// local time, see [`Self::with_int96_unit`]. The decoded values are
+    /// unchanged, this only sets the timezone of the resulting [`ArrowType::Timestamp`].
+    pub fn with_int96_timezone(self, timezone: impl Into<Arc<str>>) -> Self {
+        Self {
+            int96_timezone: Some(timezone.into()),
+            ..self
+        }
+    }
+
+    /// Set how INT96 timestamps that cannot be represented in the arrow
+    /// timestamp type of the column are handled (defaults to [`Int96Overflow::Wrap`])
+    pub fn with_int96_overflow(self, int96_overflow: Int96Overflow) -> Self {
+        Self {
+            int96_overflow,
+            ..self
+        }
+    }
+
+    /// Decode the top-level `columns` to [`VariantArray`]s (defaults to none)
+    ///
+    /// Each column must either be a `BYTE_ARRAY` column of JSON strings, which
+    /// are parsed into variants as each batch is decoded, or a variant group of
+    /// a binary `metadata` column and a binary `value` and/or `typed_value`
+    /// column, as described by the [Variant Shredding] specification, which is
+    /// reassembled into unshredded variants. The `typed_value` may be a primitive,
+    /// or a shredded object or list.
+    ///
+    /// The columns are read as the storage type of a [`VariantArray`], annotated
+    /// with the [`VariantType`] extension type. This takes precedence over the
+    /// type of the columns in any schema provided with [`Self::with_schema`]
+    /// or embedded in the file.
+    ///
+    /// Constructing the reader returns an error if any of `columns` is not a
+    /// top-level column, or cannot be decoded to variants.
+    ///
+    /// [`VariantArray`]: parquet_variant_compute::VariantArray
+    /// [`VariantType`]: parquet_variant_compute::VariantType
+    /// [Variant Shredding]: https://github.com/apache/parquet-format/blob/master/VariantShredding.md
+    #[cfg(feature = "variant_experimental")]
+    pub fn with_variant_columns<I, S>(self, columns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            variant_columns: columns.into_iter().map(Into::into).collect(),
+            ..self
+        }
+    }
+
+    /// Returns the arrow type INT96 columns are decoded to, if configured with
+    /// [`Self::with_int96_unit`] or [`Self::with_int96_timezone`]
+    fn int96_type(&self) -> Option<ArrowType> {
+        if self.int96_unit.is_none() && self.int96_timezone.is_none() {
+            return None;
+        }
+        Some(ArrowType::Timestamp(
+            self.int96_unit.unwrap_or(TimeUnit::Nanosecond),
+            self.int96_timezone.clone(),
+        ))
+    }
+
     /// Retrieve the currently set page index behavior.
     ///
     /// This can be set via [`with_page_index`][Self::with_page_index].
@@ -448,6 +777,19 @@ impl ArrowReaderOptions {
     }
 }
 
+/// How INT96 timestamps that cannot be represented in the arrow timestamp type
+/// of their column are handled, see [`ArrowReaderOptions::with_int96_overflow`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Int96Overflow {
+    /// Wrap around on overflow, producing incorrect timestamps
+    #[default]
+    Wrap,
+    /// Clamp to the minimum or maximum representable timestamp
+    Saturate,
+    /// Return an error
+    Error,
+}
+
 /// The metadata necessary to construct a [`ArrowReaderBuilder`]
 ///
 /// Note this structure is cheaply clone-able as it consists of several arcs.
@@ -470,6 +812,10 @@ pub struct ArrowReaderMetadata {
     pub(crate) schema: SchemaRef,
 
     pub(crate) fields: Option<Arc<ParquetField>>,
+
+    pub(crate) schema_adapter: Option<Arc<SchemaAdapter>>,
+
+    pub(crate) int96_overflow: Int96Overflow,
 }
 
 impl ArrowReaderMetadata {
@@ -499,8 +845,9 @@ impl ArrowReaderMetadata {
     /// This function does not attempt to load the PageIndex if not present in the metadata.
     /// See [`Self::load`] for more details.
     pub fn try_new(metadata: Arc<ParquetMetaData>, options: ArrowReaderOptions) -> Result<Self> {
-        match options.supplied_schema {
-            Some(supplied_schema) => Self::with_supplied_schema(metadata, supplied_schema.clone()),
+        let int96_type = options.int96_type();
+        let mut this = match options.supplied_schema {
+            Some(supplied_schema) => Self::with_supplied_schema(metadata, supplied_schema.clone())?,
             None => {
                 let kv_metadata = match options.skip_arrow_metadata {
                     true => None,
@@ -511,15 +858,36 @@ impl ArrowReaderMetadata {
                     metadata.file_metadata().schema_descr(),
                     ProjectionMask::all(),
                     kv_metadata,
+                    int96_type.as_ref(),
                 )?;
 
-                Ok(Self {
+                Self {
                     metadata,
                     schema: Arc::new(schema),
                     fields: fields.map(Arc::new),
-                })
+                    schema_adapter: None,
+                    int96_overflow: Int96Overflow::default(),
+                }
             }
+        };
+        this.int96_overflow = options.int96_overflow;
+
+        #[cfg(feature = "variant_experimental")]
+        if !options.variant_columns.is_empty() {
+            let (schema, fields) = crate::arrow::schema::convert_variant_columns(
+                &this.schema,
+                this.fields.as_deref(),
+                &options.variant_columns,
+            )?;
+            this.schema = Arc::new(schema);
+            this.fields = fields.map(Arc::new);
         }
+
+        if let Some(target) = options.target_schema {
+            let adapter = SchemaAdapter::try_new(target, &this.schema)?;
+            this.schema_adapter = Some(Arc::new(adapter));
+        }
+        Ok(this)
     }
 
     fn with_supplied_schema(
@@ -587,6 +955,8 @@ impl ArrowReaderMetadata {
             metadata,
             schema: supplied_schema,
             fields: field_levels.levels.map(Arc::new),
+            schema_adapter: None,
+            int96_overflow: Int96Overflow::default(),
         })
     }
 
@@ -716,10 +1086,17 @@ impl<T: ChunkReader + 'static> ParquetRecordBatchReaderBuilder<T> {
             .row_groups
             .unwrap_or_else(|| (0..self.metadata.num_row_groups()).collect());
 
+        let mut projection = self.projection;
+        if let Some(adapter) = &self.schema_adapter {
+            projection.intersect(&adapter.projection(self.metadata.file_metadata().schema_descr()));
+        }
+
+        let metrics = self.metrics;
         let reader = ReaderRowGroups {
-            reader: Arc::new(self.input.0),
+            reader: Arc::new(MeteredChunkReader::new(self.input.0, metrics.clone())),
             metadata: self.metadata,
             row_groups,
+            metrics: metrics.clone(),
         };
 
         let mut filter = self.filter;
@@ -727,21 +1104,42 @@ impl<T: ChunkReader + 'static> ParquetRecordBatchReaderBuilder<T> {
 
         // Update selection based on any filters
         if let Some(filter) = filter.as_mut() {
-            for predicate in filter.predicates.iter_mut() {
+            let _timer = start_timer(metrics.as_ref(), Phase::Filter);
+            let num_predicates = filter.predicates.len();
+            for (idx, predicate) in filter.predicates.iter_mut().enumerate() {
                 // break early if we have ruled out all rows
                 if !plan_builder.selects_any() {
                     break;
                 }
 
                 let array_reader = ArrayReaderBuilder::new(&reader)
+                    .with_parallelism(self.parallelism)
+                    .with_int96_overflow(self.int96_overflow)
                     .build_array_reader(self.fields.as_deref(), predicate.projection())?;
 
-                plan_builder = plan_builder.with_predicate(array_reader, predicate.as_mut())?;
+                // Stop evaluating the final predicate once enough rows are selected
+                let limit = (idx + 1 == num_predicates)
+                    .then(|| {
+                        self.limit
+                            .map(|l| l.saturating_add(self.offset.unwrap_or(0)))
+                    })
+                    .flatten();
+                plan_builder =
+                    plan_builder.with_predicate(array_reader, predicate.as_mut(), limit, None)?;
             }
         }
 
+        if let Some(metrics) = &metrics {
+            let total = reader.num_rows();
+            metrics.add_rows(total, plan_builder.num_rows_selected().unwrap_or(total));
+        }
+
+        let dictionary_cache = self.dictionary_reuse.then(DictionaryCache::default);
         let array_reader = ArrayReaderBuilder::new(&reader)
-            .build_array_reader(self.fields.as_deref(), &self.projection)?;
+            .with_dictionary_cache(dictionary_cache)
+            .with_parallelism(self.parallelism)
+            .with_int96_overflow(self.int96_overflow)
+            .build_array_reader(self.fields.as_deref(), &projection)?;
 
         let read_plan = plan_builder
             .limited(reader.num_rows())
@@ -750,16 +1148,20 @@ impl<T: ChunkReader + 'static> ParquetRecordBatchReaderBuilder<T> {
             .build_limited()
             .build();
 
-        Ok(ParquetRecordBatchReader::new(array_reader, read_plan))
+        Ok(ParquetRecordBatchReader::new(array_reader, read_plan)
+            .with_metrics(metrics)
+            .with_schema_adapter(self.schema_adapter))
     }
 }
 
 struct ReaderRowGroups<T: ChunkReader> {
-    reader: Arc<T>,
+    reader: Arc<MeteredChunkReader<T>>,
 
     metadata: Arc<ParquetMetaData>,
     /// Optional list of row group indices to scan
     row_groups: Vec<usize>,
+
+    metrics: Option<ReadMetrics>,
 }
 
 impl<T: ChunkReader + 'static> RowGroups for ReaderRowGroups<T> {
@@ -777,20 +1179,25 @@ impl<T: ChunkReader + 'static> RowGroups for ReaderRowGroups<T> {
             reader: self.reader.clone(),
             metadata: self.metadata.clone(),
             row_groups: self.row_groups.clone().into_iter(),
+            metrics: self.metrics.clone(),
         }))
     }
 }
 
 struct ReaderPageIterator<T: ChunkReader> {
-    reader: Arc<T>,
+    reader: Arc<MeteredChunkReader<T>>,
     column_idx: usize,
     row_groups: std::vec::IntoIter<usize>,
     metadata: Arc<ParquetMetaData>,
+    metrics: Option<ReadMetrics>,
 }
 
 impl<T: ChunkReader + 'static> ReaderPageIterator<T> {
     /// Return the next SerializedPageReader
-    fn next_page_reader(&mut self, rg_idx: usize) -> Result<SerializedPageReader<T>> {
+    fn next_page_reader(
+        &mut self,
+        rg_idx: usize,
+    ) -> Result<SerializedPageReader<MeteredChunkReader<T>>> {
         let rg = self.metadata.row_group(rg_idx);
         let column_chunk_metadata = rg.column(self.column_idx);
         let offset_index = self.metadata.offset_index();
@@ -817,9 +1224,9 @@ impl<T: ChunkReader + 'static> Iterator for ReaderPageIterator<T> {
 
     fn next(&mut self) -> Option<Self::Item> {
         let rg_idx = self.row_groups.next()?;
-        let page_reader = self
-            .next_page_reader(rg_idx)
-            .map(|page_reader| Box::new(page_reader) as _);
+        let page_reader = self.next_page_reader(rg_idx).map(|page_reader| {
+            MeteredPageReader::wrap(Box::new(page_reader), self.metrics.as_ref())
+        });
         Some(page_reader)
     }
 }
@@ -832,6 +1239,8 @@ pub struct ParquetRecordBatchReader {
     array_reader: Box<dyn ArrayReader>,
     schema: SchemaRef,
     read_plan: ReadPlan,
+    metrics: Option<ReadMetrics>,
+    schema_adapter: Option<Arc<SchemaAdapter>>,
 }
 
 impl Iterator for ParquetRecordBatchReader {
@@ -851,6 +1260,7 @@ impl ParquetRecordBatchReader {
     /// Returns `Result<Option<..>>` rather than `Option<Result<..>>` to
     /// simplify error handling with `?`
     fn next_inner(&mut self) -> Result<Option<RecordBatch>> {
+        let _timer = start_timer(self.metrics.as_ref(), Phase::Decode);
         let mut read_records = 0;
         let batch_size = self.batch_size();
         match self.read_plan.selection_mut() {
@@ -903,11 +1313,15 @@ impl ParquetRecordBatchReader {
             ArrowError::ParquetError("Struct array reader should return struct array".to_string())
         })?;
 
-        Ok(if struct_array.len() > 0 {
-            Some(RecordBatch::from(struct_array))
-        } else {
-            None
-        })
+        if struct_array.len() == 0 {
+            return Ok(None);
+        }
+
+        let batch = RecordBatch::from(struct_array);
+        match &self.schema_adapter {
+            Some(adapter) => Ok(Some(adapter.adapt(batch)?)),
+            None => Ok(Some(batch)),
+        }
     }
 }
 
@@ -952,6 +1366,8 @@ impl ParquetRecordBatchReader {
             array_reader,
             schema: Arc::new(Schema::new(levels.fields.clone())),
             read_plan,
+            metrics: None,
+            schema_adapter: None,
         })
     }
 
@@ -968,6 +1384,25 @@ impl ParquetRecordBatchReader {
             array_reader,
             schema: Arc::new(schema),
             read_plan,
+            metrics: None,
+            schema_adapter: None,
+        }
+    }
+
+    /// Record the time spent decoding into the provided [`ReadMetrics`]
+    pub(crate) fn with_metrics(self, metrics: Option<ReadMetrics>) -> Self {
+        Self { metrics, ..self }
+    }
+
+    /// Convert the decoded batches to the target schema of the provided [`SchemaAdapter`]
+    pub(crate) fn with_schema_adapter(self, schema_adapter: Option<Arc<SchemaAdapter>>) -> Self {
+        match schema_adapter {
+            Some(adapter) => Self {
+                schema: Arc::clone(adapter.schema()),
+                schema_adapter: Some(adapter),
+                ..self
+            },
+            None => self,
         }
     }
 
@@ -985,6 +1420,7 @@ mod tests {
     use std::fs::File;
     use std::io::Seek;
     use std::path::PathBuf;
+    use std::sync::atomic::{AtomicUsize, Ordering};
     use std::sync::Arc;
 
     use arrow_array::builder::*;
@@ -992,6 +1428,7 @@ mod tests {
     use arrow_array::types::{
         Date32Type, Date64Type, Decimal128Type, Decimal256Type, DecimalType, Float16Type,
         Float32Type, Float64Type, Time32MillisecondType, Time64MicrosecondType,
+        TimestampMillisecondType,
     };
     use arrow_array::*;
     use arrow_buffer::{i256, ArrowNativeType, Buffer, IntervalDayTime};
@@ -1007,8 +1444,9 @@ mod tests {
     use tempfile::tempfile;
 
     use crate::arrow::arrow_reader::{
-        ArrowPredicateFn, ArrowReaderBuilder, ArrowReaderOptions, ParquetRecordBatchReader,
-        ParquetRecordBatchReaderBuilder, RowFilter, RowSelection, RowSelector,
+        ArrowPredicateFn, ArrowReaderBuilder, ArrowReaderOptions, Int96Overflow,
+        ParquetRecordBatchReader, ParquetRecordBatchReaderBuilder, ReadMetrics, RowFilter,
+        RowSelection, RowSelector,
     };
     use crate::arrow::schema::add_encoded_arrow_schema_to_metadata;
     use crate::arrow::{ArrowWriter, ProjectionMask};
@@ -1690,6 +2128,269 @@ mod tests {
             });
     }
 
+    /// Writes an optional INT96 column containing `1970-01-02T00:00:01`, a null,
+    /// and `9999-12-31T00:00:00`, which cannot be represented in nanoseconds
+    fn int96_test_file() -> Bytes {
+        let schema = Arc::new(parse_message_type("message m { OPTIONAL INT96 ts; }").unwrap());
+        let mut valid = Int96::new();
+        valid.set_data(1_000_000_000, 0, 2_440_589);
+        let mut far_future = Int96::new();
+        far_future.set_data(0, 0, 5_373_484);
+
+        let mut buf = Vec::new();
+        let mut writer = SerializedFileWriter::new(&mut buf, schema, Default::default()).unwrap();
+        let mut row_group_writer = writer.next_row_group().unwrap();
+        let mut col_writer = row_group_writer.next_column().unwrap().unwrap();
+        col_writer
+            .typed::<Int96Type>()
+            .write_batch(&[valid, far_future], Some(&[1, 0, 1]), None)
+            .unwrap();
+        col_writer.close().unwrap();
+        row_group_writer.close().unwrap();
+        writer.close().unwrap();
+        Bytes::from(buf)
+    }
+
+    fn read_int96(options: ArrowReaderOptions) -> Result<RecordBatch> {
+        let mut reader =
+            ParquetRecordBatchReaderBuilder::try_new_with_options(int96_test_file(), options)?
+                .build()?;
+        Ok(reader.next().unwrap()?)
+    }
+
+    #[test]
+    fn test_int96_unit_and_timezone() {
+        let options = ArrowReaderOptions::new()
+            .with_int96_unit(TimeUnit::Microsecond)
+            .with_int96_timezone("UTC");
+        let batch = read_int96(options).unwrap();
+        let expected = TimestampMicrosecondArray::from(vec![
+            Some(86_401_000_000),
+            None,
+            Some(253_402_214_400_000_000),
+        ])
+        .with_timezone("UTC");
+        assert_eq!(batch.column(0).as_ref(), &expected as &dyn Array);
+
+        // The timezone defaults to none, and the unit to nanoseconds
+        let options = ArrowReaderOptions::new().with_int96_unit(TimeUnit::Millisecond);
+        let batch = read_int96(options).unwrap();
+        assert_eq!(
+            batch.column(0).data_type(),
+            &ArrowDataType::Timestamp(TimeUnit::Millisecond, None)
+        );
+
+        let options = ArrowReaderOptions::new().with_int96_timezone("+01:00");
+        let batch = read_int96(options).unwrap();
+        assert_eq!(
+            batch.column(0).data_type(),
+            &ArrowDataType::Timestamp(TimeUnit::Nanosecond, Some("+01:00".into()))
+        );
+
+        // A supplied schema takes precedence
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "ts",
+            ArrowDataType::Timestamp(TimeUnit::Second, None),
+            true,
+        )]));
+        let options = ArrowReaderOptions::new()
+            .with_schema(schema.clone())
+            .with_int96_unit(TimeUnit::Microsecond);
+        let batch = read_int96(options).unwrap();
+        assert_eq!(batch.schema(), schema);
+    }
+
+    #[test]
+    fn test_int96_overflow() {
+        let mut far_future = Int96::new();
+        far_future.set_data(0, 0, 5_373_484);
+
+        let batch = read_int96(ArrowReaderOptions::new()).unwrap();
+        let values = batch
+            .column(0)
+            .as_primitive::<types::TimestampNanosecondType>();
+        assert_eq!(values.value(0), 86_401_000_000_000);
+        assert_eq!(values.value(2), far_future.to_nanos());
+
+        let options = ArrowReaderOptions::new().with_int96_overflow(Int96Overflow::Saturate);
+        let batch = read_int96(options).unwrap();
+        let expected =
+            TimestampNanosecondArray::from(vec![Some(86_401_000_000_000), None, Some(i64::MAX)]);
+        assert_eq!(batch.column(0).as_ref(), &expected as &dyn Array);
+
+        let options = ArrowReaderOptions::new().with_int96_overflow(Int96Overflow::Error);
+        let err = read_int96(options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "External: Parquet argument error: Parquet error: INT96 timestamp with 253402214400 seconds since epoch out of range for Timestamp(Nanosecond, None)"
+        );
+
+        // Null slots never overflow
+        let options = ArrowReaderOptions::new()
+            .with_int96_unit(TimeUnit::Microsecond)
+            .with_int96_overflow(Int96Overflow::Error);
+        let batch = read_int96(options).unwrap();
+        assert_eq!(batch.column(0).null_count(), 1);
+    }
+
+    #[test]
+    #[cfg(feature = "variant_experimental")]
+    fn test_read_variant_columns() {
+        use parquet_variant::{Variant, VariantBuilder};
+        use parquet_variant_compute::{VariantArray, VariantType};
+
+        let (metadata, value) = {
+            let mut builder = VariantBuilder::new();
+            let mut object = builder.new_object();
+            object.insert("a", 1);
+            object.finish().unwrap();
+            builder.finish()
+        };
+        let group_fields = Fields::from(vec![
+            Field::new("metadata", ArrowDataType::Binary, false),
+            Field::new("value", ArrowDataType::Binary, true),
+            Field::new("typed_value", ArrowDataType::Int64, true),
+        ]);
+        let group = StructArray::new(
+            group_fields.clone(),
+            vec![
+                Arc::new(BinaryArray::from(vec![metadata.as_slice(); 3])),
+                Arc::new(BinaryArray::from(vec![Some(value.as_slice()), None, None])),
+                Arc::new(Int64Array::from(vec![None, Some(7), None])),
+            ],
+            Some(vec![true, true, false].into()),
+        );
+        let json = StringArray::from(vec![Some(r#"{"b": [1, "x"]}"#), None, Some("true")]);
+        let batch = RecordBatch::try_from_iter([
+            ("id", Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef),
+            ("json", Arc::new(json) as ArrayRef),
+            ("v", Arc::new(group) as ArrayRef),
+        ])
+        .unwrap();
+
+        let mut buf = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        let options = ArrowReaderOptions::new().with_variant_columns(["json", "v"]);
+        let builder =
+            ParquetRecordBatchReaderBuilder::try_new_with_options(data.clone(), options).unwrap();
+        let schema = builder.schema().clone();
+        for name in ["json", "v"] {
+            let field = schema.field_with_name(name).unwrap();
+            field.try_extension_type::<VariantType>().unwrap();
+        }
+        assert_eq!(schema.field(0).data_type(), &ArrowDataType::Int32);
+
+        let batch = builder.build().unwrap().next().unwrap().unwrap();
+        assert_eq!(batch.schema(), schema);
+
+        let json = VariantArray::try_new(batch.column(1).clone()).unwrap();
+        let list = json.value(0).get_object_field("b").unwrap();
+        assert_eq!(list.get_list_element(1), Some(Variant::from("x")));
+        assert!(json.is_null(1));
+        assert_eq!(json.value(2), Variant::BooleanTrue);
+
+        let v = VariantArray::try_new(batch.column(2).clone()).unwrap();
+        assert_eq!(v.value(0).get_object_field("a"), Some(Variant::Int32(1)));
+        assert_eq!(v.value(1), Variant::Int64(7));
+        assert!(v.is_null(2));
+
+        // Projecting part of a variant group is an error
+        let options = ArrowReaderOptions::new().with_variant_columns(["v"]);
+        let builder =
+            ParquetRecordBatchReaderBuilder::try_new_with_options(data.clone(), options).unwrap();
+        let mask = ProjectionMask::leaves(builder.parquet_schema(), [2]);
+        let err = builder.with_projection(mask).build().err().unwrap();
+        assert!(err.to_string().contains("must be fully projected"), "{err}");
+
+        let options = ArrowReaderOptions::new().with_variant_columns(["id"]);
+        let err = ParquetRecordBatchReaderBuilder::try_new_with_options(data.clone(), options)
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Cannot decode column id of type Int32 to variant"));
+
+        let options = ArrowReaderOptions::new().with_variant_columns(["missing"]);
+        let err = ParquetRecordBatchReaderBuilder::try_new_with_options(data, options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Arrow: Variant column missing not found in schema"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "variant_experimental")]
+    fn test_read_nested_shredded_variant() {
+        use parquet_variant::{Variant, VariantBuilder};
+        use parquet_variant_compute::VariantArray;
+
+        let (metadata, value) = {
+            let mut builder = VariantBuilder::new();
+            let mut object = builder.new_object();
+            object.insert("b", "x");
+            object.finish().unwrap();
+            builder.finish()
+        };
+        // The object field `a` shredded as an Int64
+        let a_fields = Fields::from(vec![
+            Field::new("value", ArrowDataType::Binary, true),
+            Field::new("typed_value", ArrowDataType::Int64, true),
+        ]);
+        let a = StructArray::new(
+            a_fields.clone(),
+            vec![
+                Arc::new(BinaryArray::from(vec![None::<&[u8]>; 2])),
+                Arc::new(Int64Array::from(vec![Some(1), None])),
+            ],
+            None,
+        );
+        let typed_value_fields = Fields::from(vec![Field::new(
+            "a",
+            ArrowDataType::Struct(a_fields),
+            false,
+        )]);
+        let typed_value = StructArray::new(typed_value_fields.clone(), vec![Arc::new(a)], None);
+        let group = StructArray::new(
+            Fields::from(vec![
+                Field::new("metadata", ArrowDataType::Binary, false),
+                Field::new("value", ArrowDataType::Binary, true),
+                Field::new(
+                    "typed_value",
+                    ArrowDataType::Struct(typed_value_fields),
+                    true,
+                ),
+            ]),
+            vec![
+                Arc::new(BinaryArray::from(vec![metadata.as_slice(); 2])),
+                Arc::new(BinaryArray::from(vec![Some(value.as_slice()), None])),
+                Arc::new(typed_value),
+            ],
+            None,
+        );
+        let batch = RecordBatch::try_from_iter([("v", Arc::new(group) as ArrayRef)]).unwrap();
+
+        let mut buf = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let options = ArrowReaderOptions::new().with_variant_columns(["v"]);
+        let mut reader =
+            ParquetRecordBatchReaderBuilder::try_new_with_options(Bytes::from(buf), options)
+                .unwrap()
+                .build()
+                .unwrap();
+        let batch = reader.next().unwrap().unwrap();
+
+        let v = VariantArray::try_new(batch.column(0).clone()).unwrap();
+        assert_eq!(v.value(0).get_object_field("a"), Some(Variant::Int64(1)));
+        assert_eq!(v.value(0).get_object_field("b"), Some(Variant::from("x")));
+        assert_eq!(v.value(1).get_object_field("a"), None);
+    }
+
     struct RandUtf8Gen {}
 
     impl RandGen<ByteArrayType> for RandUtf8Gen {
@@ -3671,6 +4372,297 @@ mod tests {
         arrow_reader.next().unwrap().unwrap_err();
     }
 
+    #[test]
+    fn test_dictionary_reuse() {
+        let values =
+            StringArray::from_iter_values(["a", "b", "c", "d", "e"].iter().cycle().take(30));
+        let dict = arrow_cast::cast(
+            &values,
+            &ArrowDataType::Dictionary(
+                Box::new(ArrowDataType::Int32),
+                Box::new(ArrowDataType::Utf8),
+            ),
+        )
+        .unwrap();
+        let batch = RecordBatch::try_from_iter([("d", dict)]).unwrap();
+
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(10)
+            .set_data_page_row_count_limit(5)
+            .set_write_batch_size(5)
+            .build();
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        let read = |reuse: bool| {
+            let reader = ParquetRecordBatchReaderBuilder::try_new(data.clone())
+                .unwrap()
+                .with_batch_size(5)
+                .with_dictionary_reuse(reuse)
+                .build()
+                .unwrap();
+            let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+            assert_eq!(batches.len(), 6);
+            let dictionaries: Vec<_> = batches
+                .iter()
+                .map(|b| b.column(0).as_any_dictionary().values().to_data())
+                .collect();
+            let out: Vec<_> = batches.iter().map(|b| b.column(0).clone()).collect();
+            let out =
+                arrow_select::concat::concat(&out.iter().map(|a| a.as_ref()).collect::<Vec<_>>())
+                    .unwrap();
+            assert_eq!(
+                arrow_cast::cast(&out, &ArrowDataType::Utf8)
+                    .unwrap()
+                    .as_ref(),
+                &values as &dyn Array
+            );
+            dictionaries
+        };
+
+        // Batches of the same row group always share the dictionary
+        let dictionaries = read(false);
+        assert!(dictionaries[0].ptr_eq(&dictionaries[1]));
+        assert!(!dictionaries[1].ptr_eq(&dictionaries[2]));
+
+        let dictionaries = read(true);
+        assert!(dictionaries.iter().all(|d| d.ptr_eq(&dictionaries[0])));
+    }
+
+    #[test]
+    fn test_parallel_decode() {
+        let ints: ArrayRef = Arc::new(Int64Array::from_iter(
+            (0..100).map(|x| (x % 7 != 0).then_some(x)),
+        ));
+        let strings: ArrayRef = Arc::new(StringArray::from_iter_values(
+            (0..100).map(|x| format!("s{x}")),
+        ));
+        let floats: ArrayRef = Arc::new(Float64Array::from_iter_values((0..100).map(|x| x as f64)));
+        let nested: ArrayRef = Arc::new(StructArray::from(vec![(
+            Arc::new(Field::new("v", ArrowDataType::Int64, true)),
+            ints.clone(),
+        )]));
+        let batch = RecordBatch::try_from_iter([
+            ("ints", ints),
+            ("strings", strings),
+            ("floats", floats),
+            ("nested", nested),
+        ])
+        .unwrap();
+
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(30)
+            .set_data_page_row_count_limit(10)
+            .set_write_batch_size(10)
+            .build();
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        let selection = RowSelection::from(vec![
+            RowSelector::select(15),
+            RowSelector::skip(40),
+            RowSelector::select(45),
+        ]);
+        let expected =
+            concat_batches(&batch.schema(), &[batch.slice(0, 15), batch.slice(55, 45)]).unwrap();
+
+        for parallelism in [0, 1, 2, 8] {
+            let reader = ParquetRecordBatchReaderBuilder::try_new(data.clone())
+                .unwrap()
+                .with_batch_size(16)
+                .with_row_selection(selection.clone())
+                .with_parallelism(parallelism)
+                .build()
+                .unwrap();
+            let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+            let read = concat_batches(&batch.schema(), &batches).unwrap();
+            assert_eq!(read, expected);
+        }
+    }
+
+    #[test]
+    fn test_file_row_selection() {
+        let values: ArrayRef = Arc::new(Int64Array::from_iter_values(0..100));
+        let batch = RecordBatch::try_from_iter([("id", values)]).unwrap();
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(10)
+            .set_data_page_row_count_limit(2)
+            .set_write_batch_size(2)
+            .build();
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        let row_ids = [0, 3, 4, 9, 57, 98, 99];
+        let read = |row_groups: Option<Vec<usize>>| {
+            let metrics = ReadMetrics::new();
+            let options = ArrowReaderOptions::new().with_page_index(true);
+            let mut builder =
+                ParquetRecordBatchReaderBuilder::try_new_with_options(data.clone(), options)
+                    .unwrap()
+                    .with_metrics(metrics.clone());
+            if let Some(row_groups) = row_groups {
+                builder = builder.with_row_groups(row_groups);
+            }
+            let reader = builder
+                .with_file_row_selection(RowSelection::from_row_ids(row_ids, 100))
+                .build()
+                .unwrap();
+            let ids: Vec<i64> = reader
+                .flat_map(|b| {
+                    let b = b.unwrap();
+                    b.column(0)
+                        .as_primitive::<types::Int64Type>()
+                        .values()
+                        .to_vec()
+                })
+                .collect();
+            (ids, metrics)
+        };
+
+        let (ids, metrics) = read(None);
+        assert_eq!(ids, [0, 3, 4, 9, 57, 98, 99]);
+        // Row groups 0, 5 and 9 are read, decoding only their dictionary pages
+        // and the data pages with selected rows
+        assert_eq!(metrics.rows_selected(), 7);
+        assert_eq!(metrics.pages_decoded(), 9);
+        assert_eq!(metrics.pages_skipped(), 9);
+
+        let (ids, _) = read(Some(vec![9, 1, 0]));
+        assert_eq!(ids, [98, 99, 0, 3, 4, 9]);
+
+        // A row group listed twice is read twice
+        let (ids, _) = read(Some(vec![9, 0, 9]));
+        assert_eq!(ids, [98, 99, 0, 3, 4, 9, 98, 99]);
+    }
+
+    #[test]
+    fn test_limit_with_row_filter() {
+        let values: ArrayRef = Arc::new(Int64Array::from_iter_values(0..100));
+        let batch = RecordBatch::try_from_iter([("id", values)]).unwrap();
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(25)
+            .build();
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        let read = |offset: usize, limit: usize| {
+            let evaluated = Arc::new(AtomicUsize::new(0));
+            let counter = Arc::clone(&evaluated);
+            let predicate = ArrowPredicateFn::new(ProjectionMask::all(), move |batch| {
+                counter.fetch_add(batch.num_rows(), Ordering::Relaxed);
+                let id = batch.column(0).as_primitive::<types::Int64Type>();
+                Ok(BooleanArray::from_unary(id, |v| v % 2 == 0))
+            });
+            let metrics = ReadMetrics::new();
+            let reader = ParquetRecordBatchReaderBuilder::try_new(data.clone())
+                .unwrap()
+                .with_batch_size(10)
+                .with_row_filter(RowFilter::new(vec![Box::new(predicate)]))
+                .with_offset(offset)
+                .with_limit(limit)
+                .with_metrics(metrics.clone())
+                .build()
+                .unwrap();
+            let ids: Vec<i64> = reader
+                .flat_map(|b| {
+                    let b = b.unwrap();
+                    b.column(0)
+                        .as_primitive::<types::Int64Type>()
+                        .values()
+                        .to_vec()
+                })
+                .collect();
+            (
+                ids,
+                evaluated.load(Ordering::Relaxed),
+                metrics.bytes_fetched(),
+            )
+        };
+
+        // Evaluation stops after the first batch, and later row groups are not read
+        let (ids, evaluated, fetched) = read(0, 3);
+        assert_eq!(ids, [0, 2, 4]);
+        assert_eq!(evaluated, 10);
+        let (_, _, fetched_all) = read(0, 100);
+        assert!(fetched < fetched_all);
+
+        // The offset rows must also be selected
+        let (ids, evaluated, _) = read(10, 3);
+        assert_eq!(ids, [20, 22, 24]);
+        assert_eq!(evaluated, 30);
+
+        let (ids, evaluated, _) = read(45, 10);
+        assert_eq!(ids, [90, 92, 94, 96, 98]);
+        assert_eq!(evaluated, 100);
+    }
+
+    #[test]
+    fn test_with_target_schema() {
+        let batch = RecordBatch::try_from_iter([
+            (
+                "ts",
+                Arc::new(TimestampMillisecondArray::from(vec![0, 1000])) as ArrayRef,
+            ),
+            (
+                "big",
+                Arc::new(Int64Array::from(vec![1, i64::MAX])) as ArrayRef,
+            ),
+            ("s", Arc::new(StringArray::from(vec!["a", "b"])) as ArrayRef),
+        ])
+        .unwrap();
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        let ts = ArrowDataType::Timestamp(TimeUnit::Millisecond, Some("+01:00".into()));
+        let target = Arc::new(Schema::new(vec![
+            Field::new("s", ArrowDataType::LargeUtf8, true),
+            Field::new("ts", ts, true),
+        ]));
+        let options = ArrowReaderOptions::new().with_target_schema(target.clone());
+        let builder =
+            ParquetRecordBatchReaderBuilder::try_new_with_options(data.clone(), options).unwrap();
+        // Projection refers to the columns of the file
+        let mask = ProjectionMask::roots(builder.parquet_schema(), [0, 1]);
+        let mut reader = builder.with_projection(mask).build().unwrap();
+        assert_eq!(reader.schema(), target);
+
+        let out = reader.next().unwrap().unwrap();
+        assert_eq!(out.schema(), target);
+        assert_eq!(out.column(0).null_count(), 2);
+        // Timestamps without a timezone are local times in the target timezone
+        let ts = out.column(1).as_primitive::<TimestampMillisecondType>();
+        assert_eq!(ts.values(), &[-3_600_000, -3_599_000]);
+
+        // Casts that overflow return an error
+        let target = Arc::new(Schema::new(vec![Field::new(
+            "big",
+            ArrowDataType::Int32,
+            true,
+        )]));
+        let options = ArrowReaderOptions::new().with_target_schema(target);
+        let mut reader = ParquetRecordBatchReaderBuilder::try_new_with_options(data, options)
+            .unwrap()
+            .build()
+            .unwrap();
+        let err = reader.next().unwrap().unwrap_err();
+        assert!(err.to_string().contains("Can't cast value"), "{err}");
+    }
+
     #[test]
     fn test_with_schema() {
         let nested_fields = Fields::from(vec![
@@ -4338,6 +5330,185 @@ mod tests {
         assert_eq!(out, batch.slice(2, 1));
     }
 
+    #[test]
+    fn test_read_metrics() {
+        let batch = RecordBatch::try_from_iter([(
+            "a",
+            Arc::new(Int32Array::from_iter_values(0..100)) as _,
+        )])
+        .unwrap();
+
+        // Write 10 pages of 10 rows each
+        let props = WriterProperties::builder()
+            .set_dictionary_enabled(false)
+            .set_data_page_row_count_limit(10)
+            .set_write_batch_size(10)
+            .build();
+
+        let mut buffer = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buffer, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buffer);
+
+        let selection = RowSelection::from(vec![
+            RowSelector::skip(50),
+            RowSelector::select(20),
+            RowSelector::skip(30),
+        ]);
+        let options = ArrowReaderOptions::new().with_page_index(true);
+        let metrics = ReadMetrics::new();
+        let reader = ParquetRecordBatchReaderBuilder::try_new_with_options(data.clone(), options)
+            .unwrap()
+            .with_row_selection(selection)
+            .with_metrics(metrics.clone())
+            .build()
+            .unwrap();
+        let out = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(out, vec![batch.slice(50, 20)]);
+
+        assert_eq!(metrics.rows_selected(), 20);
+        assert_eq!(metrics.rows_pruned(), 80);
+        assert_eq!(metrics.pages_decoded(), 2);
+        assert_eq!(metrics.pages_skipped(), 5);
+        assert!(metrics.bytes_fetched() > 0);
+        assert!(metrics.bytes_fetched() < data.len() as u64);
+
+        // Metrics are accumulated across readers
+        let reader = ParquetRecordBatchReaderBuilder::try_new(data)
+            .unwrap()
+            .with_row_filter(RowFilter::new(vec![Box::new(ArrowPredicateFn::new(
+                ProjectionMask::all(),
+                |batch| {
+                    arrow::compute::kernels::cmp::lt(batch.column(0), &Int32Array::new_scalar(10))
+                },
+            ))]))
+            .with_metrics(metrics.clone())
+            .build()
+            .unwrap();
+        let out = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(out, vec![batch.slice(0, 10)]);
+
+        assert_eq!(metrics.rows_selected(), 30);
+        assert_eq!(metrics.rows_pruned(), 170);
+        assert_eq!(metrics.pages_decoded(), 2 + 10 + 1);
+    }
+
+    #[test]
+    fn test_row_filter_nested_leaves() {
+        let s = StructArray::from(vec![
+            (
+                Arc::new(Field::new("a", ArrowDataType::Int32, true)),
+                Arc::new(Int32Array::from_iter_values(0..6)) as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new("b", ArrowDataType::Utf8, true)),
+                Arc::new(StringArray::from_iter_values([
+                    "a", "b", "c", "d", "e", "f",
+                ])) as ArrayRef,
+            ),
+        ]);
+        let l = ListArray::from_iter_primitive::<arrow_array::types::Int32Type, _, _>(vec![
+            Some(vec![Some(0)]),
+            Some(vec![Some(1), Some(1)]),
+            Some(vec![Some(2), Some(2)]),
+            Some(vec![Some(3)]),
+            Some(vec![Some(4), Some(4), Some(4)]),
+            None,
+        ]);
+        let batch = RecordBatch::try_from_iter([
+            ("s", Arc::new(s) as ArrayRef),
+            ("l", Arc::new(l) as ArrayRef),
+        ])
+        .unwrap();
+
+        let mut buffer = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buffer, batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let builder = ParquetRecordBatchReaderBuilder::try_new(Bytes::from(buffer)).unwrap();
+        let schema = builder.parquet_schema();
+
+        let path = |s: &str| {
+            crate::schema::types::ColumnPath::new(s.split('.').map(String::from).collect())
+        };
+
+        // Only decodes `s.a`, not `s.b`
+        let struct_filter = ArrowPredicateFn::new(
+            ProjectionMask::column_paths(schema, &[path("s.a")]),
+            |batch| {
+                let s = batch.column(0).as_struct();
+                assert_eq!(s.num_columns(), 1);
+                let a = s.column(0).as_primitive::<arrow_array::types::Int32Type>();
+                Ok(BooleanArray::from_unary(a, |x| x > 2))
+            },
+        );
+
+        // Only decodes the list elements
+        let list_filter = ArrowPredicateFn::new(
+            ProjectionMask::column_paths(schema, &[path("l.list.item")]),
+            |batch| {
+                assert_eq!(batch.num_columns(), 1);
+                let l = batch.column(0).as_list::<i32>();
+                Ok(l.iter().map(|x| x.map(|x| x.len() > 1)).collect())
+            },
+        );
+
+        let reader = builder
+            .with_row_filter(RowFilter::new(vec![
+                Box::new(struct_filter),
+                Box::new(list_filter),
+            ]))
+            .build()
+            .unwrap();
+
+        let out = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(out, vec![batch.slice(4, 1)]);
+    }
+
+    #[test]
+    fn test_decimal32_decimal64_roundtrip() {
+        let d1 = Decimal32Array::from(vec![Some(1), None, Some(-999_999_999)])
+            .with_precision_and_scale(9, 2)
+            .unwrap();
+        let d2 = Decimal32Array::from(vec![Some(1), Some(-9), None])
+            .with_precision_and_scale(1, 0)
+            .unwrap();
+        let d3 = Decimal64Array::from(vec![Some(1), None, Some(-999_999_999_999_999_999)])
+            .with_precision_and_scale(18, 4)
+            .unwrap();
+        let d4 = Decimal64Array::from(vec![Some(1), Some(-99_999), None])
+            .with_precision_and_scale(5, 1)
+            .unwrap();
+
+        let batch = RecordBatch::try_from_iter([
+            ("d1", Arc::new(d1) as ArrayRef),
+            ("d2", Arc::new(d2) as ArrayRef),
+            ("d3", Arc::new(d3) as ArrayRef),
+            ("d4", Arc::new(d4) as ArrayRef),
+        ])
+        .unwrap();
+
+        let mut buffer = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buffer, batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let builder = ParquetRecordBatchReaderBuilder::try_new(Bytes::from(buffer)).unwrap();
+        let columns = builder.parquet_schema().columns();
+        assert_eq!(columns[0].physical_type(), PhysicalType::INT32);
+        assert_eq!(columns[1].physical_type(), PhysicalType::INT64);
+        assert_eq!(columns[2].physical_type(), PhysicalType::INT64);
+        assert_eq!(columns[3].physical_type(), PhysicalType::INT32);
+
+        let mut reader = builder.build().unwrap();
+        assert_eq!(batch.schema(), reader.schema());
+
+        let out = reader.next().unwrap().unwrap();
+        assert_eq!(batch, out);
+    }
+
     fn test_decimal_roundtrip<T: DecimalType>() {
         // Precision <= 9 -> INT32
         // Precision <= 18 -> INT64
@@ -4560,6 +5731,75 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_list_selection_page_index() {
+        let mut list = ListBuilder::new(ListBuilder::new(Int32Builder::new()));
+        for i in 0..1000 {
+            for j in 0..i % 4 {
+                list.values().values().append_value(i);
+                list.values().append(j % 3 != 0);
+            }
+            list.append(i % 7 != 0);
+        }
+        let batch = RecordBatch::try_from_iter([("l", Arc::new(list.finish()) as _)]).unwrap();
+
+        let selections = [
+            vec![
+                RowSelector::select(5),
+                RowSelector::skip(290),
+                RowSelector::select(10),
+                RowSelector::skip(695),
+            ],
+            vec![
+                RowSelector::skip(25),
+                RowSelector::select(50),
+                RowSelector::skip(130),
+                RowSelector::select(1),
+                RowSelector::skip(794),
+            ],
+        ];
+
+        for version in [WriterVersion::PARQUET_1_0, WriterVersion::PARQUET_2_0] {
+            let props = WriterProperties::builder()
+                .set_writer_version(version)
+                .set_data_page_row_count_limit(50)
+                .set_write_batch_size(50)
+                .build();
+            let mut buffer = Vec::with_capacity(1024);
+            let mut writer =
+                ArrowWriter::try_new(&mut buffer, batch.schema(), Some(props)).unwrap();
+            writer.write(&batch).unwrap();
+            writer.close().unwrap();
+            let buffer = Bytes::from(buffer);
+
+            for selection in &selections {
+                let selection = RowSelection::from(selection.clone());
+                let options = ArrowReaderOptions::new().with_page_index(true);
+                let reader =
+                    ParquetRecordBatchReaderBuilder::try_new_with_options(buffer.clone(), options)
+                        .unwrap()
+                        .with_row_selection(selection.clone())
+                        .with_batch_size(64)
+                        .build()
+                        .unwrap();
+                let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+                let actual = concat_batches(batch.schema_ref(), &batches).unwrap();
+
+                let expected: Vec<_> = selection
+                    .iter()
+                    .scan(0, |offset, selector| {
+                        let start = *offset;
+                        *offset += selector.row_count;
+                        Some((!selector.skip).then(|| batch.slice(start, selector.row_count)))
+                    })
+                    .flatten()
+                    .collect();
+                let expected = concat_batches(batch.schema_ref(), &expected).unwrap();
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+
     #[test]
     fn test_read_old_nested_list() {
         use arrow::datatypes::DataType;
@@ -0,0 +1,1160 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`PruningStatistics`] and [`PruningPredicate`] to skip containers of rows, such as
+//! row groups and data pages, that cannot contain rows matching a predicate.
+//!
+//! Statistics are exposed for a set of containers as arrow arrays, with one element per
+//! container, by implementations of [`PruningStatistics`] for
+//!
+//! * row groups, from the statistics in the [`RowGroupMetaData`], see [`RowGroupPruningStatistics`]
+//! * data pages, from the page index, see [`PagePruningStatistics`]
+//! * row groups, from bloom filters, see [`BloomFilterPruningStatistics`]
+//!
+//! A [`PruningPredicate`] can then be evaluated against any of these, or an implementation
+//! of [`PruningStatistics`] for other containers, such as files.
+//!
+//! For string and binary columns, [`prune_byte_array_pages`] evaluates a
+//! [`ByteArrayPredicate`], including prefix matches such as `LIKE 'abc%'`, directly against
+//! the possibly truncated page index, returning the [`RowSelection`] of the pages to read.
+//!
+//! # Example
+//!
+//! ```
+//! # use std::sync::Arc;
+//! # use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+//! # use bytes::Bytes;
+//! # use parquet::arrow::ArrowWriter;
+//! # use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+//! # use parquet::arrow::arrow_reader::pruning::{
+//! #     CompareOp, PruningPredicate, RowGroupPruningStatistics,
+//! # };
+//! # use parquet::file::properties::WriterProperties;
+//! let values: ArrayRef = Arc::new(Int32Array::from_iter_values(0..300));
+//! let batch = RecordBatch::try_from_iter([("a", values)]).unwrap();
+//! let props = WriterProperties::builder().set_max_row_group_size(100).build();
+//! let mut buffer = Vec::new();
+//! let mut writer = ArrowWriter::try_new(&mut buffer, batch.schema(), Some(props)).unwrap();
+//! writer.write(&batch).unwrap();
+//! writer.close().unwrap();
+//!
+//! let builder = ParquetRecordBatchReaderBuilder::try_new(Bytes::from(buffer)).unwrap();
+//! let metadata = builder.metadata().clone();
+//! let statistics = RowGroupPruningStatistics::new(
+//!     builder.schema(),
+//!     metadata.file_metadata().schema_descr(),
+//!     metadata.row_groups(),
+//! );
+//!
+//! // `a > 150 AND a < 180` can only match rows of the second row group
+//! let predicate = PruningPredicate::compare("a", CompareOp::Gt, Arc::new(Int32Array::from(vec![150])))
+//!     .and(PruningPredicate::compare("a", CompareOp::Lt, Arc::new(Int32Array::from(vec![180]))));
+//! let keep = predicate.prune(&statistics).unwrap();
+//! let row_groups: Vec<usize> = keep.set_indices().collect();
+//! assert_eq!(row_groups, vec![1]);
+//!
+//! let reader = builder.with_row_groups(row_groups).build().unwrap();
+//! ```
+
+use crate::arrow::arrow_reader::statistics::StatisticsConverter;
+use crate::arrow::arrow_reader::RowSelection;
+use crate::basic::{ColumnOrder, SortOrder, Type as PhysicalType};
+use crate::bloom_filter::Sbbf;
+use crate::errors::{ParquetError, Result};
+use crate::file::metadata::{
+    ParquetColumnIndex, ParquetMetaData, ParquetOffsetIndex, RowGroupMetaData,
+};
+use crate::file::page_index::index::{Index, PageIndex};
+use crate::schema::types::SchemaDescriptor;
+use arrow_array::{Array, ArrayRef, BooleanArray, Datum, Scalar, UInt64Array};
+use arrow_buffer::BooleanBuffer;
+use arrow_cast::cast;
+use arrow_ord::cmp;
+use arrow_schema::{ArrowError, DataType, Schema};
+use std::collections::HashMap;
+
+/// Statistics of the columns of a set of containers of rows, such as row groups or pages
+///
+/// Each method returns an array with one element per container, or `None` if the
+/// statistic is not known for any container. A null element means the statistic is not
+/// known for that container.
+///
+/// Only [`Self::num_containers`] is required, the other methods default to returning
+/// `None`, and so implementations can provide whichever statistics are available.
+pub trait PruningStatistics {
+    /// The number of containers
+    fn num_containers(&self) -> usize;
+
+    /// The minimum value of `column` in each container
+    ///
+    /// This may be less than the actual minimum, for example if it is truncated
+    fn min_values(&self, _column: &str) -> Option<ArrayRef> {
+        None
+    }
+
+    /// The maximum value of `column` in each container
+    ///
+    /// This may be greater than the actual maximum, for example if it is truncated
+    fn max_values(&self, _column: &str) -> Option<ArrayRef> {
+        None
+    }
+
+    /// The number of null values of `column` in each container
+    fn null_counts(&self, _column: &str) -> Option<UInt64Array> {
+        None
+    }
+
+    /// The number of rows of `column` in each container
+    fn row_counts(&self, _column: &str) -> Option<UInt64Array> {
+        None
+    }
+
+    /// Whether each container may contain any of the non-null `values` of `column`
+    ///
+    /// `false` means the container definitely does not contain any of `values`, while
+    /// `true` means it may, as for example bloom filters have false positives.
+    fn contained(&self, _column: &str, _values: &dyn Array) -> Option<BooleanArray> {
+        None
+    }
+}
+
+/// A comparison of a column with a value in a [`PruningPredicate`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompareOp {
+    /// `column = value`
+    Eq,
+    /// `column != value`
+    NotEq,
+    /// `column < value`
+    Lt,
+    /// `column <= value`
+    LtEq,
+    /// `column > value`
+    Gt,
+    /// `column >= value`
+    GtEq,
+}
+
+/// A predicate on the rows of a container, which can be evaluated against
+/// [`PruningStatistics`] to determine the containers that may contain matching rows
+///
+/// As in SQL, comparisons never match null values.
+#[derive(Debug, Clone)]
+pub enum PruningPredicate {
+    /// `column <op> value`, where `value` is an array of length 1
+    Compare {
+        /// The column name
+        column: String,
+        /// The comparison
+        op: CompareOp,
+        /// The value to compare with
+        value: ArrayRef,
+    },
+    /// `column IN (values)`
+    InList {
+        /// The column name
+        column: String,
+        /// The values to compare with
+        values: ArrayRef,
+    },
+    /// `column IS NULL`
+    IsNull(String),
+    /// `column IS NOT NULL`
+    IsNotNull(String),
+    /// Both predicates match
+    And(Box<PruningPredicate>, Box<PruningPredicate>),
+    /// Either predicate matches
+    Or(Box<PruningPredicate>, Box<PruningPredicate>),
+}
+
+impl PruningPredicate {
+    /// Create a [`PruningPredicate::Compare`]
+    pub fn compare(column: impl Into<String>, op: CompareOp, value: ArrayRef) -> Self {
+        Self::Compare {
+            column: column.into(),
+            op,
+            value,
+        }
+    }
+
+    /// Create a [`PruningPredicate::InList`]
+    pub fn in_list(column: impl Into<String>, values: ArrayRef) -> Self {
+        Self::InList {
+            column: column.into(),
+            values,
+        }
+    }
+
+    /// Create a [`PruningPredicate::And`] of `self` and `other`
+    pub fn and(self, other: Self) -> Self {
+        Self::And(Box::new(self), Box::new(other))
+    }
+
+    /// Create a [`PruningPredicate::Or`] of `self` and `other`
+    pub fn or(self, other: Self) -> Self {
+        Self::Or(Box::new(self), Box::new(other))
+    }
+
+    /// Returns whether each container of `statistics` may contain rows matching this predicate
+    ///
+    /// A container is only pruned, returning `false`, if its statistics show it cannot
+    /// contain matching rows. Containers with unknown statistics are kept, as are all
+    /// containers for statistics that can't be compared with the values of this predicate.
+    ///
+    /// As each result is conservative, the results for different statistics of the same
+    /// containers can be combined, for example those of [`RowGroupPruningStatistics`] and
+    /// [`BloomFilterPruningStatistics`] for the same row groups.
+    ///
+    /// Returns an error if the value of a [`PruningPredicate::Compare`] is not of length 1.
+    pub fn prune(&self, statistics: &dyn PruningStatistics) -> Result<BooleanBuffer> {
+        let n = statistics.num_containers();
+        Ok(match self {
+            Self::Compare { column, op, value } => {
+                if value.len() != 1 {
+                    return Err(general_err!(
+                        "Pruning predicate value for column '{}' must have length 1, got {}",
+                        column,
+                        value.len()
+                    ));
+                }
+                let min = || statistics.min_values(column);
+                let max = || statistics.max_values(column);
+                let keep = match op {
+                    CompareOp::Eq => {
+                        let range = &keep_or_unknown(compare(min(), cmp::lt_eq, value), n)
+                            & &keep_or_unknown(compare(max(), cmp::gt_eq, value), n);
+                        &range & &keep_or_unknown(statistics.contained(column, value.as_ref()), n)
+                    }
+                    // Only pruned if all values are equal to `value`
+                    CompareOp::NotEq => {
+                        let min_eq = keep_or_unknown(compare(min(), cmp::neq, value), n);
+                        &min_eq | &keep_or_unknown(compare(max(), cmp::neq, value), n)
+                    }
+                    CompareOp::Lt => keep_or_unknown(compare(min(), cmp::lt, value), n),
+                    CompareOp::LtEq => keep_or_unknown(compare(min(), cmp::lt_eq, value), n),
+                    CompareOp::Gt => keep_or_unknown(compare(max(), cmp::gt, value), n),
+                    CompareOp::GtEq => keep_or_unknown(compare(max(), cmp::gt_eq, value), n),
+                };
+                &keep & &has_non_null(statistics, column, n)
+            }
+            Self::InList { column, values } => {
+                let (min, max) = (statistics.min_values(column), statistics.max_values(column));
+                let mut keep = BooleanBuffer::new_unset(n);
+                for i in 0..values.len() {
+                    if values.is_null(i) {
+                        continue;
+                    }
+                    let value = values.slice(i, 1);
+                    let range = &keep_or_unknown(compare(min.clone(), cmp::lt_eq, &value), n)
+                        & &keep_or_unknown(compare(max.clone(), cmp::gt_eq, &value), n);
+                    keep = &keep | &range;
+                }
+                let contained = keep_or_unknown(statistics.contained(column, values.as_ref()), n);
+                &(&keep & &contained) & &has_non_null(statistics, column, n)
+            }
+            Self::IsNull(column) => {
+                let zero = UInt64Array::new_scalar(0);
+                let null_counts = statistics.null_counts(column);
+                let has_null = null_counts.and_then(|c| cmp::gt(&c, &zero).ok());
+                keep_or_unknown(has_null, n)
+            }
+            Self::IsNotNull(column) => has_non_null(statistics, column, n),
+            Self::And(left, right) => &left.prune(statistics)? & &right.prune(statistics)?,
+            Self::Or(left, right) => &left.prune(statistics)? | &right.prune(statistics)?,
+        })
+    }
+}
+
+/// Compares `statistic` with `value` using `op`, returning `None` if either is unknown
+/// or they cannot be compared
+fn compare(
+    statistic: Option<ArrayRef>,
+    op: fn(&dyn Datum, &dyn Datum) -> Result<BooleanArray, ArrowError>,
+    value: &ArrayRef,
+) -> Option<BooleanArray> {
+    let statistic = statistic?;
+    let value = cast_lossless(value, statistic.data_type())?;
+    op(&statistic, &Scalar::new(value)).ok()
+}
+
+/// Casts `values` to `data_type`, returning `None` if this loses information, as
+/// comparing the statistics with a truncated value, such as the `Float64` 1.5 cast to
+/// the `Int32` 1, could otherwise prune containers with matching rows
+fn cast_lossless(values: &dyn Array, data_type: &DataType) -> Option<ArrayRef> {
+    let cast_values = cast(values, data_type).ok()?;
+    let round_trip = cast(&cast_values, values.data_type()).ok()?;
+    (round_trip.as_ref() == values).then_some(cast_values)
+}
+
+/// Returns whether each container may contain a non-null value of `column`
+fn has_non_null(statistics: &dyn PruningStatistics, column: &str, n: usize) -> BooleanBuffer {
+    let all_null = statistics
+        .null_counts(column)
+        .zip(statistics.row_counts(column))
+        .and_then(|(nulls, rows)| cmp::lt(&nulls, &rows).ok());
+    keep_or_unknown(all_null, n)
+}
+
+/// Converts `keep` to a [`BooleanBuffer`] of length `n`, where unknown values are `true`
+fn keep_or_unknown(keep: Option<BooleanArray>, n: usize) -> BooleanBuffer {
+    match keep {
+        Some(keep) if keep.len() == n => match keep.nulls() {
+            Some(nulls) => keep.values() | &!nulls.inner(),
+            None => keep.values().clone(),
+        },
+        _ => BooleanBuffer::new_set(n),
+    }
+}
+
+/// [`PruningStatistics`] of row groups from the statistics in their [`RowGroupMetaData`]
+///
+/// See [`StatisticsConverter`] for how the statistics are converted to arrow arrays.
+#[derive(Debug, Clone, Copy)]
+pub struct RowGroupPruningStatistics<'a> {
+    arrow_schema: &'a Schema,
+    parquet_schema: &'a SchemaDescriptor,
+    row_groups: &'a [RowGroupMetaData],
+}
+
+impl<'a> RowGroupPruningStatistics<'a> {
+    /// Create a new [`RowGroupPruningStatistics`] for `row_groups`, with the columns of
+    /// `arrow_schema` matched to those of `parquet_schema`
+    pub fn new(
+        arrow_schema: &'a Schema,
+        parquet_schema: &'a SchemaDescriptor,
+        row_groups: &'a [RowGroupMetaData],
+    ) -> Self {
+        Self {
+            arrow_schema,
+            parquet_schema,
+            row_groups,
+        }
+    }
+
+    fn converter(&self, column: &str) -> Option<StatisticsConverter<'a>> {
+        StatisticsConverter::try_new(column, self.arrow_schema, self.parquet_schema).ok()
+    }
+}
+
+impl PruningStatistics for RowGroupPruningStatistics<'_> {
+    fn num_containers(&self) -> usize {
+        self.row_groups.len()
+    }
+
+    fn min_values(&self, column: &str) -> Option<ArrayRef> {
+        self.converter(column)?.row_group_mins(self.row_groups).ok()
+    }
+
+    fn max_values(&self, column: &str) -> Option<ArrayRef> {
+        self.converter(column)?
+            .row_group_maxes(self.row_groups)
+            .ok()
+    }
+
+    fn null_counts(&self, column: &str) -> Option<UInt64Array> {
+        self.converter(column)?
+            .row_group_null_counts(self.row_groups)
+            .ok()
+    }
+
+    fn row_counts(&self, column: &str) -> Option<UInt64Array> {
+        self.converter(column)?
+            .row_group_row_counts(self.row_groups)
+            .ok()
+            .flatten()
+    }
+}
+
+/// [`PruningStatistics`] of the data pages of a single column, from the page index
+///
+/// The containers are the data pages of the column in each of the selected row groups, in
+/// order. As pages of different columns contain different rows, the statistics of other
+/// columns are unknown.
+#[derive(Debug)]
+pub struct PagePruningStatistics<'a> {
+    converter: StatisticsConverter<'a>,
+    metadata: &'a ParquetMetaData,
+    row_group_indices: &'a [usize],
+    num_pages: usize,
+}
+
+impl<'a> PagePruningStatistics<'a> {
+    /// Create a new [`PagePruningStatistics`] for the data pages of `column` in the row
+    /// groups `row_group_indices` of `metadata`
+    ///
+    /// Returns an error if `column` is not in both `arrow_schema` and the parquet schema,
+    /// or if `metadata` does not contain the page index
+    pub fn try_new(
+        column: &str,
+        arrow_schema: &'a Schema,
+        metadata: &'a ParquetMetaData,
+        row_group_indices: &'a [usize],
+    ) -> Result<Self> {
+        let parquet_schema = metadata.file_metadata().schema_descr();
+        let converter = StatisticsConverter::try_new(column, arrow_schema, parquet_schema)?;
+        let Some(parquet_index) = converter.parquet_column_index() else {
+            return Err(general_err!(
+                "Column '{}' not found in parquet schema",
+                column
+            ));
+        };
+        let (Some(_), Some(offset_index)) = (metadata.column_index(), metadata.offset_index())
+        else {
+            return Err(general_err!(
+                "Page index not loaded for column '{}'",
+                column
+            ));
+        };
+        let num_pages = row_group_indices
+            .iter()
+            .map(|i| offset_index[*i][parquet_index].page_locations().len())
+            .sum();
+        Ok(Self {
+            converter,
+            metadata,
+            row_group_indices,
+            num_pages,
+        })
+    }
+
+    /// Returns the page index if `column` is the column of these statistics
+    fn page_index(&self, column: &str) -> Option<(&'a ParquetColumnIndex, &'a ParquetOffsetIndex)> {
+        if self.converter.arrow_field().name() != column {
+            return None;
+        }
+        Some((self.metadata.column_index()?, self.metadata.offset_index()?))
+    }
+}
+
+impl PruningStatistics for PagePruningStatistics<'_> {
+    fn num_containers(&self) -> usize {
+        self.num_pages
+    }
+
+    fn min_values(&self, column: &str) -> Option<ArrayRef> {
+        let (column_index, offset_index) = self.page_index(column)?;
+        self.converter
+            .data_page_mins(column_index, offset_index, self.row_group_indices)
+            .ok()
+    }
+
+    fn max_values(&self, column: &str) -> Option<ArrayRef> {
+        let (column_index, offset_index) = self.page_index(column)?;
+        self.converter
+            .data_page_maxes(column_index, offset_index, self.row_group_indices)
+            .ok()
+    }
+
+    fn null_counts(&self, column: &str) -> Option<UInt64Array> {
+        let (column_index, offset_index) = self.page_index(column)?;
+        self.converter
+            .data_page_null_counts(column_index, offset_index, self.row_group_indices)
+            .ok()
+    }
+
+    fn row_counts(&self, column: &str) -> Option<UInt64Array> {
+        let (_, offset_index) = self.page_index(column)?;
+        let row_groups = self.metadata.row_groups();
+        self.converter
+            .data_page_row_counts(offset_index, row_groups, self.row_group_indices)
+            .ok()
+            .flatten()
+    }
+}
+
+/// [`PruningStatistics`] of row groups from the bloom filters of their columns
+///
+/// Only provides [`PruningStatistics::contained`], for columns with bloom filters added
+/// with [`Self::with_column`]. Values are checked with [`Sbbf::probe_array`], and so
+/// columns of types it does not support are unknown.
+#[derive(Debug, Clone, Default)]
+pub struct BloomFilterPruningStatistics {
+    num_containers: usize,
+    columns: HashMap<String, (DataType, Vec<Option<Sbbf>>)>,
+}
+
+impl BloomFilterPruningStatistics {
+    /// Create a new [`BloomFilterPruningStatistics`] for `num_containers` row groups
+    pub fn new(num_containers: usize) -> Self {
+        Self {
+            num_containers,
+            columns: HashMap::new(),
+        }
+    }
+
+    /// Add the bloom filters of `column`, of arrow type `data_type`, for each row group
+    ///
+    /// Returns an error if the number of `filters` differs from the number of row groups
+    pub fn with_column(
+        mut self,
+        column: impl Into<String>,
+        data_type: DataType,
+        filters: Vec<Option<Sbbf>>,
+    ) -> Result<Self> {
+        let column = column.into();
+        if filters.len() != self.num_containers {
+            return Err(general_err!(
+                "Expected {} bloom filters for column '{}', got {}",
+                self.num_containers,
+                column,
+                filters.len()
+            ));
+        }
+        self.columns.insert(column, (data_type, filters));
+        Ok(self)
+    }
+}
+
+impl PruningStatistics for BloomFilterPruningStatistics {
+    fn num_containers(&self) -> usize {
+        self.num_containers
+    }
+
+    fn contained(&self, column: &str, values: &dyn Array) -> Option<BooleanArray> {
+        let (data_type, filters) = self.columns.get(column)?;
+        let values = cast_lossless(values, data_type)?;
+        // Values of types not supported by the bloom filter are unknown
+        filters
+            .iter()
+            .map(|filter| match filter {
+                Some(filter) => Ok(Some(filter.probe_array(values.as_ref())?.true_count() > 0)),
+                None => Ok(None),
+            })
+            .collect::<Result<_>>()
+            .ok()
+    }
+}
+
+/// A predicate on the values of a byte array column, such as a string or binary column,
+/// that can be evaluated against the min and max values of its pages, see
+/// [`prune_byte_array_pages`]
+///
+/// Values are compared as unsigned bytes, which for UTF-8 strings is the same as comparing
+/// them by code point.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ByteArrayPredicate {
+    /// `column <op> value`
+    Compare(CompareOp, Vec<u8>),
+    /// `column` starts with the prefix, as in `column LIKE 'prefix%'`
+    StartsWith(Vec<u8>),
+}
+
+impl ByteArrayPredicate {
+    /// Create a [`ByteArrayPredicate`] from a SQL `LIKE` pattern, in which `%` matches any
+    /// number of characters, `_` matches any single character, and `\` escapes the
+    /// following character
+    ///
+    /// Only the literal prefix of the pattern can be used to prune, and so a pattern with
+    /// no wildcards becomes [`CompareOp::Eq`], and a pattern with a literal prefix becomes
+    /// [`Self::StartsWith`]. Returns `None` if the pattern starts with a wildcard.
+    pub fn like(pattern: &str) -> Option<Self> {
+        let mut prefix = String::with_capacity(pattern.len());
+        let mut chars = pattern.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '%' | '_' => {
+                    return (!prefix.is_empty()).then(|| Self::StartsWith(prefix.into_bytes()))
+                }
+                '\\' => prefix.push(chars.next().unwrap_or('\\')),
+                c => prefix.push(c),
+            }
+        }
+        Some(Self::Compare(CompareOp::Eq, prefix.into_bytes()))
+    }
+
+    /// Returns whether a page with the given `min` and `max` values from the page index may
+    /// contain values matching this predicate
+    ///
+    /// The page index may contain bounds rather than the actual values, such as when long
+    /// values are truncated:
+    ///
+    /// * `min` must be less than or equal to the actual minimum, for example a prefix of it
+    /// * `max` should be greater than or equal to the actual maximum, for example a prefix
+    ///   of it with the final byte incremented, as written by this crate. However, as some
+    ///   writers truncate the maximum without incrementing it, a non-empty `max` is also
+    ///   treated as possibly a prefix of the actual maximum
+    ///
+    /// As a result, [`CompareOp::NotEq`] always matches, as equal bounds do not guarantee
+    /// that all values of the page are equal.
+    pub fn may_match(&self, min: &[u8], max: &[u8]) -> bool {
+        // Whether the actual maximum may be a longer value starting with `max`
+        let below_max = |value: &[u8]| !max.is_empty() && value.starts_with(max);
+        match self {
+            Self::Compare(op, value) => {
+                let value = value.as_slice();
+                match op {
+                    CompareOp::Eq => min <= value && (max >= value || below_max(value)),
+                    CompareOp::NotEq => true,
+                    CompareOp::Lt => min < value,
+                    CompareOp::LtEq => min <= value,
+                    CompareOp::Gt => max > value || below_max(value),
+                    CompareOp::GtEq => max >= value || below_max(value),
+                }
+            }
+            Self::StartsWith(prefix) => {
+                let prefix = prefix.as_slice();
+                // Values starting with `prefix` are at least `prefix`, and any value greater
+                // than or equal to `min` starts with a prefix greater than or equal to that
+                // of `min`
+                let min_prefix = &min[..min.len().min(prefix.len())];
+                min_prefix <= prefix && (max >= prefix || below_max(prefix))
+            }
+        }
+    }
+}
+
+/// Evaluates `predicate` against the page index of the byte array column with index
+/// `column` in the parquet schema, returning the [`RowSelection`] of the pages of the row
+/// groups `row_group_indices` that may contain matching rows
+///
+/// The returned selection covers the rows of the row groups in the order given, and so
+/// can be passed to [`ArrowReaderBuilder::with_row_selection`] along with the same row
+/// groups. See [`ByteArrayPredicate::may_match`] for how pages are evaluated; as in SQL,
+/// pages containing only nulls never match. Row groups without a column index for the
+/// column are selected in full.
+///
+/// Returns an error if `metadata` does not contain the offset index, or if the column is
+/// not a byte array column ordered by unsigned byte comparison, as is the case for
+/// strings and binary but not decimals.
+///
+/// [`ArrowReaderBuilder::with_row_selection`]: crate::arrow::arrow_reader::ArrowReaderBuilder::with_row_selection
+pub fn prune_byte_array_pages(
+    metadata: &ParquetMetaData,
+    column: usize,
+    row_group_indices: &[usize],
+    predicate: &ByteArrayPredicate,
+) -> Result<RowSelection> {
+    let schema = metadata.file_metadata().schema_descr();
+    if column >= schema.num_columns() {
+        return Err(general_err!(
+            "Column index {} out of bounds for schema with {} columns",
+            column,
+            schema.num_columns()
+        ));
+    }
+    let descr = schema.column(column);
+    if !matches!(
+        descr.physical_type(),
+        PhysicalType::BYTE_ARRAY | PhysicalType::FIXED_LEN_BYTE_ARRAY
+    ) {
+        return Err(general_err!(
+            "Column '{}' is not a byte array column",
+            descr.path().string()
+        ));
+    }
+    let column_order = metadata.file_metadata().column_order(column);
+    if column_order != ColumnOrder::TYPE_DEFINED_ORDER(SortOrder::UNSIGNED) {
+        return Err(general_err!(
+            "Column '{}' is not ordered by unsigned byte comparison",
+            descr.path().string()
+        ));
+    }
+    let Some(offset_index) = metadata.offset_index() else {
+        return Err(general_err!("Page index not loaded"));
+    };
+
+    fn keep_pages<T: AsRef<[u8]>>(
+        pages: &[PageIndex<T>],
+        predicate: &ByteArrayPredicate,
+    ) -> Vec<bool> {
+        pages
+            .iter()
+            .map(|page| match (page.min(), page.max()) {
+                (Some(min), Some(max)) => predicate.may_match(min.as_ref(), max.as_ref()),
+                _ => false,
+            })
+            .collect()
+    }
+
+    let mut ranges = vec![];
+    let mut offset = 0;
+    for &row_group_idx in row_group_indices {
+        let num_rows = metadata.row_group(row_group_idx).num_rows() as usize;
+        let column_index = metadata.column_index().map(|c| &c[row_group_idx][column]);
+        let keep = match column_index {
+            Some(Index::BYTE_ARRAY(index)) => keep_pages(&index.indexes, predicate),
+            Some(Index::FIXED_LEN_BYTE_ARRAY(index)) => keep_pages(&index.indexes, predicate),
+            Some(Index::NONE) | None => {
+                ranges.push(offset..offset + num_rows);
+                offset += num_rows;
+                continue;
+            }
+            Some(_) => {
+                return Err(general_err!(
+                    "Column '{}' has a column index of unexpected type",
+                    descr.path().string()
+                ))
+            }
+        };
+
+        let locations = offset_index[row_group_idx][column].page_locations();
+        if locations.len() != keep.len() {
+            return Err(general_err!(
+                "Column index has {} pages but offset index has {} for row group {}",
+                keep.len(),
+                locations.len(),
+                row_group_idx
+            ));
+        }
+        for (idx, location) in locations.iter().enumerate() {
+            let start = location.first_row_index as usize;
+            let end = locations
+                .get(idx + 1)
+                .map(|next| next.first_row_index as usize)
+                .unwrap_or(num_rows);
+            if keep[idx] {
+                ranges.push(offset + start..offset + end);
+            }
+        }
+        offset += num_rows;
+    }
+    Ok(RowSelection::from_consecutive_ranges(
+        ranges.into_iter(),
+        offset,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrow::arrow_reader::{ArrowReaderOptions, ParquetRecordBatchReaderBuilder};
+    use crate::arrow::ArrowWriter;
+    use crate::file::properties::{ReaderProperties, WriterProperties};
+    use crate::file::reader::{FileReader, SerializedFileReader};
+    use crate::file::serialized_reader::ReadOptionsBuilder;
+    use arrow_array::{
+        Float16Array, Float64Array, Int32Array, Int64Array, RecordBatch, StringArray,
+    };
+    use bytes::Bytes;
+    use half::f16;
+    use std::sync::Arc;
+
+    /// Statistics of a single column `a` of Int32
+    struct TestStatistics {
+        mins: Int32Array,
+        maxes: Int32Array,
+        null_counts: UInt64Array,
+        row_counts: UInt64Array,
+    }
+
+    impl PruningStatistics for TestStatistics {
+        fn num_containers(&self) -> usize {
+            self.mins.len()
+        }
+
+        fn min_values(&self, column: &str) -> Option<ArrayRef> {
+            (column == "a").then(|| Arc::new(self.mins.clone()) as _)
+        }
+
+        fn max_values(&self, column: &str) -> Option<ArrayRef> {
+            (column == "a").then(|| Arc::new(self.maxes.clone()) as _)
+        }
+
+        fn null_counts(&self, column: &str) -> Option<UInt64Array> {
+            (column == "a").then(|| self.null_counts.clone())
+        }
+
+        fn row_counts(&self, column: &str) -> Option<UInt64Array> {
+            (column == "a").then(|| self.row_counts.clone())
+        }
+    }
+
+    fn keep(predicate: &PruningPredicate, statistics: &dyn PruningStatistics) -> Vec<bool> {
+        predicate.prune(statistics).unwrap().iter().collect()
+    }
+
+    #[test]
+    fn test_prune() {
+        // Containers [0, 10], [10, 10], unknown, [20, 30] with nulls, and all nulls
+        let statistics = TestStatistics {
+            mins: Int32Array::from(vec![Some(0), Some(10), None, Some(20), None]),
+            maxes: Int32Array::from(vec![Some(10), Some(10), None, Some(30), None]),
+            null_counts: UInt64Array::from(vec![Some(0), Some(0), None, Some(5), Some(10)]),
+            row_counts: UInt64Array::from(vec![10, 10, 10, 10, 10]),
+        };
+        let value = |v: i32| Arc::new(Int32Array::from(vec![v])) as ArrayRef;
+        let compare = |op, v| PruningPredicate::compare("a", op, value(v));
+
+        let cases = [
+            (compare(CompareOp::Eq, 10), [true, true, true, false, false]),
+            (
+                compare(CompareOp::Eq, 15),
+                [false, false, true, false, false],
+            ),
+            (
+                compare(CompareOp::NotEq, 10),
+                [true, false, true, true, false],
+            ),
+            (
+                compare(CompareOp::Lt, 10),
+                [true, false, true, false, false],
+            ),
+            (
+                compare(CompareOp::LtEq, 10),
+                [true, true, true, false, false],
+            ),
+            (
+                compare(CompareOp::Gt, 10),
+                [false, false, true, true, false],
+            ),
+            (
+                compare(CompareOp::GtEq, 30),
+                [false, false, true, true, false],
+            ),
+            (
+                PruningPredicate::in_list("a", Arc::new(Int32Array::from(vec![5, 25]))),
+                [true, false, true, true, false],
+            ),
+            (
+                PruningPredicate::IsNull("a".to_string()),
+                [false, false, true, true, true],
+            ),
+            (
+                PruningPredicate::IsNotNull("a".to_string()),
+                [true, true, true, true, false],
+            ),
+            (
+                compare(CompareOp::Gt, 5).and(compare(CompareOp::Lt, 10)),
+                [true, false, true, false, false],
+            ),
+            (
+                compare(CompareOp::Lt, 5).or(compare(CompareOp::Gt, 25)),
+                [true, false, true, true, false],
+            ),
+            // Unknown column
+            (
+                PruningPredicate::compare("b", CompareOp::Eq, value(1)),
+                [true, true, true, true, true],
+            ),
+            // Value that can't be compared with the statistics
+            (
+                PruningPredicate::compare(
+                    "a",
+                    CompareOp::Eq,
+                    Arc::new(StringArray::from(vec!["x"])),
+                ),
+                [true, true, true, true, false],
+            ),
+            // Values that can't be losslessly cast to the type of the statistics
+            (
+                PruningPredicate::compare(
+                    "a",
+                    CompareOp::Gt,
+                    Arc::new(Float64Array::from(vec![10.5])),
+                ),
+                [true, true, true, true, false],
+            ),
+            (
+                PruningPredicate::compare(
+                    "a",
+                    CompareOp::Eq,
+                    Arc::new(Int64Array::from(vec![i64::MAX])),
+                ),
+                [true, true, true, true, false],
+            ),
+            (
+                PruningPredicate::compare(
+                    "a",
+                    CompareOp::Gt,
+                    Arc::new(Float64Array::from(vec![10.0])),
+                ),
+                [false, false, true, true, false],
+            ),
+        ];
+        for (predicate, expected) in cases {
+            assert_eq!(keep(&predicate, &statistics), expected, "{predicate:?}");
+        }
+
+        let err =
+            PruningPredicate::compare("a", CompareOp::Eq, Arc::new(Int32Array::from(vec![1, 2])))
+                .prune(&statistics)
+                .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Pruning predicate value for column 'a' must have length 1, got 2"
+        );
+    }
+
+    #[test]
+    fn test_prune_parquet() {
+        let a = Arc::new(Int32Array::from_iter_values(0..300)) as ArrayRef;
+        let s = Arc::new(StringArray::from_iter_values(
+            (0..300).map(|i| format!("s{}", i % 100 + (i / 100) * 1000)),
+        )) as ArrayRef;
+        let batch = RecordBatch::try_from_iter([("a", a), ("s", s)]).unwrap();
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(100)
+            .set_data_page_row_count_limit(10)
+            .set_write_batch_size(10)
+            .set_bloom_filter_enabled(true)
+            .build();
+        let mut buffer = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buffer, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let buffer = Bytes::from(buffer);
+
+        let options = ArrowReaderOptions::new().with_page_index(true);
+        let builder =
+            ParquetRecordBatchReaderBuilder::try_new_with_options(buffer.clone(), options).unwrap();
+        let metadata = builder.metadata();
+        let schema = builder.schema();
+        let parquet_schema = metadata.file_metadata().schema_descr();
+
+        let a_between = |lo: i32, hi: i32| {
+            PruningPredicate::compare("a", CompareOp::GtEq, Arc::new(Int32Array::from(vec![lo])))
+                .and(PruningPredicate::compare(
+                    "a",
+                    CompareOp::Lt,
+                    Arc::new(Int32Array::from(vec![hi])),
+                ))
+        };
+
+        // Row groups
+        let row_groups =
+            RowGroupPruningStatistics::new(schema, parquet_schema, metadata.row_groups());
+        assert_eq!(row_groups.num_containers(), 3);
+        assert_eq!(keep(&a_between(150, 250), &row_groups), [false, true, true]);
+
+        // Pages of the second and third row groups
+        let indices = [1, 2];
+        let pages = PagePruningStatistics::try_new("a", schema, metadata, &indices).unwrap();
+        assert_eq!(pages.num_containers(), 20);
+        let expected: Vec<_> = (0..20).map(|i| (5..10).contains(&i)).collect();
+        assert_eq!(keep(&a_between(150, 200), &pages), expected);
+        // Other columns are unknown
+        let eq_s =
+            PruningPredicate::compare("s", CompareOp::Eq, Arc::new(StringArray::from(vec!["s1"])));
+        assert!(keep(&eq_s, &pages).iter().all(|k| *k));
+
+        // Bloom filters, for a value within the min and max of the first two row groups
+        let eq_s = PruningPredicate::compare(
+            "s",
+            CompareOp::Eq,
+            Arc::new(StringArray::from(vec!["s1050"])),
+        );
+        assert_eq!(keep(&eq_s, &row_groups), [true, true, false]);
+
+        let reader_properties = ReaderProperties::builder()
+            .set_read_bloom_filter(true)
+            .build();
+        let options = ReadOptionsBuilder::new()
+            .with_reader_properties(reader_properties)
+            .build();
+        let reader = SerializedFileReader::new_with_options(buffer, options).unwrap();
+        let filters = (0..3)
+            .map(|i| {
+                let row_group = reader.get_row_group(i).unwrap();
+                row_group.get_column_bloom_filter(1).cloned()
+            })
+            .collect();
+        let bloom_filters = BloomFilterPruningStatistics::new(3)
+            .with_column("s", DataType::Utf8, filters)
+            .unwrap();
+        assert_eq!(keep(&eq_s, &bloom_filters), [false, true, false]);
+        assert_eq!(
+            keep(&a_between(150, 250), &bloom_filters),
+            [true, true, true]
+        );
+
+        let err = BloomFilterPruningStatistics::new(2)
+            .with_column("s", DataType::Utf8, vec![None])
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Expected 2 bloom filters for column 's', got 1"
+        );
+    }
+
+    #[test]
+    fn test_prune_float16() {
+        let values = (0..300).map(|i| f16::from_f32(i as f32 - 100.));
+        let a = Arc::new(Float16Array::from_iter_values(values)) as ArrayRef;
+        let batch = RecordBatch::try_from_iter([("a", a)]).unwrap();
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(100)
+            .set_data_page_row_count_limit(10)
+            .set_write_batch_size(10)
+            .set_bloom_filter_enabled(true)
+            .build();
+        let mut buffer = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buffer, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let buffer = Bytes::from(buffer);
+
+        let options = ArrowReaderOptions::new().with_page_index(true);
+        let builder =
+            ParquetRecordBatchReaderBuilder::try_new_with_options(buffer.clone(), options).unwrap();
+        let metadata = builder.metadata();
+        let schema = builder.schema();
+        let parquet_schema = metadata.file_metadata().schema_descr();
+        let value = |v: f32| Arc::new(Float16Array::from(vec![f16::from_f32(v)])) as ArrayRef;
+
+        // Row groups [-100, -1], [0, 99] and [100, 199], compared numerically rather
+        // than by their little endian bytes
+        let row_groups =
+            RowGroupPruningStatistics::new(schema, parquet_schema, metadata.row_groups());
+        let lt = PruningPredicate::compare("a", CompareOp::Lt, value(-50.));
+        assert_eq!(keep(&lt, &row_groups), [true, false, false]);
+        let gt_eq = PruningPredicate::compare("a", CompareOp::GtEq, value(99.));
+        assert_eq!(keep(&gt_eq, &row_groups), [false, true, true]);
+
+        let pages = PagePruningStatistics::try_new("a", schema, metadata, &[0]).unwrap();
+        let expected: Vec<_> = (0..10).map(|i| i < 5).collect();
+        assert_eq!(keep(&lt, &pages), expected);
+
+        let reader_properties = ReaderProperties::builder()
+            .set_read_bloom_filter(true)
+            .build();
+        let options = ReadOptionsBuilder::new()
+            .with_reader_properties(reader_properties)
+            .build();
+        let reader = SerializedFileReader::new_with_options(buffer, options).unwrap();
+        let filters = (0..3)
+            .map(|i| {
+                let row_group = reader.get_row_group(i).unwrap();
+                row_group.get_column_bloom_filter(0).cloned()
+            })
+            .collect();
+        let bloom_filters = BloomFilterPruningStatistics::new(3)
+            .with_column("a", DataType::Float16, filters)
+            .unwrap();
+        let eq = PruningPredicate::compare("a", CompareOp::Eq, value(150.));
+        assert_eq!(keep(&eq, &bloom_filters), [false, false, true]);
+    }
+
+    #[test]
+    fn test_byte_array_predicate() {
+        let like = |p: &str| ByteArrayPredicate::like(p);
+        let starts_with = |p: &str| ByteArrayPredicate::StartsWith(p.as_bytes().to_vec());
+        let compare = |op, v: &str| ByteArrayPredicate::Compare(op, v.as_bytes().to_vec());
+
+        assert_eq!(like("abc"), Some(compare(CompareOp::Eq, "abc")));
+        assert_eq!(like("abc%"), Some(starts_with("abc")));
+        assert_eq!(like("ab_d%"), Some(starts_with("ab")));
+        assert_eq!(like(r"a\%b%"), Some(starts_with("a%b")));
+        assert_eq!(like("%abc"), None);
+
+        // Page of values "apple".."banana", with max incremented after truncation
+        let (min, max) = (b"app".as_slice(), b"bao".as_slice());
+        let cases = [
+            (compare(CompareOp::Eq, "apple"), true),
+            (compare(CompareOp::Eq, "ap"), false),
+            (compare(CompareOp::Eq, "bao"), true),
+            (compare(CompareOp::Eq, "bb"), false),
+            (compare(CompareOp::NotEq, "apple"), true),
+            (compare(CompareOp::Lt, "app"), false),
+            (compare(CompareOp::LtEq, "app"), true),
+            (compare(CompareOp::Gt, "bao"), true),
+            (compare(CompareOp::Gt, "bap"), false),
+            (compare(CompareOp::GtEq, "bao"), true),
+            (starts_with("a"), true),
+            (starts_with("appz"), true),
+            (starts_with("apa"), false),
+            (starts_with("ba"), true),
+            (starts_with("bap"), false),
+            (starts_with("c"), false),
+        ];
+        for (predicate, expected) in cases {
+            assert_eq!(predicate.may_match(min, max), expected, "{predicate:?}");
+        }
+
+        // Max truncated without being incremented may be a prefix of the actual maximum
+        let max = b"ban".as_slice();
+        assert!(compare(CompareOp::Eq, "banana").may_match(min, max));
+        assert!(compare(CompareOp::Gt, "ban").may_match(min, max));
+        assert!(starts_with("bana").may_match(min, max));
+        assert!(!starts_with("bb").may_match(min, max));
+        // But an empty max is exact
+        assert!(!compare(CompareOp::Gt, "").may_match(b"", b""));
+    }
+
+    #[test]
+    fn test_prune_byte_array_pages() {
+        let values: Vec<_> = (0..300)
+            .map(|i| format!("{:03}_{}", i, "x".repeat(20)))
+            .collect();
+        let a = Arc::new(Int32Array::from_iter_values(0..300)) as ArrayRef;
+        let s = Arc::new(StringArray::from_iter_values(&values)) as ArrayRef;
+        let batch = RecordBatch::try_from_iter([("a", a), ("s", s)]).unwrap();
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(100)
+            .set_data_page_row_count_limit(10)
+            .set_write_batch_size(10)
+            .set_column_index_truncate_length(Some(5))
+            .build();
+        let mut buffer = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buffer, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let options = ArrowReaderOptions::new().with_page_index(true);
+        let builder =
+            ParquetRecordBatchReaderBuilder::try_new_with_options(Bytes::from(buffer), options)
+                .unwrap();
+        let metadata = builder.metadata().clone();
+
+        // Returns the rows selected, checking every matching row is selected
+        let select = |predicate: &ByteArrayPredicate, matches: &dyn Fn(&str) -> bool| {
+            let row_groups = [0, 2];
+            let selection = prune_byte_array_pages(&metadata, 1, &row_groups, predicate).unwrap();
+            assert_eq!(selection.row_count() + selection.skipped_row_count(), 200);
+            let mut selected = vec![];
+            let mut row = 0;
+            for selector in selection.iter() {
+                if !selector.skip {
+                    selected.extend(row..row + selector.row_count);
+                }
+                row += selector.row_count;
+            }
+            let rows = (0..100).chain(200..300).collect::<Vec<_>>();
+            for (idx, value_idx) in rows.iter().enumerate() {
+                if matches(&values[*value_idx]) {
+                    assert!(
+                        selected.contains(&idx),
+                        "{predicate:?} {}",
+                        values[*value_idx]
+                    );
+                }
+            }
+            selected
+        };
+
+        let prefix = ByteArrayPredicate::like("21%").unwrap();
+        let selected = select(&prefix, &|v| v.starts_with("21"));
+        assert_eq!(selected, (110..120).collect::<Vec<_>>());
+
+        let eq = ByteArrayPredicate::Compare(CompareOp::Eq, values[55].as_bytes().to_vec());
+        let selected = select(&eq, &|v| v == values[55]);
+        assert_eq!(selected, (50..60).collect::<Vec<_>>());
+
+        let gt = ByteArrayPredicate::Compare(CompareOp::Gt, values[285].as_bytes().to_vec());
+        let selected = select(&gt, &|v| v > values[285].as_str());
+        assert_eq!(selected, (180..200).collect::<Vec<_>>());
+
+        let lt = ByteArrayPredicate::Compare(CompareOp::Lt, b"005".to_vec());
+        let selected = select(&lt, &|v| v < "005");
+        assert_eq!(selected, (0..10).collect::<Vec<_>>());
+
+        let err = prune_byte_array_pages(&metadata, 0, &[0], &prefix).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Column 'a' is not a byte array column"
+        );
+    }
+}
@@ -23,7 +23,7 @@ use crate::arrow::arrow_reader::{
     ArrowPredicate, ParquetRecordBatchReader, RowSelection, RowSelector,
 };
 use crate::errors::{ParquetError, Result};
-use arrow_array::Array;
+use arrow_array::{Array, RecordBatch};
 use arrow_select::filter::prep_null_mask_filter;
 use std::collections::VecDeque;
 
@@ -76,7 +76,6 @@ impl ReadPlanBuilder {
     }
 
     /// Returns the number of rows selected, or `None` if all rows are selected.
-    #[cfg(feature = "async")]
     pub(crate) fn num_rows_selected(&self) -> Option<usize> {
         self.selection.as_ref().map(|s| s.row_count())
     }
@@ -90,16 +89,31 @@ impl ReadPlanBuilder {
     /// Note: pre-existing selections may come from evaluating a previous predicate
     /// or if the [`ParquetRecordBatchReader`] specified an explicit
     /// [`RowSelection`] in addition to one or more predicates.
+    ///
+    /// If `limit` is provided, evaluation stops once `limit` rows have been selected,
+    /// with the remaining rows neither decoded nor selected. This must therefore only
+    /// be used for the final predicate of a scan.
+    ///
+    /// If `decoded` is provided, the batches passed to `predicate` are appended to it
     pub(crate) fn with_predicate(
         mut self,
         array_reader: Box<dyn ArrayReader>,
         predicate: &mut dyn ArrowPredicate,
+        limit: Option<usize>,
+        mut decoded: Option<&mut Vec<RecordBatch>>,
     ) -> Result<Self> {
         let reader = ParquetRecordBatchReader::new(array_reader, self.clone().build());
         let mut filters = vec![];
+        let mut selected = 0;
         for maybe_batch in reader {
+            if limit.is_some_and(|limit| selected >= limit) {
+                break;
+            }
             let maybe_batch = maybe_batch?;
             let input_rows = maybe_batch.num_rows();
+            if let Some(decoded) = decoded.as_mut() {
+                decoded.push(maybe_batch.clone());
+            }
             let filter = predicate.evaluate(maybe_batch)?;
             // Since user supplied predicate, check error here to catch bugs quickly
             if filter.len() != input_rows {
@@ -108,13 +122,26 @@ impl ReadPlanBuilder {
                     filter.len()
                 ));
             }
-            match filter.null_count() {
-                0 => filters.push(filter),
-                _ => filters.push(prep_null_mask_filter(&filter)),
+            let filter = match filter.null_count() {
+                0 => filter,
+                _ => prep_null_mask_filter(&filter),
             };
+            selected += filter.true_count();
+            filters.push(filter);
         }
 
-        let raw = RowSelection::from_filters(&filters);
+        let mut raw = RowSelection::from_filters(&filters);
+        if let Some(selection) = &self.selection {
+            // Skip any rows not evaluated as the limit was reached
+            let remaining = selection.row_count() - raw.row_count() - raw.skipped_row_count();
+            if remaining != 0 {
+                raw = raw
+                    .iter()
+                    .copied()
+                    .chain(std::iter::once(RowSelector::skip(remaining)))
+                    .collect();
+            }
+        }
         self.selection = match self.selection.take() {
             Some(selection) => Some(selection.and_then(&raw)),
             None => Some(raw),
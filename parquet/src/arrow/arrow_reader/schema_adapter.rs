@@ -0,0 +1,175 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`SchemaAdapter`] for reading parquet files into a target Arrow schema
+
+use crate::arrow::ProjectionMask;
+use crate::errors::{ParquetError, Result};
+use crate::schema::types::SchemaDescriptor;
+use arrow_array::{new_null_array, ArrayRef, RecordBatch, RecordBatchOptions};
+use arrow_cast::{can_cast_types, cast_with_options, CastOptions};
+use arrow_schema::{ArrowError, Schema, SchemaRef};
+use std::sync::Arc;
+
+/// Converts the [`RecordBatch`]es decoded from a parquet file into a target schema
+///
+/// Columns are matched by name, columns of the target schema missing from the
+/// file are filled with nulls, and columns with a different type are cast.
+///
+/// See [`ArrowReaderOptions::with_target_schema`](super::ArrowReaderOptions::with_target_schema)
+#[derive(Debug)]
+pub(crate) struct SchemaAdapter {
+    target: SchemaRef,
+    /// The indices of the root columns of the file present in `target`
+    file_roots: Vec<usize>,
+}
+
+impl SchemaAdapter {
+    /// Create a new [`SchemaAdapter`] reading a file with arrow schema `file_schema`
+    /// into `target`, returning an error if they are not compatible
+    pub(crate) fn try_new(target: SchemaRef, file_schema: &Schema) -> Result<Self> {
+        let mut file_roots = Vec::new();
+        let mut errors = Vec::new();
+        for field in target.fields() {
+            match file_schema.column_with_name(field.name()) {
+                Some((idx, file_field)) => {
+                    if !can_cast_types(file_field.data_type(), field.data_type()) {
+                        errors.push(format!(
+                            "cannot cast field {} from {} to {}",
+                            field.name(),
+                            file_field.data_type(),
+                            field.data_type()
+                        ));
+                    }
+                    file_roots.push(idx);
+                }
+                None if !field.is_nullable() => errors.push(format!(
+                    "non-nullable field {} not found in file",
+                    field.name()
+                )),
+                None => {}
+            }
+        }
+
+        if !errors.is_empty() {
+            let message = errors.join(", ");
+            return Err(ParquetError::ArrowError(format!(
+                "Incompatible target Arrow schema: {message}",
+            )));
+        }
+
+        file_roots.sort_unstable();
+        Ok(Self { target, file_roots })
+    }
+
+    /// Returns the target schema
+    pub(crate) fn schema(&self) -> &SchemaRef {
+        &self.target
+    }
+
+    /// Returns a [`ProjectionMask`] of the root columns of the file needed to
+    /// produce the target schema
+    pub(crate) fn projection(&self, schema: &SchemaDescriptor) -> ProjectionMask {
+        ProjectionMask::roots(schema, self.file_roots.iter().copied())
+    }
+
+    /// Converts `batch`, decoded from the file, into the target schema
+    pub(crate) fn adapt(&self, batch: RecordBatch) -> Result<RecordBatch, ArrowError> {
+        let options = CastOptions::default().with_safe(false);
+        let columns = self
+            .target
+            .fields()
+            .iter()
+            .map(|field| match batch.column_by_name(field.name()) {
+                Some(column) if column.data_type() == field.data_type() => Ok(Arc::clone(column)),
+                Some(column) => cast_with_options(column, field.data_type(), &options),
+                None => Ok(new_null_array(field.data_type(), batch.num_rows())),
+            })
+            .collect::<Result<Vec<ArrayRef>, _>>()?;
+
+        let options = RecordBatchOptions::new().with_row_count(Some(batch.num_rows()));
+        RecordBatch::try_new_with_options(Arc::clone(&self.target), columns, &options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::{Int64Type, TimestampMillisecondType};
+    use arrow_array::{Int32Array, StringArray, TimestampMillisecondArray};
+    use arrow_schema::{DataType, Field, TimeUnit};
+
+    #[test]
+    fn test_schema_adapter() {
+        let batch = RecordBatch::try_from_iter([
+            ("a", Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef),
+            ("b", Arc::new(StringArray::from(vec!["x", "y"])) as ArrayRef),
+            (
+                "c",
+                Arc::new(TimestampMillisecondArray::from(vec![0, 1000])) as ArrayRef,
+            ),
+        ])
+        .unwrap();
+
+        let ts = DataType::Timestamp(TimeUnit::Millisecond, Some("+00:00".into()));
+        let target = Arc::new(Schema::new(vec![
+            Field::new("d", DataType::Float64, true),
+            Field::new("c", ts, true),
+            Field::new("b", DataType::LargeUtf8, true),
+            Field::new("a", DataType::Int64, false),
+        ]));
+
+        let adapter = SchemaAdapter::try_new(target.clone(), &batch.schema()).unwrap();
+        assert_eq!(adapter.file_roots, [0, 1, 2]);
+
+        let adapted = adapter.adapt(batch).unwrap();
+        assert_eq!(adapted.schema(), target);
+        assert_eq!(adapted.column(0).null_count(), 2);
+        let c = adapted.column(1).as_primitive::<TimestampMillisecondType>();
+        assert_eq!(c.values(), &[0, 1000]);
+        let b = adapted.column(2).as_string::<i64>();
+        assert_eq!(b.value(1), "y");
+        let a = adapted.column(3).as_primitive::<Int64Type>();
+        assert_eq!(a.values(), &[1, 2]);
+
+        // Projected out columns of the file are filled with nulls
+        let projected =
+            RecordBatch::try_from_iter([("a", Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef)])
+                .unwrap();
+        let adapted = adapter.adapt(projected).unwrap();
+        assert_eq!(adapted.column(2).null_count(), 2);
+    }
+
+    #[test]
+    fn test_schema_adapter_incompatible() {
+        let file_schema = Schema::new(vec![Field::new(
+            "a",
+            DataType::List(Arc::new(Field::new_list_field(DataType::Int32, true))),
+            true,
+        )]);
+        let target = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Int32, false),
+        ]));
+        let err = SchemaAdapter::try_new(target, &file_schema)
+            .unwrap_err()
+            .to_string();
+        assert!(err.starts_with("Arrow: Incompatible target Arrow schema: cannot cast field a"));
+        assert!(err.ends_with("to Int32, non-nullable field b not found in file"));
+    }
+}
@@ -155,6 +155,76 @@ impl RowSelection {
         Self { selectors }
     }
 
+    /// Creates a [`RowSelection`] selecting the rows with the provided `row_ids`, out of
+    /// `total_rows` rows
+    ///
+    /// This is useful for reading the rows identified by an external source, such as a
+    /// secondary index. Duplicate row ids are ignored.
+    ///
+    /// To create a selection of the rows of a whole file, and read only the row groups
+    /// containing them, see [`ArrowReaderBuilder::with_file_row_selection`].
+    ///
+    /// ```
+    /// # use parquet::arrow::arrow_reader::{RowSelection, RowSelector};
+    /// let selection = RowSelection::from_row_ids([1, 2, 3, 3, 7], 10);
+    /// let expected = vec![
+    ///     RowSelector::skip(1),
+    ///     RowSelector::select(3),
+    ///     RowSelector::skip(3),
+    ///     RowSelector::select(1),
+    ///     RowSelector::skip(2),
+    /// ];
+    /// assert_eq!(selection, RowSelection::from(expected));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row_ids` are not sorted in ascending order, or any is not less than
+    /// `total_rows`
+    ///
+    /// [`ArrowReaderBuilder::with_file_row_selection`]: super::ArrowReaderBuilder::with_file_row_selection
+    pub fn from_row_ids<I: IntoIterator<Item = usize>>(row_ids: I, total_rows: usize) -> Self {
+        let mut ranges: Vec<Range<usize>> = Vec::new();
+        for row_id in row_ids {
+            assert!(
+                row_id < total_rows,
+                "row id {row_id} out of bounds for {total_rows} rows"
+            );
+            match ranges.last_mut() {
+                Some(last) if row_id + 1 < last.end => panic!("row ids must be sorted"),
+                Some(last) if row_id <= last.end => last.end = row_id + 1,
+                _ => ranges.push(row_id..row_id + 1),
+            }
+        }
+        Self::from_consecutive_ranges(ranges.into_iter(), total_rows)
+    }
+
+    /// Creates a [`RowSelection`] selecting all but the rows with the provided `row_ids`,
+    /// out of `total_rows` rows
+    ///
+    /// This is useful for skipping the rows identified by an external source, such as a
+    /// deletion vector. Duplicate row ids are ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row_ids` are not sorted in ascending order, or any is not less than
+    /// `total_rows`
+    pub fn from_excluded_row_ids<I: IntoIterator<Item = usize>>(
+        row_ids: I,
+        total_rows: usize,
+    ) -> Self {
+        let selection = Self::from_row_ids(row_ids, total_rows);
+        let selectors = selection
+            .selectors
+            .into_iter()
+            .map(|selector| RowSelector {
+                row_count: selector.row_count,
+                skip: !selector.skip,
+            })
+            .collect();
+        Self { selectors }
+    }
+
     /// Given an offset index, return the byte ranges for all data pages selected by `self`
     ///
     /// This is useful for determining what byte ranges to fetch from underlying storage
@@ -1266,6 +1336,43 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_from_row_ids() {
+        let selection = RowSelection::from_row_ids([0, 1, 1, 2, 5, 9], 10);
+        assert_eq!(
+            selection.selectors,
+            vec![
+                RowSelector::select(3),
+                RowSelector::skip(2),
+                RowSelector::select(1),
+                RowSelector::skip(3),
+                RowSelector::select(1)
+            ]
+        );
+
+        let selection = RowSelection::from_excluded_row_ids([0, 1, 1, 2, 5, 9], 10);
+        assert_eq!(
+            selection.selectors,
+            vec![
+                RowSelector::skip(3),
+                RowSelector::select(2),
+                RowSelector::skip(1),
+                RowSelector::select(3),
+                RowSelector::skip(1)
+            ]
+        );
+
+        let selection = RowSelection::from_row_ids([], 5);
+        assert_eq!(selection.selectors, vec![RowSelector::skip(5)]);
+        let selection = RowSelection::from_excluded_row_ids([], 5);
+        assert_eq!(selection.selectors, vec![RowSelector::select(5)]);
+
+        let result = std::panic::catch_unwind(|| RowSelection::from_row_ids([1, 5, 3], 10));
+        assert!(result.is_err());
+        let result = std::panic::catch_unwind(|| RowSelection::from_row_ids([1, 10], 10));
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_empty_selector() {
         let selection = RowSelection::from(vec![
@@ -23,6 +23,7 @@ use crate::encodings::encoding::{DeltaBitPackEncoder, Encoder};
 use crate::encodings::rle::RleEncoder;
 use crate::errors::{ParquetError, Result};
 use crate::file::properties::{EnabledStatistics, WriterProperties, WriterVersion};
+use crate::geospatial::{GeospatialAccumulator, GeospatialStatistics};
 use crate::schema::types::ColumnDescPtr;
 use crate::util::bit_util::num_required_bits;
 use crate::util::interner::{Interner, Storage};
@@ -372,6 +373,10 @@ impl DictEncoder {
         self.interner.storage().page.len()
     }
 
+    fn num_entries(&self) -> usize {
+        self.interner.storage().values.len()
+    }
+
     fn flush_dict_page(self) -> DictionaryPage {
         let storage = self.interner.into_inner();
 
@@ -421,13 +426,23 @@ pub struct ByteArrayEncoder {
     min_value: Option<ByteArray>,
     max_value: Option<ByteArray>,
     bloom_filter: Option<Sbbf>,
+    bloom_filter_adaptive_fpp: Option<f64>,
+    geo_stats: Option<GeospatialAccumulator>,
 }
 
 impl ColumnValueEncoder for ByteArrayEncoder {
     type T = ByteArray;
     type Values = dyn Array;
     fn flush_bloom_filter(&mut self) -> Option<Sbbf> {
-        self.bloom_filter.take()
+        let mut bloom_filter = self.bloom_filter.take()?;
+        if let Some(fpp) = self.bloom_filter_adaptive_fpp {
+            bloom_filter.fold_to_fpp(fpp);
+        }
+        Some(bloom_filter)
+    }
+
+    fn flush_geospatial_statistics(&mut self) -> Option<Box<GeospatialStatistics>> {
+        self.geo_stats.as_mut()?.finish()
     }
 
     fn try_new(descr: &ColumnDescPtr, props: &WriterProperties) -> Result<Self>
@@ -444,16 +459,25 @@ impl ColumnValueEncoder for ByteArrayEncoder {
             .bloom_filter_properties(descr.path())
             .map(|props| Sbbf::new_with_ndv_fpp(props.ndv, props.fpp))
             .transpose()?;
+        let bloom_filter_adaptive_fpp = props
+            .bloom_filter_properties(descr.path())
+            .filter(|_| props.bloom_filter_adaptive_ndv(descr.path()))
+            .map(|props| props.fpp);
 
         let statistics_enabled = props.statistics_enabled(descr.path());
+        let geo_stats = (statistics_enabled != EnabledStatistics::None)
+            .then(|| GeospatialAccumulator::try_new(descr))
+            .flatten();
 
         Ok(Self {
             fallback,
             statistics_enabled,
             bloom_filter,
+            bloom_filter_adaptive_fpp,
             dict_encoder: dictionary,
             min_value: None,
             max_value: None,
+            geo_stats,
         })
     }
 
@@ -501,6 +525,10 @@ impl ColumnValueEncoder for ByteArrayEncoder {
         Some(self.dict_encoder.as_ref()?.estimated_dict_page_size())
     }
 
+    fn num_dict_entries(&self) -> Option<usize> {
+        Some(self.dict_encoder.as_ref()?.num_entries())
+    }
+
     /// Returns an estimate of the data page size in bytes
     ///
     /// This includes:
@@ -546,7 +574,12 @@ where
     T: ArrayAccessor + Copy,
     T::Item: Copy + Ord + AsRef<[u8]>,
 {
-    if encoder.statistics_enabled != EnabledStatistics::None {
+    if let Some(geo_stats) = &mut encoder.geo_stats {
+        // Geospatial columns record geospatial statistics instead of min/max
+        for idx in indices {
+            geo_stats.update(values.value(*idx).as_ref());
+        }
+    } else if encoder.statistics_enabled != EnabledStatistics::None {
         if let Some((min, max)) = compute_min_max(values, indices.iter().cloned()) {
             if encoder.min_value.as_ref().is_none_or(|m| m > &min) {
                 encoder.min_value = Some(min);
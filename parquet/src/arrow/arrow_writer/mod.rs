@@ -18,6 +18,7 @@
 //! Contains writer which writes arrow data into parquet data.
 
 use bytes::Bytes;
+use std::fs::File;
 use std::io::{Read, Write};
 use std::iter::Peekable;
 use std::slice::Iter;
@@ -33,7 +34,7 @@ use arrow_schema::{ArrowError, DataType as ArrowDataType, Field, IntervalUnit, S
 use super::schema::{add_encoded_arrow_schema_to_metadata, decimal_length_from_precision};
 
 use crate::arrow::arrow_writer::byte_array::ByteArrayEncoder;
-use crate::arrow::ArrowSchemaConverter;
+use crate::arrow::{map_parallel, ArrowSchemaConverter};
 use crate::column::page::{CompressedPage, PageWriteSpec, PageWriter};
 use crate::column::page_encryption::PageEncryptor;
 use crate::column::writer::encoder::ColumnValueEncoder;
@@ -48,12 +49,17 @@ use crate::file::metadata::{KeyValue, RowGroupMetaData};
 use crate::file::properties::{WriterProperties, WriterPropertiesPtr};
 use crate::file::reader::{ChunkReader, Length};
 use crate::file::writer::{SerializedFileWriter, SerializedRowGroupWriter};
-use crate::schema::types::{ColumnDescPtr, SchemaDescriptor};
+use crate::schema::types::{ColumnDescPtr, SchemaDescriptor, TypePtr};
 use crate::thrift::TSerializable;
 use levels::{calculate_array_levels, ArrayLevels};
+use sorting::SortingColumnsValidator;
 
 mod byte_array;
 mod levels;
+mod sorting;
+mod write_statistics;
+
+pub use write_statistics::ColumnWriteStatistics;
 
 /// Encodes [`RecordBatch`] to parquet
 ///
@@ -145,6 +151,15 @@ pub struct ArrowWriter<W: Write> {
 
     /// The length of arrays to write to each row group
     max_row_group_size: usize,
+
+    /// The target encoded size of each row group, if any
+    max_row_group_bytes: Option<usize>,
+
+    /// The number of threads used to encode columns
+    parallelism: usize,
+
+    /// Validates the declared sorting columns, if enabled
+    sorting_validator: Option<SortingColumnsValidator>,
 }
 
 impl<W: Write + Send> std::fmt::Debug for ArrowWriter<W> {
@@ -156,6 +171,8 @@ impl<W: Write + Send> std::fmt::Debug for ArrowWriter<W> {
             .field("in_progress_rows", &self.in_progress_rows())
             .field("arrow_schema", &self.arrow_schema)
             .field("max_row_group_size", &self.max_row_group_size)
+            .field("max_row_group_bytes", &self.max_row_group_bytes)
+            .field("parallelism", &self.parallelism)
             .finish()
     }
 }
@@ -184,6 +201,17 @@ impl<W: Write + Send> ArrowWriter<W> {
         writer: W,
         arrow_schema: SchemaRef,
         options: ArrowWriterOptions,
+    ) -> Result<Self> {
+        Self::try_new_impl(arrow_schema, options, |schema, props| {
+            SerializedFileWriter::new(writer, schema, props)
+        })
+    }
+
+    /// Creates a new Arrow writer using `create` to construct the [`SerializedFileWriter`]
+    fn try_new_impl(
+        arrow_schema: SchemaRef,
+        options: ArrowWriterOptions,
+        create: impl FnOnce(TypePtr, WriterPropertiesPtr) -> Result<SerializedFileWriter<W>>,
     ) -> Result<Self> {
         let mut props = options.properties;
         let mut converter = ArrowSchemaConverter::new().with_coerce_types(props.coerce_types());
@@ -197,9 +225,16 @@ impl<W: Write + Send> ArrowWriter<W> {
         }
 
         let max_row_group_size = props.max_row_group_size();
+        let max_row_group_bytes = props.max_row_group_bytes();
 
-        let file_writer =
-            SerializedFileWriter::new(writer, schema.root_schema_ptr(), Arc::new(props))?;
+        let sorting_validator = match props.sorting_columns() {
+            Some(sorting_columns) if props.validate_sorting_columns() => {
+                Some(SortingColumnsValidator::try_new(sorting_columns, &schema)?)
+            }
+            _ => None,
+        };
+
+        let file_writer = create(schema.root_schema_ptr(), Arc::new(props))?;
 
         let row_group_writer_factory = ArrowRowGroupWriterFactory::new(&file_writer);
 
@@ -209,6 +244,9 @@ impl<W: Write + Send> ArrowWriter<W> {
             arrow_schema,
             row_group_writer_factory,
             max_row_group_size,
+            max_row_group_bytes,
+            parallelism: options.parallelism,
+            sorting_validator,
         })
     }
 
@@ -217,6 +255,15 @@ impl<W: Write + Send> ArrowWriter<W> {
         self.writer.flushed_row_groups()
     }
 
+    /// Returns the [`ColumnWriteStatistics`] of each leaf column, aggregated
+    /// across any flushed row groups
+    ///
+    /// Data buffered in the in progress row group is not included, see
+    /// [`Self::close_with_statistics`] to obtain the statistics of the complete file
+    pub fn column_write_statistics(&self) -> Vec<ColumnWriteStatistics> {
+        ColumnWriteStatistics::from_row_groups(self.flushed_row_groups())
+    }
+
     /// Estimated memory usage, in bytes, of this `ArrowWriter`
     ///
     /// This estimate is formed bu summing the values of
@@ -272,26 +319,40 @@ impl<W: Write + Send> ArrowWriter<W> {
 
         let in_progress = match &mut self.in_progress {
             Some(in_progress) => in_progress,
-            x => x.insert(self.row_group_writer_factory.create_row_group_writer(
-                self.writer.schema_descr(),
-                self.writer.properties(),
-                &self.arrow_schema,
-                self.writer.flushed_row_groups().len(),
-            )?),
+            x => x.insert(
+                self.row_group_writer_factory
+                    .create_row_group_writer(
+                        self.writer.schema_descr(),
+                        self.writer.properties(),
+                        &self.arrow_schema,
+                        self.writer.flushed_row_groups().len(),
+                    )?
+                    .with_parallelism(self.parallelism),
+            ),
         };
 
-        // If would exceed max_row_group_size, split batch
-        if in_progress.buffered_rows + batch.num_rows() > self.max_row_group_size {
-            let to_write = self.max_row_group_size - in_progress.buffered_rows;
+        // If would exceed max_row_group_size or max_row_group_bytes, split batch
+        let to_write = in_progress.remaining_rows(
+            self.max_row_group_size,
+            self.max_row_group_bytes,
+            self.writer.properties().write_batch_size(),
+        );
+        if batch.num_rows() > to_write {
             let a = batch.slice(0, to_write);
             let b = batch.slice(to_write, batch.num_rows() - to_write);
             self.write(&a)?;
             return self.write(&b);
         }
 
+        if let Some(validator) = &mut self.sorting_validator {
+            validator.validate(batch)?;
+        }
         in_progress.write(batch)?;
 
-        if in_progress.buffered_rows >= self.max_row_group_size {
+        let exceeds_bytes = self
+            .max_row_group_bytes
+            .is_some_and(|max| in_progress.estimated_total_bytes() >= max);
+        if in_progress.buffered_rows >= self.max_row_group_size || exceeds_bytes {
             self.flush()?
         }
         Ok(())
@@ -311,6 +372,9 @@ impl<W: Write + Send> ArrowWriter<W> {
             Some(in_progress) => in_progress,
             None => return Ok(()),
         };
+        if let Some(validator) = &mut self.sorting_validator {
+            validator.reset();
+        }
 
         let mut row_group_writer = self.writer.next_row_group()?;
         for chunk in in_progress.close()? {
@@ -364,6 +428,73 @@ impl<W: Write + Send> ArrowWriter<W> {
     pub fn close(mut self) -> Result<crate::format::FileMetaData> {
         self.finish()
     }
+
+    /// Close and finalize the underlying Parquet writer, additionally returning
+    /// the [`ColumnWriteStatistics`] of each leaf column
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+    /// # use parquet::arrow::ArrowWriter;
+    /// let col = Arc::new(Int32Array::from_iter_values([1, 2, 1, 2])) as ArrayRef;
+    /// let batch = RecordBatch::try_from_iter([("a", col)]).unwrap();
+    ///
+    /// let mut writer = ArrowWriter::try_new(vec![], batch.schema(), None).unwrap();
+    /// writer.write(&batch).unwrap();
+    /// let (_metadata, stats) = writer.close_with_statistics().unwrap();
+    ///
+    /// assert_eq!(stats[0].column_path().string(), "a");
+    /// assert_eq!(stats[0].num_values(), 4);
+    /// assert_eq!(stats[0].dictionary_page_count(), 1);
+    /// assert_eq!(stats[0].dictionary_fallback_count(), 0);
+    /// ```
+    pub fn close_with_statistics(
+        mut self,
+    ) -> Result<(crate::format::FileMetaData, Vec<ColumnWriteStatistics>)> {
+        let metadata = self.finish()?;
+        Ok((metadata, self.column_write_statistics()))
+    }
+}
+
+impl ArrowWriter<File> {
+    /// Opens an existing parquet `file` to append new row groups to
+    ///
+    /// `arrow_schema` must convert to the same parquet schema as that of the existing
+    /// file, see [`SerializedFileWriter::try_append`] for more details
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+    /// # use parquet::arrow::arrow_writer::{ArrowWriter, ArrowWriterOptions};
+    /// # use parquet::arrow::arrow_reader::ParquetRecordBatchReader;
+    /// let batch = RecordBatch::try_from_iter([
+    ///     ("a", Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef),
+    /// ]).unwrap();
+    ///
+    /// let file = tempfile::tempfile().unwrap();
+    /// let mut writer = ArrowWriter::try_new(file.try_clone().unwrap(), batch.schema(), None).unwrap();
+    /// writer.write(&batch).unwrap();
+    /// writer.close().unwrap();
+    ///
+    /// // Append a second row group
+    /// let options = ArrowWriterOptions::new();
+    /// let mut writer = ArrowWriter::try_append(file.try_clone().unwrap(), batch.schema(), options).unwrap();
+    /// writer.write(&batch).unwrap();
+    /// writer.close().unwrap();
+    ///
+    /// let reader = ParquetRecordBatchReader::try_new(file, 1024).unwrap();
+    /// let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+    /// assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), 6);
+    /// ```
+    pub fn try_append(
+        file: File,
+        arrow_schema: SchemaRef,
+        options: ArrowWriterOptions,
+    ) -> Result<Self> {
+        Self::try_new_impl(arrow_schema, options, |schema, props| {
+            SerializedFileWriter::try_append(file, schema, props)
+        })
+    }
 }
 
 impl<W: Write + Send> RecordBatchWriter for ArrowWriter<W> {
@@ -380,11 +511,23 @@ impl<W: Write + Send> RecordBatchWriter for ArrowWriter<W> {
 /// Arrow-specific configuration settings for writing parquet files.
 ///
 /// See [`ArrowWriter`] for how to configure the writer.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone)]
 pub struct ArrowWriterOptions {
     properties: WriterProperties,
     skip_arrow_metadata: bool,
     schema_root: Option<String>,
+    parallelism: usize,
+}
+
+impl Default for ArrowWriterOptions {
+    fn default() -> Self {
+        Self {
+            properties: WriterProperties::default(),
+            skip_arrow_metadata: false,
+            schema_root: None,
+            parallelism: 1,
+        }
+    }
 }
 
 impl ArrowWriterOptions {
@@ -418,6 +561,31 @@ impl ArrowWriterOptions {
             ..self
         }
     }
+
+    /// Set the number of threads used to encode the columns of each row group
+    /// (defaults to `1`)
+    ///
+    /// When greater than `1`, the leaf columns of each [`RecordBatch`] are divided
+    /// between up to `parallelism` tasks on the global [rayon] thread pool, which
+    /// encode and compress them concurrently. The encoded column chunks are still
+    /// written to the output sequentially and in order, so the resulting file is
+    /// identical.
+    ///
+    /// This can reduce write latency for wide schemas on multicore machines, at the
+    /// cost of synchronizing with the thread pool for each written batch. For finer
+    /// grained control, e.g. to encode columns using an async runtime, see
+    /// [`get_column_writers`].
+    ///
+    /// A value of `0` is treated as `1`. This has no effect unless the `rayon`
+    /// feature is enabled.
+    ///
+    /// [rayon]: https://docs.rs/rayon
+    pub fn with_parallelism(self, parallelism: usize) -> Self {
+        Self {
+            parallelism: parallelism.max(1),
+            ..self
+        }
+    }
 }
 
 /// A single column chunk produced by [`ArrowColumnWriter`]
@@ -759,6 +927,7 @@ struct ArrowRowGroupWriter {
     writers: Vec<ArrowColumnWriter>,
     schema: SchemaRef,
     buffered_rows: usize,
+    parallelism: usize,
 }
 
 impl ArrowRowGroupWriter {
@@ -767,25 +936,72 @@ impl ArrowRowGroupWriter {
             writers,
             schema: arrow.clone(),
             buffered_rows: 0,
+            parallelism: 1,
         }
     }
 
+    fn with_parallelism(self, parallelism: usize) -> Self {
+        Self {
+            parallelism,
+            ..self
+        }
+    }
+
+    fn estimated_total_bytes(&self) -> usize {
+        self.writers
+            .iter()
+            .map(|x| x.get_estimated_total_bytes())
+            .sum()
+    }
+
+    /// Returns the number of rows that can be appended before reaching `max_rows`,
+    /// or the estimated encoded size reaching `max_bytes`
+    ///
+    /// As the encoded size of a row is only known once some rows have been written,
+    /// at most `sample_rows` are returned for an empty row group
+    fn remaining_rows(
+        &self,
+        max_rows: usize,
+        max_bytes: Option<usize>,
+        sample_rows: usize,
+    ) -> usize {
+        let remaining = max_rows - self.buffered_rows;
+        let Some(max_bytes) = max_bytes else {
+            return remaining;
+        };
+        if self.buffered_rows == 0 {
+            return remaining.min(sample_rows.max(1));
+        }
+        let size = self.estimated_total_bytes();
+        let row_size = size.div_ceil(self.buffered_rows).max(1);
+        remaining.min((max_bytes.saturating_sub(size) / row_size).max(1))
+    }
+
     fn write(&mut self, batch: &RecordBatch) -> Result<()> {
         self.buffered_rows += batch.num_rows();
-        let mut writers = self.writers.iter_mut();
-        for (field, column) in self.schema.fields().iter().zip(batch.columns()) {
-            for leaf in compute_leaves(field.as_ref(), column)? {
-                writers.next().unwrap().write(&leaf)?
+        if self.parallelism <= 1 {
+            let mut writers = self.writers.iter_mut();
+            for (field, column) in self.schema.fields().iter().zip(batch.columns()) {
+                for leaf in compute_leaves(field.as_ref(), column)? {
+                    writers.next().unwrap().write(&leaf)?
+                }
             }
+            return Ok(());
+        }
+
+        let mut leaves = Vec::with_capacity(self.writers.len());
+        for (field, column) in self.schema.fields().iter().zip(batch.columns()) {
+            leaves.extend(compute_leaves(field.as_ref(), column)?);
         }
+        let tasks = self.writers.iter_mut().zip(leaves).collect();
+        map_parallel(tasks, self.parallelism, |(writer, leaf)| {
+            writer.write(&leaf)
+        })?;
         Ok(())
     }
 
     fn close(self) -> Result<Vec<ArrowColumnChunk>> {
-        self.writers
-            .into_iter()
-            .map(|writer| writer.close())
-            .collect()
+        map_parallel(self.writers, self.parallelism, |writer| writer.close())
     }
 }
 
@@ -1039,6 +1255,17 @@ fn write_leaf(writer: &mut ColumnWriter<'_>, levels: &ArrayLevels) -> Result<usi
                     let array = values.inner().typed_data::<i32>();
                     write_primitive(typed, array, levels)
                 }
+                ArrowDataType::Decimal32(_, _) => {
+                    let array = column.as_primitive::<Decimal32Type>();
+                    write_primitive(typed, array.values(), levels)
+                }
+                ArrowDataType::Decimal64(_, _) => {
+                    // use the int32 to represent the decimal with low precision
+                    let array = column
+                        .as_primitive::<Decimal64Type>()
+                        .unary::<_, Int32Type>(|v| v as i32);
+                    write_primitive(typed, array.values(), levels)
+                }
                 ArrowDataType::Decimal128(_, _) => {
                     // use the int32 to represent the decimal with low precision
                     let array = column
@@ -1054,6 +1281,18 @@ fn write_leaf(writer: &mut ColumnWriter<'_>, levels: &ArrayLevels) -> Result<usi
                     write_primitive(typed, array.values(), levels)
                 }
                 ArrowDataType::Dictionary(_, value_type) => match value_type.as_ref() {
+                    ArrowDataType::Decimal32(_, _) => {
+                        let array = arrow_cast::cast(column, value_type)?;
+                        let array = array.as_primitive::<Decimal32Type>();
+                        write_primitive(typed, array.values(), levels)
+                    }
+                    ArrowDataType::Decimal64(_, _) => {
+                        let array = arrow_cast::cast(column, value_type)?;
+                        let array = array
+                            .as_primitive::<Decimal64Type>()
+                            .unary::<_, Int32Type>(|v| v as i32);
+                        write_primitive(typed, array.values(), levels)
+                    }
                     ArrowDataType::Decimal128(_, _) => {
                         let array = arrow_cast::cast(column, value_type)?;
                         let array = array
@@ -1108,6 +1347,16 @@ fn write_leaf(writer: &mut ColumnWriter<'_>, levels: &ArrayLevels) -> Result<usi
                     let array = values.inner().typed_data::<i64>();
                     write_primitive(typed, array, levels)
                 }
+                ArrowDataType::Decimal32(_, _) => {
+                    let array = column
+                        .as_primitive::<Decimal32Type>()
+                        .unary::<_, Int64Type>(|v| v as i64);
+                    write_primitive(typed, array.values(), levels)
+                }
+                ArrowDataType::Decimal64(_, _) => {
+                    let array = column.as_primitive::<Decimal64Type>();
+                    write_primitive(typed, array.values(), levels)
+                }
                 ArrowDataType::Decimal128(_, _) => {
                     // use the int64 to represent the decimal with low precision
                     let array = column
@@ -1123,6 +1372,18 @@ fn write_leaf(writer: &mut ColumnWriter<'_>, levels: &ArrayLevels) -> Result<usi
                     write_primitive(typed, array.values(), levels)
                 }
                 ArrowDataType::Dictionary(_, value_type) => match value_type.as_ref() {
+                    ArrowDataType::Decimal32(_, _) => {
+                        let array = arrow_cast::cast(column, value_type)?;
+                        let array = array
+                            .as_primitive::<Decimal32Type>()
+                            .unary::<_, Int64Type>(|v| v as i64);
+                        write_primitive(typed, array.values(), levels)
+                    }
+                    ArrowDataType::Decimal64(_, _) => {
+                        let array = arrow_cast::cast(column, value_type)?;
+                        let array = array.as_primitive::<Decimal64Type>();
+                        write_primitive(typed, array.values(), levels)
+                    }
                     ArrowDataType::Decimal128(_, _) => {
                         let array = arrow_cast::cast(column, value_type)?;
                         let array = array
@@ -1357,12 +1618,12 @@ mod tests {
     use half::f16;
     use num::{FromPrimitive, ToPrimitive};
 
-    use crate::basic::Encoding;
+    use crate::basic::{Compression, Encoding, PageType};
     use crate::data_type::AsBytes;
     use crate::file::metadata::{ColumnChunkMetaData, ParquetMetaData, ParquetMetaDataReader};
     use crate::file::page_index::index::Index;
     use crate::file::properties::{
-        BloomFilterPosition, EnabledStatistics, ReaderProperties, WriterVersion,
+        BloomFilterPosition, DataPageVersion, EnabledStatistics, ReaderProperties, WriterVersion,
     };
     use crate::file::serialized_reader::ReadOptionsBuilder;
     use crate::file::{
@@ -2070,6 +2331,69 @@ mod tests {
         );
     }
 
+    #[test]
+    fn arrow_writer_data_page_v2() {
+        let ints = Int32Array::from_iter((0..1000).map(|i| (i % 3 != 0).then_some(i)));
+        let mut lists = ListBuilder::new(StringBuilder::new());
+        for i in 0..1000 {
+            match i % 5 {
+                0 => lists.append_null(),
+                1 => lists.append(true),
+                _ => {
+                    lists.values().append_value(format!("value {i}"));
+                    lists.values().append_null();
+                    lists.append(true)
+                }
+            }
+        }
+        let batch = RecordBatch::try_from_iter([
+            ("ints", Arc::new(ints) as ArrayRef),
+            ("lists", Arc::new(lists.finish()) as ArrayRef),
+        ])
+        .unwrap();
+
+        let codecs = [
+            Compression::UNCOMPRESSED,
+            Compression::SNAPPY,
+            Compression::GZIP(Default::default()),
+            Compression::LZ4_RAW,
+            Compression::ZSTD(Default::default()),
+            Compression::BROTLI(Default::default()),
+        ];
+        let ints = ColumnPath::from("ints");
+        for codec in codecs {
+            for ints_version in [DataPageVersion::V1, DataPageVersion::V2] {
+                let props = WriterProperties::builder()
+                    .set_compression(codec)
+                    .set_dictionary_enabled(false)
+                    .set_data_page_size_limit(256)
+                    .set_write_batch_size(64)
+                    .set_data_page_version(DataPageVersion::V2)
+                    .set_column_data_page_version(ints.clone(), ints_version)
+                    .build();
+                let file = roundtrip_opts(&batch, props);
+
+                let reader = SerializedFileReader::new(file).unwrap();
+                let row_group = reader.get_row_group(0).unwrap();
+                for (idx, version) in [ints_version, DataPageVersion::V2].into_iter().enumerate() {
+                    let expected = match version {
+                        DataPageVersion::V1 => PageType::DATA_PAGE,
+                        DataPageVersion::V2 => PageType::DATA_PAGE_V2,
+                    };
+                    let mut pages = row_group.get_column_page_reader(idx).unwrap();
+                    let mut num_data_pages = 0;
+                    while let Some(page) = pages.get_next_page().unwrap() {
+                        if page.page_type() != PageType::DICTIONARY_PAGE {
+                            assert_eq!(page.page_type(), expected);
+                            num_data_pages += 1;
+                        }
+                    }
+                    assert!(num_data_pages > 1);
+                }
+            }
+        }
+    }
+
     #[test]
     fn arrow_writer_float_nans() {
         let f16_field = Field::new("a", DataType::Float16, false);
@@ -2677,6 +3001,133 @@ mod tests {
         check_bloom_filter(files, "col".to_string(), optional_raw_values, vec![""]);
     }
 
+    #[test]
+    fn view_column_bloom_filter() {
+        let raw_values: Vec<_> = (0..SMALL_SIZE).map(|i| format!("value_{i:020}")).collect();
+
+        let array = Arc::new(StringViewArray::from_iter_values(&raw_values));
+        let mut options = RoundTripOptions::new(array, false);
+        options.bloom_filter = true;
+
+        let files = one_column_roundtrip_with_options(options);
+        check_bloom_filter(
+            files,
+            "col".to_string(),
+            raw_values.iter().map(|v| v.as_str()).collect(),
+            vec!["value_", "missing"],
+        );
+
+        let array = Arc::new(BinaryViewArray::from_iter_values(&raw_values));
+        let mut options = RoundTripOptions::new(array, false);
+        options.bloom_filter = true;
+
+        let files = one_column_roundtrip_with_options(options);
+        check_bloom_filter(
+            files,
+            "col".to_string(),
+            raw_values.iter().map(|v| v.as_bytes().to_vec()).collect(),
+            vec![b"value_".to_vec(), b"missing".to_vec()],
+        );
+    }
+
+    #[test]
+    fn decimal_column_bloom_filter() {
+        let values = 0..SMALL_SIZE as i32;
+
+        // Stored as INT32
+        let array = Decimal128Array::from_iter_values(values.clone().map(i128::from))
+            .with_precision_and_scale(9, 2)
+            .unwrap();
+        let mut options = RoundTripOptions::new(Arc::new(array), false);
+        options.bloom_filter = true;
+
+        let files = one_column_roundtrip_with_options(options);
+        check_bloom_filter(
+            files,
+            "col".to_string(),
+            values.clone().collect(),
+            (SMALL_SIZE as i32 + 1..SMALL_SIZE as i32 + 10).collect(),
+        );
+
+        // Stored as FIXED_LEN_BYTE_ARRAY of big-endian bytes
+        let array = Decimal128Array::from_iter_values(values.clone().map(i128::from))
+            .with_precision_and_scale(38, 2)
+            .unwrap();
+        let mut options = RoundTripOptions::new(Arc::new(array), false);
+        options.bloom_filter = true;
+
+        let files = one_column_roundtrip_with_options(options);
+        check_bloom_filter(
+            files,
+            "col".to_string(),
+            values
+                .map(|v| i128::from(v).to_be_bytes().to_vec())
+                .collect(),
+            vec![(SMALL_SIZE as i128 + 1).to_be_bytes().to_vec()],
+        );
+    }
+
+    #[test]
+    fn bloom_filter_adaptive_ndv() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("i32", DataType::Int32, false),
+            Field::new("utf8", DataType::Utf8View, false),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(Int32Array::from_iter_values((0..4096).map(|i| i % 100))),
+                Arc::new(StringViewArray::from_iter_values(
+                    (0..4096).map(|i| format!("v{}", i % 100)),
+                )),
+            ],
+        )
+        .unwrap();
+
+        let write = |adaptive: bool| {
+            let props = WriterProperties::builder()
+                .set_bloom_filter_enabled(true)
+                .set_bloom_filter_ndv(100_000)
+                .set_bloom_filter_adaptive_ndv(adaptive)
+                .build();
+            let mut buf = Vec::new();
+            let mut writer = ArrowWriter::try_new(&mut buf, schema.clone(), Some(props)).unwrap();
+            writer.write(&batch).unwrap();
+            writer.close().unwrap();
+            Bytes::from(buf)
+        };
+
+        let filters = |data: Bytes| {
+            let options = ReadOptionsBuilder::new()
+                .with_reader_properties(
+                    ReaderProperties::builder()
+                        .set_read_bloom_filter(true)
+                        .build(),
+                )
+                .build();
+            let reader = SerializedFileReader::new_with_options(data, options).unwrap();
+            let row_group = reader.get_row_group(0).unwrap();
+            (0..2)
+                .map(|idx| {
+                    let length = row_group.metadata().column(idx).bloom_filter_length();
+                    let sbbf = row_group.get_column_bloom_filter(idx).cloned();
+                    (length.unwrap(), sbbf.unwrap())
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let fixed = filters(write(false));
+        let adaptive = filters(write(true));
+        for ((fixed_len, _), (adaptive_len, _)) in fixed.iter().zip(&adaptive) {
+            assert!(adaptive_len * 16 < *fixed_len, "{adaptive_len} {fixed_len}");
+        }
+        // No false negatives after shrinking
+        let (_, i32_filter) = &adaptive[0];
+        assert!((0..100).all(|v: i32| i32_filter.check(&v)));
+        let (_, utf8_filter) = &adaptive[1];
+        assert!((0..100).all(|v| utf8_filter.check(format!("v{v}").as_str())));
+    }
+
     #[test]
     fn large_binary_single_column() {
         let one_vec: Vec<u8> = (0..SMALL_SIZE as u8).collect();
@@ -3733,6 +4184,200 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_arrow_writer_parallelism() {
+        let mut fields: Vec<Field> = (0..12)
+            .map(|i| match i % 3 {
+                0 => Field::new(format!("int_{i}"), DataType::Int64, true),
+                1 => Field::new(format!("str_{i}"), DataType::Utf8, true),
+                _ => Field::new_list(
+                    format!("list_{i}"),
+                    Field::new_list_field(DataType::Float32, true),
+                    true,
+                ),
+            })
+            .collect();
+        fields.push(Field::new_struct(
+            "struct",
+            vec![
+                Field::new("a", DataType::Int32, true),
+                Field::new("b", DataType::Boolean, false),
+            ],
+            true,
+        ));
+        let schema = Arc::new(Schema::new(fields));
+        let batches: Vec<_> = (0..3)
+            .map(|_| arrow::util::data_gen::create_random_batch(schema.clone(), 500, 0.2, 0.5))
+            .collect::<ArrowResult<_>>()
+            .unwrap();
+
+        let write = |parallelism: usize| {
+            let props = WriterProperties::builder()
+                .set_max_row_group_size(700)
+                .build();
+            let options = ArrowWriterOptions::new()
+                .with_properties(props)
+                .with_parallelism(parallelism);
+            let mut buf = Vec::new();
+            let mut writer =
+                ArrowWriter::try_new_with_options(&mut buf, schema.clone(), options).unwrap();
+            for batch in &batches {
+                writer.write(batch).unwrap();
+            }
+            writer.close().unwrap();
+            Bytes::from(buf)
+        };
+
+        let sequential = write(1);
+        for parallelism in [0, 2, 4, 100] {
+            assert_eq!(write(parallelism), sequential, "{parallelism}");
+        }
+
+        let reader = ParquetRecordBatchReader::try_new(write(4), 1500).unwrap();
+        let read: Vec<_> = reader.collect::<ArrowResult<_>>().unwrap();
+        assert_eq!(read.len(), 1);
+        assert_eq!(
+            read[0],
+            arrow::compute::concat_batches(&schema, &batches).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_arrow_writer_max_row_group_bytes() {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, false)]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![Arc::new(Int64Array::from_iter_values(0..5000))],
+        )
+        .unwrap();
+
+        let props = WriterProperties::builder()
+            .set_dictionary_enabled(false)
+            .set_max_row_group_bytes(Some(20_000))
+            .build();
+        let mut writer = ArrowWriter::try_new(Vec::new(), schema, Some(props)).unwrap();
+        for _ in 0..4 {
+            writer.write(&batch).unwrap();
+        }
+        let data = Bytes::from(writer.into_inner().unwrap());
+
+        let reader = ParquetRecordBatchReaderBuilder::try_new(data).unwrap();
+        let row_groups = reader.metadata().row_groups();
+        let num_rows: i64 = row_groups.iter().map(|rg| rg.num_rows()).sum();
+        assert_eq!(num_rows, 20_000);
+        assert!(row_groups.len() >= 8, "{}", row_groups.len());
+        for rg in &row_groups[..row_groups.len() - 1] {
+            // 8 bytes per value, with some allowance for page headers and the estimate
+            assert!(rg.num_rows() <= 2500, "{}", rg.num_rows());
+            assert!(rg.total_byte_size() <= 22_000, "{}", rg.total_byte_size());
+            assert!(rg.total_byte_size() >= 15_000, "{}", rg.total_byte_size());
+        }
+    }
+
+    #[test]
+    fn test_arrow_writer_append() {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        let batch = |range: std::ops::Range<i32>| {
+            RecordBatch::try_new(
+                schema.clone(),
+                vec![Arc::new(Int32Array::from_iter_values(range))],
+            )
+            .unwrap()
+        };
+
+        let props = || {
+            WriterProperties::builder()
+                .set_bloom_filter_enabled(true)
+                .set_key_value_metadata(Some(vec![KeyValue::new("k".to_string(), "v".to_string())]))
+                .build()
+        };
+
+        let file = tempfile::tempfile().unwrap();
+        let mut writer =
+            ArrowWriter::try_new(file.try_clone().unwrap(), schema.clone(), Some(props())).unwrap();
+        writer.write(&batch(0..10)).unwrap();
+        writer.close().unwrap();
+
+        for i in 1..3 {
+            let options = ArrowWriterOptions::new().with_properties(
+                WriterProperties::builder()
+                    .set_bloom_filter_enabled(true)
+                    .build(),
+            );
+            let mut writer =
+                ArrowWriter::try_append(file.try_clone().unwrap(), schema.clone(), options)
+                    .unwrap();
+            assert_eq!(writer.flushed_row_groups().len(), i);
+            writer
+                .write(&batch(i as i32 * 10..i as i32 * 10 + 10))
+                .unwrap();
+            writer.close().unwrap();
+        }
+
+        let options = crate::arrow::arrow_reader::ArrowReaderOptions::new().with_page_index(true);
+        let builder = ParquetRecordBatchReaderBuilder::try_new_with_options(
+            file.try_clone().unwrap(),
+            options,
+        )
+        .unwrap();
+        let metadata = builder.metadata().clone();
+        assert_eq!(metadata.num_row_groups(), 3);
+        assert_eq!(metadata.offset_index().unwrap().len(), 3);
+        for (idx, rg) in metadata.row_groups().iter().enumerate() {
+            assert_eq!(rg.ordinal(), Some(idx as i16));
+            assert_eq!(
+                metadata.offset_index().unwrap()[idx][0]
+                    .page_locations
+                    .len(),
+                1
+            );
+        }
+        let kv = metadata.file_metadata().key_value_metadata().unwrap();
+        assert!(kv.iter().any(|kv| kv.key == "k"));
+        assert_eq!(
+            kv.iter()
+                .filter(|kv| kv.key == ARROW_SCHEMA_META_KEY)
+                .count(),
+            1
+        );
+
+        let batches = builder
+            .build()
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let expected = batch(0..30);
+        assert_eq!(
+            arrow::compute::concat_batches(&schema, &batches).unwrap(),
+            expected
+        );
+
+        // Bloom filters of existing row groups are retained
+        check_bloom_filter(
+            vec![file.try_clone().unwrap()],
+            "a".into(),
+            vec![5, 15, 25],
+            vec![],
+        );
+
+        // Mismatched schema
+        let other = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, false)]));
+        let err = ArrowWriter::try_append(file, other, Default::default()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Cannot append to parquet file, schema does not match the existing file"
+        );
+
+        // Not a parquet file
+        let err =
+            ArrowWriter::try_append(tempfile::tempfile().unwrap(), schema, Default::default())
+                .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Cannot append to file of size 0, not a parquet file"
+        );
+    }
+
     #[test]
     fn mismatched_schemas() {
         let batch_schema = Schema::new(vec![Field::new("count", DataType::Int32, false)]);
@@ -3925,6 +4570,63 @@ mod tests {
         assert_eq!(stats.min_value.unwrap(), "Bl".as_bytes());
     }
 
+    #[test]
+    fn test_column_stats_truncation() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Utf8, false),
+            Field::new("b", DataType::Binary, false),
+            Field::new("c", DataType::Utf8, true),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(StringArray::from(vec!["Blart Versenwald III"])),
+                Arc::new(BinaryArray::from(vec![b"Blart Versenwald III".as_slice()])),
+                Arc::new(StringArray::from(vec![None::<&str>])),
+            ],
+        )
+        .unwrap();
+
+        let props = WriterProperties::builder()
+            .set_statistics_truncate_length(Some(4))
+            .set_column_statistics_truncate_length(ColumnPath::from("a"), Some(2))
+            .set_column_statistics_truncate_length(ColumnPath::from("b"), None)
+            .build();
+        let mut writer = ArrowWriter::try_new(Vec::new(), schema, Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        let metadata = writer.close().unwrap();
+
+        let stats = |idx: usize| {
+            metadata.row_groups[0].columns[idx]
+                .meta_data
+                .as_ref()
+                .unwrap()
+                .statistics
+                .clone()
+                .unwrap()
+        };
+
+        let a = stats(0);
+        assert_eq!(a.min_value.unwrap(), b"Bl");
+        assert_eq!(a.max_value.unwrap(), b"Bm");
+        assert_eq!(a.is_min_value_exact, Some(false));
+        assert_eq!(a.is_max_value_exact, Some(false));
+
+        let b = stats(1);
+        assert_eq!(b.min_value.unwrap(), b"Blart Versenwald III");
+        assert_eq!(b.max_value.unwrap(), b"Blart Versenwald III");
+        assert_eq!(b.is_min_value_exact, Some(true));
+        assert_eq!(b.is_max_value_exact, Some(true));
+
+        // No exactness flags are written without min/max values
+        let c = stats(2);
+        assert_eq!(c.null_count, Some(1));
+        assert_eq!(c.min_value, None);
+        assert_eq!(c.max_value, None);
+        assert_eq!(c.is_min_value_exact, None);
+        assert_eq!(c.is_max_value_exact, None);
+    }
+
     #[test]
     fn test_page_encoding_statistics_roundtrip() {
         let batch_schema = Schema::new(vec![Field::new(
@@ -4006,4 +4708,228 @@ mod tests {
         assert_eq!(get_dict_page_size(col0_meta), 1024 * 1024);
         assert_eq!(get_dict_page_size(col1_meta), 1024 * 1024 * 4);
     }
+
+    #[test]
+    fn test_column_write_statistics() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("dict", ArrowDataType::Int64, false),
+            Field::new("fallback", ArrowDataType::Int64, false),
+        ]));
+        let dict = Arc::new(Int64Array::from_iter_values((0..1000).map(|x| x % 4)));
+        let fallback = Arc::new(Int64Array::from_iter_values(0..1000));
+        let batch = RecordBatch::try_new(schema.clone(), vec![dict, fallback]).unwrap();
+
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(500)
+            .set_data_page_row_count_limit(100)
+            .set_write_batch_size(100)
+            .set_column_dictionary_page_size_limit(ColumnPath::from("fallback"), 256)
+            .build();
+        let mut writer = ArrowWriter::try_new(Vec::new(), schema, Some(props)).unwrap();
+        assert!(writer.column_write_statistics().is_empty());
+        writer.write(&batch).unwrap();
+        assert_eq!(writer.column_write_statistics().len(), 2);
+
+        let (metadata, stats) = writer.close_with_statistics().unwrap();
+        assert_eq!(metadata.row_groups.len(), 2);
+        assert_eq!(stats.len(), 2);
+
+        let expected_sizes = |idx: usize| {
+            metadata.row_groups.iter().fold((0, 0), |(c, u), rg| {
+                let meta = rg.columns[idx].meta_data.as_ref().unwrap();
+                (
+                    c + meta.total_compressed_size,
+                    u + meta.total_uncompressed_size,
+                )
+            })
+        };
+
+        let dict = &stats[0];
+        assert_eq!(dict.column_path().string(), "dict");
+        assert_eq!(dict.num_values(), 1000);
+        assert_eq!(
+            (dict.compressed_size(), dict.uncompressed_size()),
+            expected_sizes(0)
+        );
+        assert!(dict.compression_ratio().is_some());
+        assert!(dict.encodings().contains(&Encoding::RLE_DICTIONARY));
+        assert_eq!(dict.dictionary_page_count(), 2);
+        assert_eq!(dict.data_page_count(), 10);
+        assert_eq!(dict.dictionary_fallback_count(), 0);
+
+        let fallback = &stats[1];
+        assert_eq!(fallback.column_path().string(), "fallback");
+        assert_eq!(fallback.num_values(), 1000);
+        assert_eq!(
+            (fallback.compressed_size(), fallback.uncompressed_size()),
+            expected_sizes(1)
+        );
+        assert!(fallback.encodings().contains(&Encoding::PLAIN));
+        assert_eq!(fallback.dictionary_page_count(), 2);
+        assert_eq!(fallback.dictionary_fallback_count(), 2);
+    }
+
+    #[test]
+    fn test_validate_sorting_columns() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", ArrowDataType::Int32, true),
+            Field::new("b", ArrowDataType::Utf8, false),
+        ]));
+        let batch = |a: Vec<Option<i32>>, b: Vec<&str>| {
+            RecordBatch::try_new(
+                schema.clone(),
+                vec![
+                    Arc::new(Int32Array::from(a)),
+                    Arc::new(StringArray::from(b)),
+                ],
+            )
+            .unwrap()
+        };
+        // Sorted by `a` ascending with nulls first, then `b` descending
+        let sorting_columns = vec![
+            crate::format::SortingColumn {
+                column_idx: 0,
+                descending: false,
+                nulls_first: true,
+            },
+            crate::format::SortingColumn {
+                column_idx: 1,
+                descending: true,
+                nulls_first: false,
+            },
+        ];
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(4)
+            .set_sorting_columns(Some(sorting_columns.clone()))
+            .set_validate_sorting_columns(true)
+            .build();
+
+        let mut buf = Vec::new();
+        let mut writer =
+            ArrowWriter::try_new(&mut buf, schema.clone(), Some(props.clone())).unwrap();
+        writer
+            .write(&batch(vec![None, Some(1), Some(1)], vec!["x", "z", "y"]))
+            .unwrap();
+        // Starts a new row group after the first row, so the order restarts
+        writer
+            .write(&batch(vec![Some(2), Some(0), Some(3)], vec!["a", "a", "a"]))
+            .unwrap();
+        writer.close().unwrap();
+
+        let builder = ParquetRecordBatchReaderBuilder::try_new(Bytes::from(buf)).unwrap();
+        assert_eq!(builder.metadata().num_row_groups(), 2);
+        assert_eq!(
+            builder.metadata().sorting_columns(),
+            Some(sorting_columns.as_slice())
+        );
+
+        let mut writer =
+            ArrowWriter::try_new(Vec::new(), schema.clone(), Some(props.clone())).unwrap();
+        let err = writer
+            .write(&batch(vec![Some(1), Some(1)], vec!["y", "z"]))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Row 1 of row group violates the declared sort order of column 'b'"
+        );
+
+        // Order is validated across batches of the same row group
+        let mut writer = ArrowWriter::try_new(Vec::new(), schema.clone(), Some(props)).unwrap();
+        writer.write(&batch(vec![Some(1)], vec!["a"])).unwrap();
+        let err = writer.write(&batch(vec![None], vec!["a"])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Row 1 of row group violates the declared sort order of column 'a'"
+        );
+
+        // Unsorted data is accepted without validation
+        let props = WriterProperties::builder()
+            .set_sorting_columns(Some(sorting_columns))
+            .build();
+        let mut writer = ArrowWriter::try_new(Vec::new(), schema.clone(), Some(props)).unwrap();
+        writer
+            .write(&batch(vec![Some(1), None], vec!["a", "b"]))
+            .unwrap();
+        writer.close().unwrap();
+
+        let props = WriterProperties::builder()
+            .set_sorting_columns(Some(vec![crate::format::SortingColumn {
+                column_idx: 2,
+                descending: false,
+                nulls_first: false,
+            }]))
+            .set_validate_sorting_columns(true)
+            .build();
+        let err = ArrowWriter::try_new(Vec::new(), schema, Some(props)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Sorting column index 2 out of bounds for schema with 2 leaf columns"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "geospatial")]
+    fn test_arrow_writer_geospatial() {
+        use crate::arrow::arrow_reader::ArrowReaderOptions;
+        use crate::basic::LogicalType;
+
+        let wkb_point = |x: f64, y: f64| {
+            let mut wkb = vec![1, 1, 0, 0, 0];
+            wkb.extend_from_slice(&x.to_le_bytes());
+            wkb.extend_from_slice(&y.to_le_bytes());
+            wkb
+        };
+        let metadata = std::collections::HashMap::from([
+            (
+                "ARROW:extension:name".to_string(),
+                "geoarrow.wkb".to_string(),
+            ),
+            (
+                "ARROW:extension:metadata".to_string(),
+                r#"{"crs":"srid:3857"}"#.to_string(),
+            ),
+        ]);
+        let field = Field::new("geom", DataType::Binary, true).with_metadata(metadata);
+        let values = BinaryArray::from_iter([
+            Some(wkb_point(10.0, 20.0)),
+            None,
+            Some(wkb_point(-5.0, 30.0)),
+        ]);
+        let batch =
+            RecordBatch::try_new(Arc::new(Schema::new(vec![field])), vec![Arc::new(values)])
+                .unwrap();
+
+        let mut buf = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let buf = Bytes::from(buf);
+        let builder = ParquetRecordBatchReaderBuilder::try_new(buf.clone()).unwrap();
+        let column = builder.metadata().row_group(0).column(0);
+        assert_eq!(
+            column.column_descr().logical_type(),
+            Some(LogicalType::Geometry {
+                crs: Some("srid:3857".to_string())
+            })
+        );
+        let bbox = column.geo_statistics().unwrap().bbox().unwrap();
+        assert_eq!(
+            (bbox.xmin(), bbox.xmax(), bbox.ymin(), bbox.ymax()),
+            (-5.0, 10.0, 20.0, 30.0)
+        );
+        let read = builder.build().unwrap().next().unwrap().unwrap();
+        assert_eq!(read, batch);
+
+        // The GeoArrow metadata is derived from the logical type without the arrow schema
+        let options = ArrowReaderOptions::new().with_skip_arrow_metadata(true);
+        let builder = ParquetRecordBatchReaderBuilder::try_new_with_options(buf, options).unwrap();
+        let field = builder.schema().field(0).clone();
+        assert_eq!(field.data_type(), &DataType::Binary);
+        assert_eq!(field.metadata()["ARROW:extension:name"], "geoarrow.wkb");
+        assert_eq!(
+            field.metadata()["ARROW:extension:metadata"],
+            r#"{"crs":"srid:3857"}"#
+        );
+    }
 }
@@ -0,0 +1,165 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Validation of the declared [`SortingColumn`]s of written row groups
+
+use std::cmp::Ordering;
+
+use arrow_array::{ArrayRef, RecordBatch};
+use arrow_ord::ord::{make_comparator, DynComparator};
+use arrow_schema::SortOptions;
+
+use crate::errors::{ParquetError, Result};
+use crate::format::SortingColumn;
+use crate::schema::types::SchemaDescriptor;
+
+/// A leaf column that the rows of a row group are declared to be sorted by
+#[derive(Debug, Clone)]
+struct SortKey {
+    /// Index of the top-level arrow column
+    column_idx: usize,
+    /// Dotted path of the parquet leaf column, used in errors
+    path: String,
+    options: SortOptions,
+}
+
+/// Verifies that the rows written to a row group are ordered according to the
+/// [`SortingColumn`]s declared in the [`WriterProperties`]
+///
+/// [`WriterProperties`]: crate::file::properties::WriterProperties
+#[derive(Debug, Clone)]
+pub(crate) struct SortingColumnsValidator {
+    keys: Vec<SortKey>,
+    /// The last written row of each sort key in the current row group
+    last_row: Option<Vec<ArrayRef>>,
+    /// The number of rows written to the current row group
+    num_rows: usize,
+}
+
+impl SortingColumnsValidator {
+    /// Creates a validator for `sorting_columns`, returning an error if any of them
+    /// does not identify a leaf column that is a top-level column of `parquet`
+    pub(crate) fn try_new(
+        sorting_columns: &[SortingColumn],
+        parquet: &SchemaDescriptor,
+    ) -> Result<Self> {
+        let keys = sorting_columns
+            .iter()
+            .map(|sorting_column| {
+                let leaf_idx = usize::try_from(sorting_column.column_idx)
+                    .ok()
+                    .filter(|idx| *idx < parquet.num_columns())
+                    .ok_or_else(|| {
+                        general_err!(
+                            "Sorting column index {} out of bounds for schema with {} leaf columns",
+                            sorting_column.column_idx,
+                            parquet.num_columns()
+                        )
+                    })?;
+                let path = parquet.column(leaf_idx).path().string();
+                if !parquet.get_column_root(leaf_idx).is_primitive() {
+                    return Err(general_err!(
+                        "Cannot validate the sort order of nested column '{}'",
+                        path
+                    ));
+                }
+                Ok(SortKey {
+                    column_idx: parquet.get_column_root_idx(leaf_idx),
+                    path,
+                    options: SortOptions {
+                        descending: sorting_column.descending,
+                        nulls_first: sorting_column.nulls_first,
+                    },
+                })
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            keys,
+            last_row: None,
+            num_rows: 0,
+        })
+    }
+
+    /// Verifies that the rows of `batch` are sorted, and do not sort before the
+    /// rows previously written to the current row group
+    pub(crate) fn validate(&mut self, batch: &RecordBatch) -> Result<()> {
+        if batch.num_rows() == 0 || self.keys.is_empty() {
+            return Ok(());
+        }
+
+        let columns: Vec<_> = self
+            .keys
+            .iter()
+            .map(|key| batch.column(key.column_idx).clone())
+            .collect();
+
+        if let Some(last_row) = &self.last_row {
+            let comparators = self.comparators(last_row, &columns)?;
+            self.check(&comparators, 0, 0, 0)?;
+        }
+
+        let comparators = self.comparators(&columns, &columns)?;
+        for row in 1..batch.num_rows() {
+            self.check(&comparators, row - 1, row, row)?;
+        }
+
+        let last = batch.num_rows() - 1;
+        self.last_row = Some(columns.iter().map(|c| c.slice(last, 1)).collect());
+        self.num_rows += batch.num_rows();
+        Ok(())
+    }
+
+    /// Resets the validator for a new row group
+    pub(crate) fn reset(&mut self) {
+        self.last_row = None;
+        self.num_rows = 0;
+    }
+
+    fn comparators(&self, left: &[ArrayRef], right: &[ArrayRef]) -> Result<Vec<DynComparator>> {
+        self.keys
+            .iter()
+            .zip(left.iter().zip(right))
+            .map(|(key, (l, r))| Ok(make_comparator(l.as_ref(), r.as_ref(), key.options)?))
+            .collect()
+    }
+
+    /// Returns an error if row `left` sorts after row `right`, where `row` is the
+    /// index of `right` within the batch being validated
+    fn check(
+        &self,
+        comparators: &[DynComparator],
+        left: usize,
+        right: usize,
+        row: usize,
+    ) -> Result<()> {
+        for (key, cmp) in self.keys.iter().zip(comparators) {
+            match cmp(left, right) {
+                Ordering::Less => return Ok(()),
+                Ordering::Equal => continue,
+                Ordering::Greater => {
+                    return Err(general_err!(
+                        "Row {} of row group violates the declared sort order of column '{}'",
+                        self.num_rows + row,
+                        key.path
+                    ))
+                }
+            }
+        }
+        Ok(())
+    }
+}
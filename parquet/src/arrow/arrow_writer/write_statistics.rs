@@ -0,0 +1,167 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Per-column statistics describing how data was written
+
+use crate::basic::{Encoding, PageType};
+use crate::file::metadata::{ColumnChunkMetaData, RowGroupMetaData};
+use crate::schema::types::ColumnPath;
+
+/// Statistics describing how a single leaf column was written, aggregated
+/// across all row groups
+///
+/// Unlike the value [`Statistics`] stored in the file, these describe the
+/// encoded output, and can be used to monitor and tune the [`WriterProperties`]
+/// of a writer, see [`ArrowWriter::column_write_statistics`]
+///
+/// [`Statistics`]: crate::file::statistics::Statistics
+/// [`WriterProperties`]: crate::file::properties::WriterProperties
+/// [`ArrowWriter::column_write_statistics`]: super::ArrowWriter::column_write_statistics
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnWriteStatistics {
+    column_path: ColumnPath,
+    num_values: i64,
+    compressed_size: i64,
+    uncompressed_size: i64,
+    encodings: Vec<Encoding>,
+    data_page_count: usize,
+    dictionary_page_count: usize,
+    dictionary_fallback_count: usize,
+}
+
+impl ColumnWriteStatistics {
+    fn new(column_path: ColumnPath) -> Self {
+        Self {
+            column_path,
+            num_values: 0,
+            compressed_size: 0,
+            uncompressed_size: 0,
+            encodings: vec![],
+            data_page_count: 0,
+            dictionary_page_count: 0,
+            dictionary_fallback_count: 0,
+        }
+    }
+
+    /// Computes the statistics of each leaf column from the metadata of `row_groups`
+    pub fn from_row_groups(row_groups: &[RowGroupMetaData]) -> Vec<Self> {
+        let Some(first) = row_groups.first() else {
+            return vec![];
+        };
+        let mut stats: Vec<_> = first
+            .columns()
+            .iter()
+            .map(|c| Self::new(c.column_path().clone()))
+            .collect();
+
+        for row_group in row_groups {
+            for (s, column) in stats.iter_mut().zip(row_group.columns()) {
+                s.update(column);
+            }
+        }
+        stats
+    }
+
+    fn update(&mut self, column: &ColumnChunkMetaData) {
+        self.num_values += column.num_values();
+        self.compressed_size += column.compressed_size();
+        self.uncompressed_size += column.uncompressed_size();
+        for encoding in column.encodings() {
+            if !self.encodings.contains(encoding) {
+                self.encodings.push(*encoding);
+            }
+        }
+
+        let mut dictionary_encoded = false;
+        let mut fallback = false;
+        for s in column.page_encoding_stats().into_iter().flatten() {
+            let count = s.count as usize;
+            match s.page_type {
+                PageType::DICTIONARY_PAGE => {
+                    self.dictionary_page_count += count;
+                    dictionary_encoded = true;
+                }
+                PageType::DATA_PAGE | PageType::DATA_PAGE_V2 => {
+                    self.data_page_count += count;
+                    fallback |= !matches!(
+                        s.encoding,
+                        Encoding::RLE_DICTIONARY | Encoding::PLAIN_DICTIONARY
+                    );
+                }
+                _ => {}
+            }
+        }
+        if dictionary_encoded && fallback {
+            self.dictionary_fallback_count += 1;
+        }
+    }
+
+    /// Returns the path of this column
+    pub fn column_path(&self) -> &ColumnPath {
+        &self.column_path
+    }
+
+    /// Returns the number of values written, including nulls
+    pub fn num_values(&self) -> i64 {
+        self.num_values
+    }
+
+    /// Returns the total size in bytes of the column chunks after compression
+    pub fn compressed_size(&self) -> i64 {
+        self.compressed_size
+    }
+
+    /// Returns the total size in bytes of the column chunks before compression
+    pub fn uncompressed_size(&self) -> i64 {
+        self.uncompressed_size
+    }
+
+    /// Returns the ratio of the uncompressed to the compressed size, or `None`
+    /// if nothing has been written
+    pub fn compression_ratio(&self) -> Option<f64> {
+        (self.compressed_size > 0)
+            .then(|| self.uncompressed_size as f64 / self.compressed_size as f64)
+    }
+
+    /// Returns the distinct encodings used by any column chunk, including
+    /// those of repetition and definition levels
+    pub fn encodings(&self) -> &[Encoding] {
+        &self.encodings
+    }
+
+    /// Returns the number of data pages written
+    pub fn data_page_count(&self) -> usize {
+        self.data_page_count
+    }
+
+    /// Returns the number of dictionary pages written
+    pub fn dictionary_page_count(&self) -> usize {
+        self.dictionary_page_count
+    }
+
+    /// Returns the number of column chunks that fell back from dictionary
+    /// encoding, that is those with a dictionary page and at least one data
+    /// page that is not dictionary encoded
+    ///
+    /// This typically occurs when the dictionary exceeds the configured
+    /// [`dictionary_page_size_limit`]
+    ///
+    /// [`dictionary_page_size_limit`]: crate::file::properties::WriterProperties::dictionary_page_size_limit
+    pub fn dictionary_fallback_count(&self) -> usize {
+        self.dictionary_fallback_count
+    }
+}
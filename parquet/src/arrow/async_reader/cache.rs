@@ -0,0 +1,394 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`MetadataCache`] for sharing parsed metadata between reads of the same file
+
+use crate::arrow::arrow_reader::ArrowReaderOptions;
+use crate::arrow::async_reader::AsyncFileReader;
+use crate::bloom_filter::Sbbf;
+use crate::errors::Result;
+use crate::file::metadata::ParquetMetaData;
+use bytes::Bytes;
+use futures::future::BoxFuture;
+use futures::FutureExt;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::ops::Range;
+use std::sync::{Arc, Mutex};
+
+/// Identifies a version of a file within a [`MetadataCache`]
+///
+/// Files are identified by their location, along with an optional version
+/// identifier such as the HTTP `ETag` returned by an object store. As parquet
+/// files are rarely modified in place the version may be omitted, but doing so
+/// risks returning stale metadata should the file at `location` be replaced.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MetadataCacheKey {
+    location: String,
+    e_tag: Option<String>,
+}
+
+impl MetadataCacheKey {
+    /// Create a new [`MetadataCacheKey`] for the file at `location` with the
+    /// version identifier `e_tag`
+    pub fn new(location: impl Into<String>, e_tag: Option<String>) -> Self {
+        Self {
+            location: location.into(),
+            e_tag,
+        }
+    }
+
+    /// Returns the location of the file
+    pub fn location(&self) -> &str {
+        &self.location
+    }
+
+    /// Returns the version identifier of the file, if any
+    pub fn e_tag(&self) -> Option<&str> {
+        self.e_tag.as_deref()
+    }
+}
+
+#[cfg(feature = "object_store")]
+impl From<&object_store::ObjectMeta> for MetadataCacheKey {
+    fn from(meta: &object_store::ObjectMeta) -> Self {
+        Self::new(meta.location.to_string(), meta.e_tag.clone())
+    }
+}
+
+/// A cache of [`ParquetMetaData`] and bloom filters, shared between readers
+///
+/// Reading a parquet file from remote storage typically requires one or more
+/// requests to fetch the footer before any data can be read. When the same
+/// files are read repeatedly, wrapping each reader in a [`CachedAsyncFileReader`]
+/// backed by a shared `MetadataCache` avoids these requests for all but the
+/// first read of each file.
+///
+/// Implementations must be thread safe, and are free to evict entries at any
+/// time. See [`InMemoryMetadataCache`] for a simple unbounded implementation.
+pub trait MetadataCache: Debug + Send + Sync {
+    /// Returns the metadata of the file identified by `key`, if cached
+    fn get_metadata(&self, key: &MetadataCacheKey) -> Option<Arc<ParquetMetaData>>;
+
+    /// Stores the metadata of the file identified by `key`
+    ///
+    /// The metadata includes the page indexes if they were loaded, and replaces
+    /// any metadata previously stored for `key`.
+    fn put_metadata(&self, key: &MetadataCacheKey, metadata: Arc<ParquetMetaData>);
+
+    /// Returns the bloom filter of column `column_idx` in row group `row_group_idx`
+    /// of the file identified by `key`, if cached
+    ///
+    /// The default implementation returns `None`.
+    fn get_bloom_filter(
+        &self,
+        key: &MetadataCacheKey,
+        row_group_idx: usize,
+        column_idx: usize,
+    ) -> Option<Sbbf> {
+        let _ = (key, row_group_idx, column_idx);
+        None
+    }
+
+    /// Stores the bloom filter of column `column_idx` in row group `row_group_idx`
+    /// of the file identified by `key`
+    ///
+    /// The default implementation does nothing.
+    fn put_bloom_filter(
+        &self,
+        key: &MetadataCacheKey,
+        row_group_idx: usize,
+        column_idx: usize,
+        bloom_filter: &Sbbf,
+    ) {
+        let _ = (key, row_group_idx, column_idx, bloom_filter);
+    }
+}
+
+/// A [`MetadataCache`] that keeps all entries in memory
+///
+/// Entries are never evicted, and so this is only suitable for caching a bounded
+/// set of files. Implement [`MetadataCache`] to integrate with a cache that
+/// supports eviction.
+#[derive(Debug, Default)]
+pub struct InMemoryMetadataCache {
+    metadata: Mutex<HashMap<MetadataCacheKey, Arc<ParquetMetaData>>>,
+    bloom_filters: Mutex<HashMap<(MetadataCacheKey, usize, usize), Sbbf>>,
+}
+
+impl InMemoryMetadataCache {
+    /// Create a new, empty, [`InMemoryMetadataCache`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl MetadataCache for InMemoryMetadataCache {
+    fn get_metadata(&self, key: &MetadataCacheKey) -> Option<Arc<ParquetMetaData>> {
+        self.metadata.lock().unwrap().get(key).cloned()
+    }
+
+    fn put_metadata(&self, key: &MetadataCacheKey, metadata: Arc<ParquetMetaData>) {
+        self.metadata.lock().unwrap().insert(key.clone(), metadata);
+    }
+
+    fn get_bloom_filter(
+        &self,
+        key: &MetadataCacheKey,
+        row_group_idx: usize,
+        column_idx: usize,
+    ) -> Option<Sbbf> {
+        let bloom_filters = self.bloom_filters.lock().unwrap();
+        bloom_filters
+            .get(&(key.clone(), row_group_idx, column_idx))
+            .cloned()
+    }
+
+    fn put_bloom_filter(
+        &self,
+        key: &MetadataCacheKey,
+        row_group_idx: usize,
+        column_idx: usize,
+        bloom_filter: &Sbbf,
+    ) {
+        let mut bloom_filters = self.bloom_filters.lock().unwrap();
+        bloom_filters.insert(
+            (key.clone(), row_group_idx, column_idx),
+            bloom_filter.clone(),
+        );
+    }
+}
+
+/// An [`AsyncFileReader`] that consults a [`MetadataCache`] before reading the
+/// metadata and bloom filters of a file from the wrapped reader
+///
+/// Cached metadata without page indexes is not used when they are requested by
+/// [`ArrowReaderOptions::with_page_index`], instead the metadata is read again
+/// and the cached entry replaced.
+///
+/// # Example
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{Int32Array, RecordBatch};
+/// # use parquet::arrow::ArrowWriter;
+/// # use parquet::arrow::ParquetRecordBatchStreamBuilder;
+/// # use parquet::arrow::async_reader::{
+/// #     CachedAsyncFileReader, InMemoryMetadataCache, MetadataCache, MetadataCacheKey,
+/// # };
+/// # #[tokio::main(flavor="current_thread")]
+/// # async fn main() {
+/// # let batch = RecordBatch::try_from_iter([
+/// #     ("a", Arc::new(Int32Array::from(vec![1, 2, 3])) as _),
+/// # ]).unwrap();
+/// # let mut file = vec![];
+/// # let mut writer = ArrowWriter::try_new(&mut file, batch.schema(), None).unwrap();
+/// # writer.write(&batch).unwrap();
+/// # writer.close().unwrap();
+/// # let file = std::io::Cursor::new(file);
+/// let cache: Arc<dyn MetadataCache> = Arc::new(InMemoryMetadataCache::new());
+/// let key = MetadataCacheKey::new("data/file.parquet", Some("v1".to_string()));
+///
+/// let reader = CachedAsyncFileReader::new(file, Arc::clone(&cache), key.clone());
+/// let builder = ParquetRecordBatchStreamBuilder::new(reader).await.unwrap();
+///
+/// // Subsequent reads of the file with the same key use the cached metadata
+/// assert!(cache.get_metadata(&key).is_some());
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct CachedAsyncFileReader<R> {
+    inner: R,
+    cache: Arc<dyn MetadataCache>,
+    key: MetadataCacheKey,
+}
+
+impl<R: AsyncFileReader> CachedAsyncFileReader<R> {
+    /// Create a new [`CachedAsyncFileReader`] reading the file identified by `key`
+    /// from `inner`
+    pub fn new(inner: R, cache: Arc<dyn MetadataCache>, key: MetadataCacheKey) -> Self {
+        Self { inner, cache, key }
+    }
+
+    /// Returns the key identifying the file in the [`MetadataCache`]
+    pub fn key(&self) -> &MetadataCacheKey {
+        &self.key
+    }
+
+    /// Returns the underlying reader
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: AsyncFileReader> AsyncFileReader for CachedAsyncFileReader<R> {
+    fn get_bytes(&mut self, range: Range<u64>) -> BoxFuture<'_, Result<Bytes>> {
+        self.inner.get_bytes(range)
+    }
+
+    fn get_byte_ranges(&mut self, ranges: Vec<Range<u64>>) -> BoxFuture<'_, Result<Vec<Bytes>>> {
+        self.inner.get_byte_ranges(ranges)
+    }
+
+    fn get_metadata<'a>(
+        &'a mut self,
+        options: Option<&'a ArrowReaderOptions>,
+    ) -> BoxFuture<'a, Result<Arc<ParquetMetaData>>> {
+        async move {
+            let page_index = options.is_some_and(|o| o.page_index);
+            if let Some(metadata) = self.cache.get_metadata(&self.key) {
+                let has_page_index =
+                    metadata.column_index().is_some() && metadata.offset_index().is_some();
+                if has_page_index || !page_index {
+                    return Ok(metadata);
+                }
+            }
+
+            let metadata = self.inner.get_metadata(options).await?;
+            self.cache.put_metadata(&self.key, Arc::clone(&metadata));
+            Ok(metadata)
+        }
+        .boxed()
+    }
+
+    fn get_cached_bloom_filter(&self, row_group_idx: usize, column_idx: usize) -> Option<Sbbf> {
+        self.cache
+            .get_bloom_filter(&self.key, row_group_idx, column_idx)
+    }
+
+    fn put_cached_bloom_filter(
+        &self,
+        row_group_idx: usize,
+        column_idx: usize,
+        bloom_filter: &Sbbf,
+    ) {
+        self.cache
+            .put_bloom_filter(&self.key, row_group_idx, column_idx, bloom_filter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrow::async_reader::ParquetRecordBatchStreamBuilder;
+    use crate::arrow::ArrowWriter;
+    use crate::file::metadata::ParquetMetaDataReader;
+    use crate::file::properties::WriterProperties;
+    use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+
+    #[derive(Debug)]
+    struct CountingReader {
+        data: Bytes,
+        metadata_reads: Arc<Mutex<usize>>,
+        byte_reads: Arc<Mutex<usize>>,
+    }
+
+    impl AsyncFileReader for CountingReader {
+        fn get_bytes(&mut self, range: Range<u64>) -> BoxFuture<'_, Result<Bytes>> {
+            *self.byte_reads.lock().unwrap() += 1;
+            let bytes = self.data.slice(range.start as usize..range.end as usize);
+            futures::future::ready(Ok(bytes)).boxed()
+        }
+
+        fn get_metadata<'a>(
+            &'a mut self,
+            options: Option<&'a ArrowReaderOptions>,
+        ) -> BoxFuture<'a, Result<Arc<ParquetMetaData>>> {
+            *self.metadata_reads.lock().unwrap() += 1;
+            let metadata = ParquetMetaDataReader::new()
+                .with_page_indexes(options.is_some_and(|o| o.page_index))
+                .parse_and_finish(&self.data)
+                .map(Arc::new);
+            futures::future::ready(metadata).boxed()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cached_async_file_reader() {
+        let batch = RecordBatch::try_from_iter([(
+            "a",
+            Arc::new(Int32Array::from_iter_values(0..100)) as ArrayRef,
+        )])
+        .unwrap();
+        let props = WriterProperties::builder()
+            .set_bloom_filter_enabled(true)
+            .build();
+        let mut buf = vec![];
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        let metadata_reads = Arc::new(Mutex::new(0));
+        let byte_reads = Arc::new(Mutex::new(0));
+        let cache: Arc<dyn MetadataCache> = Arc::new(InMemoryMetadataCache::new());
+        let reader = |e_tag: &str| {
+            let inner = CountingReader {
+                data: data.clone(),
+                metadata_reads: Arc::clone(&metadata_reads),
+                byte_reads: Arc::clone(&byte_reads),
+            };
+            let key = MetadataCacheKey::new("file.parquet", Some(e_tag.to_string()));
+            CachedAsyncFileReader::new(inner, Arc::clone(&cache), key)
+        };
+
+        let mut builder = ParquetRecordBatchStreamBuilder::new(reader("1"))
+            .await
+            .unwrap();
+        let sbbf = builder
+            .get_row_group_column_bloom_filter(0, 0)
+            .await
+            .unwrap();
+        assert!(sbbf.unwrap().check(&42));
+        assert_eq!(*metadata_reads.lock().unwrap(), 1);
+        assert!(*byte_reads.lock().unwrap() > 0);
+
+        // Same version is served from the cache
+        *byte_reads.lock().unwrap() = 0;
+        let mut builder = ParquetRecordBatchStreamBuilder::new(reader("1"))
+            .await
+            .unwrap();
+        let sbbf = builder
+            .get_row_group_column_bloom_filter(0, 0)
+            .await
+            .unwrap();
+        assert!(sbbf.unwrap().check(&42));
+        assert_eq!(*metadata_reads.lock().unwrap(), 1);
+        assert_eq!(*byte_reads.lock().unwrap(), 0);
+
+        // Cached metadata lacks the page index
+        let options = ArrowReaderOptions::new().with_page_index(true);
+        let builder = ParquetRecordBatchStreamBuilder::new_with_options(reader("1"), options)
+            .await
+            .unwrap();
+        assert!(builder.metadata().offset_index().is_some());
+        assert_eq!(*metadata_reads.lock().unwrap(), 2);
+
+        // Which is then cached for subsequent reads
+        let cached = cache.get_metadata(&MetadataCacheKey::new("file.parquet", Some("1".into())));
+        assert!(cached.unwrap().offset_index().is_some());
+        ParquetRecordBatchStreamBuilder::new(reader("1"))
+            .await
+            .unwrap();
+        assert_eq!(*metadata_reads.lock().unwrap(), 2);
+
+        // A different version is not
+        ParquetRecordBatchStreamBuilder::new(reader("2"))
+            .await
+            .unwrap();
+        assert_eq!(*metadata_reads.lock().unwrap(), 3);
+    }
+}
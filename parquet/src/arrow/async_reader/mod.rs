@@ -38,10 +38,12 @@ use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt};
 use arrow_array::RecordBatch;
 use arrow_schema::{DataType, Fields, Schema, SchemaRef};
 
-use crate::arrow::array_reader::{ArrayReaderBuilder, RowGroups};
+use crate::arrow::array_reader::{ArrayReaderBuilder, DictionaryCache, PredicateCache, RowGroups};
+use crate::arrow::arrow_reader::filter::PredicateTimer;
+use crate::arrow::arrow_reader::metrics::{start_timer, MeteredPageReader, Phase};
 use crate::arrow::arrow_reader::{
     ArrowReaderBuilder, ArrowReaderMetadata, ArrowReaderOptions, ParquetRecordBatchReader,
-    RowFilter, RowSelection,
+    ReadMetrics, RowFilter, RowSelection,
 };
 use crate::arrow::ProjectionMask;
 
@@ -58,10 +60,16 @@ use crate::format::{BloomFilterAlgorithm, BloomFilterCompression, BloomFilterHas
 mod metadata;
 pub use metadata::*;
 
+mod prefetch;
+pub use prefetch::{OnDemandPrefetch, PrefetchPolicy, ReadAheadPrefetch};
+
+mod cache;
+pub use cache::{CachedAsyncFileReader, InMemoryMetadataCache, MetadataCache, MetadataCacheKey};
+
 #[cfg(feature = "object_store")]
 mod store;
 
-use crate::arrow::arrow_reader::ReadPlanBuilder;
+use crate::arrow::arrow_reader::{Int96Overflow, ReadPlanBuilder, SchemaAdapter};
 use crate::arrow::schema::ParquetField;
 #[cfg(feature = "object_store")]
 pub use store::*;
@@ -118,6 +126,29 @@ pub trait AsyncFileReader: Send {
         &'a mut self,
         options: Option<&'a ArrowReaderOptions>,
     ) -> BoxFuture<'a, Result<Arc<ParquetMetaData>>>;
+
+    /// Returns a previously cached bloom filter for column `column_idx` in row group
+    /// `row_group_idx`, if any, avoiding the need to read it from the file
+    ///
+    /// See [`CachedAsyncFileReader`]. The default implementation returns `None`.
+    fn get_cached_bloom_filter(&self, row_group_idx: usize, column_idx: usize) -> Option<Sbbf> {
+        let _ = (row_group_idx, column_idx);
+        None
+    }
+
+    /// Called with each bloom filter read from the file by
+    /// [`ParquetRecordBatchStreamBuilder::get_row_group_column_bloom_filter`], allowing
+    /// it to be returned by subsequent calls to [`Self::get_cached_bloom_filter`]
+    ///
+    /// The default implementation does nothing.
+    fn put_cached_bloom_filter(
+        &self,
+        row_group_idx: usize,
+        column_idx: usize,
+        bloom_filter: &Sbbf,
+    ) {
+        let _ = (row_group_idx, column_idx, bloom_filter);
+    }
 }
 
 /// This allows Box<dyn AsyncFileReader + '_> to be used as an AsyncFileReader,
@@ -136,6 +167,21 @@ impl AsyncFileReader for Box<dyn AsyncFileReader + '_> {
     ) -> BoxFuture<'a, Result<Arc<ParquetMetaData>>> {
         self.as_mut().get_metadata(options)
     }
+
+    fn get_cached_bloom_filter(&self, row_group_idx: usize, column_idx: usize) -> Option<Sbbf> {
+        self.as_ref()
+            .get_cached_bloom_filter(row_group_idx, column_idx)
+    }
+
+    fn put_cached_bloom_filter(
+        &self,
+        row_group_idx: usize,
+        column_idx: usize,
+        bloom_filter: &Sbbf,
+    ) {
+        self.as_ref()
+            .put_cached_bloom_filter(row_group_idx, column_idx, bloom_filter)
+    }
 }
 
 impl<T: AsyncFileReader + MetadataFetch + AsyncRead + AsyncSeek + Unpin> MetadataSuffixFetch for T {
@@ -205,7 +251,10 @@ impl ArrowReaderMetadata {
 ///
 /// Allows sharing the same builder for both the sync and async versions, whilst also not
 /// breaking the pre-existing ParquetRecordBatchStreamBuilder API
-pub struct AsyncReader<T>(T);
+pub struct AsyncReader<T> {
+    input: T,
+    prefetch: Arc<dyn PrefetchPolicy>,
+}
 
 /// A builder for reading parquet files from an `async` source as  [`ParquetRecordBatchStream`]
 ///
@@ -409,7 +458,26 @@ impl<T: AsyncFileReader + Send + 'static> ParquetRecordBatchStreamBuilder<T> {
     /// # }
     /// ```
     pub fn new_with_metadata(input: T, metadata: ArrowReaderMetadata) -> Self {
-        Self::new_builder(AsyncReader(input), metadata)
+        let input = AsyncReader {
+            input,
+            prefetch: Arc::new(OnDemandPrefetch),
+        };
+        Self::new_builder(input, metadata)
+    }
+
+    /// Set the [`PrefetchPolicy`] controlling when, and how, data is fetched from the
+    /// input (defaults to [`OnDemandPrefetch`])
+    ///
+    /// See [`ReadAheadPrefetch`] to read the next row group while the current one is
+    /// decoded, reducing the time spent waiting on high latency storage.
+    pub fn with_prefetch_policy(self, prefetch: Arc<dyn PrefetchPolicy>) -> Self {
+        Self {
+            input: AsyncReader {
+                prefetch,
+                ..self.input
+            },
+            ..self
+        }
     }
 
     /// Read bloom filter for a column in a row group
@@ -417,11 +485,22 @@ impl<T: AsyncFileReader + Send + 'static> ParquetRecordBatchStreamBuilder<T> {
     /// Returns `None` if the column does not have a bloom filter
     ///
     /// We should call this function after other forms pruning, such as projection and predicate pushdown.
+    ///
+    /// Bloom filters cached by the [`AsyncFileReader`], see [`CachedAsyncFileReader`],
+    /// are returned without reading from the file.
     pub async fn get_row_group_column_bloom_filter(
         &mut self,
         row_group_idx: usize,
         column_idx: usize,
     ) -> Result<Option<Sbbf>> {
+        if let Some(sbbf) = self
+            .input
+            .input
+            .get_cached_bloom_filter(row_group_idx, column_idx)
+        {
+            return Ok(Some(sbbf));
+        }
+
         let metadata = self.metadata.row_group(row_group_idx);
         let column_metadata = metadata.column(column_idx);
 
@@ -434,10 +513,10 @@ impl<T: AsyncFileReader + Send + 'static> ParquetRecordBatchStreamBuilder<T> {
         };
 
         let buffer = match column_metadata.bloom_filter_length() {
-            Some(length) => self.input.0.get_bytes(offset..offset + length as u64),
+            Some(length) => self.input.input.get_bytes(offset..offset + length as u64),
             None => self
                 .input
-                .0
+                .input
                 .get_bytes(offset..offset + SBBF_HEADER_SIZE_ESTIMATE as u64),
         }
         .await?;
@@ -471,12 +550,16 @@ impl<T: AsyncFileReader + Send + 'static> ParquetRecordBatchStreamBuilder<T> {
                     ParquetError::General("Bloom filter length is invalid".to_string())
                 })?;
                 self.input
-                    .0
+                    .input
                     .get_bytes(bitset_offset..bitset_offset + bitset_length)
                     .await?
             }
         };
-        Ok(Some(Sbbf::new(&bitset)))
+        let sbbf = Sbbf::new(&bitset);
+        self.input
+            .input
+            .put_cached_bloom_filter(row_group_idx, column_idx, &sbbf);
+        Ok(Some(sbbf))
     }
 
     /// Build a new [`ParquetRecordBatchStream`]
@@ -504,34 +587,52 @@ impl<T: AsyncFileReader + Send + 'static> ParquetRecordBatchStreamBuilder<T> {
             .batch_size
             .min(self.metadata.file_metadata().num_rows() as usize);
         let reader_factory = ReaderFactory {
-            input: self.input.0,
+            input: self.input.input,
+            prefetch: self.input.prefetch,
             filter: self.filter,
             metadata: self.metadata.clone(),
             fields: self.fields,
             limit: self.limit,
             offset: self.offset,
+            metrics: self.metrics,
+            schema_adapter: self.schema_adapter.clone(),
+            dictionary_cache: self.dictionary_reuse.then(DictionaryCache::default),
+            parallelism: self.parallelism,
+            int96_overflow: self.int96_overflow,
         };
 
-        // Ensure schema of ParquetRecordBatchStream respects projection, and does
-        // not store metadata (same as for ParquetRecordBatchReader and emitted RecordBatches)
-        let projected_fields = match reader_factory.fields.as_deref().map(|pf| &pf.arrow_type) {
-            Some(DataType::Struct(fields)) => {
-                fields.filter_leaves(|idx, _| self.projection.leaf_included(idx))
+        let mut projection = self.projection;
+        let schema = match &self.schema_adapter {
+            Some(adapter) => {
+                projection
+                    .intersect(&adapter.projection(self.metadata.file_metadata().schema_descr()));
+                Arc::clone(adapter.schema())
+            }
+            None => {
+                // Ensure schema of ParquetRecordBatchStream respects projection, and does
+                // not store metadata (same as for ParquetRecordBatchReader and emitted RecordBatches)
+                let projected_fields =
+                    match reader_factory.fields.as_deref().map(|pf| &pf.arrow_type) {
+                        Some(DataType::Struct(fields)) => {
+                            fields.filter_leaves(|idx, _| projection.leaf_included(idx))
+                        }
+                        None => Fields::empty(),
+                        _ => unreachable!("Must be Struct for root type"),
+                    };
+                Arc::new(Schema::new(projected_fields))
             }
-            None => Fields::empty(),
-            _ => unreachable!("Must be Struct for root type"),
         };
-        let schema = Arc::new(Schema::new(projected_fields));
 
         Ok(ParquetRecordBatchStream {
             metadata: self.metadata,
             batch_size,
             row_groups,
-            projection: self.projection,
+            projection,
             selection: self.selection,
             schema,
             reader_factory: Some(reader_factory),
             state: StreamState::Init,
+            prefetched: None,
         })
     }
 }
@@ -552,6 +653,9 @@ struct ReaderFactory<T> {
 
     input: T,
 
+    /// Controls how data is fetched from `input`
+    prefetch: Arc<dyn PrefetchPolicy>,
+
     /// Optional filter
     filter: Option<RowFilter>,
 
@@ -560,6 +664,28 @@ struct ReaderFactory<T> {
 
     /// Offset to apply to the next
     offset: Option<usize>,
+
+    /// Optional metrics to record the work performed into
+    metrics: Option<ReadMetrics>,
+
+    /// Optional conversion of the decoded batches to a target schema
+    schema_adapter: Option<Arc<SchemaAdapter>>,
+
+    /// Optional dictionaries to reuse across row groups
+    dictionary_cache: Option<DictionaryCache>,
+
+    /// The number of threads used to decode columns
+    parallelism: usize,
+
+    /// How INT96 timestamps out of range for their arrow type are handled
+    int96_overflow: Int96Overflow,
+}
+
+impl<T> ReaderFactory<T> {
+    /// Returns true if the limit has been reached, and so no further rows will be read
+    fn limit_reached(&self) -> bool {
+        self.limit == Some(0)
+    }
 }
 
 impl<T> ReaderFactory<T>
@@ -580,6 +706,10 @@ where
     ) -> ReadResult<T> {
         // TODO: calling build_array multiple times is wasteful
 
+        if self.limit_reached() {
+            return Ok((self, None));
+        }
+
         let meta = self.metadata.row_group(row_group_idx);
         let offset_index = self
             .metadata
@@ -595,28 +725,77 @@ where
             offset_index,
             row_group_idx,
             metadata: self.metadata.as_ref(),
+            metrics: self.metrics.as_ref(),
+            prefetch: self.prefetch.as_ref(),
         };
 
         let filter = self.filter.as_mut();
         let mut plan_builder = ReadPlanBuilder::new(batch_size).with_selection(selection);
+        let mut predicate_cache = PredicateCache::default();
 
         // Update selection based on any filters
         if let Some(filter) = filter {
-            for predicate in filter.predicates.iter_mut() {
+            let order = filter.evaluation_order();
+            let last = order.last().copied();
+            for idx in order {
                 if !plan_builder.selects_any() {
-                    return Ok((self, None)); // ruled out entire row group
+                    break;
                 }
 
+                let rows_in = plan_builder
+                    .num_rows_selected()
+                    .unwrap_or(row_group.row_count);
+                let predicate_timer = PredicateTimer::start();
+                let predicate = filter.predicates[idx].as_mut();
+
                 // (pre) Fetch only the columns that are selected by the predicate
                 let selection = plan_builder.selection();
                 row_group
                     .fetch(&mut self.input, predicate.projection(), selection)
                     .await?;
 
+                let _timer = start_timer(self.metrics.as_ref(), Phase::Filter);
                 let array_reader = ArrayReaderBuilder::new(&row_group)
+                    .with_dictionary_cache(self.dictionary_cache.clone())
+                    .with_parallelism(self.parallelism)
+                    .with_int96_overflow(self.int96_overflow)
                     .build_array_reader(self.fields.as_deref(), predicate.projection())?;
 
-                plan_builder = plan_builder.with_predicate(array_reader, predicate.as_mut())?;
+                // Stop evaluating the final predicate once enough rows are selected
+                let limit = (Some(idx) == last)
+                    .then(|| {
+                        self.limit
+                            .map(|l| l.saturating_add(self.offset.unwrap_or(0)))
+                    })
+                    .flatten();
+
+                // Only predicates that decode all their selected rows can be cached
+                let cache_selection = (filter.predicate_cache && limit.is_none())
+                    .then(|| plan_builder.selection().cloned());
+                let mut decoded = vec![];
+                plan_builder = plan_builder.with_predicate(
+                    array_reader,
+                    predicate,
+                    limit,
+                    cache_selection.is_some().then_some(&mut decoded),
+                )?;
+                if let (Some(selection), Some(fields)) = (cache_selection, &self.fields) {
+                    predicate_cache.insert(
+                        fields,
+                        predicate.projection(),
+                        selection.as_ref(),
+                        row_group.row_count,
+                        &decoded,
+                    )?;
+                }
+
+                // The selectivity is unknown if evaluation stopped early
+                if limit.is_none() {
+                    let rows_out = plan_builder
+                        .num_rows_selected()
+                        .unwrap_or(row_group.row_count);
+                    filter.observe(idx, rows_in, rows_out, predicate_timer.elapsed_nanos());
+                }
             }
         }
 
@@ -625,6 +804,10 @@ where
             .num_rows_selected()
             .unwrap_or(row_group.row_count);
 
+        if let Some(metrics) = &self.metrics {
+            metrics.add_rows(row_group.row_count, rows_before);
+        }
+
         if rows_before == 0 {
             return Ok((self, None)); // ruled out entire row group
         }
@@ -662,9 +845,15 @@ where
         let plan = plan_builder.build();
 
         let array_reader = ArrayReaderBuilder::new(&row_group)
+            .with_dictionary_cache(self.dictionary_cache.clone())
+            .with_predicate_cache(&predicate_cache)
+            .with_parallelism(self.parallelism)
+            .with_int96_overflow(self.int96_overflow)
             .build_array_reader(self.fields.as_deref(), &projection)?;
 
-        let reader = ParquetRecordBatchReader::new(array_reader, plan);
+        let reader = ParquetRecordBatchReader::new(array_reader, plan)
+            .with_metrics(self.metrics.clone())
+            .with_schema_adapter(self.schema_adapter.clone());
 
         Ok((self, Some(reader)))
     }
@@ -707,6 +896,9 @@ impl<T> std::fmt::Debug for StreamState<T> {
 /// required, which is especially important for object stores, where IO operations
 /// have latencies in the hundreds of milliseconds
 ///
+/// By default, the next row group is only read once all the batches of the current row
+/// group have been returned. A [`PrefetchPolicy`], such as [`ReadAheadPrefetch`], can
+/// instead read it while the current row group is decoded, at the cost of buffering both.
 ///
 /// [`Stream`]: https://docs.rs/futures/latest/futures/stream/trait.Stream.html
 pub struct ParquetRecordBatchStream<T> {
@@ -726,6 +918,9 @@ pub struct ParquetRecordBatchStream<T> {
     reader_factory: Option<ReaderFactory<T>>,
 
     state: StreamState<T>,
+
+    /// The read of the next row group, if started early by the [`PrefetchPolicy`]
+    prefetched: Option<BoxFuture<'static, ReadResult<T>>>,
 }
 
 impl<T> std::fmt::Debug for ParquetRecordBatchStream<T> {
@@ -776,6 +971,12 @@ where
                     ))
                 }
                 StreamState::Init => {
+                    let reader_factory = self.reader_factory.as_ref().expect("lost reader factory");
+                    if reader_factory.limit_reached() {
+                        self.row_groups.clear();
+                        return Ok(None);
+                    }
+
                     let row_group_idx = match self.row_groups.pop_front() {
                         Some(idx) => idx,
                         None => return Ok(None),
@@ -813,6 +1014,63 @@ where
     }
 }
 
+impl<T> ParquetRecordBatchStream<T>
+where
+    T: AsyncFileReader + Unpin + Send + 'static,
+{
+    /// Returns a future reading the next row group, or `None` if there are no more
+    fn read_next_row_group(&mut self) -> Option<BoxFuture<'static, ReadResult<T>>> {
+        let reader = self.reader_factory.as_ref().expect("lost reader factory");
+        if reader.limit_reached() {
+            self.row_groups.clear();
+            return None;
+        }
+
+        let row_group_idx = self.row_groups.pop_front()?;
+
+        let reader = self.reader_factory.take().expect("lost reader factory");
+
+        let row_count = self.metadata.row_group(row_group_idx).num_rows() as usize;
+
+        let selection = self.selection.as_mut().map(|s| s.split_off(row_count));
+
+        let fut = reader
+            .read_row_group(
+                row_group_idx,
+                selection,
+                self.projection.clone(),
+                self.batch_size,
+            )
+            .boxed();
+        Some(fut)
+    }
+
+    /// Starts reading the next row group if the [`PrefetchPolicy`] says to
+    fn start_prefetch(&mut self) {
+        let Some(next) = self.row_groups.front() else {
+            return;
+        };
+        let reader_factory = self.reader_factory.as_ref().expect("lost reader factory");
+        if reader_factory
+            .prefetch
+            .prefetch_next_row_group(self.metadata.row_group(*next))
+        {
+            self.prefetched = self.read_next_row_group();
+        }
+    }
+
+    /// Makes progress reading the prefetched row group, if any
+    ///
+    /// Once complete, the result is kept until the current row group has been decoded.
+    fn poll_prefetched(&mut self, cx: &mut Context<'_>) {
+        if let Some(fut) = self.prefetched.as_mut() {
+            if let Poll::Ready(result) = fut.poll_unpin(cx) {
+                self.prefetched = Some(futures::future::ready(result).boxed());
+            }
+        }
+    }
+}
+
 impl<T> Stream for ParquetRecordBatchStream<T>
 where
     T: AsyncFileReader + Unpin + Send + 'static,
@@ -824,43 +1082,33 @@ where
             match &mut self.state {
                 StreamState::Decoding(batch_reader) => match batch_reader.next() {
                     Some(Ok(batch)) => {
+                        self.poll_prefetched(cx);
                         return Poll::Ready(Some(Ok(batch)));
                     }
                     Some(Err(e)) => {
                         self.state = StreamState::Error;
                         return Poll::Ready(Some(Err(ParquetError::ArrowError(e.to_string()))));
                     }
-                    None => self.state = StreamState::Init,
+                    None => {
+                        self.state = match self.prefetched.take() {
+                            Some(fut) => StreamState::Reading(fut),
+                            None => StreamState::Init,
+                        }
+                    }
+                },
+                StreamState::Init => match self.read_next_row_group() {
+                    Some(fut) => self.state = StreamState::Reading(fut),
+                    None => return Poll::Ready(None),
                 },
-                StreamState::Init => {
-                    let row_group_idx = match self.row_groups.pop_front() {
-                        Some(idx) => idx,
-                        None => return Poll::Ready(None),
-                    };
-
-                    let reader = self.reader_factory.take().expect("lost reader factory");
-
-                    let row_count = self.metadata.row_group(row_group_idx).num_rows() as usize;
-
-                    let selection = self.selection.as_mut().map(|s| s.split_off(row_count));
-
-                    let fut = reader
-                        .read_row_group(
-                            row_group_idx,
-                            selection,
-                            self.projection.clone(),
-                            self.batch_size,
-                        )
-                        .boxed();
-
-                    self.state = StreamState::Reading(fut)
-                }
                 StreamState::Reading(f) => match ready!(f.poll_unpin(cx)) {
                     Ok((reader_factory, maybe_reader)) => {
                         self.reader_factory = Some(reader_factory);
                         match maybe_reader {
                             // Read records from [`ParquetRecordBatchReader`]
-                            Some(reader) => self.state = StreamState::Decoding(reader),
+                            Some(reader) => {
+                                self.state = StreamState::Decoding(reader);
+                                self.start_prefetch();
+                            }
                             // All rows skipped, read next row group
                             None => self.state = StreamState::Init,
                         }
@@ -884,6 +1132,8 @@ struct InMemoryRowGroup<'a> {
     row_count: usize,
     row_group_idx: usize,
     metadata: &'a ParquetMetaData,
+    metrics: Option<&'a ReadMetrics>,
+    prefetch: &'a dyn PrefetchPolicy,
 }
 
 impl InMemoryRowGroup<'_> {
@@ -931,7 +1181,7 @@ impl InMemoryRowGroup<'_> {
                 })
                 .collect();
 
-            let mut chunk_data = input.get_byte_ranges(fetch_ranges).await?.into_iter();
+            let mut chunk_data = self.fetch_ranges(input, fetch_ranges).await?.into_iter();
             let mut page_start_offsets = page_start_offsets.into_iter();
 
             for (idx, chunk) in self.column_chunks.iter_mut().enumerate() {
@@ -968,7 +1218,7 @@ impl InMemoryRowGroup<'_> {
                 })
                 .collect();
 
-            let mut chunk_data = input.get_byte_ranges(fetch_ranges).await?.into_iter();
+            let mut chunk_data = self.fetch_ranges(input, fetch_ranges).await?.into_iter();
 
             for (idx, chunk) in self.column_chunks.iter_mut().enumerate() {
                 if chunk.is_some() || !projection.leaf_included(idx) {
@@ -986,6 +1236,44 @@ impl InMemoryRowGroup<'_> {
 
         Ok(())
     }
+
+    /// Fetches `ranges` from `input`, in the requests returned by
+    /// [`PrefetchPolicy::coalesce_ranges`], recording the bytes fetched and time spent
+    async fn fetch_ranges<T: AsyncFileReader + Send>(
+        &self,
+        input: &mut T,
+        ranges: Vec<Range<u64>>,
+    ) -> Result<Vec<Bytes>> {
+        let _timer = start_timer(self.metrics, Phase::Fetch);
+        let requests = self.prefetch.coalesce_ranges(&ranges);
+        let coalesced = requests != ranges;
+        let fetched = input.get_byte_ranges(requests.clone()).await?;
+        if let Some(metrics) = self.metrics {
+            metrics.add_bytes_fetched(fetched.iter().map(|b| b.len() as u64).sum());
+        }
+        if !coalesced {
+            return Ok(fetched);
+        }
+
+        ranges
+            .iter()
+            .map(|range| {
+                let (request, data) = requests
+                    .iter()
+                    .zip(&fetched)
+                    .find(|(request, _)| request.start <= range.start && range.end <= request.end)
+                    .ok_or_else(|| {
+                        general_err!(
+                            "Coalesced ranges do not contain range {}..{}",
+                            range.start,
+                            range.end
+                        )
+                    })?;
+                let start = (range.start - request.start) as usize;
+                Ok(data.slice(start..start + (range.end - range.start) as usize))
+            })
+            .collect()
+    }
 }
 
 impl RowGroups for InMemoryRowGroup<'_> {
@@ -1019,7 +1307,7 @@ impl RowGroups for InMemoryRowGroup<'_> {
                     column_chunk_metadata,
                 )?;
 
-                let page_reader: Box<dyn PageReader> = Box::new(page_reader);
+                let page_reader = MeteredPageReader::wrap(Box::new(page_reader), self.metrics);
 
                 Ok(Box::new(ColumnChunkIterator {
                     reader: Some(Ok(page_reader)),
@@ -1107,7 +1395,7 @@ impl PageIterator for ColumnChunkIterator {}
 mod tests {
     use super::*;
     use crate::arrow::arrow_reader::{
-        ArrowPredicateFn, ParquetRecordBatchReaderBuilder, RowSelector,
+        ArrowPredicateFn, ParquetRecordBatchReaderBuilder, ReadMetrics, RowSelector,
     };
     use crate::arrow::arrow_reader::{ArrowReaderMetadata, ArrowReaderOptions};
     use crate::arrow::schema::parquet_to_arrow_schema_and_fields;
@@ -1118,15 +1406,16 @@ mod tests {
     use arrow::error::Result as ArrowResult;
     use arrow_array::builder::{ListBuilder, StringBuilder};
     use arrow_array::cast::AsArray;
-    use arrow_array::types::Int32Type;
+    use arrow_array::types::{Int32Type, Int64Type};
     use arrow_array::{
-        Array, ArrayRef, Int32Array, Int8Array, RecordBatchReader, Scalar, StringArray,
-        StructArray, UInt64Array,
+        Array, ArrayRef, BooleanArray, Int32Array, Int8Array, RecordBatchReader, Scalar,
+        StringArray, StructArray, UInt64Array,
     };
     use arrow_schema::{DataType, Field, Schema};
     use futures::{StreamExt, TryStreamExt};
     use rand::{rng, Rng};
     use std::collections::HashMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
     use std::sync::{Arc, Mutex};
     use tempfile::tempfile;
 
@@ -1832,6 +2121,455 @@ mod tests {
         assert_eq!(total_rows, 730);
     }
 
+    #[tokio::test]
+    async fn test_row_filter_adaptive_ordering() {
+        let batch =
+            RecordBatch::try_from_iter([("a", Arc::new(Int32Array::from_iter_values(0..30)) as _)])
+                .unwrap();
+
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(10)
+            .build();
+        let mut buffer = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buffer, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buffer);
+
+        async fn evaluated(data: Bytes, adaptive: bool) -> Vec<&'static str> {
+            let log = Arc::new(Mutex::new(vec![]));
+
+            let captured = log.clone();
+            let keep_all = ArrowPredicateFn::new(ProjectionMask::all(), move |batch| {
+                captured.lock().unwrap().push("keep_all");
+                Ok(BooleanArray::from(vec![true; batch.num_rows()]))
+            });
+            let captured = log.clone();
+            let keep_none = ArrowPredicateFn::new(ProjectionMask::all(), move |batch| {
+                captured.lock().unwrap().push("keep_none");
+                Ok(BooleanArray::from(vec![false; batch.num_rows()]))
+            });
+            let filter = RowFilter::new(vec![Box::new(keep_all), Box::new(keep_none)])
+                .with_adaptive_ordering(adaptive);
+
+            let stream = ParquetRecordBatchStreamBuilder::new(TestReader::new(data))
+                .await
+                .unwrap()
+                .with_row_filter(filter)
+                .build()
+                .unwrap();
+            let batches: Vec<_> = stream.try_collect().await.unwrap();
+            assert!(batches.is_empty());
+
+            let log = log.lock().unwrap().clone();
+            log
+        }
+
+        let expected = ["keep_all", "keep_none"].repeat(3);
+        assert_eq!(evaluated(data.clone(), false).await, expected);
+
+        // After the first row group `keep_none` is evaluated first, and `keep_all` is skipped
+        let expected = ["keep_all", "keep_none", "keep_none", "keep_none"];
+        assert_eq!(evaluated(data, true).await, expected);
+    }
+
+    #[tokio::test]
+    async fn test_row_filter_predicate_cache() {
+        let a = Int32Array::from_iter_values(0..100);
+        let b = StringArray::from_iter_values((0..100).map(|i| format!("v{i}")));
+        let batch =
+            RecordBatch::try_from_iter([("a", Arc::new(a) as _), ("b", Arc::new(b) as _)]).unwrap();
+
+        // Write 2 row groups of 5 pages of 10 rows for each column
+        let props = WriterProperties::builder()
+            .set_dictionary_enabled(false)
+            .set_data_page_row_count_limit(10)
+            .set_write_batch_size(10)
+            .set_max_row_group_size(50)
+            .build();
+        let mut buffer = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buffer, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buffer);
+
+        async fn read(data: Bytes, predicate_cache: bool) -> (Vec<RecordBatch>, u64) {
+            let builder = ParquetRecordBatchStreamBuilder::new(TestReader::new(data))
+                .await
+                .unwrap();
+            let schema_descr = builder.metadata().file_metadata().schema_descr_ptr();
+
+            let a = ArrowPredicateFn::new(ProjectionMask::leaves(&schema_descr, [0]), |batch| {
+                let a = batch.column(0).as_primitive::<Int32Type>();
+                Ok(a.iter().map(|v| v.map(|v| v % 10 < 3)).collect())
+            });
+            let b = ArrowPredicateFn::new(ProjectionMask::leaves(&schema_descr, [1]), |batch| {
+                let b = batch.column(0).as_string::<i32>();
+                Ok(b.iter().map(|v| v.map(|v| !v.ends_with('1'))).collect())
+            });
+            let filter = RowFilter::new(vec![Box::new(a), Box::new(b)])
+                .with_predicate_cache(predicate_cache);
+
+            let metrics = ReadMetrics::new();
+            let stream = builder
+                .with_row_filter(filter)
+                .with_batch_size(7)
+                .with_metrics(metrics.clone())
+                .build()
+                .unwrap();
+            let batches = stream.try_collect().await.unwrap();
+            (batches, metrics.pages_decoded())
+        }
+
+        let (expected, uncached_pages) = read(data.clone(), false).await;
+        let expected = arrow::compute::concat_batches(&expected[0].schema(), &expected).unwrap();
+        let a = expected.column(0).as_primitive::<Int32Type>();
+        assert_eq!(a.len(), 20);
+        assert!(a.values().iter().all(|v| v % 10 == 0 || v % 10 == 2));
+
+        let (batches, cached_pages) = read(data, true).await;
+        let actual = arrow::compute::concat_batches(&batches[0].schema(), &batches).unwrap();
+        assert_eq!(actual, expected);
+
+        // The columns of both predicates are decoded once, rather than again for the output
+        assert_eq!(uncached_pages, 40);
+        assert_eq!(cached_pages, 20);
+    }
+
+    #[tokio::test]
+    async fn test_async_reader_metrics() {
+        let batch = RecordBatch::try_from_iter([(
+            "a",
+            Arc::new(Int32Array::from_iter_values(0..100)) as _,
+        )])
+        .unwrap();
+
+        // Write 10 pages of 10 rows each
+        let props = WriterProperties::builder()
+            .set_dictionary_enabled(false)
+            .set_data_page_row_count_limit(10)
+            .set_write_batch_size(10)
+            .build();
+
+        let mut buffer = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buffer, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let async_reader = TestReader::new(Bytes::from(buffer));
+        let requests = async_reader.requests.clone();
+
+        let selection = RowSelection::from(vec![
+            RowSelector::skip(50),
+            RowSelector::select(20),
+            RowSelector::skip(30),
+        ]);
+        let filter = ArrowPredicateFn::new(ProjectionMask::all(), |batch| {
+            eq(batch.column(0), &Int32Array::new_scalar(55))
+        });
+
+        let metrics = ReadMetrics::new();
+        let options = ArrowReaderOptions::new().with_page_index(true);
+        let stream = ParquetRecordBatchStreamBuilder::new_with_options(async_reader, options)
+            .await
+            .unwrap()
+            .with_row_selection(selection)
+            .with_row_filter(RowFilter::new(vec![Box::new(filter)]))
+            .with_metrics(metrics.clone())
+            .build()
+            .unwrap();
+
+        let batches: Vec<_> = stream.try_collect().await.unwrap();
+        assert_eq!(batches, vec![batch.slice(55, 1)]);
+
+        let requested: usize = requests.lock().unwrap().iter().map(|r| r.len()).sum();
+        assert_eq!(metrics.bytes_fetched(), requested as u64);
+        assert_eq!(metrics.rows_selected(), 1);
+        assert_eq!(metrics.rows_pruned(), 99);
+        // Two pages are decoded to evaluate the filter, and then one to produce output
+        assert_eq!(metrics.pages_decoded(), 3);
+        assert!(metrics.pages_skipped() > 0);
+    }
+
+    #[tokio::test]
+    async fn test_async_reader_dictionary_reuse() {
+        let values = StringArray::from_iter_values(["a", "b"].iter().cycle().take(30));
+        let data_type = DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8));
+        let dict = arrow::compute::cast(&values, &data_type).unwrap();
+        let batch = RecordBatch::try_from_iter([("d", dict)]).unwrap();
+
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(10)
+            .build();
+        let mut buffer = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buffer, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buffer);
+
+        let stream = ParquetRecordBatchStreamBuilder::new(TestReader::new(data))
+            .await
+            .unwrap()
+            .with_dictionary_reuse(true)
+            .build()
+            .unwrap();
+        let batches: Vec<_> = stream.try_collect().await.unwrap();
+        assert_eq!(batches.len(), 3);
+
+        let dictionaries: Vec<_> = batches
+            .iter()
+            .map(|b| b.column(0).as_any_dictionary().values().to_data())
+            .collect();
+        assert!(dictionaries.iter().all(|d| d.ptr_eq(&dictionaries[0])));
+    }
+
+    #[tokio::test]
+    async fn test_async_reader_parallelism() {
+        let a = Arc::new(Int32Array::from_iter_values(0..100)) as ArrayRef;
+        let b = Arc::new(StringArray::from_iter_values(
+            (0..100).map(|i| format!("{i}")),
+        )) as _;
+        let batch = RecordBatch::try_from_iter([("a", a), ("b", b)]).unwrap();
+
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(30)
+            .build();
+        let mut buffer = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buffer, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buffer);
+
+        let stream = ParquetRecordBatchStreamBuilder::new(TestReader::new(data))
+            .await
+            .unwrap()
+            .with_parallelism(2)
+            .build()
+            .unwrap();
+        let batches: Vec<_> = stream.try_collect().await.unwrap();
+        let read = arrow::compute::concat_batches(&batch.schema(), &batches).unwrap();
+        assert_eq!(read, batch);
+    }
+
+    #[tokio::test]
+    async fn test_async_reader_target_schema() {
+        let a = Arc::new(Int32Array::from_iter_values(0..10)) as ArrayRef;
+        let b = Arc::new(StringArray::from_iter_values(
+            (0..10).map(|i| format!("{i}")),
+        )) as _;
+        let batch = RecordBatch::try_from_iter([("a", a), ("b", b)]).unwrap();
+
+        let mut buffer = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buffer, batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buffer);
+
+        let target = Arc::new(Schema::new(vec![
+            Field::new("c", DataType::Float64, true),
+            Field::new("a", DataType::Int64, false),
+        ]));
+        let options = ArrowReaderOptions::new().with_target_schema(target.clone());
+        let builder =
+            ParquetRecordBatchStreamBuilder::new_with_options(TestReader::new(data), options)
+                .await
+                .unwrap();
+
+        // The row filter refers to the columns of the file
+        let mask = ProjectionMask::roots(builder.parquet_schema(), [0]);
+        let predicate = ArrowPredicateFn::new(mask, |batch: RecordBatch| {
+            let a = batch.column(0).as_primitive::<Int32Type>();
+            Ok(BooleanArray::from_unary(a, |v| v % 3 == 0))
+        });
+        let stream = builder
+            .with_row_filter(RowFilter::new(vec![Box::new(predicate)]))
+            .build()
+            .unwrap();
+        assert_eq!(stream.schema(), &target);
+
+        let batches: Vec<_> = stream.try_collect().await.unwrap();
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0].schema(), target);
+        assert_eq!(batches[0].column(0).null_count(), 4);
+        let a = batches[0].column(1).as_primitive::<Int64Type>();
+        assert_eq!(a.values(), &[0, 3, 6, 9]);
+    }
+
+    #[tokio::test]
+    async fn test_async_reader_file_row_selection() {
+        let batch = RecordBatch::try_from_iter([(
+            "a",
+            Arc::new(Int32Array::from_iter_values(0..100)) as _,
+        )])
+        .unwrap();
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(25)
+            .build();
+        let mut buffer = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buffer, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let async_reader = TestReader::new(Bytes::from(buffer));
+        let requests = async_reader.requests.clone();
+        let builder = ParquetRecordBatchStreamBuilder::new(async_reader)
+            .await
+            .unwrap();
+        let metadata = builder.metadata().clone();
+
+        let selection = RowSelection::from_row_ids([1, 2, 80], 100);
+        let stream = builder.with_file_row_selection(selection).build().unwrap();
+        let batches: Vec<_> = stream.try_collect().await.unwrap();
+        let values: Vec<_> = batches
+            .iter()
+            .flat_map(|b| b.column(0).as_primitive::<Int32Type>().values().to_vec())
+            .collect();
+        assert_eq!(values, [1, 2, 80]);
+
+        // Row groups 1 and 2 are not fetched
+        let requests = requests.lock().unwrap();
+        for idx in [1, 2] {
+            let (start, len) = metadata.row_group(idx).column(0).byte_range();
+            let chunk = start as usize..(start + len) as usize;
+            assert!(requests
+                .iter()
+                .all(|r| r.end <= chunk.start || r.start >= chunk.end));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_reader_limit_early_termination() {
+        let batch = RecordBatch::try_from_iter([(
+            "a",
+            Arc::new(Int32Array::from_iter_values(0..100)) as _,
+        )])
+        .unwrap();
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(25)
+            .build();
+        let mut buffer = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buffer, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let async_reader = TestReader::new(Bytes::from(buffer));
+        let requests = async_reader.requests.clone();
+
+        let evaluated = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&evaluated);
+        let predicate = ArrowPredicateFn::new(ProjectionMask::all(), move |batch| {
+            counter.fetch_add(batch.num_rows(), Ordering::Relaxed);
+            let a = batch.column(0).as_primitive::<Int32Type>();
+            Ok(BooleanArray::from_unary(a, |v| v % 2 == 1))
+        });
+
+        let builder = ParquetRecordBatchStreamBuilder::new(async_reader)
+            .await
+            .unwrap();
+        let metadata = builder.metadata().clone();
+        let stream = builder
+            .with_batch_size(10)
+            .with_row_filter(RowFilter::new(vec![Box::new(predicate)]))
+            .with_offset(10)
+            .with_limit(5)
+            .build()
+            .unwrap();
+        let batches: Vec<_> = stream.try_collect().await.unwrap();
+        let values: Vec<_> = batches
+            .iter()
+            .flat_map(|b| b.column(0).as_primitive::<Int32Type>().values().to_vec())
+            .collect();
+        assert_eq!(values, [21, 23, 25, 27, 29]);
+
+        // The predicate stops once the offset and limit are satisfied
+        assert_eq!(evaluated.load(Ordering::Relaxed), 25 + 10);
+
+        // Row groups after the limit is reached are not fetched
+        let requests = requests.lock().unwrap();
+        for idx in [2, 3] {
+            let (start, len) = metadata.row_group(idx).column(0).byte_range();
+            let chunk = start as usize..(start + len) as usize;
+            assert!(requests
+                .iter()
+                .all(|r| r.end <= chunk.start || r.start >= chunk.end));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_reader_prefetch() {
+        let a = Arc::new(Int32Array::from_iter_values(0..300)) as ArrayRef;
+        let b = Arc::new(StringArray::from_iter_values(
+            (0..300).map(|i| format!("{i}")),
+        )) as _;
+        let batch = RecordBatch::try_from_iter([("a", a), ("b", b)]).unwrap();
+
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(100)
+            .build();
+        let mut buffer = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buffer, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buffer);
+
+        // Returns the stream and the requests made by it
+        async fn open(
+            data: Bytes,
+            policy: Arc<dyn PrefetchPolicy>,
+        ) -> (
+            ParquetRecordBatchStream<TestReader>,
+            Arc<Mutex<Vec<Range<usize>>>>,
+        ) {
+            let async_reader = TestReader::new(data);
+            let requests = async_reader.requests.clone();
+            let stream = ParquetRecordBatchStreamBuilder::new(async_reader)
+                .await
+                .unwrap()
+                .with_batch_size(50)
+                .with_offset(20)
+                .with_limit(250)
+                .with_prefetch_policy(policy)
+                .build()
+                .unwrap();
+            requests.lock().unwrap().clear();
+            (stream, requests)
+        }
+
+        let metadata = ParquetMetaDataReader::new()
+            .parse_and_finish(&data)
+            .unwrap();
+        let row_group_range = |idx: usize| {
+            let row_group = metadata.row_group(idx);
+            let (start, _) = row_group.column(0).byte_range();
+            let (b_start, b_len) = row_group.column(1).byte_range();
+            start as usize..(b_start + b_len) as usize
+        };
+
+        // Only the first row group is read before its batches are returned
+        let (mut stream, requests) = open(data.clone(), Arc::new(OnDemandPrefetch)).await;
+        stream.next().await.unwrap().unwrap();
+        assert_eq!(requests.lock().unwrap().len(), 2);
+        let expected: Vec<_> = stream.try_collect().await.unwrap();
+
+        // The next row group is read along with the batches of the current one, with the
+        // column chunks of each row group coalesced into a single request
+        let (mut stream, requests) = open(data, Arc::new(ReadAheadPrefetch::new())).await;
+        let first = stream.next().await.unwrap().unwrap();
+        assert_eq!(first, batch.slice(20, 50));
+        assert_eq!(
+            requests.lock().unwrap().as_slice(),
+            [row_group_range(0), row_group_range(1)]
+        );
+        let batches: Vec<_> = stream.try_collect().await.unwrap();
+        assert_eq!(batches, expected);
+        assert_eq!(
+            requests.lock().unwrap().as_slice(),
+            [row_group_range(0), row_group_range(1), row_group_range(2)]
+        );
+        let num_rows: usize = batches.iter().map(|b| b.num_rows()).sum();
+        assert_eq!(num_rows + first.num_rows(), 250);
+    }
+
     #[tokio::test]
     async fn test_in_memory_row_group_sparse() {
         let testdata = arrow::util::test_util::parquet_test_data();
@@ -1869,6 +2607,7 @@ mod tests {
             metadata.file_metadata().schema_descr(),
             ProjectionMask::all(),
             None,
+            None,
         )
         .unwrap();
 
@@ -1880,9 +2619,15 @@ mod tests {
             metadata,
             fields: fields.map(Arc::new),
             input: async_reader,
+            prefetch: Arc::new(OnDemandPrefetch),
             filter: None,
             limit: None,
             offset: None,
+            metrics: None,
+            schema_adapter: None,
+            dictionary_cache: None,
+            parallelism: 1,
+            int96_overflow: Int96Overflow::default(),
         };
 
         let mut skip = true;
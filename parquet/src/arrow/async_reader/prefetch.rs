@@ -0,0 +1,183 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`PrefetchPolicy`] to control how [`ParquetRecordBatchStream`] fetches data
+//!
+//! [`ParquetRecordBatchStream`]: super::ParquetRecordBatchStream
+
+use crate::file::metadata::RowGroupMetaData;
+use std::fmt::Debug;
+use std::ops::Range;
+
+/// Controls when, and how, a [`ParquetRecordBatchStream`] fetches data from its
+/// [`AsyncFileReader`]
+///
+/// By default, the stream uses [`OnDemandPrefetch`], only reading a row group once all the
+/// batches of the previous row group have been returned, and requesting exactly the byte
+/// ranges needed. On high latency storage, such as object stores, this leaves the stream
+/// waiting on I/O between every row group, which [`ReadAheadPrefetch`] avoids.
+///
+/// Set with [`ParquetRecordBatchStreamBuilder::with_prefetch_policy`].
+///
+/// [`ParquetRecordBatchStream`]: super::ParquetRecordBatchStream
+/// [`AsyncFileReader`]: super::AsyncFileReader
+/// [`ParquetRecordBatchStreamBuilder::with_prefetch_policy`]: super::ParquetRecordBatchStreamBuilder::with_prefetch_policy
+pub trait PrefetchPolicy: Debug + Send + Sync {
+    /// Returns whether to start reading `next`, the metadata of the next row group to
+    /// read, while the batches of the current row group are being returned
+    ///
+    /// Reading a row group fetches the data needed to evaluate any [`RowFilter`], evaluates
+    /// it, and then fetches the data needed for the remaining rows. This happens as the
+    /// stream is polled for the batches of the current row group, and so the data of both
+    /// row groups is buffered in memory at the same time.
+    ///
+    /// The default implementation returns `false`.
+    ///
+    /// [`RowFilter`]: crate::arrow::arrow_reader::RowFilter
+    fn prefetch_next_row_group(&self, next: &RowGroupMetaData) -> bool {
+        let _ = next;
+        false
+    }
+
+    /// Returns the byte ranges to request from the [`AsyncFileReader`] in order to fetch
+    /// `ranges`, for example merging nearby ranges to reduce the number of requests
+    ///
+    /// Each of `ranges` must be contained within one of the returned ranges.
+    ///
+    /// The default implementation returns `ranges` unchanged.
+    ///
+    /// [`AsyncFileReader`]: super::AsyncFileReader
+    fn coalesce_ranges(&self, ranges: &[Range<u64>]) -> Vec<Range<u64>> {
+        ranges.to_vec()
+    }
+}
+
+/// The default [`PrefetchPolicy`], which fetches exactly the data needed, only once it is
+/// needed
+#[derive(Debug, Default, Clone, Copy)]
+pub struct OnDemandPrefetch;
+
+impl PrefetchPolicy for OnDemandPrefetch {}
+
+/// Default value for [`ReadAheadPrefetch::with_coalesce_gap`]
+const DEFAULT_COALESCE_GAP: u64 = 1024 * 1024;
+
+/// A [`PrefetchPolicy`] that reads the next row group while the current one is being
+/// decoded, and coalesces byte ranges separated by small gaps into single requests
+///
+/// ```
+/// # use parquet::arrow::async_reader::ReadAheadPrefetch;
+/// // Merge ranges less than 64 KiB apart, and only read ahead row groups of up to 128 MiB
+/// let policy = ReadAheadPrefetch::new()
+///     .with_coalesce_gap(64 * 1024)
+///     .with_max_row_group_bytes(128 * 1024 * 1024);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ReadAheadPrefetch {
+    coalesce_gap: u64,
+    max_row_group_bytes: Option<u64>,
+}
+
+impl Default for ReadAheadPrefetch {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ReadAheadPrefetch {
+    /// Create a new [`ReadAheadPrefetch`]
+    pub fn new() -> Self {
+        Self {
+            coalesce_gap: DEFAULT_COALESCE_GAP,
+            max_row_group_bytes: None,
+        }
+    }
+
+    /// Set the maximum number of bytes between two ranges for them to be fetched with a
+    /// single request (defaults to 1 MiB)
+    ///
+    /// Larger values reduce the number of requests at the cost of fetching bytes that are
+    /// not needed, set to `0` to only merge adjacent ranges.
+    pub fn with_coalesce_gap(self, coalesce_gap: u64) -> Self {
+        Self {
+            coalesce_gap,
+            ..self
+        }
+    }
+
+    /// Only read ahead row groups with a total compressed size of at most `max_bytes`
+    /// (defaults to no limit)
+    ///
+    /// Limits the additional memory used by reading ahead, as the data of larger row
+    /// groups is instead only fetched once it is needed.
+    pub fn with_max_row_group_bytes(self, max_bytes: u64) -> Self {
+        Self {
+            max_row_group_bytes: Some(max_bytes),
+            ..self
+        }
+    }
+}
+
+impl PrefetchPolicy for ReadAheadPrefetch {
+    fn prefetch_next_row_group(&self, next: &RowGroupMetaData) -> bool {
+        self.max_row_group_bytes
+            .is_none_or(|max| next.compressed_size() as u64 <= max)
+    }
+
+    fn coalesce_ranges(&self, ranges: &[Range<u64>]) -> Vec<Range<u64>> {
+        let mut sorted = ranges.to_vec();
+        sorted.sort_unstable_by_key(|range| range.start);
+
+        let mut coalesced: Vec<Range<u64>> = Vec::with_capacity(sorted.len());
+        for range in sorted {
+            match coalesced.last_mut() {
+                Some(last) if range.start <= last.end.saturating_add(self.coalesce_gap) => {
+                    last.end = last.end.max(range.end)
+                }
+                _ => coalesced.push(range),
+            }
+        }
+        coalesced
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coalesce_ranges() {
+        let ranges = [50..60, 0..10, 12..20, 15..18, 100..110];
+
+        assert_eq!(OnDemandPrefetch.coalesce_ranges(&ranges), ranges);
+
+        let policy = ReadAheadPrefetch::new().with_coalesce_gap(0);
+        assert_eq!(
+            policy.coalesce_ranges(&ranges),
+            [0..10, 12..20, 50..60, 100..110]
+        );
+
+        let policy = ReadAheadPrefetch::new().with_coalesce_gap(2);
+        assert_eq!(policy.coalesce_ranges(&ranges), [0..20, 50..60, 100..110]);
+
+        let policy = ReadAheadPrefetch::new();
+        let coalesced = policy.coalesce_ranges(&ranges);
+        assert_eq!(coalesced.len(), 1);
+        assert_eq!(coalesced[0], 0..110);
+        assert_eq!(policy.coalesce_ranges(&[]), []);
+    }
+}
@@ -61,7 +61,7 @@ mod store;
 pub use store::*;
 
 use crate::{
-    arrow::arrow_writer::ArrowWriterOptions,
+    arrow::arrow_writer::{ArrowWriterOptions, ColumnWriteStatistics},
     arrow::ArrowWriter,
     errors::{ParquetError, Result},
     file::{metadata::RowGroupMetaData, properties::WriterProperties},
@@ -89,6 +89,18 @@ pub trait AsyncFileWriter: Send {
     ///
     /// After `complete` returns `Ok(())`, caller SHOULD not call write again.
     fn complete(&mut self) -> BoxFuture<'_, Result<()>>;
+
+    /// Abort the writing process, cleaning up any data already written where possible,
+    /// such as the parts of an in progress multipart upload.
+    ///
+    /// Called by [`AsyncArrowWriter`] if [`Self::write`] or [`Self::complete`] fail, and by
+    /// [`AsyncArrowWriter::abort`]. After `abort` is called, caller SHOULD not call write
+    /// or complete again.
+    ///
+    /// The default implementation does nothing.
+    fn abort(&mut self) -> BoxFuture<'_, Result<()>> {
+        async { Ok(()) }.boxed()
+    }
 }
 
 impl AsyncFileWriter for Box<dyn AsyncFileWriter + '_> {
@@ -99,6 +111,10 @@ impl AsyncFileWriter for Box<dyn AsyncFileWriter + '_> {
     fn complete(&mut self) -> BoxFuture<'_, Result<()>> {
         self.as_mut().complete()
     }
+
+    fn abort(&mut self) -> BoxFuture<'_, Result<()>> {
+        self.as_mut().abort()
+    }
 }
 
 impl<T: AsyncWrite + Unpin + Send> AsyncFileWriter for T {
@@ -134,6 +150,14 @@ impl<T: AsyncWrite + Unpin + Send> AsyncFileWriter for T {
 /// although this will likely increase overall file size and reduce query performance.
 /// See [ArrowWriter] for more information.
 ///
+/// ## Errors
+///
+/// If writing to, or completing, the [`AsyncFileWriter`] fails, [`AsyncFileWriter::abort`]
+/// is called to clean up any partially written output before the error is returned, and
+/// the writer should not be used further. Other errors, such as writing a [`RecordBatch`]
+/// that does not match the schema, leave the writer usable, and [`Self::abort`] can be
+/// called to give up on the file instead.
+///
 /// ```no_run
 /// # use tokio::fs::File;
 /// # use arrow_array::RecordBatch;
@@ -186,6 +210,14 @@ impl<W: AsyncFileWriter> AsyncArrowWriter<W> {
         self.sync_writer.flushed_row_groups()
     }
 
+    /// Returns the [`ColumnWriteStatistics`] of each leaf column, aggregated
+    /// across any flushed row groups
+    ///
+    /// See [`ArrowWriter::column_write_statistics`] for more details
+    pub fn column_write_statistics(&self) -> Vec<ColumnWriteStatistics> {
+        self.sync_writer.column_write_statistics()
+    }
+
     /// Estimated memory usage, in bytes, of this `ArrowWriter`
     ///
     /// See [ArrowWriter::memory_size] for more information.
@@ -250,7 +282,8 @@ impl<W: AsyncFileWriter> AsyncArrowWriter<W> {
 
         // Force to flush the remaining data.
         self.do_write().await?;
-        self.async_writer.complete().await?;
+        let result = self.async_writer.complete().await;
+        self.abort_on_error(result).await?;
 
         Ok(metadata)
     }
@@ -272,6 +305,21 @@ impl<W: AsyncFileWriter> AsyncArrowWriter<W> {
         self.async_writer
     }
 
+    /// Abort writing the file, discarding any buffered data and calling
+    /// [`AsyncFileWriter::abort`] to clean up any data already written
+    pub async fn abort(mut self) -> Result<()> {
+        self.async_writer.abort().await
+    }
+
+    /// Calls [`AsyncFileWriter::abort`] if `result` is an error, returning `result`
+    async fn abort_on_error(&mut self, result: Result<()>) -> Result<()> {
+        if result.is_err() {
+            // The original error is more useful than any error aborting
+            let _ = self.async_writer.abort().await;
+        }
+        result
+    }
+
     /// Flush the data written by `sync_writer` into the `async_writer`
     ///
     /// # Notes
@@ -281,8 +329,8 @@ impl<W: AsyncFileWriter> AsyncArrowWriter<W> {
     async fn do_write(&mut self) -> Result<()> {
         let buffer = mem::take(self.sync_writer.inner_mut());
 
-        self.async_writer
-            .write(Bytes::from(buffer))
+        let result = self.async_writer.write(Bytes::from(buffer)).await;
+        self.abort_on_error(result)
             .await
             .map_err(|e| ParquetError::External(Box::new(e)))?;
 
@@ -510,4 +558,70 @@ mod tests {
 
         writer.close().await.unwrap();
     }
+
+    /// An [`AsyncFileWriter`] that fails after writing `fail_after` times
+    #[derive(Default)]
+    struct FailingWriter {
+        fail_after: usize,
+        writes: usize,
+        aborts: usize,
+    }
+
+    impl AsyncFileWriter for &mut FailingWriter {
+        fn write(&mut self, _bs: Bytes) -> BoxFuture<'_, Result<()>> {
+            self.writes += 1;
+            let fail = self.writes > self.fail_after;
+            async move {
+                match fail {
+                    true => Err(general_err!("write failed")),
+                    false => Ok(()),
+                }
+            }
+            .boxed()
+        }
+
+        fn complete(&mut self) -> BoxFuture<'_, Result<()>> {
+            async { Ok(()) }.boxed()
+        }
+
+        fn abort(&mut self) -> BoxFuture<'_, Result<()>> {
+            self.aborts += 1;
+            async { Ok(()) }.boxed()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_writer_abort() {
+        let col = Arc::new(Int64Array::from_iter_values([1, 2, 3])) as ArrayRef;
+        let batch = RecordBatch::try_from_iter([("col", col)]).unwrap();
+
+        // Aborted when writing fails
+        let mut failing = FailingWriter {
+            fail_after: 1,
+            ..Default::default()
+        };
+        let mut writer = AsyncArrowWriter::try_new(&mut failing, batch.schema(), None).unwrap();
+        writer.write(&batch).await.unwrap();
+        writer.flush().await.unwrap();
+        writer.write(&batch).await.unwrap();
+        let err = writer.close().await.unwrap_err();
+        assert_eq!(err.to_string(), "External: Parquet error: write failed");
+        assert_eq!(failing.aborts, 1);
+
+        // Not aborted by other errors, but can be aborted explicitly
+        let mut failing = FailingWriter {
+            fail_after: usize::MAX,
+            ..Default::default()
+        };
+        let mut writer = AsyncArrowWriter::try_new(&mut failing, batch.schema(), None).unwrap();
+        let other = RecordBatch::try_from_iter([(
+            "other",
+            Arc::new(Int32Array::from(vec![1])) as ArrayRef,
+        )])
+        .unwrap();
+        writer.write(&other).await.unwrap_err();
+        writer.write(&batch).await.unwrap();
+        writer.abort().await.unwrap();
+        assert_eq!(failing.aborts, 1);
+    }
 }
@@ -68,11 +68,43 @@ use tokio::io::AsyncWriteExt;
 ///     assert_eq!(to_write, read);
 /// # }
 /// ```
+///
+/// ## Memory Usage
+///
+/// Files smaller than the capacity of the [`BufWriter`] are buffered in memory and written
+/// with a single request. Larger files are streamed using a multipart upload of parts of
+/// `capacity` bytes, with at most `max_concurrency` parts in flight, so that in addition to
+/// the row group buffered by [`AsyncArrowWriter`], roughly `capacity * (max_concurrency + 1)`
+/// bytes are buffered. See [`Self::with_capacity`] and [`Self::with_max_concurrency`].
+///
+/// ## Errors
+///
+/// If an upload fails, [`AsyncArrowWriter`] aborts the multipart upload, cleaning up any
+/// parts already uploaded, and [`AsyncArrowWriter::abort`] can be used to do so explicitly.
+/// However, [`BufWriter`] cannot be aborted once completing the upload has started, and so
+/// parts may be left behind if completing fails, which should be cleaned up using the
+/// lifecycle rules of the object store.
+///
+/// [`AsyncArrowWriter`]: crate::arrow::AsyncArrowWriter
+/// [`AsyncArrowWriter::abort`]: crate::arrow::AsyncArrowWriter::abort
 #[derive(Debug)]
 pub struct ParquetObjectWriter {
     w: BufWriter,
+    /// The maximum number of bytes passed to the [`BufWriter`] at once
+    chunk_size: usize,
+    state: WriterState,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WriterState {
+    Open,
+    Completed,
+    Aborted,
 }
 
+/// The default capacity of a [`BufWriter`]
+const DEFAULT_CAPACITY: usize = 10 * 1024 * 1024;
+
 impl ParquetObjectWriter {
     /// Create a new [`ParquetObjectWriter`] that writes to the specified path in the given store.
     ///
@@ -81,9 +113,35 @@ impl ParquetObjectWriter {
         Self::from_buf_writer(BufWriter::new(store, path))
     }
 
+    /// Create a new [`ParquetObjectWriter`] that writes to the specified path in the given
+    /// store, buffering up to `capacity` bytes before starting a multipart upload with
+    /// parts of `capacity` bytes
+    pub fn with_capacity(store: Arc<dyn ObjectStore>, path: Path, capacity: usize) -> Self {
+        Self {
+            w: BufWriter::with_capacity(store, path, capacity),
+            chunk_size: capacity,
+            state: WriterState::Open,
+        }
+    }
+
+    /// Override the maximum number of parts uploaded concurrently (defaults to 8)
+    pub fn with_max_concurrency(self, max_concurrency: usize) -> Self {
+        Self {
+            w: self.w.with_max_concurrency(max_concurrency),
+            ..self
+        }
+    }
+
     /// Construct a new ParquetObjectWriter via a existing BufWriter.
+    ///
+    /// Data is passed to the [`BufWriter`] in chunks of at most its default capacity of
+    /// 10 MiB, use [`Self::with_capacity`] to bound buffering by a different capacity.
     pub fn from_buf_writer(w: BufWriter) -> Self {
-        Self { w }
+        Self {
+            w,
+            chunk_size: DEFAULT_CAPACITY,
+            state: WriterState::Open,
+        }
     }
 
     /// Consume the writer and return the underlying BufWriter.
@@ -93,24 +151,56 @@ impl ParquetObjectWriter {
 }
 
 impl AsyncFileWriter for ParquetObjectWriter {
-    fn write(&mut self, bs: Bytes) -> BoxFuture<'_, Result<()>> {
+    fn write(&mut self, mut bs: Bytes) -> BoxFuture<'_, Result<()>> {
+        Box::pin(async move {
+            self.check_open()?;
+            // Pass at most `chunk_size` bytes at once, as the BufWriter only waits for
+            // in flight parts to complete before accepting more data
+            while !bs.is_empty() {
+                let chunk = bs.split_to(bs.len().min(self.chunk_size));
+                self.w
+                    .put(chunk)
+                    .await
+                    .map_err(|err| ParquetError::External(Box::new(err)))?;
+            }
+            Ok(())
+        })
+    }
+
+    fn complete(&mut self) -> BoxFuture<'_, Result<()>> {
         Box::pin(async {
+            self.check_open()?;
+            self.state = WriterState::Completed;
             self.w
-                .put(bs)
+                .shutdown()
                 .await
                 .map_err(|err| ParquetError::External(Box::new(err)))
         })
     }
 
-    fn complete(&mut self) -> BoxFuture<'_, Result<()>> {
+    fn abort(&mut self) -> BoxFuture<'_, Result<()>> {
         Box::pin(async {
+            if self.state != WriterState::Open {
+                return Ok(());
+            }
+            self.state = WriterState::Aborted;
             self.w
-                .shutdown()
+                .abort()
                 .await
                 .map_err(|err| ParquetError::External(Box::new(err)))
         })
     }
 }
+
+impl ParquetObjectWriter {
+    fn check_open(&self) -> Result<()> {
+        match self.state {
+            WriterState::Open => Ok(()),
+            WriterState::Completed => Err(general_err!("ParquetObjectWriter already completed")),
+            WriterState::Aborted => Err(general_err!("ParquetObjectWriter aborted")),
+        }
+    }
+}
 impl From<BufWriter> for ParquetObjectWriter {
     fn from(w: BufWriter) -> Self {
         Self::from_buf_writer(w)
@@ -154,4 +244,66 @@ mod tests {
 
         assert_eq!(to_write, read);
     }
+
+    #[tokio::test]
+    async fn test_async_writer_multipart() {
+        let store = Arc::new(InMemory::new());
+        let path = Path::from("test");
+
+        let col = Arc::new(Int64Array::from_iter_values(0..100_000)) as ArrayRef;
+        let to_write = RecordBatch::try_from_iter([("col", col)]).unwrap();
+
+        let object_store_writer =
+            ParquetObjectWriter::with_capacity(store.clone(), path.clone(), 16 * 1024)
+                .with_max_concurrency(2);
+        let mut writer =
+            AsyncArrowWriter::try_new(object_store_writer, to_write.schema(), None).unwrap();
+        writer.write(&to_write).await.unwrap();
+        writer.close().await.unwrap();
+
+        let buffer = store.get(&path).await.unwrap().bytes().await.unwrap();
+        assert!(buffer.len() > 16 * 1024);
+        let mut reader = ParquetRecordBatchReaderBuilder::try_new(buffer)
+            .unwrap()
+            .with_batch_size(100_000)
+            .build()
+            .unwrap();
+        assert_eq!(reader.next().unwrap().unwrap(), to_write);
+    }
+
+    #[tokio::test]
+    async fn test_async_writer_abort() {
+        let store = Arc::new(InMemory::new());
+        let path = Path::from("test");
+
+        let col = Arc::new(Int64Array::from_iter_values(0..100_000)) as ArrayRef;
+        let to_write = RecordBatch::try_from_iter([("col", col)]).unwrap();
+
+        let object_store_writer =
+            ParquetObjectWriter::with_capacity(store.clone(), path.clone(), 16 * 1024);
+        let mut writer =
+            AsyncArrowWriter::try_new(object_store_writer, to_write.schema(), None).unwrap();
+        // Starts a multipart upload
+        writer.write(&to_write).await.unwrap();
+        writer.flush().await.unwrap();
+        writer.abort().await.unwrap();
+        assert!(store.head(&path).await.is_err());
+
+        let mut object_store_writer =
+            ParquetObjectWriter::with_capacity(store.clone(), path.clone(), 16 * 1024);
+        let data = Bytes::from(vec![0; 64 * 1024]);
+        object_store_writer.write(data).await.unwrap();
+        object_store_writer.abort().await.unwrap();
+        assert!(store.head(&path).await.is_err());
+        let err = object_store_writer
+            .write(Bytes::from_static(b"data"))
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: ParquetObjectWriter aborted"
+        );
+        // Aborting again does nothing
+        object_store_writer.abort().await.unwrap();
+    }
 }
@@ -91,33 +91,31 @@
 //! # Example: Reading Parquet file into Arrow `RecordBatch`
 //!
 //! ```rust
-//! # use std::fs::File;
 //! # use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
 //! # use std::sync::Arc;
 //! # use arrow_array::Int32Array;
 //! # use arrow::datatypes::{DataType, Field, Schema};
 //! # use arrow_array::RecordBatch;
 //! # use parquet::arrow::arrow_writer::ArrowWriter;
+//! # use tempfile::tempfile;
 //! #
 //! # let ids = Int32Array::from(vec![1, 2, 3, 4]);
 //! # let schema = Arc::new(Schema::new(vec![
 //! #     Field::new("id", DataType::Int32, false),
 //! # ]));
 //! #
-//! # let file = File::create("data.parquet").unwrap();
+//! # let file = tempfile().unwrap();
 //! #
 //! # let batch = RecordBatch::try_new(Arc::clone(&schema), vec![Arc::new(ids)]).unwrap();
 //! # let batches = vec![batch];
 //! #
-//! # let mut writer = ArrowWriter::try_new(file, Arc::clone(&schema), None).unwrap();
+//! # let mut writer = ArrowWriter::try_new(file.try_clone().unwrap(), Arc::clone(&schema), None).unwrap();
 //! #
 //! # for batch in batches {
 //! #     writer.write(&batch).expect("Writing batch");
 //! # }
 //! # writer.close().unwrap();
 //! #
-//! let file = File::open("data.parquet").unwrap();
-//!
 //! let builder = ParquetRecordBatchReaderBuilder::try_new(file).unwrap();
 //! println!("Converted arrow schema is: {}", builder.schema());
 //!
@@ -200,9 +198,11 @@ pub use self::arrow_writer::ArrowWriter;
 pub use self::async_reader::ParquetRecordBatchStreamBuilder;
 #[cfg(feature = "async")]
 pub use self::async_writer::AsyncArrowWriter;
-use crate::schema::types::{SchemaDescriptor, Type};
+use crate::errors::Result;
+use crate::schema::types::{ColumnPath, SchemaDescriptor, Type};
 use arrow_schema::{FieldRef, Schema};
 
+pub(crate) use self::schema::decimal_length_from_precision;
 pub use self::schema::{
     add_encoded_arrow_schema_to_metadata, encode_arrow_schema, parquet_to_arrow_field_levels,
     parquet_to_arrow_schema, parquet_to_arrow_schema_by_columns, ArrowSchemaConverter, FieldLevels,
@@ -376,6 +376,64 @@ impl ProjectionMask {
         Self { mask: Some(mask) }
     }
 
+    /// Create a [`ProjectionMask`] which selects the leaf columns at or below
+    /// each of the provided [`ColumnPath`]s
+    ///
+    /// Unlike [`Self::columns`], paths are matched component-wise, and so a
+    /// path of `["a"]` will not select a column named `ab`. Paths refer to the
+    /// parquet schema, and so must include any intermediate repeated groups,
+    /// e.g. `["l", "list", "element"]` for the elements of a 3-level list `l`.
+    ///
+    /// This can be used to construct an [`ArrowPredicate`] that only decodes
+    /// specific leaves nested within structs or lists, for example
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use parquet::arrow::ProjectionMask;
+    /// # use parquet::schema::parser::parse_message_type;
+    /// # use parquet::schema::types::{ColumnPath, SchemaDescriptor};
+    /// let schema = parse_message_type(
+    ///     "message schema {
+    ///         OPTIONAL group s {
+    ///             OPTIONAL INT32 a;
+    ///             OPTIONAL INT32 ab;
+    ///         }
+    ///         OPTIONAL group l (LIST) {
+    ///             REPEATED group list {
+    ///                 OPTIONAL INT32 element;
+    ///             }
+    ///         }
+    ///     }",
+    /// )
+    /// .unwrap();
+    /// let schema = SchemaDescriptor::new(Arc::new(schema));
+    ///
+    /// let paths = [
+    ///     ColumnPath::from(vec!["s".to_string(), "a".to_string()]),
+    ///     ColumnPath::from("l"),
+    /// ];
+    /// let mask = ProjectionMask::column_paths(&schema, &paths);
+    /// assert!(mask.leaf_included(0));
+    /// assert!(!mask.leaf_included(1));
+    /// assert!(mask.leaf_included(2));
+    /// ```
+    ///
+    /// [`ArrowPredicate`]: crate::arrow::arrow_reader::ArrowPredicate
+    pub fn column_paths<'a>(
+        schema: &SchemaDescriptor,
+        paths: impl IntoIterator<Item = &'a ColumnPath>,
+    ) -> Self {
+        let mut mask = vec![false; schema.num_columns()];
+        for path in paths {
+            let path = path.parts();
+            for (idx, column) in schema.columns().iter().enumerate() {
+                if column.path().parts().starts_with(path) {
+                    mask[idx] = true;
+                }
+            }
+        }
+        Self { mask: Some(mask) }
+    }
+
     /// Returns true if the leaf column `leaf_idx` is included by the mask
     pub fn leaf_included(&self, leaf_idx: usize) -> bool {
         self.mask.as_ref().map(|m| m[leaf_idx]).unwrap_or(true)
@@ -446,13 +504,59 @@ pub fn parquet_column<'a>(
     Some((parquet_idx, field))
 }
 
+/// Applies `f` to each of `items`, dividing them between up to `parallelism`
+/// tasks on the global [rayon] thread pool, and returns the results in the order of `items`
+///
+/// [rayon]: https://docs.rs/rayon
+#[cfg(feature = "rayon")]
+pub(crate) fn map_parallel<T, R, F>(items: Vec<T>, parallelism: usize, f: F) -> Result<Vec<R>>
+where
+    T: Send,
+    R: Send,
+    F: Fn(T) -> Result<R> + Sync,
+{
+    use rayon::prelude::*;
+
+    if parallelism <= 1 || items.len() <= 1 {
+        return items.into_iter().map(f).collect();
+    }
+
+    let chunk_size = items.len().div_ceil(parallelism);
+    let mut chunks = Vec::with_capacity(parallelism);
+    let mut items = items.into_iter();
+    loop {
+        let chunk: Vec<T> = items.by_ref().take(chunk_size).collect();
+        if chunk.is_empty() {
+            break;
+        }
+        chunks.push(chunk);
+    }
+
+    let f = &f;
+    let results = chunks
+        .into_par_iter()
+        .map(|chunk| chunk.into_iter().map(f).collect::<Result<Vec<_>>>())
+        .collect::<Result<Vec<_>>>()?;
+    Ok(results.into_iter().flatten().collect())
+}
+
+/// Applies `f` to each of `items` in order, as parallel execution requires the
+/// `rayon` feature
+#[cfg(not(feature = "rayon"))]
+pub(crate) fn map_parallel<T, R, F>(items: Vec<T>, _parallelism: usize, f: F) -> Result<Vec<R>>
+where
+    F: Fn(T) -> Result<R>,
+{
+    items.into_iter().map(f).collect()
+}
+
 #[cfg(test)]
 mod test {
     use crate::arrow::ArrowWriter;
     use crate::file::metadata::{ParquetMetaData, ParquetMetaDataReader, ParquetMetaDataWriter};
     use crate::file::properties::{EnabledStatistics, WriterProperties};
     use crate::schema::parser::parse_message_type;
-    use crate::schema::types::SchemaDescriptor;
+    use crate::schema::types::{ColumnPath, SchemaDescriptor};
     use arrow_array::{ArrayRef, Int32Array, RecordBatch};
     use bytes::Bytes;
     use std::sync::Arc;
@@ -691,6 +795,43 @@ mod test {
         assert_eq!(mask.mask.unwrap(), [true, false, true, false, true]);
     }
 
+    #[test]
+    fn test_mask_from_column_paths() {
+        let message_type = "
+            message test_schema {
+                OPTIONAL group a {
+                    OPTIONAL INT32 b;
+                    OPTIONAL INT32 bc;
+                    OPTIONAL group c (LIST) {
+                        REPEATED group list {
+                            OPTIONAL INT32 element;
+                        }
+                    }
+                }
+                REQUIRED INT32 ab;
+            }
+            ";
+        let parquet_group_type = parse_message_type(message_type).unwrap();
+        let schema = SchemaDescriptor::new(Arc::new(parquet_group_type));
+
+        let path = |s: &str| ColumnPath::new(s.split('.').map(String::from).collect());
+
+        let mask = ProjectionMask::column_paths(&schema, &[path("a")]);
+        assert_eq!(mask.mask.unwrap(), [true, true, true, false]);
+
+        let mask = ProjectionMask::column_paths(&schema, &[path("a.b")]);
+        assert_eq!(mask.mask.unwrap(), [true, false, false, false]);
+
+        let mask = ProjectionMask::column_paths(&schema, &[path("a.c.list.element"), path("ab")]);
+        assert_eq!(mask.mask.unwrap(), [false, false, true, true]);
+
+        let mask = ProjectionMask::column_paths(&schema, &[path("a.c.element"), path("b")]);
+        assert_eq!(mask.mask.unwrap(), [false; 4]);
+
+        let mask = ProjectionMask::column_paths(&schema, []);
+        assert_eq!(mask.mask.unwrap(), [false; 4]);
+    }
+
     #[test]
     fn test_projection_mask_union() {
         let mut mask1 = ProjectionMask {
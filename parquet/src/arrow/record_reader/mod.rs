@@ -190,6 +190,14 @@ where
     /// Returns bitmap data for nullable columns.
     /// For non-nullable columns, the bitmap is discarded.
     pub fn consume_bitmap(&mut self) -> Option<Buffer> {
+        self.consume_bitmap_and_mask().0
+    }
+
+    /// Returns bitmap data for nullable columns, along with a mask of the values
+    /// that are not padding for a null in this column or any of its ancestors.
+    ///
+    /// Unlike the bitmap, the mask is also returned for non-nullable columns.
+    pub(crate) fn consume_bitmap_and_mask(&mut self) -> (Option<Buffer>, Option<Buffer>) {
         let mask = self
             .def_levels
             .as_mut()
@@ -199,10 +207,9 @@ where
         // the bitmask for nullable arrays. (Marking nulls on a non-nullable
         // array may fail validations, even if those nulls are masked off at
         // a higher level.)
-        if self.column_desc.self_type().is_optional() {
-            mask
-        } else {
-            None
+        match self.column_desc.self_type().is_optional() {
+            true => (mask.clone(), mask),
+            false => (None, mask),
         }
     }
 
@@ -20,7 +20,7 @@ use std::sync::Arc;
 
 use crate::arrow::schema::primitive::convert_primitive;
 use crate::arrow::{ProjectionMask, PARQUET_FIELD_ID_META_KEY};
-use crate::basic::{ConvertedType, Repetition};
+use crate::basic::{ConvertedType, Repetition, Type as PhysicalType};
 use crate::errors::ParquetError;
 use crate::errors::Result;
 use crate::schema::types::{SchemaDescriptor, Type, TypePtr};
@@ -76,6 +76,8 @@ impl ParquetField {
         match &self.field_type {
             ParquetFieldType::Primitive { .. } => None,
             ParquetFieldType::Group { children } => Some(children),
+            #[cfg(feature = "variant_experimental")]
+            ParquetFieldType::Variant { .. } => None,
         }
     }
 }
@@ -91,6 +93,12 @@ pub enum ParquetFieldType {
     Group {
         children: Vec<ParquetField>,
     },
+    /// A column decoded from `source` into a variant array
+    #[cfg(feature = "variant_experimental")]
+    Variant {
+        /// The column as stored in parquet, either JSON strings or a variant group
+        source: Box<ParquetField>,
+    },
 }
 
 /// Encodes the context of the parent of the field currently under consideration
@@ -124,6 +132,9 @@ struct Visitor {
 
     /// Mask of columns to include
     mask: ProjectionMask,
+
+    /// An optional [`DataType`] for INT96 columns, overriding the embedded arrow schema
+    int96_type: Option<DataType>,
 }
 
 impl Visitor {
@@ -142,7 +153,11 @@ impl Visitor {
         let repetition = get_repetition(primitive_type);
         let (def_level, rep_level, nullable) = context.levels(repetition);
 
-        let arrow_type = convert_primitive(primitive_type, context.data_type)?;
+        let hint = match (&self.int96_type, primitive_type.get_physical_type()) {
+            (Some(int96_type), PhysicalType::INT96) => Some(int96_type.clone()),
+            _ => context.data_type,
+        };
+        let arrow_type = convert_primitive(primitive_type, hint)?;
 
         let primitive_field = ParquetField {
             rep_level,
@@ -567,12 +582,16 @@ fn convert_field(parquet_type: &Type, field: &ParquetField, arrow_hint: Option<&
         None => {
             let mut ret = Field::new(name, data_type, nullable);
             let basic_info = parquet_type.get_basic_info();
+            let mut meta = HashMap::new();
             if basic_info.has_id() {
-                let mut meta = HashMap::with_capacity(1);
                 meta.insert(
                     PARQUET_FIELD_ID_META_KEY.to_string(),
                     basic_info.id().to_string(),
                 );
+            }
+            #[cfg(feature = "geospatial")]
+            super::geospatial::add_geoarrow_metadata(basic_info.logical_type().as_ref(), &mut meta);
+            if !meta.is_empty() {
                 ret.set_metadata(meta);
             }
             ret
@@ -584,15 +603,20 @@ fn convert_field(parquet_type: &Type, field: &ParquetField, arrow_hint: Option<&
 /// the indexes of leaf columns to project, and `embedded_arrow_schema` the optional
 /// [`Fields`] embedded in the parquet metadata
 ///
+/// If provided, `int96_type` is used for INT96 columns in place of any type from
+/// `embedded_arrow_schema`
+///
 /// Note: This does not support out of order column projection
 pub fn convert_schema(
     schema: &SchemaDescriptor,
     mask: ProjectionMask,
     embedded_arrow_schema: Option<&Fields>,
+    int96_type: Option<&DataType>,
 ) -> Result<Option<ParquetField>> {
     let mut visitor = Visitor {
         next_col_idx: 0,
         mask,
+        int96_type: int96_type.cloned(),
     };
 
     let context = VisitorContext {
@@ -609,6 +633,7 @@ pub fn convert_type(parquet_type: &TypePtr) -> Result<ParquetField> {
     let mut visitor = Visitor {
         next_col_idx: 0,
         mask: ProjectionMask::all(),
+        int96_type: None,
     };
 
     let context = VisitorContext {
@@ -0,0 +1,226 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Conversion between the [`Geometry`] and [`Geography`] logical types and the
+//! [GeoArrow] `geoarrow.wkb` extension type
+//!
+//! [`Geometry`]: LogicalType::Geometry
+//! [`Geography`]: LogicalType::Geography
+//! [GeoArrow]: https://geoarrow.org/extension-types.html
+
+use std::collections::HashMap;
+
+use arrow_schema::Field;
+use serde_json::{Map, Value};
+
+use crate::basic::{EdgeInterpolationAlgorithm, LogicalType};
+use crate::errors::{ParquetError, Result};
+
+/// The name of the GeoArrow extension type for WKB encoded features
+const GEOARROW_WKB: &str = "geoarrow.wkb";
+
+const EXTENSION_NAME_KEY: &str = "ARROW:extension:name";
+const EXTENSION_METADATA_KEY: &str = "ARROW:extension:metadata";
+
+/// The coordinate reference system of a geospatial column without an explicit one
+const DEFAULT_CRS: &str = "OGC:CRS84";
+
+const EDGES: [(EdgeInterpolationAlgorithm, &str); 5] = [
+    (EdgeInterpolationAlgorithm::SPHERICAL, "spherical"),
+    (EdgeInterpolationAlgorithm::VINCENTY, "vincenty"),
+    (EdgeInterpolationAlgorithm::THOMAS, "thomas"),
+    (EdgeInterpolationAlgorithm::ANDOYER, "andoyer"),
+    (EdgeInterpolationAlgorithm::KARNEY, "karney"),
+];
+
+/// Adds the GeoArrow extension metadata of a column with a geospatial `logical_type` to
+/// `metadata`, leaving it unchanged for other logical types
+pub(super) fn add_geoarrow_metadata(
+    logical_type: Option<&LogicalType>,
+    metadata: &mut HashMap<String, String>,
+) {
+    let (crs, edges) = match logical_type {
+        Some(LogicalType::Geometry { crs }) => (crs, None),
+        Some(LogicalType::Geography { crs, algorithm }) => {
+            let algorithm = algorithm.unwrap_or(EdgeInterpolationAlgorithm::SPHERICAL);
+            match EDGES.iter().find(|(a, _)| *a == algorithm) {
+                Some((_, edges)) => (crs, Some(*edges)),
+                // Cannot be represented in GeoArrow
+                None => return,
+            }
+        }
+        _ => return,
+    };
+
+    let mut extension_metadata = Map::new();
+    let crs = crs.as_deref().unwrap_or(DEFAULT_CRS);
+    extension_metadata.insert("crs".to_string(), Value::from(crs));
+    if let Some(edges) = edges {
+        extension_metadata.insert("edges".to_string(), Value::from(edges));
+    }
+
+    metadata.insert(EXTENSION_NAME_KEY.to_string(), GEOARROW_WKB.to_string());
+    metadata.insert(
+        EXTENSION_METADATA_KEY.to_string(),
+        Value::Object(extension_metadata).to_string(),
+    );
+}
+
+/// Returns the geospatial logical type of `field` if it has the GeoArrow WKB extension type
+pub(super) fn geoarrow_logical_type(field: &Field) -> Result<Option<LogicalType>> {
+    let metadata = field.metadata();
+    if metadata.get(EXTENSION_NAME_KEY).map(String::as_str) != Some(GEOARROW_WKB) {
+        return Ok(None);
+    }
+
+    let extension_metadata = match metadata.get(EXTENSION_METADATA_KEY) {
+        Some(m) if !m.is_empty() => serde_json::from_str::<Map<String, Value>>(m).map_err(|e| {
+            arrow_err!(
+                "Invalid GeoArrow metadata for field {}: {}",
+                field.name(),
+                e
+            )
+        })?,
+        _ => Map::new(),
+    };
+
+    let crs = match extension_metadata.get("crs") {
+        None | Some(Value::Null) => None,
+        Some(Value::String(crs)) if crs == DEFAULT_CRS => None,
+        Some(Value::String(crs)) => Some(crs.clone()),
+        // For example PROJJSON
+        Some(crs) => Some(crs.to_string()),
+    };
+
+    let logical_type = match extension_metadata.get("edges") {
+        None | Some(Value::Null) => LogicalType::Geometry { crs },
+        Some(Value::String(edges)) if edges == "planar" => LogicalType::Geometry { crs },
+        Some(Value::String(edges)) => {
+            let algorithm = EDGES
+                .iter()
+                .find(|(_, name)| name == edges)
+                .map(|(algorithm, _)| *algorithm)
+                .ok_or_else(|| {
+                    arrow_err!(
+                        "Unsupported GeoArrow edges {} for field {}",
+                        edges,
+                        field.name()
+                    )
+                })?;
+            let algorithm =
+                (algorithm != EdgeInterpolationAlgorithm::SPHERICAL).then_some(algorithm);
+            LogicalType::Geography { crs, algorithm }
+        }
+        Some(edges) => {
+            return Err(arrow_err!(
+                "Invalid GeoArrow edges {} for field {}",
+                edges,
+                field.name()
+            ))
+        }
+    };
+    Ok(Some(logical_type))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_schema::DataType;
+
+    fn roundtrip(logical_type: LogicalType) -> LogicalType {
+        let mut metadata = HashMap::new();
+        add_geoarrow_metadata(Some(&logical_type), &mut metadata);
+        let field = Field::new("geo", DataType::Binary, true).with_metadata(metadata);
+        geoarrow_logical_type(&field).unwrap().unwrap()
+    }
+
+    #[test]
+    fn test_geoarrow_metadata() {
+        let mut metadata = HashMap::new();
+        add_geoarrow_metadata(
+            Some(&LogicalType::Geography {
+                crs: None,
+                algorithm: Some(EdgeInterpolationAlgorithm::VINCENTY),
+            }),
+            &mut metadata,
+        );
+        assert_eq!(metadata[EXTENSION_NAME_KEY], GEOARROW_WKB);
+        assert_eq!(
+            metadata[EXTENSION_METADATA_KEY],
+            r#"{"crs":"OGC:CRS84","edges":"vincenty"}"#
+        );
+
+        let mut metadata = HashMap::new();
+        add_geoarrow_metadata(Some(&LogicalType::String), &mut metadata);
+        assert!(metadata.is_empty());
+
+        let types = [
+            LogicalType::Geometry { crs: None },
+            LogicalType::Geometry {
+                crs: Some("srid:4326".to_string()),
+            },
+            LogicalType::Geography {
+                crs: None,
+                algorithm: None,
+            },
+            LogicalType::Geography {
+                crs: Some("projjson:crs".to_string()),
+                algorithm: Some(EdgeInterpolationAlgorithm::KARNEY),
+            },
+        ];
+        for logical_type in types {
+            assert_eq!(roundtrip(logical_type.clone()), logical_type);
+        }
+    }
+
+    #[test]
+    fn test_geoarrow_logical_type() {
+        let field = |metadata: &str| {
+            Field::new("geo", DataType::Binary, true).with_metadata(HashMap::from([
+                (EXTENSION_NAME_KEY.to_string(), GEOARROW_WKB.to_string()),
+                (EXTENSION_METADATA_KEY.to_string(), metadata.to_string()),
+            ]))
+        };
+
+        assert_eq!(
+            geoarrow_logical_type(&field("")).unwrap(),
+            Some(LogicalType::Geometry { crs: None })
+        );
+        assert_eq!(
+            geoarrow_logical_type(&field(r#"{"crs":{"id":{"code":3857}},"edges":"planar"}"#))
+                .unwrap(),
+            Some(LogicalType::Geometry {
+                crs: Some(r#"{"id":{"code":3857}}"#.to_string())
+            })
+        );
+        assert!(
+            geoarrow_logical_type(&Field::new("geo", DataType::Binary, true))
+                .unwrap()
+                .is_none()
+        );
+
+        let err = geoarrow_logical_type(&field(r#"{"edges":"great_circle"}"#)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Arrow: Unsupported GeoArrow edges great_circle for field geo"
+        );
+        let err = geoarrow_logical_type(&field("[]")).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("Arrow: Invalid GeoArrow metadata for field geo"));
+    }
+}
@@ -35,10 +35,16 @@ use crate::file::{metadata::KeyValue, properties::WriterProperties};
 use crate::schema::types::{ColumnDescriptor, SchemaDescriptor, Type};
 
 mod complex;
+#[cfg(feature = "geospatial")]
+mod geospatial;
 mod primitive;
+#[cfg(feature = "variant_experimental")]
+mod variant;
 
 use crate::arrow::ProjectionMask;
 pub(crate) use complex::{ParquetField, ParquetFieldType};
+#[cfg(feature = "variant_experimental")]
+pub(crate) use variant::convert_variant_columns;
 
 use super::PARQUET_FIELD_ID_META_KEY;
 
@@ -60,14 +66,17 @@ pub fn parquet_to_arrow_schema_by_columns(
     mask: ProjectionMask,
     key_value_metadata: Option<&Vec<KeyValue>>,
 ) -> Result<Schema> {
-    Ok(parquet_to_arrow_schema_and_fields(parquet_schema, mask, key_value_metadata)?.0)
+    Ok(parquet_to_arrow_schema_and_fields(parquet_schema, mask, key_value_metadata, None)?.0)
 }
 
 /// Extracts the arrow metadata
+///
+/// If provided, `int96_type` is used for INT96 columns in place of the embedded arrow schema
 pub(crate) fn parquet_to_arrow_schema_and_fields(
     parquet_schema: &SchemaDescriptor,
     mask: ProjectionMask,
     key_value_metadata: Option<&Vec<KeyValue>>,
+    int96_type: Option<&DataType>,
 ) -> Result<(Schema, Option<ParquetField>)> {
     let mut metadata = parse_key_value_metadata(key_value_metadata).unwrap_or_default();
     let maybe_schema = metadata
@@ -83,7 +92,7 @@ pub(crate) fn parquet_to_arrow_schema_and_fields(
     }
 
     let hint = maybe_schema.as_ref().map(|s| s.fields());
-    let field_levels = parquet_to_arrow_field_levels(parquet_schema, mask, hint)?;
+    let field_levels = convert_field_levels(parquet_schema, mask, hint, int96_type)?;
     let schema = Schema::new_with_metadata(field_levels.fields, metadata);
     Ok((schema, field_levels.levels))
 }
@@ -126,7 +135,16 @@ pub fn parquet_to_arrow_field_levels(
     mask: ProjectionMask,
     hint: Option<&Fields>,
 ) -> Result<FieldLevels> {
-    match complex::convert_schema(schema, mask, hint)? {
+    convert_field_levels(schema, mask, hint, None)
+}
+
+fn convert_field_levels(
+    schema: &SchemaDescriptor,
+    mask: ProjectionMask,
+    hint: Option<&Fields>,
+    int96_type: Option<&DataType>,
+) -> Result<FieldLevels> {
+    match complex::convert_schema(schema, mask, hint, int96_type)? {
         Some(field) => match &field.arrow_type {
             DataType::Struct(fields) => Ok(FieldLevels {
                 fields: fields.clone(),
@@ -407,6 +425,8 @@ pub fn parquet_to_arrow_field(parquet_column: &ColumnDescriptor) -> Result<Field
             _ => {}
         }
     }
+    #[cfg(feature = "geospatial")]
+    geospatial::add_geoarrow_metadata(basic_info.logical_type().as_ref(), &mut meta);
     if !meta.is_empty() {
         ret.set_metadata(meta);
     }
@@ -425,6 +445,18 @@ pub fn decimal_length_from_precision(precision: u8) -> usize {
     (((10.0_f64.powi(precision as i32) + 1.0).log2() + 1.0) / 8.0).ceil() as usize
 }
 
+/// Returns the [`LogicalType::Geometry`] or [`LogicalType::Geography`] of a binary field
+/// with the GeoArrow WKB extension type
+fn geospatial_logical_type(field: &Field) -> Result<Option<LogicalType>> {
+    #[cfg(feature = "geospatial")]
+    return geospatial::geoarrow_logical_type(field);
+    #[cfg(not(feature = "geospatial"))]
+    {
+        let _ = field;
+        Ok(None)
+    }
+}
+
 /// Convert an arrow field to a parquet `Type`
 fn arrow_to_parquet_type(field: &Field, coerce_types: bool) -> Result<Type> {
     const PARQUET_LIST_ELEMENT_NAME: &str = "element";
@@ -606,6 +638,7 @@ fn arrow_to_parquet_type(field: &Field, coerce_types: bool) -> Result<Type> {
         }
         DataType::Binary | DataType::LargeBinary => {
             Type::primitive_type_builder(name, PhysicalType::BYTE_ARRAY)
+                .with_logical_type(geospatial_logical_type(field)?)
                 .with_repetition(repetition)
                 .with_id(id)
                 .build()
@@ -628,6 +661,7 @@ fn arrow_to_parquet_type(field: &Field, coerce_types: bool) -> Result<Type> {
                 .build()
         }
         DataType::BinaryView => Type::primitive_type_builder(name, PhysicalType::BYTE_ARRAY)
+            .with_logical_type(geospatial_logical_type(field)?)
             .with_repetition(repetition)
             .with_id(id)
             .build(),
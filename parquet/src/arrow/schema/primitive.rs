@@ -18,7 +18,10 @@
 use crate::basic::{ConvertedType, LogicalType, TimeUnit as ParquetTimeUnit, Type as PhysicalType};
 use crate::errors::{ParquetError, Result};
 use crate::schema::types::{BasicTypeInfo, Type};
-use arrow_schema::{DataType, IntervalUnit, TimeUnit, DECIMAL128_MAX_PRECISION};
+use arrow_schema::{
+    DataType, IntervalUnit, TimeUnit, DECIMAL128_MAX_PRECISION, DECIMAL32_MAX_PRECISION,
+    DECIMAL64_MAX_PRECISION,
+};
 
 /// Converts [`Type`] to [`DataType`] with an optional `arrow_type_hint`
 /// provided by the arrow schema
@@ -88,6 +91,18 @@ fn apply_hint(parquet: DataType, hint: DataType) -> DataType {
         // Promote to Decimal256
         (DataType::Decimal128(_, _), DataType::Decimal256(_, _)) => hint,
 
+        // Read as a narrower decimal if the precision fits
+        (DataType::Decimal128(p, _), DataType::Decimal32(_, _))
+            if *p <= DECIMAL32_MAX_PRECISION =>
+        {
+            hint
+        }
+        (DataType::Decimal128(p, _), DataType::Decimal64(_, _))
+            if *p <= DECIMAL64_MAX_PRECISION =>
+        {
+            hint
+        }
+
         // Potentially preserve dictionary encoding
         (_, DataType::Dictionary(_, value)) => {
             // Apply hint to inner type
@@ -274,6 +289,8 @@ fn from_byte_array(info: &BasicTypeInfo, precision: i32, scale: i32) -> Result<D
         (Some(LogicalType::Json), _) => Ok(DataType::Utf8),
         (Some(LogicalType::Bson), _) => Ok(DataType::Binary),
         (Some(LogicalType::Enum), _) => Ok(DataType::Binary),
+        (Some(LogicalType::Geometry { .. }), _) => Ok(DataType::Binary),
+        (Some(LogicalType::Geography { .. }), _) => Ok(DataType::Binary),
         (None, ConvertedType::NONE) => Ok(DataType::Binary),
         (None, ConvertedType::JSON) => Ok(DataType::Utf8),
         (None, ConvertedType::BSON) => Ok(DataType::Binary),
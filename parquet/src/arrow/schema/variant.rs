@@ -0,0 +1,218 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use arrow_schema::{DataType, Field, Fields, Schema};
+use parquet_variant_compute::VariantType;
+
+use crate::arrow::schema::{ParquetField, ParquetFieldType};
+use crate::basic::Type as PhysicalType;
+use crate::errors::{ParquetError, Result};
+
+/// The arrow type of a decoded variant column, matching the arrays built by
+/// [`VariantArrayBuilder`](parquet_variant_compute::VariantArrayBuilder)
+fn variant_data_type() -> DataType {
+    DataType::Struct(Fields::from(vec![
+        Field::new("metadata", DataType::BinaryView, false),
+        Field::new("value", DataType::BinaryView, false),
+    ]))
+}
+
+/// Converts the top-level `columns` of `schema` and `field` to be decoded to variant
+/// arrays, see [`ArrowReaderOptions::with_variant_columns`]
+///
+/// [`ArrowReaderOptions::with_variant_columns`]: crate::arrow::arrow_reader::ArrowReaderOptions::with_variant_columns
+pub(crate) fn convert_variant_columns(
+    schema: &Schema,
+    field: Option<&ParquetField>,
+    columns: &[String],
+) -> Result<(Schema, Option<ParquetField>)> {
+    if let Some(missing) = columns
+        .iter()
+        .find(|name| schema.fields().find(name).is_none())
+    {
+        return Err(arrow_err!("Variant column {} not found in schema", missing));
+    }
+
+    let (Some(field), false) = (field, columns.is_empty()) else {
+        return Ok((schema.clone(), field.cloned()));
+    };
+
+    // The root is a group with a child for every top-level field
+    let children = field.children().expect("root is a group");
+    let mut arrow_fields = Vec::with_capacity(children.len());
+    let mut parquet_fields = Vec::with_capacity(children.len());
+    for (arrow, parquet) in schema.fields().iter().zip(children) {
+        if columns.iter().any(|name| name == arrow.name()) {
+            let (arrow, parquet) = convert_variant_column(arrow, parquet)?;
+            arrow_fields.push(arrow);
+            parquet_fields.push(parquet);
+        } else {
+            arrow_fields.push(arrow.as_ref().clone());
+            parquet_fields.push(parquet.clone());
+        }
+    }
+
+    let schema = Schema::new_with_metadata(arrow_fields, schema.metadata().clone());
+    let field = ParquetField {
+        arrow_type: DataType::Struct(schema.fields().clone()),
+        field_type: ParquetFieldType::Group {
+            children: parquet_fields,
+        },
+        ..field.clone()
+    };
+    Ok((schema, Some(field)))
+}
+
+/// Converts a single column, which must either be a BYTE_ARRAY column of JSON strings
+/// or a variant group
+fn convert_variant_column(arrow: &Field, parquet: &ParquetField) -> Result<(Field, ParquetField)> {
+    let source = match (&parquet.field_type, &parquet.arrow_type) {
+        (ParquetFieldType::Primitive { primitive_type, .. }, _)
+            if primitive_type.get_physical_type() == PhysicalType::BYTE_ARRAY =>
+        {
+            ParquetField {
+                arrow_type: DataType::Utf8,
+                ..parquet.clone()
+            }
+        }
+        (ParquetFieldType::Group { .. }, DataType::Struct(fields)) => {
+            validate_variant_group(arrow.name(), fields)?;
+            parquet.clone()
+        }
+        (_, d) => {
+            return Err(arrow_err!(
+                "Cannot decode column {} of type {} to variant, expected a BYTE_ARRAY column of JSON strings or a variant group",
+                arrow.name(),
+                d
+            ))
+        }
+    };
+
+    let arrow = Field::new(arrow.name(), variant_data_type(), arrow.is_nullable())
+        .with_metadata(arrow.metadata().clone())
+        .with_extension_type(VariantType);
+    let parquet = ParquetField {
+        rep_level: parquet.rep_level,
+        def_level: parquet.def_level,
+        nullable: parquet.nullable,
+        arrow_type: variant_data_type(),
+        field_type: ParquetFieldType::Variant {
+            source: Box::new(source),
+        },
+    };
+    Ok((arrow, parquet))
+}
+
+/// Checks that `fields` are a binary `metadata` field, and a binary `value` field
+/// and/or a `typed_value` field, as described by the [Variant Shredding]
+/// specification
+///
+/// [Variant Shredding]: https://github.com/apache/parquet-format/blob/master/VariantShredding.md
+fn validate_variant_group(name: &str, fields: &Fields) -> Result<()> {
+    let mut has_metadata = false;
+    let mut has_value = false;
+    for field in fields {
+        match field.name().as_str() {
+            "metadata" if is_binary(field.data_type()) => has_metadata = true,
+            "value" if is_binary(field.data_type()) => has_value = true,
+            "typed_value" => {
+                validate_typed_value(name, field.data_type())?;
+                has_value = true
+            }
+            _ => {
+                return Err(arrow_err!(
+                    "Invalid variant group {}, unexpected field {} of type {}",
+                    name,
+                    field.name(),
+                    field.data_type()
+                ))
+            }
+        }
+    }
+
+    if !has_metadata || !has_value {
+        return Err(arrow_err!(
+            "Invalid variant group {}, expected a metadata field and a value or typed_value field",
+            name
+        ));
+    }
+    Ok(())
+}
+
+fn is_binary(data_type: &DataType) -> bool {
+    matches!(
+        data_type,
+        DataType::Binary | DataType::LargeBinary | DataType::BinaryView
+    )
+}
+
+/// Checks that `data_type` is a primitive type, or a shredded object or list
+///
+/// The fields of a shredded object, and the elements of a shredded list, are groups
+/// of a binary `value` field and/or a `typed_value` field
+fn validate_typed_value(name: &str, data_type: &DataType) -> Result<()> {
+    let groups: Vec<&Field> = match data_type {
+        DataType::Struct(fields) => fields.iter().map(|f| f.as_ref()).collect(),
+        DataType::List(element) | DataType::LargeList(element) => vec![element.as_ref()],
+        d if !d.is_nested() => return Ok(()),
+        d => {
+            return Err(arrow_err!(
+                "Decoding variant column {} shredded as {} is not supported",
+                name,
+                d
+            ))
+        }
+    };
+
+    for group in groups {
+        let DataType::Struct(fields) = group.data_type() else {
+            return Err(arrow_err!(
+                "Invalid variant group {}, expected shredded field {} to be a group, found {}",
+                name,
+                group.name(),
+                group.data_type()
+            ));
+        };
+        let mut has_value = false;
+        for field in fields {
+            match field.name().as_str() {
+                "value" if is_binary(field.data_type()) => has_value = true,
+                "typed_value" => {
+                    validate_typed_value(name, field.data_type())?;
+                    has_value = true
+                }
+                _ => {
+                    return Err(arrow_err!(
+                        "Invalid variant group {}, unexpected field {} of type {} in shredded field {}",
+                        name,
+                        field.name(),
+                        field.data_type(),
+                        group.name()
+                    ))
+                }
+            }
+        }
+        if !has_value {
+            return Err(arrow_err!(
+                "Invalid variant group {}, expected shredded field {} to have a value or typed_value field",
+                name,
+                group.name()
+            ));
+        }
+    }
+    Ok(())
+}
@@ -28,8 +28,9 @@ use crate::errors::{ParquetError, Result};
 
 // Re-export crate::format types used in this module
 pub use crate::format::{
-    BsonType, DateType, DecimalType, EnumType, IntType, JsonType, ListType, MapType, NullType,
-    StringType, TimeType, TimeUnit, TimestampType, UUIDType,
+    BsonType, DateType, DecimalType, EdgeInterpolationAlgorithm, EnumType, GeographyType,
+    GeometryType, IntType, JsonType, ListType, MapType, NullType, StringType, TimeType, TimeUnit,
+    TimestampType, UUIDType,
 };
 
 // ----------------------------------------------------------------------
@@ -231,9 +232,17 @@ pub enum LogicalType {
     /// A Variant value.
     Variant,
     /// A geospatial feature in the Well-Known Binary (WKB) format with linear/planar edges interpolation.
-    Geometry,
+    Geometry {
+        /// The coordinate reference system, `OGC:CRS84` if not set.
+        crs: Option<String>,
+    },
     /// A geospatial feature in the WKB format with an explicit (non-linear/non-planar) edges interpolation.
-    Geography,
+    Geography {
+        /// The coordinate reference system, `OGC:CRS84` if not set.
+        crs: Option<String>,
+        /// The algorithm used to interpolate edges, [`EdgeInterpolationAlgorithm::SPHERICAL`] if not set.
+        algorithm: Option<EdgeInterpolationAlgorithm>,
+    },
 }
 
 // ----------------------------------------------------------------------
@@ -584,9 +593,9 @@ impl ColumnOrder {
                 LogicalType::Unknown => SortOrder::UNDEFINED,
                 LogicalType::Uuid => SortOrder::UNSIGNED,
                 LogicalType::Float16 => SortOrder::SIGNED,
-                LogicalType::Variant | LogicalType::Geometry | LogicalType::Geography => {
-                    SortOrder::UNDEFINED
-                }
+                LogicalType::Variant
+                | LogicalType::Geometry { .. }
+                | LogicalType::Geography { .. } => SortOrder::UNDEFINED,
             },
             // Fall back to converted type
             None => Self::get_converted_sort_order(converted_type, physical_type),
@@ -850,8 +859,11 @@ impl From<parquet::LogicalType> for LogicalType {
             parquet::LogicalType::UUID(_) => LogicalType::Uuid,
             parquet::LogicalType::FLOAT16(_) => LogicalType::Float16,
             parquet::LogicalType::VARIANT(_) => LogicalType::Variant,
-            parquet::LogicalType::GEOMETRY(_) => LogicalType::Geometry,
-            parquet::LogicalType::GEOGRAPHY(_) => LogicalType::Geography,
+            parquet::LogicalType::GEOMETRY(t) => LogicalType::Geometry { crs: t.crs },
+            parquet::LogicalType::GEOGRAPHY(t) => LogicalType::Geography {
+                crs: t.crs,
+                algorithm: t.algorithm,
+            },
         }
     }
 }
@@ -894,8 +906,10 @@ impl From<LogicalType> for parquet::LogicalType {
             LogicalType::Uuid => parquet::LogicalType::UUID(Default::default()),
             LogicalType::Float16 => parquet::LogicalType::FLOAT16(Default::default()),
             LogicalType::Variant => parquet::LogicalType::VARIANT(Default::default()),
-            LogicalType::Geometry => parquet::LogicalType::GEOMETRY(Default::default()),
-            LogicalType::Geography => parquet::LogicalType::GEOGRAPHY(Default::default()),
+            LogicalType::Geometry { crs } => parquet::LogicalType::GEOMETRY(GeometryType { crs }),
+            LogicalType::Geography { crs, algorithm } => {
+                parquet::LogicalType::GEOGRAPHY(GeographyType { crs, algorithm })
+            }
         }
     }
 }
@@ -948,8 +962,8 @@ impl From<Option<LogicalType>> for ConvertedType {
                 LogicalType::Uuid
                 | LogicalType::Float16
                 | LogicalType::Variant
-                | LogicalType::Geometry
-                | LogicalType::Geography
+                | LogicalType::Geometry { .. }
+                | LogicalType::Geography { .. }
                 | LogicalType::Unknown => ConvertedType::NONE,
             },
             None => ConvertedType::NONE,
@@ -1199,6 +1213,11 @@ impl str::FromStr for LogicalType {
                 "Interval parquet logical type not yet supported"
             )),
             "FLOAT16" => Ok(LogicalType::Float16),
+            "GEOMETRY" => Ok(LogicalType::Geometry { crs: None }),
+            "GEOGRAPHY" => Ok(LogicalType::Geography {
+                crs: None,
+                algorithm: None,
+            }),
             other => Err(general_err!("Invalid parquet logical type {}", other)),
         }
     }
@@ -2235,10 +2254,37 @@ mod tests {
         check_sort_order(signed, SortOrder::SIGNED);
 
         // Undefined comparison
-        let undefined = vec![LogicalType::List, LogicalType::Map];
+        let undefined = vec![
+            LogicalType::List,
+            LogicalType::Map,
+            LogicalType::Geometry { crs: None },
+            LogicalType::Geography {
+                crs: None,
+                algorithm: None,
+            },
+        ];
         check_sort_order(undefined, SortOrder::UNDEFINED);
     }
 
+    #[test]
+    fn test_geospatial_logical_type_thrift() {
+        let types = [
+            LogicalType::Geometry { crs: None },
+            LogicalType::Geometry {
+                crs: Some("srid:3857".to_string()),
+            },
+            LogicalType::Geography {
+                crs: Some("OGC:CRS84".to_string()),
+                algorithm: Some(EdgeInterpolationAlgorithm::KARNEY),
+            },
+        ];
+        for logical_type in types {
+            let thrift = parquet::LogicalType::from(logical_type.clone());
+            assert_eq!(LogicalType::from(thrift), logical_type);
+            assert_eq!(ConvertedType::from(Some(logical_type)), ConvertedType::NONE);
+        }
+    }
+
     #[test]
     fn test_column_order_get_converted_type_sort_order() {
         // Helper to check the order in a list of values.
@@ -86,6 +86,9 @@ use std::io::Write;
 use thrift::protocol::{TCompactOutputProtocol, TOutputProtocol};
 use twox_hash::XxHash64;
 
+#[cfg(feature = "arrow")]
+mod probe;
+
 /// Salt as defined in the [spec](https://github.com/apache/parquet-format/blob/master/BloomFilter.md#technical-approach).
 const SALT: [u32; 8] = [
     0x47b6137b_u32,
@@ -140,6 +143,13 @@ impl Block {
         }
     }
 
+    /// setting every bit in the block that is set in `other`
+    fn union(&mut self, other: &Self) {
+        for i in 0..8 {
+            self[i] |= other[i];
+        }
+    }
+
     /// returns true when every bit that is set in the result of mask is also set in the block.
     fn check(&self, hash: u32) -> bool {
         let mask = Self::mask(hash);
@@ -380,7 +390,7 @@ impl Sbbf {
     }
 
     /// Check if an [AsBytes] value is probably present or definitely absent in the filter
-    pub fn check<T: AsBytes>(&self, value: &T) -> bool {
+    pub fn check<T: AsBytes + ?Sized>(&self, value: &T) -> bool {
         self.check_hash(hash_as_bytes(value))
     }
 
@@ -396,6 +406,49 @@ impl Sbbf {
     pub(crate) fn estimated_memory_size(&self) -> usize {
         self.0.capacity() * std::mem::size_of::<Block>()
     }
+
+    /// Estimate the number of distinct values inserted into this filter, returning `None`
+    /// if every bit is set and no estimate can be made.
+    ///
+    /// Each insert sets exactly one bit in each of the eight words of a single block, so
+    /// the filter behaves like eight single hash bloom filters of `32 * num_blocks` bits,
+    /// for which the number of insertions follows from the fraction of bits left unset.
+    pub(crate) fn estimated_ndv(&self) -> Option<u64> {
+        let num_bits = (self.0.len() * 256) as f64;
+        let set_bits: u32 = self
+            .0
+            .iter()
+            .flat_map(|block| block.0)
+            .map(u32::count_ones)
+            .sum();
+        let unset = 1.0 - set_bits as f64 / num_bits;
+        (unset > 0.0).then(|| (-(num_bits / 8.0) * unset.ln()).ceil() as u64)
+    }
+
+    /// Shrink this filter to the size required to hold the estimated number of distinct
+    /// values inserted so far at the given false positive probability.
+    ///
+    /// The filter is halved by merging adjacent pairs of blocks, which produces exactly the
+    /// filter that inserting the same values into a filter of half the size would have,
+    /// as a hash maps to block `i` of `n` if and only if it maps to block `i / 2` of `n / 2`.
+    /// The filter never grows, nor is it shrunk below [BITSET_MIN_LENGTH].
+    pub(crate) fn fold_to_fpp(&mut self, fpp: f64) {
+        let Some(ndv) = self.estimated_ndv() else {
+            return;
+        };
+        let num_bytes = optimal_num_of_bytes(num_of_bits_from_ndv_fpp(ndv, fpp) / 8);
+        let num_blocks = num_bytes / size_of::<Block>();
+        while self.0.len() > num_blocks && self.0.len() % 2 == 0 {
+            let half = self.0.len() / 2;
+            for i in 0..half {
+                let mut block = self.0[2 * i];
+                block.union(&self.0[2 * i + 1]);
+                self.0[i] = block;
+            }
+            self.0.truncate(half);
+        }
+        self.0.shrink_to_fit();
+    }
 }
 
 // per spec we use xxHash with seed=0
@@ -441,6 +494,38 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_sbbf_fold() {
+        let mut folded = Sbbf::new_with_num_of_bytes(64 * 1024);
+        for i in 0..1_000 {
+            folded.insert(&i);
+        }
+        let estimate = folded.estimated_ndv().unwrap();
+        assert!((950..1050).contains(&estimate), "{estimate}");
+
+        folded.fold_to_fpp(0.01);
+        let expected_bytes = optimal_num_of_bytes(num_of_bits_from_ndv_fpp(estimate, 0.01) / 8);
+        assert_eq!(folded.0.len() * size_of::<Block>(), expected_bytes);
+
+        // Folding is equivalent to inserting into a filter of the final size
+        let mut direct = Sbbf::new_with_num_of_bytes(expected_bytes);
+        for i in 0..1_000 {
+            direct.insert(&i);
+        }
+        assert_eq!(folded.0.len(), direct.0.len());
+        for (a, b) in folded.0.iter().zip(&direct.0) {
+            assert_eq!(a.0, b.0);
+        }
+
+        // Never grows
+        let mut full = Sbbf::new_with_num_of_bytes(BITSET_MIN_LENGTH);
+        for i in 0..1_000 {
+            full.insert(&i);
+        }
+        full.fold_to_fpp(0.01);
+        assert_eq!(full.0.len() * size_of::<Block>(), BITSET_MIN_LENGTH);
+    }
+
     #[test]
     fn test_with_fixture() {
         // bloom filter produced by parquet-mr/spark for a column of i64 f"a{i}" for i in 0..10
@@ -0,0 +1,304 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Probing a [`Sbbf`] with the values of an arrow [`Array`]
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
+use arrow_array::{downcast_dictionary_array, Array, ArrowPrimitiveType, BooleanArray};
+use arrow_buffer::ArrowNativeType;
+use arrow_schema::{DataType, TimeUnit};
+
+use crate::arrow::decimal_length_from_precision;
+use crate::bloom_filter::Sbbf;
+use crate::data_type::AsBytes;
+use crate::errors::{ParquetError, Result};
+
+impl Sbbf {
+    /// Check each value of `array` against the filter, returning a [`BooleanArray`] that
+    /// is `true` where the value is probably present and `false` where it is definitely
+    /// absent. Null values produce nulls.
+    ///
+    /// Values are hashed using the parquet physical type that [`ArrowWriter`] writes
+    /// for the array's [`DataType`], so the filter should be read from a column written
+    /// from the same arrow type, for example integers narrower than 32 bits are hashed
+    /// as `INT32`, and decimals as `INT32`, `INT64` or `FIXED_LEN_BYTE_ARRAY` depending
+    /// on their precision.
+    ///
+    /// Returns an error if the [`DataType`] is not supported.
+    ///
+    /// ```
+    /// # use arrow_array::{Int32Array, BooleanArray};
+    /// # use parquet::bloom_filter::Sbbf;
+    /// # fn probe(sbbf: &Sbbf) -> parquet::errors::Result<()> {
+    /// // Find which of the candidate values may be present in a column
+    /// let candidates = Int32Array::from(vec![Some(1), None, Some(42)]);
+    /// let probed: BooleanArray = sbbf.probe_array(&candidates)?;
+    /// assert_eq!(probed.len(), candidates.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`ArrowWriter`]: crate::arrow::ArrowWriter
+    pub fn probe_array(&self, array: &dyn Array) -> Result<BooleanArray> {
+        let probed = match array.data_type() {
+            DataType::Boolean => self.probe(array.as_boolean().iter()),
+            DataType::Int8 => self.probe_primitive::<Int8Type, _>(array, |v| v as i32),
+            DataType::Int16 => self.probe_primitive::<Int16Type, _>(array, |v| v as i32),
+            DataType::Int32 => self.probe_primitive::<Int32Type, _>(array, |v| v),
+            DataType::Int64 => self.probe_primitive::<Int64Type, _>(array, |v| v),
+            DataType::UInt8 => self.probe_primitive::<UInt8Type, _>(array, |v| v as i32),
+            DataType::UInt16 => self.probe_primitive::<UInt16Type, _>(array, |v| v as i32),
+            DataType::UInt32 => self.probe_primitive::<UInt32Type, _>(array, |v| v as i32),
+            DataType::UInt64 => self.probe_primitive::<UInt64Type, _>(array, |v| v as i64),
+            DataType::Float16 => {
+                let values = array.as_primitive::<Float16Type>();
+                self.probe_bytes(values.iter().map(|v| v.map(|v| v.to_le_bytes())))
+            }
+            DataType::Float32 => self.probe_primitive::<Float32Type, _>(array, |v| v),
+            DataType::Float64 => self.probe_primitive::<Float64Type, _>(array, |v| v),
+            DataType::Date32 => self.probe_primitive::<Date32Type, _>(array, |v| v),
+            DataType::Date64 => self.probe_primitive::<Date64Type, _>(array, |v| v),
+            DataType::Time32(TimeUnit::Second) => {
+                self.probe_primitive::<Time32SecondType, _>(array, |v| v)
+            }
+            DataType::Time32(TimeUnit::Millisecond) => {
+                self.probe_primitive::<Time32MillisecondType, _>(array, |v| v)
+            }
+            DataType::Time64(TimeUnit::Microsecond) => {
+                self.probe_primitive::<Time64MicrosecondType, _>(array, |v| v)
+            }
+            DataType::Time64(TimeUnit::Nanosecond) => {
+                self.probe_primitive::<Time64NanosecondType, _>(array, |v| v)
+            }
+            DataType::Timestamp(TimeUnit::Second, _) => {
+                self.probe_primitive::<TimestampSecondType, _>(array, |v| v)
+            }
+            DataType::Timestamp(TimeUnit::Millisecond, _) => {
+                self.probe_primitive::<TimestampMillisecondType, _>(array, |v| v)
+            }
+            DataType::Timestamp(TimeUnit::Microsecond, _) => {
+                self.probe_primitive::<TimestampMicrosecondType, _>(array, |v| v)
+            }
+            DataType::Timestamp(TimeUnit::Nanosecond, _) => {
+                self.probe_primitive::<TimestampNanosecondType, _>(array, |v| v)
+            }
+            DataType::Duration(TimeUnit::Second) => {
+                self.probe_primitive::<DurationSecondType, _>(array, |v| v)
+            }
+            DataType::Duration(TimeUnit::Millisecond) => {
+                self.probe_primitive::<DurationMillisecondType, _>(array, |v| v)
+            }
+            DataType::Duration(TimeUnit::Microsecond) => {
+                self.probe_primitive::<DurationMicrosecondType, _>(array, |v| v)
+            }
+            DataType::Duration(TimeUnit::Nanosecond) => {
+                self.probe_primitive::<DurationNanosecondType, _>(array, |v| v)
+            }
+            DataType::Decimal32(precision, _) => {
+                let values = array.as_primitive::<Decimal32Type>();
+                self.probe_decimal(*precision, values.iter().map(|v| v.map(i128::from)))
+            }
+            DataType::Decimal64(precision, _) => {
+                let values = array.as_primitive::<Decimal64Type>();
+                self.probe_decimal(*precision, values.iter().map(|v| v.map(i128::from)))
+            }
+            DataType::Decimal128(precision, _) => {
+                let values = array.as_primitive::<Decimal128Type>();
+                self.probe_decimal(*precision, values.iter())
+            }
+            DataType::Decimal256(precision, _) if *precision <= 18 => {
+                let values = array.as_primitive::<Decimal256Type>();
+                self.probe_decimal(*precision, values.iter().map(|v| v.map(|v| v.as_i128())))
+            }
+            DataType::Decimal256(precision, _) => {
+                let size = decimal_length_from_precision(*precision);
+                let values = array.as_primitive::<Decimal256Type>();
+                self.probe_bytes(
+                    values
+                        .iter()
+                        .map(|v| v.map(|v| v.to_be_bytes()[32 - size..].to_vec())),
+                )
+            }
+            DataType::Utf8 => self.probe_bytes(array.as_string::<i32>().iter()),
+            DataType::LargeUtf8 => self.probe_bytes(array.as_string::<i64>().iter()),
+            DataType::Utf8View => self.probe_bytes(array.as_string_view().iter()),
+            DataType::Binary => self.probe_bytes(array.as_binary::<i32>().iter()),
+            DataType::LargeBinary => self.probe_bytes(array.as_binary::<i64>().iter()),
+            DataType::BinaryView => self.probe_bytes(array.as_binary_view().iter()),
+            DataType::FixedSizeBinary(_) => self.probe_bytes(array.as_fixed_size_binary().iter()),
+            DataType::Dictionary(_, _) => downcast_dictionary_array!(
+                array => {
+                    // Probe each distinct value once, and look up the result of every key
+                    let probed = self.probe_array(array.values())?;
+                    array
+                        .keys()
+                        .iter()
+                        .map(|key| {
+                            let idx = key?.as_usize();
+                            probed.is_valid(idx).then(|| probed.value(idx))
+                        })
+                        .collect()
+                }
+                _ => unreachable!()
+            ),
+            d => return Err(nyi_err!("Probing a bloom filter with {} values", d)),
+        };
+        Ok(probed)
+    }
+
+    /// Check the values of a primitive array after converting them with `f` to the
+    /// representation written by the arrow writer
+    fn probe_primitive<T, V>(&self, array: &dyn Array, f: impl Fn(T::Native) -> V) -> BooleanArray
+    where
+        T: ArrowPrimitiveType,
+        V: AsBytes,
+    {
+        self.probe(array.as_primitive::<T>().iter().map(|v| v.map(&f)))
+    }
+
+    /// Check decimal values, which are written as `INT32`, `INT64` or big endian
+    /// `FIXED_LEN_BYTE_ARRAY` depending on their precision, see
+    /// [`ArrowSchemaConverter`](crate::arrow::ArrowSchemaConverter)
+    fn probe_decimal(
+        &self,
+        precision: u8,
+        values: impl Iterator<Item = Option<i128>>,
+    ) -> BooleanArray {
+        match precision {
+            2..=9 => self.probe(values.map(|v| v.map(|v| v as i32))),
+            ..=18 => self.probe(values.map(|v| v.map(|v| v as i64))),
+            _ => {
+                let size = decimal_length_from_precision(precision);
+                self.probe_bytes(values.map(|v| v.map(|v| v.to_be_bytes()[16 - size..].to_vec())))
+            }
+        }
+    }
+
+    fn probe<V: AsBytes>(&self, values: impl Iterator<Item = Option<V>>) -> BooleanArray {
+        values.map(|v| v.map(|v| self.check(&v))).collect()
+    }
+
+    /// Check values written as `BYTE_ARRAY` or `FIXED_LEN_BYTE_ARRAY`
+    fn probe_bytes<V: AsRef<[u8]>>(&self, values: impl Iterator<Item = Option<V>>) -> BooleanArray {
+        values.map(|v| v.map(|v| self.check(v.as_ref()))).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow_array::{
+        ArrayRef, Decimal128Array, DictionaryArray, Float16Array, Int8Array, RecordBatch,
+        StringArray, UInt32Array,
+    };
+    use bytes::Bytes;
+    use half::f16;
+
+    use super::*;
+    use crate::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    use crate::arrow::ArrowWriter;
+    use crate::file::properties::{ReaderProperties, WriterProperties};
+    use crate::file::reader::{FileReader, SerializedFileReader};
+    use crate::file::serialized_reader::ReadOptionsBuilder;
+
+    /// Writes `array` with a bloom filter, and probes the filter with `candidates`
+    fn write_and_probe(array: ArrayRef, candidates: &dyn Array) -> BooleanArray {
+        let batch = RecordBatch::try_from_iter([("col", array)]).unwrap();
+        let props = WriterProperties::builder()
+            .set_bloom_filter_enabled(true)
+            .build();
+        let mut buffer = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buffer, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        // The array must round trip to the written arrow type
+        let reader = ParquetRecordBatchReaderBuilder::try_new(Bytes::from(buffer.clone())).unwrap();
+        assert_eq!(
+            reader.schema().field(0).data_type(),
+            batch.column(0).data_type()
+        );
+
+        let properties = ReaderProperties::builder()
+            .set_read_bloom_filter(true)
+            .build();
+        let options = ReadOptionsBuilder::new()
+            .with_reader_properties(properties)
+            .build();
+        let reader = SerializedFileReader::new_with_options(Bytes::from(buffer), options).unwrap();
+        let row_group = reader.get_row_group(0).unwrap();
+        let sbbf = row_group.get_column_bloom_filter(0).unwrap();
+        sbbf.probe_array(candidates).unwrap()
+    }
+
+    #[test]
+    fn test_probe_array() {
+        let strings = Arc::new(StringArray::from(vec!["a", "b", "c"]));
+        let candidates = StringArray::from(vec![Some("c"), None, Some("zzz"), Some("a")]);
+        let probed = write_and_probe(strings, &candidates);
+        let expected = BooleanArray::from(vec![Some(true), None, Some(false), Some(true)]);
+        assert_eq!(probed, expected);
+
+        let ints = Arc::new(Int8Array::from(vec![-1, 2, 3]));
+        let probed = write_and_probe(ints, &Int8Array::from(vec![-1, 4]));
+        assert_eq!(probed, BooleanArray::from(vec![true, false]));
+
+        let uints = Arc::new(UInt32Array::from(vec![u32::MAX, 7]));
+        let probed = write_and_probe(uints, &UInt32Array::from(vec![u32::MAX, 8]));
+        assert_eq!(probed, BooleanArray::from(vec![true, false]));
+
+        let halfs = Arc::new(Float16Array::from(vec![f16::from_f32(1.5)]));
+        let candidates = Float16Array::from(vec![f16::from_f32(1.5), f16::from_f32(2.5)]);
+        let probed = write_and_probe(halfs, &candidates);
+        assert_eq!(probed, BooleanArray::from(vec![true, false]));
+
+        for precision in [5, 15, 30] {
+            let decimals = Decimal128Array::from(vec![12345, -99])
+                .with_precision_and_scale(precision, 2)
+                .unwrap();
+            let candidates = Decimal128Array::from(vec![-99, 54321])
+                .with_precision_and_scale(precision, 2)
+                .unwrap();
+            let probed = write_and_probe(Arc::new(decimals), &candidates);
+            assert_eq!(probed, BooleanArray::from(vec![true, false]), "{precision}");
+        }
+    }
+
+    #[test]
+    fn test_probe_dictionary() {
+        let strings = Arc::new(StringArray::from(vec!["a", "b", "c"]));
+        let candidates: DictionaryArray<Int32Type> = vec![Some("b"), Some("x"), None, Some("b")]
+            .into_iter()
+            .collect();
+        let probed = write_and_probe(strings, &candidates);
+        let expected = BooleanArray::from(vec![Some(true), Some(false), None, Some(true)]);
+        assert_eq!(probed, expected);
+    }
+
+    #[test]
+    fn test_probe_unsupported() {
+        let sbbf = Sbbf::new_with_num_of_bytes(32);
+        let array = arrow_array::NullArray::new(1);
+        let err = sbbf.probe_array(&array).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "NYI: Probing a bloom filter with Null values"
+        );
+    }
+}
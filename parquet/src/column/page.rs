@@ -145,6 +145,7 @@ impl Page {
 pub struct CompressedPage {
     compressed_page: Page,
     uncompressed_size: usize,
+    crc: Option<u32>,
 }
 
 impl CompressedPage {
@@ -154,9 +155,25 @@ impl CompressedPage {
         Self {
             compressed_page,
             uncompressed_size,
+            crc: None,
         }
     }
 
+    /// Computes the CRC-32 checksum of the page buffer, to be written in the page header.
+    ///
+    /// This must be called before the page buffer is encrypted, as readers verify
+    /// the checksum of the decrypted page data.
+    #[cfg(feature = "crc")]
+    pub(crate) fn with_checksum(mut self) -> Self {
+        self.crc = Some(crc32fast::hash(self.data()));
+        self
+    }
+
+    /// Returns the CRC-32 checksum of the page buffer, if computed
+    pub fn crc(&self) -> Option<u32> {
+        self.crc
+    }
+
     /// Returns page type.
     pub fn page_type(&self) -> PageType {
         self.compressed_page.page_type()
@@ -207,8 +224,7 @@ impl CompressedPage {
             type_: page_type.into(),
             uncompressed_page_size: uncompressed_size as i32,
             compressed_page_size: compressed_size as i32,
-            // TODO: Add support for crc checksum
-            crc: None,
+            crc: self.crc.map(|crc| crc as i32),
             data_page_header: None,
             index_page_header: None,
             dictionary_page_header: None,
@@ -126,6 +126,14 @@ pub struct GenericColumnReader<R, D, V> {
     /// True if the end of the current data page denotes the end of a record
     has_record_delimiter: bool,
 
+    /// The number of records in the current data page, if known without decoding
+    /// its repetition levels
+    num_buffered_records: Option<usize>,
+
+    /// The number of records from the current data page that have been read or
+    /// skipped so far, only tracked for repeated columns
+    num_decoded_records: usize,
+
     /// The decoder for the definition levels if any
     def_level_decoder: Option<D>,
 
@@ -182,6 +190,8 @@ where
             num_decoded_values: 0,
             values_decoder,
             has_record_delimiter: false,
+            num_buffered_records: None,
+            num_decoded_records: 0,
         }
     }
 
@@ -235,6 +245,7 @@ where
                         assert!(records_read < remaining_records); // Sanity check
                         records_read += reader.flush_partial() as usize;
                     }
+                    self.num_decoded_records += records_read;
                     (records_read, levels_read)
                 }
                 None => {
@@ -320,6 +331,21 @@ where
                 }
             }
 
+            // If the rest of the page is skipped, discard it without decoding its
+            // levels or values, which for repeated columns may be far more than
+            // the number of records
+            if let Some(records) = self.remaining_page_records() {
+                if records <= remaining_records {
+                    if let Some(decoder) = self.rep_level_decoder.as_mut() {
+                        decoder.flush_partial();
+                    }
+                    self.num_decoded_values = self.num_buffered_values;
+                    self.num_decoded_records += records;
+                    remaining_records -= records;
+                    continue;
+                }
+            }
+
             // start skip values in page level
 
             // The number of levels in the current data page
@@ -336,6 +362,7 @@ where
                         assert!(records_read < remaining_records); // Sanity check
                         records_read += decoder.flush_partial() as usize;
                     }
+                    self.num_decoded_records += records_read;
 
                     (records_read, levels_read)
                 }
@@ -379,6 +406,22 @@ where
         Ok(num_records - remaining_records)
     }
 
+    /// Returns the number of records remaining in the current data page, if known
+    ///
+    /// For repeated columns this requires the number of records in the page, and
+    /// that no record continues into the next page
+    fn remaining_page_records(&self) -> Option<usize> {
+        match self.rep_level_decoder {
+            None => Some(self.num_buffered_values - self.num_decoded_values),
+            Some(_) => {
+                let records = self
+                    .num_buffered_records
+                    .filter(|_| self.has_record_delimiter)?;
+                Some(records.saturating_sub(self.num_decoded_records))
+            }
+        }
+    }
+
     /// Read the next page as a dictionary page. If the next page is not a dictionary page,
     /// this will return an error.
     fn read_dictionary_page(&mut self) -> Result<()> {
@@ -401,6 +444,11 @@ where
     /// Returns false if there's no page left.
     fn read_new_page(&mut self) -> Result<bool> {
         loop {
+            // The page index may record the number of rows of a data page v1
+            let num_rows = match self.rep_level_decoder.is_some() {
+                true => self.page_reader.peek_next_page()?.and_then(|m| m.num_rows),
+                false => None,
+            };
             match self.page_reader.get_next_page()? {
                 // No more page to read
                 None => return Ok(false),
@@ -428,6 +476,8 @@ where
                         } => {
                             self.num_buffered_values = num_values as _;
                             self.num_decoded_values = 0;
+                            self.num_buffered_records = num_rows;
+                            self.num_decoded_records = 0;
 
                             let max_rep_level = self.descr.max_rep_level();
                             let max_def_level = self.descr.max_def_level();
@@ -481,7 +531,7 @@ where
                             num_values,
                             encoding,
                             num_nulls,
-                            num_rows: _,
+                            num_rows,
                             def_levels_byte_len,
                             rep_levels_byte_len,
                             is_compressed: _,
@@ -493,6 +543,8 @@ where
 
                             self.num_buffered_values = num_values as _;
                             self.num_decoded_values = 0;
+                            self.num_buffered_records = Some(num_rows as _);
+                            self.num_decoded_records = 0;
 
                             // DataPage v2 only supports RLE encoding for repetition
                             // levels
@@ -554,7 +606,7 @@ where
     }
 }
 
-fn parse_v1_level(
+pub(crate) fn parse_v1_level(
     max_level: i16,
     num_buffered_values: u32,
     encoding: Encoding,
@@ -587,8 +639,11 @@ mod tests {
     use std::{collections::VecDeque, sync::Arc};
 
     use crate::basic::Type as PhysicalType;
+    use crate::column::reader::decoder::ColumnLevelDecoder;
     use crate::schema::types::{ColumnDescriptor, ColumnPath, Type as SchemaType};
-    use crate::util::test_common::page_util::InMemoryPageReader;
+    use crate::util::test_common::page_util::{
+        DataPageBuilder, DataPageBuilderImpl, InMemoryPageReader,
+    };
     use crate::util::test_common::rand_gen::make_pages;
 
     const NUM_LEVELS: usize = 128;
@@ -1032,6 +1087,84 @@ mod tests {
         );
     }
 
+    /// A [`RepetitionLevelDecoder`] that fails if asked to skip repetition levels
+    struct NoSkipRepetitionLevelDecoder(RepetitionLevelDecoderImpl);
+
+    impl ColumnLevelDecoder for NoSkipRepetitionLevelDecoder {
+        type Buffer = Vec<i16>;
+
+        fn set_data(&mut self, encoding: Encoding, data: Bytes) {
+            self.0.set_data(encoding, data)
+        }
+    }
+
+    impl RepetitionLevelDecoder for NoSkipRepetitionLevelDecoder {
+        fn read_rep_levels(
+            &mut self,
+            out: &mut Self::Buffer,
+            num_records: usize,
+            num_levels: usize,
+        ) -> Result<(usize, usize)> {
+            self.0.read_rep_levels(out, num_records, num_levels)
+        }
+
+        fn skip_rep_levels(&mut self, _: usize, _: usize) -> Result<(usize, usize)> {
+            Err(general_err!("unexpected skip of repetition levels"))
+        }
+
+        fn flush_partial(&mut self) -> bool {
+            self.0.flush_partial()
+        }
+    }
+
+    #[test]
+    fn test_skip_records_discards_rest_of_page() {
+        let desc = Arc::new(ColumnDescriptor::new(
+            Arc::new(get_test_int32_type()),
+            1,
+            1,
+            ColumnPath::new(Vec::new()),
+        ));
+
+        // Two pages of three records, with 2, 3 and 1 values
+        let pages: Vec<_> = (0..2)
+            .map(|_| {
+                let mut builder = DataPageBuilderImpl::new(desc.clone(), 6, true);
+                builder.add_rep_levels(1, &[0, 1, 0, 1, 1, 0]);
+                builder.add_def_levels(1, &[1; 6]);
+                builder.add_values::<Int32Type>(Encoding::PLAIN, &[1, 2, 3, 4, 5, 6]);
+                builder.consume()
+            })
+            .collect();
+
+        let mut reader = GenericColumnReader::new_with_decoders(
+            desc.clone(),
+            Box::new(InMemoryPageReader::new(pages)),
+            ColumnValueDecoderImpl::<Int32Type>::new(&desc),
+            Some(DefinitionLevelDecoderImpl::new(1)),
+            Some(NoSkipRepetitionLevelDecoder(
+                RepetitionLevelDecoderImpl::new(1),
+            )),
+        );
+
+        let mut def_levels = Vec::new();
+        let mut rep_levels = Vec::new();
+        let mut values = Vec::new();
+        let mut read = |reader: &mut GenericColumnReader<_, _, _>| {
+            reader
+                .read_records(1, Some(&mut def_levels), Some(&mut rep_levels), &mut values)
+                .unwrap()
+        };
+
+        // The first page is skipped entirely, and the last two records of the
+        // second page, which ends with a whole record, without decoding their levels
+        assert_eq!(reader.skip_records(3).unwrap(), 3);
+        assert_eq!(read(&mut reader), (1, 2, 2));
+        assert_eq!(reader.skip_records(10).unwrap(), 2);
+        assert!(!reader.has_next().unwrap());
+        assert_eq!(values, vec![1, 2]);
+    }
+
     // ----------------------------------------------------------------------
     // Helper methods to make pages and test
     //
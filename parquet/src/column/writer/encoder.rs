@@ -24,10 +24,11 @@ use crate::column::writer::{
     compare_greater, fallback_encoding, has_dictionary_support, is_nan, update_max, update_min,
 };
 use crate::data_type::private::ParquetValueType;
-use crate::data_type::DataType;
+use crate::data_type::{AsBytes, DataType};
 use crate::encodings::encoding::{get_encoder, DictEncoder, Encoder};
 use crate::errors::{ParquetError, Result};
 use crate::file::properties::{EnabledStatistics, WriterProperties};
+use crate::geospatial::{GeospatialAccumulator, GeospatialStatistics};
 use crate::schema::types::{ColumnDescPtr, ColumnDescriptor};
 
 /// A collection of [`ParquetValueType`] encoded by a [`ColumnValueEncoder`]
@@ -101,6 +102,9 @@ pub trait ColumnValueEncoder {
     /// Returns an estimate of the encoded size of dictionary page size in bytes, or `None` if no dictionary
     fn estimated_dict_page_size(&self) -> Option<usize>;
 
+    /// Returns the number of entries in the dictionary, or `None` if no dictionary
+    fn num_dict_entries(&self) -> Option<usize>;
+
     /// Returns an estimate of the encoded data page size in bytes
     ///
     /// This should include:
@@ -121,6 +125,18 @@ pub trait ColumnValueEncoder {
     /// will *not* be tracked by the bloom filter as it is empty since. This should be called once
     /// near the end of encoding.
     fn flush_bloom_filter(&mut self) -> Option<Sbbf>;
+
+    /// Flushes the geospatial statistics of a [`Geometry`] or [`Geography`] column if
+    /// statistics are enabled, otherwise returns `None`. This should be called once near
+    /// the end of encoding.
+    ///
+    /// Defaults to `None`, for encoders that do not compute geospatial statistics
+    ///
+    /// [`Geometry`]: LogicalType::Geometry
+    /// [`Geography`]: LogicalType::Geography
+    fn flush_geospatial_statistics(&mut self) -> Option<Box<GeospatialStatistics>> {
+        None
+    }
 }
 
 pub struct ColumnValueEncoderImpl<T: DataType> {
@@ -132,7 +148,9 @@ pub struct ColumnValueEncoderImpl<T: DataType> {
     min_value: Option<T::T>,
     max_value: Option<T::T>,
     bloom_filter: Option<Sbbf>,
+    bloom_filter_adaptive_fpp: Option<f64>,
     variable_length_bytes: Option<i64>,
+    geo_stats: Option<GeospatialAccumulator>,
 }
 
 impl<T: DataType> ColumnValueEncoderImpl<T> {
@@ -147,6 +165,8 @@ impl<T: DataType> ColumnValueEncoderImpl<T> {
         if self.statistics_enabled != EnabledStatistics::None
             // INTERVAL has undefined sort order, so don't write min/max stats for it
             && self.descr.converted_type() != ConvertedType::INTERVAL
+            // Geospatial columns record geospatial statistics instead
+            && self.geo_stats.is_none()
         {
             if let Some((min, max)) = self.min_max(slice, None) {
                 update_min(&self.descr, &min, &mut self.min_value);
@@ -158,6 +178,12 @@ impl<T: DataType> ColumnValueEncoderImpl<T> {
             }
         }
 
+        if let Some(geo_stats) = &mut self.geo_stats {
+            for value in slice {
+                geo_stats.update(value.as_bytes());
+            }
+        }
+
         // encode the values into bloom filter if enabled
         if let Some(bloom_filter) = &mut self.bloom_filter {
             for value in slice {
@@ -178,7 +204,15 @@ impl<T: DataType> ColumnValueEncoder for ColumnValueEncoderImpl<T> {
     type Values = [T::T];
 
     fn flush_bloom_filter(&mut self) -> Option<Sbbf> {
-        self.bloom_filter.take()
+        let mut bloom_filter = self.bloom_filter.take()?;
+        if let Some(fpp) = self.bloom_filter_adaptive_fpp {
+            bloom_filter.fold_to_fpp(fpp);
+        }
+        Some(bloom_filter)
+    }
+
+    fn flush_geospatial_statistics(&mut self) -> Option<Box<GeospatialStatistics>> {
+        self.geo_stats.as_mut()?.finish()
     }
 
     fn try_new(descr: &ColumnDescPtr, props: &WriterProperties) -> Result<Self> {
@@ -195,11 +229,18 @@ impl<T: DataType> ColumnValueEncoder for ColumnValueEncoderImpl<T> {
         )?;
 
         let statistics_enabled = props.statistics_enabled(descr.path());
+        let geo_stats = (statistics_enabled != EnabledStatistics::None)
+            .then(|| GeospatialAccumulator::try_new(descr))
+            .flatten();
 
         let bloom_filter = props
             .bloom_filter_properties(descr.path())
             .map(|props| Sbbf::new_with_ndv_fpp(props.ndv, props.fpp))
             .transpose()?;
+        let bloom_filter_adaptive_fpp = props
+            .bloom_filter_properties(descr.path())
+            .filter(|_| props.bloom_filter_adaptive_ndv(descr.path()))
+            .map(|props| props.fpp);
 
         Ok(Self {
             encoder,
@@ -208,9 +249,11 @@ impl<T: DataType> ColumnValueEncoder for ColumnValueEncoderImpl<T> {
             num_values: 0,
             statistics_enabled,
             bloom_filter,
+            bloom_filter_adaptive_fpp,
             min_value: None,
             max_value: None,
             variable_length_bytes: None,
+            geo_stats,
         })
     }
 
@@ -264,6 +307,10 @@ impl<T: DataType> ColumnValueEncoder for ColumnValueEncoderImpl<T> {
         Some(self.dict_encoder.as_ref()?.dict_encoded_size())
     }
 
+    fn num_dict_entries(&self) -> Option<usize> {
+        Some(self.dict_encoder.as_ref()?.num_entries())
+    }
+
     fn estimated_data_page_size(&self) -> usize {
         match &self.dict_encoder {
             Some(encoder) => encoder.estimated_data_encoded_size(),
@@ -27,6 +27,10 @@ use std::str;
 
 use crate::basic::{Compression, ConvertedType, Encoding, LogicalType, PageType, Type};
 use crate::column::page::{CompressedPage, Page, PageWriteSpec, PageWriter};
+use crate::column::reader::decoder::{
+    ColumnLevelDecoder, RepetitionLevelDecoder, RepetitionLevelDecoderImpl,
+};
+use crate::column::reader::parse_v1_level;
 use crate::column::writer::encoder::{ColumnValueEncoder, ColumnValueEncoderImpl, ColumnValues};
 use crate::compression::{create_codec, Codec, CodecOptionsBuilder};
 use crate::data_type::private::ParquetValueType;
@@ -41,7 +45,7 @@ use crate::file::metadata::{
 };
 use crate::file::page_encoding_stats::PageEncodingStats;
 use crate::file::properties::{
-    EnabledStatistics, WriterProperties, WriterPropertiesPtr, WriterVersion,
+    DataPageVersion, EnabledStatistics, WriterProperties, WriterPropertiesPtr, WriterVersion,
 };
 use crate::file::statistics::{Statistics, ValueStatistics};
 use crate::schema::types::{ColumnDescPtr, ColumnDescriptor};
@@ -96,6 +100,12 @@ impl ColumnWriter<'_> {
         downcast_writer!(self, typed, typed.get_estimated_total_bytes())
     }
 
+    /// Write a page that has already been encoded and compressed, see
+    /// [`GenericColumnWriter::write_compressed_page`]
+    pub fn write_compressed_page(&mut self, page: CompressedPage) -> Result<()> {
+        downcast_writer!(self, typed, typed.write_compressed_page(page))
+    }
+
     /// Close this [`ColumnWriter`]
     pub fn close(self) -> Result<ColumnCloseResult> {
         downcast_writer!(self, typed, typed.close())
@@ -322,6 +332,8 @@ pub struct GenericColumnWriter<'a, E: ColumnValueEncoder> {
     descr: ColumnDescPtr,
     props: WriterPropertiesPtr,
     statistics_enabled: EnabledStatistics,
+    statistics_truncate_length: Option<usize>,
+    data_page_version: DataPageVersion,
 
     page_writer: Box<dyn PageWriter + 'a>,
     codec: Compression,
@@ -336,6 +348,8 @@ pub struct GenericColumnWriter<'a, E: ColumnValueEncoder> {
     /// but we use a BTreeSet so that the output is deterministic
     encodings: BTreeSet<Encoding>,
     encoding_stats: Vec<PageEncodingStats>,
+    /// The number of non-null values written while dictionary encoding
+    num_dict_values: u64,
     // Reused buffers
     def_levels_sink: Vec<i16>,
     rep_levels_sink: Vec<i16>,
@@ -350,6 +364,9 @@ pub struct GenericColumnWriter<'a, E: ColumnValueEncoder> {
     data_page_boundary_descending: bool,
     /// (min, max)
     last_non_null_data_page_min_max: Option<(E::T, E::T)>,
+
+    /// Whether pages have been written with [`Self::write_compressed_page`]
+    wrote_compressed_pages: bool,
 }
 
 impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
@@ -365,6 +382,8 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
         let encoder = E::try_new(&descr, props.as_ref()).unwrap();
 
         let statistics_enabled = props.statistics_enabled(descr.path());
+        let statistics_truncate_length = props.column_statistics_truncate_length(descr.path());
+        let data_page_version = props.data_page_version(descr.path());
 
         let mut encodings = BTreeSet::new();
         // Used for level information
@@ -399,6 +418,8 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
             descr,
             props,
             statistics_enabled,
+            statistics_truncate_length,
+            data_page_version,
             page_writer,
             codec,
             compressor,
@@ -412,9 +433,11 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
             offset_index_builder,
             encodings,
             encoding_stats: vec![],
+            num_dict_values: 0,
             data_page_boundary_ascending: true,
             data_page_boundary_descending: true,
             last_non_null_data_page_min_max: None,
+            wrote_compressed_pages: false,
         }
     }
 
@@ -429,6 +452,12 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
         max: Option<&E::T>,
         distinct_count: Option<u64>,
     ) -> Result<usize> {
+        if self.wrote_compressed_pages {
+            return Err(general_err!(
+                "Cannot write values to a column containing pre-encoded pages"
+            ));
+        }
+
         // Check if number of definition levels is the same as number of repetition levels.
         if let (Some(def), Some(rep)) = (def_levels, rep_levels) {
             if def.len() != rep.len() {
@@ -590,13 +619,115 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
         &self.descr
     }
 
+    /// Writes a page that has already been encoded and compressed, for example
+    /// one read with [`SerializedPageReader::get_next_compressed_page`], without
+    /// decoding its values.
+    ///
+    /// This allows copying pages between files, or changing how they are
+    /// encrypted, more efficiently than decoding and re-encoding their values.
+    /// The pages are encrypted if encryption is configured for this column.
+    ///
+    /// The page must be compressed with the codec of this column, as returned
+    /// by [`WriterProperties::compression`]. A dictionary page must be written
+    /// before any data pages, and values cannot also be written to this column
+    /// with [`Self::write_batch`].
+    ///
+    /// As the values are not decoded, the column chunk statistics, column index
+    /// and bloom filter are not written for this column.
+    ///
+    /// [`SerializedPageReader::get_next_compressed_page`]: crate::file::serialized_reader::SerializedPageReader::get_next_compressed_page
+    pub fn write_compressed_page(&mut self, page: CompressedPage) -> Result<()> {
+        if !self.wrote_compressed_pages {
+            if self.column_metrics.total_num_values > 0 || self.page_metrics.num_buffered_values > 0
+            {
+                return Err(general_err!(
+                    "Cannot write pre-encoded pages to a column containing values"
+                ));
+            }
+            self.wrote_compressed_pages = true;
+            self.column_index_builder.to_invalid();
+        }
+
+        #[cfg(feature = "crc")]
+        let page = match self.props.page_checksum_enabled() {
+            true => page.with_checksum(),
+            false => page,
+        };
+
+        let num_rows = match page.compressed_page() {
+            Page::DictionaryPage { .. } => {
+                if self.column_metrics.dictionary_page_offset.is_some()
+                    || self.column_metrics.data_page_offset.is_some()
+                {
+                    return Err(general_err!(
+                        "A dictionary page must be written once, before any data pages"
+                    ));
+                }
+                self.encodings.insert(page.encoding());
+                self.encoding_stats.push(PageEncodingStats {
+                    page_type: PageType::DICTIONARY_PAGE,
+                    encoding: page.encoding(),
+                    count: 1,
+                });
+                let page_spec = self.page_writer.write_page(page)?;
+                self.update_metrics_for_page(page_spec);
+                return Ok(());
+            }
+            Page::DataPageV2 { num_rows, .. } => *num_rows as usize,
+            Page::DataPage { num_values, .. } if self.descr.max_rep_level() == 0 => {
+                *num_values as usize
+            }
+            Page::DataPage { .. } => self.count_page_rows(&page)?,
+        };
+
+        if let Some(builder) = self.offset_index_builder.as_mut() {
+            builder.append_row_count(num_rows as i64);
+        }
+        self.column_metrics.total_rows_written += num_rows as u64;
+        self.write_data_page(page)
+    }
+
+    /// Counts the rows of a V1 data page of a repeated column by decoding its
+    /// repetition levels
+    ///
+    /// Only the records starting in the page, with a repetition level of 0, are
+    /// counted, as a record continued from a previous page was counted there
+    fn count_page_rows(&mut self, page: &CompressedPage) -> Result<usize> {
+        let Page::DataPage {
+            buf,
+            num_values,
+            rep_level_encoding,
+            ..
+        } = page.compressed_page()
+        else {
+            unreachable!("expected V1 data page")
+        };
+
+        let buf = match self.compressor.as_mut() {
+            Some(compressor) => {
+                let mut decompressed = Vec::with_capacity(page.uncompressed_size());
+                compressor.decompress(buf, &mut decompressed, Some(page.uncompressed_size()))?;
+                Bytes::from(decompressed)
+            }
+            None => buf.clone(),
+        };
+
+        let max_rep_level = self.descr.max_rep_level();
+        let (_, levels) = parse_v1_level(max_rep_level, *num_values, *rep_level_encoding, buf)?;
+        let mut decoder = RepetitionLevelDecoderImpl::new(max_rep_level);
+        decoder.set_data(*rep_level_encoding, levels);
+        let mut rep_levels = Vec::with_capacity(*num_values as usize);
+        decoder.read_rep_levels(&mut rep_levels, usize::MAX, *num_values as usize)?;
+        Ok(rep_levels.iter().filter(|level| **level == 0).count())
+    }
+
     /// Finalizes writes and closes the column writer.
     /// Returns total bytes written, total rows written and column chunk metadata.
     pub fn close(mut self) -> Result<ColumnCloseResult> {
         if self.page_metrics.num_buffered_values > 0 {
             self.add_data_page()?;
         }
-        if self.encoder.has_dictionary() {
+        if self.encoder.has_dictionary() && !self.wrote_compressed_pages {
             self.write_dictionary_page()?;
         }
         self.flush_data_pages()?;
@@ -622,10 +753,12 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
 
         let offset_index = self.offset_index_builder.map(|b| b.build_to_thrift());
 
+        let bloom_filter = self.encoder.flush_bloom_filter();
+
         Ok(ColumnCloseResult {
             bytes_written: self.column_metrics.total_bytes_written,
             rows_written: self.column_metrics.total_rows_written,
-            bloom_filter: self.encoder.flush_bloom_filter(),
+            bloom_filter: bloom_filter.filter(|_| !self.wrote_compressed_pages),
             metadata,
             column_index,
             offset_index,
@@ -709,12 +842,14 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
         }
 
         self.page_metrics.num_buffered_values += num_levels as u32;
+        self.num_dict_values += values_to_write as u64;
 
-        if self.should_add_data_page() {
+        let page_added = self.should_add_data_page();
+        if page_added {
             self.add_data_page()?;
         }
 
-        if self.should_dict_fallback() {
+        if self.should_dict_fallback(page_added) {
             self.dict_fallback()?;
         }
 
@@ -724,16 +859,24 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
     /// Returns true if we need to fall back to non-dictionary encoding.
     ///
     /// We can only fall back if dictionary encoder is set and we have exceeded dictionary
-    /// size.
+    /// size, or, once a data page has been completed, the ratio of dictionary entries to
+    /// values exceeds the configured dictionary fallback threshold.
     #[inline]
-    fn should_dict_fallback(&self) -> bool {
-        match self.encoder.estimated_dict_page_size() {
-            Some(size) => {
-                size >= self
-                    .props
-                    .column_dictionary_page_size_limit(self.descr.path())
+    fn should_dict_fallback(&self, page_added: bool) -> bool {
+        let Some(size) = self.encoder.estimated_dict_page_size() else {
+            return false;
+        };
+        let path = self.descr.path();
+        if size >= self.props.column_dictionary_page_size_limit(path) {
+            return true;
+        }
+
+        match self.props.column_dictionary_fallback_threshold(path) {
+            Some(threshold) if page_added && self.num_dict_values > 0 => {
+                let num_entries = self.encoder.num_dict_entries().unwrap_or_default();
+                num_entries as f64 > threshold * self.num_dict_values as f64
             }
-            None => false,
+            _ => false,
         }
     }
 
@@ -946,11 +1089,11 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
         match statistics {
             Statistics::ByteArray(stats) if stats._internal_has_min_max_set() => {
                 let (min, did_truncate_min) = self.truncate_min_value(
-                    self.props.statistics_truncate_length(),
+                    self.statistics_truncate_length,
                     stats.min_bytes_opt().unwrap(),
                 );
                 let (max, did_truncate_max) = self.truncate_max_value(
-                    self.props.statistics_truncate_length(),
+                    self.statistics_truncate_length,
                     stats.max_bytes_opt().unwrap(),
                 );
                 Statistics::ByteArray(
@@ -969,11 +1112,11 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
                 if (stats._internal_has_min_max_set() && self.can_truncate_value()) =>
             {
                 let (min, did_truncate_min) = self.truncate_min_value(
-                    self.props.statistics_truncate_length(),
+                    self.statistics_truncate_length,
                     stats.min_bytes_opt().unwrap(),
                 );
                 let (max, did_truncate_max) = self.truncate_max_value(
-                    self.props.statistics_truncate_length(),
+                    self.statistics_truncate_length,
                     stats.max_bytes_opt().unwrap(),
                 );
                 Statistics::FixedLenByteArray(
@@ -1039,8 +1182,8 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
             .filter(|_| self.props.write_page_header_statistics(self.descr.path()))
             .map(|stats| self.truncate_statistics(Statistics::from(stats)));
 
-        let compressed_page = match self.props.writer_version() {
-            WriterVersion::PARQUET_1_0 => {
+        let compressed_page = match self.data_page_version {
+            DataPageVersion::V1 => {
                 let mut buffer = vec![];
 
                 if max_rep_level > 0 {
@@ -1081,9 +1224,9 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
                     statistics: page_statistics,
                 };
 
-                CompressedPage::new(data_page, uncompressed_size)
+                self.new_compressed_page(data_page, uncompressed_size)
             }
-            WriterVersion::PARQUET_2_0 => {
+            DataPageVersion::V2 => {
                 let mut rep_levels_byte_len = 0;
                 let mut def_levels_byte_len = 0;
                 let mut buffer = vec![];
@@ -1123,7 +1266,7 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
                     statistics: page_statistics,
                 };
 
-                CompressedPage::new(data_page, uncompressed_size)
+                self.new_compressed_page(data_page, uncompressed_size)
             }
         };
 
@@ -1180,7 +1323,7 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
             .set_data_page_offset(data_page_offset)
             .set_dictionary_page_offset(dict_page_offset);
 
-        if self.statistics_enabled != EnabledStatistics::None {
+        if self.statistics_enabled != EnabledStatistics::None && !self.wrote_compressed_pages {
             let backwards_compatible_min_max = self.descr.sort_order().is_signed();
 
             let statistics = ValueStatistics::<E::T>::new(
@@ -1203,7 +1346,8 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
                 )
                 .set_definition_level_histogram(
                     self.column_metrics.definition_level_histogram.take(),
-                );
+                )
+                .set_geo_statistics(self.encoder.flush_geospatial_statistics());
         }
 
         builder = self.set_column_chunk_encryption_properties(builder);
@@ -1284,7 +1428,7 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
                 encoding: self.props.dictionary_page_encoding(),
                 is_sorted: page.is_sorted,
             };
-            CompressedPage::new(dict_page, uncompressed_size)
+            self.new_compressed_page(dict_page, uncompressed_size)
         };
 
         self.encodings.insert(compressed_page.encoding());
@@ -1299,6 +1443,16 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
         Ok(())
     }
 
+    /// Creates a [`CompressedPage`], computing its checksum if enabled.
+    fn new_compressed_page(&self, page: Page, uncompressed_size: usize) -> CompressedPage {
+        let page = CompressedPage::new(page, uncompressed_size);
+        #[cfg(feature = "crc")]
+        if self.props.page_checksum_enabled() {
+            return page.with_checksum();
+        }
+        page
+    }
+
     /// Updates column writer metrics with each page metadata.
     #[inline]
     fn update_metrics_for_page(&mut self, page_spec: PageWriteSpec) {
@@ -2149,6 +2303,59 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_column_writer_geospatial_statistics() {
+        let wkb_point = |x: f64, y: f64| {
+            let mut wkb = vec![1, 1, 0, 0, 0];
+            wkb.extend_from_slice(&x.to_le_bytes());
+            wkb.extend_from_slice(&y.to_le_bytes());
+            ByteArray::from(wkb)
+        };
+        let tpe = SchemaType::primitive_type_builder("geom", Type::BYTE_ARRAY)
+            .with_logical_type(Some(LogicalType::Geometry { crs: None }))
+            .build()
+            .unwrap();
+        let descr = Arc::new(ColumnDescriptor::new(
+            Arc::new(tpe),
+            0,
+            0,
+            ColumnPath::from("geom"),
+        ));
+
+        let column_writer =
+            get_column_writer(descr.clone(), Default::default(), get_test_page_writer());
+        let mut writer = get_typed_column_writer::<ByteArrayType>(column_writer);
+        writer
+            .write_batch(&[wkb_point(1.0, -2.0), wkb_point(-3.0, 4.0)], None, None)
+            .unwrap();
+        let metadata = writer.close().unwrap().metadata;
+
+        // Min/max are undefined for geospatial columns
+        let stats = metadata.statistics().unwrap();
+        assert!(stats.min_bytes_opt().is_none());
+        assert!(stats.max_bytes_opt().is_none());
+
+        let geo_stats = metadata.geo_statistics().unwrap();
+        let bbox = geo_stats.bbox().unwrap();
+        assert_eq!(
+            (bbox.xmin(), bbox.xmax(), bbox.ymin(), bbox.ymax()),
+            (-3.0, 1.0, -2.0, 4.0)
+        );
+        assert_eq!(geo_stats.geospatial_types(), Some([1].as_slice()));
+
+        // Not computed with statistics disabled
+        let props = WriterProperties::builder()
+            .set_statistics_enabled(EnabledStatistics::None)
+            .build();
+        let column_writer = get_column_writer(descr, Arc::new(props), get_test_page_writer());
+        let mut writer = get_typed_column_writer::<ByteArrayType>(column_writer);
+        writer
+            .write_batch(&[wkb_point(1.0, -2.0)], None, None)
+            .unwrap();
+        let metadata = writer.close().unwrap().metadata;
+        assert!(metadata.geo_statistics().is_none());
+    }
+
     #[test]
     fn test_column_writer_uint32_converted_type_min_max() {
         let page_writer = get_test_page_writer();
@@ -2335,6 +2542,75 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_column_writer_data_page_version() {
+        for (writer_version, data_page_version, page_type) in [
+            (
+                WriterVersion::PARQUET_1_0,
+                DataPageVersion::V2,
+                PageType::DATA_PAGE_V2,
+            ),
+            (
+                WriterVersion::PARQUET_2_0,
+                DataPageVersion::V1,
+                PageType::DATA_PAGE,
+            ),
+        ] {
+            for codec in [Compression::UNCOMPRESSED, Compression::SNAPPY] {
+                let mut buf = Vec::with_capacity(100);
+                let mut write = TrackedWrite::new(&mut buf);
+                let page_writer = Box::new(SerializedPageWriter::new(&mut write));
+                let props = WriterProperties::builder()
+                    .set_writer_version(writer_version)
+                    .set_dictionary_enabled(false)
+                    .set_compression(codec)
+                    .set_column_data_page_version(ColumnPath::from("col"), data_page_version)
+                    .build();
+
+                let mut writer =
+                    get_test_column_writer::<Int32Type>(page_writer, 1, 1, Arc::new(props));
+                writer
+                    .write_batch(
+                        &[1, 2, 3, 4],
+                        Some(&[1, 0, 1, 1, 1]),
+                        Some(&[0, 1, 0, 0, 1]),
+                    )
+                    .unwrap();
+                let r = writer.close().unwrap();
+                drop(write);
+
+                let reader = SerializedPageReader::new(
+                    Arc::new(Bytes::from(buf)),
+                    &r.metadata,
+                    r.rows_written as usize,
+                    None,
+                )
+                .unwrap();
+                let pages = reader.collect::<Result<Vec<_>>>().unwrap();
+                assert_eq!(pages.len(), 1);
+                assert_eq!(pages[0].page_type(), page_type);
+
+                if let Page::DataPageV2 {
+                    num_values,
+                    num_nulls,
+                    num_rows,
+                    def_levels_byte_len,
+                    rep_levels_byte_len,
+                    is_compressed,
+                    ..
+                } = &pages[0]
+                {
+                    assert_eq!(*num_values, 5);
+                    assert_eq!(*num_nulls, 1);
+                    assert_eq!(*num_rows, 3);
+                    assert!(*def_levels_byte_len > 0);
+                    assert!(*rep_levels_byte_len > 0);
+                    assert_eq!(*is_compressed, codec != Compression::UNCOMPRESSED);
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_column_writer_empty_column_roundtrip() {
         let props = Default::default();
@@ -2368,6 +2644,52 @@ mod tests {
         column_roundtrip_random::<Int32Type>(props, 1024, i32::MIN, i32::MAX, 10, 10);
     }
 
+    #[test]
+    fn test_column_writer_dictionary_fallback_threshold() {
+        let props = || {
+            WriterProperties::builder()
+                .set_write_batch_size(256)
+                .set_data_page_row_count_limit(512)
+                .set_dictionary_fallback_threshold(0.5)
+        };
+        let has_plain_data_pages = |meta: &ColumnChunkMetaData| {
+            meta.page_encoding_stats()
+                .unwrap()
+                .iter()
+                .any(|s| s.page_type == PageType::DATA_PAGE && s.encoding == Encoding::PLAIN)
+        };
+
+        // High cardinality values fall back after the first data page
+        let unique: Vec<i32> = (0..4096).collect();
+        let meta = column_write_and_get_metadata::<Int32Type>(props().build(), &unique);
+        assert!(meta.dictionary_page_offset().is_some());
+        assert!(has_plain_data_pages(&meta));
+
+        // Low cardinality values remain dictionary encoded
+        let repeated: Vec<i32> = (0..4096).map(|i| i % 10).collect();
+        let meta = column_write_and_get_metadata::<Int32Type>(props().build(), &repeated);
+        assert!(!has_plain_data_pages(&meta));
+
+        // The threshold can be disabled for a specific column
+        let props = props()
+            .set_column_dictionary_fallback_threshold(ColumnPath::from("col"), 1.0)
+            .build();
+        let meta = column_write_and_get_metadata::<Int32Type>(props, &unique);
+        assert!(!has_plain_data_pages(&meta));
+
+        column_roundtrip_random::<Int32Type>(
+            WriterProperties::builder()
+                .set_dictionary_fallback_threshold(0.1)
+                .set_data_page_row_count_limit(100)
+                .build(),
+            1024,
+            i32::MIN,
+            i32::MAX,
+            10,
+            10,
+        );
+    }
+
     #[test]
     fn test_column_writer_small_write_batch_size() {
         for i in &[1usize, 2, 5, 10, 11, 1023] {
@@ -3838,6 +4160,41 @@ mod tests {
         assert_eq!(size_with_two_pages, 20 + 21);
     }
 
+    #[test]
+    fn test_column_writer_compressed_pages_count_rows() {
+        let page_writer = get_test_page_writer();
+        let props = Arc::new(
+            WriterProperties::builder()
+                .set_compression(Compression::UNCOMPRESSED)
+                .build(),
+        );
+        let mut writer = get_test_column_writer::<Int32Type>(page_writer, 0, 1, props);
+
+        // The first record of the second page continues from the first page
+        for rep_levels in [&[0, 1, 0, 1][..], &[1, 1, 0]] {
+            let mut buf = writer.encode_levels_v1(Encoding::RLE, rep_levels, 1);
+            buf.extend(rep_levels.iter().flat_map(|_| 1_i32.to_le_bytes()));
+            let page = Page::DataPage {
+                num_values: rep_levels.len() as u32,
+                encoding: Encoding::PLAIN,
+                def_level_encoding: Encoding::RLE,
+                rep_level_encoding: Encoding::RLE,
+                statistics: None,
+                buf: Bytes::from(buf),
+            };
+            let uncompressed_size = page.buffer().len();
+            writer
+                .write_compressed_page(CompressedPage::new(page, uncompressed_size))
+                .unwrap();
+        }
+
+        let result = writer.close().unwrap();
+        assert_eq!(result.rows_written, 3);
+        let page_locations = result.offset_index.unwrap().page_locations;
+        let first_row_indexes: Vec<_> = page_locations.iter().map(|l| l.first_row_index).collect();
+        assert_eq!(first_row_indexes, vec![0, 2]);
+    }
+
     fn write_multiple_pages<T: DataType>(
         column_descr: &Arc<ColumnDescriptor>,
         pages: &[&[Option<T::T>]],
@@ -118,6 +118,46 @@ impl Int96 {
             .wrapping_add(nanos)
     }
 
+    /// Converts this INT96 into an i64 representing the number of SECONDS since EPOCH
+    ///
+    /// Returns `None` on overflow
+    #[inline]
+    pub fn checked_to_seconds(&self) -> Option<i64> {
+        self.checked_to_unit(SECONDS_IN_DAY, NANOSECONDS)
+    }
+
+    /// Converts this INT96 into an i64 representing the number of MILLISECONDS since EPOCH
+    ///
+    /// Returns `None` on overflow
+    #[inline]
+    pub fn checked_to_millis(&self) -> Option<i64> {
+        self.checked_to_unit(MILLISECONDS_IN_DAY, NANOSECONDS / MILLISECONDS)
+    }
+
+    /// Converts this INT96 into an i64 representing the number of MICROSECONDS since EPOCH
+    ///
+    /// Returns `None` on overflow
+    #[inline]
+    pub fn checked_to_micros(&self) -> Option<i64> {
+        self.checked_to_unit(MICROSECONDS_IN_DAY, NANOSECONDS / MICROSECONDS)
+    }
+
+    /// Converts this INT96 into an i64 representing the number of NANOSECONDS since EPOCH
+    ///
+    /// Returns `None` on overflow
+    #[inline]
+    pub fn checked_to_nanos(&self) -> Option<i64> {
+        self.checked_to_unit(NANOSECONDS_IN_DAY, 1)
+    }
+
+    #[inline]
+    fn checked_to_unit(&self, units_in_day: i64, nanos_in_unit: i64) -> Option<i64> {
+        let (day, nanos) = self.data_as_days_and_nanos();
+        (day as i64 - JULIAN_DAY_OF_EPOCH)
+            .checked_mul(units_in_day)?
+            .checked_add(nanos / nanos_in_unit)
+    }
+
     #[inline]
     fn data_as_days_and_nanos(&self) -> (i32, i64) {
         let day = self.data()[2] as i32;
@@ -1340,6 +1380,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_int96_checked_conversion() {
+        // 1970-01-02 00:00:01
+        let mut v = Int96::new();
+        v.set_data(1_000_000_000, 0, 2_440_589);
+        assert_eq!(v.checked_to_seconds(), Some(86_401));
+        assert_eq!(v.checked_to_millis(), Some(86_401_000));
+        assert_eq!(v.checked_to_micros(), Some(86_401_000_000));
+        assert_eq!(v.checked_to_nanos(), Some(86_401_000_000_000));
+        assert_eq!(v.checked_to_nanos(), Some(v.to_nanos()));
+
+        // 9999-12-31 is out of range for nanoseconds, but not microseconds
+        v.set_data(0, 0, 5_373_484);
+        assert_eq!(v.checked_to_nanos(), None);
+        assert_eq!(v.checked_to_micros(), Some(253_402_214_400_000_000));
+        assert_eq!(v.checked_to_micros(), Some(v.to_micros()));
+    }
+
     #[test]
     fn test_byte_array_from() {
         assert_eq!(ByteArray::from(b"ABC".to_vec()).data(), b"ABC");
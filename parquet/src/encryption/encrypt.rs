@@ -27,6 +27,7 @@ use crate::thrift::TSerializable;
 use ring::rand::{SecureRandom, SystemRandom};
 use std::collections::{HashMap, HashSet};
 use std::io::Write;
+use std::sync::Arc;
 use thrift::protocol::TCompactOutputProtocol;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -53,6 +54,42 @@ impl EncryptionKey {
     }
 }
 
+/// A trait for generating the key metadata stored in a Parquet file for each
+/// encryption key, which allows readers to recover the keys with a
+/// [`KeyRetriever`](crate::encryption::decrypt::KeyRetriever).
+///
+/// This enables integration with a Key Management Server, for example by storing
+/// an identifier of the key, or the key itself wrapped with a master key.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use parquet::encryption::encrypt::{FileEncryptionProperties, KeyMetadataGenerator};
+/// // Identify keys by the column they encrypt
+/// struct ColumnKeyIds;
+///
+/// impl KeyMetadataGenerator for ColumnKeyIds {
+///     fn generate_key_metadata(
+///         &self,
+///         _key: &[u8],
+///         column_path: Option<&str>,
+///     ) -> parquet::errors::Result<Vec<u8>> {
+///         Ok(format!("key_{}", column_path.unwrap_or("footer")).into_bytes())
+///     }
+/// }
+///
+/// let encryption_properties = FileEncryptionProperties::builder(b"0123456789012345".to_vec())
+///     .with_column_key("x", b"1234567890123450".to_vec())
+///     .with_key_metadata_generator(Arc::new(ColumnKeyIds))
+///     .build()?;
+/// assert_eq!(encryption_properties.footer_key_metadata(), Some(&b"key_footer".to_vec()));
+/// # Ok::<(), parquet::errors::ParquetError>(())
+/// ```
+pub trait KeyMetadataGenerator: Send + Sync {
+    /// Generate the metadata for `key`, which encrypts the column with the dotted
+    /// path `column_path`, or the footer if `column_path` is `None`
+    fn generate_key_metadata(&self, key: &[u8], column_path: Option<&str>) -> Result<Vec<u8>>;
+}
+
 #[derive(Debug, Clone, PartialEq)]
 /// Defines how data in a Parquet file should be encrypted
 ///
@@ -81,6 +118,18 @@ impl EncryptionKey {
 /// # Ok::<(), parquet::errors::ParquetError>(())
 /// ```
 ///
+/// Store the footer in plaintext, so that legacy readers without encryption support
+/// can read the file metadata and the unencrypted columns. The footer is signed with
+/// the footer key to allow readers with the key to verify its integrity:
+/// ```
+/// # use parquet::encryption::encrypt::FileEncryptionProperties;
+/// let file_encryption_properties = FileEncryptionProperties::builder(b"0123456789012345".into())
+///     .with_column_key("x", b"1234567890123450".into())
+///     .with_plaintext_footer(true)
+///     .build()?;
+/// # Ok::<(), parquet::errors::ParquetError>(())
+/// ```
+///
 /// Specify additional authenticated data, used to protect against data replacement.
 /// This should represent the file identity:
 /// ```
@@ -186,6 +235,7 @@ pub struct EncryptionPropertiesBuilder {
     column_keys: HashMap<String, EncryptionKey>,
     aad_prefix: Option<Vec<u8>>,
     store_aad_prefix: bool,
+    key_metadata_generator: Option<Arc<dyn KeyMetadataGenerator>>,
 }
 
 impl EncryptionPropertiesBuilder {
@@ -197,6 +247,7 @@ impl EncryptionPropertiesBuilder {
             aad_prefix: None,
             encrypt_footer: true,
             store_aad_prefix: false,
+            key_metadata_generator: None,
         }
     }
 
@@ -274,8 +325,30 @@ impl EncryptionPropertiesBuilder {
         self
     }
 
+    /// Set a [`KeyMetadataGenerator`] used to generate the metadata of the footer key
+    /// and column keys when the properties are built. Keys with explicitly set metadata
+    /// keep that metadata.
+    pub fn with_key_metadata_generator(mut self, generator: Arc<dyn KeyMetadataGenerator>) -> Self {
+        self.key_metadata_generator = Some(generator);
+        self
+    }
+
     /// Build the encryption properties
-    pub fn build(self) -> Result<FileEncryptionProperties> {
+    pub fn build(mut self) -> Result<FileEncryptionProperties> {
+        if let Some(generator) = &self.key_metadata_generator {
+            if self.footer_key.key_metadata.is_none() {
+                let metadata = generator.generate_key_metadata(&self.footer_key.key, None)?;
+                self.footer_key.key_metadata = Some(metadata);
+            }
+            for (column_path, column_key) in self.column_keys.iter_mut() {
+                if column_key.key_metadata.is_none() {
+                    let metadata =
+                        generator.generate_key_metadata(&column_key.key, Some(column_path))?;
+                    column_key.key_metadata = Some(metadata);
+                }
+            }
+        }
+
         Ok(FileEncryptionProperties {
             encrypt_footer: self.encrypt_footer,
             footer_key: self.footer_key,
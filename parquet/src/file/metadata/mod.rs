@@ -114,6 +114,7 @@ use crate::format::{
     BoundaryOrder, ColumnChunk, ColumnIndex, ColumnMetaData, OffsetIndex, PageLocation, RowGroup,
     SizeStatistics, SortingColumn,
 };
+use crate::geospatial::{self, GeospatialStatistics};
 use crate::schema::types::{
     ColumnDescPtr, ColumnDescriptor, ColumnPath, SchemaDescPtr, SchemaDescriptor,
     Type as SchemaType,
@@ -240,6 +241,19 @@ impl ParquetMetaData {
         &self.row_groups
     }
 
+    /// Returns the sort ordering of the rows shared by every row group in this file, if any
+    ///
+    /// Returns `None` if the file has no row groups, or if any row group does not
+    /// declare [`RowGroupMetaData::sorting_columns`] identical to the others. Note
+    /// that the rows are only sorted within each row group, not across row groups.
+    pub fn sorting_columns(&self) -> Option<&[SortingColumn]> {
+        let (first, rest) = self.row_groups.split_first()?;
+        let sorting_columns = first.sorting_columns()?;
+        rest.iter()
+            .all(|rg| rg.sorting_columns() == Some(sorting_columns))
+            .then_some(sorting_columns.as_slice())
+    }
+
     /// Returns the column index for this file if loaded
     ///
     /// Returns `None` if the parquet file does not have a `ColumnIndex` or
@@ -845,6 +859,7 @@ pub struct ColumnChunkMetaData {
     unencoded_byte_array_data_bytes: Option<i64>,
     repetition_level_histogram: Option<LevelHistogram>,
     definition_level_histogram: Option<LevelHistogram>,
+    geo_statistics: Option<Box<GeospatialStatistics>>,
     #[cfg(feature = "encryption")]
     column_crypto_metadata: Option<ColumnCryptoMetaData>,
 }
@@ -1136,6 +1151,16 @@ impl ColumnChunkMetaData {
         self.definition_level_histogram.as_ref()
     }
 
+    /// Returns the geospatial statistics of this column chunk, if any.
+    ///
+    /// Only set for columns with the [`Geometry`] or [`Geography`] logical types.
+    ///
+    /// [`Geometry`]: crate::basic::LogicalType::Geometry
+    /// [`Geography`]: crate::basic::LogicalType::Geography
+    pub fn geo_statistics(&self) -> Option<&GeospatialStatistics> {
+        self.geo_statistics.as_deref()
+    }
+
     /// Returns the encryption metadata for this column chunk.
     #[cfg(feature = "encryption")]
     pub fn crypto_metadata(&self) -> Option<&ColumnCryptoMetaData> {
@@ -1195,6 +1220,7 @@ impl ColumnChunkMetaData {
 
         let repetition_level_histogram = repetition_level_histogram.map(LevelHistogram::from);
         let definition_level_histogram = definition_level_histogram.map(LevelHistogram::from);
+        let geo_statistics = geospatial::from_thrift(col_metadata.geospatial_statistics);
 
         #[cfg(feature = "encryption")]
         let column_crypto_metadata = if let Some(crypto_metadata) = cc.crypto_metadata {
@@ -1226,6 +1252,7 @@ impl ColumnChunkMetaData {
             unencoded_byte_array_data_bytes,
             repetition_level_histogram,
             definition_level_histogram,
+            geo_statistics,
             #[cfg(feature = "encryption")]
             column_crypto_metadata,
         };
@@ -1294,7 +1321,7 @@ impl ColumnChunkMetaData {
             bloom_filter_offset: self.bloom_filter_offset,
             bloom_filter_length: self.bloom_filter_length,
             size_statistics,
-            geospatial_statistics: None,
+            geospatial_statistics: geospatial::to_thrift(self.geo_statistics.as_deref()),
         }
     }
 
@@ -1364,6 +1391,7 @@ impl ColumnChunkMetaDataBuilder {
             unencoded_byte_array_data_bytes: None,
             repetition_level_histogram: None,
             definition_level_histogram: None,
+            geo_statistics: None,
             #[cfg(feature = "encryption")]
             column_crypto_metadata: None,
         })
@@ -1501,6 +1529,12 @@ impl ColumnChunkMetaDataBuilder {
         self
     }
 
+    /// Sets optional geospatial statistics
+    pub fn set_geo_statistics(mut self, value: Option<Box<GeospatialStatistics>>) -> Self {
+        self.0.geo_statistics = value;
+        self
+    }
+
     #[cfg(feature = "encryption")]
     /// Set the encryption metadata for an encrypted column
     pub fn set_column_crypto_metadata(mut self, value: Option<ColumnCryptoMetaData>) -> Self {
@@ -1744,6 +1778,43 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parquet_metadata_sorting_columns() {
+        let schema_descr = get_test_schema_descr();
+        let row_group = |sorting_columns: Option<Vec<SortingColumn>>| {
+            let columns = schema_descr
+                .columns()
+                .iter()
+                .map(|ptr| ColumnChunkMetaData::builder(ptr.clone()).build().unwrap())
+                .collect();
+            RowGroupMetaData::builder(schema_descr.clone())
+                .set_column_metadata(columns)
+                .set_sorting_columns(sorting_columns)
+                .build()
+                .unwrap()
+        };
+        let file_metadata = FileMetaData::new(1, 0, None, None, schema_descr.clone(), None);
+        let sorting_columns = vec![SortingColumn {
+            column_idx: 1,
+            descending: true,
+            nulls_first: false,
+        }];
+
+        let metadata = ParquetMetaData::new(file_metadata.clone(), vec![]);
+        assert_eq!(metadata.sorting_columns(), None);
+
+        let row_groups = vec![
+            row_group(Some(sorting_columns.clone())),
+            row_group(Some(sorting_columns.clone())),
+        ];
+        let metadata = ParquetMetaData::new(file_metadata.clone(), row_groups);
+        assert_eq!(metadata.sorting_columns(), Some(sorting_columns.as_slice()));
+
+        let row_groups = vec![row_group(Some(sorting_columns.clone())), row_group(None)];
+        let metadata = ParquetMetaData::new(file_metadata, row_groups);
+        assert_eq!(metadata.sorting_columns(), None);
+    }
+
     /// Test reading a corrupted Parquet file with 3 columns in its schema but only 2 in its row group
     #[test]
     fn test_row_group_metadata_thrift_corrupted() {
@@ -1968,9 +2039,9 @@ mod tests {
             .build();
 
         #[cfg(not(feature = "encryption"))]
-        let base_expected_size = 2312;
+        let base_expected_size = 2344;
         #[cfg(feature = "encryption")]
-        let base_expected_size = 2648;
+        let base_expected_size = 2680;
 
         assert_eq!(parquet_meta.memory_size(), base_expected_size);
 
@@ -1998,9 +2069,9 @@ mod tests {
             .build();
 
         #[cfg(not(feature = "encryption"))]
-        let bigger_expected_size = 2816;
+        let bigger_expected_size = 2848;
         #[cfg(feature = "encryption")]
-        let bigger_expected_size = 3152;
+        let bigger_expected_size = 3184;
 
         // more set fields means more memory usage
         assert!(bigger_expected_size > base_expected_size);
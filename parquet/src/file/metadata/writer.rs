@@ -30,7 +30,7 @@ use crate::file::page_index::index::Index;
 use crate::file::writer::{get_file_magic, TrackedWrite};
 use crate::format::EncryptionAlgorithm;
 #[cfg(feature = "encryption")]
-use crate::format::{AesGcmV1, ColumnCryptoMetaData};
+use crate::format::{AesGcmV1, ColumnCryptoMetaData, ColumnMetaData};
 use crate::format::{ColumnChunk, ColumnIndex, FileMetaData, OffsetIndex, RowGroup};
 use crate::schema::types;
 use crate::schema::types::{SchemaDescPtr, SchemaDescriptor, TypePtr};
@@ -370,22 +370,7 @@ impl<'a, W: Write> ParquetMetaDataWriter<'a, W> {
             (0..self.metadata.row_groups().len())
                 .map(|rg_idx| {
                     let column_indexes = &row_group_column_indexes[rg_idx];
-                    column_indexes
-                        .iter()
-                        .map(|column_index| match column_index {
-                            Index::NONE => None,
-                            Index::BOOLEAN(column_index) => Some(column_index.to_thrift()),
-                            Index::BYTE_ARRAY(column_index) => Some(column_index.to_thrift()),
-                            Index::DOUBLE(column_index) => Some(column_index.to_thrift()),
-                            Index::FIXED_LEN_BYTE_ARRAY(column_index) => {
-                                Some(column_index.to_thrift())
-                            }
-                            Index::FLOAT(column_index) => Some(column_index.to_thrift()),
-                            Index::INT32(column_index) => Some(column_index.to_thrift()),
-                            Index::INT64(column_index) => Some(column_index.to_thrift()),
-                            Index::INT96(column_index) => Some(column_index.to_thrift()),
-                        })
-                        .collect()
+                    column_indexes.iter().map(Index::to_thrift).collect()
                 })
                 .collect()
         } else {
@@ -735,7 +720,17 @@ impl MetadataObjectWriter {
                 let ciphertext = encrypt_object_to_vec(&meta_data, &mut column_encryptor, &aad)?;
 
                 column_chunk.encrypted_column_metadata = Some(ciphertext);
-                debug_assert!(column_chunk.meta_data.is_none());
+                if !file_encryptor.properties().encrypt_footer() {
+                    // Keep a redacted copy of the metadata in a plaintext footer, so that
+                    // legacy readers can still read the file's unencrypted columns
+                    column_chunk.meta_data = Some(ColumnMetaData {
+                        statistics: None,
+                        encoding_stats: None,
+                        size_statistics: None,
+                        geospatial_statistics: None,
+                        ..meta_data
+                    });
+                }
             }
         }
 
@@ -104,6 +104,7 @@ pub mod page_encoding_stats;
 pub mod page_index;
 pub mod properties;
 pub mod reader;
+pub mod rewriter;
 pub mod serialized_reader;
 pub mod statistics;
 pub mod writer;
@@ -56,12 +56,19 @@ pub const DEFAULT_COLUMN_INDEX_TRUNCATE_LENGTH: Option<usize> = Some(64);
 pub const DEFAULT_BLOOM_FILTER_FPP: f64 = 0.05;
 /// Default value for [`BloomFilterProperties::ndv`]
 pub const DEFAULT_BLOOM_FILTER_NDV: u64 = 1_000_000_u64;
+/// Default value for [`WriterProperties::bloom_filter_adaptive_ndv`]
+pub const DEFAULT_BLOOM_FILTER_ADAPTIVE_NDV: bool = false;
 /// Default values for [`WriterProperties::statistics_truncate_length`]
 pub const DEFAULT_STATISTICS_TRUNCATE_LENGTH: Option<usize> = Some(64);
 /// Default value for [`WriterProperties::offset_index_disabled`]
 pub const DEFAULT_OFFSET_INDEX_DISABLED: bool = false;
 /// Default values for [`WriterProperties::coerce_types`]
 pub const DEFAULT_COERCE_TYPES: bool = false;
+/// Default value for [`WriterProperties::validate_sorting_columns`]
+pub const DEFAULT_VALIDATE_SORTING_COLUMNS: bool = false;
+/// Default value for [`WriterProperties::page_checksum_enabled`]
+#[cfg(feature = "crc")]
+pub const DEFAULT_PAGE_CHECKSUM_ENABLED: bool = false;
 
 /// Parquet writer version.
 ///
@@ -97,6 +104,33 @@ impl FromStr for WriterVersion {
     }
 }
 
+/// The format of the data pages written for a column
+///
+/// Defaults to the version corresponding to [`WriterProperties::writer_version`]
+///
+/// Basic constant, which is not part of the Thrift definition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataPageVersion {
+    /// Write `DataPage`s, where the repetition levels, definition levels and
+    /// values are compressed together
+    V1,
+    /// Write `DataPageV2`s, where the repetition and definition levels are
+    /// stored uncompressed ahead of the separately compressed values
+    ///
+    /// This allows readers to decode the levels without decompressing the values,
+    /// but may not be supported by some older readers.
+    V2,
+}
+
+impl From<WriterVersion> for DataPageVersion {
+    fn from(value: WriterVersion) -> Self {
+        match value {
+            WriterVersion::PARQUET_1_0 => Self::V1,
+            WriterVersion::PARQUET_2_0 => Self::V2,
+        }
+    }
+}
+
 /// Where in the file [`ArrowWriter`](crate::arrow::arrow_writer::ArrowWriter) should
 /// write Bloom filters
 ///
@@ -158,6 +192,7 @@ pub struct WriterProperties {
     data_page_row_count_limit: usize,
     write_batch_size: usize,
     max_row_group_size: usize,
+    max_row_group_bytes: Option<usize>,
     bloom_filter_position: BloomFilterPosition,
     writer_version: WriterVersion,
     created_by: String,
@@ -166,9 +201,12 @@ pub struct WriterProperties {
     default_column_properties: ColumnProperties,
     column_properties: HashMap<ColumnPath, ColumnProperties>,
     sorting_columns: Option<Vec<SortingColumn>>,
+    validate_sorting_columns: bool,
     column_index_truncate_length: Option<usize>,
     statistics_truncate_length: Option<usize>,
     coerce_types: bool,
+    #[cfg(feature = "crc")]
+    page_checksum_enabled: bool,
     #[cfg(feature = "encryption")]
     pub(crate) file_encryption_properties: Option<FileEncryptionProperties>,
 }
@@ -222,6 +260,19 @@ impl WriterProperties {
             .unwrap_or(DEFAULT_DICTIONARY_PAGE_SIZE_LIMIT)
     }
 
+    /// Returns the dictionary fallback threshold for a specific column, if any.
+    ///
+    /// For more details see [`WriterPropertiesBuilder::set_dictionary_fallback_threshold`]
+    pub fn column_dictionary_fallback_threshold(&self, col: &ColumnPath) -> Option<f64> {
+        self.column_properties
+            .get(col)
+            .and_then(|c| c.dictionary_fallback_threshold())
+            .or_else(|| {
+                self.default_column_properties
+                    .dictionary_fallback_threshold()
+            })
+    }
+
     /// Returns the maximum page row count
     ///
     /// Note: this is a best effort limit based on the write batch size
@@ -249,6 +300,13 @@ impl WriterProperties {
         self.max_row_group_size
     }
 
+    /// Returns the target maximum encoded size of a row group in bytes, if any.
+    ///
+    /// For more details see [`WriterPropertiesBuilder::set_max_row_group_bytes`]
+    pub fn max_row_group_bytes(&self) -> Option<usize> {
+        self.max_row_group_bytes
+    }
+
     /// Returns bloom filter position.
     ///
     /// For more details see [`WriterPropertiesBuilder::set_bloom_filter_position`]
@@ -302,6 +360,13 @@ impl WriterProperties {
         self.sorting_columns.as_ref()
     }
 
+    /// Returns `true` if written rows are validated against the sorting columns.
+    ///
+    /// For more details see [`WriterPropertiesBuilder::set_validate_sorting_columns`]
+    pub fn validate_sorting_columns(&self) -> bool {
+        self.validate_sorting_columns
+    }
+
     /// Returns the maximum length of truncated min/max values in the column index.
     ///
     /// `None` if truncation is disabled, must be greater than 0 otherwise.
@@ -322,6 +387,20 @@ impl WriterProperties {
         self.statistics_truncate_length
     }
 
+    /// Returns the maximum length of truncated min/max values in [`Statistics`]
+    /// for a specific column.
+    ///
+    /// `None` if truncation is disabled for this column, see
+    /// [`WriterPropertiesBuilder::set_column_statistics_truncate_length`]
+    ///
+    /// [`Statistics`]: crate::file::statistics::Statistics
+    pub fn column_statistics_truncate_length(&self, col: &ColumnPath) -> Option<usize> {
+        self.column_properties
+            .get(col)
+            .and_then(|c| c.statistics_truncate_length())
+            .unwrap_or(self.statistics_truncate_length)
+    }
+
     /// Returns `true` if type coercion is enabled.
     ///
     /// For more details see [`WriterPropertiesBuilder::set_coerce_types`]
@@ -329,6 +408,14 @@ impl WriterProperties {
         self.coerce_types
     }
 
+    /// Returns `true` if CRC checksums are written to page headers.
+    ///
+    /// For more details see [`WriterPropertiesBuilder::set_page_checksum_enabled`]
+    #[cfg(feature = "crc")]
+    pub fn page_checksum_enabled(&self) -> bool {
+        self.page_checksum_enabled
+    }
+
     /// Returns encoding for a data page, when dictionary encoding is enabled.
     ///
     /// This is not configurable.
@@ -411,6 +498,29 @@ impl WriterProperties {
             .unwrap_or(DEFAULT_WRITE_PAGE_HEADER_STATISTICS)
     }
 
+    /// Returns the format of the data pages written for a column.
+    ///
+    /// For more details see [`WriterPropertiesBuilder::set_data_page_version`]
+    pub fn data_page_version(&self, col: &ColumnPath) -> DataPageVersion {
+        self.column_properties
+            .get(col)
+            .and_then(|c| c.data_page_version())
+            .or_else(|| self.default_column_properties.data_page_version())
+            .unwrap_or_else(|| self.writer_version.into())
+    }
+
+    /// Returns `true` if bloom filters for the given column are sized from the
+    /// observed number of distinct values.
+    ///
+    /// For more details see [`WriterPropertiesBuilder::set_bloom_filter_adaptive_ndv`]
+    pub fn bloom_filter_adaptive_ndv(&self, col: &ColumnPath) -> bool {
+        self.column_properties
+            .get(col)
+            .and_then(|c| c.bloom_filter_adaptive_ndv())
+            .or_else(|| self.default_column_properties.bloom_filter_adaptive_ndv())
+            .unwrap_or(DEFAULT_BLOOM_FILTER_ADAPTIVE_NDV)
+    }
+
     /// Returns the [`BloomFilterProperties`] for the given column
     ///
     /// Returns `None` if bloom filter is disabled
@@ -440,6 +550,7 @@ pub struct WriterPropertiesBuilder {
     data_page_row_count_limit: usize,
     write_batch_size: usize,
     max_row_group_size: usize,
+    max_row_group_bytes: Option<usize>,
     bloom_filter_position: BloomFilterPosition,
     writer_version: WriterVersion,
     created_by: String,
@@ -448,9 +559,12 @@ pub struct WriterPropertiesBuilder {
     default_column_properties: ColumnProperties,
     column_properties: HashMap<ColumnPath, ColumnProperties>,
     sorting_columns: Option<Vec<SortingColumn>>,
+    validate_sorting_columns: bool,
     column_index_truncate_length: Option<usize>,
     statistics_truncate_length: Option<usize>,
     coerce_types: bool,
+    #[cfg(feature = "crc")]
+    page_checksum_enabled: bool,
     #[cfg(feature = "encryption")]
     file_encryption_properties: Option<FileEncryptionProperties>,
 }
@@ -463,6 +577,7 @@ impl WriterPropertiesBuilder {
             data_page_row_count_limit: DEFAULT_DATA_PAGE_ROW_COUNT_LIMIT,
             write_batch_size: DEFAULT_WRITE_BATCH_SIZE,
             max_row_group_size: DEFAULT_MAX_ROW_GROUP_SIZE,
+            max_row_group_bytes: None,
             bloom_filter_position: DEFAULT_BLOOM_FILTER_POSITION,
             writer_version: DEFAULT_WRITER_VERSION,
             created_by: DEFAULT_CREATED_BY.to_string(),
@@ -471,9 +586,12 @@ impl WriterPropertiesBuilder {
             default_column_properties: Default::default(),
             column_properties: HashMap::new(),
             sorting_columns: None,
+            validate_sorting_columns: DEFAULT_VALIDATE_SORTING_COLUMNS,
             column_index_truncate_length: DEFAULT_COLUMN_INDEX_TRUNCATE_LENGTH,
             statistics_truncate_length: DEFAULT_STATISTICS_TRUNCATE_LENGTH,
             coerce_types: DEFAULT_COERCE_TYPES,
+            #[cfg(feature = "crc")]
+            page_checksum_enabled: DEFAULT_PAGE_CHECKSUM_ENABLED,
             #[cfg(feature = "encryption")]
             file_encryption_properties: None,
         }
@@ -486,6 +604,7 @@ impl WriterPropertiesBuilder {
             data_page_row_count_limit: self.data_page_row_count_limit,
             write_batch_size: self.write_batch_size,
             max_row_group_size: self.max_row_group_size,
+            max_row_group_bytes: self.max_row_group_bytes,
             bloom_filter_position: self.bloom_filter_position,
             writer_version: self.writer_version,
             created_by: self.created_by,
@@ -494,9 +613,12 @@ impl WriterPropertiesBuilder {
             default_column_properties: self.default_column_properties,
             column_properties: self.column_properties,
             sorting_columns: self.sorting_columns,
+            validate_sorting_columns: self.validate_sorting_columns,
             column_index_truncate_length: self.column_index_truncate_length,
             statistics_truncate_length: self.statistics_truncate_length,
             coerce_types: self.coerce_types,
+            #[cfg(feature = "crc")]
+            page_checksum_enabled: self.page_checksum_enabled,
             #[cfg(feature = "encryption")]
             file_encryption_properties: self.file_encryption_properties,
         }
@@ -510,6 +632,11 @@ impl WriterPropertiesBuilder {
     ///
     /// This value can determine what features some readers will support.
     ///
+    /// [`PARQUET_2_0`] writes `DataPageV2`s and uses the encodings introduced by
+    /// version 2.0 of the format by default. The data page format can be set
+    /// independently with [`Self::set_data_page_version`].
+    ///
+    /// [`PARQUET_2_0`]: WriterVersion::PARQUET_2_0
     /// [`PARQUET_1_0`]: [WriterVersion::PARQUET_1_0]
     pub fn set_writer_version(mut self, value: WriterVersion) -> Self {
         self.writer_version = value;
@@ -571,6 +698,25 @@ impl WriterPropertiesBuilder {
         self
     }
 
+    /// Sets the target maximum encoded size of a row group in bytes (defaults to `None`).
+    ///
+    /// When set, the [`ArrowWriter`] estimates the encoded size of the buffered
+    /// row group as rows are appended, and closes the row group once this size is
+    /// reached, in addition to limiting it to [`Self::set_max_row_group_size`] rows.
+    ///
+    /// Note: this is a best effort limit based on the estimated encoded size of the
+    /// rows written so far, and does not account for compression
+    ///
+    /// # Panics
+    /// If the value is set to `Some(0)`.
+    ///
+    /// [`ArrowWriter`]: crate::arrow::arrow_writer::ArrowWriter
+    pub fn set_max_row_group_bytes(mut self, value: Option<usize>) -> Self {
+        assert_ne!(value, Some(0), "Cannot have a 0 max row group bytes");
+        self.max_row_group_bytes = value;
+        self
+    }
+
     /// Sets where in the final file Bloom Filters are written (defaults to  [`AfterRowGroup`]
     /// via [`DEFAULT_BLOOM_FILTER_POSITION`])
     ///
@@ -612,11 +758,30 @@ impl WriterPropertiesBuilder {
     }
 
     /// Sets sorting order of rows in the row group if any (defaults to `None`).
+    ///
+    /// The sorting columns are written to the metadata of every row group, and are
+    /// not checked against the written data unless [`Self::set_validate_sorting_columns`]
+    /// is enabled.
     pub fn set_sorting_columns(mut self, value: Option<Vec<SortingColumn>>) -> Self {
         self.sorting_columns = value;
         self
     }
 
+    /// Sets whether [`ArrowWriter`] verifies that the rows of each row group are
+    /// ordered according to the [sorting columns] (defaults to `false` via
+    /// [`DEFAULT_VALIDATE_SORTING_COLUMNS`]).
+    ///
+    /// If enabled, writing a batch that would violate the declared order returns an
+    /// error. Only sorting columns that are top-level columns of the schema can be
+    /// validated.
+    ///
+    /// [`ArrowWriter`]: crate::arrow::ArrowWriter
+    /// [sorting columns]: Self::set_sorting_columns
+    pub fn set_validate_sorting_columns(mut self, value: bool) -> Self {
+        self.validate_sorting_columns = value;
+        self
+    }
+
     /// Sets the max length of min/max value fields when writing the column
     /// [`Index`] (defaults to `Some(64)` via [`DEFAULT_COLUMN_INDEX_TRUNCATE_LENGTH`]).
     ///
@@ -691,6 +856,18 @@ impl WriterPropertiesBuilder {
         self
     }
 
+    /// Sets whether to write a CRC-32 checksum of the page data in the header of
+    /// each data and dictionary page (defaults to `false` via
+    /// [`DEFAULT_PAGE_CHECKSUM_ENABLED`]).
+    ///
+    /// The checksum is computed over the compressed page data, allowing readers
+    /// to detect corrupted pages, see [`ReaderPropertiesBuilder::set_verify_page_checksums`]
+    #[cfg(feature = "crc")]
+    pub fn set_page_checksum_enabled(mut self, value: bool) -> Self {
+        self.page_checksum_enabled = value;
+        self
+    }
+
     /// Sets FileEncryptionProperties (defaults to `None`)
     #[cfg(feature = "encryption")]
     pub fn with_file_encryption_properties(
@@ -755,6 +932,24 @@ impl WriterPropertiesBuilder {
         self
     }
 
+    /// Sets the ratio of distinct values to values above which dictionary encoding is
+    /// abandoned for all columns (defaults to disabled).
+    ///
+    /// Dictionary encoding is inefficient for columns with many distinct values. When
+    /// set, each time a data page of a dictionary encoded column chunk is completed, the
+    /// writer compares the number of entries in the dictionary to the number of non-null
+    /// values written so far.
+    /// If this ratio exceeds `value`, the writer falls back to the non-dictionary
+    /// encoding, as it would when exceeding [`Self::set_dictionary_page_size_limit`].
+    ///
+    /// # Panics
+    /// If `value` is not in the range `0.0..=1.0`
+    pub fn set_dictionary_fallback_threshold(mut self, value: f64) -> Self {
+        self.default_column_properties
+            .set_dictionary_fallback_threshold(value);
+        self
+    }
+
     /// Sets default [`EnabledStatistics`] level for all columns (defaults to [`Page`] via
     /// [`DEFAULT_STATISTICS_ENABLED`]).
     ///
@@ -791,6 +986,15 @@ impl WriterPropertiesBuilder {
         self
     }
 
+    /// Sets the default format of the data pages written for all columns (defaults
+    /// to the version corresponding to [`Self::set_writer_version`]).
+    ///
+    /// See [`DataPageVersion`] for more details.
+    pub fn set_data_page_version(mut self, value: DataPageVersion) -> Self {
+        self.default_column_properties.set_data_page_version(value);
+        self
+    }
+
     /// Sets if bloom filter should be written for all columns (defaults to `false`).
     ///
     /// # Notes
@@ -833,6 +1037,27 @@ impl WriterPropertiesBuilder {
         self
     }
 
+    /// Sets whether bloom filters for all columns are sized from the number of
+    /// distinct values actually written (defaults to `false` via
+    /// [`DEFAULT_BLOOM_FILTER_ADAPTIVE_NDV`]).
+    ///
+    /// When enabled, the ndv configured with [`set_bloom_filter_ndv`] is treated
+    /// as an upper bound: the filter is allocated for that many values and, when
+    /// the column chunk is flushed, shrunk to the size the target fpp requires
+    /// for the number of distinct values estimated from the filter contents.
+    /// This avoids writing mostly empty filters for low cardinality column
+    /// chunks, at the cost of the full sized filter being held in memory while
+    /// writing.
+    ///
+    /// Has no effect on columns without a bloom filter.
+    ///
+    /// [`set_bloom_filter_ndv`]: Self::set_bloom_filter_ndv
+    pub fn set_bloom_filter_adaptive_ndv(mut self, value: bool) -> Self {
+        self.default_column_properties
+            .set_bloom_filter_adaptive_ndv(value);
+        self
+    }
+
     // ----------------------------------------------------------------------
     // Setters for a specific column
 
@@ -884,6 +1109,18 @@ impl WriterPropertiesBuilder {
         self
     }
 
+    /// Sets dictionary fallback threshold for a specific column.
+    ///
+    /// Takes precedence over [`Self::set_dictionary_fallback_threshold`].
+    ///
+    /// # Panics
+    /// If `value` is not in the range `0.0..=1.0`
+    pub fn set_column_dictionary_fallback_threshold(mut self, col: ColumnPath, value: f64) -> Self {
+        self.get_mut_props(col)
+            .set_dictionary_fallback_threshold(value);
+        self
+    }
+
     /// Sets [`EnabledStatistics`] level for a specific column.
     ///
     /// Takes precedence over [`Self::set_statistics_enabled`].
@@ -896,6 +1133,29 @@ impl WriterPropertiesBuilder {
         self
     }
 
+    /// Sets the max length of min/max value fields in row group and data page header
+    /// [`Statistics`] for a specific column.
+    ///
+    /// Takes precedence over [`Self::set_statistics_truncate_length`], and so can be
+    /// used to truncate the statistics of columns with long values more aggressively,
+    /// or to disable truncation with `None` for columns where exact bounds are needed.
+    /// To omit the statistics of a column entirely, for example one containing large
+    /// binary blobs, see [`Self::set_column_statistics_enabled`].
+    ///
+    /// # Panics
+    /// If `max_length` is `Some(0)`
+    ///
+    /// [`Statistics`]: crate::file::statistics::Statistics
+    pub fn set_column_statistics_truncate_length(
+        mut self,
+        col: ColumnPath,
+        max_length: Option<usize>,
+    ) -> Self {
+        self.get_mut_props(col)
+            .set_statistics_truncate_length(max_length);
+        self
+    }
+
     /// Sets whether to write [`Statistics`] in the page header for a specific column.
     ///
     /// Takes precedence over [`Self::set_write_page_header_statistics`].
@@ -907,6 +1167,14 @@ impl WriterPropertiesBuilder {
         self
     }
 
+    /// Sets the format of the data pages written for a specific column.
+    ///
+    /// Takes precedence over [`Self::set_data_page_version`].
+    pub fn set_column_data_page_version(mut self, col: ColumnPath, value: DataPageVersion) -> Self {
+        self.get_mut_props(col).set_data_page_version(value);
+        self
+    }
+
     /// Sets whether a bloom filter should be written for a specific column.
     ///
     /// Takes precedence over [`Self::set_bloom_filter_enabled`].
@@ -930,6 +1198,15 @@ impl WriterPropertiesBuilder {
         self.get_mut_props(col).set_bloom_filter_ndv(value);
         self
     }
+
+    /// Sets whether the bloom filter for a specific column is sized from the
+    /// number of distinct values actually written.
+    ///
+    /// Takes precedence over [`Self::set_bloom_filter_adaptive_ndv`].
+    pub fn set_column_bloom_filter_adaptive_ndv(mut self, col: ColumnPath, value: bool) -> Self {
+        self.get_mut_props(col).set_bloom_filter_adaptive_ndv(value);
+        self
+    }
 }
 
 /// Controls the level of statistics to be computed by the writer and stored in
@@ -1028,11 +1305,16 @@ struct ColumnProperties {
     encoding: Option<Encoding>,
     codec: Option<Compression>,
     dictionary_page_size_limit: Option<usize>,
+    dictionary_fallback_threshold: Option<f64>,
     dictionary_enabled: Option<bool>,
     statistics_enabled: Option<EnabledStatistics>,
+    /// `Some(None)` disables truncation for this column
+    statistics_truncate_length: Option<Option<usize>>,
     write_page_header_statistics: Option<bool>,
+    data_page_version: Option<DataPageVersion>,
     /// bloom filter related properties
     bloom_filter_properties: Option<BloomFilterProperties>,
+    bloom_filter_adaptive_ndv: Option<bool>,
 }
 
 impl ColumnProperties {
@@ -1067,16 +1349,38 @@ impl ColumnProperties {
         self.dictionary_page_size_limit = Some(value);
     }
 
+    /// Sets dictionary fallback threshold for this column.
+    fn set_dictionary_fallback_threshold(&mut self, value: f64) {
+        assert!(
+            (0.0..=1.0).contains(&value),
+            "Dictionary fallback threshold must be between 0 and 1, got {value}"
+        );
+        self.dictionary_fallback_threshold = Some(value);
+    }
+
     /// Sets the statistics level for this column.
     fn set_statistics_enabled(&mut self, enabled: EnabledStatistics) {
         self.statistics_enabled = Some(enabled);
     }
 
+    /// Sets the max length of min/max values in statistics for this column.
+    fn set_statistics_truncate_length(&mut self, max_length: Option<usize>) {
+        if let Some(value) = max_length {
+            assert!(value > 0, "Cannot have a 0 statistics truncate length. If you wish to disable min/max value truncation, set it to `None`.");
+        }
+        self.statistics_truncate_length = Some(max_length);
+    }
+
     /// Sets whether to write statistics in the page header for this column.
     fn set_write_page_header_statistics(&mut self, enabled: bool) {
         self.write_page_header_statistics = Some(enabled);
     }
 
+    /// Sets the format of the data pages written for this column.
+    fn set_data_page_version(&mut self, value: DataPageVersion) {
+        self.data_page_version = Some(value);
+    }
+
     /// If `value` is `true`, sets bloom filter properties to default values if not previously set,
     /// otherwise it is a no-op.
     /// If `value` is `false`, resets bloom filter properties to `None`.
@@ -1113,6 +1417,12 @@ impl ColumnProperties {
             .ndv = value;
     }
 
+    /// Sets whether the bloom filter for this column is sized from the observed
+    /// number of distinct values.
+    fn set_bloom_filter_adaptive_ndv(&mut self, value: bool) {
+        self.bloom_filter_adaptive_ndv = Some(value);
+    }
+
     /// Returns optional encoding for this column.
     fn encoding(&self) -> Option<Encoding> {
         self.encoding
@@ -1135,12 +1445,23 @@ impl ColumnProperties {
         self.dictionary_page_size_limit
     }
 
+    /// Returns optional dictionary fallback threshold for this column.
+    fn dictionary_fallback_threshold(&self) -> Option<f64> {
+        self.dictionary_fallback_threshold
+    }
+
     /// Returns optional statistics level requested for this column. If result is `None`,
     /// then no setting has been provided.
     fn statistics_enabled(&self) -> Option<EnabledStatistics> {
         self.statistics_enabled
     }
 
+    /// Returns the max length of min/max values in statistics for this column. If result
+    /// is `None`, then no setting has been provided.
+    fn statistics_truncate_length(&self) -> Option<Option<usize>> {
+        self.statistics_truncate_length
+    }
+
     /// Returns `Some(true)` if [`Statistics`] are to be written to the page header for this
     /// column.
     ///
@@ -1149,16 +1470,30 @@ impl ColumnProperties {
         self.write_page_header_statistics
     }
 
+    /// Returns optional data page format for this column.
+    fn data_page_version(&self) -> Option<DataPageVersion> {
+        self.data_page_version
+    }
+
     /// Returns the bloom filter properties, or `None` if not enabled
     fn bloom_filter_properties(&self) -> Option<&BloomFilterProperties> {
         self.bloom_filter_properties.as_ref()
     }
+
+    /// Returns `Some(true)` if the bloom filter for this column is sized from the
+    /// observed number of distinct values. If result is `None`, then no setting has
+    /// been provided.
+    fn bloom_filter_adaptive_ndv(&self) -> Option<bool> {
+        self.bloom_filter_adaptive_ndv
+    }
 }
 
 /// Reference counted reader properties.
 pub type ReaderPropertiesPtr = Arc<ReaderProperties>;
 
 const DEFAULT_READ_BLOOM_FILTER: bool = false;
+#[cfg(feature = "crc")]
+const DEFAULT_VERIFY_PAGE_CHECKSUMS: bool = true;
 
 /// Configuration settings for reading parquet files.
 ///
@@ -1181,6 +1516,8 @@ const DEFAULT_READ_BLOOM_FILTER: bool = false;
 pub struct ReaderProperties {
     codec_options: CodecOptions,
     read_bloom_filter: bool,
+    #[cfg(feature = "crc")]
+    verify_page_checksums: bool,
 }
 
 impl ReaderProperties {
@@ -1198,6 +1535,12 @@ impl ReaderProperties {
     pub(crate) fn read_bloom_filter(&self) -> bool {
         self.read_bloom_filter
     }
+
+    /// Returns whether to verify the CRC checksums of pages
+    #[cfg(feature = "crc")]
+    pub(crate) fn verify_page_checksums(&self) -> bool {
+        self.verify_page_checksums
+    }
 }
 
 /// Builder for parquet file reader configuration. See example on
@@ -1205,6 +1548,8 @@ impl ReaderProperties {
 pub struct ReaderPropertiesBuilder {
     codec_options_builder: CodecOptionsBuilder,
     read_bloom_filter: Option<bool>,
+    #[cfg(feature = "crc")]
+    verify_page_checksums: Option<bool>,
 }
 
 /// Reader properties builder.
@@ -1214,6 +1559,8 @@ impl ReaderPropertiesBuilder {
         Self {
             codec_options_builder: CodecOptionsBuilder::default(),
             read_bloom_filter: None,
+            #[cfg(feature = "crc")]
+            verify_page_checksums: None,
         }
     }
 
@@ -1222,6 +1569,10 @@ impl ReaderPropertiesBuilder {
         ReaderProperties {
             codec_options: self.codec_options_builder.build(),
             read_bloom_filter: self.read_bloom_filter.unwrap_or(DEFAULT_READ_BLOOM_FILTER),
+            #[cfg(feature = "crc")]
+            verify_page_checksums: self
+                .verify_page_checksums
+                .unwrap_or(DEFAULT_VERIFY_PAGE_CHECKSUMS),
         }
     }
 
@@ -1250,6 +1601,19 @@ impl ReaderPropertiesBuilder {
         self.read_bloom_filter = Some(value);
         self
     }
+
+    /// Enable/disable verifying the CRC checksums of pages
+    ///
+    /// If enabled, the checksum of each page that has one in its header is verified
+    /// when the page is read, returning an error naming the column and page if it
+    /// does not match. Pages without a checksum are never verified.
+    ///
+    /// By default page checksums are verified.
+    #[cfg(feature = "crc")]
+    pub fn set_verify_page_checksums(mut self, value: bool) -> Self {
+        self.verify_page_checksums = Some(value);
+        self
+    }
 }
 
 #[cfg(test)]
@@ -1262,6 +1626,35 @@ mod tests {
         assert_eq!(WriterVersion::PARQUET_2_0.as_num(), 2);
     }
 
+    #[test]
+    fn test_data_page_version() {
+        let col = ColumnPath::from("col");
+        let other = ColumnPath::from("other");
+
+        let props = WriterProperties::default();
+        assert_eq!(props.data_page_version(&col), DataPageVersion::V1);
+
+        let props = WriterProperties::builder()
+            .set_writer_version(WriterVersion::PARQUET_2_0)
+            .build();
+        assert_eq!(props.data_page_version(&col), DataPageVersion::V2);
+
+        let props = WriterProperties::builder()
+            .set_writer_version(WriterVersion::PARQUET_2_0)
+            .set_column_data_page_version(col.clone(), DataPageVersion::V1)
+            .build();
+        assert_eq!(props.data_page_version(&col), DataPageVersion::V1);
+        assert_eq!(props.data_page_version(&other), DataPageVersion::V2);
+
+        let props = WriterProperties::builder()
+            .set_data_page_version(DataPageVersion::V2)
+            .set_column_data_page_version(col.clone(), DataPageVersion::V1)
+            .build();
+        assert_eq!(props.writer_version(), WriterVersion::PARQUET_1_0);
+        assert_eq!(props.data_page_version(&col), DataPageVersion::V1);
+        assert_eq!(props.data_page_version(&other), DataPageVersion::V2);
+    }
+
     #[test]
     fn test_writer_properties_default_settings() {
         let props = WriterProperties::default();
@@ -1272,6 +1665,7 @@ mod tests {
         );
         assert_eq!(props.write_batch_size(), DEFAULT_WRITE_BATCH_SIZE);
         assert_eq!(props.max_row_group_size(), DEFAULT_MAX_ROW_GROUP_SIZE);
+        assert_eq!(props.max_row_group_bytes(), None);
         assert_eq!(props.bloom_filter_position(), DEFAULT_BLOOM_FILTER_POSITION);
         assert_eq!(props.writer_version(), DEFAULT_WRITER_VERSION);
         assert_eq!(props.created_by(), DEFAULT_CREATED_BY);
@@ -1355,6 +1749,7 @@ mod tests {
             .set_dictionary_page_size_limit(20)
             .set_write_batch_size(30)
             .set_max_row_group_size(40)
+            .set_max_row_group_bytes(Some(50))
             .set_created_by("default".to_owned())
             .set_key_value_metadata(Some(vec![KeyValue::new(
                 "key".to_string(),
@@ -1369,6 +1764,7 @@ mod tests {
             .set_column_encoding(ColumnPath::from("col"), Encoding::RLE)
             .set_column_compression(ColumnPath::from("col"), Compression::SNAPPY)
             .set_column_dictionary_enabled(ColumnPath::from("col"), true)
+            .set_column_dictionary_fallback_threshold(ColumnPath::from("col"), 0.25)
             .set_column_statistics_enabled(ColumnPath::from("col"), EnabledStatistics::Chunk)
             .set_column_bloom_filter_enabled(ColumnPath::from("col"), true)
             .set_column_bloom_filter_ndv(ColumnPath::from("col"), 100_u64)
@@ -1380,6 +1776,7 @@ mod tests {
         assert_eq!(props.dictionary_page_size_limit(), 20);
         assert_eq!(props.write_batch_size(), 30);
         assert_eq!(props.max_row_group_size(), 40);
+        assert_eq!(props.max_row_group_bytes(), Some(50));
         assert_eq!(props.created_by(), "default");
         assert_eq!(
             props.key_value_metadata(),
@@ -1411,6 +1808,10 @@ mod tests {
             Compression::SNAPPY
         );
         assert!(props.dictionary_enabled(&ColumnPath::from("col")));
+        assert_eq!(
+            props.column_dictionary_fallback_threshold(&ColumnPath::from("col")),
+            Some(0.25)
+        );
         assert_eq!(
             props.statistics_enabled(&ColumnPath::from("col")),
             EnabledStatistics::Chunk
@@ -1481,6 +1882,53 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_writer_properties_bloom_filter_adaptive_ndv() {
+        let col = ColumnPath::from("col");
+        let other = ColumnPath::from("other");
+        let props = WriterProperties::builder().build();
+        assert!(!props.bloom_filter_adaptive_ndv(&col));
+
+        let props = WriterProperties::builder()
+            .set_bloom_filter_adaptive_ndv(true)
+            .set_column_bloom_filter_adaptive_ndv(col.clone(), false)
+            .build();
+        assert!(!props.bloom_filter_adaptive_ndv(&col));
+        assert!(props.bloom_filter_adaptive_ndv(&other));
+        // Does not implicitly enable the bloom filter
+        assert_eq!(props.bloom_filter_properties(&other), None);
+    }
+
+    #[test]
+    fn test_writer_properties_column_statistics_truncate_length() {
+        let props = WriterProperties::builder()
+            .set_statistics_truncate_length(Some(100))
+            .set_column_statistics_truncate_length(ColumnPath::from("short"), Some(8))
+            .set_column_statistics_truncate_length(ColumnPath::from("exact"), None)
+            .build();
+
+        assert_eq!(props.statistics_truncate_length(), Some(100));
+        assert_eq!(
+            props.column_statistics_truncate_length(&ColumnPath::from("short")),
+            Some(8)
+        );
+        assert_eq!(
+            props.column_statistics_truncate_length(&ColumnPath::from("exact")),
+            None
+        );
+        assert_eq!(
+            props.column_statistics_truncate_length(&ColumnPath::from("other")),
+            Some(100)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot have a 0 statistics truncate length")]
+    fn test_writer_properties_column_statistics_truncate_length_zero() {
+        WriterProperties::builder()
+            .set_column_statistics_truncate_length(ColumnPath::from("col"), Some(0));
+    }
+
     #[test]
     fn test_writer_properties_column_dictionary_page_size_limit() {
         let props = WriterProperties::builder()
@@ -1509,6 +1957,8 @@ mod tests {
 
         assert_eq!(props.codec_options(), &codec_options);
         assert!(!props.read_bloom_filter());
+        #[cfg(feature = "crc")]
+        assert!(props.verify_page_checksums());
     }
 
     #[test]
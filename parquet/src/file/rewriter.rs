@@ -0,0 +1,323 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Contains [`ParquetRewriter`] for combining row groups from multiple parquet
+//! files without decoding them
+
+use std::collections::HashSet;
+use std::io::Write;
+
+use crate::bloom_filter::Sbbf;
+use crate::column::writer::ColumnCloseResult;
+use crate::errors::{ParquetError, Result};
+use crate::file::metadata::{ParquetMetaData, ParquetMetaDataReader};
+use crate::file::properties::WriterPropertiesPtr;
+use crate::file::reader::ChunkReader;
+use crate::file::writer::SerializedFileWriter;
+use crate::format as parquet;
+use crate::schema::types::{SchemaDescriptor, TypePtr};
+
+/// Writes a new parquet file by copying row groups from one or more source files
+///
+/// Row groups are copied byte-for-byte, without decoding or re-encoding their
+/// pages, making this suitable for compacting many small files into larger ones.
+/// Any bloom filters, column indexes and offset indexes of the copied row groups
+/// are carried over to the new file, and the key-value metadata of the sources
+/// is merged, with the first value for each key being retained.
+///
+/// All sources must have the same schema as the file being written. Note that
+/// the [`WriterProperties`] only affect the file level metadata and the placement
+/// of bloom filters, the encoding of the copied row groups is preserved as is.
+///
+/// # Example
+/// ```
+/// # use std::sync::Arc;
+/// # use bytes::Bytes;
+/// # use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+/// # use parquet::arrow::ArrowWriter;
+/// # use parquet::file::metadata::ParquetMetaDataReader;
+/// # use parquet::file::rewriter::ParquetRewriter;
+/// # fn write(values: Vec<i32>) -> Bytes {
+/// #     let batch = RecordBatch::try_from_iter([
+/// #         ("a", Arc::new(Int32Array::from(values)) as ArrayRef),
+/// #     ]).unwrap();
+/// #     let mut buf = vec![];
+/// #     let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), None).unwrap();
+/// #     writer.write(&batch).unwrap();
+/// #     writer.close().unwrap();
+/// #     Bytes::from(buf)
+/// # }
+/// let a = write(vec![1, 2, 3]);
+/// let b = write(vec![4, 5]);
+///
+/// let metadata = ParquetMetaDataReader::new().parse_and_finish(&a).unwrap();
+/// let schema = metadata.file_metadata().schema_descr().root_schema_ptr();
+///
+/// let mut rewriter = ParquetRewriter::try_new(vec![], schema, Default::default()).unwrap();
+/// rewriter.append_file(&a).unwrap();
+/// rewriter.append_file(&b).unwrap();
+/// let out = Bytes::from(rewriter.into_inner().unwrap());
+///
+/// let metadata = ParquetMetaDataReader::new().parse_and_finish(&out).unwrap();
+/// assert_eq!(metadata.num_row_groups(), 2);
+/// assert_eq!(metadata.file_metadata().num_rows(), 5);
+/// ```
+///
+/// [`WriterProperties`]: crate::file::properties::WriterProperties
+#[derive(Debug)]
+pub struct ParquetRewriter<W: Write + Send> {
+    writer: SerializedFileWriter<W>,
+    /// Keys of the key-value metadata that will be written
+    kv_keys: HashSet<String>,
+}
+
+impl<W: Write + Send> ParquetRewriter<W> {
+    /// Create a new [`ParquetRewriter`] writing a file with `schema` to `writer`
+    pub fn try_new(writer: W, schema: TypePtr, properties: WriterPropertiesPtr) -> Result<Self> {
+        let kv_keys = properties
+            .key_value_metadata()
+            .into_iter()
+            .flatten()
+            .map(|kv| kv.key.clone())
+            .collect();
+        let writer = SerializedFileWriter::new(writer, schema, properties)?;
+        Ok(Self { writer, kv_keys })
+    }
+
+    /// Returns the schema of the file being written
+    pub fn schema_descr(&self) -> &SchemaDescriptor {
+        self.writer.schema_descr()
+    }
+
+    /// Copies all row groups of the parquet file in `reader`, along with any page
+    /// indexes and bloom filters
+    pub fn append_file<R: ChunkReader>(&mut self, reader: &R) -> Result<()> {
+        let metadata = ParquetMetaDataReader::new()
+            .with_page_indexes(true)
+            .parse_and_finish(reader)?;
+        self.append_row_groups(reader, &metadata, 0..metadata.num_row_groups())
+    }
+
+    /// Copies the row groups at the indices `row_groups` from the parquet file in
+    /// `reader`, described by `metadata`
+    ///
+    /// Column and offset indexes are only copied if they have been loaded into
+    /// `metadata`, see [`ParquetMetaDataReader::with_page_indexes`]. Bloom filters
+    /// are read from `reader`.
+    pub fn append_row_groups<R: ChunkReader>(
+        &mut self,
+        reader: &R,
+        metadata: &ParquetMetaData,
+        row_groups: impl IntoIterator<Item = usize>,
+    ) -> Result<()> {
+        if metadata.file_metadata().schema() != self.writer.schema_descr().root_schema() {
+            return Err(general_err!(
+                "Cannot append row groups, source schema does not match the schema being written"
+            ));
+        }
+
+        for kv in metadata
+            .file_metadata()
+            .key_value_metadata()
+            .into_iter()
+            .flatten()
+        {
+            if self.kv_keys.insert(kv.key.clone()) {
+                self.writer.append_key_value_metadata(kv.clone());
+            }
+        }
+
+        for rg_idx in row_groups {
+            let row_group = metadata.row_groups().get(rg_idx).ok_or_else(|| {
+                general_err!(
+                    "row group {} out of bounds 0..{}",
+                    rg_idx,
+                    metadata.num_row_groups()
+                )
+            })?;
+            let column_indexes = metadata.column_index().and_then(|x| x.get(rg_idx));
+            let offset_indexes = metadata.offset_index().and_then(|x| x.get(rg_idx));
+
+            let mut writer = self.writer.next_row_group()?;
+            for (col_idx, column) in row_group.columns().iter().enumerate() {
+                let close = ColumnCloseResult {
+                    bytes_written: column.compressed_size() as _,
+                    rows_written: row_group.num_rows() as _,
+                    metadata: column.clone(),
+                    bloom_filter: Sbbf::read_from_column_chunk(column, reader)?,
+                    column_index: column_indexes
+                        .and_then(|x| x.get(col_idx))
+                        .and_then(|x| x.to_thrift()),
+                    offset_index: offset_indexes
+                        .and_then(|x| x.get(col_idx))
+                        .map(|x| x.to_thrift()),
+                };
+                writer.append_column(reader, close)?;
+            }
+            writer.close()?;
+        }
+        Ok(())
+    }
+
+    /// Writes the footer and returns the file metadata
+    pub fn close(self) -> Result<parquet::FileMetaData> {
+        self.writer.close()
+    }
+
+    /// Writes the footer and returns the underlying writer
+    pub fn into_inner(self) -> Result<W> {
+        self.writer.into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrow::arrow_reader::{ArrowReaderOptions, ParquetRecordBatchReaderBuilder};
+    use crate::arrow::ArrowWriter;
+    use crate::file::metadata::KeyValue;
+    use crate::file::page_index::index::Index;
+    use crate::file::properties::ReaderProperties;
+    use crate::file::properties::WriterProperties;
+    use crate::file::reader::{FileReader, SerializedFileReader};
+    use crate::file::serialized_reader::ReadOptionsBuilder;
+    use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+    use bytes::Bytes;
+    use std::sync::Arc;
+
+    fn write(values: std::ops::Range<i32>, kv: &str) -> Bytes {
+        let batch = RecordBatch::try_from_iter([(
+            "a",
+            Arc::new(Int32Array::from_iter_values(values)) as ArrayRef,
+        )])
+        .unwrap();
+        let props = WriterProperties::builder()
+            .set_bloom_filter_enabled(true)
+            .set_max_row_group_size(10)
+            .set_key_value_metadata(Some(vec![KeyValue::new("k".to_string(), kv.to_string())]))
+            .build();
+        let mut buf = vec![];
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        Bytes::from(buf)
+    }
+
+    #[test]
+    fn test_rewriter() {
+        let a = write(0..20, "a");
+        let b = write(20..50, "b");
+
+        let metadata = ParquetMetaDataReader::new()
+            .with_page_indexes(true)
+            .parse_and_finish(&b)
+            .unwrap();
+        let schema = metadata.file_metadata().schema_descr().root_schema_ptr();
+
+        let mut rewriter = ParquetRewriter::try_new(vec![], schema, Default::default()).unwrap();
+        rewriter.append_file(&a).unwrap();
+        rewriter.append_row_groups(&b, &metadata, [2, 0]).unwrap();
+
+        let err = rewriter.append_row_groups(&b, &metadata, [3]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: row group 3 out of bounds 0..3"
+        );
+
+        let out = Bytes::from(rewriter.into_inner().unwrap());
+
+        let options = ArrowReaderOptions::new().with_page_index(true);
+        let builder =
+            ParquetRecordBatchReaderBuilder::try_new_with_options(out.clone(), options).unwrap();
+        let metadata = builder.metadata().clone();
+        assert_eq!(metadata.num_row_groups(), 4);
+
+        let kv = metadata.file_metadata().key_value_metadata().unwrap();
+        let k: Vec<_> = kv.iter().filter(|kv| kv.key == "k").collect();
+        assert_eq!(k.len(), 1);
+        assert_eq!(k[0].value.as_deref(), Some("a"));
+
+        let expected = [0..10, 10..20, 40..50, 20..30];
+        for (idx, range) in expected.iter().enumerate() {
+            assert_eq!(metadata.row_group(idx).num_rows(), 10);
+            match &metadata.column_index().unwrap()[idx][0] {
+                Index::INT32(index) => {
+                    assert_eq!(index.indexes[0].min, Some(range.start));
+                    assert_eq!(index.indexes[0].max, Some(range.end - 1));
+                }
+                _ => unreachable!(),
+            }
+            assert_eq!(
+                metadata.offset_index().unwrap()[idx][0]
+                    .page_locations
+                    .len(),
+                1
+            );
+        }
+
+        let values: Vec<i32> = builder
+            .build()
+            .unwrap()
+            .flat_map(|batch| {
+                let batch = batch.unwrap();
+                let values = batch.column(0).as_any().downcast_ref::<Int32Array>();
+                values.unwrap().values().to_vec()
+            })
+            .collect();
+        let expected_values: Vec<i32> = expected.iter().cloned().flatten().collect();
+        assert_eq!(values, expected_values);
+
+        let reader_options = ReadOptionsBuilder::new()
+            .with_reader_properties(
+                ReaderProperties::builder()
+                    .set_read_bloom_filter(true)
+                    .build(),
+            )
+            .build();
+        let reader = SerializedFileReader::new_with_options(out, reader_options).unwrap();
+        for (idx, range) in expected.iter().enumerate() {
+            let row_group = reader.get_row_group(idx).unwrap();
+            let sbbf = row_group.get_column_bloom_filter(0).unwrap();
+            assert!(sbbf.check(&range.start));
+            assert!(sbbf.check(&(range.end - 1)));
+        }
+    }
+
+    #[test]
+    fn test_rewriter_schema_mismatch() {
+        let a = write(0..20, "a");
+        let batch = RecordBatch::try_from_iter([(
+            "b",
+            Arc::new(Int32Array::from_iter_values(0..10)) as ArrayRef,
+        )])
+        .unwrap();
+        let mut buf = vec![];
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let b = Bytes::from(buf);
+
+        let metadata = ParquetMetaDataReader::new().parse_and_finish(&a).unwrap();
+        let schema = metadata.file_metadata().schema_descr().root_schema_ptr();
+        let mut rewriter = ParquetRewriter::try_new(vec![], schema, Default::default()).unwrap();
+        let err = rewriter.append_file(&b).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Cannot append row groups, source schema does not match the schema being written"
+        );
+    }
+}
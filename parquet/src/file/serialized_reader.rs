@@ -20,7 +20,7 @@
 
 use crate::basic::{Encoding, Type};
 use crate::bloom_filter::Sbbf;
-use crate::column::page::{Page, PageMetadata, PageReader};
+use crate::column::page::{CompressedPage, Page, PageMetadata, PageReader};
 use crate::compression::{create_codec, Codec};
 #[cfg(feature = "encryption")]
 use crate::encryption::decrypt::{read_and_decrypt, CryptoContext};
@@ -346,15 +346,6 @@ pub(crate) fn decode_page(
     physical_type: Type,
     decompressor: Option<&mut Box<dyn Codec>>,
 ) -> Result<Page> {
-    // Verify the 32-bit CRC checksum of the page
-    #[cfg(feature = "crc")]
-    if let Some(expected_crc) = page_header.crc {
-        let crc = crc32fast::hash(&buffer);
-        if crc != expected_crc as u32 {
-            return Err(general_err!("Page CRC checksum mismatch"));
-        }
-    }
-
     // When processing data page v2, depending on enabled compression for the
     // page, we should account for uncompressed data ('offset') of
     // repetition and definition levels.
@@ -413,6 +404,11 @@ pub(crate) fn decode_page(
         _ => buffer,
     };
 
+    page_from_header(page_header, buffer, physical_type)
+}
+
+/// Creates a [`Page`] from `page_header` and its data `buffer`, without decompressing it
+fn page_from_header(page_header: PageHeader, buffer: Bytes, physical_type: Type) -> Result<Page> {
     let result = match page_header.type_ {
         PageType::DICTIONARY_PAGE => {
             let dict_header = page_header.dictionary_page_header.as_ref().ok_or_else(|| {
@@ -501,6 +497,12 @@ struct SerializedPageReaderContext {
     /// Crypto context carrying objects required for decryption
     #[cfg(feature = "encryption")]
     crypto_context: Option<Arc<CryptoContext>>,
+    /// Whether to verify the CRC checksums of pages that have one
+    #[cfg(feature = "crc")]
+    verify_page_checksums: bool,
+    /// Path of the column, used to identify it in checksum errors
+    #[cfg(feature = "crc")]
+    column_path: String,
 }
 
 /// A serialized implementation for Parquet [`PageReader`].
@@ -614,7 +616,14 @@ impl<R: ChunkReader> SerializedPageReader<R> {
             decompressor,
             state,
             physical_type: meta.column_type(),
-            context: Default::default(),
+            context: SerializedPageReaderContext {
+                #[cfg(feature = "crc")]
+                verify_page_checksums: props.verify_page_checksums(),
+                #[cfg(feature = "crc")]
+                column_path: meta.column_path().string(),
+                #[cfg(feature = "encryption")]
+                crypto_context: None,
+            },
         })
     }
 
@@ -682,6 +691,146 @@ impl<R: ChunkReader> SerializedPageReader<R> {
         }
     }
 
+    /// Reads the next page without decompressing it, returning `None` once all
+    /// pages of the column chunk have been read
+    ///
+    /// Encrypted pages are decrypted, and their checksums verified if enabled in
+    /// the [`ReaderProperties`]. The returned page can be written to another file
+    /// with [`GenericColumnWriter::write_compressed_page`], for example to copy
+    /// a subset of its pages, or to re-encrypt it, without decoding its values.
+    ///
+    /// [`GenericColumnWriter::write_compressed_page`]: crate::column::writer::GenericColumnWriter::write_compressed_page
+    pub fn get_next_compressed_page(&mut self) -> Result<Option<CompressedPage>> {
+        let Some((header, buffer)) = self.read_next_page()? else {
+            return Ok(None);
+        };
+        let uncompressed_size = usize::try_from(header.uncompressed_page_size)?;
+        let page = page_from_header(header, buffer, self.physical_type)?;
+        Ok(Some(CompressedPage::new(page, uncompressed_size)))
+    }
+
+    /// Returns an iterator over the remaining pages of the column chunk, without
+    /// decompressing them, see [`Self::get_next_compressed_page`]
+    pub fn into_compressed_pages(mut self) -> impl Iterator<Item = Result<CompressedPage>> {
+        std::iter::from_fn(move || self.get_next_compressed_page().transpose())
+    }
+
+    /// Reads the header and, decrypted, but still compressed data of the next page
+    fn read_next_page(&mut self) -> Result<Option<(PageHeader, Bytes)>> {
+        loop {
+            let page = match &mut self.state {
+                SerializedPageReaderState::Values {
+                    offset,
+                    remaining_bytes: remaining,
+                    next_page_header,
+                    page_index,
+                    require_dictionary,
+                } => {
+                    if *remaining == 0 {
+                        return Ok(None);
+                    }
+
+                    let mut read = self.reader.get_read(*offset)?;
+                    let header = if let Some(header) = next_page_header.take() {
+                        *header
+                    } else {
+                        let (header_len, header) = Self::read_page_header_len(
+                            &self.context,
+                            &mut read,
+                            *page_index,
+                            *require_dictionary,
+                        )?;
+                        verify_page_header_len(header_len, *remaining)?;
+                        *offset += header_len as u64;
+                        *remaining -= header_len as u64;
+                        header
+                    };
+                    verify_page_size(
+                        header.compressed_page_size,
+                        header.uncompressed_page_size,
+                        *remaining,
+                    )?;
+                    let data_len = header.compressed_page_size as usize;
+                    *offset += data_len as u64;
+                    *remaining -= data_len as u64;
+
+                    if header.type_ == PageType::INDEX_PAGE {
+                        continue;
+                    }
+
+                    let mut buffer = Vec::with_capacity(data_len);
+                    let read = read.take(data_len as u64).read_to_end(&mut buffer)?;
+
+                    if read != data_len {
+                        return Err(eof_err!(
+                            "Expected to read {} bytes of page, read only {}",
+                            data_len,
+                            read
+                        ));
+                    }
+
+                    let buffer =
+                        self.context
+                            .decrypt_page_data(buffer, *page_index, *require_dictionary)?;
+                    self.context.verify_page_checksum(
+                        &header,
+                        &buffer,
+                        *page_index,
+                        *require_dictionary,
+                    )?;
+
+                    match header.type_ {
+                        PageType::DATA_PAGE | PageType::DATA_PAGE_V2 => *page_index += 1,
+                        PageType::DICTIONARY_PAGE => *require_dictionary = false,
+                        _ => {}
+                    }
+                    (header, Bytes::from(buffer))
+                }
+                SerializedPageReaderState::Pages {
+                    page_locations,
+                    dictionary_page,
+                    page_index,
+                    ..
+                } => {
+                    let (front, is_dictionary_page) = match dictionary_page.take() {
+                        Some(front) => (front, true),
+                        None => match page_locations.pop_front() {
+                            Some(front) => (front, false),
+                            None => return Ok(None),
+                        },
+                    };
+
+                    let page_len = usize::try_from(front.compressed_page_size)?;
+                    let buffer = self.reader.get_bytes(front.offset as u64, page_len)?;
+
+                    let (offset, header) = Self::read_page_header_len_from_bytes(
+                        &self.context,
+                        buffer.as_ref(),
+                        *page_index,
+                        is_dictionary_page,
+                    )?;
+                    let bytes = buffer.slice(offset..);
+                    let bytes =
+                        self.context
+                            .decrypt_page_data(bytes, *page_index, is_dictionary_page)?;
+                    self.context.verify_page_checksum(
+                        &header,
+                        &bytes,
+                        *page_index,
+                        is_dictionary_page,
+                    )?;
+
+                    if !is_dictionary_page {
+                        *page_index += 1;
+                    }
+                    (header, bytes)
+                }
+            };
+
+            return Ok(Some(page));
+        }
+    }
+
     fn read_page_header_len<T: Read>(
         context: &SerializedPageReaderContext,
         input: &mut T,
@@ -723,6 +872,50 @@ impl<R: ChunkReader> SerializedPageReader<R> {
     }
 }
 
+impl SerializedPageReaderContext {
+    /// Verifies the CRC checksum of the page data against the one in its header,
+    /// if the header has one and verification is enabled
+    #[cfg(feature = "crc")]
+    fn verify_page_checksum(
+        &self,
+        header: &PageHeader,
+        buffer: &[u8],
+        page_index: usize,
+        dictionary_page: bool,
+    ) -> Result<()> {
+        let expected_crc = match header.crc {
+            Some(crc) if self.verify_page_checksums => crc as u32,
+            _ => return Ok(()),
+        };
+        let crc = crc32fast::hash(buffer);
+        if crc != expected_crc {
+            let page = match dictionary_page {
+                true => "dictionary page".to_string(),
+                false => format!("data page {page_index}"),
+            };
+            return Err(general_err!(
+                "Page CRC checksum mismatch for column '{}' {}: expected {:#010x}, got {:#010x}",
+                self.column_path,
+                page,
+                expected_crc,
+                crc
+            ));
+        }
+        Ok(())
+    }
+
+    #[cfg(not(feature = "crc"))]
+    fn verify_page_checksum(
+        &self,
+        _header: &PageHeader,
+        _buffer: &[u8],
+        _page_index: usize,
+        _dictionary_page: bool,
+    ) -> Result<()> {
+        Ok(())
+    }
+}
+
 #[cfg(not(feature = "encryption"))]
 impl SerializedPageReaderContext {
     fn read_page_header<T: Read>(
@@ -837,117 +1030,16 @@ fn verify_page_size(
 
 impl<R: ChunkReader> PageReader for SerializedPageReader<R> {
     fn get_next_page(&mut self) -> Result<Option<Page>> {
-        loop {
-            let page = match &mut self.state {
-                SerializedPageReaderState::Values {
-                    offset,
-                    remaining_bytes: remaining,
-                    next_page_header,
-                    page_index,
-                    require_dictionary,
-                } => {
-                    if *remaining == 0 {
-                        return Ok(None);
-                    }
-
-                    let mut read = self.reader.get_read(*offset)?;
-                    let header = if let Some(header) = next_page_header.take() {
-                        *header
-                    } else {
-                        let (header_len, header) = Self::read_page_header_len(
-                            &self.context,
-                            &mut read,
-                            *page_index,
-                            *require_dictionary,
-                        )?;
-                        verify_page_header_len(header_len, *remaining)?;
-                        *offset += header_len as u64;
-                        *remaining -= header_len as u64;
-                        header
-                    };
-                    verify_page_size(
-                        header.compressed_page_size,
-                        header.uncompressed_page_size,
-                        *remaining,
-                    )?;
-                    let data_len = header.compressed_page_size as usize;
-                    *offset += data_len as u64;
-                    *remaining -= data_len as u64;
-
-                    if header.type_ == PageType::INDEX_PAGE {
-                        continue;
-                    }
-
-                    let mut buffer = Vec::with_capacity(data_len);
-                    let read = read.take(data_len as u64).read_to_end(&mut buffer)?;
-
-                    if read != data_len {
-                        return Err(eof_err!(
-                            "Expected to read {} bytes of page, read only {}",
-                            data_len,
-                            read
-                        ));
-                    }
-
-                    let buffer =
-                        self.context
-                            .decrypt_page_data(buffer, *page_index, *require_dictionary)?;
-
-                    let page = decode_page(
-                        header,
-                        Bytes::from(buffer),
-                        self.physical_type,
-                        self.decompressor.as_mut(),
-                    )?;
-                    if page.is_data_page() {
-                        *page_index += 1;
-                    } else if page.is_dictionary_page() {
-                        *require_dictionary = false;
-                    }
-                    page
-                }
-                SerializedPageReaderState::Pages {
-                    page_locations,
-                    dictionary_page,
-                    page_index,
-                    ..
-                } => {
-                    let (front, is_dictionary_page) = match dictionary_page.take() {
-                        Some(front) => (front, true),
-                        None => match page_locations.pop_front() {
-                            Some(front) => (front, false),
-                            None => return Ok(None),
-                        },
-                    };
-
-                    let page_len = usize::try_from(front.compressed_page_size)?;
-                    let buffer = self.reader.get_bytes(front.offset as u64, page_len)?;
-
-                    let (offset, header) = Self::read_page_header_len_from_bytes(
-                        &self.context,
-                        buffer.as_ref(),
-                        *page_index,
-                        is_dictionary_page,
-                    )?;
-                    let bytes = buffer.slice(offset..);
-                    let bytes =
-                        self.context
-                            .decrypt_page_data(bytes, *page_index, is_dictionary_page)?;
-
-                    if !is_dictionary_page {
-                        *page_index += 1;
-                    }
-                    decode_page(
-                        header,
-                        bytes,
-                        self.physical_type,
-                        self.decompressor.as_mut(),
-                    )?
-                }
-            };
-
-            return Ok(Some(page));
-        }
+        let Some((header, buffer)) = self.read_next_page()? else {
+            return Ok(None);
+        };
+        let page = decode_page(
+            header,
+            buffer,
+            self.physical_type,
+            self.decompressor.as_mut(),
+        )?;
+        Ok(Some(page))
     }
 
     fn peek_next_page(&mut self) -> Result<Option<PageMetadata>> {
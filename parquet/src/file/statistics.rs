@@ -296,11 +296,9 @@ pub fn to_thrift(stats: Option<&Statistics>) -> Option<TStatistics> {
     };
 
     // Get min/max if set.
-    let (min, max, min_exact, max_exact) = (
+    let (min, max) = (
         stats.min_bytes_opt().map(|x| x.to_vec()),
         stats.max_bytes_opt().map(|x| x.to_vec()),
-        Some(stats.min_is_exact()),
-        Some(stats.max_is_exact()),
     );
     if stats.is_min_max_backwards_compatible() {
         // Copy to deprecated min, max values for compatibility with older readers
@@ -308,14 +306,15 @@ pub fn to_thrift(stats: Option<&Statistics>) -> Option<TStatistics> {
         thrift_stats.max.clone_from(&max);
     }
 
+    // Only write the exactness flags alongside the values they describe
+    thrift_stats.is_min_value_exact = min.as_ref().map(|_| stats.min_is_exact());
+    thrift_stats.is_max_value_exact = max.as_ref().map(|_| stats.max_is_exact());
+
     if !stats.is_min_max_deprecated() {
         thrift_stats.min_value = min;
         thrift_stats.max_value = max;
     }
 
-    thrift_stats.is_min_value_exact = min_exact;
-    thrift_stats.is_max_value_exact = max_exact;
-
     Some(thrift_stats)
 }
 
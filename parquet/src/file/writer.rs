@@ -23,7 +23,8 @@ use crate::format as parquet;
 use crate::format::{ColumnIndex, OffsetIndex};
 use crate::thrift::TSerializable;
 use std::fmt::Debug;
-use std::io::{BufWriter, IoSlice, Read};
+use std::fs::File;
+use std::io::{BufWriter, IoSlice, Read, Seek, SeekFrom};
 use std::{io::Write, sync::Arc};
 use thrift::protocol::TCompactOutputProtocol;
 
@@ -43,7 +44,7 @@ use crate::file::properties::{BloomFilterPosition, WriterPropertiesPtr};
 use crate::file::reader::ChunkReader;
 #[cfg(feature = "encryption")]
 use crate::file::PARQUET_MAGIC_ENCR_FOOTER;
-use crate::file::{metadata::*, PARQUET_MAGIC};
+use crate::file::{metadata::*, FOOTER_SIZE, PARQUET_MAGIC};
 use crate::schema::types::{ColumnDescPtr, SchemaDescPtr, SchemaDescriptor, TypePtr};
 
 /// A wrapper around a [`Write`] that keeps track of the number
@@ -179,14 +180,22 @@ impl<W: Write> Debug for SerializedFileWriter<W> {
 impl<W: Write + Send> SerializedFileWriter<W> {
     /// Creates new file writer.
     pub fn new(buf: W, schema: TypePtr, properties: WriterPropertiesPtr) -> Result<Self> {
-        let mut buf = TrackedWrite::new(buf);
+        let mut writer = Self::new_with_tracked(TrackedWrite::new(buf), schema, properties)?;
+        Self::start_file(&writer.props, &mut writer.buf)?;
+        Ok(writer)
+    }
 
+    /// Creates a new file writer writing to `buf`, without writing the file magic
+    fn new_with_tracked(
+        buf: TrackedWrite<W>,
+        schema: TypePtr,
+        properties: WriterPropertiesPtr,
+    ) -> Result<Self> {
         let schema_descriptor = SchemaDescriptor::new(schema.clone());
 
         #[cfg(feature = "encryption")]
         let file_encryptor = Self::get_file_encryptor(&properties, &schema_descriptor)?;
 
-        Self::start_file(&properties, &mut buf)?;
         Ok(Self {
             buf,
             schema,
@@ -438,6 +447,124 @@ impl<W: Write + Send> SerializedFileWriter<W> {
     }
 }
 
+impl SerializedFileWriter<File> {
+    /// Opens an existing parquet `file` to append new row groups to
+    ///
+    /// Only the footer of `file` is rewritten: it is truncated, any new row groups
+    /// are written after the existing data, and closing the writer then writes a
+    /// new footer referencing both the existing and the new row groups. The data,
+    /// page indexes and bloom filters of the existing row groups are left in place.
+    ///
+    /// `schema` must be identical to the schema of the existing file. Any key-value
+    /// metadata of the existing file is retained, unless overridden by a key
+    /// in `properties`.
+    ///
+    /// `file` must be opened for both reading and writing. Appending to encrypted
+    /// files is not supported.
+    ///
+    /// Note: until the writer is closed, `file` will not have a valid footer, and
+    /// so if the writer is dropped without being closed the file will be unreadable.
+    pub fn try_append(
+        mut file: File,
+        schema: TypePtr,
+        properties: WriterPropertiesPtr,
+    ) -> Result<Self> {
+        #[cfg(feature = "encryption")]
+        if properties.file_encryption_properties.is_some() {
+            return Err(nyi_err!(
+                "Appending to encrypted parquet files is not supported"
+            ));
+        }
+
+        let file_len = file.seek(SeekFrom::End(0))?;
+        if file_len < (PARQUET_MAGIC.len() + FOOTER_SIZE) as u64 {
+            return Err(general_err!(
+                "Cannot append to file of size {}, not a parquet file",
+                file_len
+            ));
+        }
+
+        let mut footer = [0; FOOTER_SIZE];
+        file.seek(SeekFrom::End(-(FOOTER_SIZE as i64)))?;
+        file.read_exact(&mut footer)?;
+        let footer = ParquetMetaDataReader::decode_footer_tail(&footer)?;
+        if footer.is_encrypted_footer() {
+            return Err(nyi_err!(
+                "Appending to encrypted parquet files is not supported"
+            ));
+        }
+
+        let metadata_len = footer.metadata_length() as u64;
+        let footer_start = file_len
+            .checked_sub(FOOTER_SIZE as u64 + metadata_len)
+            .filter(|start| *start >= PARQUET_MAGIC.len() as u64)
+            .ok_or_else(|| {
+                general_err!(
+                    "Invalid parquet file. Metadata length {} exceeds file size {}",
+                    metadata_len,
+                    file_len
+                )
+            })?;
+
+        let mut metadata = vec![0; metadata_len as usize];
+        file.seek(SeekFrom::Start(footer_start))?;
+        file.read_exact(&mut metadata)?;
+        let metadata = ParquetMetaDataReader::decode_metadata(&metadata)?;
+
+        if metadata.file_metadata().schema() != schema.as_ref() {
+            return Err(general_err!(
+                "Cannot append to parquet file, schema does not match the existing file"
+            ));
+        }
+
+        // Retain any existing key-value metadata not overridden by `properties`
+        let kv_metadatas = metadata
+            .file_metadata()
+            .key_value_metadata()
+            .into_iter()
+            .flatten()
+            .filter(|kv| {
+                !properties
+                    .key_value_metadata()
+                    .is_some_and(|p| p.iter().any(|x| x.key == kv.key))
+            })
+            .cloned()
+            .collect();
+
+        let row_groups = metadata
+            .row_groups()
+            .iter()
+            .enumerate()
+            .map(|(idx, rg)| {
+                let ordinal = idx.try_into().map_err(|_| {
+                    general_err!(
+                        "Parquet does not support more than {} row groups per file",
+                        i16::MAX
+                    )
+                })?;
+                rg.clone().into_builder().set_ordinal(ordinal).build()
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Overwrite the existing footer
+        file.set_len(footer_start)?;
+        file.seek(SeekFrom::Start(footer_start))?;
+        let mut buf = TrackedWrite::new(file);
+        buf.bytes_written = footer_start as usize;
+
+        let mut writer = Self::new_with_tracked(buf, schema, properties)?;
+        let num_columns = writer.descr.num_columns();
+        let num_row_groups = row_groups.len();
+        writer.row_groups = row_groups;
+        writer.bloom_filters = vec![vec![None; num_columns]; num_row_groups];
+        writer.column_indexes = vec![vec![None; num_columns]; num_row_groups];
+        writer.offset_indexes = vec![vec![None; num_columns]; num_row_groups];
+        writer.row_group_index = num_row_groups;
+        writer.kv_metadatas = kv_metadatas;
+        Ok(writer)
+    }
+}
+
 /// Serialize all the bloom filters of the given row group to the given buffer,
 /// and returns the updated row group metadata.
 fn write_bloom_filters<W: Write + Send>(
@@ -709,6 +836,9 @@ impl<'a, W: Write + Send> SerializedRowGroupWriter<'a, W> {
         if let Some(page_encoding_stats) = metadata.page_encoding_stats() {
             builder = builder.set_page_encoding_stats(page_encoding_stats.clone())
         }
+        if let Some(geo_statistics) = metadata.geo_statistics() {
+            builder = builder.set_geo_statistics(Some(Box::new(geo_statistics.clone())))
+        }
         builder = self.set_column_crypto_metadata(builder, &metadata);
         close.metadata = builder.build()?;
 
@@ -867,6 +997,14 @@ impl<'a> SerializedColumnWriter<'a> {
         get_typed_column_writer_mut(&mut self.inner)
     }
 
+    /// Write a page that has already been encoded and compressed, for example one
+    /// read from another file, see [`GenericColumnWriter::write_compressed_page`]
+    ///
+    /// [`GenericColumnWriter::write_compressed_page`]: crate::column::writer::GenericColumnWriter::write_compressed_page
+    pub fn write_compressed_page(&mut self, page: CompressedPage) -> Result<()> {
+        self.inner.write_compressed_page(page)
+    }
+
     /// Close this [`SerializedColumnWriter`]
     pub fn close(mut self) -> Result<()> {
         let r = self.inner.close()?;
@@ -1353,6 +1491,194 @@ mod tests {
         assert_eq!(expected_result.as_ref(), result[0]);
     }
 
+    #[test]
+    #[cfg(feature = "crc")]
+    fn test_file_writer_page_checksums() {
+        let schema =
+            Arc::new(parse_message_type("message schema { REQUIRED INT32 col; }").unwrap());
+        let props = Arc::new(
+            WriterProperties::builder()
+                .set_dictionary_enabled(false)
+                .set_page_checksum_enabled(true)
+                .build(),
+        );
+
+        let mut buffer = vec![];
+        let mut writer = SerializedFileWriter::new(&mut buffer, schema, props).unwrap();
+        let mut row_group_writer = writer.next_row_group().unwrap();
+        let mut col_writer = row_group_writer.next_column().unwrap().unwrap();
+        col_writer
+            .typed::<Int32Type>()
+            .write_batch(&[1, 2, 3, 4, 5], None, None)
+            .unwrap();
+        col_writer.close().unwrap();
+        row_group_writer.close().unwrap();
+        writer.close().unwrap();
+
+        // Every page header should carry a checksum, which is verified on read
+        let reader = SerializedFileReader::new(Bytes::from(buffer.clone())).unwrap();
+        let row_group = reader.get_row_group(0).unwrap();
+        let mut values = vec![];
+        let mut column_reader =
+            get_typed_column_reader::<Int32Type>(row_group.get_column_reader(0).unwrap());
+        column_reader
+            .read_records(5, None, None, &mut values)
+            .unwrap();
+        assert_eq!(values, [1, 2, 3, 4, 5]);
+
+        let column = reader.metadata().row_group(0).column(0);
+        let (start, len) = column.byte_range();
+        let mut chunk = &buffer[start as usize..(start + len) as usize];
+        let mut prot = thrift::protocol::TCompactInputProtocol::new(&mut chunk);
+        let header = crate::format::PageHeader::read_from_in_protocol(&mut prot).unwrap();
+        assert_eq!(header.crc, Some(crc32fast::hash(&chunk[..4 * 5]) as i32));
+
+        // Corrupt the last value of the data page
+        let corrupt_pos = (start + len) as usize - 1;
+        buffer[corrupt_pos] ^= 0xFF;
+        let data = Bytes::from(buffer);
+
+        let reader = SerializedFileReader::new(data.clone()).unwrap();
+        let mut page_reader = reader
+            .get_row_group(0)
+            .unwrap()
+            .get_column_page_reader(0)
+            .unwrap();
+        let err = page_reader
+            .get_next_page()
+            .map(|_| ())
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.starts_with(
+                "Parquet error: Page CRC checksum mismatch for column 'col' data page 0"
+            ),
+            "{err}"
+        );
+
+        // Verification can be disabled
+        let options = ReadOptionsBuilder::new()
+            .with_reader_properties(
+                ReaderProperties::builder()
+                    .set_verify_page_checksums(false)
+                    .build(),
+            )
+            .build();
+        let reader = SerializedFileReader::new_with_options(data, options).unwrap();
+        let mut page_reader = reader
+            .get_row_group(0)
+            .unwrap()
+            .get_column_page_reader(0)
+            .unwrap();
+        assert!(page_reader.get_next_page().unwrap().is_some());
+    }
+
+    #[test]
+    fn test_file_writer_copy_compressed_pages() {
+        let schema = Arc::new(
+            parse_message_type("message schema { REQUIRED INT32 a; REPEATED INT32 b; }").unwrap(),
+        );
+        for version in [WriterVersion::PARQUET_1_0, WriterVersion::PARQUET_2_0] {
+            let props = Arc::new(
+                WriterProperties::builder()
+                    .set_writer_version(version)
+                    .set_compression(Compression::SNAPPY)
+                    .set_data_page_row_count_limit(10)
+                    .set_write_batch_size(10)
+                    .build(),
+            );
+
+            let a: Vec<i32> = (0..100).collect();
+            let b: Vec<i32> = (0..200).map(|i| i % 7).collect();
+            let b_rep: Vec<i16> = (0..200).map(|i| (i % 2) as i16).collect();
+
+            let mut buffer = vec![];
+            let mut writer =
+                SerializedFileWriter::new(&mut buffer, schema.clone(), props.clone()).unwrap();
+            let mut row_group_writer = writer.next_row_group().unwrap();
+            let mut col_writer = row_group_writer.next_column().unwrap().unwrap();
+            col_writer
+                .typed::<Int32Type>()
+                .write_batch(&a, None, None)
+                .unwrap();
+            col_writer.close().unwrap();
+            let mut col_writer = row_group_writer.next_column().unwrap().unwrap();
+            col_writer
+                .typed::<Int32Type>()
+                .write_batch(&b, Some(&[1; 200]), Some(&b_rep))
+                .unwrap();
+            col_writer.close().unwrap();
+            row_group_writer.close().unwrap();
+            writer.close().unwrap();
+
+            // Copy the pages of each column to a new file
+            let source = Bytes::from(buffer);
+            let options = ReadOptionsBuilder::new().with_page_index().build();
+            let reader = SerializedFileReader::new_with_options(source.clone(), options).unwrap();
+            let row_group = reader.metadata().row_group(0);
+
+            let mut copy = vec![];
+            let mut writer =
+                SerializedFileWriter::new(&mut copy, schema.clone(), props.clone()).unwrap();
+            let mut row_group_writer = writer.next_row_group().unwrap();
+            for column in row_group.columns() {
+                let page_reader = SerializedPageReader::new(
+                    Arc::new(source.clone()),
+                    column,
+                    row_group.num_rows() as usize,
+                    None,
+                )
+                .unwrap();
+                let mut col_writer = row_group_writer.next_column().unwrap().unwrap();
+                for page in page_reader.into_compressed_pages() {
+                    col_writer.write_compressed_page(page.unwrap()).unwrap();
+                }
+                // Values cannot be written to a column containing pre-encoded pages
+                let err = col_writer
+                    .typed::<Int32Type>()
+                    .write_batch(&[1], Some(&[1]), Some(&[0]))
+                    .unwrap_err();
+                assert_eq!(
+                    err.to_string(),
+                    "Parquet error: Cannot write values to a column containing pre-encoded pages"
+                );
+                col_writer.close().unwrap();
+            }
+            let copied_row_group = row_group_writer.close().unwrap();
+            writer.close().unwrap();
+
+            assert_eq!(copied_row_group.num_rows(), 100);
+            for (copied, original) in copied_row_group.columns().iter().zip(row_group.columns()) {
+                assert_eq!(copied.num_values(), original.num_values());
+                assert_eq!(copied.compressed_size(), original.compressed_size());
+                assert_eq!(copied.uncompressed_size(), original.uncompressed_size());
+                assert_eq!(copied.encodings(), original.encodings());
+                assert!(copied.statistics().is_none());
+            }
+
+            // The copy has the same data and row locations
+            let options = ReadOptionsBuilder::new().with_page_index().build();
+            let copy = SerializedFileReader::new_with_options(Bytes::from(copy), options).unwrap();
+            let original_rows: Vec<Row> = reader.get_row_iter(None).unwrap().flatten().collect();
+            let copied_rows: Vec<Row> = copy.get_row_iter(None).unwrap().flatten().collect();
+            assert_eq!(copied_rows, original_rows);
+
+            let first_row_indexes = |reader: &SerializedFileReader<Bytes>| {
+                reader.metadata().offset_index().unwrap()[0]
+                    .iter()
+                    .map(|index| {
+                        index
+                            .page_locations
+                            .iter()
+                            .map(|l| l.first_row_index)
+                            .collect::<Vec<_>>()
+                    })
+                    .collect::<Vec<_>>()
+            };
+            assert_eq!(first_row_indexes(&copy), first_row_indexes(&reader));
+        }
+    }
+
     #[test]
     fn test_file_writer_empty_row_groups() {
         let file = tempfile::tempfile().unwrap();
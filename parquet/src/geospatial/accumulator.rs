@@ -0,0 +1,343 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Computes [`GeospatialStatistics`] from WKB encoded values
+
+use super::{BoundingBox, GeospatialStatistics};
+use crate::basic::LogicalType;
+use crate::schema::types::ColumnDescriptor;
+use std::collections::BTreeSet;
+
+/// The maximum nesting of geometry collections
+const MAX_DEPTH: usize = 32;
+
+/// EWKB flags, which some writers use in place of the ISO WKB type codes
+const EWKB_Z: u32 = 0x8000_0000;
+const EWKB_M: u32 = 0x4000_0000;
+const EWKB_SRID: u32 = 0x2000_0000;
+
+/// Accumulates the [`GeospatialStatistics`] of the WKB values written to a column chunk
+///
+/// Values that are not valid WKB do not fail the write, instead no statistics are
+/// written for the column chunk.
+#[derive(Debug)]
+pub(crate) struct GeospatialAccumulator {
+    /// Whether to compute a bounding box
+    ///
+    /// Only computed for geometry columns, as the bounding box of features with
+    /// non-planar edges cannot be derived from their vertices alone
+    compute_bbox: bool,
+    x: Range,
+    y: Range,
+    z: Range,
+    m: Range,
+    types: BTreeSet<i32>,
+    valid: bool,
+}
+
+impl GeospatialAccumulator {
+    /// Returns a [`GeospatialAccumulator`] if `descr` is a geospatial column
+    pub(crate) fn try_new(descr: &ColumnDescriptor) -> Option<Self> {
+        let compute_bbox = match descr.logical_type()? {
+            LogicalType::Geometry { .. } => true,
+            LogicalType::Geography { .. } => false,
+            _ => return None,
+        };
+        Some(Self {
+            compute_bbox,
+            x: Range::default(),
+            y: Range::default(),
+            z: Range::default(),
+            m: Range::default(),
+            types: BTreeSet::new(),
+            valid: true,
+        })
+    }
+
+    /// Updates the statistics with a WKB encoded value
+    pub(crate) fn update(&mut self, wkb: &[u8]) {
+        if !self.valid {
+            return;
+        }
+        let mut reader = WkbReader {
+            buf: wkb,
+            offset: 0,
+        };
+        self.valid = reader.read_geometry(self, 0).is_some() && reader.offset == wkb.len();
+    }
+
+    /// Returns the accumulated statistics, resetting this accumulator
+    pub(crate) fn finish(&mut self) -> Option<Box<GeospatialStatistics>> {
+        let valid = std::mem::replace(&mut self.valid, true);
+        let x = std::mem::take(&mut self.x);
+        let y = std::mem::take(&mut self.y);
+        let z = std::mem::take(&mut self.z);
+        let m = std::mem::take(&mut self.m);
+        let types = std::mem::take(&mut self.types);
+        if !valid {
+            return None;
+        }
+
+        let bbox = match (x.get(), y.get()) {
+            (Some((xmin, xmax)), Some((ymin, ymax))) if self.compute_bbox => {
+                let mut bbox = BoundingBox::new(xmin, xmax, ymin, ymax);
+                if let Some((zmin, zmax)) = z.get() {
+                    bbox = bbox.with_zrange(zmin, zmax);
+                }
+                if let Some((mmin, mmax)) = m.get() {
+                    bbox = bbox.with_mrange(mmin, mmax);
+                }
+                Some(bbox)
+            }
+            _ => None,
+        };
+        let types = (!types.is_empty()).then(|| types.into_iter().collect());
+        Some(Box::new(GeospatialStatistics::new(bbox, types)))
+    }
+}
+
+/// The range of the non-NaN values of a coordinate
+#[derive(Debug, Clone, Copy)]
+struct Range {
+    min: f64,
+    max: f64,
+}
+
+impl Default for Range {
+    fn default() -> Self {
+        Self {
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+}
+
+impl Range {
+    fn update(&mut self, value: f64) {
+        // NaN coordinates denote empty points, and are ignored
+        if !value.is_nan() {
+            self.min = self.min.min(value);
+            self.max = self.max.max(value);
+        }
+    }
+
+    fn get(&self) -> Option<(f64, f64)> {
+        (self.min <= self.max).then_some((self.min, self.max))
+    }
+}
+
+/// A minimal reader of ISO and extended WKB
+struct WkbReader<'a> {
+    buf: &'a [u8],
+    offset: usize,
+}
+
+impl WkbReader<'_> {
+    fn read_bytes<const N: usize>(&mut self) -> Option<[u8; N]> {
+        let end = self.offset.checked_add(N)?;
+        let bytes = self.buf.get(self.offset..end)?.try_into().ok()?;
+        self.offset = end;
+        Some(bytes)
+    }
+
+    fn read_u32(&mut self, little_endian: bool) -> Option<u32> {
+        let bytes = self.read_bytes::<4>()?;
+        Some(match little_endian {
+            true => u32::from_le_bytes(bytes),
+            false => u32::from_be_bytes(bytes),
+        })
+    }
+
+    fn read_f64(&mut self, little_endian: bool) -> Option<f64> {
+        let bytes = self.read_bytes::<8>()?;
+        Some(match little_endian {
+            true => f64::from_le_bytes(bytes),
+            false => f64::from_be_bytes(bytes),
+        })
+    }
+
+    /// Reads a count of `element_size` byte elements, checking they fit in the buffer
+    fn read_count(&mut self, little_endian: bool, element_size: usize) -> Option<usize> {
+        let count = self.read_u32(little_endian)? as usize;
+        let remaining = self.buf.len() - self.offset;
+        (count.checked_mul(element_size)? <= remaining).then_some(count)
+    }
+
+    fn read_geometry(&mut self, acc: &mut GeospatialAccumulator, depth: usize) -> Option<()> {
+        if depth > MAX_DEPTH {
+            return None;
+        }
+        let little_endian = match self.read_bytes::<1>()? {
+            [0] => false,
+            [1] => true,
+            _ => return None,
+        };
+        let type_code = self.read_u32(little_endian)?;
+        if type_code & EWKB_SRID != 0 {
+            self.read_u32(little_endian)?;
+        }
+
+        let iso_code = type_code & 0x0FFF_FFFF;
+        let (kind, dimensions) = (iso_code % 1000, iso_code / 1000);
+        let has_z = type_code & EWKB_Z != 0 || matches!(dimensions, 1 | 3);
+        let has_m = type_code & EWKB_M != 0 || matches!(dimensions, 2 | 3);
+        if dimensions > 3 {
+            return None;
+        }
+
+        if depth == 0 {
+            let dimensions = match (has_z, has_m) {
+                (false, false) => 0,
+                (true, false) => 1,
+                (false, true) => 2,
+                (true, true) => 3,
+            };
+            acc.types.insert((dimensions * 1000 + kind) as i32);
+        }
+
+        let coord_size = 8 * (2 + has_z as usize + has_m as usize);
+        match kind {
+            // Point
+            1 => self.read_coord(acc, little_endian, has_z, has_m),
+            // LineString
+            2 => {
+                let count = self.read_count(little_endian, coord_size)?;
+                (0..count).try_for_each(|_| self.read_coord(acc, little_endian, has_z, has_m))
+            }
+            // Polygon
+            3 => {
+                let rings = self.read_count(little_endian, 4)?;
+                (0..rings).try_for_each(|_| {
+                    let count = self.read_count(little_endian, coord_size)?;
+                    (0..count).try_for_each(|_| self.read_coord(acc, little_endian, has_z, has_m))
+                })
+            }
+            // MultiPoint, MultiLineString, MultiPolygon, GeometryCollection
+            4..=7 => {
+                let count = self.read_count(little_endian, 5)?;
+                (0..count).try_for_each(|_| self.read_geometry(acc, depth + 1))
+            }
+            _ => None,
+        }
+    }
+
+    fn read_coord(
+        &mut self,
+        acc: &mut GeospatialAccumulator,
+        little_endian: bool,
+        has_z: bool,
+        has_m: bool,
+    ) -> Option<()> {
+        let x = self.read_f64(little_endian)?;
+        let y = self.read_f64(little_endian)?;
+        acc.x.update(x);
+        acc.y.update(y);
+        if has_z {
+            acc.z.update(self.read_f64(little_endian)?);
+        }
+        if has_m {
+            acc.m.update(self.read_f64(little_endian)?);
+        }
+        Some(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::basic::Type as PhysicalType;
+    use crate::schema::types::{ColumnPath, Type};
+    use std::sync::Arc;
+
+    /// Encodes a little endian WKB point
+    fn wkb_point(x: f64, y: f64) -> Vec<u8> {
+        let mut wkb = vec![1, 1, 0, 0, 0];
+        wkb.extend_from_slice(&x.to_le_bytes());
+        wkb.extend_from_slice(&y.to_le_bytes());
+        wkb
+    }
+
+    fn descriptor(logical_type: LogicalType) -> ColumnDescriptor {
+        let tpe = Type::primitive_type_builder("geo", PhysicalType::BYTE_ARRAY)
+            .with_logical_type(Some(logical_type))
+            .build()
+            .unwrap();
+        ColumnDescriptor::new(Arc::new(tpe), 0, 0, ColumnPath::from("geo"))
+    }
+
+    #[test]
+    fn test_accumulator() {
+        let mut acc =
+            GeospatialAccumulator::try_new(&descriptor(LogicalType::Geometry { crs: None }))
+                .unwrap();
+
+        acc.update(&wkb_point(1.0, 2.0));
+        acc.update(&wkb_point(f64::NAN, f64::NAN));
+
+        // Big endian LineString Z
+        let mut line = vec![0, 0, 0, 0x03, 0xEA, 0, 0, 0, 2];
+        for v in [-5.0_f64, 0.0, 3.0, 4.0, 10.0, -1.0] {
+            line.extend_from_slice(&v.to_be_bytes());
+        }
+        acc.update(&line);
+
+        // MultiPoint with a single point
+        let mut multi = vec![1, 4, 0, 0, 0, 1, 0, 0, 0];
+        multi.extend_from_slice(&wkb_point(7.0, -8.0));
+        acc.update(&multi);
+
+        let stats = acc.finish().unwrap();
+        let bbox = stats.bbox().unwrap();
+        assert_eq!(
+            (bbox.xmin(), bbox.xmax(), bbox.ymin(), bbox.ymax()),
+            (-5.0, 7.0, -8.0, 10.0)
+        );
+        assert_eq!((bbox.zmin(), bbox.zmax()), (Some(-1.0), Some(3.0)));
+        assert_eq!(bbox.mmin(), None);
+        assert_eq!(stats.geospatial_types(), Some([1, 4, 1002].as_slice()));
+
+        // Accumulator is reset
+        acc.update(&wkb_point(1.0, 1.0));
+        let stats = acc.finish().unwrap();
+        assert_eq!(stats.bbox().unwrap().xmax(), 1.0);
+        assert_eq!(stats.geospatial_types(), Some([1].as_slice()));
+
+        // Invalid WKB disables statistics
+        acc.update(&wkb_point(1.0, 1.0));
+        acc.update(&[1, 1, 0, 0, 0, 0]);
+        assert!(acc.finish().is_none());
+
+        // Huge counts are rejected without allocating
+        acc.update(&[1, 2, 0, 0, 0, 0xFF, 0xFF, 0xFF, 0xFF]);
+        assert!(acc.finish().is_none());
+    }
+
+    #[test]
+    fn test_accumulator_geography() {
+        let descr = descriptor(LogicalType::Geography {
+            crs: None,
+            algorithm: None,
+        });
+        let mut acc = GeospatialAccumulator::try_new(&descr).unwrap();
+        acc.update(&wkb_point(1.0, 2.0));
+        let stats = acc.finish().unwrap();
+        assert!(stats.bbox().is_none());
+        assert_eq!(stats.geospatial_types(), Some([1].as_slice()));
+
+        assert!(GeospatialAccumulator::try_new(&descriptor(LogicalType::String)).is_none());
+    }
+}
@@ -0,0 +1,232 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Statistics for columns annotated with the [`Geometry`] and [`Geography`] logical types
+//!
+//! The values of such columns are geospatial features encoded as [Well-Known Binary] (WKB).
+//! In place of min/max statistics, which are undefined for these columns, the writer may
+//! record [`GeospatialStatistics`] in the column chunk metadata, see
+//! [`ColumnChunkMetaData::geo_statistics`].
+//!
+//! [`Geometry`]: crate::basic::LogicalType::Geometry
+//! [`Geography`]: crate::basic::LogicalType::Geography
+//! [Well-Known Binary]: https://libgeos.org/specifications/wkb/
+//! [`ColumnChunkMetaData::geo_statistics`]: crate::file::metadata::ColumnChunkMetaData::geo_statistics
+
+use crate::file::metadata::HeapSize;
+use crate::format::{BoundingBox as TBoundingBox, GeospatialStatistics as TGeospatialStatistics};
+use thrift::OrderedFloat;
+
+mod accumulator;
+
+pub(crate) use accumulator::GeospatialAccumulator;
+
+/// The bounding box of the geospatial features in a column chunk
+///
+/// The X and Y ranges are always present, the Z and M ranges are only present if
+/// any feature has Z or M coordinates respectively.
+///
+/// For [`Geography`] columns, `xmin` may be greater than `xmax`, indicating a bounding
+/// box that wraps around the antimeridian.
+///
+/// [`Geography`]: crate::basic::LogicalType::Geography
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingBox {
+    xmin: f64,
+    xmax: f64,
+    ymin: f64,
+    ymax: f64,
+    zmin: Option<f64>,
+    zmax: Option<f64>,
+    mmin: Option<f64>,
+    mmax: Option<f64>,
+}
+
+impl BoundingBox {
+    /// Create a new [`BoundingBox`] from the X and Y ranges
+    pub fn new(xmin: f64, xmax: f64, ymin: f64, ymax: f64) -> Self {
+        Self {
+            xmin,
+            xmax,
+            ymin,
+            ymax,
+            zmin: None,
+            zmax: None,
+            mmin: None,
+            mmax: None,
+        }
+    }
+
+    /// Set the Z range of this [`BoundingBox`]
+    pub fn with_zrange(self, zmin: f64, zmax: f64) -> Self {
+        Self {
+            zmin: Some(zmin),
+            zmax: Some(zmax),
+            ..self
+        }
+    }
+
+    /// Set the M range of this [`BoundingBox`]
+    pub fn with_mrange(self, mmin: f64, mmax: f64) -> Self {
+        Self {
+            mmin: Some(mmin),
+            mmax: Some(mmax),
+            ..self
+        }
+    }
+
+    /// Returns the minimum X coordinate
+    pub fn xmin(&self) -> f64 {
+        self.xmin
+    }
+
+    /// Returns the maximum X coordinate
+    pub fn xmax(&self) -> f64 {
+        self.xmax
+    }
+
+    /// Returns the minimum Y coordinate
+    pub fn ymin(&self) -> f64 {
+        self.ymin
+    }
+
+    /// Returns the maximum Y coordinate
+    pub fn ymax(&self) -> f64 {
+        self.ymax
+    }
+
+    /// Returns the minimum Z coordinate, if any
+    pub fn zmin(&self) -> Option<f64> {
+        self.zmin
+    }
+
+    /// Returns the maximum Z coordinate, if any
+    pub fn zmax(&self) -> Option<f64> {
+        self.zmax
+    }
+
+    /// Returns the minimum M value, if any
+    pub fn mmin(&self) -> Option<f64> {
+        self.mmin
+    }
+
+    /// Returns the maximum M value, if any
+    pub fn mmax(&self) -> Option<f64> {
+        self.mmax
+    }
+}
+
+/// Statistics of the geospatial features in a column chunk
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeospatialStatistics {
+    bbox: Option<BoundingBox>,
+    geospatial_types: Option<Vec<i32>>,
+}
+
+impl GeospatialStatistics {
+    /// Create new [`GeospatialStatistics`]
+    ///
+    /// `geospatial_types` are the distinct [WKB geometry type codes] of the features, for
+    /// example `1` for a 2D point or `1003` for a polygon with Z coordinates.
+    ///
+    /// [WKB geometry type codes]: https://github.com/apache/parquet-format/blob/master/Geospatial.md#geospatial-types
+    pub fn new(bbox: Option<BoundingBox>, geospatial_types: Option<Vec<i32>>) -> Self {
+        Self {
+            bbox,
+            geospatial_types,
+        }
+    }
+
+    /// Returns the bounding box of the features, if known
+    pub fn bbox(&self) -> Option<&BoundingBox> {
+        self.bbox.as_ref()
+    }
+
+    /// Returns the sorted, distinct WKB geometry type codes of the features, if known
+    pub fn geospatial_types(&self) -> Option<&[i32]> {
+        self.geospatial_types.as_deref()
+    }
+}
+
+impl HeapSize for GeospatialStatistics {
+    fn heap_size(&self) -> usize {
+        self.geospatial_types.heap_size()
+    }
+}
+
+/// Converts thrift [`TGeospatialStatistics`] to [`GeospatialStatistics`]
+pub(crate) fn from_thrift(
+    stats: Option<TGeospatialStatistics>,
+) -> Option<Box<GeospatialStatistics>> {
+    let stats = stats?;
+    let bbox = stats.bbox.map(|bbox| {
+        let mut ret = BoundingBox::new(*bbox.xmin, *bbox.xmax, *bbox.ymin, *bbox.ymax);
+        if let (Some(zmin), Some(zmax)) = (bbox.zmin, bbox.zmax) {
+            ret = ret.with_zrange(*zmin, *zmax);
+        }
+        if let (Some(mmin), Some(mmax)) = (bbox.mmin, bbox.mmax) {
+            ret = ret.with_mrange(*mmin, *mmax);
+        }
+        ret
+    });
+    Some(Box::new(GeospatialStatistics::new(
+        bbox,
+        stats.geospatial_types,
+    )))
+}
+
+/// Converts [`GeospatialStatistics`] to thrift [`TGeospatialStatistics`]
+pub(crate) fn to_thrift(stats: Option<&GeospatialStatistics>) -> Option<TGeospatialStatistics> {
+    let stats = stats?;
+    let bbox = stats.bbox.map(|bbox| TBoundingBox {
+        xmin: OrderedFloat(bbox.xmin),
+        xmax: OrderedFloat(bbox.xmax),
+        ymin: OrderedFloat(bbox.ymin),
+        ymax: OrderedFloat(bbox.ymax),
+        zmin: bbox.zmin.map(OrderedFloat),
+        zmax: bbox.zmax.map(OrderedFloat),
+        mmin: bbox.mmin.map(OrderedFloat),
+        mmax: bbox.mmax.map(OrderedFloat),
+    });
+    Some(TGeospatialStatistics {
+        bbox,
+        geospatial_types: stats.geospatial_types.clone(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_geospatial_statistics_thrift() {
+        let bbox = BoundingBox::new(-1.0, 2.0, -3.0, 4.0).with_mrange(0.0, 10.0);
+        let stats = GeospatialStatistics::new(Some(bbox), Some(vec![1, 2003]));
+
+        let thrift = to_thrift(Some(&stats)).unwrap();
+        assert_eq!(*thrift.bbox.as_ref().unwrap().xmax, 2.0);
+        assert!(thrift.bbox.as_ref().unwrap().zmin.is_none());
+
+        let roundtrip = from_thrift(Some(thrift)).unwrap();
+        assert_eq!(roundtrip.as_ref(), &stats);
+        assert_eq!(roundtrip.bbox().unwrap().mmax(), Some(10.0));
+        assert_eq!(roundtrip.geospatial_types(), Some([1, 2003].as_slice()));
+
+        assert!(from_thrift(None).is_none());
+        assert!(to_thrift(None).is_none());
+    }
+}
@@ -175,6 +175,7 @@ pub mod bloom_filter;
 experimental!(pub mod encryption);
 
 pub mod file;
+pub mod geospatial;
 pub mod record;
 pub mod schema;
 
@@ -45,7 +45,9 @@
 
 use std::{fmt, io};
 
-use crate::basic::{ConvertedType, LogicalType, TimeUnit, Type as PhysicalType};
+use crate::basic::{
+    ConvertedType, EdgeInterpolationAlgorithm, LogicalType, TimeUnit, Type as PhysicalType,
+};
 use crate::file::metadata::{ColumnChunkMetaData, FileMetaData, ParquetMetaData, RowGroupMetaData};
 use crate::schema::types::Type;
 
@@ -283,6 +285,18 @@ fn print_timeunit(unit: &TimeUnit) -> &str {
     }
 }
 
+#[inline]
+fn print_edge_interpolation_algorithm(algorithm: EdgeInterpolationAlgorithm) -> String {
+    match algorithm {
+        EdgeInterpolationAlgorithm::SPHERICAL => "SPHERICAL".to_string(),
+        EdgeInterpolationAlgorithm::VINCENTY => "VINCENTY".to_string(),
+        EdgeInterpolationAlgorithm::THOMAS => "THOMAS".to_string(),
+        EdgeInterpolationAlgorithm::ANDOYER => "ANDOYER".to_string(),
+        EdgeInterpolationAlgorithm::KARNEY => "KARNEY".to_string(),
+        EdgeInterpolationAlgorithm(other) => format!("UNKNOWN({other})"),
+    }
+}
+
 #[inline]
 fn print_logical_and_converted(
     logical_type: Option<&LogicalType>,
@@ -327,8 +341,18 @@ fn print_logical_and_converted(
             LogicalType::Map => "MAP".to_string(),
             LogicalType::Float16 => "FLOAT16".to_string(),
             LogicalType::Variant => "VARIANT".to_string(),
-            LogicalType::Geometry => "GEOMETRY".to_string(),
-            LogicalType::Geography => "GEOGRAPHY".to_string(),
+            LogicalType::Geometry { crs: None } => "GEOMETRY".to_string(),
+            LogicalType::Geometry { crs: Some(crs) } => format!("GEOMETRY({crs})"),
+            LogicalType::Geography { crs, algorithm } => match (crs, algorithm) {
+                (None, None) => "GEOGRAPHY".to_string(),
+                (crs, algorithm) => format!(
+                    "GEOGRAPHY({},{})",
+                    crs.as_deref().unwrap_or("OGC:CRS84"),
+                    print_edge_interpolation_algorithm(
+                        algorithm.unwrap_or(EdgeInterpolationAlgorithm::SPHERICAL)
+                    )
+                ),
+            },
             LogicalType::Unknown => "UNKNOWN".to_string(),
         },
         None => {
@@ -855,6 +879,27 @@ mod tests {
                     .unwrap(),
                 "REQUIRED FIXED_LEN_BYTE_ARRAY (2) float16 (FLOAT16);",
             ),
+            (
+                Type::primitive_type_builder("geometry", PhysicalType::BYTE_ARRAY)
+                    .with_logical_type(Some(LogicalType::Geometry {
+                        crs: Some("srid:3857".to_string()),
+                    }))
+                    .with_repetition(Repetition::OPTIONAL)
+                    .build()
+                    .unwrap(),
+                "OPTIONAL BYTE_ARRAY geometry (GEOMETRY(srid:3857));",
+            ),
+            (
+                Type::primitive_type_builder("geography", PhysicalType::BYTE_ARRAY)
+                    .with_logical_type(Some(LogicalType::Geography {
+                        crs: None,
+                        algorithm: Some(EdgeInterpolationAlgorithm::VINCENTY),
+                    }))
+                    .with_repetition(Repetition::OPTIONAL)
+                    .build()
+                    .unwrap(),
+                "OPTIONAL BYTE_ARRAY geography (GEOGRAPHY(OGC:CRS84,VINCENTY));",
+            ),
         ];
 
         types_and_strings.into_iter().for_each(|(field, expected)| {
@@ -47,6 +47,7 @@ pub trait DataPageBuilder {
 pub struct DataPageBuilderImpl {
     encoding: Option<Encoding>,
     num_values: u32,
+    num_rows: Option<u32>,
     buffer: Vec<u8>,
     rep_levels_byte_len: u32,
     def_levels_byte_len: u32,
@@ -62,6 +63,7 @@ impl DataPageBuilderImpl {
         DataPageBuilderImpl {
             encoding: None,
             num_values,
+            num_rows: None,
             buffer: vec![],
             rep_levels_byte_len: 0,
             def_levels_byte_len: 0,
@@ -95,6 +97,9 @@ impl DataPageBuilderImpl {
 impl DataPageBuilder for DataPageBuilderImpl {
     fn add_rep_levels(&mut self, max_levels: i16, rep_levels: &[i16]) {
         self.num_values = rep_levels.len() as u32;
+        if max_levels > 0 {
+            self.num_rows = Some(rep_levels.iter().filter(|level| **level == 0).count() as u32);
+        }
         self.rep_levels_byte_len = self.add_levels(max_levels, rep_levels);
     }
 
@@ -146,8 +151,7 @@ impl DataPageBuilder for DataPageBuilderImpl {
                 encoding: self.encoding.unwrap(),
                 num_nulls: 0, /* set to dummy value - don't need this when reading
                                * data page */
-                num_rows: self.num_values, /* num_rows only needs in skip_records, now we not support skip REPEATED field,
-                                            * so we can assume num_values == num_rows */
+                num_rows: self.num_rows.unwrap_or(self.num_values),
                 def_levels_byte_len: self.def_levels_byte_len,
                 rep_levels_byte_len: self.rep_levels_byte_len,
                 is_compressed: false,
@@ -25,6 +25,11 @@ use parquet::arrow::arrow_reader::ArrowReaderBuilder;
 #[test]
 fn test_datapage_v1_corrupt_checksum() {
     let errors = read_file_batch_errors("datapage_v1-corrupt-checksum.parquet");
+    // Strip the column and page details from checksum errors
+    let errors: Vec<_> = errors
+        .into_iter()
+        .map(|r| r.map_err(|e| e.split(" for column").next().unwrap().to_string()))
+        .collect();
     assert_eq!(errors, [
         Err("Parquet argument error: Parquet error: Page CRC checksum mismatch".to_string()),
         Ok(()),
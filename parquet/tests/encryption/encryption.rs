@@ -28,10 +28,10 @@ use parquet::arrow::arrow_reader::{
     ArrowReaderMetadata, ArrowReaderOptions, ParquetRecordBatchReaderBuilder, RowSelection,
     RowSelector,
 };
-use parquet::arrow::ArrowWriter;
+use parquet::arrow::{ArrowWriter, ProjectionMask};
 use parquet::data_type::{ByteArray, ByteArrayType};
 use parquet::encryption::decrypt::FileDecryptionProperties;
-use parquet::encryption::encrypt::FileEncryptionProperties;
+use parquet::encryption::encrypt::{FileEncryptionProperties, KeyMetadataGenerator};
 use parquet::errors::ParquetError;
 use parquet::file::metadata::ParquetMetaData;
 use parquet::file::properties::WriterProperties;
@@ -788,6 +788,86 @@ fn test_write_non_uniform_encryption_column_missmatch() {
     );
 }
 
+/// Identifies keys by the name of the column they encrypt
+struct ColumnNameKeyMetadata;
+
+impl KeyMetadataGenerator for ColumnNameKeyMetadata {
+    fn generate_key_metadata(
+        &self,
+        _key: &[u8],
+        column_path: Option<&str>,
+    ) -> parquet::errors::Result<Vec<u8>> {
+        Ok(format!("key_{}", column_path.unwrap_or("footer")).into_bytes())
+    }
+}
+
+#[test]
+fn test_write_encrypted_column_subset_plaintext_footer() {
+    let batch = RecordBatch::try_from_iter([
+        ("a", Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef),
+        (
+            "b",
+            Arc::new(StringArray::from(vec!["x", "y", "z"])) as ArrayRef,
+        ),
+    ])
+    .unwrap();
+
+    let footer_key = b"0123456789012345".to_vec();
+    let column_key = b"1234567890123450".to_vec();
+    let encryption_properties = FileEncryptionProperties::builder(footer_key.clone())
+        .with_column_key("b", column_key.clone())
+        .with_plaintext_footer(true)
+        .with_key_metadata_generator(Arc::new(ColumnNameKeyMetadata))
+        .build()
+        .unwrap();
+    let props = WriterProperties::builder()
+        .with_file_encryption_properties(encryption_properties)
+        .build();
+
+    let temp_file = tempfile::tempfile().unwrap();
+    let mut writer =
+        ArrowWriter::try_new(temp_file.try_clone().unwrap(), batch.schema(), Some(props)).unwrap();
+    writer.write(&batch).unwrap();
+    writer.close().unwrap();
+
+    // A reader without decryption properties can read the metadata and the plaintext column
+    let builder = ParquetRecordBatchReaderBuilder::try_new(temp_file.try_clone().unwrap()).unwrap();
+    assert_eq!(builder.metadata().file_metadata().num_rows(), 3);
+    let mask = ProjectionMask::leaves(builder.parquet_schema(), [0]);
+    let batches = builder
+        .with_projection(mask)
+        .build()
+        .unwrap()
+        .collect::<ArrowResult<Vec<_>>>()
+        .unwrap();
+    assert_eq!(batches.len(), 1);
+    assert_eq!(batches[0].column(0).as_ref(), batch.column(0).as_ref());
+
+    // But not the encrypted column
+    let builder = ParquetRecordBatchReaderBuilder::try_new(temp_file.try_clone().unwrap()).unwrap();
+    let mask = ProjectionMask::leaves(builder.parquet_schema(), [1]);
+    let mut reader = builder.with_projection(mask).build().unwrap();
+    assert!(reader.next().unwrap().is_err());
+
+    // The generated key metadata lets a key retriever find the keys for all columns
+    let key_retriever = TestKeyRetriever::new()
+        .with_key("key_footer".to_owned(), footer_key)
+        .with_key("key_b".to_owned(), column_key);
+    let decryption_properties =
+        FileDecryptionProperties::with_key_retriever(Arc::new(key_retriever))
+            .build()
+            .unwrap();
+    let options =
+        ArrowReaderOptions::default().with_file_decryption_properties(decryption_properties);
+    let batches = ParquetRecordBatchReaderBuilder::try_new_with_options(temp_file, options)
+        .unwrap()
+        .build()
+        .unwrap()
+        .collect::<ArrowResult<Vec<_>>>()
+        .unwrap();
+    assert_eq!(batches, vec![batch]);
+}
+
 #[test]
 fn test_write_encrypted_column() {
     let message_type = "